@@ -0,0 +1,105 @@
+//! Fan-out cost of the internal quote bus: `QuoteUpdate` versus `PackedQuote`.
+//!
+//! Run with `cargo bench --bench quote_bus`. Each quote is broadcast to
+//! `RECEIVERS` subscribers and drained, which is what a live book update costs
+//! once several agents listen to the same WebSocket. The packed row includes
+//! interning and packing on the producer side.
+
+use std::time::Instant;
+
+use chrono::{TimeZone, Utc};
+use ploy::adapters::{PackedQuote, QuoteUpdate, TokenTable};
+use ploy::domain::{Quote, Side};
+use rust_decimal::Decimal;
+use tokio::sync::broadcast;
+
+const QUOTES: usize = 200_000;
+const RECEIVERS: usize = 4;
+const TOKENS: usize = 64;
+const ROUNDS: usize = 5;
+
+fn token_id(i: usize) -> String {
+    format!(
+        "7132104567925221259462638553270691275033272857194253228963137931245558399{:04}",
+        i % TOKENS
+    )
+}
+
+fn synthetic_quotes() -> Vec<(String, Quote)> {
+    let mut mid = 500i64; // in 0.001 units
+    (0..QUOTES)
+        .map(|i| {
+            mid = (mid + (i as i64 * 7919 % 5) - 2).clamp(20, 980);
+            let quote = Quote {
+                side: Side::Up,
+                best_bid: Some(Decimal::new(mid - 5, 3)),
+                best_ask: Some(Decimal::new(mid + 5, 3)),
+                bid_size: Some(Decimal::new(1000 + (i as i64 % 300) * 25, 1)),
+                ask_size: Some(Decimal::new(800 + (i as i64 % 170) * 40, 1)),
+                timestamp: Utc
+                    .timestamp_millis_opt(1_760_000_000_000 + i as i64 * 150)
+                    .unwrap(),
+            };
+            (token_id(i), quote)
+        })
+        .collect()
+}
+
+/// Best of `ROUNDS` timings
+fn best_of(run: impl Fn()) -> f64 {
+    (0..ROUNDS)
+        .map(|_| {
+            let start = Instant::now();
+            run();
+            start.elapsed().as_secs_f64()
+        })
+        .fold(f64::INFINITY, f64::min)
+}
+
+fn report(label: &str, item_bytes: usize, secs: f64) {
+    println!(
+        "{:<10} {:>6} B {:>14.0} quotes/s {:>10.1} ns/quote",
+        label,
+        item_bytes,
+        QUOTES as f64 / secs,
+        secs * 1e9 / QUOTES as f64
+    );
+}
+
+fn main() {
+    println!(
+        "{:<10} {:>8} {:>23} {:>18}  ({} receivers)",
+        "BUS", "ITEM", "THROUGHPUT", "LATENCY", RECEIVERS
+    );
+    let quotes = synthetic_quotes();
+
+    let secs = best_of(|| {
+        let (tx, _) = broadcast::channel::<QuoteUpdate>(1024);
+        let mut rxs: Vec<_> = (0..RECEIVERS).map(|_| tx.subscribe()).collect();
+        for (token_id, quote) in &quotes {
+            let _ = tx.send(QuoteUpdate {
+                token_id: token_id.clone(),
+                side: Side::Up,
+                quote: *quote,
+            });
+            for rx in &mut rxs {
+                std::hint::black_box(rx.try_recv().unwrap());
+            }
+        }
+    });
+    report("decimal", std::mem::size_of::<QuoteUpdate>(), secs);
+
+    let secs = best_of(|| {
+        let tokens = TokenTable::new();
+        let (tx, _) = broadcast::channel::<PackedQuote>(1024);
+        let mut rxs: Vec<_> = (0..RECEIVERS).map(|_| tx.subscribe()).collect();
+        for (token_id, quote) in &quotes {
+            let token = tokens.intern(token_id);
+            let _ = tx.send(PackedQuote::from_quote(token, Side::Up, quote));
+            for rx in &mut rxs {
+                std::hint::black_box(rx.try_recv().unwrap());
+            }
+        }
+    });
+    report("packed", std::mem::size_of::<PackedQuote>(), secs);
+}
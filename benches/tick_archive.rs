@@ -0,0 +1,99 @@
+//! Read throughput of archived quote/depth history versus JSON rows.
+//!
+//! Run with `cargo bench --bench tick_archive`. Data is synthetic: a random
+//! walk of top-of-book quotes and 20-level books, roughly the shape of what
+//! the collectors record.
+
+use std::time::Instant;
+
+use ploy::collector::{
+    decode_depth_block, decode_quote_block, encode_depth_block, encode_quote_block, DepthBook,
+    DepthLevel, QuoteTick,
+};
+use ploy::domain::Side;
+use rust_decimal::Decimal;
+
+const QUOTES: usize = 500_000;
+const BOOKS: usize = 20_000;
+const LEVELS: usize = 20;
+const ROUNDS: usize = 5;
+
+fn synthetic_quotes() -> Vec<QuoteTick> {
+    let mut mid = 500i64; // in 0.001 units
+    (0..QUOTES)
+        .map(|i| {
+            mid = (mid + (i as i64 * 7919 % 5) - 2).clamp(20, 980);
+            QuoteTick {
+                token_id:
+                    "71321045679252212594626385532706912750332728571942532289631379312455583992563"
+                        .to_string(),
+                side: Side::Up,
+                ts_ms: 1_760_000_000_000 + i as i64 * 150,
+                best_bid: Some(Decimal::new(mid - 5, 3)),
+                best_ask: Some(Decimal::new(mid + 5, 3)),
+                bid_size: Some(Decimal::new(1000 + (i as i64 % 300) * 25, 1)),
+                ask_size: Some(Decimal::new(800 + (i as i64 % 170) * 40, 1)),
+            }
+        })
+        .collect()
+}
+
+fn synthetic_books() -> Vec<DepthBook> {
+    (0..BOOKS)
+        .map(|i| {
+            let mid = 400 + (i as i64 % 200);
+            let level = |price: i64, j: usize| DepthLevel {
+                price: Decimal::new(price, 3),
+                size: Decimal::new(500 + ((i + j) as i64 % 97) * 130, 2),
+            };
+            DepthBook {
+                token_id:
+                    "71321045679252212594626385532706912750332728571942532289631379312455583992563"
+                        .to_string(),
+                ts_ms: 1_760_000_000_000 + i as i64 * 1_000,
+                bids: (0..LEVELS).map(|j| level(mid - 5 - j as i64, j)).collect(),
+                asks: (0..LEVELS).map(|j| level(mid + 5 + j as i64, j)).collect(),
+            }
+        })
+        .collect()
+}
+
+fn report(label: &str, records: usize, bytes: usize, secs: f64) {
+    println!(
+        "{:<14} {:>10.1} MB {:>14.0} records/s",
+        label,
+        bytes as f64 / 1e6,
+        records as f64 / secs
+    );
+}
+
+/// Best of `ROUNDS` decode timings
+fn time_decode<T>(decode: impl Fn() -> Vec<T>) -> f64 {
+    (0..ROUNDS)
+        .map(|_| {
+            let start = Instant::now();
+            std::hint::black_box(decode());
+            start.elapsed().as_secs_f64()
+        })
+        .fold(f64::INFINITY, f64::min)
+}
+
+fn main() {
+    println!("{:<14} {:>13} {:>24}", "FORMAT", "SIZE", "READ THROUGHPUT");
+
+    let quotes = synthetic_quotes();
+    let json = serde_json::to_vec(&quotes).unwrap();
+    let secs = time_decode(|| serde_json::from_slice::<Vec<QuoteTick>>(&json).unwrap());
+    report("quotes/json", QUOTES, json.len(), secs);
+    let block = encode_quote_block(&quotes).unwrap();
+    let secs = time_decode(|| decode_quote_block(&block).unwrap());
+    report("quotes/archive", QUOTES, block.len(), secs);
+
+    let books = synthetic_books();
+    let json = serde_json::to_vec(&books).unwrap();
+    let secs = time_decode(|| serde_json::from_slice::<Vec<DepthBook>>(&json).unwrap());
+    report("depth/json", BOOKS, json.len(), secs);
+    let block = encode_depth_block(&books).unwrap();
+    let secs = time_decode(|| decode_depth_block(&block).unwrap());
+    report("depth/archive", BOOKS, block.len(), secs);
+}
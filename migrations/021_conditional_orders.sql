@@ -0,0 +1,21 @@
+-- Migration: 021_conditional_orders
+-- Purpose: Client-side stop-limit / trigger orders (Polymarket has no native stops)
+
+CREATE TABLE IF NOT EXISTS conditional_orders (
+    id UUID PRIMARY KEY,
+    strategy_id TEXT,
+    condition JSONB NOT NULL,       -- TriggerCondition (price / sum / time)
+    order_request JSONB NOT NULL,   -- OrderRequest submitted on trigger
+    state TEXT NOT NULL DEFAULT 'ARMED'
+        CHECK (state IN ('ARMED', 'TRIGGERED', 'SUBMITTED', 'CANCELLED', 'FAILED')),
+    exchange_order_id TEXT,
+    error TEXT,
+    created_at TIMESTAMPTZ NOT NULL DEFAULT NOW(),
+    triggered_at TIMESTAMPTZ,
+    updated_at TIMESTAMPTZ NOT NULL DEFAULT NOW()
+);
+
+-- Recovery scans only look at live triggers.
+CREATE INDEX IF NOT EXISTS idx_conditional_orders_live
+    ON conditional_orders(state)
+    WHERE state IN ('ARMED', 'TRIGGERED');
@@ -0,0 +1,5 @@
+-- Migration: 022_cycle_checkpoints
+-- Purpose: Persist StrategyEngine cycle checkpoints so crash recovery can resume
+-- hedged-pending cycles instead of aborting them
+
+ALTER TABLE cycles ADD COLUMN IF NOT EXISTS checkpoint JSONB;
@@ -0,0 +1,25 @@
+-- Migration: 023_orderbook_depth_snapshots
+-- Purpose: Compressed N-level CLOB depth snapshots (live `GET /book` capture and
+-- orderbook-history backfill) for book replay and execution research
+
+CREATE TABLE IF NOT EXISTS clob_orderbook_depth_snapshots (
+    id BIGSERIAL PRIMARY KEY,
+    token_id TEXT NOT NULL,
+    condition_id TEXT NOT NULL DEFAULT '',
+    book_ts_ms BIGINT NOT NULL,
+    book_ts TIMESTAMPTZ NOT NULL,
+    hash TEXT NOT NULL DEFAULT '',
+    levels INT NOT NULL,
+    best_bid NUMERIC,
+    best_ask NUMERIC,
+    codec TEXT NOT NULL,            -- e.g. 'json+gzip/v1'
+    payload BYTEA NOT NULL,         -- compressed {"b": [...], "a": [...]}
+    raw_bytes INT NOT NULL,         -- uncompressed payload size
+    source TEXT NOT NULL,
+    collected_at TIMESTAMPTZ NOT NULL DEFAULT NOW()
+);
+
+CREATE UNIQUE INDEX IF NOT EXISTS uniq_clob_orderbook_depth_snapshots
+    ON clob_orderbook_depth_snapshots(token_id, book_ts_ms, hash);
+CREATE INDEX IF NOT EXISTS idx_clob_orderbook_depth_snapshots_token_time
+    ON clob_orderbook_depth_snapshots(token_id, book_ts_ms DESC);
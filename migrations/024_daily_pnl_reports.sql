@@ -0,0 +1,22 @@
+-- Migration: 024_daily_pnl_reports
+-- Purpose: Daily PnL / risk reports compiled at UTC day close by the reporting service
+
+CREATE TABLE IF NOT EXISTS daily_pnl_reports (
+    report_date DATE NOT NULL,
+    account_id TEXT NOT NULL DEFAULT '',
+    generated_at TIMESTAMPTZ NOT NULL DEFAULT NOW(),
+    realized_pnl NUMERIC(20,6) NOT NULL,
+    unrealized_pnl NUMERIC(20,6) NOT NULL,
+    fees NUMERIC(20,6) NOT NULL,
+    gas NUMERIC(20,6) NOT NULL,
+    net_pnl NUMERIC(20,6) NOT NULL,
+    win_rate DOUBLE PRECISION,
+    max_drawdown NUMERIC(20,6) NOT NULL,
+    peak_exposure NUMERIC(20,6) NOT NULL,
+    report JSONB NOT NULL,
+    markdown TEXT NOT NULL,
+    PRIMARY KEY (report_date, account_id)
+);
+
+CREATE INDEX IF NOT EXISTS idx_daily_pnl_reports_generated_at
+    ON daily_pnl_reports (generated_at DESC);
@@ -0,0 +1,226 @@
+-- no-transaction
+-- Migration: 025_tick_partitioning
+-- Purpose: Range-partition high-volume tick tables by time and record per-table
+-- retention policies. `ploy infra db-maintain` creates upcoming partitions, drops
+-- expired ones and reports table sizes.
+--
+-- Runs outside the migrator's transaction and commits between the steps of
+-- section 3, so the legacy-table scan happens under VALIDATE CONSTRAINT's SHARE
+-- UPDATE EXCLUSIVE lock (ingestion keeps writing) instead of under ATTACH
+-- PARTITION's ACCESS EXCLUSIVE. Every statement is idempotent, so a failed run
+-- can simply be retried.
+
+-- ============================================================
+-- 1. Retention policies
+-- ============================================================
+CREATE TABLE IF NOT EXISTS data_retention_policies (
+    table_name TEXT PRIMARY KEY,
+    time_column TEXT NOT NULL,
+    -- 'day' | 'month'; NULL = table is not partitioned (size reporting only)
+    partition_interval TEXT CHECK (partition_interval IN ('day', 'month')),
+    -- NULL = keep forever
+    retention_days INT CHECK (retention_days IS NULL OR retention_days > 0),
+    -- Partitions to create ahead of the current one
+    premake INT NOT NULL DEFAULT 3 CHECK (premake >= 0),
+    enabled BOOLEAN NOT NULL DEFAULT TRUE,
+    updated_at TIMESTAMPTZ NOT NULL DEFAULT NOW()
+);
+
+INSERT INTO data_retention_policies
+    (table_name, time_column, partition_interval, retention_days, premake)
+VALUES
+    ('sync_records', 'timestamp', 'day', 30, 3),
+    ('clob_quote_ticks', 'received_at', 'day', 30, 3),
+    ('binance_klines', 'open_time', NULL, NULL, 0)
+ON CONFLICT (table_name) DO NOTHING;
+
+-- ============================================================
+-- 2. Convert an existing table to a range-partitioned parent
+-- ============================================================
+-- The original table is renamed to `<table>_legacy` and attached as the first
+-- partition, covering everything up to the end of the current period, so no rows
+-- are copied. A DEFAULT partition catches rows if maintenance falls behind.
+--
+-- ATTACH PARTITION scans the table under ACCESS EXCLUSIVE unless a validated
+-- CHECK constraint already proves the partition bound, so conversion is three
+-- steps, each its own transaction:
+--   1. ploy_add_partition_bound: CHECK (<column> < end of period) NOT VALID
+--      (brief lock, no scan; new rows are checked from here on)
+--   2. ploy_validate_partition_bound: full scan, concurrent writes allowed
+--   3. ploy_convert_to_partitioned: rename, create parent, attach without a scan
+
+-- End of the period containing the newest row (or now, if later)
+CREATE OR REPLACE FUNCTION ploy_partition_upper_bound(
+    p_table TEXT,
+    p_column TEXT,
+    p_interval TEXT
+) RETURNS TIMESTAMPTZ AS $$
+DECLARE
+    upper_bound TIMESTAMPTZ;
+BEGIN
+    EXECUTE format(
+        'SELECT date_trunc(%L, GREATEST(COALESCE(MAX(%I), NOW()), NOW()) AT TIME ZONE ''UTC'')
+             AT TIME ZONE ''UTC'' + (''1 '' || %L)::INTERVAL
+         FROM %I',
+        p_interval, p_column, p_interval, p_table
+    ) INTO upper_bound;
+    RETURN upper_bound;
+END;
+$$ LANGUAGE plpgsql;
+
+CREATE OR REPLACE FUNCTION ploy_add_partition_bound(
+    p_table TEXT,
+    p_column TEXT,
+    p_interval TEXT
+) RETURNS BOOLEAN AS $$
+DECLARE
+    bound_name TEXT := left(p_table, 47) || '_partition_bound';
+BEGIN
+    IF to_regclass(p_table) IS NULL THEN
+        RETURN FALSE;
+    END IF;
+    IF EXISTS (
+        SELECT 1 FROM pg_partitioned_table WHERE partrelid = to_regclass(p_table)
+    ) OR EXISTS (
+        SELECT 1 FROM pg_constraint
+        WHERE conrelid = to_regclass(p_table) AND conname = bound_name
+    ) THEN
+        RETURN FALSE;
+    END IF;
+
+    EXECUTE format(
+        'ALTER TABLE %I ADD CONSTRAINT %I CHECK (%I IS NOT NULL AND %I < %L) NOT VALID',
+        p_table, bound_name, p_column, p_column,
+        ploy_partition_upper_bound(p_table, p_column, p_interval)
+    );
+    RETURN TRUE;
+END;
+$$ LANGUAGE plpgsql;
+
+CREATE OR REPLACE FUNCTION ploy_validate_partition_bound(p_table TEXT) RETURNS BOOLEAN AS $$
+DECLARE
+    bound_name TEXT := left(p_table, 47) || '_partition_bound';
+BEGIN
+    IF NOT EXISTS (
+        SELECT 1 FROM pg_constraint
+        WHERE conrelid = to_regclass(p_table) AND conname = bound_name AND NOT convalidated
+    ) THEN
+        RETURN FALSE;
+    END IF;
+
+    EXECUTE format('ALTER TABLE %I VALIDATE CONSTRAINT %I', p_table, bound_name);
+    RETURN TRUE;
+END;
+$$ LANGUAGE plpgsql;
+
+CREATE OR REPLACE FUNCTION ploy_convert_to_partitioned(
+    p_table TEXT,
+    p_column TEXT,
+    p_interval TEXT
+) RETURNS BOOLEAN AS $$
+DECLARE
+    legacy TEXT := p_table || '_legacy';
+    bound_name TEXT := left(p_table, 47) || '_partition_bound';
+    seq TEXT;
+    idx RECORD;
+    upper_bound TIMESTAMPTZ;
+BEGIN
+    IF to_regclass(p_table) IS NULL THEN
+        RETURN FALSE;
+    END IF;
+    IF EXISTS (
+        SELECT 1 FROM pg_partitioned_table WHERE partrelid = to_regclass(p_table)
+    ) THEN
+        RETURN FALSE;
+    END IF;
+
+    seq := pg_get_serial_sequence(p_table, 'id');
+
+    EXECUTE format('ALTER TABLE %I RENAME TO %I', p_table, legacy);
+    -- Index names are schema-global; free them up for the new parent
+    FOR idx IN
+        SELECT indexname FROM pg_indexes
+        WHERE schemaname = current_schema() AND tablename = legacy
+    LOOP
+        EXECUTE format(
+            'ALTER INDEX %I RENAME TO %I',
+            idx.indexname,
+            left(idx.indexname, 56) || '_legacy'
+        );
+    END LOOP;
+
+    EXECUTE format(
+        'CREATE TABLE %I (LIKE %I INCLUDING DEFAULTS INCLUDING CONSTRAINTS INCLUDING STORAGE)
+         PARTITION BY RANGE (%I)',
+        p_table, legacy, p_column
+    );
+    -- The bound only describes the legacy rows; the parent must not inherit it
+    EXECUTE format('ALTER TABLE %I DROP CONSTRAINT IF EXISTS %I', p_table, bound_name);
+    EXECUTE format('ALTER TABLE %I ADD PRIMARY KEY (id, %I)', p_table, p_column);
+
+    -- Keep the id sequence alive once the legacy partition expires
+    IF seq IS NOT NULL THEN
+        EXECUTE format('ALTER SEQUENCE %s OWNED BY %I.id', seq, p_table);
+    END IF;
+
+    -- Never below the validated bound, which then implies the partition
+    -- constraint and lets ATTACH skip its scan
+    upper_bound := ploy_partition_upper_bound(legacy, p_column, p_interval);
+
+    EXECUTE format(
+        'ALTER TABLE %I ATTACH PARTITION %I FOR VALUES FROM (MINVALUE) TO (%L)',
+        p_table, legacy, upper_bound
+    );
+    EXECUTE format('ALTER TABLE %I DROP CONSTRAINT IF EXISTS %I', legacy, bound_name);
+    EXECUTE format('CREATE TABLE %I PARTITION OF %I DEFAULT', p_table || '_default', p_table);
+
+    RETURN TRUE;
+END;
+$$ LANGUAGE plpgsql;
+
+COMMENT ON FUNCTION ploy_convert_to_partitioned IS
+    'Convert a table to RANGE partitioning, keeping existing rows in a <table>_legacy partition';
+
+-- ============================================================
+-- 3. Partition sync_records and clob_quote_ticks
+-- ============================================================
+-- `domain` used to be added at runtime; make sure the parent carries it
+ALTER TABLE clob_quote_ticks ADD COLUMN IF NOT EXISTS domain TEXT;
+
+SELECT ploy_add_partition_bound('sync_records', 'timestamp', 'day');
+SELECT ploy_add_partition_bound('clob_quote_ticks', 'received_at', 'day');
+COMMIT;
+
+SELECT ploy_validate_partition_bound('sync_records');
+SELECT ploy_validate_partition_bound('clob_quote_ticks');
+COMMIT;
+
+SELECT ploy_convert_to_partitioned('sync_records', 'timestamp', 'day');
+SELECT ploy_convert_to_partitioned('clob_quote_ticks', 'received_at', 'day');
+
+-- Recreate indexes on the parents; matching legacy indexes are attached, not rebuilt
+CREATE INDEX IF NOT EXISTS idx_sync_records_ts
+    ON sync_records(timestamp);
+CREATE INDEX IF NOT EXISTS idx_sync_records_symbol
+    ON sync_records(symbol);
+CREATE INDEX IF NOT EXISTS idx_sync_records_symbol_ts
+    ON sync_records(symbol, timestamp);
+
+CREATE INDEX IF NOT EXISTS idx_clob_quote_ticks_token_time
+    ON clob_quote_ticks(token_id, received_at DESC);
+CREATE INDEX IF NOT EXISTS idx_clob_quote_ticks_time
+    ON clob_quote_ticks(received_at DESC);
+CREATE INDEX IF NOT EXISTS idx_clob_quote_ticks_domain_time
+    ON clob_quote_ticks(domain, received_at DESC);
+
+-- Optional: grant to app role if present.
+DO $$
+BEGIN
+    IF EXISTS (SELECT 1 FROM pg_roles WHERE rolname = 'ploy') THEN
+        EXECUTE 'GRANT SELECT, INSERT, UPDATE, DELETE ON TABLE public.sync_records TO ploy';
+        EXECUTE 'GRANT SELECT, INSERT, UPDATE, DELETE ON TABLE public.clob_quote_ticks TO ploy';
+        EXECUTE 'GRANT SELECT ON TABLE public.data_retention_policies TO ploy';
+    END IF;
+END $$;
+
+COMMENT ON TABLE data_retention_policies IS 'Per-table partitioning and retention settings for ploy infra db-maintain';
@@ -0,0 +1,34 @@
+-- Migration: 027_api_rbac
+-- Purpose: Role-based API keys (viewer / operator / admin) and an audit log of
+-- every control action with the acting identity.
+
+CREATE TABLE IF NOT EXISTS api_keys (
+    id BIGSERIAL PRIMARY KEY,
+    name TEXT NOT NULL UNIQUE,
+    role TEXT NOT NULL CHECK (role IN ('viewer', 'operator', 'admin')),
+    -- SHA-256 hex of the key; the key itself is only shown on creation
+    key_hash TEXT NOT NULL UNIQUE,
+    -- First characters of the key, to recognise it in listings
+    key_prefix TEXT NOT NULL,
+    created_by TEXT NOT NULL,
+    created_at TIMESTAMPTZ NOT NULL DEFAULT NOW(),
+    last_used_at TIMESTAMPTZ,
+    revoked_at TIMESTAMPTZ
+);
+
+CREATE TABLE IF NOT EXISTS api_audit_log (
+    id BIGSERIAL PRIMARY KEY,
+    actor TEXT NOT NULL,
+    role TEXT NOT NULL,
+    key_id BIGINT REFERENCES api_keys(id) ON DELETE SET NULL,
+    -- e.g. system.pause, deployments.enable, api_keys.create
+    action TEXT NOT NULL,
+    allowed BOOLEAN NOT NULL,
+    details JSONB NOT NULL DEFAULT '{}'::jsonb,
+    created_at TIMESTAMPTZ NOT NULL DEFAULT NOW()
+);
+
+CREATE INDEX IF NOT EXISTS idx_api_audit_log_time
+    ON api_audit_log(created_at DESC);
+CREATE INDEX IF NOT EXISTS idx_api_audit_log_actor_time
+    ON api_audit_log(actor, created_at DESC);
@@ -0,0 +1,7 @@
+-- Migration: 028_dump_signal_thresholds
+-- Purpose: Record the drop threshold in effect for each dump signal, and the
+-- realized volatility it was scaled from, for threshold research.
+
+ALTER TABLE dump_signals
+    ADD COLUMN IF NOT EXISTS threshold_pct DECIMAL(10,6),
+    ADD COLUMN IF NOT EXISTS realized_vol DECIMAL(14,8);
@@ -0,0 +1,14 @@
+-- Migration: 029_intraday_loss_limit
+-- Purpose: Halts from the coordinator's intraday loss limit and the operator
+-- re-enable trail. The account-wide halt has its own flag so it never mixes
+-- with the engine circuit breaker's halted / halt_reason columns.
+
+ALTER TABLE daily_metrics
+    ADD COLUMN IF NOT EXISTS loss_limit_halted BOOLEAN NOT NULL DEFAULT FALSE,
+    ADD COLUMN IF NOT EXISTS loss_limit_reason TEXT,
+    ADD COLUMN IF NOT EXISTS halted_strategies TEXT[] NOT NULL DEFAULT '{}',
+    ADD COLUMN IF NOT EXISTS halted_at TIMESTAMPTZ,
+    ADD COLUMN IF NOT EXISTS reenabled_by TEXT,
+    ADD COLUMN IF NOT EXISTS reenabled_at TIMESTAMPTZ,
+    -- Scope PnL at its last re-enable; losses are measured from here
+    ADD COLUMN IF NOT EXISTS reenable_baselines JSONB NOT NULL DEFAULT '{}'::jsonb;
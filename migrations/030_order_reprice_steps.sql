@@ -0,0 +1,25 @@
+-- Migration: 030_order_reprice_steps
+-- Purpose: Audit trail of the executor's chase policy: every cancel + re-price
+-- of a resting limit order, with the quote it was priced against.
+
+CREATE TABLE IF NOT EXISTS order_reprice_steps (
+    id BIGSERIAL PRIMARY KEY,
+    account_id TEXT NOT NULL,
+    agent_id TEXT NOT NULL,
+    intent_id UUID NOT NULL,
+    step INTEGER NOT NULL,
+    replaced_order_id TEXT NOT NULL,
+    -- NULL when the re-submit failed
+    order_id TEXT,
+    from_price DECIMAL(10,6) NOT NULL,
+    to_price DECIMAL(10,6) NOT NULL,
+    best_bid DECIMAL(10,6),
+    best_ask DECIMAL(10,6),
+    filled_shares BIGINT NOT NULL,
+    remaining_shares BIGINT NOT NULL,
+    repriced_at TIMESTAMPTZ NOT NULL,
+    UNIQUE (intent_id, step)
+);
+
+CREATE INDEX IF NOT EXISTS idx_order_reprice_steps_agent_time
+    ON order_reprice_steps (agent_id, repriced_at DESC);
@@ -0,0 +1,15 @@
+-- Migration: 031_runtime_leader_leases
+-- Purpose: Heartbeat lease for hot-standby leader election. Mutual exclusion
+-- comes from a session-level advisory lock; this row lets standbys detect a
+-- leader that still holds the lock but stopped heartbeating, and fences stale
+-- leaders through the epoch.
+
+CREATE TABLE IF NOT EXISTS runtime_leader_leases (
+    lock_name TEXT PRIMARY KEY,
+    holder_id TEXT NOT NULL,
+    epoch BIGINT NOT NULL DEFAULT 1,
+    acquired_at TIMESTAMPTZ NOT NULL DEFAULT NOW(),
+    heartbeat_at TIMESTAMPTZ NOT NULL DEFAULT NOW(),
+    -- Set on graceful release so a standby can take over without waiting
+    released_at TIMESTAMPTZ
+);
@@ -0,0 +1,28 @@
+-- Migration: 032_order_queue_intents
+-- Purpose: Write-through copy of the coordinator's in-memory order queue, so
+-- intents that passed the risk gate but were not yet executed survive a
+-- restart. Rows are removed when an intent is dequeued, evicted or cancelled;
+-- on startup the remaining rows are re-validated and re-queued.
+
+CREATE TABLE IF NOT EXISTS order_queue_intents (
+    intent_id UUID PRIMARY KEY,
+    account_id TEXT NOT NULL DEFAULT 'default',
+    dry_run BOOLEAN NOT NULL DEFAULT FALSE,
+    agent_id TEXT NOT NULL,
+    domain TEXT NOT NULL,
+    market_slug TEXT NOT NULL,
+    token_id TEXT NOT NULL,
+    market_side TEXT NOT NULL,
+    is_buy BOOLEAN NOT NULL,
+    shares BIGINT NOT NULL,
+    limit_price DECIMAL(10,6) NOT NULL,
+    -- 0 = critical .. 3 = low
+    priority SMALLINT NOT NULL,
+    created_at TIMESTAMPTZ NOT NULL,
+    expires_at TIMESTAMPTZ,
+    metadata JSONB,
+    enqueued_at TIMESTAMPTZ NOT NULL DEFAULT NOW()
+);
+
+CREATE INDEX IF NOT EXISTS idx_order_queue_intents_account
+    ON order_queue_intents(account_id, dry_run, priority, created_at);
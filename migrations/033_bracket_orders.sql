@@ -0,0 +1,32 @@
+-- Migration: 033_bracket_orders
+-- Purpose: Entry + take-profit + stop-loss brackets built on conditional orders
+
+CREATE TABLE IF NOT EXISTS bracket_orders (
+    id UUID PRIMARY KEY,
+    strategy_id TEXT,
+    entry_request JSONB NOT NULL,   -- OrderRequest for the entry
+    take_profit NUMERIC(10,6) NOT NULL,
+    stop_loss NUMERIC(10,6) NOT NULL,
+    stop_limit NUMERIC(10,6) NOT NULL,
+    take_profit_id UUID NOT NULL,   -- conditional_orders.id of the TP leg
+    stop_loss_id UUID NOT NULL,     -- conditional_orders.id of the SL leg
+    state TEXT NOT NULL DEFAULT 'PENDING_ENTRY'
+        CHECK (state IN ('PENDING_ENTRY', 'ARMED', 'UNFILLED', 'FAILED')),
+    filled_shares BIGINT NOT NULL DEFAULT 0,
+    entry_order_id TEXT,
+    error TEXT,
+    created_at TIMESTAMPTZ NOT NULL DEFAULT NOW(),
+    updated_at TIMESTAMPTZ NOT NULL DEFAULT NOW()
+);
+
+-- Recovery only resumes brackets whose legs were never armed.
+CREATE INDEX IF NOT EXISTS idx_bracket_orders_pending
+    ON bracket_orders(state)
+    WHERE state = 'PENDING_ENTRY';
+
+-- Legs of one bracket cancel each other.
+ALTER TABLE conditional_orders ADD COLUMN IF NOT EXISTS bracket_id UUID;
+
+CREATE INDEX IF NOT EXISTS idx_conditional_orders_bracket
+    ON conditional_orders(bracket_id)
+    WHERE bracket_id IS NOT NULL;
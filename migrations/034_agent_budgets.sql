@@ -0,0 +1,34 @@
+-- Migration: 034_agent_budgets
+-- Purpose: Per-agent sandbox budgets (allocator ledger + operator-approved top-ups)
+
+CREATE TABLE IF NOT EXISTS agent_budget_ledger (
+    id BIGSERIAL PRIMARY KEY,
+    agent_id TEXT NOT NULL,
+    kind TEXT NOT NULL
+        CHECK (kind IN ('ALLOCATION', 'FILL', 'SETTLEMENT', 'TOP_UP')),
+    amount NUMERIC(20,6) NOT NULL,  -- USD; fills are negative
+    reference TEXT,                 -- intent / top-up id
+    created_at TIMESTAMPTZ NOT NULL DEFAULT NOW()
+);
+
+CREATE INDEX IF NOT EXISTS idx_agent_budget_ledger_agent
+    ON agent_budget_ledger(agent_id, created_at);
+
+CREATE TABLE IF NOT EXISTS agent_budget_top_ups (
+    id UUID PRIMARY KEY,
+    agent_id TEXT NOT NULL,
+    amount NUMERIC(20,6) NOT NULL CHECK (amount > 0),
+    status TEXT NOT NULL DEFAULT 'PENDING'
+        CHECK (status IN ('PENDING', 'APPROVED', 'DENIED', 'APPLIED')),
+    requested_by TEXT NOT NULL,
+    decided_by TEXT,
+    notified_at TIMESTAMPTZ,        -- Feishu approval card sent
+    created_at TIMESTAMPTZ NOT NULL DEFAULT NOW(),
+    decided_at TIMESTAMPTZ,
+    applied_at TIMESTAMPTZ
+);
+
+-- The coordinator polls open requests on every refresh.
+CREATE INDEX IF NOT EXISTS idx_agent_budget_top_ups_open
+    ON agent_budget_top_ups(status)
+    WHERE status IN ('PENDING', 'APPROVED');
@@ -0,0 +1,37 @@
+-- Migration: 035_tax_lots
+-- Purpose: Tax lots per (account, token) with FIFO/LIFO closures and realized gains
+
+CREATE TABLE IF NOT EXISTS tax_lots (
+    id UUID PRIMARY KEY,
+    method TEXT NOT NULL CHECK (method IN ('fifo', 'lifo')),
+    account_id TEXT NOT NULL,
+    agent_id TEXT NOT NULL,
+    token_id TEXT NOT NULL,
+    market_slug TEXT NOT NULL,
+    opened_at TIMESTAMPTZ NOT NULL,
+    open_intent_id UUID,
+    shares NUMERIC(20,6) NOT NULL,
+    remaining_shares NUMERIC(20,6) NOT NULL,
+    cost_basis_usd NUMERIC(20,8) NOT NULL,     -- fees and gas included
+    remaining_cost_usd NUMERIC(20,8) NOT NULL,
+    created_at TIMESTAMPTZ NOT NULL DEFAULT NOW()
+);
+
+CREATE INDEX IF NOT EXISTS idx_tax_lots_method_account
+    ON tax_lots(method, account_id, token_id, opened_at);
+
+CREATE TABLE IF NOT EXISTS tax_lot_closures (
+    id BIGSERIAL PRIMARY KEY,
+    lot_id UUID NOT NULL REFERENCES tax_lots(id) ON DELETE CASCADE,
+    closed_at TIMESTAMPTZ NOT NULL,
+    close_kind TEXT NOT NULL CHECK (close_kind IN ('sell', 'settlement')),
+    close_intent_id UUID,
+    shares NUMERIC(20,6) NOT NULL,
+    proceeds_usd NUMERIC(20,8) NOT NULL,       -- net of fees and gas
+    cost_basis_usd NUMERIC(20,8) NOT NULL,
+    realized_gain_usd NUMERIC(20,8) NOT NULL,
+    holding_days INTEGER NOT NULL
+);
+
+CREATE INDEX IF NOT EXISTS idx_tax_lot_closures_lot ON tax_lot_closures(lot_id);
+CREATE INDEX IF NOT EXISTS idx_tax_lot_closures_closed_at ON tax_lot_closures(closed_at);
@@ -0,0 +1,21 @@
+-- Migration: 036_live_verifications
+-- Purpose: Results of `ploy verify live` smoke round-trips run after deploys
+
+CREATE TABLE IF NOT EXISTS live_verifications (
+    id UUID PRIMARY KEY,
+    started_at TIMESTAMPTZ NOT NULL,
+    finished_at TIMESTAMPTZ NOT NULL,
+    passed BOOLEAN NOT NULL,
+    budget_usd NUMERIC(20,8) NOT NULL,
+    token_id TEXT,
+    market_slug TEXT,
+    balance_before NUMERIC(20,8),
+    balance_after NUMERIC(20,8),
+    net_cost_usd NUMERIC(20,8),           -- buy cost minus sell proceeds
+    residual_shares BIGINT NOT NULL DEFAULT 0,  -- bought but not sold back
+    steps JSONB NOT NULL,
+    created_at TIMESTAMPTZ NOT NULL DEFAULT NOW()
+);
+
+CREATE INDEX IF NOT EXISTS idx_live_verifications_started
+    ON live_verifications (started_at DESC);
@@ -0,0 +1,23 @@
+-- Migration: 037_ingested_fills
+-- Purpose: Persisted seen-set for exactly-once fill ingestion across poll / WS sources
+
+CREATE TABLE IF NOT EXISTS ingested_fills (
+    trade_id TEXT NOT NULL,
+    order_id TEXT NOT NULL,
+    token_id TEXT NOT NULL,
+    side TEXT NOT NULL,
+    price NUMERIC(20,8) NOT NULL,
+    shares NUMERIC(20,6) NOT NULL,
+    source TEXT NOT NULL,                  -- poll | websocket | reconcile (last writer)
+    traded_at TIMESTAMPTZ NOT NULL,
+    amendments INT NOT NULL DEFAULT 0,     -- re-reports with a different amount
+    first_seen_at TIMESTAMPTZ NOT NULL DEFAULT NOW(),
+    updated_at TIMESTAMPTZ NOT NULL DEFAULT NOW(),
+    PRIMARY KEY (trade_id, order_id)
+);
+
+CREATE INDEX IF NOT EXISTS idx_ingested_fills_order
+    ON ingested_fills (order_id);
+
+CREATE INDEX IF NOT EXISTS idx_ingested_fills_traded
+    ON ingested_fills (traded_at DESC);
@@ -1,15 +1,53 @@
 use std::net::SocketAddr;
 use std::sync::Arc;
 use tokio::net::TcpListener;
-use tracing::info;
+use tracing::{info, warn};
 
 use crate::adapters::PostgresStore;
 use crate::ai_clients::grok::GrokClient;
+use crate::api::public::{create_public_router, PublicApiConfig};
 use crate::api::state::StrategyConfigState;
+use crate::api::webhooks::WebhookDispatcher;
 use crate::api::{create_router, AppState};
 use crate::coordinator::CoordinatorHandle;
 use crate::error::Result;
 
+/// Route trading events emitted anywhere in the process to webhook subscribers
+fn install_webhooks(app_state: &AppState) {
+    match WebhookDispatcher::new(app_state.store.pool().clone()) {
+        Ok(dispatcher) => dispatcher.install(),
+        Err(e) => warn!("webhook dispatcher disabled: {}", e),
+    }
+}
+
+/// Start the public read-only listener when `PLOY_PUBLIC_API_PORT` is set
+fn spawn_public_api(app_state: &AppState) {
+    let Some(config) = PublicApiConfig::from_env() else {
+        return;
+    };
+    let app = create_public_router(app_state.clone(), &config);
+    tokio::spawn(async move {
+        let addr = SocketAddr::from(([0, 0, 0, 0], config.port));
+        info!(
+            "public read-only API listening on http://{} ({} req/min, burst {})",
+            addr, config.requests_per_minute, config.burst
+        );
+        let result = match TcpListener::bind(addr).await {
+            Ok(listener) => {
+                axum::serve(
+                    listener,
+                    app.into_make_service_with_connect_info::<SocketAddr>(),
+                )
+                .await
+            }
+            Err(e) => Err(e),
+        };
+        if let Err(e) = result {
+            warn!("public API listener stopped: {}", e);
+        }
+    });
+}
+
 /// Start the API server
 pub async fn start_api_server(
     store: Arc<PostgresStore>,
@@ -18,6 +56,8 @@ pub async fn start_api_server(
 ) -> Result<()> {
     let app_state = AppState::new(store, config);
     app_state.spawn_realtime_broadcast_loop();
+    install_webhooks(&app_state);
+    spawn_public_api(&app_state);
 
     let app = create_router(app_state);
 
@@ -49,6 +89,8 @@ pub async fn start_api_server_with_platform(
         dry_run,
     );
     app_state.spawn_realtime_broadcast_loop();
+    install_webhooks(&app_state);
+    spawn_public_api(&app_state);
 
     let app = create_router(app_state);
 
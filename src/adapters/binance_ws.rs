@@ -202,6 +202,9 @@ pub struct BinanceTrade {
     pub quantity: String,
     #[serde(rename = "T")]
     pub trade_time: u64,
+    /// True when the buyer was the maker (i.e. the seller was the aggressor)
+    #[serde(rename = "m", default)]
+    pub is_buyer_maker: Option<bool>,
 }
 
 /// Aggregated trade message (more efficient for high-volume pairs)
@@ -219,6 +222,9 @@ pub struct BinanceAggTrade {
     pub quantity: String,
     #[serde(rename = "T")]
     pub trade_time: u64,
+    /// True when the buyer was the maker (i.e. the seller was the aggressor)
+    #[serde(rename = "m", default)]
+    pub is_buyer_maker: Option<bool>,
 }
 
 /// Price update event broadcast to subscribers
@@ -228,6 +234,8 @@ pub struct PriceUpdate {
     pub price: Decimal,
     pub quantity: Option<Decimal>,
     pub timestamp: DateTime<Utc>,
+    /// Aggressor flag from the trade print (`m`), when available
+    pub is_buyer_maker: Option<bool>,
 }
 
 /// Spot price with historical data for momentum calculation
@@ -740,6 +748,7 @@ impl BinanceWebSocket {
                 &trade.price,
                 &trade.quantity,
                 trade.trade_time,
+                trade.is_buyer_maker,
             )
             .await;
             return;
@@ -752,6 +761,7 @@ impl BinanceWebSocket {
                 &trade.price,
                 &trade.quantity,
                 trade.trade_time,
+                trade.is_buyer_maker,
             )
             .await;
             return;
@@ -765,7 +775,14 @@ impl BinanceWebSocket {
     }
 
     /// Process a trade update
-    async fn process_trade(&self, symbol: &str, price_str: &str, qty_str: &str, timestamp_ms: u64) {
+    async fn process_trade(
+        &self,
+        symbol: &str,
+        price_str: &str,
+        qty_str: &str,
+        timestamp_ms: u64,
+        is_buyer_maker: Option<bool>,
+    ) {
         let price = match price_str.parse::<Decimal>() {
             Ok(p) => p,
             Err(e) => {
@@ -795,6 +812,7 @@ impl BinanceWebSocket {
             price,
             quantity,
             timestamp,
+            is_buyer_maker,
         };
 
         // Ignore send errors (no subscribers)
@@ -136,7 +136,9 @@ async fn connect_via_proxy(
 }
 
 /// Connect WebSocket, using proxy if available
-async fn connect_websocket_with_proxy(
+///
+/// Shared with the Coinbase and Kraken spot feeds.
+pub(crate) async fn connect_websocket_with_proxy(
     url: &Url,
 ) -> Result<WebSocketStream<MaybeTlsStream<TcpStream>>> {
     let host = url.host_str().unwrap_or(CHAINLINK_WS_HOST);
@@ -145,8 +147,8 @@ async fn connect_websocket_with_proxy(
     if let Some(proxy_url) = get_proxy_url() {
         if let Some((proxy_host, proxy_port)) = parse_proxy_url(&proxy_url) {
             info!(
-                "Using proxy {}:{} for Chainlink RTDS WebSocket",
-                proxy_host, proxy_port
+                "Using proxy {}:{} for WebSocket to {}",
+                proxy_host, proxy_port, host
             );
 
             let tcp_stream = connect_via_proxy(&proxy_host, proxy_port, host, port).await?;
@@ -173,7 +175,7 @@ async fn connect_websocket_with_proxy(
     // No proxy — connect directly
     let (ws_stream, _) = tokio::time::timeout(Duration::from_secs(10), connect_async(url))
         .await
-        .map_err(|_| PloyError::Internal("Chainlink RTDS WebSocket connection timeout".into()))?
+        .map_err(|_| PloyError::Internal(format!("WebSocket connection timeout: {}", host)))?
         .map_err(PloyError::WebSocket)?;
 
     Ok(ws_stream)
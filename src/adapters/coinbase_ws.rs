@@ -0,0 +1,127 @@
+//! Coinbase Exchange WebSocket adapter for spot ticker prices
+//!
+//! Subscribes to the public `ticker` channel and publishes last-trade prices
+//! keyed by the Binance symbol, as one of the inputs to the
+//! [`ConsensusPriceCache`](super::consensus_price::ConsensusPriceCache).
+
+use chrono::{DateTime, Utc};
+use rust_decimal::Decimal;
+use serde::Deserialize;
+use std::str::FromStr;
+use tokio::sync::broadcast;
+use tokio_tungstenite::tungstenite::Message;
+
+use super::consensus_price::{ExchangeSpotUpdate, SpotSource};
+use super::spot_ticker_ws::{self, SpotTickerVenue, CHANNEL_CAPACITY};
+use crate::error::Result;
+
+const COINBASE_WS_URL: &str = "wss://ws-feed.exchange.coinbase.com";
+
+/// Convert a Binance ticker (e.g. "BTCUSDT") to a Coinbase product id ("BTC-USD")
+pub fn to_coinbase_product(binance: &str) -> Option<String> {
+    let base = binance.to_uppercase();
+    let base = base.strip_suffix("USDT")?;
+    (!base.is_empty()).then(|| format!("{}-USD", base))
+}
+
+/// Convert a Coinbase product id (e.g. "BTC-USD") to the Binance ticker
+pub fn from_coinbase_product(product: &str) -> Option<String> {
+    let base = product.strip_suffix("-USD")?;
+    (!base.is_empty()).then(|| format!("{}USDT", base.to_uppercase()))
+}
+
+#[derive(Debug, Deserialize)]
+struct TickerMessage {
+    #[serde(rename = "type")]
+    msg_type: String,
+    #[serde(default)]
+    product_id: String,
+    #[serde(default)]
+    price: Option<String>,
+    #[serde(default)]
+    time: Option<DateTime<Utc>>,
+}
+
+/// Coinbase spot ticker client
+pub struct CoinbaseWebSocket {
+    update_tx: broadcast::Sender<ExchangeSpotUpdate>,
+    symbols: Vec<String>,
+}
+
+impl CoinbaseWebSocket {
+    /// # Arguments
+    /// * `symbols` - Binance-style symbols (e.g. `["BTCUSDT", "ETHUSDT"]`)
+    pub fn new(symbols: Vec<String>) -> Self {
+        let (update_tx, _) = broadcast::channel(CHANNEL_CAPACITY);
+        Self { update_tx, symbols }
+    }
+
+    pub fn subscribe(&self) -> broadcast::Receiver<ExchangeSpotUpdate> {
+        self.update_tx.subscribe()
+    }
+
+    /// Run the WebSocket connection loop, reconnecting with backoff
+    pub async fn run(&self) -> Result<()> {
+        spot_ticker_ws::run::<Self>(&self.symbols, &self.update_tx).await
+    }
+}
+
+impl SpotTickerVenue for CoinbaseWebSocket {
+    const SOURCE: SpotSource = SpotSource::Coinbase;
+    const URL: &'static str = COINBASE_WS_URL;
+
+    fn venue_symbols(symbols: &[String]) -> Vec<String> {
+        symbols
+            .iter()
+            .filter_map(|s| to_coinbase_product(s))
+            .collect()
+    }
+
+    fn subscribe_message(products: &[String]) -> Message {
+        let subscribe = serde_json::json!({
+            "type": "subscribe",
+            "product_ids": products,
+            "channels": ["ticker"],
+        });
+        Message::Text(subscribe.to_string())
+    }
+
+    fn ping_message() -> Message {
+        Message::Ping(vec![])
+    }
+
+    fn parse(text: &str) -> Vec<ExchangeSpotUpdate> {
+        parse_ticker(text).into_iter().collect()
+    }
+}
+
+fn parse_ticker(text: &str) -> Option<ExchangeSpotUpdate> {
+    let msg: TickerMessage = serde_json::from_str(text).ok()?;
+    if msg.msg_type != "ticker" {
+        return None;
+    }
+    Some(ExchangeSpotUpdate {
+        source: SpotSource::Coinbase,
+        symbol: from_coinbase_product(&msg.product_id)?,
+        price: Decimal::from_str(msg.price.as_deref()?).ok()?,
+        timestamp: msg.time.unwrap_or_else(Utc::now),
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use rust_decimal_macros::dec;
+
+    #[test]
+    fn test_parse_coinbase_ticker() {
+        assert_eq!(to_coinbase_product("BTCUSDT").as_deref(), Some("BTC-USD"));
+
+        let text = r#"{"type":"ticker","sequence":1,"product_id":"BTC-USD","price":"64123.45","time":"2024-05-01T12:00:00.123456Z"}"#;
+        let update = parse_ticker(text).unwrap();
+        assert_eq!(update.symbol, "BTCUSDT");
+        assert_eq!(update.price, dec!(64123.45));
+
+        assert!(parse_ticker(r#"{"type":"subscriptions","channels":[]}"#).is_none());
+    }
+}
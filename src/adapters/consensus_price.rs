@@ -0,0 +1,373 @@
+//! Multi-venue consensus for the underlying spot price
+//!
+//! Binance alone is a single point of failure for settlement-sensitive
+//! strategies. [`ConsensusPriceCache`] collects the latest print per venue
+//! (Binance, Coinbase, Kraken), drops stale venues and outliers that deviate
+//! too far from the cross-venue median, and returns a median or weighted price
+//! together with per-source health.
+
+use chrono::{DateTime, Utc};
+use rust_decimal::prelude::ToPrimitive;
+use rust_decimal::Decimal;
+use serde::{Deserialize, Serialize};
+use std::collections::HashMap;
+use std::fmt;
+use std::sync::Arc;
+use tokio::sync::{broadcast, RwLock};
+use tracing::debug;
+
+use super::binance_ws::PriceUpdate;
+
+/// Venue a spot print came from
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, PartialOrd, Ord, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum SpotSource {
+    Binance,
+    Coinbase,
+    Kraken,
+}
+
+impl fmt::Display for SpotSource {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            SpotSource::Binance => write!(f, "binance"),
+            SpotSource::Coinbase => write!(f, "coinbase"),
+            SpotSource::Kraken => write!(f, "kraken"),
+        }
+    }
+}
+
+/// Spot print from a non-Binance venue, keyed by the Binance symbol (e.g. "BTCUSDT")
+#[derive(Debug, Clone)]
+pub struct ExchangeSpotUpdate {
+    pub source: SpotSource,
+    pub symbol: String,
+    pub price: Decimal,
+    pub timestamp: DateTime<Utc>,
+}
+
+/// How surviving venue prices are combined
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum ConsensusMethod {
+    #[default]
+    Median,
+    WeightedMean,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(default)]
+pub struct ConsensusConfig {
+    pub method: ConsensusMethod,
+    /// Prints older than this are ignored
+    pub max_age_secs: i64,
+    /// Venues further than this from the median are excluded
+    pub max_deviation_bps: Decimal,
+    /// Fewer fresh, non-outlier venues than this yields no consensus
+    pub min_sources: usize,
+    /// Per-venue weights for `weighted_mean` (missing = 1)
+    pub weights: HashMap<SpotSource, Decimal>,
+}
+
+impl Default for ConsensusConfig {
+    fn default() -> Self {
+        Self {
+            method: ConsensusMethod::Median,
+            max_age_secs: 5,
+            max_deviation_bps: Decimal::from(50),
+            min_sources: 2,
+            weights: HashMap::new(),
+        }
+    }
+}
+
+/// Health of one venue for one symbol
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum SourceStatus {
+    Healthy,
+    Stale,
+    Outlier,
+}
+
+#[derive(Debug, Clone, Serialize)]
+pub struct SourceHealth {
+    pub source: SpotSource,
+    pub symbol: String,
+    pub status: SourceStatus,
+    pub price: Decimal,
+    pub age_secs: i64,
+    /// Deviation from the cross-venue median, in bps
+    pub deviation_bps: Option<Decimal>,
+}
+
+/// Consensus price for one symbol
+#[derive(Debug, Clone, Serialize)]
+pub struct ConsensusPrice {
+    pub symbol: String,
+    pub price: Decimal,
+    pub median: Decimal,
+    pub sources: Vec<SpotSource>,
+    pub excluded: Vec<SpotSource>,
+    pub computed_at: DateTime<Utc>,
+}
+
+#[derive(Debug, Clone, Copy)]
+struct Sample {
+    price: Decimal,
+    timestamp: DateTime<Utc>,
+}
+
+fn median(sorted: &[Decimal]) -> Option<Decimal> {
+    let n = sorted.len();
+    match n {
+        0 => None,
+        _ if n % 2 == 1 => Some(sorted[n / 2]),
+        _ => Some((sorted[n / 2 - 1] + sorted[n / 2]) / Decimal::TWO),
+    }
+}
+
+fn deviation_bps(price: Decimal, reference: Decimal) -> Decimal {
+    if reference.is_zero() {
+        return Decimal::ZERO;
+    }
+    ((price - reference) / reference).abs() * Decimal::from(10_000)
+}
+
+/// Thread-safe latest-print cache across venues
+#[derive(Debug, Clone, Default)]
+pub struct ConsensusPriceCache {
+    config: ConsensusConfig,
+    samples: Arc<RwLock<HashMap<String, HashMap<SpotSource, Sample>>>>,
+}
+
+impl ConsensusPriceCache {
+    pub fn new(config: ConsensusConfig) -> Self {
+        Self {
+            config,
+            samples: Arc::new(RwLock::new(HashMap::new())),
+        }
+    }
+
+    pub async fn update(
+        &self,
+        source: SpotSource,
+        symbol: &str,
+        price: Decimal,
+        timestamp: DateTime<Utc>,
+    ) {
+        if price <= Decimal::ZERO {
+            return;
+        }
+        let mut samples = self.samples.write().await;
+        let by_source = samples.entry(symbol.to_uppercase()).or_default();
+        // Ignore out-of-order prints
+        if by_source
+            .get(&source)
+            .is_some_and(|s| s.timestamp > timestamp)
+        {
+            return;
+        }
+        by_source.insert(source, Sample { price, timestamp });
+    }
+
+    /// Feed a Binance trade stream into the cache
+    pub fn spawn_binance(&self, mut rx: broadcast::Receiver<PriceUpdate>) {
+        let cache = self.clone();
+        tokio::spawn(async move {
+            loop {
+                match rx.recv().await {
+                    Ok(u) => {
+                        cache
+                            .update(SpotSource::Binance, &u.symbol, u.price, u.timestamp)
+                            .await
+                    }
+                    Err(broadcast::error::RecvError::Lagged(n)) => {
+                        debug!("Consensus cache lagged {} Binance updates", n);
+                    }
+                    Err(broadcast::error::RecvError::Closed) => break,
+                }
+            }
+        });
+    }
+
+    /// Feed a Coinbase or Kraken stream into the cache
+    pub fn spawn_exchange(&self, mut rx: broadcast::Receiver<ExchangeSpotUpdate>) {
+        let cache = self.clone();
+        tokio::spawn(async move {
+            loop {
+                match rx.recv().await {
+                    Ok(u) => {
+                        cache
+                            .update(u.source, &u.symbol, u.price, u.timestamp)
+                            .await
+                    }
+                    Err(broadcast::error::RecvError::Lagged(n)) => {
+                        debug!("Consensus cache lagged {} exchange updates", n);
+                    }
+                    Err(broadcast::error::RecvError::Closed) => break,
+                }
+            }
+        });
+    }
+
+    /// Consensus price for a symbol, or `None` if too few healthy venues
+    pub async fn consensus(&self, symbol: &str) -> Option<ConsensusPrice> {
+        self.consensus_at(symbol, Utc::now()).await
+    }
+
+    pub async fn consensus_at(&self, symbol: &str, now: DateTime<Utc>) -> Option<ConsensusPrice> {
+        let symbol = symbol.to_uppercase();
+        let health = self.health_at(&symbol, now).await;
+        let mut fresh: Vec<Decimal> = health
+            .iter()
+            .filter(|h| h.status != SourceStatus::Stale)
+            .map(|h| h.price)
+            .collect();
+        fresh.sort();
+        let median = median(&fresh)?;
+
+        let healthy: Vec<&SourceHealth> = health
+            .iter()
+            .filter(|h| h.status == SourceStatus::Healthy)
+            .collect();
+        if healthy.len() < self.config.min_sources.max(1) {
+            return None;
+        }
+
+        let price = match self.config.method {
+            ConsensusMethod::Median => {
+                let mut prices: Vec<Decimal> = healthy.iter().map(|h| h.price).collect();
+                prices.sort();
+                median(&prices)?
+            }
+            ConsensusMethod::WeightedMean => {
+                let weight = |s: SpotSource| {
+                    self.config
+                        .weights
+                        .get(&s)
+                        .copied()
+                        .unwrap_or(Decimal::ONE)
+                        .max(Decimal::ZERO)
+                };
+                let total: Decimal = healthy.iter().map(|h| weight(h.source)).sum();
+                if total.is_zero() {
+                    return None;
+                }
+                healthy
+                    .iter()
+                    .map(|h| h.price * weight(h.source))
+                    .sum::<Decimal>()
+                    / total
+            }
+        };
+
+        Some(ConsensusPrice {
+            symbol,
+            price,
+            median,
+            sources: healthy.iter().map(|h| h.source).collect(),
+            excluded: health
+                .iter()
+                .filter(|h| h.status == SourceStatus::Outlier)
+                .map(|h| h.source)
+                .collect(),
+            computed_at: now,
+        })
+    }
+
+    /// Per-venue health for a symbol
+    pub async fn health(&self, symbol: &str) -> Vec<SourceHealth> {
+        self.health_at(&symbol.to_uppercase(), Utc::now()).await
+    }
+
+    pub async fn health_at(&self, symbol: &str, now: DateTime<Utc>) -> Vec<SourceHealth> {
+        let samples = self.samples.read().await;
+        let Some(by_source) = samples.get(symbol) else {
+            return Vec::new();
+        };
+
+        let mut fresh: Vec<Decimal> = by_source
+            .values()
+            .filter(|s| (now - s.timestamp).num_seconds() <= self.config.max_age_secs)
+            .map(|s| s.price)
+            .collect();
+        fresh.sort();
+        let reference = median(&fresh);
+
+        let mut health: Vec<SourceHealth> = by_source
+            .iter()
+            .map(|(source, sample)| {
+                let age_secs = (now - sample.timestamp).num_seconds();
+                let deviation = reference.map(|m| deviation_bps(sample.price, m));
+                let status = if age_secs > self.config.max_age_secs {
+                    SourceStatus::Stale
+                } else if deviation.is_some_and(|d| d > self.config.max_deviation_bps) {
+                    SourceStatus::Outlier
+                } else {
+                    SourceStatus::Healthy
+                };
+                SourceHealth {
+                    source: *source,
+                    symbol: symbol.to_string(),
+                    status,
+                    price: sample.price,
+                    age_secs,
+                    deviation_bps: deviation.map(|d| d.round_dp(2)),
+                }
+            })
+            .collect();
+        health.sort_by_key(|h| h.source);
+        health
+    }
+
+    /// Spread between the highest and lowest fresh venue, in bps (diagnostics)
+    pub async fn dispersion_bps(&self, symbol: &str) -> Option<f64> {
+        let health = self.health(symbol).await;
+        let fresh: Vec<Decimal> = health
+            .iter()
+            .filter(|h| h.status != SourceStatus::Stale)
+            .map(|h| h.price)
+            .collect();
+        let lo = fresh.iter().min()?;
+        let hi = fresh.iter().max()?;
+        deviation_bps(*hi, *lo).to_f64()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use chrono::Duration;
+    use rust_decimal_macros::dec;
+
+    #[tokio::test]
+    async fn test_consensus_excludes_outlier_and_stale_sources() {
+        let cache = ConsensusPriceCache::new(ConsensusConfig::default());
+        let now = Utc::now();
+
+        cache
+            .update(SpotSource::Binance, "BTCUSDT", dec!(50000), now)
+            .await;
+        cache
+            .update(SpotSource::Coinbase, "BTCUSDT", dec!(50010), now)
+            .await;
+        // 1% off: excluded as an outlier
+        cache
+            .update(SpotSource::Kraken, "BTCUSDT", dec!(50500), now)
+            .await;
+
+        let consensus = cache.consensus_at("btcusdt", now).await.unwrap();
+        assert_eq!(consensus.price, dec!(50005));
+        assert_eq!(consensus.excluded, vec![SpotSource::Kraken]);
+
+        // Coinbase goes stale: only one healthy venue left
+        let later = now + Duration::seconds(10);
+        cache
+            .update(SpotSource::Binance, "BTCUSDT", dec!(50020), later)
+            .await;
+        assert!(cache.consensus_at("BTCUSDT", later).await.is_none());
+        let health = cache.health_at("BTCUSDT", later).await;
+        assert_eq!(health[1].status, SourceStatus::Stale);
+    }
+}
@@ -0,0 +1,429 @@
+//! Conditional Token Framework (CTF) adapter
+//!
+//! Splits USDC collateral into full YES+NO outcome sets and merges sets back
+//! into collateral on Polygon. Standard markets go through ConditionalTokens
+//! directly; negative-risk markets go through the NegRiskAdapter. Every
+//! transaction reports the gas it actually paid so callers can account for
+//! it in arbitrage PnL.
+
+use alloy::network::EthereumWallet;
+use alloy::primitives::{Address, FixedBytes, U256};
+use alloy::providers::{DynProvider, Provider, ProviderBuilder};
+use alloy::signers::local::PrivateKeySigner;
+use alloy::sol;
+use async_trait::async_trait;
+use rust_decimal::Decimal;
+use tracing::info;
+
+use crate::error::{PloyError, Result};
+
+pub(crate) const CONDITIONAL_TOKENS_POLYGON: &str = "0x4D97DCd97eC945f40cF65F87097ACe5EA0476045";
+const NEG_RISK_ADAPTER_POLYGON: &str = "0xd91E80cF2E7be2e162c6513ceD06f1dD0dA35296";
+pub(crate) const USDC_E_POLYGON: &str = "0x2791Bca1f2de4661ED88A30C99A7a9449Aa84174";
+pub(crate) const POLYGON_RPC_DEFAULT: &str = "https://polygon-bor-rpc.publicnode.com";
+/// USDC.e and outcome tokens both use 6 decimals
+const COLLATERAL_DECIMALS: u32 = 6;
+/// Native token (POL/MATIC) decimals
+const NATIVE_DECIMALS: u32 = 18;
+
+sol! {
+    /// ConditionalTokens calls shared by the split/merge adapter and the
+    /// auto-claimer
+    #[allow(missing_docs)]
+    #[sol(rpc)]
+    interface IConditionalTokens {
+        /// Redeem positions for a resolved condition
+        function redeemPositions(
+            address collateralToken,
+            bytes32 parentCollectionId,
+            bytes32 conditionId,
+            uint256[] calldata indexSets
+        ) external;
+
+        /// Get balance of a token for an account
+        function balanceOf(address account, uint256 id) external view returns (uint256);
+
+        function splitPosition(
+            address collateralToken,
+            bytes32 parentCollectionId,
+            bytes32 conditionId,
+            uint256[] calldata partition,
+            uint256 amount
+        ) external;
+
+        function mergePositions(
+            address collateralToken,
+            bytes32 parentCollectionId,
+            bytes32 conditionId,
+            uint256[] calldata partition,
+            uint256 amount
+        ) external;
+
+        function isApprovedForAll(address owner, address operator) external view returns (bool);
+
+        function setApprovalForAll(address operator, bool approved) external;
+    }
+
+    #[allow(missing_docs)]
+    #[sol(rpc)]
+    interface INegRiskAdapter {
+        function splitPosition(bytes32 conditionId, uint256 amount) external;
+
+        function mergePositions(bytes32 conditionId, uint256 amount) external;
+    }
+
+    #[allow(missing_docs)]
+    #[sol(rpc)]
+    interface IErc20Allowance {
+        function allowance(address owner, address spender) external view returns (uint256);
+
+        function approve(address spender, uint256 amount) external returns (bool);
+    }
+}
+
+/// Gas and hash of a confirmed CTF transaction
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct CtfTxReceipt {
+    pub tx_hash: String,
+    pub gas_used: u64,
+    pub effective_gas_price_wei: u128,
+}
+
+impl CtfTxReceipt {
+    /// Gas paid in native token (POL/MATIC).
+    pub fn gas_cost_native(&self) -> Decimal {
+        wei_to_native(self.gas_used as u128 * self.effective_gas_price_wei)
+    }
+
+    /// Fold another transaction (e.g. an approval) into this one's gas total.
+    pub fn absorb(&mut self, other: &CtfTxReceipt) {
+        let total_wei = self.gas_used as u128 * self.effective_gas_price_wei
+            + other.gas_used as u128 * other.effective_gas_price_wei;
+        self.gas_used += other.gas_used;
+        if self.gas_used > 0 {
+            self.effective_gas_price_wei = total_wei / self.gas_used as u128;
+        }
+    }
+}
+
+/// Convert a wei amount to native units.
+pub fn wei_to_native(wei: u128) -> Decimal {
+    Decimal::try_from_i128_with_scale(wei as i128, NATIVE_DECIMALS).unwrap_or(Decimal::MAX)
+}
+
+/// On-chain split/merge operations used by the split/merge arbitrage executor.
+#[async_trait]
+pub trait ConditionalTokens: Send + Sync {
+    /// Split `amount` USDC into `amount` YES + `amount` NO outcome tokens.
+    async fn split(
+        &self,
+        condition_id: &str,
+        amount: Decimal,
+        neg_risk: bool,
+    ) -> Result<CtfTxReceipt>;
+
+    /// Merge `amount` YES + NO pairs back into `amount` USDC.
+    async fn merge(
+        &self,
+        condition_id: &str,
+        amount: Decimal,
+        neg_risk: bool,
+    ) -> Result<CtfTxReceipt>;
+
+    /// Current network gas price in wei.
+    async fn gas_price_wei(&self) -> Result<u128>;
+}
+
+/// Alloy-backed CTF adapter for Polygon mainnet
+pub struct CtfAdapter {
+    provider: DynProvider,
+    owner: Address,
+    conditional_tokens: Address,
+    neg_risk_adapter: Address,
+    collateral: Address,
+}
+
+impl CtfAdapter {
+    /// Connect with a hex private key. `rpc_url` falls back to
+    /// `POLYGON_RPC_URL`, then a public Polygon endpoint.
+    pub fn new(private_key: &str, rpc_url: Option<&str>) -> Result<Self> {
+        let signer: PrivateKeySigner = private_key
+            .parse()
+            .map_err(|e| PloyError::Wallet(format!("Invalid private key: {}", e)))?;
+        let owner = signer.address();
+
+        let rpc = rpc_url
+            .map(str::to_string)
+            .or_else(|| std::env::var("POLYGON_RPC_URL").ok())
+            .filter(|v| !v.trim().is_empty())
+            .unwrap_or_else(|| POLYGON_RPC_DEFAULT.to_string());
+        let rpc = rpc
+            .parse()
+            .map_err(|e| PloyError::AddressParsing(format!("Invalid RPC URL: {}", e)))?;
+        let provider = ProviderBuilder::new()
+            .wallet(EthereumWallet::from(signer))
+            .connect_http(rpc)
+            .erased();
+
+        Ok(Self {
+            provider,
+            owner,
+            conditional_tokens: parse_address(CONDITIONAL_TOKENS_POLYGON)?,
+            neg_risk_adapter: parse_address(NEG_RISK_ADAPTER_POLYGON)?,
+            collateral: parse_address(USDC_E_POLYGON)?,
+        })
+    }
+
+    /// Wallet that owns collateral and outcome tokens.
+    pub fn owner(&self) -> Address {
+        self.owner
+    }
+
+    fn spender(&self, neg_risk: bool) -> Address {
+        if neg_risk {
+            self.neg_risk_adapter
+        } else {
+            self.conditional_tokens
+        }
+    }
+
+    /// Approve the split spender for USDC when the current allowance is short.
+    async fn ensure_collateral_allowance(
+        &self,
+        spender: Address,
+        amount: U256,
+    ) -> Result<Option<CtfTxReceipt>> {
+        let usdc = IErc20Allowance::new(self.collateral, self.provider.clone());
+        let allowance = usdc
+            .allowance(self.owner, spender)
+            .call()
+            .await
+            .map_err(|e| {
+                PloyError::OrderSubmission(format!("USDC allowance query failed: {}", e))
+            })?;
+        if allowance >= amount {
+            return Ok(None);
+        }
+        info!(%spender, "approving USDC for CTF split");
+        let pending = usdc
+            .approve(spender, U256::MAX)
+            .send()
+            .await
+            .map_err(|e| PloyError::OrderSubmission(format!("USDC approve failed: {}", e)))?;
+        confirm(pending, "USDC approve").await.map(Some)
+    }
+
+    /// NegRiskAdapter merges pull outcome tokens, so it needs operator rights.
+    async fn ensure_outcome_operator(&self, operator: Address) -> Result<Option<CtfTxReceipt>> {
+        let ctf = IConditionalTokens::new(self.conditional_tokens, self.provider.clone());
+        let approved = ctf
+            .isApprovedForAll(self.owner, operator)
+            .call()
+            .await
+            .map_err(|e| PloyError::OrderSubmission(format!("CTF approval query failed: {}", e)))?;
+        if approved {
+            return Ok(None);
+        }
+        info!(%operator, "approving NegRiskAdapter as CTF operator");
+        let pending = ctf
+            .setApprovalForAll(operator, true)
+            .send()
+            .await
+            .map_err(|e| {
+                PloyError::OrderSubmission(format!("CTF setApprovalForAll failed: {}", e))
+            })?;
+        confirm(pending, "CTF setApprovalForAll").await.map(Some)
+    }
+}
+
+#[async_trait]
+impl ConditionalTokens for CtfAdapter {
+    async fn split(
+        &self,
+        condition_id: &str,
+        amount: Decimal,
+        neg_risk: bool,
+    ) -> Result<CtfTxReceipt> {
+        let condition = parse_condition_id(condition_id)?;
+        let raw_amount = collateral_units(amount)?;
+        let approval = self
+            .ensure_collateral_allowance(self.spender(neg_risk), raw_amount)
+            .await?;
+
+        let pending = if neg_risk {
+            INegRiskAdapter::new(self.neg_risk_adapter, self.provider.clone())
+                .splitPosition(condition, raw_amount)
+                .send()
+                .await
+        } else {
+            IConditionalTokens::new(self.conditional_tokens, self.provider.clone())
+                .splitPosition(
+                    self.collateral,
+                    FixedBytes::ZERO,
+                    condition,
+                    binary_partition(),
+                    raw_amount,
+                )
+                .send()
+                .await
+        }
+        .map_err(|e| PloyError::OrderSubmission(format!("CTF split failed: {}", e)))?;
+
+        let mut receipt = confirm(pending, "CTF split").await?;
+        if let Some(approval) = approval {
+            receipt.absorb(&approval);
+        }
+        info!(condition_id, %amount, tx = %receipt.tx_hash, "CTF split confirmed");
+        Ok(receipt)
+    }
+
+    async fn merge(
+        &self,
+        condition_id: &str,
+        amount: Decimal,
+        neg_risk: bool,
+    ) -> Result<CtfTxReceipt> {
+        let condition = parse_condition_id(condition_id)?;
+        let raw_amount = collateral_units(amount)?;
+        let approval = if neg_risk {
+            self.ensure_outcome_operator(self.neg_risk_adapter).await?
+        } else {
+            None
+        };
+
+        let pending = if neg_risk {
+            INegRiskAdapter::new(self.neg_risk_adapter, self.provider.clone())
+                .mergePositions(condition, raw_amount)
+                .send()
+                .await
+        } else {
+            IConditionalTokens::new(self.conditional_tokens, self.provider.clone())
+                .mergePositions(
+                    self.collateral,
+                    FixedBytes::ZERO,
+                    condition,
+                    binary_partition(),
+                    raw_amount,
+                )
+                .send()
+                .await
+        }
+        .map_err(|e| PloyError::OrderSubmission(format!("CTF merge failed: {}", e)))?;
+
+        let mut receipt = confirm(pending, "CTF merge").await?;
+        if let Some(approval) = approval {
+            receipt.absorb(&approval);
+        }
+        info!(condition_id, %amount, tx = %receipt.tx_hash, "CTF merge confirmed");
+        Ok(receipt)
+    }
+
+    async fn gas_price_wei(&self) -> Result<u128> {
+        self.provider
+            .get_gas_price()
+            .await
+            .map_err(|e| PloyError::OrderSubmission(format!("gas price query failed: {}", e)))
+    }
+}
+
+async fn confirm(
+    pending: alloy::providers::PendingTransactionBuilder<alloy::network::Ethereum>,
+    label: &str,
+) -> Result<CtfTxReceipt> {
+    let receipt = pending
+        .get_receipt()
+        .await
+        .map_err(|e| PloyError::OrderSubmission(format!("{} confirmation failed: {}", label, e)))?;
+    if !receipt.status() {
+        return Err(PloyError::OrderSubmission(format!(
+            "{} reverted: {:?}",
+            label, receipt.transaction_hash
+        )));
+    }
+    Ok(CtfTxReceipt {
+        tx_hash: format!("{:?}", receipt.transaction_hash),
+        gas_used: receipt.gas_used,
+        effective_gas_price_wei: receipt.effective_gas_price,
+    })
+}
+
+fn parse_address(raw: &str) -> Result<Address> {
+    raw.parse()
+        .map_err(|e| PloyError::AddressParsing(format!("Invalid address {}: {}", raw, e)))
+}
+
+/// Index sets [1, 2] = YES and NO for a binary condition.
+fn binary_partition() -> Vec<U256> {
+    vec![U256::from(1), U256::from(2)]
+}
+
+/// Parse a bytes32 condition ID (with or without `0x`).
+pub fn parse_condition_id(raw: &str) -> Result<FixedBytes<32>> {
+    let hex_str = raw.trim().trim_start_matches("0x").trim_start_matches("0X");
+    let bytes: [u8; 32] = hex::decode(hex_str)
+        .map_err(|e| PloyError::Validation(format!("Invalid condition ID: {}", e)))?
+        .try_into()
+        .map_err(|_| PloyError::Validation("Condition ID must be 32 bytes".into()))?;
+    Ok(FixedBytes::from(bytes))
+}
+
+/// Convert a USDC amount to 6-decimal base units, rejecting sub-unit dust.
+pub fn collateral_units(amount: Decimal) -> Result<U256> {
+    if amount <= Decimal::ZERO {
+        return Err(PloyError::Validation(format!(
+            "CTF amount must be positive, got {}",
+            amount
+        )));
+    }
+    let scaled = amount * Decimal::from(10u64.pow(COLLATERAL_DECIMALS));
+    if scaled.fract() != Decimal::ZERO {
+        return Err(PloyError::Validation(format!(
+            "CTF amount {} has more than {} decimals",
+            amount, COLLATERAL_DECIMALS
+        )));
+    }
+    let units = scaled
+        .trunc()
+        .to_string()
+        .parse::<u128>()
+        .map_err(|e| PloyError::Validation(format!("CTF amount out of range: {}", e)))?;
+    Ok(U256::from(units))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use rust_decimal_macros::dec;
+
+    #[test]
+    fn test_collateral_units_and_condition_id() {
+        assert_eq!(
+            collateral_units(dec!(12.5)).unwrap(),
+            U256::from(12_500_000u64)
+        );
+        assert!(collateral_units(dec!(0.0000001)).is_err());
+        assert!(collateral_units(Decimal::ZERO).is_err());
+
+        let id = format!("0x{}", "ab".repeat(32));
+        assert_eq!(parse_condition_id(&id).unwrap().as_slice()[0], 0xab);
+        assert!(parse_condition_id("0x1234").is_err());
+    }
+
+    #[test]
+    fn test_receipt_gas_accounting() {
+        let mut split = CtfTxReceipt {
+            tx_hash: "0x1".into(),
+            gas_used: 100_000,
+            effective_gas_price_wei: 50_000_000_000,
+        };
+        assert_eq!(split.gas_cost_native(), dec!(0.005));
+
+        split.absorb(&CtfTxReceipt {
+            tx_hash: "0x2".into(),
+            gas_used: 50_000,
+            effective_gas_price_wei: 20_000_000_000,
+        });
+        assert_eq!(split.gas_used, 150_000);
+        assert_eq!(split.gas_cost_native(), dec!(0.006));
+    }
+}
@@ -0,0 +1,481 @@
+//! Discord webhook notifications
+//!
+//! Sends rich-embed trade and risk notifications to Discord. Each alert level
+//! can be routed to its own channel webhook, falling back to the default one.
+
+use async_trait::async_trait;
+use chrono::{DateTime, NaiveDate, Utc};
+use reqwest::Client;
+use serde::Serialize;
+use std::sync::Arc;
+use tracing::{debug, error, info};
+
+use crate::supervisor::alert_manager::{Alert, AlertChannel, AlertLevel};
+
+// Discord embed limits
+const MAX_TITLE_LEN: usize = 256;
+const MAX_DESCRIPTION_LEN: usize = 4096;
+const MAX_FIELD_VALUE_LEN: usize = 1024;
+const MAX_FIELDS: usize = 25;
+
+const COLOR_GREEN: u32 = 0x2ECC71;
+const COLOR_RED: u32 = 0xE74C3C;
+const COLOR_BLUE: u32 = 0x3498DB;
+const COLOR_ORANGE: u32 = 0xE67E22;
+const COLOR_DARK_RED: u32 = 0x992D22;
+
+/// Webhook per alert level; unset levels use `default`.
+#[derive(Debug, Clone, Default)]
+pub struct DiscordRoutes {
+    pub default: Option<String>,
+    pub info: Option<String>,
+    pub warning: Option<String>,
+    pub error: Option<String>,
+    pub critical: Option<String>,
+}
+
+impl DiscordRoutes {
+    /// `DISCORD_WEBHOOK_URL` plus optional `DISCORD_WEBHOOK_URL_{INFO,WARNING,ERROR,CRITICAL}`
+    pub fn from_env() -> Self {
+        let var = |name: &str| {
+            std::env::var(name)
+                .ok()
+                .map(|v| v.trim().to_string())
+                .filter(|v| !v.is_empty())
+        };
+        Self {
+            default: var("DISCORD_WEBHOOK_URL"),
+            info: var("DISCORD_WEBHOOK_URL_INFO"),
+            warning: var("DISCORD_WEBHOOK_URL_WARNING"),
+            error: var("DISCORD_WEBHOOK_URL_ERROR"),
+            critical: var("DISCORD_WEBHOOK_URL_CRITICAL"),
+        }
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.webhooks().next().is_none()
+    }
+
+    /// Webhook for a level, if any
+    pub fn webhook_for(&self, level: AlertLevel) -> Option<&str> {
+        let routed = match level {
+            AlertLevel::Info => &self.info,
+            AlertLevel::Warning => &self.warning,
+            AlertLevel::Error => &self.error,
+            AlertLevel::Critical => &self.critical,
+        };
+        routed.as_deref().or(self.default.as_deref())
+    }
+
+    fn webhooks(&self) -> impl Iterator<Item = &String> {
+        [
+            &self.default,
+            &self.info,
+            &self.warning,
+            &self.error,
+            &self.critical,
+        ]
+        .into_iter()
+        .flatten()
+    }
+}
+
+/// Discord embed field
+#[derive(Debug, Clone, Serialize)]
+pub struct EmbedField {
+    pub name: String,
+    pub value: String,
+    pub inline: bool,
+}
+
+#[derive(Debug, Clone, Serialize)]
+struct EmbedFooter {
+    text: String,
+}
+
+/// Discord rich embed
+#[derive(Debug, Clone, Serialize)]
+pub struct DiscordEmbed {
+    pub title: String,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub description: Option<String>,
+    pub color: u32,
+    #[serde(skip_serializing_if = "Vec::is_empty")]
+    pub fields: Vec<EmbedField>,
+    pub timestamp: DateTime<Utc>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    footer: Option<EmbedFooter>,
+}
+
+impl DiscordEmbed {
+    pub fn new(title: impl Into<String>, color: u32) -> Self {
+        Self {
+            title: truncate(&title.into(), MAX_TITLE_LEN),
+            description: None,
+            color,
+            fields: Vec::new(),
+            timestamp: Utc::now(),
+            footer: None,
+        }
+    }
+
+    pub fn with_description(mut self, description: impl Into<String>) -> Self {
+        self.description = Some(truncate(&description.into(), MAX_DESCRIPTION_LEN));
+        self
+    }
+
+    /// Add a field; fields beyond Discord's limit of 25 are dropped.
+    pub fn with_field(
+        mut self,
+        name: impl Into<String>,
+        value: impl Into<String>,
+        inline: bool,
+    ) -> Self {
+        if self.fields.len() < MAX_FIELDS {
+            let value = value.into();
+            self.fields.push(EmbedField {
+                name: truncate(&name.into(), MAX_TITLE_LEN),
+                value: if value.is_empty() {
+                    "-".to_string()
+                } else {
+                    truncate(&value, MAX_FIELD_VALUE_LEN)
+                },
+                inline,
+            });
+        }
+        self
+    }
+
+    pub fn with_footer(mut self, text: impl Into<String>) -> Self {
+        self.footer = Some(EmbedFooter { text: text.into() });
+        self
+    }
+
+    pub fn with_timestamp(mut self, timestamp: DateTime<Utc>) -> Self {
+        self.timestamp = timestamp;
+        self
+    }
+}
+
+#[derive(Serialize)]
+struct DiscordMessage<'a> {
+    #[serde(skip_serializing_if = "Option::is_none")]
+    username: Option<&'a str>,
+    embeds: &'a [DiscordEmbed],
+}
+
+/// End-of-day PnL summary
+#[derive(Debug, Clone)]
+pub struct DailyPnlSummary {
+    pub date: NaiveDate,
+    pub realized_pnl: f64,
+    pub unrealized_pnl: f64,
+    pub trade_count: u32,
+    pub win_count: u32,
+    pub volume: f64,
+}
+
+/// Discord notification client
+#[derive(Clone)]
+pub struct DiscordNotifier {
+    client: Client,
+    routes: DiscordRoutes,
+    username: Option<String>,
+}
+
+impl DiscordNotifier {
+    /// Create a new Discord notifier from environment variables
+    pub fn from_env() -> Option<Arc<Self>> {
+        let routes = DiscordRoutes::from_env();
+        if routes.is_empty() {
+            return None;
+        }
+        info!("Discord notifications enabled");
+        let username = std::env::var("DISCORD_USERNAME")
+            .ok()
+            .filter(|v| !v.trim().is_empty());
+        Some(Arc::new(Self {
+            client: Client::new(),
+            routes,
+            username,
+        }))
+    }
+
+    /// Create a new Discord notifier with a single webhook for all levels
+    pub fn new(webhook_url: String) -> Arc<Self> {
+        Self::with_routes(DiscordRoutes {
+            default: Some(webhook_url),
+            ..Default::default()
+        })
+    }
+
+    /// Create a new Discord notifier with per-level routing
+    pub fn with_routes(routes: DiscordRoutes) -> Arc<Self> {
+        Arc::new(Self {
+            client: Client::new(),
+            routes,
+            username: None,
+        })
+    }
+
+    pub fn routes(&self) -> &DiscordRoutes {
+        &self.routes
+    }
+
+    /// Send embeds to the webhook routed for `level`
+    pub async fn send_embeds(
+        &self,
+        level: AlertLevel,
+        embeds: &[DiscordEmbed],
+    ) -> Result<(), String> {
+        let Some(webhook_url) = self.routes.webhook_for(level) else {
+            debug!("No Discord webhook routed for {} alerts", level);
+            return Ok(());
+        };
+
+        let message = DiscordMessage {
+            username: self.username.as_deref(),
+            embeds,
+        };
+        match self.client.post(webhook_url).json(&message).send().await {
+            Ok(resp) => {
+                if resp.status().is_success() {
+                    debug!("Discord notification sent successfully");
+                    Ok(())
+                } else {
+                    let status = resp.status();
+                    let body = resp.text().await.unwrap_or_default();
+                    error!("Discord notification failed: {} - {}", status, body);
+                    Err(format!("HTTP {}: {}", status, body))
+                }
+            }
+            Err(e) => {
+                error!("Discord request failed: {}", e);
+                Err(e.to_string())
+            }
+        }
+    }
+
+    /// Send a single embed
+    pub async fn send_embed(&self, level: AlertLevel, embed: DiscordEmbed) -> Result<(), String> {
+        self.send_embeds(level, std::slice::from_ref(&embed)).await
+    }
+
+    /// Send order filled notification
+    pub async fn notify_fill(
+        &self,
+        action: &str, // "BUY" or "SELL"
+        market: &str,
+        side: &str, // "UP" or "DOWN"
+        price: f64,
+        size: f64,
+        pnl: Option<f64>,
+    ) {
+        let color = if action == "BUY" {
+            COLOR_GREEN
+        } else {
+            COLOR_RED
+        };
+        let mut embed = DiscordEmbed::new(format!("Filled: {} {}", action, side), color)
+            .with_description(market)
+            .with_field("Price", format!("{:.2}¢", price * 100.0), true)
+            .with_field("Size", format!("{}", size as i64), true)
+            .with_field("Notional", format!("${:.2}", price * size), true);
+        if let Some(pnl) = pnl {
+            embed = embed.with_field("PnL", format!("${:+.2}", pnl), true);
+        }
+
+        if let Err(e) = self.send_embed(AlertLevel::Info, embed).await {
+            error!("Failed to send fill notification: {}", e);
+        }
+    }
+
+    /// Send stop-loss exit notification
+    pub async fn notify_stop_loss(
+        &self,
+        market: &str,
+        side: &str,
+        entry_price: f64,
+        exit_price: f64,
+        size: f64,
+        pnl: f64,
+    ) {
+        let pnl_pct = if entry_price > 0.0 {
+            (exit_price / entry_price - 1.0) * 100.0
+        } else {
+            0.0
+        };
+        let embed = DiscordEmbed::new(format!("Stop-loss: {}", side), COLOR_ORANGE)
+            .with_description(market)
+            .with_field(
+                "Entry → Exit",
+                format!("{:.2}¢ → {:.2}¢", entry_price * 100.0, exit_price * 100.0),
+                true,
+            )
+            .with_field("Size", format!("{}", size as i64), true)
+            .with_field("PnL", format!("${:+.2} ({:+.1}%)", pnl, pnl_pct), true);
+
+        if let Err(e) = self.send_embed(AlertLevel::Warning, embed).await {
+            error!("Failed to send stop-loss notification: {}", e);
+        }
+    }
+
+    /// Send circuit-breaker trip notification
+    pub async fn notify_circuit_breaker(&self, reason: &str, daily_pnl: Option<f64>) {
+        let mut embed = DiscordEmbed::new("Circuit Breaker Tripped", COLOR_DARK_RED)
+            .with_description(reason)
+            .with_field("Action", "trading_paused", true);
+        if let Some(pnl) = daily_pnl {
+            embed = embed.with_field("Daily PnL", format!("${:+.2}", pnl), true);
+        }
+
+        if let Err(e) = self.send_embed(AlertLevel::Error, embed).await {
+            error!("Failed to send circuit breaker notification: {}", e);
+        }
+    }
+
+    /// Send daily PnL summary
+    pub async fn notify_daily_pnl(&self, summary: &DailyPnlSummary) {
+        if let Err(e) = self
+            .send_embed(AlertLevel::Info, daily_pnl_embed(summary))
+            .await
+        {
+            error!("Failed to send daily PnL notification: {}", e);
+        }
+    }
+}
+
+#[async_trait]
+impl AlertChannel for DiscordNotifier {
+    fn name(&self) -> &str {
+        "discord"
+    }
+
+    fn accepts(&self, level: AlertLevel) -> bool {
+        self.routes.webhook_for(level).is_some()
+    }
+
+    async fn send(&self, alert: &Alert) -> Result<(), String> {
+        self.send_embed(alert.level, alert_embed(alert)).await
+    }
+}
+
+fn level_color(level: AlertLevel) -> u32 {
+    match level {
+        AlertLevel::Info => COLOR_BLUE,
+        AlertLevel::Warning => COLOR_ORANGE,
+        AlertLevel::Error => COLOR_RED,
+        AlertLevel::Critical => COLOR_DARK_RED,
+    }
+}
+
+/// Embed for a supervisor alert; top-level metadata keys become fields.
+pub fn alert_embed(alert: &Alert) -> DiscordEmbed {
+    let mut embed = DiscordEmbed::new(
+        format!("{} {}", alert.level.emoji(), alert.title),
+        level_color(alert.level),
+    )
+    .with_description(alert.message.clone())
+    .with_field("Component", alert.component.clone(), true)
+    .with_field("Level", alert.level.as_str(), true)
+    .with_timestamp(alert.timestamp);
+
+    if let Some(serde_json::Value::Object(metadata)) = &alert.metadata {
+        for (key, value) in metadata {
+            let value = match value {
+                serde_json::Value::String(s) => s.clone(),
+                other => other.to_string(),
+            };
+            embed = embed.with_field(key.clone(), value, true);
+        }
+    }
+    embed
+}
+
+/// Embed for an end-of-day PnL summary
+pub fn daily_pnl_embed(summary: &DailyPnlSummary) -> DiscordEmbed {
+    let total = summary.realized_pnl + summary.unrealized_pnl;
+    let color = if total >= 0.0 { COLOR_GREEN } else { COLOR_RED };
+    let win_rate = if summary.trade_count > 0 {
+        summary.win_count as f64 / summary.trade_count as f64 * 100.0
+    } else {
+        0.0
+    };
+
+    DiscordEmbed::new(format!("Daily PnL — {}", summary.date), color)
+        .with_field("Realized", format!("${:+.2}", summary.realized_pnl), true)
+        .with_field(
+            "Unrealized",
+            format!("${:+.2}", summary.unrealized_pnl),
+            true,
+        )
+        .with_field("Total", format!("${:+.2}", total), true)
+        .with_field("Trades", summary.trade_count.to_string(), true)
+        .with_field("Win rate", format!("{:.1}%", win_rate), true)
+        .with_field("Volume", format!("${:.2}", summary.volume), true)
+}
+
+fn truncate(text: &str, max_chars: usize) -> String {
+    if text.chars().count() <= max_chars {
+        return text.to_string();
+    }
+    let mut out: String = text.chars().take(max_chars.saturating_sub(1)).collect();
+    out.push('…');
+    out
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_routes_fall_back_to_default() {
+        let routes = DiscordRoutes {
+            default: Some("https://discord.test/default".to_string()),
+            critical: Some("https://discord.test/pager".to_string()),
+            ..Default::default()
+        };
+        assert_eq!(
+            routes.webhook_for(AlertLevel::Info),
+            Some("https://discord.test/default")
+        );
+        assert_eq!(
+            routes.webhook_for(AlertLevel::Critical),
+            Some("https://discord.test/pager")
+        );
+
+        let critical_only = DiscordRoutes {
+            critical: Some("https://discord.test/pager".to_string()),
+            ..Default::default()
+        };
+        assert!(critical_only.webhook_for(AlertLevel::Warning).is_none());
+        assert!(DiscordRoutes::default().is_empty());
+    }
+
+    #[test]
+    fn test_alert_embed_carries_metadata_fields() {
+        let alert = Alert::new(
+            AlertLevel::Error,
+            "circuit_breaker",
+            "Circuit Breaker Tripped",
+            &"x".repeat(5000),
+        )
+        .with_metadata(serde_json::json!({"action": "trading_paused", "losses": 3}));
+
+        let embed = alert_embed(&alert);
+        assert_eq!(embed.color, COLOR_RED);
+        assert_eq!(
+            embed.description.as_ref().unwrap().chars().count(),
+            MAX_DESCRIPTION_LEN
+        );
+        let json = serde_json::to_value(&embed).unwrap();
+        let fields = json["fields"].as_array().unwrap();
+        assert_eq!(fields.len(), 4);
+        assert!(fields
+            .iter()
+            .any(|f| f["name"] == "action" && f["value"] == "trading_paused"));
+        assert!(fields
+            .iter()
+            .any(|f| f["name"] == "losses" && f["value"] == "3"));
+    }
+}
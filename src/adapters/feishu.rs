@@ -53,11 +53,31 @@ impl FeishuNotifier {
                 text: text.to_string(),
             },
         };
+        self.post(&message).await
+    }
+
+    /// Send an interactive approval card with Approve / Deny buttons.
+    ///
+    /// Button clicks are delivered to the bot's card callback URL with
+    /// `action.value = {"approval_id": .., "decision": "approve" | "deny"}`.
+    pub async fn send_approval_card(
+        &self,
+        approval_id: &str,
+        title: &str,
+        summary: &str,
+    ) -> Result<(), String> {
+        let message = serde_json::json!({
+            "msg_type": "interactive",
+            "card": approval_card(approval_id, title, summary),
+        });
+        self.post(&message).await
+    }
 
+    async fn post<T: Serialize + ?Sized>(&self, message: &T) -> Result<(), String> {
         match self
             .client
             .post(&self.webhook_url)
-            .json(&message)
+            .json(message)
             .send()
             .await
         {
@@ -207,3 +227,76 @@ impl FeishuNotifier {
         }
     }
 }
+
+fn card_button(text: &str, kind: &str, approval_id: &str, decision: &str) -> serde_json::Value {
+    serde_json::json!({
+        "tag": "button",
+        "text": { "tag": "plain_text", "content": text },
+        "type": kind,
+        "value": { "approval_id": approval_id, "decision": decision },
+    })
+}
+
+/// Interactive card body for a pending approval.
+pub fn approval_card(approval_id: &str, title: &str, summary: &str) -> serde_json::Value {
+    serde_json::json!({
+        "config": { "wide_screen_mode": true, "update_multi": true },
+        "header": {
+            "template": "orange",
+            "title": { "tag": "plain_text", "content": format!("🛂 {}", title) },
+        },
+        "elements": [
+            { "tag": "div", "text": { "tag": "lark_md", "content": summary } },
+            { "tag": "note", "elements": [
+                { "tag": "plain_text", "content": format!("Approval ID: {}", approval_id) }
+            ] },
+            { "tag": "action", "actions": [
+                card_button("Approve", "primary", approval_id, "approve"),
+                card_button("Deny", "danger", approval_id, "deny"),
+            ] },
+        ],
+    })
+}
+
+/// Card body that replaces an approval card once it has been resolved
+/// (returned from the card callback so the buttons disappear).
+pub fn resolved_approval_card(
+    approval_id: &str,
+    title: &str,
+    outcome: &str,
+    operator: &str,
+) -> serde_json::Value {
+    let template = if outcome.eq_ignore_ascii_case("approved") {
+        "green"
+    } else {
+        "grey"
+    };
+    serde_json::json!({
+        "config": { "wide_screen_mode": true, "update_multi": true },
+        "header": {
+            "template": template,
+            "title": { "tag": "plain_text", "content": format!("{} — {}", title, outcome) },
+        },
+        "elements": [
+            { "tag": "div", "text": { "tag": "lark_md",
+                "content": format!("**{}** by {}", outcome, operator) } },
+            { "tag": "note", "elements": [
+                { "tag": "plain_text", "content": format!("Approval ID: {}", approval_id) }
+            ] },
+        ],
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_approval_card_buttons_carry_decision() {
+        let card = approval_card("abc", "Trade approval", "BUY 100 @ 0.50");
+        let actions = &card["elements"][2]["actions"];
+        assert_eq!(actions[0]["value"]["approval_id"], "abc");
+        assert_eq!(actions[0]["value"]["decision"], "approve");
+        assert_eq!(actions[1]["value"]["decision"], "deny");
+    }
+}
@@ -295,23 +295,28 @@ impl KalshiClient {
     }
 
     fn build_submit_order_body(request: &OrderRequest) -> Result<(Value, String, u64)> {
+        request.validate()?;
         let (ticker, side) = OutcomeSide::from_token_id(&request.token_id);
         let (price_cents, trace_dollars) = Self::serialize_limit_price(request.limit_price)?;
 
-        Ok((
-            json!({
-                "ticker": ticker,
-                "client_order_id": request.client_order_id,
-                "action": if matches!(request.order_side, OrderSide::Buy) { "buy" } else { "sell" },
-                "side": side.as_str(),
-                "type": "limit",
-                "count": request.shares,
-                "price": price_cents,
-                "time_in_force": format!("{:?}", request.time_in_force).to_lowercase(),
-            }),
-            trace_dollars,
-            price_cents,
-        ))
+        let mut body = json!({
+            "ticker": ticker,
+            "client_order_id": request.client_order_id,
+            "action": if matches!(request.order_side, OrderSide::Buy) { "buy" } else { "sell" },
+            "side": side.as_str(),
+            "type": "limit",
+            "count": request.shares,
+            "price": price_cents,
+            "time_in_force": format!("{:?}", request.time_in_force).to_lowercase(),
+        });
+        if request.post_only {
+            body["post_only"] = json!(true);
+        }
+        if let Some(expires_at) = request.expires_at {
+            body["expiration_ts"] = json!(expires_at.timestamp());
+        }
+
+        Ok((body, trace_dollars, price_cents))
     }
 
     fn extract_book_levels(value: &Value) -> Vec<OrderBookLevel> {
@@ -930,6 +935,9 @@ mod tests {
             limit_price,
             order_type: OrderType::Limit,
             time_in_force: TimeInForce::GTC,
+            expires_at: None,
+            post_only: false,
+            execution_preference: None,
         }
     }
 
@@ -0,0 +1,134 @@
+//! Kraken WebSocket (v2) adapter for spot ticker prices
+//!
+//! Subscribes to the public `ticker` channel and publishes last-trade prices
+//! keyed by the Binance symbol, as one of the inputs to the
+//! [`ConsensusPriceCache`](super::consensus_price::ConsensusPriceCache).
+
+use chrono::{DateTime, Utc};
+use rust_decimal::Decimal;
+use serde::Deserialize;
+use tokio::sync::broadcast;
+use tokio_tungstenite::tungstenite::Message;
+
+use super::consensus_price::{ExchangeSpotUpdate, SpotSource};
+use super::spot_ticker_ws::{self, SpotTickerVenue, CHANNEL_CAPACITY};
+use crate::error::Result;
+
+const KRAKEN_WS_URL: &str = "wss://ws.kraken.com/v2";
+
+/// Convert a Binance ticker (e.g. "BTCUSDT") to a Kraken v2 pair ("BTC/USD")
+pub fn to_kraken_pair(binance: &str) -> Option<String> {
+    let base = binance.to_uppercase();
+    let base = base.strip_suffix("USDT")?;
+    (!base.is_empty()).then(|| format!("{}/USD", base))
+}
+
+/// Convert a Kraken v2 pair (e.g. "BTC/USD") to the Binance ticker
+pub fn from_kraken_pair(pair: &str) -> Option<String> {
+    let base = pair.strip_suffix("/USD")?;
+    (!base.is_empty()).then(|| format!("{}USDT", base.to_uppercase()))
+}
+
+#[derive(Debug, Deserialize)]
+struct TickerMessage {
+    channel: String,
+    #[serde(default)]
+    data: Vec<TickerData>,
+}
+
+#[derive(Debug, Deserialize)]
+struct TickerData {
+    symbol: String,
+    last: Decimal,
+    #[serde(default)]
+    timestamp: Option<DateTime<Utc>>,
+}
+
+/// Kraken spot ticker client
+pub struct KrakenWebSocket {
+    update_tx: broadcast::Sender<ExchangeSpotUpdate>,
+    symbols: Vec<String>,
+}
+
+impl KrakenWebSocket {
+    /// # Arguments
+    /// * `symbols` - Binance-style symbols (e.g. `["BTCUSDT", "ETHUSDT"]`)
+    pub fn new(symbols: Vec<String>) -> Self {
+        let (update_tx, _) = broadcast::channel(CHANNEL_CAPACITY);
+        Self { update_tx, symbols }
+    }
+
+    pub fn subscribe(&self) -> broadcast::Receiver<ExchangeSpotUpdate> {
+        self.update_tx.subscribe()
+    }
+
+    /// Run the WebSocket connection loop, reconnecting with backoff
+    pub async fn run(&self) -> Result<()> {
+        spot_ticker_ws::run::<Self>(&self.symbols, &self.update_tx).await
+    }
+}
+
+impl SpotTickerVenue for KrakenWebSocket {
+    const SOURCE: SpotSource = SpotSource::Kraken;
+    const URL: &'static str = KRAKEN_WS_URL;
+
+    fn venue_symbols(symbols: &[String]) -> Vec<String> {
+        symbols.iter().filter_map(|s| to_kraken_pair(s)).collect()
+    }
+
+    fn subscribe_message(pairs: &[String]) -> Message {
+        let subscribe = serde_json::json!({
+            "method": "subscribe",
+            "params": { "channel": "ticker", "symbol": pairs },
+        });
+        Message::Text(subscribe.to_string())
+    }
+
+    /// Kraken expects an application-level ping rather than a ping frame
+    fn ping_message() -> Message {
+        Message::Text(serde_json::json!({ "method": "ping" }).to_string())
+    }
+
+    fn parse(text: &str) -> Vec<ExchangeSpotUpdate> {
+        parse_ticker(text)
+    }
+}
+
+fn parse_ticker(text: &str) -> Vec<ExchangeSpotUpdate> {
+    let Ok(msg) = serde_json::from_str::<TickerMessage>(text) else {
+        return Vec::new();
+    };
+    if msg.channel != "ticker" {
+        return Vec::new();
+    }
+    msg.data
+        .into_iter()
+        .filter_map(|d| {
+            Some(ExchangeSpotUpdate {
+                source: SpotSource::Kraken,
+                symbol: from_kraken_pair(&d.symbol)?,
+                price: d.last,
+                timestamp: d.timestamp.unwrap_or_else(Utc::now),
+            })
+        })
+        .collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use rust_decimal_macros::dec;
+
+    #[test]
+    fn test_parse_kraken_ticker() {
+        assert_eq!(to_kraken_pair("ETHUSDT").as_deref(), Some("ETH/USD"));
+
+        let text = r#"{"channel":"ticker","type":"update","data":[{"symbol":"BTC/USD","bid":64120.1,"ask":64120.2,"last":64120.5,"volume":1234.5}]}"#;
+        let updates = parse_ticker(text);
+        assert_eq!(updates.len(), 1);
+        assert_eq!(updates[0].symbol, "BTCUSDT");
+        assert_eq!(updates[0].price, dec!(64120.5));
+
+        assert!(parse_ticker(r#"{"channel":"heartbeat"}"#).is_empty());
+    }
+}
@@ -3,13 +3,21 @@ pub mod api_server;
 pub mod binance_kline_ws;
 pub mod binance_ws;
 pub mod chainlink_rtds;
+pub mod coinbase_ws;
+pub mod consensus_price;
+pub mod ctf;
+pub mod discord;
 pub mod feishu;
 pub mod kalshi_rest;
+pub mod kraken_ws;
 pub mod onchain_indexer;
+pub mod packed_quote;
 pub mod polymarket_clob;
 pub mod polymarket_official;
 pub mod polymarket_ws;
 pub mod postgres;
+pub mod price_validator;
+mod spot_ticker_ws;
 pub mod transaction_manager;
 
 #[cfg(feature = "api")]
@@ -19,19 +27,31 @@ pub use api_server::{
 pub use binance_kline_ws::{BinanceKlineBar, BinanceKlineWebSocket, KlineUpdate};
 pub use binance_ws::{BinanceWebSocket, PriceCache, PriceUpdate, SpotPrice};
 pub use chainlink_rtds::{ChainlinkPriceCache, ChainlinkRtds, ChainlinkSpot, ChainlinkUpdate};
+pub use coinbase_ws::CoinbaseWebSocket;
+pub use consensus_price::{
+    ConsensusConfig, ConsensusMethod, ConsensusPrice, ConsensusPriceCache, ExchangeSpotUpdate,
+    SourceHealth, SourceStatus, SpotSource,
+};
+pub use ctf::{ConditionalTokens, CtfAdapter, CtfTxReceipt};
+pub use discord::{DailyPnlSummary, DiscordEmbed, DiscordNotifier, DiscordRoutes};
 pub use feishu::FeishuNotifier;
 pub use kalshi_rest::KalshiClient;
+pub use kraken_ws::KrakenWebSocket;
+pub use onchain_indexer::{CtfBalanceIndexer, CtfIndexerConfig, CtfTransfer, CtfTransferKind};
+pub use packed_quote::{PackedQuote, TokenTable};
 pub use polymarket_clob::{
     AccountSummary, BalanceResponse, GammaEventInfo, MarketResponse, MarketSummary, OrderResponse,
     PolymarketClient, PositionResponse, TradeResponse,
 };
 pub use polymarket_ws::{
     CircuitBreaker, CircuitBreakerConfig, CircuitBreakerState, DisplayQuote, PolymarketWebSocket,
-    QuoteCache, QuoteUpdate,
+    QuoteCache, QuoteRejectReason, QuoteSanitizer, QuoteSanitizerConfig, QuoteSanitizerStats,
+    QuoteUpdate,
 };
 pub use postgres::{
     DailyMetrics, IncompleteCycle, OrphanedOrder, PersistedState, PostgresStore, RecoverySummary,
 };
+pub use price_validator::{MarketRules, PriceValidator, PriceValidatorConfig};
 pub use transaction_manager::{DLQEntry, ManagedTransaction, TransactionManager, TransactionScope};
 
 // Official Polymarket SDK re-export
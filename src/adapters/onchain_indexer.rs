@@ -5,12 +5,24 @@
 //! - Smart money signal detection
 //! - Real-time trade flow analysis
 //!
+//! Also indexes the bot wallet's ERC-1155 outcome-token balances on the
+//! ConditionalTokens contract (balances plus `TransferSingle` /
+//! `TransferBatch` history) for position reconciliation.
+//!
 //! Based on data schemas from Jon Becker's prediction-market-analysis.
 
+use alloy::primitives::{Address, B256, U256};
 use alloy::sol;
+use alloy::sol_types::{SolCall, SolEvent};
 use chrono::{DateTime, Utc};
+use rust_decimal::Decimal;
 use serde::{Deserialize, Serialize};
+use serde_json::{json, Value};
 use std::collections::HashMap;
+use std::time::Duration;
+use tracing::debug;
+
+use crate::error::{PloyError, Result};
 
 // ============================================================================
 // Contract addresses & constants
@@ -29,6 +41,9 @@ pub const ORDER_FILLED_TOPIC: &str =
 /// Block where Polymarket CTF Exchange was deployed
 pub const POLYMARKET_START_BLOCK: u64 = 33_605_403;
 
+/// Gnosis ConditionalTokens (ERC-1155 outcome tokens) on Polygon
+pub const CONDITIONAL_TOKENS: &str = "0x4D97DCd97eC945f40cF65F87097ACe5EA0476045";
+
 /// USDC has 6 decimals
 const USDC_DECIMALS: u32 = 6;
 
@@ -49,6 +64,28 @@ sol! {
         uint256 takerAmountFilled,
         uint256 fee
     );
+
+    /// ERC-1155 single transfer (mint: from = 0, burn: to = 0)
+    #[derive(Debug)]
+    event TransferSingle(
+        address indexed operator,
+        address indexed from,
+        address indexed to,
+        uint256 id,
+        uint256 value
+    );
+
+    /// ERC-1155 batch transfer
+    #[derive(Debug)]
+    event TransferBatch(
+        address indexed operator,
+        address indexed from,
+        address indexed to,
+        uint256[] ids,
+        uint256[] values
+    );
+
+    function balanceOfBatch(address[] accounts, uint256[] ids) external view returns (uint256[]);
 }
 
 // ============================================================================
@@ -255,6 +292,319 @@ impl WhaleInfo {
     }
 }
 
+// ============================================================================
+// CTF balance indexer
+// ============================================================================
+
+/// Configuration for the wallet balance indexer
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(default)]
+pub struct CtfIndexerConfig {
+    /// Polygon JSON-RPC endpoint (default: `POLYGON_RPC_URL`, then a public node)
+    pub rpc_url: String,
+    /// Blocks scanned on the first sync (default: 43_200, about a day)
+    pub lookback_blocks: u64,
+    /// Largest block span per `eth_getLogs` request (default: 2_000)
+    pub max_block_range: u64,
+    /// Transfers kept per token (default: 20)
+    pub transfers_per_token: usize,
+    /// HTTP timeout per request (default: 10s)
+    pub request_timeout_ms: u64,
+}
+
+impl Default for CtfIndexerConfig {
+    fn default() -> Self {
+        Self {
+            rpc_url: std::env::var("POLYGON_RPC_URL")
+                .ok()
+                .filter(|v| !v.trim().is_empty())
+                .unwrap_or_else(|| "https://polygon-bor-rpc.publicnode.com".to_string()),
+            lookback_blocks: 43_200,
+            max_block_range: 2_000,
+            transfers_per_token: 20,
+            request_timeout_ms: 10_000,
+        }
+    }
+}
+
+/// Direction of an outcome-token transfer, seen from the indexed wallet
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum CtfTransferKind {
+    /// Split from collateral (from = 0)
+    Minted,
+    /// Redeemed or merged back into collateral (to = 0)
+    Burned,
+    /// Received from another address (fills, adapter payouts)
+    Received,
+    /// Sent to another address (fills, NegRisk adapter redemptions)
+    Sent,
+}
+
+impl CtfTransferKind {
+    /// Whether the transfer reduced the wallet's balance
+    pub fn is_outflow(&self) -> bool {
+        matches!(self, Self::Burned | Self::Sent)
+    }
+}
+
+/// One outcome-token movement in or out of the indexed wallet
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct CtfTransfer {
+    pub block_number: u64,
+    pub transaction_hash: String,
+    pub token_id: String,
+    pub from: String,
+    pub to: String,
+    /// Shares (6 decimals applied)
+    pub amount: Decimal,
+    pub kind: CtfTransferKind,
+}
+
+/// Indexes a wallet's ERC-1155 balances on the ConditionalTokens contract.
+///
+/// Balances come from `balanceOfBatch`, which is authoritative; the transfer
+/// history explains how a balance changed (e.g. a redemption burn the local
+/// position book never saw).
+pub struct CtfBalanceIndexer {
+    config: CtfIndexerConfig,
+    wallet: Address,
+    contract: Address,
+    http: reqwest::Client,
+    last_block: Option<u64>,
+    transfers: HashMap<String, Vec<CtfTransfer>>,
+}
+
+impl CtfBalanceIndexer {
+    pub fn new(config: CtfIndexerConfig, wallet: &str) -> Result<Self> {
+        let wallet = wallet
+            .parse()
+            .map_err(|e| PloyError::AddressParsing(format!("Invalid wallet {}: {}", wallet, e)))?;
+        let contract = CONDITIONAL_TOKENS
+            .parse()
+            .map_err(|e| PloyError::AddressParsing(format!("Invalid CTF address: {}", e)))?;
+        let http = reqwest::Client::builder()
+            .timeout(Duration::from_millis(config.request_timeout_ms.max(500)))
+            .build()
+            .unwrap_or_default();
+        Ok(Self {
+            config,
+            wallet,
+            contract,
+            http,
+            last_block: None,
+            transfers: HashMap::new(),
+        })
+    }
+
+    pub fn wallet(&self) -> Address {
+        self.wallet
+    }
+
+    /// Last block included in the transfer history
+    pub fn last_block(&self) -> Option<u64> {
+        self.last_block
+    }
+
+    /// Most recent indexed transfer of `token_id`
+    pub fn last_transfer(&self, token_id: &str) -> Option<&CtfTransfer> {
+        self.transfers.get(token_id).and_then(|t| t.last())
+    }
+
+    /// Tokens with indexed transfer history
+    pub fn indexed_tokens(&self) -> impl Iterator<Item = &String> {
+        self.transfers.keys()
+    }
+
+    /// Current balances (in shares) for `token_ids`; tokens not held map to zero
+    pub async fn balances(&self, token_ids: &[String]) -> Result<HashMap<String, Decimal>> {
+        let mut balances = HashMap::new();
+        for chunk in token_ids.chunks(100) {
+            let ids = chunk
+                .iter()
+                .map(|id| {
+                    id.parse::<U256>().map_err(|e| {
+                        PloyError::Validation(format!("Invalid token id {}: {}", id, e))
+                    })
+                })
+                .collect::<Result<Vec<_>>>()?;
+            let call = balanceOfBatchCall {
+                accounts: vec![self.wallet; ids.len()],
+                ids,
+            };
+            let tx = json!({
+                "to": self.contract.to_string(),
+                "data": hex_bytes(&call.abi_encode()),
+            });
+            let result = self.rpc("eth_call", json!([tx, "latest"])).await?;
+            let raw = result
+                .as_str()
+                .and_then(|s| alloy::hex::decode(s).ok())
+                .ok_or_else(|| PloyError::Internal("balanceOfBatch: bad result".to_string()))?;
+            let amounts = balanceOfBatchCall::abi_decode_returns(&raw)
+                .map_err(|e| PloyError::Internal(format!("balanceOfBatch decode: {}", e)))?;
+            for (id, amount) in chunk.iter().zip(amounts) {
+                balances.insert(id.clone(), shares_from_raw(amount));
+            }
+        }
+        Ok(balances)
+    }
+
+    /// Index transfers into and out of the wallet up to the chain head.
+    /// Returns the number of new transfers.
+    pub async fn sync(&mut self) -> Result<usize> {
+        let head = self
+            .rpc("eth_blockNumber", json!([]))
+            .await?
+            .as_str()
+            .and_then(|s| u64::from_str_radix(s.trim_start_matches("0x"), 16).ok())
+            .ok_or_else(|| PloyError::Internal("eth_blockNumber: bad result".to_string()))?;
+        let mut from = match self.last_block {
+            Some(last) => last + 1,
+            None => head.saturating_sub(self.config.lookback_blocks),
+        };
+
+        let wallet_topic = B256::left_padding_from(self.wallet.as_slice());
+        let signatures = json!([
+            TransferSingle::SIGNATURE_HASH.to_string(),
+            TransferBatch::SIGNATURE_HASH.to_string()
+        ]);
+        let mut found = 0;
+        while from <= head {
+            let to = (from + self.config.max_block_range.max(1) - 1).min(head);
+            // Wallet as sender (topic2) and as recipient (topic3)
+            for topics in [
+                json!([signatures, null, wallet_topic.to_string()]),
+                json!([signatures, null, null, wallet_topic.to_string()]),
+            ] {
+                let filter = json!([{
+                    "address": self.contract.to_string(),
+                    "fromBlock": format!("{:#x}", from),
+                    "toBlock": format!("{:#x}", to),
+                    "topics": topics,
+                }]);
+                let logs = self.rpc("eth_getLogs", filter).await?;
+                for log in logs.as_array().into_iter().flatten() {
+                    for transfer in decode_transfer_log(log, self.wallet) {
+                        found += 1;
+                        let history = self.transfers.entry(transfer.token_id.clone()).or_default();
+                        history.push(transfer);
+                        history.sort_by_key(|t| t.block_number);
+                        let excess = history
+                            .len()
+                            .saturating_sub(self.config.transfers_per_token);
+                        history.drain(..excess);
+                    }
+                }
+            }
+            self.last_block = Some(to);
+            from = to + 1;
+        }
+        debug!(wallet = %self.wallet, head, found, "CTF transfer index synced");
+        Ok(found)
+    }
+
+    async fn rpc(&self, method: &str, params: Value) -> Result<Value> {
+        let request = json!({ "jsonrpc": "2.0", "id": 1, "method": method, "params": params });
+        let resp: Value = self
+            .http
+            .post(&self.config.rpc_url)
+            .json(&request)
+            .send()
+            .await?
+            .error_for_status()?
+            .json()
+            .await?;
+        if let Some(error) = resp.get("error") {
+            return Err(PloyError::Internal(format!("{} failed: {}", method, error)));
+        }
+        resp.get("result")
+            .cloned()
+            .ok_or_else(|| PloyError::Internal(format!("{}: missing result", method)))
+    }
+}
+
+fn hex_bytes(bytes: &[u8]) -> String {
+    format!("0x{}", alloy::hex::encode(bytes))
+}
+
+/// Raw 6-decimal outcome-token amount to shares
+fn shares_from_raw(raw: U256) -> Decimal {
+    let raw = i128::try_from(raw).unwrap_or(i128::MAX);
+    Decimal::try_from_i128_with_scale(raw, USDC_DECIMALS).unwrap_or(Decimal::MAX)
+}
+
+/// Decode a `TransferSingle` / `TransferBatch` JSON-RPC log into transfers
+/// touching `wallet`; anything else decodes to nothing.
+pub fn decode_transfer_log(log: &Value, wallet: Address) -> Vec<CtfTransfer> {
+    let topics: Vec<B256> = log["topics"]
+        .as_array()
+        .into_iter()
+        .flatten()
+        .filter_map(|t| t.as_str()?.parse().ok())
+        .collect();
+    let Some(data) = log["data"]
+        .as_str()
+        .and_then(|d| alloy::hex::decode(d).ok())
+    else {
+        return Vec::new();
+    };
+    let block_number = log["blockNumber"]
+        .as_str()
+        .and_then(|b| u64::from_str_radix(b.trim_start_matches("0x"), 16).ok())
+        .unwrap_or_default();
+    let transaction_hash = log["transactionHash"]
+        .as_str()
+        .unwrap_or_default()
+        .to_string();
+
+    let (from, to, moves): (Address, Address, Vec<(U256, U256)>) = match topics.first() {
+        Some(sig) if *sig == TransferSingle::SIGNATURE_HASH => {
+            match TransferSingle::decode_raw_log(topics.iter().copied(), &data) {
+                Ok(ev) => (ev.from, ev.to, vec![(ev.id, ev.value)]),
+                Err(_) => return Vec::new(),
+            }
+        }
+        Some(sig) if *sig == TransferBatch::SIGNATURE_HASH => {
+            match TransferBatch::decode_raw_log(topics.iter().copied(), &data) {
+                Ok(ev) => (ev.from, ev.to, ev.ids.into_iter().zip(ev.values).collect()),
+                Err(_) => return Vec::new(),
+            }
+        }
+        _ => return Vec::new(),
+    };
+
+    let kind = if from == Address::ZERO {
+        CtfTransferKind::Minted
+    } else if to == Address::ZERO {
+        CtfTransferKind::Burned
+    } else if to == wallet {
+        CtfTransferKind::Received
+    } else if from == wallet {
+        CtfTransferKind::Sent
+    } else {
+        return Vec::new();
+    };
+    if kind == CtfTransferKind::Minted && to != wallet
+        || kind == CtfTransferKind::Burned && from != wallet
+    {
+        return Vec::new();
+    }
+
+    moves
+        .into_iter()
+        .map(|(id, value)| CtfTransfer {
+            block_number,
+            transaction_hash: transaction_hash.clone(),
+            token_id: id.to_string(),
+            from: from.to_string(),
+            to: to.to_string(),
+            amount: shares_from_raw(value),
+            kind,
+        })
+        .collect()
+}
+
 // ============================================================================
 // Tests
 // ============================================================================
@@ -332,6 +682,41 @@ mod tests {
         assert!(taker_whale.is_net_buyer());
     }
 
+    #[test]
+    fn test_decode_transfer_single_burn() {
+        let wallet: Address = "0x1111111111111111111111111111111111111111"
+            .parse()
+            .unwrap();
+        let pad = |a: Address| B256::left_padding_from(a.as_slice()).to_string();
+        let word = |v: u64| format!("{:064x}", v);
+        let log = json!({
+            "topics": [
+                TransferSingle::SIGNATURE_HASH.to_string(),
+                pad(wallet),
+                pad(wallet),
+                pad(Address::ZERO),
+            ],
+            "data": format!("0x{}{}", word(12345), word(2_500_000)),
+            "blockNumber": "0x10",
+            "transactionHash": "0xfeed",
+        });
+
+        let transfers = decode_transfer_log(&log, wallet);
+        assert_eq!(transfers.len(), 1);
+        let burn = &transfers[0];
+        assert_eq!(burn.kind, CtfTransferKind::Burned);
+        assert!(burn.kind.is_outflow());
+        assert_eq!(burn.token_id, "12345");
+        assert_eq!(burn.amount, Decimal::new(25, 1));
+        assert_eq!(burn.block_number, 16);
+
+        // Someone else's transfer is ignored
+        let other: Address = "0x2222222222222222222222222222222222222222"
+            .parse()
+            .unwrap();
+        assert!(decode_transfer_log(&log, other).is_empty());
+    }
+
     #[test]
     fn test_exchange_contract_addresses() {
         assert_eq!(
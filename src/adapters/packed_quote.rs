@@ -0,0 +1,278 @@
+//! Compact fixed-point quotes for the internal fan-out path.
+//!
+//! A [`QuoteUpdate`](super::QuoteUpdate) carries a ~77-digit token id and four
+//! `Option<Decimal>`s, so every broadcast receiver clones a heap string and
+//! 64+ bytes of decimals. [`PackedQuote`] is a `Copy` struct of integers:
+//! the token id is interned once into a [`TokenTable`] index, prices are
+//! fixed-point at 1e-6 and sizes at 1e-8 (the tick archive's scales), and a
+//! missing side is [`PackedQuote::NONE`]. It also has a fixed little-endian
+//! wire layout ([`PackedQuote::encode`]) for flat buffers.
+//!
+//! Conversion happens at the edges: the WebSocket packs a quote only when a
+//! packed subscriber exists, and consumers turn it back into a
+//! [`Quote`] with [`PackedQuote::to_quote`] if they need decimals.
+
+use chrono::{DateTime, TimeZone, Utc};
+use rust_decimal::Decimal;
+use std::collections::HashMap;
+use std::sync::{Arc, RwLock};
+
+use crate::domain::{Quote, Side};
+use crate::error::{PloyError, Result};
+
+/// Decimal places kept for prices
+pub const PRICE_SCALE: u32 = 6;
+/// Decimal places kept for sizes
+pub const SIZE_SCALE: u32 = 8;
+
+/// Append-only token id interner shared by the producer and packed consumers.
+#[derive(Debug, Default)]
+pub struct TokenTable {
+    inner: RwLock<TokenTableInner>,
+}
+
+#[derive(Debug, Default)]
+struct TokenTableInner {
+    index: HashMap<String, u32>,
+    ids: Vec<Arc<str>>,
+}
+
+impl TokenTable {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Index for `token_id`, assigning the next one on first sight
+    pub fn intern(&self, token_id: &str) -> u32 {
+        if let Some(idx) = self.lookup(token_id) {
+            return idx;
+        }
+        let mut inner = match self.inner.write() {
+            Ok(inner) => inner,
+            Err(poisoned) => poisoned.into_inner(),
+        };
+        if let Some(idx) = inner.index.get(token_id) {
+            return *idx;
+        }
+        let idx = inner.ids.len() as u32;
+        inner.ids.push(Arc::from(token_id));
+        inner.index.insert(token_id.to_string(), idx);
+        idx
+    }
+
+    /// Index for `token_id` if it was interned
+    pub fn lookup(&self, token_id: &str) -> Option<u32> {
+        let inner = match self.inner.read() {
+            Ok(inner) => inner,
+            Err(poisoned) => poisoned.into_inner(),
+        };
+        inner.index.get(token_id).copied()
+    }
+
+    /// Token id for an index
+    pub fn resolve(&self, idx: u32) -> Option<Arc<str>> {
+        let inner = match self.inner.read() {
+            Ok(inner) => inner,
+            Err(poisoned) => poisoned.into_inner(),
+        };
+        inner.ids.get(idx as usize).cloned()
+    }
+
+    pub fn len(&self) -> usize {
+        match self.inner.read() {
+            Ok(inner) => inner.ids.len(),
+            Err(poisoned) => poisoned.into_inner().ids.len(),
+        }
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.len() == 0
+    }
+}
+
+/// Top-of-book quote as plain integers.
+#[repr(C)]
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct PackedQuote {
+    /// Quote time, unix milliseconds
+    pub ts_ms: i64,
+    /// Best bid in 1e-6 units
+    pub bid: i64,
+    /// Best ask in 1e-6 units
+    pub ask: i64,
+    /// Best bid size in 1e-8 units
+    pub bid_size: i64,
+    /// Best ask size in 1e-8 units
+    pub ask_size: i64,
+    /// [`TokenTable`] index
+    pub token: u32,
+    /// 0 = UP, 1 = DOWN
+    pub side: u8,
+}
+
+impl PackedQuote {
+    /// Sentinel for a missing price or size
+    pub const NONE: i64 = i64::MIN;
+    /// Length of [`PackedQuote::encode`] output
+    pub const ENCODED_LEN: usize = 45;
+
+    /// Pack a quote. Values beyond the fixed-point precision are rounded;
+    /// values that overflow `i64` are treated as missing.
+    pub fn from_quote(token: u32, side: Side, quote: &Quote) -> Self {
+        Self {
+            ts_ms: quote.timestamp.timestamp_millis(),
+            bid: to_fixed(quote.best_bid, PRICE_SCALE),
+            ask: to_fixed(quote.best_ask, PRICE_SCALE),
+            bid_size: to_fixed(quote.bid_size, SIZE_SCALE),
+            ask_size: to_fixed(quote.ask_size, SIZE_SCALE),
+            token,
+            side: u8::from(side == Side::Down),
+        }
+    }
+
+    /// Unpack into a domain quote
+    pub fn to_quote(&self) -> Quote {
+        Quote {
+            side: self.side(),
+            best_bid: from_fixed(self.bid, PRICE_SCALE),
+            best_ask: from_fixed(self.ask, PRICE_SCALE),
+            bid_size: from_fixed(self.bid_size, SIZE_SCALE),
+            ask_size: from_fixed(self.ask_size, SIZE_SCALE),
+            timestamp: self.timestamp(),
+        }
+    }
+
+    pub fn side(&self) -> Side {
+        if self.side == 0 {
+            Side::Up
+        } else {
+            Side::Down
+        }
+    }
+
+    pub fn timestamp(&self) -> DateTime<Utc> {
+        Utc.timestamp_millis_opt(self.ts_ms)
+            .single()
+            .unwrap_or_default()
+    }
+
+    pub fn has_bid(&self) -> bool {
+        self.bid != Self::NONE
+    }
+
+    pub fn has_ask(&self) -> bool {
+        self.ask != Self::NONE
+    }
+
+    /// Ask minus bid in 1e-6 units, when both sides are present
+    pub fn spread(&self) -> Option<i64> {
+        (self.has_bid() && self.has_ask()).then(|| self.ask - self.bid)
+    }
+
+    /// Midpoint in 1e-6 units (rounded down), when both sides are present
+    pub fn mid(&self) -> Option<i64> {
+        (self.has_bid() && self.has_ask()).then(|| (self.bid + self.ask) / 2)
+    }
+
+    /// Fixed little-endian layout: ts_ms, bid, ask, bid_size, ask_size
+    /// (i64 each), token (u32), side (u8).
+    pub fn encode(&self) -> [u8; Self::ENCODED_LEN] {
+        let mut buf = [0u8; Self::ENCODED_LEN];
+        buf[0..8].copy_from_slice(&self.ts_ms.to_le_bytes());
+        buf[8..16].copy_from_slice(&self.bid.to_le_bytes());
+        buf[16..24].copy_from_slice(&self.ask.to_le_bytes());
+        buf[24..32].copy_from_slice(&self.bid_size.to_le_bytes());
+        buf[32..40].copy_from_slice(&self.ask_size.to_le_bytes());
+        buf[40..44].copy_from_slice(&self.token.to_le_bytes());
+        buf[44] = self.side;
+        buf
+    }
+
+    /// Decode one quote written by [`PackedQuote::encode`]
+    pub fn decode(buf: &[u8]) -> Result<Self> {
+        if buf.len() < Self::ENCODED_LEN {
+            return Err(PloyError::Validation(format!(
+                "packed quote needs {} bytes, got {}",
+                Self::ENCODED_LEN,
+                buf.len()
+            )));
+        }
+        if buf[44] > 1 {
+            return Err(PloyError::Validation(format!(
+                "invalid packed quote side {}",
+                buf[44]
+            )));
+        }
+        let i64_at = |at: usize| {
+            let mut bytes = [0u8; 8];
+            bytes.copy_from_slice(&buf[at..at + 8]);
+            i64::from_le_bytes(bytes)
+        };
+        let mut token = [0u8; 4];
+        token.copy_from_slice(&buf[40..44]);
+        Ok(Self {
+            ts_ms: i64_at(0),
+            bid: i64_at(8),
+            ask: i64_at(16),
+            bid_size: i64_at(24),
+            ask_size: i64_at(32),
+            token: u32::from_le_bytes(token),
+            side: buf[44],
+        })
+    }
+}
+
+fn to_fixed(value: Option<Decimal>, scale: u32) -> i64 {
+    let Some(value) = value else {
+        return PackedQuote::NONE;
+    };
+    let value = value.round_dp(scale);
+    let scaled = value.mantissa() * 10i128.pow(scale - value.scale());
+    match i64::try_from(scaled) {
+        Ok(v) if v != PackedQuote::NONE => v,
+        _ => PackedQuote::NONE,
+    }
+}
+
+fn from_fixed(value: i64, scale: u32) -> Option<Decimal> {
+    (value != PackedQuote::NONE).then(|| Decimal::new(value, scale).normalize())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use rust_decimal_macros::dec;
+
+    const TOKEN: &str =
+        "71321045679252212594626385532706912750332728571942532289631379312455583992563";
+
+    #[test]
+    fn test_packed_quote_round_trips() {
+        let table = TokenTable::new();
+        let token = table.intern(TOKEN);
+        assert_eq!(table.intern(TOKEN), token);
+
+        let quote = Quote {
+            side: Side::Down,
+            best_bid: Some(dec!(0.4150)),
+            best_ask: Some(dec!(0.42)),
+            bid_size: Some(dec!(1250.5)),
+            ask_size: None,
+            timestamp: Utc.timestamp_millis_opt(1_760_000_000_123).unwrap(),
+        };
+        let packed = PackedQuote::from_quote(token, Side::Down, &quote);
+        assert_eq!(packed.bid, 415_000);
+        assert_eq!(packed.spread(), Some(5_000));
+        assert_eq!(packed.ask_size, PackedQuote::NONE);
+
+        let decoded = PackedQuote::decode(&packed.encode()).unwrap();
+        assert_eq!(decoded, packed);
+        let unpacked = decoded.to_quote();
+        assert_eq!(unpacked.side, Side::Down);
+        assert_eq!(unpacked.best_bid, quote.best_bid);
+        assert_eq!(unpacked.bid_size, quote.bid_size);
+        assert_eq!(unpacked.ask_size, None);
+        assert_eq!(unpacked.timestamp, quote.timestamp);
+        assert_eq!(table.resolve(decoded.token).as_deref(), Some(TOKEN));
+    }
+}
@@ -3,16 +3,17 @@
 //! This module provides a client that uses the official polymarket-client-sdk
 //! for both CLOB (trading) and Gamma (market discovery) operations.
 
+use crate::adapters::price_validator::{MarketRules, PriceValidator, PriceValidatorConfig};
 use crate::domain::{OrderRequest, OrderSide, OrderStatus, TimeInForce};
 use crate::error::{PloyError, Result};
 use crate::exchange::{ExchangeClient, ExchangeKind};
-use crate::signing::Wallet;
+use crate::signing::{CredentialManager, StoredCredentials, Wallet};
 use alloy::primitives::{B256, U256};
 use alloy::signers::local::PrivateKeySigner;
 use alloy::signers::Signer;
 use async_trait::async_trait;
 use chrono::{DateTime, Utc};
-use polymarket_client_sdk::auth::{state::Authenticated, Normal};
+use polymarket_client_sdk::auth::{state::Authenticated, Credentials as SdkCredentials, Normal};
 use polymarket_client_sdk::clob::types::{
     request::{
         BalanceAllowanceRequest, CancelMarketOrderRequest, OrderBookSummaryRequest, OrdersRequest,
@@ -44,7 +45,9 @@ pub const POLYGON_CHAIN_ID: u64 = 137;
 
 /// Gamma API base URL
 pub const GAMMA_API_URL: &str = "https://gamma-api.polymarket.com";
-const CLOB_TERMINAL_CURSOR: &str = "LTE="; // base64("-1"), used by CLOB pagination
+pub const CLOB_TERMINAL_CURSOR: &str = "LTE="; // base64("-1"), used by CLOB pagination
+/// The CLOB expires GTD orders this many seconds before their stated expiration.
+const GTD_SECURITY_THRESHOLD_SECS: i64 = 60;
 
 type AuthClobClient = ClobClient<Authenticated<Normal>>;
 
@@ -72,8 +75,13 @@ pub struct PolymarketClient {
     neg_risk: bool,
     /// Mutex to serialize order submissions (prevents auth race condition)
     order_mutex: Arc<Mutex<()>>,
-    /// Cached authenticated CLOB client (API key) to avoid spamming `/auth/api-key`.
-    auth_client: Arc<Mutex<Option<AuthClobClient>>>,
+    /// Cached authenticated CLOB client (API key) to avoid spamming `/auth/api-key`,
+    /// tagged with the managed API key it was built from.
+    auth_client: Arc<Mutex<Option<(AuthClobClient, Option<String>)>>>,
+    /// Source of API keys; rotated when the exchange keeps rejecting them
+    credentials: Option<Arc<CredentialManager>>,
+    /// Per-market tick/min-size rules applied before signing
+    price_validator: Arc<PriceValidator>,
 }
 
 impl Clone for PolymarketClient {
@@ -89,6 +97,8 @@ impl Clone for PolymarketClient {
             neg_risk: self.neg_risk,
             order_mutex: self.order_mutex.clone(), // Share mutex across clones
             auth_client: self.auth_client.clone(),
+            credentials: self.credentials.clone(),
+            price_validator: self.price_validator.clone(),
         }
     }
 }
@@ -206,6 +216,14 @@ where
     }
 }
 
+/// Whether a CLOB error is the exchange rejecting our API key (401/403)
+fn is_auth_rejection(message: &str) -> bool {
+    let message = message.to_ascii_lowercase();
+    ["unauthorized", "forbidden", "invalid api key"]
+        .iter()
+        .any(|needle| message.contains(needle))
+}
+
 fn parse_json_array_strings(input: &str) -> std::result::Result<Vec<String>, serde_json::Error> {
     let s = input.trim();
     if s.is_empty() || s == "null" {
@@ -550,6 +568,9 @@ pub struct GammaMarketInfo {
     pub clob_token_ids: Option<String>,
     #[serde(rename = "outcomePrices")]
     pub outcome_prices: Option<String>,
+    /// Gamma sports market class (e.g. "moneyline", "spreads", "totals", "points").
+    #[serde(rename = "sportsMarketType", default)]
+    pub sports_market_type: Option<String>,
 }
 
 #[derive(Debug, Clone, Deserialize, Serialize)]
@@ -657,6 +678,43 @@ impl PolymarketClient {
         Self::validate_gateway_order_request_inner(request, Self::gateway_only_mode_enabled())
     }
 
+    fn sdk_order_type(time_in_force: TimeInForce) -> SdkOrderType {
+        match time_in_force {
+            TimeInForce::GTC => SdkOrderType::GTC,
+            TimeInForce::GTD => SdkOrderType::GTD,
+            TimeInForce::FOK => SdkOrderType::FOK,
+            // Polymarket SDK uses FAK (Fill and Kill) for IOC semantics.
+            TimeInForce::IOC => SdkOrderType::FAK,
+        }
+    }
+
+    /// Expiration to send for a GTD order, padded by the CLOB's security threshold.
+    fn sdk_expiration(request: &OrderRequest) -> Option<DateTime<Utc>> {
+        match request.time_in_force {
+            TimeInForce::GTD => request
+                .expires_at
+                .map(|at| at + chrono::Duration::seconds(GTD_SECURITY_THRESHOLD_SECS)),
+            _ => None,
+        }
+    }
+
+    /// The CLOB has no post-only flag, so refuse orders that would cross the
+    /// current book instead of letting them take liquidity.
+    async fn ensure_post_only(&self, request: &OrderRequest) -> Result<()> {
+        let (best_bid, best_ask) = self.get_best_prices(&request.token_id).await?;
+        let crosses = match request.order_side {
+            OrderSide::Buy => best_ask.is_some_and(|ask| request.limit_price >= ask),
+            OrderSide::Sell => best_bid.is_some_and(|bid| request.limit_price <= bid),
+        };
+        if crosses {
+            return Err(PloyError::Validation(format!(
+                "post-only {} @ {} would cross the book (bid={:?}, ask={:?})",
+                request.order_side, request.limit_price, best_bid, best_ask
+            )));
+        }
+        Ok(())
+    }
+
     async fn fetch_orders_paginated(
         &self,
         auth_client: &AuthClobClient,
@@ -670,7 +728,8 @@ impl PolymarketClient {
             let page = auth_client
                 .orders(req, cursor.clone())
                 .await
-                .map_err(|e| PloyError::Internal(format!("Failed to get orders: {}", e)))?;
+                .map_err(|e| PloyError::Internal(format!("Failed to get orders: {}", e)));
+            let page = self.track_auth(page).await?;
 
             for order in page.data {
                 out.push(order);
@@ -703,7 +762,8 @@ impl PolymarketClient {
             let page = auth_client
                 .trades(req, cursor.clone())
                 .await
-                .map_err(|e| PloyError::Internal(format!("Failed to get trades: {}", e)))?;
+                .map_err(|e| PloyError::Internal(format!("Failed to get trades: {}", e)));
+            let page = self.track_auth(page).await?;
 
             for trade in page.data {
                 out.push(trade);
@@ -723,20 +783,54 @@ impl PolymarketClient {
         Ok(out)
     }
 
-    #[allow(dead_code)]
     async fn clear_cached_auth(&self) {
         let mut guard = self.auth_client.lock().await;
         *guard = None;
     }
 
-    async fn authenticate_new(&self, signer: &PrivateKeySigner) -> Result<AuthClobClient> {
+    /// Report the outcome of an authenticated CLOB call to the credential
+    /// manager, dropping the cached client once the manager rotates the key.
+    async fn track_auth<T>(&self, result: Result<T>) -> Result<T> {
+        let Some(manager) = &self.credentials else {
+            return result;
+        };
+        match &result {
+            Ok(_) => manager.report_auth_success(),
+            Err(e) if is_auth_rejection(&e.to_string()) => {
+                match manager.report_auth_failure().await {
+                    Ok(Some(_)) => self.clear_cached_auth().await,
+                    Ok(None) => {}
+                    Err(err) => warn!(error = %err, "Failed to re-validate CLOB API key"),
+                }
+            }
+            Err(_) => {}
+        }
+        result
+    }
+
+    async fn authenticate_new(
+        &self,
+        signer: &PrivateKeySigner,
+        managed: Option<&StoredCredentials>,
+    ) -> Result<AuthClobClient> {
         let fresh_client = ClobClient::new(&self.base_url, ClobConfig::default())
             .map_err(|e| PloyError::Internal(format!("Failed to create CLOB client: {}", e)))?;
 
+        let mut builder = fresh_client.authentication_builder(signer);
+        if let Some(creds) = managed {
+            let key = creds.api_key.parse().map_err(|e| {
+                PloyError::Auth(format!("Invalid CLOB API key {}: {}", creds.api_key, e))
+            })?;
+            builder = builder.credentials(SdkCredentials::new(
+                key,
+                creds.secret.clone(),
+                creds.passphrase.clone(),
+            ));
+        }
+
         let auth_client = if let Some(funder) = self.funder {
             debug!("Using proxy wallet authentication, funder: {:?}", funder);
-            fresh_client
-                .authentication_builder(signer)
+            builder
                 .funder(funder)
                 .signature_type(SdkSignatureType::Proxy)
                 .authenticate()
@@ -744,8 +838,7 @@ impl PolymarketClient {
                 .map_err(|e| PloyError::Auth(format!("Proxy authentication failed: {}", e)))?
         } else {
             debug!("Using EOA wallet authentication");
-            fresh_client
-                .authentication_builder(signer)
+            builder
                 .authenticate()
                 .await
                 .map_err(|e| PloyError::Auth(format!("Authentication failed: {}", e)))?
@@ -755,11 +848,20 @@ impl PolymarketClient {
     }
 
     async fn authenticate_cached(&self, signer: &PrivateKeySigner) -> Result<AuthClobClient> {
+        // Managed keys are read on every request so a rotation takes effect immediately.
+        let managed = match &self.credentials {
+            Some(manager) => Some(manager.current_stored().await?),
+            None => None,
+        };
+        let managed_key = managed.as_ref().map(|creds| creds.api_key.clone());
+
         // Fast-path: reuse cached authenticated client (API key).
         {
             let guard = self.auth_client.lock().await;
-            if let Some(client) = guard.as_ref() {
-                return Ok(client.clone());
+            if let Some((client, key)) = guard.as_ref() {
+                if *key == managed_key {
+                    return Ok(client.clone());
+                }
             }
         }
 
@@ -768,10 +870,10 @@ impl PolymarketClient {
         let mut backoff_ms: u64 = 250;
         let mut last_err: Option<PloyError> = None;
         for attempt in 0..3 {
-            match self.authenticate_new(signer).await {
+            match self.authenticate_new(signer, managed.as_ref()).await {
                 Ok(client) => {
                     let mut guard = self.auth_client.lock().await;
-                    *guard = Some(client.clone());
+                    *guard = Some((client.clone(), managed_key.clone()));
                     return Ok(client);
                 }
                 Err(e) => {
@@ -819,6 +921,8 @@ impl PolymarketClient {
             neg_risk: false,
             order_mutex: Arc::new(Mutex::new(())),
             auth_client: Arc::new(Mutex::new(None)),
+            credentials: None,
+            price_validator: Arc::new(PriceValidator::default()),
         })
     }
 
@@ -870,6 +974,8 @@ impl PolymarketClient {
             neg_risk,
             order_mutex: Arc::new(Mutex::new(())),
             auth_client: Arc::new(Mutex::new(None)),
+            credentials: None,
+            price_validator: Arc::new(PriceValidator::default()),
         })
     }
 
@@ -932,9 +1038,28 @@ impl PolymarketClient {
             neg_risk,
             order_mutex: Arc::new(Mutex::new(())),
             auth_client: Arc::new(Mutex::new(None)),
+            credentials: None,
+            price_validator: Arc::new(PriceValidator::default()),
         })
     }
 
+    /// Replace the tick-size / minimum-size validation policy.
+    pub fn with_price_validator(mut self, config: PriceValidatorConfig) -> Self {
+        self.price_validator = Arc::new(PriceValidator::new(config));
+        self
+    }
+
+    /// Take API keys from `manager` and report 401/403 rejections to it
+    pub fn with_credential_manager(mut self, manager: Arc<CredentialManager>) -> Self {
+        self.credentials = Some(manager);
+        self
+    }
+
+    /// Get the price validator (to seed or invalidate market rules)
+    pub fn price_validator(&self) -> Arc<PriceValidator> {
+        Arc::clone(&self.price_validator)
+    }
+
     /// Set the funder address for proxy wallets
     pub fn set_funder(&mut self, funder_address: &str) -> Result<()> {
         let funder: alloy::primitives::Address = funder_address
@@ -945,6 +1070,20 @@ impl PolymarketClient {
         Ok(())
     }
 
+    /// Address holding positions: the funder (proxy wallet) when set,
+    /// otherwise the signing wallet.
+    pub fn wallet_address(&self) -> Option<String> {
+        if let Some(funder) = self.funder {
+            Some(format!("{:#x}", funder))
+        } else if let Some(w) = self.wallet.as_ref() {
+            Some(format!("{:#x}", w.address()))
+        } else {
+            self.signer
+                .as_ref()
+                .map(|signer| format!("{:#x}", signer.address()))
+        }
+    }
+
     /// Check if in dry run mode
     pub fn is_dry_run(&self) -> bool {
         self.dry_run
@@ -1397,15 +1536,83 @@ impl PolymarketClient {
         Ok(result)
     }
 
+    /// Tick size and minimum order size for a token's market.
+    ///
+    /// Served from the validator cache within its TTL; falls back to the
+    /// configured defaults (uncached) when Gamma has no answer.
+    #[instrument(skip(self))]
+    pub async fn get_market_rules(&self, token_id: &str) -> MarketRules {
+        if let Some(rules) = self.price_validator.cached(token_id) {
+            return rules;
+        }
+
+        let parse = |value: Option<String>| value.and_then(|v| Decimal::from_str(&v).ok());
+        match self.get_gamma_market_by_token_id(token_id).await {
+            Ok(market) => {
+                let defaults = self.price_validator.default_rules();
+                let tick_size = parse(
+                    market
+                        .order_price_min_tick_size
+                        .as_ref()
+                        .map(|d| d.to_string()),
+                )
+                .filter(|t| *t > Decimal::ZERO)
+                .unwrap_or(defaults.tick_size);
+                let min_size = parse(market.order_min_size.as_ref().map(|d| d.to_string()))
+                    .or(defaults.min_size);
+                let rules = MarketRules::new(tick_size, min_size);
+                self.price_validator.insert(token_id, rules);
+                rules
+            }
+            Err(e) => {
+                warn!(
+                    token_id,
+                    error = %e,
+                    "market rules unavailable, using default tick size"
+                );
+                self.price_validator.default_rules()
+            }
+        }
+    }
+
+    /// Snap and check an order against its market's tick size and minimum size.
+    ///
+    /// Live orders fetch the rules when they are not cached; dry runs only use
+    /// rules that are already cached so paper trading stays offline.
+    async fn apply_market_rules(&self, request: &OrderRequest) -> Result<OrderRequest> {
+        let rules = if self.dry_run {
+            self.price_validator.cached(&request.token_id)
+        } else {
+            Some(self.get_market_rules(&request.token_id).await)
+        };
+
+        let mut checked = request.clone();
+        if let Some(rules) = rules {
+            self.price_validator.apply(&rules, &mut checked)?;
+            if checked.limit_price != request.limit_price {
+                debug!(
+                    token_id = %request.token_id,
+                    from = %request.limit_price,
+                    to = %checked.limit_price,
+                    tick_size = %rules.tick_size,
+                    "snapped order price to tick"
+                );
+            }
+        }
+        Ok(checked)
+    }
+
     // ==================== Trading Methods ====================
 
     /// Submit an order
     #[instrument(skip(self))]
     pub async fn submit_order(&self, request: &OrderRequest) -> Result<OrderResponse> {
         Self::validate_gateway_execution_context(self.dry_run)?;
+        request.validate()?;
         if !self.dry_run {
             Self::validate_gateway_order_request(request)?;
         }
+        let request = &self.apply_market_rules(request).await?;
 
         if self.dry_run {
             info!(
@@ -1413,12 +1620,7 @@ impl PolymarketClient {
                 request.order_side, request.shares, request.token_id, request.limit_price
             );
 
-            let sdk_order_type = match request.time_in_force {
-                TimeInForce::GTC => SdkOrderType::GTC,
-                TimeInForce::FOK => SdkOrderType::FOK,
-                // Polymarket SDK uses FAK (Fill and Kill) for IOC semantics.
-                TimeInForce::IOC => SdkOrderType::FAK,
-            };
+            let sdk_order_type = Self::sdk_order_type(request.time_in_force);
 
             return Ok(OrderResponse {
                 id: request.client_order_id.clone(),
@@ -1432,11 +1634,15 @@ impl PolymarketClient {
                 price: Some(request.limit_price.to_string()),
                 associate_trades: None,
                 created_at: Some(Utc::now().to_rfc3339()),
-                expiration: None,
+                expiration: request.expires_at.map(|at| at.timestamp().to_string()),
                 order_type: Some(sdk_order_type.to_string()),
             });
         }
 
+        if request.post_only {
+            self.ensure_post_only(request).await?;
+        }
+
         let signer = self
             .signer
             .as_ref()
@@ -1452,24 +1658,24 @@ impl PolymarketClient {
             OrderSide::Sell => SdkSide::Sell,
         };
 
-        let sdk_order_type = match request.time_in_force {
-            TimeInForce::GTC => SdkOrderType::GTC,
-            TimeInForce::FOK => SdkOrderType::FOK,
-            // Polymarket SDK uses FAK (Fill and Kill) for IOC semantics.
-            TimeInForce::IOC => SdkOrderType::FAK,
-        };
+        let sdk_order_type = Self::sdk_order_type(request.time_in_force);
 
         let token_u256 = U256::from_str(&request.token_id).map_err(|e| {
             PloyError::OrderSubmission(format!("Invalid token_id '{}': {}", request.token_id, e))
         })?;
 
-        let order = auth_client
+        let mut builder = auth_client
             .limit_order()
             .token_id(token_u256)
             .price(request.limit_price)
             .size(Decimal::from(request.shares))
             .side(sdk_side)
-            .order_type(sdk_order_type)
+            .order_type(sdk_order_type);
+        if let Some(expiration) = Self::sdk_expiration(request) {
+            builder = builder.expiration(expiration);
+        }
+
+        let order = builder
             .build()
             .await
             .map_err(|e| PloyError::OrderSubmission(format!("Failed to build order: {}", e)))?;
@@ -1480,10 +1686,15 @@ impl PolymarketClient {
             .await
             .map_err(|e| PloyError::OrderSubmission(format!("Failed to sign order: {}", e)))?;
 
-        let resp = auth_client
-            .post_order(signed)
-            .await
-            .map_err(|e| PloyError::OrderSubmission(format!("Failed to post order: {}", e)))?;
+        let resp = auth_client.post_order(signed).await.map_err(|e| {
+            let message = e.to_string();
+            if message.to_ascii_lowercase().contains("tick") {
+                // The market's tick size moved since we cached it
+                self.price_validator.invalidate(&request.token_id);
+            }
+            PloyError::OrderSubmission(format!("Failed to post order: {}", message))
+        });
+        let resp = self.track_auth(resp).await?;
 
         info!("Order submitted successfully: {:?}", resp);
 
@@ -1499,7 +1710,7 @@ impl PolymarketClient {
             price: Some(request.limit_price.to_string()),
             associate_trades: None,
             created_at: Some(Utc::now().to_rfc3339()),
-            expiration: None,
+            expiration: request.expires_at.map(|at| at.timestamp().to_string()),
             order_type: Some(format!("{:?}", request.time_in_force)),
         })
     }
@@ -1518,7 +1729,8 @@ impl PolymarketClient {
         let order = auth_client
             .order(order_id)
             .await
-            .map_err(|e| PloyError::Internal(format!("Failed to get order: {}", e)))?;
+            .map_err(|e| PloyError::Internal(format!("Failed to get order: {}", e)));
+        let order = self.track_auth(order).await?;
 
         Ok(OrderResponse {
             id: order.id,
@@ -1553,10 +1765,11 @@ impl PolymarketClient {
         let _guard = self.order_mutex.lock().await;
         let auth_client = self.authenticate_cached(signer).await?;
 
-        auth_client
+        let result = auth_client
             .cancel_order(order_id)
             .await
-            .map_err(|e| PloyError::Internal(format!("Failed to cancel order: {}", e)))?;
+            .map_err(|e| PloyError::Internal(format!("Failed to cancel order: {}", e)));
+        self.track_auth(result).await?;
 
         Ok(true)
     }
@@ -1589,7 +1802,8 @@ impl PolymarketClient {
         let resp = auth_client
             .cancel_market_orders(&req)
             .await
-            .map_err(|e| PloyError::Internal(format!("Failed to cancel token orders: {}", e)))?;
+            .map_err(|e| PloyError::Internal(format!("Failed to cancel token orders: {}", e)));
+        let resp = self.track_auth(resp).await?;
 
         let not_canceled = if resp.not_canceled.is_empty() {
             None
@@ -1635,7 +1849,8 @@ impl PolymarketClient {
         let resp = auth_client
             .balance_allowance(req)
             .await
-            .map_err(|e| PloyError::Internal(format!("Failed to get balance: {}", e)))?;
+            .map_err(|e| PloyError::Internal(format!("Failed to get balance: {}", e)));
+        let resp = self.track_auth(resp).await?;
 
         Ok(BalanceResponse {
             balance: resp.balance.to_string(),
@@ -1699,6 +1914,66 @@ impl PolymarketClient {
             .collect())
     }
 
+    /// Get one page of open orders
+    ///
+    /// Returns the live orders on the page and the cursor of the next page
+    /// (`None` once the listing is exhausted).
+    #[instrument(skip(self))]
+    pub async fn get_open_orders_page(
+        &self,
+        cursor: Option<String>,
+    ) -> Result<(Vec<OrderResponse>, Option<String>)> {
+        if self.dry_run {
+            return Ok((vec![], None));
+        }
+
+        let signer = self
+            .signer
+            .as_ref()
+            .ok_or_else(|| PloyError::Auth("Not authenticated".to_string()))?;
+
+        let _guard = self.order_mutex.lock().await;
+        let auth_client = self.authenticate_cached(signer).await?;
+
+        let req = OrdersRequest::builder().build();
+        let page = auth_client
+            .orders(&req, cursor)
+            .await
+            .map_err(|e| PloyError::Internal(format!("Failed to get orders: {}", e)));
+        let page = self.track_auth(page).await?;
+
+        let next_cursor = if page.next_cursor == CLOB_TERMINAL_CURSOR || page.next_cursor.is_empty()
+        {
+            None
+        } else {
+            Some(page.next_cursor)
+        };
+        let orders = page
+            .data
+            .into_iter()
+            .filter(|o| {
+                let status = format!("{:?}", o.status);
+                status.contains("Live") || status.contains("Open")
+            })
+            .map(|o| OrderResponse {
+                id: o.id.clone(),
+                status: format!("{:?}", o.status),
+                owner: Some(o.owner.to_string()),
+                market: Some(o.market.to_string()),
+                asset_id: Some(o.asset_id.to_string()),
+                side: Some(format!("{:?}", o.side)),
+                original_size: Some(o.original_size.to_string()),
+                size_matched: Some(o.size_matched.to_string()),
+                price: Some(o.price.to_string()),
+                associate_trades: None,
+                created_at: Some(o.created_at.to_rfc3339()),
+                expiration: Some(o.expiration.to_rfc3339()),
+                order_type: Some(format!("{:?}", o.order_type)),
+            })
+            .collect();
+        Ok((orders, next_cursor))
+    }
+
     /// Get orders for a specific token
     #[instrument(skip(self))]
     pub async fn get_orders_for_token(&self, token_id: &str) -> Result<Vec<OrderResponse>> {
@@ -1790,15 +2065,9 @@ impl PolymarketClient {
             return Ok(vec![]);
         }
 
-        let user = if let Some(funder) = self.funder {
-            format!("{:#x}", funder)
-        } else if let Some(w) = self.wallet.as_ref() {
-            format!("{:#x}", w.address())
-        } else if let Some(signer) = self.signer.as_ref() {
-            format!("{:#x}", signer.address())
-        } else {
-            return Err(PloyError::Auth("Not authenticated".to_string()));
-        };
+        let user = self
+            .wallet_address()
+            .ok_or_else(|| PloyError::Auth("Not authenticated".to_string()))?;
 
         let data_client = DataClient::default();
         let user_addr: polymarket_client_sdk::types::Address = user
@@ -1983,6 +2252,7 @@ impl PolymarketClient {
                                 )
                                 .unwrap_or_default()
                             }),
+                            sports_market_type: m.sports_market_type.clone(),
                         })
                         .collect()
                 })
@@ -2176,6 +2446,18 @@ mod tests {
         assert!(!client.has_hmac_auth());
     }
 
+    #[test]
+    fn test_auth_rejections_are_recognised() {
+        assert!(is_auth_rejection(
+            "Failed to post order: error(401 Unauthorized) making POST call to /order"
+        ));
+        assert!(is_auth_rejection("Failed to get orders: 403 Forbidden"));
+        assert!(is_auth_rejection("Failed to get order: Invalid api key"));
+        assert!(!is_auth_rejection(
+            "Failed to post order: not enough balance / allowance"
+        ));
+    }
+
     #[test]
     fn test_parse_order_status() {
         assert!(matches!(
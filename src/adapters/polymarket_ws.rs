@@ -1,19 +1,21 @@
+use crate::adapters::packed_quote::{PackedQuote, TokenTable};
 use crate::domain::{Quote, Side};
 use crate::error::{PloyError, Result};
-use crate::services::HealthState;
+use crate::exchange::LatencyInjector;
+use crate::services::{HealthState, Metrics};
 use chrono::Utc;
 use futures_util::{SinkExt, StreamExt};
 use rust_decimal::Decimal;
 use serde::{Deserialize, Serialize};
-use std::collections::{HashMap, HashSet};
+use std::collections::{HashMap, HashSet, VecDeque};
 use std::sync::atomic::{AtomicU32, AtomicU64, Ordering};
 use std::sync::Arc;
-use std::sync::OnceLock;
+use std::sync::{Mutex, OnceLock};
 use std::time::Duration;
 use tokio::io::{AsyncBufReadExt, AsyncWriteExt, BufReader};
 use tokio::net::TcpStream;
-use tokio::sync::{broadcast, RwLock};
-use tokio::time::{interval, timeout, Instant};
+use tokio::sync::{broadcast, Notify, RwLock};
+use tokio::time::{interval, sleep_until, timeout, Instant};
 use tokio_tungstenite::{connect_async, tungstenite::Message, MaybeTlsStream, WebSocketStream};
 use tracing::{debug, error, info, warn};
 use url::Url;
@@ -399,6 +401,250 @@ fn extract_book_top(
     (best_bid, best_ask, bid_total, ask_total)
 }
 
+/// Quote sanitizer configuration (bad-tick filtering for incoming book snapshots)
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(default)]
+pub struct QuoteSanitizerConfig {
+    /// Enable the sanitizer. When disabled, every tick is accepted and no counters move.
+    pub enabled: bool,
+    /// Research bypass: evaluate and count rejections, but still pass ticks through.
+    pub bypass: bool,
+    /// Reject snapshots where best bid >= best ask.
+    pub reject_crossed: bool,
+    /// Maximum mid-price jump vs the last accepted tick for the same token (0 = disabled).
+    pub max_tick_jump: Decimal,
+    /// Accept a jump after this many consecutive jump rejections (re-anchors on real moves).
+    pub max_consecutive_jump_rejects: u32,
+    /// Minimum resting size on a quoted side (0 = disabled).
+    pub min_size: Decimal,
+}
+
+impl Default for QuoteSanitizerConfig {
+    fn default() -> Self {
+        Self {
+            enabled: true,
+            bypass: false,
+            reject_crossed: true,
+            max_tick_jump: Decimal::new(30, 2),
+            max_consecutive_jump_rejects: 3,
+            min_size: Decimal::ZERO,
+        }
+    }
+}
+
+/// Reason a tick was rejected by the sanitizer
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum QuoteRejectReason {
+    /// Best bid at or above best ask
+    Crossed,
+    /// Price outside the valid (0, 1) probability range
+    OutOfRange,
+    /// Mid moved more than `max_tick_jump` from the last accepted tick
+    TickJump,
+    /// Quoted side has less than `min_size` resting
+    BelowMinSize,
+}
+
+/// Snapshot of sanitizer counters
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Serialize)]
+pub struct QuoteSanitizerStats {
+    pub accepted: u64,
+    pub rejected_crossed: u64,
+    pub rejected_out_of_range: u64,
+    pub rejected_tick_jump: u64,
+    pub rejected_min_size: u64,
+    /// Ticks that would have been rejected but were passed through in bypass mode
+    pub bypassed: u64,
+}
+
+impl QuoteSanitizerStats {
+    pub fn total_rejected(&self) -> u64 {
+        self.rejected_crossed
+            + self.rejected_out_of_range
+            + self.rejected_tick_jump
+            + self.rejected_min_size
+    }
+}
+
+#[derive(Debug, Clone, Copy)]
+struct SanitizerAnchor {
+    mid: Decimal,
+    consecutive_jumps: u32,
+}
+
+/// Filters crossed, out-of-range, thin, or absurdly-jumping quotes before they
+/// reach the quote cache (and therefore signal detection).
+#[derive(Debug)]
+pub struct QuoteSanitizer {
+    config: QuoteSanitizerConfig,
+    anchors: dashmap::DashMap<String, SanitizerAnchor>,
+    accepted: AtomicU64,
+    rejected_crossed: AtomicU64,
+    rejected_out_of_range: AtomicU64,
+    rejected_tick_jump: AtomicU64,
+    rejected_min_size: AtomicU64,
+    bypassed: AtomicU64,
+}
+
+impl Default for QuoteSanitizer {
+    fn default() -> Self {
+        Self::new(QuoteSanitizerConfig::default())
+    }
+}
+
+impl QuoteSanitizer {
+    pub fn new(config: QuoteSanitizerConfig) -> Self {
+        Self {
+            config,
+            anchors: dashmap::DashMap::new(),
+            accepted: AtomicU64::new(0),
+            rejected_crossed: AtomicU64::new(0),
+            rejected_out_of_range: AtomicU64::new(0),
+            rejected_tick_jump: AtomicU64::new(0),
+            rejected_min_size: AtomicU64::new(0),
+            bypassed: AtomicU64::new(0),
+        }
+    }
+
+    pub fn config(&self) -> &QuoteSanitizerConfig {
+        &self.config
+    }
+
+    /// Check a top-of-book tick. Returns `true` if the tick should be applied.
+    ///
+    /// In bypass mode rejections are counted but the tick is still accepted.
+    pub fn check(
+        &self,
+        token_id: &str,
+        bid: Option<Decimal>,
+        ask: Option<Decimal>,
+        bid_size: Option<Decimal>,
+        ask_size: Option<Decimal>,
+    ) -> bool {
+        if !self.config.enabled {
+            return true;
+        }
+
+        match self.evaluate(token_id, bid, ask, bid_size, ask_size) {
+            Ok(()) => {
+                self.accepted.fetch_add(1, Ordering::Relaxed);
+                true
+            }
+            Err(reason) => {
+                let counter = match reason {
+                    QuoteRejectReason::Crossed => &self.rejected_crossed,
+                    QuoteRejectReason::OutOfRange => &self.rejected_out_of_range,
+                    QuoteRejectReason::TickJump => &self.rejected_tick_jump,
+                    QuoteRejectReason::BelowMinSize => &self.rejected_min_size,
+                };
+                counter.fetch_add(1, Ordering::Relaxed);
+
+                if self.config.bypass {
+                    self.bypassed.fetch_add(1, Ordering::Relaxed);
+                    if let Some(mid) = mid_of(bid, ask) {
+                        self.anchors.insert(
+                            token_id.to_string(),
+                            SanitizerAnchor {
+                                mid,
+                                consecutive_jumps: 0,
+                            },
+                        );
+                    }
+                    return true;
+                }
+
+                debug!(
+                    "Rejected tick for {}: {:?} bid={:?} ask={:?}",
+                    &token_id[..16.min(token_id.len())],
+                    reason,
+                    bid,
+                    ask
+                );
+                false
+            }
+        }
+    }
+
+    fn evaluate(
+        &self,
+        token_id: &str,
+        bid: Option<Decimal>,
+        ask: Option<Decimal>,
+        bid_size: Option<Decimal>,
+        ask_size: Option<Decimal>,
+    ) -> std::result::Result<(), QuoteRejectReason> {
+        let in_range = |p: Decimal| p > Decimal::ZERO && p < Decimal::ONE;
+        if bid.is_some_and(|b| !in_range(b)) || ask.is_some_and(|a| !in_range(a)) {
+            return Err(QuoteRejectReason::OutOfRange);
+        }
+
+        if self.config.reject_crossed {
+            if let (Some(b), Some(a)) = (bid, ask) {
+                if b >= a {
+                    return Err(QuoteRejectReason::Crossed);
+                }
+            }
+        }
+
+        if self.config.min_size > Decimal::ZERO {
+            let thin = |price: Option<Decimal>, size: Option<Decimal>| {
+                price.is_some() && size.unwrap_or_default() < self.config.min_size
+            };
+            if thin(bid, bid_size) || thin(ask, ask_size) {
+                return Err(QuoteRejectReason::BelowMinSize);
+            }
+        }
+
+        let Some(mid) = mid_of(bid, ask) else {
+            return Ok(());
+        };
+
+        let mut anchor = self
+            .anchors
+            .entry(token_id.to_string())
+            .or_insert(SanitizerAnchor {
+                mid,
+                consecutive_jumps: 0,
+            });
+
+        if self.config.max_tick_jump > Decimal::ZERO
+            && (mid - anchor.mid).abs() > self.config.max_tick_jump
+            && anchor.consecutive_jumps < self.config.max_consecutive_jump_rejects
+        {
+            anchor.consecutive_jumps += 1;
+            return Err(QuoteRejectReason::TickJump);
+        }
+
+        anchor.mid = mid;
+        anchor.consecutive_jumps = 0;
+        Ok(())
+    }
+
+    /// Drop the per-token anchor (e.g. after a round rolls over).
+    pub fn reset_token(&self, token_id: &str) {
+        self.anchors.remove(token_id);
+    }
+
+    pub fn stats(&self) -> QuoteSanitizerStats {
+        QuoteSanitizerStats {
+            accepted: self.accepted.load(Ordering::Relaxed),
+            rejected_crossed: self.rejected_crossed.load(Ordering::Relaxed),
+            rejected_out_of_range: self.rejected_out_of_range.load(Ordering::Relaxed),
+            rejected_tick_jump: self.rejected_tick_jump.load(Ordering::Relaxed),
+            rejected_min_size: self.rejected_min_size.load(Ordering::Relaxed),
+            bypassed: self.bypassed.load(Ordering::Relaxed),
+        }
+    }
+}
+
+fn mid_of(bid: Option<Decimal>, ask: Option<Decimal>) -> Option<Decimal> {
+    match (bid, ask) {
+        (Some(b), Some(a)) => Some((b + a) / Decimal::TWO),
+        (Some(p), None) | (None, Some(p)) => Some(p),
+        (None, None) => None,
+    }
+}
+
 /// Initial subscription request
 #[derive(Debug, Clone, Serialize)]
 struct SubscribeRequest {
@@ -409,12 +655,78 @@ struct SubscribeRequest {
 
 /// Dynamic subscription/unsubscription request
 #[derive(Debug, Clone, Serialize)]
-#[allow(dead_code)]
 struct DynamicSubscribeRequest {
     assets_ids: Vec<String>,
     operation: String,
 }
 
+/// Token set live on the current WebSocket session.
+///
+/// Rotations are applied as subscribe/unsubscribe diffs against this set, and the
+/// gap until every newly added token has produced data is timed.
+#[derive(Debug, Default)]
+struct SubscriptionRegistry {
+    subscribed: HashSet<String>,
+    /// Tokens added by the last rotation that have not produced data yet
+    awaiting: HashSet<String>,
+    rotation_started: Option<Instant>,
+}
+
+impl SubscriptionRegistry {
+    /// Replace the registry after a fresh connect + full subscribe.
+    fn reset(&mut self, token_ids: &[String]) {
+        self.subscribed = token_ids.iter().cloned().collect();
+        self.awaiting.clear();
+        self.rotation_started = None;
+    }
+
+    /// Returns `(subscribe, unsubscribe)` needed to move to `desired`, sorted.
+    fn diff(&self, desired: &[String]) -> (Vec<String>, Vec<String>) {
+        let desired: HashSet<&String> = desired.iter().collect();
+        let mut added: Vec<String> = desired
+            .iter()
+            .filter(|t| !self.subscribed.contains(**t))
+            .map(|t| (*t).clone())
+            .collect();
+        let mut removed: Vec<String> = self
+            .subscribed
+            .iter()
+            .filter(|t| !desired.contains(t))
+            .cloned()
+            .collect();
+        added.sort();
+        removed.sort();
+        (added, removed)
+    }
+
+    /// Record a rotation that was sent on the live connection.
+    fn apply(&mut self, added: &[String], removed: &[String], now: Instant) {
+        for token in removed {
+            self.subscribed.remove(token);
+            self.awaiting.remove(token);
+        }
+        for token in added {
+            self.subscribed.insert(token.clone());
+        }
+        if !added.is_empty() {
+            self.awaiting = added.iter().cloned().collect();
+            self.rotation_started = Some(now);
+        }
+    }
+
+    /// Mark market data for a token.
+    ///
+    /// Returns the rotation gap once every token added by the last rotation has data.
+    fn observe(&mut self, token_id: &str, now: Instant) -> Option<Duration> {
+        if !self.awaiting.remove(token_id) || !self.awaiting.is_empty() {
+            return None;
+        }
+        self.rotation_started
+            .take()
+            .map(|started| now.saturating_duration_since(started))
+    }
+}
+
 /// Simplified quote for display
 #[derive(Debug, Clone)]
 pub struct DisplayQuote {
@@ -660,13 +972,22 @@ pub struct PolymarketWebSocket {
     /// `Side` mapping (ex: YES/NO sports markets).
     extra_tokens: Arc<RwLock<HashSet<String>>>,
     update_tx: broadcast::Sender<QuoteUpdate>,
+    /// Fixed-point copy of `update_tx`; only filled while someone subscribes.
+    packed_tx: broadcast::Sender<PackedQuote>,
+    tokens: Arc<TokenTable>,
     book_tx: broadcast::Sender<Arc<BookMessage>>,
     reconnect_delay: Duration,
     max_reconnect_attempts: u32,
     circuit_breaker: Arc<CircuitBreaker>,
-    resubscribe_requested: Arc<std::sync::atomic::AtomicBool>,
+    sanitizer: Arc<QuoteSanitizer>,
+    /// Simulated quote latency (dry-run / paper stress testing only)
+    latency: Option<Arc<LatencyInjector>>,
+    resubscribe: Arc<Notify>,
+    subscriptions: Mutex<SubscriptionRegistry>,
     // Optional: wired in at runtime by the binary to report connectivity to /health.
     health_state: OnceLock<Arc<HealthState>>,
+    // Optional: receives reconnect counts and rotation gap timings.
+    metrics: OnceLock<Arc<Metrics>>,
 }
 
 /// Quote update notification
@@ -686,6 +1007,7 @@ impl PolymarketWebSocket {
     /// Create a new WebSocket client with custom circuit breaker config
     pub fn with_circuit_breaker(ws_url: &str, cb_config: CircuitBreakerConfig) -> Self {
         let (update_tx, _) = broadcast::channel(1000);
+        let (packed_tx, _) = broadcast::channel(1000);
         // Book snapshots can be significantly larger than quotes; keep a smaller buffer.
         let (book_tx, _) = broadcast::channel(256);
 
@@ -695,12 +1017,18 @@ impl PolymarketWebSocket {
             token_to_side: Arc::new(RwLock::new(HashMap::new())),
             extra_tokens: Arc::new(RwLock::new(HashSet::new())),
             update_tx,
+            packed_tx,
+            tokens: Arc::new(TokenTable::new()),
             book_tx,
             reconnect_delay: Duration::from_secs(1),
             max_reconnect_attempts: 10,
             circuit_breaker: Arc::new(CircuitBreaker::new(cb_config)),
-            resubscribe_requested: Arc::new(std::sync::atomic::AtomicBool::new(false)),
+            sanitizer: Arc::new(QuoteSanitizer::default()),
+            latency: None,
+            resubscribe: Arc::new(Notify::new()),
+            subscriptions: Mutex::new(SubscriptionRegistry::default()),
             health_state: OnceLock::new(),
+            metrics: OnceLock::new(),
         }
     }
 
@@ -711,16 +1039,56 @@ impl PolymarketWebSocket {
         let _ = self.health_state.set(state);
     }
 
+    /// Wire an optional `Metrics` collector (reconnects, rotation gaps).
+    ///
+    /// Safe to call multiple times; only the first call wins.
+    pub fn set_metrics(&self, metrics: Arc<Metrics>) {
+        let _ = self.metrics.set(metrics);
+    }
+
     /// Get the circuit breaker (for external monitoring)
     pub fn circuit_breaker(&self) -> Arc<CircuitBreaker> {
         Arc::clone(&self.circuit_breaker)
     }
 
+    /// Replace the quote sanitizer configuration (bad-tick filtering).
+    pub fn with_quote_sanitizer(mut self, config: QuoteSanitizerConfig) -> Self {
+        self.sanitizer = Arc::new(QuoteSanitizer::new(config));
+        self
+    }
+
+    /// Delay every market-data message by a sampled quote latency.
+    ///
+    /// Messages keep their arrival order; only meant for dry-run / paper runs.
+    pub fn with_latency(mut self, injector: Arc<LatencyInjector>) -> Self {
+        self.latency = Some(injector);
+        self
+    }
+
+    /// Get the quote sanitizer (for rejection counters)
+    pub fn quote_sanitizer(&self) -> Arc<QuoteSanitizer> {
+        Arc::clone(&self.sanitizer)
+    }
+
     /// Get a receiver for quote updates
     pub fn subscribe_updates(&self) -> broadcast::Receiver<QuoteUpdate> {
         self.update_tx.subscribe()
     }
 
+    /// Get a receiver for fixed-point quote updates.
+    ///
+    /// Opt-in path for latency-sensitive consumers: quotes are packed only while
+    /// at least one packed receiver exists. Resolve `PackedQuote::token` with
+    /// [`Self::token_table`].
+    pub fn subscribe_packed(&self) -> broadcast::Receiver<PackedQuote> {
+        self.packed_tx.subscribe()
+    }
+
+    /// Token id interner used by packed quotes
+    pub fn token_table(&self) -> Arc<TokenTable> {
+        Arc::clone(&self.tokens)
+    }
+
     /// Get a receiver for order book snapshot updates (full bid/ask ladders).
     pub fn subscribe_books(&self) -> broadcast::Receiver<Arc<BookMessage>> {
         self.book_tx.subscribe()
@@ -733,9 +1101,18 @@ impl PolymarketWebSocket {
 
     /// Request a WebSocket resubscription cycle.
     ///
-    /// The current connection loop will reconnect and apply the latest token set.
+    /// The live connection diffs the latest token set against what it is subscribed to and
+    /// sends subscribe/unsubscribe messages, without reconnecting.
     pub fn request_resubscribe(&self) {
-        self.resubscribe_requested.store(true, Ordering::SeqCst);
+        self.resubscribe.notify_one();
+    }
+
+    /// Token IDs subscribed on the current WebSocket session.
+    pub fn subscribed_tokens(&self) -> HashSet<String> {
+        self.subscriptions
+            .lock()
+            .map(|r| r.subscribed.clone())
+            .unwrap_or_default()
     }
 
     /// Register token ID to side mapping
@@ -884,15 +1261,24 @@ impl PolymarketWebSocket {
                 continue;
             }
 
-            match self.connect_and_subscribe(&subscription_ids).await {
+            match self
+                .connect_and_subscribe(&token_ids, &subscription_ids)
+                .await
+            {
                 Ok(()) => {
                     // Connection closed normally - still counts as success for circuit breaker
                     self.circuit_breaker.record_success().await;
                     info!("WebSocket connection closed, reconnecting...");
+                    if let Some(m) = self.metrics.get() {
+                        m.inc_reconnections();
+                    }
                     attempt = 0;
                 }
                 Err(e) => {
                     self.circuit_breaker.record_failure().await;
+                    if let Some(m) = self.metrics.get() {
+                        m.inc_reconnections();
+                    }
                     attempt = attempt.saturating_add(1);
                     error!(
                         "WebSocket error (attempt {}, circuit failures {}): {}",
@@ -932,7 +1318,11 @@ impl PolymarketWebSocket {
     }
 
     /// Connect and subscribe to token updates
-    async fn connect_and_subscribe(&self, token_ids: &[String]) -> Result<()> {
+    async fn connect_and_subscribe(
+        &self,
+        seed_tokens: &[String],
+        token_ids: &[String],
+    ) -> Result<()> {
         let health = self.health_state.get().cloned();
         struct WsHealthGuard(Option<Arc<HealthState>>);
         impl Drop for WsHealthGuard {
@@ -968,24 +1358,43 @@ impl PolymarketWebSocket {
         let msg_json = serde_json::to_string(&subscribe_msg)?;
         write.send(Message::Text(msg_json)).await?;
         info!("Subscribed to {} tokens", token_ids.len());
+        if let Ok(mut registry) = self.subscriptions.lock() {
+            registry.reset(token_ids);
+        }
 
         // Set up ping interval
         let mut ping_interval = interval(Duration::from_secs(30));
         let mut health_interval = interval(Duration::from_secs(15));
         let mut last_market_data = Instant::now();
         let stale_timeout = Duration::from_secs(90);
+        // Messages held back by simulated latency: (deliver_at, payload)
+        let mut delayed: VecDeque<(Instant, String)> = VecDeque::new();
 
         loop {
+            let next_due = delayed.front().map(|(at, _)| *at);
             tokio::select! {
                 // Handle incoming messages
                 msg = read.next() => {
                     match msg {
                         Some(Ok(Message::Text(text))) => {
-                            if self.handle_message(&text).await {
-                                last_market_data = Instant::now();
-                                if let Some(ref h) = health {
-                                    h.record_ws_message().await;
+                            let delay = self
+                                .latency
+                                .as_ref()
+                                .map(|l| l.quote_delay())
+                                .unwrap_or_default();
+                            if delay.is_zero() && delayed.is_empty() {
+                                if self.handle_message(&text).await {
+                                    last_market_data = Instant::now();
+                                    if let Some(ref h) = health {
+                                        h.record_ws_message().await;
+                                    }
                                 }
+                            } else {
+                                let now = Instant::now();
+                                let at = delayed
+                                    .back()
+                                    .map_or(now + delay, |(prev, _)| (*prev).max(now + delay));
+                                delayed.push_back((at, text));
                             }
                         }
                         Some(Ok(Message::Ping(data))) => {
@@ -1004,18 +1413,57 @@ impl PolymarketWebSocket {
                         _ => {}
                     }
                 }
+                // Release latency-delayed messages in arrival order
+                _ = sleep_until(next_due.unwrap_or_else(Instant::now)), if next_due.is_some() => {
+                    if let Some((_, text)) = delayed.pop_front() {
+                        if self.handle_message(&text).await {
+                            last_market_data = Instant::now();
+                            if let Some(ref h) = health {
+                                h.record_ws_message().await;
+                            }
+                        }
+                    }
+                }
                 // Send periodic pings
                 _ = ping_interval.tick() => {
                     write.send(Message::Ping(vec![])).await?;
                     debug!("Sent ping");
                 }
-                // Connection health / resubscribe checks
-                _ = health_interval.tick() => {
-                    if self.resubscribe_requested.swap(false, Ordering::SeqCst) {
-                        info!("Resubscribe requested; reconnecting WebSocket session");
-                        break;
+                // Apply token rotations on the live session
+                _ = self.resubscribe.notified() => {
+                    let desired = self.build_subscription_list(seed_tokens).await;
+                    if desired.is_empty() {
+                        debug!("Resubscribe requested with no tokens registered; keeping current set");
+                        continue;
                     }
-
+                    let (added, removed) = match self.subscriptions.lock() {
+                        Ok(registry) => registry.diff(&desired),
+                        Err(_) => break,
+                    };
+                    for (operation, ids) in [("unsubscribe", &removed), ("subscribe", &added)] {
+                        if ids.is_empty() {
+                            continue;
+                        }
+                        let msg = DynamicSubscribeRequest {
+                            assets_ids: ids.clone(),
+                            operation: operation.to_string(),
+                        };
+                        write.send(Message::Text(serde_json::to_string(&msg)?)).await?;
+                    }
+                    if let Ok(mut registry) = self.subscriptions.lock() {
+                        registry.apply(&added, &removed, Instant::now());
+                    }
+                    if !added.is_empty() || !removed.is_empty() {
+                        info!(
+                            "Rotated subscriptions: +{} -{} (total {})",
+                            added.len(),
+                            removed.len(),
+                            desired.len()
+                        );
+                    }
+                }
+                // Connection health checks
+                _ = health_interval.tick() => {
                     if last_market_data.elapsed() > stale_timeout {
                         return Err(PloyError::Internal(format!(
                             "No market data received for {:?}; forcing reconnect",
@@ -1076,6 +1524,17 @@ impl PolymarketWebSocket {
         let asset_id = book.asset_id.clone();
 
         let (best_bid, best_ask, bid_size, ask_size) = extract_book_top(&book);
+        self.observe_rotation(&asset_id);
+
+        // Bad ticks never reach the quote cache; the raw book is still broadcast so
+        // persistence keeps an unfiltered record.
+        if !self
+            .sanitizer
+            .check(&asset_id, best_bid, best_ask, bid_size, ask_size)
+        {
+            let _ = self.book_tx.send(Arc::new(book));
+            return;
+        }
 
         if let Some(side) = self.get_side(&asset_id).await {
             self.quote_cache
@@ -1083,6 +1542,7 @@ impl PolymarketWebSocket {
 
             // Notify subscribers
             if let Some(quote) = self.quote_cache.get(&asset_id) {
+                self.publish_packed(&asset_id, side, &quote);
                 let update = QuoteUpdate {
                     token_id: asset_id.clone(),
                     side,
@@ -1127,6 +1587,31 @@ impl PolymarketWebSocket {
         let _ = self.book_tx.send(Arc::new(book));
     }
 
+    /// Pack and broadcast a quote if anyone subscribed to the packed bus.
+    fn publish_packed(&self, token_id: &str, side: Side, quote: &Quote) {
+        if self.packed_tx.receiver_count() == 0 {
+            return;
+        }
+        let token = self.tokens.intern(token_id);
+        let _ = self
+            .packed_tx
+            .send(PackedQuote::from_quote(token, side, quote));
+    }
+
+    /// Time the gap between a subscription rotation and data for all of its new tokens.
+    fn observe_rotation(&self, token_id: &str) {
+        let gap = match self.subscriptions.lock() {
+            Ok(mut registry) => registry.observe(token_id, Instant::now()),
+            Err(_) => None,
+        };
+        if let Some(gap) = gap {
+            info!("Subscription rotation filled in {:?}", gap);
+            if let Some(m) = self.metrics.get() {
+                m.record_rotation_gap(gap);
+            }
+        }
+    }
+
     /// Process price changes message
     async fn process_price_changes(&self, msg: PriceChangesMessage) {
         for change in msg.price_changes {
@@ -1146,6 +1631,7 @@ impl PolymarketWebSocket {
                 if let Some(quote) = self.quote_cache.get(&change.asset_id) {
                     // Only broadcast if we have at least one side from a prior book snapshot
                     if quote.best_bid.is_some() || quote.best_ask.is_some() {
+                        self.publish_packed(&change.asset_id, side, &quote);
                         let update = QuoteUpdate {
                             token_id: change.asset_id.clone(),
                             side,
@@ -1344,4 +1830,106 @@ mod tests {
         assert_eq!(cb.get_state().await, CircuitBreakerState::Closed);
         assert!(cb.should_allow().await);
     }
+
+    #[test]
+    fn test_sanitizer_rejects_crossed_and_out_of_range() {
+        let sanitizer = QuoteSanitizer::default();
+
+        assert!(!sanitizer.check("t", Some(dec!(0.55)), Some(dec!(0.50)), None, None));
+        assert!(!sanitizer.check("t", Some(dec!(0.45)), Some(dec!(1.20)), None, None));
+        assert!(sanitizer.check("t", Some(dec!(0.45)), Some(dec!(0.47)), None, None));
+
+        let stats = sanitizer.stats();
+        assert_eq!(stats.rejected_crossed, 1);
+        assert_eq!(stats.rejected_out_of_range, 1);
+        assert_eq!(stats.accepted, 1);
+    }
+
+    #[test]
+    fn test_sanitizer_tick_jump_reanchors_after_consecutive_rejects() {
+        let sanitizer = QuoteSanitizer::new(QuoteSanitizerConfig {
+            max_tick_jump: dec!(0.20),
+            max_consecutive_jump_rejects: 2,
+            ..Default::default()
+        });
+
+        assert!(sanitizer.check("t", Some(dec!(0.49)), Some(dec!(0.51)), None, None));
+        // Mid jumps 0.50 -> 0.05: rejected twice, then accepted as a real move.
+        assert!(!sanitizer.check("t", Some(dec!(0.04)), Some(dec!(0.06)), None, None));
+        assert!(!sanitizer.check("t", Some(dec!(0.04)), Some(dec!(0.06)), None, None));
+        assert!(sanitizer.check("t", Some(dec!(0.04)), Some(dec!(0.06)), None, None));
+        assert_eq!(sanitizer.stats().rejected_tick_jump, 2);
+    }
+
+    #[test]
+    fn test_sanitizer_min_size_and_bypass() {
+        let sanitizer = QuoteSanitizer::new(QuoteSanitizerConfig {
+            min_size: dec!(5),
+            bypass: true,
+            ..Default::default()
+        });
+
+        // Thin book is counted as a rejection but still passed through in bypass mode.
+        assert!(sanitizer.check(
+            "t",
+            Some(dec!(0.45)),
+            Some(dec!(0.47)),
+            Some(dec!(1)),
+            Some(dec!(10)),
+        ));
+        let stats = sanitizer.stats();
+        assert_eq!(stats.rejected_min_size, 1);
+        assert_eq!(stats.bypassed, 1);
+        assert_eq!(stats.total_rejected(), 1);
+    }
+
+    #[tokio::test]
+    async fn test_crossed_book_does_not_reach_quote_cache() {
+        let ws = PolymarketWebSocket::new("wss://example.invalid");
+        ws.register_token("token_up", Side::Up).await;
+
+        let book = BookMessage {
+            asset_id: "token_up".to_string(),
+            market: "m".to_string(),
+            bids: vec![PriceLevel {
+                price: "0.60".to_string(),
+                size: "10".to_string(),
+            }],
+            asks: vec![PriceLevel {
+                price: "0.40".to_string(),
+                size: "10".to_string(),
+            }],
+            timestamp: None,
+            hash: None,
+        };
+        ws.process_book_message(book).await;
+
+        assert!(ws.quote_cache().get("token_up").is_none());
+        assert_eq!(ws.quote_sanitizer().stats().rejected_crossed, 1);
+    }
+
+    #[tokio::test]
+    async fn test_subscription_rotation_diff_and_gap() {
+        let ws = PolymarketWebSocket::new("wss://example.invalid");
+        let metrics = Arc::new(Metrics::new());
+        ws.set_metrics(metrics.clone());
+
+        let mut registry = SubscriptionRegistry::default();
+        registry.reset(&["a".to_string(), "b".to_string()]);
+        let (added, removed) = registry.diff(&["b".to_string(), "c".to_string(), "d".to_string()]);
+        assert_eq!(added, vec!["c".to_string(), "d".to_string()]);
+        assert_eq!(removed, vec!["a".to_string()]);
+
+        let start = Instant::now();
+        registry.apply(&added, &removed, start);
+        *ws.subscriptions.lock().unwrap() = registry;
+        assert_eq!(ws.subscribed_tokens().len(), 3);
+
+        // Gap is only recorded once every new token has produced data
+        ws.observe_rotation("b");
+        ws.observe_rotation("c");
+        assert_eq!(metrics.ws_rotations.load(Ordering::Relaxed), 0);
+        ws.observe_rotation("d");
+        assert_eq!(metrics.ws_rotations.load(Ordering::Relaxed), 1);
+    }
 }
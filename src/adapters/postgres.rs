@@ -381,6 +381,20 @@ impl PostgresStore {
         Ok(())
     }
 
+    /// Store the latest crash-recovery checkpoint for a cycle
+    pub async fn update_cycle_checkpoint(
+        &self,
+        cycle_id: i32,
+        checkpoint: serde_json::Value,
+    ) -> Result<()> {
+        sqlx::query("UPDATE cycles SET checkpoint = $1, updated_at = NOW() WHERE id = $2")
+            .bind(checkpoint)
+            .bind(cycle_id)
+            .execute(&self.pool)
+            .await?;
+        Ok(())
+    }
+
     /// Get cycle by ID
     pub async fn get_cycle(&self, cycle_id: i32) -> Result<Option<Cycle>> {
         let row = sqlx::query(
@@ -710,7 +724,7 @@ impl PostgresStore {
         let rows = sqlx::query(
             r#"
             SELECT c.id, c.round_id, c.state, c.leg1_side, c.leg1_entry_price, c.leg1_shares,
-                   c.leg1_filled_at, c.created_at,
+                   c.leg1_filled_at, c.created_at, c.checkpoint,
                    r.slug, r.up_token_id, r.down_token_id, r.end_time
             FROM cycles c
             JOIN rounds r ON c.round_id = r.id
@@ -742,6 +756,7 @@ impl PostgresStore {
                 up_token_id: r.get("up_token_id"),
                 down_token_id: r.get("down_token_id"),
                 round_end_time: r.get("end_time"),
+                checkpoint: r.get("checkpoint"),
             })
             .collect();
 
@@ -838,9 +853,9 @@ impl PostgresStore {
             r#"
             INSERT INTO dump_signals (
                 round_id, side, trigger_price, reference_price, drop_pct,
-                spread_bps, was_valid, timestamp
+                spread_bps, was_valid, timestamp, threshold_pct, realized_vol
             )
-            VALUES ($1, $2, $3, $4, $5, $6, $7, $8)
+            VALUES ($1, $2, $3, $4, $5, $6, $7, $8, $9, $10)
             RETURNING id
             "#,
         )
@@ -852,6 +867,8 @@ impl PostgresStore {
         .bind(signal.spread_bps as i32)
         .bind(signal.is_valid(500)) // TODO: use config
         .bind(signal.timestamp)
+        .bind(signal.threshold_pct)
+        .bind(signal.realized_vol)
         .fetch_one(&self.pool)
         .await?;
 
@@ -899,6 +916,8 @@ pub struct IncompleteCycle {
     pub up_token_id: String,
     pub down_token_id: String,
     pub round_end_time: DateTime<Utc>,
+    /// Engine checkpoint (None for cycles written before checkpoints existed)
+    pub checkpoint: Option<serde_json::Value>,
 }
 
 impl IncompleteCycle {
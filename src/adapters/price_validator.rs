@@ -0,0 +1,221 @@
+//! Per-market tick-size and minimum-size checks for outgoing orders.
+//!
+//! Polymarket rejects orders whose price is not a multiple of the market's
+//! tick size (0.01 on most markets, 0.001 once a price nears 0 or 1) or whose
+//! size is below the market minimum. [`PriceValidator`] caches those rules per
+//! token for a short TTL and snaps or checks every [`OrderRequest`] before it
+//! is signed, so violations come back as a typed [`PriceError`] instead of an
+//! exchange reject.
+
+use rust_decimal::Decimal;
+use rust_decimal::RoundingStrategy;
+use rust_decimal_macros::dec;
+use serde::{Deserialize, Serialize};
+use std::collections::HashMap;
+use std::sync::RwLock;
+use std::time::{Duration, Instant};
+
+use crate::domain::{OrderRequest, OrderSide};
+use crate::error::PriceError;
+
+/// Validator settings.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(default)]
+pub struct PriceValidatorConfig {
+    /// Tick used when a market's rules cannot be fetched. 0.01 prices are
+    /// valid on both the 0.01 and 0.001 grids.
+    pub default_tick_size: Decimal,
+    /// Minimum size used when a market's rules cannot be fetched
+    pub default_min_size: Option<Decimal>,
+    /// Round off-tick prices to the passive side (buys down, sells up)
+    /// instead of rejecting them
+    pub snap_to_tick: bool,
+    /// How long fetched rules are trusted; tick sizes change as prices move
+    pub cache_ttl_secs: u64,
+}
+
+impl Default for PriceValidatorConfig {
+    fn default() -> Self {
+        Self {
+            default_tick_size: dec!(0.01),
+            default_min_size: None,
+            snap_to_tick: true,
+            cache_ttl_secs: 300,
+        }
+    }
+}
+
+/// Price increment and size floor of one market.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+pub struct MarketRules {
+    pub tick_size: Decimal,
+    pub min_size: Option<Decimal>,
+}
+
+impl MarketRules {
+    pub fn new(tick_size: Decimal, min_size: Option<Decimal>) -> Self {
+        Self {
+            tick_size,
+            min_size,
+        }
+    }
+
+    /// Decimal places a price may carry
+    pub fn price_decimals(&self) -> u32 {
+        self.tick_size.normalize().scale()
+    }
+
+    /// Round `price` onto the tick grid without worsening it
+    pub fn snap(&self, side: OrderSide, price: Decimal) -> Decimal {
+        if self.tick_size <= Decimal::ZERO {
+            return price;
+        }
+        let ticks = price / self.tick_size;
+        let strategy = match side {
+            OrderSide::Buy => RoundingStrategy::ToNegativeInfinity,
+            OrderSide::Sell => RoundingStrategy::ToPositiveInfinity,
+        };
+        (ticks.round_dp_with_strategy(0, strategy) * self.tick_size).normalize()
+    }
+
+    /// Check price grid, price range and size floor
+    pub fn check(&self, request: &OrderRequest) -> std::result::Result<(), PriceError> {
+        if self.tick_size <= Decimal::ZERO || self.tick_size >= Decimal::ONE {
+            return Err(PriceError::InvalidTickSize(self.tick_size));
+        }
+
+        let price = request.limit_price;
+        let min = self.tick_size;
+        let max = Decimal::ONE - self.tick_size;
+        if price < min || price > max {
+            return Err(PriceError::OutOfRange { price, min, max });
+        }
+        if !(price % self.tick_size).is_zero() {
+            return Err(PriceError::OffTick {
+                price,
+                tick_size: self.tick_size,
+            });
+        }
+
+        if let Some(min_size) = self.min_size {
+            if Decimal::from(request.shares) < min_size {
+                return Err(PriceError::BelowMinSize {
+                    shares: request.shares,
+                    min_size,
+                });
+            }
+        }
+        Ok(())
+    }
+}
+
+/// Cached per-token [`MarketRules`] plus the snap/check policy.
+#[derive(Debug, Default)]
+pub struct PriceValidator {
+    config: PriceValidatorConfig,
+    rules: RwLock<HashMap<String, (MarketRules, Instant)>>,
+}
+
+impl PriceValidator {
+    pub fn new(config: PriceValidatorConfig) -> Self {
+        Self {
+            config,
+            rules: RwLock::new(HashMap::new()),
+        }
+    }
+
+    pub fn config(&self) -> &PriceValidatorConfig {
+        &self.config
+    }
+
+    /// Rules used when a market's own cannot be fetched
+    pub fn default_rules(&self) -> MarketRules {
+        MarketRules::new(self.config.default_tick_size, self.config.default_min_size)
+    }
+
+    /// Cached rules for `token_id`, if still within the TTL
+    pub fn cached(&self, token_id: &str) -> Option<MarketRules> {
+        let ttl = Duration::from_secs(self.config.cache_ttl_secs);
+        let rules = match self.rules.read() {
+            Ok(rules) => rules,
+            Err(poisoned) => poisoned.into_inner(),
+        };
+        rules
+            .get(token_id)
+            .filter(|(_, fetched_at)| fetched_at.elapsed() < ttl)
+            .map(|(rules, _)| *rules)
+    }
+
+    pub fn insert(&self, token_id: &str, rules: MarketRules) {
+        let mut cache = match self.rules.write() {
+            Ok(cache) => cache,
+            Err(poisoned) => poisoned.into_inner(),
+        };
+        cache.insert(token_id.to_string(), (rules, Instant::now()));
+    }
+
+    /// Forget `token_id`'s rules so the next order refetches them
+    pub fn invalidate(&self, token_id: &str) {
+        let mut cache = match self.rules.write() {
+            Ok(cache) => cache,
+            Err(poisoned) => poisoned.into_inner(),
+        };
+        cache.remove(token_id);
+    }
+
+    /// Snap the price when enabled, then check it against `rules`.
+    pub fn apply(
+        &self,
+        rules: &MarketRules,
+        request: &mut OrderRequest,
+    ) -> std::result::Result<(), PriceError> {
+        if self.config.snap_to_tick {
+            request.limit_price = rules.snap(request.order_side, request.limit_price);
+        }
+        rules.check(request)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::domain::Side;
+
+    #[test]
+    fn test_snaps_to_passive_side_and_checks_rules() {
+        let rules = MarketRules::new(dec!(0.01), Some(dec!(5)));
+        let snapping = PriceValidator::default();
+        let strict = PriceValidator::new(PriceValidatorConfig {
+            snap_to_tick: false,
+            ..PriceValidatorConfig::default()
+        });
+
+        let mut buy = OrderRequest::buy_limit("tok".to_string(), Side::Up, 10, dec!(0.555));
+        assert_eq!(
+            strict.apply(&rules, &mut buy.clone()),
+            Err(PriceError::OffTick {
+                price: dec!(0.555),
+                tick_size: dec!(0.01)
+            })
+        );
+        snapping.apply(&rules, &mut buy).unwrap();
+        assert_eq!(buy.limit_price, dec!(0.55));
+
+        let mut sell = OrderRequest::sell_limit("tok".to_string(), Side::Up, 10, dec!(0.555));
+        snapping.apply(&rules, &mut sell).unwrap();
+        assert_eq!(sell.limit_price, dec!(0.56));
+
+        let mut small = OrderRequest::buy_limit("tok".to_string(), Side::Up, 2, dec!(0.50));
+        assert!(matches!(
+            snapping.apply(&rules, &mut small),
+            Err(PriceError::BelowMinSize { shares: 2, .. })
+        ));
+
+        // A buy snapped below the first tick is out of range
+        let mut dust = OrderRequest::buy_limit("tok".to_string(), Side::Up, 10, dec!(0.004));
+        assert!(matches!(
+            snapping.apply(&rules, &mut dust),
+            Err(PriceError::OutOfRange { .. })
+        ));
+    }
+}
@@ -0,0 +1,156 @@
+//! Reconnect and dispatch loop shared by the exchange spot ticker feeds
+//!
+//! [`CoinbaseWebSocket`](super::CoinbaseWebSocket) and
+//! [`KrakenWebSocket`](super::KrakenWebSocket) only differ in their URL,
+//! subscribe/ping frames and message format; a [`SpotTickerVenue`] describes
+//! those and [`run`] does the rest.
+
+use std::time::Duration;
+use tokio::sync::broadcast;
+use tokio::time::interval;
+use tokio_tungstenite::tungstenite::Message;
+use tracing::{debug, error, info};
+use url::Url;
+
+use super::chainlink_rtds::connect_websocket_with_proxy;
+use super::consensus_price::{ExchangeSpotUpdate, SpotSource};
+use crate::error::{PloyError, Result};
+
+pub(crate) const CHANNEL_CAPACITY: usize = 1000;
+
+/// Venues close idle connections; pings keep them open
+const PING_INTERVAL_SECS: u64 = 30;
+const MAX_RECONNECT_DELAY_SECS: u64 = 60;
+/// Characters of an ignored message kept in the debug log
+const LOG_EXCERPT_CHARS: usize = 200;
+
+/// Exchange-specific half of a spot ticker feed
+pub(crate) trait SpotTickerVenue {
+    const SOURCE: SpotSource;
+    const URL: &'static str;
+
+    /// Venue symbols for Binance-style `symbols` (e.g. "BTCUSDT")
+    fn venue_symbols(symbols: &[String]) -> Vec<String>;
+
+    /// Ticker subscribe frame for the venue symbols
+    fn subscribe_message(venue_symbols: &[String]) -> Message;
+
+    /// Keep-alive frame sent every [`PING_INTERVAL_SECS`]
+    fn ping_message() -> Message;
+
+    /// Spot updates carried by one text frame, empty for anything else
+    fn parse(text: &str) -> Vec<ExchangeSpotUpdate>;
+}
+
+/// Run the WebSocket connection loop with linear backoff reconnection
+pub(crate) async fn run<V: SpotTickerVenue>(
+    symbols: &[String],
+    update_tx: &broadcast::Sender<ExchangeSpotUpdate>,
+) -> Result<()> {
+    let mut attempt: u32 = 0;
+    let max_delay = Duration::from_secs(MAX_RECONNECT_DELAY_SECS);
+
+    info!(
+        "Starting {} WebSocket for symbols: {:?}",
+        V::SOURCE,
+        symbols
+    );
+
+    loop {
+        match connect_and_stream::<V>(symbols, update_tx).await {
+            Ok(()) => {
+                info!("{} WebSocket connection closed normally", V::SOURCE);
+                attempt = 0;
+            }
+            Err(e) => {
+                attempt += 1;
+                error!("{} WebSocket error (attempt {}): {}", V::SOURCE, attempt, e);
+            }
+        }
+
+        let delay = (Duration::from_secs(1) * attempt.clamp(1, 10)).min(max_delay);
+        info!("Reconnecting to {} in {:?}", V::SOURCE, delay);
+        tokio::time::sleep(delay).await;
+    }
+}
+
+async fn connect_and_stream<V: SpotTickerVenue>(
+    symbols: &[String],
+    update_tx: &broadcast::Sender<ExchangeSpotUpdate>,
+) -> Result<()> {
+    use futures_util::{SinkExt, StreamExt};
+
+    let url = Url::parse(V::URL)
+        .map_err(|e| PloyError::Internal(format!("Invalid {} WebSocket URL: {}", V::SOURCE, e)))?;
+    let ws_stream = connect_websocket_with_proxy(&url).await?;
+    let (mut write, mut read) = ws_stream.split();
+
+    let venue_symbols = V::venue_symbols(symbols);
+    write
+        .send(V::subscribe_message(&venue_symbols))
+        .await
+        .map_err(|e| PloyError::Internal(format!("Failed to send subscribe: {}", e)))?;
+    info!("Subscribed to {} ticker: {:?}", V::SOURCE, venue_symbols);
+
+    let mut ping_interval = interval(Duration::from_secs(PING_INTERVAL_SECS));
+
+    loop {
+        tokio::select! {
+            msg = read.next() => {
+                match msg {
+                    Some(Ok(Message::Text(text))) => dispatch::<V>(&text, update_tx),
+                    Some(Ok(Message::Ping(data))) => {
+                        if let Err(e) = write.send(Message::Pong(data)).await {
+                            error!("Failed to send pong: {}", e);
+                        }
+                    }
+                    Some(Ok(Message::Close(_))) | None => break,
+                    Some(Err(e)) => return Err(PloyError::WebSocket(e)),
+                    _ => {}
+                }
+            }
+            _ = ping_interval.tick() => {
+                if let Err(e) = write.send(V::ping_message()).await {
+                    error!("Failed to send ping to {}: {}", V::SOURCE, e);
+                    break;
+                }
+            }
+        }
+    }
+
+    Ok(())
+}
+
+fn dispatch<V: SpotTickerVenue>(text: &str, update_tx: &broadcast::Sender<ExchangeSpotUpdate>) {
+    let updates = V::parse(text);
+    if updates.is_empty() {
+        debug!(
+            "Ignoring {} message: {}",
+            V::SOURCE,
+            excerpt(text, LOG_EXCERPT_CHARS)
+        );
+    }
+    for update in updates {
+        let _ = update_tx.send(update);
+    }
+}
+
+/// First `max_chars` characters of `text`, never splitting a UTF-8 character
+fn excerpt(text: &str, max_chars: usize) -> &str {
+    match text.char_indices().nth(max_chars) {
+        Some((end, _)) => &text[..end],
+        None => text,
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_excerpt_respects_char_boundaries() {
+        let text = "é".repeat(150);
+        assert_eq!(excerpt(&text, 100).chars().count(), 100);
+        assert_eq!(excerpt("short", 200), "short");
+    }
+}
@@ -14,6 +14,7 @@ use crate::coordinator::{
 };
 use crate::error::Result;
 use crate::platform::{AgentStatus, Domain, OrderIntent};
+use crate::supervisor::QuoteThrottle;
 
 /// Context given to each agent when spawned — not Clone (owns command receiver)
 pub struct AgentContext {
@@ -21,6 +22,7 @@ pub struct AgentContext {
     pub domain: Domain,
     handle: CoordinatorHandle,
     commands: mpsc::Receiver<CoordinatorCommand>,
+    quote_throttle: QuoteThrottle,
 }
 
 impl AgentContext {
@@ -30,11 +32,13 @@ impl AgentContext {
         handle: CoordinatorHandle,
         commands: mpsc::Receiver<CoordinatorCommand>,
     ) -> Self {
+        let quote_throttle = handle.quote_throttle().per_agent();
         Self {
             agent_id,
             domain,
             handle,
             commands,
+            quote_throttle,
         }
     }
 
@@ -95,6 +99,12 @@ impl AgentContext {
         self.handle.update_agent_state(snapshot).await
     }
 
+    /// Whether to evaluate entries on the next quote; subsampled under host
+    /// resource pressure. Exit handling should not be gated on this.
+    pub fn admit_quote(&self) -> bool {
+        self.quote_throttle.admit()
+    }
+
     /// Read the current global state (snapshot of all agents + portfolio)
     pub async fn read_global_state(&self) -> GlobalState {
         self.handle.read_state().await
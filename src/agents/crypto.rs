@@ -17,11 +17,15 @@ use tracing::{debug, error, info, warn};
 
 use crate::adapters::{BinanceWebSocket, PolymarketWebSocket, PriceUpdate, QuoteUpdate};
 use crate::agents::{AgentContext, TradingAgent};
+use crate::analysis::{ToxicityLevel, ToxicityMonitor};
 use crate::coordinator::CoordinatorCommand;
 use crate::domain::Side;
 use crate::error::Result;
-use crate::platform::{AgentRiskParams, AgentStatus, Domain, OrderIntent, OrderPriority};
+use crate::platform::{
+    AgentRiskParams, AgentStatus, Domain, OrderIntent, OrderPriority, Timeframe,
+};
 use crate::strategy::momentum::{EventInfo, EventMatcher};
+use crate::supervisor::{EventCalendar, VenueHealth};
 
 const TRADED_EVENT_RETENTION_HOURS: i64 = 24;
 const STRATEGY_ID: &str = "crypto_momentum";
@@ -54,7 +58,7 @@ fn default_exit_edge_floor() -> Decimal {
 /// - flat   (|move| < 0.05%): 77.8% win rate → conservative 2% edge
 /// - mild   (0.05% - 0.15%): 46.2% win rate → aggressive 6% edge filter
 /// - strong (> 0.15%):        63.4% avg      → standard 3% edge
-fn dynamic_min_edge(window_move_abs: Decimal, base_min_edge: Decimal) -> Decimal {
+pub(crate) fn dynamic_min_edge(window_move_abs: Decimal, base_min_edge: Decimal) -> Decimal {
     if window_move_abs < dec!(0.0005) {
         // Flat: highest win rate, use base edge
         base_min_edge
@@ -257,7 +261,10 @@ const GEQ_SETTLEMENT_BIAS: f64 = 0.002;
 /// For UP/DOWN markets:
 /// final_return = (end_price - start_price) / start_price
 /// UP wins when final_return > required_return
-fn required_return_from_threshold(start_price: Decimal, price_to_beat: Decimal) -> Option<Decimal> {
+pub(crate) fn required_return_from_threshold(
+    start_price: Decimal,
+    price_to_beat: Decimal,
+) -> Option<Decimal> {
     if start_price <= Decimal::ZERO || price_to_beat <= Decimal::ZERO {
         return None;
     }
@@ -280,7 +287,7 @@ fn required_return_from_threshold(start_price: Decimal, price_to_beat: Decimal)
 ///   R_rem ~ N(0, sigma_1s^2 * t_rem)
 /// - Current realized return from start is `window_move`
 /// - UP wins when `window_move + R_rem > required_return`
-fn estimate_p_up_window(
+pub(crate) fn estimate_p_up_window(
     window_move: Decimal,
     required_return: Decimal,
     rolling_volatility_opt: Option<Decimal>,
@@ -464,6 +471,9 @@ pub struct CryptoTradingAgent {
     binance_ws: Arc<BinanceWebSocket>,
     pm_ws: Arc<PolymarketWebSocket>,
     event_matcher: Arc<EventMatcher>,
+    toxicity: Option<Arc<ToxicityMonitor>>,
+    venue_health: Option<VenueHealth>,
+    event_calendar: Option<EventCalendar>,
 }
 
 fn should_skip_entry(
@@ -500,23 +510,19 @@ fn normalize_component(value: &str) -> String {
 
 fn normalize_timeframe(horizon: &str) -> String {
     let raw = horizon.trim().to_ascii_lowercase();
-    if raw.contains("15m") || raw == "15" {
-        "15m".to_string()
-    } else if raw.contains("5m") || raw == "5" {
-        "5m".to_string()
-    } else if raw.is_empty() {
-        "5m".to_string()
-    } else {
-        raw
+    if raw.is_empty() {
+        return "5m".to_string();
+    }
+    match Timeframe::from_hint(&raw) {
+        Some(tf) => tf.as_str().to_string(),
+        None => raw,
     }
 }
 
 fn event_window_secs_for_horizon(horizon: &str) -> u64 {
-    match normalize_timeframe(horizon).as_str() {
-        "15m" => 15 * 60,
-        "5m" => 5 * 60,
-        _ => 5 * 60,
-    }
+    Timeframe::from_hint(&normalize_timeframe(horizon))
+        .and_then(|tf| tf.duration_secs())
+        .unwrap_or(5 * 60) as u64
 }
 
 fn deployment_id_for(strategy: &str, coin: &str, horizon: &str) -> String {
@@ -612,9 +618,30 @@ impl CryptoTradingAgent {
             binance_ws,
             pm_ws,
             event_matcher,
+            toxicity: None,
+            venue_health: None,
+            event_calendar: None,
         }
     }
 
+    /// Widen the entry edge requirement when Binance order flow is toxic.
+    pub fn with_toxicity_monitor(mut self, toxicity: Option<Arc<ToxicityMonitor>>) -> Self {
+        self.toxicity = toxicity;
+        self
+    }
+
+    /// Widen the entry edge requirement while exchange / chain health is degraded.
+    pub fn with_venue_health(mut self, venue_health: Option<VenueHealth>) -> Self {
+        self.venue_health = venue_health;
+        self
+    }
+
+    /// Shrink entries and widen the edge requirement around high-impact events.
+    pub fn with_event_calendar(mut self, event_calendar: Option<EventCalendar>) -> Self {
+        self.event_calendar = event_calendar;
+        self
+    }
+
     fn config_hash(&self) -> String {
         let payload = serde_json::to_vec(&self.config).unwrap_or_default();
         let mut hasher = Sha256::new();
@@ -626,6 +653,12 @@ impl CryptoTradingAgent {
         self.config.entry_cooldown_secs
     }
 
+    /// Minimum hold before early exits, stretched for 1h / 1d rounds.
+    fn min_hold_secs_for(&self, horizon: &str) -> i64 {
+        let window_secs = event_window_secs_for_horizon(horizon) as i64;
+        (self.config.min_hold_secs as f64 * Timeframe::window_scale(window_secs)) as i64
+    }
+
     fn signal_confidence(
         sum_of_asks: Decimal,
         sum_threshold: Decimal,
@@ -782,6 +815,10 @@ impl TradingAgent for CryptoTradingAgent {
                         continue;
                     }
 
+                    if !ctx.admit_quote() {
+                        continue;
+                    }
+
                     // Check if this coin is in our watchlist
                     let coin = update.symbol.replace("USDT", "");
                     if !self.config.coins.iter().any(|c| c == &coin) {
@@ -876,7 +913,7 @@ impl TradingAgent for CryptoTradingAgent {
                         let effective_min_hold = if normalize_timeframe(&pos.horizon) == "5m" {
                             event_window_secs_for_horizon("5m") as i64
                         } else {
-                            self.config.min_hold_secs as i64
+                            self.min_hold_secs_for(&pos.horizon)
                         };
                         if held_secs < effective_min_hold {
                             continue;
@@ -1027,7 +1064,7 @@ impl TradingAgent for CryptoTradingAgent {
                             if mom_sign != 0 && mom_sign != dir_sign {
                                 continue;
                             }
-                            if timeframe == "15m" {
+                            if matches!(timeframe.as_str(), "15m" | "1h" | "1d") {
                                 if (short_sign != 0 && short_sign != dir_sign)
                                     || (long_sign != 0 && long_sign != dir_sign)
                                 {
@@ -1074,6 +1111,11 @@ impl TradingAgent for CryptoTradingAgent {
                         }
 
                         let sum_of_asks = up_ask + down_ask;
+                        let calendar = self
+                            .event_calendar
+                            .as_ref()
+                            .map(|c| c.adjustment(Domain::Crypto, Some(&coin), now))
+                            .unwrap_or_default();
 
                         // Entry mode gate + straddle path
                         match self.config.entry_mode {
@@ -1093,7 +1135,11 @@ impl TradingAgent for CryptoTradingAgent {
                                 if rolling_volatility < self.config.straddle_min_vol {
                                     continue;
                                 }
-                                let straddle_shares = self.config.default_shares;
+                                let straddle_shares =
+                                    calendar.scale_shares(self.config.default_shares);
+                                if straddle_shares == 0 {
+                                    continue;
+                                }
                                 let straddle_profit_pct = Decimal::ONE - sum_of_asks;
                                 let deployment_id =
                                     deployment_id_for(STRATEGY_ID, &coin, &event.horizon);
@@ -1182,10 +1228,29 @@ impl TradingAgent for CryptoTradingAgent {
                             Side::Down => Decimal::ONE - p_up,
                         };
                         let signal_edge = fair_value - limit_price;
-                        let effective_min_edge = dynamic_min_edge(window_move.abs(), self.config.min_edge);
+                        let (toxicity_level, toxicity_multiplier) = match self.toxicity.as_ref() {
+                            Some(monitor) => (
+                                monitor.level(&update.symbol, Utc::now()),
+                                monitor.entry_edge_multiplier(&update.symbol, Utc::now()),
+                            ),
+                            None => (ToxicityLevel::Normal, Decimal::ONE),
+                        };
+                        let venue_multiplier = self
+                            .venue_health
+                            .as_ref()
+                            .map_or(Decimal::ONE, VenueHealth::entry_edge_multiplier);
+                        let effective_min_edge =
+                            dynamic_min_edge(window_move.abs(), self.config.min_edge)
+                                * toxicity_multiplier
+                                * venue_multiplier
+                                * calendar.edge_multiplier;
                         if signal_edge < effective_min_edge {
                             continue;
                         }
+                        let entry_shares = calendar.scale_shares(self.config.default_shares);
+                        if entry_shares == 0 {
+                            continue;
+                        }
                         let move_category = classify_window_move(window_move);
 
                         // Cross-asset direction confirmation
@@ -1248,7 +1313,7 @@ impl TradingAgent for CryptoTradingAgent {
                             &token_id,
                             side,
                             true,
-                            self.config.default_shares,
+                            entry_shares,
                             limit_price,
                         );
                         let deployment_id = deployment_id_for(STRATEGY_ID, &coin, &event.horizon);
@@ -1295,6 +1360,11 @@ impl TradingAgent for CryptoTradingAgent {
                         .with_metadata("signal_edge", &signal_edge.to_string())
                         .with_metadata("signal_min_edge", &effective_min_edge.to_string())
                         .with_metadata("move_category", move_category)
+                        .with_metadata("flow_toxicity", toxicity_level.as_str())
+                        .with_metadata(
+                            "calendar_event",
+                            calendar.event.as_ref().map_or("", |e| e.title.as_str()),
+                        )
                         .with_metadata("cross_asset_score", &xa_score.to_string())
                         .with_metadata("cross_asset_agree", &xa_agree.to_string())
                         .with_metadata("cross_asset_disagree", &xa_disagree.to_string())
@@ -1373,7 +1443,7 @@ impl TradingAgent for CryptoTradingAgent {
                     }
 
                     let held_secs = Utc::now().signed_duration_since(pos.entry_time).num_seconds();
-                    if held_secs < self.config.min_hold_secs as i64 {
+                    if held_secs < self.min_hold_secs_for(&pos.horizon) {
                         continue;
                     }
 
@@ -1658,6 +1728,8 @@ mod tests {
         assert_eq!(normalize_timeframe("btc-5m"), "5m");
         assert_eq!(event_window_secs_for_horizon("15m"), 900);
         assert_eq!(event_window_secs_for_horizon("5m"), 300);
+        assert_eq!(normalize_timeframe("eth-updown-hourly"), "1h");
+        assert_eq!(event_window_secs_for_horizon("1d"), 86_400);
         assert_eq!(
             deployment_id_for("momentum", "ETH", "5m"),
             "crypto-momentum-5m"
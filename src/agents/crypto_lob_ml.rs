@@ -19,15 +19,23 @@ use std::sync::Arc;
 use tokio::sync::broadcast;
 use tracing::{debug, error, info, warn};
 
-use crate::adapters::{BinanceWebSocket, PolymarketWebSocket, PriceUpdate, QuoteUpdate, SpotPrice};
+use crate::adapters::{
+    BinanceWebSocket, FeishuNotifier, PolymarketWebSocket, PriceUpdate, QuoteUpdate, SpotPrice,
+};
 use crate::agents::{AgentContext, TradingAgent};
 use crate::collector::LobCache;
 use crate::coordinator::CoordinatorCommand;
 use crate::domain::Side;
 use crate::error::{PloyError, Result};
 #[cfg(feature = "onnx")]
-use crate::ml::OnnxModel;
-use crate::platform::{AgentRiskParams, AgentStatus, Domain, OrderIntent, OrderPriority};
+use crate::ml::{resolve_model_path, OnnxModel};
+use crate::ml::{
+    CryptoTick, DriftConfig, DriftMonitor, DriftReport, DriftStatus, FeatureSetSpec,
+    SharedFeatureStore,
+};
+use crate::platform::{
+    AgentRiskParams, AgentStatus, Domain, OrderIntent, OrderPriority, Timeframe,
+};
 use crate::strategy::momentum::{EventInfo, EventMatcher};
 
 const TRADED_EVENT_RETENTION_HOURS: i64 = 24;
@@ -290,6 +298,10 @@ pub struct CryptoLobMlConfig {
     /// When true, force SettleOnly exit mode for 5m events regardless of configured exit_mode.
     #[serde(default = "default_force_settle_only_5m")]
     pub force_settle_only_5m: bool,
+
+    /// Live feature / calibration drift monitoring against the training baseline.
+    #[serde(default)]
+    pub drift: DriftConfig,
 }
 
 fn default_lob_ml_model_type() -> String {
@@ -384,6 +396,7 @@ impl Default for CryptoLobMlConfig {
             oracle_lag_buffer_secs: default_oracle_lag_buffer_secs(),
             max_spread_pct: default_max_spread_pct(),
             force_settle_only_5m: default_force_settle_only_5m(),
+            drift: DriftConfig::default(),
         }
     }
 }
@@ -455,6 +468,35 @@ struct SequenceSnapshot {
     distance_to_beat: Decimal,
 }
 
+impl SequenceSnapshot {
+    /// Raw (un-normalized) model features, in model input order.
+    fn raw_features(&self) -> [f64; SEQ_FEATURE_DIM] {
+        [
+            self.obi_5.to_f64().unwrap_or(0.0),
+            self.obi_10.to_f64().unwrap_or(0.0),
+            self.spread_bps.to_f64().unwrap_or(0.0),
+            self.bid_volume_5.to_f64().unwrap_or(0.0),
+            self.ask_volume_5.to_f64().unwrap_or(0.0),
+            self.momentum_1s.to_f64().unwrap_or(0.0),
+            self.momentum_5s.to_f64().unwrap_or(0.0),
+            self.spot_price.to_f64().unwrap_or(0.0),
+            self.remaining_secs.to_f64().unwrap_or(0.0),
+            self.price_to_beat.to_f64().unwrap_or(0.0),
+            self.distance_to_beat.to_f64().unwrap_or(0.0),
+        ]
+    }
+}
+
+/// Latest model prediction for an event, resolved against spot at settlement
+/// to track live calibration.
+#[derive(Debug, Clone)]
+struct PendingOutcome {
+    symbol: String,
+    end_time: DateTime<Utc>,
+    price_to_beat: Decimal,
+    p_up: f64,
+}
+
 #[derive(Debug, Clone, Copy, PartialEq, Eq)]
 enum SequenceAlignMode {
     Exact,
@@ -464,7 +506,7 @@ enum SequenceAlignMode {
 
 fn sequence_len_for_horizon(horizon: &str) -> usize {
     match normalize_timeframe(horizon).as_str() {
-        "15m" => SEQ_LEN_15M,
+        "15m" | "1h" | "1d" => SEQ_LEN_15M,
         _ => SEQ_LEN_5M,
     }
 }
@@ -475,6 +517,7 @@ pub struct CryptoLobMlAgent {
     pm_ws: Arc<PolymarketWebSocket>,
     event_matcher: Arc<EventMatcher>,
     lob_cache: LobCache,
+    feature_store: Option<SharedFeatureStore>,
     #[cfg(feature = "onnx")]
     onnx_model: Option<OnnxModel>,
 }
@@ -531,23 +574,19 @@ fn normalize_component(value: &str) -> String {
 
 fn normalize_timeframe(horizon: &str) -> String {
     let raw = horizon.trim().to_ascii_lowercase();
-    if raw.contains("15m") || raw == "15" {
-        "15m".to_string()
-    } else if raw.contains("5m") || raw == "5" {
-        "5m".to_string()
-    } else if raw.is_empty() {
-        "5m".to_string()
-    } else {
-        raw
+    if raw.is_empty() {
+        return "5m".to_string();
+    }
+    match Timeframe::from_hint(&raw) {
+        Some(tf) => tf.as_str().to_string(),
+        None => raw,
     }
 }
 
 fn event_window_secs_for_horizon(horizon: &str) -> u64 {
-    match normalize_timeframe(horizon).as_str() {
-        "15m" => 15 * 60,
-        "5m" => 5 * 60,
-        _ => 5 * 60,
-    }
+    Timeframe::from_hint(&normalize_timeframe(horizon))
+        .and_then(|tf| tf.duration_secs())
+        .unwrap_or(5 * 60) as u64
 }
 
 fn deployment_id_for(strategy: &str, coin: &str, horizon: &str) -> String {
@@ -658,6 +697,7 @@ impl CryptoLobMlAgent {
 
         #[cfg(feature = "onnx")]
         {
+            let mut config = config;
             let model_path = config
                 .model_path
                 .as_deref()
@@ -669,6 +709,21 @@ impl CryptoLobMlAgent {
                     )
                 })?;
 
+            // `registry:<name>@<alias>` pins the version label and expected hash
+            let resolved = resolve_model_path(model_path)?;
+            if let Some(record) = resolved.record {
+                info!(
+                    agent = config.agent_id,
+                    model = %record.label(),
+                    stage = %record.stage,
+                    "resolved lob-ml model from registry"
+                );
+                config.model_version.get_or_insert_with(|| record.label());
+                config.model_sha256.get_or_insert(record.sha256);
+            }
+            let model_path = resolved.path.display().to_string();
+            let model_path = model_path.as_str();
+
             let configured_input_dim = std::env::var("PLOY_CRYPTO_LOB_ML__MODEL_INPUT_DIM")
                 .ok()
                 .and_then(|v| v.parse::<usize>().ok())
@@ -713,6 +768,7 @@ impl CryptoLobMlAgent {
                 pm_ws,
                 event_matcher,
                 lob_cache,
+                feature_store: None,
                 onnx_model: Some(m),
             });
         }
@@ -726,6 +782,13 @@ impl CryptoLobMlAgent {
         }
     }
 
+    /// Record per-symbol signals into `store` and stamp entries with the
+    /// resulting feature vector
+    pub fn with_feature_store(mut self, store: SharedFeatureStore) -> Self {
+        self.feature_store = Some(store);
+        self
+    }
+
     fn config_hash(&self) -> String {
         let payload = serde_json::to_vec(&self.config).unwrap_or_default();
         let mut hasher = Sha256::new();
@@ -772,19 +835,7 @@ impl CryptoLobMlAgent {
         let mut flat: Vec<f32> = Vec::with_capacity(seq_len * SEQ_FEATURE_DIM);
         let start_idx = window.len().saturating_sub(seq_len);
         for snap in window.iter().skip(start_idx) {
-            let raw = [
-                snap.obi_5.to_f64().unwrap_or(0.0) as f32,
-                snap.obi_10.to_f64().unwrap_or(0.0) as f32,
-                snap.spread_bps.to_f64().unwrap_or(0.0) as f32,
-                snap.bid_volume_5.to_f64().unwrap_or(0.0) as f32,
-                snap.ask_volume_5.to_f64().unwrap_or(0.0) as f32,
-                snap.momentum_1s.to_f64().unwrap_or(0.0) as f32,
-                snap.momentum_5s.to_f64().unwrap_or(0.0) as f32,
-                snap.spot_price.to_f64().unwrap_or(0.0) as f32,
-                snap.remaining_secs.to_f64().unwrap_or(0.0) as f32,
-                snap.price_to_beat.to_f64().unwrap_or(0.0) as f32,
-                snap.distance_to_beat.to_f64().unwrap_or(0.0) as f32,
-            ];
+            let raw = snap.raw_features().map(|v| v as f32);
             if normalize {
                 for (i, v) in raw.iter().enumerate() {
                     flat.push((v - feature_offsets[i]) * feature_scales[i]);
@@ -978,6 +1029,12 @@ impl CryptoLobMlAgent {
     fn entry_late_window_secs(&self, horizon: &str) -> u64 {
         match normalize_timeframe(horizon).as_str() {
             "15m" => self.config.entry_late_window_secs_15m,
+            // Longer rounds stretch the 15m setting with the round length
+            tf @ ("1h" | "1d") => {
+                let round_secs = event_window_secs_for_horizon(tf) as i64;
+                (self.config.entry_late_window_secs_15m as f64
+                    * Timeframe::window_scale(round_secs)) as u64
+            }
             _ => self.config.entry_late_window_secs_5m,
         }
     }
@@ -1556,6 +1613,7 @@ impl TradingAgent for CryptoLobMlAgent {
     async fn run(self, mut ctx: AgentContext) -> Result<()> {
         info!(agent = self.config.agent_id, "crypto lob-ml agent starting");
         let config_hash = self.config_hash();
+        let feature_spec = FeatureSetSpec::crypto_v1();
 
         let mut status = AgentStatus::Running;
         let mut positions: HashMap<String, TrackedPosition> = HashMap::new(); // slug -> pos
@@ -1565,6 +1623,22 @@ impl TradingAgent for CryptoLobMlAgent {
         let mut traded_events: HashMap<String, DateTime<Utc>> = HashMap::new();
         let mut sequence_cache: HashMap<String, VecDeque<SequenceSnapshot>> = HashMap::new();
 
+        let mut drift_monitor = match DriftMonitor::from_config(&self.config.drift) {
+            Ok(m) => m,
+            Err(e) => {
+                warn!(agent = self.config.agent_id, error = %e, "drift monitor disabled");
+                None
+            }
+        };
+        let mut pending_outcomes: HashMap<String, PendingOutcome> = HashMap::new(); // slug -> latest prediction
+        let mut drift_report: Option<DriftReport> = None;
+        let mut drift_paused = false;
+        let drift_alerts = FeishuNotifier::from_env();
+        let drift_dur =
+            tokio::time::Duration::from_secs(self.config.drift.check_interval_secs.max(1));
+        let mut drift_tick = tokio::time::interval(drift_dur);
+        drift_tick.set_missed_tick_behavior(tokio::time::MissedTickBehavior::Delay);
+
         let daily_pnl = Decimal::ZERO;
         sync_positions_from_global(&ctx, &self.config.agent_id, &mut positions).await;
 
@@ -1661,6 +1735,10 @@ impl TradingAgent for CryptoLobMlAgent {
                         continue;
                     }
 
+                    if !ctx.admit_quote() {
+                        continue;
+                    }
+
                     let coin = update.symbol.replace("USDT", "");
                     if !self.config.coins.iter().any(|c| c == &coin) {
                         continue;
@@ -1684,6 +1762,10 @@ impl TradingAgent for CryptoLobMlAgent {
                     let momentum_5s = spot.momentum(5).unwrap_or(Decimal::ZERO);
                     let rolling_volatility_opt = spot.volatility(60);
 
+                    // Pause on books that are resyncing or failed validation.
+                    if !self.lob_cache.trust(&update.symbol).await.is_trusted() {
+                        continue;
+                    }
                     let Some(lob) = self.lob_cache.get_snapshot(&update.symbol).await else {
                         continue;
                     };
@@ -1692,26 +1774,35 @@ impl TradingAgent for CryptoLobMlAgent {
                         continue;
                     }
 
+                    let features = self
+                        .lob_cache
+                        .get_features(&update.symbol)
+                        .await
+                        .unwrap_or_default();
                     let (obi_1, obi_2, obi_3, obi_20) = (
-                        self.lob_cache
-                            .get_obi(&update.symbol, 1)
-                            .await
-                            .unwrap_or(Decimal::ZERO),
-                        self.lob_cache
-                            .get_obi(&update.symbol, 2)
-                            .await
-                            .unwrap_or(Decimal::ZERO),
-                        self.lob_cache
-                            .get_obi(&update.symbol, 3)
-                            .await
-                            .unwrap_or(Decimal::ZERO),
-                        self.lob_cache
-                            .get_obi(&update.symbol, 20)
-                            .await
-                            .unwrap_or(Decimal::ZERO),
+                        features.obi_1,
+                        features.obi_2,
+                        features.obi_3,
+                        features.obi_20,
                     );
-                    let obi_micro = obi_1 - lob.obi_5;
-                    let obi_slope = lob.obi_5 - obi_20;
+                    let obi_micro = features.obi_micro;
+                    let obi_slope = features.obi_slope;
+
+                    // Record this tick's raw signals and read the versioned vector back.
+                    let feature_row = self.feature_store.as_ref().map(|store| {
+                        let at = Utc::now();
+                        store.record_crypto_tick(
+                            &update.symbol,
+                            at,
+                            &CryptoTick {
+                                spot: spot.price.to_f64(),
+                                obi_5: lob.obi_5.to_f64(),
+                                obi_10: lob.obi_10.to_f64(),
+                                ..CryptoTick::default()
+                            },
+                        );
+                        store.vector(&feature_spec, &update.symbol, at)
+                    });
 
                     let quote_cache = self.pm_ws.quote_cache();
                     for event in events {
@@ -1794,6 +1885,25 @@ impl TradingAgent for CryptoLobMlAgent {
                         let p_up_model_dec =
                             Decimal::from_f64_retain(p_up_model).unwrap_or(dec!(0.5));
 
+                        if let Some(monitor) = drift_monitor.as_mut() {
+                            if let Some(snap) =
+                                sequence_cache.get(&entry_key).and_then(|w| w.back())
+                            {
+                                monitor.observe(&snap.raw_features());
+                            }
+                            if let Some(price_to_beat) = event.price_to_beat {
+                                pending_outcomes.insert(
+                                    event.slug.clone(),
+                                    PendingOutcome {
+                                        symbol: update.symbol.clone(),
+                                        end_time: event.end_time,
+                                        price_to_beat,
+                                        p_up: p_up_model,
+                                    },
+                                );
+                            }
+                        }
+
                         let up = quote_cache.get(&event.up_token_id);
                         let down = quote_cache.get(&event.down_token_id);
                         let (up_bid, up_ask, down_bid, down_ask) = match (up, down) {
@@ -2158,7 +2268,7 @@ impl TradingAgent for CryptoLobMlAgent {
                         let deployment_id = deployment_id_for(STRATEGY_ID, &coin, &event.horizon);
                         let event_window_secs =
                             event_window_secs_for_horizon(&timeframe).to_string();
-                        let intent = intent
+                        let mut intent = intent
                         .with_priority(OrderPriority::Normal)
                         .with_metadata("strategy", STRATEGY_ID)
                         .with_deployment_id(&deployment_id)
@@ -2233,6 +2343,9 @@ impl TradingAgent for CryptoLobMlAgent {
                         .with_metadata("window_elapsed_secs", &window_ctx.elapsed_secs.to_string())
                         .with_metadata("window_remaining_secs", &window_ctx.remaining_secs.to_string())
                         .with_metadata("config_hash", &config_hash);
+                        if let Some(row) = &feature_row {
+                            intent.metadata.extend(row.metadata(&feature_spec));
+                        }
 
                         info!(
                             agent = self.config.agent_id,
@@ -2408,16 +2521,88 @@ impl TradingAgent for CryptoLobMlAgent {
                     }
                 }
 
+                // --- Model drift checks ---
+                _ = drift_tick.tick(), if drift_monitor.is_some() => {
+                    let Some(monitor) = drift_monitor.as_mut() else {
+                        continue;
+                    };
+
+                    // Resolve settled predictions against spot at/after end time.
+                    let now = Utc::now();
+                    let spot_cache = self.binance_ws.price_cache();
+                    let settled: Vec<String> = pending_outcomes
+                        .iter()
+                        .filter(|(_, p)| p.end_time <= now)
+                        .map(|(slug, _)| slug.clone())
+                        .collect();
+                    for slug in settled {
+                        let Some(pending) = pending_outcomes.remove(&slug) else {
+                            continue;
+                        };
+                        if let Some(spot) = spot_cache.get(&pending.symbol).await {
+                            monitor.record_outcome(pending.p_up, spot.price >= pending.price_to_beat);
+                        }
+                    }
+
+                    let report = monitor.report();
+                    let previous = drift_report
+                        .as_ref()
+                        .map(|r| r.status)
+                        .unwrap_or(DriftStatus::Insufficient);
+                    if report.status >= DriftStatus::Warning && report.status != previous {
+                        warn!(
+                            agent = self.config.agent_id,
+                            summary = %report.summary(),
+                            "model drift detected"
+                        );
+                        if let Some(notifier) = drift_alerts.as_ref() {
+                            let text = format!(
+                                "⚠️ [{}] model drift {}\n{}",
+                                self.config.agent_id,
+                                report.status.as_str(),
+                                report.summary()
+                            );
+                            if let Err(e) = notifier.send_message(&text).await {
+                                warn!(agent = self.config.agent_id, error = %e, "drift alert failed");
+                            }
+                        }
+                    } else if report.status == DriftStatus::Stable
+                        && previous >= DriftStatus::Warning
+                    {
+                        info!(agent = self.config.agent_id, "model drift cleared");
+                    }
+
+                    let resume = monitor.should_resume(&report);
+                    if monitor.should_pause(&report) && matches!(status, AgentStatus::Running) {
+                        warn!(
+                            agent = self.config.agent_id,
+                            "pausing entries on critical model drift"
+                        );
+                        status = AgentStatus::Paused;
+                        drift_paused = true;
+                    } else if drift_paused && resume {
+                        info!(
+                            agent = self.config.agent_id,
+                            "resuming entries after model drift stabilized"
+                        );
+                        status = AgentStatus::Running;
+                        drift_paused = false;
+                    }
+                    drift_report = Some(report);
+                }
+
                 // --- Coordinator commands ---
                 cmd = ctx.command_rx().recv() => {
                     match cmd {
                         Some(CoordinatorCommand::Pause) => {
                             info!(agent = self.config.agent_id, "pausing");
                             status = AgentStatus::Paused;
+                            drift_paused = false;
                         }
                         Some(CoordinatorCommand::Resume) => {
                             info!(agent = self.config.agent_id, "resuming");
                             status = AgentStatus::Running;
+                            drift_paused = false;
                         }
                         Some(CoordinatorCommand::Shutdown) => {
                             info!(agent = self.config.agent_id, "shutting down");
@@ -2543,6 +2728,13 @@ impl TradingAgent for CryptoLobMlAgent {
                         "exit_mode".to_string(),
                         self.exit_mode_label().to_string(),
                     );
+                    let mut error_message = None;
+                    if let Some(report) = drift_report.as_ref() {
+                        report.insert_metrics(&mut metrics);
+                        if report.status == DriftStatus::Critical {
+                            error_message = Some(report.summary());
+                        }
+                    }
                     let _ = ctx.report_state_with_metrics(
                         &self.config.name,
                         status,
@@ -2551,7 +2743,7 @@ impl TradingAgent for CryptoLobMlAgent {
                         daily_pnl,
                         Decimal::ZERO,
                         metrics,
-                        None,
+                        error_message,
                     ).await;
                 }
             }
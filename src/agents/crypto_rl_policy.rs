@@ -48,9 +48,7 @@
 //!  30 lob_obi_slope (obi_5 - obi_20)
 
 use async_trait::async_trait;
-use chrono::{DateTime, Utc};
-#[cfg(feature = "onnx")]
-use chrono::{Datelike, Timelike};
+use chrono::{DateTime, Datelike, Timelike, Utc};
 use rust_decimal::prelude::ToPrimitive;
 use rust_decimal::Decimal;
 use rust_decimal_macros::dec;
@@ -60,22 +58,22 @@ use std::collections::{HashMap, HashSet};
 use std::sync::Arc;
 use tracing::{error, info, warn};
 
-use crate::adapters::{BinanceWebSocket, PolymarketWebSocket};
+use crate::adapters::{BinanceWebSocket, FeishuNotifier, PolymarketWebSocket};
 use crate::agents::{AgentContext, TradingAgent};
-use crate::collector::LobCache;
-#[cfg(feature = "onnx")]
-use crate::collector::LobSnapshot;
+use crate::collector::{LobCache, LobFeatures, LobSnapshot};
 use crate::coordinator::CoordinatorCommand;
-use crate::domain::Side;
+use crate::domain::{Side, TimeInForce};
 use crate::error::Result;
 #[cfg(feature = "onnx")]
-use crate::ml::OnnxModel;
+use crate::ml::{resolve_model_path, OnnxModel};
+use crate::ml::{
+    CryptoTick, DriftConfig, DriftMonitor, DriftReport, DriftStatus, FeatureSetSpec,
+    SharedFeatureStore,
+};
 use crate::platform::{AgentRiskParams, AgentStatus, Domain, OrderIntent, OrderPriority};
 use crate::strategy::momentum::{EventInfo, EventMatcher};
 
-#[cfg(feature = "onnx")]
 const OBS_DIM_V1: usize = 25;
-#[cfg(feature = "onnx")]
 const OBS_DIM_V2: usize = 31;
 #[cfg(feature = "onnx")]
 const NUM_DISCRETE_ACTIONS: usize = 5;
@@ -151,6 +149,10 @@ pub struct CryptoRlPolicyConfig {
 
     pub risk_params: AgentRiskParams,
     pub heartbeat_interval_secs: u64,
+
+    /// Live observation drift monitoring against the training baseline.
+    #[serde(default)]
+    pub drift: DriftConfig,
 }
 
 impl Default for CryptoRlPolicyConfig {
@@ -176,6 +178,7 @@ impl Default for CryptoRlPolicyConfig {
             exploration_rate: 0.0,
             risk_params: AgentRiskParams::conservative(),
             heartbeat_interval_secs: 5,
+            drift: DriftConfig::default(),
         }
     }
 }
@@ -295,6 +298,7 @@ pub struct CryptoRlPolicyAgent {
     pm_ws: Arc<PolymarketWebSocket>,
     event_matcher: Arc<EventMatcher>,
     lob_cache: LobCache,
+    feature_store: Option<SharedFeatureStore>,
 
     #[cfg(feature = "onnx")]
     policy_model: Option<OnnxModel>,
@@ -323,8 +327,38 @@ impl CryptoRlPolicyAgent {
         config.observation_version = obs_version;
 
         #[cfg(feature = "onnx")]
-        let policy_model: Option<OnnxModel> = match config.policy_model_path.as_deref() {
-            Some(path) if !path.trim().is_empty() => {
+        let resolved_model_path: Option<String> = match config.policy_model_path.as_deref() {
+            Some(path) if !path.trim().is_empty() => match resolve_model_path(path) {
+                Ok(resolved) => {
+                    if let Some(record) = resolved.record {
+                        info!(
+                            agent = config.agent_id,
+                            model = %record.label(),
+                            stage = %record.stage,
+                            "resolved crypto RL policy model from registry"
+                        );
+                        config
+                            .policy_model_version
+                            .get_or_insert_with(|| record.label());
+                    }
+                    Some(resolved.path.display().to_string())
+                }
+                Err(e) => {
+                    warn!(
+                        agent = config.agent_id,
+                        model_path = %path,
+                        error = %e,
+                        "failed to resolve policy model; falling back to rule-based policy"
+                    );
+                    None
+                }
+            },
+            _ => None,
+        };
+
+        #[cfg(feature = "onnx")]
+        let policy_model: Option<OnnxModel> = match resolved_model_path.as_deref() {
+            Some(path) => {
                 let primary_version = obs_version;
                 let primary_dim = if primary_version == 1 {
                     OBS_DIM_V1
@@ -412,11 +446,29 @@ impl CryptoRlPolicyAgent {
             pm_ws,
             event_matcher,
             lob_cache,
+            feature_store: None,
             #[cfg(feature = "onnx")]
             policy_model,
         }
     }
 
+    /// Record decision-tick signals into `store` and stamp entries with the
+    /// resulting feature vector
+    pub fn with_feature_store(mut self, store: SharedFeatureStore) -> Self {
+        self.feature_store = Some(store);
+        self
+    }
+
+    fn load_drift_monitor(&self) -> Option<DriftMonitor> {
+        match DriftMonitor::from_config(&self.config.drift) {
+            Ok(m) => m,
+            Err(e) => {
+                warn!(agent = self.config.agent_id, error = %e, "drift monitor disabled");
+                None
+            }
+        }
+    }
+
     fn config_hash(&self) -> String {
         let payload = serde_json::to_vec(&self.config).unwrap_or_default();
         let mut hasher = Sha256::new();
@@ -581,7 +633,6 @@ impl CryptoRlPolicyAgent {
         ContinuousAction::default()
     }
 
-    #[cfg(feature = "onnx")]
     fn time_features(now: DateTime<Utc>) -> (f32, f32, f32, f32) {
         use std::f32::consts::PI;
         let hour = now.hour() as f32;
@@ -593,7 +644,6 @@ impl CryptoRlPolicyAgent {
         (hour_rad.sin(), hour_rad.cos(), day_rad.sin(), day_rad.cos())
     }
 
-    #[cfg(feature = "onnx")]
     fn build_observation_v1(
         &self,
         now: DateTime<Utc>,
@@ -680,7 +730,6 @@ impl CryptoRlPolicyAgent {
         obs
     }
 
-    #[cfg(feature = "onnx")]
     fn build_observation_v2(
         &self,
         now: DateTime<Utc>,
@@ -694,10 +743,7 @@ impl CryptoRlPolicyAgent {
         down_ask: Decimal,
         position: Option<&TrackedPosition>,
         time_remaining_secs: i64,
-        obi_1: Decimal,
-        obi_2: Decimal,
-        obi_3: Decimal,
-        obi_20: Decimal,
+        features: &LobFeatures,
     ) -> Vec<f32> {
         let mut obs = self.build_observation_v1(
             now,
@@ -713,15 +759,12 @@ impl CryptoRlPolicyAgent {
             time_remaining_secs,
         );
 
-        let obi_micro = obi_1 - lob.obi_5;
-        let obi_slope = lob.obi_5 - obi_20;
-
-        obs.push(obi_1.to_f32().unwrap_or(0.0));
-        obs.push(obi_2.to_f32().unwrap_or(0.0));
-        obs.push(obi_3.to_f32().unwrap_or(0.0));
-        obs.push(obi_20.to_f32().unwrap_or(0.0));
-        obs.push(obi_micro.to_f32().unwrap_or(0.0));
-        obs.push(obi_slope.to_f32().unwrap_or(0.0));
+        obs.push(features.obi_1.to_f32().unwrap_or(0.0));
+        obs.push(features.obi_2.to_f32().unwrap_or(0.0));
+        obs.push(features.obi_3.to_f32().unwrap_or(0.0));
+        obs.push(features.obi_20.to_f32().unwrap_or(0.0));
+        obs.push(features.obi_micro.to_f32().unwrap_or(0.0));
+        obs.push(features.obi_slope.to_f32().unwrap_or(0.0));
 
         debug_assert_eq!(obs.len(), OBS_DIM_V2);
         obs
@@ -758,6 +801,7 @@ impl TradingAgent for CryptoRlPolicyAgent {
             "crypto RL policy agent starting"
         );
         let config_hash = self.config_hash();
+        let feature_spec = FeatureSetSpec::crypto_v1();
 
         let mut status = AgentStatus::Running;
         let mut positions: HashMap<String, TrackedPosition> = HashMap::new(); // slug -> pos
@@ -765,6 +809,16 @@ impl TradingAgent for CryptoRlPolicyAgent {
         let mut subscribed_tokens: HashSet<String> = HashSet::new();
         let mut last_action_by_symbol: HashMap<String, DateTime<Utc>> = HashMap::new();
 
+        // Observations only exist when an ONNX policy is compiled in.
+        let mut drift_monitor = self.load_drift_monitor();
+        let mut drift_report: Option<DriftReport> = None;
+        let mut drift_paused = false;
+        let drift_alerts = FeishuNotifier::from_env();
+        let drift_dur =
+            tokio::time::Duration::from_secs(self.config.drift.check_interval_secs.max(1));
+        let mut drift_tick = tokio::time::interval(drift_dur);
+        drift_tick.set_missed_tick_behavior(tokio::time::MissedTickBehavior::Delay);
+
         let daily_pnl = Decimal::ZERO;
         let mut total_exposure = Decimal::ZERO;
 
@@ -877,7 +931,10 @@ impl TradingAgent for CryptoRlPolicyAgent {
                             continue;
                         }
 
-                        // LOB snapshot.
+                        // LOB snapshot (skipped while the book is suspect).
+                        if !self.lob_cache.trust(&symbol).await.is_trusted() {
+                            continue;
+                        }
                         let lob = match self.lob_cache.get_snapshot(&symbol).await {
                             Some(s) => s,
                             None => continue,
@@ -887,6 +944,22 @@ impl TradingAgent for CryptoRlPolicyAgent {
                             continue;
                         }
 
+                        // Record this tick's raw signals and read the versioned vector back.
+                        let feature_row = self.feature_store.as_ref().map(|store| {
+                            store.record_crypto_tick(
+                                &symbol,
+                                now,
+                                &CryptoTick {
+                                    spot: spot.price.to_f64(),
+                                    obi_5: lob.obi_5.to_f64(),
+                                    obi_10: lob.obi_10.to_f64(),
+                                    pm_spread: (up_ask - up_bid).to_f64(),
+                                    ..CryptoTick::default()
+                                },
+                            );
+                            store.vector(&feature_spec, &symbol, now)
+                        });
+
                         let pos = positions.get(&event.slug);
                         let has_pos = pos.is_some();
                         let time_remaining_secs = event.end_time.signed_duration_since(now).num_seconds();
@@ -899,28 +972,20 @@ impl TradingAgent for CryptoRlPolicyAgent {
                         .clamp(0.0, 1.0);
 
                         let obs_version = self.config.observation_version;
-                        let (obi_1, obi_2, obi_3, obi_20) = if obs_version == 2 {
-                            (
-                                self.lob_cache
-                                    .get_obi(&symbol, 1)
-                                    .await
-                                    .unwrap_or(Decimal::ZERO),
-                                self.lob_cache
-                                    .get_obi(&symbol, 2)
-                                    .await
-                                    .unwrap_or(Decimal::ZERO),
-                                self.lob_cache
-                                    .get_obi(&symbol, 3)
-                                    .await
-                                    .unwrap_or(Decimal::ZERO),
-                                self.lob_cache
-                                    .get_obi(&symbol, 20)
-                                    .await
-                                    .unwrap_or(Decimal::ZERO),
-                            )
+                        let lob_features = if obs_version == 2 {
+                            self.lob_cache
+                                .get_features(&symbol)
+                                .await
+                                .unwrap_or_default()
                         } else {
-                            (Decimal::ZERO, Decimal::ZERO, Decimal::ZERO, Decimal::ZERO)
+                            LobFeatures::default()
                         };
+                        let (obi_1, obi_2, obi_3, obi_20) = (
+                            lob_features.obi_1,
+                            lob_features.obi_2,
+                            lob_features.obi_3,
+                            lob_features.obi_20,
+                        );
 
                         // --- Policy inference ---
                         #[cfg(feature = "onnx")]
@@ -931,7 +996,6 @@ impl TradingAgent for CryptoRlPolicyAgent {
                         #[cfg(feature = "onnx")]
                         let mut raw_output: Option<Vec<f32>> = None;
 
-                        #[cfg(feature = "onnx")]
                         let obs = if obs_version == 2 {
                             self.build_observation_v2(
                                 now,
@@ -945,10 +1009,7 @@ impl TradingAgent for CryptoRlPolicyAgent {
                                 down_ask,
                                 pos,
                                 time_remaining_secs,
-                                obi_1,
-                                obi_2,
-                                obi_3,
-                                obi_20,
+                                &lob_features,
                             )
                         } else {
                             self.build_observation_v1(
@@ -966,6 +1027,12 @@ impl TradingAgent for CryptoRlPolicyAgent {
                             )
                         };
 
+                        // Drift is watched on the observation itself, with or without a model
+                        if let Some(monitor) = drift_monitor.as_mut() {
+                            let features: Vec<f64> = obs.iter().map(|v| *v as f64).collect();
+                            monitor.observe(&features);
+                        }
+
                         #[cfg(feature = "onnx")]
                         if let Some(model) = &self.policy_model {
                             let out = model.predict(&obs).ok();
@@ -1055,13 +1122,17 @@ impl TradingAgent for CryptoRlPolicyAgent {
                                             .with_metadata("signal_momentum_5s", momentum_5s.to_string())
                                             .with_metadata("config_hash", config_hash.clone());
 
+                                            if let Some(row) = &feature_row {
+                                                intent.metadata.extend(row.metadata(&feature_spec));
+                                            }
+
                                             if obs_version == 2 {
                                                 intent.metadata.insert("lob_obi_1".into(), obi_1.to_string());
                                                 intent.metadata.insert("lob_obi_2".into(), obi_2.to_string());
                                                 intent.metadata.insert("lob_obi_3".into(), obi_3.to_string());
                                                 intent.metadata.insert("lob_obi_20".into(), obi_20.to_string());
-                                                intent.metadata.insert("lob_obi_micro".into(), (obi_1 - lob.obi_5).to_string());
-                                                intent.metadata.insert("lob_obi_slope".into(), (lob.obi_5 - obi_20).to_string());
+                                                intent.metadata.insert("lob_obi_micro".into(), lob_features.obi_micro.to_string());
+                                                intent.metadata.insert("lob_obi_slope".into(), lob_features.obi_slope.to_string());
                                             }
 
                                             if let Some(ref o) = raw_output {
@@ -1148,13 +1219,17 @@ impl TradingAgent for CryptoRlPolicyAgent {
                                             .with_metadata("signal_momentum_5s", momentum_5s.to_string())
                                             .with_metadata("config_hash", config_hash.clone());
 
+                                            if let Some(row) = &feature_row {
+                                                intent.metadata.extend(row.metadata(&feature_spec));
+                                            }
+
                                             if obs_version == 2 {
                                                 intent.metadata.insert("lob_obi_1".into(), obi_1.to_string());
                                                 intent.metadata.insert("lob_obi_2".into(), obi_2.to_string());
                                                 intent.metadata.insert("lob_obi_3".into(), obi_3.to_string());
                                                 intent.metadata.insert("lob_obi_20".into(), obi_20.to_string());
-                                                intent.metadata.insert("lob_obi_micro".into(), (obi_1 - lob.obi_5).to_string());
-                                                intent.metadata.insert("lob_obi_slope".into(), (lob.obi_5 - obi_20).to_string());
+                                                intent.metadata.insert("lob_obi_micro".into(), lob_features.obi_micro.to_string());
+                                                intent.metadata.insert("lob_obi_slope".into(), lob_features.obi_slope.to_string());
                                             }
 
                                             if let Some(ref o) = raw_output {
@@ -1282,6 +1357,8 @@ impl TradingAgent for CryptoRlPolicyAgent {
                                                     other_ask,
                                                 )
                                                 .with_priority(OrderPriority::High)
+                                                // Leg 2 completes the pair now or not at all
+                                                .with_time_in_force(TimeInForce::FOK)
                                                 .with_metadata("strategy", "crypto_rl_policy")
                                                 .with_deployment_id(deployment_id.as_str())
                                                 .with_metadata("signal_type", "crypto_rl_policy")
@@ -1519,11 +1596,11 @@ impl TradingAgent for CryptoRlPolicyAgent {
                                     intent.metadata.insert("lob_obi_20".into(), obi_20.to_string());
                                     intent.metadata.insert(
                                         "lob_obi_micro".into(),
-                                        (obi_1 - lob.obi_5).to_string(),
+                                        lob_features.obi_micro.to_string(),
                                     );
                                     intent.metadata.insert(
                                         "lob_obi_slope".into(),
-                                        (lob.obi_5 - obi_20).to_string(),
+                                        lob_features.obi_slope.to_string(),
                                     );
                                 }
                                 if let Err(e) = ctx.submit_order(intent).await {
@@ -1599,11 +1676,11 @@ impl TradingAgent for CryptoRlPolicyAgent {
                                     intent.metadata.insert("lob_obi_20".into(), obi_20.to_string());
                                     intent.metadata.insert(
                                         "lob_obi_micro".into(),
-                                        (obi_1 - lob.obi_5).to_string(),
+                                        lob_features.obi_micro.to_string(),
                                     );
                                     intent.metadata.insert(
                                         "lob_obi_slope".into(),
-                                        (lob.obi_5 - obi_20).to_string(),
+                                        lob_features.obi_slope.to_string(),
                                     );
                                 }
                                 if let Err(e) = ctx.submit_order(intent).await {
@@ -1677,16 +1754,77 @@ impl TradingAgent for CryptoRlPolicyAgent {
                     }
                 }
 
+                // --- Model drift checks ---
+                _ = drift_tick.tick(), if drift_monitor.is_some() => {
+                    let Some(monitor) = drift_monitor.as_mut() else {
+                        continue;
+                    };
+                    let report = monitor.report();
+                    let previous = drift_report
+                        .as_ref()
+                        .map(|r| r.status)
+                        .unwrap_or(DriftStatus::Insufficient);
+                    if report.status >= DriftStatus::Warning && report.status != previous {
+                        warn!(
+                            agent = self.config.agent_id,
+                            summary = %report.summary(),
+                            "policy observation drift detected"
+                        );
+                        if let Some(notifier) = drift_alerts.as_ref() {
+                            let text = format!(
+                                "⚠️ [{}] policy drift {}\n{}",
+                                self.config.agent_id,
+                                report.status.as_str(),
+                                report.summary()
+                            );
+                            if let Err(e) = notifier.send_message(&text).await {
+                                warn!(agent = self.config.agent_id, error = %e, "drift alert failed");
+                            }
+                        }
+                    } else if report.status == DriftStatus::Stable
+                        && previous >= DriftStatus::Warning
+                    {
+                        info!(agent = self.config.agent_id, "policy drift cleared");
+                    }
+
+                    let resume = monitor.should_resume(&report);
+                    if monitor.should_pause(&report) && matches!(status, AgentStatus::Running) {
+                        warn!(
+                            agent = self.config.agent_id,
+                            "pausing entries on critical policy drift"
+                        );
+                        status = AgentStatus::Paused;
+                        drift_paused = true;
+                    } else if drift_paused && resume {
+                        info!(
+                            agent = self.config.agent_id,
+                            "resuming entries after policy drift stabilized"
+                        );
+                        status = AgentStatus::Running;
+                        drift_paused = false;
+                    }
+                    drift_report = Some(report);
+                }
+
                 // --- Heartbeat ---
                 _ = heartbeat_tick.tick() => {
-                    if let Err(e) = ctx.report_state(
+                    let mut metrics = HashMap::new();
+                    let mut error_message = None;
+                    if let Some(report) = drift_report.as_ref() {
+                        report.insert_metrics(&mut metrics);
+                        if report.status == DriftStatus::Critical {
+                            error_message = Some(report.summary());
+                        }
+                    }
+                    if let Err(e) = ctx.report_state_with_metrics(
                         &self.config.name,
                         status,
                         positions.len(),
                         total_exposure,
                         daily_pnl,
                         Decimal::ZERO,
-                        None,
+                        metrics,
+                        error_message,
                     ).await {
                         warn!(agent = self.config.agent_id, error = %e, "failed to report heartbeat");
                     }
@@ -1698,10 +1836,12 @@ impl TradingAgent for CryptoRlPolicyAgent {
                         Some(CoordinatorCommand::Pause) => {
                             info!(agent = self.config.agent_id, "pausing");
                             status = AgentStatus::Paused;
+                            drift_paused = false;
                         }
                         Some(CoordinatorCommand::Resume) => {
                             info!(agent = self.config.agent_id, "resuming");
                             status = AgentStatus::Running;
+                            drift_paused = false;
                         }
                         Some(CoordinatorCommand::Shutdown) => {
                             info!(agent = self.config.agent_id, "shutting down");
@@ -10,6 +10,7 @@ pub mod crypto_rl_policy;
 pub mod openclaw;
 pub mod politics;
 pub mod sports;
+pub mod supervision;
 pub mod traits;
 
 pub use context::AgentContext;
@@ -21,4 +22,5 @@ pub use crypto_rl_policy::{CryptoRlPolicyAgent, CryptoRlPolicyConfig};
 pub use openclaw::{OpenClawAgent, OpenClawConfig};
 pub use politics::{PoliticsTradingAgent, PoliticsTradingConfig};
 pub use sports::{SportsTradingAgent, SportsTradingConfig};
+pub use supervision::{AgentSupervisor, CrashReport};
 pub use traits::{AgentConfig, TradingAgent};
@@ -1,10 +1,21 @@
 //! Cross-agent conflict detection + resolution
 //!
-//! Detects situations where two agents hold opposing positions on the same market
-//! (e.g., crypto agent bought UP while politics agent sold DOWN on an overlapping market).
-//! Resolves by pausing the lower-scoring agent.
+//! Detects situations where two agents hold (or are about to hold) opposing
+//! sides of the same condition — e.g. the crypto agent bought UP while the
+//! momentum agent bought DOWN on the same round. The overlapping shares are a
+//! locked $1 pair: zero net exposure bought with two sets of fees.
+//!
+//! Two resolution paths:
+//! - Intent time (coordinator): a BUY that opposes another agent is either
+//!   cancelled as the redundant leg, or internalized — the opposing holder
+//!   sells instead, which leaves the account with the same net exposure.
+//! - Position time (OpenClaw): conflicts that slipped through are resolved
+//!   by pausing the lower-scoring agent.
+//!
+//! Positions only carry the market slug, which maps 1:1 to a condition for
+//! Polymarket binaries, so conflicts are keyed by the normalized slug.
 
-use std::collections::HashMap;
+use std::collections::{BTreeMap, HashMap};
 
 use chrono::{DateTime, Utc};
 use serde::{Deserialize, Serialize};
@@ -12,17 +23,52 @@ use tracing::info;
 
 use crate::coordinator::GlobalState;
 use crate::domain::Side;
+use crate::platform::{Domain, OrderIntent, Position};
 
 use super::performance::AgentPerformance;
 
+/// Where the opposing exposure was found
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum ConflictKind {
+    /// Both agents hold open positions
+    Position,
+    /// A BUY intent opposes another agent's position or queued BUY
+    Intent,
+}
+
+/// How the coordinator handles a BUY intent that opposes another agent
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum ConflictPolicy {
+    /// Record the conflict and let the intent through
+    Report,
+    /// Drop the BUY as the redundant leg
+    #[default]
+    Cancel,
+    /// Sell the opposing holder's shares instead of buying the other side;
+    /// only the remainder is bought
+    Internalize,
+}
+
 /// Detected conflict between two agents
 #[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct AgentConflict {
+    pub kind: ConflictKind,
     pub agent_a: String,
     pub agent_b: String,
     pub market_slug: String,
+    pub side_a: Side,
+    pub side_b: Side,
+    pub shares_a: u64,
+    pub shares_b: u64,
+    /// Shares that net to zero exposure
+    pub overlap_shares: u64,
     /// Brief description of the conflict
     pub description: String,
+    /// How the conflict was handled, once resolved
+    #[serde(default)]
+    pub resolution: Option<String>,
     pub detected_at: DateTime<Utc>,
 }
 
@@ -35,54 +81,216 @@ pub struct ConflictResolution {
     pub reason: String,
 }
 
+/// Another agent's open position opposing a BUY intent
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct OpposingHolding {
+    pub agent_id: String,
+    pub domain: Domain,
+    pub market_slug: String,
+    pub token_id: String,
+    pub side: Side,
+    pub shares: u64,
+}
+
+/// A BUY intent that opposes other agents on the same condition
+#[derive(Debug, Clone)]
+pub struct IntentConflict {
+    pub conflict: AgentConflict,
+    /// Opposing open positions, largest first
+    pub holdings: Vec<OpposingHolding>,
+    /// Opposing BUY shares still queued by other agents
+    pub queued_shares: u64,
+}
+
+/// What to do with a conflicting BUY intent
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum IntentResolution {
+    Allow,
+    Cancel {
+        reason: String,
+    },
+    Internalize {
+        /// Reduce-only sells of the opposing holdings
+        sells: Vec<OpposingHolding>,
+        /// BUY shares left after netting
+        remaining_shares: u64,
+    },
+}
+
+fn market_key(slug: &str) -> String {
+    slug.trim().to_ascii_lowercase()
+}
+
 pub struct ConflictDetector;
 
 impl ConflictDetector {
     /// Detect conflicts: agents holding opposing sides on the same market
     pub fn detect(state: &GlobalState) -> Vec<AgentConflict> {
-        let mut conflicts = Vec::new();
-
-        // Build a map: market_slug → [(agent_id, side)]
-        let mut market_agents: HashMap<String, Vec<(String, Side)>> = HashMap::new();
-
-        for pos in &state.positions {
-            let key = pos.market_slug.clone();
-            market_agents
+        // market → agent → (up, down) shares; BTreeMap keeps output deterministic
+        let mut holdings: BTreeMap<String, BTreeMap<String, (u64, u64)>> = BTreeMap::new();
+        let mut slugs: HashMap<String, String> = HashMap::new();
+        for pos in state.positions.iter().filter(|p| p.shares > 0) {
+            let key = market_key(&pos.market_slug);
+            slugs
+                .entry(key.clone())
+                .or_insert_with(|| pos.market_slug.clone());
+            let shares = holdings
                 .entry(key)
                 .or_default()
-                .push((pos.agent_id.clone(), pos.side));
+                .entry(pos.agent_id.clone())
+                .or_default();
+            match pos.side {
+                Side::Up => shares.0 += pos.shares,
+                Side::Down => shares.1 += pos.shares,
+            }
         }
 
-        // Check for opposing positions on the same market
-        for (market, agents) in &market_agents {
-            if agents.len() < 2 {
-                continue;
+        let mut conflicts = Vec::new();
+        for (key, by_agent) in &holdings {
+            let market = &slugs[key];
+            for (agent_a, (shares_a, _)) in by_agent.iter().filter(|(_, s)| s.0 > 0) {
+                for (agent_b, (_, shares_b)) in
+                    by_agent.iter().filter(|(a, s)| s.1 > 0 && *a != agent_a)
+                {
+                    let (side_a, side_b) = (Side::Up, Side::Down);
+                    conflicts.push(AgentConflict {
+                        kind: ConflictKind::Position,
+                        agent_a: agent_a.clone(),
+                        agent_b: agent_b.clone(),
+                        market_slug: market.clone(),
+                        side_a,
+                        side_b,
+                        shares_a: *shares_a,
+                        shares_b: *shares_b,
+                        overlap_shares: (*shares_a).min(*shares_b),
+                        description: format!(
+                            "{} ({:?} x{}) vs {} ({:?} x{}) on {}",
+                            agent_a, side_a, shares_a, agent_b, side_b, shares_b, market
+                        ),
+                        resolution: None,
+                        detected_at: Utc::now(),
+                    });
+                }
             }
+        }
 
-            // Compare each pair
-            for i in 0..agents.len() {
-                for j in (i + 1)..agents.len() {
-                    let (agent_a, side_a) = &agents[i];
-                    let (agent_b, side_b) = &agents[j];
-
-                    // Opposing sides on the same market (Up vs Down)
-                    if agents[i].1 != agents[j].1 {
-                        conflicts.push(AgentConflict {
-                            agent_a: agent_a.clone(),
-                            agent_b: agent_b.clone(),
-                            market_slug: market.clone(),
-                            description: format!(
-                                "{} ({:?}) vs {} ({:?}) on {}",
-                                agent_a, side_a, agent_b, side_b, market
-                            ),
-                            detected_at: Utc::now(),
-                        });
+        conflicts
+    }
+
+    /// Find other agents' opposing exposure for a BUY intent: open positions
+    /// on the other side of the same market and queued BUYs for it.
+    pub fn check_intent<'a>(
+        intent: &OrderIntent,
+        positions: &[Position],
+        queued: impl IntoIterator<Item = &'a OrderIntent>,
+    ) -> Option<IntentConflict> {
+        if !intent.is_buy {
+            return None;
+        }
+        let key = market_key(&intent.market_slug);
+        let opposite = intent.side.opposite();
+        let opposes = |agent_id: &str, slug: &str, side: Side| {
+            agent_id != intent.agent_id && side == opposite && market_key(slug) == key
+        };
+
+        let mut holdings: Vec<OpposingHolding> = positions
+            .iter()
+            .filter(|p| p.shares > 0 && opposes(&p.agent_id, &p.market_slug, p.side))
+            .map(|p| OpposingHolding {
+                agent_id: p.agent_id.clone(),
+                domain: p.domain,
+                market_slug: p.market_slug.clone(),
+                token_id: p.token_id.clone(),
+                side: p.side,
+                shares: p.shares,
+            })
+            .collect();
+        holdings.sort_by_key(|h| std::cmp::Reverse(h.shares));
+
+        let queued: Vec<&OrderIntent> = queued
+            .into_iter()
+            .filter(|q| q.is_buy && opposes(&q.agent_id, &q.market_slug, q.side))
+            .collect();
+        let queued_shares: u64 = queued.iter().map(|q| q.shares).sum();
+        let held_shares: u64 = holdings.iter().map(|h| h.shares).sum();
+        if held_shares == 0 && queued_shares == 0 {
+            return None;
+        }
+
+        let other = holdings
+            .first()
+            .map(|h| h.agent_id.clone())
+            .or_else(|| queued.first().map(|q| q.agent_id.clone()))
+            .unwrap_or_default();
+        let opposing_shares = held_shares + queued_shares;
+        Some(IntentConflict {
+            conflict: AgentConflict {
+                kind: ConflictKind::Intent,
+                agent_a: intent.agent_id.clone(),
+                agent_b: other.clone(),
+                market_slug: intent.market_slug.clone(),
+                side_a: intent.side,
+                side_b: opposite,
+                shares_a: intent.shares,
+                shares_b: opposing_shares,
+                overlap_shares: intent.shares.min(opposing_shares),
+                description: format!(
+                    "{} BUY {:?} x{} vs {} {:?} x{} ({} held, {} queued) on {}",
+                    intent.agent_id,
+                    intent.side,
+                    intent.shares,
+                    other,
+                    opposite,
+                    opposing_shares,
+                    held_shares,
+                    queued_shares,
+                    intent.market_slug
+                ),
+                resolution: None,
+                detected_at: Utc::now(),
+            },
+            holdings,
+            queued_shares,
+        })
+    }
+
+    /// Decide how to handle a conflicting BUY under `policy`
+    pub fn resolve_intent(policy: ConflictPolicy, conflict: &IntentConflict) -> IntentResolution {
+        let buy_shares = conflict.conflict.shares_a;
+        match policy {
+            ConflictPolicy::Report => IntentResolution::Allow,
+            ConflictPolicy::Cancel => IntentResolution::Cancel {
+                reason: format!("redundant leg: {}", conflict.conflict.description),
+            },
+            ConflictPolicy::Internalize => {
+                let mut left = buy_shares;
+                let mut sells = Vec::new();
+                for holding in &conflict.holdings {
+                    if left == 0 {
+                        break;
                     }
+                    let shares = holding.shares.min(left);
+                    left -= shares;
+                    sells.push(OpposingHolding {
+                        shares,
+                        ..holding.clone()
+                    });
+                }
+                if sells.is_empty() {
+                    // Only queued opposition: nothing held to net against yet
+                    return IntentResolution::Cancel {
+                        reason: format!(
+                            "opposing BUY already queued: {}",
+                            conflict.conflict.description
+                        ),
+                    };
+                }
+                IntentResolution::Internalize {
+                    sells,
+                    remaining_shares: left,
                 }
             }
         }
-
-        conflicts
     }
 
     /// Resolve conflicts by pausing the lower-scoring agent
@@ -146,3 +354,95 @@ impl ConflictDetector {
         resolutions
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use rust_decimal_macros::dec;
+
+    fn position(agent: &str, slug: &str, side: Side, shares: u64) -> Position {
+        Position {
+            position_id: format!("pos-{agent}"),
+            agent_id: agent.to_string(),
+            domain: Domain::Crypto,
+            market_slug: slug.to_string(),
+            token_id: format!("{slug}-{side:?}"),
+            side,
+            shares,
+            entry_price: dec!(0.5),
+            current_price: None,
+            is_hedged: false,
+            entry_time: Utc::now(),
+            updated_at: Utc::now(),
+            metadata: HashMap::new(),
+        }
+    }
+
+    #[test]
+    fn detects_opposing_positions_and_nets_buy_intents() {
+        let slug = "btc-updown-15m-1700000000";
+        let mut state = GlobalState::new();
+        state.positions = vec![
+            position("crypto", slug, Side::Up, 40),
+            position("momentum", slug, Side::Down, 25),
+            position("crypto", "eth-updown-15m-1700000000", Side::Down, 10),
+        ];
+        let conflicts = ConflictDetector::detect(&state);
+        assert_eq!(conflicts.len(), 1);
+        assert_eq!(conflicts[0].overlap_shares, 25);
+        assert_eq!(conflicts[0].agent_a, "crypto");
+
+        let buy = OrderIntent::new(
+            "momentum",
+            Domain::Crypto,
+            slug.to_uppercase(),
+            "tok-down",
+            Side::Down,
+            true,
+            30,
+            dec!(0.48),
+        );
+        let conflict =
+            ConflictDetector::check_intent(&buy, &state.positions, std::iter::empty()).unwrap();
+        assert_eq!(conflict.holdings.len(), 1);
+        assert_eq!(conflict.conflict.overlap_shares, 30);
+
+        assert_eq!(
+            ConflictDetector::resolve_intent(ConflictPolicy::Report, &conflict),
+            IntentResolution::Allow
+        );
+        assert!(matches!(
+            ConflictDetector::resolve_intent(ConflictPolicy::Cancel, &conflict),
+            IntentResolution::Cancel { .. }
+        ));
+        // crypto holds 40 UP: selling 30 of them replaces the 30 DOWN buy
+        match ConflictDetector::resolve_intent(ConflictPolicy::Internalize, &conflict) {
+            IntentResolution::Internalize {
+                sells,
+                remaining_shares,
+            } => {
+                assert_eq!(sells.len(), 1);
+                assert_eq!(sells[0].agent_id, "crypto");
+                assert_eq!(sells[0].side, Side::Up);
+                assert_eq!(sells[0].shares, 30);
+                assert_eq!(remaining_shares, 0);
+            }
+            other => panic!("expected internalize, got {other:?}"),
+        }
+
+        // Same-agent and same-side exposure is not a conflict
+        let own = OrderIntent::new(
+            "crypto",
+            Domain::Crypto,
+            slug,
+            "tok-down",
+            Side::Down,
+            true,
+            5,
+            dec!(0.48),
+        );
+        assert!(
+            ConflictDetector::check_intent(&own, &state.positions, std::iter::empty()).is_none()
+        );
+    }
+}
@@ -38,6 +38,7 @@ use crate::strategy::nba_comeback::grok_decision::{
 use crate::strategy::nba_comeback::grok_intel::{
     self, GrokGameIntel, GrokSignalEvaluator, GrokTradeSignal,
 };
+use crate::strategy::nba_comeback::score_guard::{check_live_score, ScoreCheck, ScoreObservation};
 
 /// Configuration for the SportsTradingAgent
 #[derive(Debug, Clone, Serialize, Deserialize)]
@@ -104,6 +105,7 @@ struct MarketObservation {
 
 #[derive(Debug, Clone, Default)]
 struct MarketInput {
+    event_id: Option<String>,
     market_slug: Option<String>,
     trailing_token_id: Option<String>,
     trailing_price: Option<Decimal>,
@@ -1127,6 +1129,46 @@ impl SportsTradingAgent {
             .unwrap_or(false)
     }
 
+    /// Cross-check ESPN's score/period against Polymarket live metadata before an entry
+    async fn confirm_live_score(
+        &self,
+        game: &LiveGame,
+        espn_fetched_at: chrono::DateTime<Utc>,
+        event_id: Option<&str>,
+    ) -> bool {
+        let cfg = &self.core.cfg.score_guard;
+        if !cfg.enabled {
+            return true;
+        }
+        let espn = ScoreObservation::from_espn(game, espn_fetched_at);
+        let mut others = Vec::new();
+        if let (Some(pm_client), Some(event_id)) = (self.pm_sports.as_ref(), event_id) {
+            match pm_client.get_event_details(event_id).await {
+                Ok(event) => {
+                    others.extend(ScoreObservation::from_pm_event(&event, game, Utc::now()))
+                }
+                Err(e) => warn!(
+                    agent = self.config.agent_id,
+                    event_id,
+                    error = %e,
+                    "failed to fetch PM live score"
+                ),
+            }
+        }
+        match check_live_score(cfg, &espn, &others, Utc::now()) {
+            ScoreCheck::Confirmed { .. } => true,
+            ScoreCheck::Rejected(reason) => {
+                warn!(
+                    agent = self.config.agent_id,
+                    game_id = %game.espn_game_id,
+                    reason = %reason,
+                    "entry blocked: live score not confirmed"
+                );
+                false
+            }
+        }
+    }
+
     /// Mark a game as having just received a decision
     fn set_cooldown(&mut self, game_id: &str) {
         self.decision_cooldown
@@ -1736,6 +1778,7 @@ impl TradingAgent for SportsTradingAgent {
 
         // Cached state shared between ESPN poll and Grok tick
         let mut live_games_cache: Vec<LiveGame> = Vec::new();
+        let mut live_games_fetched_at = Utc::now();
         let mut market_inputs_cache: HashMap<String, MarketInput> = HashMap::new();
 
         loop {
@@ -1780,6 +1823,7 @@ impl TradingAgent for SportsTradingAgent {
                             continue;
                         }
                     };
+                    let espn_fetched_at = Utc::now();
                     live_games.retain(Self::is_valid_nba_game);
 
                     // Calendar gating is for *trading* safety, not for observability.
@@ -1909,6 +1953,7 @@ impl TradingAgent for SportsTradingAgent {
                         market_inputs.insert(
                             game.espn_game_id.clone(),
                             MarketInput {
+                                event_id: market_obs.pm_event_id.clone(),
                                 market_slug: market_obs.pm_event_slug.clone(),
                                 trailing_token_id: market_obs.pm_trailing_token_id.clone(),
                                 trailing_price: market_obs.pm_trailing_price,
@@ -1918,6 +1963,7 @@ impl TradingAgent for SportsTradingAgent {
 
                     // Update caches for Grok tick to use
                     live_games_cache = live_games.clone();
+                    live_games_fetched_at = espn_fetched_at;
                     market_inputs_cache = market_inputs.clone();
 
                     // --- Exit management: settle at final, or early TP/SL sell ---
@@ -2099,6 +2145,10 @@ impl TradingAgent for SportsTradingAgent {
                                     .unwrap_or(dec!(0.50))
                             });
 
+                        let candidate = &self.core.apply_comment_sentiment(
+                            candidate,
+                            market_input.and_then(|m| m.event_id.as_deref()),
+                        );
                         if let Some(opp) = self.core.evaluate_opportunity(
                             candidate,
                             market_price,
@@ -2181,6 +2231,14 @@ impl TradingAgent for SportsTradingAgent {
                                 continue;
                             }
 
+                            let event_id = market_input.and_then(|m| m.event_id.as_deref());
+                            if !self
+                                .confirm_live_score(&opp.game, espn_fetched_at, event_id)
+                                .await
+                            {
+                                continue;
+                            }
+
                             // Route through Grok unified decision
                             if let Some(grok) = self.grok.as_ref() {
                                 match grok_decision::request_unified_decision(grok, &req).await {
@@ -2395,6 +2453,14 @@ impl TradingAgent for SportsTradingAgent {
                                 continue;
                             }
 
+                            let event_id = market_input.and_then(|m| m.event_id.as_deref());
+                            if !self
+                                .confirm_live_score(&candidate.game, espn_fetched_at, event_id)
+                                .await
+                            {
+                                continue;
+                            }
+
                             let pos = self.core.state.game_positions.get(game_id).unwrap();
                             let add_number = pos.entries.len() as u32; // 1-based (entry 0 was initial)
                             let existing_shares = pos.total_shares;
@@ -2669,6 +2735,16 @@ impl TradingAgent for SportsTradingAgent {
                                 continue;
                             }
 
+                            let event_id = market_inputs_cache
+                                .get(&game_id)
+                                .and_then(|m| m.event_id.as_deref());
+                            if !self
+                                .confirm_live_score(game, live_games_fetched_at, event_id)
+                                .await
+                            {
+                                continue;
+                            }
+
                             let sig_price_f64 = sig.market_price.to_string().parse::<f64>().unwrap_or(0.0);
                             // For Grok signal path, use grok_home_win_prob as fair value estimate
                             let grok_fair_value = if trailing == game.home_abbrev {
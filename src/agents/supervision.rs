@@ -0,0 +1,341 @@
+//! Agent supervision — panic isolation and restart for `TradingAgent`s
+//!
+//! Each supervised agent runs in its own tokio task. A panic inside `run()` is
+//! caught at the task boundary, recorded as a [`CrashReport`] (with the
+//! backtrace captured by a process-wide panic hook), and the agent is rebuilt
+//! after the [`RecoveryPlaybook`] restart backoff. Other agents
+//! keep running throughout.
+//!
+//! The supervisor owns the coordinator command receiver and forwards commands to
+//! the current incarnation, so restarts need no re-registration.
+
+use chrono::{DateTime, Utc};
+use serde::{Deserialize, Serialize};
+use std::collections::VecDeque;
+use std::path::PathBuf;
+use std::sync::{Arc, Mutex, Once};
+use std::time::Duration;
+use tokio::sync::mpsc;
+use tokio::task::JoinHandle;
+use tokio::time::Instant;
+use tracing::{error, info, warn};
+
+use crate::coordinator::{CoordinatorCommand, CoordinatorHandle};
+use crate::error::Result;
+use crate::platform::Domain;
+use crate::supervisor::playbook::{execute_action, FailureScenario};
+use crate::supervisor::RecoveryPlaybook;
+
+use super::context::AgentContext;
+use super::traits::TradingAgent;
+
+tokio::task_local! {
+    /// Per-task slot the panic hook fills with the backtrace of a panicking agent
+    static PANIC_BACKTRACE: Arc<Mutex<Option<String>>>;
+}
+
+static PANIC_HOOK: Once = Once::new();
+
+/// Chain a panic hook that captures backtraces for supervised agent tasks
+fn install_panic_hook() {
+    PANIC_HOOK.call_once(|| {
+        let previous = std::panic::take_hook();
+        std::panic::set_hook(Box::new(move |info| {
+            let _ = PANIC_BACKTRACE.try_with(|slot| {
+                if let Ok(mut slot) = slot.lock() {
+                    *slot = Some(std::backtrace::Backtrace::force_capture().to_string());
+                }
+            });
+            previous(info);
+        }));
+    });
+}
+
+/// Record of one agent panic
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct CrashReport {
+    pub agent_id: String,
+    pub occurred_at: DateTime<Utc>,
+    /// Crashes within the playbook restart window, including this one
+    pub crash_count: u32,
+    pub panic_message: String,
+    pub backtrace: Option<String>,
+    /// `None` when restart attempts are exhausted
+    pub restart_in_secs: Option<u64>,
+}
+
+/// How one incarnation of an agent ended
+enum Exit {
+    /// `run()` returned (shutdown or fatal error) — not restarted
+    Finished,
+    Panicked {
+        message: String,
+        backtrace: Option<String>,
+    },
+}
+
+fn panic_message(payload: Box<dyn std::any::Any + Send>) -> String {
+    if let Some(s) = payload.downcast_ref::<&str>() {
+        (*s).to_string()
+    } else if let Some(s) = payload.downcast_ref::<String>() {
+        s.clone()
+    } else {
+        "non-string panic payload".to_string()
+    }
+}
+
+/// Spawns agents under panic supervision
+#[derive(Clone)]
+pub struct AgentSupervisor {
+    playbook: Arc<RecoveryPlaybook>,
+    crash_dir: Option<PathBuf>,
+    crashes: Arc<Mutex<Vec<CrashReport>>>,
+}
+
+impl AgentSupervisor {
+    pub fn new(playbook: RecoveryPlaybook) -> Self {
+        install_panic_hook();
+        Self {
+            playbook: Arc::new(playbook),
+            crash_dir: None,
+            crashes: Arc::new(Mutex::new(Vec::new())),
+        }
+    }
+
+    /// Also write each crash report to `<dir>/<agent_id>-<ts>.json`
+    pub fn with_crash_dir(mut self, dir: impl Into<PathBuf>) -> Self {
+        self.crash_dir = Some(dir.into());
+        self
+    }
+
+    /// Crash reports recorded so far (all agents)
+    pub fn crash_reports(&self) -> Vec<CrashReport> {
+        self.crashes.lock().map(|c| c.clone()).unwrap_or_default()
+    }
+
+    /// Spawn a supervised agent.
+    ///
+    /// `agent` is the first incarnation; `rebuild` builds a fresh one for every
+    /// restart (if it fails the agent stays down). The returned handle completes
+    /// when the agent finishes normally, the command channel closes, or restarts
+    /// are exhausted.
+    pub fn spawn<A, F>(
+        &self,
+        agent_id: String,
+        domain: Domain,
+        handle: CoordinatorHandle,
+        commands: mpsc::Receiver<CoordinatorCommand>,
+        agent: A,
+        rebuild: F,
+    ) -> JoinHandle<()>
+    where
+        A: TradingAgent,
+        F: FnMut() -> Result<A> + Send + 'static,
+    {
+        let supervisor = self.clone();
+        tokio::spawn(async move {
+            supervisor
+                .supervise(agent_id, domain, handle, commands, agent, rebuild)
+                .await
+        })
+    }
+
+    async fn supervise<A, F>(
+        &self,
+        agent_id: String,
+        domain: Domain,
+        handle: CoordinatorHandle,
+        mut commands: mpsc::Receiver<CoordinatorCommand>,
+        agent: A,
+        mut rebuild: F,
+    ) where
+        A: TradingAgent,
+        F: FnMut() -> Result<A> + Send + 'static,
+    {
+        let window = Duration::from_secs(self.playbook.restart_window_secs);
+        let mut recent_crashes: VecDeque<Instant> = VecDeque::new();
+        let mut next = Some(agent);
+
+        loop {
+            let agent = match next.take() {
+                Some(agent) => agent,
+                None => match rebuild() {
+                    Ok(agent) => agent,
+                    Err(e) => {
+                        error!(agent = %agent_id, error = %e, "failed to rebuild agent; leaving it stopped");
+                        return;
+                    }
+                },
+            };
+
+            let (exit, channel_closed) =
+                Self::run_once(agent, &agent_id, domain, &handle, &mut commands).await;
+
+            let (message, backtrace) = match exit {
+                Exit::Finished => return,
+                Exit::Panicked { message, backtrace } => (message, backtrace),
+            };
+
+            let now = Instant::now();
+            while recent_crashes
+                .front()
+                .is_some_and(|t| now.duration_since(*t) > window)
+            {
+                recent_crashes.pop_front();
+            }
+            recent_crashes.push_back(now);
+            let crash_count = recent_crashes.len() as u32;
+            let backoff = self.playbook.restart_backoff(crash_count);
+
+            let report = CrashReport {
+                agent_id: agent_id.clone(),
+                occurred_at: Utc::now(),
+                crash_count,
+                panic_message: message.clone(),
+                backtrace,
+                restart_in_secs: backoff.map(|d| d.as_secs()),
+            };
+            error!(
+                agent = %agent_id,
+                crash_count,
+                panic = %message,
+                "agent panicked; other agents unaffected"
+            );
+            self.record(report).await;
+
+            for action in self.playbook.get_actions(&FailureScenario::ComponentCrash {
+                component: agent_id.clone(),
+                error: message,
+            }) {
+                execute_action(&action).await;
+            }
+
+            if channel_closed {
+                warn!(agent = %agent_id, "coordinator command channel closed; not restarting");
+                return;
+            }
+            let Some(delay) = backoff else {
+                error!(
+                    agent = %agent_id,
+                    max = self.playbook.max_restart_attempts,
+                    "agent restart attempts exhausted; leaving it stopped"
+                );
+                return;
+            };
+
+            info!(agent = %agent_id, delay_secs = delay.as_secs(), "restarting agent after backoff");
+            let deadline = Instant::now() + delay;
+            // Keep draining commands while backing off; a shutdown cancels the restart
+            loop {
+                tokio::select! {
+                    _ = tokio::time::sleep_until(deadline) => break,
+                    cmd = commands.recv() => match cmd {
+                        Some(CoordinatorCommand::Shutdown) | Some(CoordinatorCommand::ForceClose) | None => {
+                            info!(agent = %agent_id, "shutdown during restart backoff");
+                            return;
+                        }
+                        Some(_) => {}
+                    }
+                }
+            }
+        }
+    }
+
+    /// Run one incarnation, forwarding coordinator commands until it exits.
+    ///
+    /// Returns the exit and whether the coordinator command channel closed.
+    async fn run_once<A: TradingAgent>(
+        agent: A,
+        agent_id: &str,
+        domain: Domain,
+        handle: &CoordinatorHandle,
+        commands: &mut mpsc::Receiver<CoordinatorCommand>,
+    ) -> (Exit, bool) {
+        let (tx, rx) = mpsc::channel(32);
+        let ctx = AgentContext::new(agent_id.to_string(), domain, handle.clone(), rx);
+        let slot = Arc::new(Mutex::new(None));
+        let mut task = tokio::spawn(PANIC_BACKTRACE.scope(slot.clone(), agent.run(ctx)));
+
+        let mut tx = Some(tx);
+        let result = loop {
+            tokio::select! {
+                res = &mut task => break res,
+                cmd = commands.recv(), if tx.is_some() => match cmd {
+                    Some(cmd) => {
+                        if let Some(ref tx) = tx {
+                            let _ = tx.send(cmd).await;
+                        }
+                    }
+                    // Dropping the sender lets the agent observe the closed channel
+                    None => tx = None,
+                }
+            }
+        };
+        let channel_closed = tx.is_none();
+
+        let exit = match result {
+            Ok(Ok(())) => {
+                info!(agent = %agent_id, "agent exited");
+                Exit::Finished
+            }
+            Ok(Err(e)) => {
+                error!(agent = %agent_id, error = %e, "agent exited with error");
+                Exit::Finished
+            }
+            Err(e) if e.is_panic() => Exit::Panicked {
+                message: panic_message(e.into_panic()),
+                backtrace: slot.lock().ok().and_then(|mut s| s.take()),
+            },
+            Err(e) => {
+                warn!(agent = %agent_id, error = %e, "agent task cancelled");
+                Exit::Finished
+            }
+        };
+        (exit, channel_closed)
+    }
+
+    async fn record(&self, report: CrashReport) {
+        if let Some(ref dir) = self.crash_dir {
+            let path = dir.join(format!(
+                "{}-{}.json",
+                report.agent_id,
+                report.occurred_at.format("%Y%m%dT%H%M%S%.3fZ")
+            ));
+            let write = async {
+                tokio::fs::create_dir_all(dir).await?;
+                let json = serde_json::to_vec_pretty(&report).map_err(std::io::Error::other)?;
+                tokio::fs::write(&path, json).await
+            };
+            if let Err(e) = write.await {
+                warn!(path = %path.display(), error = %e, "failed to write crash report");
+            }
+        }
+        if let Ok(mut crashes) = self.crashes.lock() {
+            crashes.push(report);
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[tokio::test]
+    async fn test_panic_is_caught_with_backtrace() {
+        install_panic_hook();
+        let slot = Arc::new(Mutex::new(None));
+        let task = tokio::spawn(PANIC_BACKTRACE.scope(slot.clone(), async {
+            panic!("agent blew up");
+        }));
+
+        let err = task.await.unwrap_err();
+        assert!(err.is_panic());
+        assert_eq!(panic_message(err.into_panic()), "agent blew up");
+        assert!(slot.lock().unwrap().is_some());
+
+        let playbook = RecoveryPlaybook::default();
+        assert_eq!(playbook.restart_backoff(1), Some(Duration::from_secs(2)));
+        assert_eq!(playbook.restart_backoff(3), Some(Duration::from_secs(8)));
+        assert_eq!(playbook.restart_backoff(4), None);
+    }
+}
@@ -6,17 +6,25 @@
 use crate::adapters::PolymarketClient;
 use crate::ai_clients::client::ClaudeAgentClient;
 use crate::ai_clients::grok::{GrokClient, SearchResult};
-use crate::ai_clients::protocol::{AgentAction, AgentContext, AgentResponse, PositionInfo};
-use crate::domain::{RiskState, Side};
-use crate::error::{PloyError, Result};
-use rust_decimal::prelude::ToPrimitive;
+use crate::ai_clients::protocol::{AgentAction, AgentContext, PositionInfo};
+use crate::ai_clients::tools::{
+    AgentToolCall, ProposedOrder, ToolAuditLog, ToolCallRecord, ToolExchange, ToolOutcome,
+};
+use crate::domain::Side;
+use crate::error::Result;
+use crate::platform::{Domain, OrderIntent, RiskCheckResult, RiskGate};
+use chrono::Utc;
 use rust_decimal::Decimal;
+use serde_json::json;
 use std::sync::Arc;
 use std::time::Duration;
 use tokio::sync::{broadcast, RwLock};
 use tokio::time::Instant;
 use tracing::{debug, error, info, warn};
 
+/// Maximum tool-calling rounds per analysis cycle
+const MAX_TOOL_ROUNDS: usize = 4;
+
 /// Autonomy level for the trading agent
 #[derive(Debug, Clone, Copy, PartialEq, Eq)]
 pub enum AutonomyLevel {
@@ -125,6 +133,13 @@ pub struct AutonomousAgent {
     last_grok_call: std::sync::Mutex<Option<Instant>>,
     /// Optional trading client for real order execution
     pm_client: Option<PolymarketClient>,
+    /// Risk gate every `propose_order` must pass (proposals are rejected without one)
+    risk_gate: Option<Arc<RiskGate>>,
+    /// Agent id / domain used for risk-gate intents and audit records
+    agent_id: String,
+    domain: Domain,
+    /// Tool call/response audit log
+    audit: ToolAuditLog,
 }
 
 impl AutonomousAgent {
@@ -142,6 +157,10 @@ impl AutonomousAgent {
             grok: None,
             last_grok_call: std::sync::Mutex::new(None),
             pm_client: None,
+            risk_gate: None,
+            agent_id: "autonomous".to_string(),
+            domain: Domain::Crypto,
+            audit: ToolAuditLog::in_memory(),
         }
     }
 
@@ -157,6 +176,30 @@ impl AutonomousAgent {
         self
     }
 
+    /// Validate proposed orders against the platform risk gate as `agent_id`.
+    pub fn with_risk_gate(
+        mut self,
+        risk_gate: Arc<RiskGate>,
+        agent_id: impl Into<String>,
+        domain: Domain,
+    ) -> Self {
+        self.risk_gate = Some(risk_gate);
+        self.agent_id = agent_id.into();
+        self.domain = domain;
+        self
+    }
+
+    /// Persist tool call/response pairs to `audit`.
+    pub fn with_tool_audit(mut self, audit: ToolAuditLog) -> Self {
+        self.audit = audit;
+        self
+    }
+
+    /// Recent tool call/response pairs
+    pub fn tool_audit(&self) -> Vec<ToolCallRecord> {
+        self.audit.recent()
+    }
+
     /// Check if Grok is available
     pub fn has_grok(&self) -> bool {
         self.grok
@@ -260,7 +303,11 @@ impl AutonomousAgent {
         Ok(())
     }
 
-    /// Analyze current state and determine actions
+    /// Analyze current state and act through tool calls
+    ///
+    /// Runs up to [`MAX_TOOL_ROUNDS`] rounds: each round the agent sees the
+    /// previous call/response pairs and may issue more tool calls. Returns the
+    /// actions derived from approved proposals and the final summary.
     async fn analyze_and_act(&self, context: &AgentContext) -> Result<Vec<AgentAction>> {
         // Fetch real-time context from Grok if available
         let grok_context = self
@@ -269,32 +316,58 @@ impl AutonomousAgent {
 
         let prompt = self.build_analysis_prompt(context, grok_context.as_ref());
 
-        let response = self.client.query(&prompt, context).await?;
+        let mut transcript: Vec<ToolExchange> = Vec::new();
+        let mut actions = Vec::new();
 
-        debug!(
-            "Agent response: confidence={}, actions={}",
-            response.confidence,
-            response.recommended_actions.len()
-        );
+        for round in 0..MAX_TOOL_ROUNDS {
+            let response = self
+                .client
+                .query_tools(&prompt, context, &transcript)
+                .await?;
 
-        // Filter and validate actions
-        let valid_actions = self.validate_actions(&response, context).await?;
+            debug!(
+                "Agent round {}: confidence={}, tool_calls={}, done={}",
+                round,
+                response.confidence,
+                response.tool_calls.len(),
+                response.done
+            );
 
-        // Execute if allowed
-        if self.can_trade() {
-            for action in &valid_actions {
-                match self.execute_action(action, context).await {
-                    Ok(result) => {
-                        info!("Executed action: {} - {}", result.success, result.message);
-                    }
-                    Err(e) => {
-                        error!("Failed to execute action: {}", e);
-                    }
+            if response.tool_calls.is_empty() {
+                actions.push(AgentAction::NoAction {
+                    reason: response.summary.clone(),
+                });
+                break;
+            }
+
+            for call in &response.tool_calls {
+                let (outcome, action) = self
+                    .dispatch_tool_call(call, response.confidence, context)
+                    .await;
+                self.audit.record(ToolCallRecord {
+                    ts: Utc::now(),
+                    agent_id: self.agent_id.clone(),
+                    market_id: context.market_state.market_id.clone(),
+                    round,
+                    call: call.clone(),
+                    outcome: outcome.clone(),
+                });
+                if !outcome.ok {
+                    warn!("Tool call {} rejected: {}", call.name(), outcome.output);
                 }
+                actions.extend(action);
+                transcript.push(ToolExchange {
+                    call: call.clone(),
+                    outcome,
+                });
+            }
+
+            if response.done {
+                break;
             }
         }
 
-        Ok(valid_actions)
+        Ok(actions)
     }
 
     /// Sanitize external input before embedding in LLM prompts.
@@ -358,8 +431,8 @@ Minimum Confidence Required: {}%
 Your analysis should:
 1. Identify opportunities matching allowed strategies
 2. Assess risk for each opportunity
-3. Recommend specific actions with reasoning
-4. Consider current positions and exposure limits
+3. Use get_positions / get_market before proposing orders
+4. Act only via propose_order / cancel_order, with reasoning
 5. Factor in real-time sentiment if available
 
 Prioritize capital preservation while seeking profitable opportunities."#,
@@ -370,221 +443,174 @@ Prioritize capital preservation while seeking profitable opportunities."#,
         )
     }
 
-    /// Validate actions against configuration limits
-    async fn validate_actions(
+    /// Execute one tool call and build the response returned to the agent
+    async fn dispatch_tool_call(
         &self,
-        response: &AgentResponse,
+        call: &AgentToolCall,
+        confidence: f64,
         context: &AgentContext,
-    ) -> Result<Vec<AgentAction>> {
-        let mut valid_actions = Vec::new();
-        let current_exposure = *self.current_exposure.read().await;
-
-        // Check confidence threshold
-        if response.confidence < self.config.min_confidence {
-            debug!(
-                "Response confidence {} below threshold {}",
-                response.confidence, self.config.min_confidence
-            );
-            return Ok(valid_actions);
-        }
-
-        for action in &response.recommended_actions {
-            match action {
-                AgentAction::EnterPosition {
-                    shares, max_price, ..
-                } => {
-                    let trade_value = Decimal::from(*shares) * *max_price;
-
-                    // Check trade size limit
-                    if trade_value > self.config.max_trade_size {
-                        warn!(
-                            "Trade size ${} exceeds limit ${}",
-                            trade_value, self.config.max_trade_size
-                        );
-                        continue;
+    ) -> (ToolOutcome, Option<AgentAction>) {
+        match call {
+            AgentToolCall::GetPositions => {
+                let mut positions = context.positions.clone();
+                for tracked in self.positions.read().await.iter() {
+                    if !positions.iter().any(|p| p.token_id == tracked.token_id) {
+                        positions.push(tracked.clone());
                     }
-
-                    // Check total exposure limit
-                    if current_exposure + trade_value > self.config.max_total_exposure {
-                        warn!(
-                            "Would exceed total exposure limit: current=${}, trade=${}, limit=${}",
-                            current_exposure, trade_value, self.config.max_total_exposure
-                        );
-                        continue;
-                    }
-
-                    valid_actions.push(action.clone());
                 }
-                AgentAction::ExitPosition { .. } => {
-                    if self.config.require_exit_confirmation
-                        && self.config.autonomy_level != AutonomyLevel::FullAutonomy
-                    {
-                        // Convert to alert for confirmation
-                        valid_actions.push(AgentAction::Alert {
-                            severity: "warning".to_string(),
-                            message: "Exit recommended but requires confirmation".to_string(),
-                        });
-                    } else {
-                        valid_actions.push(action.clone());
+                (ToolOutcome::ok(json!({ "positions": positions })), None)
+            }
+            AgentToolCall::GetMarket => (ToolOutcome::ok(json!(context.market_state)), None),
+            AgentToolCall::ProposeOrder(order) => {
+                match self.review_proposal(order, confidence, context).await {
+                    Ok(intent) => {
+                        let action = if order.is_buy {
+                            AgentAction::EnterPosition {
+                                side: order.side,
+                                shares: order.shares,
+                                max_price: order.limit_price,
+                                reasoning: order.reasoning.clone(),
+                            }
+                        } else {
+                            AgentAction::ExitPosition {
+                                token_id: intent.token_id.clone(),
+                                min_price: Some(order.limit_price),
+                                reasoning: order.reasoning.clone(),
+                            }
+                        };
+                        // Direct submit stays disabled: approved proposals are published
+                        // for the coordinator intent ingress, never sent to the exchange here.
+                        let status = if self.can_trade() {
+                            "approved"
+                        } else {
+                            "advisory"
+                        };
+                        let outcome = ToolOutcome::ok(json!({
+                            "status": status,
+                            "intent_id": intent.intent_id.to_string(),
+                            "token_id": intent.token_id,
+                            "notional": intent.notional_value(),
+                        }));
+                        (outcome, Some(action))
                     }
+                    Err(reason) => (ToolOutcome::rejected(reason), None),
                 }
-                AgentAction::Wait { .. }
-                | AgentAction::Alert { .. }
-                | AgentAction::NoAction { .. } => {
-                    valid_actions.push(action.clone());
-                }
-                AgentAction::AdjustRisk {
-                    new_state,
-                    reasoning: _,
-                } => {
-                    // Only allow risk increases (more conservative)
-                    if *new_state == RiskState::Halted
-                        || (*new_state == RiskState::Elevated
-                            && context.risk_state == RiskState::Normal)
-                    {
-                        valid_actions.push(action.clone());
-                    } else {
-                        debug!("Ignoring risk reduction: {:?}", new_state);
-                    }
+            }
+            AgentToolCall::CancelOrder(args) => {
+                if !self.can_trade() {
+                    return (
+                        ToolOutcome::rejected("trading disabled (advisory mode)"),
+                        None,
+                    );
                 }
-                _ => {
-                    // Other actions passed through
-                    valid_actions.push(action.clone());
+                let Some(pm_client) = &self.pm_client else {
+                    return (ToolOutcome::rejected("no trading client configured"), None);
+                };
+                info!("Cancelling order {}: {}", args.order_id, args.reasoning);
+                match pm_client.cancel_order(&args.order_id).await {
+                    Ok(cancelled) => (
+                        ToolOutcome::ok(json!({
+                            "order_id": args.order_id,
+                            "cancelled": cancelled,
+                        })),
+                        None,
+                    ),
+                    Err(e) => (ToolOutcome::rejected(format!("cancel failed: {e}")), None),
                 }
             }
         }
-
-        Ok(valid_actions)
     }
 
-    /// Execute a validated action
-    async fn execute_action(
+    /// Check a proposal against the agent limits, then the platform risk gate
+    async fn review_proposal(
         &self,
-        action: &AgentAction,
+        order: &ProposedOrder,
+        confidence: f64,
         context: &AgentContext,
-    ) -> Result<ExecutionResult> {
-        match action {
-            AgentAction::EnterPosition {
-                side,
-                shares,
-                max_price,
-                reasoning,
-            } => {
-                info!(
-                    "Executing entry: {:?} {} shares @ max ${}: {}",
-                    side, shares, max_price, reasoning
-                );
-
-                let token_id = match side {
-                    Side::Up => context.market_state.yes_token_id.clone(),
-                    Side::Down => context.market_state.no_token_id.clone(),
-                }
-                .ok_or_else(|| {
-                    PloyError::Validation(format!(
-                        "missing token id for side {:?} on market {}",
-                        side, context.market_state.market_id
-                    ))
-                })?;
-                let _ = token_id;
-                Err(PloyError::Validation(
-                    "autonomous direct submit is disabled; route orders through coordinator intent ingress"
-                        .to_string(),
-                ))
-            }
-            AgentAction::ExitPosition {
-                token_id,
-                min_price,
-                reasoning,
-            } => {
-                info!(
-                    "Executing exit: {} @ min {:?}: {}",
-                    token_id, min_price, reasoning
-                );
-
-                let pos = self
-                    .positions
-                    .read()
-                    .await
-                    .iter()
-                    .find(|p| p.token_id == *token_id)
-                    .cloned()
-                    .ok_or_else(|| {
-                        PloyError::Validation(format!(
-                            "cannot exit token {}: no tracked position found",
-                            token_id
-                        ))
-                    })?;
-
-                let shares = pos.shares.to_u64().ok_or_else(|| {
-                    PloyError::Validation(format!(
-                        "invalid tracked share amount for token {}: {}",
-                        token_id, pos.shares
-                    ))
-                })?;
-
-                let limit_price = min_price
-                    .or(pos.current_price)
-                    .unwrap_or(pos.avg_entry_price);
-                let _ = shares;
-                let _ = limit_price;
-                Err(PloyError::Validation(
-                    "autonomous direct submit is disabled; route orders through coordinator intent ingress"
-                        .to_string(),
-                ))
+    ) -> std::result::Result<OrderIntent, String> {
+        if confidence < self.config.min_confidence {
+            return Err(format!(
+                "confidence {:.2} below threshold {:.2}",
+                confidence, self.config.min_confidence
+            ));
+        }
+        if order.shares == 0 {
+            return Err("shares must be positive".to_string());
+        }
+        if order.limit_price <= Decimal::ZERO || order.limit_price >= Decimal::ONE {
+            return Err(format!("limit_price {} outside (0, 1)", order.limit_price));
+        }
+
+        let token_id = if order.is_buy {
+            let trade_value = Decimal::from(order.shares) * order.limit_price;
+            if trade_value > self.config.max_trade_size {
+                return Err(format!(
+                    "trade size ${} exceeds limit ${}",
+                    trade_value, self.config.max_trade_size
+                ));
             }
-            AgentAction::Wait {
-                duration_secs,
-                reason,
-            } => {
-                debug!("Agent waiting {} seconds: {}", duration_secs, reason);
-                Ok(ExecutionResult {
-                    action: action.clone(),
-                    success: true,
-                    message: format!("Waiting: {}", reason),
-                    trade_id: None,
-                })
+            let current_exposure = *self.current_exposure.read().await;
+            if current_exposure + trade_value > self.config.max_total_exposure {
+                return Err(format!(
+                    "would exceed total exposure limit: current=${}, trade=${}, limit=${}",
+                    current_exposure, trade_value, self.config.max_total_exposure
+                ));
             }
-            AgentAction::Alert { severity, message } => {
-                match severity.as_str() {
-                    "critical" => error!("[AGENT ALERT] {}", message),
-                    "warning" => warn!("[AGENT ALERT] {}", message),
-                    _ => info!("[AGENT ALERT] {}", message),
-                }
-                Ok(ExecutionResult {
-                    action: action.clone(),
-                    success: true,
-                    message: format!("Alert sent: {}", message),
-                    trade_id: None,
-                })
+            match order.side {
+                Side::Up => context.market_state.yes_token_id.clone(),
+                Side::Down => context.market_state.no_token_id.clone(),
             }
-            AgentAction::AdjustRisk {
-                new_state,
-                reasoning,
-            } => {
-                warn!("Risk adjustment to {:?}: {}", new_state, reasoning);
-                // TODO: Integrate with RiskManager
-                Ok(ExecutionResult {
-                    action: action.clone(),
-                    success: true,
-                    message: format!("Risk adjusted to {:?}", new_state),
-                    trade_id: None,
-                })
+            .ok_or_else(|| format!("missing token id for side {:?}", order.side))?
+        } else {
+            if self.config.require_exit_confirmation
+                && self.config.autonomy_level != AutonomyLevel::FullAutonomy
+            {
+                return Err("exit requires human confirmation".to_string());
             }
-            AgentAction::NoAction { reason } => {
-                debug!("No action: {}", reason);
-                Ok(ExecutionResult {
-                    action: action.clone(),
-                    success: true,
-                    message: reason.clone(),
-                    trade_id: None,
-                })
+            let token_id = order
+                .token_id
+                .clone()
+                .ok_or_else(|| "token_id is required for exits".to_string())?;
+            let tracked = self.positions.read().await;
+            let held = context
+                .positions
+                .iter()
+                .chain(tracked.iter())
+                .find(|p| p.token_id == token_id)
+                .map(|p| p.shares)
+                .ok_or_else(|| format!("no tracked position for token {}", token_id))?;
+            if Decimal::from(order.shares) > held {
+                return Err(format!(
+                    "exit of {} shares exceeds held {}",
+                    order.shares, held
+                ));
             }
-            _ => Ok(ExecutionResult {
-                action: action.clone(),
-                success: false,
-                message: "Action type not implemented".to_string(),
-                trade_id: None,
-            }),
+            token_id
+        };
+
+        let Some(risk_gate) = &self.risk_gate else {
+            return Err("no risk gate configured; proposals are rejected".to_string());
+        };
+        let intent = OrderIntent::new(
+            self.agent_id.clone(),
+            self.domain,
+            context.market_state.market_id.clone(),
+            token_id,
+            order.side,
+            order.is_buy,
+            order.shares,
+            order.limit_price,
+        )
+        .with_metadata("strategy", "autonomous")
+        .with_metadata("reasoning", Self::sanitize_for_prompt(&order.reasoning));
+
+        match risk_gate.check_order(&intent).await {
+            RiskCheckResult::Passed => Ok(intent),
+            RiskCheckResult::Blocked(reason) => Err(format!("risk gate blocked: {}", reason)),
+            RiskCheckResult::Adjusted(suggestion) => Err(format!(
+                "risk gate requires at most {} shares: {}",
+                suggestion.max_shares, suggestion.reason
+            )),
         }
     }
 
@@ -631,4 +657,63 @@ mod tests {
         agent.set_config(AutonomousConfig::conservative());
         assert!(agent.can_trade());
     }
+
+    #[tokio::test]
+    async fn test_propose_order_goes_through_risk_gate() {
+        use crate::ai_clients::protocol::MarketSnapshot;
+        use crate::domain::{RiskState, StrategyState};
+        use crate::platform::{AgentRiskParams, RiskConfig};
+
+        let mut market = MarketSnapshot::new("btc-up-or-down".to_string());
+        market.yes_token_id = Some("yes-token".to_string());
+        let context = AgentContext::new(market, StrategyState::Idle, RiskState::Normal);
+        let propose = |shares| {
+            AgentToolCall::ProposeOrder(ProposedOrder {
+                side: Side::Up,
+                is_buy: true,
+                token_id: None,
+                shares,
+                limit_price: Decimal::new(40, 2),
+                reasoning: "cheap".to_string(),
+            })
+        };
+
+        // Without a risk gate every proposal is rejected
+        let agent =
+            AutonomousAgent::new(ClaudeAgentClient::new(), AutonomousConfig::conservative());
+        let (outcome, action) = agent.dispatch_tool_call(&propose(10), 0.9, &context).await;
+        assert!(!outcome.ok);
+        assert!(action.is_none());
+
+        let gate = Arc::new(RiskGate::new(RiskConfig::default()));
+        gate.register_agent(
+            "autonomous",
+            AgentRiskParams {
+                max_order_value: Decimal::from(2),
+                ..Default::default()
+            },
+        )
+        .await;
+        let agent = agent.with_risk_gate(gate, "autonomous", Domain::Crypto);
+
+        // $4 order exceeds the gate's $2 cap -> rejected with the suggested size
+        let (outcome, _) = agent.dispatch_tool_call(&propose(10), 0.9, &context).await;
+        assert!(!outcome.ok);
+        assert!(outcome.output["error"]
+            .as_str()
+            .unwrap()
+            .contains("at most 5"));
+
+        let (outcome, action) = agent.dispatch_tool_call(&propose(5), 0.9, &context).await;
+        assert!(outcome.ok, "{:?}", outcome);
+        assert_eq!(outcome.output["status"], "approved");
+        assert!(matches!(
+            action,
+            Some(AgentAction::EnterPosition { shares: 5, .. })
+        ));
+
+        // Low confidence never reaches the gate
+        let (outcome, _) = agent.dispatch_tool_call(&propose(5), 0.5, &context).await;
+        assert!(!outcome.ok);
+    }
 }
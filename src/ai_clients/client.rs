@@ -4,6 +4,7 @@
 //! This approach provides isolation and leverages the existing Claude Code CLI.
 
 use crate::ai_clients::protocol::{AgentContext, AgentResponse};
+use crate::ai_clients::tools::{parse_tool_response, tool_schema, AgentToolResponse, ToolExchange};
 use crate::error::{PloyError, Result};
 use serde::Deserialize;
 use serde_json;
@@ -184,6 +185,101 @@ Respond ONLY with valid JSON."#,
             context_json, prompt
         );
 
+        let response_text = self.run_cli(&full_prompt).await?;
+
+        // Extract JSON from response (may have markdown code blocks)
+        let json_str = extract_json(&response_text);
+
+        // Parse response using flexible parser
+        parse_flexible_response(json_str)
+    }
+
+    /// Query the agent in tool-calling mode
+    ///
+    /// `transcript` holds the tool calls and platform responses from earlier
+    /// rounds of the same cycle. Replies that are not valid tool calls are
+    /// rejected rather than interpreted.
+    pub async fn query_tools(
+        &self,
+        prompt: &str,
+        context: &AgentContext,
+        transcript: &[ToolExchange],
+    ) -> Result<AgentToolResponse> {
+        let mut attempts = 0;
+        let mut last_error = None;
+
+        while attempts < self.config.max_retries {
+            attempts += 1;
+
+            match self.execute_tool_query(prompt, context, transcript).await {
+                Ok(response) => return Ok(response),
+                Err(e) => {
+                    warn!("Agent tool query attempt {} failed: {}", attempts, e);
+                    last_error = Some(e);
+                }
+            }
+        }
+
+        Err(last_error.unwrap_or_else(|| {
+            PloyError::Internal("Agent tool query failed with unknown error".to_string())
+        }))
+    }
+
+    async fn execute_tool_query(
+        &self,
+        prompt: &str,
+        context: &AgentContext,
+        transcript: &[ToolExchange],
+    ) -> Result<AgentToolResponse> {
+        let context_json = serde_json::to_string_pretty(context)?;
+        let tools_json = serde_json::to_string_pretty(&tool_schema())?;
+        let transcript_json = serde_json::to_string_pretty(transcript)?;
+        let full_prompt = format!(
+            r#"## Current Trading Context
+
+```json
+{}
+```
+
+## Request
+
+{}
+
+## Tools
+
+You act ONLY through these tools:
+
+```json
+{}
+```
+
+## Previous Tool Calls This Cycle
+
+```json
+{}
+```
+
+## Instructions
+
+Respond with JSON matching this schema:
+{{
+    "reasoning": "Your chain of thought analysis",
+    "confidence": 0.0 to 1.0,
+    "tool_calls": [{{ "tool": "<name>", "args": {{ ... }} }}],
+    "done": true if no further tool calls are needed this cycle,
+    "summary": "Brief summary"
+}}
+
+Respond ONLY with valid JSON."#,
+            context_json, prompt, tools_json, transcript_json
+        );
+
+        let response_text = self.run_cli(&full_prompt).await?;
+        parse_tool_response(extract_json(&response_text))
+    }
+
+    /// Run one `claude --print` call and return stdout
+    async fn run_cli(&self, full_prompt: &str) -> Result<String> {
         // Build command arguments
         let mut cmd = Command::new(&self.config.cli_path);
         cmd.arg("--print")
@@ -228,14 +324,9 @@ Respond ONLY with valid JSON."#,
             )));
         }
 
-        let response_text = String::from_utf8_lossy(&output.stdout);
+        let response_text = String::from_utf8_lossy(&output.stdout).to_string();
         debug!("Raw agent response: {}", response_text);
-
-        // Extract JSON from response (may have markdown code blocks)
-        let json_str = extract_json(&response_text);
-
-        // Parse response using flexible parser
-        parse_flexible_response(json_str)
+        Ok(response_text)
     }
 
     /// Send a simple query without full context
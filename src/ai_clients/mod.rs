@@ -18,6 +18,7 @@ pub mod polymarket_sports;
 pub mod protocol;
 pub mod sports_analyst;
 pub mod sports_data;
+pub mod tools;
 
 pub use advisor::AdvisoryAgent;
 pub use autonomous::{AutonomousAgent, AutonomousConfig};
@@ -38,3 +39,4 @@ pub use protocol::{
 };
 pub use sports_analyst::{SportsAnalysis, SportsAnalysisWithDK, SportsAnalyst};
 pub use sports_data::{SportsDataFetcher, StructuredGameData};
+pub use tools::{AgentToolCall, AgentToolResponse, ToolAuditLog, ToolCallRecord, ToolOutcome};
@@ -0,0 +1,334 @@
+//! Structured tool-calling protocol for the autonomous agent
+//!
+//! Instead of free-text recommendations, Claude must answer with typed tool
+//! calls (`get_positions`, `get_market`, `propose_order`, `cancel_order`).
+//! Calls are parsed strictly — unknown tools or malformed arguments are
+//! rejected — and every call/response pair is written to an audit log.
+
+use chrono::{DateTime, Utc};
+use rust_decimal::Decimal;
+use serde::{Deserialize, Serialize};
+use serde_json::{json, Value};
+use std::collections::VecDeque;
+use std::io::Write;
+use std::path::{Path, PathBuf};
+use std::sync::Mutex;
+use tracing::warn;
+
+use crate::domain::Side;
+use crate::error::{PloyError, Result};
+
+/// Records kept in memory for inspection (the file log is unbounded)
+const RECENT_AUDIT_RECORDS: usize = 200;
+
+/// Arguments of `propose_order`
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[serde(deny_unknown_fields)]
+pub struct ProposedOrder {
+    pub side: Side,
+    /// `false` proposes an exit of an existing position
+    #[serde(default = "default_is_buy")]
+    pub is_buy: bool,
+    /// Required for exits; buys resolve the token from `side`
+    #[serde(default)]
+    pub token_id: Option<String>,
+    pub shares: u64,
+    pub limit_price: Decimal,
+    pub reasoning: String,
+}
+
+fn default_is_buy() -> bool {
+    true
+}
+
+/// Arguments of `cancel_order`
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[serde(deny_unknown_fields)]
+pub struct CancelOrderArgs {
+    pub order_id: String,
+    pub reasoning: String,
+}
+
+/// A typed tool call requested by the agent
+#[derive(Debug, Clone, PartialEq, Serialize)]
+#[serde(tag = "tool", content = "args", rename_all = "snake_case")]
+pub enum AgentToolCall {
+    GetPositions,
+    GetMarket,
+    ProposeOrder(ProposedOrder),
+    CancelOrder(CancelOrderArgs),
+}
+
+impl AgentToolCall {
+    pub fn name(&self) -> &'static str {
+        match self {
+            AgentToolCall::GetPositions => "get_positions",
+            AgentToolCall::GetMarket => "get_market",
+            AgentToolCall::ProposeOrder(_) => "propose_order",
+            AgentToolCall::CancelOrder(_) => "cancel_order",
+        }
+    }
+
+    /// Parse `{"tool": "...", "args": {...}}`
+    pub fn from_value(value: &Value) -> Result<Self> {
+        let tool = value
+            .get("tool")
+            .and_then(Value::as_str)
+            .ok_or_else(|| PloyError::Validation("tool call missing \"tool\"".to_string()))?;
+        let args = value.get("args").cloned().unwrap_or(Value::Null);
+        let no_args = |name: &str| -> Result<()> {
+            match &args {
+                Value::Null => Ok(()),
+                Value::Object(map) if map.is_empty() => Ok(()),
+                _ => Err(PloyError::Validation(format!("{name} takes no arguments"))),
+            }
+        };
+        let parse_err =
+            |name: &str, e: serde_json::Error| PloyError::Validation(format!("{name}: {e}"));
+
+        match tool {
+            "get_positions" => no_args(tool).map(|_| AgentToolCall::GetPositions),
+            "get_market" => no_args(tool).map(|_| AgentToolCall::GetMarket),
+            "propose_order" => serde_json::from_value(args)
+                .map(AgentToolCall::ProposeOrder)
+                .map_err(|e| parse_err(tool, e)),
+            "cancel_order" => serde_json::from_value(args)
+                .map(AgentToolCall::CancelOrder)
+                .map_err(|e| parse_err(tool, e)),
+            other => Err(PloyError::Validation(format!("unknown tool: {other}"))),
+        }
+    }
+}
+
+/// Result returned to the agent for one tool call
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct ToolOutcome {
+    pub ok: bool,
+    pub output: Value,
+}
+
+impl ToolOutcome {
+    pub fn ok(output: Value) -> Self {
+        Self { ok: true, output }
+    }
+
+    pub fn rejected(reason: impl Into<String>) -> Self {
+        Self {
+            ok: false,
+            output: json!({ "error": reason.into() }),
+        }
+    }
+}
+
+/// One call/response pair, fed back to the agent on the next round
+#[derive(Debug, Clone, Serialize)]
+pub struct ToolExchange {
+    pub call: AgentToolCall,
+    pub outcome: ToolOutcome,
+}
+
+/// Parsed agent turn
+#[derive(Debug, Clone)]
+pub struct AgentToolResponse {
+    pub reasoning: String,
+    pub confidence: f64,
+    pub tool_calls: Vec<AgentToolCall>,
+    /// Agent has nothing further to do this cycle
+    pub done: bool,
+    pub summary: String,
+}
+
+#[derive(Debug, Deserialize)]
+struct RawToolResponse {
+    #[serde(default)]
+    reasoning: String,
+    confidence: f64,
+    #[serde(default)]
+    tool_calls: Vec<Value>,
+    #[serde(default)]
+    done: bool,
+    #[serde(default)]
+    summary: String,
+}
+
+/// Parse a turn; any malformed tool call rejects the whole turn
+pub fn parse_tool_response(json_str: &str) -> Result<AgentToolResponse> {
+    let raw: RawToolResponse = serde_json::from_str(json_str)
+        .map_err(|e| PloyError::Validation(format!("invalid tool-call response: {e}")))?;
+    let tool_calls = raw
+        .tool_calls
+        .iter()
+        .map(AgentToolCall::from_value)
+        .collect::<Result<Vec<_>>>()?;
+    Ok(AgentToolResponse {
+        reasoning: raw.reasoning,
+        confidence: raw.confidence,
+        tool_calls,
+        done: raw.done,
+        summary: raw.summary,
+    })
+}
+
+/// Tool definitions given to the agent (JSON-schema inputs)
+pub fn tool_schema() -> Value {
+    json!([
+        {
+            "name": "get_positions",
+            "description": "List open positions (token, side, shares, entry price, PnL).",
+            "input_schema": { "type": "object", "properties": {}, "additionalProperties": false }
+        },
+        {
+            "name": "get_market",
+            "description": "Current market snapshot: token ids, bids/asks, sizes, time remaining.",
+            "input_schema": { "type": "object", "properties": {}, "additionalProperties": false }
+        },
+        {
+            "name": "propose_order",
+            "description": "Propose a limit order. It is checked against agent limits and the \
+                            platform risk gate before anything is executed.",
+            "input_schema": {
+                "type": "object",
+                "properties": {
+                    "side": { "type": "string", "enum": ["UP", "DOWN"] },
+                    "is_buy": { "type": "boolean", "default": true },
+                    "token_id": {
+                        "type": "string",
+                        "description": "Required when is_buy=false (exit an open position)"
+                    },
+                    "shares": { "type": "integer", "minimum": 1 },
+                    "limit_price": { "type": "string", "description": "Decimal in (0, 1)" },
+                    "reasoning": { "type": "string" }
+                },
+                "required": ["side", "shares", "limit_price", "reasoning"],
+                "additionalProperties": false
+            }
+        },
+        {
+            "name": "cancel_order",
+            "description": "Cancel an open order by id.",
+            "input_schema": {
+                "type": "object",
+                "properties": {
+                    "order_id": { "type": "string" },
+                    "reasoning": { "type": "string" }
+                },
+                "required": ["order_id", "reasoning"],
+                "additionalProperties": false
+            }
+        }
+    ])
+}
+
+/// Audit record of one tool call and the platform's response
+#[derive(Debug, Clone, Serialize)]
+pub struct ToolCallRecord {
+    pub ts: DateTime<Utc>,
+    pub agent_id: String,
+    pub market_id: String,
+    pub round: usize,
+    pub call: AgentToolCall,
+    pub outcome: ToolOutcome,
+}
+
+/// Append-only tool call audit log (daily JSONL files plus a bounded in-memory tail)
+#[derive(Debug)]
+pub struct ToolAuditLog {
+    dir: Option<PathBuf>,
+    recent: Mutex<VecDeque<ToolCallRecord>>,
+}
+
+impl ToolAuditLog {
+    /// Log under `dir/YYYY-MM-DD.jsonl`
+    pub fn new(dir: impl Into<PathBuf>) -> Self {
+        Self {
+            dir: Some(dir.into()),
+            recent: Mutex::new(VecDeque::new()),
+        }
+    }
+
+    /// Default location: `$PLOY_STATE_DIR/agent_tool_audit` (or `data/state/...`)
+    pub fn from_env() -> Self {
+        let base = std::env::var("PLOY_STATE_DIR").unwrap_or_else(|_| "data/state".to_string());
+        Self::new(PathBuf::from(base).join("agent_tool_audit"))
+    }
+
+    /// Keep records in memory only
+    pub fn in_memory() -> Self {
+        Self {
+            dir: None,
+            recent: Mutex::new(VecDeque::new()),
+        }
+    }
+
+    pub fn record(&self, record: ToolCallRecord) {
+        if let Some(dir) = &self.dir {
+            if let Err(e) = append_jsonl(dir, &record) {
+                warn!("tool audit log write failed: {}", e);
+            }
+        }
+        if let Ok(mut recent) = self.recent.lock() {
+            if recent.len() >= RECENT_AUDIT_RECORDS {
+                recent.pop_front();
+            }
+            recent.push_back(record);
+        }
+    }
+
+    pub fn recent(&self) -> Vec<ToolCallRecord> {
+        self.recent
+            .lock()
+            .map(|r| r.iter().cloned().collect())
+            .unwrap_or_default()
+    }
+}
+
+fn append_jsonl(dir: &Path, record: &ToolCallRecord) -> Result<()> {
+    std::fs::create_dir_all(dir)?;
+    let path = dir.join(format!("{}.jsonl", record.ts.format("%Y-%m-%d")));
+    let mut file = std::fs::OpenOptions::new()
+        .create(true)
+        .append(true)
+        .open(path)?;
+    writeln!(file, "{}", serde_json::to_string(record)?)?;
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_parse_tool_response_is_strict() {
+        let ok = r#"{
+            "reasoning": "cheap YES",
+            "confidence": 0.8,
+            "tool_calls": [
+                {"tool": "get_positions"},
+                {"tool": "propose_order",
+                 "args": {"side": "UP", "shares": 10, "limit_price": "0.42", "reasoning": "edge"}}
+            ],
+            "done": true,
+            "summary": "buy"
+        }"#;
+        let parsed = parse_tool_response(ok).unwrap();
+        assert_eq!(parsed.tool_calls.len(), 2);
+        assert_eq!(parsed.tool_calls[0], AgentToolCall::GetPositions);
+        match &parsed.tool_calls[1] {
+            AgentToolCall::ProposeOrder(order) => {
+                assert!(order.is_buy);
+                assert_eq!(order.shares, 10);
+                assert_eq!(order.limit_price, Decimal::new(42, 2));
+            }
+            other => panic!("unexpected call {other:?}"),
+        }
+
+        let unknown = r#"{"confidence": 0.9, "tool_calls": [{"tool": "withdraw_all"}]}"#;
+        assert!(parse_tool_response(unknown).is_err());
+        let extra_field = r#"{"confidence": 0.9, "tool_calls": [{"tool": "cancel_order",
+            "args": {"order_id": "1", "reasoning": "x", "force": true}}]}"#;
+        assert!(parse_tool_response(extra_field).is_err());
+        let args_on_read = r#"{"confidence": 0.9, "tool_calls": [{"tool": "get_market",
+            "args": {"market_id": "other"}}]}"#;
+        assert!(parse_tool_response(args_on_read).is_err());
+    }
+}
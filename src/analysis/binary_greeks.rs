@@ -0,0 +1,464 @@
+//! Live Greeks-style sensitivities for binary crypto round positions.
+//!
+//! An up/down round is a digital option on the oracle price struck at the
+//! round's opening price. Under the volatility model used by the strategies
+//! ([`calculate_fair_yes_price`]) the UP token is worth
+//! `N(d)` with `d = buffer / (σ₁₅ · √(τ / 15m))` and `buffer = S / K - 1`,
+//! which gives closed-form sensitivities per share:
+//!
+//! - **delta**: USD change for a +1% move in the underlying
+//! - **theta**: USD change per minute of time passing (spot unchanged)
+//! - **vega**: USD change for a +10% relative increase in σ
+//!
+//! DOWN tokens carry the negated sensitivities. [`GreeksBook`] tracks open
+//! round positions from coordinator fills plus Binance spot and a realized-vol
+//! estimate, and lets `RiskGate` cap exposure in delta terms.
+
+use chrono::{DateTime, Duration, Utc};
+use rust_decimal::prelude::ToPrimitive;
+use serde::{Deserialize, Serialize};
+use std::collections::HashMap;
+use std::sync::RwLock;
+
+use crate::adapters::PriceUpdate;
+use crate::analysis::ToxicityMonitor;
+use crate::domain::Side;
+use crate::platform::{Domain, OrderIntent};
+use crate::strategy::volatility_arb::calculate_fair_yes_price;
+
+/// Reference horizon σ is quoted over (one 15m round)
+const VOL_REFERENCE_SECS: f64 = 900.0;
+/// Relative vol bump used for the vega proxy
+const VEGA_BUMP: f64 = 0.10;
+
+/// Greeks book configuration
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(default)]
+pub struct BinaryGreeksConfig {
+    pub enabled: bool,
+    /// σ per 15 minutes used until the realized estimate warms up
+    pub default_vol_15m: f64,
+    /// Half-life of the EWMA realized-variance estimator (seconds)
+    pub vol_halflife_secs: f64,
+    /// Spot returns are sampled at most this often (seconds)
+    pub vol_sample_secs: f64,
+    /// Floor on time-to-expiry so Greeks stay finite into the close
+    pub min_tau_secs: f64,
+    /// Cap on |net delta| per underlying (USD per 1% move). None disables.
+    pub max_symbol_delta_usd: Option<f64>,
+    /// Cap on the sum of |net delta| across underlyings. None disables.
+    pub max_total_delta_usd: Option<f64>,
+}
+
+impl Default for BinaryGreeksConfig {
+    fn default() -> Self {
+        Self {
+            enabled: false,
+            default_vol_15m: 0.003,
+            vol_halflife_secs: 600.0,
+            vol_sample_secs: 1.0,
+            min_tau_secs: 5.0,
+            max_symbol_delta_usd: None,
+            max_total_delta_usd: None,
+        }
+    }
+}
+
+/// Per-share sensitivities of the UP token
+#[derive(Debug, Clone, Copy, PartialEq, Serialize)]
+pub struct BinaryGreeks {
+    pub fair_value: f64,
+    pub delta: f64,
+    pub theta: f64,
+    pub vega: f64,
+}
+
+impl BinaryGreeks {
+    /// Sensitivities of the token on `side` (DOWN = 1 - UP)
+    pub fn for_side(self, side: Side) -> Self {
+        match side {
+            Side::Up => self,
+            Side::Down => Self {
+                fair_value: 1.0 - self.fair_value,
+                delta: -self.delta,
+                theta: -self.theta,
+                vega: -self.vega,
+            },
+        }
+    }
+}
+
+fn normal_pdf(x: f64) -> f64 {
+    (-0.5 * x * x).exp() / (2.0 * std::f64::consts::PI).sqrt()
+}
+
+/// Closed-form UP-token Greeks for `spot` vs `strike` with `tau_secs` left.
+pub fn up_token_greeks(spot: f64, strike: f64, vol_15m: f64, tau_secs: f64) -> BinaryGreeks {
+    let tau_frac = tau_secs / VOL_REFERENCE_SECS;
+    let buffer = spot / strike - 1.0;
+    let fair_value = calculate_fair_yes_price(buffer, vol_15m, tau_frac);
+    let s = vol_15m * tau_frac.max(0.0).sqrt();
+    if s < 1e-10 || strike <= 0.0 {
+        return BinaryGreeks {
+            fair_value,
+            delta: 0.0,
+            theta: 0.0,
+            vega: 0.0,
+        };
+    }
+    let d = buffer / s;
+    let pdf = normal_pdf(d);
+    BinaryGreeks {
+        fair_value,
+        // dP/dS · S · 1%
+        delta: pdf * (spot / strike) * 0.01 / s,
+        // -dP/dτ per minute
+        theta: pdf * d / (2.0 * tau_secs) * 60.0,
+        // dP/dσ · 10% σ
+        vega: -pdf * d * VEGA_BUMP,
+    }
+}
+
+/// One open round position tracked by the book
+#[derive(Debug, Clone)]
+struct RoundExposure {
+    agent_id: String,
+    market_slug: String,
+    token_id: String,
+    symbol: String,
+    side: Side,
+    shares: f64,
+    strike: f64,
+    end_time: DateTime<Utc>,
+}
+
+/// Greeks of one open position (USD, already scaled by shares)
+#[derive(Debug, Clone, Serialize)]
+pub struct PositionGreeks {
+    pub agent_id: String,
+    pub market_slug: String,
+    pub token_id: String,
+    pub symbol: String,
+    pub side: Side,
+    pub shares: f64,
+    pub spot: f64,
+    pub strike: f64,
+    pub tau_secs: f64,
+    pub vol_15m: f64,
+    pub fair_value: f64,
+    pub delta_usd: f64,
+    pub theta_usd_per_min: f64,
+    pub vega_usd: f64,
+}
+
+#[derive(Debug, Clone)]
+struct SpotState {
+    price: f64,
+    sampled_price: f64,
+    sampled_at: DateTime<Utc>,
+    /// EWMA of squared log returns per second
+    var_per_sec: Option<f64>,
+}
+
+/// Shared book of round positions, spot prices and realized vol
+pub struct GreeksBook {
+    config: BinaryGreeksConfig,
+    spots: RwLock<HashMap<String, SpotState>>,
+    positions: RwLock<HashMap<(String, String), RoundExposure>>,
+}
+
+impl GreeksBook {
+    pub fn new(config: BinaryGreeksConfig) -> Self {
+        Self {
+            config,
+            spots: RwLock::new(HashMap::new()),
+            positions: RwLock::new(HashMap::new()),
+        }
+    }
+
+    pub fn config(&self) -> &BinaryGreeksConfig {
+        &self.config
+    }
+
+    /// Feed a Binance price update.
+    pub fn on_price_update(&self, update: &PriceUpdate) {
+        if let Some(price) = update.price.to_f64() {
+            self.on_spot(&update.symbol, price, update.timestamp);
+        }
+    }
+
+    pub fn on_spot(&self, symbol: &str, price: f64, timestamp: DateTime<Utc>) {
+        if price <= 0.0 {
+            return;
+        }
+        let Ok(mut spots) = self.spots.write() else {
+            return;
+        };
+        let state = spots
+            .entry(symbol.to_ascii_uppercase())
+            .or_insert_with(|| SpotState {
+                price,
+                sampled_price: price,
+                sampled_at: timestamp,
+                var_per_sec: None,
+            });
+        state.price = price;
+
+        let dt = (timestamp - state.sampled_at).num_milliseconds() as f64 / 1000.0;
+        if dt < self.config.vol_sample_secs {
+            return;
+        }
+        let r = (price / state.sampled_price).ln();
+        let sample = r * r / dt;
+        let alpha = 1.0 - (-dt * std::f64::consts::LN_2 / self.config.vol_halflife_secs).exp();
+        state.var_per_sec = Some(match state.var_per_sec {
+            Some(prev) => prev + alpha * (sample - prev),
+            None => sample,
+        });
+        state.sampled_price = price;
+        state.sampled_at = timestamp;
+    }
+
+    pub fn spot(&self, symbol: &str) -> Option<f64> {
+        self.spots
+            .read()
+            .ok()?
+            .get(&symbol.to_ascii_uppercase())
+            .map(|s| s.price)
+    }
+
+    /// σ per 15 minutes (realized estimate, else the configured default)
+    pub fn vol_15m(&self, symbol: &str) -> f64 {
+        self.spots
+            .read()
+            .ok()
+            .and_then(|spots| spots.get(&symbol.to_ascii_uppercase())?.var_per_sec)
+            .filter(|v| *v > 0.0)
+            .map(|v| (v * VOL_REFERENCE_SECS).sqrt())
+            .unwrap_or(self.config.default_vol_15m)
+    }
+
+    /// Underlying, strike and expiry of a crypto round intent, from metadata
+    fn round_terms(intent: &OrderIntent) -> Option<(String, f64, DateTime<Utc>)> {
+        if intent.domain != Domain::Crypto {
+            return None;
+        }
+        let symbol = ToxicityMonitor::symbol_for_intent(intent)?;
+        let strike = ["price_to_beat", "window_start_price"]
+            .iter()
+            .filter_map(|k| intent.metadata.get(*k)?.parse::<f64>().ok())
+            .find(|v| *v > 0.0)?;
+        let parse_ts = |key: &str| {
+            DateTime::parse_from_rfc3339(intent.metadata.get(key)?)
+                .ok()
+                .map(|t| t.with_timezone(&Utc))
+        };
+        let end_time = parse_ts("event_end_time").or_else(|| {
+            let window: i64 = intent.metadata.get("event_window_secs")?.parse().ok()?;
+            Some(parse_ts("event_start_time")? + Duration::seconds(window))
+        })?;
+        Some((symbol, strike, end_time))
+    }
+
+    /// Apply a coordinator fill. Returns false when the intent is not a
+    /// crypto round the book can price (missing strike / expiry).
+    pub fn record_fill(&self, intent: &OrderIntent, filled_shares: u64) -> bool {
+        let key = (intent.agent_id.clone(), intent.token_id.clone());
+        let Ok(mut positions) = self.positions.write() else {
+            return false;
+        };
+        if !intent.is_buy {
+            if let Some(pos) = positions.get_mut(&key) {
+                pos.shares -= filled_shares as f64;
+                if pos.shares <= 0.0 {
+                    positions.remove(&key);
+                }
+                return true;
+            }
+            return false;
+        }
+        let Some((symbol, strike, end_time)) = Self::round_terms(intent) else {
+            return false;
+        };
+        positions
+            .entry(key)
+            .or_insert_with(|| RoundExposure {
+                agent_id: intent.agent_id.clone(),
+                market_slug: intent.market_slug.clone(),
+                token_id: intent.token_id.clone(),
+                symbol,
+                side: intent.side,
+                shares: 0.0,
+                strike,
+                end_time,
+            })
+            .shares += filled_shares as f64;
+        true
+    }
+
+    fn price(&self, pos: &RoundExposure, now: DateTime<Utc>) -> Option<PositionGreeks> {
+        let spot = self.spot(&pos.symbol)?;
+        let vol = self.vol_15m(&pos.symbol);
+        let tau =
+            ((pos.end_time - now).num_milliseconds() as f64 / 1000.0).max(self.config.min_tau_secs);
+        let g = up_token_greeks(spot, pos.strike, vol, tau).for_side(pos.side);
+        Some(PositionGreeks {
+            agent_id: pos.agent_id.clone(),
+            market_slug: pos.market_slug.clone(),
+            token_id: pos.token_id.clone(),
+            symbol: pos.symbol.clone(),
+            side: pos.side,
+            shares: pos.shares,
+            spot,
+            strike: pos.strike,
+            tau_secs: tau,
+            vol_15m: vol,
+            fair_value: g.fair_value,
+            delta_usd: g.delta * pos.shares,
+            theta_usd_per_min: g.theta * pos.shares,
+            vega_usd: g.vega * pos.shares,
+        })
+    }
+
+    /// Greeks of every open, unexpired position with a known spot.
+    /// Expired rounds are dropped from the book.
+    pub fn snapshot(&self, now: DateTime<Utc>) -> Vec<PositionGreeks> {
+        let open: Vec<RoundExposure> = match self.positions.write() {
+            Ok(mut positions) => {
+                positions.retain(|_, p| p.end_time > now);
+                positions.values().cloned().collect()
+            }
+            Err(_) => return Vec::new(),
+        };
+        open.iter().filter_map(|p| self.price(p, now)).collect()
+    }
+
+    /// Net delta (USD per 1% move) by underlying
+    pub fn net_delta_by_symbol(&self, now: DateTime<Utc>) -> HashMap<String, f64> {
+        let mut out = HashMap::new();
+        for g in self.snapshot(now) {
+            *out.entry(g.symbol).or_insert(0.0) += g.delta_usd;
+        }
+        out
+    }
+
+    /// Delta a BUY intent would add, with its underlying
+    pub fn intent_delta(&self, intent: &OrderIntent, now: DateTime<Utc>) -> Option<(String, f64)> {
+        let (symbol, strike, end_time) = Self::round_terms(intent)?;
+        let pos = RoundExposure {
+            agent_id: intent.agent_id.clone(),
+            market_slug: intent.market_slug.clone(),
+            token_id: intent.token_id.clone(),
+            symbol,
+            side: intent.side,
+            shares: intent.shares as f64,
+            strike,
+            end_time,
+        };
+        let g = self.price(&pos, now)?;
+        Some((g.symbol, g.delta_usd))
+    }
+
+    /// First delta cap a BUY intent would breach: (scope, limit, current, requested).
+    pub fn check_intent(
+        &self,
+        intent: &OrderIntent,
+        now: DateTime<Utc>,
+    ) -> Option<(String, f64, f64, f64)> {
+        if !self.config.enabled || !intent.is_buy {
+            return None;
+        }
+        let (symbol, added) = self.intent_delta(intent, now)?;
+        let net = self.net_delta_by_symbol(now);
+        let current = net.get(&symbol).copied().unwrap_or(0.0);
+
+        if let Some(limit) = self.config.max_symbol_delta_usd {
+            // Orders that shrink |delta| are always allowed.
+            if (current + added).abs() > limit && (current + added).abs() > current.abs() {
+                return Some((symbol, limit, current, added));
+            }
+        }
+        if let Some(limit) = self.config.max_total_delta_usd {
+            let total: f64 = net.values().map(|d| d.abs()).sum();
+            let after = total - current.abs() + (current + added).abs();
+            if after > limit && after > total {
+                return Some(("TOTAL".to_string(), limit, total, after - total));
+            }
+        }
+        None
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use chrono::TimeZone;
+    use rust_decimal_macros::dec;
+
+    #[test]
+    fn up_greeks_have_expected_signs_and_down_mirrors_them() {
+        // Spot above strike with 5 minutes left.
+        let up = up_token_greeks(100_100.0, 100_000.0, 0.003, 300.0);
+        assert!(up.fair_value > 0.5);
+        assert!(up.delta > 0.0);
+        // In the money: time passing pulls UP toward 1, more vol pushes it back.
+        assert!(up.theta > 0.0);
+        assert!(up.vega < 0.0);
+
+        let down = up.for_side(Side::Down);
+        assert!((up.fair_value + down.fair_value - 1.0).abs() < 1e-12);
+        assert_eq!(down.delta, -up.delta);
+
+        // Delta matches a finite difference of the pricing model.
+        let bumped = up_token_greeks(100_100.0 * 1.0001, 100_000.0, 0.003, 300.0);
+        let fd = (bumped.fair_value - up.fair_value) * 100.0;
+        assert!(
+            (fd - up.delta).abs() / up.delta < 0.05,
+            "fd={fd} delta={}",
+            up.delta
+        );
+    }
+
+    #[test]
+    fn book_tracks_fills_and_caps_symbol_delta() {
+        let now = Utc.with_ymd_and_hms(2026, 3, 1, 12, 0, 0).unwrap();
+        let book = GreeksBook::new(BinaryGreeksConfig {
+            enabled: true,
+            max_symbol_delta_usd: Some(500.0),
+            ..BinaryGreeksConfig::default()
+        });
+        book.on_spot("BTCUSDT", 100_000.0, now);
+
+        let intent = |side: Side, shares: u64| {
+            OrderIntent::new(
+                "crypto",
+                Domain::Crypto,
+                "btc-updown-15m",
+                format!("tok-{side:?}"),
+                side,
+                true,
+                shares,
+                dec!(0.5),
+            )
+            .with_metadata("symbol", "BTCUSDT")
+            .with_metadata("price_to_beat", "100000")
+            .with_metadata("event_end_time", "2026-03-01T12:10:00Z")
+        };
+
+        // ATM with 10 minutes left: ~1.63 USD per share per 1% move.
+        assert!(book.record_fill(&intent(Side::Up, 200), 200));
+        let net = book.net_delta_by_symbol(now)["BTCUSDT"];
+        assert!(net > 300.0 && net < 350.0, "net={net}");
+
+        // Adding more UP breaches the cap; buying DOWN reduces delta and passes.
+        let blocked = book.check_intent(&intent(Side::Up, 200), now);
+        assert!(matches!(blocked, Some((ref s, ..)) if s == "BTCUSDT"));
+        assert!(book.check_intent(&intent(Side::Down, 200), now).is_none());
+
+        // Selling flattens the book; expired rounds drop out.
+        let mut sell = intent(Side::Up, 200);
+        sell.is_buy = false;
+        assert!(book.record_fill(&sell, 200));
+        assert!(book.snapshot(now).is_empty());
+    }
+}
@@ -1,4 +1,25 @@
-//! Offline analysis utilities (backtests, parameter sweeps, calibration).
+//! Analysis utilities: offline backtests / parameter sweeps / calibration,
+//! anti-overfit checks, and streaming market-microstructure estimators (flow
+//! toxicity, round surface, binary position Greeks).
 
+pub mod binary_greeks;
+pub mod overfit;
 pub mod pattern_memory_backtest;
+pub mod round_surface;
 pub mod updown_backtest;
+pub mod vpin;
+
+pub use binary_greeks::{
+    up_token_greeks, BinaryGreeks, BinaryGreeksConfig, GreeksBook, PositionGreeks,
+};
+pub use overfit::{
+    evaluate_overfit, CpcvConfig, OverfitConfig, OverfitReport, OverfitTrade, ParameterTrial,
+};
+pub use round_surface::{
+    CalendarArbSignal, RoundSurface, RoundSurfaceConfig, SurfaceInconsistency, SurfacePoint,
+    VarianceInversion,
+};
+
+pub use vpin::{
+    ToxicityLevel, ToxicityMonitor, ToxicitySnapshot, VpinConfig, VpinEstimator, VpinSymbolConfig,
+};
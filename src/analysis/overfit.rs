@@ -0,0 +1,552 @@
+//! Anti-overfit diagnostics for backtest trade lists.
+//!
+//! - Deflated Sharpe ratio (Bailey & López de Prado): probability that the
+//!   per-trade Sharpe beats the best Sharpe `n` unskilled trials would be
+//!   expected to reach, corrected for skew and fat tails.
+//! - Parameter-neighborhood robustness: how much of the selected
+//!   configuration's score survives at nearby parameter values. A lone peak
+//!   surrounded by losers is a fitted artefact.
+//! - Combinatorial purged cross-validation (CPCV): trades are split into
+//!   time-ordered groups and every choice of test groups is scored
+//!   out-of-sample, purging training trades that overlap a test group and
+//!   embargoing the ones right after it. With several trials, the share of
+//!   splits where the in-sample winner lands below the out-of-sample median is
+//!   the probability of backtest overfitting (PBO).
+//!
+//! [`evaluate_overfit`] runs all three and is what promotion evidence carries.
+
+use chrono::{DateTime, Duration, Utc};
+use serde::{Deserialize, Serialize};
+
+use crate::strategy::volatility::normal_cdf;
+
+const EULER_GAMMA: f64 = 0.577_215_664_901_532_9;
+/// Upper bound on CPCV groups; the split count grows combinatorially.
+const MAX_CPCV_GROUPS: usize = 16;
+
+/// One closed trade as seen by the overfit checks.
+#[derive(Debug, Clone, Copy, PartialEq, Serialize, Deserialize)]
+pub struct OverfitTrade {
+    pub entry_time: DateTime<Utc>,
+    pub exit_time: DateTime<Utc>,
+    pub pnl: f64,
+}
+
+/// One backtested parameter setting and the trades it produced.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct ParameterTrial {
+    /// Parameter values, same order for every trial
+    pub params: Vec<f64>,
+    pub trades: Vec<OverfitTrade>,
+}
+
+/// CPCV split settings.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(default)]
+pub struct CpcvConfig {
+    /// Time-ordered groups the trades are split into
+    pub groups: usize,
+    /// Groups held out per split
+    pub test_groups: usize,
+    /// Embargo after each test group, as a fraction of the full time span
+    pub embargo_pct: f64,
+}
+
+impl Default for CpcvConfig {
+    fn default() -> Self {
+        Self {
+            groups: 6,
+            test_groups: 2,
+            embargo_pct: 0.01,
+        }
+    }
+}
+
+/// Pass thresholds for [`evaluate_overfit`].
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(default)]
+pub struct OverfitConfig {
+    /// Minimum deflated Sharpe probability
+    pub min_deflated_sharpe: f64,
+    /// Relative distance per parameter that counts as a neighbor
+    pub neighborhood_radius: f64,
+    /// Minimum neighbor mean score / selected score
+    pub min_neighborhood_ratio: f64,
+    /// Maximum probability of backtest overfitting
+    pub max_pbo: f64,
+    /// Minimum share of CPCV splits with a positive out-of-sample Sharpe
+    pub min_oos_positive_fraction: f64,
+    pub cpcv: CpcvConfig,
+}
+
+impl Default for OverfitConfig {
+    fn default() -> Self {
+        Self {
+            min_deflated_sharpe: 0.95,
+            neighborhood_radius: 0.25,
+            min_neighborhood_ratio: 0.5,
+            max_pbo: 0.5,
+            min_oos_positive_fraction: 0.5,
+            cpcv: CpcvConfig::default(),
+        }
+    }
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct DeflatedSharpe {
+    /// Per-trade (non-annualized) Sharpe
+    pub sharpe: f64,
+    pub observations: usize,
+    pub trials: usize,
+    /// Sharpe the best of `trials` unskilled configurations would reach
+    pub expected_max_sharpe: f64,
+    /// P(true Sharpe > expected_max_sharpe)
+    pub probability: f64,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct NeighborhoodRobustness {
+    pub selected_score: f64,
+    pub neighbors: usize,
+    pub neighbor_mean: f64,
+    pub neighbor_min: f64,
+    /// Neighbor mean / selected score (0 when the selected score is not positive)
+    pub ratio: f64,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct CpcvResult {
+    pub splits: usize,
+    /// Out-of-sample per-trade Sharpe of the selected trial, averaged over splits
+    pub oos_sharpe_mean: f64,
+    pub oos_sharpe_min: f64,
+    pub oos_positive_fraction: f64,
+    /// Probability of backtest overfitting; needs at least two trials
+    pub pbo: Option<f64>,
+}
+
+/// Scores attached to backtest evidence for the promotion gate.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct OverfitReport {
+    pub deflated_sharpe: DeflatedSharpe,
+    pub neighborhood: Option<NeighborhoodRobustness>,
+    pub cpcv: Option<CpcvResult>,
+    pub passed: bool,
+    /// Human-readable reasons for a failed check
+    pub failures: Vec<String>,
+}
+
+/// Per-trade Sharpe (sample standard deviation); 0 for fewer than two trades.
+pub fn per_trade_sharpe(pnls: &[f64]) -> f64 {
+    if pnls.len() < 2 {
+        return 0.0;
+    }
+    let n = pnls.len() as f64;
+    let mean = pnls.iter().sum::<f64>() / n;
+    let variance = pnls.iter().map(|p| (p - mean).powi(2)).sum::<f64>() / (n - 1.0);
+    let std_dev = variance.sqrt();
+    if std_dev < 1e-12 {
+        0.0
+    } else {
+        mean / std_dev
+    }
+}
+
+/// Skewness and (non-excess) kurtosis; normal values when undefined.
+fn higher_moments(pnls: &[f64]) -> (f64, f64) {
+    let n = pnls.len() as f64;
+    if pnls.len() < 3 {
+        return (0.0, 3.0);
+    }
+    let mean = pnls.iter().sum::<f64>() / n;
+    let m2 = pnls.iter().map(|p| (p - mean).powi(2)).sum::<f64>() / n;
+    if m2 < 1e-24 {
+        return (0.0, 3.0);
+    }
+    let m3 = pnls.iter().map(|p| (p - mean).powi(3)).sum::<f64>() / n;
+    let m4 = pnls.iter().map(|p| (p - mean).powi(4)).sum::<f64>() / n;
+    (m3 / m2.powf(1.5), m4 / (m2 * m2))
+}
+
+/// Inverse standard normal CDF (Acklam's rational approximation).
+fn inverse_normal_cdf(p: f64) -> f64 {
+    const A: [f64; 6] = [
+        -3.969_683_028_665_376e1,
+        2.209_460_984_245_205e2,
+        -2.759_285_104_469_687e2,
+        1.383_577_518_672_69e2,
+        -3.066_479_806_614_716e1,
+        2.506_628_277_459_239,
+    ];
+    const B: [f64; 5] = [
+        -5.447_609_879_822_406e1,
+        1.615_858_368_580_409e2,
+        -1.556_989_798_598_866e2,
+        6.680_131_188_771_972e1,
+        -1.328_068_155_288_572e1,
+    ];
+    const C: [f64; 6] = [
+        -7.784_894_002_430_293e-3,
+        -3.223_964_580_411_365e-1,
+        -2.400_758_277_161_838,
+        -2.549_732_539_343_734,
+        4.374_664_141_464_968,
+        2.938_163_982_698_783,
+    ];
+    const D: [f64; 4] = [
+        7.784_695_709_041_462e-3,
+        3.224_671_290_700_398e-1,
+        2.445_134_137_142_996,
+        3.754_408_661_907_416,
+    ];
+    const P_LOW: f64 = 0.02425;
+
+    let p = p.clamp(1e-12, 1.0 - 1e-12);
+    if p < P_LOW {
+        let q = (-2.0 * p.ln()).sqrt();
+        (((((C[0] * q + C[1]) * q + C[2]) * q + C[3]) * q + C[4]) * q + C[5])
+            / ((((D[0] * q + D[1]) * q + D[2]) * q + D[3]) * q + 1.0)
+    } else if p <= 1.0 - P_LOW {
+        let q = p - 0.5;
+        let r = q * q;
+        (((((A[0] * r + A[1]) * r + A[2]) * r + A[3]) * r + A[4]) * r + A[5]) * q
+            / (((((B[0] * r + B[1]) * r + B[2]) * r + B[3]) * r + B[4]) * r + 1.0)
+    } else {
+        -inverse_normal_cdf(1.0 - p)
+    }
+}
+
+/// Deflated Sharpe ratio of a trade list selected out of `trials` configurations.
+///
+/// `trial_sharpe_variance` is the variance of per-trade Sharpe across the
+/// trials; when unknown, the sampling variance of the estimator (`1/T`) is used.
+pub fn deflated_sharpe(
+    pnls: &[f64],
+    trials: usize,
+    trial_sharpe_variance: Option<f64>,
+) -> DeflatedSharpe {
+    let observations = pnls.len();
+    let sharpe = per_trade_sharpe(pnls);
+    let trials = trials.max(1);
+
+    let expected_max_sharpe = if trials > 1 && observations > 0 {
+        let variance = trial_sharpe_variance
+            .filter(|v| v.is_finite() && *v > 0.0)
+            .unwrap_or(1.0 / observations as f64);
+        let n = trials as f64;
+        variance.sqrt()
+            * ((1.0 - EULER_GAMMA) * inverse_normal_cdf(1.0 - 1.0 / n)
+                + EULER_GAMMA * inverse_normal_cdf(1.0 - 1.0 / (n * std::f64::consts::E)))
+    } else {
+        0.0
+    };
+
+    let probability = if observations < 2 {
+        0.0
+    } else {
+        let (skew, kurtosis) = higher_moments(pnls);
+        let denom = 1.0 - skew * sharpe + (kurtosis - 1.0) / 4.0 * sharpe * sharpe;
+        if denom <= 0.0 {
+            0.0
+        } else {
+            let z =
+                (sharpe - expected_max_sharpe) * ((observations - 1) as f64).sqrt() / denom.sqrt();
+            normal_cdf(z)
+        }
+    };
+
+    DeflatedSharpe {
+        sharpe,
+        observations,
+        trials,
+        expected_max_sharpe,
+        probability,
+    }
+}
+
+/// Compare the selected trial's score with trials within `radius` (relative,
+/// per parameter). Returns `None` when no trial is close enough.
+pub fn neighborhood_robustness(
+    params: &[Vec<f64>],
+    scores: &[f64],
+    selected: usize,
+    radius: f64,
+) -> Option<NeighborhoodRobustness> {
+    let center = params.get(selected)?;
+    let selected_score = *scores.get(selected)?;
+
+    let neighbor_scores: Vec<f64> = params
+        .iter()
+        .zip(scores)
+        .enumerate()
+        .filter(|(i, (p, _))| {
+            *i != selected
+                && p.len() == center.len()
+                && p.iter()
+                    .zip(center)
+                    .all(|(v, c)| (v - c).abs() <= radius * c.abs().max(1e-9) * (1.0 + 1e-9))
+        })
+        .map(|(_, (_, score))| *score)
+        .collect();
+    if neighbor_scores.is_empty() {
+        return None;
+    }
+
+    let neighbor_mean = neighbor_scores.iter().sum::<f64>() / neighbor_scores.len() as f64;
+    let neighbor_min = neighbor_scores
+        .iter()
+        .copied()
+        .fold(f64::INFINITY, f64::min);
+    Some(NeighborhoodRobustness {
+        selected_score,
+        neighbors: neighbor_scores.len(),
+        neighbor_mean,
+        neighbor_min,
+        ratio: if selected_score > 0.0 {
+            neighbor_mean / selected_score
+        } else {
+            0.0
+        },
+    })
+}
+
+/// All `k`-subsets of `0..n`, in lexicographic order.
+fn combinations(n: usize, k: usize) -> Vec<Vec<usize>> {
+    let mut out = Vec::new();
+    if k == 0 || k > n {
+        return out;
+    }
+    let mut idx: Vec<usize> = (0..k).collect();
+    loop {
+        out.push(idx.clone());
+        let Some(pos) = (0..k).rev().find(|&i| idx[i] != i + n - k) else {
+            return out;
+        };
+        idx[pos] += 1;
+        let base = idx[pos];
+        for (offset, slot) in idx[pos + 1..].iter_mut().enumerate() {
+            *slot = base + offset + 1;
+        }
+    }
+}
+
+/// Run CPCV over every trial's trades, scoring `selected` out-of-sample.
+/// Returns `None` when there are fewer trades than groups.
+pub fn cpcv(trials: &[ParameterTrial], selected: usize, config: &CpcvConfig) -> Option<CpcvResult> {
+    let groups = config.groups.clamp(2, MAX_CPCV_GROUPS);
+    let test_groups = config.test_groups.clamp(1, groups - 1);
+    trials.get(selected)?;
+
+    // Group boundaries are equal-count quantiles of all entry times, so every
+    // trial is split on the same calendar.
+    let mut entries: Vec<DateTime<Utc>> = trials
+        .iter()
+        .flat_map(|t| t.trades.iter().map(|trade| trade.entry_time))
+        .collect();
+    if entries.len() < groups {
+        return None;
+    }
+    entries.sort();
+    let first = entries[0];
+    let last = entries[entries.len() - 1];
+    let mut bounds: Vec<DateTime<Utc>> = (0..groups)
+        .map(|g| entries[g * entries.len() / groups])
+        .collect();
+    bounds.push(last + Duration::nanoseconds(1));
+    let group_of = |ts: DateTime<Utc>| bounds[1..groups].iter().filter(|b| **b <= ts).count();
+    let embargo_ms =
+        ((last - first).num_milliseconds() as f64 * config.embargo_pct.max(0.0)) as i64;
+    let embargo = Duration::milliseconds(embargo_ms);
+
+    let mut oos_sharpes = Vec::new();
+    let mut overfit_splits = 0usize;
+    for test in combinations(groups, test_groups) {
+        let intervals: Vec<(DateTime<Utc>, DateTime<Utc>)> =
+            test.iter().map(|g| (bounds[*g], bounds[*g + 1])).collect();
+        let mut is_scores = Vec::with_capacity(trials.len());
+        let mut oos_scores = Vec::with_capacity(trials.len());
+
+        for trial in trials {
+            let mut train = Vec::new();
+            let mut held_out = Vec::new();
+            for trade in &trial.trades {
+                if test.contains(&group_of(trade.entry_time)) {
+                    held_out.push(trade.pnl);
+                    continue;
+                }
+                let purged = intervals.iter().any(|(lo, hi)| {
+                    (trade.entry_time < *hi && trade.exit_time >= *lo)
+                        || (trade.entry_time >= *hi && trade.entry_time < *hi + embargo)
+                });
+                if !purged {
+                    train.push(trade.pnl);
+                }
+            }
+            is_scores.push(per_trade_sharpe(&train));
+            oos_scores.push(per_trade_sharpe(&held_out));
+        }
+
+        oos_sharpes.push(oos_scores[selected]);
+        if trials.len() > 1 {
+            let best = is_scores
+                .iter()
+                .enumerate()
+                .max_by(|a, b| a.1.total_cmp(b.1))
+                .map(|(i, _)| i)
+                .unwrap_or(0);
+            // Mid-rank of the in-sample winner among out-of-sample scores; below
+            // the median means the in-sample pick did not carry over.
+            let below = oos_scores.iter().filter(|s| **s < oos_scores[best]).count();
+            let ties = oos_scores
+                .iter()
+                .filter(|s| **s == oos_scores[best])
+                .count()
+                - 1;
+            let rank = 1.0 + below as f64 + ties as f64 / 2.0;
+            if rank / ((trials.len() + 1) as f64) < 0.5 {
+                overfit_splits += 1;
+            }
+        }
+    }
+
+    let splits = oos_sharpes.len();
+    if splits == 0 {
+        return None;
+    }
+    Some(CpcvResult {
+        splits,
+        oos_sharpe_mean: oos_sharpes.iter().sum::<f64>() / splits as f64,
+        oos_sharpe_min: oos_sharpes.iter().copied().fold(f64::INFINITY, f64::min),
+        oos_positive_fraction: oos_sharpes.iter().filter(|s| **s > 0.0).count() as f64
+            / splits as f64,
+        pbo: (trials.len() > 1).then(|| overfit_splits as f64 / splits as f64),
+    })
+}
+
+/// Score trial `selected` against every other trial and the configured thresholds.
+pub fn evaluate_overfit(
+    trials: &[ParameterTrial],
+    selected: usize,
+    config: &OverfitConfig,
+) -> OverfitReport {
+    let scores: Vec<f64> = trials
+        .iter()
+        .map(|t| per_trade_sharpe(&t.trades.iter().map(|x| x.pnl).collect::<Vec<_>>()))
+        .collect();
+    let trial_sharpe_variance = (scores.len() > 1).then(|| {
+        let n = scores.len() as f64;
+        let mean = scores.iter().sum::<f64>() / n;
+        scores.iter().map(|s| (s - mean).powi(2)).sum::<f64>() / (n - 1.0)
+    });
+    let selected_pnls: Vec<f64> = trials
+        .get(selected)
+        .map(|t| t.trades.iter().map(|x| x.pnl).collect())
+        .unwrap_or_default();
+
+    let deflated_sharpe = deflated_sharpe(&selected_pnls, trials.len(), trial_sharpe_variance);
+    let params: Vec<Vec<f64>> = trials.iter().map(|t| t.params.clone()).collect();
+    let neighborhood =
+        neighborhood_robustness(&params, &scores, selected, config.neighborhood_radius);
+    let cpcv = cpcv(trials, selected, &config.cpcv);
+
+    let mut failures = Vec::new();
+    if deflated_sharpe.probability < config.min_deflated_sharpe {
+        failures.push(format!(
+            "deflated Sharpe {:.3} < {:.3}",
+            deflated_sharpe.probability, config.min_deflated_sharpe
+        ));
+    }
+    match &neighborhood {
+        Some(n) if n.ratio < config.min_neighborhood_ratio => failures.push(format!(
+            "neighborhood ratio {:.3} < {:.3}",
+            n.ratio, config.min_neighborhood_ratio
+        )),
+        Some(_) => {}
+        None => failures.push("no parameter neighbors evaluated".to_string()),
+    }
+    match &cpcv {
+        Some(c) => {
+            if c.oos_positive_fraction < config.min_oos_positive_fraction {
+                failures.push(format!(
+                    "CPCV positive OOS fraction {:.3} < {:.3}",
+                    c.oos_positive_fraction, config.min_oos_positive_fraction
+                ));
+            }
+            if let Some(pbo) = c.pbo.filter(|pbo| *pbo > config.max_pbo) {
+                failures.push(format!("PBO {:.3} > {:.3}", pbo, config.max_pbo));
+            }
+        }
+        None => failures.push("too few trades for CPCV".to_string()),
+    }
+
+    OverfitReport {
+        deflated_sharpe,
+        neighborhood,
+        cpcv,
+        passed: failures.is_empty(),
+        failures,
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn trades(pnls: &[f64]) -> Vec<OverfitTrade> {
+        let start = DateTime::from_timestamp(1_700_000_000, 0).unwrap();
+        pnls.iter()
+            .enumerate()
+            .map(|(i, pnl)| OverfitTrade {
+                entry_time: start + Duration::minutes(15 * i as i64),
+                exit_time: start + Duration::minutes(15 * i as i64 + 10),
+                pnl: *pnl,
+            })
+            .collect()
+    }
+
+    #[test]
+    fn test_deflated_sharpe_penalizes_many_trials() {
+        assert!((inverse_normal_cdf(0.975) - 1.959_964).abs() < 1e-4);
+
+        let pnls: Vec<f64> = (0..200)
+            .map(|i| if i % 3 == 0 { -1.0 } else { 1.0 })
+            .collect();
+        let single = deflated_sharpe(&pnls, 1, None);
+        let searched = deflated_sharpe(&pnls, 500, Some(0.05));
+        assert!(single.probability > 0.99);
+        assert!(searched.expected_max_sharpe > 0.5);
+        assert!(searched.probability < single.probability);
+        assert!(searched.probability < 0.5);
+    }
+
+    #[test]
+    fn test_robust_edge_passes_and_lone_peak_fails() {
+        let edge: Vec<f64> = (0..120)
+            .map(|i| if i % 4 == 0 { -1.0 } else { 1.0 })
+            .collect();
+        let noise: Vec<f64> = (0..120)
+            .map(|i| if i % 2 == 0 { -1.0 } else { 1.05 })
+            .collect();
+        let grid = |center: &[f64], others: &[f64]| -> Vec<ParameterTrial> {
+            [0.8, 1.0, 1.2]
+                .iter()
+                .map(|scale| ParameterTrial {
+                    params: vec![*scale],
+                    trades: trades(if *scale == 1.0 { center } else { others }),
+                })
+                .collect()
+        };
+        let config = OverfitConfig::default();
+
+        let robust = evaluate_overfit(&grid(&edge, &edge), 1, &config);
+        assert!(robust.passed, "{:?}", robust.failures);
+        assert_eq!(robust.neighborhood.as_ref().unwrap().neighbors, 2);
+        let cpcv = robust.cpcv.as_ref().unwrap();
+        assert_eq!(cpcv.splits, 15);
+        assert_eq!(cpcv.oos_positive_fraction, 1.0);
+
+        let peak = evaluate_overfit(&grid(&edge, &noise), 1, &config);
+        assert!(!peak.passed);
+        assert!(peak.neighborhood.unwrap().ratio < 0.5);
+    }
+}
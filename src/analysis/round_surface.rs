@@ -0,0 +1,507 @@
+//! Implied probability surface across concurrent rounds of one underlying.
+//!
+//! 5m/15m/1h up/down rounds on the same asset are all digital options on the
+//! same oracle price, struck at each round's opening price. Collecting them
+//! gives a term structure of implied probabilities (and, with the spot price,
+//! implied volatilities) that must be internally consistent:
+//!
+//! - **Co-terminal rounds** (same settlement instant, strikes `K_lo <= K_hi`):
+//!   `UP(K_lo)` pays when `S >= K_lo`, `DOWN(K_hi)` pays when `S < K_hi`, so at
+//!   least one of them always pays. Buying both for less than $1 is a
+//!   model-free calendar arbitrage.
+//! - **Different horizons**: implied total variance `σ²τ` should not shrink as
+//!   the horizon grows. Inversions are reported as diagnostics only, since
+//!   strikes differ and the smile is not modelled.
+//!
+//! [`crate::strategy::calendar_arb::CalendarArbStrategy`] consumes the signals.
+
+use chrono::{DateTime, Utc};
+use rust_decimal::prelude::ToPrimitive;
+use rust_decimal::Decimal;
+use serde::{Deserialize, Serialize};
+use std::collections::HashMap;
+
+use crate::domain::{Quote, Side};
+use crate::strategy::volatility_arb::calculate_implied_volatility;
+
+/// Reference horizon the implied vol is quoted over (one 15m round)
+const VOL_REFERENCE_SECS: f64 = 900.0;
+
+/// Surface consistency thresholds
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(default)]
+pub struct RoundSurfaceConfig {
+    /// Rounds settling within this many seconds of each other are co-terminal
+    pub coterminal_tolerance_secs: i64,
+    /// Minimum locked-in edge (1 - cost) for a co-terminal arbitrage
+    pub min_edge: Decimal,
+    /// Quotes older than this are ignored
+    pub max_quote_age_secs: i64,
+    /// Relative total-variance inversion tolerated between horizons
+    pub variance_tolerance: f64,
+    /// Skip implied vol when |spot / strike - 1| is below this (ATM is uninformative)
+    pub min_buffer_pct: f64,
+}
+
+impl Default for RoundSurfaceConfig {
+    fn default() -> Self {
+        Self {
+            coterminal_tolerance_secs: 1,
+            min_edge: Decimal::new(1, 2), // 1¢
+            max_quote_age_secs: 10,
+            variance_tolerance: 0.5,
+            min_buffer_pct: 0.0002,
+        }
+    }
+}
+
+/// One live round on the surface
+#[derive(Debug, Clone)]
+pub struct SurfaceRound {
+    pub event_id: String,
+    /// Opening price the round settles against
+    pub strike: Decimal,
+    pub end_time: DateTime<Utc>,
+    pub up_token: String,
+    pub down_token: String,
+    pub up_quote: Option<Quote>,
+    pub down_quote: Option<Quote>,
+}
+
+impl SurfaceRound {
+    fn fresh_quote(&self, side: Side, now: DateTime<Utc>, max_age_secs: i64) -> Option<&Quote> {
+        let quote = match side {
+            Side::Up => self.up_quote.as_ref(),
+            Side::Down => self.down_quote.as_ref(),
+        }?;
+        ((now - quote.timestamp).num_seconds() <= max_age_secs).then_some(quote)
+    }
+
+    /// Implied P(UP): UP mid, else 1 - DOWN mid
+    fn prob_up(&self, now: DateTime<Utc>, max_age_secs: i64) -> Option<Decimal> {
+        self.fresh_quote(Side::Up, now, max_age_secs)
+            .and_then(|q| q.mid_price())
+            .or_else(|| {
+                self.fresh_quote(Side::Down, now, max_age_secs)
+                    .and_then(|q| q.mid_price())
+                    .map(|mid| Decimal::ONE - mid)
+            })
+    }
+}
+
+/// One point of the term structure
+#[derive(Debug, Clone, Serialize)]
+pub struct SurfacePoint {
+    pub event_id: String,
+    pub end_time: DateTime<Utc>,
+    pub tau_secs: i64,
+    pub strike: Decimal,
+    pub prob_up: Decimal,
+    /// Implied vol per 15 minutes, when spot is known and the round is not ATM
+    pub implied_vol: Option<f64>,
+    /// σ² · τ / 15m
+    pub total_variance: Option<f64>,
+}
+
+/// Model-free arbitrage between two co-terminal rounds
+#[derive(Debug, Clone, Serialize)]
+pub struct CalendarArbSignal {
+    pub symbol: String,
+    /// Round bought UP (lower or equal strike)
+    pub up_event_id: String,
+    pub up_token: String,
+    pub up_ask: Decimal,
+    /// Round bought DOWN (higher or equal strike)
+    pub down_event_id: String,
+    pub down_token: String,
+    pub down_ask: Decimal,
+    /// up_ask + down_ask
+    pub cost: Decimal,
+    /// Minimum payoff per pair minus cost
+    pub edge: Decimal,
+    pub end_time: DateTime<Utc>,
+    pub detected_at: DateTime<Utc>,
+}
+
+impl CalendarArbSignal {
+    /// Stable identifier for the round pair
+    pub fn pair_key(&self) -> String {
+        format!("{}|{}", self.up_event_id, self.down_event_id)
+    }
+}
+
+/// Longer horizon implying less total variance than a shorter one
+#[derive(Debug, Clone, Serialize)]
+pub struct VarianceInversion {
+    pub symbol: String,
+    pub short_event_id: String,
+    pub short_tau_secs: i64,
+    pub short_variance: f64,
+    pub long_event_id: String,
+    pub long_tau_secs: i64,
+    pub long_variance: f64,
+}
+
+impl VarianceInversion {
+    pub fn pair_key(&self) -> String {
+        format!("{}|{}", self.short_event_id, self.long_event_id)
+    }
+}
+
+/// Inconsistency found on the surface
+#[derive(Debug, Clone, Serialize)]
+#[serde(tag = "kind", rename_all = "snake_case")]
+pub enum SurfaceInconsistency {
+    CoTerminal(CalendarArbSignal),
+    VarianceInversion(VarianceInversion),
+}
+
+/// Term structure of implied probabilities for one underlying
+#[derive(Debug, Clone)]
+pub struct RoundSurface {
+    symbol: String,
+    config: RoundSurfaceConfig,
+    rounds: HashMap<String, SurfaceRound>,
+    /// token_id -> (event_id, side)
+    tokens: HashMap<String, (String, Side)>,
+}
+
+impl RoundSurface {
+    pub fn new(symbol: impl Into<String>, config: RoundSurfaceConfig) -> Self {
+        Self {
+            symbol: symbol.into(),
+            config,
+            rounds: HashMap::new(),
+            tokens: HashMap::new(),
+        }
+    }
+
+    pub fn symbol(&self) -> &str {
+        &self.symbol
+    }
+
+    pub fn len(&self) -> usize {
+        self.rounds.len()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.rounds.is_empty()
+    }
+
+    pub fn round(&self, event_id: &str) -> Option<&SurfaceRound> {
+        self.rounds.get(event_id)
+    }
+
+    /// Add (or replace) a round
+    pub fn upsert_round(
+        &mut self,
+        event_id: &str,
+        strike: Decimal,
+        end_time: DateTime<Utc>,
+        up_token: &str,
+        down_token: &str,
+    ) {
+        self.remove_round(event_id);
+        self.tokens
+            .insert(up_token.to_string(), (event_id.to_string(), Side::Up));
+        self.tokens
+            .insert(down_token.to_string(), (event_id.to_string(), Side::Down));
+        self.rounds.insert(
+            event_id.to_string(),
+            SurfaceRound {
+                event_id: event_id.to_string(),
+                strike,
+                end_time,
+                up_token: up_token.to_string(),
+                down_token: down_token.to_string(),
+                up_quote: None,
+                down_quote: None,
+            },
+        );
+    }
+
+    pub fn remove_round(&mut self, event_id: &str) -> Option<SurfaceRound> {
+        let round = self.rounds.remove(event_id)?;
+        self.tokens.remove(&round.up_token);
+        self.tokens.remove(&round.down_token);
+        Some(round)
+    }
+
+    /// Drop rounds that have settled; returns their event ids
+    pub fn prune_expired(&mut self, now: DateTime<Utc>) -> Vec<String> {
+        let expired: Vec<String> = self
+            .rounds
+            .values()
+            .filter(|r| r.end_time <= now)
+            .map(|r| r.event_id.clone())
+            .collect();
+        for event_id in &expired {
+            self.remove_round(event_id);
+        }
+        expired
+    }
+
+    /// Record a quote; returns false when the token is not on this surface
+    pub fn update_quote(&mut self, token_id: &str, quote: &Quote) -> bool {
+        let Some((event_id, side)) = self.tokens.get(token_id) else {
+            return false;
+        };
+        let Some(round) = self.rounds.get_mut(event_id) else {
+            return false;
+        };
+        match side {
+            Side::Up => round.up_quote = Some(quote.clone()),
+            Side::Down => round.down_quote = Some(quote.clone()),
+        }
+        true
+    }
+
+    /// Live rounds ordered by time to settlement
+    pub fn term_structure(&self, spot: Option<Decimal>, now: DateTime<Utc>) -> Vec<SurfacePoint> {
+        let mut points: Vec<SurfacePoint> = self
+            .rounds
+            .values()
+            .filter(|r| r.end_time > now)
+            .filter_map(|round| {
+                let prob_up = round.prob_up(now, self.config.max_quote_age_secs)?;
+                let tau_secs = (round.end_time - now).num_seconds();
+                let implied_vol =
+                    spot.and_then(|spot| self.implied_vol(spot, round.strike, prob_up, tau_secs));
+                Some(SurfacePoint {
+                    event_id: round.event_id.clone(),
+                    end_time: round.end_time,
+                    tau_secs,
+                    strike: round.strike,
+                    prob_up,
+                    implied_vol,
+                    total_variance: implied_vol
+                        .map(|vol| vol * vol * tau_secs as f64 / VOL_REFERENCE_SECS),
+                })
+            })
+            .collect();
+        points.sort_by_key(|p| (p.tau_secs, p.strike));
+        points
+    }
+
+    fn implied_vol(
+        &self,
+        spot: Decimal,
+        strike: Decimal,
+        prob_up: Decimal,
+        tau_secs: i64,
+    ) -> Option<f64> {
+        if strike <= Decimal::ZERO || tau_secs <= 0 {
+            return None;
+        }
+        let buffer = ((spot - strike) / strike).to_f64()?;
+        if buffer.abs() < self.config.min_buffer_pct {
+            return None;
+        }
+        calculate_implied_volatility(
+            prob_up.to_f64()?,
+            buffer,
+            tau_secs as f64 / VOL_REFERENCE_SECS,
+        )
+    }
+
+    /// Co-terminal round pairs whose UP(low strike) + DOWN(high strike) costs
+    /// less than `1 - min_edge`, best edge first
+    pub fn coterminal_arbs(&self, now: DateTime<Utc>) -> Vec<CalendarArbSignal> {
+        let max_age = self.config.max_quote_age_secs;
+        let mut signals = Vec::new();
+
+        for low in self.rounds.values().filter(|r| r.end_time > now) {
+            let Some(up_ask) = low
+                .fresh_quote(Side::Up, now, max_age)
+                .and_then(|q| q.best_ask)
+            else {
+                continue;
+            };
+
+            for high in self.rounds.values() {
+                if high.event_id == low.event_id
+                    || high.strike < low.strike
+                    || (high.end_time - low.end_time).num_seconds().abs()
+                        > self.config.coterminal_tolerance_secs
+                {
+                    continue;
+                }
+                let Some(down_ask) = high
+                    .fresh_quote(Side::Down, now, max_age)
+                    .and_then(|q| q.best_ask)
+                else {
+                    continue;
+                };
+
+                let cost = up_ask + down_ask;
+                let edge = Decimal::ONE - cost;
+                if edge >= self.config.min_edge {
+                    signals.push(CalendarArbSignal {
+                        symbol: self.symbol.clone(),
+                        up_event_id: low.event_id.clone(),
+                        up_token: low.up_token.clone(),
+                        up_ask,
+                        down_event_id: high.event_id.clone(),
+                        down_token: high.down_token.clone(),
+                        down_ask,
+                        cost,
+                        edge,
+                        end_time: low.end_time.min(high.end_time),
+                        detected_at: now,
+                    });
+                }
+            }
+        }
+
+        signals.sort_by(|a, b| b.edge.cmp(&a.edge));
+        signals
+    }
+
+    /// Horizon pairs where the longer round implies materially less total variance
+    pub fn variance_inversions(&self, spot: Decimal, now: DateTime<Utc>) -> Vec<VarianceInversion> {
+        let points: Vec<(SurfacePoint, f64)> = self
+            .term_structure(Some(spot), now)
+            .into_iter()
+            .filter_map(|p| p.total_variance.map(|w| (p, w)))
+            .collect();
+
+        let mut inversions = Vec::new();
+        for (i, (short, short_w)) in points.iter().enumerate() {
+            for (long, long_w) in &points[i + 1..] {
+                if long.tau_secs - short.tau_secs <= self.config.coterminal_tolerance_secs {
+                    continue;
+                }
+                if *short_w > long_w * (1.0 + self.config.variance_tolerance) {
+                    inversions.push(VarianceInversion {
+                        symbol: self.symbol.clone(),
+                        short_event_id: short.event_id.clone(),
+                        short_tau_secs: short.tau_secs,
+                        short_variance: *short_w,
+                        long_event_id: long.event_id.clone(),
+                        long_tau_secs: long.tau_secs,
+                        long_variance: *long_w,
+                    });
+                }
+            }
+        }
+        inversions
+    }
+
+    /// All inconsistencies on the surface
+    pub fn scan(&self, spot: Option<Decimal>, now: DateTime<Utc>) -> Vec<SurfaceInconsistency> {
+        let mut found: Vec<SurfaceInconsistency> = self
+            .coterminal_arbs(now)
+            .into_iter()
+            .map(SurfaceInconsistency::CoTerminal)
+            .collect();
+        if let Some(spot) = spot {
+            found.extend(
+                self.variance_inversions(spot, now)
+                    .into_iter()
+                    .map(SurfaceInconsistency::VarianceInversion),
+            );
+        }
+        found
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use chrono::Duration;
+    use rust_decimal_macros::dec;
+
+    fn quote(side: Side, bid: Decimal, ask: Decimal, at: DateTime<Utc>) -> Quote {
+        Quote {
+            side,
+            best_bid: Some(bid),
+            best_ask: Some(ask),
+            bid_size: Some(dec!(100)),
+            ask_size: Some(dec!(100)),
+            timestamp: at,
+        }
+    }
+
+    #[test]
+    fn test_coterminal_arbitrage() {
+        let now = Utc::now();
+        let end = now + Duration::minutes(4);
+        let mut surface = RoundSurface::new("BTCUSDT", RoundSurfaceConfig::default());
+
+        // 15m round opened at 100_000, 5m round opened later at 100_200; both settle at `end`
+        surface.upsert_round("15m", dec!(100000), end, "15m-up", "15m-down");
+        surface.upsert_round("5m", dec!(100200), end, "5m-up", "5m-down");
+
+        // UP(100_000) at 0.55 + DOWN(100_200) at 0.40 = 0.95 < 1
+        assert!(surface.update_quote("15m-up", &quote(Side::Up, dec!(0.54), dec!(0.55), now)));
+        surface.update_quote("5m-down", &quote(Side::Down, dec!(0.39), dec!(0.40), now));
+        surface.update_quote("5m-up", &quote(Side::Up, dec!(0.58), dec!(0.60), now));
+        surface.update_quote("15m-down", &quote(Side::Down, dec!(0.43), dec!(0.45), now));
+        assert!(!surface.update_quote("unknown", &quote(Side::Up, dec!(0.5), dec!(0.5), now)));
+
+        let arbs = surface.coterminal_arbs(now);
+        assert_eq!(arbs.len(), 1);
+        assert_eq!(arbs[0].up_event_id, "15m");
+        assert_eq!(arbs[0].down_event_id, "5m");
+        assert_eq!(arbs[0].cost, dec!(0.95));
+        assert_eq!(arbs[0].edge, dec!(0.05));
+
+        // Stale quotes are ignored
+        assert!(surface
+            .coterminal_arbs(now + Duration::seconds(30))
+            .is_empty());
+
+        // Different settlement times are not co-terminal
+        surface.upsert_round(
+            "5m",
+            dec!(100200),
+            end + Duration::minutes(5),
+            "5m-up",
+            "5m-down",
+        );
+        surface.update_quote("5m-down", &quote(Side::Down, dec!(0.39), dec!(0.40), now));
+        assert!(surface.coterminal_arbs(now).is_empty());
+    }
+
+    #[test]
+    fn test_term_structure_and_variance_inversion() {
+        let now = Utc::now();
+        let mut surface = RoundSurface::new("BTCUSDT", RoundSurfaceConfig::default());
+        surface.upsert_round(
+            "5m",
+            dec!(99900),
+            now + Duration::minutes(5),
+            "a-up",
+            "a-down",
+        );
+        surface.upsert_round(
+            "1h",
+            dec!(99900),
+            now + Duration::minutes(60),
+            "b-up",
+            "b-down",
+        );
+
+        // Same strike, spot above it: the short round is *less* confident than
+        // the long one, implying more variance over less time.
+        surface.update_quote("a-up", &quote(Side::Up, dec!(0.54), dec!(0.56), now));
+        surface.update_quote("b-down", &quote(Side::Down, dec!(0.09), dec!(0.11), now));
+
+        let points = surface.term_structure(Some(dec!(100000)), now);
+        assert_eq!(points.len(), 2);
+        assert_eq!(points[0].event_id, "5m");
+        assert_eq!(points[1].prob_up, dec!(0.90));
+        assert!(points.iter().all(|p| p.total_variance.is_some()));
+
+        let inversions = surface.variance_inversions(dec!(100000), now);
+        assert_eq!(inversions.len(), 1);
+        assert_eq!(inversions[0].short_event_id, "5m");
+        assert_eq!(inversions[0].long_event_id, "1h");
+
+        assert_eq!(
+            surface.prune_expired(now + Duration::minutes(10)),
+            vec!["5m"]
+        );
+        assert_eq!(surface.len(), 1);
+    }
+}
@@ -0,0 +1,433 @@
+//! Order-flow toxicity (VPIN) estimator.
+//!
+//! Volume-synchronized probability of informed trading, computed from Binance
+//! aggTrade prints. Trades are classified as buyer- or seller-initiated
+//! (aggressor flag `m` when present, tick rule otherwise) and poured into
+//! equal-notional buckets; VPIN is the mean absolute buy/sell imbalance over
+//! the last `window_buckets` completed buckets.
+//!
+//! High toxicity means informed flow is hitting the spot book, which is when
+//! Polymarket quotes are most likely to be stale. Strategies use the entry
+//! edge multiplier to demand more edge, and `RiskGate` shrinks or blocks new
+//! positions.
+
+use chrono::{DateTime, Duration, Utc};
+use rust_decimal::prelude::{FromPrimitive, ToPrimitive};
+use rust_decimal::Decimal;
+use serde::{Deserialize, Serialize};
+use std::collections::{HashMap, VecDeque};
+use std::sync::RwLock;
+
+use crate::adapters::PriceUpdate;
+use crate::platform::OrderIntent;
+
+/// Per-symbol VPIN parameters
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(default)]
+pub struct VpinSymbolConfig {
+    /// Notional (USD) per volume bucket
+    pub bucket_volume_usd: f64,
+    /// Number of completed buckets averaged into VPIN
+    pub window_buckets: usize,
+    /// Minimum completed buckets before VPIN is reported
+    pub min_buckets: usize,
+    /// VPIN at or above this is `Elevated`
+    pub elevated_threshold: f64,
+    /// VPIN at or above this is `High`
+    pub high_threshold: f64,
+    /// Entry edge multiplier applied by strategies when `Elevated`
+    pub elevated_edge_multiplier: f64,
+    /// Entry edge multiplier applied by strategies when `High`
+    pub high_edge_multiplier: f64,
+    /// Scale applied to the per-order value cap in `RiskGate` when `Elevated`
+    pub elevated_size_multiplier: f64,
+    /// Block new positions in `RiskGate` when `High`
+    pub block_on_high: bool,
+    /// Ignore the estimate when no trade arrived for this long
+    pub max_staleness_secs: i64,
+}
+
+impl Default for VpinSymbolConfig {
+    fn default() -> Self {
+        Self {
+            bucket_volume_usd: 250_000.0,
+            window_buckets: 50,
+            min_buckets: 10,
+            elevated_threshold: 0.45,
+            high_threshold: 0.65,
+            elevated_edge_multiplier: 1.5,
+            high_edge_multiplier: 2.0,
+            elevated_size_multiplier: 0.5,
+            block_on_high: true,
+            max_staleness_secs: 120,
+        }
+    }
+}
+
+/// Flow-toxicity configuration
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(default)]
+pub struct VpinConfig {
+    pub enabled: bool,
+    /// Parameters used for symbols without an override
+    pub defaults: VpinSymbolConfig,
+    /// Per-symbol overrides keyed by Binance symbol (e.g. "BTCUSDT").
+    /// An override replaces the defaults for that symbol entirely.
+    pub symbols: HashMap<String, VpinSymbolConfig>,
+}
+
+impl Default for VpinConfig {
+    fn default() -> Self {
+        Self {
+            enabled: false,
+            defaults: VpinSymbolConfig::default(),
+            symbols: HashMap::new(),
+        }
+    }
+}
+
+impl VpinConfig {
+    pub fn for_symbol(&self, symbol: &str) -> &VpinSymbolConfig {
+        self.symbols
+            .get(&symbol.to_ascii_uppercase())
+            .unwrap_or(&self.defaults)
+    }
+
+    /// Merge `overrides` over the configured symbols. Keys are upper-cased,
+    /// so `btcusdt` (as env-sourced keys arrive) still matches "BTCUSDT".
+    pub fn with_symbol_overrides(mut self, overrides: HashMap<String, VpinSymbolConfig>) -> Self {
+        let symbols = std::mem::take(&mut self.symbols);
+        self.symbols = symbols
+            .into_iter()
+            .chain(overrides)
+            .map(|(symbol, config)| (symbol.to_ascii_uppercase(), config))
+            .collect();
+        self
+    }
+}
+
+/// Discrete toxicity regime
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum ToxicityLevel {
+    Normal,
+    Elevated,
+    High,
+}
+
+impl ToxicityLevel {
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            ToxicityLevel::Normal => "normal",
+            ToxicityLevel::Elevated => "elevated",
+            ToxicityLevel::High => "high",
+        }
+    }
+}
+
+/// Point-in-time toxicity reading for one symbol
+#[derive(Debug, Clone, Serialize)]
+pub struct ToxicitySnapshot {
+    pub symbol: String,
+    /// None until `min_buckets` buckets completed (or when stale)
+    pub vpin: Option<f64>,
+    pub level: ToxicityLevel,
+    pub buckets: usize,
+    pub last_trade_at: Option<DateTime<Utc>>,
+}
+
+/// Streaming VPIN estimator for a single symbol
+#[derive(Debug, Clone)]
+pub struct VpinEstimator {
+    config: VpinSymbolConfig,
+    current_buy: f64,
+    current_sell: f64,
+    /// Completed buckets as |buy - sell| / bucket volume (newest last)
+    imbalances: VecDeque<f64>,
+    last_price: Option<f64>,
+    /// Tick-rule state: +1 buy, -1 sell
+    last_direction: i8,
+    last_trade_at: Option<DateTime<Utc>>,
+}
+
+impl VpinEstimator {
+    pub fn new(config: VpinSymbolConfig) -> Self {
+        Self {
+            config,
+            current_buy: 0.0,
+            current_sell: 0.0,
+            imbalances: VecDeque::new(),
+            last_price: None,
+            last_direction: 1,
+            last_trade_at: None,
+        }
+    }
+
+    /// Add one trade print.
+    ///
+    /// `is_buyer_maker = Some(true)` means the seller was the aggressor.
+    pub fn on_trade(
+        &mut self,
+        price: f64,
+        quantity: f64,
+        is_buyer_maker: Option<bool>,
+        timestamp: DateTime<Utc>,
+    ) {
+        if !(price.is_finite() && quantity.is_finite()) || price <= 0.0 || quantity <= 0.0 {
+            return;
+        }
+        let is_buy = match is_buyer_maker {
+            Some(buyer_maker) => !buyer_maker,
+            None => {
+                if let Some(prev) = self.last_price {
+                    if price > prev {
+                        self.last_direction = 1;
+                    } else if price < prev {
+                        self.last_direction = -1;
+                    }
+                }
+                self.last_direction > 0
+            }
+        };
+        self.last_price = Some(price);
+        self.last_trade_at = Some(timestamp);
+
+        let bucket = self.config.bucket_volume_usd.max(1.0);
+        let mut remaining = price * quantity;
+        while remaining > 0.0 {
+            let room = bucket - (self.current_buy + self.current_sell);
+            let fill = remaining.min(room);
+            if is_buy {
+                self.current_buy += fill;
+            } else {
+                self.current_sell += fill;
+            }
+            remaining -= fill;
+            if self.current_buy + self.current_sell >= bucket * (1.0 - 1e-9) {
+                self.close_bucket(bucket);
+            }
+        }
+    }
+
+    fn close_bucket(&mut self, bucket: f64) {
+        let imbalance = ((self.current_buy - self.current_sell).abs() / bucket).min(1.0);
+        self.imbalances.push_back(imbalance);
+        while self.imbalances.len() > self.config.window_buckets.max(1) {
+            self.imbalances.pop_front();
+        }
+        self.current_buy = 0.0;
+        self.current_sell = 0.0;
+    }
+
+    pub fn buckets(&self) -> usize {
+        self.imbalances.len()
+    }
+
+    /// Current VPIN, or None when warming up or stale.
+    pub fn vpin(&self, now: DateTime<Utc>) -> Option<f64> {
+        if self.imbalances.len() < self.config.min_buckets.max(1) {
+            return None;
+        }
+        let last = self.last_trade_at?;
+        if now - last > Duration::seconds(self.config.max_staleness_secs.max(1)) {
+            return None;
+        }
+        Some(self.imbalances.iter().sum::<f64>() / self.imbalances.len() as f64)
+    }
+
+    pub fn level(&self, now: DateTime<Utc>) -> ToxicityLevel {
+        match self.vpin(now) {
+            Some(v) if v >= self.config.high_threshold => ToxicityLevel::High,
+            Some(v) if v >= self.config.elevated_threshold => ToxicityLevel::Elevated,
+            _ => ToxicityLevel::Normal,
+        }
+    }
+}
+
+/// Shared multi-symbol toxicity monitor
+pub struct ToxicityMonitor {
+    config: VpinConfig,
+    estimators: RwLock<HashMap<String, VpinEstimator>>,
+}
+
+impl ToxicityMonitor {
+    pub fn new(config: VpinConfig) -> Self {
+        Self {
+            config,
+            estimators: RwLock::new(HashMap::new()),
+        }
+    }
+
+    pub fn config(&self) -> &VpinConfig {
+        &self.config
+    }
+
+    pub fn on_trade(
+        &self,
+        symbol: &str,
+        price: f64,
+        quantity: f64,
+        is_buyer_maker: Option<bool>,
+        timestamp: DateTime<Utc>,
+    ) {
+        let symbol = symbol.to_ascii_uppercase();
+        let Ok(mut estimators) = self.estimators.write() else {
+            return;
+        };
+        estimators
+            .entry(symbol.clone())
+            .or_insert_with(|| VpinEstimator::new(self.config.for_symbol(&symbol).clone()))
+            .on_trade(price, quantity, is_buyer_maker, timestamp);
+    }
+
+    /// Feed a Binance price update (requires a trade quantity).
+    pub fn on_price_update(&self, update: &PriceUpdate) {
+        let (Some(price), Some(quantity)) = (
+            update.price.to_f64(),
+            update.quantity.and_then(|q| q.to_f64()),
+        ) else {
+            return;
+        };
+        self.on_trade(
+            &update.symbol,
+            price,
+            quantity,
+            update.is_buyer_maker,
+            update.timestamp,
+        );
+    }
+
+    pub fn snapshot(&self, symbol: &str, now: DateTime<Utc>) -> ToxicitySnapshot {
+        let symbol = symbol.to_ascii_uppercase();
+        let estimators = self.estimators.read().ok();
+        let estimator = estimators.as_ref().and_then(|e| e.get(&symbol));
+        ToxicitySnapshot {
+            vpin: estimator.and_then(|e| e.vpin(now)),
+            level: estimator
+                .map(|e| e.level(now))
+                .unwrap_or(ToxicityLevel::Normal),
+            buckets: estimator.map(|e| e.buckets()).unwrap_or(0),
+            last_trade_at: estimator.and_then(|e| e.last_trade_at),
+            symbol,
+        }
+    }
+
+    pub fn level(&self, symbol: &str, now: DateTime<Utc>) -> ToxicityLevel {
+        self.snapshot(symbol, now).level
+    }
+
+    /// Multiplier strategies apply to their minimum entry edge.
+    pub fn entry_edge_multiplier(&self, symbol: &str, now: DateTime<Utc>) -> Decimal {
+        let cfg = self.config.for_symbol(symbol);
+        let multiplier = match self.level(symbol, now) {
+            ToxicityLevel::Normal => 1.0,
+            ToxicityLevel::Elevated => cfg.elevated_edge_multiplier,
+            ToxicityLevel::High => cfg.high_edge_multiplier,
+        };
+        Decimal::from_f64(multiplier.max(1.0)).unwrap_or(Decimal::ONE)
+    }
+
+    /// Binance symbol an order intent trades against, if any.
+    ///
+    /// Uses the `symbol` metadata, then `coin`, then the market slug prefix
+    /// (e.g. "btc-updown-15m-..." -> "BTCUSDT").
+    pub fn symbol_for_intent(intent: &OrderIntent) -> Option<String> {
+        if let Some(symbol) = intent.metadata.get("symbol").filter(|s| !s.is_empty()) {
+            return Some(symbol.to_ascii_uppercase());
+        }
+        let coin = intent
+            .metadata
+            .get("coin")
+            .map(String::as_str)
+            .filter(|c| !c.is_empty())
+            .or_else(|| intent.market_slug.split('-').next())
+            .filter(|c| !c.is_empty())?;
+        Some(format!("{}USDT", coin.to_ascii_uppercase()))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use chrono::TimeZone;
+
+    fn cfg() -> VpinSymbolConfig {
+        VpinSymbolConfig {
+            bucket_volume_usd: 1_000.0,
+            window_buckets: 10,
+            min_buckets: 5,
+            ..VpinSymbolConfig::default()
+        }
+    }
+
+    fn t0() -> DateTime<Utc> {
+        Utc.timestamp_opt(1_700_000_000, 0).unwrap()
+    }
+
+    #[test]
+    fn test_balanced_flow_is_not_toxic() {
+        let mut est = VpinEstimator::new(cfg());
+        for i in 0..40 {
+            est.on_trade(100.0, 2.5, Some(i % 2 == 0), t0());
+        }
+        assert_eq!(est.buckets(), 10);
+        assert!(est.vpin(t0()).unwrap() < 0.01);
+        assert_eq!(est.level(t0()), ToxicityLevel::Normal);
+    }
+
+    #[test]
+    fn test_one_sided_flow_is_high_and_goes_stale() {
+        let mut est = VpinEstimator::new(cfg());
+        // 2_500 USD buy prints straddle bucket boundaries.
+        for _ in 0..4 {
+            est.on_trade(100.0, 25.0, Some(false), t0());
+        }
+        assert_eq!(est.buckets(), 10);
+        assert!((est.vpin(t0()).unwrap() - 1.0).abs() < 1e-9);
+        assert_eq!(est.level(t0()), ToxicityLevel::High);
+        assert_eq!(est.vpin(t0() + Duration::seconds(600)), None);
+    }
+
+    #[test]
+    fn test_tick_rule_and_warmup() {
+        let mut est = VpinEstimator::new(cfg());
+        est.on_trade(100.0, 5.0, None, t0());
+        est.on_trade(101.0, 5.0, None, t0());
+        assert_eq!(est.buckets(), 1);
+        assert_eq!(est.vpin(t0()), None);
+    }
+
+    #[test]
+    fn test_monitor_per_symbol_overrides_and_intent_symbol() {
+        let mut config = VpinConfig {
+            enabled: true,
+            ..VpinConfig::default()
+        };
+        config.symbols.insert("BTCUSDT".into(), cfg());
+        let monitor = ToxicityMonitor::new(config);
+        for _ in 0..5 {
+            monitor.on_trade("btcusdt", 100.0, 10.0, Some(true), t0());
+            monitor.on_trade("ETHUSDT", 100.0, 10.0, Some(true), t0());
+        }
+        assert_eq!(monitor.level("BTCUSDT", t0()), ToxicityLevel::High);
+        assert_eq!(monitor.entry_edge_multiplier("BTCUSDT", t0()), Decimal::TWO);
+        // ETH uses the 250k default bucket, so it is still warming up.
+        assert_eq!(monitor.snapshot("ETHUSDT", t0()).buckets, 0);
+        assert_eq!(monitor.level("ETHUSDT", t0()), ToxicityLevel::Normal);
+
+        let intent = OrderIntent::new(
+            "crypto",
+            crate::platform::Domain::Crypto,
+            "sol-updown-5m-123",
+            "tok",
+            crate::domain::Side::Up,
+            true,
+            10,
+            Decimal::new(5, 1),
+        );
+        assert_eq!(
+            ToxicityMonitor::symbol_for_intent(&intent).as_deref(),
+            Some("SOLUSDT")
+        );
+    }
+}
@@ -1,12 +1,15 @@
 use axum::http::{header::AUTHORIZATION, header::COOKIE, HeaderMap, StatusCode};
+use serde::{Deserialize, Serialize};
 use sha2::{Digest, Sha256};
 
+use crate::api::state::AppState;
+
 pub const ADMIN_SESSION_COOKIE: &str = "ploy_admin_auth";
 
 /// Constant-time string comparison to prevent timing side-channel attacks.
 /// The length check leaks length information, but for fixed-format bearer tokens
 /// this is acceptable — the critical protection is against byte-by-byte guessing.
-fn ct_eq(a: &str, b: &str) -> bool {
+pub(crate) fn ct_eq(a: &str, b: &str) -> bool {
     let a = a.as_bytes();
     let b = b.as_bytes();
     if a.len() != b.len() {
@@ -221,3 +224,275 @@ pub fn ensure_sidecar_or_admin_authorized(
     }
     ensure_admin_authorized(headers)
 }
+
+// ============================================================================
+// Role-based access control
+// ============================================================================
+
+/// Access level of an API caller. Ordered: each role includes the ones below it.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash, Serialize, Deserialize)]
+#[serde(rename_all = "lowercase")]
+pub enum Role {
+    /// Read-only dashboards
+    Viewer,
+    /// Day-to-day operation: pause/resume agents, toggle strategies, approvals
+    Operator,
+    /// Configuration, governance policy, API keys and webhooks
+    Admin,
+}
+
+impl Role {
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            Role::Viewer => "viewer",
+            Role::Operator => "operator",
+            Role::Admin => "admin",
+        }
+    }
+
+    pub fn parse(value: &str) -> Option<Self> {
+        match value.trim().to_ascii_lowercase().as_str() {
+            "viewer" => Some(Role::Viewer),
+            "operator" => Some(Role::Operator),
+            "admin" => Some(Role::Admin),
+            _ => None,
+        }
+    }
+
+    pub fn allows(&self, permission: Permission) -> bool {
+        *self >= permission.min_role()
+    }
+}
+
+/// What an endpoint needs from its caller
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Permission {
+    /// Read operational state (journal, approvals, deployments, governance)
+    Read,
+    /// Control running agents and strategies
+    Control,
+    /// Change configuration, policy, credentials or integrations
+    Admin,
+}
+
+impl Permission {
+    pub fn min_role(&self) -> Role {
+        match self {
+            Permission::Read => Role::Viewer,
+            Permission::Control => Role::Operator,
+            Permission::Admin => Role::Admin,
+        }
+    }
+}
+
+/// Authenticated API caller
+#[derive(Debug, Clone, PartialEq, Eq, Serialize)]
+pub struct Principal {
+    /// API key name, `admin-token` for the legacy shared token, or
+    /// `anonymous` when admin auth is disabled
+    pub actor: String,
+    pub role: Role,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub key_id: Option<i64>,
+}
+
+impl Principal {
+    fn admin_token() -> Self {
+        Self {
+            actor: "admin-token".to_string(),
+            role: Role::Admin,
+            key_id: None,
+        }
+    }
+}
+
+fn presented_token(headers: &HeaderMap) -> Option<&str> {
+    headers
+        .get("x-ploy-api-key")
+        .or_else(|| headers.get("x-ploy-admin-token"))
+        .and_then(|v| v.to_str().ok())
+        .map(str::trim)
+        .or_else(|| {
+            headers
+                .get(AUTHORIZATION)
+                .and_then(|v| v.to_str().ok())
+                .and_then(extract_bearer_token)
+        })
+        .filter(|v| !v.is_empty())
+}
+
+/// Active API key with the given SHA-256 fingerprint; bumps `last_used_at`
+async fn lookup_api_key(
+    state: &AppState,
+    key_hash: &str,
+) -> std::result::Result<Option<Principal>, (StatusCode, String)> {
+    let row: Option<(i64, String, String)> = sqlx::query_as(
+        r#"
+        UPDATE api_keys SET last_used_at = NOW()
+        WHERE key_hash = $1 AND revoked_at IS NULL
+        RETURNING id, name, role
+        "#,
+    )
+    .bind(key_hash)
+    .fetch_optional(state.store.pool())
+    .await
+    .map_err(|e| (StatusCode::INTERNAL_SERVER_ERROR, e.to_string()))?;
+
+    Ok(row.and_then(|(id, name, role)| {
+        Some(Principal {
+            actor: name,
+            role: Role::parse(&role)?,
+            key_id: Some(id),
+        })
+    }))
+}
+
+/// Identify the caller from an API key, the legacy admin token or a session
+/// cookie (which holds the SHA-256 fingerprint of either).
+pub async fn authenticate(
+    state: &AppState,
+    headers: &HeaderMap,
+) -> std::result::Result<Principal, (StatusCode, String)> {
+    let expected = expected_admin_token();
+
+    if let Some(token) = presented_token(headers) {
+        if expected.as_deref().is_some_and(|e| ct_eq(token, e)) {
+            return Ok(Principal::admin_token());
+        }
+        if let Some(principal) = lookup_api_key(state, &admin_token_fingerprint(token)).await? {
+            return Ok(principal);
+        }
+    }
+
+    if let Some(cookie) = extract_cookie(headers, ADMIN_SESSION_COOKIE) {
+        if let Some(expected) = expected.as_deref() {
+            if ct_eq(&cookie, &admin_token_fingerprint(expected)) || ct_eq(&cookie, expected) {
+                return Ok(Principal::admin_token());
+            }
+        }
+        if let Some(principal) = lookup_api_key(state, &cookie).await? {
+            return Ok(principal);
+        }
+    }
+
+    if expected.is_none() && !admin_auth_required() {
+        return Ok(Principal {
+            actor: "anonymous".to_string(),
+            role: Role::Admin,
+            key_id: None,
+        });
+    }
+    Err((
+        StatusCode::UNAUTHORIZED,
+        "auth failed (missing/invalid API key or admin token)".to_string(),
+    ))
+}
+
+fn check_permission(
+    principal: &Principal,
+    permission: Permission,
+) -> std::result::Result<(), (StatusCode, String)> {
+    if !principal.role.allows(permission) {
+        tracing::warn!(
+            actor = %principal.actor,
+            role = principal.role.as_str(),
+            required = permission.min_role().as_str(),
+            "API permission denied"
+        );
+        return Err((
+            StatusCode::FORBIDDEN,
+            format!(
+                "role '{}' cannot perform this action (requires {})",
+                principal.role.as_str(),
+                permission.min_role().as_str()
+            ),
+        ));
+    }
+    Ok(())
+}
+
+/// Authenticate the caller and check that its role grants `permission`
+pub async fn authorize(
+    state: &AppState,
+    headers: &HeaderMap,
+    permission: Permission,
+) -> std::result::Result<Principal, (StatusCode, String)> {
+    let principal = authenticate(state, headers).await?;
+    check_permission(&principal, permission)?;
+    Ok(principal)
+}
+
+/// [`authorize`] a control action and record it in `api_audit_log`.
+///
+/// Denied attempts are audited too, so the log shows who tried what.
+pub async fn authorize_action(
+    state: &AppState,
+    headers: &HeaderMap,
+    permission: Permission,
+    action: &str,
+    details: serde_json::Value,
+) -> std::result::Result<Principal, (StatusCode, String)> {
+    let principal = authenticate(state, headers).await?;
+    let allowed = check_permission(&principal, permission);
+    record_audit(state, &principal, action, allowed.is_ok(), details).await;
+    allowed.map(|()| principal)
+}
+
+async fn record_audit(
+    state: &AppState,
+    principal: &Principal,
+    action: &str,
+    allowed: bool,
+    details: serde_json::Value,
+) {
+    let result = sqlx::query(
+        r#"
+        INSERT INTO api_audit_log (actor, role, key_id, action, allowed, details)
+        VALUES ($1, $2, $3, $4, $5, $6)
+        "#,
+    )
+    .bind(&principal.actor)
+    .bind(principal.role.as_str())
+    .bind(principal.key_id)
+    .bind(action)
+    .bind(allowed)
+    .bind(sqlx::types::Json(details))
+    .execute(state.store.pool())
+    .await;
+    if let Err(e) = result {
+        tracing::warn!(
+            actor = %principal.actor,
+            action,
+            error = %e,
+            "failed to write API audit log"
+        );
+    }
+}
+
+/// New API key secret: `ploy_` followed by 64 hex characters
+pub fn generate_api_key() -> String {
+    format!(
+        "ploy_{}{}",
+        uuid::Uuid::new_v4().simple(),
+        uuid::Uuid::new_v4().simple()
+    )
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn roles_grant_permissions_cumulatively() {
+        assert!(Role::Viewer.allows(Permission::Read));
+        assert!(!Role::Viewer.allows(Permission::Control));
+        assert!(Role::Operator.allows(Permission::Control));
+        assert!(!Role::Operator.allows(Permission::Admin));
+        assert!(Role::Admin.allows(Permission::Admin));
+        assert_eq!(Role::parse(" Operator "), Some(Role::Operator));
+        assert_eq!(Role::parse("root"), None);
+
+        let key = generate_api_key();
+        assert!(key.starts_with("ploy_") && key.len() == 69);
+    }
+}
@@ -0,0 +1,191 @@
+use axum::{
+    extract::{Path, State},
+    http::{HeaderMap, StatusCode},
+    Json,
+};
+use serde::{Deserialize, Serialize};
+use serde_json::Value;
+use uuid::Uuid;
+
+use crate::adapters::feishu::resolved_approval_card;
+use crate::api::{
+    auth::{authorize, authorize_action, ct_eq, ensure_sidecar_authorized, Permission},
+    state::AppState,
+};
+use crate::coordinator::{ApprovalDecision, ApprovalSnapshot, CoordinatorHandle};
+
+#[derive(Debug, Deserialize)]
+pub struct ApprovalDecisionRequest {
+    pub decision: ApprovalDecision,
+    #[serde(default)]
+    pub operator: Option<String>,
+}
+
+#[derive(Debug, Deserialize)]
+pub struct EmergencyStopRequest {
+    pub reason: String,
+    #[serde(default)]
+    pub requested_by: Option<String>,
+}
+
+#[derive(Debug, Serialize)]
+pub struct EmergencyStopRequestResponse {
+    pub approval_id: Uuid,
+    pub status: String,
+}
+
+fn coordinator_or_unavailable(
+    state: &AppState,
+) -> std::result::Result<&CoordinatorHandle, (StatusCode, String)> {
+    state.coordinator.as_ref().ok_or_else(|| {
+        (
+            StatusCode::SERVICE_UNAVAILABLE,
+            "coordinator unavailable in this runtime".to_string(),
+        )
+    })
+}
+
+/// GET /api/approvals
+pub async fn list_approvals(
+    State(state): State<AppState>,
+    headers: HeaderMap,
+) -> std::result::Result<Json<Vec<ApprovalSnapshot>>, (StatusCode, String)> {
+    authorize(&state, &headers, Permission::Read).await?;
+    let coordinator = coordinator_or_unavailable(&state)?;
+    Ok(Json(coordinator.pending_approvals()))
+}
+
+/// POST /api/approvals/:id
+pub async fn decide_approval(
+    State(state): State<AppState>,
+    headers: HeaderMap,
+    Path(id): Path<Uuid>,
+    Json(req): Json<ApprovalDecisionRequest>,
+) -> std::result::Result<Json<ApprovalSnapshot>, (StatusCode, String)> {
+    let principal = authorize_action(
+        &state,
+        &headers,
+        Permission::Control,
+        "approvals.decide",
+        serde_json::json!({ "approval_id": id, "decision": &req.decision }),
+    )
+    .await?;
+    let coordinator = coordinator_or_unavailable(&state)?;
+    let operator = req.operator.unwrap_or(principal.actor);
+    let snapshot = coordinator
+        .resolve_approval(id, req.decision, &operator)
+        .await
+        .map_err(|e| (StatusCode::CONFLICT, e.to_string()))?;
+    Ok(Json(snapshot))
+}
+
+/// POST /api/sidecar/emergency-stop
+///
+/// Requests an emergency stop; nothing happens until an operator approves it.
+pub async fn sidecar_request_emergency_stop(
+    State(state): State<AppState>,
+    headers: HeaderMap,
+    Json(req): Json<EmergencyStopRequest>,
+) -> std::result::Result<Json<EmergencyStopRequestResponse>, (StatusCode, String)> {
+    ensure_sidecar_authorized(&headers)?;
+    let coordinator = coordinator_or_unavailable(&state)?;
+    let reason = req.reason.trim();
+    if reason.is_empty() {
+        return Err((StatusCode::BAD_REQUEST, "reason is required".to_string()));
+    }
+    let requested_by = req.requested_by.unwrap_or_else(|| "sidecar".to_string());
+    let approval_id = coordinator.request_emergency_stop(reason, &requested_by);
+    Ok(Json(EmergencyStopRequestResponse {
+        approval_id,
+        status: "pending_approval".to_string(),
+    }))
+}
+
+fn verify_feishu_token(payload: &Value) -> std::result::Result<(), (StatusCode, String)> {
+    let Some(expected) = std::env::var("FEISHU_VERIFICATION_TOKEN")
+        .ok()
+        .filter(|v| !v.trim().is_empty())
+    else {
+        return Err((
+            StatusCode::SERVICE_UNAVAILABLE,
+            "FEISHU_VERIFICATION_TOKEN is not configured".to_string(),
+        ));
+    };
+    // Legacy callbacks carry `token` at the top level, v2 callbacks in `header.token`.
+    let provided = payload
+        .get("token")
+        .or_else(|| payload.pointer("/header/token"))
+        .and_then(Value::as_str)
+        .unwrap_or_default();
+    if ct_eq(provided, expected.trim()) {
+        Ok(())
+    } else {
+        Err((
+            StatusCode::UNAUTHORIZED,
+            "invalid feishu verification token".to_string(),
+        ))
+    }
+}
+
+/// POST /api/feishu/card-callback
+///
+/// Receives Approve / Deny button clicks from Feishu approval cards and
+/// returns the replacement card.
+pub async fn feishu_card_callback(
+    State(state): State<AppState>,
+    Json(payload): Json<Value>,
+) -> std::result::Result<Json<Value>, (StatusCode, String)> {
+    verify_feishu_token(&payload)?;
+
+    if payload.get("type").and_then(Value::as_str) == Some("url_verification") {
+        let challenge = payload.get("challenge").cloned().unwrap_or(Value::Null);
+        return Ok(Json(serde_json::json!({ "challenge": challenge })));
+    }
+
+    let is_v2 = payload.get("schema").and_then(Value::as_str) == Some("2.0");
+    let (value, operator) = if is_v2 {
+        (
+            payload.pointer("/event/action/value"),
+            payload.pointer("/event/operator/open_id"),
+        )
+    } else {
+        (payload.pointer("/action/value"), payload.get("open_id"))
+    };
+    let value = value.ok_or_else(|| {
+        (
+            StatusCode::BAD_REQUEST,
+            "missing action.value in card callback".to_string(),
+        )
+    })?;
+    let approval_id = value
+        .get("approval_id")
+        .and_then(Value::as_str)
+        .and_then(|raw| Uuid::parse_str(raw).ok())
+        .ok_or_else(|| (StatusCode::BAD_REQUEST, "invalid approval_id".to_string()))?;
+    let decision: ApprovalDecision = value
+        .get("decision")
+        .cloned()
+        .and_then(|raw| serde_json::from_value(raw).ok())
+        .ok_or_else(|| (StatusCode::BAD_REQUEST, "invalid decision".to_string()))?;
+    let operator = format!(
+        "feishu:{}",
+        operator.and_then(Value::as_str).unwrap_or("unknown")
+    );
+
+    let coordinator = coordinator_or_unavailable(&state)?;
+    let (title, outcome) = match coordinator
+        .resolve_approval(approval_id, decision, &operator)
+        .await
+    {
+        Ok(snapshot) => (snapshot.title, decision.outcome().to_string()),
+        Err(e) => ("Approval".to_string(), format!("Not applied: {}", e)),
+    };
+    let card = resolved_approval_card(&approval_id.to_string(), &title, &outcome, &operator);
+    if is_v2 {
+        Ok(Json(serde_json::json!({
+            "card": { "type": "raw", "data": card }
+        })))
+    } else {
+        Ok(Json(card))
+    }
+}
@@ -1,14 +1,16 @@
 use axum::{
-    extract::State,
+    extract::{Path, Query, State},
     http::{header::SET_COOKIE, HeaderMap, HeaderValue, StatusCode},
     Json,
 };
+use chrono::{DateTime, Utc};
 use serde::{Deserialize, Serialize};
 
 use crate::api::{
     auth::{
-        admin_auth_required, build_admin_logout_cookie, build_admin_session_cookie,
-        ensure_admin_authorized, expected_admin_token, is_valid_admin_token,
+        admin_auth_required, admin_token_fingerprint, authenticate, authorize, authorize_action,
+        build_admin_logout_cookie, build_admin_session_cookie, expected_admin_token,
+        generate_api_key, is_valid_admin_token, Permission, Principal, Role,
     },
     state::AppState,
 };
@@ -22,6 +24,77 @@ pub struct AdminLoginRequest {
 pub struct AuthSessionResponse {
     pub authenticated: bool,
     pub auth_required: bool,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub principal: Option<Principal>,
+}
+
+#[derive(Debug, Deserialize)]
+pub struct CreateApiKeyRequest {
+    pub name: String,
+    pub role: Role,
+}
+
+#[derive(Debug, Serialize)]
+pub struct ApiKeyResponse {
+    pub id: i64,
+    pub name: String,
+    pub role: String,
+    pub key_prefix: String,
+    pub created_by: String,
+    pub created_at: DateTime<Utc>,
+    pub last_used_at: Option<DateTime<Utc>>,
+    pub revoked_at: Option<DateTime<Utc>>,
+    /// Full key, only returned on creation
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub key: Option<String>,
+}
+
+#[derive(Debug, Deserialize)]
+pub struct AuditLogQuery {
+    pub limit: Option<i64>,
+    pub actor: Option<String>,
+    pub action: Option<String>,
+}
+
+#[derive(Debug, Serialize)]
+pub struct AuditLogEntry {
+    pub id: i64,
+    pub actor: String,
+    pub role: String,
+    pub key_id: Option<i64>,
+    pub action: String,
+    pub allowed: bool,
+    pub details: serde_json::Value,
+    pub created_at: DateTime<Utc>,
+}
+
+type ApiKeyRow = (
+    i64,
+    String,
+    String,
+    String,
+    String,
+    DateTime<Utc>,
+    Option<DateTime<Utc>>,
+    Option<DateTime<Utc>>,
+);
+
+fn db_error(err: sqlx::Error) -> (StatusCode, String) {
+    (StatusCode::INTERNAL_SERVER_ERROR, err.to_string())
+}
+
+fn api_key_response(row: ApiKeyRow) -> ApiKeyResponse {
+    ApiKeyResponse {
+        id: row.0,
+        name: row.1,
+        role: row.2,
+        key_prefix: row.3,
+        created_by: row.4,
+        created_at: row.5,
+        last_used_at: row.6,
+        revoked_at: row.7,
+        key: None,
+    }
 }
 
 #[derive(Debug, Serialize)]
@@ -31,47 +104,57 @@ pub struct AuthMutationResponse {
 
 /// GET /api/auth/session
 pub async fn get_auth_session(
+    State(state): State<AppState>,
     headers: HeaderMap,
 ) -> std::result::Result<Json<AuthSessionResponse>, (StatusCode, String)> {
-    let authenticated = ensure_admin_authorized(&headers).is_ok();
+    let principal = authenticate(&state, &headers).await.ok();
     Ok(Json(AuthSessionResponse {
-        authenticated,
+        authenticated: principal.is_some(),
         auth_required: admin_auth_required(),
+        principal,
     }))
 }
 
 /// POST /api/auth/login
+///
+/// Accepts the admin token or an API key; the session cookie carries its
+/// fingerprint, so the session has the key's role.
 pub async fn login_admin(
-    State(_state): State<AppState>,
+    State(state): State<AppState>,
     Json(req): Json<AdminLoginRequest>,
 ) -> std::result::Result<(HeaderMap, Json<AuthMutationResponse>), (StatusCode, String)> {
-    if req.admin_token.trim().is_empty() {
+    let token = req.admin_token.trim();
+    if token.is_empty() {
         return Err((
             StatusCode::BAD_REQUEST,
             "admin_token is required".to_string(),
         ));
     }
-    let Some(expected) = expected_admin_token() else {
-        if !admin_auth_required() {
+
+    let valid = is_valid_admin_token(token) || {
+        let mut probe = HeaderMap::new();
+        let value = HeaderValue::from_str(token)
+            .map_err(|_| (StatusCode::BAD_REQUEST, "malformed token".to_string()))?;
+        probe.insert("x-ploy-api-key", value);
+        authenticate(&state, &probe)
+            .await
+            .is_ok_and(|p| p.key_id.is_some())
+    };
+    if !valid {
+        if expected_admin_token().is_none() && !admin_auth_required() {
             return Ok((
                 HeaderMap::new(),
                 Json(AuthMutationResponse { success: true }),
             ));
         }
-        return Err((
-            StatusCode::SERVICE_UNAVAILABLE,
-            "admin auth is required but PLOY_API_ADMIN_TOKEN is not configured".to_string(),
-        ));
-    };
-    if !is_valid_admin_token(&req.admin_token) {
         return Err((
             StatusCode::UNAUTHORIZED,
-            "admin auth failed (missing/invalid token)".to_string(),
+            "auth failed (missing/invalid admin token or API key)".to_string(),
         ));
     }
 
     let mut headers = HeaderMap::new();
-    let cookie = build_admin_session_cookie(&expected);
+    let cookie = build_admin_session_cookie(token);
     let cookie_value = HeaderValue::from_str(&cookie).map_err(|e| {
         (
             StatusCode::INTERNAL_SERVER_ERROR,
@@ -98,3 +181,155 @@ pub async fn logout_admin(
     headers.insert(SET_COOKIE, cookie_value);
     Ok((headers, Json(AuthMutationResponse { success: true })))
 }
+
+/// GET /api/auth/keys
+pub async fn list_api_keys(
+    State(state): State<AppState>,
+    headers: HeaderMap,
+) -> std::result::Result<Json<Vec<ApiKeyResponse>>, (StatusCode, String)> {
+    authorize(&state, &headers, Permission::Admin).await?;
+
+    let rows: Vec<ApiKeyRow> = sqlx::query_as(
+        r#"
+        SELECT id, name, role, key_prefix, created_by, created_at, last_used_at, revoked_at
+        FROM api_keys
+        ORDER BY id
+        "#,
+    )
+    .fetch_all(state.store.pool())
+    .await
+    .map_err(db_error)?;
+
+    Ok(Json(rows.into_iter().map(api_key_response).collect()))
+}
+
+/// POST /api/auth/keys
+pub async fn create_api_key(
+    State(state): State<AppState>,
+    headers: HeaderMap,
+    Json(req): Json<CreateApiKeyRequest>,
+) -> std::result::Result<(StatusCode, Json<ApiKeyResponse>), (StatusCode, String)> {
+    let name = req.name.trim();
+    let principal = authorize_action(
+        &state,
+        &headers,
+        Permission::Admin,
+        "api_keys.create",
+        serde_json::json!({ "name": name, "role": req.role.as_str() }),
+    )
+    .await?;
+    if name.is_empty() {
+        return Err((StatusCode::BAD_REQUEST, "name is required".to_string()));
+    }
+
+    let key = generate_api_key();
+    let row: ApiKeyRow = sqlx::query_as(
+        r#"
+        INSERT INTO api_keys (name, role, key_hash, key_prefix, created_by)
+        VALUES ($1, $2, $3, $4, $5)
+        RETURNING id, name, role, key_prefix, created_by, created_at, last_used_at, revoked_at
+        "#,
+    )
+    .bind(name)
+    .bind(req.role.as_str())
+    .bind(admin_token_fingerprint(&key))
+    .bind(&key[..12])
+    .bind(&principal.actor)
+    .fetch_one(state.store.pool())
+    .await
+    .map_err(|e| match &e {
+        sqlx::Error::Database(db) if db.is_unique_violation() => (
+            StatusCode::CONFLICT,
+            format!("an API key named '{name}' already exists"),
+        ),
+        _ => db_error(e),
+    })?;
+
+    let mut response = api_key_response(row);
+    response.key = Some(key);
+    Ok((StatusCode::CREATED, Json(response)))
+}
+
+/// DELETE /api/auth/keys/:id
+///
+/// Revokes the key; the row is kept so audit entries still resolve.
+pub async fn revoke_api_key(
+    State(state): State<AppState>,
+    headers: HeaderMap,
+    Path(id): Path<i64>,
+) -> std::result::Result<StatusCode, (StatusCode, String)> {
+    authorize_action(
+        &state,
+        &headers,
+        Permission::Admin,
+        "api_keys.revoke",
+        serde_json::json!({ "key_id": id }),
+    )
+    .await?;
+
+    let result =
+        sqlx::query("UPDATE api_keys SET revoked_at = NOW() WHERE id = $1 AND revoked_at IS NULL")
+            .bind(id)
+            .execute(state.store.pool())
+            .await
+            .map_err(db_error)?;
+    if result.rows_affected() == 0 {
+        return Err((
+            StatusCode::NOT_FOUND,
+            "active API key not found".to_string(),
+        ));
+    }
+    Ok(StatusCode::NO_CONTENT)
+}
+
+/// GET /api/auth/audit
+pub async fn list_audit_log(
+    State(state): State<AppState>,
+    headers: HeaderMap,
+    Query(query): Query<AuditLogQuery>,
+) -> std::result::Result<Json<Vec<AuditLogEntry>>, (StatusCode, String)> {
+    authorize(&state, &headers, Permission::Admin).await?;
+    let limit = query.limit.unwrap_or(100).clamp(1, 1000);
+
+    #[allow(clippy::type_complexity)]
+    let rows: Vec<(
+        i64,
+        String,
+        String,
+        Option<i64>,
+        String,
+        bool,
+        serde_json::Value,
+        DateTime<Utc>,
+    )> = sqlx::query_as(
+        r#"
+        SELECT id, actor, role, key_id, action, allowed, details, created_at
+        FROM api_audit_log
+        WHERE ($1::text IS NULL OR actor = $1)
+          AND ($2::text IS NULL OR action LIKE $2 || '%')
+        ORDER BY created_at DESC
+        LIMIT $3
+        "#,
+    )
+    .bind(query.actor.as_deref())
+    .bind(query.action.as_deref())
+    .bind(limit)
+    .fetch_all(state.store.pool())
+    .await
+    .map_err(db_error)?;
+
+    Ok(Json(
+        rows.into_iter()
+            .map(|row| AuditLogEntry {
+                id: row.0,
+                actor: row.1,
+                role: row.2,
+                key_id: row.3,
+                action: row.4,
+                allowed: row.5,
+                details: row.6,
+                created_at: row.7,
+            })
+            .collect(),
+    ))
+}
@@ -95,12 +95,24 @@ fn capability_endpoints(governance_available: bool) -> Vec<CapabilityEndpoint> {
                     .to_string(),
             auth: "x-ploy-admin-token".to_string(),
         },
+        CapabilityEndpoint {
+            path: "/api/journal|/api/journal/:trade_id".to_string(),
+            method: "GET/POST".to_string(),
+            description: "Trade journal notes, tags and post-mortem classifications".to_string(),
+            auth: "x-ploy-admin-token".to_string(),
+        },
         CapabilityEndpoint {
             path: "/api/system/pause|resume|halt".to_string(),
             method: "POST".to_string(),
             description: "Global/domain runtime control commands".to_string(),
             auth: "x-ploy-admin-token".to_string(),
         },
+        CapabilityEndpoint {
+            path: "/api/system/agents/:id/standby|resume".to_string(),
+            method: "POST".to_string(),
+            description: "Per-agent warm standby (would-trade logging) and go-live".to_string(),
+            auth: "x-ploy-admin-token".to_string(),
+        },
     ];
 
     if governance_available {
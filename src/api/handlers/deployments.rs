@@ -7,7 +7,10 @@ use chrono::{Duration as ChronoDuration, Utc};
 use serde::{Deserialize, Serialize};
 use sqlx::Row;
 
-use crate::api::{auth::ensure_admin_authorized, state::AppState};
+use crate::api::{
+    auth::{authorize, authorize_action, Permission},
+    state::AppState,
+};
 use crate::platform::StrategyDeployment;
 
 #[derive(Debug, Deserialize)]
@@ -72,6 +75,43 @@ fn required_evidence_stages() -> Vec<String> {
     out
 }
 
+/// Backtest evidence must carry passing anti-overfit scores
+/// (`evidence_payload.overfit`, see `analysis::overfit`).
+fn overfit_checks_required() -> bool {
+    std::env::var("PLOY_DEPLOYMENTS_REQUIRE_OVERFIT_CHECKS")
+        .ok()
+        .map(|v| parse_boolish(&v))
+        .unwrap_or(true)
+}
+
+/// Why backtest evidence fails the overfit requirement, if it does.
+fn overfit_evidence_violation(overfit: Option<&serde_json::Value>) -> Option<String> {
+    let Some(overfit) = overfit.filter(|v| v.is_object()) else {
+        return Some("missing overfit scores".to_string());
+    };
+    if overfit.get("deflated_sharpe").is_none()
+        || overfit.get("neighborhood").is_none()
+        || overfit.get("cpcv").is_none()
+    {
+        return Some("incomplete overfit scores".to_string());
+    }
+    if overfit.get("passed").and_then(|v| v.as_bool()) != Some(true) {
+        let failures = overfit
+            .get("failures")
+            .and_then(|v| v.as_array())
+            .map(|items| {
+                items
+                    .iter()
+                    .filter_map(|v| v.as_str())
+                    .collect::<Vec<_>>()
+                    .join("; ")
+            })
+            .unwrap_or_default();
+        return Some(format!("overfit checks failed ({})", failures));
+    }
+    None
+}
+
 fn max_evidence_age_hours() -> i64 {
     std::env::var("PLOY_DEPLOYMENTS_MAX_EVIDENCE_AGE_HOURS")
         .ok()
@@ -109,7 +149,8 @@ async fn ensure_required_strategy_evidence(
                 evaluated_at,
                 NULLIF(BTRIM(evidence_ref), '') AS evidence_ref,
                 NULLIF(BTRIM(evidence_hash), '') AS evidence_hash,
-                (evidence_payload IS NOT NULL) AS has_payload
+                (evidence_payload IS NOT NULL) AS has_payload,
+                evidence_payload -> 'overfit' AS overfit
             FROM strategy_evaluations
             WHERE account_id = $1
               AND stage = $2
@@ -188,6 +229,21 @@ async fn ensure_required_strategy_evidence(
                 ),
             ));
         }
+        if stage == "BACKTEST" && overfit_checks_required() {
+            let overfit = row
+                .try_get::<Option<serde_json::Value>, _>("overfit")
+                .ok()
+                .flatten();
+            if let Some(reason) = overfit_evidence_violation(overfit.as_ref()) {
+                return Err((
+                    StatusCode::UNPROCESSABLE_ENTITY,
+                    format!(
+                        "deployment '{}' cannot be enabled: latest {} evidence {}",
+                        deployment.id, stage, reason
+                    ),
+                ));
+            }
+        }
         if Utc::now().signed_duration_since(evaluated_at) > max_age {
             return Err((
                 StatusCode::UNPROCESSABLE_ENTITY,
@@ -209,7 +265,7 @@ pub async fn list_deployments(
     State(state): State<AppState>,
     headers: HeaderMap,
 ) -> std::result::Result<Json<Vec<StrategyDeployment>>, (StatusCode, String)> {
-    ensure_admin_authorized(&headers)?;
+    authorize(&state, &headers, Permission::Read).await?;
     let deployments = state.deployments.read().await;
     let mut items: Vec<StrategyDeployment> = deployments.values().cloned().collect();
     items.sort_by(|a, b| a.id.cmp(&b.id));
@@ -222,7 +278,7 @@ pub async fn get_deployment(
     headers: HeaderMap,
     Path(id): Path<String>,
 ) -> std::result::Result<Json<StrategyDeployment>, (StatusCode, String)> {
-    ensure_admin_authorized(&headers)?;
+    authorize(&state, &headers, Permission::Read).await?;
     let deployments = state.deployments.read().await;
     let key = id.trim();
     if key.is_empty() {
@@ -245,7 +301,14 @@ pub async fn upsert_deployments(
     headers: HeaderMap,
     Json(req): Json<UpsertDeploymentsRequest>,
 ) -> std::result::Result<Json<UpsertDeploymentsResponse>, (StatusCode, String)> {
-    ensure_admin_authorized(&headers)?;
+    authorize_action(
+        &state,
+        &headers,
+        Permission::Admin,
+        "deployments.upsert",
+        serde_json::json!({}),
+    )
+    .await?;
     if req.deployments.is_empty() {
         return Err((
             StatusCode::BAD_REQUEST,
@@ -303,7 +366,14 @@ pub async fn enable_deployment(
     headers: HeaderMap,
     Path(id): Path<String>,
 ) -> std::result::Result<Json<DeploymentMutationResponse>, (StatusCode, String)> {
-    ensure_admin_authorized(&headers)?;
+    authorize_action(
+        &state,
+        &headers,
+        Permission::Control,
+        "deployments.enable",
+        serde_json::json!({ "deployment_id": id.trim() }),
+    )
+    .await?;
     set_deployment_enabled(state, id, true).await
 }
 
@@ -313,7 +383,14 @@ pub async fn disable_deployment(
     headers: HeaderMap,
     Path(id): Path<String>,
 ) -> std::result::Result<Json<DeploymentMutationResponse>, (StatusCode, String)> {
-    ensure_admin_authorized(&headers)?;
+    authorize_action(
+        &state,
+        &headers,
+        Permission::Control,
+        "deployments.disable",
+        serde_json::json!({ "deployment_id": id.trim() }),
+    )
+    .await?;
     set_deployment_enabled(state, id, false).await
 }
 
@@ -370,7 +447,14 @@ pub async fn delete_deployment(
     headers: HeaderMap,
     Path(id): Path<String>,
 ) -> std::result::Result<Json<DeploymentDeleteResponse>, (StatusCode, String)> {
-    ensure_admin_authorized(&headers)?;
+    authorize_action(
+        &state,
+        &headers,
+        Permission::Admin,
+        "deployments.delete",
+        serde_json::json!({ "deployment_id": id.trim() }),
+    )
+    .await?;
     let key = id.trim();
     if key.is_empty() {
         return Err((
@@ -6,7 +6,10 @@ use axum::{
 use rust_decimal::Decimal;
 use serde::Deserialize;
 
-use crate::api::{auth::ensure_admin_authorized, state::AppState};
+use crate::api::{
+    auth::{authorize, authorize_action, Permission},
+    state::AppState,
+};
 use crate::coordinator::{
     GovernancePolicyHistoryEntry, GovernancePolicySnapshot, GovernancePolicyUpdate,
     GovernanceStatusSnapshot,
@@ -37,7 +40,7 @@ pub async fn get_governance_policy(
     State(state): State<AppState>,
     headers: HeaderMap,
 ) -> std::result::Result<Json<GovernancePolicySnapshot>, (StatusCode, String)> {
-    ensure_admin_authorized(&headers)?;
+    authorize(&state, &headers, Permission::Read).await?;
     let Some(coordinator) = state.coordinator.as_ref() else {
         return Err((
             StatusCode::SERVICE_UNAVAILABLE,
@@ -52,7 +55,7 @@ pub async fn get_governance_status(
     State(state): State<AppState>,
     headers: HeaderMap,
 ) -> std::result::Result<Json<GovernanceStatusSnapshot>, (StatusCode, String)> {
-    ensure_admin_authorized(&headers)?;
+    authorize(&state, &headers, Permission::Read).await?;
     let Some(coordinator) = state.coordinator.as_ref() else {
         return Err((
             StatusCode::SERVICE_UNAVAILABLE,
@@ -68,7 +71,7 @@ pub async fn get_governance_policy_history(
     headers: HeaderMap,
     Query(query): Query<GovernancePolicyHistoryQuery>,
 ) -> std::result::Result<Json<Vec<GovernancePolicyHistoryEntry>>, (StatusCode, String)> {
-    ensure_admin_authorized(&headers)?;
+    authorize(&state, &headers, Permission::Read).await?;
     let Some(coordinator) = state.coordinator.as_ref() else {
         return Err((
             StatusCode::SERVICE_UNAVAILABLE,
@@ -92,7 +95,14 @@ pub async fn put_governance_policy(
     headers: HeaderMap,
     Json(req): Json<GovernancePolicyUpdateRequest>,
 ) -> std::result::Result<Json<GovernancePolicySnapshot>, (StatusCode, String)> {
-    ensure_admin_authorized(&headers)?;
+    authorize_action(
+        &state,
+        &headers,
+        Permission::Admin,
+        "governance.update_policy",
+        serde_json::json!({}),
+    )
+    .await?;
     let Some(coordinator) = state.coordinator.as_ref() else {
         return Err((
             StatusCode::SERVICE_UNAVAILABLE,
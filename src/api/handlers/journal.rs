@@ -0,0 +1,86 @@
+use axum::{
+    extract::{Path, Query, State},
+    http::{HeaderMap, StatusCode},
+    Json,
+};
+
+use crate::api::auth::{authorize, authorize_action, Permission};
+use crate::api::state::AppState;
+use crate::error::PloyError;
+use crate::strategy::{
+    AnnotatedTrade, JournalEntry, JournalQuery, JournalUpdate, TradeJournal, TradeLogger,
+};
+
+fn journal_error(err: PloyError) -> (StatusCode, String) {
+    match err {
+        PloyError::Validation(msg) => (StatusCode::BAD_REQUEST, msg),
+        other => (StatusCode::INTERNAL_SERVER_ERROR, other.to_string()),
+    }
+}
+
+async fn load_trade_logger() -> std::result::Result<TradeLogger, (StatusCode, String)> {
+    let logger = TradeLogger::default_path();
+    logger.load().await.map_err(journal_error)?;
+    Ok(logger)
+}
+
+/// GET /api/journal
+pub async fn list_journal(
+    State(state): State<AppState>,
+    headers: HeaderMap,
+    Query(mut query): Query<JournalQuery>,
+) -> std::result::Result<Json<Vec<AnnotatedTrade>>, (StatusCode, String)> {
+    authorize(&state, &headers, Permission::Read).await?;
+    query.limit = Some(query.limit.unwrap_or(100).clamp(1, 500));
+
+    let logger = load_trade_logger().await?;
+    let trades = TradeJournal::from_env()
+        .query(&logger.get_all_trades().await, &query)
+        .map_err(journal_error)?;
+    Ok(Json(trades))
+}
+
+/// GET /api/journal/:trade_id
+pub async fn get_journal_trade(
+    State(state): State<AppState>,
+    headers: HeaderMap,
+    Path(trade_id): Path<String>,
+) -> std::result::Result<Json<AnnotatedTrade>, (StatusCode, String)> {
+    authorize(&state, &headers, Permission::Read).await?;
+
+    let logger = load_trade_logger().await?;
+    let Some(trade) = logger.get_trade(trade_id.trim()).await else {
+        return Err((StatusCode::NOT_FOUND, "trade not found".to_string()));
+    };
+    let journal = TradeJournal::from_env()
+        .get(&trade.id)
+        .map_err(journal_error)?;
+    Ok(Json(AnnotatedTrade { trade, journal }))
+}
+
+/// POST /api/journal/:trade_id
+pub async fn annotate_journal_trade(
+    State(state): State<AppState>,
+    headers: HeaderMap,
+    Path(trade_id): Path<String>,
+    Json(update): Json<JournalUpdate>,
+) -> std::result::Result<Json<JournalEntry>, (StatusCode, String)> {
+    authorize_action(
+        &state,
+        &headers,
+        Permission::Control,
+        "journal.annotate",
+        serde_json::json!({ "trade_id": trade_id.trim() }),
+    )
+    .await?;
+
+    let logger = load_trade_logger().await?;
+    if logger.get_trade(trade_id.trim()).await.is_none() {
+        return Err((StatusCode::NOT_FOUND, "trade not found".to_string()));
+    }
+    let entry = TradeJournal::from_env()
+        .annotate(&trade_id, &update)
+        .await
+        .map_err(journal_error)?;
+    Ok(Json(entry))
+}
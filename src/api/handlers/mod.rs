@@ -1,21 +1,31 @@
+pub mod approvals;
 pub mod auth;
 pub mod capabilities;
 pub mod deployments;
 pub mod evaluations;
 pub mod governance;
+pub mod journal;
+pub mod risk;
 pub mod sidecar;
 pub mod stats;
 pub mod strategies;
 pub mod strategy_evaluations;
 pub mod system;
+pub mod trading;
+pub mod webhooks;
 
+pub use approvals::*;
 pub use auth::*;
 pub use capabilities::*;
 pub use deployments::*;
 pub use evaluations::*;
 pub use governance::*;
+pub use journal::*;
+pub use risk::*;
 pub use sidecar::*;
 pub use stats::*;
 pub use strategies::*;
 pub use strategy_evaluations::*;
 pub use system::*;
+pub use trading::*;
+pub use webhooks::*;
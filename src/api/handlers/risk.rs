@@ -0,0 +1,199 @@
+use axum::{
+    extract::{Query, State},
+    http::{HeaderMap, StatusCode},
+    Json,
+};
+use chrono::{DateTime, NaiveDate, Utc};
+use serde::{Deserialize, Serialize};
+
+use crate::api::auth::{authorize, authorize_action, Permission};
+use crate::api::state::AppState;
+use crate::coordinator::heatmap::{self, ExposureHeatmap, HeatmapThresholds};
+use crate::coordinator::loss_limit::{self, LossLimitBreach, GLOBAL_SCOPE};
+use crate::platform::NotionalUsage;
+use crate::supervisor::VenueHealthSnapshot;
+
+#[derive(Debug, Serialize)]
+pub struct LossLimitStatusResponse {
+    pub date: NaiveDate,
+    /// Halts held by the running coordinator (empty without one)
+    pub active_halts: Vec<LossLimitBreach>,
+    /// Halted scopes recorded in `daily_metrics`
+    pub persisted_scopes: Vec<String>,
+    pub halt_reason: Option<String>,
+    pub halted_at: Option<DateTime<Utc>>,
+    pub reenabled_by: Option<String>,
+    pub reenabled_at: Option<DateTime<Utc>>,
+}
+
+#[derive(Debug, Deserialize)]
+pub struct LossLimitReenableRequest {
+    /// `global` (default) or an agent id
+    #[serde(default)]
+    pub scope: Option<String>,
+    #[serde(default)]
+    pub operator: Option<String>,
+}
+
+#[derive(Debug, Serialize)]
+pub struct LossLimitReenableResponse {
+    pub scope: String,
+    pub operator: String,
+}
+
+/// GET /api/risk/loss-limit
+pub async fn get_loss_limit_status(
+    State(state): State<AppState>,
+    headers: HeaderMap,
+) -> std::result::Result<Json<LossLimitStatusResponse>, (StatusCode, String)> {
+    authorize(&state, &headers, Permission::Read).await?;
+
+    let date = Utc::now().date_naive();
+    let persisted = loss_limit::load_halts(state.store.pool(), date)
+        .await
+        .map_err(|e| (StatusCode::INTERNAL_SERVER_ERROR, e.to_string()))?;
+    let active_halts = match state.coordinator.as_ref() {
+        Some(coordinator) => coordinator.loss_limit_halts().await,
+        None => Vec::new(),
+    };
+
+    Ok(Json(LossLimitStatusResponse {
+        date,
+        active_halts,
+        persisted_scopes: persisted.scopes,
+        halt_reason: persisted.halt_reason,
+        halted_at: persisted.halted_at,
+        reenabled_by: persisted.reenabled_by,
+        reenabled_at: persisted.reenabled_at,
+    }))
+}
+
+/// POST /api/risk/loss-limit/reenable
+///
+/// Lifts an intraday loss limit halt for today. The scope gets a fresh loss
+/// budget measured from its current PnL.
+pub async fn reenable_loss_limit(
+    State(state): State<AppState>,
+    headers: HeaderMap,
+    Json(req): Json<LossLimitReenableRequest>,
+) -> std::result::Result<Json<LossLimitReenableResponse>, (StatusCode, String)> {
+    let scope = req
+        .scope
+        .as_deref()
+        .map(str::trim)
+        .filter(|s| !s.is_empty())
+        .unwrap_or(GLOBAL_SCOPE)
+        .to_string();
+    let principal = authorize_action(
+        &state,
+        &headers,
+        Permission::Control,
+        "risk.loss_limit.reenable",
+        serde_json::json!({ "scope": &scope }),
+    )
+    .await?;
+    let operator = req.operator.unwrap_or(principal.actor);
+
+    let cleared = loss_limit::clear_halt(
+        state.store.pool(),
+        Utc::now().date_naive(),
+        &scope,
+        &operator,
+    )
+    .await
+    .map_err(|e| (StatusCode::INTERNAL_SERVER_ERROR, e.to_string()))?;
+
+    let mut active = false;
+    if let Some(coordinator) = state.coordinator.as_ref() {
+        active = coordinator
+            .loss_limit_halts()
+            .await
+            .iter()
+            .any(|h| h.scope == scope);
+        if active {
+            coordinator
+                .reenable_loss_limit(&scope)
+                .await
+                .map_err(|e| (StatusCode::INTERNAL_SERVER_ERROR, e.to_string()))?;
+        }
+    }
+
+    if !cleared && !active {
+        return Err((
+            StatusCode::CONFLICT,
+            format!("{scope} is not halted by the intraday loss limit"),
+        ));
+    }
+    Ok(Json(LossLimitReenableResponse { scope, operator }))
+}
+
+/// GET /api/risk/venue-status
+///
+/// Latest exchange / chain health reading and the de-risking actions in force.
+pub async fn get_venue_status(
+    State(state): State<AppState>,
+    headers: HeaderMap,
+) -> std::result::Result<Json<VenueHealthSnapshot>, (StatusCode, String)> {
+    authorize(&state, &headers, Permission::Read).await?;
+
+    let coordinator = state.coordinator.as_ref().ok_or_else(|| {
+        (
+            StatusCode::SERVICE_UNAVAILABLE,
+            "coordinator unavailable in this runtime".to_string(),
+        )
+    })?;
+    Ok(Json(coordinator.venue_health().snapshot()))
+}
+
+/// GET /api/risk/notional
+///
+/// Sliding-window BUY notional used against the throttle limits, platform-wide
+/// first and then per agent.
+pub async fn get_notional_usage(
+    State(state): State<AppState>,
+    headers: HeaderMap,
+) -> std::result::Result<Json<Vec<NotionalUsage>>, (StatusCode, String)> {
+    authorize(&state, &headers, Permission::Read).await?;
+
+    let coordinator = state.coordinator.as_ref().ok_or_else(|| {
+        (
+            StatusCode::SERVICE_UNAVAILABLE,
+            "coordinator unavailable in this runtime".to_string(),
+        )
+    })?;
+    Ok(Json(coordinator.notional_usage().await))
+}
+
+/// GET /api/risk/heatmap
+///
+/// Current exposure bucketed by underlying symbol, domain and minutes to
+/// settlement. Thresholds can be overridden with `concentration_pct`,
+/// `near_term_minutes` and `near_term_pct` query parameters.
+pub async fn get_risk_heatmap(
+    State(state): State<AppState>,
+    headers: HeaderMap,
+    Query(thresholds): Query<HeatmapThresholds>,
+) -> std::result::Result<Json<ExposureHeatmap>, (StatusCode, String)> {
+    authorize(&state, &headers, Permission::Read).await?;
+
+    let coordinator = state.coordinator.as_ref().ok_or_else(|| {
+        (
+            StatusCode::SERVICE_UNAVAILABLE,
+            "coordinator unavailable in this runtime".to_string(),
+        )
+    })?;
+    let positions = coordinator.read_state().await.positions;
+    let mut slugs: Vec<String> = positions.iter().map(|p| p.market_slug.clone()).collect();
+    slugs.sort();
+    slugs.dedup();
+    let markets = heatmap::load_market_settlements(state.store.pool(), &slugs)
+        .await
+        .map_err(|e| (StatusCode::INTERNAL_SERVER_ERROR, e.to_string()))?;
+
+    Ok(Json(ExposureHeatmap::build(
+        &positions,
+        &markets,
+        thresholds,
+        Utc::now(),
+    )))
+}
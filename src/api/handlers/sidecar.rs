@@ -239,6 +239,8 @@ pub struct SidecarRiskState {
     pub max_drawdown_observed_usd: f64,
     pub drawdown_limit_usd: Option<f64>,
     pub queue_depth: usize,
+    /// Age of the oldest queued intent (ms)
+    pub queue_oldest_age_ms: i64,
     pub positions: Vec<SidecarRiskPosition>,
     pub circuit_breaker_events: Vec<SidecarCircuitBreakerEvent>,
 }
@@ -1592,6 +1594,7 @@ pub async fn sidecar_get_risk(
                 max_drawdown_observed_usd: global.max_drawdown_observed.to_f64().unwrap_or(0.0),
                 drawdown_limit_usd: global.max_drawdown_limit.and_then(|v| v.to_f64()),
                 queue_depth: global.queue_stats.current_size,
+                queue_oldest_age_ms: global.queue_stats.oldest_age_ms,
                 positions,
                 circuit_breaker_events,
             }))
@@ -1798,6 +1801,7 @@ pub async fn sidecar_get_risk(
                     .and_then(|v| Decimal::from_str(v.trim()).ok())
                     .and_then(|v| v.to_f64()),
                 queue_depth: 0,
+                queue_oldest_age_ms: 0,
                 positions,
                 circuit_breaker_events,
             }))
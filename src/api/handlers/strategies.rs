@@ -9,7 +9,11 @@ use rust_decimal::prelude::ToPrimitive;
 use serde::Serialize;
 use std::collections::HashMap;
 
-use crate::api::{auth::ensure_admin_authorized, state::AppState, types::RunningStrategy};
+use crate::api::{
+    auth::{authorize, authorize_action, Permission},
+    state::AppState,
+    types::RunningStrategy,
+};
 use crate::platform::{
     AgentStatus, Domain, MarketSelector, StrategyDeployment, StrategyLifecycleStage,
     StrategyProductType,
@@ -176,6 +180,7 @@ pub async fn get_running_strategies(
                 AgentStatus::Paused => "paused",
                 AgentStatus::Error => "error",
                 AgentStatus::Stopped => "paused",
+                AgentStatus::Standby => "standby",
                 AgentStatus::Initializing | AgentStatus::Running | AgentStatus::Observing => {
                     "running"
                 }
@@ -222,7 +227,7 @@ pub async fn get_strategies_control(
     State(state): State<AppState>,
     headers: HeaderMap,
 ) -> std::result::Result<Json<StrategiesControlResponse>, (StatusCode, String)> {
-    ensure_admin_authorized(&headers)?;
+    authorize(&state, &headers, Permission::Read).await?;
 
     let (account_id, ingress_mode, domain_modes, domain_agents) =
         if let Some(coordinator) = state.coordinator.as_ref() {
@@ -325,7 +330,14 @@ pub async fn update_strategy_control(
     Path(id): Path<String>,
     Json(req): Json<UpdateStrategyControlRequest>,
 ) -> std::result::Result<Json<StrategyControlMutationResponse>, (StatusCode, String)> {
-    ensure_admin_authorized(&headers)?;
+    authorize_action(
+        &state,
+        &headers,
+        Permission::Control,
+        "strategies.update_control",
+        serde_json::json!({ "deployment_id": id.trim() }),
+    )
+    .await?;
 
     let key = id.trim();
     if key.is_empty() {
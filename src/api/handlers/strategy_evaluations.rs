@@ -8,7 +8,10 @@ use serde::{Deserialize, Serialize};
 use std::collections::HashMap;
 use uuid::Uuid;
 
-use crate::api::{auth::ensure_admin_authorized, state::AppState};
+use crate::api::{
+    auth::{authorize, authorize_action, Permission},
+    state::AppState,
+};
 use crate::platform::{
     StrategyEvaluationEvidence, StrategyEvaluationMetrics, StrategyEvaluationStage,
     StrategyLifecycleStage, StrategyProductType,
@@ -107,7 +110,7 @@ pub async fn list_strategy_evaluations(
     headers: HeaderMap,
     Query(query): Query<StrategyEvaluationsQuery>,
 ) -> std::result::Result<Json<Vec<StrategyEvaluationEvidence>>, (StatusCode, String)> {
-    ensure_admin_authorized(&headers)?;
+    authorize(&state, &headers, Permission::Read).await?;
 
     let deployment_id = normalize_opt(&query.deployment_id);
     let strategy = normalize_opt(&query.strategy).map(|v| v.to_ascii_lowercase());
@@ -164,7 +167,7 @@ pub async fn get_latest_strategy_evaluation(
     Path(deployment_id): Path<String>,
     Query(query): Query<StrategyEvaluationsQuery>,
 ) -> std::result::Result<Json<StrategyEvaluationEvidence>, (StatusCode, String)> {
-    ensure_admin_authorized(&headers)?;
+    authorize(&state, &headers, Permission::Read).await?;
     let deployment_id = deployment_id.trim();
     if deployment_id.is_empty() {
         return Err((
@@ -208,7 +211,14 @@ pub async fn create_strategy_evaluation(
     headers: HeaderMap,
     Json(req): Json<CreateStrategyEvaluationRequest>,
 ) -> std::result::Result<Json<CreateStrategyEvaluationResponse>, (StatusCode, String)> {
-    ensure_admin_authorized(&headers)?;
+    authorize_action(
+        &state,
+        &headers,
+        Permission::Control,
+        "strategy_evaluations.create",
+        serde_json::json!({}),
+    )
+    .await?;
 
     let deployment_id = req.deployment_id.trim();
     if deployment_id.is_empty() {
@@ -1,10 +1,15 @@
-use axum::{extract::State, http::HeaderMap, http::StatusCode, Json};
+use axum::{
+    extract::{Path, State},
+    http::HeaderMap,
+    http::StatusCode,
+    Json,
+};
 use serde::Deserialize;
 use sqlx::{postgres::Postgres, QueryBuilder, Row};
 use std::collections::{BTreeSet, HashMap};
 
 use crate::api::{
-    auth::ensure_admin_authorized,
+    auth::{authorize, authorize_action, Permission},
     state::{AppState, SystemRunStatus},
     types::*,
 };
@@ -207,7 +212,7 @@ pub async fn get_system_accounts(
     State(state): State<AppState>,
     headers: HeaderMap,
 ) -> std::result::Result<Json<AccountsOverview>, (StatusCode, String)> {
-    ensure_admin_authorized(&headers)?;
+    authorize(&state, &headers, Permission::Read).await?;
 
     let runtime_account = state.account_id.trim().to_string();
 
@@ -286,7 +291,14 @@ pub async fn start_system(
     State(state): State<AppState>,
     headers: HeaderMap,
 ) -> std::result::Result<Json<SystemControlResponse>, (StatusCode, String)> {
-    ensure_admin_authorized(&headers)?;
+    authorize_action(
+        &state,
+        &headers,
+        Permission::Control,
+        "system.start",
+        serde_json::json!({}),
+    )
+    .await?;
     let Some(coordinator) = state.coordinator.as_ref() else {
         return Err((
             StatusCode::SERVICE_UNAVAILABLE,
@@ -329,7 +341,14 @@ pub async fn stop_system(
     State(state): State<AppState>,
     headers: HeaderMap,
 ) -> std::result::Result<Json<SystemControlResponse>, (StatusCode, String)> {
-    ensure_admin_authorized(&headers)?;
+    authorize_action(
+        &state,
+        &headers,
+        Permission::Control,
+        "system.stop",
+        serde_json::json!({}),
+    )
+    .await?;
     let Some(coordinator) = state.coordinator.as_ref() else {
         return Err((
             StatusCode::SERVICE_UNAVAILABLE,
@@ -372,7 +391,14 @@ pub async fn restart_system(
     State(state): State<AppState>,
     headers: HeaderMap,
 ) -> std::result::Result<Json<SystemControlResponse>, (StatusCode, String)> {
-    ensure_admin_authorized(&headers)?;
+    authorize_action(
+        &state,
+        &headers,
+        Permission::Control,
+        "system.restart",
+        serde_json::json!({}),
+    )
+    .await?;
     let Some(coordinator) = state.coordinator.as_ref() else {
         return Err((
             StatusCode::SERVICE_UNAVAILABLE,
@@ -429,8 +455,15 @@ pub async fn pause_system(
     headers: HeaderMap,
     req: Option<Json<DomainControlRequest>>,
 ) -> std::result::Result<Json<SystemControlResponse>, (StatusCode, String)> {
-    ensure_admin_authorized(&headers)?;
     let domain = parse_domain_control_request(req)?;
+    authorize_action(
+        &state,
+        &headers,
+        Permission::Control,
+        "system.pause",
+        serde_json::json!({ "domain": domain.as_ref().map(ToString::to_string) }),
+    )
+    .await?;
     let Some(coordinator) = state.coordinator.as_ref() else {
         return Err((
             StatusCode::SERVICE_UNAVAILABLE,
@@ -467,8 +500,15 @@ pub async fn resume_system(
     headers: HeaderMap,
     req: Option<Json<DomainControlRequest>>,
 ) -> std::result::Result<Json<SystemControlResponse>, (StatusCode, String)> {
-    ensure_admin_authorized(&headers)?;
     let domain = parse_domain_control_request(req)?;
+    authorize_action(
+        &state,
+        &headers,
+        Permission::Control,
+        "system.resume",
+        serde_json::json!({ "domain": domain.as_ref().map(ToString::to_string) }),
+    )
+    .await?;
     let Some(coordinator) = state.coordinator.as_ref() else {
         return Err((
             StatusCode::SERVICE_UNAVAILABLE,
@@ -499,6 +539,74 @@ pub async fn resume_system(
     }))
 }
 
+/// POST /api/system/agents/:id/standby
+///
+/// Keep the agent computing with warm feeds, but log its entries as
+/// would-trade instead of executing them.
+pub async fn standby_agent(
+    State(state): State<AppState>,
+    headers: HeaderMap,
+    Path(agent_id): Path<String>,
+) -> std::result::Result<Json<SystemControlResponse>, (StatusCode, String)> {
+    authorize_action(
+        &state,
+        &headers,
+        Permission::Control,
+        "system.agent_standby",
+        serde_json::json!({ "agent_id": agent_id }),
+    )
+    .await?;
+    let Some(coordinator) = state.coordinator.as_ref() else {
+        return Err((
+            StatusCode::SERVICE_UNAVAILABLE,
+            "coordinator unavailable in this runtime".to_string(),
+        ));
+    };
+    coordinator
+        .standby_agent(&agent_id)
+        .await
+        .map_err(|e| (StatusCode::INTERNAL_SERVER_ERROR, e.to_string()))?;
+
+    Ok(Json(SystemControlResponse {
+        success: true,
+        message: format!("{} standby", agent_id),
+    }))
+}
+
+/// POST /api/system/agents/:id/resume
+///
+/// Take the agent out of pause or standby; from standby this is instant
+/// because its feeds and models are already warm.
+pub async fn resume_agent(
+    State(state): State<AppState>,
+    headers: HeaderMap,
+    Path(agent_id): Path<String>,
+) -> std::result::Result<Json<SystemControlResponse>, (StatusCode, String)> {
+    authorize_action(
+        &state,
+        &headers,
+        Permission::Control,
+        "system.agent_resume",
+        serde_json::json!({ "agent_id": agent_id }),
+    )
+    .await?;
+    let Some(coordinator) = state.coordinator.as_ref() else {
+        return Err((
+            StatusCode::SERVICE_UNAVAILABLE,
+            "coordinator unavailable in this runtime".to_string(),
+        ));
+    };
+    coordinator
+        .resume_agent(&agent_id)
+        .await
+        .map_err(|e| (StatusCode::INTERNAL_SERVER_ERROR, e.to_string()))?;
+
+    Ok(Json(SystemControlResponse {
+        success: true,
+        message: format!("{} live", agent_id),
+    }))
+}
+
 /// POST /api/system/halt
 ///
 /// Force-close all positions and mark the system as stopped.
@@ -507,8 +615,15 @@ pub async fn halt_system(
     headers: HeaderMap,
     req: Option<Json<DomainControlRequest>>,
 ) -> std::result::Result<Json<SystemControlResponse>, (StatusCode, String)> {
-    ensure_admin_authorized(&headers)?;
     let domain = parse_domain_control_request(req)?;
+    authorize_action(
+        &state,
+        &headers,
+        Permission::Control,
+        "system.halt",
+        serde_json::json!({ "domain": domain.as_ref().map(ToString::to_string) }),
+    )
+    .await?;
     let Some(coordinator) = state.coordinator.as_ref() else {
         return Err((
             StatusCode::SERVICE_UNAVAILABLE,
@@ -547,7 +662,7 @@ pub async fn get_config(
     State(state): State<AppState>,
     headers: HeaderMap,
 ) -> std::result::Result<Json<StrategyConfig>, (StatusCode, String)> {
-    ensure_admin_authorized(&headers)?;
+    authorize(&state, &headers, Permission::Read).await?;
     let config = state.config.read().await;
 
     Ok(Json(StrategyConfig {
@@ -569,7 +684,14 @@ pub async fn update_config(
     headers: HeaderMap,
     Json(new_config): Json<StrategyConfig>,
 ) -> std::result::Result<Json<serde_json::Value>, (StatusCode, String)> {
-    ensure_admin_authorized(&headers)?;
+    authorize_action(
+        &state,
+        &headers,
+        Permission::Admin,
+        "config.update",
+        serde_json::json!({ "symbols": &new_config.symbols }),
+    )
+    .await?;
     let mut config = state.config.write().await;
 
     // Update config
@@ -603,7 +725,7 @@ pub async fn get_security_events(
     headers: HeaderMap,
     axum::extract::Query(query): axum::extract::Query<SecurityEventQuery>,
 ) -> std::result::Result<Json<Vec<SecurityEvent>>, (StatusCode, String)> {
-    ensure_admin_authorized(&headers)?;
+    authorize(&state, &headers, Permission::Admin).await?;
     let limit = query.limit.unwrap_or(100).min(500);
 
     let mut qb = QueryBuilder::<Postgres>::new(
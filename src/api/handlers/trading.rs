@@ -0,0 +1,300 @@
+use axum::{
+    extract::{Query, State},
+    http::{HeaderMap, StatusCode},
+    Json,
+};
+use chrono::{DateTime, Utc};
+use rust_decimal::prelude::ToPrimitive;
+use rust_decimal::Decimal;
+use sqlx::{postgres::Postgres, QueryBuilder};
+
+use crate::api::auth::{authorize, Permission};
+use crate::api::{state::AppState, types::*};
+use crate::strategy::{
+    PersistedPosition, PersistedPositionStatus, PositionManager, PositionQuery, TradeLogger,
+    TradeOutcome, TradeRecord,
+};
+
+fn internal_error(err: impl std::fmt::Display) -> (StatusCode, String) {
+    (StatusCode::INTERNAL_SERVER_ERROR, err.to_string())
+}
+
+fn paged<T>(items: Vec<T>, total: i64, (limit, offset): (i64, i64)) -> PagedResponse<T> {
+    PagedResponse {
+        items,
+        total,
+        limit,
+        offset,
+    }
+}
+
+/// GET /api/trading/positions
+///
+/// Filters: `strategy` (strategy_id), `symbol`, `status` (open | closed)
+pub async fn list_trading_positions(
+    State(state): State<AppState>,
+    headers: HeaderMap,
+    Query(query): Query<TradingRecordQuery>,
+) -> std::result::Result<Json<PagedResponse<PersistedPosition>>, (StatusCode, String)> {
+    authorize(&state, &headers, Permission::Read).await?;
+    let page = query.page();
+    let status = match query
+        .status
+        .as_deref()
+        .map(str::to_ascii_uppercase)
+        .as_deref()
+    {
+        None => None,
+        Some("OPEN") => Some(PersistedPositionStatus::Open),
+        Some("CLOSED") => Some(PersistedPositionStatus::Closed),
+        Some(other) => {
+            return Err((
+                StatusCode::BAD_REQUEST,
+                format!("invalid position status '{other}' (expected open or closed)"),
+            ))
+        }
+    };
+
+    let manager = PositionManager::new(state.store.clone());
+    let (positions, total) = manager
+        .list_positions(&PositionQuery {
+            strategy_id: query.strategy.clone(),
+            symbol: query.symbol.clone(),
+            status,
+            limit: page.0,
+            offset: page.1,
+        })
+        .await
+        .map_err(internal_error)?;
+
+    Ok(Json(paged(positions, total, page)))
+}
+
+fn push_order_filters(qb: &mut QueryBuilder<'_, Postgres>, query: &TradingRecordQuery) {
+    if let Some(strategy) = &query.strategy {
+        qb.push(" AND agent_id = ").push_bind(strategy.clone());
+    }
+    if let Some(symbol) = &query.symbol {
+        qb.push(" AND market_slug = ").push_bind(symbol.clone());
+    }
+    if let Some(status) = &query.status {
+        qb.push(" AND lower(status) = lower(")
+            .push_bind(status.clone())
+            .push(")");
+    }
+}
+
+/// GET /api/trading/orders
+///
+/// Orders submitted through the platform (`agent_order_executions`).
+/// Filters: `strategy` (agent id), `symbol` (market slug), `status`
+pub async fn list_trading_orders(
+    State(state): State<AppState>,
+    headers: HeaderMap,
+    Query(query): Query<TradingRecordQuery>,
+) -> std::result::Result<Json<PagedResponse<OrderRecordResponse>>, (StatusCode, String)> {
+    authorize(&state, &headers, Permission::Read).await?;
+    let page = query.page();
+    let pool = state.store.pool();
+
+    let mut count_qb = QueryBuilder::<Postgres>::new(
+        "SELECT COUNT(*)::BIGINT FROM agent_order_executions WHERE 1=1",
+    );
+    push_order_filters(&mut count_qb, &query);
+    let (total,): (i64,) = count_qb
+        .build_query_as()
+        .fetch_one(pool)
+        .await
+        .map_err(internal_error)?;
+
+    let mut qb = QueryBuilder::<Postgres>::new(
+        r#"
+        SELECT id, agent_id, market_slug, domain, token_id, market_side, is_buy,
+               shares, limit_price, order_id, status, filled_shares, avg_fill_price,
+               dry_run, error, executed_at
+        FROM agent_order_executions
+        WHERE 1=1
+        "#,
+    );
+    push_order_filters(&mut qb, &query);
+    qb.push(" ORDER BY executed_at DESC, id DESC LIMIT ")
+        .push_bind(page.0)
+        .push(" OFFSET ")
+        .push_bind(page.1);
+
+    #[allow(clippy::type_complexity)]
+    let rows: Vec<(
+        i64,
+        String,
+        String,
+        String,
+        String,
+        String,
+        bool,
+        i64,
+        Decimal,
+        Option<String>,
+        String,
+        i64,
+        Option<Decimal>,
+        bool,
+        Option<String>,
+        DateTime<Utc>,
+    )> = qb
+        .build_query_as()
+        .fetch_all(pool)
+        .await
+        .map_err(internal_error)?;
+
+    let orders = rows
+        .into_iter()
+        .map(|row| OrderRecordResponse {
+            id: row.0,
+            strategy: row.1,
+            symbol: row.2,
+            domain: row.3,
+            token_id: row.4,
+            side: row.5,
+            is_buy: row.6,
+            shares: row.7,
+            limit_price: row.8.to_f64().unwrap_or(0.0),
+            order_id: row.9,
+            status: row.10,
+            filled_shares: row.11,
+            avg_fill_price: row.12.and_then(|d| d.to_f64()),
+            dry_run: row.13,
+            error: row.14,
+            executed_at: row.15,
+        })
+        .collect();
+
+    Ok(Json(paged(orders, total, page)))
+}
+
+fn outcome_status(outcome: &TradeOutcome) -> &'static str {
+    match outcome {
+        TradeOutcome::Open => "open",
+        TradeOutcome::Won => "won",
+        TradeOutcome::Lost => "lost",
+        TradeOutcome::ExitedEarly { .. } => "exited_early",
+        TradeOutcome::Cancelled => "cancelled",
+    }
+}
+
+/// Trade logger records matching `query`, newest first, with the total match count
+fn filter_fills(
+    mut fills: Vec<TradeRecord>,
+    query: &TradingRecordQuery,
+) -> (Vec<TradeRecord>, i64) {
+    fills.retain(|t| {
+        query
+            .strategy
+            .as_deref()
+            .is_none_or(|s| t.context.strategy_mode.as_deref() == Some(s))
+            && query
+                .symbol
+                .as_deref()
+                .is_none_or(|s| t.symbol.eq_ignore_ascii_case(s) || t.event_slug == s)
+            && query
+                .status
+                .as_deref()
+                .is_none_or(|s| outcome_status(&t.outcome).eq_ignore_ascii_case(s))
+    });
+    fills.sort_by(|a, b| b.timestamp.cmp(&a.timestamp));
+
+    let total = fills.len() as i64;
+    let (limit, offset) = query.page();
+    let fills = fills
+        .into_iter()
+        .skip(offset as usize)
+        .take(limit as usize)
+        .collect();
+    (fills, total)
+}
+
+/// GET /api/trading/fills
+///
+/// Executed entries from the trade logger.
+/// Filters: `strategy` (strategy mode), `symbol` (symbol or event slug),
+/// `status` (open | won | lost | exited_early | cancelled)
+pub async fn list_trading_fills(
+    State(state): State<AppState>,
+    headers: HeaderMap,
+    Query(query): Query<TradingRecordQuery>,
+) -> std::result::Result<Json<PagedResponse<TradeRecord>>, (StatusCode, String)> {
+    authorize(&state, &headers, Permission::Read).await?;
+    let logger = TradeLogger::default_path();
+    logger.load().await.map_err(internal_error)?;
+
+    let (fills, total) = filter_fills(logger.get_all_trades().await, &query);
+    Ok(Json(paged(fills, total, query.page())))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use chrono::Duration;
+    use rust_decimal_macros::dec;
+
+    fn record(
+        id: &str,
+        symbol: &str,
+        mode: &str,
+        outcome: TradeOutcome,
+        age_mins: i64,
+    ) -> TradeRecord {
+        let mut record: TradeRecord = serde_json::from_value(serde_json::json!({
+            "id": id,
+            "timestamp": Utc::now() - Duration::minutes(age_mins),
+            "symbol": symbol,
+            "event_slug": format!("{}-updown", symbol.to_lowercase()),
+            "condition_id": "0x1",
+            "direction": "Up",
+            "entry_price": dec!(0.5),
+            "shares": 10,
+            "cost_usd": dec!(5),
+            "momentum_pct": dec!(0),
+            "edge_pct": dec!(0),
+            "outcome": "Open",
+            "payout_usd": null,
+            "pnl_usd": null,
+            "resolved_at": null,
+        }))
+        .unwrap();
+        record.outcome = outcome;
+        record.context.strategy_mode = Some(mode.to_string());
+        record
+    }
+
+    #[test]
+    fn filters_and_pages_fills() {
+        let fills = vec![
+            record("a", "BTCUSDT", "late_reversal", TradeOutcome::Won, 30),
+            record("b", "BTCUSDT", "late_reversal", TradeOutcome::Open, 10),
+            record("c", "ETHUSDT", "late_reversal", TradeOutcome::Won, 20),
+            record("d", "BTCUSDT", "early_mispricing", TradeOutcome::Lost, 5),
+        ];
+
+        let query = TradingRecordQuery {
+            strategy: Some("late_reversal".into()),
+            symbol: Some("btcusdt".into()),
+            ..Default::default()
+        };
+        let (page, total) = filter_fills(fills.clone(), &query);
+        assert_eq!(total, 2);
+        assert_eq!(
+            page.iter().map(|t| t.id.as_str()).collect::<Vec<_>>(),
+            ["b", "a"]
+        );
+
+        let query = TradingRecordQuery {
+            status: Some("WON".into()),
+            limit: Some(1),
+            offset: Some(1),
+            ..Default::default()
+        };
+        let (page, total) = filter_fills(fills, &query);
+        assert_eq!(total, 2);
+        assert_eq!(page[0].id, "a");
+    }
+}
@@ -0,0 +1,329 @@
+use axum::{
+    extract::{Path, Query, State},
+    http::{HeaderMap, StatusCode},
+    Json,
+};
+use chrono::{DateTime, Utc};
+use serde::{Deserialize, Serialize};
+
+use crate::api::auth::{authorize, authorize_action, Permission};
+use crate::api::state::AppState;
+use crate::api::webhooks::{generate_secret, WebhookDispatcher, WebhookEvent, WebhookEventType};
+
+#[derive(Debug, Deserialize)]
+pub struct CreateWebhookRequest {
+    pub url: String,
+    pub events: Vec<String>,
+    #[serde(default)]
+    pub description: Option<String>,
+}
+
+#[derive(Debug, Serialize)]
+pub struct WebhookSubscriptionResponse {
+    pub id: i64,
+    pub url: String,
+    pub events: Vec<String>,
+    pub description: Option<String>,
+    pub enabled: bool,
+    pub created_at: DateTime<Utc>,
+    /// Signing secret, only returned on creation
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub secret: Option<String>,
+}
+
+#[derive(Debug, Deserialize)]
+pub struct WebhookDeliveryQuery {
+    pub limit: Option<i64>,
+    pub status: Option<String>,
+}
+
+#[derive(Debug, Serialize)]
+pub struct WebhookDeliveryResponse {
+    pub id: i64,
+    pub event_id: uuid::Uuid,
+    pub event_type: String,
+    pub status: String,
+    pub attempts: i32,
+    pub last_status_code: Option<i32>,
+    pub last_error: Option<String>,
+    pub created_at: DateTime<Utc>,
+    pub delivered_at: Option<DateTime<Utc>>,
+    pub payload: serde_json::Value,
+}
+
+type SubscriptionRow = (
+    i64,
+    String,
+    Vec<String>,
+    Option<String>,
+    bool,
+    DateTime<Utc>,
+);
+
+fn db_error(err: sqlx::Error) -> (StatusCode, String) {
+    (StatusCode::INTERNAL_SERVER_ERROR, err.to_string())
+}
+
+fn subscription_response(row: SubscriptionRow) -> WebhookSubscriptionResponse {
+    WebhookSubscriptionResponse {
+        id: row.0,
+        url: row.1,
+        events: row.2,
+        description: row.3,
+        enabled: row.4,
+        created_at: row.5,
+        secret: None,
+    }
+}
+
+/// Validate the target URL and event names of a new subscription
+fn validate_subscription(
+    req: &CreateWebhookRequest,
+) -> std::result::Result<Vec<String>, (StatusCode, String)> {
+    let url = req.url.trim();
+    let parsed = reqwest::Url::parse(url)
+        .map_err(|e| (StatusCode::BAD_REQUEST, format!("invalid url: {e}")))?;
+    if !matches!(parsed.scheme(), "https" | "http") {
+        return Err((
+            StatusCode::BAD_REQUEST,
+            "webhook url must be http(s)".to_string(),
+        ));
+    }
+    if req.events.is_empty() {
+        return Err((
+            StatusCode::BAD_REQUEST,
+            "at least one event type is required".to_string(),
+        ));
+    }
+
+    let mut events = Vec::new();
+    for name in &req.events {
+        let event = WebhookEventType::parse(name).ok_or_else(|| {
+            (
+                StatusCode::BAD_REQUEST,
+                format!(
+                    "unknown event type '{name}' (expected one of: {})",
+                    WebhookEventType::ALL.map(|t| t.as_str()).join(", ")
+                ),
+            )
+        })?;
+        if !events.contains(&event.as_str().to_string()) {
+            events.push(event.as_str().to_string());
+        }
+    }
+    Ok(events)
+}
+
+/// GET /api/webhooks
+pub async fn list_webhooks(
+    State(state): State<AppState>,
+    headers: HeaderMap,
+) -> std::result::Result<Json<Vec<WebhookSubscriptionResponse>>, (StatusCode, String)> {
+    authorize(&state, &headers, Permission::Admin).await?;
+
+    let rows: Vec<SubscriptionRow> = sqlx::query_as(
+        r#"
+        SELECT id, url, event_types, description, enabled, created_at
+        FROM webhook_subscriptions
+        ORDER BY id
+        "#,
+    )
+    .fetch_all(state.store.pool())
+    .await
+    .map_err(db_error)?;
+
+    Ok(Json(rows.into_iter().map(subscription_response).collect()))
+}
+
+/// POST /api/webhooks
+pub async fn create_webhook(
+    State(state): State<AppState>,
+    headers: HeaderMap,
+    Json(req): Json<CreateWebhookRequest>,
+) -> std::result::Result<(StatusCode, Json<WebhookSubscriptionResponse>), (StatusCode, String)> {
+    authorize_action(
+        &state,
+        &headers,
+        Permission::Admin,
+        "webhooks.create",
+        serde_json::json!({ "url": req.url.trim(), "events": &req.events }),
+    )
+    .await?;
+    let events = validate_subscription(&req)?;
+    let secret = generate_secret();
+
+    let row: SubscriptionRow = sqlx::query_as(
+        r#"
+        INSERT INTO webhook_subscriptions (url, event_types, secret, description)
+        VALUES ($1, $2, $3, $4)
+        RETURNING id, url, event_types, description, enabled, created_at
+        "#,
+    )
+    .bind(req.url.trim())
+    .bind(&events)
+    .bind(&secret)
+    .bind(&req.description)
+    .fetch_one(state.store.pool())
+    .await
+    .map_err(db_error)?;
+
+    let mut response = subscription_response(row);
+    response.secret = Some(secret);
+    Ok((StatusCode::CREATED, Json(response)))
+}
+
+/// DELETE /api/webhooks/:id
+pub async fn delete_webhook(
+    State(state): State<AppState>,
+    headers: HeaderMap,
+    Path(id): Path<i64>,
+) -> std::result::Result<StatusCode, (StatusCode, String)> {
+    authorize_action(
+        &state,
+        &headers,
+        Permission::Admin,
+        "webhooks.delete",
+        serde_json::json!({ "webhook_id": id }),
+    )
+    .await?;
+
+    let result = sqlx::query("DELETE FROM webhook_subscriptions WHERE id = $1")
+        .bind(id)
+        .execute(state.store.pool())
+        .await
+        .map_err(db_error)?;
+    if result.rows_affected() == 0 {
+        return Err((StatusCode::NOT_FOUND, "webhook not found".to_string()));
+    }
+    Ok(StatusCode::NO_CONTENT)
+}
+
+/// GET /api/webhooks/:id/deliveries
+pub async fn list_webhook_deliveries(
+    State(state): State<AppState>,
+    headers: HeaderMap,
+    Path(id): Path<i64>,
+    Query(query): Query<WebhookDeliveryQuery>,
+) -> std::result::Result<Json<Vec<WebhookDeliveryResponse>>, (StatusCode, String)> {
+    authorize(&state, &headers, Permission::Admin).await?;
+    let limit = query.limit.unwrap_or(50).clamp(1, 500);
+
+    #[allow(clippy::type_complexity)]
+    let rows: Vec<(
+        i64,
+        uuid::Uuid,
+        String,
+        String,
+        i32,
+        Option<i32>,
+        Option<String>,
+        DateTime<Utc>,
+        Option<DateTime<Utc>>,
+        serde_json::Value,
+    )> = sqlx::query_as(
+        r#"
+        SELECT id, event_id, event_type, status, attempts, last_status_code,
+               last_error, created_at, delivered_at, payload
+        FROM webhook_deliveries
+        WHERE subscription_id = $1
+          AND ($2::text IS NULL OR status = $2)
+        ORDER BY created_at DESC
+        LIMIT $3
+        "#,
+    )
+    .bind(id)
+    .bind(query.status.as_deref())
+    .bind(limit)
+    .fetch_all(state.store.pool())
+    .await
+    .map_err(db_error)?;
+
+    Ok(Json(
+        rows.into_iter()
+            .map(|row| WebhookDeliveryResponse {
+                id: row.0,
+                event_id: row.1,
+                event_type: row.2,
+                status: row.3,
+                attempts: row.4,
+                last_status_code: row.5,
+                last_error: row.6,
+                created_at: row.7,
+                delivered_at: row.8,
+                payload: row.9,
+            })
+            .collect(),
+    ))
+}
+
+/// POST /api/webhooks/:id/test
+///
+/// Sends a synthetic event of the subscription's first type and waits for
+/// the delivery (including retries) to finish.
+pub async fn test_webhook(
+    State(state): State<AppState>,
+    headers: HeaderMap,
+    Path(id): Path<i64>,
+) -> std::result::Result<Json<serde_json::Value>, (StatusCode, String)> {
+    authorize_action(
+        &state,
+        &headers,
+        Permission::Admin,
+        "webhooks.test",
+        serde_json::json!({ "webhook_id": id }),
+    )
+    .await?;
+
+    let row: Option<(String, String, Vec<String>)> =
+        sqlx::query_as("SELECT url, secret, event_types FROM webhook_subscriptions WHERE id = $1")
+            .bind(id)
+            .fetch_optional(state.store.pool())
+            .await
+            .map_err(db_error)?;
+    let Some((url, secret, events)) = row else {
+        return Err((StatusCode::NOT_FOUND, "webhook not found".to_string()));
+    };
+
+    let event_type = events
+        .first()
+        .and_then(|e| WebhookEventType::parse(e))
+        .unwrap_or(WebhookEventType::Fill);
+    let event = WebhookEvent::new(event_type, serde_json::json!({ "test": true }));
+    let dispatcher = WebhookDispatcher::new(state.store.pool().clone())
+        .map_err(|e| (StatusCode::INTERNAL_SERVER_ERROR, e.to_string()))?;
+
+    match dispatcher.deliver(id, &url, &secret, &event).await {
+        Ok(delivery_id) => Ok(Json(serde_json::json!({
+            "delivered": true,
+            "delivery_id": delivery_id,
+            "event_id": event.id,
+        }))),
+        Err(e) => Err((StatusCode::BAD_GATEWAY, e.to_string())),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn validates_subscription_url_and_events() {
+        let req = |url: &str, events: &[&str]| CreateWebhookRequest {
+            url: url.to_string(),
+            events: events.iter().map(|e| e.to_string()).collect(),
+            description: None,
+        };
+
+        assert_eq!(
+            validate_subscription(&req(
+                "https://hooks.example.com/ploy",
+                &["fill", "FILL", "settlement"]
+            ))
+            .unwrap(),
+            vec!["fill", "settlement"]
+        );
+        assert!(validate_subscription(&req("ftp://example.com", &["fill"])).is_err());
+        assert!(validate_subscription(&req("https://example.com", &[])).is_err());
+        assert!(validate_subscription(&req("https://example.com", &["trade"])).is_err());
+    }
+}
@@ -1,8 +1,10 @@
 pub mod auth;
 pub mod handlers;
+pub mod public;
 pub mod routes;
 pub mod state;
 pub mod types;
+pub mod webhooks;
 pub mod websocket;
 
 pub use routes::create_router;
@@ -0,0 +1,244 @@
+//! Public read-only API.
+//!
+//! An optional second listener that exposes sanitized performance stats so
+//! results can be shared externally without exposing the control plane. It
+//! only serves GET routes, never reads credentials, strips position sizes and
+//! order counts from strategy status, and rate limits every client IP with a
+//! token bucket.
+//!
+//! Enabled by setting `PLOY_PUBLIC_API_PORT`. Limits are tuned with
+//! `PLOY_PUBLIC_API_RATE_PER_MIN` (default 30) and `PLOY_PUBLIC_API_BURST`
+//! (default 10).
+
+use std::collections::HashMap;
+use std::net::{IpAddr, SocketAddr};
+use std::sync::{Arc, Mutex};
+use std::time::{Duration, Instant};
+
+use axum::{
+    extract::{ConnectInfo, Query, Request, State},
+    http::{header, HeaderValue, Method, StatusCode},
+    middleware::{self, Next},
+    response::{IntoResponse, Response},
+    routing::get,
+    Json, Router,
+};
+use serde::{Deserialize, Serialize};
+use tower_http::cors::{Any, CorsLayer};
+
+use crate::api::handlers;
+use crate::api::state::AppState;
+use crate::api::types::{PnLDataPoint, RunningStrategy};
+
+/// Longest PnL window served publicly
+const MAX_PNL_HOURS: i32 = 24 * 30;
+
+/// Tracked clients before idle buckets are evicted
+const MAX_TRACKED_CLIENTS: usize = 10_000;
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct PublicApiConfig {
+    pub port: u16,
+    /// Sustained requests per minute per client IP
+    pub requests_per_minute: u32,
+    /// Requests a client may make back to back before being throttled
+    pub burst: u32,
+}
+
+impl PublicApiConfig {
+    /// Read the listener config from the environment; `None` when disabled
+    pub fn from_env() -> Option<Self> {
+        let port = std::env::var("PLOY_PUBLIC_API_PORT")
+            .ok()
+            .and_then(|v| v.trim().parse::<u16>().ok())?;
+        let env_u32 = |key: &str, default: u32| {
+            std::env::var(key)
+                .ok()
+                .and_then(|v| v.trim().parse::<u32>().ok())
+                .filter(|v| *v > 0)
+                .unwrap_or(default)
+        };
+        Some(Self {
+            port,
+            requests_per_minute: env_u32("PLOY_PUBLIC_API_RATE_PER_MIN", 30),
+            burst: env_u32("PLOY_PUBLIC_API_BURST", 10),
+        })
+    }
+}
+
+#[derive(Debug, Clone, Copy)]
+struct Bucket {
+    tokens: f64,
+    updated: Instant,
+}
+
+/// Per-IP token bucket rate limiter
+#[derive(Debug)]
+pub struct RateLimiter {
+    capacity: f64,
+    refill_per_sec: f64,
+    buckets: Mutex<HashMap<IpAddr, Bucket>>,
+}
+
+impl RateLimiter {
+    pub fn new(requests_per_minute: u32, burst: u32) -> Self {
+        Self {
+            capacity: f64::from(burst.max(1)),
+            refill_per_sec: f64::from(requests_per_minute.max(1)) / 60.0,
+            buckets: Mutex::new(HashMap::new()),
+        }
+    }
+
+    /// Take one token for `ip`. On refusal returns how long until the next
+    /// token is available.
+    pub fn check(&self, ip: IpAddr, now: Instant) -> std::result::Result<(), Duration> {
+        let mut buckets = self.buckets.lock().unwrap_or_else(|e| e.into_inner());
+
+        if buckets.len() >= MAX_TRACKED_CLIENTS && !buckets.contains_key(&ip) {
+            // Buckets that would be full again carry no state worth keeping
+            let full_after = Duration::from_secs_f64(self.capacity / self.refill_per_sec);
+            buckets.retain(|_, b| now.saturating_duration_since(b.updated) < full_after);
+        }
+
+        let bucket = buckets.entry(ip).or_insert(Bucket {
+            tokens: self.capacity,
+            updated: now,
+        });
+        let elapsed = now.saturating_duration_since(bucket.updated).as_secs_f64();
+        bucket.tokens = (bucket.tokens + elapsed * self.refill_per_sec).min(self.capacity);
+        bucket.updated = now;
+
+        if bucket.tokens >= 1.0 {
+            bucket.tokens -= 1.0;
+            Ok(())
+        } else {
+            Err(Duration::from_secs_f64(
+                (1.0 - bucket.tokens) / self.refill_per_sec,
+            ))
+        }
+    }
+}
+
+async fn rate_limit(
+    State(limiter): State<Arc<RateLimiter>>,
+    ConnectInfo(addr): ConnectInfo<SocketAddr>,
+    req: Request,
+    next: Next,
+) -> Response {
+    match limiter.check(addr.ip(), Instant::now()) {
+        Ok(()) => next.run(req).await,
+        Err(wait) => {
+            let retry_after = wait.as_secs().max(1).to_string();
+            let mut response =
+                (StatusCode::TOO_MANY_REQUESTS, "rate limit exceeded").into_response();
+            if let Ok(value) = HeaderValue::from_str(&retry_after) {
+                response.headers_mut().insert(header::RETRY_AFTER, value);
+            }
+            response
+        }
+    }
+}
+
+#[derive(Debug, Deserialize)]
+pub struct PublicPnlQuery {
+    pub hours: Option<i32>,
+}
+
+/// Strategy status without sizes, order counts or dollar amounts
+#[derive(Debug, Clone, Serialize)]
+pub struct PublicStrategyStatus {
+    pub name: String,
+    pub domain: String,
+    pub status: String,
+    pub win_rate: Option<f64>,
+}
+
+impl From<RunningStrategy> for PublicStrategyStatus {
+    fn from(s: RunningStrategy) -> Self {
+        Self {
+            name: s.name,
+            domain: s.domain,
+            status: s.status,
+            win_rate: s.win_rate,
+        }
+    }
+}
+
+/// GET /public/health
+async fn public_health() -> Json<serde_json::Value> {
+    Json(serde_json::json!({ "status": "ok" }))
+}
+
+/// GET /public/pnl
+///
+/// Hourly cumulative PnL curve, at most the last 30 days.
+async fn public_pnl(
+    State(state): State<AppState>,
+    Query(query): Query<PublicPnlQuery>,
+) -> std::result::Result<Json<Vec<PnLDataPoint>>, (StatusCode, String)> {
+    let hours = query.hours.unwrap_or(24).clamp(1, MAX_PNL_HOURS);
+    let params = HashMap::from([("hours".to_string(), hours.to_string())]);
+    handlers::get_pnl_history(State(state), Query(params))
+        .await
+        .map_err(|_| {
+            (
+                StatusCode::SERVICE_UNAVAILABLE,
+                "stats unavailable".to_string(),
+            )
+        })
+}
+
+/// GET /public/strategies
+async fn public_strategies(
+    State(state): State<AppState>,
+) -> std::result::Result<Json<Vec<PublicStrategyStatus>>, (StatusCode, String)> {
+    let Json(strategies) = handlers::get_running_strategies(State(state))
+        .await
+        .map_err(|_| {
+            (
+                StatusCode::SERVICE_UNAVAILABLE,
+                "stats unavailable".to_string(),
+            )
+        })?;
+    Ok(Json(strategies.into_iter().map(Into::into).collect()))
+}
+
+/// Router for the public listener. Serve it with
+/// `into_make_service_with_connect_info::<SocketAddr>()` so the rate limiter
+/// can see client addresses.
+pub fn create_public_router(state: AppState, config: &PublicApiConfig) -> Router {
+    let limiter = Arc::new(RateLimiter::new(config.requests_per_minute, config.burst));
+    let cors = CorsLayer::new()
+        .allow_origin(Any)
+        .allow_methods([Method::GET]);
+
+    Router::new()
+        .route("/public/health", get(public_health))
+        .route("/public/pnl", get(public_pnl))
+        .route("/public/strategies", get(public_strategies))
+        .with_state(state)
+        .layer(middleware::from_fn_with_state(limiter, rate_limit))
+        .layer(cors)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn token_bucket_allows_burst_then_refills() {
+        let limiter = RateLimiter::new(60, 2);
+        let ip: IpAddr = "203.0.113.7".parse().unwrap();
+        let other: IpAddr = "203.0.113.8".parse().unwrap();
+        let t0 = Instant::now();
+
+        assert!(limiter.check(ip, t0).is_ok());
+        assert!(limiter.check(ip, t0).is_ok());
+        let wait = limiter.check(ip, t0).unwrap_err();
+        assert!(wait <= Duration::from_secs(1));
+        assert!(limiter.check(other, t0).is_ok());
+
+        assert!(limiter.check(ip, t0 + Duration::from_secs(1)).is_ok());
+        assert!(limiter.check(ip, t0 + Duration::from_secs(1)).is_err());
+    }
+}
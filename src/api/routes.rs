@@ -1,6 +1,6 @@
 use axum::{
     http::{header, HeaderValue, Method},
-    routing::{get, post, put},
+    routing::{delete, get, post, put},
     Router,
 };
 use tower_http::cors::CorsLayer;
@@ -68,14 +68,33 @@ pub fn create_router(state: AppState) -> Router {
         .route("/api/auth/session", get(handlers::get_auth_session))
         .route("/api/auth/login", post(handlers::login_admin))
         .route("/api/auth/logout", post(handlers::logout_admin))
+        .route(
+            "/api/auth/keys",
+            get(handlers::list_api_keys).post(handlers::create_api_key),
+        )
+        .route("/api/auth/keys/:id", delete(handlers::revoke_api_key))
+        .route("/api/auth/audit", get(handlers::list_audit_log))
         // Stats endpoints
         .route("/api/stats/today", get(handlers::get_today_stats))
         .route("/api/stats/pnl", get(handlers::get_pnl_history))
         // Trade endpoints
         .route("/api/trades", get(handlers::get_trades))
         .route("/api/trades/:id", get(handlers::get_trade_by_id))
+        // Trade journal (operator notes / tags / post-mortems)
+        .route("/api/journal", get(handlers::list_journal))
+        .route(
+            "/api/journal/:trade_id",
+            get(handlers::get_journal_trade).post(handlers::annotate_journal_trade),
+        )
         // Position endpoints
         .route("/api/positions", get(handlers::get_positions))
+        // Paginated trading records for external dashboards
+        .route(
+            "/api/trading/positions",
+            get(handlers::list_trading_positions),
+        )
+        .route("/api/trading/orders", get(handlers::list_trading_orders))
+        .route("/api/trading/fills", get(handlers::list_trading_fills))
         // System endpoints
         .route("/api/system/status", get(handlers::get_system_status))
         .route(
@@ -89,6 +108,14 @@ pub fn create_router(state: AppState) -> Router {
         .route("/api/system/pause", post(handlers::pause_system))
         .route("/api/system/resume", post(handlers::resume_system))
         .route("/api/system/halt", post(handlers::halt_system))
+        .route(
+            "/api/system/agents/:id/standby",
+            post(handlers::standby_agent),
+        )
+        .route(
+            "/api/system/agents/:id/resume",
+            post(handlers::resume_agent),
+        )
         // Config endpoints
         .route("/api/config", get(handlers::get_config))
         .route("/api/config", put(handlers::update_config))
@@ -143,6 +170,36 @@ pub fn create_router(state: AppState) -> Router {
             "/api/governance/policy/history",
             get(handlers::get_governance_policy_history),
         )
+        // Human-in-the-loop approvals
+        .route("/api/approvals", get(handlers::list_approvals))
+        .route("/api/approvals/:id", post(handlers::decide_approval))
+        // Intraday loss limit halts
+        .route("/api/risk/loss-limit", get(handlers::get_loss_limit_status))
+        .route(
+            "/api/risk/loss-limit/reenable",
+            post(handlers::reenable_loss_limit),
+        )
+        // Exchange / chain health (venue monitor)
+        .route("/api/risk/venue-status", get(handlers::get_venue_status))
+        // Sliding-window BUY notional throttle usage
+        .route("/api/risk/notional", get(handlers::get_notional_usage))
+        // Exposure by symbol / domain / time-to-settlement
+        .route("/api/risk/heatmap", get(handlers::get_risk_heatmap))
+        .route(
+            "/api/feishu/card-callback",
+            post(handlers::feishu_card_callback),
+        )
+        // Outbound webhook subscriptions
+        .route(
+            "/api/webhooks",
+            get(handlers::list_webhooks).post(handlers::create_webhook),
+        )
+        .route("/api/webhooks/:id", delete(handlers::delete_webhook))
+        .route(
+            "/api/webhooks/:id/deliveries",
+            get(handlers::list_webhook_deliveries),
+        )
+        .route("/api/webhooks/:id/test", post(handlers::test_webhook))
         // Security endpoints
         .route("/api/security/events", get(handlers::get_security_events))
         // Sidecar endpoints (Claude Agent SDK → Rust backend)
@@ -159,6 +216,10 @@ pub fn create_router(state: AppState) -> Router {
             get(handlers::sidecar_get_positions),
         )
         .route("/api/sidecar/risk", get(handlers::sidecar_get_risk))
+        .route(
+            "/api/sidecar/emergency-stop",
+            post(handlers::sidecar_request_emergency_stop),
+        )
         .route(
             "/api/sidecar/strategy-evaluations",
             post(handlers::upsert_strategy_evaluation).get(handlers::list_strategy_evaluations),
@@ -76,6 +76,57 @@ pub struct PositionResponse {
     pub duration_seconds: i64,
 }
 
+// ============================================================================
+// Trading Record Types (positions / orders / fills for external dashboards)
+// ============================================================================
+
+#[derive(Debug, Clone, Default, Deserialize)]
+pub struct TradingRecordQuery {
+    pub limit: Option<i64>,
+    pub offset: Option<i64>,
+    pub strategy: Option<String>,
+    pub symbol: Option<String>,
+    pub status: Option<String>,
+}
+
+impl TradingRecordQuery {
+    /// `(limit, offset)` with the same 20/100 default and cap as `/api/trades`
+    pub fn page(&self) -> (i64, i64) {
+        (
+            self.limit.unwrap_or(20).clamp(1, 100),
+            self.offset.unwrap_or(0).max(0),
+        )
+    }
+}
+
+#[derive(Debug, Clone, Serialize)]
+pub struct PagedResponse<T> {
+    pub items: Vec<T>,
+    pub total: i64,
+    pub limit: i64,
+    pub offset: i64,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct OrderRecordResponse {
+    pub id: i64,
+    pub strategy: String,
+    pub symbol: String,
+    pub domain: String,
+    pub token_id: String,
+    pub side: String,
+    pub is_buy: bool,
+    pub shares: i64,
+    pub limit_price: f64,
+    pub order_id: Option<String>,
+    pub status: String,
+    pub filled_shares: i64,
+    pub avg_fill_price: Option<f64>,
+    pub dry_run: bool,
+    pub error: Option<String>,
+    pub executed_at: DateTime<Utc>,
+}
+
 // ============================================================================
 // Health Check Types
 // ============================================================================
@@ -0,0 +1,316 @@
+//! Outbound webhooks for trading events.
+//!
+//! Clients register a URL for one or more [`WebhookEventType`]s through
+//! `/api/webhooks`. Producers call [`emit`], which is a no-op until the API
+//! server has installed a [`WebhookDispatcher`]. Each delivery is POSTed as
+//! JSON with an HMAC-SHA256 signature and retried with exponential backoff;
+//! every attempt updates a `webhook_deliveries` row so failing endpoints can
+//! be debugged from the API.
+//!
+//! Signature: `X-Ploy-Signature: sha256=<hex(hmac(secret, "{timestamp}.{body}"))>`
+//! where `timestamp` is the `X-Ploy-Timestamp` header (unix seconds).
+
+use std::sync::OnceLock;
+use std::time::Duration;
+
+use chrono::{DateTime, Utc};
+use hmac::{Hmac, Mac};
+use serde::{Deserialize, Serialize};
+use sha2::Sha256;
+use sqlx::PgPool;
+use tokio::sync::mpsc;
+use tracing::{debug, warn};
+use uuid::Uuid;
+
+use crate::error::{PloyError, Result};
+
+type HmacSha256 = Hmac<Sha256>;
+
+/// Delivery attempts per event and subscription
+const MAX_ATTEMPTS: i32 = 5;
+
+/// Backoff before the second attempt; doubles after every failure
+const INITIAL_BACKOFF: Duration = Duration::from_secs(2);
+
+/// Per-request timeout
+const REQUEST_TIMEOUT: Duration = Duration::from_secs(10);
+
+/// Events buffered before [`emit`] starts dropping
+const QUEUE_CAPACITY: usize = 1024;
+
+static DISPATCHER: OnceLock<mpsc::Sender<WebhookEvent>> = OnceLock::new();
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum WebhookEventType {
+    Fill,
+    StopLoss,
+    CircuitBreaker,
+    Settlement,
+}
+
+impl WebhookEventType {
+    pub const ALL: [WebhookEventType; 4] = [
+        WebhookEventType::Fill,
+        WebhookEventType::StopLoss,
+        WebhookEventType::CircuitBreaker,
+        WebhookEventType::Settlement,
+    ];
+
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            WebhookEventType::Fill => "fill",
+            WebhookEventType::StopLoss => "stop_loss",
+            WebhookEventType::CircuitBreaker => "circuit_breaker",
+            WebhookEventType::Settlement => "settlement",
+        }
+    }
+
+    pub fn parse(value: &str) -> Option<Self> {
+        Self::ALL
+            .into_iter()
+            .find(|t| t.as_str().eq_ignore_ascii_case(value.trim()))
+    }
+}
+
+impl std::fmt::Display for WebhookEventType {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.write_str(self.as_str())
+    }
+}
+
+/// Body POSTed to subscribers
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct WebhookEvent {
+    pub id: Uuid,
+    #[serde(rename = "type")]
+    pub event_type: WebhookEventType,
+    pub occurred_at: DateTime<Utc>,
+    pub data: serde_json::Value,
+}
+
+impl WebhookEvent {
+    pub fn new(event_type: WebhookEventType, data: serde_json::Value) -> Self {
+        Self {
+            id: Uuid::new_v4(),
+            event_type,
+            occurred_at: Utc::now(),
+            data,
+        }
+    }
+}
+
+/// Queue an event for delivery to matching subscriptions.
+///
+/// Never blocks: events are dropped (with a warning) when no dispatcher is
+/// installed or the queue is full, so trading paths are never slowed down.
+pub fn emit(event_type: WebhookEventType, data: serde_json::Value) {
+    let Some(tx) = DISPATCHER.get() else {
+        return;
+    };
+    if let Err(e) = tx.try_send(WebhookEvent::new(event_type, data)) {
+        warn!(event_type = %event_type, error = %e, "dropping webhook event");
+    }
+}
+
+/// `sha256=<hex>` signature of `{timestamp}.{body}`
+pub fn sign(secret: &str, timestamp: i64, body: &[u8]) -> String {
+    let mut mac =
+        HmacSha256::new_from_slice(secret.as_bytes()).expect("HMAC accepts keys of any length");
+    mac.update(timestamp.to_string().as_bytes());
+    mac.update(b".");
+    mac.update(body);
+    format!("sha256={}", hex::encode(mac.finalize().into_bytes()))
+}
+
+/// Random signing secret for a new subscription
+pub fn generate_secret() -> String {
+    format!(
+        "whsec_{}{}",
+        Uuid::new_v4().simple(),
+        Uuid::new_v4().simple()
+    )
+}
+
+/// Backoff before attempt `attempt + 1` (1-based `attempt`)
+fn backoff(attempt: i32) -> Duration {
+    INITIAL_BACKOFF * 2u32.pow((attempt - 1).clamp(0, 10) as u32)
+}
+
+/// Delivers queued events to subscribed endpoints
+#[derive(Clone)]
+pub struct WebhookDispatcher {
+    pool: PgPool,
+    http: reqwest::Client,
+}
+
+impl WebhookDispatcher {
+    pub fn new(pool: PgPool) -> Result<Self> {
+        let http = reqwest::Client::builder()
+            .timeout(REQUEST_TIMEOUT)
+            .user_agent("ploy-webhooks/1")
+            .build()?;
+        Ok(Self { pool, http })
+    }
+
+    /// Install as the process-wide target of [`emit`] and start the delivery
+    /// loop. Only the first call has any effect.
+    pub fn install(self) {
+        let (tx, mut rx) = mpsc::channel(QUEUE_CAPACITY);
+        if DISPATCHER.set(tx).is_err() {
+            return;
+        }
+        tokio::spawn(async move {
+            while let Some(event) = rx.recv().await {
+                if let Err(e) = self.dispatch(event).await {
+                    warn!(error = %e, "webhook dispatch failed");
+                }
+            }
+        });
+    }
+
+    /// Fan an event out to every enabled subscription for its type
+    pub async fn dispatch(&self, event: WebhookEvent) -> Result<()> {
+        let subscriptions: Vec<(i64, String, String)> = sqlx::query_as(
+            r#"
+            SELECT id, url, secret
+            FROM webhook_subscriptions
+            WHERE enabled AND $1 = ANY(event_types)
+            "#,
+        )
+        .bind(event.event_type.as_str())
+        .fetch_all(&self.pool)
+        .await?;
+
+        for (subscription_id, url, secret) in subscriptions {
+            let dispatcher = self.clone();
+            let event = event.clone();
+            tokio::spawn(async move {
+                if let Err(e) = dispatcher
+                    .deliver(subscription_id, &url, &secret, &event)
+                    .await
+                {
+                    warn!(subscription_id, error = %e, "webhook delivery failed");
+                }
+            });
+        }
+        Ok(())
+    }
+
+    /// Deliver one event to one subscription, retrying with backoff and
+    /// recording every attempt. Returns the delivery row id.
+    pub async fn deliver(
+        &self,
+        subscription_id: i64,
+        url: &str,
+        secret: &str,
+        event: &WebhookEvent,
+    ) -> Result<i64> {
+        let body = serde_json::to_vec(event)?;
+        let (delivery_id,): (i64,) = sqlx::query_as(
+            r#"
+            INSERT INTO webhook_deliveries (subscription_id, event_id, event_type, payload)
+            VALUES ($1, $2, $3, $4)
+            ON CONFLICT (subscription_id, event_id) DO UPDATE SET status = 'pending'
+            RETURNING id
+            "#,
+        )
+        .bind(subscription_id)
+        .bind(event.id)
+        .bind(event.event_type.as_str())
+        .bind(sqlx::types::Json(event))
+        .fetch_one(&self.pool)
+        .await?;
+
+        for attempt in 1..=MAX_ATTEMPTS {
+            let timestamp = Utc::now().timestamp();
+            let response = self
+                .http
+                .post(url)
+                .header("Content-Type", "application/json")
+                .header("X-Ploy-Event", event.event_type.as_str())
+                .header("X-Ploy-Delivery", delivery_id.to_string())
+                .header("X-Ploy-Timestamp", timestamp.to_string())
+                .header("X-Ploy-Signature", sign(secret, timestamp, &body))
+                .body(body.clone())
+                .send()
+                .await;
+
+            let (status_code, error) = match response {
+                Ok(resp) if resp.status().is_success() => (Some(resp.status().as_u16()), None),
+                Ok(resp) => (
+                    Some(resp.status().as_u16()),
+                    Some(format!("HTTP {}", resp.status())),
+                ),
+                Err(e) => (None, Some(e.to_string())),
+            };
+            let delivered = error.is_none();
+            let status = if delivered {
+                "delivered"
+            } else if attempt == MAX_ATTEMPTS {
+                "failed"
+            } else {
+                "pending"
+            };
+
+            sqlx::query(
+                r#"
+                UPDATE webhook_deliveries
+                SET status = $2,
+                    attempts = $3,
+                    last_status_code = $4,
+                    last_error = $5,
+                    delivered_at = CASE WHEN $2 = 'delivered' THEN NOW() ELSE NULL END
+                WHERE id = $1
+                "#,
+            )
+            .bind(delivery_id)
+            .bind(status)
+            .bind(attempt)
+            .bind(status_code.map(i32::from))
+            .bind(&error)
+            .execute(&self.pool)
+            .await?;
+
+            if delivered {
+                debug!(subscription_id, delivery_id, attempt, "webhook delivered");
+                return Ok(delivery_id);
+            }
+            if attempt < MAX_ATTEMPTS {
+                tokio::time::sleep(backoff(attempt)).await;
+            }
+        }
+
+        Err(PloyError::Internal(format!(
+            "webhook delivery {delivery_id} to {url} failed after {MAX_ATTEMPTS} attempts"
+        )))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn signs_timestamped_body_and_parses_event_types() {
+        let sig = sign("whsec_test", 1_700_000_000, br#"{"type":"fill"}"#);
+        assert!(sig.starts_with("sha256="));
+        assert_eq!(sig.len(), "sha256=".len() + 64);
+        assert_eq!(
+            sig,
+            sign("whsec_test", 1_700_000_000, br#"{"type":"fill"}"#)
+        );
+        assert_ne!(
+            sig,
+            sign("whsec_test", 1_700_000_001, br#"{"type":"fill"}"#)
+        );
+
+        assert_eq!(
+            WebhookEventType::parse("Stop_Loss"),
+            Some(WebhookEventType::StopLoss)
+        );
+        assert_eq!(WebhookEventType::parse("unknown"), None);
+        assert_eq!(backoff(1), Duration::from_secs(2));
+        assert_eq!(backoff(3), Duration::from_secs(8));
+    }
+}
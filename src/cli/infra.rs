@@ -4,6 +4,7 @@
 //! ploy infra status   - Check infrastructure status
 //! ploy infra ssh      - SSH into instance
 //! ploy infra logs     - View infrastructure logs
+//! ploy infra db-maintain - Create/drop tick table partitions and report sizes
 
 use anyhow::{bail, Context, Result};
 use clap::Subcommand;
@@ -65,6 +66,21 @@ pub enum InfraCommands {
         #[arg(short, long, default_value = "all")]
         component: String,
     },
+
+    /// Create upcoming partitions, drop expired ones and report table sizes
+    DbMaintain {
+        /// Show what would be created/dropped without changing anything
+        #[arg(long)]
+        dry_run: bool,
+
+        /// Output as JSON
+        #[arg(long)]
+        json: bool,
+
+        /// Database URL (uses DATABASE_URL env var if omitted)
+        #[arg(long)]
+        database_url: Option<String>,
+    },
 }
 
 impl InfraCommands {
@@ -75,6 +91,11 @@ impl InfraCommands {
             Self::Ssh { env, command } => ssh_connect(&env, command.as_deref()).await,
             Self::Logs { env, tail, follow } => show_logs(&env, tail, follow).await,
             Self::Update { env, component } => update_infra(&env, &component).await,
+            Self::DbMaintain {
+                dry_run,
+                json,
+                database_url,
+            } => db_maintain(dry_run, json, database_url).await,
         }
     }
 }
@@ -413,3 +434,28 @@ async fn update_infra(env: &str, component: &str) -> Result<()> {
     println!("  \x1b[32m✓ Update complete\x1b[0m\n");
     Ok(())
 }
+
+async fn db_maintain(dry_run: bool, json_output: bool, database_url: Option<String>) -> Result<()> {
+    use crate::adapters::PostgresStore;
+    use crate::services::DbMaintenance;
+
+    let db_url = database_url.unwrap_or_else(|| {
+        std::env::var("DATABASE_URL").unwrap_or_else(|_| "postgres://localhost/ploy".to_string())
+    });
+
+    let store = PostgresStore::new(&db_url, 5).await?;
+    let maintenance = DbMaintenance::new(store.pool().clone());
+    let report = maintenance.run(chrono::Utc::now(), dry_run).await?;
+
+    if json_output {
+        println!("{}", serde_json::to_string_pretty(&report)?);
+    } else {
+        print!("{}", report);
+    }
+
+    if !report.errors.is_empty() {
+        bail!("{} maintenance error(s)", report.errors.len());
+    }
+
+    Ok(())
+}
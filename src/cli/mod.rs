@@ -5,6 +5,7 @@ pub mod infra;
 pub mod pm;
 pub mod rpc;
 pub mod runtime;
+pub mod scaffold;
 pub mod service;
 pub mod strategy;
 
@@ -0,0 +1,226 @@
+//! Tab completion for `ploy pm shell`.
+//!
+//! Subcommands and `--flags` come from the clap command tree; token IDs and
+//! slugs come from the [`MarketCache`]. A non-numeric word after a
+//! `--token-id`-style flag is fuzzy-matched against cached markets and
+//! completes to their outcome tokens.
+
+use rustyline::completion::{Completer, Pair};
+use rustyline::highlight::Highlighter;
+use rustyline::hint::Hinter;
+use rustyline::validate::Validator;
+use rustyline::{Context, Helper};
+
+use super::market_cache::MarketCache;
+
+/// Commands handled by the shell itself rather than clap
+pub const SHELL_BUILTINS: &[&str] = &["find", "refresh", "help", "exit"];
+
+/// Maximum candidates offered per completion
+const MAX_CANDIDATES: usize = 50;
+
+/// Markets searched when fuzzy-completing a token ID
+const FUZZY_MARKETS: usize = 10;
+
+pub struct ShellHelper {
+    command: clap::Command,
+    pub cache: MarketCache,
+}
+
+fn truncate(s: &str, max: usize) -> String {
+    if s.chars().count() <= max {
+        s.to_string()
+    } else {
+        let cut: String = s.chars().take(max.saturating_sub(3)).collect();
+        format!("{cut}...")
+    }
+}
+
+impl ShellHelper {
+    pub fn new(command: clap::Command, cache: MarketCache) -> Self {
+        Self { command, cache }
+    }
+
+    /// Completion start offset and candidates for the text before the cursor
+    pub fn candidates(&self, line: &str) -> (usize, Vec<Pair>) {
+        let start = line.rfind(char::is_whitespace).map_or(0, |i| i + 1);
+        let word = &line[start..];
+        let words: Vec<&str> = line[..start].split_whitespace().collect();
+
+        // Descend through the subcommands typed so far
+        let mut cmd = &self.command;
+        let mut in_args = false;
+        for w in &words {
+            match cmd.find_subcommand(w) {
+                Some(sub) if !in_args => cmd = sub,
+                _ => in_args = true,
+            }
+        }
+
+        let mut candidates: Vec<Pair> = if word.starts_with('-') {
+            cmd.get_arguments()
+                .filter_map(|a| a.get_long())
+                .map(|long| format!("--{long}"))
+                .filter(|flag| flag.starts_with(word))
+                .map(|flag| Pair {
+                    display: flag.clone(),
+                    replacement: flag,
+                })
+                .collect()
+        } else if !in_args && cmd.has_subcommands() {
+            let builtins: &[&str] = if words.is_empty() {
+                SHELL_BUILTINS
+            } else {
+                &[]
+            };
+            cmd.get_subcommands()
+                .map(|s| s.get_name())
+                .chain(builtins.iter().copied())
+                .filter(|name| name.starts_with(word))
+                .map(|name| Pair {
+                    display: name.to_string(),
+                    replacement: name.to_string(),
+                })
+                .collect()
+        } else {
+            let wants_token = words.last().is_some_and(|w| w.contains("token"))
+                || (!word.is_empty() && word.chars().all(|c| c.is_ascii_digit()));
+            if wants_token {
+                self.token_candidates(word)
+            } else {
+                self.slug_candidates(word)
+            }
+        };
+        candidates.truncate(MAX_CANDIDATES);
+        (start, candidates)
+    }
+
+    fn token_candidates(&self, word: &str) -> Vec<Pair> {
+        let pair = |token_id: &str, outcome: &str, title: &str| Pair {
+            display: format!("{token_id}  {outcome} | {}", truncate(title, 50)),
+            replacement: token_id.to_string(),
+        };
+        if word.chars().all(|c| c.is_ascii_digit()) {
+            return self
+                .cache
+                .tokens()
+                .filter(|(t, _)| t.token_id.starts_with(word))
+                .map(|(t, m)| pair(&t.token_id, &t.outcome, &m.title))
+                .collect();
+        }
+        self.cache
+            .search(word, FUZZY_MARKETS)
+            .into_iter()
+            .flat_map(|m| {
+                m.tokens
+                    .iter()
+                    .map(move |t| pair(&t.token_id, &t.outcome, &m.title))
+            })
+            .collect()
+    }
+
+    fn slug_candidates(&self, word: &str) -> Vec<Pair> {
+        let pair = |slug: &str| Pair {
+            display: slug.to_string(),
+            replacement: slug.to_string(),
+        };
+        let prefixed: Vec<Pair> = self
+            .cache
+            .entries
+            .iter()
+            .filter(|e| e.slug.starts_with(word))
+            .map(|e| pair(&e.slug))
+            .collect();
+        if !prefixed.is_empty() || word.is_empty() {
+            return prefixed;
+        }
+        self.cache
+            .search(word, MAX_CANDIDATES)
+            .into_iter()
+            .map(|e| pair(&e.slug))
+            .collect()
+    }
+}
+
+impl Completer for ShellHelper {
+    type Candidate = Pair;
+
+    fn complete(
+        &self,
+        line: &str,
+        pos: usize,
+        _ctx: &Context<'_>,
+    ) -> rustyline::Result<(usize, Vec<Pair>)> {
+        Ok(self.candidates(&line[..pos]))
+    }
+}
+
+impl Hinter for ShellHelper {
+    type Hint = String;
+}
+
+impl Highlighter for ShellHelper {}
+
+impl Validator for ShellHelper {}
+
+impl Helper for ShellHelper {}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::cli::pm::market_cache::{CacheKind, CachedMarket, CachedToken};
+
+    fn helper() -> ShellHelper {
+        let command = clap::Command::new("pm")
+            .no_binary_name(true)
+            .subcommand(
+                clap::Command::new("orders").subcommand(
+                    clap::Command::new("ladder")
+                        .arg(clap::Arg::new("token_id").long("token-id"))
+                        .arg(clap::Arg::new("side").long("side")),
+                ),
+            )
+            .subcommand(clap::Command::new("markets"));
+        let cache = MarketCache {
+            refreshed_at: None,
+            entries: vec![CachedMarket {
+                kind: CacheKind::Market,
+                slug: "will-btc-hit-100k".into(),
+                title: "Will BTC hit 100k?".into(),
+                volume: Some(10.0),
+                tokens: vec![CachedToken {
+                    outcome: "Yes".into(),
+                    token_id: "71321045".into(),
+                }],
+            }],
+        };
+        ShellHelper::new(command, cache)
+    }
+
+    fn replacements(helper: &ShellHelper, line: &str) -> Vec<String> {
+        helper
+            .candidates(line)
+            .1
+            .into_iter()
+            .map(|p| p.replacement)
+            .collect()
+    }
+
+    #[test]
+    fn completes_commands_flags_and_cached_values() {
+        let h = helper();
+        assert_eq!(replacements(&h, "or"), vec!["orders"]);
+        assert_eq!(replacements(&h, "fi"), vec!["find"]);
+        assert_eq!(replacements(&h, "orders lad"), vec!["ladder"]);
+        assert_eq!(replacements(&h, "orders ladder --tok"), vec!["--token-id"]);
+        assert_eq!(
+            replacements(&h, "orders ladder --token-id btc100"),
+            vec!["71321045"]
+        );
+        assert_eq!(replacements(&h, "clob book 713"), vec!["71321045"]);
+        assert_eq!(
+            replacements(&h, "markets get-by-slug will-b"),
+            vec!["will-btc-hit-100k"]
+        );
+    }
+}
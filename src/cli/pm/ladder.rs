@@ -0,0 +1,410 @@
+//! Laddered limit orders for `ploy pm orders ladder`.
+//!
+//! A ladder spreads a total size across evenly spaced price levels between
+//! `--from` and `--to`. Size weights grow from the `--from` end toward the
+//! `--to` end (flat, linear or geometric). Placed ladders are remembered in
+//! `~/.config/polymarket/ladders.json` so they can be cancelled and replaced
+//! by name.
+
+use anyhow::{Context, Result};
+use chrono::{DateTime, Utc};
+use clap::{Args, ValueEnum};
+use rust_decimal::prelude::ToPrimitive;
+use rust_decimal::{Decimal, RoundingStrategy};
+use serde::{Deserialize, Serialize};
+use std::collections::BTreeMap;
+use std::path::PathBuf;
+use tabled::Tabled;
+
+use super::output::{self, OutputMode};
+
+/// Share sizes are quoted with two decimals on the CLOB
+const SIZE_DP: u32 = 2;
+
+/// How the total size is spread across levels
+#[derive(ValueEnum, Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "lowercase")]
+pub enum Distribution {
+    /// Same size at every level
+    Flat,
+    /// Weight grows by one unit per level (1, 2, 3, ...)
+    Linear,
+    /// Weight grows by `--ratio` per level (1, r, r^2, ...)
+    Geometric,
+}
+
+#[derive(ValueEnum, Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "UPPERCASE")]
+pub enum LadderSide {
+    #[value(alias = "b", alias = "BUY")]
+    Buy,
+    #[value(alias = "s", alias = "SELL")]
+    Sell,
+}
+
+impl LadderSide {
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            LadderSide::Buy => "BUY",
+            LadderSide::Sell => "SELL",
+        }
+    }
+}
+
+#[derive(Args, Debug, Clone)]
+pub struct LadderArgs {
+    #[arg(long)]
+    pub token_id: String,
+    #[arg(long, value_enum)]
+    pub side: LadderSide,
+    /// First level price; size weights start here.
+    #[arg(long)]
+    pub from: Decimal,
+    /// Last level price.
+    #[arg(long)]
+    pub to: Decimal,
+    /// Number of levels (inclusive of both ends).
+    #[arg(long, required_unless_present = "step", conflicts_with = "step")]
+    pub levels: Option<usize>,
+    /// Price distance between levels.
+    #[arg(long)]
+    pub step: Option<Decimal>,
+    /// Total shares across all levels.
+    #[arg(long)]
+    pub size: Decimal,
+    #[arg(long, value_enum, default_value = "flat")]
+    pub distribution: Distribution,
+    /// Per-level growth factor for the geometric distribution.
+    #[arg(long, default_value = "1.5")]
+    pub ratio: Decimal,
+    /// Price tick size; level prices are rounded to it.
+    #[arg(long, default_value = "0.01")]
+    pub tick: Decimal,
+    /// Ladder name used for cancel-and-replace (default: <token>-<side>).
+    #[arg(long)]
+    pub name: Option<String>,
+    /// Cancel the saved ladder with this name and place the new one in its place.
+    #[arg(long)]
+    pub replace: bool,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+pub struct LadderLevel {
+    pub price: Decimal,
+    pub size: Decimal,
+}
+
+/// Totals for a set of ladder levels, assuming every level fills
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize)]
+pub struct LadderSummary {
+    pub shares: Decimal,
+    /// USDC paid (BUY) or received (SELL)
+    pub notional: Decimal,
+    /// Volume-weighted fill price; the position breaks even at this price
+    pub breakeven: Decimal,
+}
+
+impl LadderSummary {
+    pub fn of(levels: &[LadderLevel]) -> Self {
+        let shares: Decimal = levels.iter().map(|l| l.size).sum();
+        let notional: Decimal = levels.iter().map(|l| l.price * l.size).sum();
+        let breakeven = if shares.is_zero() {
+            Decimal::ZERO
+        } else {
+            (notional / shares).round_dp(4)
+        };
+        Self {
+            shares,
+            notional,
+            breakeven,
+        }
+    }
+}
+
+/// A fully resolved ladder ready to preview or place
+#[derive(Debug, Clone, Serialize)]
+pub struct LadderPlan {
+    pub name: String,
+    pub token_id: String,
+    pub side: LadderSide,
+    pub distribution: Distribution,
+    pub levels: Vec<LadderLevel>,
+    pub summary: LadderSummary,
+}
+
+impl LadderArgs {
+    pub fn name(&self) -> String {
+        self.name.clone().unwrap_or_else(|| {
+            let short: String = self.token_id.chars().take(12).collect();
+            format!("{short}-{}", self.side.as_str().to_lowercase())
+        })
+    }
+
+    pub fn plan(&self) -> Result<LadderPlan> {
+        let prices = ladder_prices(self.from, self.to, self.levels, self.step, self.tick)?;
+        let sizes = distribute(self.size, prices.len(), self.distribution, self.ratio)?;
+        let levels: Vec<LadderLevel> = prices
+            .into_iter()
+            .zip(sizes)
+            .map(|(price, size)| LadderLevel { price, size })
+            .collect();
+        Ok(LadderPlan {
+            name: self.name(),
+            token_id: self.token_id.clone(),
+            side: self.side,
+            distribution: self.distribution,
+            summary: LadderSummary::of(&levels),
+            levels,
+        })
+    }
+}
+
+fn round_to_tick(price: Decimal, tick: Decimal) -> Decimal {
+    ((price / tick).round_dp_with_strategy(0, RoundingStrategy::MidpointAwayFromZero) * tick)
+        .normalize()
+}
+
+/// Level prices from `from` to `to`, rounded to `tick`.
+pub fn ladder_prices(
+    from: Decimal,
+    to: Decimal,
+    levels: Option<usize>,
+    step: Option<Decimal>,
+    tick: Decimal,
+) -> Result<Vec<Decimal>> {
+    anyhow::ensure!(tick > Decimal::ZERO, "--tick must be positive");
+    for p in [from, to] {
+        anyhow::ensure!(
+            p > Decimal::ZERO && p < Decimal::ONE,
+            "price {p} must be between 0 and 1"
+        );
+    }
+
+    let count = match (levels, step) {
+        (Some(n), _) => n,
+        (None, Some(step)) => {
+            anyhow::ensure!(step > Decimal::ZERO, "--step must be positive");
+            ((to - from).abs() / step)
+                .floor()
+                .to_usize()
+                .context("--step produces too many levels")?
+                + 1
+        }
+        (None, None) => anyhow::bail!("one of --levels or --step is required"),
+    };
+    anyhow::ensure!(count >= 1, "a ladder needs at least one level");
+    anyhow::ensure!(
+        count <= 100,
+        "a ladder is limited to 100 levels, got {count}"
+    );
+
+    let prices: Vec<Decimal> = if count == 1 {
+        vec![round_to_tick(from, tick)]
+    } else {
+        let step = match step {
+            Some(step) if to >= from => step,
+            Some(step) => -step,
+            None => (to - from) / Decimal::from(count - 1),
+        };
+        (0..count)
+            .map(|i| round_to_tick(from + step * Decimal::from(i), tick))
+            .collect()
+    };
+
+    if prices.windows(2).any(|w| w[0] == w[1]) {
+        anyhow::bail!("levels collapse onto the same price at tick {tick}; use fewer levels");
+    }
+    Ok(prices)
+}
+
+/// Split `total` shares across `count` levels by the distribution's weights.
+///
+/// Each level is rounded down to the size precision; the last level takes the
+/// remainder so the sizes always add up to `total`.
+pub fn distribute(
+    total: Decimal,
+    count: usize,
+    distribution: Distribution,
+    ratio: Decimal,
+) -> Result<Vec<Decimal>> {
+    anyhow::ensure!(total > Decimal::ZERO, "--size must be positive");
+    if distribution == Distribution::Geometric {
+        anyhow::ensure!(ratio > Decimal::ZERO, "--ratio must be positive");
+    }
+
+    let mut weight = Decimal::ONE;
+    let weights: Vec<Decimal> = (0..count)
+        .map(|i| match distribution {
+            Distribution::Flat => Decimal::ONE,
+            Distribution::Linear => Decimal::from(i + 1),
+            Distribution::Geometric => {
+                let w = weight;
+                weight *= ratio;
+                w
+            }
+        })
+        .collect();
+    let weight_sum: Decimal = weights.iter().sum();
+
+    let mut sizes: Vec<Decimal> = weights
+        .iter()
+        .map(|w| {
+            let share = total * w / weight_sum;
+            share.round_dp_with_strategy(SIZE_DP, RoundingStrategy::ToZero)
+        })
+        .collect();
+    let allocated: Decimal = sizes.iter().rev().skip(1).sum();
+    if let Some(last) = sizes.last_mut() {
+        *last = total - allocated;
+    }
+
+    if let Some(i) = sizes.iter().position(|s| *s <= Decimal::ZERO) {
+        anyhow::bail!(
+            "level {} rounds to zero shares; increase --size or use fewer levels",
+            i + 1
+        );
+    }
+    Ok(sizes)
+}
+
+#[derive(Tabled, Serialize)]
+struct LadderRow {
+    #[tabled(rename = "#")]
+    level: usize,
+    price: Decimal,
+    size: Decimal,
+    notional: Decimal,
+    #[tabled(rename = "cum size")]
+    cum_size: Decimal,
+    #[tabled(rename = "cum avg")]
+    cum_avg: Decimal,
+}
+
+/// Print the level table and fill totals.
+pub fn print_preview(plan: &LadderPlan, mode: OutputMode) -> Result<()> {
+    if mode == OutputMode::Json {
+        return output::print_item(plan, mode);
+    }
+
+    let rows: Vec<LadderRow> = plan
+        .levels
+        .iter()
+        .enumerate()
+        .map(|(i, level)| {
+            let cum = LadderSummary::of(&plan.levels[..=i]);
+            LadderRow {
+                level: i + 1,
+                price: level.price,
+                size: level.size,
+                notional: (level.price * level.size).round_dp(4),
+                cum_size: cum.shares,
+                cum_avg: cum.breakeven,
+            }
+        })
+        .collect();
+    output::print_items(&rows, mode)?;
+
+    let summary = &plan.summary;
+    output::print_kv("ladder", &plan.name);
+    output::print_kv("side", plan.side.as_str());
+    output::print_kv("total shares", &summary.shares.to_string());
+    let notional_label = match plan.side {
+        LadderSide::Buy => "total cost (USDC)",
+        LadderSide::Sell => "total proceeds (USDC)",
+    };
+    output::print_kv(notional_label, &summary.notional.round_dp(4).to_string());
+    output::print_kv("breakeven price", &summary.breakeven.to_string());
+    Ok(())
+}
+
+/// A ladder placed on the book
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct SavedLadder {
+    pub token_id: String,
+    pub side: LadderSide,
+    pub distribution: Distribution,
+    pub levels: Vec<LadderLevel>,
+    /// Live order ids, one per placed level
+    pub order_ids: Vec<String>,
+    pub placed_at: DateTime<Utc>,
+}
+
+/// Placed ladders stored in `~/.config/polymarket/ladders.json`, keyed by name.
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+pub struct LadderBook {
+    #[serde(default)]
+    pub ladders: BTreeMap<String, SavedLadder>,
+}
+
+impl LadderBook {
+    pub fn path() -> Result<PathBuf> {
+        Ok(super::config_file::PmConfig::config_dir()?.join("ladders.json"))
+    }
+
+    pub fn load() -> Result<Self> {
+        let path = Self::path()?;
+        if !path.exists() {
+            return Ok(Self::default());
+        }
+        let contents = std::fs::read_to_string(&path)
+            .with_context(|| format!("failed to read {}", path.display()))?;
+        serde_json::from_str(&contents)
+            .with_context(|| format!("failed to parse {}", path.display()))
+    }
+
+    pub fn save(&self) -> Result<()> {
+        let dir = super::config_file::PmConfig::config_dir()?;
+        std::fs::create_dir_all(&dir)
+            .with_context(|| format!("failed to create {}", dir.display()))?;
+        let path = Self::path()?;
+        std::fs::write(&path, serde_json::to_string_pretty(self)?)
+            .with_context(|| format!("failed to write {}", path.display()))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use rust_decimal_macros::dec;
+
+    #[test]
+    fn prices_span_range_on_tick() {
+        let prices = ladder_prices(dec!(0.40), dec!(0.30), Some(5), None, dec!(0.01)).unwrap();
+        assert_eq!(
+            prices,
+            vec![dec!(0.40), dec!(0.38), dec!(0.35), dec!(0.33), dec!(0.30)]
+        );
+
+        let prices =
+            ladder_prices(dec!(0.30), dec!(0.40), None, Some(dec!(0.04)), dec!(0.01)).unwrap();
+        assert_eq!(prices, vec![dec!(0.30), dec!(0.34), dec!(0.38)]);
+
+        assert!(ladder_prices(dec!(0.30), dec!(0.31), Some(5), None, dec!(0.01)).is_err());
+    }
+
+    #[test]
+    fn distributions_sum_to_total() {
+        let flat = distribute(dec!(100), 3, Distribution::Flat, dec!(1.5)).unwrap();
+        assert_eq!(flat, vec![dec!(33.33), dec!(33.33), dec!(33.34)]);
+
+        let linear = distribute(dec!(100), 4, Distribution::Linear, dec!(1.5)).unwrap();
+        assert_eq!(linear, vec![dec!(10), dec!(20), dec!(30), dec!(40)]);
+
+        let geo = distribute(dec!(70), 3, Distribution::Geometric, dec!(2)).unwrap();
+        assert_eq!(geo, vec![dec!(10), dec!(20), dec!(40)]);
+
+        let summary = LadderSummary::of(&[
+            LadderLevel {
+                price: dec!(0.40),
+                size: dec!(10),
+            },
+            LadderLevel {
+                price: dec!(0.30),
+                size: dec!(30),
+            },
+        ]);
+        assert_eq!(summary.shares, dec!(40));
+        assert_eq!(summary.notional, dec!(13.00));
+        assert_eq!(summary.breakeven, dec!(0.325));
+    }
+}
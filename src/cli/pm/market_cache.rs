@@ -0,0 +1,336 @@
+//! Local market/event index for the `ploy pm shell` picker and completion.
+//!
+//! Active Gamma markets and events are cached in
+//! `~/.config/polymarket/market_cache.json` and matched with a skim-style
+//! fuzzy scorer, so token IDs and slugs never have to be pasted by hand.
+
+use anyhow::{Context, Result};
+use chrono::{DateTime, Duration, Utc};
+use serde::{Deserialize, Serialize};
+use serde_json::Value;
+use std::path::PathBuf;
+
+/// Gamma page size used while refreshing
+const PAGE_SIZE: i32 = 500;
+
+/// Markets fetched per refresh
+const MAX_MARKETS: usize = 2000;
+
+/// Events fetched per refresh
+const MAX_EVENTS: usize = 500;
+
+/// Cache age after which the shell refreshes before searching
+const STALE_AFTER_HOURS: i64 = 6;
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "lowercase")]
+pub enum CacheKind {
+    Market,
+    Event,
+}
+
+impl CacheKind {
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            CacheKind::Market => "market",
+            CacheKind::Event => "event",
+        }
+    }
+}
+
+/// One tradable outcome token
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct CachedToken {
+    pub outcome: String,
+    pub token_id: String,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct CachedMarket {
+    pub kind: CacheKind,
+    pub slug: String,
+    /// Market question or event title
+    pub title: String,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub volume: Option<f64>,
+    /// Outcome tokens (every nested market's tokens for events)
+    #[serde(default)]
+    pub tokens: Vec<CachedToken>,
+}
+
+/// Cached markets and events stored in `~/.config/polymarket/market_cache.json`.
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+pub struct MarketCache {
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub refreshed_at: Option<DateTime<Utc>>,
+    #[serde(default)]
+    pub entries: Vec<CachedMarket>,
+}
+
+/// A string list that Gamma serves either as a JSON array or a JSON-encoded string
+fn string_list(value: Option<&Value>) -> Vec<String> {
+    match value {
+        Some(Value::Array(items)) => items
+            .iter()
+            .filter_map(|v| v.as_str().map(str::to_string))
+            .collect(),
+        Some(Value::String(s)) => serde_json::from_str(s).unwrap_or_default(),
+        _ => Vec::new(),
+    }
+}
+
+fn text<'a>(value: &'a Value, keys: &[&str]) -> Option<&'a str> {
+    keys.iter()
+        .find_map(|k| value.get(*k).and_then(Value::as_str))
+}
+
+fn number(value: &Value, keys: &[&str]) -> Option<f64> {
+    keys.iter()
+        .filter_map(|k| value.get(*k))
+        .find_map(|v| match v {
+            Value::Number(n) => n.as_f64(),
+            Value::String(s) => s.parse().ok(),
+            _ => None,
+        })
+}
+
+/// Outcome tokens of a Gamma market
+pub fn market_tokens(market: &Value) -> Vec<CachedToken> {
+    let ids = string_list(
+        market
+            .get("clobTokenIds")
+            .or_else(|| market.get("clob_token_ids")),
+    );
+    let outcomes = string_list(market.get("outcomes"));
+    ids.into_iter()
+        .enumerate()
+        .map(|(i, token_id)| CachedToken {
+            outcome: outcomes
+                .get(i)
+                .cloned()
+                .unwrap_or_else(|| format!("#{}", i + 1)),
+            token_id,
+        })
+        .collect()
+}
+
+impl CachedMarket {
+    pub fn from_market(market: &Value) -> Option<Self> {
+        Some(Self {
+            kind: CacheKind::Market,
+            slug: text(market, &["slug"])?.to_string(),
+            title: text(market, &["question", "title"])
+                .unwrap_or_default()
+                .to_string(),
+            volume: number(market, &["volumeNum", "volume_num", "volume"]),
+            tokens: market_tokens(market),
+        })
+    }
+
+    pub fn from_event(event: &Value) -> Option<Self> {
+        // Label nested tokens with the market they belong to
+        let tokens = event
+            .get("markets")
+            .and_then(Value::as_array)
+            .map(|markets| {
+                markets
+                    .iter()
+                    .flat_map(|m| {
+                        let label = text(m, &["groupItemTitle", "group_item_title", "question"])
+                            .unwrap_or_default()
+                            .to_string();
+                        market_tokens(m).into_iter().map(move |t| CachedToken {
+                            outcome: format!("{label} {}", t.outcome).trim().to_string(),
+                            token_id: t.token_id,
+                        })
+                    })
+                    .collect()
+            })
+            .unwrap_or_default();
+        Some(Self {
+            kind: CacheKind::Event,
+            slug: text(event, &["slug"])?.to_string(),
+            title: text(event, &["title"]).unwrap_or_default().to_string(),
+            volume: number(event, &["volume"]),
+            tokens,
+        })
+    }
+}
+
+/// Skim-style fuzzy score of `query` against `candidate`.
+///
+/// Every query character must appear in order (case-insensitive). Consecutive
+/// matches and matches at word starts score higher; gaps cost a little.
+/// Whitespace-separated query terms are matched independently and summed.
+pub fn fuzzy_score(query: &str, candidate: &str) -> Option<i64> {
+    let haystack: Vec<char> = candidate.to_lowercase().chars().collect();
+    let mut total = 0;
+    for term in query.split_whitespace() {
+        total += score_term(&term.to_lowercase(), &haystack)?;
+    }
+    Some(total)
+}
+
+fn score_term(term: &str, haystack: &[char]) -> Option<i64> {
+    let mut score = 0i64;
+    let mut pos = 0usize;
+    let mut prev: Option<usize> = None;
+    for c in term.chars() {
+        let idx = pos + haystack[pos..].iter().position(|h| *h == c)?;
+        score += 1;
+        match prev {
+            Some(p) if p + 1 == idx => score += 5,
+            Some(p) => score -= (idx - p - 1).min(5) as i64,
+            None => score -= idx.min(10) as i64 / 2,
+        }
+        if idx == 0 || !haystack[idx - 1].is_alphanumeric() {
+            score += 3;
+        }
+        prev = Some(idx);
+        pos = idx + 1;
+    }
+    Some(score)
+}
+
+impl MarketCache {
+    pub fn path() -> Result<PathBuf> {
+        Ok(super::config_file::PmConfig::config_dir()?.join("market_cache.json"))
+    }
+
+    pub fn load() -> Result<Self> {
+        let path = Self::path()?;
+        if !path.exists() {
+            return Ok(Self::default());
+        }
+        let contents = std::fs::read_to_string(&path)
+            .with_context(|| format!("failed to read {}", path.display()))?;
+        serde_json::from_str(&contents)
+            .with_context(|| format!("failed to parse {}", path.display()))
+    }
+
+    pub fn save(&self) -> Result<()> {
+        let dir = super::config_file::PmConfig::config_dir()?;
+        std::fs::create_dir_all(&dir)
+            .with_context(|| format!("failed to create {}", dir.display()))?;
+        let path = Self::path()?;
+        std::fs::write(&path, serde_json::to_string(self)?)
+            .with_context(|| format!("failed to write {}", path.display()))
+    }
+
+    pub fn is_stale(&self, now: DateTime<Utc>) -> bool {
+        self.entries.is_empty()
+            || self
+                .refreshed_at
+                .is_none_or(|at| now - at > Duration::hours(STALE_AFTER_HOURS))
+    }
+
+    /// Re-fetch active markets and events from Gamma
+    pub async fn refresh(&mut self, gamma: &polymarket_client_sdk::gamma::Client) -> Result<()> {
+        use polymarket_client_sdk::gamma::types::request::*;
+
+        let mut entries = Vec::new();
+        let mut offset = 0usize;
+        while offset < MAX_MARKETS {
+            let req = MarketsRequest::builder()
+                .limit(PAGE_SIZE)
+                .offset(offset as i32)
+                .closed(false)
+                .build();
+            let page = gamma.markets(&req).await?;
+            let fetched = page.len();
+            for market in page {
+                entries.extend(CachedMarket::from_market(&serde_json::to_value(&market)?));
+            }
+            if fetched < PAGE_SIZE as usize {
+                break;
+            }
+            offset += fetched;
+        }
+
+        let mut offset = 0usize;
+        while offset < MAX_EVENTS {
+            let req = EventsRequest::builder()
+                .limit(PAGE_SIZE)
+                .offset(offset as i32)
+                .closed(false)
+                .build();
+            let page = gamma.events(&req).await?;
+            let fetched = page.len();
+            for event in page {
+                entries.extend(CachedMarket::from_event(&serde_json::to_value(&event)?));
+            }
+            if fetched < PAGE_SIZE as usize {
+                break;
+            }
+            offset += fetched;
+        }
+
+        self.entries = entries;
+        self.refreshed_at = Some(Utc::now());
+        Ok(())
+    }
+
+    /// Best fuzzy matches on slug and title, highest score (then volume) first
+    pub fn search(&self, query: &str, limit: usize) -> Vec<&CachedMarket> {
+        let mut scored: Vec<(i64, &CachedMarket)> = self
+            .entries
+            .iter()
+            .filter_map(|e| {
+                let score = [fuzzy_score(query, &e.slug), fuzzy_score(query, &e.title)]
+                    .into_iter()
+                    .flatten()
+                    .max()?;
+                Some((score, e))
+            })
+            .collect();
+        scored.sort_by(|(sa, a), (sb, b)| {
+            sb.cmp(sa)
+                .then_with(|| b.volume.unwrap_or(0.0).total_cmp(&a.volume.unwrap_or(0.0)))
+        });
+        scored.into_iter().take(limit).map(|(_, e)| e).collect()
+    }
+
+    /// Cached token ids with their outcome and title, for completion
+    pub fn tokens(&self) -> impl Iterator<Item = (&CachedToken, &CachedMarket)> {
+        self.entries
+            .iter()
+            .filter(|e| e.kind == CacheKind::Market)
+            .flat_map(|e| e.tokens.iter().map(move |t| (t, e)))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use serde_json::json;
+
+    #[test]
+    fn fuzzy_prefers_tight_word_start_matches() {
+        let tight = fuzzy_score("btc 100k", "will-btc-hit-100k-in-2026").unwrap();
+        let loose = fuzzy_score("btc 100k", "bitcoin-above-100-thousand-k").unwrap();
+        assert!(tight > loose);
+        assert!(fuzzy_score("eth", "will-btc-hit-100k").is_none());
+    }
+
+    #[test]
+    fn parses_tokens_from_gamma_market() {
+        let market = json!({
+            "slug": "will-btc-hit-100k",
+            "question": "Will BTC hit 100k?",
+            "outcomes": "[\"Yes\", \"No\"]",
+            "clobTokenIds": "[\"111\", \"222\"]",
+            "volumeNum": 1234.5,
+        });
+        let cached = CachedMarket::from_market(&market).unwrap();
+        assert_eq!(cached.tokens.len(), 2);
+        assert_eq!(cached.tokens[0].outcome, "Yes");
+        assert_eq!(cached.tokens[1].token_id, "222");
+
+        let cache = MarketCache {
+            refreshed_at: None,
+            entries: vec![cached],
+        };
+        assert_eq!(cache.search("btc100", 5).len(), 1);
+        assert!(cache.is_stale(Utc::now()));
+    }
+}
@@ -0,0 +1,502 @@
+//! Filter expressions for `ploy pm markets scan`.
+//!
+//! Grammar:
+//!
+//! ```text
+//! expr  := and ("||" and)*
+//! and   := unary ("&&" unary)*
+//! unary := "!" unary | "(" expr ")" | atom
+//! atom  := "tag:" slug | field op value
+//! op    := ">" | ">=" | "<" | "<=" | "==" | "!=" | "~"   (~ = contains)
+//! ```
+//!
+//! Numeric values accept `k`/`m`/`b` suffixes (`50k`); `end` compares the time
+//! left until the market closes against a duration (`48h`, `30m`, `7d`, `2w`).
+//! Text values may be double-quoted. Example:
+//! `volume>50000 && end<48h && spread<0.03 && tag:crypto`.
+//!
+//! Fields are read from the Gamma market JSON (`spread`, `bestBid` and
+//! `bestAsk` are Gamma's snapshot of the CLOB book).
+
+use std::str::FromStr;
+
+use anyhow::{anyhow, bail, Result};
+use chrono::{DateTime, NaiveDate, Utc};
+use serde_json::Value;
+
+/// Market attribute usable in filters and as a sort key
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Field {
+    Volume,
+    Volume24h,
+    Liquidity,
+    Spread,
+    Bid,
+    Ask,
+    Price,
+    /// Hours until the market ends
+    End,
+    Question,
+    Slug,
+}
+
+impl FromStr for Field {
+    type Err = anyhow::Error;
+
+    fn from_str(s: &str) -> Result<Self> {
+        Ok(match s.trim().to_ascii_lowercase().as_str() {
+            "volume" | "vol" => Self::Volume,
+            "volume24h" | "vol24h" => Self::Volume24h,
+            "liquidity" | "liq" => Self::Liquidity,
+            "spread" => Self::Spread,
+            "bid" => Self::Bid,
+            "ask" => Self::Ask,
+            "price" | "last" => Self::Price,
+            "end" => Self::End,
+            "question" | "q" => Self::Question,
+            "slug" => Self::Slug,
+            other => bail!(
+                "unknown field '{other}' (expected volume, volume24h, liquidity, spread, bid, ask, price, end, question, slug)"
+            ),
+        })
+    }
+}
+
+fn lookup<'a>(market: &'a Value, keys: &[&str]) -> Option<&'a Value> {
+    keys.iter()
+        .filter_map(|k| market.get(*k))
+        .find(|v| !v.is_null())
+}
+
+fn as_f64(value: &Value) -> Option<f64> {
+    match value {
+        Value::Number(n) => n.as_f64(),
+        Value::String(s) => s.trim().parse().ok(),
+        _ => None,
+    }
+}
+
+fn end_time(market: &Value) -> Option<DateTime<Utc>> {
+    let raw = lookup(
+        market,
+        &["endDate", "end_date", "endDateIso", "end_date_iso"],
+    )?
+    .as_str()?;
+    raw.parse::<DateTime<Utc>>().ok().or_else(|| {
+        NaiveDate::parse_from_str(raw, "%Y-%m-%d")
+            .ok()?
+            .and_hms_opt(23, 59, 59)
+            .map(|dt| dt.and_utc())
+    })
+}
+
+impl Field {
+    fn is_text(self) -> bool {
+        matches!(self, Self::Question | Self::Slug)
+    }
+
+    /// Numeric value of this field (`End` in hours remaining, negative once ended)
+    pub fn number(self, market: &Value, now: DateTime<Utc>) -> Option<f64> {
+        let keys: &[&str] = match self {
+            Self::Volume => &["volumeNum", "volume_num", "volume"],
+            Self::Volume24h => &["volume24hr", "volume_24hr"],
+            Self::Liquidity => &["liquidityNum", "liquidity_num", "liquidity"],
+            Self::Spread => &["spread"],
+            Self::Bid => &["bestBid", "best_bid"],
+            Self::Ask => &["bestAsk", "best_ask"],
+            Self::Price => &["lastTradePrice", "last_trade_price"],
+            Self::End => {
+                let end = end_time(market)?;
+                return Some((end - now).num_seconds() as f64 / 3600.0);
+            }
+            Self::Question | Self::Slug => return None,
+        };
+        lookup(market, keys).and_then(as_f64)
+    }
+
+    pub fn text(self, market: &Value) -> Option<&str> {
+        let key = match self {
+            Self::Question => "question",
+            Self::Slug => "slug",
+            _ => return None,
+        };
+        market.get(key)?.as_str()
+    }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+enum Op {
+    Gt,
+    Ge,
+    Lt,
+    Le,
+    Eq,
+    Ne,
+    Contains,
+}
+
+#[derive(Debug, Clone, PartialEq)]
+enum Operand {
+    Number(f64),
+    Text(String),
+}
+
+#[derive(Debug, Clone, PartialEq)]
+enum Expr {
+    And(Box<Expr>, Box<Expr>),
+    Or(Box<Expr>, Box<Expr>),
+    Not(Box<Expr>),
+    Tag(String),
+    Cmp {
+        field: Field,
+        op: Op,
+        value: Operand,
+    },
+}
+
+#[derive(Debug, Clone, PartialEq)]
+enum Token {
+    And,
+    Or,
+    Not,
+    Open,
+    Close,
+    Atom(String),
+}
+
+fn tokenize(input: &str) -> Result<Vec<Token>> {
+    let chars: Vec<char> = input.chars().collect();
+    let mut tokens = Vec::new();
+    let mut i = 0;
+    while i < chars.len() {
+        let c = chars[i];
+        let next = chars.get(i + 1).copied();
+        match c {
+            c if c.is_whitespace() => i += 1,
+            '(' => {
+                tokens.push(Token::Open);
+                i += 1;
+            }
+            ')' => {
+                tokens.push(Token::Close);
+                i += 1;
+            }
+            '&' if next == Some('&') => {
+                tokens.push(Token::And);
+                i += 2;
+            }
+            '|' if next == Some('|') => {
+                tokens.push(Token::Or);
+                i += 2;
+            }
+            '!' if next != Some('=') => {
+                tokens.push(Token::Not);
+                i += 1;
+            }
+            _ => {
+                let mut atom = String::new();
+                let mut quoted = false;
+                while i < chars.len() {
+                    let c = chars[i];
+                    if c == '"' {
+                        quoted = !quoted;
+                    } else if !quoted
+                        && (c.is_whitespace()
+                            || c == '('
+                            || c == ')'
+                            || (c == '&' && chars.get(i + 1) == Some(&'&'))
+                            || (c == '|' && chars.get(i + 1) == Some(&'|')))
+                    {
+                        break;
+                    } else {
+                        atom.push(c);
+                    }
+                    i += 1;
+                }
+                if quoted {
+                    bail!("unterminated quote in filter");
+                }
+                tokens.push(Token::Atom(atom));
+            }
+        }
+    }
+    Ok(tokens)
+}
+
+/// Parse `48h`, `30m`, `7d`, `2w` or `90s` into hours
+fn parse_duration_hours(value: &str) -> Option<f64> {
+    let split = value.find(|c: char| c.is_ascii_alphabetic())?;
+    let (num, unit) = value.split_at(split);
+    let n: f64 = num.parse().ok()?;
+    let hours = match unit {
+        "s" => n / 3600.0,
+        "m" => n / 60.0,
+        "h" => n,
+        "d" => n * 24.0,
+        "w" => n * 24.0 * 7.0,
+        _ => return None,
+    };
+    Some(hours)
+}
+
+/// Parse a number with an optional `k`/`m`/`b` suffix
+fn parse_number(value: &str) -> Option<f64> {
+    let lower = value.to_ascii_lowercase();
+    let (num, mult) = match lower.chars().last()? {
+        'k' => (&lower[..lower.len() - 1], 1e3),
+        'm' => (&lower[..lower.len() - 1], 1e6),
+        'b' => (&lower[..lower.len() - 1], 1e9),
+        _ => (lower.as_str(), 1.0),
+    };
+    num.parse::<f64>().ok().map(|n| n * mult)
+}
+
+fn parse_atom(atom: &str) -> Result<Expr> {
+    if let Some(tag) = atom.strip_prefix("tag:") {
+        if tag.is_empty() {
+            bail!("empty tag in filter");
+        }
+        return Ok(Expr::Tag(tag.to_ascii_lowercase()));
+    }
+
+    let pos = atom
+        .find(['>', '<', '=', '!', '~'])
+        .ok_or_else(|| anyhow!("expected a comparison in '{atom}'"))?;
+    let (field, rest) = atom.split_at(pos);
+    let (op, value) = [
+        (">=", Op::Ge),
+        ("<=", Op::Le),
+        ("==", Op::Eq),
+        ("!=", Op::Ne),
+        (">", Op::Gt),
+        ("<", Op::Lt),
+        ("=", Op::Eq),
+        ("~", Op::Contains),
+    ]
+    .iter()
+    .find_map(|(sym, op)| rest.strip_prefix(sym).map(|v| (*op, v)))
+    .ok_or_else(|| anyhow!("invalid operator in '{atom}'"))?;
+
+    let field: Field = field.parse()?;
+    if value.is_empty() {
+        bail!("missing value in '{atom}'");
+    }
+
+    let value = if field.is_text() {
+        if !matches!(op, Op::Eq | Op::Ne | Op::Contains) {
+            bail!("'{atom}': text fields support ==, != and ~");
+        }
+        Operand::Text(value.to_ascii_lowercase())
+    } else {
+        if op == Op::Contains {
+            bail!("'{atom}': ~ only applies to question and slug");
+        }
+        let n = if field == Field::End {
+            parse_duration_hours(value)
+                .ok_or_else(|| anyhow!("'{atom}': expected a duration like 48h or 7d"))?
+        } else {
+            parse_number(value).ok_or_else(|| anyhow!("'{atom}': expected a number"))?
+        };
+        Operand::Number(n)
+    };
+    Ok(Expr::Cmp { field, op, value })
+}
+
+struct Parser {
+    tokens: Vec<Token>,
+    pos: usize,
+}
+
+impl Parser {
+    fn peek(&self) -> Option<&Token> {
+        self.tokens.get(self.pos)
+    }
+
+    fn next(&mut self) -> Option<Token> {
+        let token = self.tokens.get(self.pos).cloned();
+        self.pos += 1;
+        token
+    }
+
+    fn or(&mut self) -> Result<Expr> {
+        let mut lhs = self.and()?;
+        while self.peek() == Some(&Token::Or) {
+            self.pos += 1;
+            lhs = Expr::Or(Box::new(lhs), Box::new(self.and()?));
+        }
+        Ok(lhs)
+    }
+
+    fn and(&mut self) -> Result<Expr> {
+        let mut lhs = self.unary()?;
+        while self.peek() == Some(&Token::And) {
+            self.pos += 1;
+            lhs = Expr::And(Box::new(lhs), Box::new(self.unary()?));
+        }
+        Ok(lhs)
+    }
+
+    fn unary(&mut self) -> Result<Expr> {
+        match self.next() {
+            Some(Token::Not) => Ok(Expr::Not(Box::new(self.unary()?))),
+            Some(Token::Open) => {
+                let inner = self.or()?;
+                match self.next() {
+                    Some(Token::Close) => Ok(inner),
+                    _ => bail!("missing ')' in filter"),
+                }
+            }
+            Some(Token::Atom(atom)) => parse_atom(&atom),
+            Some(other) => bail!("unexpected {other:?} in filter"),
+            None => bail!("unexpected end of filter"),
+        }
+    }
+}
+
+fn market_has_tag(market: &Value, tag: &str) -> Option<bool> {
+    let tag_lists = std::iter::once(market.get("tags")).chain(
+        market
+            .get("events")
+            .and_then(|e| e.as_array())
+            .into_iter()
+            .flatten()
+            .map(|e| e.get("tags")),
+    );
+    let mut seen_any = false;
+    for tags in tag_lists.flatten().filter_map(|t| t.as_array()) {
+        seen_any = true;
+        let hit = tags.iter().any(|t| {
+            ["slug", "label"].iter().any(|k| {
+                t.get(*k)
+                    .and_then(|v| v.as_str())
+                    .is_some_and(|s| s.eq_ignore_ascii_case(tag))
+            })
+        });
+        if hit {
+            return Some(true);
+        }
+    }
+    seen_any.then_some(false)
+}
+
+/// Parsed `--filter` expression
+#[derive(Debug, Clone)]
+pub struct MarketFilter {
+    expr: Expr,
+}
+
+impl FromStr for MarketFilter {
+    type Err = anyhow::Error;
+
+    fn from_str(input: &str) -> Result<Self> {
+        let mut parser = Parser {
+            tokens: tokenize(input)?,
+            pos: 0,
+        };
+        let expr = parser.or()?;
+        if let Some(token) = parser.peek() {
+            bail!("unexpected {token:?} in filter");
+        }
+        Ok(Self { expr })
+    }
+}
+
+impl MarketFilter {
+    /// First `tag:` that every match must have, to push down as a Gamma `tag_id` query
+    pub fn required_tag(&self) -> Option<&str> {
+        fn walk(expr: &Expr) -> Option<&str> {
+            match expr {
+                Expr::Tag(tag) => Some(tag.as_str()),
+                Expr::And(lhs, rhs) => walk(lhs).or_else(|| walk(rhs)),
+                _ => None,
+            }
+        }
+        walk(&self.expr)
+    }
+
+    /// Evaluate against a Gamma market.
+    ///
+    /// `fetched_tag` is the tag the markets were queried by; it counts as
+    /// present when the market JSON carries no tag information.
+    pub fn matches(&self, market: &Value, now: DateTime<Utc>, fetched_tag: Option<&str>) -> bool {
+        fn eval(expr: &Expr, market: &Value, now: DateTime<Utc>, fetched: Option<&str>) -> bool {
+            match expr {
+                Expr::And(lhs, rhs) => {
+                    eval(lhs, market, now, fetched) && eval(rhs, market, now, fetched)
+                }
+                Expr::Or(lhs, rhs) => {
+                    eval(lhs, market, now, fetched) || eval(rhs, market, now, fetched)
+                }
+                Expr::Not(inner) => !eval(inner, market, now, fetched),
+                Expr::Tag(tag) => market_has_tag(market, tag)
+                    .unwrap_or_else(|| fetched.is_some_and(|f| f.eq_ignore_ascii_case(tag))),
+                Expr::Cmp {
+                    field,
+                    op,
+                    value: Operand::Number(rhs),
+                } => field.number(market, now).is_some_and(|lhs| match op {
+                    Op::Gt => lhs > *rhs,
+                    Op::Ge => lhs >= *rhs,
+                    Op::Lt => lhs < *rhs,
+                    Op::Le => lhs <= *rhs,
+                    Op::Eq => (lhs - rhs).abs() < f64::EPSILON,
+                    Op::Ne => (lhs - rhs).abs() >= f64::EPSILON,
+                    Op::Contains => false,
+                }),
+                Expr::Cmp {
+                    field,
+                    op,
+                    value: Operand::Text(rhs),
+                } => field.text(market).is_some_and(|lhs| {
+                    let lhs = lhs.to_ascii_lowercase();
+                    match op {
+                        Op::Contains => lhs.contains(rhs.as_str()),
+                        Op::Ne => lhs != *rhs,
+                        _ => lhs == *rhs,
+                    }
+                }),
+            }
+        }
+        eval(&self.expr, market, now, fetched_tag)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use serde_json::json;
+
+    #[test]
+    fn test_filter_parses_and_matches() {
+        let now = Utc::now();
+        let market = json!({
+            "question": "Will BTC close above $100k?",
+            "volumeNum": 75000.0,
+            "liquidity": "1200.5",
+            "spread": 0.02,
+            "endDate": (now + chrono::Duration::hours(24)).to_rfc3339(),
+            "events": [{ "tags": [{ "slug": "crypto", "label": "Crypto" }] }],
+        });
+
+        let filter: MarketFilter = "volume>50k && end<48h && spread<0.03 && tag:crypto"
+            .parse()
+            .unwrap();
+        assert_eq!(filter.required_tag(), Some("crypto"));
+        assert!(filter.matches(&market, now, None));
+
+        let filter: MarketFilter = "(liquidity>=5000 || question~\"close above\") && !tag:sports"
+            .parse()
+            .unwrap();
+        assert_eq!(filter.required_tag(), None);
+        assert!(filter.matches(&market, now, None));
+
+        assert!(!"end<12h"
+            .parse::<MarketFilter>()
+            .unwrap()
+            .matches(&market, now, None));
+        assert!("volume>".parse::<MarketFilter>().is_err());
+        assert!("end<soon".parse::<MarketFilter>().is_err());
+        assert!("(volume>1".parse::<MarketFilter>().is_err());
+        assert!("question>3".parse::<MarketFilter>().is_err());
+    }
+}
@@ -5,8 +5,12 @@ use serde::Serialize;
 use tabled::Tabled;
 
 use super::auth::PmAuth;
+use super::market_filter::{Field, MarketFilter};
 use super::output::{self, OutputMode};
 
+/// Gamma page size used while scanning
+const SCAN_PAGE_SIZE: i32 = 100;
+
 #[derive(Subcommand, Debug, Clone)]
 pub enum MarketsCommands {
     /// List markets with optional filters.
@@ -28,6 +32,31 @@ pub enum MarketsCommands {
         #[arg(long, default_value = "10")]
         limit: i32,
     },
+    /// Scan markets with a filter expression.
+    ///
+    /// Example: --filter "volume>50000 && end<48h && spread<0.03 && tag:crypto".
+    /// Fields: volume, volume24h, liquidity, spread, bid, ask, price, end,
+    /// question, slug. Combine with &&, ||, ! and parentheses.
+    Scan {
+        /// Filter expression
+        #[arg(long, short = 'f')]
+        filter: Option<String>,
+        /// Sort by a numeric field (volume, volume24h, liquidity, spread, bid, ask, price, end)
+        #[arg(long, default_value = "volume")]
+        sort: String,
+        /// Sort ascending instead of descending
+        #[arg(long)]
+        asc: bool,
+        /// Maximum rows to print
+        #[arg(long, default_value = "50")]
+        limit: usize,
+        /// Maximum markets to fetch from Gamma before filtering
+        #[arg(long, default_value = "1000")]
+        max_markets: usize,
+        /// Include closed markets
+        #[arg(long)]
+        include_closed: bool,
+    },
 }
 
 #[derive(Debug, Serialize, Tabled)]
@@ -39,6 +68,178 @@ pub struct MarketRow {
     pub liquidity: String,
 }
 
+/// Scan result (JSON output keeps numbers numeric)
+#[derive(Debug, Serialize)]
+pub struct ScannedMarket {
+    pub id: String,
+    pub slug: Option<String>,
+    pub question: String,
+    pub volume: Option<f64>,
+    pub volume_24h: Option<f64>,
+    pub liquidity: Option<f64>,
+    pub spread: Option<f64>,
+    pub best_bid: Option<f64>,
+    pub best_ask: Option<f64>,
+    pub hours_to_end: Option<f64>,
+    pub clob_token_ids: serde_json::Value,
+}
+
+#[derive(Debug, Serialize, Tabled)]
+pub struct ScanRow {
+    pub id: String,
+    pub question: String,
+    pub volume: String,
+    pub liquidity: String,
+    pub spread: String,
+    pub ends_in: String,
+}
+
+fn fmt_opt(value: Option<f64>, decimals: usize) -> String {
+    value
+        .map(|v| format!("{v:.decimals$}"))
+        .unwrap_or_else(|| "-".to_string())
+}
+
+fn fmt_hours(hours: Option<f64>) -> String {
+    match hours {
+        None => "-".to_string(),
+        Some(h) if h < 0.0 => "ended".to_string(),
+        Some(h) if h < 48.0 => format!("{h:.1}h"),
+        Some(h) => format!("{:.1}d", h / 24.0),
+    }
+}
+
+impl From<&ScannedMarket> for ScanRow {
+    fn from(m: &ScannedMarket) -> Self {
+        let mut question = m.question.clone();
+        if question.chars().count() > 60 {
+            question = question.chars().take(57).collect::<String>() + "...";
+        }
+        Self {
+            id: m.id.clone(),
+            question,
+            volume: fmt_opt(m.volume, 0),
+            liquidity: fmt_opt(m.liquidity, 0),
+            spread: fmt_opt(m.spread, 3),
+            ends_in: fmt_hours(m.hours_to_end),
+        }
+    }
+}
+
+#[allow(clippy::too_many_arguments)]
+async fn scan(
+    gamma: &polymarket_client_sdk::gamma::Client,
+    filter: Option<String>,
+    sort: &str,
+    asc: bool,
+    limit: usize,
+    max_markets: usize,
+    include_closed: bool,
+    mode: OutputMode,
+) -> anyhow::Result<()> {
+    use polymarket_client_sdk::gamma::types::request::*;
+
+    let filter: Option<MarketFilter> = filter.as_deref().map(str::parse).transpose()?;
+    let sort_field: Field = sort.parse()?;
+    if matches!(sort_field, Field::Question | Field::Slug) {
+        anyhow::bail!("--sort needs a numeric field, got '{sort}'");
+    }
+
+    // Push a required tag down to Gamma as a tag_id query
+    let tag = filter
+        .as_ref()
+        .and_then(|f| f.required_tag())
+        .map(str::to_string);
+    let tag_id = match &tag {
+        Some(slug) => {
+            let req = TagBySlugRequest::builder().slug(slug.clone()).build();
+            let tag = serde_json::to_value(gamma.tag_by_slug(&req).await?)?;
+            let id = match tag.get("id") {
+                Some(serde_json::Value::String(s)) => s.clone(),
+                Some(serde_json::Value::Number(n)) => n.to_string(),
+                _ => anyhow::bail!("tag '{slug}' has no id"),
+            };
+            Some(id)
+        }
+        None => None,
+    };
+
+    let now = chrono::Utc::now();
+    let mut matched = Vec::new();
+    let mut offset = 0usize;
+    while offset < max_markets {
+        let page_size = SCAN_PAGE_SIZE.min((max_markets - offset) as i32);
+        let req = MarketsRequest::builder()
+            .limit(page_size)
+            .offset(offset as i32)
+            .maybe_closed((!include_closed).then_some(false))
+            .maybe_tag_id(tag_id.clone())
+            .build();
+        let page = gamma.markets(&req).await?;
+        let fetched = page.len();
+        for market in page {
+            let value = serde_json::to_value(&market)?;
+            if filter
+                .as_ref()
+                .is_none_or(|f| f.matches(&value, now, tag.as_deref()))
+            {
+                matched.push(value);
+            }
+        }
+        if fetched < page_size as usize {
+            break;
+        }
+        offset += fetched;
+    }
+
+    let key = |m: &serde_json::Value| sort_field.number(m, now);
+    matched.sort_by(|a, b| {
+        // Markets without the sort field go last either way
+        match (key(a), key(b)) {
+            (Some(x), Some(y)) if asc => x.total_cmp(&y),
+            (Some(x), Some(y)) => y.total_cmp(&x),
+            (Some(_), None) => std::cmp::Ordering::Less,
+            (None, Some(_)) => std::cmp::Ordering::Greater,
+            (None, None) => std::cmp::Ordering::Equal,
+        }
+    });
+    matched.truncate(limit);
+
+    let results: Vec<ScannedMarket> = matched
+        .iter()
+        .map(|m| ScannedMarket {
+            id: m
+                .get("id")
+                .and_then(|v| v.as_str())
+                .unwrap_or_default()
+                .to_string(),
+            slug: Field::Slug.text(m).map(str::to_string),
+            question: Field::Question.text(m).unwrap_or_default().to_string(),
+            volume: Field::Volume.number(m, now),
+            volume_24h: Field::Volume24h.number(m, now),
+            liquidity: Field::Liquidity.number(m, now),
+            spread: Field::Spread.number(m, now),
+            best_bid: Field::Bid.number(m, now),
+            best_ask: Field::Ask.number(m, now),
+            hours_to_end: Field::End.number(m, now),
+            clob_token_ids: m
+                .get("clobTokenIds")
+                .or_else(|| m.get("clob_token_ids"))
+                .cloned()
+                .unwrap_or(serde_json::Value::Null),
+        })
+        .collect();
+
+    match mode {
+        OutputMode::Json => println!("{}", serde_json::to_string_pretty(&results)?),
+        OutputMode::Table => {
+            let rows: Vec<ScanRow> = results.iter().map(ScanRow::from).collect();
+            output::print_items(&rows, mode)?;
+        }
+    }
+    Ok(())
+}
+
 pub async fn run(cmd: MarketsCommands, _auth: &PmAuth, mode: OutputMode) -> anyhow::Result<()> {
     use polymarket_client_sdk::gamma::types::request::*;
     use polymarket_client_sdk::gamma::Client as GammaClient;
@@ -88,6 +289,26 @@ pub async fn run(cmd: MarketsCommands, _auth: &PmAuth, mode: OutputMode) -> anyh
             let results = gamma.search(&req).await?;
             output::print_item(&results, mode)?;
         }
+        MarketsCommands::Scan {
+            filter,
+            sort,
+            asc,
+            limit,
+            max_markets,
+            include_closed,
+        } => {
+            scan(
+                &gamma,
+                filter,
+                &sort,
+                asc,
+                limit,
+                max_markets,
+                include_closed,
+                mode,
+            )
+            .await?;
+        }
     }
     Ok(())
 }
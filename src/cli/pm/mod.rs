@@ -12,9 +12,13 @@ pub mod approve;
 pub mod bridge;
 pub mod clob;
 pub mod comments;
+pub mod completion;
 pub mod ctf;
 pub mod data;
 pub mod events;
+pub mod ladder;
+pub mod market_cache;
+pub mod market_filter;
 pub mod markets;
 pub mod orders;
 pub mod profiles;
@@ -24,6 +28,7 @@ pub mod shell;
 pub mod sports;
 pub mod tags;
 pub mod wallet;
+pub mod watchlist;
 
 use clap::{Args, Subcommand};
 
@@ -118,6 +123,10 @@ pub enum PmCommands {
     #[command(subcommand)]
     Bridge(bridge::BridgeCommands),
 
+    /// Token watchlists with quote alerts.
+    #[command(subcommand)]
+    Watchlist(watchlist::WatchlistCommands),
+
     /// Interactive setup wizard.
     Setup,
 
@@ -145,6 +154,7 @@ pub async fn run(cmd: PmCommands, args: &GlobalPmArgs) -> anyhow::Result<()> {
         PmCommands::Ctf(sub) => ctf::run(sub, &auth, out_mode, args).await,
         PmCommands::Approve(sub) => approve::run(sub, &auth, out_mode, args).await,
         PmCommands::Bridge(sub) => bridge::run(sub, &auth, out_mode).await,
+        PmCommands::Watchlist(sub) => watchlist::run(sub, &auth, out_mode).await,
         PmCommands::Setup => setup::run().await,
         PmCommands::Shell => shell::run(args).await,
     }
@@ -1,10 +1,21 @@
 //! `ploy pm orders` — Order management commands (authenticated).
 
+use async_trait::async_trait;
 use clap::Subcommand;
+use polymarket_client_sdk::auth::{state::Authenticated, Normal};
+use polymarket_client_sdk::clob::types::request::OrdersRequest;
+use polymarket_client_sdk::clob::Client as ClobClient;
 
 use super::auth::PmAuth;
+use super::ladder::{self, LadderArgs, LadderBook, LadderSide, SavedLadder};
 use super::output::{self, OutputMode};
 use super::GlobalPmArgs;
+use crate::adapters::polymarket_clob::CLOB_TERMINAL_CURSOR;
+use crate::coordination::cancel_all::{
+    cancel_matching, list_open_orders, parse_age, CancelAllConfig, CancelAllSummary, CancelScope,
+    CancelVenue, OpenOrder, OpenOrdersPage,
+};
+use crate::error::PloyError;
 
 #[derive(Subcommand, Debug, Clone)]
 pub enum OrdersCommands {
@@ -42,16 +53,22 @@ pub enum OrdersCommands {
     Get { order_id: String },
     /// Cancel an order.
     Cancel { order_id: String },
-    /// Cancel all orders.
+    /// Cancel all open orders, optionally scoped by market and age.
     CancelAll {
+        /// Condition ID or token ID to restrict the sweep to.
         #[arg(long)]
         market: Option<String>,
+        /// Only cancel orders at least this old (e.g. 90s, 5m, 2h, 1d).
+        #[arg(long)]
+        older_than: Option<String>,
     },
     /// List recent trades.
     Trades {
         #[arg(long)]
         market: Option<String>,
     },
+    /// Place a ladder of limit orders across a price range.
+    Ladder(LadderArgs),
 }
 
 pub async fn run(
@@ -63,10 +80,39 @@ pub async fn run(
     use alloy::primitives::{B256, U256};
     use polymarket_client_sdk::clob::types::request::*;
     use polymarket_client_sdk::clob::types::{Amount, Side, SignatureType};
-    use polymarket_client_sdk::clob::Client as ClobClient;
     use rust_decimal::Decimal;
     use std::str::FromStr;
 
+    // Ladder previews are pure math; don't require credentials for them
+    if let OrdersCommands::Ladder(spec) = &cmd {
+        if args.dry_run {
+            let plan = spec.plan()?;
+            ladder::print_preview(&plan, mode)?;
+            if spec.replace {
+                let book = LadderBook::load()?;
+                match book.ladders.get(&plan.name) {
+                    Some(old) => output::print_warn(&format!(
+                        "[DRY RUN] Would cancel {} orders of ladder '{}' and place {} new orders",
+                        old.order_ids.len(),
+                        plan.name,
+                        plan.levels.len()
+                    )),
+                    None => output::print_warn(&format!(
+                        "[DRY RUN] No saved ladder named '{}' to replace",
+                        plan.name
+                    )),
+                }
+            } else {
+                output::print_warn(&format!(
+                    "[DRY RUN] Would place {} {} orders",
+                    plan.levels.len(),
+                    plan.side.as_str()
+                ));
+            }
+            return Ok(());
+        }
+    }
+
     let signer = auth.require_signer()?;
     let config = super::config_file::PmConfig::load().unwrap_or_default();
 
@@ -208,23 +254,61 @@ pub async fn run(
             output::print_debug(&resp, mode)?;
             output::print_success(&format!("order {order_id} cancelled"));
         }
-        OrdersCommands::CancelAll { market } => {
+        OrdersCommands::CancelAll { market, older_than } => {
+            let mut scope = CancelScope::all();
+            if let Some(m) = market {
+                scope = scope.with_market(m);
+            }
+            if let Some(age) = older_than.as_deref() {
+                scope = scope.with_older_than(parse_age(age)?);
+            }
+            let config = CancelAllConfig::default();
+            let venue = SdkCancelVenue(&client);
+
+            let (orders, truncated) = list_open_orders(&venue, config.max_pages).await?;
+            let now = chrono::Utc::now();
+            let matching: Vec<&OpenOrder> =
+                orders.iter().filter(|o| scope.matches(o, now)).collect();
+            if truncated {
+                output::print_warn(&format!(
+                    "listing stopped after {} pages; more orders may be open",
+                    config.max_pages
+                ));
+            }
+            if matching.is_empty() {
+                output::print_success(&format!(
+                    "no open orders to cancel ({} scanned)",
+                    orders.len()
+                ));
+                return Ok(());
+            }
+
             if args.dry_run {
-                output::print_warn("[DRY RUN] Would cancel all orders");
+                output::print_warn(&format!(
+                    "[DRY RUN] Would cancel {} of {} open orders:",
+                    matching.len(),
+                    orders.len()
+                ));
+                for order in &matching {
+                    println!("  {}", order.id);
+                }
                 return Ok(());
             }
-            if !args.yes && !output::confirm("Cancel ALL orders? This cannot be undone.") {
+            if !args.yes
+                && !output::confirm(&format!(
+                    "Cancel {} of {} open orders? This cannot be undone.",
+                    matching.len(),
+                    orders.len()
+                ))
+            {
                 output::print_warn("cancelled");
                 return Ok(());
             }
-            if let Some(m) = market {
-                let b256 = B256::from_str(&m)?;
-                let req = CancelMarketOrderRequest::builder().market(b256).build();
-                let resp = client.cancel_market_orders(&req).await?;
-                output::print_debug(&resp, mode)?;
-            } else {
-                let resp = client.cancel_all_orders().await?;
-                output::print_debug(&resp, mode)?;
+
+            let summary = cancel_matching(&venue, &orders, truncated, &scope, &config).await;
+            print_cancel_summary(&summary, mode)?;
+            if !summary.failed.is_empty() {
+                anyhow::bail!("{} orders could not be cancelled", summary.failed.len());
             }
         }
         OrdersCommands::Trades { market } => {
@@ -233,6 +317,205 @@ pub async fn run(
             let page = client.trades(&req, None).await?;
             output::print_debug_items(&page.data, mode)?;
         }
+        OrdersCommands::Ladder(ladder_args) => {
+            let plan = ladder_args.plan()?;
+            ladder::print_preview(&plan, mode)?;
+
+            let mut book = LadderBook::load()?;
+            let existing = book.ladders.get(&plan.name).cloned();
+            match (&existing, ladder_args.replace) {
+                (Some(_), false) => anyhow::bail!(
+                    "ladder '{}' already exists; pass --replace to cancel and replace it",
+                    plan.name
+                ),
+                (None, true) => anyhow::bail!("no saved ladder named '{}' to replace", plan.name),
+                _ => {}
+            }
+
+            let prompt = match &existing {
+                Some(old) => format!(
+                    "Cancel {} orders of ladder '{}' and place {} new {} orders?",
+                    old.order_ids.len(),
+                    plan.name,
+                    plan.levels.len(),
+                    plan.side.as_str()
+                ),
+                None => format!(
+                    "Place {} {} orders for ladder '{}'?",
+                    plan.levels.len(),
+                    plan.side.as_str(),
+                    plan.name
+                ),
+            };
+            if !args.yes && !output::confirm(&prompt) {
+                output::print_warn("cancelled");
+                return Ok(());
+            }
+
+            let tid = U256::from_str(&plan.token_id)?;
+            let sdk_side = match plan.side {
+                LadderSide::Buy => Side::Buy,
+                LadderSide::Sell => Side::Sell,
+            };
+
+            // Sign every level up front so a bad level fails before anything
+            // on the book is touched
+            let mut signed_orders = Vec::with_capacity(plan.levels.len());
+            for level in &plan.levels {
+                let signable = client
+                    .limit_order()
+                    .token_id(tid)
+                    .side(sdk_side)
+                    .price(level.price)
+                    .size(level.size)
+                    .build()
+                    .await?;
+                signed_orders.push(client.sign(signer, signable).await?);
+            }
+
+            // Take the old ladder down first; if any cancel fails, keep the
+            // remaining ids saved and place nothing
+            if let Some(old) = &existing {
+                for (i, order_id) in old.order_ids.iter().enumerate() {
+                    if let Err(e) = client.cancel_order(order_id).await {
+                        if let Some(saved) = book.ladders.get_mut(&plan.name) {
+                            saved.order_ids = old.order_ids[i..].to_vec();
+                        }
+                        book.save()?;
+                        anyhow::bail!(
+                            "replace aborted: failed to cancel order {order_id} of ladder \
+                             '{}': {e}; no new orders were placed",
+                            plan.name
+                        );
+                    }
+                }
+                book.ladders.remove(&plan.name);
+                book.save()?;
+                output::print_success(&format!(
+                    "cancelled {} orders of ladder '{}'",
+                    old.order_ids.len(),
+                    plan.name
+                ));
+            }
+
+            // Post the new ladder; on failure roll back the levels already placed
+            let mut order_ids = Vec::with_capacity(signed_orders.len());
+            for signed in signed_orders {
+                match client.post_order(signed).await {
+                    Ok(resp) => order_ids.push(resp.order_id),
+                    Err(e) => {
+                        let mut stranded = Vec::new();
+                        for order_id in &order_ids {
+                            if client.cancel_order(order_id).await.is_err() {
+                                stranded.push(order_id.clone());
+                            }
+                        }
+                        if !stranded.is_empty() {
+                            output::print_error(&format!(
+                                "failed to roll back orders: {}",
+                                stranded.join(", ")
+                            ));
+                        }
+                        anyhow::bail!(
+                            "ladder '{}' failed after {} of {} orders: {e}; \
+                             placed orders were rolled back",
+                            plan.name,
+                            order_ids.len(),
+                            plan.levels.len()
+                        );
+                    }
+                }
+            }
+
+            book.ladders.insert(
+                plan.name.clone(),
+                SavedLadder {
+                    token_id: plan.token_id.clone(),
+                    side: plan.side,
+                    distribution: plan.distribution,
+                    levels: plan.levels.clone(),
+                    order_ids,
+                    placed_at: chrono::Utc::now(),
+                },
+            );
+            book.save()?;
+            output::print_success(&format!(
+                "placed {} orders for ladder '{}'",
+                plan.levels.len(),
+                plan.name
+            ));
+        }
+    }
+    Ok(())
+}
+
+/// Authenticated SDK client as a cancel-all venue
+struct SdkCancelVenue<'a>(&'a ClobClient<Authenticated<Normal>>);
+
+#[async_trait]
+impl CancelVenue for SdkCancelVenue<'_> {
+    async fn open_orders_page(
+        &self,
+        cursor: Option<String>,
+    ) -> crate::error::Result<OpenOrdersPage> {
+        let req = OrdersRequest::builder().build();
+        let page = self
+            .0
+            .orders(&req, cursor)
+            .await
+            .map_err(|e| PloyError::Internal(format!("Failed to get orders: {e}")))?;
+        let next_cursor = if page.next_cursor == CLOB_TERMINAL_CURSOR || page.next_cursor.is_empty()
+        {
+            None
+        } else {
+            Some(page.next_cursor)
+        };
+        let orders = page
+            .data
+            .into_iter()
+            .map(|o| OpenOrder {
+                id: o.id,
+                market: Some(o.market.to_string()),
+                asset_id: Some(o.asset_id.to_string()),
+                created_at: Some(o.created_at.with_timezone(&chrono::Utc)),
+            })
+            .collect();
+        Ok(OpenOrdersPage {
+            orders,
+            next_cursor,
+        })
+    }
+
+    async fn cancel_order(&self, order_id: &str) -> crate::error::Result<()> {
+        self.0
+            .cancel_order(order_id)
+            .await
+            .map_err(|e| PloyError::Internal(format!("Failed to cancel order: {e}")))?;
+        Ok(())
+    }
+}
+
+fn print_cancel_summary(summary: &CancelAllSummary, mode: OutputMode) -> anyhow::Result<()> {
+    if mode == OutputMode::Json {
+        return output::print_item(summary, mode);
+    }
+    output::print_success(&format!(
+        "cancelled {} of {} matching orders ({} scanned)",
+        summary.cancelled.len(),
+        summary.matched,
+        summary.scanned
+    ));
+    if !summary.failed.is_empty() {
+        output::print_error("could not cancel:");
+        for failure in &summary.failed {
+            output::print_error(&format!(
+                "  {} after {} attempts: {}",
+                failure.order_id, failure.attempts, failure.error
+            ));
+        }
+    }
+    if summary.truncated {
+        output::print_warn("listing was truncated; run again to catch remaining orders");
     }
     Ok(())
 }
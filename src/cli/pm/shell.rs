@@ -1,7 +1,26 @@
 //! `ploy pm shell` — Interactive REPL for Polymarket CLI.
+//!
+//! Tab completes subcommands, flags, slugs and token IDs; `find <query>`
+//! fuzzy-searches cached markets/events and drops the picked token ID into
+//! the next prompt. History is persisted after every command.
 
+use std::path::Path;
+
+use rustyline::history::DefaultHistory;
+use rustyline::Editor;
+
+use super::completion::ShellHelper;
+use super::market_cache::MarketCache;
 use super::GlobalPmArgs;
 
+type ShellEditor = Editor<ShellHelper, DefaultHistory>;
+
+/// Matches listed by `find`
+const FIND_LIMIT: usize = 15;
+
+/// History entries kept on disk
+const MAX_HISTORY: usize = 5000;
+
 pub fn run(
     args: &GlobalPmArgs,
 ) -> std::pin::Pin<Box<dyn std::future::Future<Output = anyhow::Result<()>> + '_>> {
@@ -9,8 +28,9 @@ pub fn run(
 }
 
 async fn run_inner(args: &GlobalPmArgs) -> anyhow::Result<()> {
+    use clap::CommandFactory;
+    use rustyline::config::{CompletionType, Config};
     use rustyline::error::ReadlineError;
-    use rustyline::DefaultEditor;
 
     println!("\x1b[36mPolymarket Interactive Shell\x1b[0m");
     println!("Type commands without 'ploy pm' prefix. E.g.: markets search bitcoin");
@@ -21,14 +41,32 @@ async fn run_inner(args: &GlobalPmArgs) -> anyhow::Result<()> {
         .ok()
         .map(|d| d.join("history.txt"));
 
-    let mut rl = DefaultEditor::new()?;
+    let config = Config::builder()
+        .completion_type(CompletionType::List)
+        .history_ignore_dups(true)?
+        .max_history_size(MAX_HISTORY)?
+        .build();
+    let mut rl: ShellEditor = Editor::with_config(config)?;
+    let cache = MarketCache::load().unwrap_or_else(|e| {
+        super::output::print_warn(&format!("ignoring market cache: {e}"));
+        MarketCache::default()
+    });
+    rl.set_helper(Some(ShellHelper::new(ShellCli::command(), cache)));
 
     if let Some(ref path) = history_path {
         let _ = rl.load_history(path);
     }
 
+    // Token ID or slug picked by `find`, offered after the cursor next prompt
+    let mut picked: Option<String> = None;
+
     loop {
-        match rl.readline("\x1b[36mpm>\x1b[0m ") {
+        let prompt = "\x1b[36mpm>\x1b[0m ";
+        let read = match picked.take() {
+            Some(value) => rl.readline_with_initial(prompt, ("", &format!(" {value}"))),
+            None => rl.readline(prompt),
+        };
+        match read {
             Ok(line) => {
                 let line = line.trim();
                 if line.is_empty() {
@@ -38,14 +76,33 @@ async fn run_inner(args: &GlobalPmArgs) -> anyhow::Result<()> {
                 // Skip history entries that contain sensitive data
                 if !line.contains("--private-key") {
                     let _ = rl.add_history_entry(line);
+                    if let Some(ref path) = history_path {
+                        save_history(&mut rl, path);
+                    }
                 }
 
-                match line {
-                    "exit" | "quit" | "q" => break,
-                    "help" | "?" => {
+                match line
+                    .split_once(' ')
+                    .map_or((line, ""), |(c, rest)| (c, rest.trim()))
+                {
+                    ("exit" | "quit" | "q", _) => break,
+                    ("help" | "?", _) => {
                         print_shell_help();
                         continue;
                     }
+                    ("refresh", _) => {
+                        if let Err(e) = refresh_cache(&mut rl).await {
+                            super::output::print_error(&format!("{e}"));
+                        }
+                        continue;
+                    }
+                    ("find", query) => {
+                        match find(&mut rl, query).await {
+                            Ok(value) => picked = value,
+                            Err(e) => super::output::print_error(&format!("{e}")),
+                        }
+                        continue;
+                    }
                     _ => {}
                 }
 
@@ -81,31 +138,115 @@ async fn run_inner(args: &GlobalPmArgs) -> anyhow::Result<()> {
     }
 
     if let Some(ref path) = history_path {
-        if let Some(parent) = path.parent() {
-            #[cfg(unix)]
-            {
-                use std::os::unix::fs::DirBuilderExt;
-                let mut builder = std::fs::DirBuilder::new();
-                builder.recursive(true).mode(0o700);
-                let _ = builder.create(parent);
-            }
-            #[cfg(not(unix))]
-            {
-                let _ = std::fs::create_dir_all(parent);
-            }
-        }
-        let _ = rl.save_history(path);
-        // Restrict history file permissions
+        save_history(&mut rl, path);
+    }
+
+    Ok(())
+}
+
+fn save_history(rl: &mut ShellEditor, path: &Path) {
+    if let Some(parent) = path.parent() {
         #[cfg(unix)]
         {
-            use std::os::unix::fs::PermissionsExt;
-            let _ = std::fs::set_permissions(path, std::fs::Permissions::from_mode(0o600));
+            use std::os::unix::fs::DirBuilderExt;
+            let mut builder = std::fs::DirBuilder::new();
+            builder.recursive(true).mode(0o700);
+            let _ = builder.create(parent);
         }
+        #[cfg(not(unix))]
+        {
+            let _ = std::fs::create_dir_all(parent);
+        }
+    }
+    let _ = rl.save_history(path);
+    // Restrict history file permissions
+    #[cfg(unix)]
+    {
+        use std::os::unix::fs::PermissionsExt;
+        let _ = std::fs::set_permissions(path, std::fs::Permissions::from_mode(0o600));
     }
+}
 
+/// Re-fetch the market cache from Gamma and persist it.
+async fn refresh_cache(rl: &mut ShellEditor) -> anyhow::Result<()> {
+    let config = super::config_file::PmConfig::load().unwrap_or_default();
+    let gamma = polymarket_client_sdk::gamma::Client::new(config.gamma_base_url())?;
+    let Some(helper) = rl.helper_mut() else {
+        return Ok(());
+    };
+    println!("refreshing market cache...");
+    helper.cache.refresh(&gamma).await?;
+    helper.cache.save()?;
+    println!("cached {} markets/events", helper.cache.entries.len());
     Ok(())
 }
 
+/// Read a 1-based choice; empty input or a bad number picks nothing.
+fn read_choice(rl: &mut ShellEditor, prompt: &str, count: usize) -> Option<usize> {
+    let input = rl.readline(prompt).ok()?;
+    match input.trim().parse::<usize>() {
+        Ok(n) if (1..=count).contains(&n) => Some(n - 1),
+        _ => None,
+    }
+}
+
+/// Fuzzy-pick a market/event and one of its tokens.
+///
+/// Returns the picked token ID, or the slug when no token was chosen.
+async fn find(rl: &mut ShellEditor, query: &str) -> anyhow::Result<Option<String>> {
+    if query.is_empty() {
+        anyhow::bail!("usage: find <query>");
+    }
+    let stale = rl
+        .helper()
+        .is_some_and(|h| h.cache.is_stale(chrono::Utc::now()));
+    if stale {
+        if let Err(e) = refresh_cache(rl).await {
+            super::output::print_warn(&format!("market cache refresh failed: {e}"));
+        }
+    }
+
+    let matches: Vec<_> = match rl.helper() {
+        Some(helper) => helper
+            .cache
+            .search(query, FIND_LIMIT)
+            .into_iter()
+            .cloned()
+            .collect(),
+        None => Vec::new(),
+    };
+    if matches.is_empty() {
+        super::output::print_warn(&format!("no cached markets match '{query}'"));
+        return Ok(None);
+    }
+    for (i, m) in matches.iter().enumerate() {
+        println!(
+            "{:>3}. [{}] \x1b[36m{}\x1b[0m  {}",
+            i + 1,
+            m.kind.as_str(),
+            m.slug,
+            m.title
+        );
+    }
+    let Some(choice) = read_choice(rl, "pick #> ", matches.len()) else {
+        return Ok(None);
+    };
+    let picked = &matches[choice];
+    if picked.tokens.is_empty() {
+        return Ok(Some(picked.slug.clone()));
+    }
+
+    for (i, t) in picked.tokens.iter().enumerate() {
+        println!("{:>3}. {:<24} {}", i + 1, t.outcome, t.token_id);
+    }
+    let value = match read_choice(rl, "token #> ", picked.tokens.len()) {
+        Some(i) => picked.tokens[i].token_id.clone(),
+        None => picked.slug.clone(),
+    };
+    println!("(inserted after the cursor; type a command in front of it)");
+    Ok(Some(value))
+}
+
 /// Internal CLI struct for shell parsing.
 #[derive(clap::Parser)]
 #[command(name = "pm", no_binary_name = true)]
@@ -116,7 +257,7 @@ struct ShellCli {
 
 fn print_shell_help() {
     println!("Available commands:");
-    println!("  markets  {{list, get, get-by-slug, search}}");
+    println!("  markets  {{list, get, get-by-slug, search, scan}}");
     println!("  events   {{list, get, get-by-slug}}");
     println!("  tags     {{list, get, get-by-slug, related}}");
     println!("  series   {{list, get}}");
@@ -125,12 +266,16 @@ fn print_shell_help() {
     println!("  sports   {{metadata, market-types, teams}}");
     println!("  clob     {{health, time, midpoint, price, spread, book, ...}}");
     println!("  data     {{positions, trades, activity, leaderboard, ...}}");
-    println!("  orders   {{create, market-buy, market-sell, list, cancel, ...}}");
+    println!("  orders   {{create, market-buy, market-sell, ladder, list, cancel, ...}}");
     println!("  wallet   {{address, balance, api-keys, ...}}");
     println!("  ctf      {{split, merge, redeem, condition-id}}");
     println!("  approve  {{check, set}}");
     println!("  bridge   {{deposit, supported-assets}}");
+    println!("  watchlist {{add, remove, list, run}}");
     println!("  setup    (interactive setup wizard)");
+    println!("  find     <query>  fuzzy-pick a cached market/event token");
+    println!("  refresh  (re-fetch the market cache)");
+    println!("  <TAB>    complete commands, flags, slugs and token IDs");
     println!("  help     (this message)");
     println!("  exit     (quit shell)");
 }
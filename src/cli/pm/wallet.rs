@@ -15,6 +15,8 @@ pub enum WalletCommands {
     ApiKeys,
     /// Create a new API key.
     CreateApiKey,
+    /// Rotate the HMAC API key: create a fresh one, store it encrypted, revoke the old.
+    RotateKeys,
     /// Show notifications.
     Notifications,
 }
@@ -77,6 +79,36 @@ pub async fn run(cmd: WalletCommands, auth: &PmAuth, mode: OutputMode) -> anyhow
                 "Save the full credentials securely. Use `ploy pm setup` to configure.",
             );
         }
+        WalletCommands::RotateKeys => {
+            use crate::signing::{
+                CredentialCipher, CredentialManager, EncryptedCredentialStore, Wallet,
+            };
+            use std::sync::Arc;
+
+            let mut key_hex = hex::encode(signer.to_bytes());
+            let wallet = Wallet::from_private_key(&key_hex, auth.chain_id);
+            zeroize::Zeroize::zeroize(&mut key_hex);
+            let wallet = Arc::new(wallet?);
+
+            let cipher = CredentialCipher::from_wallet(&wallet).await?;
+            let store =
+                EncryptedCredentialStore::new(EncryptedCredentialStore::default_path(), cipher);
+            let manager = CredentialManager::new(config.clob_base_url(), wallet).with_store(store);
+
+            let old = manager.current_stored().await?;
+            manager.rotate().await?;
+            let new = manager.current_stored().await?;
+
+            output::print_success("API key rotated");
+            output::print_kv("previous_api_key", &old.api_key);
+            output::print_kv("api_key", &new.api_key);
+            output::print_kv("nonce", &new.nonce.to_string());
+            output::print_kv("api_secret", "[REDACTED]");
+            output::print_kv("api_passphrase", "[REDACTED]");
+            if let Some(store) = manager.store() {
+                output::print_kv("stored_at", &store.path().display().to_string());
+            }
+        }
         WalletCommands::Notifications => {
             let client = ClobClient::new(
                 config.clob_base_url(),
@@ -0,0 +1,652 @@
+//! `ploy pm watchlist` — Persistent token watchlists with quote alerts.
+//!
+//! Entries are stored in `~/.config/polymarket/watchlist.json`. `run` streams
+//! quotes for every watched token over the CLOB market WebSocket and fires
+//! alerts (price crossing, spread blowout, volume spike) to the terminal and
+//! the AlertManager channels (Feishu when `FEISHU_WEBHOOK_URL` is set, Discord when
+//! `DISCORD_WEBHOOK_URL` is set).
+
+use anyhow::{Context, Result};
+use chrono::{DateTime, Utc};
+use clap::Subcommand;
+use rust_decimal::Decimal;
+use serde::{Deserialize, Serialize};
+use std::collections::HashMap;
+use std::path::PathBuf;
+use std::sync::Arc;
+use tabled::Tabled;
+
+use super::auth::PmAuth;
+use super::output::{self, OutputMode};
+use crate::adapters::{DiscordNotifier, FeishuNotifier, PolymarketWebSocket};
+use crate::domain::Side;
+use crate::supervisor::AlertManager;
+
+const DEFAULT_WS_URL: &str = "wss://ws-subscriptions-clob.polymarket.com/ws/market";
+const ALERT_COMPONENT: &str = "pm_watchlist";
+
+#[derive(Subcommand, Debug, Clone)]
+pub enum WatchlistCommands {
+    /// Add (or update) tokens on the watchlist.
+    Add {
+        /// CLOB token IDs to watch.
+        #[arg(required = true)]
+        token_ids: Vec<String>,
+        /// Market label used to group tokens (e.g. a market slug).
+        #[arg(long)]
+        market: Option<String>,
+        /// Human-readable label for the token(s).
+        #[arg(long)]
+        label: Option<String>,
+        /// Alert when the midpoint crosses above this price.
+        #[arg(long)]
+        above: Option<Decimal>,
+        /// Alert when the midpoint crosses below this price.
+        #[arg(long)]
+        below: Option<Decimal>,
+        /// Alert when ask - bid reaches this width (price units, e.g. 0.05).
+        #[arg(long)]
+        max_spread: Option<Decimal>,
+        /// Alert when per-poll traded volume exceeds this multiple of its average.
+        #[arg(long)]
+        volume_spike: Option<f64>,
+    },
+    /// Remove tokens, or every token of a market.
+    Remove {
+        token_ids: Vec<String>,
+        #[arg(long)]
+        market: Option<String>,
+    },
+    /// List watched tokens.
+    List,
+    /// Stream quotes for the watchlist and fire alerts until Ctrl-C.
+    Run {
+        /// Only watch tokens of this market.
+        #[arg(long)]
+        market: Option<String>,
+        /// Seconds between Gamma volume polls (volume-spike alerts).
+        #[arg(long, default_value = "60")]
+        volume_poll_secs: u64,
+        /// Minimum seconds between repeated alerts of the same kind per token.
+        #[arg(long, default_value = "300")]
+        cooldown_secs: i64,
+        /// Also push alerts through AlertManager (Feishu / Discord) channels.
+        #[arg(long)]
+        notify: bool,
+        #[arg(long, default_value = DEFAULT_WS_URL)]
+        ws_url: String,
+    },
+}
+
+/// Alert thresholds for a watched token
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+#[serde(default)]
+pub struct WatchAlerts {
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub price_above: Option<Decimal>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub price_below: Option<Decimal>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub max_spread: Option<Decimal>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub volume_spike_multiplier: Option<f64>,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct WatchEntry {
+    pub token_id: String,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub market: Option<String>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub label: Option<String>,
+    #[serde(default)]
+    pub alerts: WatchAlerts,
+    pub added_at: DateTime<Utc>,
+}
+
+impl WatchEntry {
+    fn display_name(&self) -> String {
+        match (&self.label, &self.market) {
+            (Some(label), _) => label.clone(),
+            (None, Some(market)) => format!("{}:{}", market, short_token(&self.token_id)),
+            (None, None) => short_token(&self.token_id),
+        }
+    }
+}
+
+/// Watchlist stored in `~/.config/polymarket/watchlist.json`.
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+pub struct Watchlist {
+    #[serde(default)]
+    pub entries: Vec<WatchEntry>,
+}
+
+impl Watchlist {
+    pub fn path() -> Result<PathBuf> {
+        Ok(super::config_file::PmConfig::config_dir()?.join("watchlist.json"))
+    }
+
+    pub fn load() -> Result<Self> {
+        let path = Self::path()?;
+        if !path.exists() {
+            return Ok(Self::default());
+        }
+        let contents = std::fs::read_to_string(&path)
+            .with_context(|| format!("failed to read {}", path.display()))?;
+        serde_json::from_str(&contents)
+            .with_context(|| format!("failed to parse {}", path.display()))
+    }
+
+    pub fn save(&self) -> Result<()> {
+        let dir = super::config_file::PmConfig::config_dir()?;
+        std::fs::create_dir_all(&dir)
+            .with_context(|| format!("failed to create {}", dir.display()))?;
+        let path = Self::path()?;
+        std::fs::write(&path, serde_json::to_string_pretty(self)?)
+            .with_context(|| format!("failed to write {}", path.display()))
+    }
+
+    /// Insert or update an entry; only provided thresholds overwrite existing ones.
+    pub fn upsert(&mut self, entry: WatchEntry) {
+        match self
+            .entries
+            .iter_mut()
+            .find(|e| e.token_id == entry.token_id)
+        {
+            Some(existing) => {
+                existing.market = entry.market.or(existing.market.take());
+                existing.label = entry.label.or(existing.label.take());
+                let alerts = &mut existing.alerts;
+                alerts.price_above = entry.alerts.price_above.or(alerts.price_above);
+                alerts.price_below = entry.alerts.price_below.or(alerts.price_below);
+                alerts.max_spread = entry.alerts.max_spread.or(alerts.max_spread);
+                alerts.volume_spike_multiplier = entry
+                    .alerts
+                    .volume_spike_multiplier
+                    .or(alerts.volume_spike_multiplier);
+            }
+            None => self.entries.push(entry),
+        }
+    }
+
+    /// Remove by token id or market label. Returns the number removed.
+    pub fn remove(&mut self, token_ids: &[String], market: Option<&str>) -> usize {
+        let before = self.entries.len();
+        self.entries.retain(|e| {
+            !token_ids.contains(&e.token_id)
+                && !market.is_some_and(|m| e.market.as_deref() == Some(m))
+        });
+        before - self.entries.len()
+    }
+}
+
+#[derive(Debug, Serialize, Tabled)]
+pub struct WatchRow {
+    pub token_id: String,
+    pub market: String,
+    pub label: String,
+    pub above: String,
+    pub below: String,
+    pub max_spread: String,
+    pub volume_spike: String,
+}
+
+fn opt_str<T: ToString>(value: Option<T>) -> String {
+    value.map(|v| v.to_string()).unwrap_or_default()
+}
+
+fn short_token(token_id: &str) -> String {
+    if token_id.len() <= 12 {
+        token_id.to_string()
+    } else {
+        format!("{}…{}", &token_id[..6], &token_id[token_id.len() - 4..])
+    }
+}
+
+/// Kind of watchlist alert
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum WatchAlertKind {
+    CrossAbove,
+    CrossBelow,
+    SpreadBlowout,
+    VolumeSpike,
+}
+
+impl WatchAlertKind {
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            WatchAlertKind::CrossAbove => "price crossed above",
+            WatchAlertKind::CrossBelow => "price crossed below",
+            WatchAlertKind::SpreadBlowout => "spread blowout",
+            WatchAlertKind::VolumeSpike => "volume spike",
+        }
+    }
+}
+
+/// Edge-triggered alert state for one token
+#[derive(Debug, Default)]
+pub struct WatchState {
+    last_mid: Option<Decimal>,
+    spread_wide: bool,
+    last_volume: Option<f64>,
+    /// EMA of per-poll volume deltas
+    avg_volume_delta: Option<f64>,
+    volume_samples: u32,
+    last_fired: HashMap<WatchAlertKind, DateTime<Utc>>,
+}
+
+const VOLUME_MIN_SAMPLES: u32 = 3;
+const VOLUME_EMA_ALPHA: f64 = 0.2;
+
+impl WatchState {
+    /// Evaluate a quote; returns the alerts that fired.
+    pub fn on_quote(
+        &mut self,
+        alerts: &WatchAlerts,
+        bid: Option<Decimal>,
+        ask: Option<Decimal>,
+    ) -> Vec<(WatchAlertKind, String)> {
+        let mut fired = Vec::new();
+        let mid = match (bid, ask) {
+            (Some(b), Some(a)) => Some((a + b) / Decimal::TWO),
+            (Some(p), None) | (None, Some(p)) => Some(p),
+            (None, None) => None,
+        };
+
+        if let (Some(mid), Some(prev)) = (mid, self.last_mid) {
+            if let Some(level) = alerts.price_above {
+                if prev < level && mid >= level {
+                    fired.push((
+                        WatchAlertKind::CrossAbove,
+                        format!("mid {} >= {} (was {})", mid, level, prev),
+                    ));
+                }
+            }
+            if let Some(level) = alerts.price_below {
+                if prev > level && mid <= level {
+                    fired.push((
+                        WatchAlertKind::CrossBelow,
+                        format!("mid {} <= {} (was {})", mid, level, prev),
+                    ));
+                }
+            }
+        }
+        if mid.is_some() {
+            self.last_mid = mid;
+        }
+
+        if let (Some(limit), Some(bid), Some(ask)) = (alerts.max_spread, bid, ask) {
+            let spread = ask - bid;
+            let wide = spread >= limit;
+            if wide && !self.spread_wide {
+                fired.push((
+                    WatchAlertKind::SpreadBlowout,
+                    format!("spread {} >= {} (bid {} / ask {})", spread, limit, bid, ask),
+                ));
+            }
+            self.spread_wide = wide;
+        }
+        fired
+    }
+
+    /// Evaluate a cumulative volume reading from a periodic poll.
+    pub fn on_volume(
+        &mut self,
+        alerts: &WatchAlerts,
+        volume: f64,
+    ) -> Option<(WatchAlertKind, String)> {
+        let prev = self.last_volume.replace(volume)?;
+        let delta = (volume - prev).max(0.0);
+        let avg = self.avg_volume_delta;
+        self.avg_volume_delta = Some(match avg {
+            Some(avg) => avg + VOLUME_EMA_ALPHA * (delta - avg),
+            None => delta,
+        });
+        self.volume_samples += 1;
+
+        let multiplier = alerts.volume_spike_multiplier?;
+        let avg = avg?;
+        if self.volume_samples <= VOLUME_MIN_SAMPLES || avg <= 0.0 {
+            return None;
+        }
+        (delta >= avg * multiplier).then(|| {
+            (
+                WatchAlertKind::VolumeSpike,
+                format!(
+                    "traded ${:.0} since last poll vs ${:.0} average ({:.1}x)",
+                    delta,
+                    avg,
+                    delta / avg
+                ),
+            )
+        })
+    }
+
+    /// Apply the per-kind cooldown; returns true when the alert may be emitted.
+    pub fn allow(&mut self, kind: WatchAlertKind, now: DateTime<Utc>, cooldown_secs: i64) -> bool {
+        if let Some(last) = self.last_fired.get(&kind) {
+            if (now - *last).num_seconds() < cooldown_secs {
+                return false;
+            }
+        }
+        self.last_fired.insert(kind, now);
+        true
+    }
+}
+
+pub async fn run(cmd: WatchlistCommands, _auth: &PmAuth, mode: OutputMode) -> anyhow::Result<()> {
+    match cmd {
+        WatchlistCommands::Add {
+            token_ids,
+            market,
+            label,
+            above,
+            below,
+            max_spread,
+            volume_spike,
+        } => {
+            let mut watchlist = Watchlist::load()?;
+            for token_id in &token_ids {
+                watchlist.upsert(WatchEntry {
+                    token_id: token_id.clone(),
+                    market: market.clone(),
+                    label: label.clone(),
+                    alerts: WatchAlerts {
+                        price_above: above,
+                        price_below: below,
+                        max_spread,
+                        volume_spike_multiplier: volume_spike,
+                    },
+                    added_at: Utc::now(),
+                });
+            }
+            watchlist.save()?;
+            output::print_success(&format!(
+                "watching {} token(s) ({} total)",
+                token_ids.len(),
+                watchlist.entries.len()
+            ));
+        }
+        WatchlistCommands::Remove { token_ids, market } => {
+            if token_ids.is_empty() && market.is_none() {
+                anyhow::bail!("specify token ids or --market");
+            }
+            let mut watchlist = Watchlist::load()?;
+            let removed = watchlist.remove(&token_ids, market.as_deref());
+            watchlist.save()?;
+            output::print_success(&format!("removed {removed} token(s)"));
+        }
+        WatchlistCommands::List => {
+            let watchlist = Watchlist::load()?;
+            let rows: Vec<WatchRow> = watchlist
+                .entries
+                .iter()
+                .map(|e| WatchRow {
+                    token_id: e.token_id.clone(),
+                    market: e.market.clone().unwrap_or_default(),
+                    label: e.label.clone().unwrap_or_default(),
+                    above: opt_str(e.alerts.price_above),
+                    below: opt_str(e.alerts.price_below),
+                    max_spread: opt_str(e.alerts.max_spread),
+                    volume_spike: opt_str(e.alerts.volume_spike_multiplier),
+                })
+                .collect();
+            output::print_items(&rows, mode)?;
+        }
+        WatchlistCommands::Run {
+            market,
+            volume_poll_secs,
+            cooldown_secs,
+            notify,
+            ws_url,
+        } => {
+            let mut watchlist = Watchlist::load()?;
+            if let Some(market) = market.as_deref() {
+                watchlist
+                    .entries
+                    .retain(|e| e.market.as_deref() == Some(market));
+            }
+            if watchlist.entries.is_empty() {
+                anyhow::bail!("watchlist is empty; add tokens with `ploy pm watchlist add`");
+            }
+            let alert_manager = notify.then(|| {
+                let mut manager = AlertManager::with_defaults();
+                if let Some(feishu) = FeishuNotifier::from_env() {
+                    manager = manager.with_feishu(feishu);
+                }
+                if let Some(discord) = DiscordNotifier::from_env() {
+                    manager = manager.with_channel(discord);
+                }
+                if !manager.has_channels() {
+                    output::print_warn(
+                        "FEISHU_WEBHOOK_URL / DISCORD_WEBHOOK_URL not set; alerts stay local",
+                    );
+                }
+                manager
+            });
+            run_watch(
+                watchlist,
+                &ws_url,
+                volume_poll_secs.max(5),
+                cooldown_secs.max(0),
+                alert_manager.map(Arc::new),
+            )
+            .await?;
+        }
+    }
+    Ok(())
+}
+
+async fn emit(
+    entry: &WatchEntry,
+    kind: WatchAlertKind,
+    detail: &str,
+    alert_manager: Option<&Arc<AlertManager>>,
+) {
+    let title = format!("{} {}", entry.display_name(), kind.as_str());
+    output::print_warn(&format!(
+        "[{}] {}: {}",
+        Utc::now().format("%H:%M:%S"),
+        title,
+        detail
+    ));
+    if let Some(manager) = alert_manager {
+        let message = format!("{}\nToken: {}", detail, entry.token_id);
+        manager.warning(ALERT_COMPONENT, &title, &message).await;
+    }
+}
+
+async fn poll_volumes(
+    gamma: &polymarket_client_sdk::gamma::Client,
+    token_ids: &[String],
+) -> HashMap<String, f64> {
+    use alloy::primitives::U256;
+    use polymarket_client_sdk::gamma::types::request::MarketsRequest;
+    use std::str::FromStr;
+
+    let mut out = HashMap::new();
+    for token_id in token_ids {
+        let Ok(tid) = U256::from_str(token_id) else {
+            continue;
+        };
+        let req = MarketsRequest::builder()
+            .clob_token_ids(vec![tid])
+            .limit(1)
+            .build();
+        let volume = match gamma.markets(&req).await {
+            Ok(markets) => markets
+                .into_iter()
+                .next()
+                .and_then(|m| m.volume.or(m.volume_num))
+                .and_then(|v| v.to_string().parse::<f64>().ok()),
+            Err(e) => {
+                tracing::debug!(token_id, error = %e, "watchlist volume poll failed");
+                None
+            }
+        };
+        if let Some(volume) = volume {
+            out.insert(token_id.clone(), volume);
+        }
+    }
+    out
+}
+
+async fn run_watch(
+    watchlist: Watchlist,
+    ws_url: &str,
+    volume_poll_secs: u64,
+    cooldown_secs: i64,
+    alert_manager: Option<Arc<AlertManager>>,
+) -> anyhow::Result<()> {
+    let entries: HashMap<String, WatchEntry> = watchlist
+        .entries
+        .into_iter()
+        .map(|e| (e.token_id.clone(), e))
+        .collect();
+    let token_ids: Vec<String> = entries.keys().cloned().collect();
+    let volume_tokens: Vec<String> = entries
+        .values()
+        .filter(|e| e.alerts.volume_spike_multiplier.is_some())
+        .map(|e| e.token_id.clone())
+        .collect();
+    let mut states: HashMap<String, WatchState> = HashMap::new();
+
+    let ws = Arc::new(PolymarketWebSocket::new(ws_url));
+    // The WS only broadcasts quotes for registered tokens; the outcome side is
+    // irrelevant for watchlist alerts.
+    for token_id in &token_ids {
+        ws.register_token(token_id, Side::Up).await;
+    }
+    let mut updates = ws.subscribe_updates();
+    let ws_task = {
+        let ws = ws.clone();
+        let token_ids = token_ids.clone();
+        tokio::spawn(async move {
+            if let Err(e) = ws.run(token_ids).await {
+                output::print_error(&format!("websocket stopped: {e}"));
+            }
+        })
+    };
+
+    let config = super::config_file::PmConfig::load().unwrap_or_default();
+    let gamma = polymarket_client_sdk::gamma::Client::new(config.gamma_base_url())?;
+    let mut volume_tick = tokio::time::interval(std::time::Duration::from_secs(volume_poll_secs));
+    volume_tick.set_missed_tick_behavior(tokio::time::MissedTickBehavior::Delay);
+
+    output::print_success(&format!(
+        "watching {} token(s); Ctrl-C to stop",
+        token_ids.len()
+    ));
+
+    loop {
+        tokio::select! {
+            update = updates.recv() => {
+                let update = match update {
+                    Ok(update) => update,
+                    Err(tokio::sync::broadcast::error::RecvError::Lagged(_)) => continue,
+                    Err(tokio::sync::broadcast::error::RecvError::Closed) => break,
+                };
+                let Some(entry) = entries.get(&update.token_id) else {
+                    continue;
+                };
+                let state = states.entry(update.token_id.clone()).or_default();
+                let fired = state.on_quote(&entry.alerts, update.quote.best_bid, update.quote.best_ask);
+                for (kind, detail) in fired {
+                    if state.allow(kind, Utc::now(), cooldown_secs) {
+                        emit(entry, kind, &detail, alert_manager.as_ref()).await;
+                    }
+                }
+            }
+            _ = volume_tick.tick(), if !volume_tokens.is_empty() => {
+                for (token_id, volume) in poll_volumes(&gamma, &volume_tokens).await {
+                    let Some(entry) = entries.get(&token_id) else {
+                        continue;
+                    };
+                    let state = states.entry(token_id).or_default();
+                    if let Some((kind, detail)) = state.on_volume(&entry.alerts, volume) {
+                        if state.allow(kind, Utc::now(), cooldown_secs) {
+                            emit(entry, kind, &detail, alert_manager.as_ref()).await;
+                        }
+                    }
+                }
+            }
+            _ = tokio::signal::ctrl_c() => break,
+        }
+    }
+
+    ws_task.abort();
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use rust_decimal_macros::dec;
+
+    #[test]
+    fn test_price_cross_and_spread_are_edge_triggered() {
+        let alerts = WatchAlerts {
+            price_above: Some(dec!(0.60)),
+            max_spread: Some(dec!(0.05)),
+            ..Default::default()
+        };
+        let mut state = WatchState::default();
+        assert!(state
+            .on_quote(&alerts, Some(dec!(0.55)), Some(dec!(0.57)))
+            .is_empty());
+        let fired = state.on_quote(&alerts, Some(dec!(0.60)), Some(dec!(0.62)));
+        assert_eq!(fired.len(), 1);
+        assert_eq!(fired[0].0, WatchAlertKind::CrossAbove);
+        assert!(state
+            .on_quote(&alerts, Some(dec!(0.61)), Some(dec!(0.63)))
+            .is_empty());
+
+        let fired = state.on_quote(&alerts, Some(dec!(0.55)), Some(dec!(0.70)));
+        assert_eq!(fired[0].0, WatchAlertKind::SpreadBlowout);
+        assert!(state
+            .on_quote(&alerts, Some(dec!(0.55)), Some(dec!(0.70)))
+            .is_empty());
+    }
+
+    #[test]
+    fn test_volume_spike_after_warmup_and_cooldown() {
+        let alerts = WatchAlerts {
+            volume_spike_multiplier: Some(3.0),
+            ..Default::default()
+        };
+        let mut state = WatchState::default();
+        let mut volume = 1_000.0;
+        assert!(state.on_volume(&alerts, volume).is_none());
+        for _ in 0..5 {
+            volume += 100.0;
+            assert!(state.on_volume(&alerts, volume).is_none());
+        }
+        let fired = state.on_volume(&alerts, volume + 1_000.0);
+        assert_eq!(fired.map(|f| f.0), Some(WatchAlertKind::VolumeSpike));
+
+        let now = Utc::now();
+        assert!(state.allow(WatchAlertKind::VolumeSpike, now, 300));
+        assert!(!state.allow(WatchAlertKind::VolumeSpike, now, 300));
+    }
+
+    #[test]
+    fn test_watchlist_upsert_and_remove_by_market() {
+        let mut watchlist = Watchlist::default();
+        let entry = |token: &str, above: Option<Decimal>| WatchEntry {
+            token_id: token.to_string(),
+            market: Some("btc-above-100k".to_string()),
+            label: None,
+            alerts: WatchAlerts {
+                price_above: above,
+                ..Default::default()
+            },
+            added_at: Utc::now(),
+        };
+        watchlist.upsert(entry("1", Some(dec!(0.7))));
+        watchlist.upsert(entry("2", None));
+        watchlist.upsert(entry("1", None));
+        assert_eq!(watchlist.entries.len(), 2);
+        assert_eq!(watchlist.entries[0].alerts.price_above, Some(dec!(0.7)));
+        assert_eq!(watchlist.remove(&[], Some("btc-above-100k")), 2);
+    }
+}
@@ -8,7 +8,7 @@ use crate::platform::{Domain, TradeIntent};
 use crate::signing::Wallet;
 use crate::strategy::event_edge::{discover_best_event_id_by_title, scan_event_edge_once};
 use crate::strategy::event_models::arena_text::fetch_arena_text_snapshot;
-use crate::strategy::multi_outcome::fetch_multi_outcome_event;
+use crate::strategy::multi_outcome::{fetch_multi_outcome_event, fetch_outcome_books};
 use chrono::Utc;
 use rust_decimal::prelude::ToPrimitive;
 use rust_decimal::Decimal;
@@ -1488,10 +1488,28 @@ pub async fn run_rpc(config_path: &str) -> Result<()> {
                 }
             };
 
+            let target_shares = match parse_optional_decimal(&params, "target_shares") {
+                Ok(v) => v.unwrap_or(Decimal::ONE_HUNDRED),
+                Err(e) => {
+                    println!(
+                        "{}",
+                        jsonrpc_err(
+                            req.id,
+                            -32602,
+                            "invalid params",
+                            Some(json!({"detail": e.to_string()}))
+                        )
+                    );
+                    return Ok(());
+                }
+            };
+
             match build_pm_client(&rest_url, true).await {
                 Ok(c) => match fetch_multi_outcome_event(&c, &event_id).await {
-                    Ok(monitor) => {
-                        let arbs = monitor.find_all_arbitrage();
+                    Ok(mut monitor) => {
+                        fetch_outcome_books(&c, &mut monitor).await;
+                        let bands = monitor.fair_value_bands(target_shares);
+                        let arbs = monitor.find_all_arbitrage(&bands);
                         let summary = monitor.summary();
                         jsonrpc_ok(
                             req.id,
@@ -1499,6 +1517,8 @@ pub async fn run_rpc(config_path: &str) -> Result<()> {
                                 "event_id": monitor.event_id,
                                 "event_title": monitor.event_title,
                                 "outcomes": summary,
+                                "target_shares": target_shares,
+                                "fair_value_bands": bands,
                                 "arbs": arbs
                             }),
                         )
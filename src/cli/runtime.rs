@@ -24,6 +24,11 @@ pub struct Cli {
     /// Config file path
     #[arg(short, long, default_value = "config/default.toml")]
     pub config: String,
+
+    /// Config profile overlaid on the base config (`profiles/<name>.toml`
+    /// next to it), e.g. dev, staging, prod
+    #[arg(long, global = true, env = "PLOY_PROFILE")]
+    pub profile: Option<String>,
 }
 
 #[derive(Subcommand, Debug)]
@@ -137,6 +142,63 @@ pub enum Commands {
         /// Resume from DB high-water mark per asset (ignores start_ms when set).
         #[arg(long)]
         resume_from_db: bool,
+
+        /// Also store compressed depth snapshots (clob_orderbook_depth_snapshots).
+        #[arg(long)]
+        compressed_depth: bool,
+    },
+
+    /// Capture compressed N-level Polymarket depth snapshots via `GET /book`
+    OrderbookDepth {
+        /// Asset IDs (token IDs) to capture (comma-separated)
+        #[arg(long)]
+        asset_ids: String,
+
+        /// Max depth levels to persist per side (bids/asks).
+        #[arg(long, default_value = "50")]
+        levels: usize,
+
+        /// Capture cadence per asset (milliseconds).
+        #[arg(long, default_value = "1000")]
+        interval_ms: u64,
+
+        /// Capture duration in seconds (0 = until Ctrl+C).
+        #[arg(long, default_value = "0")]
+        duration_secs: u64,
+
+        /// Persist every snapshot even when the book hash is unchanged.
+        #[arg(long)]
+        keep_unchanged: bool,
+
+        /// Override API base URL (default: https://clob.polymarket.com).
+        #[arg(long, default_value = "https://clob.polymarket.com")]
+        base_url: String,
+    },
+
+    /// Check Binance LOB feature parity between the collector and the live path
+    ///
+    /// Replays recorded `binance_lob_ticks` through both implementations and
+    /// exits non-zero when any feature diverges above the tolerance.
+    LobParity {
+        /// Restrict to one symbol (e.g. BTCUSDT)
+        #[arg(long)]
+        symbol: Option<String>,
+
+        /// Replay ticks from the last N hours
+        #[arg(long, default_value = "24")]
+        hours: i64,
+
+        /// Max ticks to replay
+        #[arg(long, default_value = "100000")]
+        limit: i64,
+
+        /// Max allowed divergence (absolute for OBI/spread, relative for prices/volumes)
+        #[arg(long, default_value = "0.000001")]
+        tolerance: f64,
+
+        /// Print the report as JSON
+        #[arg(long)]
+        json: bool,
     },
 
     /// Crypto market strategies (BTC, ETH, SOL UP/DOWN)
@@ -151,6 +213,18 @@ pub enum Commands {
     #[command(subcommand)]
     Strategy(super::strategy::StrategyCommands),
 
+    /// Configuration management (show, validate, risk-check)
+    #[command(subcommand)]
+    Config(super::config::ConfigCommands),
+
+    /// Compare stored backtest reports
+    #[command(subcommand)]
+    Backtest(BacktestCommands),
+
+    /// Browse versioned research report artifacts
+    #[command(subcommand)]
+    Research(ResearchCommands),
+
     /// Claim/redeem winning positions from resolved markets
     Claim {
         /// Check only (don't actually claim)
@@ -178,6 +252,60 @@ pub enum Commands {
         /// Show open trades only
         #[arg(long)]
         open_only: bool,
+        /// Fit the fill/slippage model from logged fills and save it
+        #[arg(long)]
+        fit_fill_model: bool,
+    },
+
+    /// Annotate and query logged trades (notes, tags, post-mortems)
+    #[command(subcommand)]
+    Journal(JournalCommands),
+
+    /// Intraday loss limits, sandbox budgets and operator decisions
+    #[command(subcommand)]
+    Risk(RiskCommands),
+
+    /// Export trades, positions or daily PnL as CSV / XLSX for accounting
+    #[command(subcommand)]
+    Export(ExportCommands),
+
+    /// Post-deploy live checks
+    #[command(subcommand)]
+    Verify(VerifyCommands),
+
+    /// Split/merge arbitrage on one binary market: split USDC and sell both
+    /// legs when the bids sum above $1, or buy both and merge when the asks
+    /// sum below $1
+    SplitMerge {
+        /// Condition ID (bytes32 hex)
+        #[arg(long)]
+        condition_id: String,
+        /// YES token ID
+        #[arg(long)]
+        yes_token: String,
+        /// NO token ID
+        #[arg(long)]
+        no_token: String,
+        /// Outcome sets to trade (capped by the executor's max_sets)
+        #[arg(long, default_value = "100")]
+        sets: u64,
+        /// Negative-risk market (splits and merges through the NegRiskAdapter)
+        #[arg(long)]
+        neg_risk: bool,
+        /// Send real orders and on-chain transactions (default: dry run)
+        #[arg(long)]
+        live: bool,
+    },
+
+    /// Interactive what-if REPL: evaluate a strategy on live state without placing orders
+    Simulate {
+        /// Strategy to start with (switch with `use` inside the REPL)
+        #[arg(long, value_enum, default_value_t = crate::strategy::simulate::SimStrategy::Crypto)]
+        strategy: crate::strategy::simulate::SimStrategy,
+
+        /// Count dry-run fills as open positions
+        #[arg(long)]
+        include_dry_run: bool,
     },
 
     /// Reinforcement learning strategies (requires 'rl' feature)
@@ -206,6 +334,14 @@ pub enum Commands {
         /// Stats print interval (seconds)
         #[arg(long, default_value = "300")]
         stats_interval: u64,
+
+        /// Simulated quote latency: fixed:MS | normal:MEAN,STD | pareto:SCALE,SHAPE
+        #[arg(long)]
+        quote_latency: Option<String>,
+
+        /// RNG seed for reproducible latency samples
+        #[arg(long)]
+        latency_seed: Option<u64>,
     },
 
     /// Multi-agent platform (Coordinator + Agents)
@@ -302,6 +438,260 @@ pub enum CryptoCommands {
     },
 }
 
+/// Backtest report subcommands
+#[derive(Subcommand, Debug)]
+pub enum BacktestCommands {
+    /// Diff two stored backtest reports (run B against baseline run A)
+    Diff {
+        /// Baseline report (JSON written by `ploy strategy backtest --output`)
+        run_a: String,
+        /// Candidate report to compare against the baseline
+        run_b: String,
+        /// Relative worsening tolerated before a metric is flagged (0.05 = 5%)
+        #[arg(long, default_value = "0.05")]
+        tolerance: f64,
+        /// Output JSON
+        #[arg(long)]
+        json: bool,
+        /// Exit with an error when any regression is flagged
+        #[arg(long)]
+        fail_on_regression: bool,
+    },
+}
+
+/// Research report subcommands
+#[derive(Subcommand, Debug)]
+pub enum ResearchCommands {
+    /// List stored research reports, newest first
+    List {
+        /// Only reports of this kind (e.g. backtest.momentum)
+        #[arg(long)]
+        kind: Option<String>,
+        /// Maximum number of reports to show
+        #[arg(long, default_value = "20")]
+        limit: usize,
+        /// Output JSON
+        #[arg(long)]
+        json: bool,
+    },
+    /// Show one research report
+    Show {
+        /// Report id (from `ploy research list`)
+        id: String,
+        /// Output the full report as JSON
+        #[arg(long)]
+        json: bool,
+    },
+    /// Estimate deployable capital by replaying recorded signals against recorded depth
+    Capacity {
+        /// Strategy id (or agent id) as recorded in signal_history
+        strategy: String,
+        /// Lookback window in days
+        #[arg(long, default_value = "7")]
+        days: i64,
+        /// Order notionals in USD to evaluate (comma-separated)
+        #[arg(long)]
+        sizes: Option<String>,
+        /// EV per filled dollar a size must keep to count as deployable
+        #[arg(long, default_value = "0.01")]
+        min_ev_per_dollar: f64,
+        /// Output JSON
+        #[arg(long)]
+        json: bool,
+    },
+    /// Copy quote / depth history from Postgres into the compressed tick archive
+    Archive {
+        /// quotes | depth | all
+        #[arg(long, default_value = "all")]
+        kind: String,
+        /// Lookback window in days (ending now)
+        #[arg(long, default_value = "7")]
+        days: i64,
+        /// Archive directory (default: $PLOY_ARCHIVE_DIR or data/archive)
+        #[arg(long)]
+        dir: Option<std::path::PathBuf>,
+        /// Output JSON
+        #[arg(long)]
+        json: bool,
+    },
+}
+
+/// Trade journal subcommands
+#[derive(Subcommand, Debug)]
+pub enum JournalCommands {
+    /// Attach a note, tags and/or a classification to a trade
+    Note {
+        /// Trade id (from `ploy journal list` or data/trades.json)
+        trade_id: String,
+        /// Note text
+        #[arg(short, long)]
+        text: Option<String>,
+        /// Tag to add (repeatable), e.g. stale-quote
+        #[arg(long = "tag")]
+        tags: Vec<String>,
+        /// Tag to remove (repeatable)
+        #[arg(long = "untag")]
+        untags: Vec<String>,
+        /// Post-mortem classification, e.g. execution-error
+        #[arg(long)]
+        classify: Option<String>,
+        /// Clear the post-mortem classification
+        #[arg(long, conflicts_with = "classify")]
+        clear_classification: bool,
+        /// Note author
+        #[arg(long)]
+        author: Option<String>,
+    },
+    /// Show a trade with its journal entry
+    Show {
+        trade_id: String,
+        /// Output JSON
+        #[arg(long)]
+        json: bool,
+    },
+    /// List trades filtered by journal tags / classification / outcome
+    List {
+        #[arg(long)]
+        tag: Option<String>,
+        #[arg(long)]
+        classification: Option<String>,
+        /// open | won | lost | exited | cancelled
+        #[arg(long)]
+        outcome: Option<String>,
+        #[arg(short, long)]
+        symbol: Option<String>,
+        /// Only trades that have journal entries
+        #[arg(long)]
+        annotated: bool,
+        #[arg(short, long, default_value = "50")]
+        limit: usize,
+        /// Output JSON
+        #[arg(long)]
+        json: bool,
+    },
+}
+
+/// Risk control subcommands
+#[derive(Subcommand, Debug)]
+pub enum RiskCommands {
+    /// Show today's intraday loss limit halts
+    Status {
+        /// Output JSON
+        #[arg(long)]
+        json: bool,
+    },
+    /// Re-enable trading halted by the intraday loss limit
+    Reenable {
+        /// Agent id to re-enable; omit for the account-wide halt
+        #[arg(long)]
+        strategy: Option<String>,
+        /// Operator recorded in daily_metrics
+        #[arg(long)]
+        operator: Option<String>,
+    },
+    /// Show per-agent sandbox budgets and open top-up requests
+    Budgets {
+        /// Output JSON
+        #[arg(long)]
+        json: bool,
+    },
+    /// Request a sandbox budget top-up for an agent
+    TopUp {
+        /// Agent id
+        #[arg(long)]
+        agent: String,
+        /// Amount to add (USD)
+        #[arg(long)]
+        amount: rust_decimal::Decimal,
+        /// Requesting operator
+        #[arg(long)]
+        operator: Option<String>,
+    },
+    /// Approve (or deny) a pending budget top-up
+    DecideTopUp {
+        /// Top-up id
+        id: uuid::Uuid,
+        /// Deny instead of approve
+        #[arg(long)]
+        deny: bool,
+        /// Deciding operator
+        #[arg(long)]
+        operator: Option<String>,
+    },
+}
+
+/// Accounting export subcommands
+#[derive(Subcommand, Debug)]
+pub enum ExportCommands {
+    /// Fills with fees and gas, plus settlement payouts
+    Trades(ExportArgs),
+    /// Positions open at the end of the range
+    Positions(ExportArgs),
+    /// Daily per-strategy PnL
+    Pnl(ExportArgs),
+    /// Realized gains of one tax year from FIFO/LIFO tax lots
+    TaxLots {
+        /// Tax year (UTC calendar year)
+        #[arg(long)]
+        year: i32,
+        /// Lot matching method
+        #[arg(long, value_enum, default_value_t = crate::persistence::tax_lots::LotMethod::Fifo)]
+        method: crate::persistence::tax_lots::LotMethod,
+        /// One row per lot closure instead of the per-token summary
+        #[arg(long)]
+        detail: bool,
+        /// Output format
+        #[arg(long, value_enum, default_value_t = crate::services::export::ExportFormat::Csv)]
+        format: crate::services::export::ExportFormat,
+        /// Output file (default: data/exports/tax_lots_<year>_<method>.<ext>)
+        #[arg(short, long)]
+        output: Option<std::path::PathBuf>,
+        /// Restrict to one account id
+        #[arg(long)]
+        account: Option<String>,
+    },
+}
+
+/// Options shared by the export subcommands
+#[derive(clap::Args, Debug, Clone)]
+pub struct ExportArgs {
+    /// First day (UTC, YYYY-MM-DD)
+    #[arg(long)]
+    pub from: chrono::NaiveDate,
+    /// Last day, inclusive (UTC, YYYY-MM-DD)
+    #[arg(long)]
+    pub to: chrono::NaiveDate,
+    /// Output format
+    #[arg(long, value_enum, default_value_t = crate::services::export::ExportFormat::Csv)]
+    pub format: crate::services::export::ExportFormat,
+    /// Output file (default: data/exports/<kind>_<from>_<to>.<ext>)
+    #[arg(short, long)]
+    pub output: Option<std::path::PathBuf>,
+    /// Restrict to one account id
+    #[arg(long)]
+    pub account: Option<String>,
+    /// Include dry-run executions
+    #[arg(long)]
+    pub include_dry_run: bool,
+}
+
+#[derive(Subcommand, Debug, Clone)]
+pub enum VerifyCommands {
+    /// Place one minimal real round trip on a deep market and check the submit / fill /
+    /// cancel paths, auth and balance updates
+    Live {
+        /// Maximum notional of the BUY leg (USD)
+        #[arg(long, default_value = "5")]
+        budget: rust_decimal::Decimal,
+        /// Token to trade instead of the deepest active round
+        #[arg(long)]
+        token: Option<String>,
+        /// Skip the confirmation prompt
+        #[arg(long, short = 'y')]
+        yes: bool,
+    },
+}
+
 /// Sports market subcommands
 #[derive(Subcommand, Debug)]
 pub enum SportsCommands {
@@ -331,12 +721,50 @@ pub enum SportsCommands {
         /// Leagues to monitor (comma-separated: NBA,NFL)
         #[arg(long, default_value = "NBA")]
         leagues: String,
+        /// Market classes (comma-separated: moneyline,spread,total,prop)
+        #[arg(long, default_value = "moneyline")]
+        markets: String,
         /// Dry run mode
         #[arg(long)]
         dry_run: bool,
     },
 }
 
+/// Curriculum options for segment-based RL training
+#[cfg(feature = "rl")]
+#[derive(clap::Args, Debug, Clone)]
+pub struct CurriculumArgs {
+    /// Train on curriculum segments (calm -> volatile -> rotation boundaries)
+    #[arg(long)]
+    pub curriculum: bool,
+    /// Data points per curriculum segment (one episode)
+    #[arg(long, default_value = "600")]
+    pub curriculum_segment_len: usize,
+    /// Episodes per curriculum stage before the next is unlocked
+    #[arg(long, default_value = "200")]
+    pub curriculum_stage_episodes: usize,
+    /// Volatility quantile above which segments count as high-volatility
+    #[arg(long, default_value = "0.7")]
+    pub curriculum_vol_quantile: f64,
+    /// Unlock the next stage early once the recent mean reward reaches this
+    #[arg(long)]
+    pub curriculum_promote_reward: Option<f32>,
+}
+
+#[cfg(feature = "rl")]
+impl CurriculumArgs {
+    pub fn to_config(&self) -> crate::rl::CurriculumConfig {
+        crate::rl::CurriculumConfig {
+            enabled: self.curriculum,
+            segment_len: self.curriculum_segment_len,
+            high_vol_quantile: self.curriculum_vol_quantile,
+            episodes_per_stage: self.curriculum_stage_episodes,
+            promote_reward: self.curriculum_promote_reward,
+            ..Default::default()
+        }
+    }
+}
+
 /// Reinforcement Learning subcommands
 #[cfg(feature = "rl")]
 #[derive(Subcommand, Debug)]
@@ -367,6 +795,16 @@ pub enum RlCommands {
         /// Resume from checkpoint
         #[arg(long)]
         resume: Option<String>,
+        /// Parallel rollout workers (1 = single-threaded)
+        #[arg(long, default_value = "1")]
+        workers: usize,
+        /// Base seed for rollout workers (worker i uses seed + i)
+        #[arg(long)]
+        seed: Option<u64>,
+        /// Market regime script: preset (trend_crash_chop, jump_diffusion,
+        /// spread_blowout) or path to a scenario JSON file
+        #[arg(long)]
+        scenario: Option<String>,
         /// Verbose output
         #[arg(short, long)]
         verbose: bool,
@@ -446,6 +884,33 @@ pub enum RlCommands {
         #[arg(short, long)]
         verbose: bool,
     },
+    /// Backtest/train RL on recorded multi-day LOB + Polymarket snapshots (one episode per round)
+    SnapshotBacktest {
+        /// Number of episodes (rounds replayed, wrapping around the dataset)
+        #[arg(short, long, default_value = "500")]
+        episodes: usize,
+        /// Binance symbol
+        #[arg(short, long, default_value = "BTCUSDT")]
+        symbol: String,
+        /// Round horizon filter (5m, 15m, 1h, 1d)
+        #[arg(long)]
+        horizon: Option<String>,
+        /// Days of recorded data to load
+        #[arg(long, default_value = "7")]
+        days: i64,
+        /// Step cadence in milliseconds
+        #[arg(long, default_value = "1000")]
+        step_ms: i64,
+        /// Initial capital per episode
+        #[arg(long, default_value = "1000.0")]
+        capital: f64,
+        /// Maximum shares per position
+        #[arg(long, default_value = "100")]
+        max_position: u64,
+        /// Verbose output
+        #[arg(short, long)]
+        verbose: bool,
+    },
     /// Train lead-lag RL strategy using LOB data
     LeadLag {
         /// Number of training episodes
@@ -469,6 +934,8 @@ pub enum RlCommands {
         /// Verbose output
         #[arg(short, long)]
         verbose: bool,
+        #[command(flatten)]
+        curriculum: CurriculumArgs,
     },
     /// Run live trading with trained lead-lag model
     LeadLagLive {
@@ -539,4 +1006,49 @@ pub enum RlCommands {
         #[arg(long)]
         policy_version: Option<String>,
     },
+    /// Register a model artifact in the model registry as a candidate
+    Register {
+        /// Model name (e.g. "crypto-rl-policy")
+        #[arg(short, long)]
+        name: String,
+        /// Semantic version (major.minor.patch)
+        #[arg(short, long)]
+        version: String,
+        /// Artifact file to copy into the registry
+        #[arg(short, long)]
+        artifact: String,
+        /// Hash of the training dataset
+        #[arg(long)]
+        training_data_hash: Option<String>,
+        /// Eval metrics as key=value (repeatable)
+        #[arg(long = "metric")]
+        metrics: Vec<String>,
+        /// Registry directory (default: PLOY_MODEL_REGISTRY_DIR or ./models/registry)
+        #[arg(long)]
+        registry_dir: Option<String>,
+    },
+    /// Move a registered model version between stages
+    Promote {
+        /// Model name
+        #[arg(short, long)]
+        name: String,
+        /// Version to promote
+        #[arg(short, long)]
+        version: String,
+        /// Target stage (candidate, staging, production, archived)
+        #[arg(short, long, default_value = "production")]
+        stage: String,
+        /// Registry directory (default: PLOY_MODEL_REGISTRY_DIR or ./models/registry)
+        #[arg(long)]
+        registry_dir: Option<String>,
+    },
+    /// List registered model versions
+    Models {
+        /// Only show versions of this model
+        #[arg(short, long)]
+        name: Option<String>,
+        /// Registry directory (default: PLOY_MODEL_REGISTRY_DIR or ./models/registry)
+        #[arg(long)]
+        registry_dir: Option<String>,
+    },
 }
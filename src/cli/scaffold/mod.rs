@@ -0,0 +1,491 @@
+//! `ploy strategy scaffold` - generate a new strategy module
+//!
+//! Writes `src/strategy/<name>.rs` (config struct, validation chain,
+//! metrics and test skeletons around a `Strategy` impl) and
+//! `config/strategies/<name>_default.toml` from the templates next to this
+//! file, then registers the module in `src/strategy/mod.rs` and with
+//! `StrategyFactory`.
+
+use anyhow::{anyhow, bail, Context, Result};
+use clap::ValueEnum;
+use std::fs;
+use std::path::{Path, PathBuf};
+
+const MODULE_TEMPLATE: &str = include_str!("strategy.rs.tmpl");
+const CONFIG_TEMPLATE: &str = include_str!("config.toml.tmpl");
+
+/// Anchor in `StrategyFactory::from_toml_tagged` new arms go before
+const FACTORY_FALLBACK_ARM: &str =
+    "            other => Err(anyhow!(\"Unknown strategy type: {}\", other).into()),";
+/// End of the `available_strategies` list
+const AVAILABLE_LIST_END: &str =
+    "        ]\n    }\n}\n\n/// Information about an available strategy type";
+
+/// Rust keywords that cannot be module names
+const RESERVED: &[&str] = &[
+    "as", "async", "await", "box", "break", "const", "continue", "crate", "dyn", "else", "enum",
+    "extern", "false", "fn", "for", "if", "impl", "in", "let", "loop", "match", "mod", "move",
+    "mut", "pub", "ref", "return", "self", "static", "struct", "super", "trait", "true", "try",
+    "type", "unsafe", "use", "where", "while", "yield",
+];
+
+#[derive(ValueEnum, Debug, Clone, Copy, PartialEq, Eq)]
+pub enum ScaffoldKind {
+    /// Single-side entries from a directional signal
+    Signal,
+    /// Paired UP+DOWN entries below $1 after buffers
+    Arb,
+    /// Entries where a model probability beats the ask
+    Ml,
+}
+
+impl ScaffoldKind {
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            Self::Signal => "signal",
+            Self::Arb => "arb",
+            Self::Ml => "ml",
+        }
+    }
+
+    fn default_description(&self) -> &'static str {
+        match self {
+            Self::Signal => "Buys the side offered below a signal threshold",
+            Self::Arb => "Buys UP and DOWN together when the pair costs less than $1",
+            Self::Ml => "Buys the side whose model probability beats the ask",
+        }
+    }
+
+    fn validation_imports(&self) -> &'static str {
+        match self {
+            Self::Arb => {
+                "use crate::strategy::risk_mgmt::validation::{\n    \
+                 ExposureValidator, SpreadValidator, SumTargetValidator, ValidationChain, \
+                 ValidationContext,\n};"
+            }
+            Self::Signal | Self::Ml => {
+                "use crate::strategy::risk_mgmt::validation::{\n    \
+                 ExposureValidator, SpreadValidator, ValidationChain, ValidationContext,\n};"
+            }
+        }
+    }
+
+    fn trade_fields(&self) -> &'static str {
+        match self {
+            Self::Signal => {
+                "    /// Buy a side when its ask is at or below this price\n    \
+                 pub entry_below: Decimal,\n"
+            }
+            Self::Arb => {
+                "    /// Fee buffer below $1\n    pub fee_buffer: Decimal,\n    \
+                 /// Slippage buffer below $1\n    pub slippage_buffer: Decimal,\n    \
+                 /// Required edge below $1 after buffers\n    pub min_edge: Decimal,\n"
+            }
+            Self::Ml => {
+                "    /// Required model probability minus ask\n    pub min_edge: Decimal,\n    \
+                 /// Weight of the book imbalance in the placeholder model\n    \
+                 pub imbalance_weight: f64,\n    \
+                 /// Trained model to load (unused by the placeholder model)\n    \
+                 pub model_path: Option<String>,\n"
+            }
+        }
+    }
+
+    fn trade_defaults(&self) -> &'static str {
+        match self {
+            Self::Signal => "            entry_below: Decimal::new(30, 2),\n",
+            Self::Arb => {
+                "            fee_buffer: Decimal::new(1, 2),\n            \
+                 slippage_buffer: Decimal::new(1, 2),\n            \
+                 min_edge: Decimal::new(1, 2),\n"
+            }
+            Self::Ml => {
+                "            min_edge: Decimal::new(5, 2),\n            \
+                 imbalance_weight: 0.2,\n            model_path: None,\n"
+            }
+        }
+    }
+
+    fn toml_trade(&self) -> &'static str {
+        match self {
+            Self::Signal => "entry_below = 0.30\n",
+            Self::Arb => "fee_buffer = 0.01\nslippage_buffer = 0.01\nmin_edge = 0.01\n",
+            Self::Ml => {
+                "min_edge = 0.05\nimbalance_weight = 0.2\n# model_path = \"models/<name>.onnx\"\n"
+            }
+        }
+    }
+
+    fn chain_extra(&self) -> &'static str {
+        match self {
+            Self::Arb => {
+                "\n            .add(SumTargetValidator::new(Decimal::ONE).with_buffers(\n                \
+                 cfg.trade.fee_buffer,\n                cfg.trade.slippage_buffer,\n                \
+                 cfg.trade.min_edge,\n            ))"
+            }
+            Self::Signal | Self::Ml => "",
+        }
+    }
+
+    fn evaluate(&self) -> &'static str {
+        match self {
+            Self::Signal => SIGNAL_EVALUATE,
+            Self::Arb => ARB_EVALUATE,
+            Self::Ml => ML_EVALUATE,
+        }
+    }
+}
+
+const SIGNAL_EVALUATE: &str = r#"    /// Entry signal: buy whichever side is offered at or below `entry_below`.
+    /// Replace with the strategy's own signal.
+    fn evaluate(&self, market: &MarketConfig) -> Option<Entry> {
+        [
+            (&market.up_token, Side::Up),
+            (&market.down_token, Side::Down),
+        ]
+        .into_iter()
+        .find_map(|(token_id, side)| {
+            let quote = self.quotes.get(token_id)?;
+            let ask = quote.best_ask?;
+            (ask <= self.cfg.trade.entry_below).then(|| Entry {
+                legs: vec![(token_id.clone(), side, ask)],
+                context: self.context(ask, quote),
+                reason: format!("{} ask {} <= {}", side, ask, self.cfg.trade.entry_below),
+            })
+        })
+    }
+"#;
+
+const ARB_EVALUATE: &str = r#"    /// Buy both sides when the pair costs less than $1; the sum-target
+    /// validator enforces the buffers and the edge.
+    fn evaluate(&self, market: &MarketConfig) -> Option<Entry> {
+        let up = self.quotes.get(&market.up_token)?;
+        let down = self.quotes.get(&market.down_token)?;
+        let (up_ask, down_ask) = (up.best_ask?, down.best_ask?);
+        let cost = up_ask + down_ask;
+        if cost >= Decimal::ONE {
+            return None;
+        }
+        Some(Entry {
+            legs: vec![
+                (market.up_token.clone(), Side::Up, up_ask),
+                (market.down_token.clone(), Side::Down, down_ask),
+            ],
+            context: self
+                .context(cost, up)
+                .with_leg1(up_ask)
+                .with_opposite_ask(down_ask),
+            reason: format!("pair cost {} + {} = {}", up_ask, down_ask, cost),
+        })
+    }
+"#;
+
+const ML_EVALUATE: &str = r#"    /// Order-book features of one quote: mid, spread and size imbalance
+    fn features(quote: &Quote) -> Option<[f64; 3]> {
+        let bid = quote.best_bid?.to_f64()?;
+        let ask = quote.best_ask?.to_f64()?;
+        let bid_size = quote.bid_size.and_then(|s| s.to_f64()).unwrap_or(0.0);
+        let ask_size = quote.ask_size.and_then(|s| s.to_f64()).unwrap_or(0.0);
+        let depth = bid_size + ask_size;
+        let imbalance = if depth > 0.0 {
+            (bid_size - ask_size) / depth
+        } else {
+            0.0
+        };
+        Some([(bid + ask) / 2.0, ask - bid, imbalance])
+    }
+
+    /// P(UP) from the UP token's features. Placeholder: a coin flip tilted
+    /// by the book imbalance; replace with the model at `model_path`.
+    fn predict(&self, features: &[f64; 3]) -> f64 {
+        let [_mid, _spread, imbalance] = *features;
+        (0.5 + self.cfg.trade.imbalance_weight * imbalance).clamp(0.0, 1.0)
+    }
+
+    /// Buy the side whose predicted probability beats its ask by `min_edge`
+    fn evaluate(&self, market: &MarketConfig) -> Option<Entry> {
+        let up = self.quotes.get(&market.up_token)?;
+        let down = self.quotes.get(&market.down_token)?;
+        let p_up = Decimal::try_from(self.predict(&Self::features(up)?)).ok()?;
+        [
+            (&market.up_token, Side::Up, up, p_up),
+            (&market.down_token, Side::Down, down, Decimal::ONE - p_up),
+        ]
+        .into_iter()
+        .find_map(|(token_id, side, quote, p)| {
+            let ask = quote.best_ask?;
+            (p - ask >= self.cfg.trade.min_edge).then(|| Entry {
+                legs: vec![(token_id.clone(), side, ask)],
+                context: self.context(ask, quote),
+                reason: format!("{} p={} ask={}", side, p.round_dp(3), ask),
+            })
+        })
+    }
+"#;
+
+/// Files written and patched by a scaffold run
+#[derive(Debug)]
+pub struct ScaffoldReport {
+    pub type_name: String,
+    pub created: Vec<PathBuf>,
+    pub patched: Vec<PathBuf>,
+}
+
+/// Check that `name` is a usable snake_case module name
+pub fn validate_name(name: &str) -> Result<()> {
+    let valid = name.chars().next().is_some_and(|c| c.is_ascii_lowercase())
+        && name
+            .chars()
+            .all(|c| c.is_ascii_lowercase() || c.is_ascii_digit() || c == '_')
+        && !name.ends_with('_')
+        && !name.contains("__");
+    if !valid {
+        bail!(
+            "strategy name '{}' must be snake_case (a-z, 0-9, _), starting with a letter",
+            name
+        );
+    }
+    if RESERVED.contains(&name) {
+        bail!("strategy name '{}' is a Rust keyword", name);
+    }
+    Ok(())
+}
+
+/// `mean_revert` -> `MeanRevertStrategy`
+pub fn type_name(name: &str) -> String {
+    let mut out: String = name
+        .split('_')
+        .filter(|part| !part.is_empty())
+        .map(|part| {
+            let mut chars = part.chars();
+            match chars.next() {
+                Some(first) => first.to_ascii_uppercase().to_string() + chars.as_str(),
+                None => String::new(),
+            }
+        })
+        .collect();
+    if !out.ends_with("Strategy") {
+        out.push_str("Strategy");
+    }
+    out
+}
+
+fn escape(text: &str) -> String {
+    text.replace('\\', "\\\\").replace('"', "\\\"")
+}
+
+fn render(template: &str, name: &str, kind: ScaffoldKind, description: &str) -> String {
+    template
+        .replace("__VALIDATION_IMPORTS__", kind.validation_imports())
+        .replace("__TRADE_FIELDS__", kind.trade_fields())
+        .replace("__TRADE_DEFAULTS__", kind.trade_defaults())
+        .replace("__CHAIN_EXTRA__", kind.chain_extra())
+        .replace("__EVALUATE__", kind.evaluate())
+        .replace("__TOML_TRADE__", kind.toml_trade())
+        .replace("__TYPE__", &type_name(name))
+        .replace("__NAME__", name)
+        .replace("__KIND__", kind.as_str())
+        .replace("__DESCRIPTION__", description)
+}
+
+/// Source of `src/strategy/<name>.rs`
+pub fn render_module(name: &str, kind: ScaffoldKind, description: &str) -> String {
+    render(MODULE_TEMPLATE, name, kind, &escape(description))
+}
+
+/// Contents of `config/strategies/<name>_default.toml`
+pub fn render_config(name: &str, kind: ScaffoldKind, description: &str) -> String {
+    render(CONFIG_TEMPLATE, name, kind, description)
+}
+
+/// Add `pub mod <name>;` to `src/strategy/mod.rs`, before the first
+/// declaration that sorts after it (and its attributes).
+pub fn patch_strategy_mod(content: &str, name: &str) -> Result<String> {
+    let declaration = format!("pub mod {};", name);
+    let lines: Vec<&str> = content.lines().collect();
+    if lines.iter().any(|l| l.trim() == declaration) {
+        bail!("src/strategy/mod.rs already declares `{}`", declaration);
+    }
+    let module_of = |line: &str| {
+        line.strip_prefix("pub mod ")
+            .and_then(|rest| rest.strip_suffix(';'))
+            .map(str::to_string)
+    };
+
+    let mut insert_at = lines
+        .iter()
+        .position(|l| module_of(l).is_some_and(|m| m.as_str() > name));
+    if let Some(idx) = insert_at {
+        let mut idx = idx;
+        while idx > 0 && lines[idx - 1].starts_with("#[") {
+            idx -= 1;
+        }
+        insert_at = Some(idx);
+    } else {
+        insert_at = lines
+            .iter()
+            .rposition(|l| module_of(l).is_some())
+            .map(|idx| idx + 1);
+    }
+    let insert_at = insert_at.ok_or_else(|| anyhow!("no `pub mod` declarations found"))?;
+
+    let mut out: Vec<&str> = lines[..insert_at].to_vec();
+    out.push(&declaration);
+    out.extend_from_slice(&lines[insert_at..]);
+    let mut patched = out.join("\n");
+    if content.ends_with('\n') {
+        patched.push('\n');
+    }
+    Ok(patched)
+}
+
+/// Register the strategy in `StrategyFactory` (factory arm and
+/// `available_strategies` entry) in `src/strategy/manager.rs`.
+pub fn patch_factory(content: &str, name: &str, description: &str) -> Result<String> {
+    if content.contains(&format!("            \"{}\" => {{", name)) {
+        bail!("StrategyFactory already has a `{}` strategy", name);
+    }
+    let type_name = type_name(name);
+
+    let call = format!(
+        "                let strat = super::{}::{}::from_toml(strategy_id, config_content, dry_run)?;",
+        name, type_name
+    );
+    let call = if call.len() <= 100 {
+        call
+    } else {
+        format!(
+            "                let strat = super::{}::{}::from_toml(\n                    \
+             strategy_id,\n                    config_content,\n                    \
+             dry_run,\n                )?;",
+            name, type_name
+        )
+    };
+    let arm = format!(
+        "            \"{}\" => {{\n{}\n                Ok(Box::new(strat))\n            }}\n",
+        name, call
+    );
+    if !content.contains(FACTORY_FALLBACK_ARM) {
+        bail!("could not find the fallback arm of StrategyFactory::from_toml_tagged");
+    }
+    let content = content.replacen(FACTORY_FALLBACK_ARM, &(arm + FACTORY_FALLBACK_ARM), 1);
+
+    let description = escape(description);
+    let description_line = format!(
+        "                description: \"{}\".to_string(),",
+        description
+    );
+    let description_line = if description_line.len() <= 100 {
+        description_line
+    } else {
+        format!(
+            "                description: \"{}\"\n                    .to_string(),",
+            description
+        )
+    };
+    let info = format!(
+        "            StrategyInfo {{\n                name: \"{}\".to_string(),\n{}\n                \
+         config_template: \"{}_default.toml\".to_string(),\n            }},\n",
+        name, description_line, name
+    );
+    if !content.contains(AVAILABLE_LIST_END) {
+        bail!("could not find the end of StrategyFactory::available_strategies");
+    }
+    Ok(content.replacen(AVAILABLE_LIST_END, &(info + AVAILABLE_LIST_END), 1))
+}
+
+/// Generate and register a strategy in the repository at `root`
+pub fn scaffold_strategy(
+    root: &Path,
+    name: &str,
+    kind: ScaffoldKind,
+    description: Option<&str>,
+) -> Result<ScaffoldReport> {
+    validate_name(name)?;
+    let description = description
+        .map(str::trim)
+        .filter(|d| !d.is_empty())
+        .unwrap_or(kind.default_description());
+
+    let strategy_dir = root.join("src/strategy");
+    let mod_path = strategy_dir.join("mod.rs");
+    let manager_path = strategy_dir.join("manager.rs");
+    if !mod_path.is_file() || !manager_path.is_file() {
+        bail!(
+            "{} is not a ploy checkout (run from the repository root or pass --root)",
+            root.display()
+        );
+    }
+    let module_path = strategy_dir.join(format!("{}.rs", name));
+    if module_path.exists() || strategy_dir.join(name).exists() {
+        bail!("{} already exists", module_path.display());
+    }
+    let config_path = root
+        .join("config/strategies")
+        .join(format!("{}_default.toml", name));
+    if config_path.exists() {
+        bail!("{} already exists", config_path.display());
+    }
+
+    // Patch in memory first so a failed patch leaves the tree untouched.
+    let mod_rs = fs::read_to_string(&mod_path)
+        .with_context(|| format!("Failed to read {}", mod_path.display()))?;
+    let manager_rs = fs::read_to_string(&manager_path)
+        .with_context(|| format!("Failed to read {}", manager_path.display()))?;
+    let mod_rs = patch_strategy_mod(&mod_rs, name)?;
+    let manager_rs = patch_factory(&manager_rs, name, description)?;
+
+    if let Some(parent) = config_path.parent() {
+        fs::create_dir_all(parent)?;
+    }
+    fs::write(&module_path, render_module(name, kind, description))?;
+    fs::write(&config_path, render_config(name, kind, description))?;
+    fs::write(&mod_path, mod_rs)?;
+    fs::write(&manager_path, manager_rs)?;
+
+    Ok(ScaffoldReport {
+        type_name: type_name(name),
+        created: vec![module_path, config_path],
+        patched: vec![mod_path, manager_path],
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_names() {
+        assert!(validate_name("mean_revert").is_ok());
+        assert!(validate_name("MeanRevert").is_err());
+        assert!(validate_name("2fast").is_err());
+        assert!(validate_name("match").is_err());
+        assert_eq!(type_name("mean_revert"), "MeanRevertStrategy");
+        assert_eq!(type_name("foo_strategy"), "FooStrategy");
+    }
+
+    #[test]
+    fn test_registers_module_and_factory_entry() {
+        let mod_rs =
+            "pub mod ab_test;\n#[cfg(feature = \"analysis\")]\npub mod parquet;\npub mod zeta;\n";
+        let patched = patch_strategy_mod(mod_rs, "mean_revert").unwrap();
+        assert_eq!(
+            patched,
+            "pub mod ab_test;\npub mod mean_revert;\n#[cfg(feature = \"analysis\")]\npub mod parquet;\npub mod zeta;\n"
+        );
+        assert!(patch_strategy_mod(&patched, "mean_revert").is_err());
+
+        let manager = include_str!("../../strategy/manager.rs");
+        let patched = patch_factory(manager, "mean_revert", "Fades \"spikes\"").unwrap();
+        assert!(patched.contains(
+            "            \"mean_revert\" => {\n                let strat = super::mean_revert::MeanRevertStrategy::from_toml("
+        ));
+        assert!(patched.contains("description: \"Fades \\\"spikes\\\"\".to_string(),"));
+        assert!(patch_factory(&patched, "mean_revert", "again").is_err());
+
+        let module = render_module("mean_revert", ScaffoldKind::Arb, "Fades \"spikes\"");
+        assert!(module.contains("pub struct MeanRevertStrategy {"));
+        assert!(module.contains("SumTargetValidator::new(Decimal::ONE)"));
+        assert!(!module.contains("__"));
+    }
+}
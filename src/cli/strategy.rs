@@ -6,6 +6,9 @@
 //! ploy strategy status [name]     - Show strategy status
 //! ploy strategy logs <name>       - View strategy logs
 //! ploy strategy reload <name>     - Reload strategy config
+//! ploy strategy ab-start <file>   - Run two strategy variants with split capital
+//! ploy strategy ab-status <name>  - Compare the variants of an A/B experiment
+//! ploy strategy scaffold <name>   - Generate a new strategy module
 
 use anyhow::{Context, Result};
 use clap::{Subcommand, ValueEnum};
@@ -18,10 +21,15 @@ use tracing::{error, info, warn};
 
 use crate::adapters::polymarket_clob::POLYGON_CHAIN_ID;
 use crate::adapters::PolymarketClient;
+use crate::cli::scaffold::{scaffold_strategy, ScaffoldKind};
 use crate::config::ExecutionConfig;
+use crate::domain::OrderStatus;
 use crate::signing::Wallet;
 use crate::strategy::executor::OrderExecutor;
-use crate::strategy::{StrategyFactory, StrategyManager};
+use crate::strategy::{
+    AbExperimentConfig, AbReport, AbTracker, Strategy, StrategyFactory, StrategyManager,
+    WarmupConfig, WarmupStatus,
+};
 
 #[derive(ValueEnum, Debug, Clone, Copy, PartialEq, Eq)]
 pub enum CryptoLobDatasetFormat {
@@ -304,6 +312,44 @@ pub enum StrategyCommands {
         name: String,
     },
 
+    /// Run two variants of a strategy side by side with capital split by weight
+    AbStart {
+        /// Experiment file (`[experiment]` plus two `[[variants]]`)
+        experiment: PathBuf,
+
+        /// Run in dry-run mode (no real orders)
+        #[arg(long)]
+        dry_run: bool,
+    },
+
+    /// Compare the variants of a running or finished A/B experiment
+    AbStatus {
+        /// Experiment name
+        name: String,
+
+        /// Output JSON
+        #[arg(long)]
+        json: bool,
+    },
+
+    /// Generate a new strategy module and register it with StrategyFactory
+    Scaffold {
+        /// Strategy name (snake_case, becomes the module and factory name)
+        name: String,
+
+        /// Skeleton to start from
+        #[arg(long, value_enum, default_value_t = ScaffoldKind::Signal)]
+        kind: ScaffoldKind,
+
+        /// One-line description for `ploy strategy list`
+        #[arg(long)]
+        description: Option<String>,
+
+        /// Repository root to write into
+        #[arg(long, default_value = ".")]
+        root: PathBuf,
+    },
+
     /// Seed NBA team comeback stats into the database
     NbaSeedStats {
         /// Season string (e.g. "2025-26")
@@ -478,6 +524,10 @@ pub enum StrategyCommands {
         #[arg(long)]
         json: bool,
 
+        /// Write the JSON report to this path (input for `ploy backtest diff`)
+        #[arg(long)]
+        output: Option<String>,
+
         /// Settlement-mode lookback window in hours
         #[arg(long, default_value = "168")]
         lookback_hours: u64,
@@ -522,6 +572,17 @@ impl StrategyCommands {
             Self::Status { name } => show_status(name.as_deref()).await,
             Self::Logs { name, tail, follow } => show_logs(&name, tail, follow).await,
             Self::Reload { name } => reload_strategy(&name).await,
+            Self::AbStart {
+                experiment,
+                dry_run,
+            } => run_ab_experiment(&experiment, dry_run).await,
+            Self::AbStatus { name, json } => show_ab_status(&name, json).await,
+            Self::Scaffold {
+                name,
+                kind,
+                description,
+                root,
+            } => scaffold(&name, kind, description.as_deref(), &root),
             Self::NbaSeedStats {
                 season,
                 database_url,
@@ -567,6 +628,7 @@ impl StrategyCommands {
                     10000.0,
                     false,
                     false,
+                    None,
                     lookback_hours,
                     account_id,
                     agent_id,
@@ -613,6 +675,7 @@ impl StrategyCommands {
                 capital,
                 save,
                 json,
+                output,
                 lookback_hours,
                 account_id,
                 agent_id,
@@ -630,6 +693,7 @@ impl StrategyCommands {
                     capital,
                     save,
                     json,
+                    output,
                     lookback_hours,
                     account_id,
                     agent_id,
@@ -812,9 +876,6 @@ async fn start_strategy(
 
 /// Run strategy in foreground using StrategyManager
 async fn run_strategy_foreground(name: &str, config_path: &PathBuf, dry_run: bool) -> Result<()> {
-    use crate::adapters::PolymarketWebSocket;
-    use crate::strategy::DataFeedManager;
-
     // Load config
     let config_content = fs::read_to_string(config_path)
         .context(format!("Failed to read config: {}", config_path.display()))?;
@@ -827,16 +888,64 @@ async fn run_strategy_foreground(name: &str, config_path: &PathBuf, dry_run: boo
     // Create strategy via factory
     let strategy = StrategyFactory::from_toml(&config_content, dry_run)
         .context("Failed to create strategy from config")?;
+    let warmup = StrategyFactory::warmup_from_toml(&config_content)
+        .context("Failed to read warmup config")?;
+
+    run_foreground(
+        vec![ForegroundStrategy {
+            strategy,
+            config_path: config_path.clone(),
+            warmup,
+            status_name: name.to_string(),
+        }],
+        dry_run,
+        None,
+    )
+    .await
+}
 
-    let strategy_id = strategy.id().to_string();
-    let required_feeds = strategy.required_feeds();
+/// One strategy instance hosted by a foreground process
+struct ForegroundStrategy {
+    strategy: Box<dyn Strategy>,
+    config_path: PathBuf,
+    warmup: WarmupConfig,
+    /// Run-dir name for published status files
+    status_name: String,
+}
 
-    println!("  Strategy ID: {}", strategy_id);
-    println!("  Strategy: {}", strategy.name());
-    println!("  Description: {}", strategy.description());
-    println!("  Dry Run: {}", dry_run);
-    println!("  Required Feeds: {:?}", required_feeds);
-    println!();
+/// Run strategies in this process, sharing one executor and one set of feeds
+async fn run_foreground(
+    strategies: Vec<ForegroundStrategy>,
+    dry_run: bool,
+    ab: Option<Arc<tokio::sync::Mutex<AbTracker>>>,
+) -> Result<()> {
+    use crate::adapters::PolymarketWebSocket;
+    use crate::strategy::DataFeedManager;
+
+    let mut required_feeds: Vec<crate::strategy::DataFeed> = Vec::new();
+    for hosted in &strategies {
+        let strategy = &hosted.strategy;
+        let feeds = strategy.required_feeds();
+
+        println!("  Strategy ID: {}", strategy.id());
+        println!("  Strategy: {}", strategy.name());
+        println!("  Description: {}", strategy.description());
+        println!("  Dry Run: {}", dry_run);
+        println!("  Required Feeds: {:?}", feeds);
+        if hosted.warmup.enabled {
+            println!(
+                "  Warmup: {} ticks, {}s before trading",
+                hosted.warmup.min_ticks, hosted.warmup.min_secs
+            );
+        }
+        println!();
+
+        for feed in feeds {
+            if !required_feeds.contains(&feed) {
+                required_feeds.push(feed);
+            }
+        }
+    }
 
     // Create order executor (authenticated client for live trading)
     let executor = if dry_run {
@@ -974,13 +1083,43 @@ async fn run_strategy_foreground(name: &str, config_path: &PathBuf, dry_run: boo
         feed_manager = feed_manager.with_polymarket(pm_ws, pm_client);
     }
 
-    // Start the strategy
-    manager
-        .start_strategy(strategy, Some(config_path.display().to_string()))
-        .await
-        .context("Failed to start strategy")?;
+    // Start the strategies
+    let mut started = Vec::new();
+    for hosted in strategies {
+        let strategy_id = hosted.strategy.id().to_string();
+        manager
+            .start_strategy_with_warmup(
+                hosted.strategy,
+                Some(hosted.config_path.display().to_string()),
+                hosted.warmup,
+            )
+            .await
+            .context("Failed to start strategy")?;
+
+        println!("\x1b[32m✓ Strategy {} started\x1b[0m", strategy_id);
 
-    println!("\x1b[32m✓ Strategy started\x1b[0m");
+        // Publish warmup progress for `ploy strategy status` (runs in this process).
+        let warmup_file = run_dir().join(format!("{}.warmup.json", hosted.status_name));
+        let warmup_handle = tokio::spawn(publish_warmup_status(
+            manager.clone(),
+            strategy_id.clone(),
+            warmup_file.clone(),
+        ));
+        started.push((strategy_id, warmup_file, warmup_handle));
+    }
+
+    let ab_handle = match ab.clone() {
+        Some(tracker) => {
+            let name = tracker.lock().await.name().to_string();
+            let report_file = run_dir().join(format!("{}.ab.json", name));
+            Some(tokio::spawn(publish_ab_report(
+                manager.clone(),
+                tracker,
+                report_file,
+            )))
+        }
+        None => None,
+    };
 
     // Start data feeds
     println!("  \x1b[36mStarting data feeds...\x1b[0m");
@@ -995,7 +1134,7 @@ async fn run_strategy_foreground(name: &str, config_path: &PathBuf, dry_run: boo
     println!("\x1b[32m✓ Data feeds started\x1b[0m\n");
 
     // Spawn action handler task with executor
-    let action_handle = tokio::spawn(handle_strategy_actions(action_rx, executor));
+    let action_handle = tokio::spawn(handle_strategy_actions(action_rx, executor, ab));
 
     // Wait for shutdown signal
     println!("Press Ctrl+C to stop...\n");
@@ -1005,23 +1144,277 @@ async fn run_strategy_foreground(name: &str, config_path: &PathBuf, dry_run: boo
 
     // Graceful shutdown
     println!("Stopping strategy gracefully...");
-    manager
-        .stop_strategy(&strategy_id, true)
-        .await
-        .context("Failed to stop strategy")?;
+    for (strategy_id, warmup_file, warmup_handle) in started {
+        manager
+            .stop_strategy(&strategy_id, true)
+            .await
+            .context("Failed to stop strategy")?;
+        warmup_handle.abort();
+        let _ = fs::remove_file(&warmup_file);
+    }
 
-    // Cancel action handler
+    // Cancel action handler; the A/B report stays for `ab-status`
     action_handle.abort();
+    if let Some(handle) = ab_handle {
+        handle.abort();
+    }
 
     println!("\x1b[32m✓ Strategy stopped\x1b[0m");
 
     Ok(())
 }
 
+/// Write the strategy's warmup status to the run dir until it is warm
+async fn publish_warmup_status(manager: Arc<StrategyManager>, strategy_id: String, path: PathBuf) {
+    if let Some(parent) = path.parent() {
+        let _ = fs::create_dir_all(parent);
+    }
+
+    let mut interval = tokio::time::interval(tokio::time::Duration::from_secs(2));
+    loop {
+        interval.tick().await;
+        let Some(status) = manager.get_strategy_status(&strategy_id).await else {
+            return;
+        };
+        match serde_json::to_string(&status.warmup) {
+            Ok(json) => {
+                if let Err(e) = fs::write(&path, json) {
+                    warn!("Failed to write warmup status {}: {}", path.display(), e);
+                }
+            }
+            Err(e) => warn!("Failed to serialize warmup status: {}", e),
+        }
+        if status.warmup.warm {
+            return;
+        }
+    }
+}
+
+/// Warmup status published by a running strategy process, if any
+fn read_warmup_status(name: &str) -> Option<WarmupStatus> {
+    let raw = fs::read_to_string(run_dir().join(format!("{}.warmup.json", name))).ok()?;
+    serde_json::from_str(&raw).ok()
+}
+
+/// Feed variant PnL into the A/B tracker and write its report to the run dir
+async fn publish_ab_report(
+    manager: Arc<StrategyManager>,
+    tracker: Arc<tokio::sync::Mutex<AbTracker>>,
+    path: PathBuf,
+) {
+    if let Some(parent) = path.parent() {
+        let _ = fs::create_dir_all(parent);
+    }
+
+    let sample_every = tracker.lock().await.sample_interval_secs();
+    let mut last_sample = chrono::Utc::now();
+    let mut interval = tokio::time::interval(tokio::time::Duration::from_secs(5));
+    loop {
+        interval.tick().await;
+        let now = chrono::Utc::now();
+
+        let ids = tracker.lock().await.strategy_ids();
+        let mut pnl = Vec::new();
+        let mut open = Vec::new();
+        for id in ids {
+            if let Some(status) = manager.get_strategy_status(&id).await {
+                pnl.push((id.clone(), status.state.realized_pnl_today));
+            }
+            if let Some(positions) = manager.get_positions(&id).await {
+                let notional: rust_decimal::Decimal = positions
+                    .iter()
+                    .map(|p| p.entry_price * rust_decimal::Decimal::from(p.shares))
+                    .sum();
+                open.push((id, notional));
+            }
+        }
+
+        let report = {
+            let mut tracker = tracker.lock().await;
+            for (id, realized) in pnl {
+                tracker.update_pnl(&id, realized);
+            }
+            for (id, notional) in open {
+                tracker.sync_open_notional(&id, notional);
+            }
+            if (now - last_sample).num_seconds() >= sample_every as i64 {
+                tracker.sample();
+                last_sample = now;
+            }
+            tracker.report(now)
+        };
+
+        match serde_json::to_string_pretty(&report) {
+            Ok(json) => {
+                if let Err(e) = fs::write(&path, json) {
+                    warn!("Failed to write A/B report {}: {}", path.display(), e);
+                }
+            }
+            Err(e) => warn!("Failed to serialize A/B report: {}", e),
+        }
+    }
+}
+
+/// Start both variants of an A/B experiment in this process
+async fn run_ab_experiment(experiment: &PathBuf, dry_run: bool) -> Result<()> {
+    if !dry_run {
+        crate::safety::direct_live::enforce_live_gate("ploy strategy ab-start")?;
+    }
+
+    let config = AbExperimentConfig::load(experiment)?;
+    let name = config.experiment.name.clone();
+
+    let mut hosted = Vec::new();
+    for variant in &config.variants {
+        let content = fs::read_to_string(&variant.config).context(format!(
+            "Failed to read variant config: {}",
+            variant.config.display()
+        ))?;
+        let strategy = StrategyFactory::from_toml_tagged(&content, dry_run, Some(&variant.label))
+            .context(format!("Failed to create variant {}", variant.label))?;
+        let warmup =
+            StrategyFactory::warmup_from_toml(&content).context("Failed to read warmup config")?;
+        hosted.push(ForegroundStrategy {
+            strategy,
+            config_path: variant.config.clone(),
+            warmup,
+            status_name: format!("{}.{}", name, variant.label),
+        });
+    }
+
+    if hosted[0].strategy.name() != hosted[1].strategy.name() {
+        anyhow::bail!(
+            "A/B variants must run the same strategy ({} vs {})",
+            hosted[0].strategy.name(),
+            hosted[1].strategy.name()
+        );
+    }
+
+    let ids: Vec<String> = hosted.iter().map(|h| h.strategy.id().to_string()).collect();
+    let tracker = AbTracker::new(&config, &ids, chrono::Utc::now());
+
+    println!("\n\x1b[36m▶ A/B experiment {}\x1b[0m", name);
+    println!("  Capital: ${}", config.experiment.capital);
+    for variant in &config.variants {
+        println!(
+            "  {:<12} {:>5.1}%  {}",
+            variant.label,
+            variant.weight * 100.0,
+            variant.config.display()
+        );
+    }
+    println!();
+
+    run_foreground(
+        hosted,
+        dry_run,
+        Some(Arc::new(tokio::sync::Mutex::new(tracker))),
+    )
+    .await
+}
+
+/// Generate a strategy module from the scaffold templates
+fn scaffold(
+    name: &str,
+    kind: ScaffoldKind,
+    description: Option<&str>,
+    root: &std::path::Path,
+) -> Result<()> {
+    let report = scaffold_strategy(root, name, kind, description)?;
+
+    println!(
+        "Scaffolded {} ({} strategy)",
+        report.type_name,
+        kind.as_str()
+    );
+    for path in &report.created {
+        println!("  created  {}", path.display());
+    }
+    for path in &report.patched {
+        println!("  patched  {}", path.display());
+    }
+    println!();
+    println!("Next steps:");
+    println!("  1. Fill in `evaluate` in src/strategy/{}.rs", name);
+    println!("  2. cargo test {}", name);
+    println!("  3. ploy strategy start {} --dry-run", name);
+    Ok(())
+}
+
+/// Print the latest report of an A/B experiment
+async fn show_ab_status(name: &str, json: bool) -> Result<()> {
+    let path = run_dir().join(format!("{}.ab.json", name));
+    let raw = fs::read_to_string(&path).context(format!(
+        "No A/B report for '{}' ({})",
+        name,
+        path.display()
+    ))?;
+    let report: AbReport = serde_json::from_str(&raw).context("Invalid A/B report")?;
+
+    if json {
+        println!("{}", serde_json::to_string_pretty(&report)?);
+        return Ok(());
+    }
+
+    println!("\n{}", "=".repeat(80));
+    println!("  A/B EXPERIMENT: {}", report.experiment);
+    println!(
+        "  Capital ${}  started {}  updated {}",
+        report.capital,
+        report.started_at.format("%Y-%m-%d %H:%M"),
+        report.updated_at.format("%Y-%m-%d %H:%M:%S")
+    );
+    println!("{}\n", "=".repeat(80));
+
+    println!(
+        "  {:<12} {:>6} {:>10} {:>7} {:>8} {:>10} {:>8} {:>8} {:>10}",
+        "VARIANT", "WEIGHT", "BUDGET", "ORDERS", "BLOCKED", "PNL", "RETURN", "SAMPLES", "MEAN/INT"
+    );
+    println!("  {}", "-".repeat(78));
+    for v in &report.variants {
+        println!(
+            "  {:<12} {:>5.0}% {:>10} {:>7} {:>8} {:>10} {:>7.2}% {:>8} {:>9.4}%",
+            v.label,
+            v.weight * 100.0,
+            v.budget,
+            v.orders,
+            v.blocked_orders,
+            v.realized_pnl.round_dp(2),
+            v.return_on_budget * 100.0,
+            v.samples,
+            v.mean_return * 100.0
+        );
+    }
+    println!();
+
+    match &report.comparison {
+        Some(c) => {
+            println!(
+                "  {} - {}: mean diff {:+.4}%/interval, t={:.2}, p={:.4} (alpha {})",
+                report.variants[1].label,
+                report.variants[0].label,
+                c.mean_diff * 100.0,
+                c.t_stat,
+                c.p_value,
+                c.alpha
+            );
+            match &c.leader {
+                Some(leader) => println!("  \x1b[32m✓ Significant: {} is ahead\x1b[0m", leader),
+                None => println!("  \x1b[33mNot significant yet\x1b[0m"),
+            }
+        }
+        None => println!("  \x1b[90mNot enough samples to compare yet\x1b[0m"),
+    }
+    println!();
+
+    Ok(())
+}
+
 /// Handle actions emitted by strategies
 async fn handle_strategy_actions(
     mut rx: tokio::sync::mpsc::Receiver<(String, crate::strategy::StrategyAction)>,
     executor: Option<Arc<OrderExecutor>>,
+    ab: Option<Arc<tokio::sync::Mutex<AbTracker>>>,
 ) {
     use crate::strategy::StrategyAction;
 
@@ -1032,6 +1425,15 @@ async fn handle_strategy_actions(
                 order,
                 priority: _,
             } => {
+                if let Some(ref tracker) = ab {
+                    if !tracker.lock().await.admit(&strategy_id, &order) {
+                        println!(
+                            "  \x1b[33m[{}]\x1b[0m Order {} blocked: A/B variant budget used up",
+                            strategy_id, client_order_id
+                        );
+                        continue;
+                    }
+                }
                 let price_cents = order.limit_price * rust_decimal::Decimal::from(100);
                 println!("\n  \x1b[36m╔══════════════════════════════════════════════════════════════╗\x1b[0m");
                 println!("  \x1b[36m║\x1b[0m  📤 ORDER SUBMISSION                                          \x1b[36m║\x1b[0m");
@@ -1055,7 +1457,18 @@ async fn handle_strategy_actions(
                 // Execute order if executor is available
                 if let Some(ref exec) = executor {
                     info!("Executing order: {} @ {:.2}¢", client_order_id, price_cents);
-                    match exec.execute(&order).await {
+                    let outcome = exec.execute(&order).await;
+                    if let Some(ref tracker) = ab {
+                        let (status, filled) = match &outcome {
+                            Ok(result) => (result.status, result.filled_shares),
+                            Err(_) => (OrderStatus::Failed, 0),
+                        };
+                        tracker
+                            .lock()
+                            .await
+                            .settle_order(&strategy_id, &order, &status, filled);
+                    }
+                    match outcome {
                         Ok(result) => {
                             println!("  \x1b[32m✓ Order executed!\x1b[0m");
                             println!("    Order ID: {}", result.order_id);
@@ -1079,6 +1492,14 @@ async fn handle_strategy_actions(
                         }
                     }
                 } else {
+                    if let Some(ref tracker) = ab {
+                        tracker.lock().await.settle_order(
+                            &strategy_id,
+                            &order,
+                            &OrderStatus::Failed,
+                            0,
+                        );
+                    }
                     println!("  \x1b[33m⚠ No executor - order logged but not submitted\x1b[0m\n");
                     warn!(
                         "Order {} not executed - no executor configured",
@@ -1265,10 +1686,10 @@ async fn show_status(name: Option<&str>) -> Result<()> {
     };
 
     println!(
-        "  {:<15} {:<12} {:<10} {}",
-        "NAME", "STATUS", "PID", "UPTIME"
+        "  {:<15} {:<12} {:<10} {:<12} {}",
+        "NAME", "STATUS", "PID", "UPTIME", "WARMUP"
     );
-    println!("  {}", "-".repeat(55));
+    println!("  {}", "-".repeat(70));
 
     for strat_name in strategies {
         let status = get_strategy_status(&strat_name);
@@ -1284,9 +1705,12 @@ async fn show_status(name: Option<&str>) -> Result<()> {
                 } else {
                     get_process_uptime(pid).unwrap_or_else(|| "unknown".into())
                 };
+                let warmup = read_warmup_status(&strat_name)
+                    .map(|w| w.summary())
+                    .unwrap_or_else(|| "-".into());
                 println!(
-                    "  {:<15} \x1b[32m{:<12}\x1b[0m {:<10} {}",
-                    strat_name, "● running", pid_str, uptime
+                    "  {:<15} \x1b[32m{:<12}\x1b[0m {:<10} {:<12} {}",
+                    strat_name, "● running", pid_str, uptime, warmup
                 );
             }
             StrategyStatus::Stopped => {
@@ -3145,6 +3569,8 @@ async fn run_nba_comeback(_config: Option<PathBuf>, _dry_run: bool) -> Result<()
             early_exit_enabled: true,
             early_exit_take_profit_pct: 15.0,
             early_exit_stop_loss_pct: 20.0,
+            comment_sentiment: Default::default(),
+            score_guard: Default::default(),
         }
     });
 
@@ -3267,6 +3693,7 @@ async fn run_backtest(
     capital: f64,
     save: bool,
     json_output: bool,
+    output: Option<String>,
     lookback_hours: u64,
     account_id: Option<String>,
     agent_id: Option<String>,
@@ -3298,6 +3725,9 @@ async fn run_backtest(
         if save {
             warn!("--save has no effect in settlement mode");
         }
+        if output.is_some() {
+            warn!("--output has no effect in settlement mode");
+        }
         return backtest_directional_signals_pm_settlement(
             lookback_hours,
             account_id,
@@ -3342,12 +3772,24 @@ async fn run_backtest(
     let mut feed = HistoricalFeed::from_database(store.pool(), &symbol_list, from_dt, to_dt).await?;
 
     let initial_capital = Decimal::from_f64(capital).unwrap_or_else(|| Decimal::new(10000, 0));
+    let data_snapshot_id = feed.snapshot_id();
+    let fill_model = crate::strategy::FillModel::load_default();
+    if let Some(model) = &fill_model {
+        info!(
+            "Using learned fill model ({} samples, fitted {})",
+            model.samples, model.fitted_at
+        );
+    }
 
-    let results = match name {
+    let (results, engine_config, overfit) = match name {
         "directional" => {
             let mut config = DirectionalBacktestConfig::with_symbols(symbol_list.clone());
             config.initial_capital = initial_capital;
             let mut engine = DirectionalBacktestEngine::new(config);
+            if let Some(model) = fill_model {
+                engine = engine.with_fill_model(model);
+            }
+            let engine_config = serde_json::to_value(engine.config())?;
             let results = engine.run(&mut feed);
 
             // Print directional-specific summary (includes exit reasons, calibration)
@@ -3357,14 +3799,31 @@ async fn run_backtest(
             if save {
                 warn!("--save currently persists momentum replay backtests only; directional replay prints summary only");
             }
-            results
+            (results, engine_config, None)
         }
         _ => {
             let config =
                 MomentumBacktestConfig::default_with_symbols(symbol_list.clone(), initial_capital);
             let mut engine = MomentumBacktestEngine::new(config);
+            if let Some(model) = fill_model {
+                engine = engine.with_fill_model(model);
+            }
+            let engine_config = serde_json::to_value(engine.config())?;
+            let replay_feed = feed.clone();
             let results = engine.run(&mut feed);
 
+            let overfit = crate::strategy::momentum_backtest::evaluate_momentum_overfit(
+                engine.config(),
+                &replay_feed,
+                &results,
+                &crate::analysis::OverfitConfig::default(),
+            );
+            if overfit.passed {
+                info!("Overfit checks passed");
+            } else {
+                warn!("Overfit checks failed: {}", overfit.failures.join("; "));
+            }
+
             // Optionally save momentum results to DB
             if save {
                 let store = PostgresStore::new(&db_url, 5).await?;
@@ -3372,14 +3831,59 @@ async fn run_backtest(
                     store.pool(),
                     &engine.config(),
                     &results,
+                    Some(&overfit),
                 )
                 .await?;
                 info!("Backtest results saved to database");
             }
-            results
+            (results, engine_config, Some(overfit))
         }
     };
 
+    // Versioned research artifact (input for `ploy research list|show`)
+    let report_config = serde_json::json!({
+        "strategy": name,
+        "symbols": symbol_list,
+        "from": from_dt,
+        "to": to_dt,
+        "engine": engine_config,
+    });
+    let report_summary = serde_json::json!({
+        "total_trades": results.total_trades,
+        "win_rate": results.win_rate,
+        "total_pnl": results.total_pnl,
+        "sharpe_ratio": results.sharpe_ratio,
+        "max_drawdown": results.max_drawdown,
+        "overfit": overfit,
+    });
+    let report_store = crate::strategy::research_report::ResearchReportStore::from_env();
+    match crate::strategy::research_report::ResearchReport::new(
+        &format!("backtest.{}", name),
+        &report_config,
+        &results,
+    ) {
+        Ok(report) => {
+            let report = report
+                .with_data_snapshot(data_snapshot_id)
+                .with_summary(report_summary);
+            match report_store.save(&report) {
+                Ok(path) => info!(
+                    "Research report {} written to {}",
+                    report.id,
+                    path.display()
+                ),
+                Err(e) => warn!("Failed to write research report: {}", e),
+            }
+        }
+        Err(e) => warn!("Failed to build research report: {}", e),
+    }
+
+    if let Some(path) = output {
+        std::fs::write(&path, serde_json::to_string_pretty(&results)?)
+            .with_context(|| format!("Failed to write backtest report to {}", path))?;
+        info!("Backtest report written to {}", path);
+    }
+
     if json_output {
         println!("{}", serde_json::to_string_pretty(&results)?);
     } else {
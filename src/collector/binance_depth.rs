@@ -2,28 +2,43 @@
 //!
 //! Collects real-time order book data via @depth@100ms stream
 //! for lead-lag analysis with Polymarket.
+//!
+//! Books are seeded from a REST snapshot and every diff is checked against
+//! Binance's update-id sequence. A gap, a crossed book or a failed periodic
+//! checksum reconciliation against a fresh snapshot triggers an automatic
+//! resync; [`LobCache::trust`] tells consumers when a book is suspect.
 
 use chrono::{DateTime, Utc};
 use rust_decimal::Decimal;
 use serde::{Deserialize, Serialize};
-use std::collections::BTreeMap;
+use std::collections::hash_map::DefaultHasher;
+use std::collections::{BTreeMap, HashMap, VecDeque};
+use std::hash::{Hash, Hasher};
 use std::sync::Arc;
 use std::time::Duration;
 use tokio::sync::{broadcast, RwLock};
 use tokio_tungstenite::{connect_async, tungstenite::Message};
-use tracing::{debug, error, info};
+use tracing::{debug, error, info, warn};
 use url::Url;
 
+use super::lob_features::{self, LobFeatures};
 use crate::error::{PloyError, Result};
 
 const BINANCE_WS_URL: &str = "wss://stream.binance.com:9443/ws";
+const BINANCE_DEPTH_REST_URL: &str = "https://api.binance.com/api/v3/depth";
 const PING_INTERVAL_SECS: u64 = 30;
 const MAX_RECONNECT_DELAY_SECS: u64 = 60;
 const CHANNEL_CAPACITY: usize = 10000;
 const MAX_DEPTH_LEVELS: usize = 20;
+/// Levels requested per REST snapshot
+const SNAPSHOT_LIMIT: usize = 100;
+/// Recent diffs kept per symbol for snapshot replay (~30s at 100ms)
+const RECENT_DIFFS: usize = 300;
+const MAX_RESYNC_ATTEMPTS: u32 = 5;
+const DEFAULT_RECONCILE_INTERVAL_SECS: u64 = 60;
 
 /// Binance depth update message
-#[derive(Debug, Deserialize)]
+#[derive(Debug, Clone, Deserialize)]
 pub struct DepthUpdate {
     #[serde(rename = "e")]
     pub event_type: String,
@@ -31,6 +46,9 @@ pub struct DepthUpdate {
     pub event_time: i64,
     #[serde(rename = "s")]
     pub symbol: String,
+    /// First update id in the event (0 when absent, e.g. recorded data)
+    #[serde(rename = "U", default)]
+    pub first_update_id: i64,
     #[serde(rename = "u")]
     pub final_update_id: i64,
     #[serde(rename = "b")]
@@ -39,6 +57,66 @@ pub struct DepthUpdate {
     pub asks: Vec<(String, String)>,
 }
 
+/// REST `GET /api/v3/depth` snapshot
+#[derive(Debug, Clone, Deserialize)]
+pub struct DepthSnapshot {
+    #[serde(rename = "lastUpdateId")]
+    pub last_update_id: i64,
+    pub bids: Vec<(String, String)>,
+    pub asks: Vec<(String, String)>,
+}
+
+/// How far a depth diff fits the local book's update-id sequence
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum SequenceCheck {
+    /// Next in sequence; apply it
+    InSequence,
+    /// Already covered by the book; drop it
+    Stale,
+    /// Updates between `expected` and `got` were missed
+    Gap { expected: i64, got: i64 },
+}
+
+/// Confidence in a cached book, for strategies to pause on
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize)]
+#[serde(rename_all = "snake_case")]
+pub enum BookTrust {
+    /// Seeded from a snapshot with an unbroken diff sequence
+    Trusted,
+    /// Diffs still apply but validation failed (crossed book); resync running
+    Suspect,
+    /// Sequence broken or never seeded; diffs are buffered until a snapshot
+    Resyncing,
+}
+
+impl BookTrust {
+    /// Trust gauge: 1 = trusted, 0.5 = suspect, 0 = resyncing
+    pub fn score(&self) -> f64 {
+        match self {
+            BookTrust::Trusted => 1.0,
+            BookTrust::Suspect => 0.5,
+            BookTrust::Resyncing => 0.0,
+        }
+    }
+
+    pub fn is_trusted(&self) -> bool {
+        *self == BookTrust::Trusted
+    }
+}
+
+/// Result of applying a REST snapshot to a cached book
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum SnapshotOutcome {
+    /// Untrusted book rebuilt from the snapshot
+    Resynced,
+    /// Trusted book matches the snapshot replayed to the same update id
+    Matched,
+    /// Trusted book had drifted and was replaced
+    Drifted,
+    /// Snapshot predates the buffered diffs (or is ahead of the book); retry
+    Unusable,
+}
+
 /// Order book state for a symbol
 #[derive(Debug, Clone, Default)]
 pub struct OrderBookState {
@@ -48,20 +126,146 @@ pub struct OrderBookState {
     pub last_update_time: Option<DateTime<Utc>>,
 }
 
+/// Parse a Binance `[price, qty]` level into (price_cents, qty); zero qty means removal
+fn parse_level(price_str: &str, qty_str: &str) -> Option<(i64, Decimal)> {
+    let price = price_str.parse::<f64>().ok()?;
+    let qty = qty_str.parse::<f64>().ok()?;
+    let price_cents = (price * 100.0).round() as i64;
+    let qty_dec = if qty == 0.0 {
+        Decimal::ZERO
+    } else {
+        Decimal::try_from(qty).unwrap_or_default()
+    };
+    Some((price_cents, qty_dec))
+}
+
+fn apply_levels(side: &mut BTreeMap<i64, Decimal>, levels: &[(String, String)]) {
+    for (price_str, qty_str) in levels {
+        if let Some((price_cents, qty)) = parse_level(price_str, qty_str) {
+            if qty.is_zero() {
+                side.remove(&price_cents);
+            } else {
+                side.insert(price_cents, qty);
+            }
+        }
+    }
+}
+
 impl OrderBookState {
-    /// Calculate Order Book Imbalance (OBI)
-    /// OBI = (bid_volume - ask_volume) / (bid_volume + ask_volume)
-    /// Range: -1 (all asks) to +1 (all bids)
-    pub fn calculate_obi(&self, levels: usize) -> Option<Decimal> {
-        let bid_sum: Decimal = self.bids.iter().rev().take(levels).map(|(_, q)| *q).sum();
-        let ask_sum: Decimal = self.asks.iter().take(levels).map(|(_, q)| *q).sum();
-        let total = bid_sum + ask_sum;
+    /// Build a book from full `[price, qty]` levels (e.g. a recorded snapshot)
+    pub fn from_levels(bids: &[(String, String)], asks: &[(String, String)]) -> Self {
+        let mut book = Self::default();
+        apply_levels(&mut book.bids, bids);
+        apply_levels(&mut book.asks, asks);
+        book
+    }
 
-        if total.is_zero() {
-            return None;
+    /// Rebuild a book from a REST snapshot and the diffs received since.
+    ///
+    /// Returns None when the diffs after the snapshot do not continue its
+    /// sequence (snapshot too old for the buffered diffs).
+    pub fn from_snapshot<'a>(
+        snapshot: &DepthSnapshot,
+        diffs: impl IntoIterator<Item = &'a DepthUpdate>,
+    ) -> Option<Self> {
+        let mut book = Self::from_levels(&snapshot.bids, &snapshot.asks);
+        book.trim();
+        book.last_update_id = snapshot.last_update_id;
+        for diff in diffs {
+            match book.check_sequence(diff) {
+                SequenceCheck::InSequence => book.apply_update(diff),
+                SequenceCheck::Stale => {}
+                SequenceCheck::Gap { .. } => return None,
+            }
+        }
+        Some(book)
+    }
+
+    /// Check a diff against Binance's sequencing rule: the first event after
+    /// update id `n` must satisfy `U <= n + 1 <= u`.
+    pub fn check_sequence(&self, update: &DepthUpdate) -> SequenceCheck {
+        if update.first_update_id == 0 {
+            return SequenceCheck::InSequence;
         }
+        let expected = self.last_update_id + 1;
+        if update.final_update_id < expected {
+            SequenceCheck::Stale
+        } else if update.first_update_id > expected {
+            SequenceCheck::Gap {
+                expected,
+                got: update.first_update_id,
+            }
+        } else {
+            SequenceCheck::InSequence
+        }
+    }
 
-        Some((bid_sum - ask_sum) / total)
+    /// Apply a diff without sequence checks
+    pub fn apply_update(&mut self, update: &DepthUpdate) {
+        apply_levels(&mut self.bids, &update.bids);
+        apply_levels(&mut self.asks, &update.asks);
+        self.trim();
+        self.last_update_id = update.final_update_id;
+        self.last_update_time =
+            Some(DateTime::from_timestamp_millis(update.event_time).unwrap_or_else(Utc::now));
+    }
+
+    /// Keep the levels nearest the touch
+    fn trim(&mut self) {
+        while self.bids.len() > MAX_DEPTH_LEVELS * 2 {
+            self.bids.pop_first();
+        }
+        while self.asks.len() > MAX_DEPTH_LEVELS * 2 {
+            self.asks.pop_last();
+        }
+    }
+
+    /// Best bid at or above best ask
+    pub fn is_crossed(&self) -> bool {
+        match (self.bids.keys().next_back(), self.asks.keys().next()) {
+            (Some(bid), Some(ask)) => bid >= ask,
+            _ => false,
+        }
+    }
+
+    /// Checksum of the top `levels` on each side
+    pub fn checksum(&self, levels: usize) -> u64 {
+        let mut hasher = DefaultHasher::new();
+        for (price, qty) in self.bids.iter().rev().take(levels) {
+            (price, qty.normalize().to_string()).hash(&mut hasher);
+        }
+        0u8.hash(&mut hasher);
+        for (price, qty) in self.asks.iter().take(levels) {
+            (price, qty.normalize().to_string()).hash(&mut hasher);
+        }
+        hasher.finish()
+    }
+
+    /// Bid levels as (price, qty), best first
+    pub fn bid_levels(&self) -> Vec<(Decimal, Decimal)> {
+        self.bids
+            .iter()
+            .rev()
+            .map(|(&p, q)| (Decimal::from(p) / Decimal::from(100), *q))
+            .collect()
+    }
+
+    /// Ask levels as (price, qty), best first
+    pub fn ask_levels(&self) -> Vec<(Decimal, Decimal)> {
+        self.asks
+            .iter()
+            .map(|(&p, q)| (Decimal::from(p) / Decimal::from(100), *q))
+            .collect()
+    }
+
+    /// Shared LOB features for the current book
+    pub fn features(&self) -> Option<LobFeatures> {
+        LobFeatures::compute(&self.bid_levels(), &self.ask_levels())
+    }
+
+    /// Calculate Order Book Imbalance (OBI) over the top `levels`
+    pub fn calculate_obi(&self, levels: usize) -> Option<Decimal> {
+        lob_features::obi(&self.bid_levels(), &self.ask_levels(), levels)
     }
 
     /// Get best bid price
@@ -90,12 +294,25 @@ impl OrderBookState {
 
     /// Get spread in basis points
     pub fn spread_bps(&self) -> Option<Decimal> {
-        match (self.best_bid(), self.best_ask()) {
-            (Some(bid), Some(ask)) if !bid.is_zero() => {
-                Some((ask - bid) / bid * Decimal::from(10000))
-            }
-            _ => None,
-        }
+        lob_features::spread_bps(self.best_bid()?, self.best_ask()?)
+    }
+
+    /// Snapshot of the current book for storage/broadcast
+    pub fn snapshot(&self, symbol: &str, timestamp: DateTime<Utc>) -> Option<LobSnapshot> {
+        let features = self.features()?;
+        Some(LobSnapshot {
+            timestamp,
+            symbol: symbol.to_string(),
+            best_bid: features.best_bid,
+            best_ask: features.best_ask,
+            mid_price: features.mid_price,
+            spread_bps: features.spread_bps,
+            obi_5: features.obi_5,
+            obi_10: features.obi_10,
+            bid_volume_5: features.bid_volume_5,
+            ask_volume_5: features.ask_volume_5,
+            update_id: self.last_update_id,
+        })
     }
 }
 
@@ -123,135 +340,264 @@ pub struct LobUpdate {
     pub raw_state: OrderBookState,
 }
 
+/// Sequencing state kept next to each cached book
+#[derive(Debug)]
+struct BookSync {
+    trust: BookTrust,
+    /// Every diff received, applied or not, for snapshot replay
+    recent: VecDeque<DepthUpdate>,
+    resync_in_flight: bool,
+    resyncs: u64,
+    drifts: u64,
+}
+
+impl Default for BookSync {
+    fn default() -> Self {
+        Self {
+            trust: BookTrust::Resyncing,
+            recent: VecDeque::new(),
+            resync_in_flight: false,
+            resyncs: 0,
+            drifts: 0,
+        }
+    }
+}
+
+#[derive(Debug, Default)]
+struct BookEntry {
+    book: OrderBookState,
+    sync: BookSync,
+}
+
 /// Thread-safe LOB cache
 #[derive(Debug, Clone, Default)]
 pub struct LobCache {
-    books: Arc<RwLock<std::collections::HashMap<String, OrderBookState>>>,
+    books: Arc<RwLock<HashMap<String, BookEntry>>>,
 }
 
 impl LobCache {
     pub fn new() -> Self {
         Self {
-            books: Arc::new(RwLock::new(std::collections::HashMap::new())),
+            books: Arc::new(RwLock::new(HashMap::new())),
         }
     }
 
     /// Get order book state for a symbol
     pub async fn get(&self, symbol: &str) -> Option<OrderBookState> {
         let books = self.books.read().await;
-        books.get(symbol).cloned()
+        books.get(symbol).map(|e| e.book.clone())
     }
 
     /// Get OBI for a symbol
     pub async fn get_obi(&self, symbol: &str, levels: usize) -> Option<Decimal> {
         let books = self.books.read().await;
-        books.get(symbol)?.calculate_obi(levels)
+        books.get(symbol)?.book.calculate_obi(levels)
+    }
+
+    /// Get full LOB features for a symbol (one consistent book read)
+    pub async fn get_features(&self, symbol: &str) -> Option<LobFeatures> {
+        let books = self.books.read().await;
+        books.get(symbol)?.book.features()
     }
 
     /// Get snapshot for a symbol
     pub async fn get_snapshot(&self, symbol: &str) -> Option<LobSnapshot> {
         let books = self.books.read().await;
-        let book = books.get(symbol)?;
-
-        let best_bid = book.best_bid()?;
-        let best_ask = book.best_ask()?;
-        let mid_price = book.mid_price()?;
-        let spread_bps = book.spread_bps()?;
-        let obi_5 = book.calculate_obi(5)?;
-        let obi_10 = book.calculate_obi(10)?;
+        let book = &books.get(symbol)?.book;
+        book.snapshot(symbol, book.last_update_time.unwrap_or_else(Utc::now))
+    }
 
-        let bid_volume_5: Decimal = book.bids.iter().rev().take(5).map(|(_, q)| *q).sum();
-        let ask_volume_5: Decimal = book.asks.iter().take(5).map(|(_, q)| *q).sum();
+    /// Trust in the cached book for a symbol (`Resyncing` when unknown)
+    pub async fn trust(&self, symbol: &str) -> BookTrust {
+        let books = self.books.read().await;
+        books
+            .get(symbol)
+            .map_or(BookTrust::Resyncing, |e| e.sync.trust)
+    }
 
-        Some(LobSnapshot {
-            timestamp: book.last_update_time.unwrap_or_else(Utc::now),
-            symbol: symbol.to_string(),
-            best_bid,
-            best_ask,
-            mid_price,
-            spread_bps,
-            obi_5,
-            obi_10,
-            bid_volume_5,
-            ask_volume_5,
-            update_id: book.last_update_id,
-        })
+    /// Trust gauge per symbol (see [`BookTrust::score`])
+    pub async fn trust_scores(&self) -> Vec<(String, f64)> {
+        let books = self.books.read().await;
+        books
+            .iter()
+            .map(|(symbol, e)| (symbol.clone(), e.sync.trust.score()))
+            .collect()
     }
 
-    /// Update order book from depth update
-    async fn apply_depth_update(&self, update: &DepthUpdate) -> Option<LobSnapshot> {
+    /// Mark every book for resync (e.g. after a reconnect lost diffs)
+    async fn invalidate_all(&self) {
         let mut books = self.books.write().await;
-        let book = books.entry(update.symbol.clone()).or_default();
+        for entry in books.values_mut() {
+            entry.sync.trust = BookTrust::Resyncing;
+            entry.sync.recent.clear();
+            entry.sync.resync_in_flight = false;
+        }
+    }
 
-        let ts = DateTime::from_timestamp_millis(update.event_time).unwrap_or_else(Utc::now);
+    /// Update order book from depth update.
+    ///
+    /// Returns the new snapshot (None when the diff was buffered or dropped)
+    /// and whether the caller should start a resync for the symbol.
+    async fn apply_depth_update(&self, update: &DepthUpdate) -> (Option<LobSnapshot>, bool) {
+        let mut books = self.books.write().await;
+        let entry = books.entry(update.symbol.clone()).or_default();
 
-        // Apply bid updates
-        for (price_str, qty_str) in &update.bids {
-            if let (Ok(price), Ok(qty)) = (price_str.parse::<f64>(), qty_str.parse::<f64>()) {
-                let price_cents = (price * 100.0).round() as i64;
-                let qty_dec = Decimal::try_from(qty).unwrap_or_default();
+        entry.sync.recent.push_back(update.clone());
+        while entry.sync.recent.len() > RECENT_DIFFS {
+            entry.sync.recent.pop_front();
+        }
 
-                if qty == 0.0 {
-                    book.bids.remove(&price_cents);
-                } else {
-                    book.bids.insert(price_cents, qty_dec);
+        if entry.sync.trust != BookTrust::Resyncing {
+            match entry.book.check_sequence(update) {
+                SequenceCheck::Stale => return (None, false),
+                SequenceCheck::Gap { expected, got } => {
+                    warn!(
+                        symbol = %update.symbol,
+                        expected,
+                        got,
+                        "binance depth sequence gap; resyncing book"
+                    );
+                    entry.sync.trust = BookTrust::Resyncing;
+                }
+                SequenceCheck::InSequence => {
+                    entry.book.apply_update(update);
+                    if entry.book.is_crossed() && entry.sync.trust == BookTrust::Trusted {
+                        warn!(symbol = %update.symbol, "binance depth book crossed; resyncing");
+                        entry.sync.trust = BookTrust::Suspect;
+                    }
                 }
             }
         }
 
-        // Apply ask updates
-        for (price_str, qty_str) in &update.asks {
-            if let (Ok(price), Ok(qty)) = (price_str.parse::<f64>(), qty_str.parse::<f64>()) {
-                let price_cents = (price * 100.0).round() as i64;
-                let qty_dec = Decimal::try_from(qty).unwrap_or_default();
-
-                if qty == 0.0 {
-                    book.asks.remove(&price_cents);
-                } else {
-                    book.asks.insert(price_cents, qty_dec);
-                }
-            }
+        let needs_resync = entry.sync.trust != BookTrust::Trusted && !entry.sync.resync_in_flight;
+        if needs_resync {
+            entry.sync.resync_in_flight = true;
         }
+        let snapshot = if entry.sync.trust == BookTrust::Resyncing {
+            None
+        } else {
+            let ts = entry.book.last_update_time.unwrap_or_else(Utc::now);
+            entry.book.snapshot(&update.symbol, ts)
+        };
+        (snapshot, needs_resync)
+    }
+
+    /// Rebuild or reconcile a book from a REST snapshot plus buffered diffs
+    async fn apply_snapshot(&self, symbol: &str, snapshot: &DepthSnapshot) -> SnapshotOutcome {
+        let mut books = self.books.write().await;
+        let entry = books.entry(symbol.to_string()).or_default();
 
-        // Trim to max levels
-        while book.bids.len() > MAX_DEPTH_LEVELS * 2 {
-            if let Some(k) = book.bids.keys().next().cloned() {
-                book.bids.remove(&k);
+        let Some(rebuilt) = OrderBookState::from_snapshot(snapshot, entry.sync.recent.iter())
+        else {
+            return SnapshotOutcome::Unusable;
+        };
+
+        if entry.sync.trust == BookTrust::Trusted {
+            // Only comparable once replayed up to the live book's update id
+            if rebuilt.last_update_id != entry.book.last_update_id {
+                return SnapshotOutcome::Unusable;
             }
-        }
-        while book.asks.len() > MAX_DEPTH_LEVELS * 2 {
-            if let Some(k) = book.asks.keys().next_back().cloned() {
-                book.asks.remove(&k);
+            if rebuilt.checksum(MAX_DEPTH_LEVELS) == entry.book.checksum(MAX_DEPTH_LEVELS) {
+                return SnapshotOutcome::Matched;
             }
+            entry.sync.drifts += 1;
+            warn!(
+                symbol,
+                update_id = rebuilt.last_update_id,
+                drifts = entry.sync.drifts,
+                "binance depth book drifted from REST snapshot; replaced"
+            );
+            entry.book = rebuilt;
+            return SnapshotOutcome::Drifted;
         }
 
-        book.last_update_id = update.final_update_id;
-        book.last_update_time = Some(ts);
+        entry.sync.resyncs += 1;
+        info!(
+            symbol,
+            update_id = rebuilt.last_update_id,
+            resyncs = entry.sync.resyncs,
+            "binance depth book resynced"
+        );
+        entry.book = rebuilt;
+        entry.sync.trust = BookTrust::Trusted;
+        entry.sync.resync_in_flight = false;
+        SnapshotOutcome::Resynced
+    }
 
-        // Generate snapshot
-        let best_bid = book.best_bid()?;
-        let best_ask = book.best_ask()?;
-        let mid_price = book.mid_price()?;
-        let spread_bps = book.spread_bps()?;
-        let obi_5 = book.calculate_obi(5)?;
-        let obi_10 = book.calculate_obi(10)?;
+    /// Allow a new resync to start after the previous one gave up
+    async fn end_resync(&self, symbol: &str) {
+        if let Some(entry) = self.books.write().await.get_mut(symbol) {
+            entry.sync.resync_in_flight = false;
+        }
+    }
 
-        let bid_volume_5: Decimal = book.bids.iter().rev().take(5).map(|(_, q)| *q).sum();
-        let ask_volume_5: Decimal = book.asks.iter().take(5).map(|(_, q)| *q).sum();
+    /// Symbols whose book is trusted and not being resynced
+    async fn reconcilable_symbols(&self) -> Vec<String> {
+        let books = self.books.read().await;
+        books
+            .iter()
+            .filter(|(_, e)| e.sync.trust.is_trusted() && !e.sync.resync_in_flight)
+            .map(|(symbol, _)| symbol.clone())
+            .collect()
+    }
+}
 
-        Some(LobSnapshot {
-            timestamp: ts,
-            symbol: update.symbol.clone(),
-            best_bid,
-            best_ask,
-            mid_price,
-            spread_bps,
-            obi_5,
-            obi_10,
-            bid_volume_5,
-            ask_volume_5,
-            update_id: update.final_update_id,
-        })
+/// REST depth snapshot client shared by resync and reconciliation tasks
+#[derive(Debug, Clone)]
+struct SnapshotFetcher {
+    http: reqwest::Client,
+    url: String,
+}
+
+impl SnapshotFetcher {
+    async fn fetch(&self, symbol: &str) -> Result<DepthSnapshot> {
+        let limit = SNAPSHOT_LIMIT.to_string();
+        let snapshot = self
+            .http
+            .get(&self.url)
+            .query(&[("symbol", symbol), ("limit", limit.as_str())])
+            .send()
+            .await?
+            .error_for_status()?
+            .json::<DepthSnapshot>()
+            .await?;
+        Ok(snapshot)
+    }
+
+    /// Fetch snapshots until the symbol's book is rebuilt or attempts run out
+    async fn resync(self, cache: LobCache, symbol: String) {
+        for attempt in 1..=MAX_RESYNC_ATTEMPTS {
+            match self.fetch(&symbol).await {
+                Ok(snapshot) => match cache.apply_snapshot(&symbol, &snapshot).await {
+                    SnapshotOutcome::Unusable => {
+                        debug!(%symbol, attempt, "depth snapshot predates buffered diffs")
+                    }
+                    _ => return,
+                },
+                Err(e) => warn!(%symbol, attempt, error = %e, "depth snapshot fetch failed"),
+            }
+            tokio::time::sleep(Duration::from_millis(500) * attempt).await;
+        }
+        warn!(%symbol, "binance depth resync gave up; retrying on next diff");
+        cache.end_resync(&symbol).await;
+    }
+
+    /// Periodically compare trusted books against fresh snapshots
+    async fn reconcile_loop(self, cache: LobCache, interval: Duration) {
+        let mut ticker = tokio::time::interval(interval);
+        ticker.tick().await;
+        loop {
+            ticker.tick().await;
+            for symbol in cache.reconcilable_symbols().await {
+                match self.fetch(&symbol).await {
+                    Ok(snapshot) => {
+                        let outcome = cache.apply_snapshot(&symbol, &snapshot).await;
+                        debug!(%symbol, ?outcome, "binance depth reconciliation");
+                    }
+                    Err(e) => debug!(%symbol, error = %e, "reconciliation fetch failed"),
+                }
+            }
+        }
     }
 }
 
@@ -260,6 +606,8 @@ pub struct BinanceDepthStream {
     symbols: Vec<String>,
     cache: LobCache,
     update_tx: broadcast::Sender<LobUpdate>,
+    snapshots: SnapshotFetcher,
+    reconcile_interval: Duration,
 }
 
 impl BinanceDepthStream {
@@ -271,9 +619,24 @@ impl BinanceDepthStream {
             symbols,
             cache: LobCache::new(),
             update_tx,
+            snapshots: SnapshotFetcher {
+                http: reqwest::Client::builder()
+                    .timeout(Duration::from_secs(10))
+                    .build()
+                    .unwrap_or_default(),
+                url: BINANCE_DEPTH_REST_URL.to_string(),
+            },
+            reconcile_interval: Duration::from_secs(DEFAULT_RECONCILE_INTERVAL_SECS),
         }
     }
 
+    /// Set how often trusted books are reconciled against REST snapshots
+    /// (zero disables reconciliation)
+    pub fn with_reconcile_interval(mut self, interval: Duration) -> Self {
+        self.reconcile_interval = interval;
+        self
+    }
+
     /// Get reference to LOB cache
     pub fn cache(&self) -> &LobCache {
         &self.cache
@@ -302,6 +665,14 @@ impl BinanceDepthStream {
 
         info!("Starting Binance depth stream for: {:?}", self.symbols);
 
+        if !self.reconcile_interval.is_zero() {
+            tokio::spawn(
+                self.snapshots
+                    .clone()
+                    .reconcile_loop(self.cache.clone(), self.reconcile_interval),
+            );
+        }
+
         loop {
             match self.connect_and_stream().await {
                 Ok(()) => {
@@ -336,6 +707,8 @@ impl BinanceDepthStream {
             .map_err(PloyError::WebSocket)?;
 
         info!("Connected to Binance depth stream");
+        // Diffs may have been missed while disconnected
+        self.cache.invalidate_all().await;
 
         let (mut write, mut read) = ws_stream.split();
         let mut ping_interval = tokio::time::interval(Duration::from_secs(PING_INTERVAL_SECS));
@@ -389,7 +762,15 @@ impl BinanceDepthStream {
 
             if let Ok(update) = serde_json::from_value::<DepthUpdate>(payload) {
                 if update.event_type == "depthUpdate" {
-                    if let Some(snapshot) = self.cache.apply_depth_update(&update).await {
+                    let (snapshot, needs_resync) = self.cache.apply_depth_update(&update).await;
+                    if needs_resync {
+                        tokio::spawn(
+                            self.snapshots
+                                .clone()
+                                .resync(self.cache.clone(), update.symbol.clone()),
+                        );
+                    }
+                    if let Some(snapshot) = snapshot {
                         let lob_update = LobUpdate {
                             symbol: update.symbol.clone(),
                             snapshot,
@@ -436,4 +817,62 @@ mod tests {
         let mid = book.mid_price().unwrap();
         assert_eq!(mid, dec!(100.05));
     }
+
+    fn diff(first: i64, last: i64, bids: &[(&str, &str)]) -> DepthUpdate {
+        DepthUpdate {
+            event_type: "depthUpdate".to_string(),
+            event_time: 0,
+            symbol: "BTCUSDT".to_string(),
+            first_update_id: first,
+            final_update_id: last,
+            bids: bids
+                .iter()
+                .map(|(p, q)| (p.to_string(), q.to_string()))
+                .collect(),
+            asks: Vec::new(),
+        }
+    }
+
+    #[test]
+    fn test_snapshot_replay_and_sequence_gaps() {
+        let snapshot = DepthSnapshot {
+            last_update_id: 100,
+            bids: vec![("100.00".to_string(), "1".to_string())],
+            asks: vec![("100.10".to_string(), "1".to_string())],
+        };
+        let diffs = [
+            diff(95, 100, &[("99.00", "5")]), // already in the snapshot
+            diff(99, 102, &[("100.00", "2")]),
+            diff(103, 104, &[("99.90", "3")]),
+        ];
+
+        let book = OrderBookState::from_snapshot(&snapshot, &diffs).unwrap();
+        assert_eq!(book.last_update_id, 104);
+        assert_eq!(book.bids.get(&10000), Some(&dec!(2)));
+        assert!(!book.bids.contains_key(&9900));
+        assert_eq!(
+            book.check_sequence(&diff(106, 107, &[])),
+            SequenceCheck::Gap {
+                expected: 105,
+                got: 106
+            }
+        );
+        assert_eq!(
+            book.check_sequence(&diff(100, 104, &[])),
+            SequenceCheck::Stale
+        );
+
+        // A snapshot older than the first buffered diff cannot be replayed.
+        let stale = DepthSnapshot {
+            last_update_id: 90,
+            ..snapshot.clone()
+        };
+        assert!(OrderBookState::from_snapshot(&stale, &diffs).is_none());
+
+        // Same levels hash the same; a drifted level does not.
+        let mut drifted = book.clone();
+        assert_eq!(drifted.checksum(20), book.checksum(20));
+        drifted.bids.insert(9990, dec!(4));
+        assert_ne!(drifted.checksum(20), book.checksum(20));
+    }
 }
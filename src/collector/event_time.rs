@@ -0,0 +1,246 @@
+//! Event-time alignment across feeds.
+//!
+//! Binance stamps depth updates with exchange event time while Polymarket
+//! quotes arrive on their own schedule, so joining "whatever was latest on
+//! arrival" can pair a book with a quote from the future. The aligner buffers
+//! keyed events by event time and emits fixed bars only once the watermark
+//! (`now - max_skew`) has passed the bar end; each bar carries the as-of value
+//! of every key at that instant and nothing later.
+//!
+//! Event times more than `max_skew` away from local arrival are restamped into
+//! the skew band, which is what makes the watermark safe: no event accepted
+//! after `now` can carry an event time before `now - max_skew`.
+
+use std::collections::{BTreeMap, HashMap};
+
+use chrono::{DateTime, Duration, Utc};
+use serde::{Deserialize, Serialize};
+
+/// Most bars one `drain` emits; older ones in a longer backlog are skipped.
+const MAX_BARS_PER_DRAIN: i64 = 10_000;
+
+/// Alignment settings.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+pub struct EventTimeConfig {
+    /// Bar length (ms)
+    pub bar_interval_ms: i64,
+    /// Largest tolerated gap between a source's event time and local arrival (ms)
+    pub max_skew_ms: i64,
+    /// Values older than this at bar end are left out of the bar (ms)
+    pub max_staleness_ms: i64,
+}
+
+impl Default for EventTimeConfig {
+    fn default() -> Self {
+        Self {
+            bar_interval_ms: 100,
+            max_skew_ms: 500,
+            max_staleness_ms: 5_000,
+        }
+    }
+}
+
+/// Counters for monitoring alignment quality.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Serialize)]
+pub struct AlignmentStats {
+    pub accepted: u64,
+    /// Event time restamped into the skew band
+    pub skew_clamped: u64,
+    /// Arrived after its bar was already emitted
+    pub late_dropped: u64,
+    pub bars_emitted: u64,
+    pub bars_skipped: u64,
+}
+
+/// As-of state of every key at `bar_end`.
+#[derive(Debug, Clone)]
+pub struct AlignedBar<V> {
+    pub bar_end: DateTime<Utc>,
+    /// key -> (event time, value), only values no older than `max_staleness_ms`
+    pub values: HashMap<String, (DateTime<Utc>, V)>,
+}
+
+impl<V> AlignedBar<V> {
+    pub fn get(&self, key: &str) -> Option<&V> {
+        self.values.get(key).map(|(_, v)| v)
+    }
+}
+
+/// Buffers keyed events and releases watermark-complete bars.
+#[derive(Debug)]
+pub struct EventTimeAligner<V> {
+    config: EventTimeConfig,
+    /// (event time, arrival sequence) -> (key, value)
+    pending: BTreeMap<(DateTime<Utc>, u64), (String, V)>,
+    state: HashMap<String, (DateTime<Utc>, V)>,
+    /// End of the next bar to emit
+    next_bar_end: Option<DateTime<Utc>>,
+    seq: u64,
+    stats: AlignmentStats,
+}
+
+impl<V: Clone> EventTimeAligner<V> {
+    pub fn new(config: EventTimeConfig) -> Self {
+        Self {
+            config: EventTimeConfig {
+                bar_interval_ms: config.bar_interval_ms.max(1),
+                max_skew_ms: config.max_skew_ms.max(0),
+                max_staleness_ms: config.max_staleness_ms.max(0),
+            },
+            pending: BTreeMap::new(),
+            state: HashMap::new(),
+            next_bar_end: None,
+            seq: 0,
+            stats: AlignmentStats::default(),
+        }
+    }
+
+    pub fn stats(&self) -> AlignmentStats {
+        self.stats
+    }
+
+    fn bar_ceil(&self, ts: DateTime<Utc>) -> DateTime<Utc> {
+        let interval = self.config.bar_interval_ms;
+        let ms = ts.timestamp_millis();
+        let ceil = (ms + interval - 1).div_euclid(interval) * interval;
+        DateTime::from_timestamp_millis(ceil).unwrap_or(ts)
+    }
+
+    /// Accept an event. Returns `false` when it arrived after its bar closed.
+    pub fn push(
+        &mut self,
+        key: impl Into<String>,
+        mut event_time: DateTime<Utc>,
+        arrival: DateTime<Utc>,
+        value: V,
+    ) -> bool {
+        let skew = Duration::milliseconds(self.config.max_skew_ms);
+        if event_time > arrival + skew {
+            event_time = arrival;
+            self.stats.skew_clamped += 1;
+        } else if event_time < arrival - skew {
+            event_time = arrival - skew;
+            self.stats.skew_clamped += 1;
+        }
+
+        match self.next_bar_end {
+            // Bars up to and including `next_bar_end - interval` are already out.
+            Some(next)
+                if event_time <= next - Duration::milliseconds(self.config.bar_interval_ms) =>
+            {
+                self.stats.late_dropped += 1;
+                return false;
+            }
+            Some(_) => {}
+            None => self.next_bar_end = Some(self.bar_ceil(event_time)),
+        }
+
+        self.seq += 1;
+        self.pending
+            .insert((event_time, self.seq), (key.into(), value));
+        self.stats.accepted += 1;
+        true
+    }
+
+    /// Emit every bar whose end is behind the watermark at `now`.
+    pub fn drain(&mut self, now: DateTime<Utc>) -> Vec<AlignedBar<V>> {
+        let interval_ms = self.config.bar_interval_ms;
+        let watermark = now - Duration::milliseconds(self.config.max_skew_ms);
+        let interval = Duration::milliseconds(interval_ms);
+        let staleness = Duration::milliseconds(self.config.max_staleness_ms);
+        let mut bars = Vec::new();
+
+        let Some(mut bar_end) = self.next_bar_end else {
+            return bars;
+        };
+        // After an idle stretch, skip the oldest bars; events they would have
+        // shown are still applied in order to the first emitted bar.
+        let backlog = (watermark - bar_end).num_milliseconds() / interval_ms;
+        if backlog > MAX_BARS_PER_DRAIN {
+            let skipped = backlog - MAX_BARS_PER_DRAIN;
+            bar_end += Duration::milliseconds(skipped * interval_ms);
+            self.stats.bars_skipped += skipped as u64;
+        }
+
+        while bar_end < watermark {
+            while let Some(entry) = self.pending.first_entry() {
+                if entry.key().0 > bar_end {
+                    break;
+                }
+                let ((event_time, _), (key, value)) = entry.remove_entry();
+                self.state.insert(key, (event_time, value));
+            }
+            self.state.retain(|_, (ts, _)| bar_end - *ts <= staleness);
+
+            bars.push(AlignedBar {
+                bar_end,
+                values: self.state.clone(),
+            });
+            bar_end += interval;
+        }
+
+        self.stats.bars_emitted += bars.len() as u64;
+        self.next_bar_end = Some(bar_end);
+        bars
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn at(ms: i64) -> DateTime<Utc> {
+        DateTime::from_timestamp_millis(1_700_000_000_000 + ms).unwrap()
+    }
+
+    #[test]
+    fn test_bars_only_see_events_at_or_before_bar_end() {
+        let mut aligner = EventTimeAligner::new(EventTimeConfig {
+            bar_interval_ms: 100,
+            max_skew_ms: 200,
+            max_staleness_ms: 1_000,
+        });
+
+        // Binance book at t=50, PM quote at t=150 that arrives before a
+        // later Binance update at t=120.
+        assert!(aligner.push("bn:BTCUSDT", at(50), at(60), 1));
+        assert!(aligner.push("pm:btc", at(150), at(155), 10));
+        assert!(aligner.push("bn:BTCUSDT", at(120), at(170), 2));
+
+        // Watermark 390 - 200 = 190: only the bar ending at 100 is complete.
+        let bars = aligner.drain(at(390));
+        assert_eq!(bars.len(), 1);
+        assert_eq!(bars[0].bar_end, at(100));
+        assert_eq!(bars[0].get("bn:BTCUSDT"), Some(&1));
+        assert_eq!(bars[0].get("pm:btc"), None);
+
+        let bars = aligner.drain(at(420));
+        assert_eq!(bars.len(), 1);
+        assert_eq!(bars[0].get("bn:BTCUSDT"), Some(&2));
+        assert_eq!(bars[0].get("pm:btc"), Some(&10));
+
+        // An event for a bar that is already out is dropped, not back-filled.
+        assert!(!aligner.push("pm:btc", at(180), at(380), 11));
+        assert_eq!(aligner.stats().late_dropped, 1);
+    }
+
+    #[test]
+    fn test_skewed_clocks_are_restamped_and_stale_values_expire() {
+        let mut aligner = EventTimeAligner::new(EventTimeConfig {
+            bar_interval_ms: 100,
+            max_skew_ms: 100,
+            max_staleness_ms: 300,
+        });
+
+        // Source clock 5s ahead: treated as arriving now.
+        assert!(aligner.push("pm:eth", at(5_000), at(10), 7));
+        assert_eq!(aligner.stats().skew_clamped, 1);
+
+        let bars = aligner.drain(at(600));
+        let ends: Vec<_> = bars.iter().map(|b| b.bar_end).collect();
+        assert_eq!(ends, vec![at(100), at(200), at(300), at(400)]);
+        assert_eq!(bars[0].get("pm:eth"), Some(&7));
+        assert_eq!(bars[2].get("pm:eth"), Some(&7));
+        assert!(bars[3].get("pm:eth").is_none());
+    }
+}
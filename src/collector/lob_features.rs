@@ -0,0 +1,135 @@
+//! Shared Binance LOB feature computation
+//!
+//! Single source of truth for the order book features used by the collector
+//! (persisted to `binance_lob_ticks` / `sync_records`) and by the live LOB
+//! agents (model observations and intent metadata). Everything works on
+//! price levels ordered best-first, so it can be fed from the in-memory
+//! [`OrderBookState`](super::OrderBookState) or from recorded JSONB levels.
+
+use rust_decimal::Decimal;
+use serde::Serialize;
+
+/// Order Book Imbalance over the top `levels` of each side
+/// OBI = (bid_volume - ask_volume) / (bid_volume + ask_volume)
+/// Range: -1 (all asks) to +1 (all bids)
+pub fn obi(
+    bids: &[(Decimal, Decimal)],
+    asks: &[(Decimal, Decimal)],
+    levels: usize,
+) -> Option<Decimal> {
+    let bid_sum = depth_volume(bids, levels);
+    let ask_sum = depth_volume(asks, levels);
+    let total = bid_sum + ask_sum;
+
+    if total.is_zero() {
+        return None;
+    }
+
+    Some((bid_sum - ask_sum) / total)
+}
+
+/// Total quantity over the top `levels` of one side
+pub fn depth_volume(side: &[(Decimal, Decimal)], levels: usize) -> Decimal {
+    side.iter().take(levels).map(|(_, q)| *q).sum()
+}
+
+/// Spread in basis points, relative to the best bid
+pub fn spread_bps(best_bid: Decimal, best_ask: Decimal) -> Option<Decimal> {
+    if best_bid.is_zero() {
+        return None;
+    }
+    Some((best_ask - best_bid) / best_bid * Decimal::from(10000))
+}
+
+/// Full LOB feature vector for one book state
+#[derive(Debug, Clone, Default, PartialEq, Serialize)]
+pub struct LobFeatures {
+    pub best_bid: Decimal,
+    pub best_ask: Decimal,
+    pub mid_price: Decimal,
+    pub spread_bps: Decimal,
+    pub obi_1: Decimal,
+    pub obi_2: Decimal,
+    pub obi_3: Decimal,
+    pub obi_5: Decimal,
+    pub obi_10: Decimal,
+    pub obi_20: Decimal,
+    /// Top-of-book pressure relative to the 5-level book (obi_1 - obi_5)
+    pub obi_micro: Decimal,
+    /// Shallow vs deep imbalance (obi_5 - obi_20)
+    pub obi_slope: Decimal,
+    pub bid_volume_5: Decimal,
+    pub ask_volume_5: Decimal,
+    /// Levels available per side (min of bids/asks)
+    pub depth: usize,
+}
+
+impl LobFeatures {
+    /// Compute features from best-first `(price, qty)` levels.
+    ///
+    /// Returns `None` when either side is empty.
+    pub fn compute(bids: &[(Decimal, Decimal)], asks: &[(Decimal, Decimal)]) -> Option<Self> {
+        let best_bid = bids.first()?.0;
+        let best_ask = asks.first()?.0;
+        let obi_at = |n| obi(bids, asks, n).unwrap_or(Decimal::ZERO);
+
+        let obi_1 = obi_at(1);
+        let obi_5 = obi_at(5);
+        let obi_20 = obi_at(20);
+
+        Some(Self {
+            best_bid,
+            best_ask,
+            mid_price: (best_bid + best_ask) / Decimal::from(2),
+            spread_bps: spread_bps(best_bid, best_ask)?,
+            obi_1,
+            obi_2: obi_at(2),
+            obi_3: obi_at(3),
+            obi_5,
+            obi_10: obi_at(10),
+            obi_20,
+            obi_micro: obi_1 - obi_5,
+            obi_slope: obi_5 - obi_20,
+            bid_volume_5: depth_volume(bids, 5),
+            ask_volume_5: depth_volume(asks, 5),
+            depth: bids.len().min(asks.len()),
+        })
+    }
+
+    /// OBI at one of the precomputed depths (1, 2, 3, 5, 10, 20)
+    pub fn obi(&self, levels: usize) -> Option<Decimal> {
+        match levels {
+            1 => Some(self.obi_1),
+            2 => Some(self.obi_2),
+            3 => Some(self.obi_3),
+            5 => Some(self.obi_5),
+            10 => Some(self.obi_10),
+            20 => Some(self.obi_20),
+            _ => None,
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use rust_decimal_macros::dec;
+
+    #[test]
+    fn test_features_from_levels() {
+        let bids = vec![(dec!(100.00), dec!(100)), (dec!(99.90), dec!(50))];
+        let asks = vec![(dec!(100.10), dec!(80)), (dec!(100.20), dec!(40))];
+
+        let f = LobFeatures::compute(&bids, &asks).unwrap();
+        assert_eq!(f.mid_price, dec!(100.05));
+        assert_eq!(f.spread_bps, dec!(10));
+        // (150 - 120) / 270
+        assert_eq!(f.obi_5, dec!(30) / dec!(270));
+        assert_eq!(f.obi_1, dec!(20) / dec!(180));
+        assert_eq!(f.obi_micro, f.obi_1 - f.obi_5);
+        assert_eq!(f.bid_volume_5, dec!(150));
+        assert_eq!(f.depth, 2);
+
+        assert!(LobFeatures::compute(&bids, &[]).is_none());
+    }
+}
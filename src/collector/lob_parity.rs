@@ -0,0 +1,262 @@
+//! Collector vs live LOB feature parity checks
+//!
+//! Replays recorded `binance_lob_ticks` rows through both feature paths and
+//! reports divergence:
+//!
+//! - **collector**: the feature columns persisted by the collector at record time
+//! - **live**: the recorded levels rebuilt into an [`OrderBookState`] and run
+//!   through [`LobFeatures`], exactly as the live agents see the book
+//!
+//! Divergence is measured as `|a - b| / max(1, |b|)`, i.e. absolute for
+//! bounded features (OBI, spread) and relative for prices and volumes.
+
+use chrono::{DateTime, Utc};
+use rust_decimal::Decimal;
+use serde::Serialize;
+use sqlx::PgPool;
+use std::collections::BTreeMap;
+use std::fmt;
+
+use super::binance_depth::OrderBookState;
+use super::lob_features::LobFeatures;
+use crate::error::Result;
+
+/// One recorded LOB tick with the collector's persisted features
+#[derive(Debug, Clone, sqlx::FromRow)]
+pub struct RecordedLobTick {
+    pub id: i64,
+    pub symbol: String,
+    pub event_time: DateTime<Utc>,
+    pub best_bid: Decimal,
+    pub best_ask: Decimal,
+    pub mid_price: Decimal,
+    pub spread_bps: Decimal,
+    pub obi_5: Decimal,
+    pub obi_10: Decimal,
+    pub bid_volume_5: Decimal,
+    pub ask_volume_5: Decimal,
+    pub bids: sqlx::types::Json<Vec<(String, String)>>,
+    pub asks: sqlx::types::Json<Vec<(String, String)>>,
+}
+
+impl RecordedLobTick {
+    /// Features as persisted by the collector
+    fn collector_features(&self) -> Vec<(&'static str, Decimal)> {
+        vec![
+            ("best_bid", self.best_bid),
+            ("best_ask", self.best_ask),
+            ("mid_price", self.mid_price),
+            ("spread_bps", self.spread_bps),
+            ("obi_5", self.obi_5),
+            ("obi_10", self.obi_10),
+            ("bid_volume_5", self.bid_volume_5),
+            ("ask_volume_5", self.ask_volume_5),
+        ]
+    }
+
+    /// Features recomputed from the recorded levels via the live path
+    fn live_features(&self) -> Option<LobFeatures> {
+        OrderBookState::from_levels(&self.bids, &self.asks).features()
+    }
+}
+
+/// Per-feature divergence statistics
+#[derive(Debug, Clone, Default, Serialize)]
+pub struct FeatureDivergence {
+    pub compared: u64,
+    pub violations: u64,
+    pub max_divergence: Decimal,
+    pub worst_tick_id: Option<i64>,
+}
+
+/// Result of a parity run
+#[derive(Debug, Clone, Default, Serialize)]
+pub struct LobParityReport {
+    pub tolerance: Decimal,
+    pub ticks: u64,
+    /// Ticks whose recorded levels could not produce a live feature vector
+    pub unusable: u64,
+    pub features: BTreeMap<&'static str, FeatureDivergence>,
+}
+
+impl LobParityReport {
+    pub fn violations(&self) -> u64 {
+        self.features.values().map(|f| f.violations).sum()
+    }
+
+    pub fn passed(&self) -> bool {
+        self.violations() == 0
+    }
+}
+
+impl fmt::Display for LobParityReport {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        writeln!(
+            f,
+            "LOB feature parity: {} ticks ({} unusable), tolerance {}",
+            self.ticks, self.unusable, self.tolerance
+        )?;
+        writeln!(
+            f,
+            "{:<14} {:>10} {:>10} {:>14} {:>12}",
+            "feature", "compared", "violations", "max_div", "worst_id"
+        )?;
+        for (name, d) in &self.features {
+            writeln!(
+                f,
+                "{:<14} {:>10} {:>10} {:>14} {:>12}",
+                name,
+                d.compared,
+                d.violations,
+                d.max_divergence.round_dp(8),
+                d.worst_tick_id
+                    .map(|id| id.to_string())
+                    .unwrap_or_else(|| "-".into())
+            )?;
+        }
+        write!(f, "result: {}", if self.passed() { "PASS" } else { "FAIL" })
+    }
+}
+
+fn divergence(a: Decimal, b: Decimal) -> Decimal {
+    (a - b).abs() / b.abs().max(Decimal::ONE)
+}
+
+/// Runs both feature implementations over recorded ticks
+#[derive(Debug, Clone)]
+pub struct LobParityChecker {
+    tolerance: Decimal,
+    report: LobParityReport,
+}
+
+impl LobParityChecker {
+    pub fn new(tolerance: Decimal) -> Self {
+        Self {
+            tolerance,
+            report: LobParityReport {
+                tolerance,
+                ..Default::default()
+            },
+        }
+    }
+
+    /// Compare one recorded tick
+    pub fn check(&mut self, tick: &RecordedLobTick) {
+        self.report.ticks += 1;
+        let Some(live) = tick.live_features() else {
+            self.report.unusable += 1;
+            return;
+        };
+
+        for (name, recorded) in tick.collector_features() {
+            // obi_10 needs 10 recorded levels per side to be reproducible
+            if name == "obi_10" && live.depth < 10 {
+                continue;
+            }
+            let recomputed = match name {
+                "best_bid" => live.best_bid,
+                "best_ask" => live.best_ask,
+                "mid_price" => live.mid_price,
+                "spread_bps" => live.spread_bps,
+                "obi_5" => live.obi_5,
+                "obi_10" => live.obi_10,
+                "bid_volume_5" => live.bid_volume_5,
+                "ask_volume_5" => live.ask_volume_5,
+                _ => continue,
+            };
+
+            let div = divergence(recomputed, recorded);
+            let entry = self.report.features.entry(name).or_default();
+            entry.compared += 1;
+            if div > self.tolerance {
+                entry.violations += 1;
+            }
+            if div > entry.max_divergence {
+                entry.max_divergence = div;
+                entry.worst_tick_id = Some(tick.id);
+            }
+        }
+    }
+
+    pub fn finish(self) -> LobParityReport {
+        self.report
+    }
+
+    /// Replay recorded ticks from `binance_lob_ticks`
+    pub async fn run(
+        pool: &PgPool,
+        symbol: Option<&str>,
+        since: DateTime<Utc>,
+        limit: i64,
+        tolerance: Decimal,
+    ) -> Result<LobParityReport> {
+        let ticks: Vec<RecordedLobTick> = sqlx::query_as(
+            r#"
+            SELECT id, symbol, event_time,
+                   best_bid, best_ask, mid_price, spread_bps,
+                   obi_5, obi_10, bid_volume_5, ask_volume_5,
+                   bids, asks
+            FROM binance_lob_ticks
+            WHERE event_time >= $1
+              AND ($2::TEXT IS NULL OR symbol = $2)
+              AND bids IS NOT NULL AND asks IS NOT NULL
+              AND jsonb_array_length(bids) > 0
+              AND jsonb_array_length(asks) > 0
+            ORDER BY event_time
+            LIMIT $3
+            "#,
+        )
+        .bind(since)
+        .bind(symbol.map(|s| s.to_uppercase()))
+        .bind(limit)
+        .fetch_all(pool)
+        .await?;
+
+        let mut checker = Self::new(tolerance);
+        for tick in &ticks {
+            checker.check(tick);
+        }
+        Ok(checker.finish())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use rust_decimal_macros::dec;
+
+    fn tick(obi_5: Decimal) -> RecordedLobTick {
+        let level = |p: &str, q: &str| (p.to_string(), q.to_string());
+        RecordedLobTick {
+            id: 1,
+            symbol: "BTCUSDT".into(),
+            event_time: Utc::now(),
+            best_bid: dec!(100.00),
+            best_ask: dec!(100.10),
+            mid_price: dec!(100.05),
+            spread_bps: dec!(10),
+            obi_5,
+            obi_10: dec!(0),
+            bid_volume_5: dec!(150),
+            ask_volume_5: dec!(120),
+            bids: sqlx::types::Json(vec![level("100.00", "100"), level("99.90", "50")]),
+            asks: sqlx::types::Json(vec![level("100.10", "80"), level("100.20", "40")]),
+        }
+    }
+
+    #[test]
+    fn test_parity_flags_divergent_obi() {
+        let mut checker = LobParityChecker::new(dec!(0.0001));
+        checker.check(&tick(dec!(30) / dec!(270)));
+        let report = checker.finish();
+        assert!(report.passed(), "{report}");
+        // Too shallow to compare obi_10
+        assert!(!report.features.contains_key("obi_10"));
+
+        let mut checker = LobParityChecker::new(dec!(0.0001));
+        checker.check(&tick(dec!(0.2)));
+        let report = checker.finish();
+        assert!(!report.passed());
+        assert_eq!(report.features["obi_5"].violations, 1);
+    }
+}
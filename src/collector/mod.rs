@@ -6,8 +6,13 @@
 pub mod backtest_collector;
 mod binance_depth;
 mod binance_klines;
+mod event_time;
+pub mod lob_features;
+mod lob_parity;
+mod polymarket_orderbook_depth;
 mod polymarket_orderbook_history;
 mod sync_collector;
+mod tick_archive;
 mod token_targets;
 
 pub use backtest_collector::{
@@ -16,6 +21,11 @@ pub use backtest_collector::{
 };
 pub use binance_depth::*;
 pub use binance_klines::*;
+pub use event_time::*;
+pub use lob_features::LobFeatures;
+pub use lob_parity::*;
+pub use polymarket_orderbook_depth::*;
 pub use polymarket_orderbook_history::*;
 pub use sync_collector::*;
+pub use tick_archive::*;
 pub use token_targets::*;
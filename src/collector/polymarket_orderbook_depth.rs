@@ -0,0 +1,628 @@
+//! Compressed N-level Polymarket orderbook depth snapshots.
+//!
+//! - Live capture: polls `GET /book` for a set of assets at a fixed cadence and
+//!   stores the top N levels per side as gzip-compressed JSON.
+//! - Backfill: `OrderbookHistoryCollector` can write the same compressed rows
+//!   while it walks `/orderbook-history`.
+//! - Replay: `OrderbookDepthReader` reconstructs the book as of any timestamp
+//!   (falling back to the uncompressed history ticks) for execution research.
+
+use std::io::{Read, Write};
+
+use chrono::{DateTime, Utc};
+use flate2::read::GzDecoder;
+use flate2::write::GzEncoder;
+use flate2::Compression;
+use reqwest::Url;
+use rust_decimal::Decimal;
+use serde::{Deserialize, Serialize};
+use sqlx::postgres::PgPool;
+use tracing::{debug, info, warn};
+
+use super::OrderbookHistoryEntry;
+use crate::adapters::polymarket_ws::PriceLevel;
+use crate::domain::OrderSide;
+use crate::error::{PloyError, Result};
+
+/// Codec tag persisted with every payload (bump if the layout changes).
+pub const DEPTH_CODEC: &str = "json+gzip/v1";
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+pub struct DepthLevel {
+    pub price: Decimal,
+    pub size: Decimal,
+}
+
+/// A depth snapshot: bids best-first (desc), asks best-first (asc).
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct DepthBook {
+    pub token_id: String,
+    pub ts_ms: i64,
+    pub bids: Vec<DepthLevel>,
+    pub asks: Vec<DepthLevel>,
+}
+
+/// Result of walking the book for a given size.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct BookSweep {
+    pub filled: Decimal,
+    pub avg_price: Decimal,
+    pub worst_price: Decimal,
+    pub levels_used: usize,
+}
+
+fn parse_levels(levels: &[PriceLevel], is_bid: bool, max_levels: usize) -> Vec<DepthLevel> {
+    let mut parsed: Vec<DepthLevel> = levels
+        .iter()
+        .filter_map(|lvl| {
+            let price = lvl.price.parse::<Decimal>().ok()?;
+            let size = lvl.size.parse::<Decimal>().ok()?;
+            (size > Decimal::ZERO).then_some(DepthLevel { price, size })
+        })
+        .collect();
+
+    if is_bid {
+        parsed.sort_by(|a, b| b.price.cmp(&a.price));
+    } else {
+        parsed.sort_by(|a, b| a.price.cmp(&b.price));
+    }
+    parsed.truncate(max_levels);
+    parsed
+}
+
+impl DepthBook {
+    pub fn from_levels(
+        token_id: &str,
+        ts_ms: i64,
+        bids: &[PriceLevel],
+        asks: &[PriceLevel],
+        max_levels: usize,
+    ) -> Self {
+        Self {
+            token_id: token_id.to_string(),
+            ts_ms,
+            bids: parse_levels(bids, true, max_levels),
+            asks: parse_levels(asks, false, max_levels),
+        }
+    }
+
+    pub fn best_bid(&self) -> Option<Decimal> {
+        self.bids.first().map(|l| l.price)
+    }
+
+    pub fn best_ask(&self) -> Option<Decimal> {
+        self.asks.first().map(|l| l.price)
+    }
+
+    pub fn mid(&self) -> Option<Decimal> {
+        Some((self.best_bid()? + self.best_ask()?) / Decimal::TWO)
+    }
+
+    /// Walk asks (buy) or bids (sell) for `shares`; `None` if that side is empty.
+    pub fn sweep(&self, side: OrderSide, shares: Decimal) -> Option<BookSweep> {
+        let levels = match side {
+            OrderSide::Buy => &self.asks,
+            OrderSide::Sell => &self.bids,
+        };
+        let mut remaining = shares;
+        let mut filled = Decimal::ZERO;
+        let mut notional = Decimal::ZERO;
+        let mut worst_price = levels.first()?.price;
+        let mut levels_used = 0;
+
+        for level in levels {
+            if remaining <= Decimal::ZERO {
+                break;
+            }
+            let take = remaining.min(level.size);
+            filled += take;
+            notional += take * level.price;
+            remaining -= take;
+            worst_price = level.price;
+            levels_used += 1;
+        }
+
+        (filled > Decimal::ZERO).then(|| BookSweep {
+            filled,
+            avg_price: notional / filled,
+            worst_price,
+            levels_used,
+        })
+    }
+}
+
+#[derive(Serialize, Deserialize)]
+struct DepthPayload {
+    b: Vec<DepthLevel>,
+    a: Vec<DepthLevel>,
+}
+
+/// Compress both sides of a book. Returns (payload, uncompressed byte count).
+pub fn encode_depth(book: &DepthBook) -> Result<(Vec<u8>, usize)> {
+    let raw = serde_json::to_vec(&DepthPayload {
+        b: book.bids.clone(),
+        a: book.asks.clone(),
+    })?;
+    let mut encoder = GzEncoder::new(Vec::with_capacity(raw.len() / 3), Compression::default());
+    encoder.write_all(&raw)?;
+    Ok((encoder.finish()?, raw.len()))
+}
+
+/// Decode a payload written by [`encode_depth`] into (bids, asks).
+pub fn decode_depth(payload: &[u8]) -> Result<(Vec<DepthLevel>, Vec<DepthLevel>)> {
+    let mut raw = Vec::new();
+    GzDecoder::new(payload).read_to_end(&mut raw)?;
+    let decoded: DepthPayload = serde_json::from_slice(&raw)?;
+    Ok((decoded.b, decoded.a))
+}
+
+/// As-of replay: for each step in `[start_ms, end_ms]`, the last snapshot at or
+/// before that time. `snapshots` must be sorted by `ts_ms` ascending.
+pub fn replay_asof(
+    snapshots: &[DepthBook],
+    start_ms: i64,
+    end_ms: i64,
+    step_ms: i64,
+) -> Vec<(i64, DepthBook)> {
+    let step_ms = step_ms.max(1);
+    let mut out = Vec::new();
+    let mut idx = 0usize;
+    let mut current: Option<&DepthBook> = None;
+    let mut t = start_ms;
+
+    while t <= end_ms {
+        while idx < snapshots.len() && snapshots[idx].ts_ms <= t {
+            current = Some(&snapshots[idx]);
+            idx += 1;
+        }
+        if let Some(book) = current {
+            out.push((t, book.clone()));
+        }
+        t = t.saturating_add(step_ms);
+    }
+    out
+}
+
+/// A compressed row ready for insert.
+#[derive(Debug, Clone)]
+pub struct DepthSnapshotRow {
+    pub condition_id: String,
+    pub hash: String,
+    pub book: DepthBook,
+}
+
+pub async fn ensure_depth_tables(pool: &PgPool) -> Result<()> {
+    sqlx::query(
+        r#"
+        CREATE TABLE IF NOT EXISTS clob_orderbook_depth_snapshots (
+            id BIGSERIAL PRIMARY KEY,
+            token_id TEXT NOT NULL,
+            condition_id TEXT NOT NULL DEFAULT '',
+            book_ts_ms BIGINT NOT NULL,
+            book_ts TIMESTAMPTZ NOT NULL,
+            hash TEXT NOT NULL DEFAULT '',
+            levels INT NOT NULL,
+            best_bid NUMERIC,
+            best_ask NUMERIC,
+            codec TEXT NOT NULL,
+            payload BYTEA NOT NULL,
+            raw_bytes INT NOT NULL,
+            source TEXT NOT NULL,
+            collected_at TIMESTAMPTZ NOT NULL DEFAULT NOW()
+        )
+        "#,
+    )
+    .execute(pool)
+    .await?;
+
+    sqlx::query(
+        r#"
+        CREATE UNIQUE INDEX IF NOT EXISTS uniq_clob_orderbook_depth_snapshots
+          ON clob_orderbook_depth_snapshots(token_id, book_ts_ms, hash)
+        "#,
+    )
+    .execute(pool)
+    .await?;
+
+    sqlx::query(
+        r#"
+        CREATE INDEX IF NOT EXISTS idx_clob_orderbook_depth_snapshots_token_time
+          ON clob_orderbook_depth_snapshots(token_id, book_ts_ms DESC)
+        "#,
+    )
+    .execute(pool)
+    .await?;
+
+    Ok(())
+}
+
+/// Insert compressed snapshots; duplicates (token, ts, hash) are skipped.
+pub async fn insert_depth_snapshots(
+    pool: &PgPool,
+    source: &str,
+    rows: &[DepthSnapshotRow],
+) -> Result<u64> {
+    if rows.is_empty() {
+        return Ok(0);
+    }
+
+    let mut encoded = Vec::with_capacity(rows.len());
+    for row in rows {
+        let (payload, raw_bytes) = encode_depth(&row.book)?;
+        let ts = DateTime::<Utc>::from_timestamp_millis(row.book.ts_ms).ok_or_else(|| {
+            PloyError::Validation(format!("invalid book timestamp {}", row.book.ts_ms))
+        })?;
+        encoded.push((row, ts, payload, raw_bytes));
+    }
+
+    let mut qb: sqlx::QueryBuilder<sqlx::Postgres> = sqlx::QueryBuilder::new(
+        r#"
+        INSERT INTO clob_orderbook_depth_snapshots (
+            token_id,
+            condition_id,
+            book_ts_ms,
+            book_ts,
+            hash,
+            levels,
+            best_bid,
+            best_ask,
+            codec,
+            payload,
+            raw_bytes,
+            source
+        )
+        "#,
+    );
+    qb.push_values(encoded.iter(), |mut b, (row, ts, payload, raw_bytes)| {
+        let levels = row.book.bids.len().max(row.book.asks.len()) as i32;
+        b.push_bind(&row.book.token_id)
+            .push_bind(&row.condition_id)
+            .push_bind(row.book.ts_ms)
+            .push_bind(*ts)
+            .push_bind(&row.hash)
+            .push_bind(levels)
+            .push_bind(row.book.best_bid())
+            .push_bind(row.book.best_ask())
+            .push_bind(DEPTH_CODEC)
+            .push_bind(payload.as_slice())
+            .push_bind(*raw_bytes as i32)
+            .push_bind(source);
+    });
+    qb.push(" ON CONFLICT (token_id, book_ts_ms, hash) DO NOTHING");
+
+    let result = qb.build().execute(pool).await?;
+    Ok(result.rows_affected())
+}
+
+#[derive(Debug, Clone)]
+pub struct OrderbookDepthRecorderConfig {
+    pub clob_base_url: String,
+    pub levels: usize,
+    /// Capture cadence per asset
+    pub interval_ms: u64,
+    /// Skip persisting a snapshot when the book hash did not change
+    pub skip_unchanged: bool,
+    pub source: String,
+}
+
+impl Default for OrderbookDepthRecorderConfig {
+    fn default() -> Self {
+        Self {
+            clob_base_url: "https://clob.polymarket.com".to_string(),
+            levels: 50,
+            interval_ms: 1000,
+            skip_unchanged: true,
+            source: "polymarket_book_depth".to_string(),
+        }
+    }
+}
+
+/// Live N-level depth capture at a fixed cadence.
+pub struct OrderbookDepthRecorder {
+    http: reqwest::Client,
+    pool: PgPool,
+    cfg: OrderbookDepthRecorderConfig,
+}
+
+impl OrderbookDepthRecorder {
+    pub fn new(pool: PgPool, cfg: OrderbookDepthRecorderConfig) -> Self {
+        Self {
+            http: reqwest::Client::new(),
+            pool,
+            cfg,
+        }
+    }
+
+    async fn fetch_book(&self, asset_id: &str) -> Result<OrderbookHistoryEntry> {
+        let mut url = Url::parse(&self.cfg.clob_base_url)
+            .map_err(|e| PloyError::Internal(format!("invalid clob_base_url: {e}")))?;
+        url.set_path("book");
+
+        let resp = self
+            .http
+            .get(url)
+            .query(&[("token_id", asset_id)])
+            .send()
+            .await?;
+        if !resp.status().is_success() {
+            let status = resp.status();
+            let body = resp.text().await.unwrap_or_default();
+            return Err(PloyError::Internal(format!(
+                "book request failed (status={status}): {body}"
+            )));
+        }
+        Ok(resp.json::<OrderbookHistoryEntry>().await?)
+    }
+
+    /// Capture one snapshot per asset. `last_hashes` tracks the previous book
+    /// hash per asset so unchanged books can be skipped.
+    pub async fn capture_once(
+        &self,
+        asset_ids: &[String],
+        last_hashes: &mut std::collections::HashMap<String, String>,
+    ) -> Result<u64> {
+        let levels = self.cfg.levels.clamp(1, 2000);
+        let mut rows = Vec::with_capacity(asset_ids.len());
+
+        for asset_id in asset_ids {
+            let entry = match self.fetch_book(asset_id).await {
+                Ok(e) => e,
+                Err(e) => {
+                    warn!(asset_id = asset_id.as_str(), error = %e, "depth snapshot fetch failed");
+                    continue;
+                }
+            };
+            let hash = entry.hash_str().to_string();
+            if self.cfg.skip_unchanged
+                && !hash.is_empty()
+                && last_hashes.get(asset_id) == Some(&hash)
+            {
+                continue;
+            }
+            let ts_ms = entry
+                .timestamp_ms()
+                .unwrap_or_else(|| Utc::now().timestamp_millis());
+            rows.push(DepthSnapshotRow {
+                condition_id: entry.market.clone(),
+                hash: hash.clone(),
+                book: DepthBook::from_levels(asset_id, ts_ms, &entry.bids, &entry.asks, levels),
+            });
+            last_hashes.insert(asset_id.clone(), hash);
+        }
+
+        insert_depth_snapshots(&self.pool, &self.cfg.source, &rows).await
+    }
+
+    /// Capture until `duration` elapses (`None` = run forever).
+    pub async fn run(
+        &self,
+        asset_ids: &[String],
+        duration: Option<std::time::Duration>,
+    ) -> Result<u64> {
+        ensure_depth_tables(&self.pool).await?;
+
+        let started = std::time::Instant::now();
+        let mut ticker = tokio::time::interval(std::time::Duration::from_millis(
+            self.cfg.interval_ms.max(100),
+        ));
+        ticker.set_missed_tick_behavior(tokio::time::MissedTickBehavior::Skip);
+        let mut last_hashes = std::collections::HashMap::new();
+        let mut inserted: u64 = 0;
+
+        loop {
+            if duration.is_some_and(|d| started.elapsed() >= d) {
+                break;
+            }
+            ticker.tick().await;
+            match self.capture_once(asset_ids, &mut last_hashes).await {
+                Ok(n) => {
+                    inserted = inserted.saturating_add(n);
+                    debug!(inserted = n, "depth snapshots captured");
+                }
+                Err(e) => warn!(error = %e, "depth snapshot insert failed"),
+            }
+        }
+
+        info!(
+            assets = asset_ids.len(),
+            inserted,
+            elapsed_secs = started.elapsed().as_secs(),
+            "depth capture finished"
+        );
+        Ok(inserted)
+    }
+}
+
+/// Reconstructs books from compressed snapshots (or history ticks as fallback).
+pub struct OrderbookDepthReader {
+    pool: PgPool,
+}
+
+impl OrderbookDepthReader {
+    pub fn new(pool: PgPool) -> Self {
+        Self { pool }
+    }
+
+    fn decode_row(token_id: &str, ts_ms: i64, payload: &[u8]) -> Result<DepthBook> {
+        let (bids, asks) = decode_depth(payload)?;
+        Ok(DepthBook {
+            token_id: token_id.to_string(),
+            ts_ms,
+            bids,
+            asks,
+        })
+    }
+
+    fn from_ticks(
+        token_id: &str,
+        ts_ms: i64,
+        bids: serde_json::Value,
+        asks: serde_json::Value,
+    ) -> Result<DepthBook> {
+        let bids: Vec<PriceLevel> = serde_json::from_value(bids)?;
+        let asks: Vec<PriceLevel> = serde_json::from_value(asks)?;
+        Ok(DepthBook::from_levels(
+            token_id,
+            ts_ms,
+            &bids,
+            &asks,
+            usize::MAX,
+        ))
+    }
+
+    /// The book as of `ts_ms`: the latest snapshot at or before that time.
+    pub async fn book_at(&self, token_id: &str, ts_ms: i64) -> Result<Option<DepthBook>> {
+        let row = sqlx::query_as::<_, (i64, Vec<u8>)>(
+            r#"
+            SELECT book_ts_ms, payload
+            FROM clob_orderbook_depth_snapshots
+            WHERE token_id = $1 AND book_ts_ms <= $2
+            ORDER BY book_ts_ms DESC
+            LIMIT 1
+            "#,
+        )
+        .bind(token_id)
+        .bind(ts_ms)
+        .fetch_optional(&self.pool)
+        .await?;
+        if let Some((book_ts_ms, payload)) = row {
+            return Self::decode_row(token_id, book_ts_ms, &payload).map(Some);
+        }
+
+        let tick = sqlx::query_as::<_, (i64, serde_json::Value, serde_json::Value)>(
+            r#"
+            SELECT book_ts_ms, bids, asks
+            FROM clob_orderbook_history_ticks
+            WHERE token_id = $1 AND book_ts_ms <= $2
+            ORDER BY book_ts_ms DESC
+            LIMIT 1
+            "#,
+        )
+        .bind(token_id)
+        .bind(ts_ms)
+        .fetch_optional(&self.pool)
+        .await?;
+        tick.map(|(book_ts_ms, bids, asks)| Self::from_ticks(token_id, book_ts_ms, bids, asks))
+            .transpose()
+    }
+
+    /// All snapshots in `[start_ms, end_ms]` plus the one in force at `start_ms`.
+    pub async fn snapshots_between(
+        &self,
+        token_id: &str,
+        start_ms: i64,
+        end_ms: i64,
+    ) -> Result<Vec<DepthBook>> {
+        let mut books: Vec<DepthBook> = self
+            .book_at(token_id, start_ms)
+            .await?
+            .into_iter()
+            .collect();
+
+        let rows = sqlx::query_as::<_, (i64, Vec<u8>)>(
+            r#"
+            SELECT book_ts_ms, payload
+            FROM clob_orderbook_depth_snapshots
+            WHERE token_id = $1 AND book_ts_ms > $2 AND book_ts_ms <= $3
+            ORDER BY book_ts_ms ASC
+            "#,
+        )
+        .bind(token_id)
+        .bind(start_ms)
+        .bind(end_ms)
+        .fetch_all(&self.pool)
+        .await?;
+
+        if rows.is_empty() {
+            let ticks = sqlx::query_as::<_, (i64, serde_json::Value, serde_json::Value)>(
+                r#"
+                SELECT book_ts_ms, bids, asks
+                FROM clob_orderbook_history_ticks
+                WHERE token_id = $1 AND book_ts_ms > $2 AND book_ts_ms <= $3
+                ORDER BY book_ts_ms ASC
+                "#,
+            )
+            .bind(token_id)
+            .bind(start_ms)
+            .bind(end_ms)
+            .fetch_all(&self.pool)
+            .await?;
+            for (ts, bids, asks) in ticks {
+                books.push(Self::from_ticks(token_id, ts, bids, asks)?);
+            }
+        } else {
+            for (ts, payload) in rows {
+                books.push(Self::decode_row(token_id, ts, &payload)?);
+            }
+        }
+
+        Ok(books)
+    }
+
+    /// Replay the book on a fixed grid (as-of semantics).
+    pub async fn replay(
+        &self,
+        token_id: &str,
+        start_ms: i64,
+        end_ms: i64,
+        step_ms: i64,
+    ) -> Result<Vec<(i64, DepthBook)>> {
+        let snapshots = self.snapshots_between(token_id, start_ms, end_ms).await?;
+        Ok(replay_asof(&snapshots, start_ms, end_ms, step_ms))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use rust_decimal_macros::dec;
+
+    fn level(price: &str, size: &str) -> PriceLevel {
+        PriceLevel {
+            price: price.to_string(),
+            size: size.to_string(),
+        }
+    }
+
+    fn book(ts_ms: i64) -> DepthBook {
+        DepthBook::from_levels(
+            "tok",
+            ts_ms,
+            &[
+                level("0.48", "100"),
+                level("0.50", "50"),
+                level("0.49", "0"),
+            ],
+            &[level("0.53", "200"), level("0.52", "40")],
+            10,
+        )
+    }
+
+    #[test]
+    fn test_depth_roundtrip_and_sweep() {
+        let b = book(1_000);
+        assert_eq!(b.best_bid(), Some(dec!(0.50)));
+        assert_eq!(b.best_ask(), Some(dec!(0.52)));
+        assert_eq!(b.bids.len(), 2); // zero-size level dropped
+
+        let (payload, raw_bytes) = encode_depth(&b).unwrap();
+        assert!(raw_bytes > 0);
+        let (bids, asks) = decode_depth(&payload).unwrap();
+        assert_eq!(bids, b.bids);
+        assert_eq!(asks, b.asks);
+
+        let sweep = b.sweep(OrderSide::Buy, dec!(100)).unwrap();
+        assert_eq!(sweep.filled, dec!(100));
+        assert_eq!(sweep.levels_used, 2);
+        assert_eq!(sweep.worst_price, dec!(0.53));
+        assert_eq!(sweep.avg_price, dec!(0.526)); // (40*0.52 + 60*0.53) / 100
+    }
+
+    #[test]
+    fn test_replay_asof_uses_last_snapshot() {
+        let snapshots = vec![book(1_000), book(2_500)];
+        let grid = replay_asof(&snapshots, 500, 3_000, 1_000);
+        let stamps: Vec<(i64, i64)> = grid.iter().map(|(t, b)| (*t, b.ts_ms)).collect();
+        assert_eq!(stamps, vec![(1_500, 1_000), (2_500, 2_500)]);
+    }
+}
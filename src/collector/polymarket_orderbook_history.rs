@@ -7,6 +7,8 @@
 //!
 //! This collector can backfill or continuously harvest L2 snapshots for one or
 //! more assets and persist them into Postgres for research/backtesting.
+//! With `store_compressed_depth`, each sampled snapshot is also written to
+//! `clob_orderbook_depth_snapshots` (see `polymarket_orderbook_depth`).
 
 use chrono::{DateTime, Utc};
 use reqwest::Url;
@@ -15,6 +17,7 @@ use serde::Deserialize;
 use sqlx::postgres::PgPool;
 use tracing::{debug, info, warn};
 
+use super::{ensure_depth_tables, insert_depth_snapshots, DepthBook, DepthSnapshotRow};
 use crate::adapters::polymarket_ws::PriceLevel;
 use crate::error::Result;
 
@@ -28,6 +31,8 @@ pub struct OrderbookHistoryCollectorConfig {
     pub page_limit: usize,
     pub max_pages: usize,
     pub source: String,
+    /// Also persist compressed N-level snapshots for depth replay.
+    pub store_compressed_depth: bool,
 }
 
 impl Default for OrderbookHistoryCollectorConfig {
@@ -39,6 +44,7 @@ impl Default for OrderbookHistoryCollectorConfig {
             page_limit: 500,
             max_pages: 50,
             source: "polymarket_orderbook_history".to_string(),
+            store_compressed_depth: false,
         }
     }
 }
@@ -189,6 +195,10 @@ impl OrderbookHistoryCollector {
         .execute(&self.pool)
         .await?;
 
+        if self.cfg.store_compressed_depth {
+            ensure_depth_tables(&self.pool).await?;
+        }
+
         Ok(())
     }
 
@@ -341,6 +351,7 @@ impl OrderbookHistoryCollector {
                 Vec<DepthLevelJson>,
                 Vec<DepthLevelJson>,
             )> = Vec::with_capacity(resp.data.len());
+            let mut depth_rows: Vec<DepthSnapshotRow> = Vec::new();
 
             for entry in resp.data {
                 let Some(ts_ms) = entry.timestamp_ms() else {
@@ -365,6 +376,20 @@ impl OrderbookHistoryCollector {
                     .as_deref()
                     .unwrap_or_else(|| entry.market.as_str());
 
+                if self.cfg.store_compressed_depth {
+                    depth_rows.push(DepthSnapshotRow {
+                        condition_id: condition.to_string(),
+                        hash: hash.clone(),
+                        book: DepthBook::from_levels(
+                            &entry.asset_id,
+                            ts_ms,
+                            &entry.bids,
+                            &entry.asks,
+                            levels,
+                        ),
+                    });
+                }
+
                 batch.push((
                     entry.asset_id,
                     condition.to_string(),
@@ -382,6 +407,11 @@ impl OrderbookHistoryCollector {
             }
 
             inserted = inserted.saturating_add(self.insert_rows(&batch).await?);
+            if !depth_rows.is_empty() {
+                let compressed =
+                    insert_depth_snapshots(&self.pool, &self.cfg.source, &depth_rows).await?;
+                debug!(asset_id, compressed, "stored compressed depth snapshots");
+            }
 
             offset = offset.saturating_add(limit);
             if resp.count > 0 && offset as i64 >= resp.count {
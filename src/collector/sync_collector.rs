@@ -2,6 +2,10 @@
 //!
 //! Collects and aligns Binance LOB data with Polymarket prices
 //! to analyze the lead-lag relationship for the 15-min crypto prediction strategy.
+//!
+//! Both feeds go through an [`EventTimeAligner`]: one record per symbol is
+//! emitted per `snapshot_interval_ms` bar, joining the book and quotes as of
+//! the bar end by event time, so a record never sees data from after it.
 
 use chrono::{DateTime, Utc};
 use rust_decimal::Decimal;
@@ -11,7 +15,8 @@ use std::sync::Arc;
 use tokio::sync::{broadcast, RwLock};
 use tracing::{debug, error, info, warn};
 
-use super::binance_depth::{BinanceDepthStream, LobUpdate};
+use super::binance_depth::{BinanceDepthStream, LobSnapshot, LobUpdate};
+use super::event_time::{AlignedBar, EventTimeAligner, EventTimeConfig};
 use crate::error::Result;
 
 /// Synchronized price record for lag analysis
@@ -59,6 +64,11 @@ pub struct SyncCollectorConfig {
     pub polymarket_slugs: Vec<String>,
     /// How often to save snapshots (milliseconds)
     pub snapshot_interval_ms: u64,
+    /// Largest tolerated gap between a feed's event time and local arrival (ms);
+    /// records are held back by this much
+    pub max_skew_ms: i64,
+    /// Polymarket quotes older than this at record time are left out (ms)
+    pub max_staleness_ms: i64,
     /// Database connection string
     pub database_url: String,
 }
@@ -69,11 +79,23 @@ impl Default for SyncCollectorConfig {
             binance_symbols: vec!["BTCUSDT".to_string()],
             polymarket_slugs: vec![],
             snapshot_interval_ms: 100, // 100ms = 10 snapshots/sec
+            max_skew_ms: 500,
+            max_staleness_ms: 5_000,
             database_url: String::new(),
         }
     }
 }
 
+/// Feed value buffered by the aligner.
+#[derive(Debug, Clone)]
+enum FeedValue {
+    Lob(LobSnapshot),
+    Pm(PolymarketPrice),
+}
+
+const LOB_KEY_PREFIX: &str = "bn:";
+const PM_KEY_PREFIX: &str = "pm:";
+
 /// Price history for momentum calculation
 #[derive(Debug, Clone, Default)]
 struct PriceHistory {
@@ -197,7 +219,7 @@ pub struct SyncCollector {
     config: SyncCollectorConfig,
     depth_stream: Arc<BinanceDepthStream>,
     price_histories: Arc<RwLock<std::collections::HashMap<String, PriceHistory>>>,
-    polymarket_prices: Arc<RwLock<std::collections::HashMap<String, PolymarketPrice>>>,
+    aligner: Arc<RwLock<EventTimeAligner<FeedValue>>>,
     record_tx: broadcast::Sender<SyncRecord>,
     pool: Option<PgPool>,
 }
@@ -207,12 +229,17 @@ impl SyncCollector {
     pub fn new(config: SyncCollectorConfig) -> Self {
         let depth_stream = Arc::new(BinanceDepthStream::new(config.binance_symbols.clone()));
         let (record_tx, _) = broadcast::channel(10000);
+        let aligner = EventTimeAligner::new(EventTimeConfig {
+            bar_interval_ms: config.snapshot_interval_ms.max(1) as i64,
+            max_skew_ms: config.max_skew_ms,
+            max_staleness_ms: config.max_staleness_ms,
+        });
 
         Self {
             config,
             depth_stream,
             price_histories: Arc::new(RwLock::new(std::collections::HashMap::new())),
-            polymarket_prices: Arc::new(RwLock::new(std::collections::HashMap::new())),
+            aligner: Arc::new(RwLock::new(aligner)),
             record_tx,
             pool: None,
         }
@@ -231,8 +258,12 @@ impl SyncCollector {
 
     /// Update Polymarket price (call this from Polymarket WS handler)
     pub async fn update_polymarket_price(&self, price: PolymarketPrice) {
-        let mut prices = self.polymarket_prices.write().await;
-        prices.insert(price.market_slug.clone(), price);
+        let key = format!("{PM_KEY_PREFIX}{}", price.market_slug);
+        let event_time = price.timestamp;
+        let mut aligner = self.aligner.write().await;
+        if !aligner.push(key, event_time, Utc::now(), FeedValue::Pm(price)) {
+            debug!("Dropped late Polymarket price");
+        }
     }
 
     /// Get LOB cache reference
@@ -260,42 +291,93 @@ impl SyncCollector {
             }
         });
 
-        // Process LOB updates
+        // Buffer LOB updates by event time; emit aligned bars as the watermark passes
+        let mut ticker = tokio::time::interval(std::time::Duration::from_millis(
+            self.config.snapshot_interval_ms.max(1),
+        ));
+        ticker.set_missed_tick_behavior(tokio::time::MissedTickBehavior::Skip);
         loop {
-            match lob_rx.recv().await {
-                Ok(update) => {
-                    self.process_lob_update(update).await;
-                }
-                Err(broadcast::error::RecvError::Lagged(n)) => {
-                    warn!("Sync collector lagged {} messages", n);
-                }
-                Err(broadcast::error::RecvError::Closed) => {
-                    info!("LOB channel closed");
-                    break;
+            tokio::select! {
+                received = lob_rx.recv() => match received {
+                    Ok(update) => self.process_lob_update(update).await,
+                    Err(broadcast::error::RecvError::Lagged(n)) => {
+                        warn!("Sync collector lagged {} messages", n);
+                    }
+                    Err(broadcast::error::RecvError::Closed) => {
+                        info!("LOB channel closed");
+                        break;
+                    }
+                },
+                _ = ticker.tick() => {
+                    let bars = self.aligner.write().await.drain(Utc::now());
+                    for bar in &bars {
+                        self.emit_bar(bar).await;
+                    }
                 }
             }
         }
 
+        let stats = self.aligner.read().await.stats();
+        info!(
+            accepted = stats.accepted,
+            late_dropped = stats.late_dropped,
+            skew_clamped = stats.skew_clamped,
+            bars = stats.bars_emitted,
+            "Sync collector alignment stats"
+        );
         Ok(())
     }
 
-    /// Process a LOB update and generate sync record
+    /// Buffer a LOB update, stamped with its exchange event time
     async fn process_lob_update(&self, update: LobUpdate) {
-        let snapshot = &update.snapshot;
+        let key = format!("{LOB_KEY_PREFIX}{}", update.symbol);
+        let event_time = update.snapshot.timestamp;
+        let mut aligner = self.aligner.write().await;
+        if !aligner.push(key, event_time, Utc::now(), FeedValue::Lob(update.snapshot)) {
+            debug!(symbol = %update.symbol, "Dropped late LOB update");
+        }
+    }
 
+    /// Generate one sync record per symbol from an aligned bar
+    async fn emit_bar(&self, bar: &AlignedBar<FeedValue>) {
+        let pm_prices: std::collections::HashMap<String, PolymarketPrice> = bar
+            .values
+            .iter()
+            .filter_map(|(_, (_, value))| match value {
+                FeedValue::Pm(price) => Some((price.market_slug.clone(), price.clone())),
+                FeedValue::Lob(_) => None,
+            })
+            .collect();
+
+        for symbol in &self.config.binance_symbols {
+            let key = format!("{LOB_KEY_PREFIX}{symbol}");
+            if let Some(FeedValue::Lob(snapshot)) = bar.get(&key) {
+                self.emit_record(bar.bar_end, symbol, snapshot, &pm_prices)
+                    .await;
+            }
+        }
+    }
+
+    async fn emit_record(
+        &self,
+        bar_end: DateTime<Utc>,
+        symbol: &str,
+        snapshot: &LobSnapshot,
+        pm_prices: &std::collections::HashMap<String, PolymarketPrice>,
+    ) {
         // Update price history
         {
             let mut histories = self.price_histories.write().await;
             let history = histories
-                .entry(update.symbol.clone())
+                .entry(symbol.to_string())
                 .or_insert_with(|| PriceHistory::new(600)); // 60 seconds at 10/sec
-            history.push(snapshot.timestamp, snapshot.mid_price);
+            history.push(bar_end, snapshot.mid_price);
         }
 
         // Get momentum signals
         let (price_change_1s, price_change_5s, momentum) = {
             let histories = self.price_histories.read().await;
-            if let Some(history) = histories.get(&update.symbol) {
+            if let Some(history) = histories.get(symbol) {
                 (
                     history.momentum(1),
                     history.momentum(5),
@@ -307,15 +389,12 @@ impl SyncCollector {
         };
 
         // Get Polymarket prices (symbol -> slug mapping, deterministic and safe for unknown symbols).
-        let (pm_yes, pm_no, pm_slug) = {
-            let prices = self.polymarket_prices.read().await;
-            select_polymarket_price_for_symbol(&update.symbol, &prices)
-        };
+        let (pm_yes, pm_no, pm_slug) = select_polymarket_price_for_symbol(symbol, pm_prices);
 
         // Create sync record
         let record = SyncRecord {
-            timestamp: snapshot.timestamp,
-            symbol: update.symbol.clone(),
+            timestamp: bar_end,
+            symbol: symbol.to_string(),
             bn_mid_price: snapshot.mid_price,
             bn_best_bid: snapshot.best_bid,
             bn_best_ask: snapshot.best_ask,
@@ -0,0 +1,761 @@
+//! Compact binary archive for quote and LOB history.
+//!
+//! JSON/NUMERIC rows are expensive to store and slow to scan for backtests.
+//! The archive keeps one zstd-compressed block per (kind, token, UTC day):
+//!
+//! - Quotes are stored column-wise: delta-encoded timestamps, then each price /
+//!   size column as fixed-point zigzag varint deltas with a presence column.
+//! - Depth books are stored row-wise: timestamp delta, level counts, then each
+//!   level as a price delta against the previous level plus its size.
+//!
+//! Prices use 6 decimals and sizes 8 (the precision of the source tables);
+//! values that would lose precision are rejected rather than rounded.
+//!
+//! `migrate_quote_ticks` / `migrate_depth_history` copy existing Postgres
+//! history into the archive, e.g. before old tick partitions are dropped.
+
+use std::collections::BTreeMap;
+use std::path::{Path, PathBuf};
+
+use chrono::{DateTime, Duration, NaiveDate, Utc};
+use rust_decimal::prelude::ToPrimitive;
+use rust_decimal::Decimal;
+use serde::{Deserialize, Serialize};
+use sqlx::postgres::PgPool;
+use tracing::info;
+
+use super::{decode_depth, DepthBook, DepthLevel};
+use crate::adapters::polymarket_ws::PriceLevel;
+use crate::domain::Side;
+use crate::error::{PloyError, Result};
+
+/// Codec tag of archive blocks (bump if the layout changes).
+pub const ARCHIVE_CODEC: &str = "ploy-bin+zstd/v1";
+
+const MAGIC: &[u8; 4] = b"PTA1";
+const KIND_QUOTES: u8 = 1;
+const KIND_DEPTH: u8 = 2;
+const ZSTD_LEVEL: i32 = 9;
+const PRICE_SCALE: u32 = 6;
+const SIZE_SCALE: u32 = 8;
+
+/// One top-of-book quote update.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct QuoteTick {
+    pub token_id: String,
+    pub side: Side,
+    pub ts_ms: i64,
+    pub best_bid: Option<Decimal>,
+    pub best_ask: Option<Decimal>,
+    pub bid_size: Option<Decimal>,
+    pub ask_size: Option<Decimal>,
+}
+
+/// What an archive write or migration produced.
+#[derive(Debug, Clone, Default, Serialize)]
+pub struct ArchiveStats {
+    pub records: u64,
+    pub files: u64,
+    /// Size of the same records as JSON
+    pub json_bytes: u64,
+    /// Compressed size of the written files
+    pub archive_bytes: u64,
+}
+
+impl ArchiveStats {
+    pub fn compression_ratio(&self) -> Option<f64> {
+        (self.archive_bytes > 0).then(|| self.json_bytes as f64 / self.archive_bytes as f64)
+    }
+
+    fn add(&mut self, other: &ArchiveStats) {
+        self.records += other.records;
+        self.files += other.files;
+        self.json_bytes += other.json_bytes;
+        self.archive_bytes += other.archive_bytes;
+    }
+}
+
+// ---------------------------------------------------------------------------
+// Block codec
+// ---------------------------------------------------------------------------
+
+fn zigzag(v: i64) -> u64 {
+    ((v << 1) ^ (v >> 63)) as u64
+}
+
+fn unzigzag(v: u64) -> i64 {
+    ((v >> 1) as i64) ^ -((v & 1) as i64)
+}
+
+fn to_fixed(value: Decimal, scale: u32) -> Result<i64> {
+    let scaled = value * Decimal::from(10i64.pow(scale));
+    if !scaled.fract().is_zero() {
+        return Err(PloyError::Validation(format!(
+            "{} has more than {} decimals",
+            value, scale
+        )));
+    }
+    scaled
+        .to_i64()
+        .ok_or_else(|| PloyError::Validation(format!("{} out of archive range", value)))
+}
+
+fn from_fixed(value: i64, scale: u32) -> Decimal {
+    Decimal::new(value, scale).normalize()
+}
+
+#[derive(Default)]
+struct BlockWriter {
+    buf: Vec<u8>,
+}
+
+impl BlockWriter {
+    fn header(kind: u8, token_id: &str, count: usize) -> Self {
+        let mut w = Self::default();
+        w.buf.extend_from_slice(MAGIC);
+        w.buf.push(kind);
+        w.varint(token_id.len() as u64);
+        w.buf.extend_from_slice(token_id.as_bytes());
+        w.varint(count as u64);
+        w
+    }
+
+    fn varint(&mut self, mut v: u64) {
+        while v >= 0x80 {
+            self.buf.push((v as u8) | 0x80);
+            v >>= 7;
+        }
+        self.buf.push(v as u8);
+    }
+
+    fn signed(&mut self, v: i64) {
+        self.varint(zigzag(v));
+    }
+
+    /// Presence bytes, then deltas of the present values
+    fn optional_column(&mut self, values: &[Option<i64>]) {
+        self.buf
+            .extend(values.iter().map(|v| u8::from(v.is_some())));
+        let mut prev = 0i64;
+        for v in values.iter().flatten() {
+            self.signed(v - prev);
+            prev = *v;
+        }
+    }
+
+    fn finish(self) -> Result<Vec<u8>> {
+        Ok(zstd::encode_all(self.buf.as_slice(), ZSTD_LEVEL)?)
+    }
+}
+
+struct BlockReader {
+    buf: Vec<u8>,
+    pos: usize,
+}
+
+impl BlockReader {
+    /// Decompress and check the header; returns the reader, token id and record count
+    fn open(payload: &[u8], kind: u8) -> Result<(Self, String, usize)> {
+        let buf = zstd::decode_all(payload)?;
+        if buf.len() < 5 || &buf[..4] != MAGIC || buf[4] != kind {
+            return Err(PloyError::Validation(
+                "not a tick archive block of the expected kind".to_string(),
+            ));
+        }
+        let mut r = Self { buf, pos: 5 };
+        let len = r.varint()? as usize;
+        let token_id = String::from_utf8(r.bytes(len)?.to_vec())
+            .map_err(|e| PloyError::Validation(format!("archive token id: {}", e)))?;
+        let count = r.varint()? as usize;
+        Ok((r, token_id, count))
+    }
+
+    fn bytes(&mut self, n: usize) -> Result<&[u8]> {
+        let end = self.pos.checked_add(n).filter(|end| *end <= self.buf.len());
+        let end =
+            end.ok_or_else(|| PloyError::Validation("truncated archive block".to_string()))?;
+        let out = &self.buf[self.pos..end];
+        self.pos = end;
+        Ok(out)
+    }
+
+    fn varint(&mut self) -> Result<u64> {
+        let mut v = 0u64;
+        for shift in (0..64).step_by(7) {
+            let byte = self.bytes(1)?[0];
+            v |= u64::from(byte & 0x7f) << shift;
+            if byte & 0x80 == 0 {
+                return Ok(v);
+            }
+        }
+        Err(PloyError::Validation("archive varint overflow".to_string()))
+    }
+
+    fn signed(&mut self) -> Result<i64> {
+        self.varint().map(unzigzag)
+    }
+
+    fn optional_column(&mut self, count: usize, scale: u32) -> Result<Vec<Option<Decimal>>> {
+        let present = self.bytes(count)?.to_vec();
+        let mut prev = 0i64;
+        present
+            .into_iter()
+            .map(|p| {
+                if p == 0 {
+                    return Ok(None);
+                }
+                prev += self.signed()?;
+                Ok(Some(from_fixed(prev, scale)))
+            })
+            .collect()
+    }
+}
+
+fn fixed_column(
+    values: impl Iterator<Item = Option<Decimal>>,
+    scale: u32,
+) -> Result<Vec<Option<i64>>> {
+    values
+        .map(|v| v.map(|d| to_fixed(d, scale)).transpose())
+        .collect()
+}
+
+/// Encode quotes of a single token (in timestamp order) into one block.
+pub fn encode_quote_block(ticks: &[QuoteTick]) -> Result<Vec<u8>> {
+    let token_id = ticks.first().map(|t| t.token_id.as_str()).unwrap_or("");
+    if ticks.iter().any(|t| t.token_id != token_id) {
+        return Err(PloyError::Validation(
+            "a quote block holds a single token".to_string(),
+        ));
+    }
+
+    let mut w = BlockWriter::header(KIND_QUOTES, token_id, ticks.len());
+    let mut prev_ts = 0i64;
+    for tick in ticks {
+        w.signed(tick.ts_ms - prev_ts);
+        prev_ts = tick.ts_ms;
+    }
+    w.buf
+        .extend(ticks.iter().map(|t| u8::from(t.side == Side::Down)));
+    w.optional_column(&fixed_column(
+        ticks.iter().map(|t| t.best_bid),
+        PRICE_SCALE,
+    )?);
+    w.optional_column(&fixed_column(
+        ticks.iter().map(|t| t.best_ask),
+        PRICE_SCALE,
+    )?);
+    w.optional_column(&fixed_column(ticks.iter().map(|t| t.bid_size), SIZE_SCALE)?);
+    w.optional_column(&fixed_column(ticks.iter().map(|t| t.ask_size), SIZE_SCALE)?);
+    w.finish()
+}
+
+/// Decode a block written by [`encode_quote_block`].
+pub fn decode_quote_block(payload: &[u8]) -> Result<Vec<QuoteTick>> {
+    let (mut r, token_id, count) = BlockReader::open(payload, KIND_QUOTES)?;
+    let mut ts = Vec::with_capacity(count);
+    let mut prev_ts = 0i64;
+    for _ in 0..count {
+        prev_ts += r.signed()?;
+        ts.push(prev_ts);
+    }
+    let sides = r.bytes(count)?.to_vec();
+    let bids = r.optional_column(count, PRICE_SCALE)?;
+    let asks = r.optional_column(count, PRICE_SCALE)?;
+    let bid_sizes = r.optional_column(count, SIZE_SCALE)?;
+    let ask_sizes = r.optional_column(count, SIZE_SCALE)?;
+
+    Ok((0..count)
+        .map(|i| QuoteTick {
+            token_id: token_id.clone(),
+            side: if sides[i] == 0 { Side::Up } else { Side::Down },
+            ts_ms: ts[i],
+            best_bid: bids[i],
+            best_ask: asks[i],
+            bid_size: bid_sizes[i],
+            ask_size: ask_sizes[i],
+        })
+        .collect())
+}
+
+fn write_levels(w: &mut BlockWriter, levels: &[DepthLevel]) -> Result<()> {
+    let mut prev = 0i64;
+    for level in levels {
+        let price = to_fixed(level.price, PRICE_SCALE)?;
+        w.signed(price - prev);
+        w.signed(to_fixed(level.size, SIZE_SCALE)?);
+        prev = price;
+    }
+    Ok(())
+}
+
+fn read_levels(r: &mut BlockReader, count: usize) -> Result<Vec<DepthLevel>> {
+    let mut prev = 0i64;
+    (0..count)
+        .map(|_| {
+            prev += r.signed()?;
+            Ok(DepthLevel {
+                price: from_fixed(prev, PRICE_SCALE),
+                size: from_fixed(r.signed()?, SIZE_SCALE),
+            })
+        })
+        .collect()
+}
+
+/// Encode depth snapshots of a single token (in timestamp order) into one block.
+pub fn encode_depth_block(books: &[DepthBook]) -> Result<Vec<u8>> {
+    let token_id = books.first().map(|b| b.token_id.as_str()).unwrap_or("");
+    if books.iter().any(|b| b.token_id != token_id) {
+        return Err(PloyError::Validation(
+            "a depth block holds a single token".to_string(),
+        ));
+    }
+
+    let mut w = BlockWriter::header(KIND_DEPTH, token_id, books.len());
+    let mut prev_ts = 0i64;
+    for book in books {
+        w.signed(book.ts_ms - prev_ts);
+        prev_ts = book.ts_ms;
+        w.varint(book.bids.len() as u64);
+        w.varint(book.asks.len() as u64);
+        write_levels(&mut w, &book.bids)?;
+        write_levels(&mut w, &book.asks)?;
+    }
+    w.finish()
+}
+
+/// Decode a block written by [`encode_depth_block`].
+pub fn decode_depth_block(payload: &[u8]) -> Result<Vec<DepthBook>> {
+    let (mut r, token_id, count) = BlockReader::open(payload, KIND_DEPTH)?;
+    let mut books = Vec::with_capacity(count);
+    let mut prev_ts = 0i64;
+    for _ in 0..count {
+        prev_ts += r.signed()?;
+        let bid_count = r.varint()? as usize;
+        let ask_count = r.varint()? as usize;
+        let bids = read_levels(&mut r, bid_count)?;
+        let asks = read_levels(&mut r, ask_count)?;
+        books.push(DepthBook {
+            token_id: token_id.clone(),
+            ts_ms: prev_ts,
+            bids,
+            asks,
+        });
+    }
+    Ok(books)
+}
+
+// ---------------------------------------------------------------------------
+// File store
+// ---------------------------------------------------------------------------
+
+trait Archived: Clone + PartialEq + Serialize {
+    const DIR: &'static str;
+    fn token_id(&self) -> &str;
+    fn ts_ms(&self) -> i64;
+    fn encode(items: &[Self]) -> Result<Vec<u8>>;
+    fn decode(payload: &[u8]) -> Result<Vec<Self>>;
+}
+
+impl Archived for QuoteTick {
+    const DIR: &'static str = "quotes";
+    fn token_id(&self) -> &str {
+        &self.token_id
+    }
+    fn ts_ms(&self) -> i64 {
+        self.ts_ms
+    }
+    fn encode(items: &[Self]) -> Result<Vec<u8>> {
+        encode_quote_block(items)
+    }
+    fn decode(payload: &[u8]) -> Result<Vec<Self>> {
+        decode_quote_block(payload)
+    }
+}
+
+impl Archived for DepthBook {
+    const DIR: &'static str = "depth";
+    fn token_id(&self) -> &str {
+        &self.token_id
+    }
+    fn ts_ms(&self) -> i64 {
+        self.ts_ms
+    }
+    fn encode(items: &[Self]) -> Result<Vec<u8>> {
+        encode_depth_block(items)
+    }
+    fn decode(payload: &[u8]) -> Result<Vec<Self>> {
+        decode_depth_block(payload)
+    }
+}
+
+fn day_of(ts_ms: i64) -> NaiveDate {
+    DateTime::from_timestamp_millis(ts_ms)
+        .unwrap_or_default()
+        .date_naive()
+}
+
+/// Sort by time and drop exact duplicates (re-running a migration is a no-op)
+fn sort_dedup<T: Archived>(items: &mut Vec<T>) {
+    items.sort_by_key(T::ts_ms);
+    let mut out: Vec<T> = Vec::with_capacity(items.len());
+    let mut group_start = 0;
+    for item in items.drain(..) {
+        if out.last().map(T::ts_ms) != Some(item.ts_ms()) {
+            group_start = out.len();
+        }
+        if !out[group_start..].contains(&item) {
+            out.push(item);
+        }
+    }
+    *items = out;
+}
+
+/// Day-partitioned archive of quote and depth history:
+/// `<root>/{quotes,depth}/<token_id>/<YYYY-MM-DD>.bin.zst`
+#[derive(Debug, Clone)]
+pub struct TickArchive {
+    root: PathBuf,
+}
+
+impl TickArchive {
+    pub fn new(root: impl Into<PathBuf>) -> Self {
+        Self { root: root.into() }
+    }
+
+    /// `$PLOY_ARCHIVE_DIR`, or `data/archive`
+    pub fn from_env() -> Self {
+        Self::new(std::env::var("PLOY_ARCHIVE_DIR").unwrap_or_else(|_| "data/archive".to_string()))
+    }
+
+    pub fn root(&self) -> &Path {
+        &self.root
+    }
+
+    fn file(&self, dir: &str, token_id: &str, day: NaiveDate) -> Result<PathBuf> {
+        if token_id.is_empty()
+            || !token_id
+                .chars()
+                .all(|c| c.is_ascii_alphanumeric() || c == '-' || c == '_')
+        {
+            return Err(PloyError::Validation(format!(
+                "token id {:?} is not archivable",
+                token_id
+            )));
+        }
+        Ok(self
+            .root
+            .join(dir)
+            .join(token_id)
+            .join(format!("{}.bin.zst", day.format("%Y-%m-%d"))))
+    }
+
+    fn read_file<T: Archived>(path: &Path) -> Result<Vec<T>> {
+        match std::fs::read(path) {
+            Ok(payload) => T::decode(&payload),
+            Err(e) if e.kind() == std::io::ErrorKind::NotFound => Ok(Vec::new()),
+            Err(e) => Err(e.into()),
+        }
+    }
+
+    /// Merge `items` into the matching day files
+    fn append<T: Archived>(&self, items: Vec<T>) -> Result<ArchiveStats> {
+        let mut stats = ArchiveStats {
+            records: items.len() as u64,
+            ..ArchiveStats::default()
+        };
+        let mut groups: BTreeMap<(String, NaiveDate), Vec<T>> = BTreeMap::new();
+        for item in items {
+            stats.json_bytes += serde_json::to_vec(&item)?.len() as u64;
+            groups
+                .entry((item.token_id().to_string(), day_of(item.ts_ms())))
+                .or_default()
+                .push(item);
+        }
+
+        for ((token_id, day), new_items) in groups {
+            let path = self.file(T::DIR, &token_id, day)?;
+            let mut merged = Self::read_file::<T>(&path)?;
+            merged.extend(new_items);
+            sort_dedup(&mut merged);
+            let payload = T::encode(&merged)?;
+
+            if let Some(parent) = path.parent() {
+                std::fs::create_dir_all(parent)?;
+            }
+            // Write-then-rename so readers never see a torn block
+            let tmp = path.with_extension("zst.tmp");
+            std::fs::write(&tmp, &payload)?;
+            std::fs::rename(&tmp, &path)?;
+            stats.files += 1;
+            stats.archive_bytes += payload.len() as u64;
+        }
+        Ok(stats)
+    }
+
+    fn read<T: Archived>(&self, token_id: &str, start_ms: i64, end_ms: i64) -> Result<Vec<T>> {
+        let mut out = Vec::new();
+        let mut day = day_of(start_ms);
+        while day <= day_of(end_ms) {
+            let items = Self::read_file::<T>(&self.file(T::DIR, token_id, day)?)?;
+            out.extend(
+                items
+                    .into_iter()
+                    .filter(|i| (start_ms..=end_ms).contains(&i.ts_ms())),
+            );
+            day = match day.succ_opt() {
+                Some(next) => next,
+                None => break,
+            };
+        }
+        Ok(out)
+    }
+
+    pub fn append_quotes(&self, ticks: Vec<QuoteTick>) -> Result<ArchiveStats> {
+        self.append(ticks)
+    }
+
+    pub fn append_depth(&self, books: Vec<DepthBook>) -> Result<ArchiveStats> {
+        self.append(books)
+    }
+
+    /// Quotes of `token_id` in `[start_ms, end_ms]`, in time order
+    pub fn read_quotes(
+        &self,
+        token_id: &str,
+        start_ms: i64,
+        end_ms: i64,
+    ) -> Result<Vec<QuoteTick>> {
+        self.read(token_id, start_ms, end_ms)
+    }
+
+    /// Depth snapshots of `token_id` in `[start_ms, end_ms]`, in time order
+    pub fn read_depth(&self, token_id: &str, start_ms: i64, end_ms: i64) -> Result<Vec<DepthBook>> {
+        self.read(token_id, start_ms, end_ms)
+    }
+}
+
+// ---------------------------------------------------------------------------
+// Migration from Postgres
+// ---------------------------------------------------------------------------
+
+fn day_windows(from: DateTime<Utc>, to: DateTime<Utc>) -> Vec<(DateTime<Utc>, DateTime<Utc>)> {
+    let mut windows = Vec::new();
+    let mut start = from;
+    while start < to {
+        let end = (start + Duration::days(1)).min(to);
+        windows.push((start, end));
+        start = end;
+    }
+    windows
+}
+
+/// Copy `clob_quote_ticks` rows in `[from, to)` into the archive, one day at a time.
+pub async fn migrate_quote_ticks(
+    pool: &PgPool,
+    archive: &TickArchive,
+    from: DateTime<Utc>,
+    to: DateTime<Utc>,
+) -> Result<ArchiveStats> {
+    type Row = (
+        String,
+        String,
+        DateTime<Utc>,
+        Option<Decimal>,
+        Option<Decimal>,
+        Option<Decimal>,
+        Option<Decimal>,
+    );
+
+    let mut total = ArchiveStats::default();
+    for (start, end) in day_windows(from, to) {
+        let rows: Vec<Row> = sqlx::query_as(
+            r#"
+            SELECT token_id, side, received_at, best_bid, best_ask, bid_size, ask_size
+            FROM clob_quote_ticks
+            WHERE received_at >= $1 AND received_at < $2
+            ORDER BY token_id, received_at
+            "#,
+        )
+        .bind(start)
+        .bind(end)
+        .fetch_all(pool)
+        .await?;
+
+        let ticks: Vec<QuoteTick> = rows
+            .into_iter()
+            .map(
+                |(token_id, side, received_at, best_bid, best_ask, bid_size, ask_size)| QuoteTick {
+                    token_id,
+                    side: if side == "DOWN" { Side::Down } else { Side::Up },
+                    ts_ms: received_at.timestamp_millis(),
+                    best_bid,
+                    best_ask,
+                    bid_size,
+                    ask_size,
+                },
+            )
+            .collect();
+        let stats = archive.append_quotes(ticks)?;
+        info!(
+            day = %start.format("%Y-%m-%d"),
+            records = stats.records,
+            files = stats.files,
+            "archived quote ticks"
+        );
+        total.add(&stats);
+    }
+    Ok(total)
+}
+
+/// Copy depth history in `[from, to)` into the archive: compressed snapshots
+/// (`clob_orderbook_depth_snapshots`) and raw `/orderbook-history` ticks.
+pub async fn migrate_depth_history(
+    pool: &PgPool,
+    archive: &TickArchive,
+    from: DateTime<Utc>,
+    to: DateTime<Utc>,
+) -> Result<ArchiveStats> {
+    let mut total = ArchiveStats::default();
+    for (start, end) in day_windows(from, to) {
+        let (start_ms, end_ms) = (start.timestamp_millis(), end.timestamp_millis());
+        let mut books = Vec::new();
+
+        let snapshots = sqlx::query_as::<_, (String, i64, Vec<u8>)>(
+            r#"
+            SELECT token_id, book_ts_ms, payload
+            FROM clob_orderbook_depth_snapshots
+            WHERE book_ts_ms >= $1 AND book_ts_ms < $2
+            "#,
+        )
+        .bind(start_ms)
+        .bind(end_ms)
+        .fetch_all(pool)
+        .await?;
+        for (token_id, ts_ms, payload) in snapshots {
+            let (bids, asks) = decode_depth(&payload)?;
+            books.push(DepthBook {
+                token_id,
+                ts_ms,
+                bids,
+                asks,
+            });
+        }
+
+        let ticks = sqlx::query_as::<_, (String, i64, serde_json::Value, serde_json::Value)>(
+            r#"
+            SELECT token_id, book_ts_ms, bids, asks
+            FROM clob_orderbook_history_ticks
+            WHERE book_ts_ms >= $1 AND book_ts_ms < $2
+            "#,
+        )
+        .bind(start_ms)
+        .bind(end_ms)
+        .fetch_all(pool)
+        .await?;
+        for (token_id, ts_ms, bids, asks) in ticks {
+            let bids: Vec<PriceLevel> = serde_json::from_value(bids)?;
+            let asks: Vec<PriceLevel> = serde_json::from_value(asks)?;
+            books.push(DepthBook::from_levels(
+                &token_id,
+                ts_ms,
+                &bids,
+                &asks,
+                usize::MAX,
+            ));
+        }
+
+        let stats = archive.append_depth(books)?;
+        info!(
+            day = %start.format("%Y-%m-%d"),
+            records = stats.records,
+            files = stats.files,
+            "archived depth snapshots"
+        );
+        total.add(&stats);
+    }
+    Ok(total)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use rust_decimal_macros::dec;
+
+    fn tick(ts_ms: i64, bid: Option<Decimal>, ask: Decimal) -> QuoteTick {
+        QuoteTick {
+            token_id: "123".to_string(),
+            side: Side::Down,
+            ts_ms,
+            best_bid: bid,
+            best_ask: Some(ask),
+            bid_size: bid.map(|_| dec!(1250.5)),
+            ask_size: None,
+        }
+    }
+
+    #[test]
+    fn test_blocks_roundtrip() {
+        let ticks = vec![
+            tick(1_700_000_000_000, Some(dec!(0.45)), dec!(0.47)),
+            tick(1_700_000_000_250, None, dec!(0.465)),
+            tick(1_700_000_000_250, Some(dec!(0.001)), dec!(0.999)),
+        ];
+        let block = encode_quote_block(&ticks).unwrap();
+        assert_eq!(decode_quote_block(&block).unwrap(), ticks);
+
+        let book = DepthBook {
+            token_id: "123".to_string(),
+            ts_ms: 1_700_000_000_000,
+            bids: vec![
+                DepthLevel {
+                    price: dec!(0.45),
+                    size: dec!(100),
+                },
+                DepthLevel {
+                    price: dec!(0.44),
+                    size: dec!(2500.25),
+                },
+            ],
+            asks: vec![DepthLevel {
+                price: dec!(0.47),
+                size: dec!(80),
+            }],
+        };
+        let block = encode_depth_block(&[book.clone()]).unwrap();
+        assert_eq!(decode_depth_block(&block).unwrap(), vec![book]);
+        assert!(decode_depth_block(&encode_quote_block(&ticks).unwrap()).is_err());
+
+        // Sub-tick precision is rejected rather than silently rounded
+        assert!(encode_quote_block(&[tick(0, None, dec!(0.1234567))]).is_err());
+    }
+
+    #[test]
+    fn test_archive_merges_and_reads_ranges() {
+        let dir = std::env::temp_dir().join(format!("ploy-tick-archive-{}", uuid::Uuid::new_v4()));
+        let archive = TickArchive::new(&dir);
+        let day_ms = 86_400_000;
+        let ticks = vec![
+            tick(day_ms - 1, Some(dec!(0.40)), dec!(0.42)),
+            tick(day_ms + 10, Some(dec!(0.41)), dec!(0.43)),
+        ];
+
+        let stats = archive.append_quotes(ticks.clone()).unwrap();
+        assert_eq!((stats.records, stats.files), (2, 2));
+        // Re-running is idempotent
+        archive.append_quotes(ticks.clone()).unwrap();
+
+        assert_eq!(archive.read_quotes("123", 0, 2 * day_ms).unwrap(), ticks);
+        assert_eq!(
+            archive.read_quotes("123", day_ms, 2 * day_ms).unwrap(),
+            ticks[1..].to_vec()
+        );
+        assert!(archive
+            .append_quotes(vec![QuoteTick {
+                token_id: "../x".to_string(),
+                ..ticks[0].clone()
+            }])
+            .is_err());
+        let _ = std::fs::remove_dir_all(dir);
+    }
+}
@@ -1,7 +1,23 @@
+use crate::adapters::polymarket_ws::QuoteSanitizerConfig;
+use crate::analysis::VpinConfig;
+use crate::domain::ExecutionPreference;
+use crate::exchange::latency::LatencyConfig;
+use crate::platform::Timeframe;
+use crate::strategy::calculations::MidPriceConfig;
+use config::builder::{ConfigBuilder, DefaultState};
 use config::{Config, ConfigError, Environment, File};
 use rust_decimal::Decimal;
-use serde::Deserialize;
-use std::path::Path;
+use serde::de::DeserializeOwned;
+use serde::{Deserialize, Serialize};
+use std::collections::HashMap;
+use std::path::{Path, PathBuf};
+
+pub mod secrets;
+
+pub use secrets::{SecretsConfig, SecretsProvider};
+
+/// Environment variable selecting the config profile overlay (`--profile`)
+pub const PROFILE_ENV: &str = "PLOY_PROFILE";
 
 /// Main configuration structure
 #[derive(Debug, Clone, Deserialize)]
@@ -37,6 +53,12 @@ pub struct AppConfig {
     /// Optional event registry discovery service
     #[serde(default)]
     pub event_registry: Option<DiscoveryConfig>,
+    /// Where credentials come from (env, encrypted file, AWS Secrets Manager)
+    #[serde(default)]
+    pub secrets: SecretsConfig,
+    /// Order-flow toxicity (VPIN) tuning for the coordinator risk gate
+    #[serde(default)]
+    pub toxicity: Option<VpinConfig>,
 }
 
 #[derive(Debug, Clone, Deserialize)]
@@ -146,6 +168,12 @@ pub struct EventEdgeAgentConfig {
     /// Maximum Claude turns per cycle (framework mode)
     #[serde(default = "default_event_edge_claude_max_turns")]
     pub claude_max_turns: u32,
+    /// Optional Polymarket comment sentiment feature
+    #[serde(default)]
+    pub comment_sentiment: CommentSentimentConfig,
+    /// Optional Arena leaderboard history (trend-adjusted confidence, flip alerts)
+    #[serde(default)]
+    pub arena_history: ArenaHistoryConfig,
 }
 
 impl EventEdgeAgentConfig {
@@ -197,6 +225,8 @@ impl Default for EventEdgeAgentConfig {
             max_daily_spend_usd: default_event_edge_max_daily_spend_usd(),
             model: None,
             claude_max_turns: default_event_edge_claude_max_turns(),
+            comment_sentiment: CommentSentimentConfig::default(),
+            arena_history: ArenaHistoryConfig::default(),
         }
     }
 }
@@ -233,6 +263,73 @@ fn default_event_edge_claude_max_turns() -> u32 {
     20
 }
 
+/// Polymarket comment sentiment (Grok-scored, rolling per market)
+///
+/// When enabled, the rolling index in [-1, 1] shifts the strategy's fair
+/// probability by at most `weight` once `min_comments` have been scored.
+#[derive(Debug, Clone, Deserialize)]
+#[serde(default)]
+pub struct CommentSentimentConfig {
+    pub enabled: bool,
+    /// Maximum probability shift at index ±1 (e.g. 0.03 = 3pp)
+    pub weight: Decimal,
+    /// Minimum scored comments in the window before the index is used
+    pub min_comments: usize,
+    /// Comment poll interval per monitored event
+    pub poll_interval_secs: u64,
+    /// Rolling window for the index
+    pub window_secs: u64,
+    /// Comments fetched per event per poll (newest first)
+    pub max_comments_per_poll: u32,
+}
+
+impl Default for CommentSentimentConfig {
+    fn default() -> Self {
+        Self {
+            enabled: false,
+            weight: Decimal::new(3, 2), // 0.03
+            min_comments: 5,
+            poll_interval_secs: 300,
+            window_secs: 6 * 3600,
+            max_comments_per_poll: 50,
+        }
+    }
+}
+
+/// Arena leaderboard history for event-edge
+///
+/// Snapshots are persisted to a JSON file; the score/ranking trend over the
+/// last `lookback_snapshots` scales the model confidence, and a change of the
+/// leading org among a market's outcomes raises an alert.
+#[derive(Debug, Clone, Deserialize)]
+#[serde(default)]
+pub struct ArenaHistoryConfig {
+    pub enabled: bool,
+    /// History file (default: `$PLOY_STATE_DIR/arena_text_history.json`)
+    pub path: Option<String>,
+    /// Snapshots kept on disk
+    pub max_snapshots: usize,
+    /// Snapshots considered when computing the trend
+    pub lookback_snapshots: usize,
+    /// Confidence penalty per leader flip in the lookback window
+    pub flip_penalty: f64,
+    /// Confidence boost when the leader is stable and its lead is widening
+    pub stable_boost: f64,
+}
+
+impl Default for ArenaHistoryConfig {
+    fn default() -> Self {
+        Self {
+            enabled: false,
+            path: None,
+            max_snapshots: 180,
+            lookback_snapshots: 7,
+            flip_penalty: 0.15,
+            stable_boost: 0.10,
+        }
+    }
+}
+
 /// NBA Q3→Q4 comeback trading agent configuration
 #[derive(Debug, Clone, Deserialize)]
 pub struct NbaComebackConfig {
@@ -357,6 +454,43 @@ pub struct NbaComebackConfig {
     /// Stop-loss trigger as percentage drawdown from average entry (default 20%).
     #[serde(default = "default_early_exit_stop_loss_pct")]
     pub early_exit_stop_loss_pct: f64,
+    /// Optional Polymarket comment sentiment feature
+    #[serde(default)]
+    pub comment_sentiment: CommentSentimentConfig,
+    /// Cross-source live score check required before new entries / scale-ins
+    #[serde(default)]
+    pub score_guard: LiveScoreGuardConfig,
+}
+
+/// Live score mismatch guard for sports entries
+///
+/// ESPN's score must be confirmed by at least `min_sources` fresh sources in
+/// total (ESPN included, e.g. Polymarket live-game metadata) agreeing within
+/// tolerance before an entry is placed.
+#[derive(Debug, Clone, Deserialize)]
+#[serde(default)]
+pub struct LiveScoreGuardConfig {
+    pub enabled: bool,
+    /// Sources (ESPN included) that must agree
+    pub min_sources: usize,
+    /// Maximum per-team score difference in points
+    pub score_tolerance: i32,
+    /// Maximum period difference (0 = same quarter)
+    pub period_tolerance: u8,
+    /// Observations older than this are ignored (ESPN stale => entry blocked)
+    pub max_staleness_secs: i64,
+}
+
+impl Default for LiveScoreGuardConfig {
+    fn default() -> Self {
+        Self {
+            enabled: true,
+            min_sources: 2,
+            score_tolerance: 3,
+            period_tolerance: 0,
+            max_staleness_secs: 45,
+        }
+    }
 }
 
 fn default_nba_comeback_min_edge() -> Decimal {
@@ -523,13 +657,17 @@ pub struct MarketConfig {
     /// Optional exchange-specific REST endpoint override.
     #[serde(default)]
     pub exchange_rest_url: Option<String>,
+    /// Bad-tick filtering for incoming Polymarket quotes.
+    #[serde(default)]
+    pub quote_sanitizer: QuoteSanitizerConfig,
 }
 
 #[derive(Debug, Clone, Deserialize)]
 pub struct StrategyConfig {
     /// Number of shares per leg
     pub shares: u64,
-    /// Minutes to watch for dump after round start
+    /// Minutes to watch for dump after round start (calibrated on 15m rounds;
+    /// stretched for 1h / 1d rounds, see [`StrategyConfig::watch_window_secs`])
     pub window_min: u64,
     /// Percentage drop to trigger Leg1 (e.g., 0.15 = 15%)
     pub move_pct: Decimal,
@@ -541,6 +679,16 @@ pub struct StrategyConfig {
     pub slippage_buffer: Decimal,
     /// Minimum profit target (e.g., 0.01 = 1%)
     pub profit_buffer: Decimal,
+    /// What crash recovery does with this strategy's incomplete cycles
+    #[serde(default)]
+    pub resume: CycleResumeConfig,
+    /// Detect dumps on a bounce-resistant mid estimate instead of raw best ask
+    #[serde(default)]
+    pub signal_mid: Option<MidPriceConfig>,
+    /// Scale the dump threshold with realized volatility instead of using a
+    /// fixed `move_pct`
+    #[serde(default)]
+    pub vol_threshold: Option<VolThresholdConfig>,
 }
 
 impl StrategyConfig {
@@ -549,6 +697,137 @@ impl StrategyConfig {
     pub fn effective_sum_target(&self) -> Decimal {
         self.sum_target - self.fee_buffer - self.slippage_buffer - self.profit_buffer
     }
+
+    /// Watch window for a round of `round_secs`.
+    ///
+    /// `window_min` holds as-is for 5m / 15m rounds and scales linearly with
+    /// round length for 1h / 1d rounds (2 min on 15m -> 8 min on 1h).
+    pub fn watch_window_secs(&self, round_secs: i64) -> i64 {
+        (self.window_min as f64 * 60.0 * Timeframe::window_scale(round_secs)) as i64
+    }
+}
+
+/// How crash recovery treats an incomplete cycle
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum CycleResumePolicy {
+    /// Abort every incomplete cycle (legacy behaviour)
+    #[default]
+    Abort,
+    /// Resume hedged-pending cycles and keep waiting for Leg2 at the
+    /// checkpointed sum target while the round is live
+    Resume,
+    /// Resume hedged-pending cycles and force Leg2 immediately
+    Hedge,
+}
+
+#[derive(Debug, Clone, Deserialize)]
+pub struct CycleResumeConfig {
+    #[serde(default)]
+    pub policy: CycleResumePolicy,
+    /// `resume` falls back to abort with less than this left in the round
+    #[serde(default = "default_resume_min_remaining_secs")]
+    pub min_remaining_secs: i64,
+}
+
+fn default_resume_min_remaining_secs() -> i64 {
+    60
+}
+
+impl Default for CycleResumeConfig {
+    fn default() -> Self {
+        Self {
+            policy: CycleResumePolicy::default(),
+            min_remaining_secs: default_resume_min_remaining_secs(),
+        }
+    }
+}
+
+/// Volatility-regime dump threshold.
+///
+/// The effective threshold is `k × sigma`, where sigma is the realized
+/// volatility of the signal price over the rolling-high window, clamped to
+/// `[min_move_pct, max_move_pct]`. Until `min_samples` returns have been seen
+/// in the lookback the fixed `move_pct` applies.
+#[derive(Debug, Clone, Deserialize)]
+pub struct VolThresholdConfig {
+    #[serde(default = "default_vol_k")]
+    pub k: Decimal,
+    #[serde(default = "default_vol_min_move_pct")]
+    pub min_move_pct: Decimal,
+    #[serde(default = "default_vol_max_move_pct")]
+    pub max_move_pct: Decimal,
+    /// History used to estimate realized volatility
+    #[serde(default = "default_vol_lookback_secs")]
+    pub lookback_secs: i64,
+    #[serde(default = "default_vol_min_samples")]
+    pub min_samples: usize,
+    /// Per-symbol overrides keyed by lowercase symbol (`btc`, `sol`, ...)
+    #[serde(default)]
+    pub symbols: HashMap<String, VolThresholdOverride>,
+}
+
+#[derive(Debug, Clone, Default, Deserialize)]
+pub struct VolThresholdOverride {
+    pub k: Option<Decimal>,
+    pub min_move_pct: Option<Decimal>,
+    pub max_move_pct: Option<Decimal>,
+}
+
+/// `k` and bounds resolved for one symbol
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct VolThresholdParams {
+    pub k: Decimal,
+    pub min_move_pct: Decimal,
+    pub max_move_pct: Decimal,
+}
+
+fn default_vol_k() -> Decimal {
+    Decimal::from(3)
+}
+
+fn default_vol_min_move_pct() -> Decimal {
+    Decimal::new(5, 2)
+}
+
+fn default_vol_max_move_pct() -> Decimal {
+    Decimal::new(40, 2)
+}
+
+fn default_vol_lookback_secs() -> i64 {
+    300
+}
+
+fn default_vol_min_samples() -> usize {
+    20
+}
+
+impl Default for VolThresholdConfig {
+    fn default() -> Self {
+        Self {
+            k: default_vol_k(),
+            min_move_pct: default_vol_min_move_pct(),
+            max_move_pct: default_vol_max_move_pct(),
+            lookback_secs: default_vol_lookback_secs(),
+            min_samples: default_vol_min_samples(),
+            symbols: HashMap::new(),
+        }
+    }
+}
+
+impl VolThresholdConfig {
+    /// Parameters for `symbol`, with any per-symbol override applied
+    pub fn params_for(&self, symbol: Option<&str>) -> VolThresholdParams {
+        let o = symbol
+            .and_then(|s| self.symbols.get(&s.to_ascii_lowercase()))
+            .cloned()
+            .unwrap_or_default();
+        VolThresholdParams {
+            k: o.k.unwrap_or(self.k),
+            min_move_pct: o.min_move_pct.unwrap_or(self.min_move_pct),
+            max_move_pct: o.max_move_pct.unwrap_or(self.max_move_pct),
+        }
+    }
 }
 
 #[derive(Debug, Clone, Deserialize)]
@@ -574,6 +853,15 @@ pub struct ExecutionConfig {
     /// Maximum quote age in seconds before rejecting trade (default: 5s)
     #[serde(default = "default_max_quote_age")]
     pub max_quote_age_secs: u64,
+    /// Compensation run when Leg2 fails after Leg1 filled
+    #[serde(default)]
+    pub compensation: CompensationConfig,
+    /// Re-pricing of resting GTC limit orders that stay unfilled
+    #[serde(default)]
+    pub chase: ChaseConfig,
+    /// Maker-first vs taker execution, per strategy
+    #[serde(default)]
+    pub preference: ExecutionPreferenceConfig,
 }
 
 fn default_poll_interval() -> u64 {
@@ -603,6 +891,161 @@ impl Default for ExecutionConfig {
             confirm_fills: false,
             confirm_fill_timeout_ms: default_confirm_fill_timeout_ms(),
             max_quote_age_secs: default_max_quote_age(),
+            compensation: CompensationConfig::default(),
+            chase: ChaseConfig::default(),
+            preference: ExecutionPreferenceConfig::default(),
+        }
+    }
+}
+
+/// One step of the Leg2 compensation saga
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum CompensationStep {
+    /// Cancel the failed Leg2 order if it may still be resting
+    Cancel,
+    /// Resubmit the unhedged shares at a widened (break-even capped) price
+    Resubmit,
+    /// Sell the unhedged Leg1 shares IOC
+    MarketOut,
+    /// Halt trading for manual intervention
+    Alert,
+}
+
+#[derive(Debug, Clone, Deserialize)]
+pub struct CompensationConfig {
+    /// Steps run in order until the cycle is flat
+    #[serde(default = "default_compensation_steps")]
+    pub steps: Vec<CompensationStep>,
+    /// Resubmit attempts before moving on to the next step
+    #[serde(default = "default_compensation_max_resubmits")]
+    pub max_resubmits: u32,
+    /// Price added to the Leg2 limit on each resubmit
+    #[serde(default = "default_compensation_widen_step")]
+    pub widen_step: Decimal,
+}
+
+fn default_compensation_steps() -> Vec<CompensationStep> {
+    vec![
+        CompensationStep::Cancel,
+        CompensationStep::Resubmit,
+        CompensationStep::MarketOut,
+        CompensationStep::Alert,
+    ]
+}
+
+fn default_compensation_max_resubmits() -> u32 {
+    2
+}
+
+fn default_compensation_widen_step() -> Decimal {
+    Decimal::new(1, 2) // 0.01
+}
+
+impl Default for CompensationConfig {
+    fn default() -> Self {
+        Self {
+            steps: default_compensation_steps(),
+            max_resubmits: default_compensation_max_resubmits(),
+            widen_step: default_compensation_widen_step(),
+        }
+    }
+}
+
+/// Chase policy for resting limit orders: after `reprice_after_ms` without a
+/// full fill, cancel and re-submit the remainder one step closer to the
+/// market, never further than `max_slippage` from the original limit
+#[derive(Debug, Clone, Deserialize)]
+pub struct ChaseConfig {
+    #[serde(default)]
+    pub enabled: bool,
+    /// Time an order may rest unfilled before each re-price
+    #[serde(default = "default_chase_reprice_after_ms")]
+    pub reprice_after_ms: u64,
+    /// Price increment per re-price step
+    #[serde(default = "default_chase_tick_size")]
+    pub tick_size: Decimal,
+    /// Re-price steps before the order is left resting at its last price
+    #[serde(default = "default_chase_max_steps")]
+    pub max_steps: u32,
+    /// Maximum distance from the original limit price (absolute)
+    #[serde(default = "default_chase_max_slippage")]
+    pub max_slippage: Decimal,
+}
+
+fn default_chase_reprice_after_ms() -> u64 {
+    1500
+}
+
+fn default_chase_tick_size() -> Decimal {
+    Decimal::new(1, 2) // 0.01
+}
+
+fn default_chase_max_steps() -> u32 {
+    3
+}
+
+fn default_chase_max_slippage() -> Decimal {
+    Decimal::new(3, 2) // 0.03
+}
+
+impl Default for ChaseConfig {
+    fn default() -> Self {
+        Self {
+            enabled: false,
+            reprice_after_ms: default_chase_reprice_after_ms(),
+            tick_size: default_chase_tick_size(),
+            max_steps: default_chase_max_steps(),
+            max_slippage: default_chase_max_slippage(),
+        }
+    }
+}
+
+/// Execution preference policy
+///
+/// Maker-first orders rest post-only one tick inside the spread for up to
+/// `maker_max_wait_ms`; whatever is still unfilled is then sent as the
+/// original (taker) order.
+#[derive(Debug, Clone, Deserialize)]
+pub struct ExecutionPreferenceConfig {
+    /// Preference for strategies without an override
+    #[serde(default)]
+    pub default: ExecutionPreference,
+    /// Overrides keyed by strategy name (intent `strategy` metadata, else agent id)
+    #[serde(default)]
+    pub strategies: HashMap<String, ExecutionPreference>,
+    /// Time a maker order may rest before the remainder crosses the spread
+    #[serde(default = "default_maker_max_wait_ms")]
+    pub maker_max_wait_ms: u64,
+    /// Price improvement over the touch for the maker order
+    #[serde(default = "default_maker_tick_size")]
+    pub tick_size: Decimal,
+}
+
+fn default_maker_max_wait_ms() -> u64 {
+    3000
+}
+
+fn default_maker_tick_size() -> Decimal {
+    Decimal::new(1, 2) // 0.01
+}
+
+impl ExecutionPreferenceConfig {
+    pub fn for_strategy(&self, strategy: &str) -> ExecutionPreference {
+        self.strategies
+            .get(strategy)
+            .copied()
+            .unwrap_or(self.default)
+    }
+}
+
+impl Default for ExecutionPreferenceConfig {
+    fn default() -> Self {
+        Self {
+            default: ExecutionPreference::Taker,
+            strategies: HashMap::new(),
+            maker_max_wait_ms: default_maker_max_wait_ms(),
+            tick_size: default_maker_tick_size(),
         }
     }
 }
@@ -687,6 +1130,9 @@ fn default_max_connections() -> u32 {
 pub struct DryRunConfig {
     /// Enable dry run mode (no real orders)
     pub enabled: bool,
+    /// Simulated quote / order-ack latency (dry run only)
+    #[serde(default)]
+    pub latency: LatencyConfig,
 }
 
 #[derive(Debug, Clone, Deserialize, Default)]
@@ -703,16 +1149,195 @@ fn default_log_level() -> String {
     "info".to_string()
 }
 
+/// Profile selected via `--profile` / `PLOY_PROFILE`
+pub fn active_profile() -> Option<String> {
+    env_string_raw(&[PROFILE_ENV])
+}
+
+/// Directory holding `<profile>.toml` overlays, next to the base config
+pub fn profiles_dir(config_path: &Path) -> PathBuf {
+    let base = if config_path.is_file() {
+        config_path.parent().unwrap_or_else(|| Path::new("."))
+    } else {
+        config_path
+    };
+    base.join("profiles")
+}
+
+/// Names of the profiles available for `config_path`
+pub fn available_profiles(config_path: &Path) -> Vec<String> {
+    let mut names: Vec<String> = std::fs::read_dir(profiles_dir(config_path))
+        .into_iter()
+        .flatten()
+        .flatten()
+        .map(|entry| entry.path())
+        .filter(|path| path.extension().is_some_and(|e| e == "toml"))
+        .filter_map(|path| Some(path.file_stem()?.to_string_lossy().to_string()))
+        .collect();
+    names.sort();
+    names
+}
+
+/// One config file layer and whether it must exist
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct ConfigLayer {
+    pub path: PathBuf,
+    pub required: bool,
+}
+
+impl ConfigLayer {
+    /// Whether the file exists (extension-less layers resolve like the config crate)
+    pub fn exists(&self) -> bool {
+        self.path.is_file() || self.path.with_extension("toml").is_file()
+    }
+}
+
+/// Config files applied in order: base, `PLOY_ENV` file, then the profile.
+///
+/// Environment variables (`PLOY_*`) are layered on top of these.
+pub fn config_layers(
+    config_path: &Path,
+    profile: Option<&str>,
+) -> Result<Vec<ConfigLayer>, ConfigError> {
+    // Accept either a config directory (`config/`) or a single TOML file
+    // (`config/default.toml`) for CLI loading.
+    let mut layers = if config_path.is_file() {
+        vec![ConfigLayer {
+            path: config_path.to_path_buf(),
+            required: true,
+        }]
+    } else {
+        vec![
+            ConfigLayer {
+                path: config_path.join("default.toml"),
+                required: false,
+            },
+            // Environment-specific config (e.g., config/production.toml)
+            ConfigLayer {
+                path: config_path
+                    .join(std::env::var("PLOY_ENV").unwrap_or_else(|_| "development".to_string())),
+                required: false,
+            },
+        ]
+    };
+
+    if let Some(name) = profile {
+        let path = profiles_dir(config_path).join(format!("{name}.toml"));
+        if !path.is_file() {
+            let available = available_profiles(config_path);
+            return Err(ConfigError::Message(format!(
+                "config profile '{name}' not found at {} (available: {})",
+                path.display(),
+                if available.is_empty() {
+                    "none".to_string()
+                } else {
+                    available.join(", ")
+                }
+            )));
+        }
+        layers.push(ConfigLayer {
+            path,
+            required: true,
+        });
+    }
+    Ok(layers)
+}
+
+/// Check each section of the merged config on its own so every broken
+/// section is reported with its key path.
+fn schema_errors(root: &serde_json::Value) -> Vec<String> {
+    fn check<T: DeserializeOwned>(
+        root: &serde_json::Value,
+        key: &str,
+        required: bool,
+        errors: &mut Vec<String>,
+    ) {
+        match root.get(key) {
+            None | Some(serde_json::Value::Null) if required => {
+                errors.push(format!("{key}: missing required section [{key}]"))
+            }
+            None | Some(serde_json::Value::Null) => {}
+            Some(value) => {
+                if let Err(e) = serde_json::from_value::<T>(value.clone()) {
+                    errors.push(format!("{key}: {e}"));
+                }
+            }
+        }
+    }
+
+    let mut errors = Vec::new();
+    check::<AccountConfig>(root, "account", false, &mut errors);
+    check::<MarketConfig>(root, "market", true, &mut errors);
+    check::<StrategyConfig>(root, "strategy", true, &mut errors);
+    check::<ExecutionConfig>(root, "execution", true, &mut errors);
+    check::<RiskConfig>(root, "risk", true, &mut errors);
+    check::<DatabaseConfig>(root, "database", true, &mut errors);
+    check::<DryRunConfig>(root, "dry_run", true, &mut errors);
+    check::<KalshiConfig>(root, "kalshi", false, &mut errors);
+    check::<LoggingConfig>(root, "logging", false, &mut errors);
+    check::<AgentFrameworkConfig>(root, "agent_framework", false, &mut errors);
+    check::<EventEdgeAgentConfig>(root, "event_edge_agent", false, &mut errors);
+    check::<NbaComebackConfig>(root, "nba_comeback", false, &mut errors);
+    check::<DiscoveryConfig>(root, "event_registry", false, &mut errors);
+    check::<SecretsConfig>(root, "secrets", false, &mut errors);
+    check::<VpinConfig>(root, "toxicity", false, &mut errors);
+    errors
+}
+
 impl AppConfig {
     /// Load configuration from files and environment
     pub fn load() -> Result<Self, ConfigError> {
         Self::load_from("config")
     }
 
-    /// Load configuration from either a config directory or a single TOML file
+    /// Load configuration from either a config directory or a single TOML file,
+    /// overlaid with the active profile (`PLOY_PROFILE`) if one is set
     pub fn load_from<P: AsRef<Path>>(config_dir: P) -> Result<Self, ConfigError> {
-        let config_path = config_dir.as_ref();
+        Self::load_profile(config_dir, active_profile().as_deref())
+    }
 
+    /// Load base config + `profiles/<profile>.toml` + `PLOY_*` environment
+    pub fn load_profile<P: AsRef<Path>>(
+        config_dir: P,
+        profile: Option<&str>,
+    ) -> Result<Self, ConfigError> {
+        let merged = Self::layered_builder(config_dir.as_ref(), profile)?.build()?;
+        let mut cfg = match merged.clone().try_deserialize::<Self>() {
+            Ok(cfg) => cfg,
+            Err(err) => {
+                let root: serde_json::Value = merged.try_deserialize().unwrap_or_default();
+                let mut problems = schema_errors(&root);
+                if problems.is_empty() {
+                    problems.push(err.to_string());
+                }
+                return Err(ConfigError::Message(format!(
+                    "invalid configuration{}:\n  - {}",
+                    profile
+                        .map(|p| format!(" (profile '{p}')"))
+                        .unwrap_or_default(),
+                    problems.join("\n  - ")
+                )));
+            }
+        };
+        cfg.apply_env_overrides();
+        Ok(cfg)
+    }
+
+    /// The merged (defaults + files + profile + environment) config tree,
+    /// before it is deserialized into [`AppConfig`]
+    pub fn resolved_value<P: AsRef<Path>>(
+        config_dir: P,
+        profile: Option<&str>,
+    ) -> Result<serde_json::Value, ConfigError> {
+        Self::layered_builder(config_dir.as_ref(), profile)?
+            .build()?
+            .try_deserialize()
+    }
+
+    fn layered_builder(
+        config_path: &Path,
+        profile: Option<&str>,
+    ) -> Result<ConfigBuilder<DefaultState>, ConfigError> {
         let mut builder = Config::builder()
             // Start with default values
             .set_default("logging.level", "info")?
@@ -728,21 +1353,8 @@ impl AppConfig {
             .set_default("kalshi.base_url", default_kalshi_base_url())?
             .set_default("api_port", 8081)?;
 
-        // Accept either a config directory (`config/`) or a single TOML file
-        // (`config/default.toml`) for CLI loading.
-        if config_path.is_file() {
-            builder = builder.add_source(File::from(config_path).required(true));
-        } else {
-            builder = builder
-                // Load default config file
-                .add_source(File::from(config_path.join("default.toml")).required(false))
-                // Load environment-specific config (e.g., config/production.toml)
-                .add_source(
-                    File::from(config_path.join(
-                        std::env::var("PLOY_ENV").unwrap_or_else(|_| "development".to_string()),
-                    ))
-                    .required(false),
-                );
+        for layer in config_layers(config_path, profile)? {
+            builder = builder.add_source(File::from(layer.path).required(layer.required));
         }
 
         builder = builder.add_source(
@@ -755,10 +1367,7 @@ impl AppConfig {
                 .with_list_parse_key("event_edge_agent.titles")
                 .try_parsing(true),
         );
-
-        let mut cfg: Self = builder.build()?.try_deserialize()?;
-        cfg.apply_env_overrides();
-        Ok(cfg)
+        Ok(builder)
     }
 
     /// Create a default configuration for CLI usage
@@ -774,6 +1383,7 @@ impl AppConfig {
                 condition_id: None,
                 exchange_ws_url: None,
                 exchange_rest_url: None,
+                quote_sanitizer: QuoteSanitizerConfig::default(),
             },
             strategy: StrategyConfig {
                 shares: 20,
@@ -783,6 +1393,9 @@ impl AppConfig {
                 fee_buffer: dec!(0.005),
                 slippage_buffer: dec!(0.02),
                 profit_buffer: dec!(0.01),
+                resume: CycleResumeConfig::default(),
+                signal_mid: None,
+                vol_threshold: None,
             },
             execution: ExecutionConfig {
                 exchange: default_execution_exchange(),
@@ -793,6 +1406,9 @@ impl AppConfig {
                 confirm_fills: false,
                 confirm_fill_timeout_ms: default_confirm_fill_timeout_ms(),
                 max_quote_age_secs: default_max_quote_age(),
+                compensation: CompensationConfig::default(),
+                chase: ChaseConfig::default(),
+                preference: ExecutionPreferenceConfig::default(),
             },
             risk: RiskConfig {
                 max_single_exposure_usd: dec!(100),
@@ -811,7 +1427,10 @@ impl AppConfig {
                 url: "postgres://localhost/ploy".to_string(),
                 max_connections: 5,
             },
-            dry_run: DryRunConfig { enabled: dry_run },
+            dry_run: DryRunConfig {
+                enabled: dry_run,
+                latency: LatencyConfig::default(),
+            },
             kalshi: KalshiConfig::default(),
             logging: LoggingConfig::default(),
             agent_framework: AgentFrameworkConfig::default(),
@@ -820,6 +1439,8 @@ impl AppConfig {
             event_edge_agent: None,
             nba_comeback: None,
             event_registry: None,
+            secrets: SecretsConfig::default(),
+            toxicity: None,
         }
     }
 
@@ -1128,6 +1749,9 @@ mod tests {
             fee_buffer: dec!(0.005),
             slippage_buffer: dec!(0.02),
             profit_buffer: dec!(0.01),
+            resume: CycleResumeConfig::default(),
+            signal_mid: None,
+            vol_threshold: None,
         };
 
         // 0.95 - 0.005 - 0.02 - 0.01 = 0.915
@@ -1156,6 +1780,35 @@ mod tests {
         );
     }
 
+    #[test]
+    fn test_profile_overlay_and_schema_errors() {
+        let dir = std::env::temp_dir().join(format!("ploy-config-{}", std::process::id()));
+        std::fs::create_dir_all(dir.join("profiles")).unwrap();
+        let base = std::fs::read_to_string("config/default.toml").unwrap();
+        std::fs::write(dir.join("default.toml"), base).unwrap();
+        std::fs::write(
+            dir.join("profiles/prod.toml"),
+            "[strategy]\nshares = 7\n[dry_run]\nenabled = false\n",
+        )
+        .unwrap();
+        std::fs::write(
+            dir.join("profiles/broken.toml"),
+            "[risk]\nmax_positions = \"many\"\n",
+        )
+        .unwrap();
+
+        let cfg = AppConfig::load_profile(&dir, Some("prod")).unwrap();
+        assert_eq!(cfg.strategy.shares, 7);
+        assert_eq!(available_profiles(&dir), vec!["broken", "prod"]);
+
+        let err = AppConfig::load_profile(&dir, Some("broken")).unwrap_err();
+        assert!(err.to_string().contains("risk:"), "{err}");
+        let err = AppConfig::load_profile(&dir, Some("staging")).unwrap_err();
+        assert!(err.to_string().contains("available: broken, prod"), "{err}");
+
+        std::fs::remove_dir_all(&dir).ok();
+    }
+
     #[test]
     fn test_validate_rejects_unknown_execution_exchange() {
         let mut cfg = AppConfig::default_config(true, "test-market");
@@ -0,0 +1,374 @@
+//! Secrets backends for private keys and API credentials.
+//!
+//! The rest of the codebase reads credentials from environment variables
+//! (`POLYMARKET_PRIVATE_KEY`, `POLYMARKET_API_KEY`, ...). A [`SecretsProvider`]
+//! fetches those values from somewhere safer at startup and [`hydrate_env`]
+//! exports them into the process environment, so deployments no longer need
+//! keys baked into systemd units.
+//!
+//! ```toml
+//! [secrets]
+//! backend = "aws"            # env | file | aws
+//! secret_id = "ploy/prod"    # JSON key/value secret
+//! region = "us-east-1"
+//! ```
+//!
+//! Variables already present in the environment always win, so an operator
+//! can still override a single key for a run.
+
+use std::collections::HashMap;
+use std::path::{Path, PathBuf};
+
+use async_trait::async_trait;
+use serde::{Deserialize, Serialize};
+use tokio::process::Command;
+use tracing::{info, warn};
+use zeroize::Zeroize;
+
+use super::{active_profile, AppConfig};
+use crate::error::{PloyError, Result};
+
+/// Credentials the env backend reports, in addition to whatever a remote
+/// backend returns
+pub const KNOWN_SECRET_KEYS: &[&str] = &[
+    "POLYMARKET_PRIVATE_KEY",
+    "PRIVATE_KEY",
+    "POLYMARKET_API_KEY",
+    "POLYMARKET_SECRET",
+    "POLYMARKET_PASSPHRASE",
+    "POLYMARKET_FUNDER",
+    "KALSHI_API_KEY",
+    "KALSHI_API_SECRET",
+    "DATABASE_URL",
+    "FEISHU_WEBHOOK_URL",
+    "DISCORD_WEBHOOK_URL",
+];
+
+/// How an encrypted secrets file is decrypted
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "lowercase")]
+pub enum FileEncryption {
+    /// `sops --decrypt <path>`
+    #[default]
+    Sops,
+    /// `age --decrypt -i <identity> <path>`
+    Age,
+    /// Plaintext file (permissions must be 0600 or stricter)
+    None,
+}
+
+/// `[secrets]` config section
+#[derive(Debug, Clone, Default, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(tag = "backend", rename_all = "lowercase")]
+pub enum SecretsConfig {
+    /// Read credentials from environment variables only
+    #[default]
+    Env,
+    /// Decrypt a JSON or `KEY=VALUE` file with sops or age
+    File {
+        path: PathBuf,
+        #[serde(default)]
+        encryption: FileEncryption,
+        /// age identity file (required for `encryption = "age"`)
+        #[serde(default)]
+        age_identity: Option<PathBuf>,
+    },
+    /// AWS Secrets Manager JSON secret, fetched with the AWS CLI so EC2
+    /// instance-profile credentials are used
+    Aws {
+        secret_id: String,
+        #[serde(default)]
+        region: Option<String>,
+    },
+}
+
+impl SecretsConfig {
+    /// `[secrets]` from the layered config at `config_path` (profile and
+    /// `PLOY_SECRETS__*` aware); defaults to the env backend
+    pub fn load_from(config_path: &Path) -> Result<Self> {
+        let root = AppConfig::resolved_value(config_path, active_profile().as_deref())?;
+        match root.get("secrets") {
+            None | Some(serde_json::Value::Null) => Ok(Self::default()),
+            Some(section) => Ok(serde_json::from_value(section.clone())?),
+        }
+    }
+
+    pub fn provider(&self) -> Box<dyn SecretsProvider> {
+        match self {
+            SecretsConfig::Env => Box::new(EnvSecrets),
+            SecretsConfig::File {
+                path,
+                encryption,
+                age_identity,
+            } => Box::new(FileSecrets {
+                path: path.clone(),
+                encryption: *encryption,
+                age_identity: age_identity.clone(),
+            }),
+            SecretsConfig::Aws { secret_id, region } => Box::new(AwsSecretsManager {
+                secret_id: secret_id.clone(),
+                region: region.clone(),
+            }),
+        }
+    }
+}
+
+/// Source of named secrets
+#[async_trait]
+pub trait SecretsProvider: Send + Sync {
+    fn name(&self) -> &'static str;
+
+    /// Every secret the backend holds, as `ENV_NAME -> value`
+    async fn load(&self) -> Result<HashMap<String, String>>;
+
+    /// A single secret by name
+    async fn get(&self, key: &str) -> Result<Option<String>> {
+        let mut secrets = self.load().await?;
+        let value = secrets.remove(key);
+        secrets.values_mut().for_each(Zeroize::zeroize);
+        Ok(value)
+    }
+}
+
+/// Process environment
+pub struct EnvSecrets;
+
+#[async_trait]
+impl SecretsProvider for EnvSecrets {
+    fn name(&self) -> &'static str {
+        "env"
+    }
+
+    async fn load(&self) -> Result<HashMap<String, String>> {
+        Ok(KNOWN_SECRET_KEYS
+            .iter()
+            .filter_map(|k| Some((k.to_string(), std::env::var(k).ok()?)))
+            .collect())
+    }
+
+    async fn get(&self, key: &str) -> Result<Option<String>> {
+        Ok(std::env::var(key).ok())
+    }
+}
+
+/// sops/age encrypted file
+pub struct FileSecrets {
+    path: PathBuf,
+    encryption: FileEncryption,
+    age_identity: Option<PathBuf>,
+}
+
+#[async_trait]
+impl SecretsProvider for FileSecrets {
+    fn name(&self) -> &'static str {
+        "file"
+    }
+
+    async fn load(&self) -> Result<HashMap<String, String>> {
+        let path = self.path.to_string_lossy().to_string();
+        let mut plaintext = match self.encryption {
+            FileEncryption::Sops => run_tool("sops", &["--decrypt", path.as_str()]).await?,
+            FileEncryption::Age => {
+                let identity = self.age_identity.as_ref().ok_or_else(|| {
+                    PloyError::Validation(
+                        "secrets.age_identity is required for age-encrypted files".to_string(),
+                    )
+                })?;
+                let identity = identity.to_string_lossy().to_string();
+                run_tool(
+                    "age",
+                    &["--decrypt", "-i", identity.as_str(), path.as_str()],
+                )
+                .await?
+            }
+            FileEncryption::None => {
+                check_permissions(&self.path)?;
+                tokio::fs::read_to_string(&self.path).await?
+            }
+        };
+        let parsed = parse_secrets(&plaintext);
+        plaintext.zeroize();
+        parsed
+    }
+}
+
+/// AWS Secrets Manager via `aws secretsmanager get-secret-value`
+pub struct AwsSecretsManager {
+    secret_id: String,
+    region: Option<String>,
+}
+
+#[async_trait]
+impl SecretsProvider for AwsSecretsManager {
+    fn name(&self) -> &'static str {
+        "aws"
+    }
+
+    async fn load(&self) -> Result<HashMap<String, String>> {
+        let mut args = vec![
+            "secretsmanager",
+            "get-secret-value",
+            "--secret-id",
+            self.secret_id.as_str(),
+            "--query",
+            "SecretString",
+            "--output",
+            "text",
+        ];
+        if let Some(region) = &self.region {
+            args.extend(["--region", region.as_str()]);
+        }
+        let mut plaintext = run_tool("aws", &args).await?;
+        let parsed = parse_secrets(&plaintext);
+        plaintext.zeroize();
+        parsed
+    }
+}
+
+/// Run a decryption/fetch tool and return its stdout
+async fn run_tool(program: &str, args: &[&str]) -> Result<String> {
+    let output = Command::new(program)
+        .args(args)
+        .output()
+        .await
+        .map_err(|e| PloyError::Internal(format!("failed to run `{program}` for secrets: {e}")))?;
+    if !output.status.success() {
+        return Err(PloyError::Internal(format!(
+            "`{program}` exited with {}: {}",
+            output.status,
+            String::from_utf8_lossy(&output.stderr).trim()
+        )));
+    }
+    String::from_utf8(output.stdout)
+        .map_err(|_| PloyError::Internal(format!("`{program}` returned non-UTF-8 secrets")))
+}
+
+#[cfg(unix)]
+fn check_permissions(path: &Path) -> Result<()> {
+    use std::os::unix::fs::PermissionsExt;
+    let mode = std::fs::metadata(path)?.permissions().mode();
+    if mode & 0o077 != 0 {
+        return Err(PloyError::Validation(format!(
+            "plaintext secrets file {} is accessible by other users (mode {:o}); chmod 600 it",
+            path.display(),
+            mode & 0o777
+        )));
+    }
+    Ok(())
+}
+
+#[cfg(not(unix))]
+fn check_permissions(_path: &Path) -> Result<()> {
+    Ok(())
+}
+
+/// Parse a JSON object or `KEY=VALUE` lines (dotenv style, `#` comments)
+pub fn parse_secrets(text: &str) -> Result<HashMap<String, String>> {
+    let trimmed = text.trim();
+    if trimmed.starts_with('{') {
+        let map: HashMap<String, serde_json::Value> = serde_json::from_str(trimmed)?;
+        return Ok(map
+            .into_iter()
+            .filter_map(|(k, v)| match v {
+                serde_json::Value::String(s) => Some((k, s)),
+                serde_json::Value::Number(n) => Some((k, n.to_string())),
+                serde_json::Value::Bool(b) => Some((k, b.to_string())),
+                _ => None,
+            })
+            .collect());
+    }
+
+    let mut secrets = HashMap::new();
+    for (lineno, line) in trimmed.lines().enumerate() {
+        let line = line.trim();
+        if line.is_empty() || line.starts_with('#') {
+            continue;
+        }
+        let line = line.strip_prefix("export ").unwrap_or(line);
+        let (key, value) = line.split_once('=').ok_or_else(|| {
+            PloyError::Validation(format!("secrets line {} is not KEY=VALUE", lineno + 1))
+        })?;
+        let value = value.trim();
+        let value = value
+            .strip_prefix('"')
+            .and_then(|v| v.strip_suffix('"'))
+            .or_else(|| value.strip_prefix('\'').and_then(|v| v.strip_suffix('\'')))
+            .unwrap_or(value);
+        secrets.insert(key.trim().to_string(), value.to_string());
+    }
+    Ok(secrets)
+}
+
+/// Load secrets from the configured backend and export the ones not already
+/// set into the process environment. Returns the number exported.
+///
+/// Call at startup, before any task reads credentials from the environment.
+pub async fn hydrate_env(config: &SecretsConfig) -> Result<usize> {
+    if *config == SecretsConfig::Env {
+        return Ok(0);
+    }
+    let provider = config.provider();
+    let mut secrets = provider.load().await?;
+
+    let mut exported = 0;
+    let mut skipped = Vec::new();
+    for (key, value) in secrets.iter_mut() {
+        if std::env::var_os(key).is_some() {
+            skipped.push(key.clone());
+        } else {
+            std::env::set_var(key, &*value);
+            exported += 1;
+        }
+        value.zeroize();
+    }
+    if !skipped.is_empty() {
+        skipped.sort();
+        warn!(
+            backend = provider.name(),
+            "environment overrides secrets backend for: {}",
+            skipped.join(", ")
+        );
+    }
+    info!(
+        backend = provider.name(),
+        exported, "loaded secrets into environment"
+    );
+    Ok(exported)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn parses_json_and_dotenv_secrets() {
+        let json = parse_secrets(r#"{"POLYMARKET_PRIVATE_KEY": "0xabc", "PORT": 5}"#).unwrap();
+        assert_eq!(json["POLYMARKET_PRIVATE_KEY"], "0xabc");
+        assert_eq!(json["PORT"], "5");
+
+        let dotenv = parse_secrets(
+            "# comment\nexport POLYMARKET_API_KEY=\"key\"\nPOLYMARKET_SECRET='s=1'\n",
+        )
+        .unwrap();
+        assert_eq!(dotenv["POLYMARKET_API_KEY"], "key");
+        assert_eq!(dotenv["POLYMARKET_SECRET"], "s=1");
+        assert!(parse_secrets("not a pair").is_err());
+    }
+
+    #[test]
+    fn secrets_config_selects_backend() {
+        let cfg: SecretsConfig =
+            toml::from_str("backend = \"aws\"\nsecret_id = \"ploy/prod\"\n").unwrap();
+        assert_eq!(cfg.provider().name(), "aws");
+        let cfg: SecretsConfig =
+            toml::from_str("backend = \"file\"\npath = \"/etc/ploy/secrets.env\"\n").unwrap();
+        assert!(matches!(
+            cfg,
+            SecretsConfig::File {
+                encryption: FileEncryption::Sops,
+                ..
+            }
+        ));
+        assert_eq!(SecretsConfig::default().provider().name(), "env");
+    }
+}
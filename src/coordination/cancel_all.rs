@@ -0,0 +1,407 @@
+//! Venue-wide Cancel-All
+//!
+//! Bulk cancellation behind `ploy pm orders cancel-all` and the emergency
+//! stop. Open orders are listed page by page first (cancelling while paging
+//! would shift the cursor), filtered to the requested scope, then cancelled
+//! concurrently with bounded retries. A failed cancel never aborts the sweep;
+//! it lands in the [`CancelAllSummary`] so the operator sees exactly which
+//! orders may still be resting.
+
+use std::collections::HashSet;
+
+use async_trait::async_trait;
+use chrono::{DateTime, Duration, Utc};
+use futures_util::stream::{self, StreamExt};
+use serde::Serialize;
+use tracing::{info, warn};
+
+use crate::adapters::polymarket_clob::OrderResponse;
+use crate::adapters::PolymarketClient;
+use crate::error::{PloyError, Result};
+
+/// Open order as seen by the cancel sweep
+#[derive(Debug, Clone, PartialEq, Serialize)]
+pub struct OpenOrder {
+    pub id: String,
+    /// Condition ID
+    pub market: Option<String>,
+    /// Token ID
+    pub asset_id: Option<String>,
+    pub created_at: Option<DateTime<Utc>>,
+}
+
+impl From<&OrderResponse> for OpenOrder {
+    fn from(order: &OrderResponse) -> Self {
+        Self {
+            id: order.id.clone(),
+            market: order.market.clone(),
+            asset_id: order.asset_id.clone(),
+            created_at: order
+                .created_at
+                .as_deref()
+                .and_then(|s| DateTime::parse_from_rfc3339(s).ok())
+                .map(|dt| dt.with_timezone(&Utc)),
+        }
+    }
+}
+
+/// One page of the venue's open-order listing
+#[derive(Debug, Clone, Default)]
+pub struct OpenOrdersPage {
+    pub orders: Vec<OpenOrder>,
+    /// `None` once the listing is exhausted
+    pub next_cursor: Option<String>,
+}
+
+/// Venue operations the cancel sweep needs
+#[async_trait]
+pub trait CancelVenue: Send + Sync {
+    async fn open_orders_page(&self, cursor: Option<String>) -> Result<OpenOrdersPage>;
+    async fn cancel_order(&self, order_id: &str) -> Result<()>;
+}
+
+#[async_trait]
+impl CancelVenue for PolymarketClient {
+    async fn open_orders_page(&self, cursor: Option<String>) -> Result<OpenOrdersPage> {
+        let (orders, next_cursor) = self.get_open_orders_page(cursor).await?;
+        Ok(OpenOrdersPage {
+            orders: orders.iter().map(OpenOrder::from).collect(),
+            next_cursor,
+        })
+    }
+
+    async fn cancel_order(&self, order_id: &str) -> Result<()> {
+        PolymarketClient::cancel_order(self, order_id).await?;
+        Ok(())
+    }
+}
+
+/// Which open orders a sweep cancels
+#[derive(Debug, Clone, Default)]
+pub struct CancelScope {
+    /// Condition ID or token ID; `None` = every market
+    pub market: Option<String>,
+    /// Only orders at least this old; orders of unknown age are kept
+    pub older_than: Option<Duration>,
+}
+
+impl CancelScope {
+    /// Every open order on the venue
+    pub fn all() -> Self {
+        Self::default()
+    }
+
+    pub fn with_market(mut self, market: impl Into<String>) -> Self {
+        self.market = Some(market.into());
+        self
+    }
+
+    pub fn with_older_than(mut self, age: Duration) -> Self {
+        self.older_than = Some(age);
+        self
+    }
+
+    pub fn matches(&self, order: &OpenOrder, now: DateTime<Utc>) -> bool {
+        if let Some(market) = &self.market {
+            let same = |id: &Option<String>| {
+                id.as_deref()
+                    .is_some_and(|id| id.eq_ignore_ascii_case(market))
+            };
+            if !same(&order.market) && !same(&order.asset_id) {
+                return false;
+            }
+        }
+        match self.older_than {
+            Some(age) => order.created_at.is_some_and(|at| now - at >= age),
+            None => true,
+        }
+    }
+}
+
+/// Concurrency and retry limits for a sweep
+#[derive(Debug, Clone)]
+pub struct CancelAllConfig {
+    /// Cancels in flight at once
+    pub concurrency: usize,
+    /// Attempts per order before it is reported as not cancelled
+    pub max_attempts: u32,
+    /// Backoff before the first retry; doubles on each further retry
+    pub retry_backoff_ms: u64,
+    /// Safety cap on listing pages
+    pub max_pages: usize,
+}
+
+impl Default for CancelAllConfig {
+    fn default() -> Self {
+        Self {
+            concurrency: 8,
+            max_attempts: 3,
+            retry_backoff_ms: 200,
+            max_pages: 100,
+        }
+    }
+}
+
+/// Order that could not be cancelled
+#[derive(Debug, Clone, Serialize)]
+pub struct CancelFailure {
+    pub order_id: String,
+    pub attempts: u32,
+    pub error: String,
+}
+
+/// Outcome of a cancel-all sweep
+#[derive(Debug, Clone, Default, Serialize)]
+pub struct CancelAllSummary {
+    /// Open orders listed
+    pub scanned: usize,
+    /// Open orders inside the scope
+    pub matched: usize,
+    pub cancelled: Vec<String>,
+    pub failed: Vec<CancelFailure>,
+    /// Listing stopped at `max_pages`; more orders may be open
+    pub truncated: bool,
+}
+
+impl CancelAllSummary {
+    /// Every matched order was cancelled and the listing was complete
+    pub fn is_complete(&self) -> bool {
+        self.failed.is_empty() && !self.truncated
+    }
+}
+
+/// List every open order, following the cursor
+///
+/// The flag is set when the listing stopped at `max_pages` with more pages
+/// left.
+pub async fn list_open_orders<V: CancelVenue + ?Sized>(
+    venue: &V,
+    max_pages: usize,
+) -> Result<(Vec<OpenOrder>, bool)> {
+    let mut orders = Vec::new();
+    let mut cursor = None;
+    for _ in 0..max_pages.max(1) {
+        let page = venue.open_orders_page(cursor).await?;
+        orders.extend(page.orders);
+        match page.next_cursor {
+            Some(next) => cursor = Some(next),
+            None => return Ok((orders, false)),
+        }
+    }
+    Ok((orders, true))
+}
+
+async fn cancel_with_retry<V: CancelVenue + ?Sized>(
+    venue: &V,
+    order_id: String,
+    config: &CancelAllConfig,
+) -> std::result::Result<String, CancelFailure> {
+    let max_attempts = config.max_attempts.max(1);
+    let mut backoff = std::time::Duration::from_millis(config.retry_backoff_ms);
+    let mut attempt = 1;
+    loop {
+        match venue.cancel_order(&order_id).await {
+            Ok(()) => return Ok(order_id),
+            Err(e) if attempt >= max_attempts => {
+                return Err(CancelFailure {
+                    order_id,
+                    attempts: attempt,
+                    error: e.to_string(),
+                })
+            }
+            Err(e) => {
+                warn!(
+                    "Cancel of {} failed (attempt {}/{}): {}",
+                    order_id, attempt, max_attempts, e
+                );
+                tokio::time::sleep(backoff).await;
+                backoff *= 2;
+                attempt += 1;
+            }
+        }
+    }
+}
+
+/// Cancel every open order in `scope`
+///
+/// Errors only when the open orders cannot be listed; per-order failures are
+/// reported in the summary.
+pub async fn cancel_all<V: CancelVenue + ?Sized>(
+    venue: &V,
+    scope: &CancelScope,
+    config: &CancelAllConfig,
+) -> Result<CancelAllSummary> {
+    let (orders, truncated) = list_open_orders(venue, config.max_pages).await?;
+    Ok(cancel_matching(venue, &orders, truncated, scope, config).await)
+}
+
+/// Cancel the orders of an existing listing that fall inside `scope`
+pub async fn cancel_matching<V: CancelVenue + ?Sized>(
+    venue: &V,
+    orders: &[OpenOrder],
+    truncated: bool,
+    scope: &CancelScope,
+    config: &CancelAllConfig,
+) -> CancelAllSummary {
+    let now = Utc::now();
+    let mut ids: Vec<String> = orders
+        .iter()
+        .filter(|o| scope.matches(o, now))
+        .map(|o| o.id.clone())
+        .collect();
+    let mut seen = HashSet::new();
+    ids.retain(|id| seen.insert(id.clone()));
+
+    let mut summary = CancelAllSummary {
+        scanned: orders.len(),
+        matched: ids.len(),
+        truncated,
+        ..Default::default()
+    };
+    if truncated {
+        warn!(
+            "Open-order listing stopped after {} pages; some orders were not scanned",
+            config.max_pages
+        );
+    }
+
+    let results: Vec<_> = stream::iter(ids)
+        .map(|id| cancel_with_retry(venue, id, config))
+        .buffer_unordered(config.concurrency.max(1))
+        .collect()
+        .await;
+    for result in results {
+        match result {
+            Ok(id) => summary.cancelled.push(id),
+            Err(failure) => summary.failed.push(failure),
+        }
+    }
+
+    info!(
+        "Cancel-all: {} scanned, {} matched, {} cancelled, {} failed",
+        summary.scanned,
+        summary.matched,
+        summary.cancelled.len(),
+        summary.failed.len()
+    );
+    summary
+}
+
+/// Parse a `--older-than` age such as `90s`, `5m`, `2h` or `1d`
+pub fn parse_age(input: &str) -> Result<Duration> {
+    let input = input.trim();
+    let split = input
+        .find(|c: char| !c.is_ascii_digit())
+        .unwrap_or(input.len());
+    let (digits, unit) = input.split_at(split);
+    let value: i64 = digits
+        .parse()
+        .map_err(|_| PloyError::Validation(format!("invalid age '{}'", input)))?;
+    match unit {
+        "" | "s" => Ok(Duration::seconds(value)),
+        "m" => Ok(Duration::minutes(value)),
+        "h" => Ok(Duration::hours(value)),
+        "d" => Ok(Duration::days(value)),
+        _ => Err(PloyError::Validation(format!(
+            "invalid age unit in '{}' (use s, m, h or d)",
+            input
+        ))),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::collections::HashMap;
+    use std::sync::Mutex;
+
+    /// Two pages of orders; `flaky` fails N times before cancelling
+    struct FakeVenue {
+        pages: Vec<Vec<OpenOrder>>,
+        failures: Mutex<HashMap<String, u32>>,
+        cancelled: Mutex<Vec<String>>,
+    }
+
+    #[async_trait]
+    impl CancelVenue for FakeVenue {
+        async fn open_orders_page(&self, cursor: Option<String>) -> Result<OpenOrdersPage> {
+            let index: usize = cursor.map(|c| c.parse().unwrap()).unwrap_or(0);
+            Ok(OpenOrdersPage {
+                orders: self.pages[index].clone(),
+                next_cursor: (index + 1 < self.pages.len()).then(|| (index + 1).to_string()),
+            })
+        }
+
+        async fn cancel_order(&self, order_id: &str) -> Result<()> {
+            let mut failures = self.failures.lock().unwrap();
+            if let Some(left) = failures.get_mut(order_id) {
+                if *left > 0 {
+                    *left -= 1;
+                    return Err(PloyError::Internal("rate limited".to_string()));
+                }
+            }
+            self.cancelled.lock().unwrap().push(order_id.to_string());
+            Ok(())
+        }
+    }
+
+    fn order(id: &str, market: &str, age_secs: i64) -> OpenOrder {
+        OpenOrder {
+            id: id.to_string(),
+            market: Some(market.to_string()),
+            asset_id: Some(format!("{}-token", market)),
+            created_at: Some(Utc::now() - Duration::seconds(age_secs)),
+        }
+    }
+
+    #[tokio::test]
+    async fn test_cancel_all_pages_scope_and_retries() {
+        let venue = FakeVenue {
+            pages: vec![
+                vec![order("a", "0xM1", 600), order("b", "0xM2", 600)],
+                vec![order("c", "0xm1", 10), order("d", "0xM1", 900)],
+            ],
+            failures: Mutex::new(HashMap::from([
+                ("a".to_string(), 1),  // recovers on retry
+                ("d".to_string(), 99), // never cancels
+            ])),
+            cancelled: Mutex::new(Vec::new()),
+        };
+        let config = CancelAllConfig {
+            retry_backoff_ms: 1,
+            ..Default::default()
+        };
+        let scope = CancelScope::all()
+            .with_market("0xM1")
+            .with_older_than(parse_age("5m").unwrap());
+
+        let summary = cancel_all(&venue, &scope, &config).await.unwrap();
+
+        assert_eq!(summary.scanned, 4);
+        // "c" is too young, "b" is another market
+        assert_eq!(summary.matched, 2);
+        assert_eq!(summary.cancelled, vec!["a".to_string()]);
+        assert_eq!(summary.failed.len(), 1);
+        assert_eq!(summary.failed[0].order_id, "d");
+        assert_eq!(summary.failed[0].attempts, 3);
+        assert!(!summary.is_complete());
+
+        // Token IDs match too; unscoped sweeps take everything left
+        let by_token = CancelScope::all().with_market("0xM2-token");
+        assert!(by_token.matches(&order("b", "0xM2", 0), Utc::now()));
+        let rest = cancel_all(&venue, &CancelScope::all(), &config)
+            .await
+            .unwrap();
+        assert_eq!(rest.matched, 4);
+    }
+
+    #[test]
+    fn test_parse_age() {
+        assert_eq!(parse_age("90s").unwrap(), Duration::seconds(90));
+        assert_eq!(parse_age("5m").unwrap(), Duration::minutes(5));
+        assert_eq!(parse_age("2h").unwrap(), Duration::hours(2));
+        assert_eq!(parse_age("30").unwrap(), Duration::seconds(30));
+        assert!(parse_age("5w").is_err());
+        assert!(parse_age("m").is_err());
+    }
+}
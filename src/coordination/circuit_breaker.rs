@@ -10,6 +10,9 @@ use std::sync::Arc;
 use tokio::sync::RwLock;
 use tracing::{debug, info, warn};
 
+#[cfg(feature = "api")]
+use crate::api::webhooks::{self, WebhookEventType};
+
 /// Circuit breaker states
 #[derive(Debug, Clone, Copy, PartialEq, Eq)]
 pub enum CircuitState {
@@ -285,6 +288,16 @@ impl TradingCircuitBreaker {
             self.total_trips.fetch_add(1, Ordering::SeqCst);
 
             warn!("Circuit breaker TRIPPED: {}", reason);
+            #[cfg(feature = "api")]
+            webhooks::emit(
+                WebhookEventType::CircuitBreaker,
+                serde_json::json!({
+                    "source": "trading_circuit_breaker",
+                    "state": "open",
+                    "reason": reason.to_string(),
+                    "halted_at": Utc::now(),
+                }),
+            );
         }
     }
 
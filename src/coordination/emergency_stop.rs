@@ -3,10 +3,12 @@
 //! Provides emergency shutdown capabilities for critical situations:
 //! - Immediate trading halt
 //! - Cancel all pending orders
-//! - Close open positions (optional)
+//! - Close open positions via a sequenced unwind (optional)
 //! - Persist emergency state
 //! - Prevent new operations
 
+use super::cancel_all::{cancel_all, CancelAllConfig, CancelScope};
+use super::unwind::{UnwindConfig, UnwindExecutor, UnwindPlan, UnwindPosition, UnwindProgress};
 use crate::adapters::{PolymarketClient, PostgresStore};
 use crate::error::{PloyError, Result};
 use crate::strategy::position_manager::PositionManager;
@@ -16,7 +18,7 @@ use serde::{Deserialize, Serialize};
 use sqlx::types::chrono::{DateTime, Utc};
 use std::sync::atomic::{AtomicBool, Ordering};
 use std::sync::Arc;
-use tokio::sync::RwLock;
+use tokio::sync::{mpsc, RwLock};
 use tracing::{error, info, warn};
 
 /// Emergency stop reason
@@ -85,8 +87,12 @@ pub struct EmergencyStopConfig {
     pub close_open_positions: bool,
     /// Maximum time to wait for order cancellations (seconds)
     pub cancel_timeout_secs: u64,
-    /// Maximum time to wait for position closures (seconds)
+    /// Concurrency and retries for the cancel sweep
+    pub cancel: CancelAllConfig,
+    /// Maximum time to wait for each unwind chunk order (seconds)
     pub close_timeout_secs: u64,
+    /// Chunking and price limits for the position unwind
+    pub unwind: UnwindConfig,
 }
 
 impl Default for EmergencyStopConfig {
@@ -95,7 +101,9 @@ impl Default for EmergencyStopConfig {
             cancel_pending_orders: true,
             close_open_positions: false, // Don't auto-close by default (too risky)
             cancel_timeout_secs: 30,
+            cancel: CancelAllConfig::default(),
             close_timeout_secs: 60,
+            unwind: UnwindConfig::default(),
         }
     }
 }
@@ -108,6 +116,7 @@ pub struct EmergencyStopManager {
     position_manager: Arc<PositionManager>,
     store: Arc<PostgresStore>,
     config: EmergencyStopConfig,
+    unwind_progress: Option<mpsc::UnboundedSender<UnwindProgress>>,
 }
 
 impl EmergencyStopManager {
@@ -125,9 +134,16 @@ impl EmergencyStopManager {
             position_manager,
             store,
             config,
+            unwind_progress: None,
         }
     }
 
+    /// Stream unwind progress (per chunk / per position) to `tx`
+    pub fn with_unwind_progress(mut self, tx: mpsc::UnboundedSender<UnwindProgress>) -> Self {
+        self.unwind_progress = Some(tx);
+        self
+    }
+
     /// Check if emergency stop is active (fast atomic check)
     pub fn is_stopped(&self) -> bool {
         self.is_stopped.load(Ordering::Relaxed)
@@ -225,43 +241,29 @@ impl EmergencyStopManager {
         }
     }
 
-    /// Cancel all pending orders (parallel with timeout)
+    /// Cancel all pending orders venue-wide (paginated, concurrent, with retries)
     async fn cancel_all_orders(&self) -> Result<usize> {
-        let orders = self.client.get_open_orders().await?;
-
-        if orders.is_empty() {
-            return Ok(0);
-        }
-
         let timeout_dur = std::time::Duration::from_secs(self.config.cancel_timeout_secs);
-        let client = self.client.clone();
-
-        let futs: Vec<_> = orders
-            .iter()
-            .map(|order| {
-                let client = client.clone();
-                let order_id = order.id.clone();
-                async move {
-                    match client.cancel_order(&order_id).await {
-                        Ok(true) => {
-                            info!("Cancelled order: {}", order_id);
-                            true
-                        }
-                        Ok(false) => {
-                            warn!("Order {} already cancelled or filled", order_id);
-                            false
-                        }
-                        Err(e) => {
-                            error!("Failed to cancel order {}: {}", order_id, e);
-                            false
-                        }
-                    }
-                }
-            })
-            .collect();
+        let sweep = cancel_all(
+            self.client.as_ref(),
+            &CancelScope::all(),
+            &self.config.cancel,
+        );
 
-        match tokio::time::timeout(timeout_dur, futures_util::future::join_all(futs)).await {
-            Ok(results) => Ok(results.into_iter().filter(|r| *r).count()),
+        match tokio::time::timeout(timeout_dur, sweep).await {
+            Ok(summary) => {
+                let summary = summary?;
+                for failure in &summary.failed {
+                    error!(
+                        "Failed to cancel order {} after {} attempts: {}",
+                        failure.order_id, failure.attempts, failure.error
+                    );
+                }
+                if summary.truncated {
+                    error!("Open-order listing was truncated; some orders may still be resting");
+                }
+                Ok(summary.cancelled.len())
+            }
             Err(_) => {
                 error!(
                     "Order cancellation timed out after {}s",
@@ -275,48 +277,95 @@ impl EmergencyStopManager {
         }
     }
 
+    fn unwind_executor(&self) -> UnwindExecutor {
+        let executor = UnwindExecutor::new(self.client.clone(), self.config.unwind.clone())
+            .with_chunk_timeout(std::time::Duration::from_secs(
+                self.config.close_timeout_secs,
+            ));
+        match &self.unwind_progress {
+            Some(tx) => executor.with_progress(tx.clone()),
+            None => executor,
+        }
+    }
+
+    /// Plan (and simulate) the unwind of all open positions without trading
+    pub async fn plan_unwind(&self) -> Result<UnwindPlan> {
+        let positions = self.position_manager.get_open_positions().await?;
+        let positions = positions.iter().map(UnwindPosition::from).collect();
+        Ok(self.unwind_executor().plan(positions).await)
+    }
+
     /// Close all open positions
+    ///
+    /// Sells are sequenced by the unwind planner, each chunk bounded by
+    /// `close_timeout_secs`. Fully sold positions are closed at their realized
+    /// average price; partially sold ones are reduced by the shares that did
+    /// fill, so the book matches the venue even when the unwind stops early.
     async fn close_all_positions(&self) -> Result<usize> {
-        // Get all open positions
+        let executor = self.unwind_executor();
         let positions = self.position_manager.get_open_positions().await?;
+        let plan = executor
+            .plan(positions.iter().map(UnwindPosition::from).collect())
+            .await;
+        let report = executor.execute(&plan).await?;
 
         let mut closed = 0;
-        for pos in positions {
-            // Get current market price
-            match self.client.get_best_prices(&pos.token_id).await {
-                Ok((Some(bid), _)) => {
-                    // Close at current bid price (taker order, assume 2% market depth)
-                    match self
-                        .position_manager
-                        .close_position(
-                            pos.id,
-                            bid,
-                            OrderType::Taker,
-                            dec!(0.02), // 2% of market depth
-                        )
-                        .await
-                    {
-                        Ok(pnl) => {
-                            closed += 1;
-                            info!(
-                                "Closed position #{} for {} (PnL: ${:.2})",
-                                pos.id, pos.symbol, pnl
-                            );
-                        }
-                        Err(e) => {
-                            error!("Failed to close position #{}: {}", pos.id, e);
-                        }
-                    }
+        for leg in &report.legs {
+            let Some(position_id) = leg.position.position_id else {
+                continue;
+            };
+            let Some(exit_price) = leg.avg_price else {
+                warn!(
+                    "Position #{} ({}) not unwound: {} shares remain",
+                    position_id, leg.position.symbol, leg.residual_shares
+                );
+                continue;
+            };
+            if !leg.is_closed() {
+                warn!(
+                    "Position #{} ({}) partially unwound: {} sold, {} shares remain",
+                    position_id, leg.position.symbol, leg.filled_shares, leg.residual_shares
+                );
+                if let Err(e) = self
+                    .position_manager
+                    .reduce_position(position_id, leg.filled_shares, exit_price)
+                    .await
+                {
+                    error!("Failed to reduce position #{}: {}", position_id, e);
                 }
-                Ok((None, _)) => {
-                    warn!("No bid price available for position #{}", pos.id);
+                continue;
+            }
+            match self
+                .position_manager
+                .close_position(
+                    position_id,
+                    exit_price,
+                    OrderType::Taker,
+                    dec!(0.02), // 2% of market depth
+                )
+                .await
+            {
+                Ok(pnl) => {
+                    closed += 1;
+                    info!(
+                        "Closed position #{} for {} (PnL: ${:.2})",
+                        position_id, leg.position.symbol, pnl
+                    );
                 }
                 Err(e) => {
-                    error!("Failed to get price for position #{}: {}", pos.id, e);
+                    error!("Failed to close position #{}: {}", position_id, e);
                 }
             }
         }
 
+        info!(
+            "Unwind finished: {} shares sold for ${:.2} (cost vs mid ${:.2}, expected ${:.2}), {} shares residual",
+            report.filled_shares(),
+            report.proceeds(),
+            report.realized_cost(),
+            report.expected_cost,
+            report.residual_shares()
+        );
         Ok(closed)
     }
 
@@ -0,0 +1,626 @@
+//! Leader Election for Hot-Standby Failover
+//!
+//! Lets a standby instance share the database with the active one and take
+//! over when it disappears, without both ever trading at once:
+//! - The leader holds a session-level Postgres advisory lock on a dedicated
+//!   connection and renews a lease row (`runtime_leader_leases`) every heartbeat
+//! - A leader that cannot renew within `lease_timeout_ms` fences itself (stops
+//!   executing orders and shuts the runtime down); a renewal rejected because
+//!   the epoch moved on fences it immediately
+//! - A standby only activates once the lease is older than
+//!   `lease_timeout_ms + takeover_grace_ms` (or was released), terminating the
+//!   lock holder's session if a hung leader still holds the lock
+//! - The leader checkpoints in-memory runtime state to the event store; the new
+//!   leader restores it after the usual database restores
+//!
+//! Worst-case trading gap after a leader crash is roughly
+//! `lease_timeout_ms + takeover_grace_ms + heartbeat_interval_ms` plus startup.
+
+use chrono::{DateTime, Utc};
+use serde::{Deserialize, Serialize};
+use sqlx::{PgConnection, PgPool};
+use std::sync::atomic::{AtomicBool, AtomicI64, Ordering};
+use std::sync::Arc;
+use std::time::{Duration, Instant};
+use tokio::sync::broadcast;
+use tracing::{error, info, warn};
+
+use crate::coordinator::CoordinatorHandle;
+use crate::error::{PloyError, Result};
+use crate::persistence::{EventMetadata, EventStore};
+use crate::platform::AgentStatus;
+
+const AGGREGATE_TYPE: &str = "runtime_leadership";
+const CHECKPOINT_EVENT: &str = "RuntimeCheckpoint";
+
+/// Configuration for leader election
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(default)]
+pub struct LeaderElectionConfig {
+    /// Enable hot-standby election (default: false, single instance)
+    pub enabled: bool,
+    /// Identity of this instance (default: `$HOSTNAME:<pid>`)
+    pub instance_id: Option<String>,
+    /// Election name; instances with the same name compete (default: per account)
+    pub lock_name: Option<String>,
+    /// Lease renewal / standby poll interval (default: 1s)
+    pub heartbeat_interval_ms: u64,
+    /// Leader fences itself after this long without a renewal (default: 10s)
+    pub lease_timeout_ms: u64,
+    /// Extra wait before a standby takes over a stale lease; raised to at
+    /// least two heartbeat intervals (default: 3s)
+    pub takeover_grace_ms: u64,
+    /// Interval between runtime state checkpoints (default: 15s)
+    pub checkpoint_interval_secs: u64,
+}
+
+impl Default for LeaderElectionConfig {
+    fn default() -> Self {
+        Self {
+            enabled: false,
+            instance_id: None,
+            lock_name: None,
+            heartbeat_interval_ms: 1_000,
+            lease_timeout_ms: 10_000,
+            takeover_grace_ms: 3_000,
+            checkpoint_interval_secs: 15,
+        }
+    }
+}
+
+impl LeaderElectionConfig {
+    /// Lease age (ms) after which a standby may take over
+    pub fn takeover_after_ms(&self) -> u64 {
+        let grace = self
+            .takeover_grace_ms
+            .max(self.heartbeat_interval_ms.saturating_mul(2));
+        self.lease_timeout_ms.saturating_add(grace)
+    }
+}
+
+/// Whether this instance may execute orders. Without election every
+/// instance is the leader.
+#[derive(Debug, Clone)]
+pub struct Leadership {
+    active: Arc<AtomicBool>,
+    epoch: Arc<AtomicI64>,
+}
+
+impl Default for Leadership {
+    fn default() -> Self {
+        Self {
+            active: Arc::new(AtomicBool::new(true)),
+            epoch: Arc::new(AtomicI64::new(0)),
+        }
+    }
+}
+
+impl Leadership {
+    /// Handle for an instance that starts as standby
+    pub fn standby() -> Self {
+        let leadership = Self::default();
+        leadership.active.store(false, Ordering::SeqCst);
+        leadership
+    }
+
+    pub fn is_leader(&self) -> bool {
+        self.active.load(Ordering::SeqCst)
+    }
+
+    /// Lease epoch held while leader (0 without election)
+    pub fn epoch(&self) -> i64 {
+        self.epoch.load(Ordering::SeqCst)
+    }
+
+    fn activate(&self, epoch: i64) {
+        self.epoch.store(epoch, Ordering::SeqCst);
+        self.active.store(true, Ordering::SeqCst);
+    }
+
+    fn fence(&self) {
+        self.active.store(false, Ordering::SeqCst);
+    }
+}
+
+/// In-memory runtime state handed from one leader to the next
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+pub struct RuntimeCheckpoint {
+    pub holder_id: String,
+    pub epoch: i64,
+    /// Agents paused by an operator or monitor; not persisted elsewhere
+    pub paused_agents: Vec<String>,
+    pub open_positions: usize,
+    pub taken_at: DateTime<Utc>,
+}
+
+/// Current lease row as seen by a standby
+#[derive(Debug, Clone, PartialEq)]
+struct LeaseView {
+    holder_id: String,
+    epoch: i64,
+    age_ms: i64,
+    released: bool,
+}
+
+impl LeaseView {
+    /// Not renewed for longer than the holder's own fence deadline
+    fn stale(&self, config: &LeaderElectionConfig) -> bool {
+        self.age_ms > config.takeover_after_ms() as i64
+    }
+
+    /// Whether the previous holder can no longer be trading
+    fn takeover_allowed(&self, config: &LeaderElectionConfig, instance_id: &str) -> bool {
+        self.released || self.holder_id == instance_id || self.stale(config)
+    }
+}
+
+/// Stable advisory lock key for an election name (FNV-1a)
+fn lock_key(name: &str) -> i64 {
+    let mut hash: u64 = 0xcbf2_9ce4_8422_2325;
+    for byte in name.bytes() {
+        hash ^= byte as u64;
+        hash = hash.wrapping_mul(0x0100_0000_01b3);
+    }
+    hash as i64
+}
+
+/// Postgres-backed leader elector
+pub struct LeaderElector {
+    config: LeaderElectionConfig,
+    pool: PgPool,
+    events: EventStore,
+    instance_id: String,
+    lock_name: String,
+    lock_key: i64,
+    /// Connection holding the advisory lock; the lock dies with it
+    conn: Option<PgConnection>,
+    leadership: Leadership,
+}
+
+impl LeaderElector {
+    pub fn new(config: LeaderElectionConfig, pool: PgPool, account_id: &str) -> Self {
+        let instance_id = config.instance_id.clone().unwrap_or_else(|| {
+            let host = std::env::var("HOSTNAME").unwrap_or_else(|_| "ploy".to_string());
+            format!("{}:{}", host, std::process::id())
+        });
+        let lock_name = config
+            .lock_name
+            .clone()
+            .unwrap_or_else(|| format!("ploy-runtime:{account_id}"));
+        Self {
+            lock_key: lock_key(&lock_name),
+            events: EventStore::new(pool.clone()),
+            config,
+            pool,
+            instance_id,
+            lock_name,
+            conn: None,
+            leadership: Leadership::standby(),
+        }
+    }
+
+    /// Handle the coordinator checks before executing orders
+    pub fn leadership(&self) -> Leadership {
+        self.leadership.clone()
+    }
+
+    pub fn instance_id(&self) -> &str {
+        &self.instance_id
+    }
+
+    async fn ensure_schema(&self) -> Result<()> {
+        sqlx::query(
+            r#"
+            CREATE TABLE IF NOT EXISTS runtime_leader_leases (
+                lock_name TEXT PRIMARY KEY,
+                holder_id TEXT NOT NULL,
+                epoch BIGINT NOT NULL DEFAULT 1,
+                acquired_at TIMESTAMPTZ NOT NULL DEFAULT NOW(),
+                heartbeat_at TIMESTAMPTZ NOT NULL DEFAULT NOW(),
+                released_at TIMESTAMPTZ
+            )
+            "#,
+        )
+        .execute(&self.pool)
+        .await?;
+        Ok(())
+    }
+
+    async fn read_lease(&self) -> Result<Option<LeaseView>> {
+        let row = sqlx::query_as::<_, (String, i64, f64, bool)>(
+            r#"
+            SELECT holder_id, epoch,
+                   (EXTRACT(EPOCH FROM (NOW() - heartbeat_at)) * 1000)::float8,
+                   released_at IS NOT NULL
+            FROM runtime_leader_leases
+            WHERE lock_name = $1
+            "#,
+        )
+        .bind(&self.lock_name)
+        .fetch_optional(&self.pool)
+        .await?;
+        Ok(row.map(|(holder_id, epoch, age_ms, released)| LeaseView {
+            holder_id,
+            epoch,
+            age_ms: age_ms as i64,
+            released,
+        }))
+    }
+
+    /// Block until this instance is the leader. Runs as a standby until then.
+    pub async fn wait_for_leadership(&mut self) -> Result<()> {
+        self.ensure_schema().await?;
+        let poll = Duration::from_millis(self.config.heartbeat_interval_ms.max(100));
+        let mut standby_logged = false;
+
+        loop {
+            if self.conn.is_none() {
+                match self.pool.acquire().await {
+                    Ok(conn) => self.conn = Some(conn.detach()),
+                    Err(e) => {
+                        warn!(error = %e, "leader election: failed to open lock connection");
+                        tokio::time::sleep(poll).await;
+                        continue;
+                    }
+                }
+            }
+
+            match self.try_acquire().await {
+                Ok(true) => return Ok(()),
+                Ok(false) => {
+                    if !standby_logged {
+                        info!(
+                            instance_id = %self.instance_id,
+                            lock_name = %self.lock_name,
+                            "running as hot standby; waiting for leader lease"
+                        );
+                        standby_logged = true;
+                    }
+                }
+                Err(e) => {
+                    warn!(error = %e, "leader election attempt failed; reconnecting");
+                    self.conn = None;
+                }
+            }
+            tokio::time::sleep(poll).await;
+        }
+    }
+
+    async fn try_acquire(&mut self) -> Result<bool> {
+        let conn = self
+            .conn
+            .as_mut()
+            .ok_or_else(|| PloyError::Internal("no lock connection".to_string()))?;
+        let locked: bool = sqlx::query_scalar("SELECT pg_try_advisory_lock($1)")
+            .bind(self.lock_key)
+            .fetch_one(&mut *conn)
+            .await?;
+
+        let lease = self.read_lease().await?;
+        if !locked {
+            if let Some(lease) = lease.filter(|l| !l.released && l.stale(&self.config)) {
+                // The holder stopped heartbeating but its session still holds
+                // the lock; it has fenced itself by now, so end the session.
+                warn!(
+                    holder_id = %lease.holder_id,
+                    epoch = lease.epoch,
+                    lease_age_ms = lease.age_ms,
+                    "leader lease expired while lock held; terminating holder session"
+                );
+                sqlx::query(
+                    r#"
+                    SELECT pg_terminate_backend(pid)
+                    FROM pg_locks
+                    WHERE locktype = 'advisory' AND granted AND objsubid = 1
+                      AND ((classid::bigint << 32) | objid::bigint) = $1
+                    "#,
+                )
+                .bind(self.lock_key)
+                .execute(&self.pool)
+                .await?;
+            }
+            return Ok(false);
+        }
+
+        // The lock can come free before the old leader's fence deadline (its
+        // connection dropped); hold it and wait that deadline out.
+        if let Some(lease) = &lease {
+            if !lease.takeover_allowed(&self.config, &self.instance_id) {
+                let wait_ms = self.config.takeover_after_ms() as i64 - lease.age_ms + 1;
+                info!(
+                    holder_id = %lease.holder_id,
+                    wait_ms,
+                    "lock acquired; waiting out previous leader's lease"
+                );
+                tokio::time::sleep(Duration::from_millis(wait_ms.max(0) as u64)).await;
+            }
+        }
+
+        let conn = self
+            .conn
+            .as_mut()
+            .ok_or_else(|| PloyError::Internal("no lock connection".to_string()))?;
+        let epoch: i64 = sqlx::query_scalar(
+            r#"
+            INSERT INTO runtime_leader_leases (lock_name, holder_id, epoch)
+            VALUES ($1, $2, 1)
+            ON CONFLICT (lock_name) DO UPDATE SET
+                holder_id = EXCLUDED.holder_id,
+                epoch = runtime_leader_leases.epoch + 1,
+                acquired_at = NOW(),
+                heartbeat_at = NOW(),
+                released_at = NULL
+            RETURNING epoch
+            "#,
+        )
+        .bind(&self.lock_name)
+        .bind(&self.instance_id)
+        .fetch_one(&mut *conn)
+        .await?;
+
+        self.leadership.activate(epoch);
+        let gap_ms = lease.as_ref().filter(|l| !l.released).map(|l| l.age_ms);
+        info!(
+            instance_id = %self.instance_id,
+            epoch,
+            previous_holder = ?lease.as_ref().map(|l| &l.holder_id),
+            gap_ms = ?gap_ms,
+            "acquired runtime leadership"
+        );
+        self.append_event(
+            "LeadershipAcquired",
+            serde_json::json!({
+                "holder_id": self.instance_id,
+                "epoch": epoch,
+                "previous_holder": lease.as_ref().map(|l| &l.holder_id),
+                "previous_epoch": lease.as_ref().map(|l| l.epoch),
+                "gap_ms": gap_ms,
+            }),
+        )
+        .await;
+        Ok(true)
+    }
+
+    /// Latest runtime checkpoint written by any leader of this election
+    pub async fn last_checkpoint(&self) -> Result<Option<RuntimeCheckpoint>> {
+        let event = self
+            .events
+            .get_latest_event(&self.lock_name, AGGREGATE_TYPE, CHECKPOINT_EVENT)
+            .await?;
+        Ok(event.and_then(|e| serde_json::from_value(e.payload).ok()))
+    }
+
+    /// Re-apply the previous leader's in-memory state after takeover
+    pub async fn restore_checkpoint(&self, handle: &CoordinatorHandle) -> Result<()> {
+        let Some(checkpoint) = self.last_checkpoint().await? else {
+            return Ok(());
+        };
+        if checkpoint.holder_id == self.instance_id {
+            return Ok(());
+        }
+        let known = handle.read_state().await.agents;
+        for agent_id in &checkpoint.paused_agents {
+            if !known.contains_key(agent_id) {
+                continue;
+            }
+            if let Err(e) = handle.pause_agent(agent_id).await {
+                warn!(agent_id = %agent_id, error = %e, "failed to restore agent pause");
+            }
+        }
+        info!(
+            previous_holder = %checkpoint.holder_id,
+            previous_epoch = checkpoint.epoch,
+            paused_agents = checkpoint.paused_agents.len(),
+            checkpoint_age_secs = (Utc::now() - checkpoint.taken_at).num_seconds(),
+            "restored runtime checkpoint from previous leader"
+        );
+        Ok(())
+    }
+
+    async fn checkpoint(&self, handle: &CoordinatorHandle) {
+        let state = handle.read_state().await;
+        let mut paused_agents: Vec<String> = state
+            .agents
+            .iter()
+            .filter(|(_, a)| a.status == AgentStatus::Paused)
+            .map(|(id, _)| id.clone())
+            .collect();
+        paused_agents.sort();
+        let checkpoint = RuntimeCheckpoint {
+            holder_id: self.instance_id.clone(),
+            epoch: self.leadership.epoch(),
+            paused_agents,
+            open_positions: state.positions.len(),
+            taken_at: Utc::now(),
+        };
+        match serde_json::to_value(&checkpoint) {
+            Ok(payload) => self.append_event(CHECKPOINT_EVENT, payload).await,
+            Err(e) => warn!(error = %e, "failed to serialize runtime checkpoint"),
+        }
+    }
+
+    async fn append_event(&self, event_type: &str, payload: serde_json::Value) {
+        let epoch = i32::try_from(self.leadership.epoch()).unwrap_or(i32::MAX);
+        let metadata = EventMetadata::default().with_triggered_by(&self.instance_id);
+        if let Err(e) = self
+            .events
+            .append(
+                &self.lock_name,
+                AGGREGATE_TYPE,
+                event_type,
+                epoch,
+                payload,
+                Some(metadata),
+            )
+            .await
+        {
+            warn!(event_type, error = %e, "failed to append leadership event");
+        }
+    }
+
+    /// Renew the lease on the lock connection. `Ok(false)` means another
+    /// instance has claimed a newer epoch.
+    async fn renew(&mut self) -> Result<bool> {
+        let timeout = Duration::from_millis(self.config.heartbeat_interval_ms.max(100));
+        let conn = self
+            .conn
+            .as_mut()
+            .ok_or_else(|| PloyError::Internal("no lock connection".to_string()))?;
+        let query = sqlx::query(
+            r#"
+            UPDATE runtime_leader_leases
+            SET heartbeat_at = NOW()
+            WHERE lock_name = $1 AND holder_id = $2 AND epoch = $3
+            "#,
+        )
+        .bind(&self.lock_name)
+        .bind(&self.instance_id)
+        .bind(self.leadership.epoch())
+        .execute(&mut *conn);
+        let result = tokio::time::timeout(timeout, query)
+            .await
+            .map_err(|_| PloyError::Internal("lease renewal timed out".to_string()))??;
+        Ok(result.rows_affected() == 1)
+    }
+
+    fn fence(&mut self, shutdown_tx: &broadcast::Sender<()>, reason: &str) {
+        self.leadership.fence();
+        self.conn = None;
+        error!(
+            instance_id = %self.instance_id,
+            epoch = self.leadership.epoch(),
+            reason,
+            "lost runtime leadership; order execution fenced, shutting down"
+        );
+        let _ = shutdown_tx.send(());
+    }
+
+    async fn release(&mut self) {
+        if !self.leadership.is_leader() {
+            return;
+        }
+        self.append_event(
+            "LeadershipReleased",
+            serde_json::json!({
+                "holder_id": self.instance_id,
+                "epoch": self.leadership.epoch(),
+            }),
+        )
+        .await;
+        self.leadership.fence();
+        if let Some(conn) = self.conn.as_mut() {
+            let released = sqlx::query(
+                r#"
+                UPDATE runtime_leader_leases
+                SET released_at = NOW()
+                WHERE lock_name = $1 AND holder_id = $2 AND epoch = $3
+                "#,
+            )
+            .bind(&self.lock_name)
+            .bind(&self.instance_id)
+            .bind(self.leadership.epoch())
+            .execute(&mut *conn)
+            .await;
+            if let Err(e) = released {
+                warn!(error = %e, "failed to mark leader lease released");
+            }
+        }
+        // Dropping the connection frees the advisory lock.
+        self.conn = None;
+        info!(instance_id = %self.instance_id, "released runtime leadership");
+    }
+
+    /// Hold the lease until shutdown, checkpointing runtime state. Fences
+    /// and triggers shutdown if the lease is lost.
+    pub async fn run(mut self, handle: CoordinatorHandle, shutdown_tx: broadcast::Sender<()>) {
+        let mut shutdown_rx = shutdown_tx.subscribe();
+        let lease_timeout = Duration::from_millis(self.config.lease_timeout_ms);
+        let mut heartbeat = tokio::time::interval(Duration::from_millis(
+            self.config.heartbeat_interval_ms.max(100),
+        ));
+        heartbeat.set_missed_tick_behavior(tokio::time::MissedTickBehavior::Delay);
+        let mut checkpoint = tokio::time::interval(Duration::from_secs(
+            self.config.checkpoint_interval_secs.max(1),
+        ));
+        let mut renewed_at = Instant::now();
+
+        loop {
+            tokio::select! {
+                _ = heartbeat.tick() => {
+                    let sent = Instant::now();
+                    match self.renew().await {
+                        Ok(true) => renewed_at = sent,
+                        Ok(false) => {
+                            self.fence(&shutdown_tx, "lease claimed by another instance");
+                            return;
+                        }
+                        Err(e) => {
+                            warn!(error = %e, "leader lease renewal failed");
+                            if renewed_at.elapsed() > lease_timeout {
+                                self.fence(&shutdown_tx, "lease not renewed within timeout");
+                                return;
+                            }
+                        }
+                    }
+                }
+                _ = checkpoint.tick() => self.checkpoint(&handle).await,
+                _ = shutdown_rx.recv() => break,
+            }
+        }
+
+        self.checkpoint(&handle).await;
+        self.release().await;
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_takeover_waits_for_lease_timeout_plus_grace() {
+        let config = LeaderElectionConfig {
+            heartbeat_interval_ms: 2_000,
+            lease_timeout_ms: 10_000,
+            takeover_grace_ms: 1_000,
+            ..Default::default()
+        };
+        // Grace is raised to two heartbeats so the leader's own fence check
+        // always fires first.
+        assert_eq!(config.takeover_after_ms(), 14_000);
+
+        let lease = |age_ms, released| LeaseView {
+            holder_id: "a:1".to_string(),
+            epoch: 3,
+            age_ms,
+            released,
+        };
+        assert!(!lease(13_000, false).takeover_allowed(&config, "b:2"));
+        assert!(lease(14_001, false).takeover_allowed(&config, "b:2"));
+        assert!(lease(500, true).takeover_allowed(&config, "b:2"));
+        // Restarting under the same identity re-claims immediately.
+        assert!(lease(500, false).takeover_allowed(&config, "a:1"));
+    }
+
+    #[test]
+    fn test_lock_key_is_stable_and_leadership_fences() {
+        assert_eq!(
+            lock_key("ploy-runtime:default"),
+            lock_key("ploy-runtime:default")
+        );
+        assert_ne!(
+            lock_key("ploy-runtime:default"),
+            lock_key("ploy-runtime:alt")
+        );
+
+        assert!(Leadership::default().is_leader());
+        let leadership = Leadership::standby();
+        let shared = leadership.clone();
+        assert!(!shared.is_leader());
+        leadership.activate(7);
+        assert!(shared.is_leader());
+        assert_eq!(shared.epoch(), 7);
+        leadership.fence();
+        assert!(!shared.is_leader());
+    }
+}
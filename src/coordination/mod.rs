@@ -5,15 +5,30 @@
 //! - Circuit breaker for trading operations
 //! - Backpressure control for quote processing
 //! - Graceful shutdown handling
+//! - Emergency position unwind planning
+//! - Leader election for hot-standby failover
+//! - Venue-wide bulk order cancellation
 
+pub mod cancel_all;
 pub mod circuit_breaker;
 pub mod emergency_stop;
+pub mod leader;
 pub mod lifecycle;
 pub mod shutdown;
+pub mod unwind;
 
+pub use cancel_all::{
+    cancel_all, cancel_matching, list_open_orders, CancelAllConfig, CancelAllSummary,
+    CancelFailure, CancelScope, CancelVenue, OpenOrder, OpenOrdersPage,
+};
 pub use circuit_breaker::{CircuitState, TradingCircuitBreaker, TradingCircuitBreakerConfig};
 pub use emergency_stop::{
     EmergencyReason, EmergencyState, EmergencyStopConfig, EmergencyStopManager,
 };
+pub use leader::{LeaderElectionConfig, LeaderElector, Leadership, RuntimeCheckpoint};
 pub use lifecycle::{ComponentState, LifecycleEvent, LifecycleManager};
 pub use shutdown::{GracefulShutdown, ShutdownSignal};
+pub use unwind::{
+    BookLevel, UnwindBook, UnwindChunk, UnwindConfig, UnwindExecutor, UnwindLeg, UnwindLegOutcome,
+    UnwindPlan, UnwindPlanner, UnwindPosition, UnwindProgress, UnwindReport,
+};
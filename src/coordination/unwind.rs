@@ -0,0 +1,658 @@
+//! Emergency Unwind Planner
+//!
+//! Turns an emergency close into a sequenced liquidation instead of a
+//! bookkeeping flag:
+//! - Positions are ordered most-liquid first, then by expected cost vs mid
+//! - Each position is sold in chunks against the visible bid book
+//! - Chunk limits never go below a floor derived from the mid price
+//! - The plan is simulated up front (expected proceeds, cost vs mid, residual)
+//! - Execution reports progress per chunk and per position
+
+use crate::adapters::polymarket_clob::OrderBookResponse;
+use crate::adapters::PolymarketClient;
+use crate::domain::{OrderRequest, Side, TimeInForce};
+use crate::error::{PloyError, Result};
+use crate::strategy::position_manager::Position;
+use rust_decimal::prelude::ToPrimitive;
+use rust_decimal::Decimal;
+use rust_decimal_macros::dec;
+use serde::{Deserialize, Serialize};
+use std::sync::Arc;
+use tokio::sync::mpsc;
+use tracing::{error, info, warn};
+
+/// Unwind planner configuration
+#[derive(Debug, Clone)]
+pub struct UnwindConfig {
+    /// Maximum shares per child sell order
+    pub max_chunk_shares: u64,
+    /// Maximum notional (at mid) per child sell order (USD)
+    pub max_chunk_notional: Decimal,
+    /// Lowest acceptable fill, in basis points below mid
+    pub max_slippage_bps: u32,
+    /// Pause between chunks of the same position (lets the book refill)
+    pub chunk_interval_ms: u64,
+}
+
+impl Default for UnwindConfig {
+    fn default() -> Self {
+        Self {
+            max_chunk_shares: 250,
+            max_chunk_notional: dec!(100),
+            max_slippage_bps: 1500, // 15% below mid
+            chunk_interval_ms: 500,
+        }
+    }
+}
+
+/// One bid level of the order book
+#[derive(Debug, Clone, Copy, PartialEq, Serialize, Deserialize)]
+pub struct BookLevel {
+    pub price: Decimal,
+    pub size: Decimal,
+}
+
+/// Order book snapshot used for planning
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+pub struct UnwindBook {
+    /// Bids sorted best (highest) first
+    pub bids: Vec<BookLevel>,
+    pub best_ask: Option<Decimal>,
+}
+
+impl UnwindBook {
+    pub fn new(mut bids: Vec<BookLevel>, best_ask: Option<Decimal>) -> Self {
+        bids.retain(|l| l.price > Decimal::ZERO && l.size > Decimal::ZERO);
+        bids.sort_by(|a, b| b.price.cmp(&a.price));
+        Self { bids, best_ask }
+    }
+
+    pub fn from_response(book: &OrderBookResponse) -> Self {
+        let parse = |price: &str, size: &str| {
+            Some(BookLevel {
+                price: price.parse().ok()?,
+                size: size.parse().ok()?,
+            })
+        };
+        let bids = book
+            .bids
+            .iter()
+            .filter_map(|l| parse(&l.price, &l.size))
+            .collect();
+        let best_ask = book
+            .asks
+            .iter()
+            .filter_map(|l| l.price.parse::<Decimal>().ok())
+            .filter(|p| *p > Decimal::ZERO)
+            .min();
+        Self::new(bids, best_ask)
+    }
+
+    pub fn best_bid(&self) -> Option<Decimal> {
+        self.bids.first().map(|l| l.price)
+    }
+
+    /// Mid price; falls back to the best bid for one-sided books
+    pub fn mid(&self) -> Option<Decimal> {
+        match (self.best_bid(), self.best_ask) {
+            (Some(bid), Some(ask)) if ask >= bid => Some((bid + ask) / dec!(2)),
+            (Some(bid), _) => Some(bid),
+            _ => None,
+        }
+    }
+}
+
+/// Position to unwind
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct UnwindPosition {
+    /// `positions.id` when the position is tracked in the database
+    pub position_id: Option<i32>,
+    pub symbol: String,
+    pub token_id: String,
+    pub market_side: Side,
+    pub shares: u64,
+    pub avg_entry_price: Decimal,
+}
+
+impl From<&Position> for UnwindPosition {
+    fn from(pos: &Position) -> Self {
+        Self {
+            position_id: Some(pos.id),
+            symbol: pos.symbol.clone(),
+            token_id: pos.token_id.clone(),
+            market_side: pos.market_side,
+            shares: pos.shares.max(0) as u64,
+            avg_entry_price: pos.avg_entry_price,
+        }
+    }
+}
+
+/// One child sell order of a leg
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct UnwindChunk {
+    pub shares: u64,
+    /// Worst price the chunk is allowed to fill at
+    pub limit_price: Decimal,
+    /// Simulated average fill price against the snapshot book
+    pub expected_price: Decimal,
+}
+
+/// Planned exit for one position
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct UnwindLeg {
+    pub position: UnwindPosition,
+    pub mid: Option<Decimal>,
+    /// Lowest acceptable price (mid minus max slippage)
+    pub floor_price: Option<Decimal>,
+    pub chunks: Vec<UnwindChunk>,
+    /// Bid notional available at or above the floor (USD)
+    pub liquidity_usd: Decimal,
+    pub expected_proceeds: Decimal,
+    /// Proceeds given up vs selling everything at mid
+    pub expected_cost: Decimal,
+    /// Expected realized PnL vs entry on the fillable shares
+    pub expected_pnl: Decimal,
+    /// Shares the visible book cannot absorb above the floor
+    pub residual_shares: u64,
+}
+
+impl UnwindLeg {
+    pub fn fillable_shares(&self) -> u64 {
+        self.chunks.iter().map(|c| c.shares).sum()
+    }
+
+    /// Expected cost per fillable share (sort key for loss minimization)
+    fn cost_per_share(&self) -> Decimal {
+        let fillable = self.fillable_shares();
+        if fillable == 0 {
+            return Decimal::MAX;
+        }
+        self.expected_cost / Decimal::from(fillable)
+    }
+}
+
+/// Sequenced unwind plan
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+pub struct UnwindPlan {
+    /// Legs in execution order
+    pub legs: Vec<UnwindLeg>,
+}
+
+impl UnwindPlan {
+    pub fn total_shares(&self) -> u64 {
+        self.legs.iter().map(|l| l.position.shares).sum()
+    }
+
+    pub fn expected_proceeds(&self) -> Decimal {
+        self.legs.iter().map(|l| l.expected_proceeds).sum()
+    }
+
+    pub fn expected_cost(&self) -> Decimal {
+        self.legs.iter().map(|l| l.expected_cost).sum()
+    }
+
+    pub fn residual_shares(&self) -> u64 {
+        self.legs.iter().map(|l| l.residual_shares).sum()
+    }
+}
+
+/// Builds unwind plans from positions and book snapshots
+#[derive(Debug, Clone, Default)]
+pub struct UnwindPlanner {
+    config: UnwindConfig,
+}
+
+impl UnwindPlanner {
+    pub fn new(config: UnwindConfig) -> Self {
+        Self { config }
+    }
+
+    pub fn config(&self) -> &UnwindConfig {
+        &self.config
+    }
+
+    /// Plan and sequence exits: most liquid first, then cheapest vs mid
+    pub fn plan(&self, positions: Vec<(UnwindPosition, UnwindBook)>) -> UnwindPlan {
+        let mut legs: Vec<UnwindLeg> = positions
+            .into_iter()
+            .filter(|(pos, _)| pos.shares > 0)
+            .map(|(pos, book)| self.plan_leg(pos, &book))
+            .collect();
+
+        legs.sort_by(|a, b| {
+            b.liquidity_usd
+                .cmp(&a.liquidity_usd)
+                .then_with(|| a.cost_per_share().cmp(&b.cost_per_share()))
+        });
+        UnwindPlan { legs }
+    }
+
+    /// Simulate selling one position into the snapshot book
+    pub fn plan_leg(&self, position: UnwindPosition, book: &UnwindBook) -> UnwindLeg {
+        let mid = book.mid();
+        let floor_price = mid.map(|m| {
+            let slip = Decimal::from(self.config.max_slippage_bps) / dec!(10000);
+            (m * (Decimal::ONE - slip)).max(dec!(0.01))
+        });
+
+        // Walk the bids above the floor
+        let mut fills: Vec<(Decimal, u64)> = Vec::new();
+        let mut remaining = position.shares;
+        let mut liquidity_usd = Decimal::ZERO;
+        if let Some(floor) = floor_price {
+            for level in book.bids.iter().take_while(|l| l.price >= floor) {
+                liquidity_usd += level.price * level.size;
+                let size = level.size.floor().to_u64().unwrap_or(0);
+                let take = size.min(remaining);
+                if take > 0 {
+                    fills.push((level.price, take));
+                    remaining -= take;
+                }
+            }
+        }
+
+        let chunks = self.chunk_fills(&fills, mid);
+        let fillable: u64 = fills.iter().map(|(_, q)| q).sum();
+        let expected_proceeds: Decimal = fills.iter().map(|(p, q)| *p * Decimal::from(*q)).sum();
+        let expected_cost = mid
+            .map(|m| m * Decimal::from(fillable) - expected_proceeds)
+            .unwrap_or(Decimal::ZERO);
+        let expected_pnl = expected_proceeds - position.avg_entry_price * Decimal::from(fillable);
+
+        UnwindLeg {
+            position,
+            mid,
+            floor_price,
+            chunks,
+            liquidity_usd,
+            expected_proceeds,
+            expected_cost,
+            expected_pnl,
+            residual_shares: remaining,
+        }
+    }
+
+    fn chunk_size(&self, mid: Option<Decimal>) -> u64 {
+        let by_notional = mid
+            .filter(|m| *m > Decimal::ZERO)
+            .and_then(|m| (self.config.max_chunk_notional / m).floor().to_u64())
+            .unwrap_or(u64::MAX);
+        self.config.max_chunk_shares.min(by_notional).max(1)
+    }
+
+    /// Split the simulated fills into child orders; each chunk's limit is the
+    /// worst level it reaches, assuming earlier chunks consumed the levels above
+    fn chunk_fills(&self, fills: &[(Decimal, u64)], mid: Option<Decimal>) -> Vec<UnwindChunk> {
+        let size = self.chunk_size(mid);
+        let mut chunks = Vec::new();
+        let mut shares = 0u64;
+        let mut notional = Decimal::ZERO;
+        let mut worst = Decimal::ZERO;
+
+        for &(price, qty) in fills {
+            let mut left = qty;
+            while left > 0 {
+                let take = left.min(size - shares);
+                shares += take;
+                notional += price * Decimal::from(take);
+                worst = price;
+                left -= take;
+                if shares == size {
+                    chunks.push(UnwindChunk {
+                        shares,
+                        limit_price: worst,
+                        expected_price: notional / Decimal::from(shares),
+                    });
+                    shares = 0;
+                    notional = Decimal::ZERO;
+                }
+            }
+        }
+        if shares > 0 {
+            chunks.push(UnwindChunk {
+                shares,
+                limit_price: worst,
+                expected_price: notional / Decimal::from(shares),
+            });
+        }
+        chunks
+    }
+}
+
+/// Executed result for one leg
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct UnwindLegOutcome {
+    pub position: UnwindPosition,
+    pub filled_shares: u64,
+    pub avg_price: Option<Decimal>,
+    pub proceeds: Decimal,
+    /// Proceeds given up vs mid at planning time
+    pub realized_cost: Decimal,
+    /// Shares still held after the leg (book too thin or order failure)
+    pub residual_shares: u64,
+}
+
+impl UnwindLegOutcome {
+    pub fn is_closed(&self) -> bool {
+        self.residual_shares == 0 && self.filled_shares > 0
+    }
+}
+
+/// Final unwind report
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+pub struct UnwindReport {
+    pub legs: Vec<UnwindLegOutcome>,
+    pub expected_cost: Decimal,
+}
+
+impl UnwindReport {
+    pub fn filled_shares(&self) -> u64 {
+        self.legs.iter().map(|l| l.filled_shares).sum()
+    }
+
+    pub fn proceeds(&self) -> Decimal {
+        self.legs.iter().map(|l| l.proceeds).sum()
+    }
+
+    pub fn realized_cost(&self) -> Decimal {
+        self.legs.iter().map(|l| l.realized_cost).sum()
+    }
+
+    pub fn residual_shares(&self) -> u64 {
+        self.legs.iter().map(|l| l.residual_shares).sum()
+    }
+}
+
+/// Progress events emitted while executing a plan
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub enum UnwindProgress {
+    Started {
+        legs: usize,
+        total_shares: u64,
+        expected_proceeds: Decimal,
+        expected_cost: Decimal,
+    },
+    ChunkSubmitted {
+        symbol: String,
+        chunk: usize,
+        chunks: usize,
+        shares: u64,
+        limit_price: Decimal,
+    },
+    ChunkFilled {
+        symbol: String,
+        chunk: usize,
+        filled_shares: u64,
+        avg_price: Decimal,
+    },
+    ChunkFailed {
+        symbol: String,
+        chunk: usize,
+        error: String,
+    },
+    LegCompleted(UnwindLegOutcome),
+    Finished(UnwindReport),
+}
+
+/// Plans against live books and executes chunked IOC sells
+pub struct UnwindExecutor {
+    client: Arc<PolymarketClient>,
+    planner: UnwindPlanner,
+    progress: Option<mpsc::UnboundedSender<UnwindProgress>>,
+    chunk_timeout: Option<std::time::Duration>,
+}
+
+impl UnwindExecutor {
+    pub fn new(client: Arc<PolymarketClient>, config: UnwindConfig) -> Self {
+        Self {
+            client,
+            planner: UnwindPlanner::new(config),
+            progress: None,
+            chunk_timeout: None,
+        }
+    }
+
+    /// Stream progress events to `tx`
+    pub fn with_progress(mut self, tx: mpsc::UnboundedSender<UnwindProgress>) -> Self {
+        self.progress = Some(tx);
+        self
+    }
+
+    /// Give up on a chunk order that has not been acknowledged within `timeout`.
+    /// The leg stops there; earlier chunks still count.
+    pub fn with_chunk_timeout(mut self, timeout: std::time::Duration) -> Self {
+        self.chunk_timeout = Some(timeout);
+        self
+    }
+
+    fn emit(&self, event: UnwindProgress) {
+        if let Some(tx) = &self.progress {
+            let _ = tx.send(event);
+        }
+    }
+
+    /// Fetch current books and build a plan; positions without a book get an
+    /// empty leg (all residual) so they still show up in the report
+    pub async fn plan(&self, positions: Vec<UnwindPosition>) -> UnwindPlan {
+        let mut inputs = Vec::with_capacity(positions.len());
+        for pos in positions {
+            let book = match self.client.get_order_book(&pos.token_id).await {
+                Ok(book) => UnwindBook::from_response(&book),
+                Err(e) => {
+                    warn!("No order book for {} ({}): {}", pos.symbol, pos.token_id, e);
+                    UnwindBook::default()
+                }
+            };
+            inputs.push((pos, book));
+        }
+        self.planner.plan(inputs)
+    }
+
+    /// Execute a plan leg by leg; a leg stops at its first short fill, error or
+    /// chunk timeout, and every leg is reported with what it actually sold
+    pub async fn execute(&self, plan: &UnwindPlan) -> Result<UnwindReport> {
+        info!(
+            "Unwinding {} positions ({} shares), expected proceeds ${:.2}, cost vs mid ${:.2}",
+            plan.legs.len(),
+            plan.total_shares(),
+            plan.expected_proceeds(),
+            plan.expected_cost()
+        );
+        self.emit(UnwindProgress::Started {
+            legs: plan.legs.len(),
+            total_shares: plan.total_shares(),
+            expected_proceeds: plan.expected_proceeds(),
+            expected_cost: plan.expected_cost(),
+        });
+
+        let interval = std::time::Duration::from_millis(self.planner.config().chunk_interval_ms);
+        let dry_run = self.client.is_dry_run();
+        let mut report = UnwindReport {
+            legs: Vec::with_capacity(plan.legs.len()),
+            expected_cost: plan.expected_cost(),
+        };
+
+        for leg in &plan.legs {
+            let pos = &leg.position;
+            let mut filled = 0u64;
+            let mut proceeds = Decimal::ZERO;
+
+            for (idx, chunk) in leg.chunks.iter().enumerate() {
+                if idx > 0 && !interval.is_zero() {
+                    tokio::time::sleep(interval).await;
+                }
+
+                let mut request = OrderRequest::sell_limit(
+                    pos.token_id.clone(),
+                    pos.market_side,
+                    chunk.shares,
+                    chunk.limit_price,
+                );
+                request.time_in_force = TimeInForce::IOC;
+                self.emit(UnwindProgress::ChunkSubmitted {
+                    symbol: pos.symbol.clone(),
+                    chunk: idx + 1,
+                    chunks: leg.chunks.len(),
+                    shares: chunk.shares,
+                    limit_price: chunk.limit_price,
+                });
+
+                let submitted = match self.chunk_timeout {
+                    Some(limit) => tokio::time::timeout(limit, self.client.submit_order(&request))
+                        .await
+                        .unwrap_or_else(|_| {
+                            Err(PloyError::OrderTimeout(format!(
+                                "unwind chunk not acknowledged within {}ms",
+                                limit.as_millis()
+                            )))
+                        }),
+                    None => self.client.submit_order(&request).await,
+                };
+                let (chunk_filled, avg_price) = match submitted {
+                    // Dry run never matches; assume the simulated fill
+                    Ok(_) if dry_run => (chunk.shares, chunk.expected_price),
+                    Ok(resp) => {
+                        let (size, avg) = PolymarketClient::calculate_fill(&resp);
+                        (size.floor().to_u64().unwrap_or(0).min(chunk.shares), avg)
+                    }
+                    Err(e) => {
+                        error!("Unwind chunk {} for {} failed: {}", idx + 1, pos.symbol, e);
+                        self.emit(UnwindProgress::ChunkFailed {
+                            symbol: pos.symbol.clone(),
+                            chunk: idx + 1,
+                            error: e.to_string(),
+                        });
+                        break;
+                    }
+                };
+
+                filled += chunk_filled;
+                proceeds += avg_price * Decimal::from(chunk_filled);
+                self.emit(UnwindProgress::ChunkFilled {
+                    symbol: pos.symbol.clone(),
+                    chunk: idx + 1,
+                    filled_shares: chunk_filled,
+                    avg_price,
+                });
+
+                if chunk_filled < chunk.shares {
+                    warn!(
+                        "Unwind chunk {} for {} filled {}/{} shares, stopping leg",
+                        idx + 1,
+                        pos.symbol,
+                        chunk_filled,
+                        chunk.shares
+                    );
+                    break;
+                }
+            }
+
+            let avg_price = (filled > 0).then(|| proceeds / Decimal::from(filled));
+            let realized_cost = leg
+                .mid
+                .map(|m| m * Decimal::from(filled) - proceeds)
+                .unwrap_or(Decimal::ZERO);
+            let outcome = UnwindLegOutcome {
+                position: pos.clone(),
+                filled_shares: filled,
+                avg_price,
+                proceeds,
+                realized_cost,
+                residual_shares: pos.shares.saturating_sub(filled),
+            };
+            info!(
+                "Unwound {}: {}/{} shares, proceeds ${:.2}, residual {}",
+                pos.symbol, filled, pos.shares, proceeds, outcome.residual_shares
+            );
+            self.emit(UnwindProgress::LegCompleted(outcome.clone()));
+            report.legs.push(outcome);
+        }
+
+        self.emit(UnwindProgress::Finished(report.clone()));
+        Ok(report)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn position(symbol: &str, shares: u64, entry: Decimal) -> UnwindPosition {
+        UnwindPosition {
+            position_id: None,
+            symbol: symbol.to_string(),
+            token_id: format!("{}-token", symbol),
+            market_side: Side::Up,
+            shares,
+            avg_entry_price: entry,
+        }
+    }
+
+    fn book(bids: &[(Decimal, Decimal)], ask: Decimal) -> UnwindBook {
+        UnwindBook::new(
+            bids.iter()
+                .map(|&(price, size)| BookLevel { price, size })
+                .collect(),
+            Some(ask),
+        )
+    }
+
+    #[test]
+    fn test_leg_chunks_respect_floor_and_simulate_cost() {
+        let planner = UnwindPlanner::new(UnwindConfig {
+            max_chunk_shares: 100,
+            max_chunk_notional: dec!(1000),
+            max_slippage_bps: 1000,
+            chunk_interval_ms: 0,
+        });
+        // mid 0.50, floor 0.45; the 0.40 level is out of bounds
+        let leg = planner.plan_leg(
+            position("BTC", 300, dec!(0.55)),
+            &book(
+                &[
+                    (dec!(0.40), dec!(500)),
+                    (dec!(0.49), dec!(150)),
+                    (dec!(0.46), dec!(80)),
+                ],
+                dec!(0.51),
+            ),
+        );
+
+        assert_eq!(leg.mid, Some(dec!(0.50)));
+        assert_eq!(leg.fillable_shares(), 230);
+        assert_eq!(leg.residual_shares, 70);
+        let limits: Vec<Decimal> = leg.chunks.iter().map(|c| c.limit_price).collect();
+        assert_eq!(limits, vec![dec!(0.49), dec!(0.46), dec!(0.46)]);
+        assert_eq!(leg.chunks[1].shares, 100);
+        // 150 @ 0.49 + 80 @ 0.46 = 110.30; at mid 115.00
+        assert_eq!(leg.expected_proceeds, dec!(110.30));
+        assert_eq!(leg.expected_cost, dec!(4.70));
+        assert_eq!(leg.expected_pnl, dec!(110.30) - dec!(126.50));
+    }
+
+    #[test]
+    fn test_plan_orders_most_liquid_first() {
+        let planner = UnwindPlanner::default();
+        let plan = planner.plan(vec![
+            (
+                position("THIN", 50, dec!(0.5)),
+                book(&[(dec!(0.50), dec!(60))], dec!(0.52)),
+            ),
+            (position("NOBOOK", 40, dec!(0.5)), UnwindBook::default()),
+            (
+                position("DEEP", 50, dec!(0.5)),
+                book(&[(dec!(0.60), dec!(2000))], dec!(0.61)),
+            ),
+        ]);
+
+        let order: Vec<&str> = plan
+            .legs
+            .iter()
+            .map(|l| l.position.symbol.as_str())
+            .collect();
+        assert_eq!(order, vec!["DEEP", "THIN", "NOBOOK"]);
+        assert_eq!(plan.residual_shares(), 40);
+        // 100 USD chunk cap at ~0.605 mid -> 165 shares, so one chunk each
+        assert_eq!(plan.legs[0].chunks.len(), 1);
+    }
+}
@@ -0,0 +1,334 @@
+//! Human-in-the-loop approvals
+//!
+//! High-impact actions (large autonomous BUY intents, emergency stop requests,
+//! large sandbox budget top-ups) are parked here instead of being executed
+//! directly. An operator resolves them from a Feishu interactive card or the
+//! admin API; approved trade intents are re-submitted to the coordinator,
+//! denied or expired ones are dropped.
+
+use chrono::{DateTime, Duration as ChronoDuration, Utc};
+use rust_decimal::Decimal;
+use serde::{Deserialize, Serialize};
+use std::collections::{HashMap, HashSet};
+use std::sync::{Arc, Mutex};
+use tracing::{info, warn};
+use uuid::Uuid;
+
+use crate::adapters::FeishuNotifier;
+use crate::platform::OrderIntent;
+
+/// Action waiting for operator approval
+#[derive(Debug, Clone)]
+pub enum ApprovalAction {
+    /// Order intent held back by the coordinator
+    TradeIntent(OrderIntent),
+    /// Request to force-close everything and halt ingress
+    EmergencyStop {
+        reason: String,
+        requested_by: String,
+    },
+    /// Sandbox budget top-up above the auto-approval limit
+    BudgetTopUp {
+        top_up_id: Uuid,
+        agent_id: String,
+        amount_usd: Decimal,
+        requested_by: String,
+    },
+}
+
+impl ApprovalAction {
+    pub fn kind(&self) -> &'static str {
+        match self {
+            ApprovalAction::TradeIntent(_) => "trade_intent",
+            ApprovalAction::EmergencyStop { .. } => "emergency_stop",
+            ApprovalAction::BudgetTopUp { .. } => "budget_top_up",
+        }
+    }
+
+    pub fn title(&self) -> String {
+        match self {
+            ApprovalAction::TradeIntent(intent) => {
+                format!("Trade approval: {}", intent.agent_id)
+            }
+            ApprovalAction::EmergencyStop { requested_by, .. } => {
+                format!("Emergency stop requested by {}", requested_by)
+            }
+            ApprovalAction::BudgetTopUp { agent_id, .. } => {
+                format!("Budget top-up: {}", agent_id)
+            }
+        }
+    }
+
+    pub fn summary(&self) -> String {
+        match self {
+            ApprovalAction::TradeIntent(intent) => format!(
+                "**{} {} {:?}** {}\nShares: {} @ {} | Notional: ${}\nDomain: {} | Intent: {}",
+                intent.agent_id,
+                if intent.is_buy { "BUY" } else { "SELL" },
+                intent.side,
+                intent.market_slug,
+                intent.shares,
+                intent.limit_price,
+                intent.notional_value().round_dp(2),
+                intent.domain,
+                intent.intent_id,
+            ),
+            ApprovalAction::EmergencyStop {
+                reason,
+                requested_by,
+            } => format!(
+                "**Force-close all positions and halt ingress**\nRequested by: {}\nReason: {}",
+                requested_by, reason
+            ),
+            ApprovalAction::BudgetTopUp {
+                top_up_id,
+                agent_id,
+                amount_usd,
+                requested_by,
+            } => format!(
+                "**Add ${} to {}'s sandbox budget**\nRequested by: {}\nTop-up: {}",
+                amount_usd.round_dp(2),
+                agent_id,
+                requested_by,
+                top_up_id
+            ),
+        }
+    }
+}
+
+/// Operator decision on a pending approval
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum ApprovalDecision {
+    #[serde(alias = "approved")]
+    Approve,
+    #[serde(alias = "denied")]
+    Deny,
+}
+
+impl ApprovalDecision {
+    pub fn outcome(&self) -> &'static str {
+        match self {
+            ApprovalDecision::Approve => "Approved",
+            ApprovalDecision::Deny => "Denied",
+        }
+    }
+}
+
+/// A parked high-impact action
+#[derive(Debug, Clone)]
+pub struct PendingApproval {
+    pub id: Uuid,
+    pub action: ApprovalAction,
+    pub created_at: DateTime<Utc>,
+    pub expires_at: DateTime<Utc>,
+}
+
+/// API view of a pending approval
+#[derive(Debug, Clone, Serialize)]
+pub struct ApprovalSnapshot {
+    pub id: Uuid,
+    pub kind: String,
+    pub title: String,
+    pub summary: String,
+    pub agent_id: Option<String>,
+    pub notional_usd: Option<Decimal>,
+    pub created_at: DateTime<Utc>,
+    pub expires_at: DateTime<Utc>,
+}
+
+impl From<&PendingApproval> for ApprovalSnapshot {
+    fn from(pending: &PendingApproval) -> Self {
+        let (agent_id, notional_usd) = match &pending.action {
+            ApprovalAction::TradeIntent(intent) => {
+                (Some(intent.agent_id.clone()), Some(intent.notional_value()))
+            }
+            ApprovalAction::EmergencyStop { .. } => (None, None),
+            ApprovalAction::BudgetTopUp {
+                agent_id,
+                amount_usd,
+                ..
+            } => (Some(agent_id.clone()), Some(*amount_usd)),
+        };
+        Self {
+            id: pending.id,
+            kind: pending.action.kind().to_string(),
+            title: pending.action.title(),
+            summary: pending.action.summary(),
+            agent_id,
+            notional_usd,
+            created_at: pending.created_at,
+            expires_at: pending.expires_at,
+        }
+    }
+}
+
+/// Store of pending approvals shared by the coordinator and its handles
+pub struct ApprovalBook {
+    ttl: ChronoDuration,
+    notifier: Option<Arc<FeishuNotifier>>,
+    pending: Mutex<HashMap<Uuid, PendingApproval>>,
+    /// Intent IDs approved by an operator and allowed through the gate once
+    approved_intents: Mutex<HashSet<Uuid>>,
+}
+
+impl ApprovalBook {
+    pub fn new(ttl_secs: u64, notifier: Option<Arc<FeishuNotifier>>) -> Self {
+        Self {
+            ttl: ChronoDuration::seconds(ttl_secs.max(1) as i64),
+            notifier,
+            pending: Mutex::new(HashMap::new()),
+            approved_intents: Mutex::new(HashSet::new()),
+        }
+    }
+
+    /// Park an action and (if Feishu is configured) push an approval card.
+    pub fn request(&self, action: ApprovalAction) -> Uuid {
+        let now = Utc::now();
+        let pending = PendingApproval {
+            id: Uuid::new_v4(),
+            action,
+            created_at: now,
+            expires_at: now + self.ttl,
+        };
+        let id = pending.id;
+        let title = pending.action.title();
+        let summary = pending.action.summary();
+        if let Ok(mut map) = self.pending.lock() {
+            map.insert(id, pending);
+        }
+        info!(approval_id = %id, %title, "approval requested");
+
+        if let Some(notifier) = self.notifier.clone() {
+            tokio::spawn(async move {
+                if let Err(e) = notifier
+                    .send_approval_card(&id.to_string(), &title, &summary)
+                    .await
+                {
+                    warn!(approval_id = %id, error = %e, "failed to send approval card");
+                }
+            });
+        }
+        id
+    }
+
+    /// Resolve a pending approval. Returns the action when it was still pending.
+    ///
+    /// Approved trade intents are remembered so the coordinator lets them
+    /// through exactly once when they are re-submitted.
+    pub fn resolve(&self, id: Uuid, decision: ApprovalDecision) -> Option<PendingApproval> {
+        let pending = self.pending.lock().ok()?.remove(&id)?;
+        if pending.expires_at < Utc::now() {
+            return None;
+        }
+        if decision == ApprovalDecision::Approve {
+            if let ApprovalAction::TradeIntent(intent) = &pending.action {
+                if let Ok(mut approved) = self.approved_intents.lock() {
+                    approved.insert(intent.intent_id);
+                }
+            }
+        }
+        Some(pending)
+    }
+
+    /// Consume a previously granted approval for an intent.
+    pub fn consume_approved(&self, intent_id: Uuid) -> bool {
+        self.approved_intents
+            .lock()
+            .map(|mut approved| approved.remove(&intent_id))
+            .unwrap_or(false)
+    }
+
+    /// Whether an intent is already parked (avoids duplicate cards on retries).
+    pub fn is_pending_intent(&self, intent_id: Uuid) -> bool {
+        self.pending.lock().is_ok_and(|map| {
+            map.values().any(
+                |p| matches!(&p.action, ApprovalAction::TradeIntent(i) if i.intent_id == intent_id),
+            )
+        })
+    }
+
+    /// Drop approvals past their deadline. Returns the expired entries.
+    pub fn expire(&self, now: DateTime<Utc>) -> Vec<PendingApproval> {
+        let Ok(mut map) = self.pending.lock() else {
+            return Vec::new();
+        };
+        let expired_ids: Vec<Uuid> = map
+            .values()
+            .filter(|p| p.expires_at < now)
+            .map(|p| p.id)
+            .collect();
+        expired_ids
+            .into_iter()
+            .filter_map(|id| map.remove(&id))
+            .collect()
+    }
+
+    /// Pending approvals, oldest first.
+    pub fn list(&self) -> Vec<ApprovalSnapshot> {
+        let mut rows: Vec<ApprovalSnapshot> = self
+            .pending
+            .lock()
+            .map(|map| map.values().map(ApprovalSnapshot::from).collect())
+            .unwrap_or_default();
+        rows.sort_by(|a, b| a.created_at.cmp(&b.created_at));
+        rows
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::domain::Side;
+    use crate::platform::Domain;
+    use rust_decimal_macros::dec;
+
+    fn intent() -> OrderIntent {
+        OrderIntent::new(
+            "crypto_lob_ml",
+            Domain::Crypto,
+            "btc-updown-15m",
+            "token-up",
+            Side::Up,
+            true,
+            200,
+            dec!(0.55),
+        )
+    }
+
+    #[test]
+    fn test_approved_intent_passes_once() {
+        let book = ApprovalBook::new(300, None);
+        let intent = intent();
+        let intent_id = intent.intent_id;
+        let id = book.request(ApprovalAction::TradeIntent(intent));
+        assert!(book.is_pending_intent(intent_id));
+        assert_eq!(book.list().len(), 1);
+
+        assert!(book.resolve(id, ApprovalDecision::Approve).is_some());
+        assert!(book.resolve(id, ApprovalDecision::Approve).is_none());
+        assert!(book.consume_approved(intent_id));
+        assert!(!book.consume_approved(intent_id));
+    }
+
+    #[test]
+    fn test_denied_and_expired_approvals() {
+        let book = ApprovalBook::new(60, None);
+        let intent = intent();
+        let intent_id = intent.intent_id;
+        let id = book.request(ApprovalAction::TradeIntent(intent));
+        assert!(book.resolve(id, ApprovalDecision::Deny).is_some());
+        assert!(!book.consume_approved(intent_id));
+
+        book.request(ApprovalAction::EmergencyStop {
+            reason: "feed outage".into(),
+            requested_by: "sidecar".into(),
+        });
+        assert!(book.expire(Utc::now()).is_empty());
+        assert_eq!(
+            book.expire(Utc::now() + ChronoDuration::seconds(61)).len(),
+            1
+        );
+        assert!(book.list().is_empty());
+    }
+}
@@ -12,17 +12,22 @@ use tracing::{debug, error, info, trace, warn};
 
 use crate::adapters::polymarket_clob::POLYGON_CHAIN_ID;
 use crate::adapters::polymarket_ws::PriceLevel;
-use crate::adapters::{BinanceWebSocket, PolymarketClient, PolymarketWebSocket, PostgresStore};
+use crate::adapters::{
+    BinanceWebSocket, DiscordNotifier, FeishuNotifier, PolymarketClient, PolymarketWebSocket,
+    PostgresStore, QuoteCache,
+};
+use crate::agents::openclaw::conflict::ConflictPolicy;
 use crate::agents::{
-    AgentContext, CryptoLobMlAgent, CryptoLobMlConfig, CryptoLobMlEntrySidePolicy,
+    AgentSupervisor, CryptoLobMlAgent, CryptoLobMlConfig, CryptoLobMlEntrySidePolicy,
     CryptoLobMlExitMode, CryptoTradingAgent, CryptoTradingConfig, OpenClawAgent, OpenClawConfig,
     PoliticsTradingAgent, PoliticsTradingConfig, SportsTradingAgent, SportsTradingConfig,
-    TradingAgent,
 };
 #[cfg(feature = "rl")]
 use crate::agents::{CryptoRlPolicyAgent, CryptoRlPolicyConfig};
 use crate::ai_clients::PolymarketSportsClient;
-use crate::config::AppConfig;
+use crate::analysis::{GreeksBook, ToxicityMonitor, VpinSymbolConfig};
+use crate::config::{AppConfig, CycleResumePolicy};
+use crate::coordination::LeaderElector;
 use crate::coordinator::config::DuplicateGuardScope;
 use crate::coordinator::{
     AgentHealthResponse, AgentSnapshot, Coordinator, CoordinatorCommand, CoordinatorConfig,
@@ -30,15 +35,27 @@ use crate::coordinator::{
 };
 use crate::domain::{OrderStatus, Side};
 use crate::error::Result;
-use crate::exchange::{build_exchange_client, parse_exchange_kind, ExchangeKind};
-use crate::platform::{AgentRiskParams, AgentStatus, Domain, MarketSelector, StrategyDeployment};
-use crate::signing::Wallet;
+use crate::exchange::{build_exchange_client, parse_exchange_kind, ExchangeClient, ExchangeKind};
+use crate::platform::{
+    AgentRiskParams, AgentStatus, Domain, MarketSelector, StrategyDeployment, Timeframe,
+};
+use crate::services::{
+    DailyReportConfig, DailyReportService, FillLedger, HealthServer, HealthState, Metrics,
+    OrderMonitor, OrderMonitorConfig,
+};
+use crate::signing::{CredentialCipher, CredentialManager, EncryptedCredentialStore, Wallet};
 use crate::strategy::event_edge::core::EventEdgeCore;
+use crate::strategy::event_models::comment_sentiment::spawn_comment_sentiment;
 use crate::strategy::executor::OrderExecutor;
 use crate::strategy::idempotency::IdempotencyManager;
 use crate::strategy::momentum::EventMatcher;
 use crate::strategy::{
-    DataFeed, DataFeedManager, StrategyAction, StrategyFactory, StrategyManager,
+    BracketManager, ConditionalOrderManager, DataFeed, DataFeedManager, FillabilityConfig,
+    FillabilityTracker, StrategyAction, StrategyEngine, StrategyFactory, StrategyManager,
+};
+use crate::supervisor::{
+    AlertManager, EventCalendarService, MarketAnomalyDetector, PerformanceMonitor,
+    RecoveryPlaybook, ResourceMonitor, VenueMonitor,
 };
 use chrono::Utc;
 use futures_util::StreamExt;
@@ -1243,6 +1260,38 @@ async fn ensure_clob_trade_alerts_table(pool: &PgPool) -> Result<()> {
     Ok(())
 }
 
+/// Flag untradable tokens on this feed in the coordinator's shared registry
+fn spawn_market_anomaly_detector(
+    config: &PlatformBootstrapConfig,
+    pm_ws: &Arc<PolymarketWebSocket>,
+    handle: &CoordinatorHandle,
+    shutdown_tx: &broadcast::Sender<()>,
+) {
+    if !config.coordinator.market_anomaly.enabled {
+        return;
+    }
+    let detector = MarketAnomalyDetector::new(
+        config.coordinator.market_anomaly.clone(),
+        handle.market_anomalies(),
+    );
+    tokio::spawn(detector.run(pm_ws.subscribe_updates(), shutdown_tx.subscribe()));
+}
+
+/// Let armed conditional orders see this feed's quotes
+fn spawn_conditional_quote_feed(
+    conditionals: Option<&Arc<ConditionalOrderManager>>,
+    pm_ws: &Arc<PolymarketWebSocket>,
+    shutdown_tx: &broadcast::Sender<()>,
+) {
+    if let Some(conditionals) = conditionals {
+        tokio::spawn(
+            conditionals
+                .clone()
+                .watch(pm_ws.subscribe_updates(), shutdown_tx.subscribe()),
+        );
+    }
+}
+
 fn spawn_clob_quote_persistence(
     pm_ws: Arc<PolymarketWebSocket>,
     pool: PgPool,
@@ -1361,6 +1410,66 @@ fn spawn_clob_quote_persistence(
     });
 }
 
+/// Settle strategy cycles a crash left incomplete.
+///
+/// The platform runs no engine quote loop that could keep waiting for Leg2,
+/// so the `resume` policy is applied as `hedge` here.
+async fn recover_strategy_cycles(
+    app_config: &AppConfig,
+    pool: &PgPool,
+    exchange_client: Arc<dyn ExchangeClient>,
+) -> Result<()> {
+    let store = PostgresStore::from_pool(pool.clone());
+    let summary = store.get_recovery_summary().await?;
+    summary.log_summary();
+    if summary.incomplete_cycle_count == 0 {
+        return Ok(());
+    }
+
+    let mut config = app_config.clone();
+    if config.strategy.resume.policy == CycleResumePolicy::Resume {
+        config.strategy.resume.policy = CycleResumePolicy::Hedge;
+    }
+    let executor = OrderExecutor::new_with_exchange(exchange_client, config.execution.clone());
+    let engine = StrategyEngine::new(config, store, executor, QuoteCache::new()).await?;
+    for (cycle_id, decision) in engine.recover_cycles().await? {
+        info!(cycle_id, decision = ?decision, "strategy cycle recovered");
+    }
+    Ok(())
+}
+
+/// Feed Binance trade prints into the order-flow toxicity monitor.
+fn spawn_toxicity_feed(binance_ws: Arc<BinanceWebSocket>, monitor: Arc<ToxicityMonitor>) {
+    tokio::spawn(async move {
+        let mut rx = binance_ws.subscribe();
+        loop {
+            match rx.recv().await {
+                Ok(update) => monitor.on_price_update(&update),
+                Err(broadcast::error::RecvError::Lagged(n)) => {
+                    debug!(skipped = n, "toxicity feed lagged");
+                }
+                Err(broadcast::error::RecvError::Closed) => break,
+            }
+        }
+    });
+}
+
+/// Feed Binance spot into the binary position Greeks book.
+fn spawn_greeks_feed(binance_ws: Arc<BinanceWebSocket>, book: Arc<GreeksBook>) {
+    tokio::spawn(async move {
+        let mut rx = binance_ws.subscribe();
+        loop {
+            match rx.recv().await {
+                Ok(update) => book.on_price_update(&update),
+                Err(broadcast::error::RecvError::Lagged(n)) => {
+                    debug!(skipped = n, "greeks feed lagged");
+                }
+                Err(broadcast::error::RecvError::Closed) => break,
+            }
+        }
+    });
+}
+
 fn spawn_binance_price_persistence(
     binance_ws: Arc<BinanceWebSocket>,
     pool: PgPool,
@@ -2858,8 +2967,11 @@ async fn upsert_pm_token_settlement_rows(
             .get(idx)
             .and_then(|v| v.parse::<rust_decimal::Decimal>().ok());
 
-        sqlx::query(
+        let (was_resolved,): (bool,) = sqlx::query_as(
             r#"
+            WITH prev AS (
+                SELECT resolved FROM pm_token_settlements WHERE token_id = $1
+            )
             INSERT INTO pm_token_settlements (
                 token_id,
                 condition_id,
@@ -2883,6 +2995,7 @@ async fn upsert_pm_token_settlement_rows(
                 resolved_at = COALESCE(pm_token_settlements.resolved_at, EXCLUDED.resolved_at),
                 fetched_at = NOW(),
                 raw_market = EXCLUDED.raw_market
+            RETURNING COALESCE((SELECT resolved FROM prev), FALSE)
             "#,
         )
         .bind(token_id)
@@ -2894,9 +3007,27 @@ async fn upsert_pm_token_settlement_rows(
         .bind(resolved_market)
         .bind(resolved_at)
         .bind(sqlx::types::Json(raw_market.clone()))
-        .execute(pool)
+        .fetch_one(pool)
         .await?;
 
+        #[cfg(feature = "api")]
+        if resolved_market && !was_resolved {
+            crate::api::webhooks::emit(
+                crate::api::webhooks::WebhookEventType::Settlement,
+                serde_json::json!({
+                    "token_id": token_id,
+                    "condition_id": market.condition_id.map(|b| b.to_string()),
+                    "market_id": &market.id,
+                    "market_slug": market.slug.as_deref(),
+                    "outcome": outcome.as_deref(),
+                    "settled_price": settled_price,
+                    "resolved_at": resolved_at,
+                }),
+            );
+        }
+        #[cfg(not(feature = "api"))]
+        let _ = was_resolved;
+
         upserted_rows += 1;
     }
 
@@ -2943,29 +3074,14 @@ async fn backfill_pm_market_metadata_from_settlement(
         None
     };
 
-    // Infer horizon from slug pattern (most reliable)
-    let horizon = if slug.contains("-5m-") {
-        Some("5m")
-    } else if slug.contains("-15m-") {
-        Some("15m")
-    } else if slug.contains("-60m-") {
-        Some("60m")
-    } else {
-        // Fallback to duration
-        match (start_time, end_time) {
-            (Some(s), Some(e)) => {
-                let secs = (e - s).num_seconds();
-                if secs <= 360 {
-                    Some("5m")
-                } else if secs <= 1080 {
-                    Some("15m")
-                } else {
-                    Some("60m")
-                }
-            }
+    // Infer horizon from slug pattern (most reliable), then fall back to duration
+    let horizon = Timeframe::from_hint(slug)
+        .filter(|tf| tf.duration_secs().is_some())
+        .or_else(|| match (start_time, end_time) {
+            (Some(s), Some(e)) => Timeframe::from_duration_secs((e - s).num_seconds()),
             _ => None,
-        }
-    };
+        })
+        .map(|tf| tf.as_str().to_string());
 
     let threshold: Option<rust_decimal::Decimal> = market
         .group_item_threshold
@@ -3176,6 +3292,18 @@ fn deployments_state_path() -> PathBuf {
     PathBuf::from("data/state/deployments.json")
 }
 
+/// Per-symbol VPIN parameters keyed by Binance symbol; omitted fields take the
+/// built-in defaults. Invalid JSON is logged and ignored.
+fn parse_toxicity_symbol_overrides(raw: &str) -> HashMap<String, VpinSymbolConfig> {
+    if raw.trim().is_empty() {
+        return HashMap::new();
+    }
+    serde_json::from_str(raw).unwrap_or_else(|e| {
+        warn!(error = %e, "ignoring invalid PLOY_COORDINATOR__TOXICITY_SYMBOLS_JSON");
+        HashMap::new()
+    })
+}
+
 fn parse_strategy_deployments(raw: &str) -> Vec<StrategyDeployment> {
     let mut out = Vec::new();
     if let Ok(items) = serde_json::from_str::<Vec<StrategyDeployment>>(raw) {
@@ -3296,14 +3424,13 @@ fn strategy_is_lob_ml(strategy_key: &str) -> bool {
 }
 
 fn normalize_horizon(value: &str) -> Option<&'static str> {
-    let key = value.to_ascii_lowercase().replace(['-', '_', ' '], "");
-    if key == "5m" || key == "5min" || key == "5minute" {
-        return Some("5m");
-    }
-    if key == "15m" || key == "15min" || key == "15minute" {
-        return Some("15m");
+    match Timeframe::from_hint(value)? {
+        Timeframe::M5 => Some("5m"),
+        Timeframe::M15 => Some("15m"),
+        Timeframe::H1 => Some("1h"),
+        Timeframe::D1 => Some("1d"),
+        Timeframe::Other(_) => None,
     }
-    None
 }
 
 fn crypto_series_id_for(coin: &str, horizon: &str) -> Option<&'static str> {
@@ -3915,6 +4042,23 @@ impl PlatformBootstrapConfig {
         cfg.coordinator.risk.economics_daily_loss_limit =
             env_decimal_opt("PLOY_RISK__ECONOMICS_DAILY_LOSS_LIMIT_USD");
 
+        // Optional notional throttles (sliding windows, BUY only).
+        // Example:
+        // - PLOY_RISK__MAX_NOTIONAL_PER_MINUTE_USD=500
+        // - PLOY_RISK__MAX_NOTIONAL_PER_HOUR_USD=5000
+        // - PLOY_RISK__AGENT_MAX_NOTIONAL_PER_MINUTE_USD=200
+        // - PLOY_RISK__AGENT_MAX_NOTIONAL_PER_HOUR_USD=2000
+        let throttle = &mut cfg.coordinator.risk.notional_throttle;
+        throttle.global.per_minute = env_decimal_opt("PLOY_RISK__MAX_NOTIONAL_PER_MINUTE_USD")
+            .filter(|v| *v > rust_decimal::Decimal::ZERO);
+        throttle.global.per_hour = env_decimal_opt("PLOY_RISK__MAX_NOTIONAL_PER_HOUR_USD")
+            .filter(|v| *v > rust_decimal::Decimal::ZERO);
+        throttle.per_agent.per_minute =
+            env_decimal_opt("PLOY_RISK__AGENT_MAX_NOTIONAL_PER_MINUTE_USD")
+                .filter(|v| *v > rust_decimal::Decimal::ZERO);
+        throttle.per_agent.per_hour = env_decimal_opt("PLOY_RISK__AGENT_MAX_NOTIONAL_PER_HOUR_USD")
+            .filter(|v| *v > rust_decimal::Decimal::ZERO);
+
         cfg.coordinator.duplicate_guard_enabled = env_bool(
             "PLOY_COORDINATOR__DUPLICATE_GUARD_ENABLED",
             cfg.coordinator.duplicate_guard_enabled,
@@ -4071,6 +4215,170 @@ impl PlatformBootstrapConfig {
                 cfg.coordinator.governance_blocked_domains = domains;
             }
         }
+        // Human-in-the-loop approval gate for large BUY intents.
+        cfg.coordinator.approval_min_notional_usd =
+            env_decimal_opt("PLOY_COORDINATOR__APPROVAL_MIN_NOTIONAL_USD")
+                .filter(|v| *v > rust_decimal::Decimal::ZERO)
+                .or(cfg.coordinator.approval_min_notional_usd);
+        if let Ok(raw) = std::env::var("PLOY_COORDINATOR__APPROVAL_AGENT_IDS") {
+            cfg.coordinator.approval_agent_ids = raw
+                .split(',')
+                .map(str::trim)
+                .filter(|v| !v.is_empty())
+                .map(str::to_string)
+                .collect();
+        }
+        cfg.coordinator.approval_timeout_secs = env_u64(
+            "PLOY_COORDINATOR__APPROVAL_TIMEOUT_SECS",
+            cfg.coordinator.approval_timeout_secs,
+        );
+        // Order-flow toxicity (VPIN) throttling from [toxicity], with per-symbol
+        // overrides from [toxicity.symbols.<SYMBOL>] or
+        // PLOY_COORDINATOR__TOXICITY_SYMBOLS_JSON='{"BTCUSDT":{"high_threshold":0.7}}'.
+        let toxicity_overrides = std::env::var("PLOY_COORDINATOR__TOXICITY_SYMBOLS_JSON")
+            .map(|raw| parse_toxicity_symbol_overrides(&raw))
+            .unwrap_or_default();
+        cfg.coordinator.toxicity = app
+            .toxicity
+            .clone()
+            .unwrap_or_default()
+            .with_symbol_overrides(toxicity_overrides);
+        cfg.coordinator.toxicity.enabled = env_bool(
+            "PLOY_COORDINATOR__TOXICITY_ENABLED",
+            cfg.coordinator.toxicity.enabled,
+        );
+        // Binary position Greeks; delta caps are USD per 1% underlying move.
+        cfg.coordinator.greeks.enabled = env_bool(
+            "PLOY_COORDINATOR__GREEKS_ENABLED",
+            cfg.coordinator.greeks.enabled,
+        );
+        let env_f64 = |name: &str| std::env::var(name).ok()?.trim().parse::<f64>().ok();
+        if let Some(v) = env_f64("PLOY_COORDINATOR__GREEKS_MAX_SYMBOL_DELTA_USD") {
+            cfg.coordinator.greeks.max_symbol_delta_usd = Some(v);
+        }
+        if let Some(v) = env_f64("PLOY_COORDINATOR__GREEKS_MAX_TOTAL_DELTA_USD") {
+            cfg.coordinator.greeks.max_total_delta_usd = Some(v);
+        }
+        // Live fillability gate: BUYs above base size need a proven passive fill rate.
+        cfg.coordinator.fillability.enabled = env_bool(
+            "PLOY_COORDINATOR__FILLABILITY_ENABLED",
+            cfg.coordinator.fillability.enabled,
+        );
+        cfg.coordinator.fillability.base_shares = env_u64(
+            "PLOY_COORDINATOR__FILLABILITY_BASE_SHARES",
+            cfg.coordinator.fillability.base_shares,
+        );
+        if let Some(v) = env_f64("PLOY_COORDINATOR__FILLABILITY_MIN_FILL_RATE") {
+            cfg.coordinator.fillability.min_fill_rate = v;
+        }
+        // Host resource pressure monitor (quote subsampling + non-critical agent pauses).
+        cfg.coordinator.resource_monitor.enabled = env_bool(
+            "PLOY_COORDINATOR__RESOURCE_MONITOR_ENABLED",
+            cfg.coordinator.resource_monitor.enabled,
+        );
+        if let Ok(raw) = std::env::var("PLOY_COORDINATOR__RESOURCE_MONITOR_CRITICAL_AGENTS") {
+            cfg.coordinator.resource_monitor.critical_agents = raw
+                .split(',')
+                .map(str::trim)
+                .filter(|v| !v.is_empty())
+                .map(str::to_string)
+                .collect();
+        }
+        // Exchange / chain health monitor (pre-emptive de-risking).
+        cfg.coordinator.venue_monitor.enabled = env_bool(
+            "PLOY_COORDINATOR__VENUE_MONITOR_ENABLED",
+            cfg.coordinator.venue_monitor.enabled,
+        );
+        if let Ok(raw) = std::env::var("PLOY_COORDINATOR__VENUE_MONITOR_POLYGON_RPC_URL")
+            .or_else(|_| std::env::var("POLYGON_RPC_URL"))
+        {
+            cfg.coordinator.venue_monitor.polygon_rpc_url = raw.trim().to_string();
+        }
+        if let Some(v) = env_decimal_opt("PLOY_COORDINATOR__VENUE_MONITOR_DERISK_FRACTION") {
+            cfg.coordinator.venue_monitor.derisk_fraction = v;
+        }
+        // High-impact event calendar (size / threshold adjustments).
+        cfg.coordinator.event_calendar.enabled = env_bool(
+            "PLOY_COORDINATOR__EVENT_CALENDAR_ENABLED",
+            cfg.coordinator.event_calendar.enabled,
+        );
+        if let Ok(raw) = std::env::var("PLOY_COORDINATOR__EVENT_CALENDAR_SCHEDULE_PATH") {
+            let raw = raw.trim();
+            cfg.coordinator.event_calendar.schedule_path =
+                (!raw.is_empty()).then(|| raw.to_string());
+        }
+        // Per-token spread / depth anomaly detector (untradable flags).
+        cfg.coordinator.market_anomaly.enabled = env_bool(
+            "PLOY_COORDINATOR__MARKET_ANOMALY_ENABLED",
+            cfg.coordinator.market_anomaly.enabled,
+        );
+        // Live-vs-backtest strategy decay alerts.
+        cfg.coordinator.performance_monitor.enabled = env_bool(
+            "PLOY_COORDINATOR__PERFORMANCE_MONITOR_ENABLED",
+            cfg.coordinator.performance_monitor.enabled,
+        );
+        cfg.coordinator.performance_monitor.auto_pause = env_bool(
+            "PLOY_COORDINATOR__PERFORMANCE_MONITOR_AUTO_PAUSE",
+            cfg.coordinator.performance_monitor.auto_pause,
+        );
+        if let Some(v) = env_f64("PLOY_COORDINATOR__PERFORMANCE_MONITOR_Z_THRESHOLD") {
+            cfg.coordinator.performance_monitor.z_threshold = v;
+        }
+        // Hot-standby leader election.
+        cfg.coordinator.leader_election.enabled = env_bool(
+            "PLOY_COORDINATOR__LEADER_ELECTION_ENABLED",
+            cfg.coordinator.leader_election.enabled,
+        );
+        if let Ok(raw) = std::env::var("PLOY_COORDINATOR__LEADER_ELECTION_INSTANCE_ID") {
+            let raw = raw.trim();
+            if !raw.is_empty() {
+                cfg.coordinator.leader_election.instance_id = Some(raw.to_string());
+            }
+        }
+        cfg.coordinator.leader_election.lease_timeout_ms = env_u64(
+            "PLOY_COORDINATOR__LEADER_ELECTION_LEASE_TIMEOUT_MS",
+            cfg.coordinator.leader_election.lease_timeout_ms,
+        );
+        // Intraday loss limit (flat-and-halt until operator re-enable).
+        cfg.coordinator.loss_limit.enabled = env_bool(
+            "PLOY_COORDINATOR__LOSS_LIMIT_ENABLED",
+            cfg.coordinator.loss_limit.enabled,
+        );
+        if let Some(v) = env_decimal_opt("PLOY_COORDINATOR__LOSS_LIMIT_GLOBAL_USD")
+            .filter(|v| *v > rust_decimal::Decimal::ZERO)
+        {
+            cfg.coordinator.loss_limit.global_limit_usd = Some(v);
+        }
+        if let Some(v) = env_decimal_opt("PLOY_COORDINATOR__LOSS_LIMIT_AGENT_USD")
+            .filter(|v| *v > rust_decimal::Decimal::ZERO)
+        {
+            cfg.coordinator.loss_limit.agent_limit_usd = Some(v);
+        }
+        // Per-agent sandbox budgets.
+        cfg.coordinator.budget.enabled = env_bool(
+            "PLOY_COORDINATOR__BUDGET_ENABLED",
+            cfg.coordinator.budget.enabled,
+        );
+        if let Some(v) = env_decimal_opt("PLOY_COORDINATOR__BUDGET_DEFAULT_USD")
+            .filter(|v| *v > rust_decimal::Decimal::ZERO)
+        {
+            cfg.coordinator.budget.default_budget_usd = Some(v);
+        }
+        if let Some(v) = env_decimal_opt("PLOY_COORDINATOR__BUDGET_AUTO_TOP_UP_MAX_USD")
+            .filter(|v| *v >= rust_decimal::Decimal::ZERO)
+        {
+            cfg.coordinator.budget.auto_top_up_max_usd = v;
+        }
+        // Cross-agent opposing-side conflicts: report | cancel | internalize.
+        if let Ok(raw) = std::env::var("PLOY_COORDINATOR__CONFLICT_POLICY") {
+            let v = raw.trim().to_ascii_lowercase();
+            cfg.coordinator.conflict_policy = match v.as_str() {
+                "report" => ConflictPolicy::Report,
+                "cancel" => ConflictPolicy::Cancel,
+                "internalize" => ConflictPolicy::Internalize,
+                _ => cfg.coordinator.conflict_policy,
+            };
+        }
         // Coordinator-level Kelly sizing (optional; applied when intents carry `signal_fair_value`).
         cfg.coordinator.kelly_sizing_enabled = env_bool(
             "PLOY_COORDINATOR__KELLY_SIZING_ENABLED",
@@ -4858,6 +5166,7 @@ async fn run_managed_strategy_runtime(
     mut shutdown_rx: broadcast::Receiver<()>,
 ) -> Result<()> {
     let strategy = StrategyFactory::from_toml(&strategy_config_toml, dry_run)?;
+    let warmup = StrategyFactory::warmup_from_toml(&strategy_config_toml)?;
     let strategy_id = strategy.id().to_string();
     let required_feeds = strategy.required_feeds();
     let started_at = Utc::now();
@@ -4935,7 +5244,9 @@ async fn run_managed_strategy_runtime(
         feed_manager = feed_manager.with_polymarket(pm_ws, pm_client.clone());
     }
 
-    manager.start_strategy(strategy, None).await?;
+    manager
+        .start_strategy_with_warmup(strategy, None, warmup)
+        .await?;
     feed_manager.start().await?;
     let subscribed_tokens = feed_manager.start_for_feeds(required_feeds).await?;
 
@@ -5077,6 +5388,26 @@ async fn run_managed_strategy_runtime(
     Ok(())
 }
 
+/// API key manager backed by the encrypted on-disk store, so the live
+/// client rotates keys the exchange keeps rejecting.
+async fn build_credential_manager(
+    rest_url: &str,
+    wallet: &Wallet,
+) -> Option<Arc<CredentialManager>> {
+    let wallet = Arc::new(wallet.clone());
+    let cipher = match CredentialCipher::from_wallet(&wallet).await {
+        Ok(cipher) => cipher,
+        Err(e) => {
+            warn!("CLOB credential manager disabled: {}", e);
+            return None;
+        }
+    };
+    let store = EncryptedCredentialStore::new(EncryptedCredentialStore::default_path(), cipher);
+    Some(Arc::new(
+        CredentialManager::new(rest_url, wallet).with_store(store),
+    ))
+}
+
 /// Start the multi-agent platform
 ///
 /// Creates shared infrastructure, registers configured agents,
@@ -5088,6 +5419,12 @@ pub async fn start_platform(
 ) -> Result<()> {
     let exchange_kind = parse_exchange_kind(&app_config.execution.exchange)?;
     let exchange_client = build_exchange_client(app_config, config.dry_run).await?;
+    // Simulated quote latency for dry-run stress testing (never applied live).
+    let dry_run_latency = if config.dry_run {
+        app_config.dry_run.latency.injector()
+    } else {
+        None
+    };
     let non_pm_builtin_agents_enabled = exchange_kind != ExchangeKind::Polymarket
         && (config.enable_crypto || config.enable_sports || config.enable_politics);
     if non_pm_builtin_agents_enabled {
@@ -5115,15 +5452,18 @@ pub async fn start_platform(
             Some(PolymarketClient::new(rest_url, true)?)
         } else {
             let wallet = Wallet::from_env(POLYGON_CHAIN_ID)?;
+            let credentials = build_credential_manager(rest_url, &wallet).await;
             let funder = std::env::var("POLYMARKET_FUNDER").ok();
-            if let Some(funder_addr) = funder {
-                Some(
-                    PolymarketClient::new_authenticated_proxy(rest_url, wallet, &funder_addr, true)
-                        .await?,
-                )
+            let client = if let Some(funder_addr) = funder {
+                PolymarketClient::new_authenticated_proxy(rest_url, wallet, &funder_addr, true)
+                    .await?
             } else {
-                Some(PolymarketClient::new_authenticated(rest_url, wallet, true).await?)
-            }
+                PolymarketClient::new_authenticated(rest_url, wallet, true).await?
+            };
+            Some(match credentials {
+                Some(manager) => client.with_credential_manager(manager),
+                None => client,
+            })
         }
     } else {
         None
@@ -5202,10 +5542,63 @@ pub async fn start_platform(
         }
     };
 
+    // 0b. Shared Prometheus counters, served on PLOY_METRICS_PORT (0 disables)
+    let metrics = Arc::new(Metrics::new());
+    let metrics_port = u16::try_from(env_u64("PLOY_METRICS_PORT", 9090)).unwrap_or(0);
+    if metrics_port > 0 {
+        let server = HealthServer::new(
+            Arc::new(HealthState::new().with_metrics(metrics.clone())),
+            metrics_port,
+        );
+        tokio::spawn(async move {
+            if let Err(e) = server.run().await {
+                warn!(port = metrics_port, error = %e, "metrics server stopped");
+            }
+        });
+    }
+
     // 1. Create shared executor (+ DB-backed idempotency when DB is available)
     let exec_config = app_config.execution.clone();
     let mut executor_builder =
         OrderExecutor::new_with_exchange(exchange_client.clone(), exec_config);
+
+    // One fill ledger for every source of trade reports: the executor's own
+    // order reads and the order monitor's polls. Fills stay in memory for the
+    // warm-start window.
+    let fill_window = chrono::Duration::hours(env_i64("PLOY_FILL_LEDGER_WARM_START_HOURS", 72));
+    let mut fill_ledger = FillLedger::new()
+        .with_retention(fill_window)
+        .with_metrics(metrics.clone());
+    if let Some(pool) = shared_pool.as_ref() {
+        fill_ledger = fill_ledger.with_pool(pool.clone());
+    }
+    let fill_ledger = Arc::new(fill_ledger);
+    executor_builder = executor_builder.with_fill_ledger(fill_ledger.clone());
+    // Passive order outcomes feed the live fillability gauges and the risk
+    // gate's fillability check.
+    executor_builder = executor_builder.with_fillability(Arc::new(
+        FillabilityTracker::new(FillabilityConfig::default()).with_metrics(metrics.clone()),
+    ));
+    if exchange_kind == ExchangeKind::Polymarket && !config.dry_run {
+        if let Some(client) = pm_client.clone() {
+            let monitor_config = OrderMonitorConfig {
+                auto_cancel_orphans: env_bool("PLOY_ORDER_MONITOR_CANCEL_ORPHANS", false),
+                ..OrderMonitorConfig::default()
+            };
+            let monitor = Arc::new(
+                OrderMonitor::new(
+                    Arc::new(client),
+                    shared_pool
+                        .as_ref()
+                        .map(|pool| Arc::new(PostgresStore::from_pool(pool.clone()))),
+                    monitor_config,
+                )
+                .with_fill_ledger(fill_ledger.clone()),
+            );
+            monitor.start().await;
+            executor_builder = executor_builder.with_order_monitor(monitor);
+        }
+    }
     if let Some(pool) = shared_pool.as_ref() {
         let idem_store = PostgresStore::from_pool(pool.clone());
         let idem_mgr = Arc::new(IdempotencyManager::new_with_account(
@@ -5234,13 +5627,42 @@ pub async fn start_platform(
     }
     let executor = Arc::new(executor_builder);
 
+    // 1c. Hot standby: block until this instance holds the leader lease, so
+    // runtime state is restored from what the previous leader persisted.
+    let mut leader_elector = None;
+    if config.coordinator.leader_election.enabled {
+        let pool = shared_pool.as_ref().ok_or_else(|| {
+            crate::error::PloyError::Internal(
+                "leader election requires a database connection".to_string(),
+            )
+        })?;
+        let mut elector = LeaderElector::new(
+            config.coordinator.leader_election.clone(),
+            pool.clone(),
+            &account_id,
+        );
+        let instance_id = elector.instance_id().to_string();
+        tokio::select! {
+            acquired = elector.wait_for_leadership() => acquired?,
+            _ = tokio::signal::ctrl_c() => {
+                info!(%instance_id, "standby stopped before taking over");
+                return Ok(());
+            }
+        }
+        leader_elector = Some(elector);
+    }
+
     // 2. Create coordinator
     let mut coordinator = Coordinator::new(
         config.coordinator.clone(),
-        executor,
+        executor.clone(),
         account_id.clone(),
         allowed_domains.clone(),
+        Some(metrics.clone()),
     );
+    if let Some(elector) = leader_elector.as_ref() {
+        coordinator.set_leadership(elector.leadership());
+    }
     if let Some(pool) = shared_pool.as_ref() {
         // Run migrations by default whenever a DB connection is available, even in dry-run.
         // This prevents long-lived services from starting on a stale schema.
@@ -5390,6 +5812,12 @@ pub async fn start_platform(
         } else if let Err(e) = coordinator.restore_risk_runtime_state().await {
             warn!(error = %e, "failed to restore risk runtime state");
         }
+        if let Err(e) = coordinator.restore_loss_limit_halts().await {
+            warn!(error = %e, "failed to restore intraday loss limit halts");
+        }
+        if let Err(e) = coordinator.restore_agent_budgets().await {
+            warn!(error = %e, "failed to restore sandbox budgets");
+        }
         if config.enable_crypto {
             if let Err(e) = ensure_clob_trade_alerts_table(pool).await {
                 if require_startup_schema {
@@ -5406,6 +5834,70 @@ pub async fn start_platform(
         }
     }
 
+    // Reload recently ingested fills so reports straddling a restart stay deduplicated
+    match fill_ledger.warm_start().await {
+        Ok(loaded) if loaded > 0 => info!(loaded, "fill ledger warm-started"),
+        Ok(_) => {}
+        Err(e) => warn!(error = %e, "fill ledger warm start failed"),
+    }
+
+    // 2b. Client-side conditional orders: restore armed triggers and finish
+    // brackets interrupted before their exits were armed, before any agent trades.
+    let conditional_orders = match shared_pool.as_ref() {
+        Some(pool) => {
+            let store = Arc::new(PostgresStore::from_pool(pool.clone()));
+            let (executions_tx, executions_rx) = mpsc::channel(256);
+            let conditionals = Arc::new(
+                ConditionalOrderManager::new(store.clone(), executor.clone())
+                    .with_executions(executions_tx),
+            );
+            let brackets = Arc::new(BracketManager::new(
+                store,
+                executor.clone(),
+                conditionals.clone(),
+            ));
+            let recovered = match conditionals.recover().await {
+                Ok(_) => brackets.recover().await.map(|_| ()),
+                Err(e) => Err(e),
+            };
+            if let Err(e) = recovered {
+                if env_bool(
+                    "PLOY_REQUIRE_RUNTIME_STATE_RESTORE",
+                    !app_config.dry_run.enabled,
+                ) {
+                    return Err(crate::error::PloyError::Internal(format!(
+                        "failed to recover conditional orders: {}",
+                        e
+                    )));
+                }
+                warn!(error = %e, "failed to recover conditional orders");
+            }
+            coordinator.set_conditional_orders(brackets, executions_rx);
+            Some(conditionals)
+        }
+        None => {
+            warn!("conditional orders and brackets disabled (no database connection)");
+            None
+        }
+    };
+
+    // 2c. Strategy cycles interrupted mid-hedge: hedge, compensate or abort them
+    // per `strategy.resume` before any agent trades.
+    if let Some(pool) = shared_pool.as_ref() {
+        if let Err(e) = recover_strategy_cycles(app_config, pool, exchange_client.clone()).await {
+            if env_bool(
+                "PLOY_REQUIRE_RUNTIME_STATE_RESTORE",
+                !app_config.dry_run.enabled,
+            ) {
+                return Err(crate::error::PloyError::Internal(format!(
+                    "failed to recover strategy cycles: {}",
+                    e
+                )));
+            }
+            warn!(error = %e, "failed to recover strategy cycles");
+        }
+    }
+
     let ingress_agents = std::env::var("PLOY_EXTERNAL_INGRESS_AGENT_IDS")
         .unwrap_or_else(|_| "openclaw_rpc,sidecar".to_string());
     for agent_id in ingress_agents
@@ -5494,6 +5986,9 @@ pub async fn start_platform(
 
     // 3. Shutdown broadcast channel
     let (shutdown_tx, _) = broadcast::channel::<()>(1);
+    if let Some(conditionals) = conditional_orders.as_ref() {
+        tokio::spawn(conditionals.clone().run(shutdown_tx.subscribe()));
+    }
 
     // 3b. Optional Polymarket settlement persistence (Gamma) for training labels.
     // Keep it read-only and enabled even in dry-run (no order placement).
@@ -5523,8 +6018,32 @@ pub async fn start_platform(
         }
     }
 
-    // 4. Spawn agents
+    // 3c. Daily PnL / risk report at UTC day close (read-only).
+    if let Some(pool) = shared_pool.as_ref() {
+        if env_bool("PLOY_DAILY_REPORT_ENABLED", true) {
+            let mut alerts = AlertManager::with_defaults();
+            if let Some(feishu) = FeishuNotifier::from_env() {
+                alerts = alerts.with_feishu(feishu);
+            }
+            if let Some(discord) = DiscordNotifier::from_env() {
+                alerts = alerts.with_channel(discord);
+            }
+            let mut report_cfg = DailyReportConfig::from_env();
+            report_cfg.account_id = Some(account_id.clone());
+            report_cfg.include_dry_run |= config.dry_run;
+            DailyReportService::new(pool.clone(), report_cfg)
+                .with_alerts(Arc::new(alerts))
+                .spawn();
+            info!(account_id = %account_id, "daily pnl report task started");
+        }
+    }
+
+    // 4. Spawn agents (each under panic supervision; a crashing agent is restarted
+    // with playbook backoff while the others keep running)
     let mut agent_handles = Vec::new();
+    let agent_supervisor = AgentSupervisor::new(RecoveryPlaybook::default()).with_crash_dir(
+        std::env::var("PLOY_CRASH_REPORT_DIR").unwrap_or_else(|_| "data/crash_reports".into()),
+    );
 
     if config.enable_crypto {
         let crypto_cfg = config.crypto.clone();
@@ -5593,7 +6112,16 @@ pub async fn start_platform(
         // Create WebSocket feeds
         let symbols: Vec<String> = all_coins.iter().map(|c| format!("{}USDT", c)).collect();
         let binance_ws = Arc::new(BinanceWebSocket::new(symbols));
-        let pm_ws = Arc::new(PolymarketWebSocket::new(&app_config.market.ws_url));
+        let mut pm_ws = PolymarketWebSocket::new(&app_config.market.ws_url)
+            .with_quote_sanitizer(app_config.market.quote_sanitizer.clone());
+        if let Some(latency) = dry_run_latency.clone() {
+            pm_ws = pm_ws.with_latency(latency);
+        }
+        let pm_ws = Arc::new(pm_ws);
+        // Reconnect and rotation-gap metrics
+        pm_ws.set_metrics(metrics.clone());
+        spawn_market_anomaly_detector(&config, &pm_ws, &handle, &shutdown_tx);
+        spawn_conditional_quote_feed(conditional_orders.as_ref(), &pm_ws, &shutdown_tx);
 
         // Seed PM token → side mapping for data collection, so QuoteUpdates carry the correct
         // UP/DOWN side and can be persisted to Postgres.
@@ -5836,7 +6364,15 @@ pub async fn start_platform(
                 Err(_) => all_coins.iter().map(|c| format!("{}USDT", c)).collect(),
             };
 
-            let depth_stream = Arc::new(crate::collector::BinanceDepthStream::new(depth_symbols));
+            let mut depth_stream = crate::collector::BinanceDepthStream::new(depth_symbols);
+            if let Some(secs) = std::env::var("PLOY_BINANCE_LOB__RECONCILE_SECS")
+                .ok()
+                .and_then(|raw| raw.trim().parse::<u64>().ok())
+            {
+                depth_stream =
+                    depth_stream.with_reconcile_interval(std::time::Duration::from_secs(secs));
+            }
+            let depth_stream = Arc::new(depth_stream);
             let lob_cache = depth_stream.cache().clone();
             lob_cache_opt = Some(lob_cache.clone());
 
@@ -5861,6 +6397,13 @@ pub async fn start_platform(
             );
         }
 
+        if let Some(monitor) = handle.toxicity_monitor() {
+            spawn_toxicity_feed(binance_ws.clone(), monitor);
+        }
+        if let Some(book) = handle.greeks_book() {
+            spawn_greeks_feed(binance_ws.clone(), book);
+        }
+
         // Spawn Binance WS in background
         let bws = binance_ws.clone();
         tokio::spawn(async move {
@@ -5879,24 +6422,35 @@ pub async fn start_platform(
 
         if momentum_enabled {
             if let Some(cmd_rx) = cmd_rx_opt {
-                let agent = CryptoTradingAgent::new(
+                let (cfg, bws, pws, matcher, toxicity, venue_health, calendar) = (
                     crypto_cfg.clone(),
                     binance_ws.clone(),
                     pm_ws.clone(),
                     event_matcher.clone(),
+                    handle.toxicity_monitor(),
+                    handle.venue_health(),
+                    handle.event_calendar(),
                 );
-                let ctx = AgentContext::new(
+                let mut build = move || -> Result<CryptoTradingAgent> {
+                    Ok(CryptoTradingAgent::new(
+                        cfg.clone(),
+                        bws.clone(),
+                        pws.clone(),
+                        matcher.clone(),
+                    )
+                    .with_toxicity_monitor(toxicity.clone())
+                    .with_venue_health(Some(venue_health.clone()))
+                    .with_event_calendar(Some(calendar.clone())))
+                };
+                let agent = build()?;
+                let jh = agent_supervisor.spawn(
                     crypto_cfg.agent_id.clone(),
                     Domain::Crypto,
                     handle.clone(),
                     cmd_rx,
+                    agent,
+                    build,
                 );
-
-                let jh = tokio::spawn(async move {
-                    if let Err(e) = agent.run(ctx).await {
-                        error!(agent = "crypto", error = %e, "agent exited with error");
-                    }
-                });
                 agent_handles.push(jh);
                 info!("crypto momentum agent spawned");
             } else {
@@ -6063,6 +6617,11 @@ pub async fn start_platform(
             }
         }
 
+        // Point-in-time features shared by the crypto ML agents.
+        let feature_store = crate::ml::SharedFeatureStore::new(
+            crate::ml::FeatureStore::new().with_retention(chrono::Duration::hours(1)),
+        );
+
         if lob_agent_enabled {
             let model_type = lob_cfg.model_type.trim().to_ascii_lowercase();
             let model_is_tcn = matches!(
@@ -6091,30 +6650,38 @@ pub async fn start_platform(
             } else {
                 if let Some(lob_cache) = lob_cache_opt.clone() {
                     let risk_params = lob_cfg.risk_params.clone();
-                    let agent = CryptoLobMlAgent::new(
+                    let (cfg, bws, pws, matcher, features) = (
                         lob_cfg.clone(),
                         binance_ws.clone(),
                         pm_ws.clone(),
                         event_matcher.clone(),
-                        lob_cache,
-                    )?;
+                        feature_store.clone(),
+                    );
+                    let mut build = move || {
+                        CryptoLobMlAgent::new(
+                            cfg.clone(),
+                            bws.clone(),
+                            pws.clone(),
+                            matcher.clone(),
+                            lob_cache.clone(),
+                        )
+                        .map(|agent| agent.with_feature_store(features.clone()))
+                    };
+                    let agent = build()?;
                     let cmd_rx = coordinator.register_agent(
                         lob_cfg.agent_id.clone(),
                         Domain::Crypto,
                         risk_params,
                     );
-                    let ctx = AgentContext::new(
+
+                    let jh = agent_supervisor.spawn(
                         lob_cfg.agent_id.clone(),
                         Domain::Crypto,
                         handle.clone(),
                         cmd_rx,
+                        agent,
+                        build,
                     );
-
-                    let jh = tokio::spawn(async move {
-                        if let Err(e) = agent.run(ctx).await {
-                            error!(agent = "crypto_lob_ml", error = %e, "agent exited with error");
-                        }
-                    });
                     agent_handles.push(jh);
                     info!("crypto lob-ml agent spawned");
                 } else {
@@ -6137,25 +6704,33 @@ pub async fn start_platform(
                     risk_params,
                 );
 
-                let agent = CryptoRlPolicyAgent::new(
+                let (cfg, bws, pws, matcher, features) = (
                     rl_cfg.clone(),
                     binance_ws.clone(),
                     pm_ws.clone(),
                     event_matcher.clone(),
-                    lob_cache,
+                    feature_store.clone(),
                 );
-                let ctx = AgentContext::new(
+                let mut build = move || -> Result<CryptoRlPolicyAgent> {
+                    Ok(CryptoRlPolicyAgent::new(
+                        cfg.clone(),
+                        bws.clone(),
+                        pws.clone(),
+                        matcher.clone(),
+                        lob_cache.clone(),
+                    )
+                    .with_feature_store(features.clone()))
+                };
+                let agent = build()?;
+
+                let jh = agent_supervisor.spawn(
                     rl_cfg.agent_id.clone(),
                     Domain::Crypto,
                     handle.clone(),
                     cmd_rx,
+                    agent,
+                    build,
                 );
-
-                let jh = tokio::spawn(async move {
-                    if let Err(e) = agent.run(ctx).await {
-                        error!(agent = "crypto_rl_policy", error = %e, "agent exited with error");
-                    }
-                });
                 agent_handles.push(jh);
                 info!("crypto RL policy agent spawned");
             } else {
@@ -6200,7 +6775,19 @@ pub async fn start_platform(
             // collector_token_targets (domain = SPORTS_NBA) and refreshed every cycle
             // together with the trade persistence above.
             {
-                let sports_pm_ws = Arc::new(PolymarketWebSocket::new(&app_config.market.ws_url));
+                let mut sports_pm_ws = PolymarketWebSocket::new(&app_config.market.ws_url)
+                    .with_quote_sanitizer(app_config.market.quote_sanitizer.clone());
+                if let Some(latency) = dry_run_latency.clone() {
+                    sports_pm_ws = sports_pm_ws.with_latency(latency);
+                }
+                let sports_pm_ws = Arc::new(sports_pm_ws);
+                sports_pm_ws.set_metrics(metrics.clone());
+                spawn_market_anomaly_detector(&config, &sports_pm_ws, &handle, &shutdown_tx);
+                spawn_conditional_quote_feed(
+                    conditional_orders.as_ref(),
+                    &sports_pm_ws,
+                    &shutdown_tx,
+                );
 
                 // Seed initial NBA tokens from collector_token_targets
                 let mut sports_desired: HashMap<String, Side> = HashMap::new();
@@ -6304,63 +6891,72 @@ pub async fn start_platform(
                 );
             }
 
-            let espn = crate::strategy::nba_comeback::espn::EspnClient::new();
-            let stats = crate::strategy::nba_comeback::ComebackStatsProvider::new(
-                pool.clone(),
-                nba_cfg.season.clone(),
-            );
-            let core =
-                crate::strategy::nba_comeback::NbaComebackCore::new(espn, stats, nba_cfg.clone());
-            let mut agent =
-                SportsTradingAgent::new(sports_cfg.clone(), core).with_observation_pool(pool);
-            match PolymarketSportsClient::new() {
-                Ok(pm_sports) => {
-                    agent = agent.with_pm_sports(pm_sports);
-                }
-                Err(e) => {
-                    warn!(
-                        agent = sports_cfg.agent_id,
-                        error = %e,
-                        "failed to initialize PolymarketSportsClient; continuing without PM market observations"
-                    );
+            let sentiment = spawn_comment_sentiment(&nba_cfg.comment_sentiment);
+            let (agent_cfg, nba_cfg) = (sports_cfg.clone(), nba_cfg.clone());
+            let mut build = move || -> Result<SportsTradingAgent> {
+                let espn = crate::strategy::nba_comeback::espn::EspnClient::new();
+                let stats = crate::strategy::nba_comeback::ComebackStatsProvider::new(
+                    pool.clone(),
+                    nba_cfg.season.clone(),
+                );
+                let mut core = crate::strategy::nba_comeback::NbaComebackCore::new(
+                    espn,
+                    stats,
+                    nba_cfg.clone(),
+                );
+                if let Some(sentiment) = sentiment.clone() {
+                    core = core.with_sentiment(sentiment);
                 }
-            }
-            if nba_cfg.grok_enabled {
-                match crate::ai_clients::grok::GrokClient::from_env() {
-                    Ok(grok) if grok.is_configured() => {
-                        info!(
-                            agent = sports_cfg.agent_id,
-                            "grok live search enabled for sports agent"
-                        );
-                        agent = agent.with_grok(grok);
-                    }
-                    Ok(_) => {
-                        warn!(
-                            agent = sports_cfg.agent_id,
-                            "grok_enabled=true but GROK_API_KEY not set; continuing without Grok"
-                        );
+                let mut agent = SportsTradingAgent::new(agent_cfg.clone(), core)
+                    .with_observation_pool(pool.clone());
+                match PolymarketSportsClient::new() {
+                    Ok(pm_sports) => {
+                        agent = agent.with_pm_sports(pm_sports);
                     }
                     Err(e) => {
                         warn!(
-                            agent = sports_cfg.agent_id,
+                            agent = agent_cfg.agent_id,
                             error = %e,
-                            "failed to initialize GrokClient; continuing without Grok"
+                            "failed to initialize PolymarketSportsClient; continuing without PM market observations"
                         );
                     }
                 }
-            }
-            let ctx = AgentContext::new(
+                if nba_cfg.grok_enabled {
+                    match crate::ai_clients::grok::GrokClient::from_env() {
+                        Ok(grok) if grok.is_configured() => {
+                            info!(
+                                agent = agent_cfg.agent_id,
+                                "grok live search enabled for sports agent"
+                            );
+                            agent = agent.with_grok(grok);
+                        }
+                        Ok(_) => {
+                            warn!(
+                                agent = agent_cfg.agent_id,
+                                "grok_enabled=true but GROK_API_KEY not set; continuing without Grok"
+                            );
+                        }
+                        Err(e) => {
+                            warn!(
+                                agent = agent_cfg.agent_id,
+                                error = %e,
+                                "failed to initialize GrokClient; continuing without Grok"
+                            );
+                        }
+                    }
+                }
+                Ok(agent)
+            };
+            let agent = build()?;
+
+            let jh = agent_supervisor.spawn(
                 sports_cfg.agent_id.clone(),
                 Domain::Sports,
                 handle.clone(),
                 cmd_rx,
+                agent,
+                build,
             );
-
-            let jh = tokio::spawn(async move {
-                if let Err(e) = agent.run(ctx).await {
-                    error!(agent = "sports", error = %e, "agent exited with error");
-                }
-            });
             agent_handles.push(jh);
             info!("sports agent spawned");
         }
@@ -6382,20 +6978,39 @@ pub async fn start_platform(
                         .to_string(),
                 )
             })?;
-            let core = EventEdgeCore::new(pm_client_ref.clone(), ee_cfg.clone());
-            let agent = PoliticsTradingAgent::new(politics_cfg.clone(), core);
-            let ctx = AgentContext::new(
+            let sentiment = spawn_comment_sentiment(&ee_cfg.comment_sentiment);
+            let flip_alerts = ee_cfg.arena_history.enabled.then(|| {
+                let mut alerts = AlertManager::with_defaults();
+                if let Some(feishu) = FeishuNotifier::from_env() {
+                    alerts = alerts.with_feishu(feishu);
+                }
+                if let Some(discord) = DiscordNotifier::from_env() {
+                    alerts = alerts.with_channel(discord);
+                }
+                Arc::new(alerts)
+            });
+            let (client, core_cfg, agent_cfg) =
+                (pm_client_ref.clone(), ee_cfg.clone(), politics_cfg.clone());
+            let mut build = move || -> Result<PoliticsTradingAgent> {
+                let mut core = EventEdgeCore::new(client.clone(), core_cfg.clone());
+                if let Some(sentiment) = sentiment.clone() {
+                    core = core.with_sentiment(sentiment);
+                }
+                if let Some(alerts) = flip_alerts.clone() {
+                    core = core.with_alerts(alerts);
+                }
+                Ok(PoliticsTradingAgent::new(agent_cfg.clone(), core))
+            };
+            let agent = build()?;
+
+            let jh = agent_supervisor.spawn(
                 politics_cfg.agent_id.clone(),
                 Domain::Politics,
                 handle.clone(),
                 cmd_rx,
+                agent,
+                build,
             );
-
-            let jh = tokio::spawn(async move {
-                if let Err(e) = agent.run(ctx).await {
-                    error!(agent = "politics", error = %e, "agent exited with error");
-                }
-            });
             agent_handles.push(jh);
             info!("politics agent spawned");
         }
@@ -6433,19 +7048,20 @@ pub async fn start_platform(
         let cmd_rx =
             coordinator.register_agent(oc_agent_id.clone(), Domain::Custom(0), oc_risk_params);
 
-        let agent = OpenClawAgent::new(config.openclaw.clone(), oc_binance_ws);
-        let ctx = AgentContext::new(
+        let oc_cfg = config.openclaw.clone();
+        let mut build = move || -> Result<OpenClawAgent> {
+            Ok(OpenClawAgent::new(oc_cfg.clone(), oc_binance_ws.clone()))
+        };
+        let agent = build()?;
+
+        let jh = agent_supervisor.spawn(
             oc_agent_id.clone(),
             Domain::Custom(0),
             handle.clone(),
             cmd_rx,
+            agent,
+            build,
         );
-
-        let jh = tokio::spawn(async move {
-            if let Err(e) = agent.run(ctx).await {
-                tracing::error!(agent = "openclaw", error = %e, "openclaw meta-agent exited with error");
-            }
-        });
         agent_handles.push(jh);
         info!(
             agent_id = %oc_agent_id,
@@ -6480,6 +7096,66 @@ pub async fn start_platform(
         }
     }
 
+    // 4c. Host resource monitor (throttles quotes / pauses non-critical agents under pressure)
+    if config.coordinator.resource_monitor.enabled {
+        let monitor = ResourceMonitor::new(
+            config.coordinator.resource_monitor.clone(),
+            handle.quote_throttle(),
+        );
+        tokio::spawn(monitor.run(handle.clone(), shutdown_tx.subscribe()));
+    }
+
+    // 4d. Exchange / chain health monitor (widens thresholds / pauses entries / de-risks)
+    if config.coordinator.venue_monitor.enabled {
+        let monitor = VenueMonitor::new(
+            config.coordinator.venue_monitor.clone(),
+            handle.venue_health(),
+        );
+        tokio::spawn(monitor.run(handle.clone(), shutdown_tx.subscribe()));
+    }
+
+    // 4d'. High-impact event calendar (NBA schedule, CPI / FOMC, unlocks)
+    if config.coordinator.event_calendar.enabled {
+        let service = EventCalendarService::new(
+            config.coordinator.event_calendar.clone(),
+            handle.event_calendar(),
+        );
+        tokio::spawn(service.run(shutdown_tx.subscribe()));
+    }
+
+    // 4e. Strategy decay monitor (live vs backtest EV / fill rate; alerts, optional pause)
+    if config.coordinator.performance_monitor.enabled {
+        if let Some(pool) = shared_pool.as_ref() {
+            let mut alerts = AlertManager::with_defaults();
+            if let Some(feishu) = FeishuNotifier::from_env() {
+                alerts = alerts.with_feishu(feishu);
+            }
+            if let Some(discord) = DiscordNotifier::from_env() {
+                alerts = alerts.with_channel(discord);
+            }
+            let mut monitor_cfg = config.coordinator.performance_monitor.clone();
+            monitor_cfg.include_dry_run |= config.dry_run;
+            let monitor = PerformanceMonitor::new(monitor_cfg, pool.clone(), &account_id)
+                .with_alerts(Arc::new(alerts));
+            tokio::spawn(monitor.run(handle.clone(), shutdown_tx.subscribe()));
+        } else {
+            warn!("performance monitor enabled but no database pool; skipping");
+        }
+    }
+
+    // 4f. Leader lease heartbeat + runtime checkpoints (fences and shuts down on loss)
+    if let Some(elector) = leader_elector {
+        if let Err(e) = elector.restore_checkpoint(&handle).await {
+            warn!(error = %e, "failed to restore runtime checkpoint from previous leader");
+        }
+        tokio::spawn(elector.run(handle.clone(), shutdown_tx.clone()));
+    }
+
+    // 4g. Re-validate and re-queue intents persisted by the previous run
+    if let Err(e) = coordinator.restore_queued_intents().await {
+        warn!(error = %e, "failed to restore persisted order queue");
+    }
+
     // 5. Run coordinator (blocks until shutdown signal)
     let shutdown_rx = shutdown_tx.subscribe();
 
@@ -6521,10 +7197,12 @@ pub fn print_platform_status(state: &GlobalState) {
         state.total_realized_pnl
     );
     println!(
-        "Queue: size={} enqueued={} dequeued={}",
+        "Queue: size={} enqueued={} dequeued={} restored={} oldest_age={}ms",
         state.queue_stats.current_size,
         state.queue_stats.enqueued_total,
-        state.queue_stats.dequeued_total
+        state.queue_stats.dequeued_total,
+        state.queue_stats.restored_total,
+        state.queue_stats.oldest_age_ms
     );
     println!("\n--- Agents ({}) ---", state.agents.len());
     for (id, agent) in &state.agents {
@@ -6844,4 +7522,35 @@ mod tests {
             None => set_env(legacy_price_exits_key, None),
         }
     }
+
+    #[test]
+    fn from_app_config_merges_toxicity_symbol_overrides() {
+        let _guard = ENV_LOCK.lock().unwrap();
+
+        let key = "PLOY_COORDINATOR__TOXICITY_SYMBOLS_JSON";
+        let prev = std::env::var(key).ok();
+        set_env(key, Some(r#"{"ethusdt":{"high_threshold":0.8}}"#));
+
+        let mut app = AppConfig::default_config(true, "btc-up-or-down-test");
+        let mut toxicity = crate::analysis::VpinConfig::default();
+        toxicity.symbols.insert(
+            "btcusdt".to_string(),
+            VpinSymbolConfig {
+                bucket_volume_usd: 500_000.0,
+                ..VpinSymbolConfig::default()
+            },
+        );
+        app.toxicity = Some(toxicity);
+        let cfg = PlatformBootstrapConfig::from_app_config(&app);
+
+        let toxicity = &cfg.coordinator.toxicity;
+        assert_eq!(toxicity.for_symbol("BTCUSDT").bucket_volume_usd, 500_000.0);
+        assert_eq!(toxicity.for_symbol("ETHUSDT").high_threshold, 0.8);
+        assert_eq!(
+            toxicity.for_symbol("SOLUSDT").high_threshold,
+            VpinSymbolConfig::default().high_threshold
+        );
+
+        set_env(key, prev.as_deref());
+    }
 }
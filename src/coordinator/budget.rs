@@ -0,0 +1,377 @@
+//! Per-agent sandbox budgets
+//!
+//! Every agent trades out of its own spendable balance, which bounds the
+//! damage a single misbehaving agent can do. Balances are the sum of entries
+//! in the `agent_budget_ledger` table: an opening allocation, BUY fills
+//! (debit the notional), SELL fills and settlements (credit the proceeds) and
+//! top-ups. BUY intents larger than the remaining balance are blocked.
+//!
+//! Top-ups are requested from the CLI (`ploy risk budget-top-up`) into
+//! `agent_budget_top_ups`. The coordinator applies requests up to
+//! `auto_top_up_max_usd` on its next refresh; larger ones wait for an
+//! operator, who approves from the Feishu card or the CLI.
+
+use chrono::{DateTime, Utc};
+use rust_decimal::Decimal;
+use serde::{Deserialize, Serialize};
+use sqlx::PgPool;
+use std::collections::HashMap;
+use uuid::Uuid;
+
+use crate::error::Result;
+
+/// Sandbox budget configuration
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+#[serde(default)]
+pub struct AgentBudgetConfig {
+    pub enabled: bool,
+    /// Opening allocation for agents without an override; None leaves them unbudgeted
+    pub default_budget_usd: Option<Decimal>,
+    /// Per-agent overrides of `default_budget_usd`
+    pub agent_budgets_usd: HashMap<String, Decimal>,
+    /// Largest top-up applied without operator approval
+    pub auto_top_up_max_usd: Decimal,
+}
+
+impl AgentBudgetConfig {
+    pub fn allocation_for(&self, agent_id: &str) -> Option<Decimal> {
+        self.agent_budgets_usd
+            .get(agent_id)
+            .copied()
+            .or(self.default_budget_usd)
+    }
+}
+
+/// Kind of a ledger entry
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "SCREAMING_SNAKE_CASE")]
+pub enum LedgerEntryKind {
+    /// Opening allocation
+    Allocation,
+    /// BUY fill (negative amount)
+    Fill,
+    /// SELL fill or redemption proceeds
+    Settlement,
+    /// Operator or auto-approved top-up
+    TopUp,
+}
+
+impl LedgerEntryKind {
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            LedgerEntryKind::Allocation => "ALLOCATION",
+            LedgerEntryKind::Fill => "FILL",
+            LedgerEntryKind::Settlement => "SETTLEMENT",
+            LedgerEntryKind::TopUp => "TOP_UP",
+        }
+    }
+
+    pub fn parse(raw: &str) -> Option<Self> {
+        match raw {
+            "ALLOCATION" => Some(LedgerEntryKind::Allocation),
+            "FILL" => Some(LedgerEntryKind::Fill),
+            "SETTLEMENT" => Some(LedgerEntryKind::Settlement),
+            "TOP_UP" => Some(LedgerEntryKind::TopUp),
+            _ => None,
+        }
+    }
+}
+
+/// Running totals of one agent's budget
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+pub struct AgentBudget {
+    pub agent_id: String,
+    /// Opening allocation plus top-ups
+    pub allocated: Decimal,
+    /// Notional of BUY fills
+    pub spent: Decimal,
+    /// Proceeds of SELL fills and settlements
+    pub credited: Decimal,
+}
+
+impl AgentBudget {
+    pub fn balance(&self) -> Decimal {
+        self.allocated - self.spent + self.credited
+    }
+}
+
+/// In-memory view of the ledger
+#[derive(Debug, Default)]
+pub struct BudgetLedger {
+    budgets: HashMap<String, AgentBudget>,
+}
+
+impl BudgetLedger {
+    /// Apply a ledger entry; fills are passed as positive notionals
+    pub fn apply(&mut self, agent_id: &str, kind: LedgerEntryKind, amount: Decimal) {
+        let budget = self
+            .budgets
+            .entry(agent_id.to_string())
+            .or_insert_with(|| AgentBudget {
+                agent_id: agent_id.to_string(),
+                ..Default::default()
+            });
+        match kind {
+            LedgerEntryKind::Allocation | LedgerEntryKind::TopUp => budget.allocated += amount,
+            LedgerEntryKind::Fill => budget.spent += amount.abs(),
+            LedgerEntryKind::Settlement => budget.credited += amount,
+        }
+    }
+
+    pub fn get(&self, agent_id: &str) -> Option<&AgentBudget> {
+        self.budgets.get(agent_id)
+    }
+
+    pub fn is_tracked(&self, agent_id: &str) -> bool {
+        self.budgets.contains_key(agent_id)
+    }
+
+    /// Block reason when a BUY of `notional` exceeds the agent's balance.
+    /// Agents without a budget are not constrained.
+    pub fn check_buy(&self, agent_id: &str, notional: Decimal) -> Option<String> {
+        let budget = self.budgets.get(agent_id)?;
+        let balance = budget.balance();
+        (notional > balance).then(|| {
+            format!(
+                "sandbox budget exhausted: {} needs ${} but has ${} left",
+                agent_id,
+                notional.round_dp(2),
+                balance.round_dp(2)
+            )
+        })
+    }
+
+    /// All budgets, by agent id
+    pub fn snapshot(&self) -> Vec<AgentBudget> {
+        let mut rows: Vec<_> = self.budgets.values().cloned().collect();
+        rows.sort_by(|a, b| a.agent_id.cmp(&b.agent_id));
+        rows
+    }
+}
+
+/// Append an entry to `agent_budget_ledger`. Fills are stored negative.
+pub async fn record_entry(
+    pool: &PgPool,
+    agent_id: &str,
+    kind: LedgerEntryKind,
+    amount: Decimal,
+    reference: Option<&str>,
+) -> Result<()> {
+    let amount = if kind == LedgerEntryKind::Fill {
+        -amount.abs()
+    } else {
+        amount
+    };
+    sqlx::query(
+        r#"
+        INSERT INTO agent_budget_ledger (agent_id, kind, amount, reference)
+        VALUES ($1, $2, $3, $4)
+        "#,
+    )
+    .bind(agent_id)
+    .bind(kind.as_str())
+    .bind(amount)
+    .bind(reference)
+    .execute(pool)
+    .await?;
+    Ok(())
+}
+
+/// Rebuild the ledger from `agent_budget_ledger`
+pub async fn load_ledger(pool: &PgPool) -> Result<BudgetLedger> {
+    let rows: Vec<(String, String, Decimal)> = sqlx::query_as(
+        r#"
+        SELECT agent_id, kind, COALESCE(SUM(amount), 0)
+        FROM agent_budget_ledger
+        GROUP BY agent_id, kind
+        "#,
+    )
+    .fetch_all(pool)
+    .await?;
+
+    let mut ledger = BudgetLedger::default();
+    for (agent_id, kind, amount) in rows {
+        if let Some(kind) = LedgerEntryKind::parse(&kind) {
+            ledger.apply(&agent_id, kind, amount);
+        }
+    }
+    Ok(ledger)
+}
+
+/// A requested top-up
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct TopUpRequest {
+    pub id: Uuid,
+    pub agent_id: String,
+    pub amount_usd: Decimal,
+    /// PENDING, APPROVED, DENIED or APPLIED
+    pub status: String,
+    pub requested_by: String,
+    pub decided_by: Option<String>,
+    pub created_at: DateTime<Utc>,
+}
+
+/// Record a top-up request; the coordinator applies or escalates it
+pub async fn request_top_up(
+    pool: &PgPool,
+    agent_id: &str,
+    amount_usd: Decimal,
+    requested_by: &str,
+) -> Result<Uuid> {
+    let id = Uuid::new_v4();
+    sqlx::query(
+        r#"
+        INSERT INTO agent_budget_top_ups (id, agent_id, amount, requested_by)
+        VALUES ($1, $2, $3, $4)
+        "#,
+    )
+    .bind(id)
+    .bind(agent_id)
+    .bind(amount_usd)
+    .bind(requested_by)
+    .execute(pool)
+    .await?;
+    Ok(id)
+}
+
+/// Operator decision on a pending top-up. Returns false if it was not pending.
+pub async fn decide_top_up(pool: &PgPool, id: Uuid, approve: bool, operator: &str) -> Result<bool> {
+    let result = sqlx::query(
+        r#"
+        UPDATE agent_budget_top_ups
+        SET status = $2, decided_by = $3, decided_at = NOW()
+        WHERE id = $1 AND status = 'PENDING'
+        "#,
+    )
+    .bind(id)
+    .bind(if approve { "APPROVED" } else { "DENIED" })
+    .bind(operator)
+    .execute(pool)
+    .await?;
+    Ok(result.rows_affected() == 1)
+}
+
+/// Top-ups in `status`, oldest first
+pub async fn load_top_ups(pool: &PgPool, status: &str) -> Result<Vec<TopUpRequest>> {
+    #[allow(clippy::type_complexity)]
+    let rows: Vec<(
+        Uuid,
+        String,
+        Decimal,
+        String,
+        String,
+        Option<String>,
+        DateTime<Utc>,
+    )> = sqlx::query_as(
+        r#"
+        SELECT id, agent_id, amount, status, requested_by, decided_by, created_at
+        FROM agent_budget_top_ups
+        WHERE status = $1
+        ORDER BY created_at
+        "#,
+    )
+    .bind(status)
+    .fetch_all(pool)
+    .await?;
+    Ok(rows
+        .into_iter()
+        .map(
+            |(id, agent_id, amount_usd, status, requested_by, decided_by, created_at)| {
+                TopUpRequest {
+                    id,
+                    agent_id,
+                    amount_usd,
+                    status,
+                    requested_by,
+                    decided_by,
+                    created_at,
+                }
+            },
+        )
+        .collect())
+}
+
+/// Mark a pending top-up as sent to Feishu. Returns false if it already was.
+pub async fn mark_top_up_notified(pool: &PgPool, id: Uuid) -> Result<bool> {
+    let result = sqlx::query(
+        r#"
+        UPDATE agent_budget_top_ups
+        SET notified_at = NOW()
+        WHERE id = $1 AND status = 'PENDING' AND notified_at IS NULL
+        "#,
+    )
+    .bind(id)
+    .execute(pool)
+    .await?;
+    Ok(result.rows_affected() == 1)
+}
+
+/// Credit a top-up currently in `from` (PENDING for auto-approval, APPROVED
+/// otherwise) and mark it APPLIED in one statement. Returns the applied
+/// request, or None if another process got there first.
+pub async fn apply_top_up(pool: &PgPool, id: Uuid, from: &str) -> Result<Option<TopUpRequest>> {
+    let row: Option<(String, Decimal, String, Option<String>, DateTime<Utc>)> = sqlx::query_as(
+        r#"
+        WITH applied AS (
+            UPDATE agent_budget_top_ups
+            SET status = 'APPLIED', applied_at = NOW()
+            WHERE id = $1 AND status = $2
+            RETURNING id, agent_id, amount, requested_by, decided_by, created_at
+        ), entry AS (
+            INSERT INTO agent_budget_ledger (agent_id, kind, amount, reference)
+            SELECT agent_id, 'TOP_UP', amount, id::text FROM applied
+        )
+        SELECT agent_id, amount, requested_by, decided_by, created_at FROM applied
+        "#,
+    )
+    .bind(id)
+    .bind(from)
+    .fetch_optional(pool)
+    .await?;
+    Ok(row.map(
+        |(agent_id, amount_usd, requested_by, decided_by, created_at)| TopUpRequest {
+            id,
+            agent_id,
+            amount_usd,
+            status: "APPLIED".to_string(),
+            requested_by,
+            decided_by,
+            created_at,
+        },
+    ))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use rust_decimal_macros::dec;
+
+    #[test]
+    fn fills_debit_and_settlements_credit_the_balance() {
+        let config = AgentBudgetConfig {
+            enabled: true,
+            default_budget_usd: Some(dec!(100)),
+            agent_budgets_usd: HashMap::from([("sports".to_string(), dec!(250))]),
+            auto_top_up_max_usd: dec!(50),
+        };
+        assert_eq!(config.allocation_for("sports"), Some(dec!(250)));
+        assert_eq!(config.allocation_for("crypto"), Some(dec!(100)));
+
+        let mut ledger = BudgetLedger::default();
+        assert!(ledger.check_buy("crypto", dec!(1000)).is_none());
+
+        ledger.apply("crypto", LedgerEntryKind::Allocation, dec!(100));
+        ledger.apply("crypto", LedgerEntryKind::Fill, dec!(80));
+        assert!(ledger.check_buy("crypto", dec!(20)).is_none());
+        assert!(ledger.check_buy("crypto", dec!(25)).is_some());
+
+        ledger.apply("crypto", LedgerEntryKind::Settlement, dec!(40));
+        ledger.apply("crypto", LedgerEntryKind::TopUp, dec!(10));
+        // Stored fills are negative; both signs debit
+        ledger.apply("crypto", LedgerEntryKind::Fill, dec!(-5));
+        let budget = ledger.get("crypto").unwrap();
+        assert_eq!(budget.allocated, dec!(110));
+        assert_eq!(budget.spent, dec!(85));
+        assert_eq!(budget.balance(), dec!(65));
+    }
+}
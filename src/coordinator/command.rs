@@ -5,6 +5,7 @@ use rust_decimal::Decimal;
 use serde::{Deserialize, Serialize};
 use std::collections::HashMap;
 use tokio::sync::oneshot;
+use uuid::Uuid;
 
 use super::state::{AgentSnapshot, QueueStatsSnapshot};
 use crate::platform::{Domain, PlatformRiskState};
@@ -37,8 +38,12 @@ pub enum CoordinatorControlCommand {
     ResumeAll,
     /// Resume agents for specific domain
     ResumeDomain(Domain),
-    /// Resume a single agent by ID
+    /// Resume a single agent by ID (also takes it out of standby)
     ResumeAgent(String),
+    /// Put a single agent in warm standby: it keeps running and computing
+    /// intents, but the coordinator logs its entries as would-trade instead
+    /// of executing them
+    StandbyAgent(String),
     /// Force-close all positions and stop agents
     ForceCloseAll,
     /// Force-close only positions for specific domain
@@ -47,6 +52,17 @@ pub enum CoordinatorControlCommand {
     ShutdownAll,
     /// Graceful shutdown for specific domain
     ShutdownDomain(Domain),
+    /// Operator re-enable of a scope halted by the intraday loss limit
+    /// (`global` or an agent id)
+    ReenableLossLimit(String),
+    /// Sell a fraction of every open position ahead of a venue outage
+    Derisk { fraction: Decimal, reason: String },
+    /// Operator decision on a sandbox budget top-up
+    DecideBudgetTopUp {
+        top_up_id: Uuid,
+        approve: bool,
+        operator: String,
+    },
 }
 
 /// Response to a HealthCheck command
@@ -3,7 +3,18 @@
 use rust_decimal::Decimal;
 use serde::{Deserialize, Serialize};
 
+use crate::agents::openclaw::conflict::ConflictPolicy;
+use crate::analysis::{BinaryGreeksConfig, VpinConfig};
+use crate::coordination::LeaderElectionConfig;
 use crate::platform::RiskConfig;
+use crate::strategy::execution::FillabilityGate;
+use crate::supervisor::{
+    EventCalendarConfig, MarketAnomalyConfig, PerformanceMonitorConfig, ResourceMonitorConfig,
+    VenueMonitorConfig,
+};
+
+use super::budget::AgentBudgetConfig;
+use super::loss_limit::LossLimitConfig;
 
 /// Scope for duplicate-intent guard.
 ///
@@ -95,6 +106,76 @@ pub struct CoordinatorConfig {
     /// Governance blocklist for domains (e.g. ["sports", "politics"]).
     pub governance_blocked_domains: Vec<String>,
 
+    // === Human-in-the-loop approvals ===
+    /// BUY intents at or above this notional (USD) are parked for operator
+    /// approval (Feishu card / admin API). None disables the approval gate.
+    pub approval_min_notional_usd: Option<Decimal>,
+    /// Agents subject to the approval gate. Empty = all agents.
+    pub approval_agent_ids: Vec<String>,
+    /// Pending approvals older than this are dropped.
+    pub approval_timeout_secs: u64,
+
+    // === Order-flow toxicity ===
+    /// VPIN estimator from Binance aggTrades; throttles crypto BUY intents in the
+    /// risk gate and widens strategy entry thresholds when flow is toxic.
+    pub toxicity: VpinConfig,
+
+    // === Binary position Greeks ===
+    /// Delta / theta / vega of open crypto round positions from Binance spot and
+    /// realized vol; caps new crypto BUYs by net delta (USD per 1% move).
+    pub greeks: BinaryGreeksConfig,
+
+    // === Live fillability ===
+    /// Holds BUY intents at the gate's base size until the token's live
+    /// passive fill rate (from the shared executor) is proven.
+    pub fillability: FillabilityGate,
+
+    // === Host resource pressure ===
+    /// Subsamples quote processing and pauses non-critical agents when CPU,
+    /// memory or file descriptors run short (small EC2 instances).
+    pub resource_monitor: ResourceMonitorConfig,
+
+    // === Venue health ===
+    /// Polymarket / Binance status and Polygon chain health; widens entry
+    /// thresholds, pauses entries or partially de-risks while degraded.
+    pub venue_monitor: VenueMonitorConfig,
+
+    // === Event calendar ===
+    /// NBA schedule, CPI / FOMC and token unlocks; agents shrink entries and
+    /// widen edge thresholds around high-impact events.
+    pub event_calendar: EventCalendarConfig,
+
+    // === Market anomalies ===
+    /// Per-token spread blowout / depth collapse / one-sided book detector;
+    /// flagged tokens are untradable for new entries until they recover.
+    pub market_anomaly: MarketAnomalyConfig,
+
+    // === Strategy decay ===
+    /// Live EV per trade / fill rate versus the latest backtest evaluation;
+    /// alerts (optionally pauses) when live falls significantly short.
+    pub performance_monitor: PerformanceMonitorConfig,
+
+    // === Hot standby ===
+    /// Postgres leader election; a standby waits for the lease and takes
+    /// over with a bounded gap when the leader stops heartbeating.
+    pub leader_election: LeaderElectionConfig,
+
+    // === Intraday loss limit ===
+    /// Realized + unrealized daily loss limits (account-wide and per agent);
+    /// a breach flattens the scope and halts it until operator re-enable.
+    pub loss_limit: LossLimitConfig,
+
+    // === Sandbox budgets ===
+    /// Per-agent spendable balance: BUY fills debit it, SELL fills credit it,
+    /// and large top-ups need operator approval.
+    pub budget: AgentBudgetConfig,
+
+    // === Cross-agent conflicts ===
+    /// Handling of BUY intents that oppose another agent's position or queued
+    /// BUY on the same market (cancel the redundant leg, internalize, or only
+    /// report).
+    pub conflict_policy: ConflictPolicy,
+
     // === Sizing policy (Coordinator-level) ===
     /// Enable Kelly-based sizing for buy intents when a strategy provides `signal_fair_value`.
     ///
@@ -163,6 +244,21 @@ impl Default for CoordinatorConfig {
             governance_max_intent_notional_usd: None,
             governance_max_total_notional_usd: None,
             governance_blocked_domains: Vec::new(),
+            approval_min_notional_usd: None,
+            approval_agent_ids: Vec::new(),
+            approval_timeout_secs: 300,
+            toxicity: VpinConfig::default(),
+            greeks: BinaryGreeksConfig::default(),
+            fillability: FillabilityGate::default(),
+            resource_monitor: ResourceMonitorConfig::default(),
+            venue_monitor: VenueMonitorConfig::default(),
+            event_calendar: EventCalendarConfig::default(),
+            market_anomaly: MarketAnomalyConfig::default(),
+            performance_monitor: PerformanceMonitorConfig::default(),
+            leader_election: LeaderElectionConfig::default(),
+            loss_limit: LossLimitConfig::default(),
+            budget: AgentBudgetConfig::default(),
+            conflict_policy: ConflictPolicy::default(),
 
             // Kelly sizing is opt-in by env to preserve conservative default behavior.
             kelly_sizing_enabled: false,
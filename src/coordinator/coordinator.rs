@@ -21,14 +21,22 @@ use uuid::Uuid;
 
 use sqlx::{PgPool, Row};
 
-use crate::domain::{OrderRequest, Side};
+use crate::agents::openclaw::conflict::{ConflictDetector, IntentResolution};
+use crate::analysis::{GreeksBook, ToxicityMonitor};
+use crate::coordination::{Leadership, UnwindConfig, UnwindPlanner};
+use crate::domain::{OrderRequest, Side, TimeInForce};
 use crate::error::Result;
 use crate::platform::{
-    AgentRiskParams, Domain, MarketSelector, OrderIntent, OrderPriority, OrderQueue,
-    PositionAggregator, RiskCheckResult, RiskGate, StrategyDeployment,
+    AgentRiskParams, Domain, MarketSelector, NotionalUsage, OrderIntent, OrderPriority, OrderQueue,
+    PositionAggregator, RiskCheckResult, RiskGate, RiskPolicy, StrategyDeployment, Timeframe,
 };
-use crate::strategy::executor::OrderExecutor;
+use crate::services::Metrics;
+use crate::strategy::executor::{ExecutionResult, OrderExecutor};
+use crate::strategy::{Bracket, BracketManager, ConditionalExecution};
+use crate::supervisor::{EventCalendar, MarketAnomalies, QuoteThrottle, VenueHealth};
 
+use super::approval::{ApprovalAction, ApprovalBook, ApprovalDecision, ApprovalSnapshot};
+use super::budget::{self, AgentBudget, BudgetLedger, LedgerEntryKind};
 use super::command::{
     AllocatorLedgerSnapshot, CoordinatorCommand, CoordinatorControlCommand,
     DeploymentLedgerSnapshot, DomainIngressSnapshot, GovernanceAgentSnapshot,
@@ -36,6 +44,7 @@ use super::command::{
     GovernanceStatusSnapshot,
 };
 use super::config::{CoordinatorConfig, DuplicateGuardScope};
+use super::loss_limit::{self, LossLimitBreach, LossLimitTracker, GLOBAL_SCOPE};
 use super::state::{AgentSnapshot, GlobalState, QueueStatsSnapshot};
 
 #[derive(Debug, Clone, Copy, PartialEq, Eq)]
@@ -439,6 +448,134 @@ async fn load_execution_log_outcomes(
         .collect())
 }
 
+fn parse_persisted_priority(raw: i16) -> OrderPriority {
+    match raw {
+        0 => OrderPriority::Critical,
+        1 => OrderPriority::High,
+        3 => OrderPriority::Low,
+        _ => OrderPriority::Normal,
+    }
+}
+
+/// One `order_queue_intents` row.
+#[derive(Debug, Clone, sqlx::FromRow)]
+struct QueuedIntentRow {
+    intent_id: Uuid,
+    agent_id: String,
+    domain: String,
+    market_slug: String,
+    token_id: String,
+    market_side: String,
+    is_buy: bool,
+    shares: i64,
+    limit_price: Decimal,
+    priority: i16,
+    created_at: DateTime<Utc>,
+    expires_at: Option<DateTime<Utc>>,
+    metadata: Option<sqlx::types::Json<serde_json::Value>>,
+}
+
+impl QueuedIntentRow {
+    fn from_intent(intent: &OrderIntent) -> Self {
+        Self {
+            intent_id: intent.intent_id,
+            agent_id: intent.agent_id.clone(),
+            domain: intent.domain.to_string(),
+            market_slug: intent.market_slug.clone(),
+            token_id: intent.token_id.clone(),
+            market_side: intent.side.as_str().to_string(),
+            is_buy: intent.is_buy,
+            shares: i64::try_from(intent.shares).unwrap_or(i64::MAX),
+            limit_price: intent.limit_price,
+            priority: intent.priority as i16,
+            created_at: intent.created_at,
+            expires_at: intent.expires_at,
+            metadata: Some(sqlx::types::Json(
+                serde_json::to_value(&intent.metadata).unwrap_or_else(|_| serde_json::json!({})),
+            )),
+        }
+    }
+
+    /// `None` when the stored domain or side no longer parses
+    fn into_intent(self) -> Option<OrderIntent> {
+        let (Some(domain), Some(side)) = (
+            parse_persisted_domain(&self.domain),
+            parse_persisted_side(&self.market_side),
+        ) else {
+            warn!(
+                intent_id = %self.intent_id, domain = %self.domain, side = %self.market_side,
+                "skipping unparseable queued intent"
+            );
+            return None;
+        };
+        let mut intent = OrderIntent::new(
+            self.agent_id,
+            domain,
+            self.market_slug,
+            self.token_id,
+            side,
+            self.is_buy,
+            self.shares.max(0) as u64,
+            self.limit_price,
+        )
+        .with_priority(parse_persisted_priority(self.priority));
+        intent.intent_id = self.intent_id;
+        intent.created_at = self.created_at;
+        intent.expires_at = self.expires_at;
+        intent.metadata = string_metadata_from_json(self.metadata);
+        Some(intent)
+    }
+}
+
+/// Intents left in the persisted order queue by a previous run.
+async fn load_queued_intents(
+    pool: &PgPool,
+    account_id: &str,
+    dry_run: bool,
+) -> Result<Vec<OrderIntent>> {
+    let rows = sqlx::query_as::<_, QueuedIntentRow>(
+        r#"
+        SELECT
+            intent_id,
+            agent_id,
+            domain,
+            market_slug,
+            token_id,
+            market_side,
+            is_buy,
+            shares,
+            limit_price,
+            priority,
+            created_at,
+            expires_at,
+            metadata
+        FROM order_queue_intents
+        WHERE account_id = $1
+          AND dry_run = $2
+        ORDER BY priority ASC, created_at ASC
+        "#,
+    )
+    .bind(account_id)
+    .bind(dry_run)
+    .fetch_all(pool)
+    .await
+    .map_err(|e| crate::error::PloyError::Internal(format!("load queued intents: {}", e)))?;
+
+    Ok(rows
+        .into_iter()
+        .filter_map(QueuedIntentRow::into_intent)
+        .collect())
+}
+
+/// Split restored intents into those to re-submit, highest priority and
+/// oldest first, and those that expired while the process was down.
+fn partition_restored_intents(intents: Vec<OrderIntent>) -> (Vec<OrderIntent>, Vec<OrderIntent>) {
+    let (mut live, expired): (Vec<_>, Vec<_>) =
+        intents.into_iter().partition(|intent| !intent.is_expired());
+    live.sort_by_key(|intent| (intent.priority, intent.created_at));
+    (live, expired)
+}
+
 fn normalized_identity_component(raw: &str) -> Option<String> {
     let trimmed = raw.trim();
     (!trimmed.is_empty()).then(|| trimmed.to_ascii_lowercase())
@@ -893,6 +1030,13 @@ pub struct CoordinatorHandle {
     authorized_agents: Arc<std::sync::RwLock<HashSet<String>>>,
     governance_policy: Arc<RwLock<GovernancePolicy>>,
     governance_store_pool: Option<PgPool>,
+    approvals: Arc<ApprovalBook>,
+    quote_throttle: QuoteThrottle,
+    venue_health: VenueHealth,
+    event_calendar: EventCalendar,
+    market_anomalies: MarketAnomalies,
+    loss_limit: Arc<RwLock<LossLimitTracker>>,
+    budgets: Arc<RwLock<BudgetLedger>>,
 }
 
 impl CoordinatorHandle {
@@ -1014,6 +1158,111 @@ impl CoordinatorHandle {
             })
     }
 
+    /// Order-flow toxicity monitor (None when disabled)
+    pub fn toxicity_monitor(&self) -> Option<Arc<ToxicityMonitor>> {
+        self.risk_gate.toxicity_monitor()
+    }
+
+    /// Binary position Greeks book (None when disabled)
+    pub fn greeks_book(&self) -> Option<Arc<GreeksBook>> {
+        self.risk_gate.greeks_book()
+    }
+
+    /// BUY notional throttle usage, platform-wide first then per agent
+    pub async fn notional_usage(&self) -> Vec<NotionalUsage> {
+        self.risk_gate.notional_usage().await
+    }
+
+    /// Shared quote-processing throttle (driven by the resource monitor)
+    pub fn quote_throttle(&self) -> QuoteThrottle {
+        self.quote_throttle.clone()
+    }
+
+    /// Shared exchange / chain health (driven by the venue monitor)
+    pub fn venue_health(&self) -> VenueHealth {
+        self.venue_health.clone()
+    }
+
+    /// Shared high-impact event calendar (driven by the event calendar service)
+    pub fn event_calendar(&self) -> EventCalendar {
+        self.event_calendar.clone()
+    }
+
+    /// Shared untradable-token flags (driven by the market anomaly detector)
+    pub fn market_anomalies(&self) -> MarketAnomalies {
+        self.market_anomalies.clone()
+    }
+
+    /// Pending operator approvals (oldest first)
+    pub fn pending_approvals(&self) -> Vec<ApprovalSnapshot> {
+        self.approvals.list()
+    }
+
+    /// Ask an operator to approve an emergency stop (force-close + halt).
+    pub fn request_emergency_stop(&self, reason: &str, requested_by: &str) -> Uuid {
+        self.approvals.request(ApprovalAction::EmergencyStop {
+            reason: reason.to_string(),
+            requested_by: requested_by.to_string(),
+        })
+    }
+
+    /// Apply an operator decision to a pending approval.
+    ///
+    /// Approved trade intents are re-submitted through the coordinator (and
+    /// still pass every other risk check); an approved emergency stop
+    /// force-closes all positions. Returns the resolved approval's snapshot.
+    pub async fn resolve_approval(
+        &self,
+        approval_id: Uuid,
+        decision: ApprovalDecision,
+        operator: &str,
+    ) -> Result<ApprovalSnapshot> {
+        let pending = self
+            .approvals
+            .resolve(approval_id, decision)
+            .ok_or_else(|| {
+                crate::error::PloyError::Validation(format!(
+                    "approval {} is not pending (unknown, resolved or expired)",
+                    approval_id
+                ))
+            })?;
+        let snapshot = ApprovalSnapshot::from(&pending);
+        info!(
+            %approval_id,
+            kind = pending.action.kind(),
+            decision = decision.outcome(),
+            operator,
+            "operator resolved approval"
+        );
+        if let ApprovalAction::BudgetTopUp { top_up_id, .. } = &pending.action {
+            // Denials are recorded too, so the CLI shows the request as decided
+            self.control_tx
+                .send(CoordinatorControlCommand::DecideBudgetTopUp {
+                    top_up_id: *top_up_id,
+                    approve: decision == ApprovalDecision::Approve,
+                    operator: operator.to_string(),
+                })
+                .await
+                .map_err(|_| {
+                    crate::error::PloyError::Internal("coordinator control channel closed".into())
+                })?;
+            return Ok(snapshot);
+        }
+        if decision == ApprovalDecision::Deny {
+            return Ok(snapshot);
+        }
+        match pending.action {
+            ApprovalAction::TradeIntent(intent) => {
+                self.order_tx.send(intent).await.map_err(|_| {
+                    crate::error::PloyError::Internal("coordinator order channel closed".into())
+                })?;
+            }
+            ApprovalAction::EmergencyStop { .. } => self.force_close_all().await?,
+            ApprovalAction::BudgetTopUp { .. } => {}
+        }
+        Ok(snapshot)
+    }
+
     /// Shutdown all agents gracefully
     pub async fn shutdown_all(&self) -> Result<()> {
         {
@@ -1095,6 +1344,18 @@ impl CoordinatorHandle {
             })
     }
 
+    /// Put a single agent in warm standby; `resume_agent` flips it live
+    pub async fn standby_agent(&self, agent_id: &str) -> Result<()> {
+        self.control_tx
+            .send(CoordinatorControlCommand::StandbyAgent(
+                agent_id.to_string(),
+            ))
+            .await
+            .map_err(|_| {
+                crate::error::PloyError::Internal("coordinator control channel closed".into())
+            })
+    }
+
     /// Resume a single agent by ID (used by OpenClaw meta-agent)
     pub async fn resume_agent(&self, agent_id: &str) -> Result<()> {
         self.control_tx
@@ -1105,6 +1366,39 @@ impl CoordinatorHandle {
             })
     }
 
+    /// Scopes currently halted by the intraday loss limit
+    pub async fn loss_limit_halts(&self) -> Vec<LossLimitBreach> {
+        self.loss_limit.read().await.halts()
+    }
+
+    /// Operator re-enable of a scope halted by the intraday loss limit
+    /// (`global` or an agent id)
+    pub async fn reenable_loss_limit(&self, scope: &str) -> Result<()> {
+        self.control_tx
+            .send(CoordinatorControlCommand::ReenableLossLimit(
+                scope.to_string(),
+            ))
+            .await
+            .map_err(|_| {
+                crate::error::PloyError::Internal("coordinator control channel closed".into())
+            })
+    }
+
+    /// Per-agent sandbox budgets, by agent id
+    pub async fn agent_budgets(&self) -> Vec<AgentBudget> {
+        self.budgets.read().await.snapshot()
+    }
+
+    /// Sell `fraction` of every open position (venue monitor de-risking)
+    pub async fn derisk_positions(&self, fraction: Decimal, reason: String) -> Result<()> {
+        self.control_tx
+            .send(CoordinatorControlCommand::Derisk { fraction, reason })
+            .await
+            .map_err(|_| {
+                crate::error::PloyError::Internal("coordinator control channel closed".into())
+            })
+    }
+
     /// Read the current global state (non-blocking snapshot)
     pub async fn read_state(&self) -> GlobalState {
         self.global_state.read().await.clone()
@@ -1310,6 +1604,20 @@ pub struct Coordinator {
     governance_policy: Arc<RwLock<GovernancePolicy>>,
     stale_heartbeat_warn_at: Arc<RwLock<HashMap<String, chrono::DateTime<Utc>>>>,
     paused_agent_ids: Arc<RwLock<HashSet<String>>>,
+    /// Agents in warm standby → would-trade intents logged so far
+    standby_agents: Arc<RwLock<HashMap<String, u64>>>,
+    approvals: Arc<ApprovalBook>,
+    quote_throttle: QuoteThrottle,
+    venue_health: VenueHealth,
+    market_anomalies: MarketAnomalies,
+    loss_limit: Arc<RwLock<LossLimitTracker>>,
+    /// Per-agent sandbox budgets
+    budgets: Arc<RwLock<BudgetLedger>>,
+    leadership: Leadership,
+    /// Arms take-profit / stop-loss exits for bracket entries
+    brackets: Option<Arc<BracketManager>>,
+    /// Fired conditional orders, booked like queued executions
+    conditional_rx: Option<mpsc::Receiver<ConditionalExecution>>,
 
     // Channels
     order_tx: mpsc::Sender<OrderIntent>,
@@ -1323,6 +1631,16 @@ pub struct Coordinator {
     agent_commands: HashMap<String, AgentCommandChannel>,
 }
 
+/// Next fired conditional order, or pending forever when none are configured
+async fn recv_conditional(
+    rx: &mut Option<mpsc::Receiver<ConditionalExecution>>,
+) -> Option<ConditionalExecution> {
+    match rx {
+        Some(rx) => rx.recv().await,
+        None => std::future::pending().await,
+    }
+}
+
 #[derive(Debug)]
 struct IntentDuplicateGuard {
     enabled: bool,
@@ -1412,13 +1730,14 @@ impl IntentDuplicateGuard {
     }
 }
 
-const KNOWN_5M_SERIES_IDS: &[&str] = &["10684", "10683", "10686", "10685"];
-const KNOWN_15M_SERIES_IDS: &[&str] = &["10192", "10191", "10423", "10422"];
-
+/// Crypto round horizon used for allocator buckets. 1h / 1d rounds get their
+/// own buckets but share the `other` cap.
 #[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
 enum CryptoHorizon {
     M5,
     M15,
+    H1,
+    D1,
     Other,
 }
 
@@ -1427,28 +1746,29 @@ impl CryptoHorizon {
         match self {
             Self::M5 => "5m",
             Self::M15 => "15m",
+            Self::H1 => "1h",
+            Self::D1 => "1d",
             Self::Other => "other",
         }
     }
 
     fn from_hint(raw: &str) -> Option<Self> {
-        let normalized = raw.trim().to_ascii_lowercase();
-        if normalized.is_empty() {
-            return None;
+        match Timeframe::from_hint(raw)? {
+            Timeframe::M5 => Some(Self::M5),
+            Timeframe::M15 => Some(Self::M15),
+            Timeframe::H1 => Some(Self::H1),
+            Timeframe::D1 => Some(Self::D1),
+            Timeframe::Other(_) => None,
         }
-        if normalized.contains("15m") || normalized == "15" {
-            return Some(Self::M15);
-        }
-        if normalized.contains("5m") || normalized == "5" {
-            return Some(Self::M5);
-        }
-        if KNOWN_15M_SERIES_IDS.iter().any(|id| *id == normalized) {
-            return Some(Self::M15);
-        }
-        if KNOWN_5M_SERIES_IDS.iter().any(|id| *id == normalized) {
-            return Some(Self::M5);
+    }
+
+    fn window_secs(&self) -> i64 {
+        match self {
+            Self::M5 | Self::Other => 5 * 60,
+            Self::M15 => 15 * 60,
+            Self::H1 => 60 * 60,
+            Self::D1 => 24 * 60 * 60,
         }
-        None
     }
 }
 
@@ -2503,6 +2823,7 @@ impl Coordinator {
         executor: Arc<OrderExecutor>,
         account_id: String,
         allowed_domains: HashSet<Domain>,
+        metrics: Option<Arc<Metrics>>,
     ) -> Self {
         let (order_tx, order_rx) = mpsc::channel(256);
         let (state_tx, state_rx) = mpsc::channel(128);
@@ -2510,7 +2831,44 @@ impl Coordinator {
 
         let allowed_domains = Arc::new(allowed_domains);
         let authorized_agents = Arc::new(std::sync::RwLock::new(HashSet::new()));
-        let risk_gate = Arc::new(RiskGate::new(config.risk.clone()));
+        let mut risk_gate = RiskGate::new(config.risk.clone());
+        if let Some(metrics) = metrics {
+            risk_gate = risk_gate.with_metrics(metrics);
+        }
+        if config.toxicity.enabled {
+            risk_gate = risk_gate
+                .with_toxicity_monitor(Arc::new(ToxicityMonitor::new(config.toxicity.clone())));
+        }
+        if config.greeks.enabled {
+            risk_gate =
+                risk_gate.with_greeks_book(Arc::new(GreeksBook::new(config.greeks.clone())));
+        }
+        if config.fillability.enabled {
+            match executor.fillability() {
+                Some(tracker) => {
+                    risk_gate = risk_gate.with_fillability(tracker, config.fillability.clone());
+                }
+                None => warn!("fillability gate enabled but the executor tracks no passive fills"),
+            }
+        }
+        match RiskPolicy::load_default() {
+            Ok(Some(policy)) => {
+                info!(
+                    path = %RiskPolicy::default_path().display(),
+                    banned_markets = policy.markets.banned.len(),
+                    trading_windows = policy.trading_hours.len(),
+                    "loaded risk policy"
+                );
+                risk_gate = risk_gate.with_policy(policy);
+            }
+            Ok(None) => {}
+            Err(e) => error!(
+                "failed to load risk policy {}, using env risk limits only: {}",
+                RiskPolicy::default_path().display(),
+                e
+            ),
+        }
+        let risk_gate = Arc::new(risk_gate);
         let order_queue = Arc::new(RwLock::new(OrderQueue::new(1024)));
         let duplicate_guard = Arc::new(RwLock::new(IntentDuplicateGuard::new(
             config.duplicate_guard_window_ms,
@@ -2530,6 +2888,10 @@ impl Coordinator {
         let governance_policy = Arc::new(RwLock::new(GovernancePolicy::from_config(&config)));
         let domain_ingress_mode = Arc::new(RwLock::new(HashMap::new()));
         let stale_heartbeat_warn_at = Arc::new(RwLock::new(HashMap::new()));
+        let approvals = Arc::new(ApprovalBook::new(
+            config.approval_timeout_secs,
+            crate::adapters::FeishuNotifier::from_env(),
+        ));
         let account_id = if account_id.trim().is_empty() {
             "default".to_string()
         } else {
@@ -2559,6 +2921,17 @@ impl Coordinator {
             governance_policy,
             stale_heartbeat_warn_at,
             paused_agent_ids: Arc::new(RwLock::new(HashSet::new())),
+            standby_agents: Arc::new(RwLock::new(HashMap::new())),
+            approvals,
+            quote_throttle: QuoteThrottle::new(),
+            venue_health: VenueHealth::new(),
+            event_calendar: EventCalendar::new(config.event_calendar.clone()),
+            market_anomalies: MarketAnomalies::new(),
+            loss_limit: Arc::new(RwLock::new(LossLimitTracker::default())),
+            budgets: Arc::new(RwLock::new(BudgetLedger::default())),
+            leadership: Leadership::default(),
+            brackets: None,
+            conditional_rx: None,
             order_tx,
             order_rx,
             state_tx,
@@ -2574,6 +2947,22 @@ impl Coordinator {
         self.execution_log_pool = Some(pool);
     }
 
+    /// Gate order execution on hot-standby leadership.
+    pub fn set_leadership(&mut self, leadership: Leadership) {
+        self.leadership = leadership;
+    }
+
+    /// Route bracket entries through `brackets` and book the exits its
+    /// conditional order manager reports on `executions`.
+    pub fn set_conditional_orders(
+        &mut self,
+        brackets: Arc<BracketManager>,
+        executions: mpsc::Receiver<ConditionalExecution>,
+    ) {
+        self.brackets = Some(brackets);
+        self.conditional_rx = Some(executions);
+    }
+
     /// Restore persisted risk runtime state (drawdown + daily pnl continuity).
     pub async fn restore_risk_runtime_state(&self) -> Result<()> {
         let Some(pool) = self.execution_log_pool.as_ref() else {
@@ -2729,6 +3118,9 @@ impl Coordinator {
 
             self.settle_domain_success(&intent, fill.filled_shares, fill.fill_price)
                 .await;
+            if let Some(book) = self.risk_gate.greeks_book() {
+                book.record_fill(&intent, fill.filled_shares);
+            }
 
             if fill.is_buy {
                 let _ = self
@@ -2837,6 +3229,13 @@ impl Coordinator {
             authorized_agents: self.authorized_agents.clone(),
             governance_policy: self.governance_policy.clone(),
             governance_store_pool: self.governance_store_pool.clone(),
+            approvals: self.approvals.clone(),
+            quote_throttle: self.quote_throttle.clone(),
+            venue_health: self.venue_health.clone(),
+            event_calendar: self.event_calendar.clone(),
+            market_anomalies: self.market_anomalies.clone(),
+            loss_limit: self.loss_limit.clone(),
+            budgets: self.budgets.clone(),
         }
     }
 
@@ -2940,6 +3339,8 @@ impl Coordinator {
             return;
         }
 
+        let dropped_ids: Vec<Uuid> = dropped.iter().map(|i| i.intent_id).collect();
+        self.remove_queued_intents(&dropped_ids).await;
         for intent in dropped {
             self.persist_risk_decision(&intent, "BLOCKED", Some(reason.to_string()), None)
                 .await;
@@ -3099,12 +3500,39 @@ impl Coordinator {
                         }
                         CoordinatorControlCommand::PauseAgent(id) => {
                             // Track per-agent pause so handle_order_intent blocks BUY intents
+                            self.standby_agents.write().await.remove(&id);
                             self.paused_agent_ids.write().await.insert(id.clone());
                             self.send_command(&id, CoordinatorCommand::Pause).await.ok();
                         }
                         CoordinatorControlCommand::ResumeAgent(id) => {
+                            let standby = self.standby_agents.write().await.remove(&id);
+                            if let Some(would_trade) = standby {
+                                info!(agent_id = %id, would_trade, "agent leaving standby; live");
+                            }
+                            self.paused_agent_ids.write().await.remove(&id);
+                            self.send_command(&id, CoordinatorCommand::Resume).await.ok();
+                        }
+                        CoordinatorControlCommand::StandbyAgent(id) => {
+                            // The agent runs normally so feeds and models stay warm;
+                            // handle_order_intent diverts its entries to the would-trade log
+                            self.standby_agents.write().await.entry(id.clone()).or_insert(0);
                             self.paused_agent_ids.write().await.remove(&id);
                             self.send_command(&id, CoordinatorCommand::Resume).await.ok();
+                            info!(agent_id = %id, "agent in standby");
+                        }
+                        CoordinatorControlCommand::ReenableLossLimit(scope) => {
+                            self.reenable_loss_limit(&scope).await
+                        }
+                        CoordinatorControlCommand::Derisk { fraction, reason } => {
+                            self.derisk_positions(fraction, &reason).await
+                        }
+                        CoordinatorControlCommand::DecideBudgetTopUp {
+                            top_up_id,
+                            approve,
+                            operator,
+                        } => {
+                            self.decide_budget_top_up(top_up_id, approve, &operator)
+                                .await
                         }
                     }
                 }
@@ -3119,6 +3547,11 @@ impl Coordinator {
                     self.handle_state_update(snapshot).await;
                 }
 
+                // --- Fired conditional orders (bracket exits) ---
+                Some(execution) = recv_conditional(&mut self.conditional_rx) => {
+                    self.handle_conditional_execution(execution).await;
+                }
+
                 // --- Periodic: drain queue and execute ---
                 _ = drain_tick.tick() => {
                     self.drain_and_execute().await;
@@ -3127,6 +3560,7 @@ impl Coordinator {
                 // --- Periodic: refresh global state ---
                 _ = refresh_tick.tick() => {
                     self.refresh_global_state().await;
+                    self.expire_approvals();
                 }
 
                 // --- Shutdown signal ---
@@ -3169,6 +3603,16 @@ impl Coordinator {
             return;
         }
 
+        if let Some(reason) = self.intent_order_flags_reason(&intent) {
+            self.persist_risk_decision(&intent, "BLOCKED", Some(reason.clone()), None)
+                .await;
+            warn!(
+                %agent_id, %intent_id, reason = %reason,
+                "order blocked due to invalid order flags"
+            );
+            return;
+        }
+
         if !intent.is_buy {
             let tracked_open_shares = self
                 .positions
@@ -3251,6 +3695,87 @@ impl Coordinator {
             );
             return;
         }
+        // Warm standby: log what the agent would have entered, execute nothing
+        if intent.is_buy {
+            if let Some(would_trade) = self.standby_agents.write().await.get_mut(&intent.agent_id) {
+                *would_trade += 1;
+                let reason = format!(
+                    "Agent {} in standby; would-trade {} {} @ {}",
+                    intent.agent_id, intent.shares, intent.token_id, intent.limit_price
+                );
+                self.persist_risk_decision(&intent, "BLOCKED", Some(reason.clone()), None)
+                    .await;
+                info!(
+                    %agent_id, %intent_id, market = %intent.market_slug,
+                    shares = intent.shares, price = %intent.limit_price,
+                    "standby would-trade"
+                );
+                return;
+            }
+        }
+        if intent.is_buy {
+            let halt = self
+                .loss_limit
+                .read()
+                .await
+                .blocks(&intent.agent_id)
+                .map(LossLimitBreach::reason);
+            if let Some(halt) = halt {
+                let reason = format!("{halt}; blocking BUY intent until operator re-enable");
+                self.persist_risk_decision(&intent, "BLOCKED", Some(reason.clone()), None)
+                    .await;
+                warn!(
+                    %agent_id, %intent_id, reason = %reason,
+                    "order blocked by intraday loss limit"
+                );
+                return;
+            }
+            if let Some(reason) = self.check_agent_budget(&intent).await {
+                self.persist_risk_decision(&intent, "BLOCKED", Some(reason.clone()), None)
+                    .await;
+                warn!(
+                    %agent_id, %intent_id, reason = %reason,
+                    "order blocked by sandbox budget"
+                );
+                return;
+            }
+        }
+        if intent.is_buy && self.venue_health.entries_paused() {
+            let reason = format!(
+                "Venue status {}; new entries paused by venue monitor",
+                self.venue_health.status()
+            );
+            self.persist_risk_decision(&intent, "BLOCKED", Some(reason.clone()), None)
+                .await;
+            warn!(
+                %agent_id, %intent_id, reason = %reason,
+                "order blocked by venue health"
+            );
+            return;
+        }
+        if intent.is_buy {
+            if let Some(anomaly) = self.market_anomalies.get(&intent.token_id) {
+                let reason = format!(
+                    "Market untradable since {}: {} ({})",
+                    anomaly.since.format("%H:%M:%S"),
+                    anomaly.kind,
+                    anomaly.detail
+                );
+                self.persist_risk_decision(&intent, "BLOCKED", Some(reason.clone()), None)
+                    .await;
+                warn!(
+                    %agent_id, %intent_id, reason = %reason,
+                    "order blocked by market anomaly detector"
+                );
+                return;
+            }
+        }
+        if intent.is_buy {
+            match self.resolve_intent_conflict(&intent).await {
+                Some(shares) => intent.shares = shares,
+                None => return,
+            }
+        }
 
         if let Some(reason) = self.check_governance_policy(&intent).await {
             self.persist_risk_decision(&intent, "BLOCKED", Some(reason.clone()), None)
@@ -3262,6 +3787,22 @@ impl Coordinator {
             return;
         }
 
+        if self.requires_operator_approval(&intent) {
+            if !self.approvals.is_pending_intent(intent_id) {
+                let approval_id = self
+                    .approvals
+                    .request(ApprovalAction::TradeIntent(intent.clone()));
+                let reason = format!("awaiting operator approval ({})", approval_id);
+                self.persist_risk_decision(&intent, "BLOCKED", Some(reason.clone()), None)
+                    .await;
+                warn!(
+                    %agent_id, %intent_id, %approval_id, reason = %reason,
+                    "order parked for operator approval"
+                );
+            }
+            return;
+        }
+
         if let Err(reason) = self.enforce_live_buy_deployment_gate(&mut intent).await {
             self.persist_risk_decision(&intent, "BLOCKED", Some(reason.clone()), None)
                 .await;
@@ -3313,6 +3854,9 @@ impl Coordinator {
             match self.risk_gate.check_order(&evaluated).await {
                 RiskCheckResult::Passed => {
                     if let Some(reason) = self.reserve_domain_capital(&evaluated).await {
+                        self.risk_gate
+                            .release_notional(intent_id, Decimal::ZERO)
+                            .await;
                         self.persist_risk_decision(
                             &evaluated,
                             "BLOCKED",
@@ -3329,13 +3873,23 @@ impl Coordinator {
 
                     self.persist_risk_decision(&evaluated, "PASSED", None, adjusted.clone())
                         .await;
-                    let mut queue = self.order_queue.write().await;
-                    match queue.enqueue(evaluated) {
-                        Ok(()) => {
+                    let queued = evaluated.clone();
+                    let enqueued = self
+                        .order_queue
+                        .write()
+                        .await
+                        .enqueue_with_eviction(evaluated);
+                    match enqueued {
+                        Ok(evicted) => {
                             debug!(
                                 %agent_id, %intent_id,
                                 "order enqueued"
                             );
+                            self.persist_queued_intent(&queued).await;
+                            if let Some(evicted) = evicted {
+                                self.remove_queued_intents(&[evicted.intent_id]).await;
+                                self.settle_domain_failure(&evicted).await;
+                            }
                         }
                         Err(e) => {
                             self.release_domain_reservation(intent_id).await;
@@ -3998,6 +4552,39 @@ impl Coordinator {
         guard.register_or_block(intent, Utc::now())
     }
 
+    /// Whether a BUY intent must be approved by an operator before it may proceed.
+    ///
+    /// Consumes a previously granted approval, so re-submitted approved intents
+    /// pass exactly once.
+    fn requires_operator_approval(&self, intent: &OrderIntent) -> bool {
+        let Some(threshold) = self.config.approval_min_notional_usd else {
+            return false;
+        };
+        if !intent.is_buy || intent.notional_value() < threshold {
+            return false;
+        }
+        if !self.config.approval_agent_ids.is_empty()
+            && !self
+                .config
+                .approval_agent_ids
+                .iter()
+                .any(|id| id == &intent.agent_id)
+        {
+            return false;
+        }
+        !self.approvals.consume_approved(intent.intent_id)
+    }
+
+    fn expire_approvals(&self) {
+        for expired in self.approvals.expire(Utc::now()) {
+            warn!(
+                approval_id = %expired.id,
+                kind = expired.action.kind(),
+                "pending approval expired without operator decision"
+            );
+        }
+    }
+
     async fn check_governance_policy(&self, intent: &OrderIntent) -> Option<String> {
         let policy = self.governance_policy.read().await.clone();
         let current_notional = self.current_account_notional().await;
@@ -4067,6 +4654,9 @@ impl Coordinator {
     }
 
     async fn release_domain_reservation(&self, intent_id: Uuid) {
+        self.risk_gate
+            .release_notional(intent_id, Decimal::ZERO)
+            .await;
         {
             let mut allocator = self.crypto_allocator.write().await;
             allocator.release_buy_reservation(intent_id);
@@ -4089,6 +4679,12 @@ impl Coordinator {
         filled_shares: u64,
         fill_price: Decimal,
     ) {
+        self.record_budget_fill(intent, filled_shares, fill_price)
+            .await;
+        // Keep only the filled notional counted against the throttle
+        self.risk_gate
+            .release_notional(intent.intent_id, Decimal::from(filled_shares) * fill_price)
+            .await;
         match intent.domain {
             Domain::Crypto => {
                 let mut allocator = self.crypto_allocator.write().await;
@@ -4127,6 +4723,9 @@ impl Coordinator {
     }
 
     async fn settle_domain_failure(&self, intent: &OrderIntent) {
+        self.risk_gate
+            .release_notional(intent.intent_id, Decimal::ZERO)
+            .await;
         if !intent.is_buy {
             return;
         }
@@ -4152,9 +4751,19 @@ impl Coordinator {
     }
 
     /// Update agent snapshot in global state
-    async fn handle_state_update(&self, snapshot: AgentSnapshot) {
+    async fn handle_state_update(&self, mut snapshot: AgentSnapshot) {
         let agent_id = snapshot.agent_id.clone();
 
+        // Agents run normally in standby; report the coordinator-side state
+        if let Some(would_trade) = self.standby_agents.read().await.get(&agent_id) {
+            if snapshot.status == crate::platform::AgentStatus::Running {
+                snapshot.status = crate::platform::AgentStatus::Standby;
+            }
+            snapshot
+                .metrics
+                .insert("standby_would_trade".to_string(), would_trade.to_string());
+        }
+
         // Store snapshot
         let mut state = self.global_state.write().await;
         state.agents.insert(agent_id, snapshot);
@@ -4176,6 +4785,15 @@ impl Coordinator {
 
     /// Drain the order queue and execute via OrderExecutor
     async fn drain_and_execute(&self) {
+        // A fenced former leader must not reach the exchange, even with intents
+        // already queued; the runtime is shutting down.
+        if !self.leadership.is_leader() {
+            debug!(
+                epoch = self.leadership.epoch(),
+                "not the runtime leader; order execution fenced"
+            );
+            return;
+        }
         let (expired, batch) = {
             let mut queue = self.order_queue.write().await;
             let expired = queue.cleanup_expired_intents();
@@ -4183,6 +4801,15 @@ impl Coordinator {
             (expired, batch)
         };
 
+        // Forget dequeued intents before executing them: a crash mid-batch
+        // must not replay an order on restart.
+        let finished: Vec<Uuid> = expired
+            .iter()
+            .chain(batch.iter())
+            .map(|i| i.intent_id)
+            .collect();
+        self.remove_queued_intents(&finished).await;
+
         for intent in expired {
             self.settle_domain_failure(&intent).await;
         }
@@ -4205,7 +4832,7 @@ impl Coordinator {
             // Convert OrderIntent → OrderRequest for the executor
             let request = self.intent_to_request(&intent);
 
-            match self.executor.execute(&request).await {
+            match self.execute_intent(&intent, &request).await {
                 Ok(result) => {
                     info!(
                         %agent_id, %intent_id,
@@ -4213,63 +4840,8 @@ impl Coordinator {
                         filled = result.filled_shares,
                         "order executed successfully"
                     );
-
-                    self.persist_execution(
-                        &intent,
-                        &request,
-                        Some(&result),
-                        None,
-                        Some(queue_delay_ms),
-                    )
-                    .await;
-
-                    let fill_price = result.avg_fill_price.unwrap_or(intent.limit_price);
-                    self.settle_domain_success(&intent, result.filled_shares, fill_price)
+                    self.book_execution(&intent, &request, &result, Some(queue_delay_ms))
                         .await;
-
-                    let mut realized_pnl = Decimal::ZERO;
-                    if result.filled_shares > 0 {
-                        if intent.is_buy {
-                            let _ = self
-                                .positions
-                                .open_position(
-                                    &agent_id,
-                                    intent.domain.clone(),
-                                    &intent.market_slug,
-                                    &intent.token_id,
-                                    intent.side.clone(),
-                                    result.filled_shares,
-                                    fill_price,
-                                )
-                                .await;
-                        } else {
-                            realized_pnl = self
-                                .apply_sell_fill_to_positions(
-                                    &intent,
-                                    result.filled_shares,
-                                    fill_price,
-                                )
-                                .await;
-                        }
-
-                        self.refresh_risk_exposure_for_agent(&agent_id).await;
-                    }
-
-                    // Record execution outcome with RiskGate (including realized PnL on exits).
-                    // For binary options, PnL is realized on SELL fills (reduce/close).
-                    if realized_pnl < Decimal::ZERO {
-                        self.risk_gate
-                            .record_success(&agent_id, Decimal::ZERO)
-                            .await;
-                        self.risk_gate
-                            .record_loss(&agent_id, realized_pnl.abs())
-                            .await;
-                    } else {
-                        self.risk_gate.record_success(&agent_id, realized_pnl).await;
-                    }
-
-                    // Record execution outcome with realized PnL attribution.
-                    self.risk_gate.record_success(&agent_id, realized_pnl).await;
                 }
                 Err(e) => {
                     error!(
@@ -4297,6 +4869,153 @@ impl Coordinator {
         }
     }
 
+    /// Execute a queued intent; bracket entries also arm their exits.
+    async fn execute_intent(
+        &self,
+        intent: &OrderIntent,
+        request: &OrderRequest,
+    ) -> Result<ExecutionResult> {
+        let Some((take_profit, stop_loss, stop_limit)) =
+            intent.bracket_exits().filter(|_| intent.is_buy)
+        else {
+            return self.executor.execute(request).await;
+        };
+        let Some(brackets) = self.brackets.as_ref() else {
+            warn!(
+                agent_id = %intent.agent_id,
+                intent_id = %intent.intent_id,
+                "bracket requested but conditional orders are not enabled; exits not armed"
+            );
+            return self.executor.execute(request).await;
+        };
+        let bracket = Bracket::new(
+            request.clone(),
+            take_profit,
+            stop_loss,
+            stop_limit,
+            Some(intent.agent_id.clone()),
+        )?;
+        let (bracket, result) = brackets.execute(bracket).await?;
+        info!(
+            agent_id = %intent.agent_id,
+            intent_id = %intent.intent_id,
+            bracket_id = %bracket.id,
+            state = bracket.state.as_str(),
+            "bracket entry executed"
+        );
+        Ok(result)
+    }
+
+    /// Book a successful execution: allocators, positions, exposure and risk PnL.
+    async fn book_execution(
+        &self,
+        intent: &OrderIntent,
+        request: &OrderRequest,
+        result: &ExecutionResult,
+        queue_delay_ms: Option<i64>,
+    ) {
+        let agent_id = &intent.agent_id;
+        self.persist_execution(intent, request, Some(result), None, queue_delay_ms)
+            .await;
+
+        let fill_price = result.avg_fill_price.unwrap_or(intent.limit_price);
+        self.settle_domain_success(intent, result.filled_shares, fill_price)
+            .await;
+
+        let mut realized_pnl = Decimal::ZERO;
+        if result.filled_shares > 0 {
+            if intent.is_buy {
+                let _ = self
+                    .positions
+                    .open_position(
+                        agent_id,
+                        intent.domain.clone(),
+                        &intent.market_slug,
+                        &intent.token_id,
+                        intent.side.clone(),
+                        result.filled_shares,
+                        fill_price,
+                    )
+                    .await;
+            } else {
+                realized_pnl = self
+                    .apply_sell_fill_to_positions(intent, result.filled_shares, fill_price)
+                    .await;
+            }
+
+            if let Some(book) = self.risk_gate.greeks_book() {
+                book.record_fill(intent, result.filled_shares);
+            }
+            self.refresh_risk_exposure_for_agent(agent_id).await;
+        }
+
+        // Record execution outcome with RiskGate (including realized PnL on exits).
+        // For binary options, PnL is realized on SELL fills (reduce/close).
+        if realized_pnl < Decimal::ZERO {
+            self.risk_gate.record_success(agent_id, Decimal::ZERO).await;
+            self.risk_gate
+                .record_loss(agent_id, realized_pnl.abs())
+                .await;
+        } else {
+            self.risk_gate.record_success(agent_id, realized_pnl).await;
+        }
+
+        // Record execution outcome with realized PnL attribution.
+        self.risk_gate.record_success(agent_id, realized_pnl).await;
+    }
+
+    /// Book a fired conditional order (bracket exit) against the position it
+    /// protects. The trigger's strategy ID is the agent that opened it.
+    async fn handle_conditional_execution(&self, execution: ConditionalExecution) {
+        let ConditionalExecution { order, result } = execution;
+        let Some(agent_id) = order.strategy_id.as_deref() else {
+            warn!(
+                conditional_id = %order.id,
+                "conditional order executed without an owning agent; not booked"
+            );
+            return;
+        };
+        let request = &order.order;
+        let Some(position) = self
+            .positions
+            .get_agent_positions(agent_id)
+            .await
+            .into_iter()
+            .find(|p| p.token_id == request.token_id && p.side == request.market_side)
+        else {
+            warn!(
+                %agent_id,
+                conditional_id = %order.id,
+                token_id = %request.token_id,
+                "conditional order executed with no tracked position; not booked"
+            );
+            return;
+        };
+
+        let mut intent = OrderIntent::new(
+            agent_id,
+            position.domain,
+            position.market_slug,
+            request.token_id.clone(),
+            request.market_side,
+            request.order_side == crate::domain::OrderSide::Buy,
+            request.shares,
+            request.limit_price,
+        )
+        .with_metadata("conditional_order_id", order.id.to_string());
+        intent.intent_id = order.id;
+        if let Some(bracket_id) = order.bracket_id {
+            intent = intent.with_metadata("bracket_id", bracket_id.to_string());
+        }
+        info!(
+            %agent_id,
+            conditional_id = %order.id,
+            filled = result.filled_shares,
+            "booking conditional order execution"
+        );
+        self.book_execution(&intent, request, &result, None).await;
+    }
+
     async fn apply_sell_fill_to_positions(
         &self,
         intent: &OrderIntent,
@@ -4453,6 +5172,13 @@ impl Coordinator {
             );
         }
 
+        if let Some(r) = result.filter(|r| !r.chase_steps.is_empty()) {
+            self.persist_chase_steps(pool, intent, &r.chase_steps).await;
+        }
+
+        #[cfg(feature = "api")]
+        self.emit_execution_webhooks(intent, result, dry_run);
+
         self.persist_execution_analysis(intent, request, result, queue_delay_ms, config_hash)
             .await;
 
@@ -4462,6 +5188,97 @@ impl Coordinator {
         }
     }
 
+    /// Record each re-price the executor's chase policy made for this intent
+    async fn persist_chase_steps(
+        &self,
+        pool: &PgPool,
+        intent: &OrderIntent,
+        steps: &[crate::strategy::executor::ChaseStep],
+    ) {
+        for step in steps {
+            let result = sqlx::query(
+                r#"
+                INSERT INTO order_reprice_steps (
+                    account_id, agent_id, intent_id, step, replaced_order_id, order_id,
+                    from_price, to_price, best_bid, best_ask, filled_shares, remaining_shares,
+                    repriced_at
+                )
+                VALUES ($1,$2,$3,$4,$5,$6,$7,$8,$9,$10,$11,$12,$13)
+                ON CONFLICT (intent_id, step) DO NOTHING
+                "#,
+            )
+            .bind(&self.account_id)
+            .bind(&intent.agent_id)
+            .bind(intent.intent_id)
+            .bind(step.step as i32)
+            .bind(&step.replaced_order_id)
+            .bind(&step.order_id)
+            .bind(step.from_price)
+            .bind(step.to_price)
+            .bind(step.best_bid)
+            .bind(step.best_ask)
+            .bind(step.filled_shares as i64)
+            .bind(step.remaining_shares as i64)
+            .bind(step.at)
+            .execute(pool)
+            .await;
+
+            if let Err(e) = result {
+                warn!(
+                    agent_id = %intent.agent_id,
+                    intent_id = %intent.intent_id,
+                    step = step.step,
+                    error = %e,
+                    "failed to persist order re-price step"
+                );
+            }
+        }
+    }
+
+    /// Notify webhook subscribers of a (partial) fill and, for exits, a stop-loss
+    #[cfg(feature = "api")]
+    fn emit_execution_webhooks(
+        &self,
+        intent: &OrderIntent,
+        result: Option<&crate::strategy::executor::ExecutionResult>,
+        dry_run: bool,
+    ) {
+        use crate::api::webhooks::{emit, WebhookEventType};
+
+        let Some(r) = result.filter(|r| r.filled_shares > 0) else {
+            return;
+        };
+        let exit_reason = intent
+            .metadata
+            .get("exit_reason")
+            .or_else(|| intent.metadata.get("reason_code"))
+            .cloned();
+        let payload = serde_json::json!({
+            "account_id": &self.account_id,
+            "strategy": &intent.agent_id,
+            "intent_id": intent.intent_id,
+            "order_id": &r.order_id,
+            "domain": intent.domain.to_string(),
+            "market_slug": &intent.market_slug,
+            "token_id": &intent.token_id,
+            "side": intent.side.as_str(),
+            "is_buy": intent.is_buy,
+            "filled_shares": r.filled_shares,
+            "avg_fill_price": r.avg_fill_price,
+            "exit_reason": &exit_reason,
+            "dry_run": dry_run,
+        });
+
+        let is_stop_loss = !intent.is_buy
+            && exit_reason
+                .as_deref()
+                .is_some_and(|reason| reason.to_ascii_lowercase().contains("stop"));
+        if is_stop_loss {
+            emit(WebhookEventType::StopLoss, payload.clone());
+        }
+        emit(WebhookEventType::Fill, payload);
+    }
+
     fn metadata_decimal(intent: &OrderIntent, key: &str) -> Option<Decimal> {
         intent
             .metadata
@@ -4550,6 +5367,124 @@ impl Coordinator {
         }
     }
 
+    /// Write-through copy of an enqueued intent so it survives a restart.
+    async fn persist_queued_intent(&self, intent: &OrderIntent) {
+        let Some(pool) = self.execution_log_pool.as_ref() else {
+            return;
+        };
+        let row = QueuedIntentRow::from_intent(intent);
+
+        let result = sqlx::query(
+            r#"
+            INSERT INTO order_queue_intents (
+                intent_id, account_id, dry_run, agent_id, domain, market_slug, token_id,
+                market_side, is_buy, shares, limit_price, priority, created_at, expires_at,
+                metadata
+            )
+            VALUES ($1,$2,$3,$4,$5,$6,$7,$8,$9,$10,$11,$12,$13,$14,$15)
+            ON CONFLICT (intent_id) DO UPDATE SET
+                shares = EXCLUDED.shares,
+                limit_price = EXCLUDED.limit_price,
+                priority = EXCLUDED.priority,
+                expires_at = EXCLUDED.expires_at,
+                metadata = EXCLUDED.metadata,
+                enqueued_at = NOW()
+            "#,
+        )
+        .bind(row.intent_id)
+        .bind(&self.account_id)
+        .bind(self.executor.is_dry_run())
+        .bind(&row.agent_id)
+        .bind(&row.domain)
+        .bind(&row.market_slug)
+        .bind(&row.token_id)
+        .bind(&row.market_side)
+        .bind(row.is_buy)
+        .bind(row.shares)
+        .bind(row.limit_price)
+        .bind(row.priority)
+        .bind(row.created_at)
+        .bind(row.expires_at)
+        .bind(&row.metadata)
+        .execute(pool)
+        .await;
+
+        if let Err(e) = result {
+            warn!(intent_id = %intent.intent_id, error = %e, "failed to persist queued intent");
+        }
+    }
+
+    async fn remove_queued_intents(&self, intent_ids: &[Uuid]) {
+        if intent_ids.is_empty() {
+            return;
+        }
+        let Some(pool) = self.execution_log_pool.as_ref() else {
+            return;
+        };
+        let result = sqlx::query(
+            "DELETE FROM order_queue_intents WHERE account_id = $1 AND intent_id = ANY($2)",
+        )
+        .bind(&self.account_id)
+        .bind(intent_ids)
+        .execute(pool)
+        .await;
+
+        if let Err(e) = result {
+            warn!(count = intent_ids.len(), error = %e, "failed to remove queued intents");
+        }
+    }
+
+    /// Reload intents queued by the previous run. Expired ones are dropped;
+    /// the rest go back through the full intent pipeline (risk gate,
+    /// allocators, pauses), so anything no longer valid is blocked as usual.
+    /// Call after agents are registered and before `run`.
+    pub async fn restore_queued_intents(&self) -> Result<usize> {
+        let Some(pool) = self.execution_log_pool.as_ref() else {
+            return Ok(0);
+        };
+        let intents =
+            load_queued_intents(pool, &self.account_id, self.executor.is_dry_run()).await?;
+        if intents.is_empty() {
+            return Ok(0);
+        }
+        // Rows are re-written by the intents that make it back into the queue.
+        let ids: Vec<Uuid> = intents.iter().map(|i| i.intent_id).collect();
+        self.remove_queued_intents(&ids).await;
+
+        let loaded = intents.len();
+        let (live, expired) = partition_restored_intents(intents);
+        for intent in &expired {
+            self.persist_risk_decision(
+                intent,
+                "BLOCKED",
+                Some("queued intent expired across restart".to_string()),
+                None,
+            )
+            .await;
+        }
+        let expired = expired.len();
+
+        let before = self.order_queue.read().await.len();
+        for intent in live {
+            self.handle_order_intent(intent).await;
+        }
+
+        let restored = {
+            let mut queue = self.order_queue.write().await;
+            let restored = queue.len().saturating_sub(before);
+            queue.record_restored(restored as u64);
+            restored
+        };
+        info!(
+            loaded,
+            expired,
+            restored,
+            not_requeued = loaded.saturating_sub(expired + restored),
+            "restored persisted order queue"
+        );
+        Ok(restored)
+    }
+
     async fn persist_risk_decision(
         &self,
         intent: &OrderIntent,
@@ -5062,6 +5997,7 @@ impl Coordinator {
         let mut state = self.global_state.write().await;
         state.portfolio = portfolio;
         state.positions = positions;
+        state.position_conflicts = ConflictDetector::detect(&state);
         state.risk_state = risk_state;
         state.daily_pnl = daily_pnl;
         state.daily_loss_limit = daily_loss_limit;
@@ -5104,6 +6040,574 @@ impl Coordinator {
         drop(state);
 
         self.persist_risk_runtime_state().await;
+        self.enforce_loss_limits().await;
+        self.sync_budget_top_ups().await;
+    }
+
+    /// Cross-agent conflict check for a BUY intent. Returns the BUY shares to
+    /// keep, or `None` when the intent was cancelled or fully internalized.
+    async fn resolve_intent_conflict(&self, intent: &OrderIntent) -> Option<u64> {
+        const MAX_INTENT_CONFLICTS: usize = 200;
+
+        let conflict = {
+            let positions = self.positions.all_positions().await;
+            let queue = self.order_queue.read().await;
+            ConflictDetector::check_intent(intent, &positions, queue.pending_buy_intents())
+        };
+        let Some(conflict) = conflict else {
+            return Some(intent.shares);
+        };
+
+        let agent_id = &intent.agent_id;
+        let intent_id = intent.intent_id;
+        let (keep, resolution) =
+            match ConflictDetector::resolve_intent(self.config.conflict_policy, &conflict) {
+                IntentResolution::Allow => {
+                    info!(
+                        %agent_id, %intent_id, conflict = %conflict.conflict.description,
+                        "cross-agent conflict reported"
+                    );
+                    (Some(intent.shares), "reported".to_string())
+                }
+                IntentResolution::Cancel { reason } => {
+                    self.persist_risk_decision(intent, "BLOCKED", Some(reason.clone()), None)
+                        .await;
+                    warn!(
+                        %agent_id, %intent_id, reason = %reason,
+                        "order blocked by cross-agent conflict"
+                    );
+                    (None, "cancelled redundant leg".to_string())
+                }
+                IntentResolution::Internalize {
+                    sells,
+                    remaining_shares,
+                } => {
+                    // Selling the opposite side at 1 - p matches buying this side at p
+                    let sell_price = (Decimal::ONE - intent.limit_price).max(Decimal::new(1, 2));
+                    let internalized: u64 = sells.iter().map(|s| s.shares).sum();
+                    for sell in sells {
+                        let mut sell_intent = OrderIntent::new(
+                            sell.agent_id,
+                            sell.domain,
+                            sell.market_slug,
+                            sell.token_id,
+                            sell.side,
+                            false,
+                            sell.shares,
+                            sell_price,
+                        )
+                        .with_priority(intent.priority)
+                        .with_metadata("exit_reason", "conflict_internalized")
+                        .with_metadata("internalized_for", intent.agent_id.clone());
+                        if let Some(condition_id) = intent.condition_id() {
+                            sell_intent = sell_intent.with_condition_id(condition_id);
+                        }
+                        Box::pin(self.handle_order_intent(sell_intent)).await;
+                    }
+
+                    let summary = format!(
+                        "internalized {} shares, {} left to buy",
+                        internalized, remaining_shares
+                    );
+                    info!(%agent_id, %intent_id, %summary, "cross-agent conflict internalized");
+                    if remaining_shares == 0 {
+                        self.persist_risk_decision(
+                            intent,
+                            "BLOCKED",
+                            Some(format!("{}: {}", summary, conflict.conflict.description)),
+                            None,
+                        )
+                        .await;
+                        (None, summary)
+                    } else {
+                        (Some(remaining_shares), summary)
+                    }
+                }
+            };
+
+        let mut record = conflict.conflict;
+        record.resolution = Some(resolution);
+        let mut state = self.global_state.write().await;
+        state.intent_conflicts.push(record);
+        if state.intent_conflicts.len() > MAX_INTENT_CONFLICTS {
+            let excess = state.intent_conflicts.len() - MAX_INTENT_CONFLICTS;
+            state.intent_conflicts.drain(..excess);
+        }
+        keep
+    }
+
+    /// Rebuild sandbox budgets from `agent_budget_ledger` (restart continuity).
+    pub async fn restore_agent_budgets(&self) -> Result<()> {
+        let Some(pool) = self.execution_log_pool.as_ref() else {
+            return Ok(());
+        };
+        if !self.config.budget.enabled {
+            return Ok(());
+        }
+        let ledger = budget::load_ledger(pool).await?;
+        info!(agents = ledger.snapshot().len(), "restored sandbox budgets");
+        *self.budgets.write().await = ledger;
+        Ok(())
+    }
+
+    /// Book the opening allocation the first time a budgeted agent shows up.
+    async fn ensure_budget_allocation(&self, budgets: &mut BudgetLedger, agent_id: &str) {
+        if budgets.is_tracked(agent_id) {
+            return;
+        }
+        let Some(allocation) = self.config.budget.allocation_for(agent_id) else {
+            return;
+        };
+        budgets.apply(agent_id, LedgerEntryKind::Allocation, allocation);
+        if let Some(pool) = self.execution_log_pool.as_ref() {
+            if let Err(e) = budget::record_entry(
+                pool,
+                agent_id,
+                LedgerEntryKind::Allocation,
+                allocation,
+                None,
+            )
+            .await
+            {
+                warn!(%agent_id, error = %e, "failed to persist sandbox budget allocation");
+            }
+        }
+        info!(%agent_id, %allocation, "sandbox budget allocated");
+    }
+
+    /// Block reason when a BUY intent exceeds its agent's sandbox budget.
+    /// In-flight BUYs are not reserved, so concurrent intents can overshoot
+    /// the balance by at most their own notional.
+    async fn check_agent_budget(&self, intent: &OrderIntent) -> Option<String> {
+        if !self.config.budget.enabled {
+            return None;
+        }
+        let mut budgets = self.budgets.write().await;
+        self.ensure_budget_allocation(&mut budgets, &intent.agent_id)
+            .await;
+        budgets.check_buy(&intent.agent_id, intent.notional_value())
+    }
+
+    /// Debit a BUY fill from (or credit SELL proceeds to) the agent's budget.
+    async fn record_budget_fill(&self, intent: &OrderIntent, filled_shares: u64, price: Decimal) {
+        if !self.config.budget.enabled || filled_shares == 0 {
+            return;
+        }
+        let kind = if intent.is_buy {
+            LedgerEntryKind::Fill
+        } else {
+            LedgerEntryKind::Settlement
+        };
+        let amount = Decimal::from(filled_shares) * price;
+        {
+            let mut budgets = self.budgets.write().await;
+            if !budgets.is_tracked(&intent.agent_id) {
+                return;
+            }
+            budgets.apply(&intent.agent_id, kind, amount);
+        }
+        if let Some(pool) = self.execution_log_pool.as_ref() {
+            let reference = intent.intent_id.to_string();
+            if let Err(e) =
+                budget::record_entry(pool, &intent.agent_id, kind, amount, Some(&reference)).await
+            {
+                warn!(
+                    agent_id = %intent.agent_id, intent_id = %intent.intent_id, error = %e,
+                    "failed to persist sandbox budget entry"
+                );
+            }
+        }
+    }
+
+    /// Apply approved top-ups, auto-approve small ones and ask an operator
+    /// (Feishu card) about the rest. Cards are sent once per request; after a
+    /// card expires the request can still be decided from the CLI.
+    async fn sync_budget_top_ups(&self) {
+        if !self.config.budget.enabled {
+            return;
+        }
+        let Some(pool) = self.execution_log_pool.as_ref() else {
+            return;
+        };
+
+        match budget::load_top_ups(pool, "APPROVED").await {
+            Ok(approved) => {
+                for top_up in approved {
+                    self.apply_budget_top_up(pool, top_up.id, "APPROVED").await;
+                }
+            }
+            Err(e) => warn!(error = %e, "failed to load approved budget top-ups"),
+        }
+
+        let pending = match budget::load_top_ups(pool, "PENDING").await {
+            Ok(pending) => pending,
+            Err(e) => {
+                warn!(error = %e, "failed to load pending budget top-ups");
+                return;
+            }
+        };
+        for top_up in pending {
+            if top_up.amount_usd <= self.config.budget.auto_top_up_max_usd {
+                self.apply_budget_top_up(pool, top_up.id, "PENDING").await;
+                continue;
+            }
+            match budget::mark_top_up_notified(pool, top_up.id).await {
+                Ok(true) => {
+                    let approval_id = self.approvals.request(ApprovalAction::BudgetTopUp {
+                        top_up_id: top_up.id,
+                        agent_id: top_up.agent_id.clone(),
+                        amount_usd: top_up.amount_usd,
+                        requested_by: top_up.requested_by.clone(),
+                    });
+                    info!(
+                        agent_id = %top_up.agent_id, top_up_id = %top_up.id, %approval_id,
+                        amount = %top_up.amount_usd,
+                        "budget top-up awaiting operator approval"
+                    );
+                }
+                Ok(false) => {}
+                Err(e) => warn!(top_up_id = %top_up.id, error = %e, "failed to mark top-up"),
+            }
+        }
+    }
+
+    /// Operator decision on a top-up (Feishu card)
+    async fn decide_budget_top_up(&self, top_up_id: Uuid, approve: bool, operator: &str) {
+        let Some(pool) = self.execution_log_pool.as_ref() else {
+            warn!(%top_up_id, "budget top-up decided without a database; ignoring");
+            return;
+        };
+        match budget::decide_top_up(pool, top_up_id, approve, operator).await {
+            Ok(true) if approve => self.apply_budget_top_up(pool, top_up_id, "APPROVED").await,
+            Ok(true) => info!(%top_up_id, operator, "budget top-up denied"),
+            Ok(false) => warn!(%top_up_id, "budget top-up is no longer pending"),
+            Err(e) => warn!(%top_up_id, error = %e, "failed to record top-up decision"),
+        }
+    }
+
+    async fn apply_budget_top_up(&self, pool: &PgPool, top_up_id: Uuid, from: &str) {
+        match budget::apply_top_up(pool, top_up_id, from).await {
+            Ok(Some(top_up)) => {
+                let mut budgets = self.budgets.write().await;
+                self.ensure_budget_allocation(&mut budgets, &top_up.agent_id)
+                    .await;
+                budgets.apply(&top_up.agent_id, LedgerEntryKind::TopUp, top_up.amount_usd);
+                info!(
+                    agent_id = %top_up.agent_id, %top_up_id, amount = %top_up.amount_usd,
+                    decided_by = top_up.decided_by.as_deref().unwrap_or("auto"),
+                    "budget top-up applied"
+                );
+            }
+            Ok(None) => {}
+            Err(e) => warn!(%top_up_id, error = %e, "failed to apply budget top-up"),
+        }
+    }
+
+    /// Restore today's loss limit halts from `daily_metrics` (restart continuity).
+    pub async fn restore_loss_limit_halts(&self) -> Result<()> {
+        let Some(pool) = self.execution_log_pool.as_ref() else {
+            return Ok(());
+        };
+        if !self.config.loss_limit.enabled {
+            return Ok(());
+        }
+
+        let today = Utc::now().date_naive();
+        let persisted = loss_limit::load_halts(pool, today).await?;
+        let halts: Vec<LossLimitBreach> = persisted
+            .scopes
+            .iter()
+            .map(|scope| LossLimitBreach {
+                scope: scope.clone(),
+                pnl: Decimal::ZERO,
+                limit: self.config.loss_limit.limit_for(scope).unwrap_or_default(),
+                halted_at: persisted.halted_at.unwrap_or_else(Utc::now),
+            })
+            .collect();
+        if halts.is_empty() && persisted.baselines.is_empty() {
+            return Ok(());
+        }
+
+        for halt in &halts {
+            warn!(scope = %halt.scope, "restored intraday loss limit halt");
+            if halt.is_global() {
+                *self.ingress_mode.write().await = IngressMode::Halted;
+            } else {
+                self.paused_agent_ids
+                    .write()
+                    .await
+                    .insert(halt.scope.clone());
+            }
+        }
+        self.loss_limit
+            .write()
+            .await
+            .restore(today, halts, persisted.baselines);
+        Ok(())
+    }
+
+    /// Evaluate the intraday loss limits. Newly breached scopes are halted,
+    /// persisted and flattened; halts lifted by an operator through the
+    /// database (CLI) or expired at the UTC day roll are released.
+    async fn enforce_loss_limits(&self) {
+        if !self.config.loss_limit.enabled {
+            return;
+        }
+        let today = Utc::now().date_naive();
+
+        let expired = self.loss_limit.write().await.roll_day(today);
+        for halt in expired {
+            info!(scope = %halt.scope, "intraday loss limit halt expired at day roll");
+            self.release_loss_limit_scope(&halt.scope).await;
+        }
+        self.sync_loss_limit_reenables(today).await;
+
+        let breaches = {
+            let state = self.global_state.read().await;
+            self.loss_limit
+                .write()
+                .await
+                .evaluate(&self.config.loss_limit, &state, today)
+        };
+        for breach in breaches {
+            self.apply_loss_limit_breach(&breach, today).await;
+        }
+    }
+
+    /// Release halts whose re-enable was recorded in `daily_metrics` by
+    /// another process (CLI)
+    async fn sync_loss_limit_reenables(&self, today: chrono::NaiveDate) {
+        let Some(pool) = self.execution_log_pool.as_ref() else {
+            return;
+        };
+        let halts = self.loss_limit.read().await.halts();
+        if halts.is_empty() {
+            return;
+        }
+
+        let persisted = match loss_limit::load_halts(pool, today).await {
+            Ok(persisted) => persisted,
+            Err(e) => {
+                debug!(error = %e, "failed to poll loss limit halts");
+                return;
+            }
+        };
+        for halt in halts.iter().filter(|h| persisted.lifts(h)) {
+            info!(
+                scope = %halt.scope,
+                operator = ?persisted.reenabled_by,
+                "intraday loss limit halt re-enabled by operator"
+            );
+            self.reenable_loss_limit(&halt.scope).await;
+        }
+    }
+
+    /// Operator re-enable: lift the halt with a fresh loss budget and resume
+    /// the scope.
+    async fn reenable_loss_limit(&self, scope: &str) {
+        let current_pnl = {
+            let state = self.global_state.read().await;
+            loss_limit::scope_pnl(&state, scope)
+        };
+        if !self.loss_limit.write().await.reenable(scope, current_pnl) {
+            debug!(%scope, "loss limit re-enable for scope that is not halted");
+            return;
+        }
+        if let (Some(pool), Some(baseline)) = (self.execution_log_pool.as_ref(), current_pnl) {
+            let today = Utc::now().date_naive();
+            if let Err(e) = loss_limit::persist_baseline(pool, today, scope, baseline).await {
+                warn!(%scope, error = %e, "failed to persist loss limit re-enable baseline");
+            }
+        }
+        info!(%scope, "intraday loss limit halt lifted");
+        self.release_loss_limit_scope(scope).await;
+    }
+
+    async fn release_loss_limit_scope(&self, scope: &str) {
+        if scope == GLOBAL_SCOPE {
+            self.resume_all().await;
+        } else {
+            self.paused_agent_ids.write().await.remove(scope);
+            self.send_command(scope, CoordinatorCommand::Resume)
+                .await
+                .ok();
+        }
+    }
+
+    /// Halt a breached scope, record it and sequence an exit of its positions
+    async fn apply_loss_limit_breach(&self, breach: &LossLimitBreach, today: chrono::NaiveDate) {
+        error!(
+            scope = %breach.scope,
+            pnl = %breach.pnl,
+            limit = %breach.limit,
+            "INTRADAY LOSS LIMIT BREACHED: halting and flattening"
+        );
+
+        if breach.is_global() {
+            *self.ingress_mode.write().await = IngressMode::Halted;
+            self.cancel_queued_buy_intents(None, "dropped by intraday loss limit halt")
+                .await;
+            for (id, entry) in &self.agent_commands {
+                if let Err(e) = entry.tx.send(CoordinatorCommand::Pause).await {
+                    warn!(agent_id = %id, error = %e, "failed to send loss limit pause");
+                }
+            }
+        } else {
+            self.paused_agent_ids
+                .write()
+                .await
+                .insert(breach.scope.clone());
+            self.send_command(&breach.scope, CoordinatorCommand::Pause)
+                .await
+                .ok();
+        }
+
+        if let Some(pool) = self.execution_log_pool.as_ref() {
+            if let Err(e) = loss_limit::persist_halt(pool, today, breach).await {
+                error!(scope = %breach.scope, error = %e, "failed to persist loss limit halt");
+            }
+        }
+
+        #[cfg(feature = "api")]
+        crate::api::webhooks::emit(
+            crate::api::webhooks::WebhookEventType::CircuitBreaker,
+            serde_json::json!({
+                "source": "intraday_loss_limit",
+                "state": "halted",
+                "scope": &breach.scope,
+                "pnl": breach.pnl,
+                "limit": breach.limit,
+                "reason": breach.reason(),
+                "halted_at": breach.halted_at,
+            }),
+        );
+
+        self.flatten_loss_limit_scope(breach).await;
+    }
+
+    /// Sell every open position of the scope through the unwind planner.
+    async fn flatten_loss_limit_scope(&self, breach: &LossLimitBreach) {
+        let positions = if breach.is_global() {
+            self.positions.all_positions().await
+        } else {
+            self.positions.get_agent_positions(&breach.scope).await
+        };
+        self.unwind_positions(
+            positions,
+            "intraday_loss_limit",
+            ("loss_limit_scope", breach.scope.clone()),
+        )
+        .await;
+    }
+
+    /// Sell `fraction` of every open position (rounded down per position)
+    /// ahead of an exchange or chain outage.
+    async fn derisk_positions(&self, fraction: Decimal, reason: &str) {
+        let fraction = fraction.clamp(Decimal::ZERO, Decimal::ONE);
+        let positions: Vec<crate::platform::Position> = self
+            .positions
+            .all_positions()
+            .await
+            .into_iter()
+            .filter_map(|mut position| {
+                position.shares = (Decimal::from(position.shares) * fraction)
+                    .floor()
+                    .to_u64()?;
+                Some(position)
+            })
+            .collect();
+        warn!(
+            %fraction,
+            positions = positions.iter().filter(|p| p.shares > 0).count(),
+            reason,
+            "VENUE DE-RISK: reducing open positions"
+        );
+        self.unwind_positions(
+            positions,
+            "venue_derisk",
+            ("derisk_reason", reason.to_string()),
+        )
+        .await;
+    }
+
+    /// Sell the given positions through the unwind planner. Chunks are
+    /// submitted as critical reduce-only sells tagged with `exit_reason`;
+    /// shares the book cannot absorb above the planner's floor are left for
+    /// the agent/operator.
+    async fn unwind_positions(
+        &self,
+        positions: Vec<crate::platform::Position>,
+        exit_reason: &str,
+        (tag_key, tag_value): (&str, String),
+    ) {
+        let mut by_agent: HashMap<String, Vec<crate::platform::Position>> = HashMap::new();
+        for position in positions.into_iter().filter(|p| p.shares > 0) {
+            by_agent
+                .entry(position.agent_id.clone())
+                .or_default()
+                .push(position);
+        }
+
+        let planner = UnwindPlanner::new(UnwindConfig::default());
+        for (agent_id, positions) in by_agent {
+            let mut inputs = Vec::with_capacity(positions.len());
+            for position in &positions {
+                let (bid, ask) = match self.executor.get_prices(&position.token_id).await {
+                    Ok(prices) => prices,
+                    Err(e) => {
+                        warn!(
+                            %agent_id, token_id = %position.token_id, error = %e,
+                            "unwind: no quote for position"
+                        );
+                        (None, None)
+                    }
+                };
+                inputs.push(loss_limit::unwind_input(position, bid, ask));
+            }
+
+            let plan = planner.plan(inputs);
+            info!(
+                exit_reason,
+                %agent_id,
+                legs = plan.legs.len(),
+                shares = plan.total_shares(),
+                expected_proceeds = %plan.expected_proceeds(),
+                residual_shares = plan.residual_shares(),
+                "unwind planned"
+            );
+
+            for leg in plan.legs {
+                let Some(position) = positions.iter().find(|p| {
+                    p.token_id == leg.position.token_id && p.side == leg.position.market_side
+                }) else {
+                    continue;
+                };
+                if leg.residual_shares > 0 {
+                    warn!(
+                        %agent_id,
+                        market = %position.market_slug,
+                        residual_shares = leg.residual_shares,
+                        "unwind: book too thin above floor, shares left open"
+                    );
+                }
+                for chunk in &leg.chunks {
+                    let intent = OrderIntent::new(
+                        agent_id.clone(),
+                        position.domain,
+                        position.market_slug.clone(),
+                        position.token_id.clone(),
+                        position.side,
+                        false,
+                        chunk.shares,
+                        chunk.limit_price,
+                    )
+                    .with_priority(OrderPriority::Critical)
+                    .with_metadata("exit_reason", exit_reason)
+                    .with_metadata(tag_key, tag_value.clone());
+                    self.handle_order_intent(intent).await;
+                }
+            }
+        }
     }
 
     fn infer_time_bucket_seconds(intent: &OrderIntent) -> i64 {
@@ -5128,11 +6632,7 @@ impl Coordinator {
 
         for raw in hints {
             if let Some(horizon) = CryptoHorizon::from_hint(raw) {
-                return match horizon {
-                    CryptoHorizon::M15 => 15 * 60,
-                    CryptoHorizon::M5 => 5 * 60,
-                    CryptoHorizon::Other => 5 * 60,
-                };
+                return horizon.window_secs();
             }
         }
 
@@ -5228,6 +6728,10 @@ impl Coordinator {
             intent,
             self.config.duplicate_guard_scope,
         );
+        // Unparseable flags are rejected at ingress (see `intent_order_flags_reason`).
+        let time_in_force = intent.time_in_force().unwrap_or(TimeInForce::GTC);
+        let strategy = Self::metadata_value(&intent.metadata, &["strategy", "deployment_strategy"])
+            .unwrap_or(&intent.agent_id);
         OrderRequest {
             client_order_id: format!("intent:{}", intent.intent_id),
             idempotency_key: Some(idempotency_key),
@@ -5237,8 +6741,35 @@ impl Coordinator {
             shares: intent.shares,
             limit_price: intent.limit_price,
             order_type: crate::domain::OrderType::Limit,
-            time_in_force: crate::domain::TimeInForce::GTC,
-        }
+            time_in_force,
+            // Only GTD hands the expiry to the venue; otherwise it just bounds queueing.
+            expires_at: (time_in_force == TimeInForce::GTD)
+                .then_some(intent.expires_at)
+                .flatten(),
+            post_only: intent.is_post_only(),
+            execution_preference: Some(self.executor.preference_for(strategy)),
+        }
+    }
+
+    /// Why the intent's time-in-force/post-only/bracket flags cannot be
+    /// executed, if so.
+    fn intent_order_flags_reason(&self, intent: &OrderIntent) -> Option<String> {
+        let request = intent
+            .time_in_force()
+            .and_then(|_| {
+                let request = self.intent_to_request(intent);
+                request.validate().map(|_| request)
+            })
+            .map_err(|e| e.to_string());
+        let request = match request {
+            Ok(request) => request,
+            Err(reason) => return Some(reason),
+        };
+        let (take_profit, stop_loss, stop_limit) =
+            intent.bracket_exits().filter(|_| intent.is_buy)?;
+        Bracket::new(request, take_profit, stop_loss, stop_limit, None)
+            .err()
+            .map(|e| e.to_string())
     }
 }
 
@@ -5282,6 +6813,7 @@ mod tests {
             executor,
             "acct-test".to_string(),
             allowed_domains,
+            None,
         );
         let handle = coordinator.handle();
         (handle, coordinator)
@@ -5315,14 +6847,19 @@ mod tests {
             enqueued_total: 50,
             dequeued_total: 45,
             expired_total: 3,
+            restored_total: 2,
             critical_count: 1,
             high_count: 2,
             normal_count: 1,
             low_count: 1,
+            oldest_age_ms: 1_500,
+            avg_age_ms: 400,
         };
         let snap = QueueStatsSnapshot::from(qs);
         assert_eq!(snap.current_size, 5);
         assert_eq!(snap.enqueued_total, 50);
+        assert_eq!(snap.restored_total, 2);
+        assert_eq!(snap.oldest_age_ms, 1_500);
     }
 
     fn make_intent(is_buy: bool, priority: OrderPriority) -> OrderIntent {
@@ -5942,6 +7479,88 @@ mod tests {
         assert!(reason.is_none(), "sell intent should remain allowed");
     }
 
+    #[tokio::test]
+    async fn test_bracket_entry_arms_exits_and_books_take_profit() {
+        use crate::strategy::execution::bracket::mock::MockBracketStore;
+        use crate::strategy::execution::conditional::mock::{DryRunExchange, MockConditionalStore};
+        use crate::strategy::ConditionalOrderManager;
+
+        let executor = Arc::new(OrderExecutor::new_with_exchange(
+            Arc::new(DryRunExchange),
+            ExecutionConfig::default(),
+        ));
+        let mut coordinator = Coordinator::new(
+            CoordinatorConfig::default(),
+            executor.clone(),
+            "acct-test".to_string(),
+            HashSet::from([Domain::Crypto]),
+            None,
+        );
+        let (tx, mut executions) = mpsc::channel(4);
+        let conditionals = Arc::new(
+            ConditionalOrderManager::new(
+                Arc::new(MockConditionalStore::default()),
+                executor.clone(),
+            )
+            .with_executions(tx),
+        );
+        let brackets = Arc::new(BracketManager::new(
+            Arc::new(MockBracketStore::default()),
+            executor,
+            conditionals.clone(),
+        ));
+        let (_unused_tx, unused_rx) = mpsc::channel(1);
+        coordinator.set_conditional_orders(brackets, unused_rx);
+
+        let bad = make_intent(true, OrderPriority::Normal).with_bracket(
+            dec!(0.40),
+            dec!(0.35),
+            dec!(0.33),
+        );
+        assert!(coordinator.intent_order_flags_reason(&bad).is_some());
+
+        let intent = make_intent(true, OrderPriority::Normal).with_bracket(
+            dec!(0.60),
+            dec!(0.35),
+            dec!(0.33),
+        );
+        assert!(coordinator.intent_order_flags_reason(&intent).is_none());
+        let request = coordinator.intent_to_request(&intent);
+        let result = coordinator.execute_intent(&intent, &request).await.unwrap();
+        assert_eq!(result.filled_shares, 100);
+        coordinator
+            .book_execution(&intent, &request, &result, None)
+            .await;
+        assert_eq!(conditionals.armed().await.len(), 2);
+        assert_eq!(
+            coordinator
+                .positions
+                .get_agent_positions("crypto_lob_ml")
+                .await
+                .len(),
+            1
+        );
+
+        let quote = crate::domain::Quote {
+            side: crate::domain::Side::Up,
+            best_bid: Some(dec!(0.61)),
+            best_ask: Some(dec!(0.62)),
+            bid_size: Some(dec!(500)),
+            ask_size: Some(dec!(500)),
+            timestamp: Utc::now(),
+        };
+        assert_eq!(conditionals.on_quote("token-up-123", quote).await, 1);
+        let execution = executions.try_recv().unwrap();
+        coordinator.handle_conditional_execution(execution).await;
+
+        assert!(conditionals.armed().await.is_empty());
+        assert!(coordinator
+            .positions
+            .get_agent_positions("crypto_lob_ml")
+            .await
+            .is_empty());
+    }
+
     #[tokio::test]
     async fn test_handle_force_close_domain_blocks_new_buy_immediately() {
         let (handle, _coordinator) = make_test_handle();
@@ -6037,6 +7656,58 @@ mod tests {
                 && agent.status == "running"));
     }
 
+    #[tokio::test]
+    async fn test_standby_agent_logs_would_trade_and_reports_standby() {
+        let (_handle, coordinator) = make_test_handle();
+        coordinator
+            .standby_agents
+            .write()
+            .await
+            .insert("sports".to_string(), 0);
+
+        let intent = OrderIntent::new(
+            "sports",
+            Domain::Sports,
+            "nba-game-3",
+            "sports-token-yes",
+            crate::domain::Side::Up,
+            true,
+            10,
+            dec!(0.42),
+        )
+        .with_deployment_id("deploy.sports.nba.test");
+        coordinator.handle_order_intent(intent).await;
+
+        assert!(coordinator.order_queue.read().await.is_empty());
+        assert_eq!(
+            coordinator.standby_agents.read().await.get("sports"),
+            Some(&1)
+        );
+
+        coordinator
+            .handle_state_update(AgentSnapshot {
+                agent_id: "sports".to_string(),
+                name: "sports".to_string(),
+                domain: Domain::Sports,
+                status: AgentStatus::Running,
+                position_count: 0,
+                exposure: Decimal::ZERO,
+                daily_pnl: Decimal::ZERO,
+                unrealized_pnl: Decimal::ZERO,
+                metrics: HashMap::new(),
+                last_heartbeat: Utc::now(),
+                error_message: None,
+            })
+            .await;
+        let state = coordinator.global_state.read().await;
+        let agent = &state.agents["sports"];
+        assert_eq!(agent.status, AgentStatus::Standby);
+        assert_eq!(
+            agent.metrics.get("standby_would_trade").map(String::as_str),
+            Some("1")
+        );
+    }
+
     #[test]
     fn test_clamp_governance_history_limit_bounds() {
         assert_eq!(clamp_governance_history_limit(0), 1);
@@ -6090,6 +7761,60 @@ mod tests {
         assert!(!execution_error_is_failure(None));
     }
 
+    #[test]
+    fn test_queued_intents_round_trip_in_priority_order_without_expired() {
+        let now = Utc::now();
+        let mut low = make_intent(true, OrderPriority::Low).with_metadata("strategy", "momentum");
+        low.created_at = now - chrono::Duration::seconds(30);
+        let mut older_high = make_intent(true, OrderPriority::High);
+        older_high.created_at = now - chrono::Duration::seconds(20);
+        let mut newer_high = make_intent(true, OrderPriority::High);
+        newer_high.created_at = now - chrono::Duration::seconds(10);
+        let critical = make_intent(false, OrderPriority::Critical);
+        let mut stale = make_intent(true, OrderPriority::Critical);
+        stale.expires_at = Some(now - chrono::Duration::seconds(1));
+
+        let restored: Vec<OrderIntent> = [&low, &newer_high, &stale, &critical, &older_high]
+            .into_iter()
+            .map(QueuedIntentRow::from_intent)
+            .filter_map(QueuedIntentRow::into_intent)
+            .collect();
+        assert_eq!(restored.len(), 5);
+
+        let (live, expired) = partition_restored_intents(restored);
+        let ids = |intents: &[OrderIntent]| intents.iter().map(|i| i.intent_id).collect::<Vec<_>>();
+        assert_eq!(ids(&expired), vec![stale.intent_id]);
+        assert_eq!(
+            ids(&live),
+            vec![
+                critical.intent_id,
+                older_high.intent_id,
+                newer_high.intent_id,
+                low.intent_id
+            ]
+        );
+        let restored_low = &live[3];
+        assert_eq!(restored_low.created_at, low.created_at);
+        assert_eq!(restored_low.metadata, low.metadata);
+        assert_eq!(restored_low.shares, low.shares);
+        assert!(!live[0].is_buy);
+
+        let mut queue = OrderQueue::new(8);
+        for intent in live {
+            queue.enqueue(intent).unwrap();
+        }
+        queue.record_restored(queue.len() as u64);
+        assert_eq!(queue.stats().restored_total, 4);
+        assert_eq!(
+            queue.dequeue().map(|i| i.intent_id),
+            Some(critical.intent_id)
+        );
+        assert_eq!(
+            queue.dequeue().map(|i| i.intent_id),
+            Some(older_high.intent_id)
+        );
+    }
+
     fn make_allocator_config(total_cap: Decimal) -> CoordinatorConfig {
         let mut cfg = CoordinatorConfig::default();
         cfg.crypto_allocator_enabled = true;
@@ -0,0 +1,352 @@
+//! Exposure heatmap for concentration risk
+//!
+//! Buckets open position exposure by underlying symbol, domain and minutes
+//! to settlement. Positions do not carry settlement times, so those (and
+//! the underlying symbol of crypto rounds) are looked up by market slug in
+//! `pm_market_metadata` and `rounds`; unknown settlement times land in their
+//! own bucket. Any symbol, domain or settlement bucket holding too large a
+//! share of total exposure is flagged, and exposure settling within the
+//! near-term window gets its own alert (e.g. everything resolving in the
+//! next 3 minutes).
+
+use chrono::{DateTime, Utc};
+use rust_decimal::Decimal;
+use serde::{Deserialize, Serialize};
+use sqlx::PgPool;
+use std::collections::{BTreeMap, HashMap};
+
+use crate::error::Result;
+use crate::platform::Position;
+
+/// Settlement buckets as (upper bound in minutes, label); past-due
+/// positions fall into the first bucket.
+pub const SETTLEMENT_BUCKETS: [(i64, &str); 5] = [
+    (3, "<3m"),
+    (15, "3-15m"),
+    (60, "15-60m"),
+    (24 * 60, "1-24h"),
+    (i64::MAX, ">24h"),
+];
+
+/// Bucket for positions whose settlement time is not known
+pub const UNKNOWN_BUCKET: &str = "unknown";
+
+/// Concentration thresholds (shares of total exposure, 0..1)
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(default)]
+pub struct HeatmapThresholds {
+    /// Flag a symbol / domain / bucket above this share
+    pub concentration_pct: Decimal,
+    /// Window for the near-term settlement alert
+    pub near_term_minutes: i64,
+    /// Alert when exposure settling within the window exceeds this share
+    pub near_term_pct: Decimal,
+}
+
+impl Default for HeatmapThresholds {
+    fn default() -> Self {
+        Self {
+            concentration_pct: Decimal::new(5, 1),
+            near_term_minutes: 3,
+            near_term_pct: Decimal::new(5, 1),
+        }
+    }
+}
+
+/// Settlement time and underlying of one market
+#[derive(Debug, Clone, Default)]
+pub struct MarketSettlement {
+    pub end_time: Option<DateTime<Utc>>,
+    pub symbol: Option<String>,
+}
+
+/// Exposure in one (symbol, domain, settlement bucket) cell
+#[derive(Debug, Clone, Serialize)]
+pub struct HeatmapCell {
+    pub symbol: String,
+    pub domain: String,
+    pub bucket: String,
+    pub exposure: Decimal,
+    pub positions: usize,
+}
+
+/// Exposure along one dimension
+#[derive(Debug, Clone, Serialize)]
+pub struct ExposureShare {
+    pub key: String,
+    pub exposure: Decimal,
+    /// Share of total exposure (0..1)
+    pub share: Decimal,
+    pub concentrated: bool,
+}
+
+#[derive(Debug, Clone, Serialize)]
+pub struct ExposureHeatmap {
+    pub generated_at: DateTime<Utc>,
+    pub total_exposure: Decimal,
+    pub thresholds: HeatmapThresholds,
+    /// Column order for the settlement dimension
+    pub buckets: Vec<String>,
+    pub cells: Vec<HeatmapCell>,
+    pub by_symbol: Vec<ExposureShare>,
+    pub by_domain: Vec<ExposureShare>,
+    pub by_settlement: Vec<ExposureShare>,
+    /// Exposure settling within `near_term_minutes`
+    pub near_term_exposure: Decimal,
+    pub alerts: Vec<String>,
+}
+
+/// Settlement bucket label for `minutes` to settlement
+pub fn settlement_bucket(minutes: Option<i64>) -> &'static str {
+    match minutes {
+        Some(m) => SETTLEMENT_BUCKETS
+            .iter()
+            .find(|(upper, _)| m < *upper)
+            .map(|(_, label)| *label)
+            .unwrap_or(UNKNOWN_BUCKET),
+        None => UNKNOWN_BUCKET,
+    }
+}
+
+/// Underlying of a market: the metadata symbol without its quote currency
+/// (`BTCUSDT` -> `BTC`), else the first segment of the slug.
+fn underlying(slug: &str, symbol: Option<&str>) -> String {
+    match symbol.map(str::trim).filter(|s| !s.is_empty()) {
+        Some(symbol) => {
+            let upper = symbol.to_ascii_uppercase();
+            match upper.strip_suffix("USDT") {
+                Some(base) if !base.is_empty() => base.to_string(),
+                _ => upper,
+            }
+        }
+        None => slug
+            .split('-')
+            .next()
+            .filter(|s| !s.is_empty())
+            .unwrap_or(slug)
+            .to_ascii_uppercase(),
+    }
+}
+
+impl ExposureHeatmap {
+    pub fn build(
+        positions: &[Position],
+        markets: &HashMap<String, MarketSettlement>,
+        thresholds: HeatmapThresholds,
+        now: DateTime<Utc>,
+    ) -> Self {
+        let mut cells: BTreeMap<(String, String, &'static str), HeatmapCell> = BTreeMap::new();
+        let mut near_term_exposure = Decimal::ZERO;
+
+        for position in positions {
+            let exposure = position.notional_value();
+            if exposure <= Decimal::ZERO {
+                continue;
+            }
+            let market = markets.get(&position.market_slug);
+            let symbol = underlying(
+                &position.market_slug,
+                market.and_then(|m| m.symbol.as_deref()),
+            );
+            let minutes = market
+                .and_then(|m| m.end_time)
+                .map(|end| (end - now).num_minutes().max(0));
+            if minutes.is_some_and(|m| m < thresholds.near_term_minutes) {
+                near_term_exposure += exposure;
+            }
+            let domain = position.domain.to_string();
+            let bucket = settlement_bucket(minutes);
+            let cell = cells
+                .entry((symbol.clone(), domain.clone(), bucket))
+                .or_insert_with(|| HeatmapCell {
+                    symbol,
+                    domain,
+                    bucket: bucket.to_string(),
+                    exposure: Decimal::ZERO,
+                    positions: 0,
+                });
+            cell.exposure += exposure;
+            cell.positions += 1;
+        }
+
+        let cells: Vec<HeatmapCell> = cells.into_values().collect();
+        let total_exposure: Decimal = cells.iter().map(|c| c.exposure).sum();
+        let share_of = |exposure: Decimal| {
+            if total_exposure.is_zero() {
+                Decimal::ZERO
+            } else {
+                (exposure / total_exposure).round_dp(4)
+            }
+        };
+        let shares = |key: fn(&HeatmapCell) -> &str| -> Vec<ExposureShare> {
+            let mut totals: BTreeMap<&str, Decimal> = BTreeMap::new();
+            for cell in &cells {
+                *totals.entry(key(cell)).or_default() += cell.exposure;
+            }
+            let mut out: Vec<ExposureShare> = totals
+                .into_iter()
+                .map(|(key, exposure)| ExposureShare {
+                    key: key.to_string(),
+                    exposure,
+                    share: share_of(exposure),
+                    concentrated: share_of(exposure) > thresholds.concentration_pct,
+                })
+                .collect();
+            out.sort_by(|a, b| b.exposure.cmp(&a.exposure));
+            out
+        };
+        let by_symbol = shares(|c| c.symbol.as_str());
+        let by_domain = shares(|c| c.domain.as_str());
+        let mut by_settlement = shares(|c| c.bucket.as_str());
+        let bucket_order = |key: &str| {
+            SETTLEMENT_BUCKETS
+                .iter()
+                .position(|(_, label)| *label == key)
+                .unwrap_or(SETTLEMENT_BUCKETS.len())
+        };
+        by_settlement.sort_by_key(|s| bucket_order(&s.key));
+
+        let mut alerts = Vec::new();
+        let near_term_share = share_of(near_term_exposure);
+        if near_term_exposure > Decimal::ZERO && near_term_share > thresholds.near_term_pct {
+            alerts.push(format!(
+                "{}% of exposure (${}) settles within {}m",
+                (near_term_share * Decimal::ONE_HUNDRED).round_dp(1),
+                near_term_exposure.round_dp(2),
+                thresholds.near_term_minutes
+            ));
+        }
+        for (dimension, list) in [
+            ("symbol", &by_symbol),
+            ("domain", &by_domain),
+            ("settlement bucket", &by_settlement),
+        ] {
+            for share in list.iter().filter(|s| s.concentrated) {
+                alerts.push(format!(
+                    "{} {} holds {}% of exposure (${})",
+                    dimension,
+                    share.key,
+                    (share.share * Decimal::ONE_HUNDRED).round_dp(1),
+                    share.exposure.round_dp(2)
+                ));
+            }
+        }
+
+        let mut buckets: Vec<String> = SETTLEMENT_BUCKETS
+            .iter()
+            .map(|(_, label)| label.to_string())
+            .collect();
+        buckets.push(UNKNOWN_BUCKET.to_string());
+
+        Self {
+            generated_at: now,
+            total_exposure,
+            thresholds,
+            buckets,
+            cells,
+            by_symbol,
+            by_domain,
+            by_settlement,
+            near_term_exposure,
+            alerts,
+        }
+    }
+
+    /// Exposure of one symbol in one settlement bucket, across domains
+    pub fn exposure_at(&self, symbol: &str, bucket: &str) -> Decimal {
+        self.cells
+            .iter()
+            .filter(|c| c.symbol == symbol && c.bucket == bucket)
+            .map(|c| c.exposure)
+            .sum()
+    }
+}
+
+/// Look up settlement times and symbols for `slugs`
+pub async fn load_market_settlements(
+    pool: &PgPool,
+    slugs: &[String],
+) -> Result<HashMap<String, MarketSettlement>> {
+    let rows: Vec<(String, Option<DateTime<Utc>>, Option<String>)> = sqlx::query_as(
+        r#"
+        SELECT s.slug, COALESCE(m.end_time, r.end_time), m.symbol
+        FROM unnest($1::text[]) AS s(slug)
+        LEFT JOIN pm_market_metadata m ON m.market_slug = s.slug
+        LEFT JOIN rounds r ON r.slug = s.slug
+        "#,
+    )
+    .bind(slugs)
+    .fetch_all(pool)
+    .await?;
+
+    Ok(rows
+        .into_iter()
+        .map(|(slug, end_time, symbol)| (slug, MarketSettlement { end_time, symbol }))
+        .collect())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::domain::Side;
+    use crate::platform::Domain;
+    use chrono::Duration;
+    use rust_decimal_macros::dec;
+
+    fn position(domain: Domain, slug: &str, shares: u64, price: Decimal) -> Position {
+        Position {
+            position_id: format!("pos-{}", slug),
+            agent_id: "agent".into(),
+            domain,
+            market_slug: slug.into(),
+            token_id: format!("{}-up", slug),
+            side: Side::Up,
+            shares,
+            entry_price: price,
+            current_price: Some(price),
+            is_hedged: false,
+            entry_time: Utc::now(),
+            updated_at: Utc::now(),
+            metadata: HashMap::new(),
+        }
+    }
+
+    #[test]
+    fn test_heatmap_flags_near_term_concentration() {
+        let now = Utc::now();
+        let positions = vec![
+            position(Domain::Crypto, "btc-updown-5m-1", 200, dec!(0.50)),
+            position(Domain::Crypto, "eth-updown-15m-1", 40, dec!(0.50)),
+            position(Domain::Sports, "nba-lal-bos-2026-10-16", 50, dec!(0.40)),
+        ];
+        let markets = HashMap::from([
+            (
+                "btc-updown-5m-1".to_string(),
+                MarketSettlement {
+                    end_time: Some(now + Duration::seconds(150)),
+                    symbol: Some("BTCUSDT".into()),
+                },
+            ),
+            (
+                "eth-updown-15m-1".to_string(),
+                MarketSettlement {
+                    end_time: Some(now + Duration::minutes(12)),
+                    symbol: Some("ETHUSDT".into()),
+                },
+            ),
+        ]);
+
+        let heatmap =
+            ExposureHeatmap::build(&positions, &markets, HeatmapThresholds::default(), now);
+
+        assert_eq!(heatmap.total_exposure, dec!(140));
+        assert_eq!(heatmap.near_term_exposure, dec!(100));
+        assert_eq!(heatmap.exposure_at("BTC", "<3m"), dec!(100));
+        assert_eq!(heatmap.exposure_at("ETH", "3-15m"), dec!(20));
+        assert_eq!(heatmap.exposure_at("NBA", UNKNOWN_BUCKET), dec!(20));
+        assert_eq!(heatmap.by_symbol[0].key, "BTC");
+        assert!(heatmap.by_symbol[0].concentrated);
+        assert_eq!(heatmap.by_settlement[0].key, "<3m");
+        assert!(heatmap.alerts[0].contains("settles within 3m"));
+    }
+}
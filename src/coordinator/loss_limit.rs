@@ -0,0 +1,504 @@
+//! Intraday loss limit with automatic flat-and-halt
+//!
+//! Tracks realized + unrealized daily PnL per agent and account-wide. When a
+//! scope breaches its limit the coordinator blocks new BUY intents for it,
+//! sequences an exit of its open positions through the unwind planner and
+//! records the halt in `daily_metrics`. The halt lasts until the UTC day
+//! rolls over unless an operator re-enables the scope earlier (CLI or API);
+//! a re-enabled scope gets a fresh loss budget measured from its PnL at that
+//! moment, so it does not immediately trip again. That baseline is persisted
+//! with the halts, so it survives a restart later the same day.
+
+use chrono::{DateTime, NaiveDate, Utc};
+use rust_decimal::Decimal;
+use serde::{Deserialize, Serialize};
+use sqlx::types::Json;
+use sqlx::PgPool;
+use std::collections::HashMap;
+
+use super::state::GlobalState;
+use crate::coordination::{BookLevel, UnwindBook, UnwindPosition};
+use crate::error::Result;
+use crate::platform::Position;
+
+/// Scope name of the account-wide limit
+pub const GLOBAL_SCOPE: &str = "global";
+
+/// Loss limit configuration (USD amounts are positive loss sizes)
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+#[serde(default)]
+pub struct LossLimitConfig {
+    pub enabled: bool,
+    /// Account-wide limit on realized + unrealized daily PnL
+    pub global_limit_usd: Option<Decimal>,
+    /// Default per-agent limit
+    pub agent_limit_usd: Option<Decimal>,
+    /// Per-agent overrides of `agent_limit_usd`
+    pub agent_limits_usd: HashMap<String, Decimal>,
+}
+
+impl LossLimitConfig {
+    pub fn limit_for(&self, scope: &str) -> Option<Decimal> {
+        if scope == GLOBAL_SCOPE {
+            return self.global_limit_usd;
+        }
+        self.agent_limits_usd
+            .get(scope)
+            .copied()
+            .or(self.agent_limit_usd)
+    }
+}
+
+/// A scope that crossed its loss limit
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct LossLimitBreach {
+    /// `global` or an agent id
+    pub scope: String,
+    /// Realized + unrealized PnL since the day start (or last re-enable)
+    pub pnl: Decimal,
+    pub limit: Decimal,
+    pub halted_at: DateTime<Utc>,
+}
+
+impl LossLimitBreach {
+    pub fn is_global(&self) -> bool {
+        self.scope == GLOBAL_SCOPE
+    }
+
+    pub fn reason(&self) -> String {
+        format!(
+            "intraday loss limit breached: {} pnl {} <= -{}",
+            self.scope,
+            self.pnl.round_dp(2),
+            self.limit
+        )
+    }
+}
+
+/// Realized + unrealized daily PnL of a scope
+pub fn scope_pnl(state: &GlobalState, scope: &str) -> Option<Decimal> {
+    if scope == GLOBAL_SCOPE {
+        return Some(state.daily_pnl + state.total_unrealized_pnl());
+    }
+    state
+        .agents
+        .get(scope)
+        .map(|agent| agent.daily_pnl + agent.unrealized_pnl)
+}
+
+/// In-memory halt book for the current UTC day
+#[derive(Debug, Default)]
+pub struct LossLimitTracker {
+    date: Option<NaiveDate>,
+    halted: HashMap<String, LossLimitBreach>,
+    /// PnL at the last operator re-enable; losses are measured from here
+    baselines: HashMap<String, Decimal>,
+}
+
+impl LossLimitTracker {
+    /// Start a new day: halts and re-enable baselines expire. Returns the
+    /// halts that expired.
+    pub fn roll_day(&mut self, today: NaiveDate) -> Vec<LossLimitBreach> {
+        if self.date == Some(today) {
+            return Vec::new();
+        }
+        self.date = Some(today);
+        self.baselines.clear();
+        self.halted.drain().map(|(_, halt)| halt).collect()
+    }
+
+    /// Check every configured scope and return the ones that newly breached.
+    /// Breached scopes are recorded as halted.
+    pub fn evaluate(
+        &mut self,
+        config: &LossLimitConfig,
+        state: &GlobalState,
+        today: NaiveDate,
+    ) -> Vec<LossLimitBreach> {
+        self.roll_day(today);
+        if !config.enabled {
+            return Vec::new();
+        }
+
+        let scopes = std::iter::once(GLOBAL_SCOPE.to_string()).chain(state.agents.keys().cloned());
+        let mut breaches = Vec::new();
+        for scope in scopes {
+            if self.halted.contains_key(&scope) {
+                continue;
+            }
+            let (Some(limit), Some(pnl)) = (config.limit_for(&scope), scope_pnl(state, &scope))
+            else {
+                continue;
+            };
+            if limit <= Decimal::ZERO {
+                continue;
+            }
+            let baseline = self.baselines.get(&scope).copied().unwrap_or(Decimal::ZERO);
+            let pnl = pnl - baseline;
+            if pnl <= -limit {
+                let breach = LossLimitBreach {
+                    scope: scope.clone(),
+                    pnl,
+                    limit,
+                    halted_at: Utc::now(),
+                };
+                self.halted.insert(scope, breach.clone());
+                breaches.push(breach);
+            }
+        }
+        breaches
+    }
+
+    /// Whether today's halt covers `agent_id` (directly or account-wide)
+    pub fn blocks(&self, agent_id: &str) -> Option<&LossLimitBreach> {
+        self.halted
+            .get(GLOBAL_SCOPE)
+            .or_else(|| self.halted.get(agent_id))
+    }
+
+    pub fn is_halted(&self, scope: &str) -> bool {
+        self.halted.contains_key(scope)
+    }
+
+    pub fn halts(&self) -> Vec<LossLimitBreach> {
+        let mut halts: Vec<_> = self.halted.values().cloned().collect();
+        halts.sort_by(|a, b| a.scope.cmp(&b.scope));
+        halts
+    }
+
+    /// Restore halts and re-enable baselines persisted earlier today (e.g.
+    /// after a restart)
+    pub fn restore(
+        &mut self,
+        today: NaiveDate,
+        halts: Vec<LossLimitBreach>,
+        baselines: HashMap<String, Decimal>,
+    ) {
+        self.roll_day(today);
+        for halt in halts {
+            self.halted.insert(halt.scope.clone(), halt);
+        }
+        self.baselines.extend(baselines);
+    }
+
+    /// Lift the halt of `scope`; its loss budget restarts from `current_pnl`
+    pub fn reenable(&mut self, scope: &str, current_pnl: Option<Decimal>) -> bool {
+        let was_halted = self.halted.remove(scope).is_some();
+        if let Some(pnl) = current_pnl {
+            self.baselines.insert(scope.to_string(), pnl);
+        }
+        was_halted
+    }
+}
+
+/// Unwind input for a coordinator position: the exchange trait only exposes
+/// top of book, so the best bid is assumed deep enough for the whole position
+/// and the planner's slippage floor and chunking bound the exit.
+pub fn unwind_input(
+    position: &Position,
+    best_bid: Option<Decimal>,
+    best_ask: Option<Decimal>,
+) -> (UnwindPosition, UnwindBook) {
+    let bids = best_bid
+        .map(|price| {
+            vec![BookLevel {
+                price,
+                size: Decimal::from(position.shares),
+            }]
+        })
+        .unwrap_or_default();
+    (
+        UnwindPosition {
+            position_id: None,
+            symbol: position.market_slug.clone(),
+            token_id: position.token_id.clone(),
+            market_side: position.side,
+            shares: position.shares,
+            avg_entry_price: position.entry_price,
+        },
+        UnwindBook::new(bids, best_ask),
+    )
+}
+
+async fn ensure_daily_metrics_row(pool: &PgPool, date: NaiveDate) -> Result<()> {
+    sqlx::query("INSERT INTO daily_metrics (date) VALUES ($1) ON CONFLICT (date) DO NOTHING")
+        .bind(date)
+        .execute(pool)
+        .await?;
+    Ok(())
+}
+
+/// Record a halt in today's `daily_metrics` row. The account-wide halt uses
+/// `loss_limit_halted`; `halted` belongs to the engine circuit breaker.
+pub async fn persist_halt(pool: &PgPool, date: NaiveDate, breach: &LossLimitBreach) -> Result<()> {
+    ensure_daily_metrics_row(pool, date).await?;
+    if breach.is_global() {
+        sqlx::query(
+            r#"
+            UPDATE daily_metrics
+            SET loss_limit_halted = TRUE, loss_limit_reason = $2, halted_at = $3,
+                updated_at = NOW()
+            WHERE date = $1
+            "#,
+        )
+        .bind(date)
+        .bind(breach.reason())
+        .bind(breach.halted_at)
+        .execute(pool)
+        .await?;
+    } else {
+        sqlx::query(
+            r#"
+            UPDATE daily_metrics
+            SET halted_strategies = array_append(
+                    array_remove(halted_strategies, $2), $2),
+                halted_at = $3,
+                updated_at = NOW()
+            WHERE date = $1
+            "#,
+        )
+        .bind(date)
+        .bind(&breach.scope)
+        .bind(breach.halted_at)
+        .execute(pool)
+        .await?;
+    }
+    Ok(())
+}
+
+/// Halt state of one day as recorded in `daily_metrics`
+#[derive(Debug, Clone, Default)]
+pub struct PersistedHalts {
+    /// Halted scopes (`global` first)
+    pub scopes: Vec<String>,
+    /// Reason of the account-wide halt
+    pub halt_reason: Option<String>,
+    pub halted_at: Option<DateTime<Utc>>,
+    /// Last operator re-enable
+    pub reenabled_by: Option<String>,
+    pub reenabled_at: Option<DateTime<Utc>>,
+    /// Scope PnL at its last re-enable
+    pub baselines: HashMap<String, Decimal>,
+}
+
+impl PersistedHalts {
+    /// Whether an operator re-enabled `halt` after it was triggered
+    pub fn lifts(&self, halt: &LossLimitBreach) -> bool {
+        !self.scopes.contains(&halt.scope)
+            && self.reenabled_at.is_some_and(|at| at > halt.halted_at)
+    }
+}
+
+/// Halt state in `daily_metrics` for `date`
+pub async fn load_halts(pool: &PgPool, date: NaiveDate) -> Result<PersistedHalts> {
+    #[allow(clippy::type_complexity)]
+    let row: Option<(
+        bool,
+        Option<String>,
+        Vec<String>,
+        Option<DateTime<Utc>>,
+        Option<String>,
+        Option<DateTime<Utc>>,
+        Json<HashMap<String, Decimal>>,
+    )> = sqlx::query_as(
+        r#"
+        SELECT loss_limit_halted, loss_limit_reason, halted_strategies, halted_at,
+               reenabled_by, reenabled_at, reenable_baselines
+        FROM daily_metrics
+        WHERE date = $1
+        "#,
+    )
+    .bind(date)
+    .fetch_optional(pool)
+    .await?;
+
+    let Some((
+        halted,
+        halt_reason,
+        strategies,
+        halted_at,
+        reenabled_by,
+        reenabled_at,
+        Json(baselines),
+    )) = row
+    else {
+        return Ok(PersistedHalts::default());
+    };
+    let mut scopes = Vec::with_capacity(strategies.len() + 1);
+    if halted {
+        scopes.push(GLOBAL_SCOPE.to_string());
+    }
+    scopes.extend(strategies);
+    Ok(PersistedHalts {
+        scopes,
+        halt_reason,
+        halted_at,
+        reenabled_by,
+        reenabled_at,
+        baselines,
+    })
+}
+
+/// Record the PnL `scope` was re-enabled at, so a restart later that day
+/// keeps measuring its losses from there
+pub async fn persist_baseline(
+    pool: &PgPool,
+    date: NaiveDate,
+    scope: &str,
+    baseline: Decimal,
+) -> Result<()> {
+    ensure_daily_metrics_row(pool, date).await?;
+    sqlx::query(
+        r#"
+        UPDATE daily_metrics
+        SET reenable_baselines = jsonb_set(reenable_baselines, ARRAY[$2], to_jsonb($3::text)),
+            updated_at = NOW()
+        WHERE date = $1
+        "#,
+    )
+    .bind(date)
+    .bind(scope)
+    .bind(baseline.to_string())
+    .execute(pool)
+    .await?;
+    Ok(())
+}
+
+/// Operator re-enable: clear the halt of `scope` for `date`. Returns whether
+/// the scope was halted.
+pub async fn clear_halt(
+    pool: &PgPool,
+    date: NaiveDate,
+    scope: &str,
+    operator: &str,
+) -> Result<bool> {
+    let result = if scope == GLOBAL_SCOPE {
+        sqlx::query(
+            r#"
+            UPDATE daily_metrics
+            SET loss_limit_halted = FALSE, loss_limit_reason = NULL,
+                reenabled_by = $2, reenabled_at = NOW(), updated_at = NOW()
+            WHERE date = $1 AND loss_limit_halted
+            "#,
+        )
+        .bind(date)
+        .bind(operator)
+        .execute(pool)
+        .await?
+    } else {
+        sqlx::query(
+            r#"
+            UPDATE daily_metrics
+            SET halted_strategies = array_remove(halted_strategies, $3),
+                reenabled_by = $2, reenabled_at = NOW(), updated_at = NOW()
+            WHERE date = $1 AND $3 = ANY(halted_strategies)
+            "#,
+        )
+        .bind(date)
+        .bind(operator)
+        .bind(scope)
+        .execute(pool)
+        .await?
+    };
+    Ok(result.rows_affected() > 0)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::coordinator::state::AgentSnapshot;
+    use crate::platform::{AgentStatus, Domain};
+    use rust_decimal_macros::dec;
+
+    fn agent(id: &str, realized: Decimal, unrealized: Decimal) -> AgentSnapshot {
+        AgentSnapshot {
+            agent_id: id.to_string(),
+            name: id.to_string(),
+            domain: Domain::Crypto,
+            status: AgentStatus::Running,
+            position_count: 1,
+            exposure: dec!(100),
+            daily_pnl: realized,
+            unrealized_pnl: unrealized,
+            metrics: HashMap::new(),
+            last_heartbeat: Utc::now(),
+            error_message: None,
+        }
+    }
+
+    #[test]
+    fn breaches_once_per_day_and_rearms_after_reenable() {
+        let config = LossLimitConfig {
+            enabled: true,
+            global_limit_usd: Some(dec!(100)),
+            agent_limit_usd: Some(dec!(50)),
+            agent_limits_usd: HashMap::from([("sports".to_string(), dec!(80))]),
+        };
+        let today = Utc::now().date_naive();
+        let mut state = GlobalState::new();
+        // crypto: -30 realized, -25 unrealized -> breaches the 50 default
+        state
+            .agents
+            .insert("crypto".into(), agent("crypto", dec!(-30), dec!(-25)));
+        // sports: -60 total stays under its 80 override
+        state
+            .agents
+            .insert("sports".into(), agent("sports", dec!(-60), dec!(0)));
+        state.daily_pnl = dec!(-90);
+
+        let mut tracker = LossLimitTracker::default();
+        let breaches = tracker.evaluate(&config, &state, today);
+        assert_eq!(breaches.len(), 1);
+        assert_eq!(breaches[0].scope, "crypto");
+        assert_eq!(breaches[0].pnl, dec!(-55));
+        assert!(tracker.blocks("crypto").is_some());
+        assert!(tracker.blocks("sports").is_none());
+
+        // Already halted: no repeat breach
+        assert!(tracker.evaluate(&config, &state, today).is_empty());
+
+        // Re-enable restarts the budget from the current -55
+        assert!(tracker.reenable("crypto", scope_pnl(&state, "crypto")));
+        assert!(tracker.evaluate(&config, &state, today).is_empty());
+        state.agents.get_mut("crypto").unwrap().unrealized_pnl = dec!(-80);
+        assert_eq!(tracker.evaluate(&config, &state, today).len(), 1);
+
+        // Unrealized losses count toward the account-wide limit
+        state.daily_pnl = dec!(-90);
+        state.portfolio.unrealized_pnl = dec!(-20);
+        let breaches = tracker.evaluate(&config, &state, today);
+        assert_eq!(breaches[0].scope, GLOBAL_SCOPE);
+        assert!(tracker.blocks("sports").is_some());
+
+        // A new day clears every halt
+        let tomorrow = today.succ_opt().unwrap();
+        state.portfolio.unrealized_pnl = Decimal::ZERO;
+        state.daily_pnl = Decimal::ZERO;
+        state.agents.clear();
+        assert!(tracker.evaluate(&config, &state, tomorrow).is_empty());
+        assert!(tracker.halts().is_empty());
+    }
+
+    #[test]
+    fn restored_baseline_keeps_reenabled_scope_running() {
+        let config = LossLimitConfig {
+            enabled: true,
+            agent_limit_usd: Some(dec!(50)),
+            ..LossLimitConfig::default()
+        };
+        let today = Utc::now().date_naive();
+        let mut state = GlobalState::new();
+        state
+            .agents
+            .insert("crypto".into(), agent("crypto", dec!(-60), dec!(0)));
+
+        // Re-enabled at -55 before the restart: only -5 counts against the limit
+        let mut tracker = LossLimitTracker::default();
+        tracker.restore(
+            today,
+            Vec::new(),
+            HashMap::from([("crypto".to_string(), dec!(-55))]),
+        );
+        assert!(tracker.evaluate(&config, &state, today).is_empty());
+    }
+}
@@ -4,13 +4,21 @@
 //! Provides a single order submission chokepoint with risk checks,
 //! cross-agent position awareness, and dynamic pause/resume control.
 
+pub mod approval;
 pub mod bootstrap;
+pub mod budget;
 pub mod command;
 pub mod config;
 pub mod coordinator;
+pub mod heatmap;
+pub mod loss_limit;
 pub mod state;
 
+pub use approval::{
+    ApprovalAction, ApprovalBook, ApprovalDecision, ApprovalSnapshot, PendingApproval,
+};
 pub use bootstrap::{start_platform, PlatformBootstrapConfig, PlatformStartControl};
+pub use budget::{AgentBudget, AgentBudgetConfig, BudgetLedger};
 pub use command::{
     AgentHealthResponse, AllocatorLedgerSnapshot, CoordinatorCommand, CoordinatorControlCommand,
     DomainIngressSnapshot, GovernanceAgentSnapshot, GovernancePolicyHistoryEntry,
@@ -18,4 +26,6 @@ pub use command::{
 };
 pub use config::CoordinatorConfig;
 pub use coordinator::{Coordinator, CoordinatorHandle};
+pub use heatmap::{ExposureHeatmap, HeatmapThresholds};
+pub use loss_limit::{LossLimitBreach, LossLimitConfig, LossLimitTracker, GLOBAL_SCOPE};
 pub use state::{AgentSnapshot, GlobalState, QueueStatsSnapshot};
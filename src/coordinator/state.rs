@@ -5,6 +5,7 @@ use rust_decimal::Decimal;
 use serde::{Deserialize, Serialize};
 use std::collections::HashMap;
 
+use crate::agents::openclaw::conflict::AgentConflict;
 use crate::platform::{
     AgentStatus, AggregatedPosition, CircuitBreakerEvent, Domain, PlatformRiskState, Position,
     QueueStats,
@@ -35,6 +36,10 @@ pub struct QueueStatsSnapshot {
     pub enqueued_total: u64,
     pub dequeued_total: u64,
     pub expired_total: u64,
+    pub restored_total: u64,
+    /// Age of the oldest waiting intent (ms)
+    pub oldest_age_ms: i64,
+    pub avg_age_ms: i64,
 }
 
 impl From<QueueStats> for QueueStatsSnapshot {
@@ -45,6 +50,9 @@ impl From<QueueStats> for QueueStatsSnapshot {
             enqueued_total: qs.enqueued_total,
             dequeued_total: qs.dequeued_total,
             expired_total: qs.expired_total,
+            restored_total: qs.restored_total,
+            oldest_age_ms: qs.oldest_age_ms,
+            avg_age_ms: qs.avg_age_ms,
         }
     }
 }
@@ -72,6 +80,10 @@ pub struct GlobalState {
     pub max_drawdown_limit: Option<Decimal>,
     /// Circuit breaker event history
     pub circuit_breaker_events: Vec<CircuitBreakerEvent>,
+    /// Agents currently holding opposing sides of the same market
+    pub position_conflicts: Vec<AgentConflict>,
+    /// Recent BUY intents that opposed another agent, with their resolution
+    pub intent_conflicts: Vec<AgentConflict>,
     /// Order queue statistics
     pub queue_stats: QueueStatsSnapshot,
     /// Total realized PnL across all agents
@@ -96,6 +108,8 @@ impl GlobalState {
             max_drawdown_observed: Decimal::ZERO,
             max_drawdown_limit: None,
             circuit_breaker_events: Vec::new(),
+            position_conflicts: Vec::new(),
+            intent_conflicts: Vec::new(),
             queue_stats: QueueStatsSnapshot::default(),
             total_realized_pnl: Decimal::ZERO,
             started_at: now,
@@ -33,7 +33,7 @@ impl std::fmt::Display for Side {
     }
 }
 
-/// A trading round (15-minute window)
+/// A trading round (5m / 15m / 1h / 1d window)
 #[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct Round {
     pub id: Option<i32>,
@@ -74,6 +74,16 @@ impl Round {
     pub fn minutes_elapsed(&self) -> i64 {
         (Utc::now() - self.start_time).num_minutes().max(0)
     }
+
+    /// Seconds elapsed since round start
+    pub fn seconds_elapsed(&self) -> i64 {
+        (Utc::now() - self.start_time).num_seconds().max(0)
+    }
+
+    /// Round length in seconds
+    pub fn duration_secs(&self) -> i64 {
+        (self.end_time - self.start_time).num_seconds().max(0)
+    }
 }
 
 /// Best bid/ask quote for one side
@@ -183,6 +193,13 @@ pub struct DumpSignal {
     pub timestamp: DateTime<Utc>,
     /// Current spread in bps
     pub spread_bps: u32,
+    /// Drop threshold in effect when the signal fired
+    #[serde(default)]
+    pub threshold_pct: Decimal,
+    /// Realized volatility over the rolling-high window, when the threshold
+    /// was volatility-scaled
+    #[serde(default)]
+    pub realized_vol: Option<Decimal>,
 }
 
 impl DumpSignal {
@@ -1,4 +1,5 @@
 use super::StrategyState;
+use crate::error::{PloyError, Result};
 use chrono::{DateTime, Utc};
 use rust_decimal::Decimal;
 use serde::{Deserialize, Serialize};
@@ -40,6 +41,51 @@ pub enum TimeInForce {
     FOK,
     /// Immediate Or Cancel
     IOC,
+    /// Good Till Date (requires `expires_at`)
+    GTD,
+}
+
+/// How an order meets the book
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum ExecutionPreference {
+    /// Submit at the limit price, crossing the spread if it is marketable
+    #[default]
+    Taker,
+    /// Rest post-only inside the spread first; the remainder crosses after a max wait
+    MakerFirst,
+}
+
+impl TimeInForce {
+    /// Whether an unfilled remainder rests on the book.
+    pub fn is_resting(&self) -> bool {
+        matches!(self, TimeInForce::GTC | TimeInForce::GTD)
+    }
+
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            TimeInForce::GTC => "GTC",
+            TimeInForce::FOK => "FOK",
+            TimeInForce::IOC => "IOC",
+            TimeInForce::GTD => "GTD",
+        }
+    }
+}
+
+impl std::str::FromStr for TimeInForce {
+    type Err = PloyError;
+
+    fn from_str(s: &str) -> Result<Self> {
+        match s.trim().to_ascii_uppercase().as_str() {
+            "GTC" => Ok(TimeInForce::GTC),
+            "FOK" => Ok(TimeInForce::FOK),
+            "IOC" | "FAK" => Ok(TimeInForce::IOC),
+            "GTD" => Ok(TimeInForce::GTD),
+            other => Err(PloyError::Validation(format!(
+                "unknown time in force: {other}"
+            ))),
+        }
+    }
 }
 
 /// Order status
@@ -97,6 +143,15 @@ pub struct OrderRequest {
     pub limit_price: Decimal,
     pub order_type: OrderType,
     pub time_in_force: TimeInForce,
+    /// Expiry for GTD orders
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub expires_at: Option<DateTime<Utc>>,
+    /// Reject instead of taking liquidity if the order would cross the book
+    #[serde(default)]
+    pub post_only: bool,
+    /// Overrides the executor's configured preference for this order
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub execution_preference: Option<ExecutionPreference>,
 }
 
 impl OrderRequest {
@@ -111,6 +166,9 @@ impl OrderRequest {
             limit_price: price,
             order_type: OrderType::Limit,
             time_in_force: TimeInForce::GTC,
+            expires_at: None,
+            post_only: false,
+            execution_preference: None,
         }
     }
 
@@ -125,6 +183,61 @@ impl OrderRequest {
             limit_price: price,
             order_type: OrderType::Limit,
             time_in_force: TimeInForce::GTC,
+            expires_at: None,
+            post_only: false,
+            execution_preference: None,
+        }
+    }
+
+    pub fn with_time_in_force(mut self, time_in_force: TimeInForce) -> Self {
+        self.time_in_force = time_in_force;
+        self
+    }
+
+    /// Rest on the book until `expires_at` (GTD).
+    pub fn good_till(mut self, expires_at: DateTime<Utc>) -> Self {
+        self.time_in_force = TimeInForce::GTD;
+        self.expires_at = Some(expires_at);
+        self
+    }
+
+    pub fn post_only(mut self) -> Self {
+        self.post_only = true;
+        self
+    }
+
+    pub fn with_execution_preference(mut self, preference: ExecutionPreference) -> Self {
+        self.execution_preference = Some(preference);
+        self
+    }
+
+    /// Reject flag combinations the venues cannot honour.
+    pub fn validate(&self) -> Result<()> {
+        if self.post_only {
+            if self.order_type != OrderType::Limit {
+                return Err(PloyError::Validation(
+                    "post-only orders must be limit orders".to_string(),
+                ));
+            }
+            if !self.time_in_force.is_resting() {
+                return Err(PloyError::Validation(format!(
+                    "post-only orders must rest on the book, got {}",
+                    self.time_in_force.as_str()
+                )));
+            }
+        }
+        match (self.time_in_force, self.expires_at) {
+            (TimeInForce::GTD, None) => Err(PloyError::Validation(
+                "GTD orders require an expiry".to_string(),
+            )),
+            (TimeInForce::GTD, Some(at)) if at <= Utc::now() => Err(PloyError::Validation(
+                format!("GTD expiry {at} is in the past"),
+            )),
+            (TimeInForce::GTD, Some(_)) | (_, None) => Ok(()),
+            (tif, Some(_)) => Err(PloyError::Validation(format!(
+                "expiry is only valid for GTD orders, got {}",
+                tif.as_str()
+            ))),
         }
     }
 }
@@ -323,6 +436,44 @@ mod tests {
         assert_eq!(order.fill_pct(), dec!(100));
     }
 
+    #[test]
+    fn test_order_request_validate_combinations() {
+        let buy = || OrderRequest::buy_limit("token".to_string(), Side::Up, 10, dec!(0.45));
+
+        assert!(buy().validate().is_ok());
+        assert!(buy().post_only().validate().is_ok());
+        assert!(buy()
+            .good_till(Utc::now() + chrono::Duration::minutes(5))
+            .post_only()
+            .validate()
+            .is_ok());
+
+        // Post-only cannot take liquidity
+        assert!(buy()
+            .with_time_in_force(TimeInForce::FOK)
+            .post_only()
+            .validate()
+            .is_err());
+        let mut market = buy().post_only();
+        market.order_type = OrderType::Market;
+        assert!(market.validate().is_err());
+
+        // GTD needs a future expiry, and only GTD carries one
+        assert!(buy()
+            .with_time_in_force(TimeInForce::GTD)
+            .validate()
+            .is_err());
+        assert!(buy()
+            .good_till(Utc::now() - chrono::Duration::seconds(1))
+            .validate()
+            .is_err());
+        let mut ioc = buy().with_time_in_force(TimeInForce::IOC);
+        ioc.expires_at = Some(Utc::now() + chrono::Duration::minutes(5));
+        assert!(ioc.validate().is_err());
+
+        assert_eq!("fak".parse::<TimeInForce>().unwrap(), TimeInForce::IOC);
+    }
+
     #[test]
     fn test_cycle_expected_pnl() {
         let mut cycle = Cycle::new(1, StrategyState::Leg1Filled);
@@ -88,6 +88,9 @@ pub enum PloyError {
     #[error("Validation failed: {0}")]
     Validation(String),
 
+    #[error("Order violates market rules: {0}")]
+    PriceRule(#[from] PriceError),
+
     // Crypto/signing errors
     #[error("Wallet error: {0}")]
     Wallet(String),
@@ -145,6 +148,32 @@ pub enum OrderError {
     MaxRetriesExceeded { attempts: u8 },
 }
 
+/// Tick-size and minimum-size violations caught before an order is signed
+#[derive(Error, Debug, Clone, PartialEq)]
+pub enum PriceError {
+    #[error("price {price} is not a multiple of tick size {tick_size}")]
+    OffTick {
+        price: rust_decimal::Decimal,
+        tick_size: rust_decimal::Decimal,
+    },
+
+    #[error("price {price} outside [{min}, {max}]")]
+    OutOfRange {
+        price: rust_decimal::Decimal,
+        min: rust_decimal::Decimal,
+        max: rust_decimal::Decimal,
+    },
+
+    #[error("size {shares} below market minimum {min_size}")]
+    BelowMinSize {
+        shares: u64,
+        min_size: rust_decimal::Decimal,
+    },
+
+    #[error("invalid tick size {0}")]
+    InvalidTickSize(rust_decimal::Decimal),
+}
+
 /// Specific error types for risk management
 #[derive(Error, Debug, Clone)]
 pub enum RiskError {
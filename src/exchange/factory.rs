@@ -4,8 +4,9 @@ use crate::adapters::{KalshiClient, PolymarketClient};
 use crate::config::AppConfig;
 use crate::error::{PloyError, Result};
 use crate::signing::Wallet;
+use tracing::info;
 
-use super::{parse_exchange_kind, ExchangeClient, ExchangeKind};
+use super::{parse_exchange_kind, ExchangeClient, ExchangeKind, LatencyInjectedExchange};
 
 fn kalshi_experimental_enabled() -> bool {
     std::env::var("PLOY_ENABLE_KALSHI_EXPERIMENTAL")
@@ -47,8 +48,9 @@ pub async fn build_exchange_client_for(
                 .unwrap_or(&app_config.market.rest_url);
 
             if dry_run {
-                let client = PolymarketClient::new(rest_url, true)?;
-                Ok(Arc::new(client))
+                let client: Arc<dyn ExchangeClient> =
+                    Arc::new(PolymarketClient::new(rest_url, true)?);
+                Ok(with_dry_run_latency(client, app_config))
             } else {
                 let wallet = Wallet::from_env(crate::adapters::polymarket_clob::POLYGON_CHAIN_ID)?;
                 let funder = std::env::var("POLYMARKET_FUNDER").ok();
@@ -95,9 +97,36 @@ pub async fn build_exchange_client_for(
                     .or_else(|| std::env::var("KALSHI_ACCESS_SECRET").ok());
             }
 
-            let client = KalshiClient::new(Some(base_url), api_key, api_secret, dry_run)?;
-            Ok(Arc::new(client))
+            let client: Arc<dyn ExchangeClient> = Arc::new(KalshiClient::new(
+                Some(base_url),
+                api_key,
+                api_secret,
+                dry_run,
+            )?);
+            if dry_run {
+                Ok(with_dry_run_latency(client, app_config))
+            } else {
+                Ok(client)
+            }
+        }
+    }
+}
+
+/// Wrap a dry-run client with simulated ack / quote latency when configured.
+fn with_dry_run_latency(
+    client: Arc<dyn ExchangeClient>,
+    app_config: &AppConfig,
+) -> Arc<dyn ExchangeClient> {
+    match app_config.dry_run.latency.injector() {
+        Some(injector) => {
+            info!(
+                quote = ?injector.config().quote,
+                order_ack = ?injector.config().order_ack,
+                "dry-run latency injection enabled"
+            );
+            Arc::new(LatencyInjectedExchange::new(client, injector))
         }
+        None => client,
     }
 }
 
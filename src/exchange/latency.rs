@@ -0,0 +1,315 @@
+//! Simulated latency injection for dry-run / paper modes.
+//!
+//! Delays market-data quotes and order acknowledgements by samples from a
+//! configurable distribution so strategies can be stress-tested for latency
+//! sensitivity (e.g. a distant server) before deployment. Never applied to
+//! live order flow: [`LatencyInjectedExchange`] is only wired for dry-run
+//! clients.
+
+use async_trait::async_trait;
+use rand::rngs::StdRng;
+use rand::{Rng, SeedableRng};
+use rust_decimal::Decimal;
+use serde::{Deserialize, Serialize};
+use std::str::FromStr;
+use std::sync::{Arc, Mutex};
+use std::time::Duration;
+
+use crate::adapters::{
+    BalanceResponse, MarketResponse, MarketSummary, OrderResponse, PositionResponse, TradeResponse,
+};
+use crate::domain::{OrderRequest, OrderStatus};
+use crate::error::{PloyError, Result};
+
+use super::{ExchangeClient, ExchangeKind};
+
+/// Latency distribution (milliseconds)
+#[derive(Debug, Clone, Copy, PartialEq, Serialize, Deserialize)]
+#[serde(tag = "kind", rename_all = "snake_case")]
+pub enum LatencyDistribution {
+    /// Constant delay
+    Fixed { ms: f64 },
+    /// Gaussian delay, truncated at zero
+    Normal { mean_ms: f64, std_ms: f64 },
+    /// Heavy-tailed delay: `scale_ms · U^(-1/shape)` (minimum `scale_ms`)
+    Pareto { scale_ms: f64, shape: f64 },
+}
+
+impl LatencyDistribution {
+    /// Draw one delay in milliseconds
+    pub fn sample_ms<R: Rng + ?Sized>(&self, rng: &mut R) -> f64 {
+        let ms = match *self {
+            Self::Fixed { ms } => ms,
+            Self::Normal { mean_ms, std_ms } => {
+                // Box-Muller
+                let u1: f64 = rng.gen_range(f64::EPSILON..1.0);
+                let u2: f64 = rng.gen();
+                let z = (-2.0 * u1.ln()).sqrt() * (2.0 * std::f64::consts::PI * u2).cos();
+                mean_ms + std_ms * z
+            }
+            Self::Pareto { scale_ms, shape } => {
+                let u: f64 = rng.gen_range(f64::EPSILON..1.0);
+                scale_ms * u.powf(-1.0 / shape.max(f64::EPSILON))
+            }
+        };
+        ms.max(0.0)
+    }
+}
+
+impl FromStr for LatencyDistribution {
+    type Err = PloyError;
+
+    /// `fixed:150`, `normal:120,30`, `pareto:80,2.5`
+    fn from_str(raw: &str) -> Result<Self> {
+        let invalid = || {
+            PloyError::Validation(format!(
+                "invalid latency '{raw}'; expected fixed:MS | normal:MEAN,STD | pareto:SCALE,SHAPE"
+            ))
+        };
+        let (kind, args) = raw.trim().split_once(':').ok_or_else(invalid)?;
+        let nums = args
+            .split(',')
+            .map(|v| v.trim().parse::<f64>())
+            .collect::<std::result::Result<Vec<_>, _>>()
+            .map_err(|_| invalid())?;
+        if nums.iter().any(|v| !v.is_finite() || *v < 0.0) {
+            return Err(invalid());
+        }
+        match (kind.trim().to_ascii_lowercase().as_str(), nums.as_slice()) {
+            ("fixed", [ms]) => Ok(Self::Fixed { ms: *ms }),
+            ("normal", [mean_ms, std_ms]) => Ok(Self::Normal {
+                mean_ms: *mean_ms,
+                std_ms: *std_ms,
+            }),
+            ("pareto", [scale_ms, shape]) if *shape > 0.0 => Ok(Self::Pareto {
+                scale_ms: *scale_ms,
+                shape: *shape,
+            }),
+            _ => Err(invalid()),
+        }
+    }
+}
+
+/// Latency injection configuration (`[dry_run.latency]`)
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(default)]
+pub struct LatencyConfig {
+    pub enabled: bool,
+    /// Delay applied to market-data quotes (WebSocket updates, best-price polls)
+    pub quote: Option<LatencyDistribution>,
+    /// Delay applied to order submit / cancel / status acknowledgements
+    pub order_ack: Option<LatencyDistribution>,
+    /// Samples are capped at this many milliseconds
+    pub max_ms: u64,
+    /// Fixed RNG seed for reproducible runs
+    pub seed: Option<u64>,
+}
+
+impl Default for LatencyConfig {
+    fn default() -> Self {
+        Self {
+            enabled: false,
+            quote: None,
+            order_ack: None,
+            max_ms: 10_000,
+            seed: None,
+        }
+    }
+}
+
+impl LatencyConfig {
+    /// Build an injector when enabled and at least one delay is configured.
+    pub fn injector(&self) -> Option<Arc<LatencyInjector>> {
+        (self.enabled && (self.quote.is_some() || self.order_ack.is_some()))
+            .then(|| Arc::new(LatencyInjector::new(self.clone())))
+    }
+}
+
+/// Samples delays from a [`LatencyConfig`]
+pub struct LatencyInjector {
+    config: LatencyConfig,
+    rng: Mutex<StdRng>,
+}
+
+impl LatencyInjector {
+    pub fn new(config: LatencyConfig) -> Self {
+        let rng = match config.seed {
+            Some(seed) => StdRng::seed_from_u64(seed),
+            None => StdRng::from_entropy(),
+        };
+        Self {
+            config,
+            rng: Mutex::new(rng),
+        }
+    }
+
+    pub fn config(&self) -> &LatencyConfig {
+        &self.config
+    }
+
+    fn sample(&self, dist: Option<LatencyDistribution>) -> Duration {
+        let Some(dist) = dist else {
+            return Duration::ZERO;
+        };
+        let ms = match self.rng.lock() {
+            Ok(mut rng) => dist.sample_ms(&mut *rng),
+            Err(_) => return Duration::ZERO,
+        };
+        Duration::from_micros((ms.min(self.config.max_ms as f64) * 1000.0) as u64)
+    }
+
+    /// Next quote delay
+    pub fn quote_delay(&self) -> Duration {
+        self.sample(self.config.quote)
+    }
+
+    /// Next order-ack delay
+    pub fn ack_delay(&self) -> Duration {
+        self.sample(self.config.order_ack)
+    }
+
+    async fn delay_ack(&self) {
+        let delay = self.ack_delay();
+        if !delay.is_zero() {
+            tokio::time::sleep(delay).await;
+        }
+    }
+
+    async fn delay_quote(&self) {
+        let delay = self.quote_delay();
+        if !delay.is_zero() {
+            tokio::time::sleep(delay).await;
+        }
+    }
+}
+
+/// Exchange client wrapper that delays acks and best-price polls
+pub struct LatencyInjectedExchange {
+    inner: Arc<dyn ExchangeClient>,
+    injector: Arc<LatencyInjector>,
+}
+
+impl LatencyInjectedExchange {
+    pub fn new(inner: Arc<dyn ExchangeClient>, injector: Arc<LatencyInjector>) -> Self {
+        Self { inner, injector }
+    }
+}
+
+#[async_trait]
+impl ExchangeClient for LatencyInjectedExchange {
+    fn kind(&self) -> ExchangeKind {
+        self.inner.kind()
+    }
+
+    fn is_dry_run(&self) -> bool {
+        self.inner.is_dry_run()
+    }
+
+    async fn submit_order_gateway(&self, request: &OrderRequest) -> Result<OrderResponse> {
+        self.injector.delay_ack().await;
+        self.inner.submit_order_gateway(request).await
+    }
+
+    async fn get_order(&self, order_id: &str) -> Result<OrderResponse> {
+        self.injector.delay_ack().await;
+        self.inner.get_order(order_id).await
+    }
+
+    async fn cancel_order(&self, order_id: &str) -> Result<bool> {
+        self.injector.delay_ack().await;
+        self.inner.cancel_order(order_id).await
+    }
+
+    async fn get_best_prices(&self, token_id: &str) -> Result<(Option<Decimal>, Option<Decimal>)> {
+        self.injector.delay_quote().await;
+        self.inner.get_best_prices(token_id).await
+    }
+
+    fn infer_order_status(&self, order: &OrderResponse) -> OrderStatus {
+        self.inner.infer_order_status(order)
+    }
+
+    fn calculate_fill(&self, order: &OrderResponse) -> (u64, Option<Decimal>) {
+        self.inner.calculate_fill(order)
+    }
+
+    async fn get_market(&self, market_id: &str) -> Result<MarketResponse> {
+        self.inner.get_market(market_id).await
+    }
+
+    async fn search_markets(&self, query: &str) -> Result<Vec<MarketSummary>> {
+        self.inner.search_markets(query).await
+    }
+
+    async fn get_balance(&self) -> Result<BalanceResponse> {
+        self.inner.get_balance().await
+    }
+
+    async fn get_positions(&self) -> Result<Vec<PositionResponse>> {
+        self.inner.get_positions().await
+    }
+
+    async fn get_order_history(&self, limit: Option<u32>) -> Result<Vec<OrderResponse>> {
+        self.inner.get_order_history(limit).await
+    }
+
+    async fn get_trades(&self, limit: Option<u32>) -> Result<Vec<TradeResponse>> {
+        self.inner.get_trades(limit).await
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn parses_specs_and_rejects_bad_ones() {
+        assert_eq!(
+            "fixed:150".parse::<LatencyDistribution>().unwrap(),
+            LatencyDistribution::Fixed { ms: 150.0 }
+        );
+        assert_eq!(
+            "Normal: 120, 30".parse::<LatencyDistribution>().unwrap(),
+            LatencyDistribution::Normal {
+                mean_ms: 120.0,
+                std_ms: 30.0
+            }
+        );
+        assert!("pareto:80,0".parse::<LatencyDistribution>().is_err());
+        assert!("normal:120".parse::<LatencyDistribution>().is_err());
+        assert!("uniform:1,2".parse::<LatencyDistribution>().is_err());
+    }
+
+    #[test]
+    fn samples_follow_distribution_and_respect_cap() {
+        let mut rng = StdRng::seed_from_u64(7);
+        let n = 20_000;
+
+        let normal = LatencyDistribution::Normal {
+            mean_ms: 100.0,
+            std_ms: 20.0,
+        };
+        let mean = (0..n).map(|_| normal.sample_ms(&mut rng)).sum::<f64>() / n as f64;
+        assert!((mean - 100.0).abs() < 1.0, "mean={mean}");
+
+        let pareto = LatencyDistribution::Pareto {
+            scale_ms: 50.0,
+            shape: 3.0,
+        };
+        let samples: Vec<f64> = (0..n).map(|_| pareto.sample_ms(&mut rng)).collect();
+        assert!(samples.iter().all(|v| *v >= 50.0));
+        // E[X] = scale · shape / (shape - 1) = 75
+        let mean = samples.iter().sum::<f64>() / n as f64;
+        assert!((mean - 75.0).abs() < 3.0, "mean={mean}");
+
+        let injector = LatencyInjector::new(LatencyConfig {
+            enabled: true,
+            order_ack: Some(LatencyDistribution::Fixed { ms: 5_000.0 }),
+            max_ms: 250,
+            seed: Some(1),
+            ..LatencyConfig::default()
+        });
+        assert_eq!(injector.ack_delay(), Duration::from_millis(250));
+        assert_eq!(injector.quote_delay(), Duration::ZERO);
+    }
+}
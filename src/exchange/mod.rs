@@ -1,5 +1,7 @@
 pub mod factory;
+pub mod latency;
 mod traits;
 
 pub use factory::{build_exchange_client, build_exchange_client_for};
+pub use latency::{LatencyConfig, LatencyDistribution, LatencyInjectedExchange, LatencyInjector};
 pub use traits::{parse_exchange_kind, ExchangeClient, ExchangeKind};
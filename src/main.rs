@@ -11,5 +11,7 @@ mod main_runtime;
 #[tokio::main]
 async fn main() -> Result<()> {
     let cli = Cli::parse();
-    main_dispatch::run(&cli).await
+    let result = main_dispatch::run(&cli).await;
+    ploy::services::telemetry::shutdown();
+    result
 }
@@ -46,8 +46,28 @@ pub(super) async fn run_autonomous_mode(
     }
 
     use ploy::ai_clients::AgentClientConfig;
+    use ploy::ai_clients::ToolAuditLog;
+    use ploy::platform::{AgentRiskParams, Domain, RiskConfig, RiskGate};
+    use std::sync::Arc;
+
+    // Every propose_order tool call is checked against this gate before execution
+    let risk_gate = Arc::new(RiskGate::new(RiskConfig::default()));
+    risk_gate
+        .register_agent_with_domain(
+            "autonomous",
+            Domain::Crypto,
+            AgentRiskParams {
+                max_order_value: config.max_trade_size,
+                max_total_exposure: config.max_total_exposure,
+                ..Default::default()
+            },
+        )
+        .await;
+
     let client = ClaudeAgentClient::with_config(AgentClientConfig::for_autonomous());
-    let mut agent = AutonomousAgent::new(client, config);
+    let mut agent = AutonomousAgent::new(client, config)
+        .with_risk_gate(risk_gate, "autonomous", Domain::Crypto)
+        .with_tool_audit(ToolAuditLog::from_env());
 
     if enable_trading {
         println!("  Trading backend: initializing authenticated Polymarket client...");
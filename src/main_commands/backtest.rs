@@ -0,0 +1,39 @@
+use ploy::cli::runtime::BacktestCommands;
+use ploy::error::{PloyError, Result};
+
+pub(crate) fn run_backtest_command(cmd: &BacktestCommands) -> Result<()> {
+    use ploy::strategy::backtest_diff::{load_backtest_report, BacktestDiff};
+
+    match cmd {
+        BacktestCommands::Diff {
+            run_a,
+            run_b,
+            tolerance,
+            json,
+            fail_on_regression,
+        } => {
+            let report_a = load_backtest_report(run_a)?;
+            let report_b = load_backtest_report(run_b)?;
+            let diff = BacktestDiff::compute(&report_a, &report_b, *tolerance);
+
+            if *json {
+                println!("{}", serde_json::to_string_pretty(&diff)?);
+            } else {
+                println!("A: {}", run_a);
+                println!("B: {}", run_b);
+                print!("{}", diff);
+            }
+
+            if *fail_on_regression && diff.has_regressions() {
+                return Err(PloyError::Validation(format!(
+                    "{} regressed against {} beyond {:.1}% tolerance",
+                    run_b,
+                    run_a,
+                    tolerance * 100.0
+                )));
+            }
+        }
+    }
+
+    Ok(())
+}
@@ -0,0 +1,97 @@
+use ploy::adapters::PostgresStore;
+use ploy::cli::runtime::{ExportArgs, ExportCommands};
+use ploy::config::AppConfig;
+use ploy::error::Result;
+use ploy::services::export::{self, ExportFilter, ExportFormat, ExportRange};
+use std::path::PathBuf;
+
+pub(crate) async fn run_export_command(cmd: &ExportCommands) -> Result<()> {
+    let config = AppConfig::load()?;
+    let store = PostgresStore::new(&config.database.url, 2).await?;
+    let pool = store.pool();
+
+    let (table, format, path, label) = match cmd {
+        ExportCommands::Trades(args) => {
+            let (range, filter) = range_and_filter(args)?;
+            let table = export::export_trades(pool, &range, &filter).await?;
+            (
+                table,
+                args.format,
+                output_path("trades", args),
+                range_label("trades", args),
+            )
+        }
+        ExportCommands::Positions(args) => {
+            let (range, filter) = range_and_filter(args)?;
+            let table = export::export_positions(pool, &range, &filter).await?;
+            (
+                table,
+                args.format,
+                output_path("positions", args),
+                range_label("positions", args),
+            )
+        }
+        ExportCommands::Pnl(args) => {
+            let (range, filter) = range_and_filter(args)?;
+            let table = export::export_pnl(pool, &range, &filter).await?;
+            (
+                table,
+                args.format,
+                output_path("pnl", args),
+                range_label("pnl", args),
+            )
+        }
+        ExportCommands::TaxLots {
+            year,
+            method,
+            detail,
+            format,
+            output,
+            account,
+        } => {
+            let table =
+                export::export_tax_lots(pool, *year, *method, account.as_deref(), *detail).await?;
+            let kind = if *detail { "tax_lots" } else { "tax_summary" };
+            let path = output.clone().unwrap_or_else(|| {
+                default_path(&format!("{}_{}_{}", kind, year, method.as_str()), *format)
+            });
+            (table, *format, path, format!("{} {}", kind, year))
+        }
+    };
+
+    if let Some(dir) = path.parent().filter(|d| !d.as_os_str().is_empty()) {
+        std::fs::create_dir_all(dir)?;
+    }
+    table.write(format, &path)?;
+    println!(
+        "Exported {} {} rows to {}",
+        table.rows.len(),
+        label,
+        path.display()
+    );
+    Ok(())
+}
+
+fn range_and_filter(args: &ExportArgs) -> Result<(ExportRange, ExportFilter)> {
+    Ok((
+        ExportRange::new(args.from, args.to)?,
+        ExportFilter {
+            account_id: args.account.clone(),
+            include_dry_run: args.include_dry_run,
+        },
+    ))
+}
+
+fn range_label(kind: &str, args: &ExportArgs) -> String {
+    format!("{} ({} to {})", kind, args.from, args.to)
+}
+
+fn default_path(stem: &str, format: ExportFormat) -> PathBuf {
+    PathBuf::from("data/exports").join(format!("{}.{}", stem, format.extension()))
+}
+
+fn output_path(kind: &str, args: &ExportArgs) -> PathBuf {
+    args.output.clone().unwrap_or_else(|| {
+        default_path(&format!("{}_{}_{}", kind, args.from, args.to), args.format)
+    })
+}
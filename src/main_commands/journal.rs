@@ -0,0 +1,132 @@
+use ploy::cli::runtime::JournalCommands;
+use ploy::error::{PloyError, Result};
+use ploy::strategy::trade_journal::{outcome_label, AnnotatedTrade};
+use ploy::strategy::{JournalQuery, JournalUpdate, TradeJournal, TradeLogger};
+
+pub(crate) async fn run_journal_command(cmd: &JournalCommands) -> Result<()> {
+    let logger = TradeLogger::default_path();
+    if let Err(e) = logger.load().await {
+        eprintln!("Warning: Could not load trades: {}", e);
+    }
+    let journal = TradeJournal::from_env();
+
+    match cmd {
+        JournalCommands::Note {
+            trade_id,
+            text,
+            tags,
+            untags,
+            classify,
+            clear_classification,
+            author,
+        } => {
+            if logger.get_trade(trade_id).await.is_none() {
+                return Err(PloyError::Validation(format!(
+                    "trade {} not found in data/trades.json",
+                    trade_id
+                )));
+            }
+            let update = JournalUpdate {
+                note: text.clone(),
+                author: author.clone().or_else(|| std::env::var("USER").ok()),
+                add_tags: tags.clone(),
+                remove_tags: untags.clone(),
+                classification: classify.clone(),
+                clear_classification: *clear_classification,
+            };
+            let entry = journal.annotate(trade_id, &update).await?;
+            println!(
+                "Journal updated for {} ({} notes, tags: [{}], classification: {})",
+                trade_id,
+                entry.notes.len(),
+                entry.tags.iter().cloned().collect::<Vec<_>>().join(", "),
+                entry.classification.as_deref().unwrap_or("-")
+            );
+        }
+        JournalCommands::Show { trade_id, json } => {
+            let trade = logger.get_trade(trade_id).await.ok_or_else(|| {
+                PloyError::Validation(format!("trade {} not found in data/trades.json", trade_id))
+            })?;
+            let annotated = AnnotatedTrade {
+                trade,
+                journal: journal.get(trade_id)?,
+            };
+
+            if *json {
+                println!("{}", serde_json::to_string_pretty(&annotated)?);
+            } else {
+                print_trade(&annotated);
+                match &annotated.journal {
+                    None => println!("  (no journal entry)"),
+                    Some(entry) => {
+                        for note in &entry.notes {
+                            println!(
+                                "  [{}] {}: {}",
+                                note.created_at.format("%Y-%m-%d %H:%M"),
+                                note.author.as_deref().unwrap_or("-"),
+                                note.text
+                            );
+                        }
+                    }
+                }
+            }
+        }
+        JournalCommands::List {
+            tag,
+            classification,
+            outcome,
+            symbol,
+            annotated,
+            limit,
+            json,
+        } => {
+            let query = JournalQuery {
+                tag: tag.clone(),
+                classification: classification.clone(),
+                outcome: outcome.clone(),
+                symbol: symbol.clone(),
+                annotated_only: *annotated,
+                limit: Some(*limit),
+            };
+            let trades = journal.query(&logger.get_all_trades().await, &query)?;
+
+            if *json {
+                println!("{}", serde_json::to_string_pretty(&trades)?);
+            } else if trades.is_empty() {
+                println!("No matching trades");
+            } else {
+                for trade in &trades {
+                    print_trade(trade);
+                }
+            }
+        }
+    }
+
+    Ok(())
+}
+
+fn print_trade(annotated: &AnnotatedTrade) {
+    let trade = &annotated.trade;
+    let pnl = trade
+        .pnl_usd
+        .map(|p| format!("${:+.2}", p))
+        .unwrap_or_else(|| "-".to_string());
+    let (tags, class) = match &annotated.journal {
+        Some(entry) => (
+            entry.tags.iter().cloned().collect::<Vec<_>>().join(","),
+            entry.classification.clone().unwrap_or_default(),
+        ),
+        None => (String::new(), String::new()),
+    };
+    println!(
+        "{}  {:<40} {:<9} {:<4} {:<9} {:>8}  [{}] {}",
+        trade.timestamp.format("%Y-%m-%d %H:%M"),
+        trade.id,
+        trade.symbol,
+        trade.direction,
+        outcome_label(trade),
+        pnl,
+        tags,
+        class
+    );
+}
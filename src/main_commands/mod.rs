@@ -1,4 +1,12 @@
+pub mod backtest;
 pub mod crypto;
+pub mod export;
+pub mod journal;
+pub mod research;
+pub mod risk;
 #[cfg(feature = "rl")]
 pub mod rl;
+pub mod simulate;
+pub mod split_merge;
 pub mod sports;
+pub mod verify;
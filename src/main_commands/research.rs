@@ -0,0 +1,237 @@
+use chrono::{Duration, Utc};
+use ploy::adapters::PostgresStore;
+use ploy::cli::runtime::ResearchCommands;
+use ploy::collector::{migrate_depth_history, migrate_quote_ticks, TickArchive, ARCHIVE_CODEC};
+use ploy::config::AppConfig;
+use ploy::error::{PloyError, Result};
+use ploy::strategy::capacity::{self, CapacityConfig};
+use rust_decimal::prelude::*;
+
+pub(crate) async fn run_research_command(cmd: &ResearchCommands) -> Result<()> {
+    use ploy::strategy::research_report::{ResearchReport, ResearchReportStore};
+
+    let store = ResearchReportStore::from_env();
+
+    match cmd {
+        ResearchCommands::List { kind, limit, json } => {
+            let mut reports = store.list(kind.as_deref())?;
+            reports.truncate(*limit);
+
+            if *json {
+                println!("{}", serde_json::to_string_pretty(&reports)?);
+            } else if reports.is_empty() {
+                println!("No research reports under {}", store.root().display());
+            } else {
+                println!(
+                    "{:<56} {:<20} {:<10} {:<10}",
+                    "ID", "CREATED", "GIT", "DATA"
+                );
+                for report in &reports {
+                    println!(
+                        "{:<56} {:<20} {:<10} {:<10}",
+                        report.id,
+                        report.created_at.format("%Y-%m-%d %H:%M:%S"),
+                        short(report.repro.git_sha.as_deref()),
+                        short(report.repro.data_snapshot_id.as_deref()),
+                    );
+                }
+            }
+        }
+        ResearchCommands::Show { id, json } => {
+            let report = store.load(id)?;
+
+            if *json {
+                println!("{}", serde_json::to_string_pretty(&report)?);
+            } else {
+                println!("id:            {}", report.id);
+                println!("kind:          {}", report.kind);
+                println!("created:       {}", report.created_at.to_rfc3339());
+                println!("schema:        v{}", report.schema_version);
+                println!("config hash:   {}", report.repro.config_hash);
+                println!(
+                    "git sha:       {}",
+                    report.repro.git_sha.as_deref().unwrap_or("-")
+                );
+                println!(
+                    "data snapshot: {}",
+                    report.repro.data_snapshot_id.as_deref().unwrap_or("-")
+                );
+                println!(
+                    "seed:          {}",
+                    report
+                        .repro
+                        .seed
+                        .map(|s| s.to_string())
+                        .unwrap_or_else(|| "-".to_string())
+                );
+                println!("config:\n{}", serde_json::to_string_pretty(&report.config)?);
+                println!(
+                    "summary:\n{}",
+                    serde_json::to_string_pretty(&report.summary)?
+                );
+            }
+        }
+        ResearchCommands::Capacity {
+            strategy,
+            days,
+            sizes,
+            min_ev_per_dollar,
+            json,
+        } => {
+            let mut config = CapacityConfig {
+                min_ev_per_dollar: Decimal::from_f64(*min_ev_per_dollar).ok_or_else(|| {
+                    PloyError::Validation("--min-ev-per-dollar must be finite".to_string())
+                })?,
+                ..CapacityConfig::default()
+            };
+            if let Some(sizes) = sizes {
+                config.sizes_usd = sizes
+                    .split(',')
+                    .map(str::trim)
+                    .filter(|s| !s.is_empty())
+                    .map(|s| {
+                        Decimal::from_str(s).map_err(|_| {
+                            PloyError::Validation(format!("invalid size in --sizes: {s}"))
+                        })
+                    })
+                    .collect::<Result<Vec<_>>>()?;
+            }
+
+            let app = AppConfig::load()?;
+            let db = PostgresStore::new(&app.database.url, 2).await?;
+            let to = Utc::now();
+            let from = to - Duration::days((*days).max(1));
+            let samples =
+                capacity::load_capacity_samples(db.pool(), strategy, from, to, &config).await?;
+            let curves = capacity::estimate_capacity(&samples, &config);
+
+            let report = ResearchReport::new(
+                &format!("capacity.{}", strategy),
+                &serde_json::json!({
+                    "strategy": strategy,
+                    "from": from,
+                    "to": to,
+                    "capacity": &config,
+                }),
+                &curves,
+            )?
+            .with_summary(serde_json::json!({
+                "signals": samples.len(),
+                "max_capacity_usd": curves
+                    .iter()
+                    .map(|c| (c.symbol.clone(), c.max_capacity_usd))
+                    .collect::<std::collections::BTreeMap<_, _>>(),
+            }));
+            let path = store.save(&report)?;
+
+            if *json {
+                println!("{}", serde_json::to_string_pretty(&curves)?);
+                return Ok(());
+            }
+
+            println!(
+                "Capacity for {} ({} signals with recorded depth, {} .. {})",
+                strategy,
+                samples.len(),
+                from.format("%Y-%m-%d"),
+                to.format("%Y-%m-%d")
+            );
+            if curves.is_empty() {
+                println!("  no signals could be replayed against recorded books");
+            }
+            for curve in &curves {
+                let cap = curve
+                    .max_capacity_usd
+                    .map(|c| format!("${}", c))
+                    .unwrap_or_else(|| "none".to_string());
+                println!();
+                println!(
+                    "{}  signals={}  max capacity={}",
+                    curve.symbol, curve.signals, cap
+                );
+                println!(
+                    "  {:>10} {:>8} {:>10} {:>12} {:>12}",
+                    "SIZE", "FILL", "SLIPPAGE", "EV/$", "EXP PNL"
+                );
+                for point in &curve.points {
+                    println!(
+                        "  {:>10} {:>7.1}% {:>10.4} {:>12.4} {:>12.2}",
+                        point.size_usd,
+                        point.fill_ratio * Decimal::ONE_HUNDRED,
+                        point.avg_slippage,
+                        point.ev_per_dollar,
+                        point.expected_pnl
+                    );
+                }
+            }
+            println!();
+            println!("Report {} written to {}", report.id, path.display());
+        }
+        ResearchCommands::Archive {
+            kind,
+            days,
+            dir,
+            json,
+        } => {
+            let (quotes, depth) = match kind.as_str() {
+                "quotes" => (true, false),
+                "depth" => (false, true),
+                "all" => (true, true),
+                other => {
+                    return Err(PloyError::Validation(format!(
+                        "--kind must be quotes, depth or all (got {other})"
+                    )))
+                }
+            };
+            let archive = dir
+                .as_ref()
+                .map(TickArchive::new)
+                .unwrap_or_else(TickArchive::from_env);
+
+            let app = AppConfig::load()?;
+            let db = PostgresStore::new(&app.database.url, 2).await?;
+            let to = Utc::now();
+            let from = to - Duration::days((*days).max(1));
+
+            let mut results = Vec::new();
+            if quotes {
+                let stats = migrate_quote_ticks(db.pool(), &archive, from, to).await?;
+                results.push(("quotes", stats));
+            }
+            if depth {
+                let stats = migrate_depth_history(db.pool(), &archive, from, to).await?;
+                results.push(("depth", stats));
+            }
+
+            if *json {
+                let out: std::collections::BTreeMap<_, _> = results.into_iter().collect();
+                println!("{}", serde_json::to_string_pretty(&out)?);
+                return Ok(());
+            }
+
+            println!(
+                "Archived {} .. {} into {} ({})",
+                from.format("%Y-%m-%d"),
+                to.format("%Y-%m-%d"),
+                archive.root().display(),
+                ARCHIVE_CODEC
+            );
+            for (kind, stats) in &results {
+                let ratio = stats
+                    .compression_ratio()
+                    .map(|r| format!("{:.1}x", r))
+                    .unwrap_or_else(|| "-".to_string());
+                println!(
+                    "  {:<7} records={:<10} files={:<6} json={}B archive={}B ratio={}",
+                    kind, stats.records, stats.files, stats.json_bytes, stats.archive_bytes, ratio
+                );
+            }
+        }
+    }
+
+    Ok(())
+}
+
+fn short(value: Option<&str>) -> &str {
+    value.map(|v| &v[..v.len().min(8)]).unwrap_or("-")
+}
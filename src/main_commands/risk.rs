@@ -0,0 +1,149 @@
+use chrono::Utc;
+use ploy::adapters::PostgresStore;
+use ploy::cli::runtime::RiskCommands;
+use ploy::config::AppConfig;
+use ploy::coordinator::budget;
+use ploy::coordinator::loss_limit::{self, GLOBAL_SCOPE};
+use ploy::error::{PloyError, Result};
+
+pub(crate) async fn run_risk_command(cmd: &RiskCommands) -> Result<()> {
+    let config = AppConfig::load()?;
+    let store = PostgresStore::new(&config.database.url, 2).await?;
+    let today = Utc::now().date_naive();
+
+    match cmd {
+        RiskCommands::Status { json } => {
+            let halts = loss_limit::load_halts(store.pool(), today).await?;
+            if *json {
+                println!(
+                    "{}",
+                    serde_json::to_string_pretty(&serde_json::json!({
+                        "date": today,
+                        "halted_scopes": &halts.scopes,
+                        "halt_reason": &halts.halt_reason,
+                        "halted_at": halts.halted_at,
+                        "reenabled_by": &halts.reenabled_by,
+                        "reenabled_at": halts.reenabled_at,
+                    }))?
+                );
+                return Ok(());
+            }
+
+            println!("Intraday loss limit ({})", today);
+            if halts.scopes.is_empty() {
+                println!("  no halts");
+            }
+            for scope in &halts.scopes {
+                println!("  HALTED  {}", scope);
+            }
+            if let Some(reason) = &halts.halt_reason {
+                println!("  reason: {}", reason);
+            }
+            if let (Some(by), Some(at)) = (&halts.reenabled_by, halts.reenabled_at) {
+                println!("  last re-enable: {} at {}", by, at);
+            }
+        }
+        RiskCommands::Reenable { strategy, operator } => {
+            let scope = strategy.as_deref().unwrap_or(GLOBAL_SCOPE);
+            let operator = resolve_operator(operator)?;
+
+            if !loss_limit::clear_halt(store.pool(), today, scope, &operator).await? {
+                return Err(PloyError::Validation(format!(
+                    "{} is not halted by the intraday loss limit today",
+                    scope
+                )));
+            }
+            println!(
+                "Re-enabled {} (operator: {}); a running coordinator resumes it on its next refresh",
+                scope, operator
+            );
+        }
+        RiskCommands::Budgets { json } => {
+            let budgets = budget::load_ledger(store.pool()).await?.snapshot();
+            let mut open = budget::load_top_ups(store.pool(), "PENDING").await?;
+            open.extend(budget::load_top_ups(store.pool(), "APPROVED").await?);
+            if *json {
+                println!(
+                    "{}",
+                    serde_json::to_string_pretty(&serde_json::json!({
+                        "budgets": &budgets,
+                        "open_top_ups": &open,
+                    }))?
+                );
+                return Ok(());
+            }
+
+            println!("Sandbox budgets");
+            if budgets.is_empty() {
+                println!("  no budgeted agents");
+            }
+            for b in &budgets {
+                println!(
+                    "  {:<28} balance ${:>10}  allocated ${:>10}  spent ${:>10}  credited ${:>10}",
+                    b.agent_id,
+                    b.balance().round_dp(2),
+                    b.allocated.round_dp(2),
+                    b.spent.round_dp(2),
+                    b.credited.round_dp(2)
+                );
+            }
+            if !open.is_empty() {
+                println!("Open top-ups");
+            }
+            for t in &open {
+                println!(
+                    "  {}  {:<9} {:<24} ${}  (requested by {})",
+                    t.id,
+                    t.status,
+                    t.agent_id,
+                    t.amount_usd.round_dp(2),
+                    t.requested_by
+                );
+            }
+        }
+        RiskCommands::TopUp {
+            agent,
+            amount,
+            operator,
+        } => {
+            if *amount <= rust_decimal::Decimal::ZERO {
+                return Err(PloyError::Validation(
+                    "--amount must be positive".to_string(),
+                ));
+            }
+            let operator = resolve_operator(operator)?;
+            let id = budget::request_top_up(store.pool(), agent, *amount, &operator).await?;
+            println!(
+                "Requested top-up {} of ${} for {}; small top-ups apply automatically, \
+                 larger ones need `ploy risk decide-top-up {}` or the Feishu card",
+                id, amount, agent, id
+            );
+        }
+        RiskCommands::DecideTopUp { id, deny, operator } => {
+            let operator = resolve_operator(operator)?;
+            if !budget::decide_top_up(store.pool(), *id, !*deny, &operator).await? {
+                return Err(PloyError::Validation(format!(
+                    "top-up {} is not pending",
+                    id
+                )));
+            }
+            if *deny {
+                println!("Denied top-up {} (operator: {})", id, operator);
+            } else {
+                println!(
+                    "Approved top-up {} (operator: {}); a running coordinator credits it \
+                     on its next refresh",
+                    id, operator
+                );
+            }
+        }
+    }
+    Ok(())
+}
+
+fn resolve_operator(operator: &Option<String>) -> Result<String> {
+    operator
+        .clone()
+        .or_else(|| std::env::var("USER").ok())
+        .ok_or_else(|| PloyError::Validation("--operator is required".to_string()))
+}
@@ -11,6 +11,8 @@ mod backtest;
 mod core_modes;
 #[cfg(feature = "rl")]
 mod lead_lag;
+#[cfg(feature = "rl")]
+mod registry;
 
 /// RL strategy commands
 #[cfg(feature = "rl")]
@@ -25,6 +27,9 @@ pub(crate) async fn run_rl_command(cmd: &RlCommands) -> Result<()> {
             series,
             symbol,
             resume,
+            workers,
+            seed,
+            scenario,
             verbose,
         } => {
             core_modes::run_train(
@@ -36,6 +41,9 @@ pub(crate) async fn run_rl_command(cmd: &RlCommands) -> Result<()> {
                 series,
                 symbol,
                 resume,
+                *workers,
+                *seed,
+                scenario.as_deref(),
                 *verbose,
             )
             .await?;
@@ -93,6 +101,29 @@ pub(crate) async fn run_rl_command(cmd: &RlCommands) -> Result<()> {
                 .await?;
         }
 
+        RlCommands::SnapshotBacktest {
+            episodes,
+            symbol,
+            horizon,
+            days,
+            step_ms,
+            capital,
+            max_position,
+            verbose,
+        } => {
+            backtest::run_snapshot_backtest(
+                *episodes,
+                symbol,
+                horizon.as_deref(),
+                *days,
+                *step_ms,
+                *capital,
+                *max_position,
+                *verbose,
+            )
+            .await?;
+        }
+
         RlCommands::LeadLag {
             episodes,
             trade_size,
@@ -101,6 +132,7 @@ pub(crate) async fn run_rl_command(cmd: &RlCommands) -> Result<()> {
             lr: _lr,
             checkpoint,
             verbose,
+            curriculum,
         } => {
             lead_lag::run_lead_lag(
                 *episodes,
@@ -109,6 +141,7 @@ pub(crate) async fn run_rl_command(cmd: &RlCommands) -> Result<()> {
                 symbol,
                 checkpoint,
                 *verbose,
+                curriculum.to_config(),
             )
             .await?;
         }
@@ -166,6 +199,37 @@ pub(crate) async fn run_rl_command(cmd: &RlCommands) -> Result<()> {
             )
             .await?;
         }
+
+        RlCommands::Register {
+            name,
+            version,
+            artifact,
+            training_data_hash,
+            metrics,
+            registry_dir,
+        } => {
+            registry::run_register(
+                name,
+                version,
+                artifact,
+                training_data_hash.clone(),
+                metrics,
+                registry_dir.as_deref(),
+            )?;
+        }
+
+        RlCommands::Promote {
+            name,
+            version,
+            stage,
+            registry_dir,
+        } => {
+            registry::run_promote(name, version, stage, registry_dir.as_deref())?;
+        }
+
+        RlCommands::Models { name, registry_dir } => {
+            registry::run_models(name.as_deref(), registry_dir.as_deref())?;
+        }
     }
 
     Ok(())
@@ -130,3 +130,111 @@ pub(super) async fn run_backtest(
 
     Ok(())
 }
+
+#[cfg(feature = "rl")]
+#[allow(clippy::too_many_arguments)]
+pub(super) async fn run_snapshot_backtest(
+    episodes: usize,
+    symbol: &str,
+    horizon: Option<&str>,
+    days: i64,
+    step_ms: i64,
+    capital: f64,
+    max_position: u64,
+    verbose: bool,
+) -> Result<()> {
+    use ploy::adapters::PostgresStore;
+    use ploy::config::AppConfig;
+    use ploy::rl::algorithms::ppo::{PPOTrainer, PPOTrainerConfig};
+    use ploy::rl::environment::{
+        SnapshotDataset, SnapshotEnvConfig, SnapshotEnvironment, SnapshotQuery,
+    };
+    use ploy::rl::training::{summarize_backtest_results, train_snapshot_backtest};
+    use ploy::rl::PPOConfig;
+
+    let config = AppConfig::load()?;
+    let store = PostgresStore::new(&config.database.url, 5).await?;
+
+    let to = chrono::Utc::now();
+    let query = SnapshotQuery {
+        symbol: symbol.to_uppercase(),
+        horizon: horizon.map(str::to_string),
+        from: to - chrono::Duration::days(days.max(1)),
+        to,
+        step_ms,
+        min_steps: 10,
+    };
+    info!(
+        "Loading recorded rounds for {} over {} days...",
+        query.symbol, days
+    );
+    let dataset = SnapshotDataset::load(store.pool(), &query).await?;
+
+    if dataset.rounds.is_empty() {
+        println!(
+            "No recorded rounds with both Polymarket quotes and Binance LOB for {}.",
+            query.symbol
+        );
+        return Ok(());
+    }
+
+    let settled = dataset
+        .rounds
+        .iter()
+        .filter(|r| r.outcome_up.is_some())
+        .count();
+    println!(
+        "Loaded {} rounds ({} settled), {} steps",
+        dataset.rounds.len(),
+        settled,
+        dataset.total_steps()
+    );
+
+    let mut env = SnapshotEnvironment::new(
+        dataset,
+        SnapshotEnvConfig {
+            initial_capital: capital,
+            max_position,
+            ..Default::default()
+        },
+    );
+    let ppo_config = PPOTrainerConfig {
+        ppo: PPOConfig::default(),
+        hidden_dim: 128,
+    };
+    let mut trainer = PPOTrainer::with_exploration(ppo_config, 0.998, 0.05);
+
+    let results = train_snapshot_backtest(&mut trainer, &mut env, episodes, verbose);
+    let summary = summarize_backtest_results(&results);
+
+    println!("\n╔══════════════════════════════════════════════════════════════╗");
+    println!("║               Snapshot Backtest Summary                      ║");
+    println!("╠══════════════════════════════════════════════════════════════╣");
+    println!(
+        "║  Episodes:        {:>10}                                   ║",
+        summary.num_episodes
+    );
+    println!(
+        "║  Avg PnL:         {:>10.2}                                   ║",
+        summary.avg_pnl
+    );
+    println!(
+        "║  Total PnL:       {:>10.2}                                   ║",
+        summary.total_pnl
+    );
+    println!(
+        "║  Win Rate:        {:>9.1}%                                   ║",
+        summary.avg_win_rate * 100.0
+    );
+    println!(
+        "║  Episode Win %:   {:>9.1}%                                   ║",
+        summary.episode_win_rate * 100.0
+    );
+    println!(
+        "║  Profit Factor:   {:>10.2}                                   ║",
+        summary.profit_factor
+    );
+    println!("╚══════════════════════════════════════════════════════════════╝");
+
+    Ok(())
+}
@@ -16,11 +16,20 @@ pub(super) async fn run_train(
     series: &Option<String>,
     symbol: &str,
     resume: &Option<String>,
+    workers: usize,
+    seed: Option<u64>,
+    scenario: Option<&str>,
     verbose: bool,
 ) -> Result<()> {
     use ploy::rl::algorithms::ppo::{PPOTrainer, PPOTrainerConfig};
-    use ploy::rl::training::{summarize_results, train_simulated, Checkpointer};
-    use ploy::rl::{MarketConfig, PPOConfig, RLConfig, TradingEnvConfig, TrainingConfig};
+    use ploy::rl::training::{
+        summarize_results, train_parallel, train_simulated, Checkpointer, RolloutConfig,
+    };
+    use ploy::rl::{
+        MarketConfig, MarketScenario, PPOConfig, RLConfig, TradingEnvConfig, TrainingConfig,
+    };
+
+    let scenario = scenario.map(MarketScenario::load).transpose()?;
 
     info!("Starting RL training mode");
     println!("╔══════════════════════════════════════════════════════════════╗");
@@ -56,6 +65,12 @@ pub(super) async fn run_train(
             series_id
         );
     }
+    if let Some(scenario) = scenario.as_ref() {
+        println!(
+            "║  Scenario:       {}                                          ║",
+            scenario.name
+        );
+    }
     println!("╚══════════════════════════════════════════════════════════════╝");
 
     let checkpoint_dir = Path::new(checkpoint);
@@ -103,6 +118,7 @@ pub(super) async fn run_train(
         spread_pct: 0.02,
         quote_update_freq: 5,
         trend: 0.0,
+        scenario,
     };
 
     let env_config = TradingEnvConfig {
@@ -120,7 +136,19 @@ pub(super) async fn run_train(
         episodes
     );
 
-    let results = train_simulated(&mut ppo_trainer, env_config, episodes, verbose);
+    let results = if workers > 1 || seed.is_some() {
+        let rollout = RolloutConfig {
+            workers: workers.max(1),
+            seed: seed.unwrap_or_else(rand::random),
+        };
+        println!(
+            "Collecting rollouts with {} workers (seed {})",
+            rollout.workers, rollout.seed
+        );
+        train_parallel(&mut ppo_trainer, env_config, episodes, &rollout, verbose)?
+    } else {
+        train_simulated(&mut ppo_trainer, env_config, episodes, verbose)
+    };
     let summary = summarize_results(&results);
 
     let final_name = checkpointer
@@ -13,10 +13,12 @@ pub(super) async fn run_lead_lag(
     symbol: &str,
     checkpoint: &str,
     verbose: bool,
+    curriculum: ploy::rl::CurriculumConfig,
 ) -> Result<()> {
     use ploy::adapters::PostgresStore;
     use ploy::config::AppConfig;
     use ploy::rl::environment::{LeadLagAction, LeadLagConfig, LeadLagEnvironment, LobDataPoint};
+    use ploy::rl::training::CurriculumScheduler;
     use rust_decimal::Decimal;
 
     println!("╔══════════════════════════════════════════════════════════════╗");
@@ -42,6 +44,10 @@ pub(super) async fn run_lead_lag(
         "║  Checkpoint:     {}                                          ║",
         checkpoint
     );
+    println!(
+        "║  Curriculum:     {:>10}                                    ║",
+        if curriculum.enabled { "on" } else { "off" }
+    );
     println!("╚══════════════════════════════════════════════════════════════╝\n");
 
     std::fs::create_dir_all(checkpoint).ok();
@@ -118,6 +124,21 @@ pub(super) async fn run_lead_lag(
         ..Default::default()
     };
 
+    let mut scheduler = if curriculum.enabled {
+        Some(CurriculumScheduler::new(curriculum, &data))
+    } else {
+        None
+    };
+    if let Some(s) = &scheduler {
+        let counts = s
+            .tier_counts()
+            .iter()
+            .map(|(tier, n)| format!("{tier:?}={n}"))
+            .collect::<Vec<_>>()
+            .join(", ");
+        println!("Curriculum segments: {}", counts);
+    }
+
     let mut total_rewards = Vec::new();
     let mut exploration_rate = 0.5f32;
     let exploration_decay = 0.995f32;
@@ -129,9 +150,16 @@ pub(super) async fn run_lead_lag(
 
     println!("\nTraining {} episodes...\n", episodes);
 
+    let mut env = LeadLagEnvironment::new(env_config.clone(), data);
     for episode in 0..episodes {
-        let mut env = LeadLagEnvironment::new(env_config.clone(), data.clone());
-        let mut _obs = env.reset();
+        let segment = scheduler
+            .as_ref()
+            .and_then(|s| s.next_segment())
+            .map(|s| s.range());
+        let mut _obs = match segment {
+            Some(range) => env.reset_range(range.start, range.end),
+            None => env.reset(),
+        };
         let mut episode_reward = 0.0f32;
         let mut steps = 0;
 
@@ -165,6 +193,17 @@ pub(super) async fn run_lead_lag(
         exploration_rate = (exploration_rate * exploration_decay).max(min_exploration);
         total_rewards.push(episode_reward);
 
+        if let Some(s) = scheduler.as_mut() {
+            if s.record_episode(episode_reward) {
+                println!(
+                    "Episode {:>5}: curriculum stage {} unlocked ({:?} segments)",
+                    episode + 1,
+                    s.stage(),
+                    s.max_tier()
+                );
+            }
+        }
+
         if verbose || episode % 100 == 0 {
             let recent_avg: f32 = total_rewards.iter().rev().take(100).sum::<f32>()
                 / total_rewards.len().min(100) as f32;
@@ -327,6 +366,7 @@ pub(super) async fn run_lead_lag_live(
         polymarket_slugs: vec![market.to_string()],
         snapshot_interval_ms: 100,
         database_url: config.database.url.clone(),
+        ..SyncCollectorConfig::default()
     };
 
     let pool = sqlx::postgres::PgPoolOptions::new()
@@ -0,0 +1,103 @@
+#[cfg(feature = "rl")]
+use ploy::error::{PloyError, Result};
+#[cfg(feature = "rl")]
+use ploy::ml::{ModelRegistry, ModelStage, ModelVersion, RegisterOptions};
+
+#[cfg(feature = "rl")]
+fn open_registry(registry_dir: Option<&str>) -> Result<ModelRegistry> {
+    match registry_dir {
+        Some(dir) => ModelRegistry::open(dir),
+        None => ModelRegistry::open_default(),
+    }
+}
+
+#[cfg(feature = "rl")]
+pub(super) fn run_register(
+    name: &str,
+    version: &str,
+    artifact: &str,
+    training_data_hash: Option<String>,
+    metrics: &[String],
+    registry_dir: Option<&str>,
+) -> Result<()> {
+    let version: ModelVersion = version.parse()?;
+    let mut options = RegisterOptions {
+        training_data_hash,
+        ..Default::default()
+    };
+    for metric in metrics {
+        let (key, value) = metric
+            .split_once('=')
+            .and_then(|(k, v)| Some((k.trim(), v.trim().parse::<f64>().ok()?)))
+            .ok_or_else(|| {
+                PloyError::Validation(format!("invalid metric '{metric}' (expected key=value)"))
+            })?;
+        options.metrics.insert(key.to_string(), value);
+    }
+
+    let mut registry = open_registry(registry_dir)?;
+    let record = registry.register(name, version, artifact, options)?;
+    println!(
+        "Registered {} as {} (sha256 {})",
+        record.label(),
+        record.stage,
+        record.sha256
+    );
+    println!(
+        "Artifact: {}",
+        registry.root().join(&record.artifact).display()
+    );
+    Ok(())
+}
+
+#[cfg(feature = "rl")]
+pub(super) fn run_promote(
+    name: &str,
+    version: &str,
+    stage: &str,
+    registry_dir: Option<&str>,
+) -> Result<()> {
+    let version: ModelVersion = version.parse()?;
+    let stage: ModelStage = stage.parse()?;
+
+    let mut registry = open_registry(registry_dir)?;
+    let demoted = registry.promote(name, version, stage)?;
+    println!("Promoted {name}@{version} to {stage}");
+    if let Some(prev) = demoted {
+        println!("Archived previous {stage} version {}", prev.label());
+    }
+    Ok(())
+}
+
+#[cfg(feature = "rl")]
+pub(super) fn run_models(name: Option<&str>, registry_dir: Option<&str>) -> Result<()> {
+    let registry = open_registry(registry_dir)?;
+    let records = registry.list(name);
+    if records.is_empty() {
+        println!("No models registered in {}", registry.root().display());
+        return Ok(());
+    }
+
+    println!(
+        "{:<24} {:>10} {:<11} {:<20} {:<14} metrics",
+        "name", "version", "stage", "created", "sha256"
+    );
+    for r in records {
+        let metrics = r
+            .metrics
+            .iter()
+            .map(|(k, v)| format!("{k}={v:.4}"))
+            .collect::<Vec<_>>()
+            .join(" ");
+        println!(
+            "{:<24} {:>10} {:<11} {:<20} {:<14} {}",
+            r.name,
+            r.version.to_string(),
+            r.stage.to_string(),
+            r.created_at.format("%Y-%m-%d %H:%M:%S"),
+            &r.sha256[..12.min(r.sha256.len())],
+            metrics
+        );
+    }
+    Ok(())
+}
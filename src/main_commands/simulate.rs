@@ -0,0 +1,215 @@
+use ploy::adapters::PostgresStore;
+use ploy::agents::crypto::CryptoTradingConfig;
+use ploy::config::AppConfig;
+use ploy::domain::RiskState;
+use ploy::error::{PloyError, Result};
+use ploy::strategy::simulate::{
+    self, load_snapshot, patch_config, SimDecision, SimMarket, SimSnapshot, SimStrategy,
+    TwoLegSimConfig,
+};
+use rustyline::error::ReadlineError;
+use rustyline::DefaultEditor;
+use sqlx::PgPool;
+
+const HELP: &str = "\
+Commands:
+  markets              list active rounds with quotes and spot
+  positions            list open positions
+  use <strategy>       switch strategy (two-leg | crypto)
+  show                 print the current strategy config
+  set <key> <value>    override one config field (e.g. set min_edge 0.03)
+  reset                restore the configured defaults
+  risk <state>         assume a risk state (normal | elevated | halted)
+  eval <slug>|all      what would the strategy do right now
+  reload               reload live state from the database
+  quit                 leave the simulator
+Nothing typed here places an order.";
+
+struct Session {
+    strategy: SimStrategy,
+    two_leg: TwoLegSimConfig,
+    crypto: CryptoTradingConfig,
+    snapshot: SimSnapshot,
+}
+
+impl Session {
+    fn evaluate(&self, market: &SimMarket) -> SimDecision {
+        let now = chrono::Utc::now();
+        match self.strategy {
+            SimStrategy::TwoLeg => {
+                simulate::simulate_two_leg(&self.two_leg, market, &self.snapshot, now)
+            }
+            SimStrategy::Crypto => {
+                simulate::simulate_crypto(&self.crypto, market, &self.snapshot, now)
+            }
+        }
+    }
+
+    fn config_json(&self) -> Result<String> {
+        Ok(match self.strategy {
+            SimStrategy::TwoLeg => serde_json::to_string_pretty(&self.two_leg)?,
+            SimStrategy::Crypto => serde_json::to_string_pretty(&self.crypto)?,
+        })
+    }
+
+    fn set(&mut self, key: &str, value: &str) -> Result<()> {
+        match self.strategy {
+            SimStrategy::TwoLeg => self.two_leg = patch_config(&self.two_leg, key, value)?,
+            SimStrategy::Crypto => self.crypto = patch_config(&self.crypto, key, value)?,
+        }
+        Ok(())
+    }
+}
+
+pub(crate) async fn run_simulate_repl(strategy: SimStrategy, include_dry_run: bool) -> Result<()> {
+    let config = AppConfig::load()?;
+    let store = PostgresStore::new(&config.database.url, 2).await?;
+    let pool = store.pool();
+
+    let mut session = Session {
+        strategy,
+        two_leg: TwoLegSimConfig::from_app_config(&config),
+        crypto: CryptoTradingConfig::default(),
+        snapshot: load_snapshot(pool, include_dry_run).await?,
+    };
+    println!("Strategy simulator (what-if only, no orders are placed)");
+    print_loaded(&session.snapshot);
+    println!("Type 'help' for commands.");
+
+    let mut rl = DefaultEditor::new().map_err(|e| PloyError::Internal(e.to_string()))?;
+    loop {
+        let prompt = format!("simulate[{}]> ", session.strategy.as_str());
+        let line = match rl.readline(&prompt) {
+            Ok(line) => line,
+            Err(ReadlineError::Interrupted | ReadlineError::Eof) => break,
+            Err(e) => return Err(PloyError::Internal(format!("readline error: {}", e))),
+        };
+        let line = line.trim();
+        if line.is_empty() {
+            continue;
+        }
+        let _ = rl.add_history_entry(line);
+
+        let mut words = line.split_whitespace();
+        let command = words.next().unwrap_or_default();
+        let args: Vec<&str> = words.collect();
+        match run_line(&mut session, pool, include_dry_run, command, &args).await {
+            Ok(true) => {}
+            Ok(false) => break,
+            Err(e) => eprintln!("error: {}", e),
+        }
+    }
+    Ok(())
+}
+
+/// Execute one REPL command; returns false to quit
+async fn run_line(
+    session: &mut Session,
+    pool: &PgPool,
+    include_dry_run: bool,
+    command: &str,
+    args: &[&str],
+) -> Result<bool> {
+    match (command, args) {
+        ("quit" | "exit" | "q", _) => return Ok(false),
+        ("help" | "?", _) => println!("{}", HELP),
+        ("markets", _) => print_markets(&session.snapshot),
+        ("positions", _) => print_positions(&session.snapshot),
+        ("use", [name]) => {
+            session.strategy = <SimStrategy as clap::ValueEnum>::from_str(name, true)
+                .map_err(PloyError::Validation)?;
+        }
+        ("show", _) => println!("{}", session.config_json()?),
+        ("set", [key, value @ ..]) if !value.is_empty() => {
+            session.set(key, &value.join(" "))?;
+            println!("{} = {}", key, value.join(" "));
+        }
+        ("reset", _) => {
+            let config = AppConfig::load()?;
+            session.two_leg = TwoLegSimConfig::from_app_config(&config);
+            session.crypto = CryptoTradingConfig::default();
+            session.snapshot.risk_state = RiskState::Normal;
+            println!("configs reset");
+        }
+        ("risk", [state]) => {
+            session.snapshot.risk_state = match state.to_ascii_lowercase().as_str() {
+                "normal" => RiskState::Normal,
+                "elevated" => RiskState::Elevated,
+                "halted" => RiskState::Halted,
+                _ => {
+                    return Err(PloyError::Validation(format!(
+                        "unknown risk state '{}'",
+                        state
+                    )))
+                }
+            };
+        }
+        ("eval", ["all"]) => {
+            for market in &session.snapshot.markets {
+                println!("{}\n", session.evaluate(market));
+            }
+        }
+        ("eval", [slug]) => {
+            let market = session.snapshot.find(slug).ok_or_else(|| {
+                PloyError::Validation(format!("no active round matching '{}'", slug))
+            })?;
+            println!("{}", session.evaluate(market));
+        }
+        ("reload", _) => {
+            let risk_state = session.snapshot.risk_state;
+            session.snapshot = load_snapshot(pool, include_dry_run).await?;
+            session.snapshot.risk_state = risk_state;
+            print_loaded(&session.snapshot);
+        }
+        _ => println!("unrecognized command '{}' (type 'help')", command),
+    }
+    Ok(true)
+}
+
+fn print_loaded(snapshot: &SimSnapshot) {
+    println!(
+        "Loaded {} active rounds and {} open positions ({:.2} USD exposure) at {}",
+        snapshot.markets.len(),
+        snapshot.positions.len(),
+        snapshot.exposure(),
+        snapshot.loaded_at.format("%H:%M:%S UTC")
+    );
+}
+
+fn print_markets(snapshot: &SimSnapshot) {
+    let fmt = |v: Option<rust_decimal::Decimal>| v.map_or("-".to_string(), |d| format!("{:.3}", d));
+    println!(
+        "{:<40} {:>6} {:>11} {:>11} {:>12} {:>12}",
+        "slug", "left", "up bid/ask", "down b/a", "spot start", "spot now"
+    );
+    for m in &snapshot.markets {
+        let left = (m.round.end_time - snapshot.loaded_at).num_seconds();
+        let quote = |q: Option<&simulate::SimQuote>| {
+            q.map_or("-".to_string(), |q| {
+                format!("{}/{}", fmt(q.best_bid), fmt(q.best_ask))
+            })
+        };
+        println!(
+            "{:<40} {:>5}s {:>11} {:>11} {:>12} {:>12}",
+            m.round.slug,
+            left,
+            quote(m.up.as_ref()),
+            quote(m.down.as_ref()),
+            m.spot_start.map_or("-".to_string(), |d| d.to_string()),
+            m.spot_now.map_or("-".to_string(), |d| d.to_string()),
+        );
+    }
+}
+
+fn print_positions(snapshot: &SimSnapshot) {
+    if snapshot.positions.is_empty() {
+        println!("no open positions");
+        return;
+    }
+    for p in &snapshot.positions {
+        println!(
+            "{:<40} {:<4} {:>10} @ {:.4}",
+            p.market_slug, p.side, p.shares, p.avg_price
+        );
+    }
+}
@@ -0,0 +1,107 @@
+use std::sync::Arc;
+
+use ploy::adapters::polymarket_clob::OrderBookLevel;
+use ploy::adapters::CtfAdapter;
+use ploy::config::AppConfig;
+use ploy::error::{PloyError, Result};
+use ploy::strategy::split_merge_executor::{
+    SplitMergeExecutor, SplitMergeExecutorConfig, SplitMergeMarket,
+};
+use ploy::strategy::{detect_split_merge_opportunity, OrderExecutor};
+use rust_decimal::Decimal;
+
+pub(crate) async fn run_split_merge_command(
+    market: SplitMergeMarket,
+    sets: u64,
+    live: bool,
+) -> Result<()> {
+    if live {
+        crate::main_runtime::enforce_coordinator_only_live("ploy split-merge --live")?;
+    }
+    let config = AppConfig::load()?;
+    // The CTF adapter prices gas even in dry run
+    let private_key = std::env::var("POLYMARKET_PRIVATE_KEY")
+        .or_else(|_| std::env::var("PRIVATE_KEY"))
+        .map_err(|_| {
+            PloyError::Wallet(
+                "POLYMARKET_PRIVATE_KEY or PRIVATE_KEY environment variable not set".to_string(),
+            )
+        })?;
+    let client = crate::main_runtime::create_pm_client(&config.market.rest_url, !live).await?;
+
+    let yes_book = client.get_order_book(&market.yes_token_id).await?;
+    let no_book = client.get_order_book(&market.no_token_id).await?;
+    let (Some((yes_bid, yes_ask)), Some((no_bid, no_ask))) = (
+        touch(&yes_book.bids, &yes_book.asks),
+        touch(&no_book.bids, &no_book.asks),
+    ) else {
+        return Err(PloyError::Validation(
+            "both outcome books need a bid and an ask".to_string(),
+        ));
+    };
+
+    let Some(opportunity) =
+        detect_split_merge_opportunity(yes_bid, yes_ask, no_bid, no_ask, Decimal::ZERO)
+    else {
+        println!(
+            "No split/merge edge: bids sum {}, asks sum {}",
+            yes_bid + no_bid,
+            yes_ask + no_ask
+        );
+        return Ok(());
+    };
+
+    let executor = SplitMergeExecutor::new(
+        Arc::new(OrderExecutor::new(client, config.execution.clone())),
+        Arc::new(CtfAdapter::new(&private_key, None)?),
+        SplitMergeExecutorConfig::default(),
+    );
+    let Some(plan) = executor.plan(&opportunity, sets).await? else {
+        println!(
+            "{} edge does not clear fees, gas and the ${} profit floor",
+            opportunity.opportunity_type,
+            executor.config().min_net_profit_usd
+        );
+        return Ok(());
+    };
+    println!(
+        "{}{} {} sets: YES @ {}, NO @ {}, fees ${}, gas ${}, expected ${} (worst case ${})",
+        if live { "" } else { "[DRY RUN] " },
+        plan.opportunity_type,
+        plan.sets,
+        plan.yes_limit,
+        plan.no_limit,
+        plan.expected_fees_usd.round_dp(4),
+        plan.estimated_gas_usd.round_dp(4),
+        plan.expected_net_usd.round_dp(4),
+        plan.worst_case_net_usd.round_dp(4)
+    );
+
+    let execution = executor.execute(&market, plan).await?;
+    println!(
+        "  filled YES {} / NO {}, {} sets on chain, gas ${}, PnL ${}",
+        execution.yes_filled,
+        execution.no_filled,
+        execution.sets_on_chain,
+        execution.gas_cost_usd.round_dp(4),
+        execution.realized_pnl_usd.round_dp(4)
+    );
+    for tx_hash in &execution.tx_hashes {
+        println!("  tx {}", tx_hash);
+    }
+    if execution.has_residual() {
+        println!(
+            "  ! left {} YES / {} NO shares one-sided; close them manually",
+            execution.residual_yes, execution.residual_no
+        );
+    }
+    Ok(())
+}
+
+/// Best bid and best ask of one book
+fn touch(bids: &[OrderBookLevel], asks: &[OrderBookLevel]) -> Option<(Decimal, Decimal)> {
+    let price = |level: &OrderBookLevel| level.price.parse::<Decimal>().ok();
+    let bid = bids.iter().filter_map(price).max()?;
+    let ask = asks.iter().filter_map(price).min()?;
+    Some((bid, ask))
+}
@@ -9,7 +9,8 @@ pub(crate) async fn run_sports_command(cmd: &SportsCommands) -> Result<()> {
     use ploy::adapters::polymarket_clob::POLYGON_CHAIN_ID;
     use ploy::signing::Wallet;
     use ploy::strategy::{
-        core::SplitArbConfig, run_sports_split_arb, SportsLeague, SportsSplitArbConfig,
+        core::SplitArbConfig, run_sports_split_arb, SportsLeague, SportsMarketClass,
+        SportsSplitArbConfig,
     };
     use rust_decimal::Decimal;
     use rust_decimal_macros::dec;
@@ -25,6 +26,7 @@ pub(crate) async fn run_sports_command(cmd: &SportsCommands) -> Result<()> {
             max_unhedged,
             stop_loss,
             leagues,
+            markets,
             dry_run,
         } => {
             info!("Starting sports split-arb strategy");
@@ -45,6 +47,17 @@ pub(crate) async fn run_sports_command(cmd: &SportsCommands) -> Result<()> {
                 })
                 .collect();
 
+            let market_classes: Vec<SportsMarketClass> = markets
+                .split(',')
+                .filter_map(|m| match m.trim().to_lowercase().as_str() {
+                    "moneyline" => Some(SportsMarketClass::Moneyline),
+                    "spread" | "spreads" => Some(SportsMarketClass::Spread),
+                    "total" | "totals" => Some(SportsMarketClass::Total),
+                    "prop" | "props" | "player_prop" => Some(SportsMarketClass::PlayerProp),
+                    _ => None,
+                })
+                .collect();
+
             let config = SportsSplitArbConfig {
                 base: SplitArbConfig {
                     max_entry_price: Decimal::from_str(&format!("{:.6}", max_entry / 100.0))
@@ -60,6 +73,7 @@ pub(crate) async fn run_sports_command(cmd: &SportsCommands) -> Result<()> {
                         .unwrap_or(dec!(0.20)),
                 },
                 leagues: league_list,
+                market_classes,
             };
 
             let client = if *dry_run {
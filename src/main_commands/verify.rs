@@ -0,0 +1,68 @@
+use ploy::adapters::PostgresStore;
+use ploy::cli::runtime::VerifyCommands;
+use ploy::config::AppConfig;
+use ploy::error::{PloyError, Result};
+use ploy::services::smoke_trade;
+use std::io::Write;
+
+pub(crate) async fn run_verify_command(cmd: &VerifyCommands) -> Result<()> {
+    match cmd {
+        VerifyCommands::Live { budget, token, yes } => {
+            let config = AppConfig::load()?;
+            if !*yes {
+                print!(
+                    "This places REAL orders (up to ${} notional) on {}. Continue? [y/N] ",
+                    budget, config.market.rest_url
+                );
+                std::io::stdout().flush()?;
+                let mut input = String::new();
+                std::io::stdin().read_line(&mut input)?;
+                if !input.trim().eq_ignore_ascii_case("y") {
+                    println!("Cancelled.");
+                    return Ok(());
+                }
+            }
+
+            let store = PostgresStore::new(&config.database.url, 2).await?;
+            let client =
+                crate::main_runtime::create_pm_client(&config.market.rest_url, false).await?;
+            let report =
+                smoke_trade::run_smoke_trade(&client, store.pool(), *budget, token.as_deref())
+                    .await?;
+            smoke_trade::save_report(store.pool(), &report).await?;
+
+            println!(
+                "Live verification {} ({})",
+                report.id,
+                report
+                    .market_slug
+                    .as_deref()
+                    .or(report.token_id.as_deref())
+                    .unwrap_or("-")
+            );
+            for step in &report.steps {
+                println!(
+                    "  {} {:<9} {:>6}ms  {}",
+                    if step.passed { "✓" } else { "✗" },
+                    step.name,
+                    step.elapsed_ms,
+                    step.detail
+                );
+            }
+            if report.residual_shares > 0 {
+                println!(
+                    "  ! {} shares of {} were not sold back; close them manually",
+                    report.residual_shares,
+                    report.token_id.as_deref().unwrap_or("-")
+                );
+            }
+            if !report.passed() {
+                return Err(PloyError::Validation(
+                    "live verification failed; keep strategies off until fixed".to_string(),
+                ));
+            }
+            println!("Live path verified.");
+            Ok(())
+        }
+    }
+}
@@ -5,6 +5,7 @@ use ploy::adapters::PostgresStore;
 #[cfg(feature = "api")]
 use ploy::api::state::StrategyConfigState;
 use ploy::cli::runtime::{Cli, Commands};
+use ploy::config::secrets::{self, SecretsConfig};
 #[cfg(feature = "api")]
 use ploy::config::AppConfig;
 use ploy::error::{PloyError, Result};
@@ -14,6 +15,18 @@ use std::sync::Arc;
 use tracing::warn;
 
 pub(crate) async fn run(cli: &Cli) -> Result<()> {
+    // AppConfig::load* call sites pick the profile up from the environment
+    if let Some(profile) = &cli.profile {
+        std::env::set_var(ploy::config::PROFILE_ENV, profile);
+    }
+
+    // Export backend-held credentials before anything reads them from the
+    // environment; config commands must work even when the backend is down
+    if !matches!(cli.command, Some(Commands::Config(_))) {
+        let secrets = SecretsConfig::load_from(std::path::Path::new(&cli.config))?;
+        secrets::hydrate_env(&secrets).await?;
+    }
+
     match &cli.command {
         Some(Commands::Serve { port }) => {
             run_serve(cli, *port).await?;
@@ -54,7 +67,7 @@ pub(crate) async fn run(cli: &Cli) -> Result<()> {
             if *demo {
                 ploy::tui::run_demo().await?;
             } else {
-                crate::main_runtime::init_logging();
+                crate::main_runtime::init_logging_dashboard();
                 ploy::tui::run_dashboard_auto(series.as_deref(), cli.dry_run.unwrap_or(true))
                     .await?;
             }
@@ -78,6 +91,7 @@ pub(crate) async fn run(cli: &Cli) -> Result<()> {
             max_pages,
             base_url,
             resume_from_db,
+            compressed_depth,
         }) => {
             crate::main_runtime::init_logging();
             crate::main_modes::run_orderbook_history_mode(
@@ -92,6 +106,45 @@ pub(crate) async fn run(cli: &Cli) -> Result<()> {
                 *max_pages,
                 base_url,
                 *resume_from_db,
+                *compressed_depth,
+            )
+            .await?;
+        }
+        Some(Commands::OrderbookDepth {
+            asset_ids,
+            levels,
+            interval_ms,
+            duration_secs,
+            keep_unchanged,
+            base_url,
+        }) => {
+            crate::main_runtime::init_logging();
+            crate::main_modes::run_orderbook_depth_mode(
+                &cli.config,
+                asset_ids,
+                *levels,
+                *interval_ms,
+                *duration_secs,
+                *keep_unchanged,
+                base_url,
+            )
+            .await?;
+        }
+        Some(Commands::LobParity {
+            symbol,
+            hours,
+            limit,
+            tolerance,
+            json,
+        }) => {
+            crate::main_runtime::init_logging();
+            crate::main_modes::run_lob_parity_mode(
+                &cli.config,
+                symbol.as_deref(),
+                *hours,
+                *limit,
+                *tolerance,
+                *json,
             )
             .await?;
         }
@@ -107,6 +160,18 @@ pub(crate) async fn run(cli: &Cli) -> Result<()> {
             crate::main_runtime::init_logging();
             strategy_cmd.clone().run().await?;
         }
+        Some(Commands::Config(config_cmd)) => {
+            config_cmd
+                .clone()
+                .run_with_config(std::path::Path::new(&cli.config))
+                .await?;
+        }
+        Some(Commands::Backtest(backtest_cmd)) => {
+            crate::main_commands::backtest::run_backtest_command(backtest_cmd)?;
+        }
+        Some(Commands::Research(research_cmd)) => {
+            crate::main_commands::research::run_research_command(research_cmd).await?;
+        }
         #[cfg(feature = "rl")]
         Some(Commands::Rl(rl_cmd)) => {
             crate::main_runtime::init_logging();
@@ -125,8 +190,52 @@ pub(crate) async fn run(cli: &Cli) -> Result<()> {
             symbol,
             stats_only,
             open_only,
+            fit_fill_model,
+        }) => {
+            crate::main_modes::run_history(
+                *limit,
+                symbol.clone(),
+                *stats_only,
+                *open_only,
+                *fit_fill_model,
+            )
+            .await?;
+        }
+        Some(Commands::Journal(journal_cmd)) => {
+            crate::main_commands::journal::run_journal_command(journal_cmd).await?;
+        }
+        Some(Commands::Risk(risk_cmd)) => {
+            crate::main_commands::risk::run_risk_command(risk_cmd).await?;
+        }
+        Some(Commands::Export(export_cmd)) => {
+            crate::main_commands::export::run_export_command(export_cmd).await?;
+        }
+        Some(Commands::Verify(verify_cmd)) => {
+            crate::main_commands::verify::run_verify_command(verify_cmd).await?;
+        }
+        Some(Commands::SplitMerge {
+            condition_id,
+            yes_token,
+            no_token,
+            sets,
+            neg_risk,
+            live,
+        }) => {
+            crate::main_runtime::init_logging();
+            let market = ploy::strategy::split_merge_executor::SplitMergeMarket {
+                condition_id: condition_id.clone(),
+                yes_token_id: yes_token.clone(),
+                no_token_id: no_token.clone(),
+                neg_risk: *neg_risk,
+            };
+            crate::main_commands::split_merge::run_split_merge_command(market, *sets, *live)
+                .await?;
+        }
+        Some(Commands::Simulate {
+            strategy,
+            include_dry_run,
         }) => {
-            crate::main_modes::run_history(*limit, symbol.clone(), *stats_only, *open_only).await?;
+            crate::main_commands::simulate::run_simulate_repl(*strategy, *include_dry_run).await?;
         }
         Some(Commands::Paper {
             symbols,
@@ -134,6 +243,8 @@ pub(crate) async fn run(cli: &Cli) -> Result<()> {
             min_price_edge,
             log_file,
             stats_interval,
+            quote_latency,
+            latency_seed,
         }) => {
             crate::main_runtime::init_logging();
             crate::main_modes::run_paper_trading(
@@ -142,6 +253,8 @@ pub(crate) async fn run(cli: &Cli) -> Result<()> {
                 *min_price_edge,
                 log_file.clone(),
                 *stats_interval,
+                quote_latency.clone(),
+                *latency_seed,
             )
             .await?;
         }
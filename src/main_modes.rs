@@ -6,7 +6,9 @@ mod platform_mode;
 mod watch_modes;
 
 pub use claimer_mode::run_claimer;
-pub use collector_modes::{run_collect_mode, run_orderbook_history_mode};
+pub use collector_modes::{
+    run_collect_mode, run_lob_parity_mode, run_orderbook_depth_mode, run_orderbook_history_mode,
+};
 pub use history_mode::run_history;
 pub use paper_mode::run_paper_trading;
 pub use platform_mode::run_platform_mode;
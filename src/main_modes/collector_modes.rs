@@ -48,6 +48,7 @@ pub async fn run_collect_mode(symbols: &str, markets: Option<&str>, duration: u6
         polymarket_slugs,
         snapshot_interval_ms: 100,
         database_url: config.database.url.clone(),
+        ..SyncCollectorConfig::default()
     };
 
     // Create database pool
@@ -278,6 +279,7 @@ pub async fn run_orderbook_history_mode(
     max_pages: usize,
     base_url: &str,
     resume_from_db: bool,
+    compressed_depth: bool,
 ) -> Result<()> {
     use ploy::collector::{OrderbookHistoryCollector, OrderbookHistoryCollectorConfig};
 
@@ -303,6 +305,7 @@ pub async fn run_orderbook_history_mode(
     col_cfg.sample_ms = sample_ms;
     col_cfg.page_limit = limit;
     col_cfg.max_pages = max_pages;
+    col_cfg.store_compressed_depth = compressed_depth;
 
     let collector = OrderbookHistoryCollector::new(store.pool().clone(), col_cfg);
     collector.ensure_tables().await?;
@@ -371,3 +374,95 @@ pub async fn run_orderbook_history_mode(
 
     Ok(())
 }
+
+pub async fn run_orderbook_depth_mode(
+    config_path: &str,
+    asset_ids: &str,
+    levels: usize,
+    interval_ms: u64,
+    duration_secs: u64,
+    keep_unchanged: bool,
+    base_url: &str,
+) -> Result<()> {
+    use ploy::collector::{OrderbookDepthRecorder, OrderbookDepthRecorderConfig};
+
+    let ids: Vec<String> = asset_ids
+        .split(',')
+        .map(str::trim)
+        .filter(|s| !s.is_empty())
+        .map(|s| s.to_string())
+        .collect();
+    if ids.is_empty() {
+        return Err(PloyError::Validation(
+            "--asset-ids must contain at least one token id".to_string(),
+        ));
+    }
+
+    let cfg = AppConfig::load_from(config_path)?;
+    let store = PostgresStore::new(&cfg.database.url, 5).await?;
+
+    let rec_cfg = OrderbookDepthRecorderConfig {
+        clob_base_url: base_url.trim_end_matches('/').to_string(),
+        levels,
+        interval_ms,
+        skip_unchanged: !keep_unchanged,
+        ..Default::default()
+    };
+    let recorder = OrderbookDepthRecorder::new(store.pool().clone(), rec_cfg);
+
+    info!(
+        assets = ids.len(),
+        levels, interval_ms, duration_secs, "starting orderbook depth capture"
+    );
+
+    let duration = (duration_secs > 0).then_some(std::time::Duration::from_secs(duration_secs));
+    tokio::select! {
+        res = recorder.run(&ids, duration) => {
+            res?;
+        }
+        _ = signal::ctrl_c() => {
+            info!("orderbook depth capture stopped (Ctrl+C)");
+        }
+    }
+
+    Ok(())
+}
+
+pub async fn run_lob_parity_mode(
+    config_path: &str,
+    symbol: Option<&str>,
+    hours: i64,
+    limit: i64,
+    tolerance: f64,
+    json: bool,
+) -> Result<()> {
+    use ploy::collector::LobParityChecker;
+
+    let tolerance = Decimal::try_from(tolerance)
+        .map_err(|e| PloyError::Validation(format!("invalid --tolerance: {e}")))?;
+
+    let cfg = AppConfig::load_from(config_path)?;
+    let store = PostgresStore::new(&cfg.database.url, 5).await?;
+
+    let since = Utc::now() - chrono::Duration::hours(hours.max(1));
+    let report =
+        LobParityChecker::run(store.pool(), symbol, since, limit.max(1), tolerance).await?;
+
+    if json {
+        println!("{}", serde_json::to_string_pretty(&report)?);
+    } else {
+        println!("{report}");
+    }
+
+    if report.ticks == 0 {
+        warn!("no recorded LOB ticks with levels in the selected window");
+    }
+    if !report.passed() {
+        return Err(PloyError::Validation(format!(
+            "LOB feature parity failed: {} divergences above tolerance {}",
+            report.violations(),
+            tolerance
+        )));
+    }
+    Ok(())
+}
@@ -5,6 +5,7 @@ pub async fn run_history(
     symbol: Option<String>,
     stats_only: bool,
     open_only: bool,
+    fit_fill_model: bool,
 ) -> Result<()> {
     use ploy::strategy::TradeLogger;
     use std::path::PathBuf;
@@ -15,6 +16,10 @@ pub async fn run_history(
         eprintln!("Warning: Could not load trades: {}", e);
     }
 
+    if fit_fill_model {
+        return fit_and_save_fill_model(&logger).await;
+    }
+
     let stats = logger.get_stats().await;
 
     if stats.total_trades == 0 {
@@ -79,3 +84,21 @@ pub async fn run_history(
     println!();
     Ok(())
 }
+
+async fn fit_and_save_fill_model(logger: &ploy::strategy::TradeLogger) -> Result<()> {
+    use ploy::strategy::FillModel;
+    use std::path::Path;
+
+    let trades = logger.get_all_trades().await;
+    let model = FillModel::fit_trades(&trades)?;
+    model.save(Path::new(FillModel::DEFAULT_PATH))?;
+
+    println!(
+        "\n  Fill model fitted from {} orders ({} filled)",
+        model.samples, model.filled_samples
+    );
+    println!("  Fill coefficients:     {:?}", model.fill_coefficients);
+    println!("  Slippage coefficients: {:?}", model.slippage_coefficients);
+    println!("  Saved to: {}\n", FillModel::DEFAULT_PATH);
+    Ok(())
+}
@@ -7,7 +7,10 @@ pub async fn run_paper_trading(
     min_price_edge: f64,
     log_file: String,
     stats_interval: u64,
+    quote_latency: Option<String>,
+    latency_seed: Option<u64>,
 ) -> Result<()> {
+    use ploy::exchange::{LatencyConfig, LatencyDistribution};
     use ploy::strategy::{run_paper_trading, PaperTradingConfig, VolatilityArbConfig};
     use rust_decimal::Decimal;
     use rust_decimal_macros::dec;
@@ -33,6 +36,17 @@ pub async fn run_paper_trading(
         Decimal::from_f64_retain(min_price_edge / 100.0).unwrap_or(dec!(0.02));
     vol_arb_config.symbols = symbols.clone();
 
+    let quote = quote_latency
+        .as_deref()
+        .map(str::parse::<LatencyDistribution>)
+        .transpose()?;
+    let latency = LatencyConfig {
+        enabled: quote.is_some(),
+        quote,
+        seed: latency_seed,
+        ..LatencyConfig::default()
+    };
+
     let config = PaperTradingConfig {
         vol_arb_config,
         symbols,
@@ -40,6 +54,7 @@ pub async fn run_paper_trading(
         kline_update_interval_secs: 60,
         stats_interval_secs: stats_interval,
         log_file: Some(log_file),
+        latency,
     };
 
     let pm_client = PolymarketClient::new("https://clob.polymarket.com", true)?;
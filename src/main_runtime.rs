@@ -31,6 +31,16 @@ pub fn enforce_coordinator_only_live(cmd: &str) -> Result<()> {
 }
 
 pub fn init_logging() {
+    init_logging_with(None);
+}
+
+/// Logging for the dashboard: the console writer would corrupt the terminal,
+/// so events go to the dashboard's log pane (and the log file) instead.
+pub fn init_logging_dashboard() {
+    init_logging_with(Some(ploy::tui::logs::install_log_tail()));
+}
+
+fn init_logging_with(log_tail: Option<ploy::tui::logs::LogTailLayer>) {
     use tracing_subscriber::layer::SubscriberExt;
     use tracing_subscriber::util::SubscriberInitExt;
 
@@ -87,19 +97,49 @@ pub fn init_logging() {
     };
 
     // Console layer
-    let console_layer = tracing_subscriber::fmt::layer()
-        .with_target(true)
-        .with_thread_ids(false)
-        .with_file(false)
-        .with_line_number(false);
+    let console_layer = log_tail.is_none().then(|| {
+        tracing_subscriber::fmt::layer()
+            .with_target(true)
+            .with_thread_ids(false)
+            .with_file(false)
+            .with_line_number(false)
+    });
 
     // Combine layers
     let file_logging_enabled = file_layer.is_some();
-    tracing_subscriber::registry()
+    let registry = tracing_subscriber::registry()
         .with(filter)
         .with(console_layer)
         .with(file_layer)
-        .init();
+        .with(log_tail);
+
+    // OTLP trace export (quote → signal → risk → order → fill spans)
+    #[cfg(feature = "telemetry")]
+    let (registry, otlp) = {
+        use ploy::services::telemetry::{otlp_layer, OtlpConfig};
+        let config = OtlpConfig::from_env();
+        let (layer, otlp) = match config.as_ref().map(otlp_layer) {
+            Some(Ok(layer)) => (Some(layer), config.map(Ok)),
+            Some(Err(e)) => (None, Some(Err(e))),
+            None => (None, None),
+        };
+        (registry.with(layer), otlp)
+    };
+
+    registry.init();
+
+    #[cfg(feature = "telemetry")]
+    match otlp {
+        Some(Ok(config)) => tracing::info!(
+            endpoint = %config.endpoint,
+            service = %config.service_name,
+            "OpenTelemetry tracing export enabled"
+        ),
+        Some(Err(e)) => {
+            tracing::warn!(error = %e, "OpenTelemetry init failed; tracing export disabled")
+        }
+        None => {}
+    }
 
     if file_logging_enabled {
         eprintln!("Logging to: {}/ploy.log", log_dir);
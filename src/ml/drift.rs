@@ -0,0 +1,491 @@
+//! Live model drift detection.
+//!
+//! Compares rolling live feature distributions against a training-time
+//! baseline (PSI + binned KS) and tracks predicted-vs-realized calibration
+//! (Brier score). Agents feed observations in and act on the resulting
+//! [`DriftStatus`] (alert, optionally pause entries).
+
+use crate::error::{PloyError, Result};
+use serde::{Deserialize, Serialize};
+use std::collections::{HashMap, VecDeque};
+use std::path::Path;
+
+/// Floor for bin fractions so PSI stays finite on empty bins.
+const PSI_EPSILON: f64 = 1e-4;
+
+/// Training-time distribution of a single feature (quantile bins).
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct FeatureBaseline {
+    pub name: String,
+    /// Ascending interior cut points; `edges.len() + 1` bins.
+    pub edges: Vec<f64>,
+    /// Expected fraction of samples per bin (sums to ~1).
+    pub expected: Vec<f64>,
+}
+
+impl FeatureBaseline {
+    /// Build a quantile-binned baseline from a training sample.
+    pub fn from_sample(name: &str, sample: &[f64], bins: usize) -> Self {
+        let mut sorted: Vec<f64> = sample.iter().copied().filter(|v| v.is_finite()).collect();
+        sorted.sort_by(|a, b| a.total_cmp(b));
+
+        let bins = bins.max(2);
+        let mut edges = Vec::with_capacity(bins - 1);
+        if !sorted.is_empty() {
+            for i in 1..bins {
+                let idx = (i * sorted.len() / bins).min(sorted.len() - 1);
+                let edge = sorted[idx];
+                if !edges.last().is_some_and(|last| edge <= *last) {
+                    edges.push(edge);
+                }
+            }
+        }
+
+        let mut baseline = Self {
+            name: name.to_string(),
+            edges,
+            expected: Vec::new(),
+        };
+        baseline.expected = baseline.fractions(sorted.iter().copied());
+        baseline
+    }
+
+    fn bin_of(&self, value: f64) -> usize {
+        self.edges.partition_point(|edge| *edge <= value)
+    }
+
+    fn fractions(&self, values: impl Iterator<Item = f64>) -> Vec<f64> {
+        let mut counts = vec![0usize; self.edges.len() + 1];
+        let mut total = 0usize;
+        for v in values.filter(|v| v.is_finite()) {
+            counts[self.bin_of(v)] += 1;
+            total += 1;
+        }
+        if total == 0 {
+            return vec![0.0; counts.len()];
+        }
+        counts
+            .into_iter()
+            .map(|c| c as f64 / total as f64)
+            .collect()
+    }
+
+    /// Population stability index of `actual` fractions vs the baseline.
+    pub fn psi(&self, actual: &[f64]) -> f64 {
+        self.expected
+            .iter()
+            .zip(actual)
+            .map(|(e, a)| {
+                let e = e.max(PSI_EPSILON);
+                let a = a.max(PSI_EPSILON);
+                (a - e) * (a / e).ln()
+            })
+            .sum()
+    }
+
+    /// Kolmogorov-Smirnov statistic computed on the baseline bins.
+    pub fn ks(&self, actual: &[f64]) -> f64 {
+        let mut cum_e = 0.0;
+        let mut cum_a = 0.0;
+        let mut max_gap: f64 = 0.0;
+        for (e, a) in self.expected.iter().zip(actual) {
+            cum_e += e;
+            cum_a += a;
+            max_gap = max_gap.max((cum_e - cum_a).abs());
+        }
+        max_gap
+    }
+}
+
+/// Training-time baseline for one model (written by the training pipeline).
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+pub struct DriftBaseline {
+    #[serde(default)]
+    pub model_version: Option<String>,
+    /// One entry per model input feature, in input order.
+    pub features: Vec<FeatureBaseline>,
+    /// Validation Brier score at training time.
+    #[serde(default)]
+    pub brier_score: Option<f64>,
+}
+
+impl DriftBaseline {
+    pub fn load(path: impl AsRef<Path>) -> Result<Self> {
+        let path = path.as_ref();
+        let raw = std::fs::read_to_string(path).map_err(|e| {
+            PloyError::Validation(format!(
+                "failed to read drift baseline {}: {}",
+                path.display(),
+                e
+            ))
+        })?;
+        Ok(serde_json::from_str(&raw)?)
+    }
+}
+
+/// Drift monitoring thresholds
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(default)]
+pub struct DriftConfig {
+    pub enabled: bool,
+    /// JSON [`DriftBaseline`] produced at training time.
+    pub baseline_path: Option<String>,
+    /// Rolling window of live observations per feature.
+    pub window: usize,
+    /// Minimum observations before feature drift is evaluated.
+    pub min_samples: usize,
+    pub psi_warn: f64,
+    pub psi_critical: f64,
+    pub ks_warn: f64,
+    pub ks_critical: f64,
+    /// Rolling window of resolved predictions for calibration.
+    pub outcome_window: usize,
+    /// Minimum resolved predictions before calibration is evaluated.
+    pub min_outcomes: usize,
+    /// Brier score increase over baseline treated as critical.
+    pub max_brier_increase: f64,
+    /// Pause new entries while drift is critical.
+    pub pause_on_critical: bool,
+    /// Consecutive stable checks before a drift pause lifts (0 = stay paused
+    /// until the coordinator resumes the agent).
+    pub resume_after_stable_checks: u32,
+    /// How often agents re-evaluate drift (seconds).
+    pub check_interval_secs: u64,
+}
+
+impl Default for DriftConfig {
+    fn default() -> Self {
+        Self {
+            enabled: false,
+            baseline_path: None,
+            window: 2000,
+            min_samples: 300,
+            psi_warn: 0.10,
+            psi_critical: 0.25,
+            ks_warn: 0.10,
+            ks_critical: 0.20,
+            outcome_window: 200,
+            min_outcomes: 30,
+            max_brier_increase: 0.05,
+            pause_on_critical: false,
+            resume_after_stable_checks: 3,
+            check_interval_secs: 60,
+        }
+    }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum DriftStatus {
+    /// Not enough data yet
+    Insufficient,
+    Stable,
+    Warning,
+    Critical,
+}
+
+impl DriftStatus {
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            DriftStatus::Insufficient => "insufficient",
+            DriftStatus::Stable => "stable",
+            DriftStatus::Warning => "warning",
+            DriftStatus::Critical => "critical",
+        }
+    }
+}
+
+#[derive(Debug, Clone, Serialize)]
+pub struct FeatureDrift {
+    pub name: String,
+    pub psi: f64,
+    pub ks: f64,
+}
+
+#[derive(Debug, Clone, Serialize)]
+pub struct DriftReport {
+    pub status: DriftStatus,
+    pub samples: usize,
+    pub features: Vec<FeatureDrift>,
+    pub outcomes: usize,
+    pub brier_score: Option<f64>,
+    pub baseline_brier_score: Option<f64>,
+    /// Human-readable reasons for Warning/Critical status.
+    pub reasons: Vec<String>,
+}
+
+impl DriftReport {
+    pub fn max_psi(&self) -> f64 {
+        self.features.iter().map(|f| f.psi).fold(0.0, f64::max)
+    }
+
+    pub fn max_ks(&self) -> f64 {
+        self.features.iter().map(|f| f.ks).fold(0.0, f64::max)
+    }
+
+    /// One-line summary for logs and alerts.
+    pub fn summary(&self) -> String {
+        let mut out = format!(
+            "drift={} samples={} max_psi={:.3} max_ks={:.3}",
+            self.status.as_str(),
+            self.samples,
+            self.max_psi(),
+            self.max_ks()
+        );
+        if let Some(brier) = self.brier_score {
+            out.push_str(&format!(" brier={:.4} outcomes={}", brier, self.outcomes));
+        }
+        if !self.reasons.is_empty() {
+            out.push_str(&format!(" [{}]", self.reasons.join("; ")));
+        }
+        out
+    }
+
+    /// Add drift metrics to an agent heartbeat metrics map.
+    pub fn insert_metrics(&self, metrics: &mut HashMap<String, String>) {
+        metrics.insert("drift_status".to_string(), self.status.as_str().to_string());
+        metrics.insert("drift_samples".to_string(), self.samples.to_string());
+        metrics.insert(
+            "drift_max_psi".to_string(),
+            format!("{:.4}", self.max_psi()),
+        );
+        metrics.insert("drift_max_ks".to_string(), format!("{:.4}", self.max_ks()));
+        if let Some(brier) = self.brier_score {
+            metrics.insert("drift_brier".to_string(), format!("{:.4}", brier));
+            metrics.insert("drift_outcomes".to_string(), self.outcomes.to_string());
+        }
+    }
+}
+
+/// Rolling drift monitor for one model
+#[derive(Debug)]
+pub struct DriftMonitor {
+    config: DriftConfig,
+    baseline: DriftBaseline,
+    windows: Vec<VecDeque<f64>>,
+    outcomes: VecDeque<(f64, bool)>,
+    /// Consecutive stable reports seen by [`DriftMonitor::should_resume`]
+    stable_streak: u32,
+}
+
+impl DriftMonitor {
+    pub fn new(config: DriftConfig, baseline: DriftBaseline) -> Self {
+        let windows = vec![VecDeque::new(); baseline.features.len()];
+        Self {
+            config,
+            baseline,
+            windows,
+            outcomes: VecDeque::new(),
+            stable_streak: 0,
+        }
+    }
+
+    /// Build from config, loading the baseline file. Returns `None` when disabled.
+    pub fn from_config(config: &DriftConfig) -> Result<Option<Self>> {
+        if !config.enabled {
+            return Ok(None);
+        }
+        let path = config.baseline_path.as_deref().ok_or_else(|| {
+            PloyError::Validation("drift.enabled requires drift.baseline_path".to_string())
+        })?;
+        let baseline = DriftBaseline::load(path)?;
+        Ok(Some(Self::new(config.clone(), baseline)))
+    }
+
+    pub fn config(&self) -> &DriftConfig {
+        &self.config
+    }
+
+    /// Record one live feature vector (same order as the baseline features).
+    pub fn observe(&mut self, features: &[f64]) {
+        let cap = self.config.window.max(1);
+        for (window, value) in self.windows.iter_mut().zip(features) {
+            if !value.is_finite() {
+                continue;
+            }
+            if window.len() >= cap {
+                window.pop_front();
+            }
+            window.push_back(*value);
+        }
+    }
+
+    /// Record a resolved prediction (predicted P(up) vs realized outcome).
+    pub fn record_outcome(&mut self, predicted: f64, realized_up: bool) {
+        if !predicted.is_finite() {
+            return;
+        }
+        if self.outcomes.len() >= self.config.outcome_window.max(1) {
+            self.outcomes.pop_front();
+        }
+        self.outcomes
+            .push_back((predicted.clamp(0.0, 1.0), realized_up));
+    }
+
+    pub fn report(&self) -> DriftReport {
+        let samples = self.windows.iter().map(VecDeque::len).min().unwrap_or(0);
+        let mut status = DriftStatus::Insufficient;
+        let mut reasons = Vec::new();
+        let mut features = Vec::new();
+
+        if samples >= self.config.min_samples && !self.windows.is_empty() {
+            status = DriftStatus::Stable;
+            for (baseline, window) in self.baseline.features.iter().zip(&self.windows) {
+                let actual = baseline.fractions(window.iter().copied());
+                let psi = baseline.psi(&actual);
+                let ks = baseline.ks(&actual);
+
+                if psi >= self.config.psi_critical || ks >= self.config.ks_critical {
+                    status = DriftStatus::Critical;
+                    reasons.push(format!("{} psi={:.3} ks={:.3}", baseline.name, psi, ks));
+                } else if psi >= self.config.psi_warn || ks >= self.config.ks_warn {
+                    status = status.max(DriftStatus::Warning);
+                    reasons.push(format!("{} psi={:.3} ks={:.3}", baseline.name, psi, ks));
+                }
+                features.push(FeatureDrift {
+                    name: baseline.name.clone(),
+                    psi,
+                    ks,
+                });
+            }
+        }
+
+        let brier_score = (self.outcomes.len() >= self.config.min_outcomes.max(1)).then(|| {
+            self.outcomes
+                .iter()
+                .map(|(p, up)| {
+                    let y = if *up { 1.0 } else { 0.0 };
+                    (p - y).powi(2)
+                })
+                .sum::<f64>()
+                / self.outcomes.len() as f64
+        });
+
+        if let (Some(live), Some(base)) = (brier_score, self.baseline.brier_score) {
+            status = status.max(DriftStatus::Stable);
+            if live - base >= self.config.max_brier_increase {
+                status = DriftStatus::Critical;
+                reasons.push(format!("brier {:.4} vs baseline {:.4}", live, base));
+            }
+        }
+
+        DriftReport {
+            status,
+            samples,
+            features,
+            outcomes: self.outcomes.len(),
+            brier_score,
+            baseline_brier_score: self.baseline.brier_score,
+            reasons,
+        }
+    }
+
+    /// Whether entries should be paused for this report.
+    pub fn should_pause(&self, report: &DriftReport) -> bool {
+        self.config.pause_on_critical && report.status == DriftStatus::Critical
+    }
+
+    /// Track consecutive stable reports and say whether a drift pause may
+    /// lift. Warning and insufficient reports reset the streak, so a status
+    /// hovering around the critical threshold does not flap entries on/off.
+    pub fn should_resume(&mut self, report: &DriftReport) -> bool {
+        if report.status == DriftStatus::Stable {
+            self.stable_streak = self.stable_streak.saturating_add(1);
+        } else {
+            self.stable_streak = 0;
+        }
+        self.config.resume_after_stable_checks > 0
+            && self.stable_streak >= self.config.resume_after_stable_checks
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn uniform(n: usize, lo: f64, hi: f64) -> Vec<f64> {
+        (0..n)
+            .map(|i| lo + (hi - lo) * i as f64 / n as f64)
+            .collect()
+    }
+
+    fn monitor(config: DriftConfig) -> DriftMonitor {
+        let baseline = DriftBaseline {
+            model_version: None,
+            features: vec![FeatureBaseline::from_sample(
+                "x",
+                &uniform(1000, 0.0, 1.0),
+                10,
+            )],
+            brier_score: Some(0.20),
+        };
+        DriftMonitor::new(config, baseline)
+    }
+
+    #[test]
+    fn test_same_distribution_is_stable() {
+        let mut m = monitor(DriftConfig {
+            min_samples: 100,
+            ..Default::default()
+        });
+        for v in uniform(500, 0.0, 1.0) {
+            m.observe(&[v]);
+        }
+        let report = m.report();
+        assert_eq!(report.status, DriftStatus::Stable);
+        assert!(report.max_psi() < 0.01);
+    }
+
+    #[test]
+    fn test_shifted_distribution_is_critical_and_pauses() {
+        let mut m = monitor(DriftConfig {
+            window: 500,
+            min_samples: 100,
+            pause_on_critical: true,
+            ..Default::default()
+        });
+        for v in uniform(500, 0.6, 1.6) {
+            m.observe(&[v]);
+        }
+        let report = m.report();
+        assert_eq!(report.status, DriftStatus::Critical);
+        assert!(report.max_ks() > 0.5);
+        assert!(m.should_pause(&report));
+        assert!(!m.should_resume(&report));
+
+        // Only consecutive stable checks lift the pause
+        for v in uniform(500, 0.0, 1.0) {
+            m.observe(&[v]);
+        }
+        let stable = m.report();
+        assert_eq!(stable.status, DriftStatus::Stable);
+        assert!(!m.should_resume(&stable));
+        assert!(!m.should_resume(&stable));
+        assert!(!m.should_resume(&report));
+        assert!(!m.should_resume(&stable));
+        assert!(!m.should_resume(&stable));
+        assert!(m.should_resume(&stable));
+    }
+
+    #[test]
+    fn test_insufficient_samples() {
+        let mut m = monitor(DriftConfig::default());
+        m.observe(&[0.5]);
+        assert_eq!(m.report().status, DriftStatus::Insufficient);
+    }
+
+    #[test]
+    fn test_calibration_degradation_is_critical() {
+        let mut m = monitor(DriftConfig {
+            min_outcomes: 10,
+            ..Default::default()
+        });
+        // Confident and wrong: Brier ~0.81 vs baseline 0.20.
+        for _ in 0..20 {
+            m.record_outcome(0.9, false);
+        }
+        let report = m.report();
+        assert_eq!(report.status, DriftStatus::Critical);
+        assert!(report.brier_score.unwrap() > 0.8);
+    }
+}
@@ -0,0 +1,498 @@
+//! Point-in-time feature store.
+//!
+//! Raw market signals (Binance OBI and spot, implied vol, funding, Polymarket
+//! spread) are recorded once with their event time and the time they became
+//! known. A versioned [`FeatureSetSpec`] turns them into feature vectors as of
+//! any instant using only data observable at that instant, so offline
+//! training rows ([`FeatureStore::materialize`]) and the online vector
+//! ([`FeatureStore::vector`]) come from the same definitions.
+//!
+//! Online agents share one [`SharedFeatureStore`]: each decision tick records
+//! its raw inputs ([`CryptoTick`]) and reads the versioned vector back, which
+//! is stamped onto entry intents so training rows can be rebuilt from the
+//! same definitions.
+
+use crate::error::{PloyError, Result};
+use chrono::{DateTime, Duration, Utc};
+use serde::{Deserialize, Serialize};
+use std::collections::HashMap;
+use std::path::Path;
+use std::sync::{Arc, RwLock};
+
+/// Raw signal recorded per entity (symbol or market).
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Serialize, Deserialize)]
+#[serde(tag = "kind", rename_all = "snake_case")]
+pub enum Signal {
+    /// Order book imbalance over the top `levels`
+    Obi { levels: u32 },
+    /// Spot / reference price
+    Spot,
+    /// Annualized implied volatility
+    ImpliedVol,
+    /// Perp funding rate
+    Funding,
+    /// Polymarket best ask minus best bid
+    PmSpread,
+}
+
+/// How one feature is derived from raw signals.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[serde(tag = "kind", rename_all = "snake_case")]
+pub enum FeatureDef {
+    Obi {
+        levels: u32,
+    },
+    /// Spot return over the lookback
+    Momentum {
+        lookback_secs: u64,
+    },
+    ImpliedVol,
+    Funding,
+    PmSpread,
+}
+
+impl FeatureDef {
+    fn signal(&self) -> Signal {
+        match self {
+            Self::Obi { levels } => Signal::Obi { levels: *levels },
+            Self::Momentum { .. } => Signal::Spot,
+            Self::ImpliedVol => Signal::ImpliedVol,
+            Self::Funding => Signal::Funding,
+            Self::PmSpread => Signal::PmSpread,
+        }
+    }
+}
+
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct FeatureSpec {
+    pub name: String,
+    #[serde(flatten)]
+    pub def: FeatureDef,
+    /// Inputs older than this (at the as-of time) yield a missing value.
+    pub max_age_secs: u64,
+}
+
+impl FeatureSpec {
+    pub fn new(name: &str, def: FeatureDef, max_age_secs: u64) -> Self {
+        Self {
+            name: name.to_string(),
+            def,
+            max_age_secs,
+        }
+    }
+}
+
+/// Named, versioned list of features in model input order.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct FeatureSetSpec {
+    pub name: String,
+    pub version: u32,
+    pub features: Vec<FeatureSpec>,
+}
+
+impl FeatureSetSpec {
+    /// Crypto round features shared by the RL and LOB-ML agents.
+    pub fn crypto_v1() -> Self {
+        Self {
+            name: "crypto".to_string(),
+            version: 1,
+            features: vec![
+                FeatureSpec::new("obi_5", FeatureDef::Obi { levels: 5 }, 5),
+                FeatureSpec::new("obi_10", FeatureDef::Obi { levels: 10 }, 5),
+                FeatureSpec::new("momentum_5s", FeatureDef::Momentum { lookback_secs: 5 }, 5),
+                FeatureSpec::new(
+                    "momentum_60s",
+                    FeatureDef::Momentum { lookback_secs: 60 },
+                    5,
+                ),
+                FeatureSpec::new("implied_vol", FeatureDef::ImpliedVol, 300),
+                FeatureSpec::new("funding_rate", FeatureDef::Funding, 3600),
+                FeatureSpec::new("pm_spread", FeatureDef::PmSpread, 10),
+            ],
+        }
+    }
+
+    pub fn names(&self) -> Vec<String> {
+        self.features.iter().map(|f| f.name.clone()).collect()
+    }
+
+    /// Stable hash of the definitions (FNV-1a over canonical JSON).
+    ///
+    /// Stored with materialized data so a model is never served features from
+    /// a different definition under the same name/version.
+    pub fn fingerprint(&self) -> String {
+        let canonical = serde_json::to_string(&self.features).unwrap_or_default();
+        let hash = canonical.bytes().fold(0xcbf2_9ce4_8422_2325u64, |h, b| {
+            (h ^ b as u64).wrapping_mul(0x0100_0000_01b3)
+        });
+        format!("{:016x}", hash)
+    }
+}
+
+#[derive(Debug, Clone, Copy)]
+struct Observation {
+    event_time: DateTime<Utc>,
+    /// When the value became known to us (>= event_time for late data)
+    available_at: DateTime<Utc>,
+    value: f64,
+}
+
+/// One feature vector as of a point in time.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct FeatureRow {
+    pub as_of: DateTime<Utc>,
+    /// `None` where inputs were missing or stale
+    pub values: Vec<Option<f64>>,
+}
+
+impl FeatureRow {
+    pub fn is_complete(&self) -> bool {
+        self.values.iter().all(Option::is_some)
+    }
+
+    /// Intent metadata: set name/version, fingerprint and every present value
+    /// as `fs_<name>`.
+    pub fn metadata(&self, spec: &FeatureSetSpec) -> Vec<(String, String)> {
+        let mut out = vec![
+            (
+                "feature_set".to_string(),
+                format!("{}@v{}", spec.name, spec.version),
+            ),
+            ("feature_fingerprint".to_string(), spec.fingerprint()),
+        ];
+        out.extend(
+            spec.features
+                .iter()
+                .zip(&self.values)
+                .filter_map(|(f, v)| v.map(|v| (format!("fs_{}", f.name), format!("{v:.6}")))),
+        );
+        out
+    }
+}
+
+/// Materialized training rows tagged with the definitions that produced them.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct MaterializedFeatures {
+    pub set: String,
+    pub version: u32,
+    pub fingerprint: String,
+    pub entity: String,
+    pub names: Vec<String>,
+    pub rows: Vec<FeatureRow>,
+}
+
+impl MaterializedFeatures {
+    pub fn save(&self, path: impl AsRef<Path>) -> Result<()> {
+        let path = path.as_ref();
+        if let Some(parent) = path.parent() {
+            std::fs::create_dir_all(parent)?;
+        }
+        std::fs::write(path, serde_json::to_string(self)?)?;
+        Ok(())
+    }
+
+    /// Load rows, refusing ones built from different definitions than `spec`.
+    pub fn load(path: impl AsRef<Path>, spec: &FeatureSetSpec) -> Result<Self> {
+        let path = path.as_ref();
+        let raw = std::fs::read_to_string(path).map_err(|e| {
+            PloyError::Validation(format!(
+                "failed to read feature set {}: {}",
+                path.display(),
+                e
+            ))
+        })?;
+        let materialized: Self = serde_json::from_str(&raw)?;
+        if materialized.fingerprint != spec.fingerprint() {
+            return Err(PloyError::Validation(format!(
+                "feature set {} v{} in {} does not match {} v{} definitions",
+                materialized.set,
+                materialized.version,
+                path.display(),
+                spec.name,
+                spec.version
+            )));
+        }
+        Ok(materialized)
+    }
+}
+
+/// Event-time indexed raw signals per entity.
+#[derive(Debug, Default)]
+pub struct FeatureStore {
+    series: HashMap<(String, Signal), Vec<Observation>>,
+    retention: Option<Duration>,
+}
+
+impl FeatureStore {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Drop observations older than `retention` behind the newest one (online use).
+    pub fn with_retention(mut self, retention: Duration) -> Self {
+        self.retention = Some(retention);
+        self
+    }
+
+    /// Record a value known as soon as it happened.
+    pub fn record(&mut self, entity: &str, signal: Signal, event_time: DateTime<Utc>, value: f64) {
+        self.record_at(entity, signal, event_time, event_time, value);
+    }
+
+    /// Record a value that only became known at `available_at` (late data).
+    pub fn record_at(
+        &mut self,
+        entity: &str,
+        signal: Signal,
+        event_time: DateTime<Utc>,
+        available_at: DateTime<Utc>,
+        value: f64,
+    ) {
+        if !value.is_finite() {
+            return;
+        }
+        let series = self.series.entry((entity.to_string(), signal)).or_default();
+        let obs = Observation {
+            event_time,
+            available_at: available_at.max(event_time),
+            value,
+        };
+        let idx = series.partition_point(|o| o.event_time <= event_time);
+        series.insert(idx, obs);
+
+        if let (Some(retention), Some(newest)) = (self.retention, series.last()) {
+            let cutoff = newest.event_time - retention;
+            let stale = series.partition_point(|o| o.event_time < cutoff);
+            series.drain(..stale);
+        }
+    }
+
+    /// Latest value with event time <= `as_of` that was already known at `as_of`.
+    fn value_as_of(
+        &self,
+        entity: &str,
+        signal: Signal,
+        event_cutoff: DateTime<Utc>,
+        known_at: DateTime<Utc>,
+        max_age: Duration,
+    ) -> Option<f64> {
+        let series = self.series.get(&(entity.to_string(), signal))?;
+        let end = series.partition_point(|o| o.event_time <= event_cutoff);
+        series[..end]
+            .iter()
+            .rev()
+            .take_while(|o| event_cutoff - o.event_time <= max_age)
+            .find(|o| o.available_at <= known_at)
+            .map(|o| o.value)
+    }
+
+    fn feature_as_of(&self, entity: &str, spec: &FeatureSpec, as_of: DateTime<Utc>) -> Option<f64> {
+        let max_age = Duration::seconds(spec.max_age_secs as i64);
+        let signal = spec.def.signal();
+        match spec.def {
+            FeatureDef::Momentum { lookback_secs } => {
+                let now = self.value_as_of(entity, signal, as_of, as_of, max_age)?;
+                let then_at = as_of - Duration::seconds(lookback_secs as i64);
+                let then = self.value_as_of(entity, signal, then_at, as_of, max_age)?;
+                (then != 0.0).then(|| now / then - 1.0)
+            }
+            _ => self.value_as_of(entity, signal, as_of, as_of, max_age),
+        }
+    }
+
+    /// Feature vector for online inference.
+    pub fn vector(&self, spec: &FeatureSetSpec, entity: &str, as_of: DateTime<Utc>) -> FeatureRow {
+        FeatureRow {
+            as_of,
+            values: spec
+                .features
+                .iter()
+                .map(|f| self.feature_as_of(entity, f, as_of))
+                .collect(),
+        }
+    }
+
+    /// Point-in-time rows for offline training, one per label timestamp.
+    pub fn materialize(
+        &self,
+        spec: &FeatureSetSpec,
+        entity: &str,
+        as_of: &[DateTime<Utc>],
+    ) -> MaterializedFeatures {
+        MaterializedFeatures {
+            set: spec.name.clone(),
+            version: spec.version,
+            fingerprint: spec.fingerprint(),
+            entity: entity.to_string(),
+            names: spec.names(),
+            rows: as_of
+                .iter()
+                .map(|t| self.vector(spec, entity, *t))
+                .collect(),
+        }
+    }
+}
+
+/// Raw crypto signals observed at one agent decision tick.
+#[derive(Debug, Clone, Copy, Default)]
+pub struct CryptoTick {
+    pub spot: Option<f64>,
+    pub obi_5: Option<f64>,
+    pub obi_10: Option<f64>,
+    pub implied_vol: Option<f64>,
+    pub funding: Option<f64>,
+    pub pm_spread: Option<f64>,
+}
+
+impl FeatureStore {
+    /// Record every signal present in `tick` for `entity`.
+    pub fn record_crypto_tick(&mut self, entity: &str, at: DateTime<Utc>, tick: &CryptoTick) {
+        let signals = [
+            (Signal::Spot, tick.spot),
+            (Signal::Obi { levels: 5 }, tick.obi_5),
+            (Signal::Obi { levels: 10 }, tick.obi_10),
+            (Signal::ImpliedVol, tick.implied_vol),
+            (Signal::Funding, tick.funding),
+            (Signal::PmSpread, tick.pm_spread),
+        ];
+        for (signal, value) in signals {
+            if let Some(value) = value {
+                self.record(entity, signal, at, value);
+            }
+        }
+    }
+}
+
+/// [`FeatureStore`] shared by the online agents that write and read it.
+#[derive(Debug, Clone, Default)]
+pub struct SharedFeatureStore {
+    inner: Arc<RwLock<FeatureStore>>,
+}
+
+impl SharedFeatureStore {
+    pub fn new(store: FeatureStore) -> Self {
+        Self {
+            inner: Arc::new(RwLock::new(store)),
+        }
+    }
+
+    pub fn record_crypto_tick(&self, entity: &str, at: DateTime<Utc>, tick: &CryptoTick) {
+        let mut store = match self.inner.write() {
+            Ok(store) => store,
+            Err(poisoned) => poisoned.into_inner(),
+        };
+        store.record_crypto_tick(entity, at, tick);
+    }
+
+    pub fn vector(&self, spec: &FeatureSetSpec, entity: &str, as_of: DateTime<Utc>) -> FeatureRow {
+        let store = match self.inner.read() {
+            Ok(store) => store,
+            Err(poisoned) => poisoned.into_inner(),
+        };
+        store.vector(spec, entity, as_of)
+    }
+
+    pub fn materialize(
+        &self,
+        spec: &FeatureSetSpec,
+        entity: &str,
+        as_of: &[DateTime<Utc>],
+    ) -> MaterializedFeatures {
+        let store = match self.inner.read() {
+            Ok(store) => store,
+            Err(poisoned) => poisoned.into_inner(),
+        };
+        store.materialize(spec, entity, as_of)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_point_in_time_ignores_future_and_late_data() {
+        let t0 = Utc::now();
+        let mut store = FeatureStore::new();
+        store.record("BTCUSDT", Signal::Spot, t0, 100.0);
+        store.record("BTCUSDT", Signal::Spot, t0 + Duration::seconds(5), 101.0);
+        store.record("BTCUSDT", Signal::Spot, t0 + Duration::seconds(9), 150.0);
+        // OBI at t0+4 that only arrived at t0+8.
+        store.record_at(
+            "BTCUSDT",
+            Signal::Obi { levels: 5 },
+            t0 + Duration::seconds(4),
+            t0 + Duration::seconds(8),
+            0.3,
+        );
+
+        let spec = FeatureSetSpec {
+            name: "test".to_string(),
+            version: 1,
+            features: vec![
+                FeatureSpec::new("momentum_5s", FeatureDef::Momentum { lookback_secs: 5 }, 5),
+                FeatureSpec::new("obi_5", FeatureDef::Obi { levels: 5 }, 5),
+            ],
+        };
+
+        let row = store.vector(&spec, "BTCUSDT", t0 + Duration::seconds(6));
+        assert!((row.values[0].unwrap() - 0.01).abs() < 1e-9);
+        assert_eq!(row.values[1], None);
+
+        let rows = store.materialize(&spec, "BTCUSDT", &[t0 + Duration::seconds(8)]);
+        assert_eq!(rows.rows[0].values[1], Some(0.3));
+        assert_eq!(rows.fingerprint, spec.fingerprint());
+        assert_ne!(
+            spec.fingerprint(),
+            FeatureSetSpec::crypto_v1().fingerprint()
+        );
+    }
+
+    #[test]
+    fn test_shared_store_round_trips_agent_ticks() {
+        let t0 = Utc::now();
+        let shared = SharedFeatureStore::new(FeatureStore::new());
+        let writer = shared.clone();
+        writer.record_crypto_tick(
+            "ETHUSDT",
+            t0,
+            &CryptoTick {
+                spot: Some(2000.0),
+                obi_5: Some(0.25),
+                pm_spread: Some(0.02),
+                ..CryptoTick::default()
+            },
+        );
+        writer.record_crypto_tick(
+            "ETHUSDT",
+            t0 + Duration::seconds(5),
+            &CryptoTick {
+                spot: Some(2010.0),
+                obi_5: Some(-0.1),
+                ..CryptoTick::default()
+            },
+        );
+
+        let spec = FeatureSetSpec::crypto_v1();
+        let row = shared.vector(&spec, "ETHUSDT", t0 + Duration::seconds(5));
+        let value = |name: &str| row.values[spec.names().iter().position(|n| n == name).unwrap()];
+        assert_eq!(value("obi_5"), Some(-0.1));
+        assert!((value("momentum_5s").unwrap() - 0.005).abs() < 1e-9);
+        assert_eq!(value("pm_spread"), Some(0.02));
+        assert_eq!(value("obi_10"), None);
+        assert_eq!(value("implied_vol"), None);
+
+        let metadata: HashMap<_, _> = row.metadata(&spec).into_iter().collect();
+        assert_eq!(metadata["feature_set"], "crypto@v1");
+        assert_eq!(metadata["feature_fingerprint"], spec.fingerprint());
+        assert_eq!(metadata["fs_obi_5"], "-0.100000");
+        assert!(!metadata.contains_key("fs_obi_10"));
+
+        // Another agent's symbol stays empty
+        assert!(shared
+            .vector(&spec, "BTCUSDT", t0)
+            .values
+            .iter()
+            .all(Option::is_none));
+    }
+}
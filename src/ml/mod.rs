@@ -4,9 +4,24 @@
 //! EC2 instances without GPU/toolchain complexity.
 
 pub mod dense;
+pub mod drift;
+pub mod feature_store;
 #[cfg(feature = "onnx")]
 pub mod onnx;
+pub mod registry;
 
 pub use dense::{Activation, DenseLayer, DenseNetwork};
+pub use drift::{
+    DriftBaseline, DriftConfig, DriftMonitor, DriftReport, DriftStatus, FeatureBaseline,
+    FeatureDrift,
+};
+pub use feature_store::{
+    CryptoTick, FeatureDef, FeatureRow, FeatureSetSpec, FeatureSpec, FeatureStore,
+    MaterializedFeatures, SharedFeatureStore, Signal,
+};
 #[cfg(feature = "onnx")]
 pub use onnx::OnnxModel;
+pub use registry::{
+    resolve_model_path, ModelRecord, ModelRegistry, ModelStage, ModelVersion, RegisterOptions,
+    ResolvedModel,
+};
@@ -0,0 +1,445 @@
+//! File-backed model registry.
+//!
+//! Model artifacts (ONNX policies, LOB classifiers, RL checkpoints) are copied
+//! into `<root>/<name>/<version>/` and tracked in `<root>/registry.json` with
+//! a semantic version, artifact and training-data hashes, eval metrics and a
+//! lifecycle stage. Agents reference models as `registry:<name>@<alias>`
+//! (alias defaults to `production`) instead of loose file paths, and
+//! `ploy rl promote` moves versions between stages.
+
+use crate::error::{PloyError, Result};
+use chrono::{DateTime, Utc};
+use serde::{Deserialize, Serialize};
+use sha2::{Digest, Sha256};
+use std::collections::BTreeMap;
+use std::fmt;
+use std::path::{Path, PathBuf};
+use std::str::FromStr;
+
+/// Default registry root when `PLOY_MODEL_REGISTRY_DIR` is unset
+pub const DEFAULT_REGISTRY_DIR: &str = "./models/registry";
+
+/// Model path prefix that resolves through the registry
+pub const REGISTRY_URI_PREFIX: &str = "registry:";
+
+const INDEX_FILE: &str = "registry.json";
+
+/// Lifecycle stage of a registered model version
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum ModelStage {
+    Candidate,
+    Staging,
+    Production,
+    Archived,
+}
+
+impl fmt::Display for ModelStage {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let s = match self {
+            Self::Candidate => "candidate",
+            Self::Staging => "staging",
+            Self::Production => "production",
+            Self::Archived => "archived",
+        };
+        f.write_str(s)
+    }
+}
+
+impl FromStr for ModelStage {
+    type Err = PloyError;
+
+    fn from_str(s: &str) -> Result<Self> {
+        match s.trim().to_ascii_lowercase().as_str() {
+            "candidate" => Ok(Self::Candidate),
+            "staging" => Ok(Self::Staging),
+            "production" | "prod" => Ok(Self::Production),
+            "archived" => Ok(Self::Archived),
+            other => Err(PloyError::Validation(format!(
+                "unknown model stage '{other}' (expected candidate, staging, production or archived)"
+            ))),
+        }
+    }
+}
+
+/// Semantic version `major.minor.patch`
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub struct ModelVersion {
+    pub major: u32,
+    pub minor: u32,
+    pub patch: u32,
+}
+
+impl ModelVersion {
+    pub fn new(major: u32, minor: u32, patch: u32) -> Self {
+        Self {
+            major,
+            minor,
+            patch,
+        }
+    }
+}
+
+impl fmt::Display for ModelVersion {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{}.{}.{}", self.major, self.minor, self.patch)
+    }
+}
+
+impl FromStr for ModelVersion {
+    type Err = PloyError;
+
+    fn from_str(s: &str) -> Result<Self> {
+        let invalid = || PloyError::Validation(format!("invalid model version '{s}'"));
+        let parts: Vec<&str> = s.trim().trim_start_matches('v').split('.').collect();
+        if parts.len() != 3 {
+            return Err(invalid());
+        }
+        let part = |i: usize| parts[i].parse::<u32>().map_err(|_| invalid());
+        Ok(Self::new(part(0)?, part(1)?, part(2)?))
+    }
+}
+
+impl Serialize for ModelVersion {
+    fn serialize<S: serde::Serializer>(
+        &self,
+        serializer: S,
+    ) -> std::result::Result<S::Ok, S::Error> {
+        serializer.collect_str(self)
+    }
+}
+
+impl<'de> Deserialize<'de> for ModelVersion {
+    fn deserialize<D: serde::Deserializer<'de>>(
+        deserializer: D,
+    ) -> std::result::Result<Self, D::Error> {
+        let s = String::deserialize(deserializer)?;
+        s.parse().map_err(serde::de::Error::custom)
+    }
+}
+
+/// One registered model version
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct ModelRecord {
+    pub name: String,
+    pub version: ModelVersion,
+    pub stage: ModelStage,
+    /// Artifact path relative to the registry root
+    pub artifact: PathBuf,
+    /// SHA256 hex digest of the artifact
+    pub sha256: String,
+    /// Hash of the training dataset (e.g. a feature set fingerprint)
+    #[serde(default)]
+    pub training_data_hash: Option<String>,
+    /// Evaluation metrics (sharpe, win_rate, ...)
+    #[serde(default)]
+    pub metrics: BTreeMap<String, f64>,
+    pub created_at: DateTime<Utc>,
+    #[serde(default)]
+    pub promoted_at: Option<DateTime<Utc>>,
+}
+
+impl ModelRecord {
+    /// `name@version`, used as the version label in order metadata
+    pub fn label(&self) -> String {
+        format!("{}@{}", self.name, self.version)
+    }
+}
+
+/// Optional metadata supplied when registering a model
+#[derive(Debug, Clone, Default)]
+pub struct RegisterOptions {
+    pub training_data_hash: Option<String>,
+    pub metrics: BTreeMap<String, f64>,
+}
+
+#[derive(Debug, Default, Serialize, Deserialize)]
+struct RegistryIndex {
+    #[serde(default)]
+    models: Vec<ModelRecord>,
+}
+
+/// A model path after registry resolution
+#[derive(Debug, Clone)]
+pub struct ResolvedModel {
+    pub path: PathBuf,
+    /// Registry record when the path was a `registry:` reference
+    pub record: Option<ModelRecord>,
+}
+
+fn sha256_file(path: &Path) -> Result<String> {
+    let bytes = std::fs::read(path).map_err(|e| {
+        PloyError::Validation(format!("failed to read model {}: {}", path.display(), e))
+    })?;
+    Ok(format!("{:x}", Sha256::digest(&bytes)))
+}
+
+fn validate_name(name: &str) -> Result<()> {
+    let ok = !name.is_empty()
+        && name
+            .chars()
+            .all(|c| c.is_ascii_alphanumeric() || c == '-' || c == '_');
+    if !ok {
+        return Err(PloyError::Validation(format!(
+            "invalid model name '{name}' (use [A-Za-z0-9_-])"
+        )));
+    }
+    Ok(())
+}
+
+/// Registry rooted at a directory
+#[derive(Debug)]
+pub struct ModelRegistry {
+    root: PathBuf,
+    index: RegistryIndex,
+}
+
+impl ModelRegistry {
+    /// Open (or lazily create) the registry at `root`
+    pub fn open(root: impl AsRef<Path>) -> Result<Self> {
+        let root = root.as_ref().to_path_buf();
+        let index_path = root.join(INDEX_FILE);
+        let index = if index_path.exists() {
+            let raw = std::fs::read_to_string(&index_path).map_err(|e| {
+                PloyError::Validation(format!(
+                    "failed to read model registry {}: {}",
+                    index_path.display(),
+                    e
+                ))
+            })?;
+            serde_json::from_str(&raw)?
+        } else {
+            RegistryIndex::default()
+        };
+        Ok(Self { root, index })
+    }
+
+    /// Open the registry configured by `PLOY_MODEL_REGISTRY_DIR`
+    pub fn open_default() -> Result<Self> {
+        let root = std::env::var("PLOY_MODEL_REGISTRY_DIR")
+            .ok()
+            .filter(|v| !v.trim().is_empty())
+            .unwrap_or_else(|| DEFAULT_REGISTRY_DIR.to_string());
+        Self::open(root)
+    }
+
+    pub fn root(&self) -> &Path {
+        &self.root
+    }
+
+    fn save(&self) -> Result<()> {
+        std::fs::create_dir_all(&self.root)?;
+        let tmp = self.root.join(format!("{INDEX_FILE}.tmp"));
+        std::fs::write(&tmp, serde_json::to_string_pretty(&self.index)?)?;
+        std::fs::rename(&tmp, self.root.join(INDEX_FILE))?;
+        Ok(())
+    }
+
+    /// Copy `artifact` into the registry as a new candidate version
+    pub fn register(
+        &mut self,
+        name: &str,
+        version: ModelVersion,
+        artifact: impl AsRef<Path>,
+        options: RegisterOptions,
+    ) -> Result<ModelRecord> {
+        validate_name(name)?;
+        let artifact = artifact.as_ref();
+        if self.get(name, version).is_some() {
+            return Err(PloyError::Validation(format!(
+                "model {name}@{version} is already registered"
+            )));
+        }
+        let file_name = artifact.file_name().ok_or_else(|| {
+            PloyError::Validation(format!("invalid artifact path {}", artifact.display()))
+        })?;
+
+        let relative = Path::new(name).join(version.to_string()).join(file_name);
+        let dest = self.root.join(&relative);
+        if let Some(parent) = dest.parent() {
+            std::fs::create_dir_all(parent)?;
+        }
+        std::fs::copy(artifact, &dest).map_err(|e| {
+            PloyError::Validation(format!(
+                "failed to copy model {} into registry: {}",
+                artifact.display(),
+                e
+            ))
+        })?;
+
+        let record = ModelRecord {
+            name: name.to_string(),
+            version,
+            stage: ModelStage::Candidate,
+            artifact: relative,
+            sha256: sha256_file(&dest)?,
+            training_data_hash: options.training_data_hash,
+            metrics: options.metrics,
+            created_at: Utc::now(),
+            promoted_at: None,
+        };
+        self.index.models.push(record.clone());
+        self.save()?;
+        Ok(record)
+    }
+
+    /// Move a version to `stage`.
+    ///
+    /// Staging and production hold a single version per model; the version
+    /// previously in that stage is archived. Returns the archived record.
+    pub fn promote(
+        &mut self,
+        name: &str,
+        version: ModelVersion,
+        stage: ModelStage,
+    ) -> Result<Option<ModelRecord>> {
+        if self.get(name, version).is_none() {
+            return Err(PloyError::Validation(format!(
+                "model {name}@{version} is not registered"
+            )));
+        }
+
+        let now = Utc::now();
+        let mut demoted = None;
+        for record in self.index.models.iter_mut().filter(|r| r.name == name) {
+            if record.version == version {
+                record.stage = stage;
+                record.promoted_at = Some(now);
+            } else if record.stage == stage
+                && matches!(stage, ModelStage::Staging | ModelStage::Production)
+            {
+                record.stage = ModelStage::Archived;
+                record.promoted_at = Some(now);
+                demoted = Some(record.clone());
+            }
+        }
+        self.save()?;
+        Ok(demoted)
+    }
+
+    pub fn get(&self, name: &str, version: ModelVersion) -> Option<&ModelRecord> {
+        self.index
+            .models
+            .iter()
+            .find(|r| r.name == name && r.version == version)
+    }
+
+    /// Resolve an alias: a stage name, `latest`, or an explicit version
+    pub fn resolve(&self, name: &str, alias: &str) -> Result<&ModelRecord> {
+        let versions = self.index.models.iter().filter(|r| r.name == name);
+        let found = match alias.trim().to_ascii_lowercase().as_str() {
+            "latest" => versions.max_by_key(|r| r.version),
+            a => match a.parse::<ModelStage>() {
+                Ok(stage) => versions
+                    .filter(|r| r.stage == stage)
+                    .max_by_key(|r| r.version),
+                Err(_) => self.get(name, a.parse()?),
+            },
+        };
+        found.ok_or_else(|| {
+            PloyError::Validation(format!("no model matches {name}@{alias} in registry"))
+        })
+    }
+
+    /// All versions, optionally of one model, ordered by name then version
+    pub fn list(&self, name: Option<&str>) -> Vec<&ModelRecord> {
+        let mut records: Vec<&ModelRecord> = self
+            .index
+            .models
+            .iter()
+            .filter(|r| name.is_none_or(|n| r.name == n))
+            .collect();
+        records.sort_by(|a, b| a.name.cmp(&b.name).then(a.version.cmp(&b.version)));
+        records
+    }
+
+    /// Absolute artifact path, verified against the recorded hash
+    pub fn artifact_path(&self, record: &ModelRecord) -> Result<PathBuf> {
+        let path = self.root.join(&record.artifact);
+        let actual = sha256_file(&path)?;
+        if actual != record.sha256 {
+            return Err(PloyError::Validation(format!(
+                "model {} artifact {} hash mismatch (expected {}, got {})",
+                record.label(),
+                path.display(),
+                record.sha256,
+                actual
+            )));
+        }
+        Ok(path)
+    }
+}
+
+/// Resolve a configured model path.
+///
+/// `registry:<name>[@alias]` is looked up in the default registry (alias
+/// defaults to `production`); anything else is returned as a plain path.
+pub fn resolve_model_path(path: &str) -> Result<ResolvedModel> {
+    let Some(reference) = path.trim().strip_prefix(REGISTRY_URI_PREFIX) else {
+        return Ok(ResolvedModel {
+            path: PathBuf::from(path),
+            record: None,
+        });
+    };
+    let (name, alias) = reference
+        .split_once('@')
+        .unwrap_or((reference, "production"));
+
+    let registry = ModelRegistry::open_default()?;
+    let record = registry.resolve(name, alias)?.clone();
+    Ok(ResolvedModel {
+        path: registry.artifact_path(&record)?,
+        record: Some(record),
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_register_promote_resolve() {
+        let dir = std::env::temp_dir().join(format!("ploy-registry-{}", uuid::Uuid::new_v4()));
+        let artifact = dir.join("policy.onnx");
+        std::fs::create_dir_all(&dir).unwrap();
+        std::fs::write(&artifact, b"v1").unwrap();
+
+        let mut registry = ModelRegistry::open(dir.join("registry")).unwrap();
+        let v1 = ModelVersion::new(1, 0, 0);
+        let v2: ModelVersion = "1.1.0".parse().unwrap();
+        registry
+            .register("rl-policy", v1, &artifact, RegisterOptions::default())
+            .unwrap();
+        std::fs::write(&artifact, b"v2").unwrap();
+        let rec = registry
+            .register("rl-policy", v2, &artifact, RegisterOptions::default())
+            .unwrap();
+        assert_eq!(rec.stage, ModelStage::Candidate);
+        assert!(registry
+            .register("rl-policy", v2, &artifact, RegisterOptions::default())
+            .is_err());
+        assert!(registry.resolve("rl-policy", "production").is_err());
+
+        registry
+            .promote("rl-policy", v1, ModelStage::Production)
+            .unwrap();
+        let demoted = registry
+            .promote("rl-policy", v2, ModelStage::Production)
+            .unwrap();
+        assert_eq!(demoted.map(|r| r.version), Some(v1));
+
+        // Reopen from disk
+        let registry = ModelRegistry::open(dir.join("registry")).unwrap();
+        let prod = registry.resolve("rl-policy", "production").unwrap();
+        assert_eq!(prod.version, v2);
+        assert_eq!(
+            registry.get("rl-policy", v1).unwrap().stage,
+            ModelStage::Archived
+        );
+        let path = registry.artifact_path(prod).unwrap();
+        assert_eq!(std::fs::read(path).unwrap(), b"v2");
+        assert_eq!(registry.resolve("rl-policy", "1.0.0").unwrap().version, v1);
+
+        std::fs::remove_dir_all(&dir).ok();
+    }
+}
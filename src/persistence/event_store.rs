@@ -297,6 +297,41 @@ impl EventStore {
         Ok(events)
     }
 
+    /// Get the most recent event of a type for an aggregate
+    pub async fn get_latest_event(
+        &self,
+        aggregate_id: &str,
+        aggregate_type: &str,
+        event_type: &str,
+    ) -> crate::error::Result<Option<StoredEvent>> {
+        let row = sqlx::query(
+            r#"
+            SELECT id, aggregate_id, aggregate_type, event_type, event_version,
+                   payload, metadata, created_at
+            FROM strategy_events
+            WHERE aggregate_id = $1 AND aggregate_type = $2 AND event_type = $3
+            ORDER BY id DESC
+            LIMIT 1
+            "#,
+        )
+        .bind(aggregate_id)
+        .bind(aggregate_type)
+        .bind(event_type)
+        .fetch_optional(&self.pool)
+        .await?;
+
+        Ok(row.map(|row| StoredEvent {
+            id: row.get("id"),
+            aggregate_id: row.get("aggregate_id"),
+            aggregate_type: row.get("aggregate_type"),
+            event_type: row.get("event_type"),
+            event_version: row.get("event_version"),
+            payload: row.get("payload"),
+            metadata: row.get("metadata"),
+            created_at: row.get("created_at"),
+        }))
+    }
+
     /// Count events for an aggregate
     pub async fn count_events(
         &self,
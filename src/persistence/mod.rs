@@ -4,11 +4,14 @@
 //! - Checkpoint service for periodic state snapshots
 //! - Dead letter queue processor for failed operation retry
 //! - Event store for event sourcing (audit trail and state replay)
+//! - Tax-lot tracking with FIFO/LIFO realized gains
 
 pub mod checkpoint;
 pub mod dlq_processor;
 pub mod event_store;
+pub mod tax_lots;
 
 pub use checkpoint::{CheckpointConfig, CheckpointService, Checkpointable};
 pub use dlq_processor::{DLQHandler, DLQProcessor, DLQProcessorConfig};
 pub use event_store::{EventMetadata, EventStore, StoredEvent};
+pub use tax_lots::{LotClosure, LotMethod, TaxLot, TaxLotBook};
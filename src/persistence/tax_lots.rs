@@ -0,0 +1,521 @@
+//! Tax-Lot Accounting
+//!
+//! Every buy fill opens a tax lot per (account, token). Sells and settlement
+//! payouts close open lots FIFO or LIFO, and each closure records proceeds,
+//! cost basis, realized gain and holding period. Buy fees and gas are
+//! capitalized into the lot's basis; sell fees and gas reduce proceeds.
+//!
+//! Lots are rebuilt from the execution log (`agent_order_executions`,
+//! `pm_token_settlements`) and persisted per method, so both FIFO and LIFO
+//! books can be kept side by side. Dry-run executions are never included.
+
+use crate::error::Result;
+use crate::services::export::{load_fills, load_settlements, token_ids, ExportFill, ExportFilter};
+use crate::services::reporting::{replay_events, ReplayEvent, TokenSettlement};
+use chrono::{DateTime, Utc};
+use clap::ValueEnum;
+use rust_decimal::Decimal;
+use serde::{Deserialize, Serialize};
+use sqlx::PgPool;
+use std::collections::{BTreeMap, HashMap};
+use uuid::Uuid;
+
+/// Holding period (days) above which a gain is long-term
+pub const LONG_TERM_DAYS: i64 = 365;
+
+/// Order in which open lots are consumed
+#[derive(ValueEnum, Debug, Clone, Copy, PartialEq, Eq, Default, Serialize, Deserialize)]
+#[serde(rename_all = "lowercase")]
+pub enum LotMethod {
+    /// First in, first out
+    #[default]
+    Fifo,
+    /// Last in, first out
+    Lifo,
+}
+
+impl LotMethod {
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            LotMethod::Fifo => "fifo",
+            LotMethod::Lifo => "lifo",
+        }
+    }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "lowercase")]
+pub enum CloseKind {
+    Sell,
+    Settlement,
+}
+
+impl CloseKind {
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            CloseKind::Sell => "sell",
+            CloseKind::Settlement => "settlement",
+        }
+    }
+
+    pub fn parse(raw: &str) -> Option<Self> {
+        match raw {
+            "sell" => Some(CloseKind::Sell),
+            "settlement" => Some(CloseKind::Settlement),
+            _ => None,
+        }
+    }
+}
+
+/// Shares bought by one fill
+#[derive(Debug, Clone)]
+pub struct TaxLot {
+    pub id: Uuid,
+    pub account_id: String,
+    pub agent_id: String,
+    pub token_id: String,
+    pub market_slug: String,
+    pub opened_at: DateTime<Utc>,
+    pub open_intent_id: Option<Uuid>,
+    pub shares: Decimal,
+    pub remaining_shares: Decimal,
+    /// Cost of the original shares, fees and gas included
+    pub cost_basis: Decimal,
+    /// Basis of the shares still open
+    pub remaining_cost: Decimal,
+}
+
+/// A (partial) lot disposal
+#[derive(Debug, Clone, Serialize)]
+pub struct LotClosure {
+    pub lot_id: Uuid,
+    pub account_id: String,
+    pub agent_id: String,
+    pub token_id: String,
+    pub market_slug: String,
+    pub opened_at: DateTime<Utc>,
+    pub closed_at: DateTime<Utc>,
+    pub kind: CloseKind,
+    pub close_intent_id: Option<Uuid>,
+    pub shares: Decimal,
+    /// Proceeds net of fees and gas
+    pub proceeds: Decimal,
+    pub cost_basis: Decimal,
+    pub realized_gain: Decimal,
+}
+
+impl LotClosure {
+    pub fn holding_days(&self) -> i64 {
+        (self.closed_at - self.opened_at).num_days()
+    }
+
+    pub fn is_long_term(&self) -> bool {
+        self.holding_days() > LONG_TERM_DAYS
+    }
+}
+
+/// Open lots and closures for one lot method
+#[derive(Debug, Clone, Default)]
+pub struct TaxLotBook {
+    method: LotMethod,
+    /// Lots per (account, token) in opening order
+    lots: BTreeMap<(String, String), Vec<TaxLot>>,
+    closures: Vec<LotClosure>,
+}
+
+impl TaxLotBook {
+    pub fn new(method: LotMethod) -> Self {
+        Self {
+            method,
+            ..Self::default()
+        }
+    }
+
+    pub fn method(&self) -> LotMethod {
+        self.method
+    }
+
+    /// Every lot ever opened, fully closed ones included
+    pub fn lots(&self) -> impl Iterator<Item = &TaxLot> {
+        self.lots.values().flatten()
+    }
+
+    pub fn open_lots(&self) -> impl Iterator<Item = &TaxLot> {
+        self.lots()
+            .filter(|lot| lot.remaining_shares > Decimal::ZERO)
+    }
+
+    pub fn closures(&self) -> &[LotClosure] {
+        &self.closures
+    }
+
+    /// Open a lot for a buy fill or close lots for a sell fill. Returns the
+    /// sold shares that matched no open lot.
+    pub fn apply_fill(&mut self, fill: &ExportFill) -> Decimal {
+        let costs = fill.fee_usd + fill.gas_usd;
+        if fill.is_buy {
+            let cost = fill.shares * fill.price + costs;
+            self.lots
+                .entry((fill.account_id.clone(), fill.token_id.clone()))
+                .or_default()
+                .push(TaxLot {
+                    id: Uuid::new_v4(),
+                    account_id: fill.account_id.clone(),
+                    agent_id: fill.agent_id.clone(),
+                    token_id: fill.token_id.clone(),
+                    market_slug: fill.market_slug.clone(),
+                    opened_at: fill.executed_at,
+                    open_intent_id: Some(fill.intent_id),
+                    shares: fill.shares,
+                    remaining_shares: fill.shares,
+                    cost_basis: cost,
+                    remaining_cost: cost,
+                });
+            return Decimal::ZERO;
+        }
+        self.close(
+            &fill.account_id,
+            &fill.token_id,
+            fill.shares,
+            fill.price,
+            costs,
+            fill.executed_at,
+            CloseKind::Sell,
+            Some(fill.intent_id),
+        )
+    }
+
+    /// Close every open lot of `token_id` at the settlement price
+    pub fn settle(&mut self, token_id: &str, price: Decimal, at: DateTime<Utc>) {
+        let accounts: Vec<(String, Decimal)> = self
+            .lots
+            .iter()
+            .filter(|((_, token), _)| token == token_id)
+            .map(|((account, _), lots)| {
+                (
+                    account.clone(),
+                    lots.iter().map(|lot| lot.remaining_shares).sum(),
+                )
+            })
+            .filter(|(_, shares)| *shares > Decimal::ZERO)
+            .collect();
+        for (account, shares) in accounts {
+            self.close(
+                &account,
+                token_id,
+                shares,
+                price,
+                Decimal::ZERO,
+                at,
+                CloseKind::Settlement,
+                None,
+            );
+        }
+    }
+
+    #[allow(clippy::too_many_arguments)]
+    fn close(
+        &mut self,
+        account_id: &str,
+        token_id: &str,
+        shares: Decimal,
+        price: Decimal,
+        costs: Decimal,
+        at: DateTime<Utc>,
+        kind: CloseKind,
+        intent_id: Option<Uuid>,
+    ) -> Decimal {
+        let Some(lots) = self
+            .lots
+            .get_mut(&(account_id.to_string(), token_id.to_string()))
+        else {
+            return shares;
+        };
+        let order: Vec<usize> = match self.method {
+            LotMethod::Fifo => (0..lots.len()).collect(),
+            LotMethod::Lifo => (0..lots.len()).rev().collect(),
+        };
+
+        let mut left = shares;
+        for i in order {
+            if left <= Decimal::ZERO {
+                break;
+            }
+            let lot = &mut lots[i];
+            if lot.remaining_shares <= Decimal::ZERO {
+                continue;
+            }
+            let qty = left.min(lot.remaining_shares);
+            let basis = if qty == lot.remaining_shares {
+                lot.remaining_cost
+            } else {
+                lot.remaining_cost * qty / lot.remaining_shares
+            };
+            // Sell-side costs are spread over the requested shares.
+            let proceeds = price * qty - costs * qty / shares;
+            lot.remaining_shares -= qty;
+            lot.remaining_cost -= basis;
+            left -= qty;
+
+            self.closures.push(LotClosure {
+                lot_id: lot.id,
+                account_id: lot.account_id.clone(),
+                agent_id: lot.agent_id.clone(),
+                token_id: lot.token_id.clone(),
+                market_slug: lot.market_slug.clone(),
+                opened_at: lot.opened_at,
+                closed_at: at,
+                kind,
+                close_intent_id: intent_id,
+                shares: qty,
+                proceeds,
+                cost_basis: basis,
+                realized_gain: proceeds - basis,
+            });
+        }
+        left
+    }
+
+    /// Replay fills and settlements before `until` in time order
+    pub fn replay(
+        method: LotMethod,
+        fills: &[ExportFill],
+        settlements: &HashMap<String, TokenSettlement>,
+        until: DateTime<Utc>,
+    ) -> Self {
+        let events = replay_events(fills, settlements, until, None);
+
+        let mut book = Self::new(method);
+        for (at, event) in events {
+            match event {
+                ReplayEvent::Fill(fill) => {
+                    book.apply_fill(fill);
+                }
+                ReplayEvent::Settle { token_id, price } => book.settle(token_id, price, at),
+            }
+        }
+        book
+    }
+}
+
+/// Rebuild the `method` book from live executions and persist it
+pub async fn rebuild(
+    pool: &PgPool,
+    method: LotMethod,
+    account_id: Option<&str>,
+) -> Result<TaxLotBook> {
+    let filter = ExportFilter {
+        account_id: account_id.map(str::to_string),
+        include_dry_run: false,
+    };
+    let until = Utc::now();
+    let fills = load_fills(pool, until, &filter).await?;
+    let settlements = load_settlements(pool, &token_ids(&fills)).await?;
+    let book = TaxLotBook::replay(method, &fills, &settlements, until);
+    save(pool, &book, account_id).await?;
+    Ok(book)
+}
+
+/// Replace the persisted lots of `book.method()` (for one account, or all)
+pub async fn save(pool: &PgPool, book: &TaxLotBook, account_id: Option<&str>) -> Result<()> {
+    let method = book.method().as_str();
+    let mut tx = pool.begin().await?;
+
+    sqlx::query("DELETE FROM tax_lots WHERE method = $1 AND ($2::text IS NULL OR account_id = $2)")
+        .bind(method)
+        .bind(account_id)
+        .execute(&mut *tx)
+        .await?;
+
+    for lot in book.lots() {
+        sqlx::query(
+            r#"
+            INSERT INTO tax_lots (
+                id, method, account_id, agent_id, token_id, market_slug, opened_at,
+                open_intent_id, shares, remaining_shares, cost_basis_usd, remaining_cost_usd
+            )
+            VALUES ($1,$2,$3,$4,$5,$6,$7,$8,$9,$10,$11,$12)
+            "#,
+        )
+        .bind(lot.id)
+        .bind(method)
+        .bind(&lot.account_id)
+        .bind(&lot.agent_id)
+        .bind(&lot.token_id)
+        .bind(&lot.market_slug)
+        .bind(lot.opened_at)
+        .bind(lot.open_intent_id)
+        .bind(lot.shares)
+        .bind(lot.remaining_shares)
+        .bind(lot.cost_basis)
+        .bind(lot.remaining_cost)
+        .execute(&mut *tx)
+        .await?;
+    }
+
+    for closure in book.closures() {
+        sqlx::query(
+            r#"
+            INSERT INTO tax_lot_closures (
+                lot_id, closed_at, close_kind, close_intent_id, shares,
+                proceeds_usd, cost_basis_usd, realized_gain_usd, holding_days
+            )
+            VALUES ($1,$2,$3,$4,$5,$6,$7,$8,$9)
+            "#,
+        )
+        .bind(closure.lot_id)
+        .bind(closure.closed_at)
+        .bind(closure.kind.as_str())
+        .bind(closure.close_intent_id)
+        .bind(closure.shares)
+        .bind(closure.proceeds)
+        .bind(closure.cost_basis)
+        .bind(closure.realized_gain)
+        .bind(closure.holding_days() as i32)
+        .execute(&mut *tx)
+        .await?;
+    }
+
+    tx.commit().await?;
+    Ok(())
+}
+
+/// Persisted closures of `method` with `from <= closed_at < to`
+pub async fn load_closures(
+    pool: &PgPool,
+    method: LotMethod,
+    account_id: Option<&str>,
+    from: DateTime<Utc>,
+    to: DateTime<Utc>,
+) -> Result<Vec<LotClosure>> {
+    #[allow(clippy::type_complexity)]
+    let rows: Vec<(
+        Uuid,
+        String,
+        String,
+        String,
+        String,
+        DateTime<Utc>,
+        DateTime<Utc>,
+        String,
+        Option<Uuid>,
+        Decimal,
+        Decimal,
+        Decimal,
+        Decimal,
+    )> = sqlx::query_as(
+        r#"
+        SELECT l.id, l.account_id, l.agent_id, l.token_id, l.market_slug, l.opened_at,
+               c.closed_at, c.close_kind, c.close_intent_id, c.shares,
+               c.proceeds_usd, c.cost_basis_usd, c.realized_gain_usd
+        FROM tax_lot_closures c
+        JOIN tax_lots l ON l.id = c.lot_id
+        WHERE l.method = $1
+          AND ($2::text IS NULL OR l.account_id = $2)
+          AND c.closed_at >= $3 AND c.closed_at < $4
+        ORDER BY c.closed_at ASC, c.id ASC
+        "#,
+    )
+    .bind(method.as_str())
+    .bind(account_id)
+    .bind(from)
+    .bind(to)
+    .fetch_all(pool)
+    .await?;
+
+    Ok(rows
+        .into_iter()
+        .filter_map(
+            |(
+                lot_id,
+                account_id,
+                agent_id,
+                token_id,
+                market_slug,
+                opened_at,
+                closed_at,
+                kind,
+                close_intent_id,
+                shares,
+                proceeds,
+                cost_basis,
+                realized_gain,
+            )| {
+                Some(LotClosure {
+                    lot_id,
+                    account_id,
+                    agent_id,
+                    token_id,
+                    market_slug,
+                    opened_at,
+                    closed_at,
+                    kind: CloseKind::parse(&kind)?,
+                    close_intent_id,
+                    shares,
+                    proceeds,
+                    cost_basis,
+                    realized_gain,
+                })
+            },
+        )
+        .collect())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::services::export::sample_fill;
+    use chrono::TimeZone;
+    use rust_decimal_macros::dec;
+
+    fn fill(day: u32, is_buy: bool, shares: Decimal, price: Decimal) -> ExportFill {
+        ExportFill {
+            fee_usd: if is_buy { dec!(0.10) } else { dec!(0.20) },
+            ..sample_fill(
+                Utc.with_ymd_and_hms(2026, 3, day, 12, 0, 0).unwrap(),
+                is_buy,
+                shares,
+                price,
+            )
+        }
+    }
+
+    #[test]
+    fn test_fifo_and_lifo_close_different_lots() {
+        let fills = vec![
+            fill(1, true, dec!(10), dec!(0.30)),
+            fill(2, true, dec!(10), dec!(0.50)),
+            fill(3, false, dec!(5), dec!(0.60)),
+        ];
+        let settlements = HashMap::from([(
+            "tok".to_string(),
+            TokenSettlement {
+                settled_price: dec!(1),
+                resolved_at: Some(Utc.with_ymd_and_hms(2026, 3, 10, 0, 0, 0).unwrap()),
+            },
+        )]);
+        let until = Utc.with_ymd_and_hms(2026, 4, 1, 0, 0, 0).unwrap();
+
+        let fifo = TaxLotBook::replay(LotMethod::Fifo, &fills, &settlements, until);
+        let sell = &fifo.closures()[0];
+        assert_eq!(sell.kind, CloseKind::Sell);
+        assert_eq!(sell.opened_at.format("%d").to_string(), "01");
+        // basis 5 * 0.30 + half the 0.10 fee; proceeds 5 * 0.60 - 0.20
+        assert_eq!(sell.cost_basis, dec!(1.55));
+        assert_eq!(sell.realized_gain, dec!(1.25));
+
+        let lifo = TaxLotBook::replay(LotMethod::Lifo, &fills, &settlements, until);
+        let sell = &lifo.closures()[0];
+        assert_eq!(sell.opened_at.format("%d").to_string(), "02");
+        assert_eq!(sell.realized_gain, dec!(0.25));
+
+        // Settlement closes the rest; total gain does not depend on the method
+        for book in [&fifo, &lifo] {
+            assert_eq!(book.open_lots().count(), 0);
+            assert_eq!(book.closures().len(), 3);
+            let total: Decimal = book.closures().iter().map(|c| c.realized_gain).sum();
+            assert_eq!(total, dec!(9.60));
+            assert!(book.closures().iter().all(|c| !c.is_long_term()));
+        }
+    }
+}
@@ -317,6 +317,8 @@ mod tests {
             early_exit_enabled: true,
             early_exit_take_profit_pct: 15.0,
             early_exit_stop_loss_pct: 20.0,
+            comment_sentiment: Default::default(),
+            score_guard: Default::default(),
         };
 
         // Test status transitions without DB
@@ -15,7 +15,7 @@ use tracing::{debug, info, warn};
 use crate::domain::Side;
 use crate::error::Result;
 #[cfg(feature = "onnx")]
-use crate::ml::OnnxModel;
+use crate::ml::{resolve_model_path, OnnxModel};
 use crate::platform::{
     AgentRiskParams, AgentStatus, Domain, DomainAgent, DomainEvent, ExecutionReport, OrderIntent,
     OrderPriority,
@@ -175,34 +175,65 @@ impl RLCryptoAgent {
         info!("Creating RLCryptoAgent: {} ({})", config.name, config.id);
 
         let buffer_size = config.rl_config.training.buffer_size;
+        let replay_config = config.rl_config.training.replay.clone();
         let exploration = config.exploration_rate;
 
         #[cfg(feature = "onnx")]
-        let policy_model: Option<OnnxModel> = match config.policy_model_path.as_deref() {
-            Some(path) if !path.trim().is_empty() => {
-                match OnnxModel::load_for_vec_input(path, TOTAL_FEATURES) {
-                    Ok(m) => {
+        let mut config = config;
+        #[cfg(feature = "onnx")]
+        let resolved_model_path: Option<String> = match config.policy_model_path.as_deref() {
+            Some(path) if !path.trim().is_empty() => match resolve_model_path(path) {
+                Ok(resolved) => {
+                    if let Some(record) = resolved.record {
                         info!(
                             agent = %config.id,
-                            policy_path = %path,
-                            input_dim = m.input_dim(),
-                            output_dim = m.output_dim(),
-                            policy_output = %config.policy_output,
-                            "loaded RL policy ONNX model"
-                        );
-                        Some(m)
-                    }
-                    Err(e) => {
-                        warn!(
-                            agent = %config.id,
-                            policy_path = %path,
-                            error = %e,
-                            "failed to load RL policy ONNX model; falling back to rule-based policy"
+                            model = %record.label(),
+                            stage = %record.stage,
+                            "resolved RL policy model from registry"
                         );
-                        None
+                        config
+                            .policy_model_version
+                            .get_or_insert_with(|| record.label());
                     }
+                    Some(resolved.path.display().to_string())
                 }
-            }
+                Err(e) => {
+                    warn!(
+                        agent = %config.id,
+                        policy_path = %path,
+                        error = %e,
+                        "failed to resolve RL policy model; falling back to rule-based policy"
+                    );
+                    None
+                }
+            },
+            _ => None,
+        };
+
+        #[cfg(feature = "onnx")]
+        let policy_model: Option<OnnxModel> = match resolved_model_path.as_deref() {
+            Some(path) => match OnnxModel::load_for_vec_input(path, TOTAL_FEATURES) {
+                Ok(m) => {
+                    info!(
+                        agent = %config.id,
+                        policy_path = %path,
+                        input_dim = m.input_dim(),
+                        output_dim = m.output_dim(),
+                        policy_output = %config.policy_output,
+                        "loaded RL policy ONNX model"
+                    );
+                    Some(m)
+                }
+                Err(e) => {
+                    warn!(
+                        agent = %config.id,
+                        policy_path = %path,
+                        error = %e,
+                        "failed to load RL policy ONNX model; falling back to rule-based policy"
+                    );
+                    None
+                }
+            },
             _ => None,
         };
 
@@ -222,7 +253,10 @@ impl RLCryptoAgent {
             status: AgentStatus::Initializing,
             encoder: Arc::new(DefaultStateEncoder::new()),
             reward_fn: Box::new(PnLRewardFunction::new()),
-            replay_buffer: Arc::new(RwLock::new(ReplayBuffer::new(buffer_size))),
+            replay_buffer: Arc::new(RwLock::new(ReplayBuffer::with_config(
+                buffer_size,
+                replay_config,
+            ))),
             current_obs: RawObservation::new(),
             prev_obs: None,
             position: None,
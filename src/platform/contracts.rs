@@ -21,17 +21,124 @@ pub enum Timeframe {
     M5,
     #[serde(rename = "15m")]
     M15,
+    #[serde(rename = "1h")]
+    H1,
+    #[serde(rename = "1d")]
+    D1,
     Other(String),
 }
 
+/// Polymarket crypto up/down series with a known round length.
+const KNOWN_5M_SERIES_IDS: &[&str] = &["10684", "10683", "10686", "10685"];
+const KNOWN_15M_SERIES_IDS: &[&str] = &["10192", "10191", "10423", "10422"];
+
 impl Timeframe {
+    /// Round length that strategy defaults are calibrated on.
+    pub const REFERENCE_SECS: i64 = 15 * 60;
+
     pub fn as_str(&self) -> &str {
         match self {
             Self::M5 => "5m",
             Self::M15 => "15m",
+            Self::H1 => "1h",
+            Self::D1 => "1d",
             Self::Other(v) => v.as_str(),
         }
     }
+
+    /// Round length in seconds (None for `Other`).
+    pub fn duration_secs(&self) -> Option<i64> {
+        match self {
+            Self::M5 => Some(5 * 60),
+            Self::M15 => Some(15 * 60),
+            Self::H1 => Some(60 * 60),
+            Self::D1 => Some(24 * 60 * 60),
+            Self::Other(_) => None,
+        }
+    }
+
+    /// Infer a timeframe from a slug, title, deployment id, label or series id
+    /// (e.g. `btc-updown-15m-1739...`, `Bitcoin Up or Down - hourly`, `10192`).
+    pub fn from_hint(raw: &str) -> Option<Self> {
+        let normalized = raw.trim().to_ascii_lowercase();
+        if normalized.is_empty() {
+            return None;
+        }
+        match normalized.as_str() {
+            "5" => return Some(Self::M5),
+            "15" => return Some(Self::M15),
+            id if KNOWN_5M_SERIES_IDS.contains(&id) => return Some(Self::M5),
+            id if KNOWN_15M_SERIES_IDS.contains(&id) => return Some(Self::M15),
+            _ => {}
+        }
+
+        let tokens: Vec<&str> = normalized
+            .split(|c: char| !c.is_ascii_alphanumeric())
+            .filter(|t| !t.is_empty())
+            .collect();
+        for (i, token) in tokens.iter().enumerate() {
+            // Join "15 minute" / "1-hour" style pairs into a single unit token.
+            let joined = match tokens.get(i + 1) {
+                Some(unit) if token.bytes().all(|b| b.is_ascii_digit()) => {
+                    let unit = match *unit {
+                        "m" | "min" | "mins" | "minute" | "minutes" => "m",
+                        "h" | "hr" | "hour" | "hours" => "h",
+                        "d" | "day" | "days" => "d",
+                        _ => "",
+                    };
+                    format!("{token}{unit}")
+                }
+                _ => token.to_string(),
+            };
+            let found = match joined.as_str() {
+                "5m" | "5min" => Some(Self::M5),
+                "15m" | "15min" => Some(Self::M15),
+                "1h" | "1hr" | "60m" | "60min" | "hourly" => Some(Self::H1),
+                "1d" | "24h" | "daily" => Some(Self::D1),
+                _ => None,
+            };
+            if found.is_some() {
+                return found;
+            }
+        }
+
+        // Legacy substring match for compact ids such as `btc15m`.
+        if normalized.contains("15m") {
+            Some(Self::M15)
+        } else if normalized.contains("5m") {
+            Some(Self::M5)
+        } else {
+            None
+        }
+    }
+
+    /// Nearest standard timeframe for an observed round length.
+    pub fn from_duration_secs(secs: i64) -> Option<Self> {
+        match secs {
+            s if s <= 0 => None,
+            s if s <= 6 * 60 => Some(Self::M5),
+            s if s <= 18 * 60 => Some(Self::M15),
+            s if s <= 90 * 60 => Some(Self::H1),
+            s if s <= 36 * 60 * 60 => Some(Self::D1),
+            _ => None,
+        }
+    }
+
+    /// Linear stretch for windows calibrated on 15m rounds (watch windows,
+    /// hedge timeouts). Never shrinks: 5m / 15m / unknown rounds map to 1.0.
+    pub fn window_scale(round_secs: i64) -> f64 {
+        Self::from_duration_secs(round_secs)
+            .and_then(|tf| tf.duration_secs())
+            .map(|secs| (secs as f64 / Self::REFERENCE_SECS as f64).max(1.0))
+            .unwrap_or(1.0)
+    }
+
+    /// √time stretch for lead times calibrated on 15m rounds (exit-before-
+    /// resolution buffers, rolling-high windows): price noise over a horizon
+    /// grows with √t, so a 1h round gets 2x and a 1d round ~9.8x.
+    pub fn lead_time_scale(round_secs: i64) -> f64 {
+        Self::window_scale(round_secs).sqrt()
+    }
 }
 
 /// Execution-mode scope for a deployment.
@@ -436,6 +543,33 @@ mod tests {
         );
     }
 
+    #[test]
+    fn timeframe_hints_durations_and_scaling() {
+        assert_eq!(
+            Timeframe::from_hint("btc-updown-15m-1739000000"),
+            Some(Timeframe::M15)
+        );
+        assert_eq!(Timeframe::from_hint("eth-5m"), Some(Timeframe::M5));
+        assert_eq!(Timeframe::from_hint("10192"), Some(Timeframe::M15));
+        assert_eq!(Timeframe::from_hint("eth-updown-1h"), Some(Timeframe::H1));
+        assert_eq!(
+            Timeframe::from_hint("Bitcoin 1 hour up or down"),
+            Some(Timeframe::H1)
+        );
+        assert_eq!(Timeframe::from_hint("btc-daily"), Some(Timeframe::D1));
+        assert_eq!(Timeframe::from_hint("fed-decision-october"), None);
+
+        assert_eq!(Timeframe::from_duration_secs(960), Some(Timeframe::M15));
+        assert_eq!(Timeframe::from_duration_secs(3600), Some(Timeframe::H1));
+        assert_eq!(Timeframe::from_duration_secs(86_400), Some(Timeframe::D1));
+
+        assert_eq!(Timeframe::window_scale(300), 1.0);
+        assert_eq!(Timeframe::window_scale(960), 1.0);
+        assert_eq!(Timeframe::window_scale(3600), 4.0);
+        assert_eq!(Timeframe::lead_time_scale(3600), 2.0);
+        assert_eq!(serde_json::to_string(&Timeframe::H1).unwrap(), "\"1h\"");
+    }
+
     #[test]
     fn deployment_runtime_scope_matching() {
         let mut deployment = StrategyDeployment {
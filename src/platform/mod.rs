@@ -9,7 +9,9 @@ mod platform;
 mod position;
 mod queue;
 mod risk;
+mod risk_policy;
 mod router;
+mod throttle;
 mod traits;
 mod types;
 
@@ -25,7 +27,15 @@ pub use risk::{
     BlockReason, CircuitBreakerEvent, DrawdownSnapshot, PlatformRiskState, RiskCheckResult,
     RiskConfig, RiskGate,
 };
+pub use risk_policy::{
+    DomainPolicy, MarketPolicy, PolicyContext, PolicyEvaluation, PolicyLimits, PolicyRuleResult,
+    RiskPolicy, TradingWindow, RISK_POLICY_FILE,
+};
 pub use router::{AgentSubscription, EventRouter, RouterStats};
+pub use throttle::{
+    NotionalLimits, NotionalThrottle, NotionalThrottleConfig, NotionalUsage, ThrottleBreach,
+    ThrottleInterval,
+};
 pub use traits::{AgentHealthStatus, AgentRiskParams, AgentStatus, DomainAgent, SimpleAgent};
 pub use types::{
     CryptoEvent, Domain, DomainEvent, ExecutionReport, ExecutionStatus, OrderIntent, OrderPriority,
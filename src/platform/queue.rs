@@ -57,6 +57,8 @@ pub struct OrderQueue {
     dequeued_count: u64,
     /// 統計：已過期數量
     expired_count: u64,
+    /// 統計：重啟後恢復的數量
+    restored_count: u64,
 }
 
 impl OrderQueue {
@@ -69,6 +71,7 @@ impl OrderQueue {
             enqueued_count: 0,
             dequeued_count: 0,
             expired_count: 0,
+            restored_count: 0,
         }
     }
 
@@ -263,14 +266,25 @@ impl OrderQueue {
         removed
     }
 
+    /// Count intents re-enqueued from persistence after a restart.
+    pub fn record_restored(&mut self, count: u64) {
+        self.restored_count += count;
+    }
+
     /// 獲取隊列統計
     pub fn stats(&self) -> QueueStats {
+        let now = Utc::now();
         let mut priority_counts = [0usize; 4];
+        let mut oldest_age_ms = 0i64;
+        let mut total_age_ms = 0i64;
         for item in self.heap.iter() {
             let idx = item.intent.priority as usize;
             if idx < 4 {
                 priority_counts[idx] += 1;
             }
+            let age_ms = (now - item.intent.created_at).num_milliseconds().max(0);
+            oldest_age_ms = oldest_age_ms.max(age_ms);
+            total_age_ms = total_age_ms.saturating_add(age_ms);
         }
 
         QueueStats {
@@ -279,13 +293,28 @@ impl OrderQueue {
             enqueued_total: self.enqueued_count,
             dequeued_total: self.dequeued_count,
             expired_total: self.expired_count,
+            restored_total: self.restored_count,
             critical_count: priority_counts[0],
             high_count: priority_counts[1],
             normal_count: priority_counts[2],
             low_count: priority_counts[3],
+            oldest_age_ms,
+            avg_age_ms: if self.heap.is_empty() {
+                0
+            } else {
+                total_age_ms / self.heap.len() as i64
+            },
         }
     }
 
+    /// Unexpired BUY intents waiting in the queue (heap order).
+    pub fn pending_buy_intents(&self) -> impl Iterator<Item = &OrderIntent> {
+        self.heap
+            .iter()
+            .map(|item| &item.intent)
+            .filter(|intent| intent.is_buy && !intent.is_expired())
+    }
+
     /// Sum buy-intent notionals in queue, excluding specific domains.
     pub fn pending_buy_notional_excluding_domains(&self, excluded: &[Domain]) -> Decimal {
         self.heap
@@ -330,17 +359,22 @@ pub struct QueueStats {
     pub enqueued_total: u64,
     pub dequeued_total: u64,
     pub expired_total: u64,
+    /// Re-enqueued from persistence after a restart
+    pub restored_total: u64,
     pub critical_count: usize,
     pub high_count: usize,
     pub normal_count: usize,
     pub low_count: usize,
+    /// Age of the oldest waiting intent (ms since creation)
+    pub oldest_age_ms: i64,
+    pub avg_age_ms: i64,
 }
 
 impl std::fmt::Display for QueueStats {
     fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
         write!(
             f,
-            "Queue[{}/{}, enq={}, deq={}, exp={}, C={}/H={}/N={}/L={}]",
+            "Queue[{}/{}, enq={}, deq={}, exp={}, C={}/H={}/N={}/L={}, oldest={}ms]",
             self.current_size,
             self.max_size,
             self.enqueued_total,
@@ -349,7 +383,8 @@ impl std::fmt::Display for QueueStats {
             self.critical_count,
             self.high_count,
             self.normal_count,
-            self.low_count
+            self.low_count,
+            self.oldest_age_ms
         )
     }
 }
@@ -479,6 +514,7 @@ mod tests {
 
         let stats = queue.stats();
         assert_eq!(stats.current_size, 4);
+        assert!(stats.oldest_age_ms >= stats.avg_age_ms);
         assert_eq!(stats.critical_count, 1);
         assert_eq!(stats.high_count, 1);
         assert_eq!(stats.normal_count, 1);
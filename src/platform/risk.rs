@@ -6,17 +6,26 @@
 //! - 組合級別風控 (每日損失、連續失敗)
 
 use chrono::{DateTime, NaiveDate, Utc};
-use rust_decimal::prelude::ToPrimitive;
+use rust_decimal::prelude::{FromPrimitive, ToPrimitive};
 use rust_decimal::Decimal;
 use serde::{Deserialize, Serialize};
 use std::collections::HashMap;
 use std::sync::atomic::{AtomicU32, Ordering};
 use std::sync::Arc;
 use tokio::sync::RwLock;
-use tracing::{debug, error, info, warn};
+use tracing::{debug, error, info, instrument, warn};
+use uuid::Uuid;
 
+use super::risk_policy::RiskPolicy;
+use super::throttle::{NotionalThrottle, NotionalThrottleConfig, NotionalUsage, ThrottleInterval};
 use super::traits::AgentRiskParams;
 use super::types::{Domain, OrderIntent, OrderPriority};
+use crate::analysis::{GreeksBook, ToxicityLevel, ToxicityMonitor};
+#[cfg(feature = "api")]
+use crate::api::webhooks::{self, WebhookEventType};
+use crate::services::Metrics;
+use crate::strategy::execution::{FillabilityGate, FillabilityTracker};
+use crate::strategy::risk_mgmt::validation::{FillabilityValidator, ValidationContext, Validator};
 
 /// 風控配置
 #[derive(Debug, Clone, Serialize, Deserialize)]
@@ -49,6 +58,9 @@ pub struct RiskConfig {
     pub sports_daily_loss_limit: Option<Decimal>,
     pub politics_daily_loss_limit: Option<Decimal>,
     pub economics_daily_loss_limit: Option<Decimal>,
+    /// Notional-per-interval throttles (global and per agent)
+    #[serde(default)]
+    pub notional_throttle: NotionalThrottleConfig,
 }
 
 fn default_circuit_breaker_auto_recover() -> bool {
@@ -78,6 +90,7 @@ impl Default for RiskConfig {
             sports_daily_loss_limit: None,
             politics_daily_loss_limit: None,
             economics_daily_loss_limit: None,
+            notional_throttle: NotionalThrottleConfig::default(),
         }
     }
 }
@@ -165,6 +178,29 @@ pub enum BlockReason {
     OrderExpired,
     /// 未對沖倉位過多
     TooManyUnhedgedPositions { limit: u32, current: u32 },
+    /// Order-flow toxicity (VPIN) too high for new positions
+    ToxicFlow {
+        symbol: String,
+        vpin: Decimal,
+        threshold: Decimal,
+    },
+    /// 名目金額節流 (每分鐘 / 每小時)
+    NotionalThrottled {
+        scope: String,
+        interval: ThrottleInterval,
+        limit: Decimal,
+        used: Decimal,
+        requested: Decimal,
+    },
+    /// 違反宣告式風控政策 (risk_policy.toml)
+    PolicyViolation { rule: String, detail: String },
+    /// Delta 暴露超限 (USD / 標的 1% 變動)
+    DeltaExposureExceeded {
+        scope: String,
+        limit: Decimal,
+        current: Decimal,
+        requested: Decimal,
+    },
 }
 
 impl std::fmt::Display for BlockReason {
@@ -229,6 +265,41 @@ impl std::fmt::Display for BlockReason {
             BlockReason::TooManyUnhedgedPositions { limit, current } => {
                 write!(f, "Unhedged positions {} exceeds limit {}", current, limit)
             }
+            BlockReason::ToxicFlow {
+                symbol,
+                vpin,
+                threshold,
+            } => {
+                write!(f, "{} flow toxicity VPIN {} >= {}", symbol, vpin, threshold)
+            }
+            BlockReason::NotionalThrottled {
+                scope,
+                interval,
+                limit,
+                used,
+                requested,
+            } => {
+                write!(
+                    f,
+                    "{} notional ${} + ${} exceeds ${} per {}",
+                    scope, used, requested, limit, interval
+                )
+            }
+            BlockReason::PolicyViolation { rule, detail } => {
+                write!(f, "Risk policy {}: {}", rule, detail)
+            }
+            BlockReason::DeltaExposureExceeded {
+                scope,
+                limit,
+                current,
+                requested,
+            } => {
+                write!(
+                    f,
+                    "{} delta ${} + ${} per 1% exceeds ${}",
+                    scope, current, requested, limit
+                )
+            }
         }
     }
 }
@@ -350,11 +421,24 @@ pub struct RiskGate {
     circuit_events: Arc<RwLock<Vec<CircuitBreakerEvent>>>,
     /// Last HALTED timestamp (for auto-recovery cooldown checks)
     halted_at: Arc<RwLock<Option<DateTime<Utc>>>>,
+    /// Optional order-flow toxicity monitor (crypto BUY throttling)
+    toxicity: Option<Arc<ToxicityMonitor>>,
+    /// Sliding-window BUY notional accounting
+    notional_throttle: Arc<RwLock<NotionalThrottle>>,
+    /// Optional metrics sink (throttled order counter)
+    metrics: Option<Arc<Metrics>>,
+    /// 宣告式風控政策 (黑名單、交易時段、單筆上限)
+    policy: Option<Arc<RiskPolicy>>,
+    /// 二元倉位 Greeks (crypto 回合的 delta 暴露上限)
+    greeks: Option<Arc<GreeksBook>>,
+    /// 自身掛單的即時成交率 (未證明前不放大下單量)
+    fillability: Option<(Arc<FillabilityTracker>, FillabilityGate)>,
 }
 
 impl RiskGate {
     /// 創建新的風控閘門
     pub fn new(config: RiskConfig) -> Self {
+        let notional_throttle = NotionalThrottle::new(config.notional_throttle.clone());
         Self {
             config,
             state: Arc::new(RwLock::new(PlatformRiskState::Normal)),
@@ -368,9 +452,62 @@ impl RiskGate {
             drawdown_stats: Arc::new(RwLock::new(DrawdownStats::default())),
             circuit_events: Arc::new(RwLock::new(Vec::new())),
             halted_at: Arc::new(RwLock::new(None)),
+            toxicity: None,
+            notional_throttle: Arc::new(RwLock::new(notional_throttle)),
+            metrics: None,
+            policy: None,
+            greeks: None,
+            fillability: None,
         }
     }
 
+    /// Throttle crypto BUY intents by order-flow toxicity.
+    pub fn with_toxicity_monitor(mut self, monitor: Arc<ToxicityMonitor>) -> Self {
+        self.toxicity = Some(monitor);
+        self
+    }
+
+    pub fn toxicity_monitor(&self) -> Option<Arc<ToxicityMonitor>> {
+        self.toxicity.clone()
+    }
+
+    /// Count notional-throttled orders in `metrics`.
+    pub fn with_metrics(mut self, metrics: Arc<Metrics>) -> Self {
+        self.metrics = Some(metrics);
+        self
+    }
+
+    /// 套用風控政策：數值上限寫入 RiskConfig，黑名單與交易時段於下單時檢查
+    pub fn with_policy(mut self, policy: RiskPolicy) -> Self {
+        policy.apply_to(&mut self.config);
+        self.policy = Some(Arc::new(policy));
+        self
+    }
+
+    pub fn policy(&self) -> Option<Arc<RiskPolicy>> {
+        self.policy.clone()
+    }
+
+    /// 以 delta 計的 crypto 回合暴露上限 (GreeksBook 由成交回報維護)
+    pub fn with_greeks_book(mut self, book: Arc<GreeksBook>) -> Self {
+        self.greeks = Some(book);
+        self
+    }
+
+    pub fn greeks_book(&self) -> Option<Arc<GreeksBook>> {
+        self.greeks.clone()
+    }
+
+    /// BUY 超過 `gate.base_shares` 時，需 token 的即時被動成交率達標
+    pub fn with_fillability(
+        mut self,
+        tracker: Arc<FillabilityTracker>,
+        gate: FillabilityGate,
+    ) -> Self {
+        self.fillability = Some((tracker, gate));
+        self
+    }
+
     /// 註冊 Agent 的風控參數
     pub async fn register_agent(&self, agent_id: &str, params: AgentRiskParams) {
         let mut params_map = self.agent_params.write().await;
@@ -423,6 +560,16 @@ impl RiskGate {
     /// 檢查訂單是否可以執行
     ///
     /// 這是主要的風控入口點，會依序執行多層檢查。
+    #[instrument(
+        name = "risk",
+        skip_all,
+        fields(
+            agent_id = %intent.agent_id,
+            trade_id = %intent.intent_id,
+            token_id = %intent.token_id,
+            is_buy = intent.is_buy,
+        )
+    )]
     pub async fn check_order(&self, intent: &OrderIntent) -> RiskCheckResult {
         // Try automatic recovery before evaluating trading eligibility.
         self.try_auto_recover_circuit_breaker().await;
@@ -480,27 +627,88 @@ impl RiskGate {
             });
         }
 
+        // 5a. 風控政策：禁止市場 / 交易時段 / 單筆上限
+        let mut max_order_value = params.max_order_value;
+        if let Some(policy) = &self.policy {
+            if let Some(reason) = policy.check_intent(intent, Utc::now()) {
+                return RiskCheckResult::Blocked(reason);
+            }
+            if let Some(cap) = policy.limits.max_order_value {
+                max_order_value = max_order_value.min(cap);
+            }
+        }
+
+        // 5b. Order-flow toxicity: block when high, shrink the order cap when elevated
+        if let Some((monitor, symbol)) = self.toxicity_symbol_for(intent) {
+            let cfg = monitor.config().for_symbol(&symbol);
+            let snapshot = monitor.snapshot(&symbol, Utc::now());
+            match snapshot.level {
+                ToxicityLevel::High if cfg.block_on_high => {
+                    let vpin = snapshot
+                        .vpin
+                        .and_then(Decimal::from_f64)
+                        .unwrap_or_default();
+                    return RiskCheckResult::Blocked(BlockReason::ToxicFlow {
+                        symbol,
+                        vpin: vpin.round_dp(4),
+                        threshold: Decimal::from_f64(cfg.high_threshold).unwrap_or_default(),
+                    });
+                }
+                ToxicityLevel::High | ToxicityLevel::Elevated => {
+                    let scale = Decimal::from_f64(cfg.elevated_size_multiplier.clamp(0.0, 1.0))
+                        .unwrap_or(Decimal::ONE);
+                    max_order_value *= scale;
+                }
+                ToxicityLevel::Normal => {}
+            }
+        }
+
+        // 5c. Delta 暴露上限 (crypto 回合)
+        if let Some(book) = &self.greeks {
+            if let Some((scope, limit, current, requested)) = book.check_intent(intent, Utc::now())
+            {
+                let to_dec = |v: f64| Decimal::from_f64(v).unwrap_or_default().round_dp(2);
+                return RiskCheckResult::Blocked(BlockReason::DeltaExposureExceeded {
+                    scope,
+                    limit: to_dec(limit),
+                    current: to_dec(current),
+                    requested: to_dec(requested),
+                });
+            }
+        }
+
+        // 5d. 即時成交率未證明前，下單量縮回 base_shares
+        if let Some((tracker, gate)) = &self.fillability {
+            let ctx = ValidationContext::new()
+                .with_trade(intent.shares, intent.limit_price)
+                .with_live_fill_rate(gate.fill_rate(tracker, &intent.token_id));
+            if let Err(e) = FillabilityValidator::from_gate(gate).validate(&ctx) {
+                return RiskCheckResult::Adjusted(AdjustmentSuggestion {
+                    max_shares: gate.base_shares,
+                    reason: e.to_string(),
+                });
+            }
+        }
+
         // 6. 計算訂單價值
         let order_value = intent.notional_value();
 
         // 7. 檢查單筆限額
-        if order_value > params.max_order_value {
+        if order_value > max_order_value {
             // 可以建議調整數量
-            let max_shares = (params.max_order_value / intent.limit_price)
-                .to_u64()
-                .unwrap_or(0);
+            let max_shares = (max_order_value / intent.limit_price).to_u64().unwrap_or(0);
 
             if max_shares > 0 {
                 return RiskCheckResult::Adjusted(AdjustmentSuggestion {
                     max_shares,
                     reason: format!(
                         "Order value ${} exceeds agent limit ${}",
-                        order_value, params.max_order_value
+                        order_value, max_order_value
                     ),
                 });
             } else {
                 return RiskCheckResult::Blocked(BlockReason::ExceedsSingleLimit {
-                    limit: params.max_order_value,
+                    limit: max_order_value,
                     requested: order_value,
                 });
             }
@@ -596,7 +804,90 @@ impl RiskGate {
             }
         }
 
-        RiskCheckResult::Passed
+        // 13. 名目金額節流 (最後一步：通過即計入滑動視窗)
+        self.reserve_notional(intent, order_value).await
+    }
+
+    async fn reserve_notional(
+        &self,
+        intent: &OrderIntent,
+        order_value: Decimal,
+    ) -> RiskCheckResult {
+        if !self.config.notional_throttle.is_enabled() {
+            return RiskCheckResult::Passed;
+        }
+
+        let reserved = self.notional_throttle.write().await.try_reserve_for(
+            intent.intent_id,
+            &intent.agent_id,
+            order_value,
+            Utc::now(),
+        );
+        let Err(breach) = reserved else {
+            return RiskCheckResult::Passed;
+        };
+
+        if let Some(metrics) = &self.metrics {
+            metrics.inc_orders_throttled();
+        }
+
+        // 剩餘額度足以容納部分數量時建議縮單
+        if intent.limit_price > Decimal::ZERO {
+            let max_shares = (breach.remaining() / intent.limit_price)
+                .floor()
+                .to_u64()
+                .unwrap_or(0);
+            if max_shares > 0 && max_shares < intent.shares {
+                return RiskCheckResult::Adjusted(AdjustmentSuggestion {
+                    max_shares,
+                    reason: format!(
+                        "{} notional ${} left of ${} per {}",
+                        breach.scope,
+                        breach.remaining(),
+                        breach.limit,
+                        breach.interval
+                    ),
+                });
+            }
+        }
+
+        RiskCheckResult::Blocked(BlockReason::NotionalThrottled {
+            scope: breach.scope,
+            interval: breach.interval,
+            limit: breach.limit,
+            used: breach.used,
+            requested: breach.requested,
+        })
+    }
+
+    /// 扣回 intent 已計入節流視窗的名目金額，保留已成交的 `filled_value`
+    ///
+    /// 訂單在通過風控後被擋下、過期或執行失敗時呼叫，避免未成交的訂單佔用額度。
+    pub async fn release_notional(&self, intent_id: Uuid, filled_value: Decimal) {
+        if !self.config.notional_throttle.is_enabled() {
+            return;
+        }
+        let released = self
+            .notional_throttle
+            .write()
+            .await
+            .release(intent_id, filled_value);
+        if released > Decimal::ZERO {
+            debug!(%intent_id, %released, "released throttled notional");
+        }
+    }
+
+    /// 平台與各 Agent 的名目金額節流使用量
+    pub async fn notional_usage(&self) -> Vec<NotionalUsage> {
+        self.notional_throttle.write().await.usage(Utc::now())
+    }
+
+    fn toxicity_symbol_for(&self, intent: &OrderIntent) -> Option<(&Arc<ToxicityMonitor>, String)> {
+        let monitor = self.toxicity.as_ref()?;
+        if !monitor.config().enabled || intent.domain != Domain::Crypto {
+            return None;
+        }
+        Some((monitor, ToxicityMonitor::symbol_for_intent(intent)?))
     }
 
     // ==================== 狀態更新 ====================
@@ -786,9 +1077,20 @@ impl RiskGate {
         *state = PlatformRiskState::Halted;
         drop(state);
 
-        *self.halted_at.write().await = Some(Utc::now());
+        let halted_at = Utc::now();
+        *self.halted_at.write().await = Some(halted_at);
         self.push_circuit_event(reason.to_string(), PlatformRiskState::Halted)
             .await;
+        #[cfg(feature = "api")]
+        webhooks::emit(
+            WebhookEventType::CircuitBreaker,
+            serde_json::json!({
+                "source": "platform_risk",
+                "state": "halted",
+                "reason": reason,
+                "halted_at": halted_at,
+            }),
+        );
     }
 
     /// 重置熔斷
@@ -1040,6 +1342,7 @@ impl RiskGate {
         *self.drawdown_stats.write().await = DrawdownStats::default();
         self.circuit_events.write().await.clear();
         *self.halted_at.write().await = None;
+        self.notional_throttle.write().await.clear();
     }
 
     async fn try_auto_recover_circuit_breaker(&self) {
@@ -1416,4 +1719,151 @@ mod tests {
         assert_eq!(success, 1);
         assert_eq!(failure, 3);
     }
+
+    #[tokio::test]
+    async fn test_toxic_flow_blocks_crypto_buys_only() {
+        let monitor = Arc::new(ToxicityMonitor::new(crate::analysis::VpinConfig {
+            enabled: true,
+            defaults: crate::analysis::VpinSymbolConfig {
+                bucket_volume_usd: 1_000.0,
+                min_buckets: 2,
+                ..Default::default()
+            },
+            ..Default::default()
+        }));
+        for _ in 0..4 {
+            monitor.on_trade("BTCUSDT", 100.0, 10.0, Some(true), Utc::now());
+        }
+        let gate = RiskGate::new(RiskConfig::default()).with_toxicity_monitor(monitor);
+        gate.register_agent("agent1", AgentRiskParams::default())
+            .await;
+
+        let buy = make_intent("agent1", 10, Decimal::from_str_exact("0.50").unwrap());
+        match gate.check_order(&buy).await {
+            RiskCheckResult::Blocked(BlockReason::ToxicFlow { symbol, .. }) => {
+                assert_eq!(symbol, "BTCUSDT");
+            }
+            other => panic!("expected toxic-flow block, got {:?}", other),
+        }
+        let sell = make_sell_intent("agent1", 10, Decimal::from_str_exact("0.50").unwrap());
+        assert!(gate.check_order(&sell).await.is_passed());
+    }
+
+    #[tokio::test]
+    async fn test_notional_throttle_adjusts_then_blocks() {
+        let metrics = Arc::new(Metrics::new());
+        let mut config = RiskConfig::default();
+        config.notional_throttle.global.per_minute = Some(Decimal::from(40));
+        let gate = RiskGate::new(config).with_metrics(metrics.clone());
+        gate.register_agent("agent1", AgentRiskParams::default())
+            .await;
+
+        let price = Decimal::from_str_exact("0.50").unwrap();
+        let first = make_intent("agent1", 60, price);
+        assert!(gate.check_order(&first).await.is_passed());
+
+        // $30 used, $10 left: 40 shares shrink to 20
+        match gate.check_order(&make_intent("agent1", 40, price)).await {
+            RiskCheckResult::Adjusted(adj) => assert_eq!(adj.max_shares, 20),
+            other => panic!("expected throttle adjustment, got {:?}", other),
+        }
+        assert!(gate
+            .check_order(&make_intent("agent1", 20, price))
+            .await
+            .is_passed());
+
+        match gate.check_order(&make_intent("agent1", 1, price)).await {
+            RiskCheckResult::Blocked(BlockReason::NotionalThrottled {
+                scope, interval, ..
+            }) => {
+                assert_eq!(scope, "global");
+                assert_eq!(interval, ThrottleInterval::Minute);
+            }
+            other => panic!("expected throttle block, got {:?}", other),
+        }
+        assert_eq!(metrics.orders_throttled.load(Ordering::Relaxed), 2);
+
+        // The first order only filled $20: the unfilled $10 is freed again
+        gate.release_notional(first.intent_id, Decimal::from(20))
+            .await;
+        assert_eq!(
+            gate.notional_usage().await[0].used_minute,
+            Decimal::from(30)
+        );
+        assert!(gate
+            .check_order(&make_intent("agent1", 20, price))
+            .await
+            .is_passed());
+
+        // SELL exits are never throttled
+        assert!(gate
+            .check_order(&make_sell_intent("agent1", 100, price))
+            .await
+            .is_passed());
+    }
+
+    #[tokio::test]
+    async fn test_unproven_fillability_caps_size() {
+        let tracker = Arc::new(FillabilityTracker::default());
+        let gate = FillabilityGate {
+            enabled: true,
+            base_shares: 20,
+            min_orders: 2,
+            ..FillabilityGate::default()
+        };
+        let risk = RiskGate::new(RiskConfig::default()).with_fillability(tracker.clone(), gate);
+        risk.register_agent("agent1", AgentRiskParams::default())
+            .await;
+        let price = Decimal::from_str_exact("0.50").unwrap();
+
+        match risk.check_order(&make_intent("agent1", 60, price)).await {
+            RiskCheckResult::Adjusted(adj) => assert_eq!(adj.max_shares, 20),
+            other => panic!("expected fillability adjustment, got {:?}", other),
+        }
+        assert!(risk
+            .check_order(&make_intent("agent1", 20, price))
+            .await
+            .is_passed());
+
+        // Resting orders on the token mostly filled: sizing up is allowed
+        tracker.record("token-123", 100, 80);
+        tracker.record("token-123", 100, 60);
+        assert!(risk
+            .check_order(&make_intent("agent1", 60, price))
+            .await
+            .is_passed());
+    }
+
+    #[tokio::test]
+    async fn test_policy_bans_markets_and_caps_order_value() {
+        let policy = RiskPolicy::from_toml_str(
+            r#"
+            [limits]
+            max_order_value = 10
+
+            [markets]
+            banned = ["eth-*"]
+            "#,
+        )
+        .unwrap();
+        let gate = RiskGate::new(RiskConfig::default()).with_policy(policy);
+        gate.register_agent("agent1", AgentRiskParams::default())
+            .await;
+
+        let price = Decimal::from_str_exact("0.50").unwrap();
+        let mut banned = make_intent("agent1", 10, price);
+        banned.market_slug = "eth-15m".to_string();
+        match gate.check_order(&banned).await {
+            RiskCheckResult::Blocked(BlockReason::PolicyViolation { rule, .. }) => {
+                assert_eq!(rule, "banned_market")
+            }
+            other => panic!("expected policy block, got {:?}", other),
+        }
+
+        // $25 order shrinks to the policy's $10 cap
+        match gate.check_order(&make_intent("agent1", 50, price)).await {
+            RiskCheckResult::Adjusted(adj) => assert_eq!(adj.max_shares, 20),
+            other => panic!("expected policy cap adjustment, got {:?}", other),
+        }
+    }
 }
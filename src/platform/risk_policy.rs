@@ -0,0 +1,569 @@
+//! Risk Policy - 宣告式風控政策
+//!
+//! 從 `risk_policy.toml` 載入平台級風控規則：
+//! - 暴露 / 單筆上限與每日損失、回撤上限 (覆寫 `RiskConfig`)
+//! - 各領域暴露與損失上限
+//! - 禁止交易的市場 (支援 `*` 萬用字元)
+//! - 交易時段 (UTC)
+//!
+//! `evaluate` 對假設的訂單意圖逐條評估，供 `ploy config risk-check` 乾跑使用。
+
+use chrono::{DateTime, Datelike, NaiveTime, Utc, Weekday};
+use rust_decimal::Decimal;
+use serde::{Deserialize, Serialize};
+use std::collections::HashMap;
+use std::path::{Path, PathBuf};
+use std::str::FromStr;
+
+use super::risk::{BlockReason, RiskConfig};
+use super::types::{Domain, OrderIntent};
+use crate::error::{PloyError, Result};
+
+/// 預設政策檔名
+pub const RISK_POLICY_FILE: &str = "risk_policy.toml";
+
+/// 平台級上限 (None = 沿用 RiskConfig)
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+#[serde(default)]
+pub struct PolicyLimits {
+    /// 平台最大總暴露 (USD)
+    pub max_platform_exposure: Option<Decimal>,
+    /// 單筆訂單名目上限 (USD)，與 Agent 參數取較小值
+    pub max_order_value: Option<Decimal>,
+    /// 每日最大損失 (USD)
+    pub daily_loss_limit: Option<Decimal>,
+    /// 回撤上限 (USD)
+    pub max_drawdown: Option<Decimal>,
+}
+
+/// 領域級上限
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+#[serde(default)]
+pub struct DomainPolicy {
+    pub max_exposure: Option<Decimal>,
+    pub daily_loss_limit: Option<Decimal>,
+}
+
+/// 市場黑名單
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+#[serde(default)]
+pub struct MarketPolicy {
+    /// 市場 slug 樣式，`*` 匹配任意字元 (不分大小寫)
+    pub banned: Vec<String>,
+}
+
+/// 交易時段 (UTC)
+///
+/// `start > end` 表示跨午夜，例如 22:00-04:00。
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct TradingWindow {
+    /// 適用領域 (空 = 全部)
+    #[serde(default)]
+    pub domains: Vec<String>,
+    /// 允許的星期 (空 = 每天)，以時段開始的那天計算
+    #[serde(default)]
+    pub days: Vec<Weekday>,
+    /// 開始時間 "HH:MM"
+    pub start: String,
+    /// 結束時間 "HH:MM"
+    pub end: String,
+}
+
+impl TradingWindow {
+    fn applies_to(&self, domain: Domain) -> bool {
+        self.domains.is_empty()
+            || self
+                .domains
+                .iter()
+                .any(|d| Domain::from_str(d).ok() == Some(domain))
+    }
+
+    fn contains(&self, now: DateTime<Utc>) -> bool {
+        let (Ok(start), Ok(end)) = (parse_hhmm(&self.start), parse_hhmm(&self.end)) else {
+            return false;
+        };
+        let time = now.time();
+        let today = now.weekday();
+        let day_allowed = |day: Weekday| self.days.is_empty() || self.days.contains(&day);
+
+        if start <= end {
+            day_allowed(today) && time >= start && time < end
+        } else if time >= start {
+            day_allowed(today)
+        } else {
+            // 跨午夜的後半段屬於前一天開始的時段
+            time < end && day_allowed(today.pred())
+        }
+    }
+
+    fn describe(&self) -> String {
+        let days = if self.days.is_empty() {
+            "daily".to_string()
+        } else {
+            self.days
+                .iter()
+                .map(|d| d.to_string())
+                .collect::<Vec<_>>()
+                .join(",")
+        };
+        format!("{}-{} UTC ({})", self.start, self.end, days)
+    }
+}
+
+fn parse_hhmm(raw: &str) -> std::result::Result<NaiveTime, chrono::ParseError> {
+    NaiveTime::parse_from_str(raw.trim(), "%H:%M")
+}
+
+/// 萬用字元匹配 (`*` = 任意長度)
+fn wildcard_match(pattern: &str, text: &str) -> bool {
+    let pattern = pattern.to_ascii_lowercase();
+    let text = text.to_ascii_lowercase();
+    let parts: Vec<&str> = pattern.split('*').collect();
+    if parts.len() == 1 {
+        return pattern == text;
+    }
+
+    let first = parts[0];
+    let last = parts[parts.len() - 1];
+    if !text.starts_with(first) || !text[first.len()..].ends_with(last) {
+        return false;
+    }
+    let mut rest = &text[first.len()..text.len() - last.len()];
+    for part in &parts[1..parts.len() - 1] {
+        match rest.find(part) {
+            Some(idx) => rest = &rest[idx + part.len()..],
+            None => return false,
+        }
+    }
+    true
+}
+
+/// 乾跑評估時的平台狀態 (假設值)
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+#[serde(default)]
+pub struct PolicyContext {
+    /// 平台當前總暴露
+    pub platform_exposure: Decimal,
+    /// 訂單所屬領域的當前暴露
+    pub domain_exposure: Decimal,
+    /// 今日已實現損益 (負值 = 虧損)
+    pub daily_pnl: Decimal,
+    /// 訂單所屬領域今日損益
+    pub domain_daily_pnl: Decimal,
+    /// 當前回撤 (USD)
+    pub drawdown: Decimal,
+}
+
+/// 單一規則的評估結果
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct PolicyRuleResult {
+    pub rule: String,
+    pub passed: bool,
+    pub detail: String,
+}
+
+/// 訂單意圖的完整評估結果
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+pub struct PolicyEvaluation {
+    pub results: Vec<PolicyRuleResult>,
+}
+
+impl PolicyEvaluation {
+    pub fn passed(&self) -> bool {
+        self.results.iter().all(|r| r.passed)
+    }
+
+    pub fn failures(&self) -> impl Iterator<Item = &PolicyRuleResult> {
+        self.results.iter().filter(|r| !r.passed)
+    }
+
+    fn push(&mut self, rule: &str, passed: bool, detail: String) {
+        self.results.push(PolicyRuleResult {
+            rule: rule.to_string(),
+            passed,
+            detail,
+        });
+    }
+}
+
+/// 宣告式風控政策
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+#[serde(default)]
+pub struct RiskPolicy {
+    pub limits: PolicyLimits,
+    /// 領域 (crypto / sports / politics / economics / custom:<id>) -> 上限
+    pub domains: HashMap<String, DomainPolicy>,
+    pub markets: MarketPolicy,
+    /// 允許交易的時段；空 = 全天候，否則需落在任一適用時段內
+    pub trading_hours: Vec<TradingWindow>,
+}
+
+impl RiskPolicy {
+    /// 解析 TOML 並驗證
+    pub fn from_toml_str(raw: &str) -> Result<Self> {
+        let policy: Self = toml::from_str(raw)
+            .map_err(|e| PloyError::Validation(format!("invalid risk policy: {}", e)))?;
+        policy.validate()?;
+        Ok(policy)
+    }
+
+    /// 從檔案載入
+    pub fn load(path: impl AsRef<Path>) -> Result<Self> {
+        let raw = std::fs::read_to_string(path.as_ref())?;
+        Self::from_toml_str(&raw)
+    }
+
+    /// 預設路徑：`PLOY_RISK_POLICY_FILE` 或 `config/risk_policy.toml`
+    pub fn default_path() -> PathBuf {
+        std::env::var("PLOY_RISK_POLICY_FILE")
+            .ok()
+            .map(|v| v.trim().to_string())
+            .filter(|v| !v.is_empty())
+            .map(PathBuf::from)
+            .unwrap_or_else(|| PathBuf::from("config").join(RISK_POLICY_FILE))
+    }
+
+    /// 預設路徑存在時載入；檔案不存在回傳 None
+    pub fn load_default() -> Result<Option<Self>> {
+        let path = Self::default_path();
+        if !path.exists() {
+            return Ok(None);
+        }
+        Self::load(path).map(Some)
+    }
+
+    pub fn validate(&self) -> Result<()> {
+        for key in self.domains.keys() {
+            Domain::from_str(key)
+                .map_err(|e| PloyError::Validation(format!("domains.{}: {}", key, e)))?;
+        }
+        for (idx, window) in self.trading_hours.iter().enumerate() {
+            for raw in [&window.start, &window.end] {
+                parse_hhmm(raw).map_err(|_| {
+                    PloyError::Validation(format!(
+                        "trading_hours[{}]: invalid time '{}', expected HH:MM",
+                        idx, raw
+                    ))
+                })?;
+            }
+            for domain in &window.domains {
+                Domain::from_str(domain).map_err(|e| {
+                    PloyError::Validation(format!("trading_hours[{}].domains: {}", idx, e))
+                })?;
+            }
+        }
+        Ok(())
+    }
+
+    pub fn domain_policy(&self, domain: Domain) -> Option<&DomainPolicy> {
+        self.domains
+            .iter()
+            .find(|(key, _)| Domain::from_str(key).ok() == Some(domain))
+            .map(|(_, policy)| policy)
+    }
+
+    /// 將數值上限套用到 RiskConfig (由 RiskGate 的既有檢查執行)
+    pub fn apply_to(&self, config: &mut RiskConfig) {
+        if let Some(v) = self.limits.max_platform_exposure {
+            config.max_platform_exposure = v;
+        }
+        if let Some(v) = self.limits.daily_loss_limit {
+            config.daily_loss_limit = v;
+        }
+        if let Some(v) = self.limits.max_drawdown {
+            config.max_drawdown_limit = Some(v);
+        }
+
+        for domain in [
+            Domain::Crypto,
+            Domain::Sports,
+            Domain::Politics,
+            Domain::Economics,
+        ] {
+            let Some(policy) = self.domain_policy(domain) else {
+                continue;
+            };
+            let (exposure, loss) = match domain {
+                Domain::Crypto => (
+                    &mut config.crypto_max_exposure,
+                    &mut config.crypto_daily_loss_limit,
+                ),
+                Domain::Sports => (
+                    &mut config.sports_max_exposure,
+                    &mut config.sports_daily_loss_limit,
+                ),
+                Domain::Politics => (
+                    &mut config.politics_max_exposure,
+                    &mut config.politics_daily_loss_limit,
+                ),
+                Domain::Economics => (
+                    &mut config.economics_max_exposure,
+                    &mut config.economics_daily_loss_limit,
+                ),
+                Domain::Custom(_) => continue,
+            };
+            if policy.max_exposure.is_some() {
+                *exposure = policy.max_exposure;
+            }
+            if policy.daily_loss_limit.is_some() {
+                *loss = policy.daily_loss_limit;
+            }
+        }
+    }
+
+    /// 命中的黑名單樣式
+    pub fn banned_pattern(&self, market_slug: &str) -> Option<&str> {
+        self.markets
+            .banned
+            .iter()
+            .find(|pattern| wildcard_match(pattern, market_slug))
+            .map(String::as_str)
+    }
+
+    /// 不在任何適用交易時段內時回傳說明；未設定時段則永遠允許
+    pub fn outside_trading_hours(&self, domain: Domain, now: DateTime<Utc>) -> Option<String> {
+        let windows: Vec<&TradingWindow> = self
+            .trading_hours
+            .iter()
+            .filter(|w| w.applies_to(domain))
+            .collect();
+        if windows.is_empty() || windows.iter().any(|w| w.contains(now)) {
+            return None;
+        }
+        let allowed = windows
+            .iter()
+            .map(|w| w.describe())
+            .collect::<Vec<_>>()
+            .join("; ");
+        Some(format!(
+            "{} {} outside {} trading hours: {}",
+            now.weekday(),
+            now.format("%H:%M UTC"),
+            domain,
+            allowed
+        ))
+    }
+
+    /// RiskGate 使用：非數值類規則 (黑名單、交易時段)
+    pub fn check_intent(&self, intent: &OrderIntent, now: DateTime<Utc>) -> Option<BlockReason> {
+        if let Some(pattern) = self.banned_pattern(&intent.market_slug) {
+            return Some(BlockReason::PolicyViolation {
+                rule: "banned_market".to_string(),
+                detail: format!("{} matches '{}'", intent.market_slug, pattern),
+            });
+        }
+        self.outside_trading_hours(intent.domain, now)
+            .map(|detail| BlockReason::PolicyViolation {
+                rule: "trading_hours".to_string(),
+                detail,
+            })
+    }
+
+    /// 乾跑：對假設的訂單意圖逐條評估所有已設定的規則
+    pub fn evaluate(
+        &self,
+        intent: &OrderIntent,
+        ctx: &PolicyContext,
+        now: DateTime<Utc>,
+    ) -> PolicyEvaluation {
+        let mut eval = PolicyEvaluation::default();
+
+        // 與 RiskGate 一致：SELL 為減倉，不受政策限制
+        if !intent.is_buy {
+            eval.push(
+                "reduce_only",
+                true,
+                "SELL intents are reduce-only exits and bypass policy limits".to_string(),
+            );
+            return eval;
+        }
+
+        let order_value = intent.notional_value();
+
+        match self.banned_pattern(&intent.market_slug) {
+            Some(pattern) => eval.push(
+                "banned_market",
+                false,
+                format!("{} matches '{}'", intent.market_slug, pattern),
+            ),
+            None => eval.push(
+                "banned_market",
+                true,
+                format!(
+                    "{} matches none of {} banned patterns",
+                    intent.market_slug,
+                    self.markets.banned.len()
+                ),
+            ),
+        }
+
+        match self.outside_trading_hours(intent.domain, now) {
+            Some(detail) => eval.push("trading_hours", false, detail),
+            None => eval.push(
+                "trading_hours",
+                true,
+                format!("{} within allowed hours", now.format("%a %H:%M UTC")),
+            ),
+        }
+
+        if let Some(limit) = self.limits.max_order_value {
+            eval.push(
+                "max_order_value",
+                order_value <= limit,
+                format!("order ${} vs limit ${}", order_value, limit),
+            );
+        }
+
+        if let Some(limit) = self.limits.max_platform_exposure {
+            let projected = ctx.platform_exposure + order_value;
+            eval.push(
+                "max_platform_exposure",
+                projected <= limit,
+                format!(
+                    "${} + ${} = ${} vs limit ${}",
+                    ctx.platform_exposure, order_value, projected, limit
+                ),
+            );
+        }
+
+        let domain_policy = self.domain_policy(intent.domain);
+        if let Some(limit) = domain_policy.and_then(|p| p.max_exposure) {
+            let projected = ctx.domain_exposure + order_value;
+            eval.push(
+                "domain_exposure",
+                projected <= limit,
+                format!(
+                    "{} ${} + ${} = ${} vs limit ${}",
+                    intent.domain, ctx.domain_exposure, order_value, projected, limit
+                ),
+            );
+        }
+
+        if let Some(limit) = self.limits.daily_loss_limit {
+            let loss = (-ctx.daily_pnl).max(Decimal::ZERO);
+            eval.push(
+                "daily_loss_limit",
+                loss < limit,
+                format!("daily loss ${} vs limit ${}", loss, limit),
+            );
+        }
+
+        if let Some(limit) = domain_policy.and_then(|p| p.daily_loss_limit) {
+            let loss = (-ctx.domain_daily_pnl).max(Decimal::ZERO);
+            eval.push(
+                "domain_daily_loss_limit",
+                loss < limit,
+                format!("{} daily loss ${} vs limit ${}", intent.domain, loss, limit),
+            );
+        }
+
+        if let Some(limit) = self.limits.max_drawdown {
+            eval.push(
+                "max_drawdown",
+                limit <= Decimal::ZERO || ctx.drawdown < limit,
+                format!("drawdown ${} vs limit ${}", ctx.drawdown, limit),
+            );
+        }
+
+        eval
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::domain::Side;
+    use chrono::TimeZone;
+    use rust_decimal_macros::dec;
+
+    const POLICY: &str = r#"
+        [limits]
+        max_platform_exposure = 1000
+        max_order_value = 100
+        daily_loss_limit = 200
+
+        [domains.crypto]
+        max_exposure = 300
+
+        [markets]
+        banned = ["*-election-*", "btc-updown-5m-*"]
+
+        [[trading_hours]]
+        domains = ["sports"]
+        days = ["Sat", "Sun"]
+        start = "22:00"
+        end = "04:00"
+    "#;
+
+    fn intent(domain: Domain, slug: &str, shares: u64, price: Decimal) -> OrderIntent {
+        OrderIntent::new(
+            "agent",
+            domain,
+            slug,
+            "token",
+            Side::Up,
+            true,
+            shares,
+            price,
+        )
+    }
+
+    #[test]
+    fn test_policy_parses_and_applies_to_risk_config() {
+        let policy = RiskPolicy::from_toml_str(POLICY).unwrap();
+        let mut config = RiskConfig::default();
+        policy.apply_to(&mut config);
+
+        assert_eq!(config.max_platform_exposure, dec!(1000));
+        assert_eq!(config.daily_loss_limit, dec!(200));
+        assert_eq!(config.crypto_max_exposure, Some(dec!(300)));
+        assert_eq!(config.sports_max_exposure, None);
+
+        assert!(
+            RiskPolicy::from_toml_str("[[trading_hours]]\nstart = \"25:00\"\nend = \"01:00\"")
+                .is_err()
+        );
+        assert!(RiskPolicy::from_toml_str("[domains.weather]\nmax_exposure = 1").is_err());
+    }
+
+    #[test]
+    fn test_evaluate_explains_each_rule() {
+        let policy = RiskPolicy::from_toml_str(POLICY).unwrap();
+        // Sunday 01:30 UTC: inside the Saturday 22:00-04:00 window
+        let sunday_night = Utc.with_ymd_and_hms(2026, 3, 1, 1, 30, 0).unwrap();
+        let ctx = PolicyContext {
+            domain_exposure: dec!(250),
+            ..Default::default()
+        };
+
+        let eval = policy.evaluate(
+            &intent(Domain::Crypto, "btc-updown-15m-1", 200, dec!(0.40)),
+            &ctx,
+            sunday_night,
+        );
+        let failed: Vec<&str> = eval.failures().map(|r| r.rule.as_str()).collect();
+        assert_eq!(failed, vec!["domain_exposure"]);
+
+        let eval = policy.evaluate(
+            &intent(Domain::Sports, "nba-election-night", 100, dec!(0.50)),
+            &PolicyContext::default(),
+            sunday_night + chrono::Duration::hours(3),
+        );
+        let failed: Vec<&str> = eval.failures().map(|r| r.rule.as_str()).collect();
+        assert_eq!(failed, vec!["banned_market", "trading_hours"]);
+
+        let sell = OrderIntent::new(
+            "agent",
+            Domain::Sports,
+            "nba-election-night",
+            "token",
+            Side::Up,
+            false,
+            10,
+            dec!(0.5),
+        );
+        assert!(policy
+            .evaluate(&sell, &PolicyContext::default(), sunday_night)
+            .passed());
+    }
+}
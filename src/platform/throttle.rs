@@ -0,0 +1,440 @@
+//! Notional Throttle - 名目金額節流
+//!
+//! 以滑動視窗統計 BUY 訂單名目金額 (USD)，限制每分鐘 / 每小時的下單速度：
+//! - 平台級別 (所有 Agent 合計)
+//! - Agent 級別 (預設值 + 個別覆寫)
+
+use chrono::{DateTime, Duration, Utc};
+use rust_decimal::Decimal;
+use serde::{Deserialize, Serialize};
+use std::collections::{HashMap, VecDeque};
+use uuid::Uuid;
+
+/// 每個時間區間的名目上限 (USD, None = 不限制)
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+#[serde(default)]
+pub struct NotionalLimits {
+    pub per_minute: Option<Decimal>,
+    pub per_hour: Option<Decimal>,
+}
+
+impl NotionalLimits {
+    pub fn is_unlimited(&self) -> bool {
+        self.per_minute.is_none() && self.per_hour.is_none()
+    }
+}
+
+/// 節流配置
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+#[serde(default)]
+pub struct NotionalThrottleConfig {
+    /// 平台合計上限
+    pub global: NotionalLimits,
+    /// 每個 Agent 的預設上限
+    pub per_agent: NotionalLimits,
+    /// 個別 Agent 覆寫 (agent_id -> limits)
+    pub agent_overrides: HashMap<String, NotionalLimits>,
+}
+
+impl NotionalThrottleConfig {
+    pub fn is_enabled(&self) -> bool {
+        !self.global.is_unlimited()
+            || !self.per_agent.is_unlimited()
+            || self.agent_overrides.values().any(|l| !l.is_unlimited())
+    }
+
+    pub fn agent_limits(&self, agent_id: &str) -> &NotionalLimits {
+        self.agent_overrides
+            .get(agent_id)
+            .unwrap_or(&self.per_agent)
+    }
+}
+
+/// 節流時間區間
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+pub enum ThrottleInterval {
+    Minute,
+    Hour,
+}
+
+impl ThrottleInterval {
+    fn duration(&self) -> Duration {
+        match self {
+            ThrottleInterval::Minute => Duration::minutes(1),
+            ThrottleInterval::Hour => Duration::hours(1),
+        }
+    }
+}
+
+impl std::fmt::Display for ThrottleInterval {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            ThrottleInterval::Minute => write!(f, "minute"),
+            ThrottleInterval::Hour => write!(f, "hour"),
+        }
+    }
+}
+
+/// 超出節流上限
+#[derive(Debug, Clone, PartialEq)]
+pub struct ThrottleBreach {
+    /// "global" 或 agent_id
+    pub scope: String,
+    pub interval: ThrottleInterval,
+    pub limit: Decimal,
+    /// 視窗內已使用的名目金額
+    pub used: Decimal,
+    pub requested: Decimal,
+}
+
+impl ThrottleBreach {
+    /// 視窗內剩餘可用名目金額
+    pub fn remaining(&self) -> Decimal {
+        (self.limit - self.used).max(Decimal::ZERO)
+    }
+}
+
+/// 單一範圍的節流使用量快照
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct NotionalUsage {
+    pub scope: String,
+    pub used_minute: Decimal,
+    pub used_hour: Decimal,
+    pub limits: NotionalLimits,
+}
+
+/// 滑動視窗累計
+#[derive(Debug, Clone)]
+struct SlidingWindow {
+    span: Duration,
+    entries: VecDeque<(DateTime<Utc>, Decimal)>,
+    total: Decimal,
+}
+
+impl SlidingWindow {
+    fn new(span: Duration) -> Self {
+        Self {
+            span,
+            entries: VecDeque::new(),
+            total: Decimal::ZERO,
+        }
+    }
+
+    fn evict(&mut self, now: DateTime<Utc>) {
+        let cutoff = now - self.span;
+        while let Some((ts, value)) = self.entries.front().copied() {
+            if ts > cutoff {
+                break;
+            }
+            self.total -= value;
+            self.entries.pop_front();
+        }
+    }
+
+    fn used(&mut self, now: DateTime<Utc>) -> Decimal {
+        self.evict(now);
+        self.total
+    }
+
+    fn record(&mut self, now: DateTime<Utc>, value: Decimal) {
+        self.entries.push_back((now, value));
+        self.total += value;
+    }
+
+    /// 從 `at` 時記入的一筆中扣回 `value`；該筆已滑出視窗則不處理
+    fn release(&mut self, at: DateTime<Utc>, value: Decimal) {
+        let Some(entry) = self
+            .entries
+            .iter_mut()
+            .rev()
+            .find(|(ts, v)| *ts == at && *v > Decimal::ZERO)
+        else {
+            return;
+        };
+        let released = value.min(entry.1);
+        entry.1 -= released;
+        self.total -= released;
+    }
+}
+
+/// 一個範圍 (平台或 Agent) 的分鐘 / 小時視窗
+#[derive(Debug, Clone)]
+struct ScopeWindows {
+    minute: SlidingWindow,
+    hour: SlidingWindow,
+}
+
+impl Default for ScopeWindows {
+    fn default() -> Self {
+        Self {
+            minute: SlidingWindow::new(ThrottleInterval::Minute.duration()),
+            hour: SlidingWindow::new(ThrottleInterval::Hour.duration()),
+        }
+    }
+}
+
+impl ScopeWindows {
+    fn check(
+        &mut self,
+        scope: &str,
+        limits: &NotionalLimits,
+        value: Decimal,
+        now: DateTime<Utc>,
+    ) -> Option<ThrottleBreach> {
+        let windows = [
+            (
+                ThrottleInterval::Minute,
+                limits.per_minute,
+                &mut self.minute,
+            ),
+            (ThrottleInterval::Hour, limits.per_hour, &mut self.hour),
+        ];
+        for (interval, limit, window) in windows {
+            let Some(limit) = limit else { continue };
+            let used = window.used(now);
+            if used + value > limit {
+                return Some(ThrottleBreach {
+                    scope: scope.to_string(),
+                    interval,
+                    limit,
+                    used,
+                    requested: value,
+                });
+            }
+        }
+        None
+    }
+
+    fn record(&mut self, value: Decimal, now: DateTime<Utc>) {
+        self.minute.record(now, value);
+        self.hour.record(now, value);
+    }
+
+    fn release(&mut self, value: Decimal, at: DateTime<Utc>) {
+        self.minute.release(at, value);
+        self.hour.release(at, value);
+    }
+
+    fn usage(&mut self, scope: &str, limits: &NotionalLimits, now: DateTime<Utc>) -> NotionalUsage {
+        NotionalUsage {
+            scope: scope.to_string(),
+            used_minute: self.minute.used(now),
+            used_hour: self.hour.used(now),
+            limits: limits.clone(),
+        }
+    }
+}
+
+/// 依 intent 記錄的已計入名目金額，供訂單未成交時扣回
+#[derive(Debug, Clone)]
+struct Reservation {
+    agent_id: String,
+    value: Decimal,
+    at: DateTime<Utc>,
+}
+
+/// 名目金額節流器
+///
+/// `try_reserve` 在檢查通過時立即計入視窗，檢查與記帳為同一步驟。
+/// 以 `try_reserve_for` 計入的金額可在訂單被擋下或執行失敗時 `release` 扣回。
+#[derive(Debug, Clone)]
+pub struct NotionalThrottle {
+    config: NotionalThrottleConfig,
+    global: ScopeWindows,
+    agents: HashMap<String, ScopeWindows>,
+    reservations: HashMap<Uuid, Reservation>,
+}
+
+impl NotionalThrottle {
+    pub fn new(config: NotionalThrottleConfig) -> Self {
+        Self {
+            config,
+            global: ScopeWindows::default(),
+            agents: HashMap::new(),
+            reservations: HashMap::new(),
+        }
+    }
+
+    pub fn config(&self) -> &NotionalThrottleConfig {
+        &self.config
+    }
+
+    pub fn is_enabled(&self) -> bool {
+        self.config.is_enabled()
+    }
+
+    /// 檢查並計入一筆名目金額；任一範圍超限則不計入並回傳最先觸發的限制
+    pub fn try_reserve(
+        &mut self,
+        agent_id: &str,
+        value: Decimal,
+        now: DateTime<Utc>,
+    ) -> std::result::Result<(), ThrottleBreach> {
+        if let Some(breach) = self.global.check("global", &self.config.global, value, now) {
+            return Err(breach);
+        }
+
+        let agent_limits = self.config.agent_limits(agent_id);
+        let agent = self.agents.entry(agent_id.to_string()).or_default();
+        if let Some(breach) = agent.check(agent_id, agent_limits, value, now) {
+            return Err(breach);
+        }
+
+        self.global.record(value, now);
+        agent.record(value, now);
+        Ok(())
+    }
+
+    /// 同 `try_reserve`，並記下 `intent_id` 以便之後扣回
+    pub fn try_reserve_for(
+        &mut self,
+        intent_id: Uuid,
+        agent_id: &str,
+        value: Decimal,
+        now: DateTime<Utc>,
+    ) -> std::result::Result<(), ThrottleBreach> {
+        self.try_reserve(agent_id, value, now)?;
+        // 超過小時視窗的紀錄已無可扣回的金額
+        let cutoff = now - ThrottleInterval::Hour.duration();
+        self.reservations.retain(|_, r| r.at > cutoff);
+        self.reservations.insert(
+            intent_id,
+            Reservation {
+                agent_id: agent_id.to_string(),
+                value,
+                at: now,
+            },
+        );
+        Ok(())
+    }
+
+    /// 扣回 `intent_id` 的名目金額，保留 `keep` (例如已成交部分)；回傳扣回金額
+    pub fn release(&mut self, intent_id: Uuid, keep: Decimal) -> Decimal {
+        let Some(reservation) = self.reservations.remove(&intent_id) else {
+            return Decimal::ZERO;
+        };
+        let released = (reservation.value - keep.max(Decimal::ZERO)).max(Decimal::ZERO);
+        if released.is_zero() {
+            return Decimal::ZERO;
+        }
+        self.global.release(released, reservation.at);
+        if let Some(agent) = self.agents.get_mut(&reservation.agent_id) {
+            agent.release(released, reservation.at);
+        }
+        released
+    }
+
+    /// 平台與各 Agent 當前視窗使用量
+    pub fn usage(&mut self, now: DateTime<Utc>) -> Vec<NotionalUsage> {
+        let mut usage = vec![self.global.usage("global", &self.config.global, now)];
+        let mut agent_ids: Vec<String> = self.agents.keys().cloned().collect();
+        agent_ids.sort();
+        for agent_id in agent_ids {
+            let limits = self.config.agent_limits(&agent_id).clone();
+            if let Some(windows) = self.agents.get_mut(&agent_id) {
+                usage.push(windows.usage(&agent_id, &limits, now));
+            }
+        }
+        usage
+    }
+
+    pub fn clear(&mut self) {
+        self.global = ScopeWindows::default();
+        self.agents.clear();
+        self.reservations.clear();
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use rust_decimal_macros::dec;
+
+    #[test]
+    fn test_sliding_window_expires_old_notional() {
+        let mut throttle = NotionalThrottle::new(NotionalThrottleConfig {
+            global: NotionalLimits {
+                per_minute: Some(dec!(500)),
+                per_hour: Some(dec!(800)),
+            },
+            ..Default::default()
+        });
+        let t0 = Utc::now();
+
+        assert!(throttle.try_reserve("a", dec!(300), t0).is_ok());
+        let breach = throttle
+            .try_reserve("b", dec!(250), t0 + Duration::seconds(30))
+            .unwrap_err();
+        assert_eq!(breach.interval, ThrottleInterval::Minute);
+        assert_eq!(breach.remaining(), dec!(200));
+
+        // Minute window has rolled; hour window still holds the first $300
+        assert!(throttle
+            .try_reserve("b", dec!(250), t0 + Duration::seconds(61))
+            .is_ok());
+        let breach = throttle
+            .try_reserve("b", dec!(300), t0 + Duration::minutes(5))
+            .unwrap_err();
+        assert_eq!(breach.interval, ThrottleInterval::Hour);
+        assert_eq!(breach.used, dec!(550));
+    }
+
+    #[test]
+    fn test_agent_override_and_default_limits() {
+        let mut throttle = NotionalThrottle::new(NotionalThrottleConfig {
+            per_agent: NotionalLimits {
+                per_minute: Some(dec!(100)),
+                per_hour: None,
+            },
+            agent_overrides: HashMap::from([(
+                "big".to_string(),
+                NotionalLimits {
+                    per_minute: Some(dec!(1000)),
+                    per_hour: None,
+                },
+            )]),
+            ..Default::default()
+        });
+        let now = Utc::now();
+
+        assert!(throttle.try_reserve("small", dec!(150), now).is_err());
+        assert!(throttle.try_reserve("big", dec!(150), now).is_ok());
+        // A rejected reservation is not counted
+        assert!(throttle.try_reserve("small", dec!(100), now).is_ok());
+
+        let usage = throttle.usage(now);
+        assert_eq!(usage[0].scope, "global");
+        assert_eq!(usage[0].used_minute, dec!(250));
+        assert_eq!(usage.len(), 3);
+    }
+
+    #[test]
+    fn test_release_returns_unfilled_notional() {
+        let mut throttle = NotionalThrottle::new(NotionalThrottleConfig {
+            global: NotionalLimits {
+                per_minute: Some(dec!(100)),
+                per_hour: None,
+            },
+            ..Default::default()
+        });
+        let now = Utc::now();
+        let failed = Uuid::new_v4();
+        let partial = Uuid::new_v4();
+
+        assert!(throttle.try_reserve_for(failed, "a", dec!(60), now).is_ok());
+        assert!(throttle
+            .try_reserve_for(partial, "a", dec!(40), now)
+            .is_ok());
+        assert!(throttle.try_reserve("a", dec!(10), now).is_err());
+
+        assert_eq!(throttle.release(failed, Decimal::ZERO), dec!(60));
+        assert_eq!(throttle.release(partial, dec!(15)), dec!(25));
+        // Each reservation is released at most once
+        assert_eq!(throttle.release(failed, Decimal::ZERO), Decimal::ZERO);
+
+        let usage = throttle.usage(now);
+        assert_eq!(usage[0].used_minute, dec!(15));
+        assert_eq!(usage[1].used_minute, dec!(15));
+        assert!(throttle.try_reserve("a", dec!(85), now).is_ok());
+    }
+}
@@ -18,6 +18,8 @@ pub enum AgentStatus {
     Paused,
     /// 僅監控 (不下單)
     Observing,
+    /// 待命 (保持預熱並計算意圖, 但只記錄不下單)
+    Standby,
     /// 已停止
     Stopped,
     /// 錯誤狀態
@@ -32,7 +34,10 @@ impl AgentStatus {
     pub fn is_active(&self) -> bool {
         matches!(
             self,
-            AgentStatus::Running | AgentStatus::Observing | AgentStatus::Paused
+            AgentStatus::Running
+                | AgentStatus::Observing
+                | AgentStatus::Paused
+                | AgentStatus::Standby
         )
     }
 }
@@ -44,6 +49,7 @@ impl std::fmt::Display for AgentStatus {
             AgentStatus::Running => write!(f, "Running"),
             AgentStatus::Paused => write!(f, "Paused"),
             AgentStatus::Observing => write!(f, "Observing"),
+            AgentStatus::Standby => write!(f, "Standby"),
             AgentStatus::Stopped => write!(f, "Stopped"),
             AgentStatus::Error => write!(f, "Error"),
         }
@@ -8,7 +8,7 @@ use uuid::Uuid;
 
 use std::str::FromStr;
 
-use crate::domain::Side;
+use crate::domain::{Side, TimeInForce};
 
 /// 領域類型
 #[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Serialize, Deserialize)]
@@ -279,6 +279,11 @@ pub struct OrderIntent {
 impl OrderIntent {
     const METADATA_KEY_DEPLOYMENT_ID: &'static str = "deployment_id";
     const METADATA_KEY_CONDITION_ID: &'static str = "condition_id";
+    const METADATA_KEY_TIME_IN_FORCE: &'static str = "time_in_force";
+    const METADATA_KEY_POST_ONLY: &'static str = "post_only";
+    const METADATA_KEY_TAKE_PROFIT: &'static str = "bracket_take_profit";
+    const METADATA_KEY_STOP_LOSS: &'static str = "bracket_stop_loss";
+    const METADATA_KEY_STOP_LIMIT: &'static str = "bracket_stop_limit";
 
     pub fn new(
         agent_id: impl Into<String>,
@@ -355,6 +360,62 @@ impl OrderIntent {
             .find_map(|key| self.metadata_value(key))
     }
 
+    /// 有效期類型; GTD 使用 `expires_at` 作為交易所端過期時間
+    pub fn with_time_in_force(mut self, time_in_force: TimeInForce) -> Self {
+        self.metadata.insert(
+            Self::METADATA_KEY_TIME_IN_FORCE.to_string(),
+            time_in_force.as_str().to_string(),
+        );
+        self
+    }
+
+    /// 未指定時為 GTC
+    pub fn time_in_force(&self) -> crate::error::Result<TimeInForce> {
+        self.metadata_value(Self::METADATA_KEY_TIME_IN_FORCE)
+            .map_or(Ok(TimeInForce::GTC), str::parse)
+    }
+
+    /// 只掛單, 會吃單時拒絕
+    pub fn with_post_only(mut self) -> Self {
+        self.metadata
+            .insert(Self::METADATA_KEY_POST_ONLY.to_string(), "true".to_string());
+        self
+    }
+
+    pub fn is_post_only(&self) -> bool {
+        self.metadata_value(Self::METADATA_KEY_POST_ONLY)
+            .is_some_and(|v| v.eq_ignore_ascii_case("true"))
+    }
+
+    /// 成交後自動掛上止盈/止損 (bracket); `stop_limit` 為止損觸發後的賣出限價
+    pub fn with_bracket(
+        mut self,
+        take_profit: Decimal,
+        stop_loss: Decimal,
+        stop_limit: Decimal,
+    ) -> Self {
+        for (key, value) in [
+            (Self::METADATA_KEY_TAKE_PROFIT, take_profit),
+            (Self::METADATA_KEY_STOP_LOSS, stop_loss),
+            (Self::METADATA_KEY_STOP_LIMIT, stop_limit),
+        ] {
+            self.metadata.insert(key.to_string(), value.to_string());
+        }
+        self
+    }
+
+    /// (take_profit, stop_loss, stop_limit); 未指定止損限價時等於止損價
+    pub fn bracket_exits(&self) -> Option<(Decimal, Decimal, Decimal)> {
+        let parse = |key| {
+            self.metadata_value(key)
+                .and_then(|v| v.parse::<Decimal>().ok())
+        };
+        let take_profit = parse(Self::METADATA_KEY_TAKE_PROFIT)?;
+        let stop_loss = parse(Self::METADATA_KEY_STOP_LOSS)?;
+        let stop_limit = parse(Self::METADATA_KEY_STOP_LIMIT).unwrap_or(stop_loss);
+        Some((take_profit, stop_loss, stop_limit))
+    }
+
     fn metadata_value(&self, key: &str) -> Option<&str> {
         self.metadata
             .get(key)
@@ -521,4 +582,18 @@ mod tests {
         let alias = sample_intent().with_metadata("marketConditionId", " 0xdef ");
         assert_eq!(alias.condition_id(), Some("0xdef"));
     }
+
+    #[test]
+    fn order_intent_bracket_exits_default_stop_limit() {
+        let tp = Decimal::new(60, 2);
+        let sl = Decimal::new(35, 2);
+        let intent = sample_intent().with_bracket(tp, sl, Decimal::new(33, 2));
+        assert_eq!(intent.bracket_exits(), Some((tp, sl, Decimal::new(33, 2))));
+
+        let partial = sample_intent()
+            .with_metadata("bracket_take_profit", "0.60")
+            .with_metadata("bracket_stop_loss", "0.35");
+        assert_eq!(partial.bracket_exits(), Some((tp, sl, sl)));
+        assert_eq!(sample_intent().bracket_exits(), None);
+    }
 }
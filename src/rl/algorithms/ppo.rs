@@ -111,6 +111,7 @@ pub struct PPOOutput {
 ///
 /// Manages actor-critic networks and PPO training loop.
 /// This implementation uses pure Rust for maximum compatibility.
+#[derive(Debug, Clone)]
 pub struct PPOTrainer {
     /// Configuration
     config: PPOConfig,
@@ -152,7 +153,16 @@ impl PPOTrainer {
     /// Returns (action, log_prob) pair.
     /// Uses random exploration with decaying epsilon.
     pub fn get_action(&self, state: &[f32]) -> (Vec<f32>, f32) {
-        let mut rng = rand::thread_rng();
+        self.get_action_with_rng(state, &mut rand::thread_rng())
+    }
+
+    /// [`get_action`](Self::get_action) drawing exploration from `rng`
+    /// (seeded rollout workers)
+    pub fn get_action_with_rng<R: Rng + ?Sized>(
+        &self,
+        state: &[f32],
+        rng: &mut R,
+    ) -> (Vec<f32>, f32) {
         let action_dim = 4; // Hold, BuyUp, BuyDown, Sell
 
         // Epsilon-greedy exploration
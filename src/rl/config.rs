@@ -91,6 +91,15 @@ pub struct TrainingConfig {
     pub exploration_decay: f32,
     /// Minimum exploration rate
     pub exploration_min: f32,
+    /// Replay buffer sampling and n-step configuration
+    #[serde(default)]
+    pub replay: ReplayConfig,
+    /// Live online-learning guardrails
+    #[serde(default)]
+    pub guardrails: GuardrailConfig,
+    /// Curriculum schedule for segment-based environments
+    #[serde(default)]
+    pub curriculum: CurriculumConfig,
 }
 
 impl Default for TrainingConfig {
@@ -105,6 +114,113 @@ impl Default for TrainingConfig {
             exploration_rate: 1.0,
             exploration_decay: 0.995,
             exploration_min: 0.05,
+            replay: ReplayConfig::default(),
+            guardrails: GuardrailConfig::default(),
+            curriculum: CurriculumConfig::default(),
+        }
+    }
+}
+
+/// Replay buffer configuration
+///
+/// Defaults reproduce the plain uniform, 1-step buffer.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(default)]
+pub struct ReplayConfig {
+    /// Sample proportionally to TD-error priority instead of uniformly
+    pub prioritized: bool,
+    /// Priority exponent (0 = uniform, 1 = fully proportional)
+    pub alpha: f32,
+    /// Initial importance-sampling exponent, annealed towards 1
+    pub beta: f32,
+    /// Beta increase per sampled batch
+    pub beta_increment: f32,
+    /// Added to |TD error| so no transition has zero priority
+    pub priority_epsilon: f32,
+    /// Steps folded into each stored transition's return
+    pub n_step: usize,
+    /// Discount used for n-step returns
+    pub gamma: f32,
+}
+
+impl Default for ReplayConfig {
+    fn default() -> Self {
+        Self {
+            prioritized: false,
+            alpha: 0.6,
+            beta: 0.4,
+            beta_increment: 0.001,
+            priority_epsilon: 1e-6,
+            n_step: 1,
+            gamma: 0.99,
+        }
+    }
+}
+
+/// Online learning guardrail configuration
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(default)]
+pub struct GuardrailConfig {
+    /// Shadow-evaluate and cap updates; when false every update is applied
+    pub enabled: bool,
+    /// Recent experiences kept as the rolling holdout
+    pub holdout_size: usize,
+    /// Holdout experiences required before any update is evaluated
+    pub min_holdout: usize,
+    /// Allowed score drop relative to the live weights (fraction of |score|, min 1)
+    pub max_degradation: f32,
+    /// Maximum L2 norm of cumulative weight change per interval
+    pub max_update_norm: f32,
+    /// Length of the update budget interval in seconds
+    pub update_interval_secs: u64,
+}
+
+impl Default for GuardrailConfig {
+    fn default() -> Self {
+        Self {
+            enabled: true,
+            holdout_size: 512,
+            min_holdout: 64,
+            max_degradation: 0.05,
+            max_update_norm: 1.0,
+            update_interval_secs: 300,
+        }
+    }
+}
+
+/// Curriculum schedule for segment-based training (LeadLag)
+///
+/// Episodes start on calm segments; volatile and round-rotation segments are
+/// introduced as training progresses.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(default)]
+pub struct CurriculumConfig {
+    /// Sample curriculum segments instead of replaying the full dataset
+    pub enabled: bool,
+    /// Data points per segment (one episode)
+    pub segment_len: usize,
+    /// Segments above this volatility quantile count as high-volatility
+    pub high_vol_quantile: f64,
+    /// Market rotation period in milliseconds (15m rounds by default)
+    pub rotation_period_ms: i64,
+    /// Episodes spent in a stage before the next one is unlocked
+    pub episodes_per_stage: usize,
+    /// Unlock the next stage early once the recent mean reward reaches this
+    pub promote_reward: Option<f32>,
+    /// Episodes in the rolling reward window used for early promotion
+    pub promote_window: usize,
+}
+
+impl Default for CurriculumConfig {
+    fn default() -> Self {
+        Self {
+            enabled: false,
+            segment_len: 600,
+            high_vol_quantile: 0.7,
+            rotation_period_ms: 15 * 60 * 1000,
+            episodes_per_stage: 200,
+            promote_reward: None,
+            promote_window: 50,
         }
     }
 }
@@ -241,6 +241,8 @@ pub struct LeadLagEnvironment {
     obi_history: VecDeque<f32>,
     data: Vec<LobDataPoint>,
     data_index: usize,
+    /// Exclusive end of the current episode's data window
+    data_end: usize,
     episode_reward: f32,
 }
 
@@ -269,18 +271,25 @@ impl LeadLagEnvironment {
             step_count: 0,
             current_obs: LobObservation::default(),
             obi_history: VecDeque::with_capacity(10),
+            data_end: data.len(),
             data,
             data_index: 0,
             episode_reward: 0.0,
         }
     }
 
-    /// Reset the environment for a new episode
+    /// Reset the environment for a new episode over the full dataset
     pub fn reset(&mut self) -> Vec<f32> {
+        self.reset_range(0, self.data.len())
+    }
+
+    /// Reset for an episode over `data[start..end]` (e.g. a curriculum segment)
+    pub fn reset_range(&mut self, start: usize, end: usize) -> Vec<f32> {
         self.position = Position::default();
         self.step_count = 0;
         self.obi_history.clear();
-        self.data_index = 0;
+        self.data_end = end.min(self.data.len());
+        self.data_index = start.min(self.data_end.saturating_sub(1));
         self.episode_reward = 0.0;
 
         // Initialize OBI history
@@ -427,7 +436,7 @@ impl LeadLagEnvironment {
         self.episode_reward += reward;
 
         // Check if done
-        let done = self.data_index >= self.data.len() - 1;
+        let done = self.data_index + 1 >= self.data_end;
         let truncated = self.step_count >= self.config.max_steps;
 
         // Build info
@@ -528,6 +537,11 @@ impl LeadLagEnvironment {
     pub fn observation_dim(&self) -> usize {
         LobObservation::FEATURE_DIM
     }
+
+    /// Training data backing the environment
+    pub fn data(&self) -> &[LobDataPoint] {
+        &self.data
+    }
 }
 
 /// Convert Decimal to f32
@@ -2,7 +2,10 @@
 //!
 //! Generates realistic market data for training RL agents.
 
-use rand::Rng;
+use rand::rngs::StdRng;
+use rand::{Rng, SeedableRng};
+
+use super::scenario::{MarketScenario, RegimePhase};
 
 /// Market simulation configuration
 #[derive(Debug, Clone)]
@@ -21,6 +24,9 @@ pub struct MarketConfig {
     pub quote_update_freq: usize,
     /// Trend strength (-1 to 1)
     pub trend: f64,
+    /// Scripted regimes; while set, the active phase replaces volatility,
+    /// mean reversion, trend and spread above
+    pub scenario: Option<MarketScenario>,
 }
 
 impl Default for MarketConfig {
@@ -33,6 +39,7 @@ impl Default for MarketConfig {
             spread_pct: 0.02,
             quote_update_freq: 5,
             trend: 0.0,
+            scenario: None,
         }
     }
 }
@@ -80,16 +87,40 @@ impl MarketState {
     }
 }
 
+/// Per-step dynamics, from the config or the active scenario phase
+#[derive(Debug, Clone, Copy)]
+struct StepDynamics {
+    drift: f64,
+    volatility: f64,
+    mean_reversion: f64,
+    mean_price: f64,
+    jump_probability: f64,
+    jump_mean: f64,
+    jump_std: f64,
+    spread_pct: f64,
+}
+
 /// Simulated market for generating price and quote data
 pub struct SimulatedMarket {
     config: MarketConfig,
     state: MarketState,
-    rng: rand::rngs::ThreadRng,
+    rng: StdRng,
+    /// Spread currently applied to quotes
+    spread_pct: f64,
 }
 
 impl SimulatedMarket {
     /// Create a new simulated market
     pub fn new(config: MarketConfig) -> Self {
+        Self::with_rng(config, StdRng::from_entropy())
+    }
+
+    /// Create a reproducible simulated market
+    pub fn with_seed(config: MarketConfig, seed: u64) -> Self {
+        Self::with_rng(config, StdRng::seed_from_u64(seed))
+    }
+
+    fn with_rng(config: MarketConfig, rng: StdRng) -> Self {
         let initial_price = config.initial_price;
 
         // Initialize quotes based on initial price
@@ -108,10 +139,12 @@ impl SimulatedMarket {
             step: 0,
         };
 
+        let spread_pct = config.spread_pct;
         let mut market = Self {
             config,
             state,
-            rng: rand::thread_rng(),
+            rng,
+            spread_pct,
         };
 
         market.update_sum_of_asks();
@@ -142,22 +175,63 @@ impl SimulatedMarket {
             price_history: vec![initial_price],
             step: 0,
         };
+        self.spread_pct = self.config.spread_pct;
 
         self.update_sum_of_asks();
         &self.state
     }
 
+    fn dynamics(&self, step: usize) -> StepDynamics {
+        let config = &self.config;
+        let base = StepDynamics {
+            drift: config.trend * config.volatility,
+            volatility: config.volatility,
+            mean_reversion: config.mean_reversion,
+            mean_price: config.mean_price,
+            jump_probability: 0.0,
+            jump_mean: 0.0,
+            jump_std: 0.0,
+            spread_pct: config.spread_pct,
+        };
+        let Some(scenario) = config.scenario.as_ref() else {
+            return base;
+        };
+        let multiplier = scenario.spread_multiplier(step);
+        match scenario.phase_at(step) {
+            Some(phase) => StepDynamics {
+                drift: phase.drift,
+                volatility: phase.volatility,
+                mean_reversion: phase.mean_reversion,
+                mean_price: phase.mean_price.unwrap_or(config.mean_price),
+                jump_probability: phase.jump_probability,
+                jump_mean: phase.jump_mean,
+                jump_std: phase.jump_std,
+                spread_pct: phase.spread_pct * multiplier,
+            },
+            None => StepDynamics {
+                spread_pct: base.spread_pct * multiplier,
+                ..base
+            },
+        }
+    }
+
     /// Step the market forward one time step
     pub fn step(&mut self) -> &MarketState {
+        let dynamics = self.dynamics(self.state.step);
         self.state.step += 1;
 
         // Generate price return with mean reversion and trend
-        let random_return = self.sample_normal() * self.config.volatility;
-        let reversion =
-            self.config.mean_reversion * (self.config.mean_price - self.state.spot_price);
-        let trend_component = self.config.trend * self.config.volatility;
+        let random_return = self.sample_normal() * dynamics.volatility;
+        let reversion = dynamics.mean_reversion * (dynamics.mean_price - self.state.spot_price);
+        let jump = if dynamics.jump_probability > 0.0
+            && self.rng.gen::<f64>() < dynamics.jump_probability
+        {
+            dynamics.jump_mean + dynamics.jump_std * self.sample_normal()
+        } else {
+            0.0
+        };
 
-        let total_return = random_return + reversion + trend_component;
+        let total_return = random_return + reversion + dynamics.drift + jump;
 
         // Update spot price (clamped to valid range)
         self.state.spot_price = (self.state.spot_price + total_return).clamp(0.01, 0.99);
@@ -168,17 +242,25 @@ impl SimulatedMarket {
             self.state.price_history.remove(0);
         }
 
-        // Update quotes periodically
-        if self.state.step % self.config.quote_update_freq == 0 {
+        // Update quotes periodically, and right away when the spread regime changes
+        let spread_changed = (dynamics.spread_pct - self.spread_pct).abs() > f64::EPSILON;
+        self.spread_pct = dynamics.spread_pct;
+        if spread_changed || self.state.step % self.config.quote_update_freq == 0 {
             self.update_quotes();
         }
 
         &self.state
     }
 
+    /// Step `steps` times and collect every state, e.g. as a fixture for
+    /// strategy tests
+    pub fn path(&mut self, steps: usize) -> Vec<MarketState> {
+        (0..steps).map(|_| self.step().clone()).collect()
+    }
+
     /// Update quotes based on current spot price
     fn update_quotes(&mut self) {
-        let half_spread = self.config.spread_pct / 2.0;
+        let half_spread = self.spread_pct / 2.0;
         let noise: f64 = self.rng.gen_range(-0.005..0.005);
 
         let up_mid = self.state.spot_price + noise;
@@ -210,6 +292,14 @@ impl SimulatedMarket {
         &self.config
     }
 
+    /// Scenario phase that produced the latest step
+    pub fn current_phase(&self) -> Option<&RegimePhase> {
+        self.config
+            .scenario
+            .as_ref()
+            .and_then(|s| s.phase_at(self.state.step.saturating_sub(1)))
+    }
+
     /// Set trend direction (-1 to 1)
     pub fn set_trend(&mut self, trend: f64) {
         self.config.trend = trend.clamp(-1.0, 1.0);
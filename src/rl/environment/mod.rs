@@ -6,6 +6,8 @@
 mod backtest;
 mod leadlag;
 mod market;
+mod scenario;
+mod snapshot;
 mod trading;
 
 pub use backtest::{
@@ -17,4 +19,9 @@ pub use leadlag::{
     LobObservation,
 };
 pub use market::{MarketConfig, MarketState, SimulatedMarket};
+pub use scenario::{MarketScenario, RegimePhase, SpreadEvent};
+pub use snapshot::{
+    RoundSnapshot, SnapshotDataset, SnapshotEnvConfig, SnapshotEnvironment, SnapshotQuery,
+    SnapshotTick,
+};
 pub use trading::{EnvAction, StepResult, TradingEnvConfig, TradingEnvironment};
@@ -0,0 +1,349 @@
+//! Scripted Market Regimes
+//!
+//! A [`MarketScenario`] is an ordered list of [`RegimePhase`]s (trend, crash,
+//! chop, jump diffusion, ...) plus spread-widening events on the step clock.
+//! `SimulatedMarket` reads the phase in force every step, so RL training and
+//! strategy tests can replay a specific stress path; with a fixed seed the
+//! path is identical run to run. Scenarios are plain serde structs and can be
+//! loaded from JSON scripts.
+
+use std::path::Path;
+
+use serde::{Deserialize, Serialize};
+
+use crate::error::{PloyError, Result};
+
+/// Dynamics of one scripted phase
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[serde(default)]
+pub struct RegimePhase {
+    /// Label shown in logs and tests
+    pub name: String,
+    /// Length of the phase in steps
+    pub steps: usize,
+    /// Deterministic price change per step
+    pub drift: f64,
+    /// Std dev of the diffusive return per step
+    pub volatility: f64,
+    /// Mean reversion strength (0 = random walk)
+    pub mean_reversion: f64,
+    /// Reversion target; `None` keeps the market's configured mean
+    pub mean_price: Option<f64>,
+    /// Probability of a jump on any step
+    pub jump_probability: f64,
+    /// Mean jump size
+    pub jump_mean: f64,
+    /// Std dev of jump size
+    pub jump_std: f64,
+    /// Bid-ask spread as percentage
+    pub spread_pct: f64,
+}
+
+impl Default for RegimePhase {
+    fn default() -> Self {
+        Self {
+            name: "calm".to_string(),
+            steps: 100,
+            drift: 0.0,
+            volatility: 0.02,
+            mean_reversion: 0.1,
+            mean_price: None,
+            jump_probability: 0.0,
+            jump_mean: 0.0,
+            jump_std: 0.0,
+            spread_pct: 0.02,
+        }
+    }
+}
+
+impl RegimePhase {
+    /// Steady drift with low noise and no reversion
+    pub fn trend(steps: usize, drift: f64) -> Self {
+        Self {
+            name: "trend".to_string(),
+            steps,
+            drift,
+            volatility: 0.01,
+            mean_reversion: 0.0,
+            ..Default::default()
+        }
+    }
+
+    /// Move by `total_move` over `steps` with high noise and wide spreads
+    pub fn crash(steps: usize, total_move: f64) -> Self {
+        Self {
+            name: "crash".to_string(),
+            steps,
+            drift: total_move / steps.max(1) as f64,
+            volatility: 0.03,
+            mean_reversion: 0.0,
+            spread_pct: 0.06,
+            ..Default::default()
+        }
+    }
+
+    /// Range-bound noise around the current mean
+    pub fn chop(steps: usize) -> Self {
+        Self {
+            name: "chop".to_string(),
+            steps,
+            volatility: 0.02,
+            mean_reversion: 0.3,
+            ..Default::default()
+        }
+    }
+
+    /// Mild diffusion with zero-mean jumps
+    pub fn jump_diffusion(steps: usize, jump_probability: f64, jump_std: f64) -> Self {
+        Self {
+            name: "jump_diffusion".to_string(),
+            steps,
+            volatility: 0.01,
+            mean_reversion: 0.05,
+            jump_probability,
+            jump_std,
+            ..Default::default()
+        }
+    }
+
+    pub fn named(mut self, name: impl Into<String>) -> Self {
+        self.name = name.into();
+        self
+    }
+
+    pub fn with_volatility(mut self, volatility: f64) -> Self {
+        self.volatility = volatility;
+        self
+    }
+
+    pub fn with_mean_price(mut self, mean_price: f64) -> Self {
+        self.mean_price = Some(mean_price);
+        self
+    }
+
+    pub fn with_spread(mut self, spread_pct: f64) -> Self {
+        self.spread_pct = spread_pct;
+        self
+    }
+
+    pub fn with_jumps(mut self, probability: f64, mean: f64, std: f64) -> Self {
+        self.jump_probability = probability;
+        self.jump_mean = mean;
+        self.jump_std = std;
+        self
+    }
+}
+
+/// Temporary spread widening, independent of phases
+#[derive(Debug, Clone, Copy, PartialEq, Serialize, Deserialize)]
+pub struct SpreadEvent {
+    /// First affected step (0-based, counted from reset)
+    pub start_step: usize,
+    /// Number of affected steps
+    pub duration: usize,
+    /// Spread multiplier while active
+    pub multiplier: f64,
+}
+
+/// Ordered regime script for the simulated market
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct MarketScenario {
+    pub name: String,
+    pub phases: Vec<RegimePhase>,
+    #[serde(default)]
+    pub spread_events: Vec<SpreadEvent>,
+    /// Start over after the last phase; otherwise the last phase persists
+    #[serde(default)]
+    pub repeat: bool,
+}
+
+impl MarketScenario {
+    /// Built-in scenario names accepted by [`MarketScenario::preset`]
+    pub const PRESETS: &'static [&'static str] =
+        &["trend_crash_chop", "jump_diffusion", "spread_blowout"];
+
+    pub fn new(name: impl Into<String>) -> Self {
+        Self {
+            name: name.into(),
+            phases: Vec::new(),
+            spread_events: Vec::new(),
+            repeat: false,
+        }
+    }
+
+    /// Append a phase
+    pub fn then(mut self, phase: RegimePhase) -> Self {
+        self.phases.push(phase);
+        self
+    }
+
+    pub fn with_spread_event(
+        mut self,
+        start_step: usize,
+        duration: usize,
+        multiplier: f64,
+    ) -> Self {
+        self.spread_events.push(SpreadEvent {
+            start_step,
+            duration,
+            multiplier,
+        });
+        self
+    }
+
+    pub fn repeating(mut self) -> Self {
+        self.repeat = true;
+        self
+    }
+
+    /// Total scripted steps across all phases
+    pub fn total_steps(&self) -> usize {
+        self.phases.iter().map(|p| p.steps).sum()
+    }
+
+    /// Phase in force at 0-based `step`
+    pub fn phase_at(&self, step: usize) -> Option<&RegimePhase> {
+        let total = self.total_steps();
+        if total == 0 {
+            return self.phases.last();
+        }
+        let mut offset = if self.repeat { step % total } else { step };
+        for phase in &self.phases {
+            if offset < phase.steps {
+                return Some(phase);
+            }
+            offset -= phase.steps;
+        }
+        self.phases.last()
+    }
+
+    /// Combined multiplier of the spread events active at 0-based `step`
+    pub fn spread_multiplier(&self, step: usize) -> f64 {
+        let total = self.total_steps();
+        let step = if self.repeat && total > 0 {
+            step % total
+        } else {
+            step
+        };
+        self.spread_events
+            .iter()
+            .filter(|e| step >= e.start_step && step < e.start_step + e.duration)
+            .map(|e| e.multiplier.max(0.0))
+            .product()
+    }
+
+    /// Built-in stress scenario by name (see [`MarketScenario::PRESETS`])
+    pub fn preset(name: &str) -> Option<Self> {
+        match name {
+            "trend_crash_chop" => Some(
+                Self::new(name)
+                    .then(RegimePhase::trend(200, 0.001))
+                    .then(RegimePhase::crash(20, -0.4))
+                    .then(RegimePhase::chop(200).with_mean_price(0.30))
+                    .with_spread_event(200, 40, 3.0),
+            ),
+            "jump_diffusion" => Some(
+                Self::new(name)
+                    .then(RegimePhase::jump_diffusion(1000, 0.02, 0.05))
+                    .repeating(),
+            ),
+            "spread_blowout" => Some(
+                Self::new(name)
+                    .then(RegimePhase::chop(300))
+                    .with_spread_event(100, 30, 4.0)
+                    .with_spread_event(220, 10, 8.0)
+                    .repeating(),
+            ),
+            _ => None,
+        }
+    }
+
+    /// Preset name, or path to a JSON scenario script
+    pub fn load(name_or_path: &str) -> Result<Self> {
+        if let Some(scenario) = Self::preset(name_or_path) {
+            return Ok(scenario);
+        }
+        let path = Path::new(name_or_path);
+        if !path.exists() {
+            return Err(PloyError::Validation(format!(
+                "unknown scenario '{}' (presets: {})",
+                name_or_path,
+                Self::PRESETS.join(", ")
+            )));
+        }
+        let scenario: Self = serde_json::from_str(&std::fs::read_to_string(path)?)?;
+        if scenario.phases.is_empty() {
+            return Err(PloyError::Validation(format!(
+                "scenario '{}' has no phases",
+                scenario.name
+            )));
+        }
+        Ok(scenario)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::rl::environment::{MarketConfig, SimulatedMarket};
+
+    #[test]
+    fn test_phase_lookup_and_spread_events() {
+        let scenario = MarketScenario::new("test")
+            .then(RegimePhase::trend(10, 0.01))
+            .then(RegimePhase::chop(5))
+            .with_spread_event(8, 4, 3.0);
+
+        assert_eq!(scenario.total_steps(), 15);
+        assert_eq!(scenario.phase_at(0).unwrap().name, "trend");
+        assert_eq!(scenario.phase_at(10).unwrap().name, "chop");
+        // Without repeat the last phase persists
+        assert_eq!(scenario.phase_at(100).unwrap().name, "chop");
+        assert_eq!(scenario.spread_multiplier(7), 1.0);
+        assert_eq!(scenario.spread_multiplier(11), 3.0);
+        assert_eq!(scenario.spread_multiplier(12), 1.0);
+
+        let repeating = scenario.clone().repeating();
+        assert_eq!(repeating.phase_at(15).unwrap().name, "trend");
+        assert_eq!(repeating.spread_multiplier(23), 3.0);
+
+        let json = serde_json::to_string(&scenario).unwrap();
+        assert_eq!(
+            serde_json::from_str::<MarketScenario>(&json).unwrap(),
+            scenario
+        );
+    }
+
+    #[test]
+    fn test_trend_crash_chop_is_reproducible() {
+        // Quiet the noise so the scripted shape dominates
+        let mut scenario = MarketScenario::preset("trend_crash_chop").unwrap();
+        for phase in &mut scenario.phases {
+            phase.volatility = 0.001;
+        }
+        let config = MarketConfig {
+            scenario: Some(scenario),
+            ..Default::default()
+        };
+        let run = |seed| {
+            let mut market = SimulatedMarket::with_seed(config.clone(), seed);
+            market
+                .path(420)
+                .iter()
+                .map(|s| (s.spot_price, s.up_ask - s.up_bid))
+                .collect::<Vec<_>>()
+        };
+
+        let path = run(11);
+        assert_eq!(path, run(11));
+
+        // Trend lifts the price, the crash takes it well below where it started
+        let pre_crash = path[199].0;
+        let post_crash = path[219].0;
+        assert!(pre_crash > 0.65, "pre-crash {pre_crash}");
+        assert!(post_crash < pre_crash - 0.25, "post-crash {post_crash}");
+        // Spreads blow out during the crash window and normalize afterwards
+        assert!(path[215].1 > 2.0 * path[150].1);
+        assert!(path[400].1 < path[215].1);
+    }
+}
@@ -0,0 +1,688 @@
+//! Snapshot Environment for RL Training on Recorded Market Data
+//!
+//! Streams multi-day recorded Binance LOB and Polymarket quote snapshots.
+//! Each episode is exactly one round: it starts at the round open, steps on a
+//! fixed cadence with forward-filled quotes, and ends at the round close where
+//! open positions settle at the official outcome. Fees, gas and depth-based
+//! slippage come from [`TradingCostCalculator`].
+
+use chrono::{DateTime, Utc};
+use rust_decimal::prelude::ToPrimitive;
+use rust_decimal::Decimal;
+use sqlx::PgPool;
+
+use super::backtest::{BacktestInfo, BacktestStepResult};
+use super::trading::EnvAction;
+use crate::error::Result;
+use crate::strategy::trading_costs::{OrderType, TradingCostCalculator, TradingCostConfig};
+
+/// Market state at one step of a recorded round
+#[derive(Debug, Clone, Default)]
+pub struct SnapshotTick {
+    pub timestamp_ms: i64,
+    pub up_bid: f64,
+    pub up_ask: f64,
+    pub up_ask_size: f64,
+    pub down_bid: f64,
+    pub down_ask: f64,
+    pub down_ask_size: f64,
+    /// Binance mid price
+    pub spot_price: f64,
+    pub obi_5: f64,
+    pub obi_10: f64,
+    pub spread_bps: f64,
+}
+
+/// One recorded round
+#[derive(Debug, Clone)]
+pub struct RoundSnapshot {
+    pub round_slug: String,
+    pub start_ms: i64,
+    pub end_ms: i64,
+    pub price_to_beat: f64,
+    /// Winning side if the round settled (true = UP)
+    pub outcome_up: Option<bool>,
+    pub ticks: Vec<SnapshotTick>,
+}
+
+/// Which recorded rounds to load
+#[derive(Debug, Clone)]
+pub struct SnapshotQuery {
+    /// Binance symbol (e.g. BTCUSDT)
+    pub symbol: String,
+    /// Round horizon filter (e.g. "5m", "15m")
+    pub horizon: Option<String>,
+    pub from: DateTime<Utc>,
+    pub to: DateTime<Utc>,
+    /// Step cadence in milliseconds
+    pub step_ms: i64,
+    /// Rounds with fewer usable steps are dropped
+    pub min_steps: usize,
+}
+
+/// Recorded rounds in chronological order
+#[derive(Debug, Clone, Default)]
+pub struct SnapshotDataset {
+    pub rounds: Vec<RoundSnapshot>,
+}
+
+/// (received_at, side, best_bid, best_ask, ask_size)
+type QuoteRow = (
+    DateTime<Utc>,
+    String,
+    Option<Decimal>,
+    Option<Decimal>,
+    Option<Decimal>,
+);
+/// (event_time, mid_price, obi_5, obi_10, spread_bps)
+type LobRow = (DateTime<Utc>, Decimal, Decimal, Decimal, Decimal);
+
+fn to_f64(d: Option<Decimal>) -> Option<f64> {
+    d.and_then(|d| d.to_f64())
+}
+
+impl SnapshotDataset {
+    /// Load rounds from `pm_market_metadata`, PM quotes from `clob_quote_ticks`
+    /// (mapped to rounds via `pm_token_settlements`) and Binance features from
+    /// `binance_lob_ticks`.
+    pub async fn load(pool: &PgPool, query: &SnapshotQuery) -> Result<Self> {
+        let rounds: Vec<(String, Decimal, DateTime<Utc>, DateTime<Utc>)> = sqlx::query_as(
+            r#"
+            SELECT market_slug, price_to_beat, start_time, end_time
+            FROM pm_market_metadata
+            WHERE symbol = $1
+              AND ($2::TEXT IS NULL OR horizon = $2)
+              AND start_time >= $3
+              AND end_time <= $4
+            ORDER BY start_time
+            "#,
+        )
+        .bind(query.symbol.to_uppercase())
+        .bind(query.horizon.as_deref())
+        .bind(query.from)
+        .bind(query.to)
+        .fetch_all(pool)
+        .await?;
+
+        let mut dataset = Self::default();
+        for (slug, price_to_beat, start, end) in rounds {
+            let quotes: Vec<QuoteRow> = sqlx::query_as(
+                r#"
+                SELECT q.received_at, q.side, q.best_bid, q.best_ask, q.ask_size
+                FROM clob_quote_ticks q
+                JOIN pm_token_settlements s ON s.token_id = q.token_id
+                WHERE s.market_slug = $1
+                  AND q.received_at >= $2
+                  AND q.received_at < $3
+                ORDER BY q.received_at
+                "#,
+            )
+            .bind(&slug)
+            .bind(start)
+            .bind(end)
+            .fetch_all(pool)
+            .await?;
+
+            let lob: Vec<LobRow> = sqlx::query_as(
+                r#"
+                SELECT event_time, mid_price, obi_5, obi_10, spread_bps
+                FROM binance_lob_ticks
+                WHERE symbol = $1
+                  AND event_time >= $2
+                  AND event_time < $3
+                ORDER BY event_time
+                "#,
+            )
+            .bind(query.symbol.to_uppercase())
+            .bind(start)
+            .bind(end)
+            .fetch_all(pool)
+            .await?;
+
+            let outcome: Option<String> = sqlx::query_scalar(
+                r#"
+                SELECT outcome
+                FROM pm_token_settlements
+                WHERE market_slug = $1 AND resolved AND settled_price >= 0.99
+                LIMIT 1
+                "#,
+            )
+            .bind(&slug)
+            .fetch_optional(pool)
+            .await?
+            .flatten();
+
+            let ticks = resample(
+                start.timestamp_millis(),
+                end.timestamp_millis(),
+                query.step_ms.max(1),
+                &quotes,
+                &lob,
+            );
+            if ticks.len() < query.min_steps.max(1) {
+                continue;
+            }
+
+            dataset.rounds.push(RoundSnapshot {
+                round_slug: slug,
+                start_ms: start.timestamp_millis(),
+                end_ms: end.timestamp_millis(),
+                price_to_beat: price_to_beat.to_f64().unwrap_or(0.0),
+                outcome_up: outcome.map(|o| o.eq_ignore_ascii_case("up")),
+                ticks,
+            });
+        }
+
+        Ok(dataset)
+    }
+
+    pub fn total_steps(&self) -> usize {
+        self.rounds.iter().map(|r| r.ticks.len()).sum()
+    }
+}
+
+/// Sample forward-filled state every `step_ms`, starting once both sides and
+/// the Binance book have been seen
+fn resample(
+    start_ms: i64,
+    end_ms: i64,
+    step_ms: i64,
+    quotes: &[QuoteRow],
+    lob: &[LobRow],
+) -> Vec<SnapshotTick> {
+    let mut ticks = Vec::new();
+    let mut state = SnapshotTick::default();
+    let (mut has_up, mut has_down, mut has_lob) = (false, false, false);
+    let (mut qi, mut li) = (0, 0);
+
+    let mut t = start_ms;
+    while t < end_ms {
+        while qi < quotes.len() && quotes[qi].0.timestamp_millis() <= t {
+            let (_, side, bid, ask, ask_size) = &quotes[qi];
+            let (bid, ask) = (to_f64(*bid), to_f64(*ask));
+            if side == "UP" {
+                state.up_bid = bid.unwrap_or(state.up_bid);
+                state.up_ask = ask.unwrap_or(state.up_ask);
+                state.up_ask_size = to_f64(*ask_size).unwrap_or(state.up_ask_size);
+                has_up |= state.up_ask > 0.0;
+            } else {
+                state.down_bid = bid.unwrap_or(state.down_bid);
+                state.down_ask = ask.unwrap_or(state.down_ask);
+                state.down_ask_size = to_f64(*ask_size).unwrap_or(state.down_ask_size);
+                has_down |= state.down_ask > 0.0;
+            }
+            qi += 1;
+        }
+        while li < lob.len() && lob[li].0.timestamp_millis() <= t {
+            let (_, mid, obi_5, obi_10, spread_bps) = lob[li];
+            state.spot_price = mid.to_f64().unwrap_or(state.spot_price);
+            state.obi_5 = obi_5.to_f64().unwrap_or(0.0);
+            state.obi_10 = obi_10.to_f64().unwrap_or(0.0);
+            state.spread_bps = spread_bps.to_f64().unwrap_or(0.0);
+            has_lob = true;
+            li += 1;
+        }
+
+        if has_up && has_down && has_lob {
+            state.timestamp_ms = t;
+            ticks.push(state.clone());
+        }
+        t += step_ms;
+    }
+
+    ticks
+}
+
+/// Snapshot environment configuration
+#[derive(Debug, Clone)]
+pub struct SnapshotEnvConfig {
+    /// Capital at the start of each episode (round)
+    pub initial_capital: f64,
+    /// Maximum shares per position
+    pub max_position: u64,
+    /// Fee, gas and slippage model
+    pub costs: TradingCostConfig,
+    /// Weight of unrealized PnL in the step reward
+    pub unrealized_weight: f64,
+}
+
+impl Default for SnapshotEnvConfig {
+    fn default() -> Self {
+        Self {
+            initial_capital: 1000.0,
+            max_position: 100,
+            costs: TradingCostConfig::default(),
+            unrealized_weight: 0.1,
+        }
+    }
+}
+
+#[derive(Debug, Clone, Default)]
+struct SnapshotPosition {
+    is_up: bool,
+    shares: u64,
+    /// Average cost per share including entry costs
+    cost_basis: f64,
+    entry_time: i64,
+}
+
+/// Round-aligned environment over a [`SnapshotDataset`]
+pub struct SnapshotEnvironment {
+    dataset: SnapshotDataset,
+    config: SnapshotEnvConfig,
+    costs: TradingCostCalculator,
+    round_idx: usize,
+    step_idx: usize,
+    /// UP ask history for price features
+    price_history: Vec<f64>,
+    position: SnapshotPosition,
+    capital: f64,
+    episode_pnl: f64,
+    total_costs: f64,
+    num_trades: usize,
+    winning_trades: usize,
+    /// Rounds completed across all episodes
+    episodes: usize,
+}
+
+impl SnapshotEnvironment {
+    pub fn new(dataset: SnapshotDataset, config: SnapshotEnvConfig) -> Self {
+        let costs = TradingCostCalculator::with_config(config.costs.clone());
+        Self {
+            dataset,
+            capital: config.initial_capital,
+            config,
+            costs,
+            round_idx: 0,
+            step_idx: 0,
+            price_history: Vec::new(),
+            position: SnapshotPosition::default(),
+            episode_pnl: 0.0,
+            total_costs: 0.0,
+            num_trades: 0,
+            winning_trades: 0,
+            episodes: 0,
+        }
+    }
+
+    pub fn num_rounds(&self) -> usize {
+        self.dataset.rounds.len()
+    }
+
+    /// Start the next round (wrapping around the dataset)
+    pub fn reset(&mut self) -> Vec<f32> {
+        let idx = if self.episodes == 0 {
+            0
+        } else {
+            (self.round_idx + 1) % self.num_rounds().max(1)
+        };
+        self.reset_round(idx)
+    }
+
+    /// Start a specific round
+    pub fn reset_round(&mut self, idx: usize) -> Vec<f32> {
+        self.round_idx = idx;
+        self.step_idx = 0;
+        self.position = SnapshotPosition::default();
+        self.capital = self.config.initial_capital;
+        self.episode_pnl = 0.0;
+        self.total_costs = 0.0;
+        self.num_trades = 0;
+        self.winning_trades = 0;
+        self.episodes += 1;
+        self.price_history = self.current().map(|t| vec![t.up_ask]).unwrap_or_default();
+        self.get_observation()
+    }
+
+    fn round(&self) -> Option<&RoundSnapshot> {
+        self.dataset.rounds.get(self.round_idx)
+    }
+
+    fn current(&self) -> Option<&SnapshotTick> {
+        let round = self.round()?;
+        round
+            .ticks
+            .get(self.step_idx.min(round.ticks.len().saturating_sub(1)))
+    }
+
+    pub fn round_slug(&self) -> &str {
+        self.round().map(|r| r.round_slug.as_str()).unwrap_or("")
+    }
+
+    pub fn remaining_steps(&self) -> usize {
+        self.round()
+            .map(|r| r.ticks.len().saturating_sub(self.step_idx + 1))
+            .unwrap_or(0)
+    }
+
+    pub fn total_steps(&self) -> usize {
+        self.round().map(|r| r.ticks.len()).unwrap_or(0)
+    }
+
+    pub fn step(&mut self, action: EnvAction) -> BacktestStepResult {
+        let mut realized = self.execute_action(action);
+
+        self.step_idx += 1;
+        if let Some(tick) = self.current() {
+            let up_ask = tick.up_ask;
+            self.price_history.push(up_ask);
+            if self.price_history.len() > 60 {
+                self.price_history.remove(0);
+            }
+        }
+
+        // Round boundary: settle whatever is still open
+        let done = self.remaining_steps() == 0;
+        if done {
+            realized += self.settle();
+        }
+
+        let mut reward = realized;
+        if !done {
+            reward += self.unrealized_pnl() * self.config.unrealized_weight;
+        }
+
+        BacktestStepResult {
+            observation: self.get_observation(),
+            reward: reward as f32,
+            done,
+            info: self.final_stats(),
+        }
+    }
+
+    fn execute_action(&mut self, action: EnvAction) -> f64 {
+        let Some(tick) = self.current().cloned() else {
+            return 0.0;
+        };
+        match action {
+            EnvAction::Hold => 0.0,
+            EnvAction::BuyUp | EnvAction::BuyDown => {
+                if self.position.shares > 0 {
+                    return 0.0;
+                }
+                let is_up = action == EnvAction::BuyUp;
+                let (price, depth) = if is_up {
+                    (tick.up_ask, tick.up_ask_size)
+                } else {
+                    (tick.down_ask, tick.down_ask_size)
+                };
+                if price <= 0.0 || price >= 1.0 {
+                    return 0.0;
+                }
+                let shares = ((self.capital / price) as u64).min(self.config.max_position);
+                if shares == 0 {
+                    return 0.0;
+                }
+
+                let notional = shares as f64 * price;
+                let cost = self.trade_cost(notional, shares, depth);
+                self.capital -= notional + cost;
+                self.total_costs += cost;
+                self.position = SnapshotPosition {
+                    is_up,
+                    shares,
+                    cost_basis: (notional + cost) / shares as f64,
+                    entry_time: tick.timestamp_ms,
+                };
+                -cost
+            }
+            EnvAction::Sell => {
+                if self.position.shares == 0 {
+                    return 0.0;
+                }
+                let bid = if self.position.is_up {
+                    tick.up_bid
+                } else {
+                    tick.down_bid
+                };
+                let notional = self.position.shares as f64 * bid;
+                // Exit depth is not recorded; assume top-of-book size matches the ask side
+                let depth = if self.position.is_up {
+                    tick.up_ask_size
+                } else {
+                    tick.down_ask_size
+                };
+                let cost = self.trade_cost(notional, self.position.shares, depth);
+                self.close_position(notional - cost, cost)
+            }
+        }
+    }
+
+    /// Taker fee + gas + slippage for one fill
+    fn trade_cost(&self, notional: f64, shares: u64, depth: f64) -> f64 {
+        let notional_dec = Decimal::try_from(notional).unwrap_or_default();
+        let depth_ratio = if depth > 0.0 {
+            (shares as f64 / depth).min(1.0)
+        } else {
+            1.0
+        };
+        let depth_ratio = Decimal::try_from(depth_ratio).unwrap_or(Decimal::ONE);
+        let cost = self
+            .costs
+            .calculate_entry_fee(notional_dec, OrderType::Taker)
+            + self.costs.config().gas_cost_usd
+            + self.costs.estimate_slippage(notional_dec, depth_ratio);
+        cost.to_f64().unwrap_or(0.0)
+    }
+
+    fn close_position(&mut self, proceeds: f64, cost: f64) -> f64 {
+        let pnl = proceeds - self.position.shares as f64 * self.position.cost_basis;
+        self.capital += proceeds;
+        self.total_costs += cost;
+        self.episode_pnl += pnl;
+        self.num_trades += 1;
+        if pnl > 0.0 {
+            self.winning_trades += 1;
+        }
+        self.position = SnapshotPosition::default();
+        pnl
+    }
+
+    /// Settle an open position at round end: redeem at the official outcome,
+    /// or exit at the last bid when the round has no recorded resolution
+    fn settle(&mut self) -> f64 {
+        if self.position.shares == 0 {
+            return 0.0;
+        }
+        let outcome = self.round().and_then(|r| r.outcome_up);
+        match outcome {
+            Some(up_won) => {
+                let payout = if up_won == self.position.is_up {
+                    self.position.shares as f64
+                } else {
+                    0.0
+                };
+                let gas = self.costs.config().gas_cost_usd.to_f64().unwrap_or(0.0);
+                self.close_position(payout - gas, gas)
+            }
+            None => self.execute_action(EnvAction::Sell),
+        }
+    }
+
+    fn unrealized_pnl(&self) -> f64 {
+        let Some(tick) = self.current() else {
+            return 0.0;
+        };
+        if self.position.shares == 0 {
+            return 0.0;
+        }
+        let bid = if self.position.is_up {
+            tick.up_bid
+        } else {
+            tick.down_bid
+        };
+        self.position.shares as f64 * (bid - self.position.cost_basis)
+    }
+
+    fn position_value(&self) -> f64 {
+        let Some(tick) = self.current() else {
+            return 0.0;
+        };
+        let bid = if self.position.is_up {
+            tick.up_bid
+        } else {
+            tick.down_bid
+        };
+        self.position.shares as f64 * bid
+    }
+
+    fn momentum(&self, n: usize) -> f64 {
+        let h = &self.price_history;
+        if h.len() < n + 1 {
+            return 0.0;
+        }
+        h[h.len() - 1] - h[h.len() - 1 - n]
+    }
+
+    /// Same 42-feature layout as `BacktestEnvironment`, with the placeholder
+    /// slots filled from recorded liquidity, round timing and Binance LOB data
+    fn get_observation(&self) -> Vec<f32> {
+        let mut obs = Vec::with_capacity(42);
+        let tick = self.current().cloned().unwrap_or_default();
+        let round = self.round();
+
+        // Price features (20)
+        obs.push(tick.up_ask as f32);
+        for i in 0..15 {
+            let h = &self.price_history;
+            obs.push(if i < h.len() {
+                h[h.len() - 1 - i] as f32
+            } else {
+                tick.up_ask as f32
+            });
+        }
+        obs.push(self.momentum(1) as f32);
+        obs.push(self.momentum(5) as f32);
+        obs.push(self.momentum(15) as f32);
+        obs.push(self.momentum(30) as f32);
+
+        // Quote features (8)
+        obs.push(tick.up_bid as f32);
+        obs.push(tick.up_ask as f32);
+        obs.push(tick.down_bid as f32);
+        obs.push(tick.down_ask as f32);
+        obs.push((tick.up_ask - tick.up_bid) as f32);
+        obs.push((tick.down_ask - tick.down_bid) as f32);
+        obs.push((tick.up_ask + tick.down_ask) as f32);
+        obs.push(((tick.up_ask_size + tick.down_ask_size).ln_1p() / 10.0) as f32);
+
+        // Position features (6)
+        let has_position = self.position.shares > 0;
+        obs.push(if has_position { 1.0 } else { 0.0 });
+        obs.push(if self.position.is_up { 1.0 } else { -1.0 });
+        obs.push(self.position.shares as f32 / self.config.max_position.max(1) as f32);
+        obs.push(self.position.cost_basis as f32);
+        obs.push(self.unrealized_pnl() as f32);
+        obs.push(if has_position {
+            (tick.timestamp_ms - self.position.entry_time) as f32 / 60000.0
+        } else {
+            0.0
+        });
+
+        // Risk features (4)
+        let total_value = self.capital + self.position_value();
+        obs.push((self.position_value() / total_value.max(f64::EPSILON)) as f32);
+        obs.push((total_value / self.config.initial_capital - 1.0) as f32);
+        obs.push((self.episode_pnl / self.config.initial_capital) as f32);
+        let time_remaining = round
+            .map(|r| {
+                let len = (r.end_ms - r.start_ms).max(1) as f64;
+                ((r.end_ms - tick.timestamp_ms) as f64 / len).clamp(0.0, 1.0)
+            })
+            .unwrap_or(0.0);
+        obs.push(time_remaining as f32);
+
+        // Binance features (4)
+        let distance_bps = round
+            .filter(|r| r.price_to_beat > 0.0)
+            .map(|r| (tick.spot_price - r.price_to_beat) / r.price_to_beat * 10_000.0)
+            .unwrap_or(0.0);
+        obs.push((distance_bps / 100.0) as f32);
+        obs.push(tick.obi_5 as f32);
+        obs.push(tick.obi_10 as f32);
+        obs.push((tick.spread_bps / 10.0) as f32);
+
+        obs
+    }
+
+    /// Stats for the current episode
+    pub fn final_stats(&self) -> BacktestInfo {
+        BacktestInfo {
+            timestamp_ms: self.current().map(|t| t.timestamp_ms).unwrap_or(0),
+            capital: self.capital,
+            position_value: self.position_value(),
+            total_pnl: self.episode_pnl,
+            num_trades: self.num_trades,
+            win_rate: if self.num_trades > 0 {
+                self.winning_trades as f64 / self.num_trades as f64
+            } else {
+                0.0
+            },
+        }
+    }
+
+    /// Fees, gas and slippage paid this episode
+    pub fn total_costs(&self) -> f64 {
+        self.total_costs
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn round(outcome_up: Option<bool>) -> RoundSnapshot {
+        let ticks = (0..10)
+            .map(|i| SnapshotTick {
+                timestamp_ms: i * 1000,
+                up_bid: 0.49,
+                up_ask: 0.50,
+                up_ask_size: 500.0,
+                down_bid: 0.49,
+                down_ask: 0.51,
+                down_ask_size: 500.0,
+                spot_price: 50_000.0,
+                ..Default::default()
+            })
+            .collect();
+        RoundSnapshot {
+            round_slug: "btc-updown-5m-test".into(),
+            start_ms: 0,
+            end_ms: 10_000,
+            price_to_beat: 49_990.0,
+            outcome_up,
+            ticks,
+        }
+    }
+
+    #[test]
+    fn test_episode_ends_at_round_and_settles_with_costs() {
+        let dataset = SnapshotDataset {
+            rounds: vec![round(Some(true)), round(None)],
+        };
+        let mut env = SnapshotEnvironment::new(dataset, SnapshotEnvConfig::default());
+
+        let obs = env.reset();
+        assert_eq!(obs.len(), 42);
+        assert_eq!(env.round_slug(), "btc-updown-5m-test");
+
+        let first = env.step(EnvAction::BuyUp);
+        assert!(first.reward < 0.0, "entry costs are charged");
+        let mut steps = 1;
+        let mut last = first;
+        while !last.done {
+            last = env.step(EnvAction::Hold);
+            steps += 1;
+        }
+        assert_eq!(steps, 9);
+        // 100 shares at 0.50 redeemed at 1.0, minus fees/gas/slippage
+        assert!(last.info.total_pnl > 40.0 && last.info.total_pnl < 50.0);
+        assert!(env.total_costs() > 0.0);
+
+        // Next episode is the next round; unresolved rounds exit at the bid
+        env.reset();
+        env.step(EnvAction::BuyUp);
+        while !env.step(EnvAction::Hold).done {}
+        assert!(env.final_stats().total_pnl < 0.0);
+    }
+}
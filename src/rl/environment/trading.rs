@@ -178,6 +178,16 @@ impl TradingEnvironment {
     /// Create a new trading environment
     pub fn new(config: TradingEnvConfig) -> Self {
         let market = SimulatedMarket::new(config.market.clone());
+        Self::with_market(config, market)
+    }
+
+    /// Create a trading environment with a reproducible market
+    pub fn with_seed(config: TradingEnvConfig, seed: u64) -> Self {
+        let market = SimulatedMarket::with_seed(config.market.clone(), seed);
+        Self::with_market(config, market)
+    }
+
+    fn with_market(config: TradingEnvConfig, market: SimulatedMarket) -> Self {
         let initial_capital = config.initial_capital;
 
         Self {
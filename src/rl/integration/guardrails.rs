@@ -0,0 +1,330 @@
+//! Online Learning Guardrails
+//!
+//! Protects a live policy from silent degradation while it learns online:
+//!
+//! - updates are shadow-evaluated on a rolling holdout of recent experiences
+//!   and rejected if they score worse than the live weights
+//! - the total weight change per interval is capped (L2 norm budget)
+//! - an accepted update is reverted to the previous weights if it later
+//!   underperforms them on the refreshed holdout
+//!
+//! Every decision is logged and kept in a bounded event log.
+
+use std::collections::VecDeque;
+
+use chrono::{DateTime, Duration, Utc};
+use serde::Serialize;
+use tracing::{info, warn};
+
+use crate::rl::config::GuardrailConfig;
+use crate::rl::memory::Transition;
+
+const MAX_EVENTS: usize = 256;
+
+/// Scores policy weights on holdout experiences (higher is better)
+pub trait PolicyEvaluator: Send + Sync {
+    fn score(&self, weights: &[f32], holdout: &[Transition]) -> f32;
+}
+
+impl<F> PolicyEvaluator for F
+where
+    F: Fn(&[f32], &[Transition]) -> f32 + Send + Sync,
+{
+    fn score(&self, weights: &[f32], holdout: &[Transition]) -> f32 {
+        self(weights, holdout)
+    }
+}
+
+/// Outcome of a proposed policy update
+#[derive(Debug, Clone, PartialEq, Serialize)]
+#[serde(tag = "decision", rename_all = "snake_case")]
+pub enum GuardDecision {
+    /// Update applied; `scale` < 1 when the step was clipped to the budget
+    Accepted {
+        score: f32,
+        baseline: f32,
+        scale: f32,
+    },
+    /// Not enough holdout experiences to evaluate the update
+    InsufficientHoldout { available: usize, required: usize },
+    /// Update budget for the current interval is exhausted
+    RateLimited { used: f32, budget: f32 },
+    /// Shadow evaluation scored the update below the live weights
+    Degraded { score: f32, baseline: f32 },
+}
+
+impl GuardDecision {
+    pub fn is_accepted(&self) -> bool {
+        matches!(self, Self::Accepted { .. })
+    }
+}
+
+/// Logged guardrail event
+#[derive(Debug, Clone, Serialize)]
+pub struct GuardrailEvent {
+    pub at: DateTime<Utc>,
+    pub kind: GuardrailEventKind,
+}
+
+#[derive(Debug, Clone, PartialEq, Serialize)]
+#[serde(tag = "event", rename_all = "snake_case")]
+pub enum GuardrailEventKind {
+    Update(GuardDecision),
+    /// Live weights rolled back to the previous accepted weights
+    Reverted {
+        score: f32,
+        previous_score: f32,
+    },
+}
+
+/// Guards online weight updates for one live policy
+pub struct OnlineLearningGuard {
+    config: GuardrailConfig,
+    holdout: VecDeque<Transition>,
+    weights: Vec<f32>,
+    /// Weights before the most recent accepted update
+    previous: Option<Vec<f32>>,
+    interval_start: DateTime<Utc>,
+    interval_norm: f32,
+    events: VecDeque<GuardrailEvent>,
+}
+
+impl OnlineLearningGuard {
+    pub fn new(config: GuardrailConfig, weights: Vec<f32>) -> Self {
+        Self {
+            holdout: VecDeque::with_capacity(config.holdout_size),
+            config,
+            weights,
+            previous: None,
+            interval_start: Utc::now(),
+            interval_norm: 0.0,
+            events: VecDeque::new(),
+        }
+    }
+
+    /// Current live weights
+    pub fn weights(&self) -> &[f32] {
+        &self.weights
+    }
+
+    pub fn events(&self) -> impl Iterator<Item = &GuardrailEvent> {
+        self.events.iter()
+    }
+
+    pub fn holdout_len(&self) -> usize {
+        self.holdout.len()
+    }
+
+    /// Add a recent experience to the rolling holdout
+    pub fn observe(&mut self, transition: Transition) {
+        if self.config.holdout_size == 0 {
+            return;
+        }
+        if self.holdout.len() >= self.config.holdout_size {
+            self.holdout.pop_front();
+        }
+        self.holdout.push_back(transition);
+    }
+
+    fn holdout_slice(&mut self) -> &[Transition] {
+        self.holdout.make_contiguous()
+    }
+
+    fn record(&mut self, at: DateTime<Utc>, kind: GuardrailEventKind) {
+        if self.events.len() >= MAX_EVENTS {
+            self.events.pop_front();
+        }
+        self.events.push_back(GuardrailEvent { at, kind });
+    }
+
+    /// Allowed score drop below `baseline` before an update counts as degraded
+    fn tolerance(&self, baseline: f32) -> f32 {
+        self.config.max_degradation * baseline.abs().max(1.0)
+    }
+
+    /// Shadow-evaluate `proposed` weights and apply them if they pass
+    pub fn propose(
+        &mut self,
+        proposed: Vec<f32>,
+        evaluator: &dyn PolicyEvaluator,
+        now: DateTime<Utc>,
+    ) -> GuardDecision {
+        if !self.config.enabled || self.weights.len() != proposed.len() {
+            // Disabled, or first weights for an uninitialised policy
+            self.previous = Some(std::mem::replace(&mut self.weights, proposed));
+            let decision = GuardDecision::Accepted {
+                score: 0.0,
+                baseline: 0.0,
+                scale: 1.0,
+            };
+            self.record(now, GuardrailEventKind::Update(decision.clone()));
+            return decision;
+        }
+
+        if now - self.interval_start >= Duration::seconds(self.config.update_interval_secs as i64) {
+            self.interval_start = now;
+            self.interval_norm = 0.0;
+        }
+
+        let decision = self.evaluate(proposed, evaluator);
+        match &decision {
+            GuardDecision::Accepted {
+                score,
+                baseline,
+                scale,
+            } => info!(
+                score,
+                baseline, scale, "online learning update accepted by guardrail"
+            ),
+            other => warn!(decision = ?other, "online learning update rejected by guardrail"),
+        }
+        self.record(now, GuardrailEventKind::Update(decision.clone()));
+        decision
+    }
+
+    fn evaluate(&mut self, proposed: Vec<f32>, evaluator: &dyn PolicyEvaluator) -> GuardDecision {
+        let required = self.config.min_holdout;
+        if self.holdout.len() < required {
+            return GuardDecision::InsufficientHoldout {
+                available: self.holdout.len(),
+                required,
+            };
+        }
+
+        let budget = self.config.max_update_norm;
+        let remaining = budget - self.interval_norm;
+        if remaining <= 0.0 {
+            return GuardDecision::RateLimited {
+                used: self.interval_norm,
+                budget,
+            };
+        }
+
+        // Cap the step so the interval's cumulative change stays within budget
+        let norm = self
+            .weights
+            .iter()
+            .zip(&proposed)
+            .map(|(w, p)| (p - w).powi(2))
+            .sum::<f32>()
+            .sqrt();
+        let applied = norm.min(remaining);
+        let scale = if norm > remaining {
+            remaining / norm
+        } else {
+            1.0
+        };
+        let candidate: Vec<f32> = self
+            .weights
+            .iter()
+            .zip(&proposed)
+            .map(|(w, p)| w + (p - w) * scale)
+            .collect();
+
+        let live = self.weights.clone();
+        let holdout = self.holdout_slice();
+        let baseline = evaluator.score(&live, holdout);
+        let score = evaluator.score(&candidate, holdout);
+        if score < baseline - self.tolerance(baseline) {
+            return GuardDecision::Degraded { score, baseline };
+        }
+
+        self.interval_norm += applied;
+        self.previous = Some(std::mem::replace(&mut self.weights, candidate));
+        GuardDecision::Accepted {
+            score,
+            baseline,
+            scale,
+        }
+    }
+
+    /// Re-check the latest accepted update on the refreshed holdout.
+    ///
+    /// Returns the restored weights when the update was reverted.
+    pub fn check(&mut self, evaluator: &dyn PolicyEvaluator, now: DateTime<Utc>) -> Option<&[f32]> {
+        if !self.config.enabled || self.holdout.len() < self.config.min_holdout {
+            return None;
+        }
+        let previous = self.previous.take()?;
+
+        let live = self.weights.clone();
+        let holdout = self.holdout_slice();
+        let score = evaluator.score(&live, holdout);
+        let previous_score = evaluator.score(&previous, holdout);
+        if score >= previous_score - self.tolerance(previous_score) {
+            self.previous = Some(previous);
+            return None;
+        }
+
+        warn!(
+            score,
+            previous_score, "live policy degraded after online update; reverting weights"
+        );
+        self.weights = previous;
+        self.record(
+            now,
+            GuardrailEventKind::Reverted {
+                score,
+                previous_score,
+            },
+        );
+        Some(&self.weights)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    /// Score is the negative distance of the single weight from the mean reward
+    fn evaluator(weights: &[f32], holdout: &[Transition]) -> f32 {
+        let target = holdout.iter().map(|t| t.reward).sum::<f32>() / holdout.len() as f32;
+        -(weights[0] - target).abs()
+    }
+
+    fn guard() -> OnlineLearningGuard {
+        let config = GuardrailConfig {
+            holdout_size: 8,
+            min_holdout: 4,
+            max_degradation: 0.0,
+            max_update_norm: 1.0,
+            ..Default::default()
+        };
+        let mut guard = OnlineLearningGuard::new(config, vec![0.0]);
+        for _ in 0..8 {
+            guard.observe(Transition::new(vec![], vec![], 2.0, vec![], false));
+        }
+        guard
+    }
+
+    #[test]
+    fn test_guard_caps_rejects_and_reverts() {
+        let mut guard = guard();
+        let now = Utc::now();
+
+        // Moving towards 2.0 improves the score, but the step is capped at 1.0
+        let decision = guard.propose(vec![4.0], &evaluator, now);
+        assert!(decision.is_accepted());
+        assert_eq!(guard.weights(), &[1.0]);
+        // Budget exhausted for this interval
+        assert!(matches!(
+            guard.propose(vec![2.0], &evaluator, now),
+            GuardDecision::RateLimited { .. }
+        ));
+
+        // Next interval: a step away from the target is rejected
+        let later = now + Duration::seconds(3600);
+        assert!(matches!(
+            guard.propose(vec![0.5], &evaluator, later),
+            GuardDecision::Degraded { .. }
+        ));
+        assert_eq!(guard.weights(), &[1.0]);
+
+        // Holdout drifts so the accepted update now underperforms: revert
+        for _ in 0..8 {
+            guard.observe(Transition::new(vec![], vec![], 0.0, vec![], false));
+        }
+        assert_eq!(guard.check(&evaluator, later), Some(&[0.0][..]));
+        assert_eq!(guard.events().count(), 4);
+    }
+}
@@ -2,6 +2,10 @@
 //!
 //! Connects RL agents to the trading strategy system.
 
+pub mod guardrails;
 pub mod rl_strategy;
 
+pub use guardrails::{
+    GuardDecision, GuardrailEvent, GuardrailEventKind, OnlineLearningGuard, PolicyEvaluator,
+};
 pub use rl_strategy::RLStrategy;
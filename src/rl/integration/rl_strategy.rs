@@ -12,13 +12,16 @@ use tracing::{debug, info};
 use uuid::Uuid;
 
 use crate::domain::{OrderRequest, OrderSide, OrderType, Side, TimeInForce};
-use crate::error::Result;
+use crate::error::{PloyError, Result};
 use crate::rl::config::RLConfig;
 use crate::rl::core::{
     ContinuousAction, DefaultStateEncoder, DiscreteAction, PnLRewardFunction, RawObservation,
-    RewardFunction, RewardTransition,
+    RewardFunction, RewardTransition, StateEncoder,
 };
-use crate::rl::memory::ReplayBuffer;
+use crate::rl::integration::guardrails::{
+    GuardDecision, GuardrailEvent, OnlineLearningGuard, PolicyEvaluator,
+};
+use crate::rl::memory::{ReplayBuffer, Transition};
 use crate::strategy::{
     DataFeed, MarketUpdate, OrderUpdate, PositionInfo, Strategy, StrategyAction, StrategyStateInfo,
 };
@@ -57,6 +60,12 @@ pub struct RLStrategy {
     last_action: Option<ContinuousAction>,
     /// Exploration rate
     exploration_rate: f32,
+    /// Guardrails for online weight updates
+    guard: OnlineLearningGuard,
+    /// Scores weights on the guard's holdout
+    policy_evaluator: Option<Arc<dyn PolicyEvaluator>>,
+    /// Last time the latest accepted update was re-checked
+    last_guard_check: DateTime<Utc>,
 }
 
 impl RLStrategy {
@@ -80,7 +89,10 @@ impl RLStrategy {
             config: config.clone(),
             encoder: Arc::new(DefaultStateEncoder::new()),
             reward_fn: Box::new(PnLRewardFunction::new()),
-            replay_buffer: Arc::new(RwLock::new(ReplayBuffer::new(config.training.buffer_size))),
+            replay_buffer: Arc::new(RwLock::new(ReplayBuffer::with_config(
+                config.training.buffer_size,
+                config.training.replay.clone(),
+            ))),
             current_obs: RawObservation::new(),
             prev_obs: None,
             position: None,
@@ -91,9 +103,39 @@ impl RLStrategy {
             step_count: 0,
             last_action: None,
             exploration_rate: config.training.exploration_rate,
+            guard: OnlineLearningGuard::new(config.training.guardrails.clone(), Vec::new()),
+            policy_evaluator: None,
+            last_guard_check: Utc::now(),
         }
     }
 
+    /// Attach the evaluator used to shadow-test online weight updates
+    pub fn with_policy_evaluator(mut self, evaluator: Arc<dyn PolicyEvaluator>) -> Self {
+        self.policy_evaluator = Some(evaluator);
+        self
+    }
+
+    /// Live policy weights, as admitted by the guardrails
+    pub fn policy_weights(&self) -> &[f32] {
+        self.guard.weights()
+    }
+
+    /// Guardrail decisions and reverts, oldest first
+    pub fn guardrail_events(&self) -> impl Iterator<Item = &GuardrailEvent> {
+        self.guard.events()
+    }
+
+    /// Submit weights produced by an online update.
+    ///
+    /// The update is shadow-evaluated on recent experiences and capped before
+    /// it replaces the live weights.
+    pub fn propose_policy_update(&mut self, weights: Vec<f32>) -> Result<GuardDecision> {
+        let evaluator = self.policy_evaluator.clone().ok_or_else(|| {
+            PloyError::Validation("online update requires a policy evaluator".to_string())
+        })?;
+        Ok(self.guard.propose(weights, evaluator.as_ref(), Utc::now()))
+    }
+
     /// Update observation from market data
     fn update_observation(&mut self, update: &MarketUpdate) {
         match update {
@@ -273,6 +315,9 @@ impl RLStrategy {
                                 OrderType::Limit
                             },
                             time_in_force: TimeInForce::GTC,
+                            expires_at: None,
+                            post_only: false,
+                            execution_preference: None,
                         };
 
                         actions.push(StrategyAction::SubmitOrder {
@@ -323,6 +368,9 @@ impl RLStrategy {
                 OrderType::Limit
             },
             time_in_force: TimeInForce::GTC,
+            expires_at: None,
+            post_only: false,
+            execution_preference: None,
         }
     }
 
@@ -404,6 +452,7 @@ impl Strategy for RLStrategy {
         self.step_count += 1;
 
         // Select action using policy
+        let prev_action = self.last_action;
         let action = self.select_action();
 
         // Convert to strategy actions
@@ -414,8 +463,19 @@ impl Strategy for RLStrategy {
             let reward_transition = self.compute_reward_transition();
             let reward_signal = self.reward_fn.compute(&reward_transition);
 
-            // Store transition in replay buffer
-            // (This would be a full transition once we have next state)
+            // Store the previous step's transition for training and as guardrail holdout
+            if let (Some(prev_obs), Some(prev_action)) = (&self.prev_obs, prev_action) {
+                let transition = Transition::new(
+                    self.encoder.encode(prev_obs),
+                    prev_action.to_tensor().to_vec(),
+                    reward_signal.total,
+                    self.encoder.encode(&self.current_obs),
+                    false,
+                );
+                self.guard.observe(transition.clone());
+                self.replay_buffer.write().await.push(transition);
+            }
+
             debug!(
                 "Step {}: reward={:.4}, exploration={:.4}",
                 self.step_count, reward_signal.total, self.exploration_rate
@@ -497,6 +557,16 @@ impl Strategy for RLStrategy {
         self.current_obs
             .update_time_features(now.hour(), now.weekday().num_days_from_monday());
 
+        // Revert the latest online update if it now underperforms on recent experiences
+        let check_interval =
+            chrono::Duration::seconds(self.config.training.guardrails.update_interval_secs as i64);
+        if self.online_learning && now - self.last_guard_check >= check_interval {
+            self.last_guard_check = now;
+            if let Some(evaluator) = self.policy_evaluator.clone() {
+                self.guard.check(evaluator.as_ref(), now);
+            }
+        }
+
         // Update position price if we have one
         if let Some(pos) = &mut self.position {
             let current_price = if pos.side == Side::Up {
@@ -552,6 +622,9 @@ impl Strategy for RLStrategy {
                         limit_price: bid,
                         order_type: OrderType::Market,
                         time_in_force: TimeInForce::IOC,
+                        expires_at: None,
+                        post_only: false,
+                        execution_preference: None,
                     },
                     priority: 100, // Highest priority
                 });
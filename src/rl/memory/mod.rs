@@ -4,4 +4,4 @@
 
 pub mod replay_buffer;
 
-pub use replay_buffer::{ReplayBuffer, RolloutBuffer, Transition};
+pub use replay_buffer::{ReplayBuffer, RolloutBuffer, SampledBatch, Transition};
@@ -1,12 +1,17 @@
 //! Replay Buffer
 //!
 //! Experience replay buffer for off-policy learning and PPO rollouts.
+//!
+//! [`ReplayBuffer`] optionally samples proportionally to TD-error priority
+//! (with importance-sampling weights) and folds n-step discounted returns into
+//! stored transitions; both are off by default.
 
 use rand::seq::SliceRandom;
-use rand::thread_rng;
+use rand::{thread_rng, Rng};
 use serde::{Deserialize, Serialize};
 use std::collections::VecDeque;
 
+use crate::rl::config::ReplayConfig;
 use crate::rl::core::{DiscreteAction, RewardSignal};
 
 pub type BatchTensors = (
@@ -47,6 +52,13 @@ pub struct Transition {
     pub log_prob: Option<f32>,
     /// Value estimate at state (for PPO)
     pub value: Option<f32>,
+    /// Environment steps folded into `reward`; bootstrap `next_state` with gamma^n_steps
+    #[serde(default = "default_n_steps")]
+    pub n_steps: usize,
+}
+
+fn default_n_steps() -> usize {
+    1
 }
 
 impl Transition {
@@ -68,6 +80,7 @@ impl Transition {
             done,
             log_prob: None,
             value: None,
+            n_steps: 1,
         }
     }
 
@@ -91,68 +104,274 @@ impl Transition {
     }
 }
 
+/// Array-backed binary sum tree over leaf priorities
+#[derive(Debug)]
+struct SumTree {
+    /// Leaves live at `[leaves, 2 * leaves)`
+    nodes: Vec<f32>,
+    leaves: usize,
+}
+
+impl SumTree {
+    fn new(capacity: usize) -> Self {
+        let leaves = capacity.max(1).next_power_of_two();
+        Self {
+            nodes: vec![0.0; 2 * leaves],
+            leaves,
+        }
+    }
+
+    fn total(&self) -> f32 {
+        self.nodes[1]
+    }
+
+    fn get(&self, idx: usize) -> f32 {
+        self.nodes[self.leaves + idx]
+    }
+
+    fn set(&mut self, idx: usize, priority: f32) {
+        let mut node = self.leaves + idx;
+        self.nodes[node] = priority;
+        while node > 1 {
+            node /= 2;
+            self.nodes[node] = self.nodes[2 * node] + self.nodes[2 * node + 1];
+        }
+    }
+
+    /// Leaf whose cumulative priority range contains `mass`
+    fn find(&self, mut mass: f32) -> usize {
+        let mut node = 1;
+        while node < self.leaves {
+            let left = 2 * node;
+            if mass < self.nodes[left] || self.nodes[left + 1] <= 0.0 {
+                node = left;
+            } else {
+                mass -= self.nodes[left];
+                node = left + 1;
+            }
+        }
+        node - self.leaves
+    }
+
+    fn clear(&mut self) {
+        self.nodes.iter_mut().for_each(|n| *n = 0.0);
+    }
+}
+
+/// Batch drawn from a [`ReplayBuffer`]
+#[derive(Debug, Clone)]
+pub struct SampledBatch {
+    pub transitions: Vec<Transition>,
+    /// Buffer slots, for [`ReplayBuffer::update_priorities`]
+    pub indices: Vec<usize>,
+    /// Importance-sampling weights, max-normalized (all 1.0 when uniform)
+    pub weights: Vec<f32>,
+}
+
 /// Replay buffer for experience storage
 #[derive(Debug)]
 pub struct ReplayBuffer {
-    /// Storage for transitions
-    buffer: VecDeque<Transition>,
+    /// Ring storage for transitions
+    slots: Vec<Transition>,
+    /// Next slot to overwrite once full
+    head: usize,
     /// Maximum capacity
     capacity: usize,
+    config: ReplayConfig,
+    /// Priorities (prioritized mode only)
+    tree: Option<SumTree>,
+    /// Largest raw priority seen; new transitions enter with it
+    max_priority: f32,
+    /// Current importance-sampling exponent
+    beta: f32,
+    /// Recent steps awaiting n-step aggregation
+    pending: VecDeque<Transition>,
 }
 
 impl ReplayBuffer {
-    /// Create a new replay buffer with given capacity
+    /// Create a new uniform, 1-step replay buffer with given capacity
     pub fn new(capacity: usize) -> Self {
+        Self::with_config(capacity, ReplayConfig::default())
+    }
+
+    /// Create a replay buffer with prioritized sampling / n-step returns
+    pub fn with_config(capacity: usize, config: ReplayConfig) -> Self {
+        let capacity = capacity.max(1);
         Self {
-            buffer: VecDeque::with_capacity(capacity),
+            slots: Vec::with_capacity(capacity),
+            head: 0,
             capacity,
+            tree: config.prioritized.then(|| SumTree::new(capacity)),
+            max_priority: 1.0,
+            beta: config.beta,
+            pending: VecDeque::with_capacity(config.n_step.max(1)),
+            config,
         }
     }
 
     /// Add a transition to the buffer
+    ///
+    /// With `n_step > 1` the transition is stored once enough following steps
+    /// have arrived (or the episode ends), carrying the discounted n-step return.
     pub fn push(&mut self, transition: Transition) {
-        if self.buffer.len() >= self.capacity {
-            self.buffer.pop_front();
+        let n = self.config.n_step.max(1);
+        if n == 1 {
+            self.insert(transition);
+            return;
+        }
+
+        let done = transition.done;
+        self.pending.push_back(transition);
+        if done {
+            self.flush_pending();
+        } else if self.pending.len() >= n {
+            let aggregated = self.aggregate_pending();
+            self.insert(aggregated);
+            self.pending.pop_front();
+        }
+    }
+
+    /// Store partial n-step returns for the steps still pending (e.g. at a
+    /// truncated episode end)
+    pub fn flush_pending(&mut self) {
+        while !self.pending.is_empty() {
+            let aggregated = self.aggregate_pending();
+            self.insert(aggregated);
+            self.pending.pop_front();
+        }
+    }
+
+    /// Fold the pending window into one transition starting at its first step
+    fn aggregate_pending(&self) -> Transition {
+        let mut out = self.pending[0].clone();
+        let mut reward = 0.0;
+        let mut discount = 1.0;
+        for step in &self.pending {
+            reward += discount * step.reward;
+            discount *= self.config.gamma;
+        }
+        let last = &self.pending[self.pending.len() - 1];
+        out.reward = reward;
+        out.next_state = last.next_state.clone();
+        out.done = last.done;
+        out.n_steps = self.pending.len();
+        out
+    }
+
+    fn insert(&mut self, transition: Transition) {
+        let idx = if self.slots.len() < self.capacity {
+            self.slots.push(transition);
+            self.slots.len() - 1
+        } else {
+            let idx = self.head;
+            self.slots[idx] = transition;
+            self.head = (self.head + 1) % self.capacity;
+            idx
+        };
+
+        let priority = self.max_priority.powf(self.config.alpha);
+        if let Some(tree) = self.tree.as_mut() {
+            tree.set(idx, priority);
         }
-        self.buffer.push_back(transition);
     }
 
     /// Sample a random batch of transitions
-    pub fn sample(&self, batch_size: usize) -> Vec<Transition> {
+    pub fn sample(&mut self, batch_size: usize) -> Vec<Transition> {
+        self.sample_batch(batch_size).transitions
+    }
+
+    /// Sample a batch with slot indices and importance-sampling weights
+    ///
+    /// Prioritized mode uses stratified proportional sampling (with
+    /// replacement) and anneals beta towards 1; otherwise sampling is uniform
+    /// without replacement and all weights are 1.
+    pub fn sample_batch(&mut self, batch_size: usize) -> SampledBatch {
+        let len = self.slots.len();
+        let batch_size = batch_size.min(len);
         let mut rng = thread_rng();
-        let mut indices: Vec<usize> = (0..self.buffer.len()).collect();
-        indices.shuffle(&mut rng);
 
-        indices
-            .into_iter()
-            .take(batch_size.min(self.buffer.len()))
-            .map(|i| self.buffer[i].clone())
-            .collect()
+        let Some(tree) = self.tree.as_ref().filter(|t| t.total() > 0.0) else {
+            let mut indices: Vec<usize> = (0..len).collect();
+            indices.shuffle(&mut rng);
+            indices.truncate(batch_size);
+            return SampledBatch {
+                transitions: indices.iter().map(|&i| self.slots[i].clone()).collect(),
+                weights: vec![1.0; indices.len()],
+                indices,
+            };
+        };
+
+        let total = tree.total();
+        let segment = total / batch_size.max(1) as f32;
+        let mut indices = Vec::with_capacity(batch_size);
+        let mut weights = Vec::with_capacity(batch_size);
+        for i in 0..batch_size {
+            let mass = segment * (i as f32 + rng.gen::<f32>());
+            let idx = tree.find(mass.min(total)).min(len - 1);
+            let p = tree.get(idx) / total;
+            indices.push(idx);
+            weights.push((len as f32 * p).powf(-self.beta));
+        }
+
+        let max_w = weights.iter().cloned().fold(f32::MIN_POSITIVE, f32::max);
+        for w in &mut weights {
+            *w /= max_w;
+        }
+        self.beta = (self.beta + self.config.beta_increment).min(1.0);
+
+        SampledBatch {
+            transitions: indices.iter().map(|&i| self.slots[i].clone()).collect(),
+            indices,
+            weights,
+        }
     }
 
-    /// Get all transitions (for PPO on-policy training)
+    /// Update priorities from the TD errors of a sampled batch
+    pub fn update_priorities(&mut self, indices: &[usize], td_errors: &[f32]) {
+        let Some(tree) = self.tree.as_mut() else {
+            return;
+        };
+        for (&idx, &err) in indices.iter().zip(td_errors) {
+            if idx >= self.slots.len() {
+                continue;
+            }
+            let raw = err.abs() + self.config.priority_epsilon;
+            self.max_priority = self.max_priority.max(raw);
+            tree.set(idx, raw.powf(self.config.alpha));
+        }
+    }
+
+    /// Get all transitions in insertion order (for PPO on-policy training)
     pub fn get_all(&self) -> Vec<Transition> {
-        self.buffer.iter().cloned().collect()
+        let (newer, older) = self.slots.split_at(self.head);
+        older.iter().chain(newer.iter()).cloned().collect()
     }
 
     /// Clear all transitions
     pub fn clear(&mut self) {
-        self.buffer.clear();
+        self.slots.clear();
+        self.head = 0;
+        self.pending.clear();
+        self.max_priority = 1.0;
+        self.beta = self.config.beta;
+        if let Some(tree) = self.tree.as_mut() {
+            tree.clear();
+        }
     }
 
     /// Get current number of transitions
     pub fn len(&self) -> usize {
-        self.buffer.len()
+        self.slots.len()
     }
 
     /// Check if buffer is empty
     pub fn is_empty(&self) -> bool {
-        self.buffer.is_empty()
+        self.slots.is_empty()
     }
 
     /// Check if buffer has enough samples for training
     pub fn has_enough_samples(&self, min_samples: usize) -> bool {
-        self.buffer.len() >= min_samples
+        self.slots.len() >= min_samples
     }
 
     /// Get buffer capacity
@@ -162,7 +381,12 @@ impl ReplayBuffer {
 
     /// Get fill ratio (0.0 to 1.0)
     pub fn fill_ratio(&self) -> f32 {
-        self.buffer.len() as f32 / self.capacity as f32
+        self.slots.len() as f32 / self.capacity as f32
+    }
+
+    /// Replay configuration
+    pub fn config(&self) -> &ReplayConfig {
+        &self.config
     }
 
     /// Extract batch tensors for training
@@ -272,7 +496,51 @@ impl RolloutBuffer {
             next_value = value;
         }
 
-        // Normalize advantages
+        self.normalize_advantages();
+    }
+
+    /// Compute n-step bootstrapped returns, with advantage = return - value
+    ///
+    /// `n_steps = 1` is TD(0); larger values trade bias for variance and
+    /// propagate rare terminal rewards faster than 1-step targets.
+    pub fn compute_n_step_returns(&mut self, gamma: f32, n_steps: usize, last_value: f32) {
+        let n = self.transitions.len();
+        let n_steps = n_steps.max(1);
+        self.advantages = vec![0.0; n];
+        self.returns = vec![0.0; n];
+
+        for t in 0..n {
+            let mut ret = 0.0;
+            let mut discount = 1.0;
+            let mut k = t;
+            let mut terminated = false;
+            while k < n && k < t + n_steps {
+                ret += discount * self.transitions[k].reward;
+                discount *= gamma;
+                if self.transitions[k].done {
+                    terminated = true;
+                    break;
+                }
+                k += 1;
+            }
+            if !terminated {
+                let bootstrap = if k < n {
+                    self.transitions[k].value.unwrap_or(0.0)
+                } else {
+                    last_value
+                };
+                ret += discount * bootstrap;
+            }
+
+            self.returns[t] = ret;
+            self.advantages[t] = ret - self.transitions[t].value.unwrap_or(0.0);
+        }
+
+        self.normalize_advantages();
+    }
+
+    fn normalize_advantages(&mut self) {
+        let n = self.advantages.len();
         if n > 1 {
             let mean: f32 = self.advantages.iter().sum::<f32>() / n as f32;
             let var: f32 = self
@@ -383,4 +651,63 @@ mod tests {
         assert_eq!(buffer.advantages.len(), 10);
         assert_eq!(buffer.returns.len(), 10);
     }
+
+    #[test]
+    fn test_prioritized_sampling_and_weights() {
+        let config = ReplayConfig {
+            prioritized: true,
+            ..Default::default()
+        };
+        let mut buffer = ReplayBuffer::with_config(64, config);
+        for i in 0..64 {
+            buffer.push(make_transition(i as f32, false));
+        }
+
+        // One rare, high-error transition dominates sampling
+        let all: Vec<usize> = (0..64).collect();
+        let mut errors = vec![0.01; 64];
+        errors[7] = 50.0;
+        buffer.update_priorities(&all, &errors);
+
+        let batch = buffer.sample_batch(32);
+        let hits = batch.indices.iter().filter(|&&i| i == 7).count();
+        assert!(hits > 16, "hits={hits}");
+        // Frequently sampled transitions get the smallest weights
+        let pos = batch.indices.iter().position(|&i| i == 7).unwrap();
+        assert!(batch
+            .weights
+            .iter()
+            .all(|&w| w <= 1.0 && w >= batch.weights[pos]));
+    }
+
+    #[test]
+    fn test_n_step_returns() {
+        let config = ReplayConfig {
+            n_step: 3,
+            gamma: 0.5,
+            ..Default::default()
+        };
+        let mut buffer = ReplayBuffer::with_config(100, config);
+        for i in 0..5 {
+            buffer.push(make_transition(1.0, i == 4));
+        }
+
+        let stored = buffer.get_all();
+        assert_eq!(stored.len(), 5);
+        // 1 + 0.5 + 0.25
+        assert_eq!(stored[0].reward, 1.75);
+        assert_eq!(stored[0].n_steps, 3);
+        assert!(!stored[0].done);
+        // Tail is flushed at episode end with shorter horizons
+        assert_eq!(stored[3].reward, 1.5);
+        assert!(stored[3].done);
+        assert_eq!(stored[4].n_steps, 1);
+
+        let mut rollout = RolloutBuffer::new(10);
+        for i in 0..3 {
+            rollout.push(make_transition(1.0, i == 2));
+        }
+        rollout.compute_n_step_returns(0.5, 2, 0.0);
+        assert_eq!(rollout.returns, vec![1.5, 1.5, 1.0]);
+    }
 }
@@ -26,7 +26,10 @@ pub mod networks;
 pub mod training;
 
 // Config exports
-pub use config::{PPOConfig, RLConfig, RewardConfig, TrainingConfig};
+pub use config::{
+    CurriculumConfig, GuardrailConfig, PPOConfig, RLConfig, ReplayConfig, RewardConfig,
+    TrainingConfig,
+};
 
 // Core exports
 pub use core::{
@@ -43,5 +46,6 @@ pub use integration::RLStrategy;
 
 // Environment exports
 pub use environment::{
-    EnvAction, MarketConfig, SimulatedMarket, StepResult, TradingEnvConfig, TradingEnvironment,
+    EnvAction, MarketConfig, MarketScenario, RegimePhase, SimulatedMarket, StepResult,
+    TradingEnvConfig, TradingEnvironment,
 };
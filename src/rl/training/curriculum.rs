@@ -0,0 +1,255 @@
+//! Curriculum Scheduling
+//!
+//! Splits LeadLag training data into fixed-length segments, grades each one
+//! by difficulty, and hands out segments in stages:
+//!
+//! 1. calm (low-volatility) segments only
+//! 2. plus high-volatility segments
+//! 3. plus segments that cross a market rotation boundary
+//!
+//! A stage is unlocked after a fixed number of episodes, or earlier once the
+//! rolling mean episode reward reaches the promotion threshold.
+
+use std::collections::VecDeque;
+use std::ops::Range;
+
+use rand::seq::SliceRandom;
+use rust_decimal::prelude::ToPrimitive;
+use serde::Serialize;
+
+use crate::rl::config::CurriculumConfig;
+use crate::rl::environment::LobDataPoint;
+
+/// Segment difficulty, in the order segments are introduced
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Serialize)]
+#[serde(rename_all = "snake_case")]
+pub enum CurriculumTier {
+    Calm,
+    Volatile,
+    Rotation,
+}
+
+impl CurriculumTier {
+    const ALL: [CurriculumTier; 3] = [Self::Calm, Self::Volatile, Self::Rotation];
+}
+
+/// One episode-sized slice of the training data
+#[derive(Debug, Clone, Serialize)]
+pub struct CurriculumSegment {
+    pub start: usize,
+    pub end: usize,
+    /// Standard deviation of mid-price log returns
+    pub volatility: f64,
+    pub tier: CurriculumTier,
+}
+
+impl CurriculumSegment {
+    pub fn range(&self) -> Range<usize> {
+        self.start..self.end
+    }
+}
+
+fn segment_volatility(points: &[LobDataPoint]) -> f64 {
+    let returns: Vec<f64> = points
+        .windows(2)
+        .filter_map(|w| {
+            let prev = w[0].bn_mid_price.to_f64()?;
+            let next = w[1].bn_mid_price.to_f64()?;
+            (prev > 0.0 && next > 0.0).then(|| (next / prev).ln())
+        })
+        .collect();
+    if returns.len() < 2 {
+        return 0.0;
+    }
+    let mean = returns.iter().sum::<f64>() / returns.len() as f64;
+    let var = returns.iter().map(|r| (r - mean).powi(2)).sum::<f64>() / returns.len() as f64;
+    var.sqrt()
+}
+
+fn crosses_rotation(points: &[LobDataPoint], period_ms: i64) -> bool {
+    match (points.first(), points.last()) {
+        (Some(first), Some(last)) if period_ms > 0 => {
+            first.timestamp_ms.div_euclid(period_ms) != last.timestamp_ms.div_euclid(period_ms)
+        }
+        _ => false,
+    }
+}
+
+/// Hands out training segments by curriculum stage
+#[derive(Debug)]
+pub struct CurriculumScheduler {
+    config: CurriculumConfig,
+    segments: Vec<CurriculumSegment>,
+    stage: usize,
+    stage_episodes: usize,
+    recent_rewards: VecDeque<f32>,
+}
+
+impl CurriculumScheduler {
+    /// Segment and grade `data`
+    pub fn new(config: CurriculumConfig, data: &[LobDataPoint]) -> Self {
+        let len = config.segment_len.max(2);
+        let mut segments: Vec<CurriculumSegment> = (0..data.len())
+            .step_by(len)
+            .map(|start| {
+                let end = (start + len).min(data.len());
+                let points = &data[start..end];
+                let tier = if crosses_rotation(points, config.rotation_period_ms) {
+                    CurriculumTier::Rotation
+                } else {
+                    CurriculumTier::Calm
+                };
+                CurriculumSegment {
+                    start,
+                    end,
+                    volatility: segment_volatility(points),
+                    tier,
+                }
+            })
+            .filter(|s| s.end - s.start >= 2)
+            .collect();
+
+        let mut vols: Vec<f64> = segments.iter().map(|s| s.volatility).collect();
+        vols.sort_by(|a, b| a.total_cmp(b));
+        if let Some(&threshold) = vols.get(
+            ((vols.len() as f64 * config.high_vol_quantile.clamp(0.0, 1.0)) as usize)
+                .min(vols.len().saturating_sub(1)),
+        ) {
+            for s in segments
+                .iter_mut()
+                .filter(|s| s.tier == CurriculumTier::Calm && s.volatility > threshold)
+            {
+                s.tier = CurriculumTier::Volatile;
+            }
+        }
+
+        Self {
+            config,
+            segments,
+            stage: 0,
+            stage_episodes: 0,
+            recent_rewards: VecDeque::new(),
+        }
+    }
+
+    pub fn segments(&self) -> &[CurriculumSegment] {
+        &self.segments
+    }
+
+    /// Current stage (0-based, see [`CurriculumTier`])
+    pub fn stage(&self) -> usize {
+        self.stage
+    }
+
+    /// Hardest tier currently unlocked
+    pub fn max_tier(&self) -> CurriculumTier {
+        CurriculumTier::ALL[self.stage.min(CurriculumTier::ALL.len() - 1)]
+    }
+
+    /// Number of segments per tier
+    pub fn tier_counts(&self) -> [(CurriculumTier, usize); 3] {
+        CurriculumTier::ALL.map(|t| (t, self.segments.iter().filter(|s| s.tier == t).count()))
+    }
+
+    /// Pick the data range for the next episode.
+    ///
+    /// Falls back to easier tiers when the unlocked tiers have no segments.
+    pub fn next_segment(&self) -> Option<&CurriculumSegment> {
+        let max_tier = self.max_tier();
+        let eligible: Vec<&CurriculumSegment> = self
+            .segments
+            .iter()
+            .filter(|s| s.tier <= max_tier)
+            .collect();
+        eligible.choose(&mut rand::thread_rng()).copied()
+    }
+
+    /// Record an episode result; returns true when a new stage was unlocked
+    pub fn record_episode(&mut self, reward: f32) -> bool {
+        self.stage_episodes += 1;
+        let window = self.config.promote_window.max(1);
+        if self.recent_rewards.len() >= window {
+            self.recent_rewards.pop_front();
+        }
+        self.recent_rewards.push_back(reward);
+
+        if self.stage + 1 >= CurriculumTier::ALL.len() {
+            return false;
+        }
+
+        let by_episodes = self.stage_episodes >= self.config.episodes_per_stage;
+        let by_reward = self.config.promote_reward.is_some_and(|target| {
+            self.recent_rewards.len() >= window
+                && self.recent_rewards.iter().sum::<f32>() / window as f32 >= target
+        });
+        if by_episodes || by_reward {
+            self.stage += 1;
+            self.stage_episodes = 0;
+            self.recent_rewards.clear();
+            return true;
+        }
+        false
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use rust_decimal::Decimal;
+
+    fn point(timestamp_ms: i64, mid: i64) -> LobDataPoint {
+        LobDataPoint {
+            timestamp_ms,
+            bn_mid_price: Decimal::from(mid),
+            bn_obi_5: Decimal::ZERO,
+            bn_obi_10: Decimal::ZERO,
+            bn_spread_bps: Decimal::ONE,
+            bn_bid_volume: Decimal::ONE,
+            bn_ask_volume: Decimal::ONE,
+            momentum_1s: Decimal::ZERO,
+            momentum_5s: Decimal::ZERO,
+            pm_yes_price: Decimal::new(50, 2),
+            pm_no_price: Decimal::new(50, 2),
+        }
+    }
+
+    #[test]
+    fn test_curriculum_tiers_and_promotion() {
+        // Three 10-point segments: flat, choppy, and one crossing a rotation
+        let mut data: Vec<LobDataPoint> = (0..10).map(|i| point(i * 1000, 100)).collect();
+        data.extend((10..20).map(|i| point(i * 1000, if i % 2 == 0 { 100 } else { 110 })));
+        data.extend((0..10).map(|i| point(895_000 + i * 1000, 100)));
+
+        let config = CurriculumConfig {
+            enabled: true,
+            segment_len: 10,
+            high_vol_quantile: 0.5,
+            episodes_per_stage: 2,
+            ..Default::default()
+        };
+        let mut scheduler = CurriculumScheduler::new(config, &data);
+        let tiers: Vec<CurriculumTier> = scheduler.segments().iter().map(|s| s.tier).collect();
+        assert_eq!(
+            tiers,
+            vec![
+                CurriculumTier::Calm,
+                CurriculumTier::Volatile,
+                CurriculumTier::Rotation
+            ]
+        );
+
+        for _ in 0..10 {
+            assert_eq!(scheduler.next_segment().unwrap().start, 0);
+        }
+        assert!(!scheduler.record_episode(0.0));
+        assert!(scheduler.record_episode(0.0));
+        assert_eq!(scheduler.max_tier(), CurriculumTier::Volatile);
+        assert!(scheduler
+            .next_segment()
+            .is_some_and(|s| s.tier <= CurriculumTier::Volatile));
+        scheduler.record_episode(0.0);
+        scheduler.record_episode(0.0);
+        assert_eq!(scheduler.max_tier(), CurriculumTier::Rotation);
+        assert!(!scheduler.record_episode(0.0));
+    }
+}
@@ -3,10 +3,17 @@
 //! Training loops, checkpointing, and evaluation utilities.
 
 pub mod checkpointing;
+pub mod curriculum;
+pub mod rollout;
 pub mod trainer;
 
 pub use checkpointing::Checkpointer;
+pub use curriculum::{CurriculumScheduler, CurriculumSegment, CurriculumTier};
+pub use rollout::{
+    collect_episode, learn_from_trajectory, train_parallel, RolloutConfig, RolloutPool, Trajectory,
+};
 pub use trainer::{
     run_backtest, summarize_backtest_results, summarize_results, train_backtest, train_simulated,
-    BacktestResult, BacktestSummary, EpisodeResult, TrainingLoop, TrainingStats, TrainingSummary,
+    train_snapshot_backtest, BacktestResult, BacktestSummary, EpisodeResult, TrainingLoop,
+    TrainingStats, TrainingSummary,
 };
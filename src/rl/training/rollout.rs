@@ -0,0 +1,290 @@
+//! Parallel Rollout Collection
+//!
+//! A pool of worker threads, each owning a simulated [`TradingEnvironment`]
+//! seeded from `seed + worker_id`, collects episodes with a snapshot of the
+//! learner's policy and streams trajectories back over a channel.
+//!
+//! Collection runs in rounds: every worker plays one episode with the same
+//! policy snapshot, and the learner consumes the round in worker order before
+//! publishing the next snapshot. With a fixed seed a run is therefore fully
+//! reproducible regardless of thread scheduling.
+
+use std::sync::mpsc;
+use std::sync::Arc;
+use std::thread::JoinHandle;
+
+use rand::rngs::StdRng;
+use rand::{Rng, SeedableRng};
+use tracing::info;
+
+use super::trainer::EpisodeResult;
+use crate::error::{PloyError, Result};
+use crate::rl::algorithms::ppo::{PPOBatch, PPOTrainer};
+use crate::rl::environment::{EnvAction, TradingEnvConfig, TradingEnvironment};
+
+/// Worker pool configuration
+#[derive(Debug, Clone)]
+pub struct RolloutConfig {
+    /// Number of worker threads
+    pub workers: usize,
+    /// Base seed; worker `i` uses `seed + i`
+    pub seed: u64,
+}
+
+impl Default for RolloutConfig {
+    fn default() -> Self {
+        Self {
+            workers: 1,
+            seed: 42,
+        }
+    }
+}
+
+/// One collected episode
+#[derive(Debug, Clone)]
+pub struct Trajectory {
+    pub worker_id: usize,
+    pub states: Vec<Vec<f32>>,
+    pub actions: Vec<Vec<f32>>,
+    pub log_probs: Vec<f32>,
+    pub rewards: Vec<f32>,
+    pub values: Vec<f32>,
+    pub dones: Vec<bool>,
+    pub result: EpisodeResult,
+}
+
+/// Play one episode with `policy`, drawing exploration from `rng`
+pub fn collect_episode<R: Rng + ?Sized>(
+    policy: &PPOTrainer,
+    env: &mut TradingEnvironment,
+    rng: &mut R,
+    worker_id: usize,
+) -> Trajectory {
+    let mut obs = env.reset();
+    let mut trajectory = Trajectory {
+        worker_id,
+        states: Vec::new(),
+        actions: Vec::new(),
+        log_probs: Vec::new(),
+        rewards: Vec::new(),
+        values: Vec::new(),
+        dones: Vec::new(),
+        result: EpisodeResult {
+            total_reward: 0.0,
+            length: 0,
+            final_pnl: 0.0,
+            num_trades: 0,
+            win_rate: 0.0,
+        },
+    };
+
+    let mut done = false;
+    while !done {
+        let (action_vec, log_prob) = policy.get_action_with_rng(&obs, rng);
+        let value = policy.get_value(&obs);
+
+        let action_idx = action_vec
+            .iter()
+            .enumerate()
+            .max_by(|(_, a), (_, b)| a.partial_cmp(b).unwrap_or(std::cmp::Ordering::Equal))
+            .map(|(i, _)| i)
+            .unwrap_or(0);
+
+        trajectory.states.push(obs);
+        trajectory.actions.push(action_vec);
+        trajectory.log_probs.push(log_prob);
+        trajectory.values.push(value);
+
+        let result = env.step(EnvAction::from(action_idx));
+        done = result.done || result.truncated;
+        trajectory.result.total_reward += result.reward;
+        trajectory.rewards.push(result.reward);
+        trajectory.dones.push(done);
+        obs = result.observation;
+    }
+
+    trajectory.result.length = env.step_count();
+    trajectory.result.final_pnl = env.episode_pnl();
+    trajectory.result.num_trades = env.num_trades();
+    trajectory.result.win_rate = env.win_rate();
+    trajectory
+}
+
+/// Compute GAE for a finished episode and apply one PPO update
+pub fn learn_from_trajectory(trainer: &mut PPOTrainer, trajectory: Trajectory) -> EpisodeResult {
+    let (advantages, returns) = trainer.compute_gae(
+        &trajectory.rewards,
+        &trajectory.values,
+        &trajectory.dones,
+        0.0,
+    );
+
+    let batch = PPOBatch {
+        states: trajectory.states,
+        actions: trajectory.actions,
+        old_log_probs: trajectory.log_probs,
+        returns,
+        advantages,
+        old_values: trajectory.values,
+    };
+    let _ppo_output = trainer.train_step(batch);
+    trainer.decay_exploration();
+
+    trajectory.result
+}
+
+/// Worker threads collecting episodes in parallel
+pub struct RolloutPool {
+    policies: Vec<mpsc::Sender<Arc<PPOTrainer>>>,
+    trajectories: mpsc::Receiver<Trajectory>,
+    handles: Vec<JoinHandle<()>>,
+}
+
+impl RolloutPool {
+    /// Spawn `config.workers` workers over `env_config`
+    pub fn spawn(env_config: TradingEnvConfig, config: &RolloutConfig) -> Result<Self> {
+        let workers = config.workers.max(1);
+        let (traj_tx, trajectories) = mpsc::channel();
+        let mut policies = Vec::with_capacity(workers);
+        let mut handles = Vec::with_capacity(workers);
+
+        for worker_id in 0..workers {
+            let (policy_tx, policy_rx) = mpsc::channel::<Arc<PPOTrainer>>();
+            let traj_tx = traj_tx.clone();
+            let env_config = env_config.clone();
+            let seed = config.seed.wrapping_add(worker_id as u64);
+
+            let handle = std::thread::Builder::new()
+                .name(format!("rl-rollout-{worker_id}"))
+                .spawn(move || {
+                    let mut env = TradingEnvironment::with_seed(env_config, seed);
+                    // Separate stream for exploration so it doesn't shift the market path
+                    let mut rng = StdRng::seed_from_u64(seed ^ 0x9E37_79B9_7F4A_7C15);
+                    while let Ok(policy) = policy_rx.recv() {
+                        let trajectory = collect_episode(&policy, &mut env, &mut rng, worker_id);
+                        if traj_tx.send(trajectory).is_err() {
+                            break;
+                        }
+                    }
+                })
+                .map_err(|e| PloyError::Internal(format!("failed to spawn rollout worker: {e}")))?;
+
+            policies.push(policy_tx);
+            handles.push(handle);
+        }
+
+        Ok(Self {
+            policies,
+            trajectories,
+            handles,
+        })
+    }
+
+    pub fn workers(&self) -> usize {
+        self.policies.len()
+    }
+
+    /// Run one episode per worker with `policy`; trajectories are returned in worker order
+    pub fn collect_round(&self, policy: &PPOTrainer) -> Result<Vec<Trajectory>> {
+        let snapshot = Arc::new(policy.clone());
+        for tx in &self.policies {
+            tx.send(Arc::clone(&snapshot))
+                .map_err(|_| PloyError::Internal("rollout worker exited".to_string()))?;
+        }
+
+        let mut round = Vec::with_capacity(self.policies.len());
+        for _ in 0..self.policies.len() {
+            let trajectory = self
+                .trajectories
+                .recv()
+                .map_err(|_| PloyError::Internal("rollout worker exited".to_string()))?;
+            round.push(trajectory);
+        }
+        round.sort_by_key(|t| t.worker_id);
+        Ok(round)
+    }
+}
+
+impl Drop for RolloutPool {
+    fn drop(&mut self) {
+        // Closing the policy channels ends each worker loop
+        self.policies.clear();
+        for handle in self.handles.drain(..) {
+            let _ = handle.join();
+        }
+    }
+}
+
+/// Train with rollouts collected by a worker pool.
+///
+/// Runs `ceil(num_episodes / workers)` rounds; the final round is truncated
+/// to `num_episodes`.
+pub fn train_parallel(
+    trainer: &mut PPOTrainer,
+    env_config: TradingEnvConfig,
+    num_episodes: usize,
+    config: &RolloutConfig,
+    verbose: bool,
+) -> Result<Vec<EpisodeResult>> {
+    let pool = RolloutPool::spawn(env_config, config)?;
+    let mut results = Vec::with_capacity(num_episodes);
+
+    while results.len() < num_episodes {
+        let round = pool.collect_round(trainer)?;
+        for trajectory in round {
+            if results.len() >= num_episodes {
+                break;
+            }
+            let worker_id = trajectory.worker_id;
+            let result = learn_from_trajectory(trainer, trajectory);
+            if verbose {
+                info!(
+                    "Episode {}/{} (worker {}): reward={:.2}, pnl={:.2}, trades={}, eps={:.3}",
+                    results.len() + 1,
+                    num_episodes,
+                    worker_id,
+                    result.total_reward,
+                    result.final_pnl,
+                    result.num_trades,
+                    trainer.exploration_rate()
+                );
+            }
+            results.push(result);
+        }
+    }
+
+    Ok(results)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::rl::algorithms::ppo::PPOTrainerConfig;
+
+    fn env_config() -> TradingEnvConfig {
+        TradingEnvConfig {
+            max_steps: 50,
+            ..Default::default()
+        }
+    }
+
+    #[test]
+    fn test_parallel_training_is_reproducible() {
+        let config = RolloutConfig {
+            workers: 3,
+            seed: 7,
+        };
+        let run = || {
+            let mut trainer = PPOTrainer::new(PPOTrainerConfig::default());
+            train_parallel(&mut trainer, env_config(), 7, &config, false)
+                .unwrap()
+                .iter()
+                .map(|r| (r.total_reward, r.length, r.num_trades))
+                .collect::<Vec<_>>()
+        };
+
+        let first = run();
+        assert_eq!(first.len(), 7);
+        assert_eq!(first, run());
+    }
+}
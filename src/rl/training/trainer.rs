@@ -9,18 +9,20 @@
 use std::sync::Arc;
 use tracing::info;
 
-use crate::rl::algorithms::ppo::{PPOBatch, PPOTrainer};
+use crate::rl::algorithms::ppo::PPOTrainer;
 use crate::rl::config::TrainingConfig;
 use crate::rl::core::{
     ContinuousAction, DefaultStateEncoder, PnLRewardFunction, RawObservation, RewardFunction,
     RewardTransition, StateEncoder,
 };
 use crate::rl::environment::{
-    generate_sample_data, BacktestEnvironment, EnvAction, HistoricalData, TradingEnvConfig,
-    TradingEnvironment,
+    generate_sample_data, BacktestEnvironment, EnvAction, HistoricalData, SnapshotEnvironment,
+    TradingEnvConfig, TradingEnvironment,
 };
 use crate::rl::memory::{RolloutBuffer, Transition};
 
+use super::rollout::{collect_episode, learn_from_trajectory};
+
 /// Training statistics
 #[derive(Debug, Clone, Default)]
 pub struct TrainingStats {
@@ -195,85 +197,19 @@ pub fn train_simulated(
     verbose: bool,
 ) -> Vec<EpisodeResult> {
     let mut env = TradingEnvironment::new(env_config);
+    let mut rng = rand::thread_rng();
     let mut results = Vec::with_capacity(num_episodes);
 
     for episode in 0..num_episodes {
-        let mut obs = env.reset();
-        let mut total_reward = 0.0f32;
-        let mut done = false;
-
-        // Collect experience for this episode
-        let mut states = Vec::new();
-        let mut actions = Vec::new();
-        let mut rewards = Vec::new();
-        let mut values = Vec::new();
-        let mut log_probs = Vec::new();
-        let mut dones = Vec::new();
-
-        while !done {
-            // Get action from trainer
-            let (action_vec, log_prob) = trainer.get_action(&obs);
-            let value = trainer.get_value(&obs);
-
-            // Convert action to discrete
-            let action_idx = action_vec
-                .iter()
-                .enumerate()
-                .max_by(|(_, a), (_, b)| a.partial_cmp(b).unwrap_or(std::cmp::Ordering::Equal))
-                .map(|(i, _)| i)
-                .unwrap_or(0);
-            let action = EnvAction::from(action_idx);
-
-            // Store transition
-            states.push(obs.clone());
-            actions.push(action_vec.clone());
-            log_probs.push(log_prob);
-            values.push(value);
-
-            // Take step
-            let result = env.step(action);
-            total_reward += result.reward;
-            rewards.push(result.reward);
-            dones.push(result.done || result.truncated);
-            obs = result.observation;
-            done = result.done || result.truncated;
-        }
-
-        // Compute GAE and train
-        let last_value = if done { 0.0 } else { trainer.get_value(&obs) };
-        let (advantages, returns) = trainer.compute_gae(&rewards, &values, &dones, last_value);
-
-        // Create batch
-        let batch = PPOBatch {
-            states,
-            actions,
-            old_log_probs: log_probs,
-            returns,
-            advantages,
-            old_values: values,
-        };
-
-        // Train step
-        let _ppo_output = trainer.train_step(batch);
-
-        // Decay exploration
-        trainer.decay_exploration();
-
-        // Record result
-        let episode_result = EpisodeResult {
-            total_reward,
-            length: env.step_count(),
-            final_pnl: env.episode_pnl(),
-            num_trades: env.num_trades(),
-            win_rate: env.win_rate(),
-        };
+        let trajectory = collect_episode(trainer, &mut env, &mut rng, 0);
+        let episode_result = learn_from_trajectory(trainer, trajectory);
 
         if verbose {
             info!(
                 "Episode {}/{}: reward={:.2}, pnl={:.2}, trades={}, win_rate={:.1}%, eps={:.3}",
                 episode + 1,
                 num_episodes,
-                total_reward,
+                episode_result.total_reward,
                 episode_result.final_pnl,
                 episode_result.num_trades,
                 episode_result.win_rate * 100.0,
@@ -474,6 +410,72 @@ pub fn train_backtest(
     results
 }
 
+/// Train over recorded rounds, one episode per round
+pub fn train_snapshot_backtest(
+    trainer: &mut PPOTrainer,
+    env: &mut SnapshotEnvironment,
+    num_episodes: usize,
+    verbose: bool,
+) -> Vec<BacktestResult> {
+    let mut results = Vec::with_capacity(num_episodes);
+    if env.num_rounds() == 0 {
+        return results;
+    }
+
+    for episode in 0..num_episodes {
+        let mut obs = env.reset();
+        let mut total_reward = 0.0f32;
+        let mut length = 0;
+
+        while env.remaining_steps() > 0 {
+            let (action_vec, _log_prob) = trainer.get_action(&obs);
+            let action_idx = action_vec
+                .iter()
+                .enumerate()
+                .max_by(|(_, a), (_, b)| a.partial_cmp(b).unwrap_or(std::cmp::Ordering::Equal))
+                .map(|(i, _)| i)
+                .unwrap_or(0);
+
+            let result = env.step(EnvAction::from(action_idx));
+            total_reward += result.reward;
+            length += 1;
+            obs = result.observation;
+
+            if result.done {
+                break;
+            }
+        }
+
+        let stats = env.final_stats();
+        if verbose && (episode + 1) % 10 == 0 {
+            info!(
+                "Episode {}/{} ({}): pnl={:.2}, costs={:.2}, trades={}, eps={:.3}",
+                episode + 1,
+                num_episodes,
+                env.round_slug(),
+                stats.total_pnl,
+                env.total_costs(),
+                stats.num_trades,
+                trainer.exploration_rate()
+            );
+        }
+
+        trainer.decay_exploration();
+
+        results.push(BacktestResult {
+            round_slug: env.round_slug().to_string(),
+            total_reward,
+            length,
+            final_pnl: stats.total_pnl,
+            num_trades: stats.num_trades,
+            win_rate: stats.win_rate,
+            final_capital: stats.capital,
+        });
+    }
+
+    results
+}
+
 /// Summarize backtest results
 pub fn summarize_backtest_results(results: &[BacktestResult]) -> BacktestSummary {
     if results.is_empty() {
@@ -0,0 +1,1082 @@
+//! Accounting exports (`ploy export trades|positions|pnl`)
+//!
+//! Builds flat tables from the coordinator execution log and writes them as
+//! CSV or XLSX:
+//! - trades: every fill with fees and gas, plus settlement payouts of tokens
+//!   still held at resolution, with average-cost realized PnL
+//! - positions: inventory open at the end of the range, marked at the last
+//!   bid
+//! - pnl: per-strategy daily rows from the daily report replay
+//! - tax-lots: realized gains of one tax year from the FIFO/LIFO lot book,
+//!   summarized per token and term or one row per lot closure
+//!
+//! Column names and order are part of the file format; new columns go at the
+//! end.
+
+use crate::error::{PloyError, Result};
+use crate::persistence::tax_lots::{self, LotClosure, LotMethod};
+use crate::services::reporting::{
+    replay_events, DailyReport, DailyReportConfig, DailyReportService, Lot, ReplayEvent, TimedFill,
+    TokenSettlement,
+};
+use chrono::{DateTime, Duration, NaiveDate, Utc};
+use clap::ValueEnum;
+use rust_decimal::prelude::ToPrimitive;
+use rust_decimal::Decimal;
+use sqlx::PgPool;
+use std::collections::{BTreeMap, HashMap, HashSet};
+use std::io::Write;
+use std::path::Path;
+use uuid::Uuid;
+
+/// Output file format
+#[derive(ValueEnum, Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum ExportFormat {
+    #[default]
+    Csv,
+    Xlsx,
+}
+
+impl ExportFormat {
+    pub fn extension(&self) -> &'static str {
+        match self {
+            ExportFormat::Csv => "csv",
+            ExportFormat::Xlsx => "xlsx",
+        }
+    }
+}
+
+pub const TRADE_COLUMNS: &[&str] = &[
+    "timestamp_utc",
+    "account_id",
+    "agent_id",
+    "domain",
+    "market_slug",
+    "token_id",
+    "outcome_side",
+    "event",
+    "shares",
+    "price",
+    "notional_usd",
+    "fee_usd",
+    "gas_usd",
+    "net_cash_usd",
+    "realized_pnl_usd",
+    "order_id",
+    "intent_id",
+];
+
+pub const POSITION_COLUMNS: &[&str] = &[
+    "as_of_utc",
+    "account_id",
+    "agent_id",
+    "domain",
+    "market_slug",
+    "token_id",
+    "outcome_side",
+    "shares",
+    "avg_cost",
+    "cost_basis_usd",
+    "mark_price",
+    "market_value_usd",
+    "unrealized_pnl_usd",
+    "opened_at_utc",
+];
+
+pub const PNL_COLUMNS: &[&str] = &[
+    "date",
+    "strategy",
+    "domain",
+    "fills",
+    "buy_notional_usd",
+    "sell_notional_usd",
+    "realized_pnl_usd",
+    "unrealized_pnl_usd",
+    "fees_usd",
+    "gas_usd",
+    "net_pnl_usd",
+    "wins",
+    "losses",
+    "win_rate",
+    "max_drawdown_usd",
+];
+
+pub const TAX_SUMMARY_COLUMNS: &[&str] = &[
+    "tax_year",
+    "account_id",
+    "method",
+    "term",
+    "token_id",
+    "market_slug",
+    "closures",
+    "shares",
+    "proceeds_usd",
+    "cost_basis_usd",
+    "realized_gain_usd",
+];
+
+pub const TAX_LOT_COLUMNS: &[&str] = &[
+    "account_id",
+    "method",
+    "lot_id",
+    "token_id",
+    "market_slug",
+    "opened_at_utc",
+    "closed_at_utc",
+    "holding_days",
+    "term",
+    "close_kind",
+    "shares",
+    "proceeds_usd",
+    "cost_basis_usd",
+    "realized_gain_usd",
+    "close_intent_id",
+];
+
+/// One cell of an export table
+#[derive(Debug, Clone, PartialEq)]
+pub enum ExportCell {
+    Empty,
+    Text(String),
+    Number(Decimal),
+    Integer(i64),
+    Float(f64),
+    Timestamp(DateTime<Utc>),
+    Date(NaiveDate),
+}
+
+impl ExportCell {
+    fn text(value: impl Into<String>) -> Self {
+        ExportCell::Text(value.into())
+    }
+
+    fn opt_number(value: Option<Decimal>) -> Self {
+        value.map(ExportCell::Number).unwrap_or(ExportCell::Empty)
+    }
+
+    /// CSV rendering: plain decimals without trailing zeros, UTC timestamps as
+    /// `YYYY-MM-DD HH:MM:SS`, and text quoted as needed. Text that a
+    /// spreadsheet would read as a formula is prefixed with `'`.
+    pub fn to_csv(&self) -> String {
+        match self {
+            ExportCell::Empty => String::new(),
+            ExportCell::Text(s) => {
+                let s = if s.starts_with(['=', '+', '-', '@']) {
+                    format!("'{}", s)
+                } else {
+                    s.clone()
+                };
+                if s.contains([',', '"', '\n', '\r']) {
+                    format!("\"{}\"", s.replace('"', "\"\""))
+                } else {
+                    s
+                }
+            }
+            ExportCell::Number(d) => d.round_dp(8).normalize().to_string(),
+            ExportCell::Integer(n) => n.to_string(),
+            ExportCell::Float(f) => format!("{:.6}", f),
+            ExportCell::Timestamp(t) => t.format("%Y-%m-%d %H:%M:%S").to_string(),
+            ExportCell::Date(d) => d.format("%Y-%m-%d").to_string(),
+        }
+    }
+}
+
+/// Excel serial date (days since 1899-12-30, fraction = time of day)
+fn excel_serial(at: DateTime<Utc>) -> f64 {
+    let epoch = NaiveDate::from_ymd_opt(1899, 12, 30)
+        .and_then(|d| d.and_hms_opt(0, 0, 0))
+        .unwrap_or_default()
+        .and_utc();
+    (at - epoch).num_milliseconds() as f64 / 86_400_000.0
+}
+
+fn xlsx_error(e: rust_xlsxwriter::XlsxError) -> PloyError {
+    PloyError::Internal(format!("xlsx export failed: {}", e))
+}
+
+/// A table with a fixed column schema
+#[derive(Debug, Clone)]
+pub struct ExportTable {
+    /// Worksheet name
+    pub name: &'static str,
+    pub columns: &'static [&'static str],
+    pub rows: Vec<Vec<ExportCell>>,
+}
+
+impl ExportTable {
+    pub fn write_csv<W: Write>(&self, mut out: W) -> Result<()> {
+        writeln!(out, "{}", self.columns.join(","))?;
+        for row in &self.rows {
+            let line: Vec<String> = row.iter().map(ExportCell::to_csv).collect();
+            writeln!(out, "{}", line.join(","))?;
+        }
+        out.flush()?;
+        Ok(())
+    }
+
+    /// Single-sheet workbook with a bold, frozen header row
+    pub fn write_xlsx(&self, path: &Path) -> Result<()> {
+        use rust_xlsxwriter::{Format, Workbook};
+
+        let header = Format::new().set_bold();
+        let number = Format::new().set_num_format("0.00######");
+        let timestamp = Format::new().set_num_format("yyyy-mm-dd hh:mm:ss");
+        let date = Format::new().set_num_format("yyyy-mm-dd");
+
+        let mut workbook = Workbook::new();
+        let sheet = workbook.add_worksheet();
+        sheet.set_name(self.name).map_err(xlsx_error)?;
+        for (col, name) in self.columns.iter().enumerate() {
+            sheet
+                .write_string_with_format(0, col as u16, *name, &header)
+                .map_err(xlsx_error)?;
+        }
+        for (i, row) in self.rows.iter().enumerate() {
+            let r = i as u32 + 1;
+            for (col, cell) in row.iter().enumerate() {
+                let c = col as u16;
+                match cell {
+                    ExportCell::Empty => continue,
+                    ExportCell::Text(s) => sheet.write_string(r, c, s),
+                    ExportCell::Number(d) => {
+                        sheet.write_number_with_format(r, c, d.to_f64().unwrap_or(0.0), &number)
+                    }
+                    ExportCell::Integer(n) => sheet.write_number(r, c, *n as f64),
+                    ExportCell::Float(f) => sheet.write_number(r, c, *f),
+                    ExportCell::Timestamp(t) => {
+                        sheet.write_number_with_format(r, c, excel_serial(*t), &timestamp)
+                    }
+                    ExportCell::Date(d) => sheet.write_number_with_format(
+                        r,
+                        c,
+                        excel_serial(d.and_hms_opt(0, 0, 0).unwrap_or_default().and_utc()),
+                        &date,
+                    ),
+                }
+                .map_err(xlsx_error)?;
+            }
+        }
+        sheet.set_freeze_panes(1, 0).map_err(xlsx_error)?;
+        workbook.save(path).map_err(xlsx_error)?;
+        Ok(())
+    }
+
+    pub fn write(&self, format: ExportFormat, path: &Path) -> Result<()> {
+        match format {
+            ExportFormat::Csv => {
+                self.write_csv(std::io::BufWriter::new(std::fs::File::create(path)?))
+            }
+            ExportFormat::Xlsx => self.write_xlsx(path),
+        }
+    }
+}
+
+/// Inclusive UTC date range of an export
+#[derive(Debug, Clone, Copy)]
+pub struct ExportRange {
+    pub from: NaiveDate,
+    pub to: NaiveDate,
+}
+
+impl ExportRange {
+    pub fn new(from: NaiveDate, to: NaiveDate) -> Result<Self> {
+        if to < from {
+            return Err(PloyError::Validation(format!(
+                "--to {} is before --from {}",
+                to, from
+            )));
+        }
+        Ok(Self { from, to })
+    }
+
+    pub fn start(&self) -> DateTime<Utc> {
+        self.from.and_hms_opt(0, 0, 0).unwrap_or_default().and_utc()
+    }
+
+    /// Exclusive end (midnight after `to`)
+    pub fn end(&self) -> DateTime<Utc> {
+        self.to.and_hms_opt(0, 0, 0).unwrap_or_default().and_utc() + Duration::days(1)
+    }
+}
+
+/// Which executions to include
+#[derive(Debug, Clone, Default)]
+pub struct ExportFilter {
+    pub account_id: Option<String>,
+    pub include_dry_run: bool,
+}
+
+/// A filled execution from `agent_order_executions`
+#[derive(Debug, Clone)]
+pub struct ExportFill {
+    pub executed_at: DateTime<Utc>,
+    pub account_id: String,
+    pub agent_id: String,
+    pub domain: String,
+    pub market_slug: String,
+    pub token_id: String,
+    pub market_side: String,
+    pub is_buy: bool,
+    pub shares: Decimal,
+    pub price: Decimal,
+    pub fee_usd: Decimal,
+    pub gas_usd: Decimal,
+    pub order_id: Option<String>,
+    pub intent_id: Uuid,
+}
+
+impl TimedFill for ExportFill {
+    fn executed_at(&self) -> DateTime<Utc> {
+        self.executed_at
+    }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum TradeEvent {
+    Buy,
+    Sell,
+    /// Resolution payout of shares still held
+    Settlement,
+}
+
+impl TradeEvent {
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            TradeEvent::Buy => "BUY",
+            TradeEvent::Sell => "SELL",
+            TradeEvent::Settlement => "SETTLEMENT",
+        }
+    }
+}
+
+/// A fill or settlement with its cash impact
+#[derive(Debug, Clone)]
+pub struct TradeRecord {
+    pub at: DateTime<Utc>,
+    pub account_id: String,
+    pub agent_id: String,
+    pub domain: String,
+    pub market_slug: String,
+    pub token_id: String,
+    pub market_side: String,
+    pub event: TradeEvent,
+    pub shares: Decimal,
+    pub price: Decimal,
+    pub fee_usd: Decimal,
+    pub gas_usd: Decimal,
+    /// Average-cost PnL of sells and settlements
+    pub realized_pnl: Option<Decimal>,
+    pub order_id: Option<String>,
+    pub intent_id: Option<Uuid>,
+}
+
+impl TradeRecord {
+    pub fn notional(&self) -> Decimal {
+        self.shares * self.price
+    }
+
+    /// Cash in (positive) or out (negative) after fees and gas
+    pub fn net_cash(&self) -> Decimal {
+        let costs = self.fee_usd + self.gas_usd;
+        match self.event {
+            TradeEvent::Buy => -self.notional() - costs,
+            TradeEvent::Sell | TradeEvent::Settlement => self.notional() - costs,
+        }
+    }
+}
+
+/// Inventory still held after the replay
+#[derive(Debug, Clone)]
+pub struct OpenPosition {
+    pub account_id: String,
+    pub agent_id: String,
+    pub domain: String,
+    pub market_slug: String,
+    pub token_id: String,
+    pub market_side: String,
+    pub shares: Decimal,
+    pub cost: Decimal,
+    pub opened_at: DateTime<Utc>,
+}
+
+struct OpenLot {
+    lot: Lot,
+    template: ExportFill,
+    opened_at: DateTime<Utc>,
+}
+
+/// Replay fills and settlements before `until` in time order with
+/// average-cost lots per (account, agent, token). Returns every trade event
+/// and the positions still open afterwards. Settlements without a resolution
+/// time are skipped. Pure: no I/O.
+pub fn replay_trades(
+    fills: &[ExportFill],
+    settlements: &HashMap<String, TokenSettlement>,
+    until: DateTime<Utc>,
+) -> (Vec<TradeRecord>, Vec<OpenPosition>) {
+    let events = replay_events(fills, settlements, until, None);
+
+    let mut lots: HashMap<(String, String, String), OpenLot> = HashMap::new();
+    let mut trades = Vec::new();
+    for (at, event) in events {
+        match event {
+            ReplayEvent::Fill(fill) => {
+                let key = (
+                    fill.account_id.clone(),
+                    fill.agent_id.clone(),
+                    fill.token_id.clone(),
+                );
+                let open = lots.entry(key).or_insert_with(|| OpenLot {
+                    lot: Lot::default(),
+                    template: fill.clone(),
+                    opened_at: at,
+                });
+                if open.lot.shares.is_zero() {
+                    open.opened_at = at;
+                }
+                let realized = if fill.is_buy {
+                    open.lot.shares += fill.shares;
+                    open.lot.cost += fill.shares * fill.price;
+                    None
+                } else {
+                    Some(open.lot.close(fill.shares, fill.price).1)
+                };
+                trades.push(TradeRecord {
+                    at,
+                    account_id: fill.account_id.clone(),
+                    agent_id: fill.agent_id.clone(),
+                    domain: fill.domain.clone(),
+                    market_slug: fill.market_slug.clone(),
+                    token_id: fill.token_id.clone(),
+                    market_side: fill.market_side.clone(),
+                    event: if fill.is_buy {
+                        TradeEvent::Buy
+                    } else {
+                        TradeEvent::Sell
+                    },
+                    shares: fill.shares,
+                    price: fill.price,
+                    fee_usd: fill.fee_usd,
+                    gas_usd: fill.gas_usd,
+                    realized_pnl: realized,
+                    order_id: fill.order_id.clone(),
+                    intent_id: Some(fill.intent_id),
+                });
+            }
+            ReplayEvent::Settle { token_id, price } => {
+                let mut settled: Vec<_> = lots
+                    .values_mut()
+                    .filter(|o| o.template.token_id == token_id && !o.lot.shares.is_zero())
+                    .collect();
+                settled.sort_by(|a, b| {
+                    (&a.template.account_id, &a.template.agent_id)
+                        .cmp(&(&b.template.account_id, &b.template.agent_id))
+                });
+                for open in settled {
+                    let shares = open.lot.shares;
+                    let (_, realized) = open.lot.close(shares, price);
+                    let t = &open.template;
+                    trades.push(TradeRecord {
+                        at,
+                        account_id: t.account_id.clone(),
+                        agent_id: t.agent_id.clone(),
+                        domain: t.domain.clone(),
+                        market_slug: t.market_slug.clone(),
+                        token_id: t.token_id.clone(),
+                        market_side: t.market_side.clone(),
+                        event: TradeEvent::Settlement,
+                        shares,
+                        price,
+                        fee_usd: Decimal::ZERO,
+                        gas_usd: Decimal::ZERO,
+                        realized_pnl: Some(realized),
+                        order_id: None,
+                        intent_id: None,
+                    });
+                }
+            }
+        }
+    }
+
+    let mut open: Vec<OpenPosition> = lots
+        .into_values()
+        .filter(|o| o.lot.shares > Decimal::ZERO)
+        .map(|o| OpenPosition {
+            account_id: o.template.account_id,
+            agent_id: o.template.agent_id,
+            domain: o.template.domain,
+            market_slug: o.template.market_slug,
+            token_id: o.template.token_id,
+            market_side: o.template.market_side,
+            shares: o.lot.shares,
+            cost: o.lot.cost,
+            opened_at: o.opened_at,
+        })
+        .collect();
+    open.sort_by(|a, b| {
+        (&a.account_id, &a.agent_id, &a.token_id).cmp(&(&b.account_id, &b.agent_id, &b.token_id))
+    });
+    (trades, open)
+}
+
+/// Trades table for events inside `range`
+pub fn trades_table(trades: &[TradeRecord], range: &ExportRange) -> ExportTable {
+    let (start, end) = (range.start(), range.end());
+    let rows = trades
+        .iter()
+        .filter(|t| t.at >= start && t.at < end)
+        .map(|t| {
+            vec![
+                ExportCell::Timestamp(t.at),
+                ExportCell::text(&t.account_id),
+                ExportCell::text(&t.agent_id),
+                ExportCell::text(&t.domain),
+                ExportCell::text(&t.market_slug),
+                ExportCell::text(&t.token_id),
+                ExportCell::text(&t.market_side),
+                ExportCell::text(t.event.as_str()),
+                ExportCell::Number(t.shares),
+                ExportCell::Number(t.price),
+                ExportCell::Number(t.notional()),
+                ExportCell::Number(t.fee_usd),
+                ExportCell::Number(t.gas_usd),
+                ExportCell::Number(t.net_cash()),
+                ExportCell::opt_number(t.realized_pnl),
+                t.order_id
+                    .as_deref()
+                    .map(ExportCell::text)
+                    .unwrap_or(ExportCell::Empty),
+                t.intent_id
+                    .map(|id| ExportCell::Text(id.to_string()))
+                    .unwrap_or(ExportCell::Empty),
+            ]
+        })
+        .collect();
+    ExportTable {
+        name: "trades",
+        columns: TRADE_COLUMNS,
+        rows,
+    }
+}
+
+/// Positions table; positions without a mark leave the mark columns empty
+pub fn positions_table(
+    positions: &[OpenPosition],
+    marks: &HashMap<String, Decimal>,
+    as_of: DateTime<Utc>,
+) -> ExportTable {
+    let rows = positions
+        .iter()
+        .map(|p| {
+            let avg = if p.shares.is_zero() {
+                Decimal::ZERO
+            } else {
+                p.cost / p.shares
+            };
+            let mark = marks.get(&p.token_id).copied();
+            let value = mark.map(|m| m * p.shares);
+            vec![
+                ExportCell::Timestamp(as_of),
+                ExportCell::text(&p.account_id),
+                ExportCell::text(&p.agent_id),
+                ExportCell::text(&p.domain),
+                ExportCell::text(&p.market_slug),
+                ExportCell::text(&p.token_id),
+                ExportCell::text(&p.market_side),
+                ExportCell::Number(p.shares),
+                ExportCell::Number(avg),
+                ExportCell::Number(p.cost),
+                ExportCell::opt_number(mark),
+                ExportCell::opt_number(value),
+                ExportCell::opt_number(value.map(|v| v - p.cost)),
+                ExportCell::Timestamp(p.opened_at),
+            ]
+        })
+        .collect();
+    ExportTable {
+        name: "positions",
+        columns: POSITION_COLUMNS,
+        rows,
+    }
+}
+
+/// PnL table: one row per strategy per day, then the day's TOTAL row
+pub fn pnl_table(reports: &[DailyReport]) -> ExportTable {
+    let rows = reports
+        .iter()
+        .flat_map(|report| {
+            report
+                .strategies
+                .iter()
+                .chain(std::iter::once(&report.totals))
+                .map(move |s| {
+                    vec![
+                        ExportCell::Date(report.date),
+                        ExportCell::text(&s.strategy),
+                        ExportCell::text(&s.domain),
+                        ExportCell::Integer(s.fills as i64),
+                        ExportCell::Number(s.buy_notional),
+                        ExportCell::Number(s.sell_notional),
+                        ExportCell::Number(s.realized_pnl),
+                        ExportCell::Number(s.unrealized_pnl),
+                        ExportCell::Number(s.fees),
+                        ExportCell::Number(s.gas),
+                        ExportCell::Number(s.net_pnl),
+                        ExportCell::Integer(s.wins as i64),
+                        ExportCell::Integer(s.losses as i64),
+                        s.win_rate
+                            .map(ExportCell::Float)
+                            .unwrap_or(ExportCell::Empty),
+                        ExportCell::Number(s.max_drawdown),
+                    ]
+                })
+        })
+        .collect();
+    ExportTable {
+        name: "pnl",
+        columns: PNL_COLUMNS,
+        rows,
+    }
+}
+
+fn tax_term(closure: &LotClosure) -> &'static str {
+    if closure.is_long_term() {
+        "long"
+    } else {
+        "short"
+    }
+}
+
+/// Annual tax summary: one row per (account, term, token), then a TOTAL row
+/// per (account, term)
+pub fn tax_summary_table(year: i32, method: LotMethod, closures: &[LotClosure]) -> ExportTable {
+    #[derive(Default)]
+    struct Sum {
+        market_slug: String,
+        closures: i64,
+        shares: Decimal,
+        proceeds: Decimal,
+        cost_basis: Decimal,
+        gain: Decimal,
+    }
+    impl Sum {
+        fn add(&mut self, c: &LotClosure) {
+            self.closures += 1;
+            self.shares += c.shares;
+            self.proceeds += c.proceeds;
+            self.cost_basis += c.cost_basis;
+            self.gain += c.realized_gain;
+        }
+    }
+
+    let mut by_token: BTreeMap<(&str, &str, &str), Sum> = BTreeMap::new();
+    let mut totals: BTreeMap<(&str, &str), Sum> = BTreeMap::new();
+    for c in closures {
+        let term = tax_term(c);
+        let sum = by_token
+            .entry((c.account_id.as_str(), term, c.token_id.as_str()))
+            .or_default();
+        sum.market_slug.clone_from(&c.market_slug);
+        sum.add(c);
+        totals
+            .entry((c.account_id.as_str(), term))
+            .or_default()
+            .add(c);
+    }
+
+    let row = |account: &str, term: &str, token: &str, sum: &Sum| {
+        vec![
+            ExportCell::Integer(year as i64),
+            ExportCell::text(account),
+            ExportCell::text(method.as_str()),
+            ExportCell::text(term),
+            ExportCell::text(token),
+            ExportCell::text(&sum.market_slug),
+            ExportCell::Integer(sum.closures),
+            ExportCell::Number(sum.shares),
+            ExportCell::Number(sum.proceeds),
+            ExportCell::Number(sum.cost_basis),
+            ExportCell::Number(sum.gain),
+        ]
+    };
+    let mut rows: Vec<Vec<ExportCell>> = by_token
+        .iter()
+        .map(|(&(account, term, token), sum)| row(account, term, token, sum))
+        .collect();
+    rows.extend(
+        totals
+            .iter()
+            .map(|(&(account, term), sum)| row(account, term, "TOTAL", sum)),
+    );
+    ExportTable {
+        name: "tax_summary",
+        columns: TAX_SUMMARY_COLUMNS,
+        rows,
+    }
+}
+
+/// One row per lot closure
+pub fn tax_lot_table(method: LotMethod, closures: &[LotClosure]) -> ExportTable {
+    let rows = closures
+        .iter()
+        .map(|c| {
+            vec![
+                ExportCell::text(&c.account_id),
+                ExportCell::text(method.as_str()),
+                ExportCell::Text(c.lot_id.to_string()),
+                ExportCell::text(&c.token_id),
+                ExportCell::text(&c.market_slug),
+                ExportCell::Timestamp(c.opened_at),
+                ExportCell::Timestamp(c.closed_at),
+                ExportCell::Integer(c.holding_days()),
+                ExportCell::text(tax_term(c)),
+                ExportCell::text(c.kind.as_str()),
+                ExportCell::Number(c.shares),
+                ExportCell::Number(c.proceeds),
+                ExportCell::Number(c.cost_basis),
+                ExportCell::Number(c.realized_gain),
+                c.close_intent_id
+                    .map(|id| ExportCell::Text(id.to_string()))
+                    .unwrap_or(ExportCell::Empty),
+            ]
+        })
+        .collect();
+    ExportTable {
+        name: "tax_lots",
+        columns: TAX_LOT_COLUMNS,
+        rows,
+    }
+}
+
+/// All fills before `until` (earlier fills rebuild the opening inventory)
+pub async fn load_fills(
+    pool: &PgPool,
+    until: DateTime<Utc>,
+    filter: &ExportFilter,
+) -> Result<Vec<ExportFill>> {
+    #[allow(clippy::type_complexity)]
+    let rows: Vec<(
+        DateTime<Utc>,
+        String,
+        String,
+        String,
+        String,
+        String,
+        String,
+        bool,
+        Decimal,
+        Option<Decimal>,
+        Decimal,
+        Decimal,
+        Decimal,
+        Option<String>,
+        Uuid,
+    )> = sqlx::query_as(
+        r#"
+        SELECT executed_at, account_id, agent_id, domain, market_slug, token_id, market_side,
+               is_buy, filled_shares::numeric, avg_fill_price, limit_price,
+               CASE WHEN metadata->>'fee_usd' ~ '^-?[0-9]+(\.[0-9]+)?$'
+                    THEN (metadata->>'fee_usd')::numeric ELSE 0 END AS fee_usd,
+               CASE WHEN metadata->>'gas_cost_usd' ~ '^-?[0-9]+(\.[0-9]+)?$'
+                    THEN (metadata->>'gas_cost_usd')::numeric ELSE 0 END AS gas_usd,
+               order_id, intent_id
+        FROM agent_order_executions
+        WHERE executed_at < $1
+          AND filled_shares > 0
+          AND ($2::text IS NULL OR account_id = $2)
+          AND ($3 OR dry_run = FALSE)
+        ORDER BY executed_at ASC, id ASC
+        "#,
+    )
+    .bind(until)
+    .bind(filter.account_id.as_deref())
+    .bind(filter.include_dry_run)
+    .fetch_all(pool)
+    .await?;
+
+    Ok(rows
+        .into_iter()
+        .map(
+            |(
+                executed_at,
+                account_id,
+                agent_id,
+                domain,
+                market_slug,
+                token_id,
+                market_side,
+                is_buy,
+                shares,
+                avg,
+                limit,
+                fee_usd,
+                gas_usd,
+                order_id,
+                intent_id,
+            )| ExportFill {
+                executed_at,
+                account_id,
+                agent_id,
+                domain,
+                market_slug,
+                token_id,
+                market_side,
+                is_buy,
+                shares,
+                price: avg.unwrap_or(limit),
+                fee_usd,
+                gas_usd,
+                order_id,
+                intent_id,
+            },
+        )
+        .collect())
+}
+
+/// Distinct token ids of `fills`
+pub fn token_ids(fills: &[ExportFill]) -> Vec<String> {
+    fills
+        .iter()
+        .map(|f| f.token_id.clone())
+        .collect::<HashSet<_>>()
+        .into_iter()
+        .collect()
+}
+
+/// Resolved settlements of `tokens`
+pub async fn load_settlements(
+    pool: &PgPool,
+    tokens: &[String],
+) -> Result<HashMap<String, TokenSettlement>> {
+    Ok(
+        sqlx::query_as::<_, (String, Decimal, Option<DateTime<Utc>>)>(
+            r#"
+            SELECT token_id, settled_price, resolved_at
+            FROM pm_token_settlements
+            WHERE resolved = TRUE AND settled_price IS NOT NULL AND token_id = ANY($1)
+            "#,
+        )
+        .bind(tokens)
+        .fetch_all(pool)
+        .await?
+        .into_iter()
+        .map(|(token, settled_price, resolved_at)| {
+            (
+                token,
+                TokenSettlement {
+                    settled_price,
+                    resolved_at,
+                },
+            )
+        })
+        .collect(),
+    )
+}
+
+/// Trades (fills and settlements) inside `range`
+pub async fn export_trades(
+    pool: &PgPool,
+    range: &ExportRange,
+    filter: &ExportFilter,
+) -> Result<ExportTable> {
+    let fills = load_fills(pool, range.end(), filter).await?;
+    let settlements = load_settlements(pool, &token_ids(&fills)).await?;
+    let (trades, _) = replay_trades(&fills, &settlements, range.end());
+    Ok(trades_table(&trades, range))
+}
+
+/// Positions open at the end of `range`, marked at the last bid before it
+pub async fn export_positions(
+    pool: &PgPool,
+    range: &ExportRange,
+    filter: &ExportFilter,
+) -> Result<ExportTable> {
+    let end = range.end();
+    let fills = load_fills(pool, end, filter).await?;
+    let tokens = token_ids(&fills);
+    let settlements = load_settlements(pool, &tokens).await?;
+    let (_, open) = replay_trades(&fills, &settlements, end);
+
+    let marks: HashMap<String, Decimal> = sqlx::query_as::<_, (String, Decimal)>(
+        r#"
+        SELECT DISTINCT ON (token_id) token_id, best_bid
+        FROM clob_quote_ticks
+        WHERE token_id = ANY($1) AND received_at < $2 AND best_bid IS NOT NULL
+        ORDER BY token_id, received_at DESC
+        "#,
+    )
+    .bind(&tokens)
+    .bind(end)
+    .fetch_all(pool)
+    .await?
+    .into_iter()
+    .collect();
+
+    Ok(positions_table(&open, &marks, end))
+}
+
+/// Daily per-strategy PnL for every day in `range`
+pub async fn export_pnl(
+    pool: &PgPool,
+    range: &ExportRange,
+    filter: &ExportFilter,
+) -> Result<ExportTable> {
+    let service = DailyReportService::new(
+        pool.clone(),
+        DailyReportConfig {
+            account_id: filter.account_id.clone(),
+            include_dry_run: filter.include_dry_run,
+            ..DailyReportConfig::default()
+        },
+    );
+    let mut reports = Vec::new();
+    let mut day = range.from;
+    while day <= range.to {
+        reports.push(service.generate(day).await?);
+        day = match day.succ_opt() {
+            Some(next) => next,
+            None => break,
+        };
+    }
+    Ok(pnl_table(&reports))
+}
+
+/// Rebuild and persist the `method` lot book, then export the closures of
+/// calendar year `year` (UTC) as a summary or per-closure detail
+pub async fn export_tax_lots(
+    pool: &PgPool,
+    year: i32,
+    method: LotMethod,
+    account_id: Option<&str>,
+    detail: bool,
+) -> Result<ExportTable> {
+    let bounds = |y: i32| {
+        NaiveDate::from_ymd_opt(y, 1, 1)
+            .and_then(|d| d.and_hms_opt(0, 0, 0))
+            .map(|d| d.and_utc())
+            .ok_or_else(|| PloyError::Validation(format!("invalid tax year {}", year)))
+    };
+    let (from, to) = (bounds(year)?, bounds(year + 1)?);
+
+    tax_lots::rebuild(pool, method, account_id).await?;
+    let closures = tax_lots::load_closures(pool, method, account_id, from, to).await?;
+    Ok(if detail {
+        tax_lot_table(method, &closures)
+    } else {
+        tax_summary_table(year, method, &closures)
+    })
+}
+
+/// Fill factory shared by the export and tax-lot tests
+#[cfg(test)]
+pub(crate) fn sample_fill(
+    executed_at: DateTime<Utc>,
+    is_buy: bool,
+    shares: Decimal,
+    price: Decimal,
+) -> ExportFill {
+    ExportFill {
+        executed_at,
+        account_id: "default".into(),
+        agent_id: "crypto".into(),
+        domain: "crypto".into(),
+        market_slug: "btc-updown-15m".into(),
+        token_id: "tok".into(),
+        market_side: "UP".into(),
+        is_buy,
+        shares,
+        price,
+        fee_usd: rust_decimal_macros::dec!(0.02),
+        gas_usd: Decimal::ZERO,
+        order_id: Some("0xabc".into()),
+        intent_id: Uuid::nil(),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use chrono::TimeZone;
+    use rust_decimal_macros::dec;
+
+    fn fill(minute: u32, is_buy: bool, shares: Decimal, price: Decimal) -> ExportFill {
+        sample_fill(
+            Utc.with_ymd_and_hms(2026, 3, 2, 14, minute, 0).unwrap(),
+            is_buy,
+            shares,
+            price,
+        )
+    }
+
+    #[test]
+    fn test_replay_realizes_sells_and_settlements() {
+        let fills = vec![
+            fill(0, true, dec!(10), dec!(0.40)),
+            fill(5, false, dec!(4), dec!(0.60)),
+        ];
+        let settlements = HashMap::from([(
+            "tok".to_string(),
+            TokenSettlement {
+                settled_price: dec!(1),
+                resolved_at: Some(Utc.with_ymd_and_hms(2026, 3, 2, 15, 0, 0).unwrap()),
+            },
+        )]);
+        let range = ExportRange::new(
+            NaiveDate::from_ymd_opt(2026, 3, 2).unwrap(),
+            NaiveDate::from_ymd_opt(2026, 3, 2).unwrap(),
+        )
+        .unwrap();
+
+        let (trades, open) = replay_trades(&fills, &settlements, range.end());
+        assert!(open.is_empty());
+        let events: Vec<_> = trades.iter().map(|t| t.event).collect();
+        assert_eq!(
+            events,
+            vec![TradeEvent::Buy, TradeEvent::Sell, TradeEvent::Settlement]
+        );
+        assert_eq!(trades[0].net_cash(), dec!(-4.02));
+        assert_eq!(trades[1].realized_pnl, Some(dec!(0.80)));
+        assert_eq!(trades[2].shares, dec!(6));
+        assert_eq!(trades[2].realized_pnl, Some(dec!(3.60)));
+
+        // Before resolution the remainder is still an open position
+        let (_, open) = replay_trades(&fills, &settlements, trades[2].at);
+        assert_eq!(open.len(), 1);
+        assert_eq!(open[0].cost, dec!(2.40));
+    }
+
+    #[test]
+    fn test_csv_formatting_is_stable() {
+        let range = ExportRange::new(
+            NaiveDate::from_ymd_opt(2026, 3, 2).unwrap(),
+            NaiveDate::from_ymd_opt(2026, 3, 2).unwrap(),
+        )
+        .unwrap();
+        let mut buy = fill(7, true, dec!(10.000), dec!(0.4500));
+        buy.market_slug = "will \"x\", or y?".into();
+        buy.order_id = Some("=HYPERLINK(1)".into());
+        let (trades, _) = replay_trades(&[buy], &HashMap::new(), range.end());
+
+        let mut out = Vec::new();
+        trades_table(&trades, &range).write_csv(&mut out).unwrap();
+        let csv = String::from_utf8(out).unwrap();
+        let lines: Vec<&str> = csv.lines().collect();
+        assert_eq!(
+            lines[0],
+            "timestamp_utc,account_id,agent_id,domain,market_slug,token_id,outcome_side,event,\
+             shares,price,notional_usd,fee_usd,gas_usd,net_cash_usd,realized_pnl_usd,order_id,\
+             intent_id"
+        );
+        assert_eq!(
+            lines[1],
+            "2026-03-02 14:07:00,default,crypto,crypto,\"will \"\"x\"\", or y?\",tok,UP,BUY,\
+             10,0.45,4.5,0.02,0,-4.52,,'=HYPERLINK(1),00000000-0000-0000-0000-000000000000"
+        );
+        assert_eq!(ExportCell::Number(dec!(-0.000)).to_csv(), "0");
+        assert_eq!(ExportCell::Float(0.5).to_csv(), "0.500000");
+        assert!(
+            (excel_serial(Utc.with_ymd_and_hms(2026, 1, 1, 12, 0, 0).unwrap()) - 46023.5).abs()
+                < 1e-9
+        );
+    }
+}
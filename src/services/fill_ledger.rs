@@ -0,0 +1,648 @@
+//! Exactly-once fill ingestion
+//!
+//! The same exchange trade can reach us more than once: order-status polls
+//! and WebSocket user events overlap, and a retried poll replays trades it
+//! already returned. Every fill is keyed by (exchange trade id, order id) and
+//! passed through [`FillLedger::ingest`]; callers account only the
+//! [`FillDelta`] of `New` and `Amended` outcomes, never the exchange's
+//! cumulative matched size. The seen-set is persisted in `ingested_fills`, so
+//! a restart does not replay fills either.
+//!
+//! A repeat of a known key with a different size or price is not dropped:
+//! the latest report wins and the caller gets the share delta to apply.
+//! [`FillLedger::reconcile_order`] compares the ledger total for an order
+//! against the exchange's cumulative matched size.
+//!
+//! In memory, fills are grouped by order and kept for the retention window
+//! that [`FillLedger::warm_start`] reloads; older orders fall back to the
+//! persisted seen-set.
+
+use chrono::{DateTime, Duration, Utc};
+use rust_decimal::Decimal;
+use serde::Serialize;
+use sqlx::PgPool;
+use std::collections::HashMap;
+use std::ops::AddAssign;
+use std::sync::atomic::{AtomicU64, Ordering};
+use std::sync::Arc;
+use tokio::sync::Mutex;
+use tracing::{debug, warn};
+
+use crate::adapters::polymarket_clob::{TradeInfo, TradeResponse};
+use crate::error::Result;
+use crate::services::Metrics;
+
+/// Where a fill report came from
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize)]
+#[serde(rename_all = "snake_case")]
+pub enum FillSource {
+    /// Order-status or trades REST poll
+    Poll,
+    /// WebSocket user channel
+    WebSocket,
+    /// Startup / periodic reconciliation
+    Reconcile,
+}
+
+impl FillSource {
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            Self::Poll => "poll",
+            Self::WebSocket => "websocket",
+            Self::Reconcile => "reconcile",
+        }
+    }
+}
+
+/// Dedupe key of one fill
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub struct FillKey {
+    pub trade_id: String,
+    pub order_id: String,
+}
+
+/// One fill as reported by the exchange
+#[derive(Debug, Clone)]
+pub struct ReportedFill {
+    pub key: FillKey,
+    pub token_id: String,
+    pub side: String,
+    pub price: Decimal,
+    pub shares: Decimal,
+    pub traded_at: DateTime<Utc>,
+    pub source: FillSource,
+}
+
+fn parse_time(raw: Option<&str>) -> DateTime<Utc> {
+    raw.and_then(|s| {
+        DateTime::parse_from_rfc3339(s)
+            .map(|t| t.with_timezone(&Utc))
+            .ok()
+            .or_else(|| {
+                s.parse::<i64>()
+                    .ok()
+                    .and_then(|secs| DateTime::from_timestamp(secs, 0))
+            })
+    })
+    .unwrap_or_else(Utc::now)
+}
+
+impl ReportedFill {
+    /// From a trades-endpoint row; `None` without ids or a parsable amount.
+    pub fn from_trade_response(trade: &TradeResponse, source: FillSource) -> Option<Self> {
+        Some(Self {
+            key: FillKey {
+                trade_id: trade.id.clone().filter(|s| !s.is_empty())?,
+                order_id: trade.order_id.clone().filter(|s| !s.is_empty())?,
+            },
+            token_id: trade.asset_id.clone(),
+            side: trade.side.clone(),
+            price: trade.price.parse().ok()?,
+            shares: trade.size.parse().ok()?,
+            traded_at: parse_time(trade.timestamp.as_deref()),
+            source,
+        })
+    }
+
+    /// From a trade attached to an order-status response for `order_id`.
+    pub fn from_trade_info(order_id: &str, trade: &TradeInfo, source: FillSource) -> Option<Self> {
+        if trade.id.is_empty() || order_id.is_empty() {
+            return None;
+        }
+        Some(Self {
+            key: FillKey {
+                trade_id: trade.id.clone(),
+                order_id: order_id.to_string(),
+            },
+            token_id: trade.asset_id.clone(),
+            side: trade.side.clone(),
+            price: trade.price.parse().ok()?,
+            shares: trade.size.parse().ok()?,
+            traded_at: parse_time(Some(&trade.match_time)),
+            source,
+        })
+    }
+}
+
+/// Result of ingesting one fill report
+#[derive(Debug, Clone, PartialEq)]
+pub enum FillOutcome {
+    /// First report of this fill; count it
+    New,
+    /// Already counted with the same amount; drop it
+    Duplicate,
+    /// Already counted with a different amount; apply `shares_delta`
+    Amended {
+        previous_shares: Decimal,
+        previous_price: Decimal,
+        shares_delta: Decimal,
+    },
+}
+
+impl FillOutcome {
+    /// Whether downstream accounting must act on this report
+    pub fn is_actionable(&self) -> bool {
+        !matches!(self, Self::Duplicate)
+    }
+}
+
+/// Shares and notional counted by the ledger
+#[derive(Debug, Clone, Copy, Default, PartialEq, Serialize)]
+pub struct FillDelta {
+    pub shares: Decimal,
+    pub notional: Decimal,
+}
+
+impl FillDelta {
+    /// What `outcome` adds to the ledger for `fill`
+    pub fn of(outcome: &FillOutcome, fill: &ReportedFill) -> Self {
+        match outcome {
+            FillOutcome::New => Self {
+                shares: fill.shares,
+                notional: fill.shares * fill.price,
+            },
+            FillOutcome::Duplicate => Self::default(),
+            FillOutcome::Amended {
+                previous_shares,
+                previous_price,
+                shares_delta,
+            } => Self {
+                shares: *shares_delta,
+                notional: fill.shares * fill.price - previous_shares * previous_price,
+            },
+        }
+    }
+
+    pub fn is_zero(&self) -> bool {
+        self.shares.is_zero() && self.notional.is_zero()
+    }
+
+    /// Share-weighted price, when any shares were counted
+    pub fn avg_price(&self) -> Option<Decimal> {
+        (self.shares > Decimal::ZERO).then(|| self.notional / self.shares)
+    }
+}
+
+impl AddAssign for FillDelta {
+    fn add_assign(&mut self, other: Self) {
+        self.shares += other.shares;
+        self.notional += other.notional;
+    }
+}
+
+/// Outcome counts and counted delta of one order read
+#[derive(Debug, Clone, Copy, Default, PartialEq)]
+pub struct OrderIngest {
+    pub delta: FillDelta,
+    pub new: u64,
+    pub duplicates: u64,
+    pub amended: u64,
+    /// Reports that could not be recorded and were not counted
+    pub failed: u64,
+}
+
+/// Ledger total for an order disagreeing with the exchange
+#[derive(Debug, Clone, PartialEq, Serialize)]
+pub struct FillMismatch {
+    pub order_id: String,
+    pub ledger_shares: Decimal,
+    pub exchange_shares: Decimal,
+}
+
+#[derive(Debug, Clone, Copy, Default, Serialize)]
+pub struct FillLedgerStats {
+    pub ingested: u64,
+    pub duplicates_prevented: u64,
+    pub amendments: u64,
+    pub mismatches: u64,
+}
+
+#[derive(Debug, Clone, Copy)]
+struct SeenFill {
+    price: Decimal,
+    shares: Decimal,
+    traded_at: DateTime<Utc>,
+}
+
+/// How often the in-memory seen-set is swept for expired orders
+const EVICT_INTERVAL_SECS: i64 = 60;
+
+/// In-memory seen-set: order id -> trade id -> fill
+#[derive(Debug, Default)]
+struct SeenFills {
+    orders: HashMap<String, HashMap<String, SeenFill>>,
+    evicted_at: Option<DateTime<Utc>>,
+}
+
+impl SeenFills {
+    fn get(&self, key: &FillKey) -> Option<SeenFill> {
+        self.orders
+            .get(&key.order_id)
+            .and_then(|trades| trades.get(&key.trade_id))
+            .copied()
+    }
+
+    fn insert(&mut self, key: &FillKey, fill: SeenFill) {
+        self.orders
+            .entry(key.order_id.clone())
+            .or_default()
+            .insert(key.trade_id.clone(), fill);
+    }
+
+    /// Drop orders whose every fill traded before `cutoff`
+    fn evict(&mut self, cutoff: DateTime<Utc>, now: DateTime<Utc>) {
+        self.orders
+            .retain(|_, trades| trades.values().any(|fill| fill.traded_at >= cutoff));
+        self.evicted_at = Some(now);
+    }
+}
+
+fn classify(previous: Option<SeenFill>, fill: &ReportedFill) -> FillOutcome {
+    match previous {
+        None => FillOutcome::New,
+        Some(seen) if seen.shares == fill.shares && seen.price == fill.price => {
+            FillOutcome::Duplicate
+        }
+        Some(seen) => FillOutcome::Amended {
+            previous_shares: seen.shares,
+            previous_price: seen.price,
+            shares_delta: fill.shares - seen.shares,
+        },
+    }
+}
+
+/// Seen-set of fills keyed by (trade id, order id)
+pub struct FillLedger {
+    /// Held across the persistence round-trip so concurrent sources
+    /// reporting the same fill are serialized.
+    seen: Mutex<SeenFills>,
+    /// How long fills stay in memory; also the warm-start window
+    retention: Duration,
+    pool: Option<PgPool>,
+    metrics: Option<Arc<Metrics>>,
+    ingested: AtomicU64,
+    duplicates_prevented: AtomicU64,
+    amendments: AtomicU64,
+    mismatches: AtomicU64,
+}
+
+impl Default for FillLedger {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl FillLedger {
+    /// In-memory ledger; add `with_pool` to persist the seen-set.
+    pub fn new() -> Self {
+        Self {
+            seen: Mutex::new(SeenFills::default()),
+            retention: Duration::hours(72),
+            pool: None,
+            metrics: None,
+            ingested: AtomicU64::new(0),
+            duplicates_prevented: AtomicU64::new(0),
+            amendments: AtomicU64::new(0),
+            mismatches: AtomicU64::new(0),
+        }
+    }
+
+    pub fn with_pool(mut self, pool: PgPool) -> Self {
+        self.pool = Some(pool);
+        self
+    }
+
+    /// Keep fills in memory (and reload them at warm start) for `retention`
+    pub fn with_retention(mut self, retention: Duration) -> Self {
+        self.retention = retention;
+        self
+    }
+
+    /// Count duplicates, amendments and mismatches in `metrics`.
+    pub fn with_metrics(mut self, metrics: Arc<Metrics>) -> Self {
+        self.metrics = Some(metrics);
+        self
+    }
+
+    /// Load fills traded within the retention window into the seen-set.
+    pub async fn warm_start(&self) -> Result<usize> {
+        let Some(pool) = &self.pool else {
+            return Ok(0);
+        };
+        let rows: Vec<(String, String, Decimal, Decimal, DateTime<Utc>)> = sqlx::query_as(
+            r#"
+            SELECT trade_id, order_id, price, shares, traded_at
+            FROM ingested_fills
+            WHERE traded_at >= $1
+            "#,
+        )
+        .bind(Utc::now() - self.retention)
+        .fetch_all(pool)
+        .await?;
+
+        let mut seen = self.seen.lock().await;
+        let loaded = rows.len();
+        for (trade_id, order_id, price, shares, traded_at) in rows {
+            seen.insert(
+                &FillKey { trade_id, order_id },
+                SeenFill {
+                    price,
+                    shares,
+                    traded_at,
+                },
+            );
+        }
+        Ok(loaded)
+    }
+
+    /// Record `fill` at most once and say what the caller should do with it.
+    pub async fn ingest(&self, fill: &ReportedFill) -> Result<FillOutcome> {
+        let mut seen = self.seen.lock().await;
+        let now = Utc::now();
+        let swept_recently = seen
+            .evicted_at
+            .is_some_and(|at| now - at < Duration::seconds(EVICT_INTERVAL_SECS));
+        if !swept_recently {
+            seen.evict(now - self.retention, now);
+        }
+
+        let mut previous = seen.get(&fill.key);
+        if previous.is_none() {
+            // Not in memory: it may still predate the warm-start window or
+            // have been recorded by another process.
+            previous = self.persist_new(fill).await?;
+        }
+
+        let outcome = classify(previous, fill);
+        match &outcome {
+            FillOutcome::New => {
+                self.ingested.fetch_add(1, Ordering::Relaxed);
+            }
+            FillOutcome::Duplicate => {
+                self.duplicates_prevented.fetch_add(1, Ordering::Relaxed);
+                if let Some(metrics) = &self.metrics {
+                    metrics.inc_fill_duplicates_prevented();
+                }
+                debug!(
+                    trade_id = %fill.key.trade_id,
+                    order_id = %fill.key.order_id,
+                    source = fill.source.as_str(),
+                    "duplicate fill report dropped"
+                );
+            }
+            FillOutcome::Amended {
+                previous_shares,
+                shares_delta,
+                ..
+            } => {
+                self.amendments.fetch_add(1, Ordering::Relaxed);
+                if let Some(metrics) = &self.metrics {
+                    metrics.inc_fill_amendments();
+                }
+                warn!(
+                    trade_id = %fill.key.trade_id,
+                    order_id = %fill.key.order_id,
+                    source = fill.source.as_str(),
+                    previous_shares = %previous_shares,
+                    shares = %fill.shares,
+                    shares_delta = %shares_delta,
+                    "fill amount changed between reports; applying latest"
+                );
+                self.persist_amendment(fill).await?;
+            }
+        }
+
+        seen.insert(
+            &fill.key,
+            SeenFill {
+                price: fill.price,
+                shares: fill.shares,
+                traded_at: fill.traded_at,
+            },
+        );
+        Ok(outcome)
+    }
+
+    /// Ingest the trades attached to one read of `order_id`. Reports that
+    /// fail to record are logged and left uncounted.
+    pub async fn ingest_order(
+        &self,
+        order_id: &str,
+        trades: &[TradeInfo],
+        source: FillSource,
+    ) -> OrderIngest {
+        let mut ingest = OrderIngest::default();
+        for trade in trades {
+            let Some(fill) = ReportedFill::from_trade_info(order_id, trade, source) else {
+                continue;
+            };
+            match self.ingest(&fill).await {
+                Ok(outcome) => {
+                    match outcome {
+                        FillOutcome::New => ingest.new += 1,
+                        FillOutcome::Duplicate => ingest.duplicates += 1,
+                        FillOutcome::Amended { .. } => ingest.amended += 1,
+                    }
+                    ingest.delta += FillDelta::of(&outcome, &fill);
+                }
+                Err(e) => {
+                    ingest.failed += 1;
+                    warn!(
+                        order_id,
+                        trade_id = %fill.key.trade_id,
+                        error = %e,
+                        "failed to ingest fill"
+                    );
+                }
+            }
+        }
+        ingest
+    }
+
+    /// Insert a new fill; returns the stored amount if the key already existed.
+    async fn persist_new(&self, fill: &ReportedFill) -> Result<Option<SeenFill>> {
+        let Some(pool) = &self.pool else {
+            return Ok(None);
+        };
+        let inserted: Option<(String,)> = sqlx::query_as(
+            r#"
+            INSERT INTO ingested_fills
+                (trade_id, order_id, token_id, side, price, shares, source, traded_at)
+            VALUES ($1, $2, $3, $4, $5, $6, $7, $8)
+            ON CONFLICT (trade_id, order_id) DO NOTHING
+            RETURNING trade_id
+            "#,
+        )
+        .bind(&fill.key.trade_id)
+        .bind(&fill.key.order_id)
+        .bind(&fill.token_id)
+        .bind(&fill.side)
+        .bind(fill.price)
+        .bind(fill.shares)
+        .bind(fill.source.as_str())
+        .bind(fill.traded_at)
+        .fetch_optional(pool)
+        .await?;
+        if inserted.is_some() {
+            return Ok(None);
+        }
+
+        let existing: Option<(Decimal, Decimal)> = sqlx::query_as(
+            "SELECT price, shares FROM ingested_fills WHERE trade_id = $1 AND order_id = $2",
+        )
+        .bind(&fill.key.trade_id)
+        .bind(&fill.key.order_id)
+        .fetch_optional(pool)
+        .await?;
+        Ok(existing.map(|(price, shares)| SeenFill { price, shares }))
+    }
+
+    async fn persist_amendment(&self, fill: &ReportedFill) -> Result<()> {
+        let Some(pool) = &self.pool else {
+            return Ok(());
+        };
+        sqlx::query(
+            r#"
+            UPDATE ingested_fills
+            SET price = $3,
+                shares = $4,
+                source = $5,
+                amendments = amendments + 1,
+                updated_at = NOW()
+            WHERE trade_id = $1 AND order_id = $2
+            "#,
+        )
+        .bind(&fill.key.trade_id)
+        .bind(&fill.key.order_id)
+        .bind(fill.price)
+        .bind(fill.shares)
+        .bind(fill.source.as_str())
+        .execute(pool)
+        .await?;
+        Ok(())
+    }
+
+    /// Shares and notional the ledger has counted for `order_id`
+    pub async fn order_fill(&self, order_id: &str) -> FillDelta {
+        let seen = self.seen.lock().await;
+        let mut total = FillDelta::default();
+        if let Some(trades) = seen.orders.get(order_id) {
+            for fill in trades.values() {
+                total += FillDelta {
+                    shares: fill.shares,
+                    notional: fill.shares * fill.price,
+                };
+            }
+        }
+        total
+    }
+
+    /// Compare the ledger total for `order_id` with the exchange's cumulative
+    /// matched size. A mismatch means a fill was missed or over-counted.
+    pub async fn reconcile_order(
+        &self,
+        order_id: &str,
+        exchange_shares: Decimal,
+    ) -> Option<FillMismatch> {
+        let ledger_shares = self.order_fill(order_id).await.shares;
+        if ledger_shares == exchange_shares {
+            return None;
+        }
+        self.mismatches.fetch_add(1, Ordering::Relaxed);
+        if let Some(metrics) = &self.metrics {
+            metrics.inc_fill_amount_mismatches();
+        }
+        warn!(
+            order_id = %order_id,
+            ledger_shares = %ledger_shares,
+            exchange_shares = %exchange_shares,
+            "ledger fills disagree with exchange matched size"
+        );
+        Some(FillMismatch {
+            order_id: order_id.to_string(),
+            ledger_shares,
+            exchange_shares,
+        })
+    }
+
+    pub fn stats(&self) -> FillLedgerStats {
+        FillLedgerStats {
+            ingested: self.ingested.load(Ordering::Relaxed),
+            duplicates_prevented: self.duplicates_prevented.load(Ordering::Relaxed),
+            amendments: self.amendments.load(Ordering::Relaxed),
+            mismatches: self.mismatches.load(Ordering::Relaxed),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use rust_decimal_macros::dec;
+
+    fn fill(trade_id: &str, shares: Decimal, source: FillSource) -> ReportedFill {
+        ReportedFill {
+            key: FillKey {
+                trade_id: trade_id.into(),
+                order_id: "order-1".into(),
+            },
+            token_id: "token".into(),
+            side: "BUY".into(),
+            price: dec!(0.42),
+            shares,
+            traded_at: Utc::now(),
+            source,
+        }
+    }
+
+    #[tokio::test]
+    async fn test_overlapping_reports_count_once() {
+        let metrics = Arc::new(Metrics::new());
+        let ledger = FillLedger::new().with_metrics(metrics.clone());
+
+        let polled = fill("t1", dec!(10), FillSource::Poll);
+        let streamed = fill("t1", dec!(10), FillSource::WebSocket);
+        assert_eq!(ledger.ingest(&polled).await.unwrap(), FillOutcome::New);
+        assert_eq!(
+            ledger.ingest(&streamed).await.unwrap(),
+            FillOutcome::Duplicate
+        );
+
+        let amended = fill("t1", dec!(12), FillSource::Poll);
+        let outcome = ledger.ingest(&amended).await.unwrap();
+        assert_eq!(
+            outcome,
+            FillOutcome::Amended {
+                previous_shares: dec!(10),
+                previous_price: dec!(0.42),
+                shares_delta: dec!(2),
+            }
+        );
+        assert_eq!(FillDelta::of(&outcome, &amended).shares, dec!(2));
+        ledger
+            .ingest(&fill("t2", dec!(5), FillSource::WebSocket))
+            .await
+            .unwrap();
+
+        assert_eq!(ledger.order_fill("order-1").await.shares, dec!(17));
+        assert!(ledger.reconcile_order("order-1", dec!(17)).await.is_none());
+        assert!(ledger.reconcile_order("order-1", dec!(20)).await.is_some());
+
+        let stats = ledger.stats();
+        assert_eq!(stats.ingested, 2);
+        assert_eq!(stats.duplicates_prevented, 1);
+        assert_eq!(stats.amendments, 1);
+        assert_eq!(stats.mismatches, 1);
+        assert_eq!(metrics.fill_duplicates_prevented.load(Ordering::Relaxed), 1);
+
+        // Orders with no fill inside the retention window leave memory
+        let mut stale = fill("t3", dec!(4), FillSource::Poll);
+        stale.key.order_id = "order-2".into();
+        stale.traded_at = Utc::now() - Duration::hours(100);
+        ledger.ingest(&stale).await.unwrap();
+        let now = Utc::now();
+        ledger.seen.lock().await.evict(now - ledger.retention, now);
+        assert!(ledger.order_fill("order-2").await.is_zero());
+        assert_eq!(ledger.order_fill("order-1").await.shares, dec!(17));
+    }
+}
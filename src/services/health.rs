@@ -323,6 +323,12 @@ async fn metrics_handler(State(state): State<Arc<HealthState>>) -> impl IntoResp
         } else {
             (0, 0, 0, 0)
         };
+    let (ws_rotations, ws_rotation_gap_ms) = state.metrics.as_ref().map_or((0, 0), |m| {
+        (
+            m.ws_rotations.load(Ordering::Relaxed),
+            m.ws_rotation_gap_ms_last.load(Ordering::Relaxed),
+        )
+    });
 
     // Get risk metrics
     let (daily_pnl, cycle_count, consecutive_failures) = if let Some(ref rm) = state.risk_manager {
@@ -338,7 +344,7 @@ async fn metrics_handler(State(state): State<Arc<HealthState>>) -> impl IntoResp
         HealthStatus::Unhealthy => -1,
     };
 
-    let metrics = format!(
+    let mut metrics = format!(
         r#"# HELP ploy_up Health status (1=healthy, 0=degraded, -1=unhealthy)
 # TYPE ploy_up gauge
 ploy_up {}
@@ -371,6 +377,14 @@ ploy_orders_filled_total {}
 # TYPE ploy_ws_reconnections_total counter
 ploy_ws_reconnections_total {}
 
+# HELP ploy_ws_rotations_total WebSocket subscription rotations without reconnect
+# TYPE ploy_ws_rotations_total counter
+ploy_ws_rotations_total {}
+
+# HELP ploy_ws_rotation_gap_ms Data gap of the last subscription rotation in milliseconds
+# TYPE ploy_ws_rotation_gap_ms gauge
+ploy_ws_rotation_gap_ms {}
+
 # HELP ploy_daily_pnl_usd Daily profit/loss in USD
 # TYPE ploy_daily_pnl_usd gauge
 ploy_daily_pnl_usd {}
@@ -391,10 +405,16 @@ ploy_consecutive_failures {}
         orders_submitted,
         orders_filled,
         ws_reconnections,
+        ws_rotations,
+        ws_rotation_gap_ms,
         daily_pnl,
         cycle_count,
         consecutive_failures,
     );
+    if let Some(ref m) = state.metrics {
+        metrics.push('\n');
+        metrics.push_str(&m.prometheus_execution());
+    }
 
     (
         StatusCode::OK,
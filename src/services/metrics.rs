@@ -1,7 +1,10 @@
 use crate::domain::StrategyState;
 use crate::strategy::RiskManager;
 use chrono::Utc;
+use std::collections::BTreeMap;
 use std::sync::atomic::{AtomicU64, Ordering};
+use std::sync::Mutex;
+use std::time::Duration;
 use tokio::sync::RwLock;
 use tracing::info;
 
@@ -15,6 +18,28 @@ pub struct Metrics {
     pub orders_filled: AtomicU64,
     /// WebSocket reconnections
     pub ws_reconnections: AtomicU64,
+    /// Orders throttled by notional-per-interval limits
+    pub orders_throttled: AtomicU64,
+    /// WebSocket subscription rotations applied without reconnecting
+    pub ws_rotations: AtomicU64,
+    /// Gap of the last rotation, until every new token produced data (ms)
+    pub ws_rotation_gap_ms_last: AtomicU64,
+    /// Sum of all rotation gaps (ms)
+    pub ws_rotation_gap_ms_total: AtomicU64,
+    /// Fill reports dropped as already ingested
+    pub fill_duplicates_prevented: AtomicU64,
+    /// Known fills re-reported with a different amount
+    pub fill_amendments: AtomicU64,
+    /// Orders whose ledger fills disagreed with the exchange matched size
+    pub fill_amount_mismatches: AtomicU64,
+    /// Passive (post-only / maker) orders that reached a terminal state
+    pub passive_orders_resolved: AtomicU64,
+    /// Shares posted by resolved passive orders
+    pub passive_shares_posted: AtomicU64,
+    /// Shares filled on resolved passive orders
+    pub passive_shares_filled: AtomicU64,
+    /// Live passive fill rate per (token, window seconds)
+    fillability: Mutex<BTreeMap<(String, u64), f64>>,
     /// Current state
     current_state: RwLock<String>,
     /// Last update timestamp
@@ -29,6 +54,17 @@ impl Metrics {
             orders_submitted: AtomicU64::new(0),
             orders_filled: AtomicU64::new(0),
             ws_reconnections: AtomicU64::new(0),
+            orders_throttled: AtomicU64::new(0),
+            ws_rotations: AtomicU64::new(0),
+            ws_rotation_gap_ms_last: AtomicU64::new(0),
+            ws_rotation_gap_ms_total: AtomicU64::new(0),
+            fill_duplicates_prevented: AtomicU64::new(0),
+            fill_amendments: AtomicU64::new(0),
+            fill_amount_mismatches: AtomicU64::new(0),
+            passive_orders_resolved: AtomicU64::new(0),
+            passive_shares_posted: AtomicU64::new(0),
+            passive_shares_filled: AtomicU64::new(0),
+            fillability: Mutex::new(BTreeMap::new()),
             current_state: RwLock::new("IDLE".to_string()),
             last_update: RwLock::new(Utc::now().timestamp()),
         }
@@ -54,6 +90,59 @@ impl Metrics {
         self.ws_reconnections.fetch_add(1, Ordering::Relaxed);
     }
 
+    /// Increment notional-throttled orders
+    pub fn inc_orders_throttled(&self) {
+        self.orders_throttled.fetch_add(1, Ordering::Relaxed);
+    }
+
+    /// Increment duplicate fill reports dropped
+    pub fn inc_fill_duplicates_prevented(&self) {
+        self.fill_duplicates_prevented
+            .fetch_add(1, Ordering::Relaxed);
+    }
+
+    /// Increment amended fill reports
+    pub fn inc_fill_amendments(&self) {
+        self.fill_amendments.fetch_add(1, Ordering::Relaxed);
+    }
+
+    /// Increment ledger / exchange fill amount mismatches
+    pub fn inc_fill_amount_mismatches(&self) {
+        self.fill_amount_mismatches.fetch_add(1, Ordering::Relaxed);
+    }
+
+    /// Count a resolved passive order
+    pub fn record_passive_outcome(&self, posted_shares: u64, filled_shares: u64) {
+        self.passive_orders_resolved.fetch_add(1, Ordering::Relaxed);
+        self.passive_shares_posted
+            .fetch_add(posted_shares, Ordering::Relaxed);
+        self.passive_shares_filled
+            .fetch_add(filled_shares, Ordering::Relaxed);
+    }
+
+    /// Set the live passive fill rate of a token over a window
+    pub fn set_fillability(&self, token_id: &str, window_secs: u64, fill_rate: f64) {
+        if let Ok(mut fillability) = self.fillability.lock() {
+            fillability.insert((token_id.to_string(), window_secs), fill_rate);
+        }
+    }
+
+    /// Drop a token's fill-rate gauges once it has no recent passive orders
+    pub fn clear_fillability(&self, token_id: &str) {
+        if let Ok(mut fillability) = self.fillability.lock() {
+            fillability.retain(|(token, _), _| token != token_id);
+        }
+    }
+
+    /// Record the data gap of a WebSocket subscription rotation
+    pub fn record_rotation_gap(&self, gap: Duration) {
+        let ms = u64::try_from(gap.as_millis()).unwrap_or(u64::MAX);
+        self.ws_rotations.fetch_add(1, Ordering::Relaxed);
+        self.ws_rotation_gap_ms_last.store(ms, Ordering::Relaxed);
+        self.ws_rotation_gap_ms_total
+            .fetch_add(ms, Ordering::Relaxed);
+    }
+
     /// Update current state
     pub async fn set_state(&self, state: StrategyState) {
         *self.current_state.write().await = state.to_string();
@@ -79,7 +168,7 @@ State: {} | Risk: {}
 Daily PnL: ${:.2} | Cycles: {} | Leg2 Rate: {:.1}%
 Consecutive Failures: {}
 Quote Updates: {} | Orders: {}/{}
-WS Reconnections: {}
+WS Reconnections: {} | Rotations: {} (last gap {}ms)
 ================================
 "#,
             state,
@@ -92,6 +181,8 @@ WS Reconnections: {}
             self.orders_filled.load(Ordering::Relaxed),
             self.orders_submitted.load(Ordering::Relaxed),
             self.ws_reconnections.load(Ordering::Relaxed),
+            self.ws_rotations.load(Ordering::Relaxed),
+            self.ws_rotation_gap_ms_last.load(Ordering::Relaxed),
         )
     }
 
@@ -99,7 +190,7 @@ WS Reconnections: {}
     pub async fn prometheus(&self, risk_manager: &RiskManager) -> String {
         let (daily_pnl, cycle_count, leg2_completions) = risk_manager.daily_stats().await;
 
-        format!(
+        let mut out = format!(
             r#"# HELP ploy_quote_updates_total Total quote updates processed
 # TYPE ploy_quote_updates_total counter
 ploy_quote_updates_total {}
@@ -116,6 +207,18 @@ ploy_orders_filled_total {}
 # TYPE ploy_ws_reconnections_total counter
 ploy_ws_reconnections_total {}
 
+# HELP ploy_ws_rotations_total WebSocket subscription rotations without reconnect
+# TYPE ploy_ws_rotations_total counter
+ploy_ws_rotations_total {}
+
+# HELP ploy_ws_rotation_gap_ms Data gap of the last subscription rotation in milliseconds
+# TYPE ploy_ws_rotation_gap_ms gauge
+ploy_ws_rotation_gap_ms {}
+
+# HELP ploy_ws_rotation_gap_ms_total Cumulative subscription rotation gap in milliseconds
+# TYPE ploy_ws_rotation_gap_ms_total counter
+ploy_ws_rotation_gap_ms_total {}
+
 # HELP ploy_daily_pnl_usd Daily profit/loss in USD
 # TYPE ploy_daily_pnl_usd gauge
 ploy_daily_pnl_usd {}
@@ -136,11 +239,76 @@ ploy_consecutive_failures {}
             self.orders_submitted.load(Ordering::Relaxed),
             self.orders_filled.load(Ordering::Relaxed),
             self.ws_reconnections.load(Ordering::Relaxed),
+            self.ws_rotations.load(Ordering::Relaxed),
+            self.ws_rotation_gap_ms_last.load(Ordering::Relaxed),
+            self.ws_rotation_gap_ms_total.load(Ordering::Relaxed),
             daily_pnl,
             cycle_count,
             leg2_completions,
             risk_manager.consecutive_failures(),
-        )
+        );
+
+        out.push('\n');
+        out.push_str(&self.prometheus_execution());
+        out
+    }
+
+    /// Execution counters (throttling, fill ledger, passive fillability) in
+    /// Prometheus format; these need no risk manager.
+    pub fn prometheus_execution(&self) -> String {
+        let mut out = format!(
+            r#"# HELP ploy_orders_throttled_total Orders throttled by notional limits
+# TYPE ploy_orders_throttled_total counter
+ploy_orders_throttled_total {}
+
+# HELP ploy_fill_duplicates_prevented_total Fill reports dropped as already ingested
+# TYPE ploy_fill_duplicates_prevented_total counter
+ploy_fill_duplicates_prevented_total {}
+
+# HELP ploy_fill_amendments_total Known fills re-reported with a different amount
+# TYPE ploy_fill_amendments_total counter
+ploy_fill_amendments_total {}
+
+# HELP ploy_fill_amount_mismatches_total Ledger fills disagreeing with exchange matched size
+# TYPE ploy_fill_amount_mismatches_total counter
+ploy_fill_amount_mismatches_total {}
+
+# HELP ploy_passive_orders_resolved_total Passive orders that reached a terminal state
+# TYPE ploy_passive_orders_resolved_total counter
+ploy_passive_orders_resolved_total {}
+
+# HELP ploy_passive_shares_posted_total Shares posted by resolved passive orders
+# TYPE ploy_passive_shares_posted_total counter
+ploy_passive_shares_posted_total {}
+
+# HELP ploy_passive_shares_filled_total Shares filled on resolved passive orders
+# TYPE ploy_passive_shares_filled_total counter
+ploy_passive_shares_filled_total {}
+"#,
+            self.orders_throttled.load(Ordering::Relaxed),
+            self.fill_duplicates_prevented.load(Ordering::Relaxed),
+            self.fill_amendments.load(Ordering::Relaxed),
+            self.fill_amount_mismatches.load(Ordering::Relaxed),
+            self.passive_orders_resolved.load(Ordering::Relaxed),
+            self.passive_shares_posted.load(Ordering::Relaxed),
+            self.passive_shares_filled.load(Ordering::Relaxed),
+        );
+
+        if let Ok(fillability) = self.fillability.lock() {
+            if !fillability.is_empty() {
+                out.push_str(
+                    "\n# HELP ploy_live_fillability Filled / posted shares of passive orders\n",
+                );
+                out.push_str("# TYPE ploy_live_fillability gauge\n");
+                for ((token_id, window_secs), rate) in fillability.iter() {
+                    out.push_str(&format!(
+                        "ploy_live_fillability{{token_id=\"{}\",window_secs=\"{}\"}} {}\n",
+                        token_id, window_secs, rate
+                    ));
+                }
+            }
+        }
+        out
     }
 
     /// Log periodic status
@@ -1,17 +1,28 @@
 pub mod data_collector;
+pub mod db_maintenance;
 pub mod discovery;
 pub mod event_edge_claude_framework;
 pub mod event_edge_event_driven;
+pub mod export;
+pub mod fill_ledger;
 pub mod health;
 pub mod metrics;
 pub mod order_monitor;
+pub mod reporting;
+pub mod smoke_trade;
+pub mod telemetry;
 
 pub use data_collector::DataCollector;
+pub use db_maintenance::{DbMaintenance, MaintenanceReport, RetentionPolicy};
 pub use discovery::DiscoveryService;
 pub use event_edge_claude_framework::EventEdgeClaudeFrameworkAgent;
 pub use event_edge_event_driven::EventEdgeEventDrivenAgent;
+pub use fill_ledger::{
+    FillDelta, FillKey, FillLedger, FillOutcome, FillSource, OrderIngest, ReportedFill,
+};
 pub use health::{ComponentHealth, HealthResponse, HealthServer, HealthState, HealthStatus};
 pub use metrics::Metrics;
 pub use order_monitor::{
     MonitorStats, OrderMonitor, OrderMonitorConfig, ReconciliationResult, TrackedOrder,
 };
+pub use reporting::{DailyReport, DailyReportConfig, DailyReportService, StrategyDayStats};
@@ -4,10 +4,13 @@
 //! - Orphaned order detection and cleanup
 //! - Order status reconciliation with exchange
 //! - Position tracking updates
+//! - Exactly-once ingestion of order trades (with a [`FillLedger`]); fill
+//!   updates then follow the ledger's deduplicated totals
 
 use crate::adapters::{PolymarketClient, PostgresStore};
 use crate::domain::OrderStatus;
 use crate::error::Result;
+use crate::services::fill_ledger::{FillLedger, FillSource};
 use chrono::{DateTime, Duration, Utc};
 use rust_decimal::prelude::ToPrimitive;
 use rust_decimal::Decimal;
@@ -64,6 +67,8 @@ pub struct MonitorStats {
     pub orders_cancelled: u64,
     pub orders_orphaned: u64,
     pub reconciliation_errors: u64,
+    pub fills_ingested: u64,
+    pub fill_duplicates_prevented: u64,
     pub last_check: Option<DateTime<Utc>>,
 }
 
@@ -78,6 +83,8 @@ pub struct OrderMonitor {
     running: Arc<AtomicBool>,
     /// Statistics
     stats: Arc<RwLock<MonitorStats>>,
+    /// Optional fill dedupe ledger
+    fill_ledger: Option<Arc<FillLedger>>,
 }
 
 impl OrderMonitor {
@@ -94,9 +101,17 @@ impl OrderMonitor {
             tracked_orders: Arc::new(RwLock::new(HashMap::new())),
             running: Arc::new(AtomicBool::new(false)),
             stats: Arc::new(RwLock::new(MonitorStats::default())),
+            fill_ledger: None,
         }
     }
 
+    /// Ingest each order's trades through `ledger` and reconcile them
+    /// against the exchange matched size.
+    pub fn with_fill_ledger(mut self, ledger: Arc<FillLedger>) -> Self {
+        self.fill_ledger = Some(ledger);
+        self
+    }
+
     /// Add an order to track
     pub async fn track_order(&self, order: TrackedOrder) {
         let mut orders = self.tracked_orders.write().await;
@@ -143,6 +158,7 @@ impl OrderMonitor {
         let tracked_orders = self.tracked_orders.clone();
         let running = self.running.clone();
         let stats = self.stats.clone();
+        let fill_ledger = self.fill_ledger.clone();
 
         tokio::spawn(async move {
             let mut interval =
@@ -157,6 +173,7 @@ impl OrderMonitor {
                     &config,
                     &tracked_orders,
                     &stats,
+                    fill_ledger.as_deref(),
                 )
                 .await
                 {
@@ -181,6 +198,7 @@ impl OrderMonitor {
         config: &OrderMonitorConfig,
         tracked_orders: &RwLock<HashMap<String, TrackedOrder>>,
         stats: &RwLock<MonitorStats>,
+        fill_ledger: Option<&FillLedger>,
     ) -> Result<()> {
         let now = Utc::now();
         let orphan_threshold = Duration::seconds(config.orphan_threshold_secs as i64);
@@ -215,6 +233,8 @@ impl OrderMonitor {
         let mut cancelled = 0u64;
         let mut orphaned = 0u64;
         let mut errors = 0u64;
+        let mut fills_ingested = 0u64;
+        let mut fill_duplicates = 0u64;
 
         for order in orders_to_check {
             checked += 1;
@@ -276,7 +296,27 @@ impl OrderMonitor {
                         let new_status = PolymarketClient::infer_order_status(&response);
                         let (filled_shares, avg_price) =
                             PolymarketClient::calculate_fill(&response);
-                        let filled_u64 = filled_shares.to_u64().unwrap_or(0);
+
+                        // Fill to record: with the ledger, its deduplicated total for
+                        // the order rather than the exchange's matched size
+                        let fill_update = match (fill_ledger, &response.associate_trades) {
+                            (Some(ledger), Some(trades)) if !trades.is_empty() => {
+                                let ingest = ledger
+                                    .ingest_order(exchange_id, trades, FillSource::Poll)
+                                    .await;
+                                fills_ingested += ingest.new;
+                                fill_duplicates += ingest.duplicates;
+                                errors += ingest.failed;
+                                ledger.reconcile_order(exchange_id, filled_shares).await;
+                                let total = ledger.order_fill(exchange_id).await;
+                                total.avg_price().map(|avg| (total.shares, avg))
+                            }
+                            _ => Some((filled_shares, avg_price)),
+                        };
+                        let fill_update = fill_update.and_then(|(shares, avg)| {
+                            let shares = shares.to_u64().filter(|s| *s > 0)?;
+                            (avg > Decimal::ZERO).then_some((shares, avg))
+                        });
 
                         if new_status != order.status {
                             info!(
@@ -306,12 +346,12 @@ impl OrderMonitor {
 
                                 // Update fill info whenever we can observe it.
                                 // This also persists partial fills for later reconciliation.
-                                if filled_u64 > 0 && avg_price > Decimal::ZERO {
+                                if let Some((shares, avg)) = fill_update {
                                     let _ = store
                                         .update_order_fill(
                                             &order.client_order_id,
-                                            filled_u64,
-                                            avg_price,
+                                            shares,
+                                            avg,
                                             new_status,
                                         )
                                         .await;
@@ -336,17 +376,15 @@ impl OrderMonitor {
                             }
 
                             // Status didn't change, but fills might have progressed (partial fills).
-                            if let Some(store) = store {
-                                if filled_u64 > 0 && avg_price > Decimal::ZERO {
-                                    let _ = store
-                                        .update_order_fill(
-                                            &order.client_order_id,
-                                            filled_u64,
-                                            avg_price,
-                                            new_status,
-                                        )
-                                        .await;
-                                }
+                            if let (Some(store), Some((shares, avg))) = (store, fill_update) {
+                                let _ = store
+                                    .update_order_fill(
+                                        &order.client_order_id,
+                                        shares,
+                                        avg,
+                                        new_status,
+                                    )
+                                    .await;
                             }
                         }
                     }
@@ -369,6 +407,8 @@ impl OrderMonitor {
             s.orders_cancelled += cancelled;
             s.orders_orphaned += orphaned;
             s.reconciliation_errors += errors;
+            s.fills_ingested += fills_ingested;
+            s.fill_duplicates_prevented += fill_duplicates;
             s.last_check = Some(now);
         }
 
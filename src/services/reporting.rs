@@ -0,0 +1,940 @@
+//! Daily PnL and risk reporting service
+//!
+//! At UTC day close this service replays the day's fills (plus any market
+//! settlements) per strategy and compiles:
+//! - Realized / unrealized PnL, fees and gas
+//! - Win rate over closed trades
+//! - Max drawdown of the intraday net PnL curve
+//! - Peak and closing exposure (open cost basis)
+//!
+//! The report is written to disk as Markdown + JSON, upserted into
+//! `daily_pnl_reports`, and a summary is pushed through the AlertManager.
+
+use crate::error::Result;
+use crate::supervisor::alert_manager::Alert;
+use crate::supervisor::{AlertLevel, AlertManager};
+use chrono::{DateTime, Duration, NaiveDate, Utc};
+use rust_decimal::prelude::ToPrimitive;
+use rust_decimal::Decimal;
+use serde::{Deserialize, Serialize};
+use sqlx::PgPool;
+use std::collections::{BTreeMap, HashMap, HashSet};
+use std::path::PathBuf;
+use std::sync::Arc;
+use tracing::{error, info, warn};
+
+const TOTAL_ROW: &str = "TOTAL";
+
+/// Configuration for the daily report service
+#[derive(Debug, Clone)]
+pub struct DailyReportConfig {
+    /// Restrict the report to one account (None = all accounts)
+    pub account_id: Option<String>,
+    /// Directory for `pnl-YYYY-MM-DD.{md,json}` files
+    pub output_dir: PathBuf,
+    /// How many days of fills before the report day are replayed to rebuild
+    /// the opening inventory
+    pub lookback_days: i64,
+    /// Include dry-run executions
+    pub include_dry_run: bool,
+    /// Delay after UTC midnight before compiling (lets late fills land)
+    pub close_delay_secs: u64,
+}
+
+impl Default for DailyReportConfig {
+    fn default() -> Self {
+        Self {
+            account_id: None,
+            output_dir: PathBuf::from("data/reports"),
+            lookback_days: 30,
+            include_dry_run: false,
+            close_delay_secs: 60,
+        }
+    }
+}
+
+impl DailyReportConfig {
+    /// Build from `PLOY_DAILY_REPORT_*` environment variables.
+    pub fn from_env() -> Self {
+        let mut cfg = Self::default();
+        if let Ok(dir) = std::env::var("PLOY_DAILY_REPORT_DIR") {
+            if !dir.trim().is_empty() {
+                cfg.output_dir = PathBuf::from(dir.trim());
+            }
+        }
+        if let Some(v) = env_parse::<i64>("PLOY_DAILY_REPORT_LOOKBACK_DAYS") {
+            cfg.lookback_days = v.max(0);
+        }
+        if let Some(v) = env_parse::<bool>("PLOY_DAILY_REPORT_INCLUDE_DRY_RUN") {
+            cfg.include_dry_run = v;
+        }
+        if let Some(v) = env_parse::<u64>("PLOY_DAILY_REPORT_DELAY_SECS") {
+            cfg.close_delay_secs = v;
+        }
+        cfg
+    }
+}
+
+fn env_parse<T: std::str::FromStr>(key: &str) -> Option<T> {
+    std::env::var(key).ok()?.trim().parse().ok()
+}
+
+/// A filled execution as loaded from `agent_order_executions`.
+#[derive(Debug, Clone)]
+pub struct ExecutionFill {
+    pub agent_id: String,
+    pub domain: String,
+    pub token_id: String,
+    pub is_buy: bool,
+    pub shares: Decimal,
+    pub price: Decimal,
+    pub fee_usd: Decimal,
+    pub gas_usd: Decimal,
+    pub executed_at: DateTime<Utc>,
+}
+
+/// A resolved token payout as loaded from `pm_token_settlements`.
+#[derive(Debug, Clone, Copy)]
+pub struct TokenSettlement {
+    pub settled_price: Decimal,
+    pub resolved_at: Option<DateTime<Utc>>,
+}
+
+/// Per-strategy statistics for one report day.
+#[derive(Debug, Clone, Default, Serialize, Deserialize, PartialEq)]
+pub struct StrategyDayStats {
+    pub strategy: String,
+    pub domain: String,
+    pub fills: u64,
+    pub buy_notional: Decimal,
+    pub sell_notional: Decimal,
+    pub realized_pnl: Decimal,
+    pub unrealized_pnl: Decimal,
+    pub fees: Decimal,
+    pub gas: Decimal,
+    pub net_pnl: Decimal,
+    pub wins: u64,
+    pub losses: u64,
+    pub win_rate: Option<f64>,
+    pub max_drawdown: Decimal,
+    pub peak_exposure: Decimal,
+    pub open_exposure: Decimal,
+    /// Open positions with no closing mark (valued at cost)
+    pub unmarked_positions: u64,
+}
+
+impl StrategyDayStats {
+    fn new(strategy: &str, domain: &str) -> Self {
+        Self {
+            strategy: strategy.to_string(),
+            domain: domain.to_string(),
+            ..Default::default()
+        }
+    }
+
+    fn finalize(&mut self) {
+        self.net_pnl = self.realized_pnl + self.unrealized_pnl - self.fees - self.gas;
+        let closed = self.wins + self.losses;
+        self.win_rate = (closed > 0).then(|| self.wins as f64 / closed as f64);
+    }
+}
+
+/// Compiled daily report.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct DailyReport {
+    pub date: NaiveDate,
+    pub generated_at: DateTime<Utc>,
+    pub account_id: Option<String>,
+    pub strategies: Vec<StrategyDayStats>,
+    pub totals: StrategyDayStats,
+}
+
+impl DailyReport {
+    /// One-line summary used for the alert body.
+    pub fn summary_line(&self) -> String {
+        let t = &self.totals;
+        format!(
+            "net {} (realized {}, unrealized {}, fees {}, gas {}) | win rate {} | max DD {} | peak exposure {} | {} strategies",
+            t.net_pnl.round_dp(2),
+            t.realized_pnl.round_dp(2),
+            t.unrealized_pnl.round_dp(2),
+            t.fees.round_dp(2),
+            t.gas.round_dp(2),
+            fmt_win_rate(t.win_rate),
+            t.max_drawdown.round_dp(2),
+            t.peak_exposure.round_dp(2),
+            self.strategies.len(),
+        )
+    }
+
+    /// Render as a Markdown document.
+    pub fn to_markdown(&self) -> String {
+        let mut out = format!("# Daily PnL & Risk Report — {}\n\n", self.date);
+        out.push_str(&format!(
+            "Generated at {} UTC",
+            self.generated_at.format("%Y-%m-%d %H:%M:%S")
+        ));
+        if let Some(account) = &self.account_id {
+            out.push_str(&format!(" · account `{account}`"));
+        }
+        out.push_str("\n\n## Summary\n\n");
+        out.push_str(&format!("{}\n\n", self.summary_line()));
+        out.push_str("## Strategies\n\n");
+        out.push_str(
+            "| Strategy | Domain | Fills | Realized | Unrealized | Fees | Gas | Net | Win rate | Max DD | Peak exp | Open exp |\n",
+        );
+        out.push_str("|---|---|---:|---:|---:|---:|---:|---:|---:|---:|---:|---:|\n");
+        for row in self.strategies.iter().chain(std::iter::once(&self.totals)) {
+            out.push_str(&format!(
+                "| {} | {} | {} | {} | {} | {} | {} | {} | {} | {} | {} | {} |\n",
+                row.strategy,
+                row.domain,
+                row.fills,
+                row.realized_pnl.round_dp(2),
+                row.unrealized_pnl.round_dp(2),
+                row.fees.round_dp(2),
+                row.gas.round_dp(2),
+                row.net_pnl.round_dp(2),
+                fmt_win_rate(row.win_rate),
+                row.max_drawdown.round_dp(2),
+                row.peak_exposure.round_dp(2),
+                row.open_exposure.round_dp(2),
+            ));
+        }
+        let unmarked: u64 = self.strategies.iter().map(|s| s.unmarked_positions).sum();
+        if unmarked > 0 {
+            out.push_str(&format!(
+                "\n_{unmarked} open position(s) had no closing quote and are valued at cost._\n"
+            ));
+        }
+        out
+    }
+}
+
+fn fmt_win_rate(rate: Option<f64>) -> String {
+    rate.map(|r| format!("{:.1}%", r * 100.0))
+        .unwrap_or_else(|| "n/a".to_string())
+}
+
+/// Average-cost inventory of one token (shared with the accounting export)
+#[derive(Debug, Default, Clone, Copy)]
+pub(crate) struct Lot {
+    pub(crate) shares: Decimal,
+    pub(crate) cost: Decimal,
+}
+
+impl Lot {
+    fn avg(&self) -> Decimal {
+        if self.shares.is_zero() {
+            Decimal::ZERO
+        } else {
+            self.cost / self.shares
+        }
+    }
+
+    /// Close up to `shares` at `price`, returning (closed shares, realized PnL).
+    pub(crate) fn close(&mut self, shares: Decimal, price: Decimal) -> (Decimal, Decimal) {
+        let qty = shares.min(self.shares);
+        if qty <= Decimal::ZERO {
+            return (Decimal::ZERO, Decimal::ZERO);
+        }
+        let avg = self.avg();
+        self.shares -= qty;
+        self.cost = if self.shares.is_zero() {
+            Decimal::ZERO
+        } else {
+            self.cost - avg * qty
+        };
+        (qty, qty * (price - avg))
+    }
+}
+
+/// A fill with an execution time, replayable alongside settlements
+pub trait TimedFill {
+    fn executed_at(&self) -> DateTime<Utc>;
+}
+
+impl TimedFill for ExecutionFill {
+    fn executed_at(&self) -> DateTime<Utc> {
+        self.executed_at
+    }
+}
+
+/// One step of a fill/settlement replay
+pub enum ReplayEvent<'a, F> {
+    Fill(&'a F),
+    Settle { token_id: &'a str, price: Decimal },
+}
+
+/// Fills and settlements before `until` as one time-ordered stream.
+/// Settlements without a resolution time are placed at `unresolved_at`, or
+/// skipped when it is `None`.
+pub fn replay_events<'a, F: TimedFill>(
+    fills: &'a [F],
+    settlements: &'a HashMap<String, TokenSettlement>,
+    until: DateTime<Utc>,
+    unresolved_at: Option<DateTime<Utc>>,
+) -> Vec<(DateTime<Utc>, ReplayEvent<'a, F>)> {
+    let mut events: Vec<(DateTime<Utc>, ReplayEvent<'a, F>)> = fills
+        .iter()
+        .filter(|f| f.executed_at() < until)
+        .map(|f| (f.executed_at(), ReplayEvent::Fill(f)))
+        .collect();
+    for (token_id, settlement) in settlements {
+        match settlement.resolved_at.or(unresolved_at) {
+            Some(at) if at < until => events.push((
+                at,
+                ReplayEvent::Settle {
+                    token_id: token_id.as_str(),
+                    price: settlement.settled_price,
+                },
+            )),
+            _ => {}
+        }
+    }
+    // Stable sort keeps fills ahead of a settlement stamped at the same instant.
+    events.sort_by_key(|(at, _)| *at);
+    events
+}
+
+#[derive(Default)]
+struct Curve {
+    equity: Decimal,
+    peak: Decimal,
+    max_drawdown: Decimal,
+}
+
+impl Curve {
+    fn step(&mut self, delta: Decimal) {
+        self.equity += delta;
+        self.peak = self.peak.max(self.equity);
+        self.max_drawdown = self.max_drawdown.max(self.peak - self.equity);
+    }
+}
+
+/// Build a report from fills (lookback + report day, any order), resolved
+/// settlements and closing marks. Pure: no I/O.
+pub fn build_daily_report(
+    date: NaiveDate,
+    account_id: Option<String>,
+    fills: &[ExecutionFill],
+    settlements: &HashMap<String, TokenSettlement>,
+    marks: &HashMap<String, Decimal>,
+) -> DailyReport {
+    let (day_start, day_end) = day_bounds(date);
+
+    // Settlements without a timestamp are applied at day close.
+    let events = replay_events(
+        fills,
+        settlements,
+        day_end,
+        Some(day_end - Duration::milliseconds(1)),
+    );
+
+    let mut lots: HashMap<(String, String), Lot> = HashMap::new();
+    let mut stats: BTreeMap<String, StrategyDayStats> = BTreeMap::new();
+    let mut curves: HashMap<String, Curve> = HashMap::new();
+    let mut total_curve = Curve::default();
+    let mut total_peak_exposure = Decimal::ZERO;
+    let mut opened = false;
+
+    let exposure_of = |lots: &HashMap<(String, String), Lot>, agent: Option<&str>| -> Decimal {
+        lots.iter()
+            .filter(|((a, _), _)| agent.is_none() || agent == Some(a.as_str()))
+            .map(|(_, lot)| lot.cost)
+            .sum()
+    };
+
+    for (at, event) in &events {
+        let in_day = *at >= day_start;
+        if in_day && !opened {
+            // Opening inventory counts toward the day's exposure peaks.
+            opened = true;
+            total_peak_exposure = exposure_of(&lots, None);
+            for (agent, _) in lots.keys() {
+                let open = exposure_of(&lots, Some(agent));
+                let domain = fills
+                    .iter()
+                    .find(|f| &f.agent_id == agent)
+                    .map(|f| f.domain.as_str())
+                    .unwrap_or_default();
+                let row = stats
+                    .entry(agent.clone())
+                    .or_insert_with(|| StrategyDayStats::new(agent, domain));
+                row.peak_exposure = row.peak_exposure.max(open);
+            }
+        }
+
+        let touched: Vec<(String, Decimal)> = match event {
+            ReplayEvent::Fill(fill) => {
+                let key = (fill.agent_id.clone(), fill.token_id.clone());
+                let lot = lots.entry(key).or_default();
+                let mut realized = Decimal::ZERO;
+                let mut closed = Decimal::ZERO;
+                if fill.is_buy {
+                    lot.shares += fill.shares;
+                    lot.cost += fill.shares * fill.price;
+                } else {
+                    (closed, realized) = lot.close(fill.shares, fill.price);
+                }
+                if in_day {
+                    let row = stats
+                        .entry(fill.agent_id.clone())
+                        .or_insert_with(|| StrategyDayStats::new(&fill.agent_id, &fill.domain));
+                    row.fills += 1;
+                    let notional = fill.shares * fill.price;
+                    if fill.is_buy {
+                        row.buy_notional += notional;
+                    } else {
+                        row.sell_notional += notional;
+                    }
+                    row.realized_pnl += realized;
+                    row.fees += fill.fee_usd;
+                    row.gas += fill.gas_usd;
+                    if closed > Decimal::ZERO {
+                        if realized > Decimal::ZERO {
+                            row.wins += 1;
+                        } else {
+                            row.losses += 1;
+                        }
+                    }
+                }
+                vec![(
+                    fill.agent_id.clone(),
+                    realized - fill.fee_usd - fill.gas_usd,
+                )]
+            }
+            ReplayEvent::Settle { token_id, price } => {
+                let mut deltas = Vec::new();
+                for ((agent, token), lot) in lots.iter_mut() {
+                    if token != token_id || lot.shares.is_zero() {
+                        continue;
+                    }
+                    let (_, realized) = lot.close(lot.shares, *price);
+                    if in_day {
+                        let domain = fills
+                            .iter()
+                            .find(|f| &f.agent_id == agent)
+                            .map(|f| f.domain.as_str())
+                            .unwrap_or_default();
+                        let row = stats
+                            .entry(agent.clone())
+                            .or_insert_with(|| StrategyDayStats::new(agent, domain));
+                        row.realized_pnl += realized;
+                        if realized > Decimal::ZERO {
+                            row.wins += 1;
+                        } else {
+                            row.losses += 1;
+                        }
+                    }
+                    deltas.push((agent.clone(), realized));
+                }
+                deltas
+            }
+        };
+        lots.retain(|_, lot| !lot.shares.is_zero());
+
+        if !in_day {
+            continue;
+        }
+        for (agent, delta) in touched {
+            let curve = curves.entry(agent.clone()).or_default();
+            curve.step(delta);
+            total_curve.step(delta);
+            let open = exposure_of(&lots, Some(&agent));
+            if let Some(row) = stats.get_mut(&agent) {
+                row.max_drawdown = curve.max_drawdown;
+                row.peak_exposure = row.peak_exposure.max(open);
+            }
+        }
+        total_peak_exposure = total_peak_exposure.max(exposure_of(&lots, None));
+    }
+    if !opened {
+        total_peak_exposure = exposure_of(&lots, None);
+    }
+
+    // Mark remaining inventory at the close.
+    for ((agent, token), lot) in &lots {
+        let domain = fills
+            .iter()
+            .find(|f| &f.agent_id == agent)
+            .map(|f| f.domain.as_str())
+            .unwrap_or_default();
+        let row = stats
+            .entry(agent.clone())
+            .or_insert_with(|| StrategyDayStats::new(agent, domain));
+        row.open_exposure += lot.cost;
+        row.peak_exposure = row.peak_exposure.max(row.open_exposure);
+        match marks.get(token) {
+            Some(mark) => row.unrealized_pnl += lot.shares * (*mark - lot.avg()),
+            None => row.unmarked_positions += 1,
+        }
+    }
+
+    let mut totals = StrategyDayStats::new(TOTAL_ROW, "");
+    let mut strategies: Vec<StrategyDayStats> = stats.into_values().collect();
+    for row in &mut strategies {
+        row.finalize();
+        totals.fills += row.fills;
+        totals.buy_notional += row.buy_notional;
+        totals.sell_notional += row.sell_notional;
+        totals.realized_pnl += row.realized_pnl;
+        totals.unrealized_pnl += row.unrealized_pnl;
+        totals.fees += row.fees;
+        totals.gas += row.gas;
+        totals.wins += row.wins;
+        totals.losses += row.losses;
+        totals.open_exposure += row.open_exposure;
+        totals.unmarked_positions += row.unmarked_positions;
+    }
+    totals.max_drawdown = total_curve.max_drawdown;
+    totals.peak_exposure = total_peak_exposure.max(totals.open_exposure);
+    totals.finalize();
+
+    DailyReport {
+        date,
+        generated_at: Utc::now(),
+        account_id,
+        strategies,
+        totals,
+    }
+}
+
+fn day_bounds(date: NaiveDate) -> (DateTime<Utc>, DateTime<Utc>) {
+    let start = date.and_hms_opt(0, 0, 0).unwrap_or_default().and_utc();
+    (start, start + Duration::days(1))
+}
+
+/// Ensure the `daily_pnl_reports` table exists.
+pub async fn ensure_daily_report_table(pool: &PgPool) -> Result<()> {
+    sqlx::query(
+        r#"
+        CREATE TABLE IF NOT EXISTS daily_pnl_reports (
+            report_date DATE NOT NULL,
+            account_id TEXT NOT NULL DEFAULT '',
+            generated_at TIMESTAMPTZ NOT NULL DEFAULT NOW(),
+            realized_pnl NUMERIC(20,6) NOT NULL,
+            unrealized_pnl NUMERIC(20,6) NOT NULL,
+            fees NUMERIC(20,6) NOT NULL,
+            gas NUMERIC(20,6) NOT NULL,
+            net_pnl NUMERIC(20,6) NOT NULL,
+            win_rate DOUBLE PRECISION,
+            max_drawdown NUMERIC(20,6) NOT NULL,
+            peak_exposure NUMERIC(20,6) NOT NULL,
+            report JSONB NOT NULL,
+            markdown TEXT NOT NULL,
+            PRIMARY KEY (report_date, account_id)
+        )
+        "#,
+    )
+    .execute(pool)
+    .await?;
+    Ok(())
+}
+
+/// Compiles and publishes the daily report.
+pub struct DailyReportService {
+    pool: PgPool,
+    config: DailyReportConfig,
+    alerts: Option<Arc<AlertManager>>,
+}
+
+impl DailyReportService {
+    pub fn new(pool: PgPool, config: DailyReportConfig) -> Self {
+        Self {
+            pool,
+            config,
+            alerts: None,
+        }
+    }
+
+    pub fn with_alerts(mut self, alerts: Arc<AlertManager>) -> Self {
+        self.alerts = Some(alerts);
+        self
+    }
+
+    /// Load fills, settlements and marks and compile the report for `date`.
+    pub async fn generate(&self, date: NaiveDate) -> Result<DailyReport> {
+        let (day_start, day_end) = day_bounds(date);
+        let from = day_start - Duration::days(self.config.lookback_days);
+
+        let rows = sqlx::query_as::<
+            _,
+            (
+                String,
+                String,
+                String,
+                bool,
+                Decimal,
+                Option<Decimal>,
+                Option<Decimal>,
+                Decimal,
+                Decimal,
+                DateTime<Utc>,
+            ),
+        >(
+            r#"
+            SELECT agent_id, domain, token_id, is_buy, filled_shares, avg_fill_price, limit_price,
+                   CASE WHEN metadata->>'fee_usd' ~ '^-?[0-9]+(\.[0-9]+)?$'
+                        THEN (metadata->>'fee_usd')::numeric ELSE 0 END AS fee_usd,
+                   CASE WHEN metadata->>'gas_cost_usd' ~ '^-?[0-9]+(\.[0-9]+)?$'
+                        THEN (metadata->>'gas_cost_usd')::numeric ELSE 0 END AS gas_usd,
+                   executed_at
+            FROM agent_order_executions
+            WHERE executed_at >= $1
+              AND executed_at < $2
+              AND filled_shares > 0
+              AND ($3::text IS NULL OR account_id = $3)
+              AND ($4 OR dry_run = FALSE)
+            ORDER BY executed_at ASC, id ASC
+            "#,
+        )
+        .bind(from)
+        .bind(day_end)
+        .bind(self.config.account_id.as_deref())
+        .bind(self.config.include_dry_run)
+        .fetch_all(&self.pool)
+        .await?;
+
+        let fills: Vec<ExecutionFill> = rows
+            .into_iter()
+            .filter_map(
+                |(agent_id, domain, token_id, is_buy, shares, avg, limit, fee, gas, at)| {
+                    Some(ExecutionFill {
+                        agent_id,
+                        domain,
+                        token_id,
+                        is_buy,
+                        shares,
+                        price: avg.or(limit)?,
+                        fee_usd: fee,
+                        gas_usd: gas,
+                        executed_at: at,
+                    })
+                },
+            )
+            .collect();
+
+        let tokens: Vec<String> = fills
+            .iter()
+            .map(|f| f.token_id.clone())
+            .collect::<HashSet<_>>()
+            .into_iter()
+            .collect();
+
+        let settlements: HashMap<String, TokenSettlement> =
+            sqlx::query_as::<_, (String, Decimal, Option<DateTime<Utc>>)>(
+                r#"
+            SELECT token_id, settled_price, resolved_at
+            FROM pm_token_settlements
+            WHERE resolved = TRUE AND settled_price IS NOT NULL AND token_id = ANY($1)
+            "#,
+            )
+            .bind(&tokens)
+            .fetch_all(&self.pool)
+            .await?
+            .into_iter()
+            .map(|(token, settled_price, resolved_at)| {
+                (
+                    token,
+                    TokenSettlement {
+                        settled_price,
+                        resolved_at,
+                    },
+                )
+            })
+            .collect();
+
+        let marks: HashMap<String, Decimal> = sqlx::query_as::<_, (String, Decimal)>(
+            r#"
+            SELECT DISTINCT ON (token_id) token_id, best_bid
+            FROM clob_quote_ticks
+            WHERE token_id = ANY($1) AND received_at < $2 AND best_bid IS NOT NULL
+            ORDER BY token_id, received_at DESC
+            "#,
+        )
+        .bind(&tokens)
+        .bind(day_end)
+        .fetch_all(&self.pool)
+        .await?
+        .into_iter()
+        .collect();
+
+        Ok(build_daily_report(
+            date,
+            self.config.account_id.clone(),
+            &fills,
+            &settlements,
+            &marks,
+        ))
+    }
+
+    /// Write `pnl-YYYY-MM-DD.md` and `.json` to the output directory.
+    pub async fn write_files(&self, report: &DailyReport) -> Result<PathBuf> {
+        tokio::fs::create_dir_all(&self.config.output_dir).await?;
+        let stem = match &self.config.account_id {
+            Some(account) => format!("pnl-{}-{}", account, report.date),
+            None => format!("pnl-{}", report.date),
+        };
+        let md_path = self.config.output_dir.join(format!("{stem}.md"));
+        tokio::fs::write(&md_path, report.to_markdown()).await?;
+        tokio::fs::write(
+            self.config.output_dir.join(format!("{stem}.json")),
+            serde_json::to_vec_pretty(report)?,
+        )
+        .await?;
+        Ok(md_path)
+    }
+
+    /// Upsert the report into `daily_pnl_reports`.
+    pub async fn persist(&self, report: &DailyReport) -> Result<()> {
+        let t = &report.totals;
+        sqlx::query(
+            r#"
+            INSERT INTO daily_pnl_reports (
+                report_date, account_id, generated_at, realized_pnl, unrealized_pnl, fees, gas,
+                net_pnl, win_rate, max_drawdown, peak_exposure, report, markdown
+            )
+            VALUES ($1, $2, $3, $4, $5, $6, $7, $8, $9, $10, $11, $12, $13)
+            ON CONFLICT (report_date, account_id) DO UPDATE SET
+                generated_at = EXCLUDED.generated_at,
+                realized_pnl = EXCLUDED.realized_pnl,
+                unrealized_pnl = EXCLUDED.unrealized_pnl,
+                fees = EXCLUDED.fees,
+                gas = EXCLUDED.gas,
+                net_pnl = EXCLUDED.net_pnl,
+                win_rate = EXCLUDED.win_rate,
+                max_drawdown = EXCLUDED.max_drawdown,
+                peak_exposure = EXCLUDED.peak_exposure,
+                report = EXCLUDED.report,
+                markdown = EXCLUDED.markdown
+            "#,
+        )
+        .bind(report.date)
+        .bind(report.account_id.clone().unwrap_or_default())
+        .bind(report.generated_at)
+        .bind(t.realized_pnl)
+        .bind(t.unrealized_pnl)
+        .bind(t.fees)
+        .bind(t.gas)
+        .bind(t.net_pnl)
+        .bind(t.win_rate)
+        .bind(t.max_drawdown)
+        .bind(t.peak_exposure)
+        .bind(serde_json::to_value(report)?)
+        .bind(report.to_markdown())
+        .execute(&self.pool)
+        .await?;
+        Ok(())
+    }
+
+    /// Generate, write, persist and announce the report for `date`.
+    pub async fn run_for(&self, date: NaiveDate) -> Result<DailyReport> {
+        let report = self.generate(date).await?;
+        let path = self.write_files(&report).await?;
+        self.persist(&report).await?;
+        info!(
+            "daily report {} written to {}: {}",
+            report.date,
+            path.display(),
+            report.summary_line()
+        );
+
+        if let Some(alerts) = &self.alerts {
+            let level = if report.totals.net_pnl < Decimal::ZERO {
+                AlertLevel::Warning
+            } else {
+                AlertLevel::Info
+            };
+            let alert = Alert::new(
+                level,
+                "reporting",
+                &format!("Daily PnL {}", report.date),
+                &report.summary_line(),
+            )
+            .with_metadata(serde_json::json!({
+                "report_date": report.date,
+                "net_pnl": report.totals.net_pnl.to_f64(),
+                "max_drawdown": report.totals.max_drawdown.to_f64(),
+                "path": path.display().to_string(),
+            }));
+            alerts.report(alert).await;
+        }
+        Ok(report)
+    }
+
+    /// Run forever, compiling the previous day's report shortly after each
+    /// UTC midnight.
+    pub async fn run(self) {
+        if let Err(e) = ensure_daily_report_table(&self.pool).await {
+            warn!("daily report table ensure failed: {}", e);
+        }
+        loop {
+            let now = Utc::now();
+            let delay = Duration::seconds(self.config.close_delay_secs as i64);
+            let mut next_close = day_bounds(now.date_naive()).0 + delay;
+            if next_close <= now {
+                next_close += Duration::days(1);
+            }
+            let wait = (next_close - now).to_std().unwrap_or_default();
+            tokio::time::sleep(wait).await;
+
+            let date = (next_close - Duration::days(1)).date_naive();
+            if let Err(e) = self.run_for(date).await {
+                error!("daily report for {} failed: {}", date, e);
+            }
+        }
+    }
+
+    pub fn spawn(self) -> tokio::task::JoinHandle<()> {
+        tokio::spawn(self.run())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use chrono::TimeZone;
+    use rust_decimal_macros::dec;
+
+    fn fill(
+        agent: &str,
+        token: &str,
+        buy: bool,
+        shares: Decimal,
+        price: Decimal,
+        hour: u32,
+        day: u32,
+    ) -> ExecutionFill {
+        ExecutionFill {
+            agent_id: agent.to_string(),
+            domain: "crypto".to_string(),
+            token_id: token.to_string(),
+            is_buy: buy,
+            shares,
+            price,
+            fee_usd: dec!(0.10),
+            gas_usd: Decimal::ZERO,
+            executed_at: Utc.with_ymd_and_hms(2026, 3, day, hour, 0, 0).unwrap(),
+        }
+    }
+
+    #[test]
+    fn replays_inventory_settlements_and_drawdown() {
+        let date = NaiveDate::from_ymd_opt(2026, 3, 2).unwrap();
+        let fills = vec![
+            // Opening inventory from the prior day.
+            fill("a", "t1", true, dec!(100), dec!(0.40), 20, 1),
+            // Day: lose on t1, then win on t2 via settlement.
+            fill("a", "t1", false, dec!(100), dec!(0.30), 1, 2),
+            fill("a", "t2", true, dec!(50), dec!(0.60), 2, 2),
+            fill("b", "t3", true, dec!(10), dec!(0.50), 3, 2),
+        ];
+        let mut settlements = HashMap::new();
+        settlements.insert(
+            "t2".to_string(),
+            TokenSettlement {
+                settled_price: dec!(1),
+                resolved_at: Some(Utc.with_ymd_and_hms(2026, 3, 2, 5, 0, 0).unwrap()),
+            },
+        );
+        let marks = HashMap::from([("t3".to_string(), dec!(0.55))]);
+
+        let report = build_daily_report(date, None, &fills, &settlements, &marks);
+        let a = report
+            .strategies
+            .iter()
+            .find(|s| s.strategy == "a")
+            .unwrap();
+        // -10 on t1, +20 on t2.
+        assert_eq!(a.realized_pnl, dec!(10));
+        assert_eq!(a.fills, 2);
+        assert_eq!((a.wins, a.losses), (1, 1));
+        assert_eq!(a.win_rate, Some(0.5));
+        // Curve: -10.10, -10.20, +9.80 → drawdown 10.20.
+        assert_eq!(a.max_drawdown, dec!(10.20));
+        assert_eq!(a.peak_exposure, dec!(40));
+        assert_eq!(a.open_exposure, Decimal::ZERO);
+
+        let b = report
+            .strategies
+            .iter()
+            .find(|s| s.strategy == "b")
+            .unwrap();
+        assert_eq!(b.unrealized_pnl, dec!(0.5));
+        assert_eq!(b.open_exposure, dec!(5));
+        assert_eq!(report.totals.net_pnl, dec!(10.2));
+    }
+
+    #[test]
+    fn markdown_lists_strategies_and_flags_unmarked_positions() {
+        let date = NaiveDate::from_ymd_opt(2026, 3, 2).unwrap();
+        let fills = vec![fill("a", "t1", true, dec!(10), dec!(0.5), 1, 2)];
+        let report = build_daily_report(
+            date,
+            Some("acct".into()),
+            &fills,
+            &HashMap::new(),
+            &HashMap::new(),
+        );
+        assert_eq!(report.totals.win_rate, None);
+        let md = report.to_markdown();
+        assert!(md.contains("2026-03-02"));
+        assert!(md.contains("| a | crypto | 1 |"));
+        assert!(md.contains("| TOTAL |"));
+        assert!(md.contains("1 open position(s) had no closing quote"));
+    }
+
+    #[test]
+    fn replay_events_orders_fills_before_same_instant_settlements() {
+        let fills = vec![
+            fill("a", "t1", true, dec!(10), dec!(0.5), 12, 2),
+            fill("a", "t1", false, dec!(5), dec!(0.6), 9, 2),
+            fill("a", "t1", true, dec!(1), dec!(0.5), 23, 2),
+        ];
+        let noon = Utc.with_ymd_and_hms(2026, 3, 2, 12, 0, 0).unwrap();
+        let until = Utc.with_ymd_and_hms(2026, 3, 2, 18, 0, 0).unwrap();
+        let settlements = HashMap::from([
+            (
+                "t1".to_string(),
+                TokenSettlement {
+                    settled_price: dec!(1),
+                    resolved_at: Some(noon),
+                },
+            ),
+            (
+                "t2".to_string(),
+                TokenSettlement {
+                    settled_price: dec!(0),
+                    resolved_at: None,
+                },
+            ),
+        ]);
+
+        let kinds = |events: &[(DateTime<Utc>, ReplayEvent<'_, ExecutionFill>)]| {
+            events
+                .iter()
+                .map(|(at, event)| match event {
+                    ReplayEvent::Fill(f) => format!("{} fill {}", at.format("%H"), f.shares),
+                    ReplayEvent::Settle { token_id, .. } => {
+                        format!("{} settle {}", at.format("%H"), token_id)
+                    }
+                })
+                .collect::<Vec<_>>()
+        };
+
+        let skipped = replay_events(&fills, &settlements, until, None);
+        assert_eq!(
+            kinds(&skipped),
+            vec!["09 fill 5", "12 fill 10", "12 settle t1"]
+        );
+
+        let at_close = replay_events(
+            &fills,
+            &settlements,
+            until,
+            Some(until - Duration::hours(1)),
+        );
+        assert_eq!(kinds(&at_close).last().unwrap(), "17 settle t2");
+    }
+}
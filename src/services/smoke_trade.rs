@@ -0,0 +1,579 @@
+//! Post-deploy smoke trade (`ploy verify live`)
+//!
+//! Places one minimal real round trip on a deep market to prove the live
+//! path works before strategies are switched on: L2 (HMAC) auth and balance
+//! read, a post-only resting order that is looked up and cancelled, a
+//! marketable BUY and the matching SELL, and finally the collateral balance
+//! moving by the round-trip cost. Orders go through the execution gateway
+//! entry point like coordinator orders, and every run is recorded in
+//! `live_verifications` whether it passes or not.
+
+use chrono::{DateTime, Duration as ChronoDuration, Utc};
+use rust_decimal::Decimal;
+use serde::Serialize;
+use sqlx::PgPool;
+use std::time::{Duration, Instant};
+use uuid::Uuid;
+
+use crate::adapters::polymarket_clob::{OrderBookLevel, PolymarketClient};
+use crate::domain::{OrderRequest, OrderStatus, Side};
+use crate::error::{PloyError, Result};
+use crate::exchange::ExchangeClient;
+
+/// Smallest order size the CLOB accepts
+pub const MIN_ORDER_SHARES: u64 = 5;
+/// Marketable orders must be worth at least $1
+const MIN_MARKETABLE_USD: Decimal = Decimal::ONE;
+/// Top-of-book size required on each side, in multiples of our order
+const DEPTH_MULTIPLE: u64 = 4;
+/// Widest spread (in price) we are willing to cross for the round trip
+const MAX_SPREAD: Decimal = Decimal::from_parts(3, 0, 0, false, 2);
+/// Distance below the best bid for the resting (never filled) order
+const REST_OFFSET: Decimal = Decimal::from_parts(10, 0, 0, false, 2);
+const ORDER_TIMEOUT: Duration = Duration::from_secs(15);
+const BALANCE_TIMEOUT: Duration = Duration::from_secs(30);
+const POLL_INTERVAL: Duration = Duration::from_millis(500);
+
+/// Token to trade and the round it belongs to
+#[derive(Debug, Clone)]
+pub struct SmokeCandidate {
+    pub token_id: String,
+    pub market_slug: Option<String>,
+    pub side: Side,
+}
+
+/// Prices and sizes of the round trip, sized from the live book
+#[derive(Debug, Clone, PartialEq)]
+pub struct RoundTripPlan {
+    pub shares: u64,
+    /// Best ask, paid by the marketable BUY
+    pub buy_price: Decimal,
+    /// Best bid, received by the marketable SELL
+    pub sell_price: Decimal,
+    /// Post-only BUY price well below the bid
+    pub rest_price: Decimal,
+}
+
+impl RoundTripPlan {
+    /// Cost of crossing the spread, before fees
+    pub fn expected_cost(&self) -> Decimal {
+        (self.buy_price - self.sell_price) * Decimal::from(self.shares)
+    }
+}
+
+fn parse_levels(levels: &[OrderBookLevel]) -> Vec<(Decimal, Decimal)> {
+    levels
+        .iter()
+        .filter_map(|l| Some((l.price.parse().ok()?, l.size.parse().ok()?)))
+        .collect()
+}
+
+/// Size the round trip so the BUY fits in `budget_usd` and both legs fill
+/// at the top of book without walking it.
+pub fn plan_round_trip(
+    bids: &[(Decimal, Decimal)],
+    asks: &[(Decimal, Decimal)],
+    budget_usd: Decimal,
+) -> Result<RoundTripPlan> {
+    let best_bid = bids.iter().max_by_key(|(price, _)| *price);
+    let best_ask = asks.iter().min_by_key(|(price, _)| *price);
+    let (Some(&(bid, bid_size)), Some(&(ask, ask_size))) = (best_bid, best_ask) else {
+        return Err(PloyError::Validation("one-sided book".to_string()));
+    };
+    if bid <= Decimal::ZERO || ask <= bid {
+        return Err(PloyError::Validation(format!(
+            "unusable top of book {} / {}",
+            bid, ask
+        )));
+    }
+    if ask - bid > MAX_SPREAD {
+        return Err(PloyError::Validation(format!(
+            "spread {} wider than {}",
+            ask - bid,
+            MAX_SPREAD
+        )));
+    }
+
+    let min_for_notional = (MIN_MARKETABLE_USD / ask)
+        .ceil()
+        .try_into()
+        .unwrap_or(u64::MAX);
+    let shares = MIN_ORDER_SHARES.max(min_for_notional);
+    let cost = ask * Decimal::from(shares);
+    if cost > budget_usd {
+        return Err(PloyError::Validation(format!(
+            "budget ${} is below the minimum round trip ${} ({} shares @ {})",
+            budget_usd, cost, shares, ask
+        )));
+    }
+    let needed = Decimal::from(shares * DEPTH_MULTIPLE);
+    if bid_size < needed || ask_size < needed {
+        return Err(PloyError::Validation(format!(
+            "book too thin: top of book {}/{} shares, need {}",
+            bid_size, ask_size, needed
+        )));
+    }
+
+    Ok(RoundTripPlan {
+        shares,
+        buy_price: ask,
+        sell_price: bid,
+        rest_price: (bid - REST_OFFSET).max(Decimal::new(1, 2)).round_dp(2),
+    })
+}
+
+/// One verified path
+#[derive(Debug, Clone, Serialize)]
+pub struct VerifyStep {
+    pub name: String,
+    pub passed: bool,
+    pub detail: String,
+    pub elapsed_ms: u128,
+}
+
+/// Outcome of one `ploy verify live` run
+#[derive(Debug, Clone, Serialize)]
+pub struct VerifyReport {
+    pub id: Uuid,
+    pub started_at: DateTime<Utc>,
+    pub finished_at: DateTime<Utc>,
+    pub budget_usd: Decimal,
+    pub token_id: Option<String>,
+    pub market_slug: Option<String>,
+    pub balance_before: Option<Decimal>,
+    pub balance_after: Option<Decimal>,
+    /// BUY cost minus SELL proceeds
+    pub net_cost_usd: Option<Decimal>,
+    /// Shares bought but not sold back (needs manual cleanup)
+    pub residual_shares: u64,
+    pub steps: Vec<VerifyStep>,
+}
+
+impl VerifyReport {
+    fn new(budget_usd: Decimal) -> Self {
+        let now = Utc::now();
+        Self {
+            id: Uuid::new_v4(),
+            started_at: now,
+            finished_at: now,
+            budget_usd,
+            token_id: None,
+            market_slug: None,
+            balance_before: None,
+            balance_after: None,
+            net_cost_usd: None,
+            residual_shares: 0,
+            steps: Vec::new(),
+        }
+    }
+
+    /// Record a step; returns whether it passed
+    fn record(&mut self, name: &str, started: Instant, outcome: Result<String>) -> bool {
+        let (passed, detail) = match outcome {
+            Ok(detail) => (true, detail),
+            Err(e) => (false, e.to_string()),
+        };
+        self.steps.push(VerifyStep {
+            name: name.to_string(),
+            passed,
+            detail,
+            elapsed_ms: started.elapsed().as_millis(),
+        });
+        passed
+    }
+
+    pub fn passed(&self) -> bool {
+        !self.steps.is_empty() && self.steps.iter().all(|s| s.passed) && self.residual_shares == 0
+    }
+
+    fn finish(mut self) -> Self {
+        self.finished_at = Utc::now();
+        self
+    }
+}
+
+/// Gateway-style ids so the order passes gateway-only mode checks
+fn tagged(mut request: OrderRequest, run: Uuid, leg: &str) -> OrderRequest {
+    request.client_order_id = format!("intent:verify-{}-{}", run, leg);
+    request.idempotency_key = Some(format!("verify:{}:{}", run, leg));
+    request
+}
+
+/// Poll an order until it reaches a terminal status or `ORDER_TIMEOUT`
+async fn wait_for_order(
+    client: &PolymarketClient,
+    order_id: &str,
+    done: impl Fn(OrderStatus, u64) -> bool,
+) -> Result<(OrderStatus, u64, Option<Decimal>)> {
+    let deadline = Instant::now() + ORDER_TIMEOUT;
+    loop {
+        let order = ExchangeClient::get_order(client, order_id).await?;
+        let status = PolymarketClient::infer_order_status(&order);
+        let (filled, avg_price) = ExchangeClient::calculate_fill(client, &order);
+        if done(status, filled) || Instant::now() >= deadline {
+            return Ok((status, filled, avg_price));
+        }
+        tokio::time::sleep(POLL_INTERVAL).await;
+    }
+}
+
+fn is_terminal(status: OrderStatus) -> bool {
+    matches!(
+        status,
+        OrderStatus::Filled | OrderStatus::Cancelled | OrderStatus::Rejected | OrderStatus::Expired
+    )
+}
+
+/// Deep, two-sided tokens of active rounds with time to spare, deepest first
+pub async fn find_candidates(pool: &PgPool) -> Result<Vec<SmokeCandidate>> {
+    let rows: Vec<(String, String, String)> = sqlx::query_as(
+        r#"
+        SELECT r.slug, q.token_id, q.side
+        FROM rounds r
+        JOIN LATERAL (
+            SELECT DISTINCT ON (token_id) token_id, side, best_bid, best_ask, bid_size, ask_size
+            FROM clob_quote_ticks
+            WHERE token_id IN (r.up_token_id, r.down_token_id)
+              AND received_at > NOW() - INTERVAL '2 minutes'
+            ORDER BY token_id, received_at DESC
+        ) q ON TRUE
+        WHERE r.start_time <= NOW() AND r.end_time > NOW() + INTERVAL '10 minutes'
+          AND q.best_bid IS NOT NULL AND q.best_ask IS NOT NULL
+          AND q.best_ask - q.best_bid <= $1
+          AND q.best_ask BETWEEN 0.15 AND 0.85
+        ORDER BY LEAST(COALESCE(q.bid_size, 0), COALESCE(q.ask_size, 0)) DESC
+        LIMIT 5
+        "#,
+    )
+    .bind(MAX_SPREAD)
+    .fetch_all(pool)
+    .await?;
+
+    Ok(rows
+        .into_iter()
+        .map(|(slug, token_id, side)| SmokeCandidate {
+            token_id,
+            market_slug: Some(slug),
+            side: if side == "DOWN" { Side::Down } else { Side::Up },
+        })
+        .collect())
+}
+
+/// Run the smoke round trip. Failures are recorded as steps; the returned
+/// error is reserved for setup problems (dry-run client, database).
+pub async fn run_smoke_trade(
+    client: &PolymarketClient,
+    pool: &PgPool,
+    budget_usd: Decimal,
+    token_id: Option<&str>,
+) -> Result<VerifyReport> {
+    if client.is_dry_run() {
+        return Err(PloyError::Validation(
+            "verify live needs an authenticated live client".to_string(),
+        ));
+    }
+    let mut report = VerifyReport::new(budget_usd);
+
+    // Auth: the balance endpoint requires L2 (HMAC) headers
+    let started = Instant::now();
+    let balance = client.get_usdc_balance().await;
+    let before = balance.as_ref().ok().copied();
+    report.balance_before = before;
+    if !report.record("auth", started, balance.map(|b| format!("balance ${}", b))) {
+        return Ok(report.finish());
+    }
+
+    // Market: first candidate whose live book supports the round trip
+    let started = Instant::now();
+    let candidates = match token_id {
+        Some(token_id) => vec![SmokeCandidate {
+            token_id: token_id.to_string(),
+            market_slug: None,
+            side: Side::Up,
+        }],
+        None => find_candidates(pool).await?,
+    };
+    let mut rejected = Vec::new();
+    let mut chosen = None;
+    for candidate in candidates {
+        let plan = client
+            .get_order_book(&candidate.token_id)
+            .await
+            .and_then(|book| {
+                plan_round_trip(
+                    &parse_levels(&book.bids),
+                    &parse_levels(&book.asks),
+                    budget_usd,
+                )
+            });
+        match plan {
+            Ok(plan) => {
+                chosen = Some((candidate, plan));
+                break;
+            }
+            Err(e) => rejected.push(format!("{}: {}", candidate.token_id, e)),
+        }
+    }
+    let market = chosen.ok_or_else(|| {
+        PloyError::Validation(if rejected.is_empty() {
+            "no deep active market found".to_string()
+        } else {
+            format!("no usable market ({})", rejected.join("; "))
+        })
+    });
+    let (candidate, plan) = match market {
+        Ok((candidate, plan)) => {
+            report.token_id = Some(candidate.token_id.clone());
+            report.market_slug = candidate.market_slug.clone();
+            report.record(
+                "market",
+                started,
+                Ok(format!(
+                    "{} shares, bid {} / ask {}, expected spread cost ${}",
+                    plan.shares,
+                    plan.sell_price,
+                    plan.buy_price,
+                    plan.expected_cost()
+                )),
+            );
+            (candidate, plan)
+        }
+        Err(e) => {
+            report.record("market", started, Err(e));
+            return Ok(report.finish());
+        }
+    };
+    let token = candidate.token_id.clone();
+
+    // Submission, status lookup and cancel on an order that cannot fill
+    let started = Instant::now();
+    let rest = tagged(
+        OrderRequest::buy_limit(
+            token.clone(),
+            candidate.side,
+            MIN_ORDER_SHARES,
+            plan.rest_price,
+        )
+        .good_till(Utc::now() + ChronoDuration::minutes(2))
+        .post_only(),
+        report.id,
+        "rest",
+    );
+    let submitted = client.submit_order_gateway(&rest).await;
+    let rest_id = submitted.as_ref().ok().map(|o| o.id.clone());
+    if !report.record(
+        "submit",
+        started,
+        submitted.map(|o| format!("order {} resting @ {}", o.id, plan.rest_price)),
+    ) {
+        return Ok(report.finish());
+    }
+    let rest_id = rest_id.unwrap_or_default();
+
+    let started = Instant::now();
+    let status = wait_for_order(client, &rest_id, |s, _| s != OrderStatus::Pending)
+        .await
+        .and_then(|(status, _, _)| match status {
+            OrderStatus::Submitted => Ok("order visible as live".to_string()),
+            other => Err(PloyError::Validation(format!(
+                "unexpected status {:?}",
+                other
+            ))),
+        });
+    report.record("status", started, status);
+
+    let started = Instant::now();
+    let cancelled = match ExchangeClient::cancel_order(client, &rest_id).await {
+        Ok(_) => wait_for_order(client, &rest_id, |s, _| is_terminal(s))
+            .await
+            .and_then(|(status, filled, _)| match (status, filled) {
+                (OrderStatus::Cancelled, 0) => Ok("order cancelled".to_string()),
+                (status, filled) => Err(PloyError::Validation(format!(
+                    "after cancel: status {:?}, {} filled",
+                    status, filled
+                ))),
+            }),
+        Err(e) => Err(e),
+    };
+    if !report.record("cancel", started, cancelled) {
+        return Ok(report.finish());
+    }
+
+    // Fill path: marketable BUY, then sell the same shares back
+    let started = Instant::now();
+    let buy = tagged(
+        OrderRequest::buy_limit(token.clone(), candidate.side, plan.shares, plan.buy_price)
+            .with_time_in_force(crate::domain::TimeInForce::IOC),
+        report.id,
+        "buy",
+    );
+    let bought = match client.submit_order_gateway(&buy).await {
+        Ok(order) => wait_for_order(client, &order.id, |s, filled| {
+            is_terminal(s) || filled >= plan.shares
+        })
+        .await
+        .and_then(|(_, filled, avg)| match filled {
+            0 => Err(PloyError::Validation("BUY did not fill".to_string())),
+            filled => Ok((filled, avg.unwrap_or(plan.buy_price))),
+        }),
+        Err(e) => Err(e),
+    };
+    let (bought_shares, buy_price) = match bought {
+        Ok((shares, price)) => {
+            report.record(
+                "fill_buy",
+                started,
+                Ok(format!("bought {} @ {}", shares, price)),
+            );
+            (shares, price)
+        }
+        Err(e) => {
+            report.record("fill_buy", started, Err(e));
+            return Ok(report.finish());
+        }
+    };
+    report.residual_shares = bought_shares;
+
+    let started = Instant::now();
+    let sell = tagged(
+        OrderRequest::sell_limit(
+            token.clone(),
+            candidate.side,
+            bought_shares,
+            plan.sell_price,
+        )
+        .with_time_in_force(crate::domain::TimeInForce::IOC),
+        report.id,
+        "sell",
+    );
+    let sold = match client.submit_order_gateway(&sell).await {
+        Ok(order) => wait_for_order(client, &order.id, |s, filled| {
+            is_terminal(s) || filled >= bought_shares
+        })
+        .await
+        .and_then(|(_, filled, avg)| match filled {
+            0 => Err(PloyError::Validation("SELL did not fill".to_string())),
+            filled => Ok((filled, avg.unwrap_or(plan.sell_price))),
+        }),
+        Err(e) => Err(e),
+    };
+    let sold = match sold {
+        Ok((shares, price)) => {
+            report.residual_shares = bought_shares.saturating_sub(shares);
+            let net_cost = buy_price * Decimal::from(bought_shares) - price * Decimal::from(shares);
+            report.net_cost_usd = Some(net_cost);
+            report.record(
+                "fill_sell",
+                started,
+                Ok(format!(
+                    "sold {} @ {}, net cost ${}",
+                    shares,
+                    price,
+                    net_cost.round_dp(4)
+                )),
+            );
+            true
+        }
+        Err(e) => {
+            report.record("fill_sell", started, Err(e));
+            false
+        }
+    };
+
+    // Balance: collateral must move by about the round-trip cost (fees and
+    // settlement lag allowed for)
+    if let (true, Some(before), Some(net_cost)) = (sold, before, report.net_cost_usd) {
+        let started = Instant::now();
+        let tolerance =
+            (buy_price * Decimal::from(bought_shares) * Decimal::new(3, 2)).max(Decimal::new(5, 2));
+        let deadline = Instant::now() + BALANCE_TIMEOUT;
+        let mut after = None;
+        loop {
+            if let Ok(balance) = client.get_usdc_balance().await {
+                after = Some(balance);
+                if balance != before && (before - balance - net_cost).abs() <= tolerance {
+                    break;
+                }
+            }
+            if Instant::now() >= deadline {
+                break;
+            }
+            tokio::time::sleep(POLL_INTERVAL).await;
+        }
+        report.balance_after = after;
+        let outcome = match after {
+            Some(after) if after != before && (before - after - net_cost).abs() <= tolerance => {
+                Ok(format!("balance ${} -> ${}", before, after))
+            }
+            Some(after) => Err(PloyError::Validation(format!(
+                "balance ${} -> ${}, expected a drop of about ${}",
+                before,
+                after,
+                net_cost.round_dp(4)
+            ))),
+            None => Err(PloyError::Validation("balance unavailable".to_string())),
+        };
+        report.record("balance", started, outcome);
+    }
+
+    Ok(report.finish())
+}
+
+/// Persist a run in `live_verifications`
+pub async fn save_report(pool: &PgPool, report: &VerifyReport) -> Result<()> {
+    sqlx::query(
+        r#"
+        INSERT INTO live_verifications (
+            id, started_at, finished_at, passed, budget_usd, token_id, market_slug,
+            balance_before, balance_after, net_cost_usd, residual_shares, steps
+        )
+        VALUES ($1, $2, $3, $4, $5, $6, $7, $8, $9, $10, $11, $12)
+        "#,
+    )
+    .bind(report.id)
+    .bind(report.started_at)
+    .bind(report.finished_at)
+    .bind(report.passed())
+    .bind(report.budget_usd)
+    .bind(&report.token_id)
+    .bind(&report.market_slug)
+    .bind(report.balance_before)
+    .bind(report.balance_after)
+    .bind(report.net_cost_usd)
+    .bind(report.residual_shares as i64)
+    .bind(serde_json::to_value(&report.steps)?)
+    .execute(pool)
+    .await?;
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use rust_decimal_macros::dec;
+
+    #[test]
+    fn test_plan_round_trip_sizing_and_guards() {
+        let bids = [(dec!(0.47), dec!(400)), (dec!(0.48), dec!(250))];
+        let asks = [(dec!(0.50), dec!(300)), (dec!(0.49), dec!(200))];
+
+        let plan = plan_round_trip(&bids, &asks, dec!(5)).unwrap();
+        assert_eq!(plan.shares, MIN_ORDER_SHARES);
+        assert_eq!(plan.buy_price, dec!(0.49));
+        assert_eq!(plan.sell_price, dec!(0.48));
+        assert_eq!(plan.rest_price, dec!(0.38));
+        assert_eq!(plan.expected_cost(), dec!(0.05));
+
+        // $1 marketable minimum drives the size on cheap tokens
+        let cheap = plan_round_trip(
+            &[(dec!(0.09), dec!(500))],
+            &[(dec!(0.10), dec!(500))],
+            dec!(5),
+        );
+        assert_eq!(cheap.unwrap().shares, 10);
+
+        assert!(plan_round_trip(&bids, &asks, dec!(2)).is_err());
+        assert!(plan_round_trip(&[(dec!(0.48), dec!(10))], &asks, dec!(5)).is_err());
+        assert!(plan_round_trip(&[(dec!(0.40), dec!(500))], &asks, dec!(5)).is_err());
+    }
+}
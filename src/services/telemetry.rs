@@ -0,0 +1,132 @@
+//! Trade lifecycle tracing (quote → signal → risk → order → fill).
+//!
+//! Spans: `quote` (StrategyEngine quote handling), `signal` (leg entry),
+//! `risk` (RiskGate::check_order) and `order` (OrderExecutor::execute, which
+//! covers submission and fill confirmation).
+//!
+//! Span names and the `cycle_id` / `trade_id` fields are always emitted via
+//! `tracing`, so they show up in regular logs. `trade_id` is the intent id on
+//! every span, so one trade can be followed from risk check to fill. With the
+//! `telemetry` feature the same spans are exported over OTLP (Jaeger, Tempo,
+//! ...) when `OTEL_EXPORTER_OTLP_ENDPOINT` is set.
+
+use tracing::Span;
+
+/// Prefix the coordinator puts on client order ids built from an intent
+const INTENT_ORDER_PREFIX: &str = "intent:";
+
+/// Trade id for an intent id or an `intent:<id>` client order id
+pub fn trade_id(id: &str) -> &str {
+    id.strip_prefix(INTENT_ORDER_PREFIX).unwrap_or(id)
+}
+
+/// Attach a cycle id to the current span (no-op if the span has no `cycle_id` field).
+pub fn record_cycle_id(cycle_id: i32) {
+    Span::current().record("cycle_id", cycle_id);
+}
+
+/// Attach a trade id (client order id / intent id) to the current span.
+pub fn record_trade_id(id: &str) {
+    Span::current().record("trade_id", trade_id(id));
+}
+
+/// OTLP exporter settings from the standard `OTEL_*` variables.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct OtlpConfig {
+    pub endpoint: String,
+    pub service_name: String,
+}
+
+impl OtlpConfig {
+    /// `None` unless `OTEL_EXPORTER_OTLP_ENDPOINT` is set
+    pub fn from_env() -> Option<Self> {
+        Self::from_vars(
+            std::env::var("OTEL_EXPORTER_OTLP_ENDPOINT").ok(),
+            std::env::var("OTEL_SERVICE_NAME").ok(),
+        )
+    }
+
+    fn from_vars(endpoint: Option<String>, service_name: Option<String>) -> Option<Self> {
+        let endpoint = endpoint.filter(|e| !e.trim().is_empty())?;
+        Some(Self {
+            endpoint,
+            service_name: service_name
+                .filter(|s| !s.trim().is_empty())
+                .unwrap_or_else(|| "ploy".to_string()),
+        })
+    }
+}
+
+#[cfg(feature = "telemetry")]
+mod otlp {
+    use super::OtlpConfig;
+    use opentelemetry::trace::{TraceError, TracerProvider as _};
+    use opentelemetry::KeyValue;
+    use opentelemetry_otlp::WithExportConfig;
+    use opentelemetry_sdk::{runtime, trace, Resource};
+    use tracing_subscriber::registry::LookupSpan;
+    use tracing_subscriber::Layer;
+
+    /// Build an OTLP export layer for `config`.
+    ///
+    /// Must be called from inside a Tokio runtime (the batch exporter spawns a task).
+    pub fn otlp_layer<S>(config: &OtlpConfig) -> Result<Box<dyn Layer<S> + Send + Sync>, TraceError>
+    where
+        S: tracing::Subscriber + for<'a> LookupSpan<'a> + Send + Sync,
+    {
+        let provider = opentelemetry_otlp::new_pipeline()
+            .tracing()
+            .with_exporter(
+                opentelemetry_otlp::new_exporter()
+                    .tonic()
+                    .with_endpoint(config.endpoint.clone()),
+            )
+            .with_trace_config(trace::Config::default().with_resource(Resource::new(vec![
+                KeyValue::new("service.name", config.service_name.clone()),
+            ])))
+            .install_batch(runtime::Tokio)?;
+
+        let tracer = provider.tracer("ploy");
+        opentelemetry::global::set_tracer_provider(provider);
+        Ok(tracing_opentelemetry::layer().with_tracer(tracer).boxed())
+    }
+
+    /// Flush pending spans before exit.
+    pub fn shutdown() {
+        opentelemetry::global::shutdown_tracer_provider();
+    }
+}
+
+#[cfg(feature = "telemetry")]
+pub use otlp::{otlp_layer, shutdown};
+
+/// Flush pending spans before exit (no-op without the `telemetry` feature).
+#[cfg(not(feature = "telemetry"))]
+pub fn shutdown() {}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_risk_and_order_spans_share_the_intent_trade_id() {
+        let intent_id = "6f1c2a9e-8d3b-4f7a-9c1e-2b5d7e8f9a0b";
+        let client_order_id = format!("{}{}", INTENT_ORDER_PREFIX, intent_id);
+        assert_eq!(trade_id(intent_id), intent_id);
+        assert_eq!(trade_id(&client_order_id), intent_id);
+        assert_eq!(trade_id("momentum-entry-1"), "momentum-entry-1");
+    }
+
+    #[test]
+    fn test_otlp_config_requires_endpoint() {
+        assert_eq!(OtlpConfig::from_vars(None, Some("svc".to_string())), None);
+        assert_eq!(OtlpConfig::from_vars(Some(" ".to_string()), None), None);
+        assert_eq!(
+            OtlpConfig::from_vars(Some("http://collector:4317".to_string()), None),
+            Some(OtlpConfig {
+                endpoint: "http://collector:4317".to_string(),
+                service_name: "ploy".to_string(),
+            })
+        );
+    }
+}
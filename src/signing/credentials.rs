@@ -0,0 +1,629 @@
+//! CLOB API credential lifecycle
+//!
+//! [`CredentialManager`] provisions L2 (HMAC) API keys from the wallet's L1
+//! EIP-712 signature, caches them in memory and in an encrypted file, checks
+//! them against `/auth/api-keys`, and rotates them once the exchange keeps
+//! rejecting them.
+//!
+//! At-rest format is AES-256-CTR with an HMAC-SHA256 tag (encrypt-then-MAC).
+//! The key comes from `PLOY_CREDENTIALS_KEY` when set, otherwise from a
+//! deterministic wallet signature, so only the key holder can read the file.
+
+use crate::error::{PloyError, Result};
+use crate::signing::{build_clob_auth_signature, ApiCredentials, HmacAuth, Wallet};
+use aes::cipher::{KeyIvInit, StreamCipher};
+use base64::{engine::general_purpose::STANDARD as BASE64, Engine};
+use chrono::{DateTime, Utc};
+use hmac::{Hmac, Mac};
+use reqwest::header::{HeaderMap, HeaderValue};
+use reqwest::{Client, Method, StatusCode};
+use serde::{Deserialize, Serialize};
+use sha2::{Digest, Sha256};
+use std::path::{Path, PathBuf};
+use std::sync::atomic::{AtomicU32, Ordering};
+use std::sync::Arc;
+use tokio::sync::RwLock;
+use tracing::{debug, info, warn};
+use zeroize::Zeroize;
+
+type Aes256Ctr = ctr::Ctr128BE<aes::Aes256>;
+type HmacSha256 = Hmac<Sha256>;
+
+/// Encrypted store layout version
+const STORE_VERSION: u32 = 1;
+/// Message signed to derive the store key when `PLOY_CREDENTIALS_KEY` is unset
+const KEY_DERIVATION_MESSAGE: &str = "ploy: encrypt CLOB API credentials at rest (v1)";
+/// Consecutive auth failures before the current key is re-validated
+const DEFAULT_ROTATE_AFTER_FAILURES: u32 = 2;
+
+/// API key as returned by `/auth/api-key` and `/auth/derive-api-key`
+#[derive(Deserialize)]
+struct ApiKeyResponse {
+    #[serde(rename = "apiKey")]
+    api_key: String,
+    secret: String,
+    passphrase: String,
+}
+
+/// Persisted API credentials
+#[derive(Clone, Serialize, Deserialize)]
+pub struct StoredCredentials {
+    pub api_key: String,
+    pub secret: String,
+    pub passphrase: String,
+    /// Wallet address the key belongs to
+    pub address: String,
+    /// L1 nonce the key was created with
+    pub nonce: u64,
+    pub created_at: DateTime<Utc>,
+}
+
+impl StoredCredentials {
+    pub fn to_api_credentials(&self) -> ApiCredentials {
+        ApiCredentials::new(
+            self.api_key.clone(),
+            self.secret.clone(),
+            self.passphrase.clone(),
+        )
+    }
+}
+
+impl std::fmt::Debug for StoredCredentials {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.debug_struct("StoredCredentials")
+            .field("api_key", &self.api_key)
+            .field("secret", &"[REDACTED]")
+            .field("passphrase", &"[REDACTED]")
+            .field("address", &self.address)
+            .field("nonce", &self.nonce)
+            .field("created_at", &self.created_at)
+            .finish()
+    }
+}
+
+impl Drop for StoredCredentials {
+    fn drop(&mut self) {
+        self.secret.zeroize();
+        self.passphrase.zeroize();
+    }
+}
+
+/// Encrypted file contents
+#[derive(Serialize, Deserialize)]
+struct EncryptedBlob {
+    version: u32,
+    address: String,
+    /// Hex CTR IV
+    iv: String,
+    /// Base64 ciphertext
+    ciphertext: String,
+    /// Hex HMAC-SHA256 over version, address, iv and ciphertext
+    mac: String,
+}
+
+/// Symmetric cipher for the credential store
+#[derive(Clone)]
+pub struct CredentialCipher {
+    enc_key: [u8; 32],
+    mac_key: [u8; 32],
+}
+
+impl CredentialCipher {
+    /// Derive encryption and MAC keys from a master secret
+    pub fn from_secret(secret: &[u8]) -> Self {
+        let derive = |label: &[u8]| -> [u8; 32] {
+            let mut hasher = Sha256::new();
+            hasher.update(label);
+            hasher.update(secret);
+            let mut key = [0u8; 32];
+            key.copy_from_slice(&hasher.finalize());
+            key
+        };
+        Self {
+            enc_key: derive(b"ploy-credentials-enc"),
+            mac_key: derive(b"ploy-credentials-mac"),
+        }
+    }
+
+    /// `PLOY_CREDENTIALS_KEY`, or a deterministic signature from `wallet`
+    pub async fn from_wallet(wallet: &Wallet) -> Result<Self> {
+        if let Ok(mut key) = std::env::var("PLOY_CREDENTIALS_KEY") {
+            if !key.trim().is_empty() {
+                let cipher = Self::from_secret(key.trim().as_bytes());
+                key.zeroize();
+                return Ok(cipher);
+            }
+        }
+
+        // RFC 6979 signatures are deterministic, so the same wallet always
+        // yields the same store key.
+        let signature = wallet.sign_message(KEY_DERIVATION_MESSAGE).await?;
+        let mut bytes = signature.to_vec();
+        let cipher = Self::from_secret(&bytes);
+        bytes.zeroize();
+        Ok(cipher)
+    }
+
+    fn mac(&self, version: u32, address: &str, iv: &[u8], ciphertext: &[u8]) -> HmacSha256 {
+        let mut mac =
+            HmacSha256::new_from_slice(&self.mac_key).expect("HMAC accepts any key length");
+        mac.update(&version.to_be_bytes());
+        mac.update(address.as_bytes());
+        mac.update(iv);
+        mac.update(ciphertext);
+        mac
+    }
+
+    fn seal(&self, address: &str, plaintext: &[u8]) -> EncryptedBlob {
+        let iv: [u8; 16] = rand::random();
+        let mut buf = plaintext.to_vec();
+        Aes256Ctr::new((&self.enc_key).into(), (&iv).into()).apply_keystream(&mut buf);
+
+        let tag = self
+            .mac(STORE_VERSION, address, &iv, &buf)
+            .finalize()
+            .into_bytes();
+        EncryptedBlob {
+            version: STORE_VERSION,
+            address: address.to_string(),
+            iv: hex::encode(iv),
+            ciphertext: BASE64.encode(&buf),
+            mac: hex::encode(tag),
+        }
+    }
+
+    fn open(&self, blob: &EncryptedBlob) -> Result<Vec<u8>> {
+        if blob.version != STORE_VERSION {
+            return Err(PloyError::Auth(format!(
+                "unsupported credential store version {}",
+                blob.version
+            )));
+        }
+        let invalid = |what: &str| PloyError::Auth(format!("corrupt credential store: {}", what));
+        let iv: [u8; 16] = hex::decode(&blob.iv)
+            .ok()
+            .and_then(|v| v.try_into().ok())
+            .ok_or_else(|| invalid("iv"))?;
+        let mut buf = BASE64
+            .decode(&blob.ciphertext)
+            .map_err(|_| invalid("ciphertext"))?;
+        let tag = hex::decode(&blob.mac).map_err(|_| invalid("mac"))?;
+
+        self.mac(blob.version, &blob.address, &iv, &buf)
+            .verify_slice(&tag)
+            .map_err(|_| {
+                PloyError::Auth(
+                    "credential store MAC mismatch (wrong key or tampered file)".to_string(),
+                )
+            })?;
+
+        Aes256Ctr::new((&self.enc_key).into(), (&iv).into()).apply_keystream(&mut buf);
+        Ok(buf)
+    }
+}
+
+impl Drop for CredentialCipher {
+    fn drop(&mut self) {
+        self.enc_key.zeroize();
+        self.mac_key.zeroize();
+    }
+}
+
+/// Encrypted single-file credential store
+#[derive(Clone)]
+pub struct EncryptedCredentialStore {
+    path: PathBuf,
+    cipher: CredentialCipher,
+}
+
+impl EncryptedCredentialStore {
+    pub fn new(path: impl Into<PathBuf>, cipher: CredentialCipher) -> Self {
+        Self {
+            path: path.into(),
+            cipher,
+        }
+    }
+
+    /// `PLOY_CLOB_CREDENTIALS_FILE`, or `<config dir>/ploy/clob_credentials.enc`
+    pub fn default_path() -> PathBuf {
+        if let Ok(path) = std::env::var("PLOY_CLOB_CREDENTIALS_FILE") {
+            if !path.trim().is_empty() {
+                return PathBuf::from(path);
+            }
+        }
+        dirs::config_dir()
+            .unwrap_or_else(|| PathBuf::from("."))
+            .join("ploy")
+            .join("clob_credentials.enc")
+    }
+
+    pub fn path(&self) -> &Path {
+        &self.path
+    }
+
+    /// Load credentials for `address`; a store for another wallet reads as empty
+    pub fn load(&self, address: &str) -> Result<Option<StoredCredentials>> {
+        if !self.path.exists() {
+            return Ok(None);
+        }
+        let raw = std::fs::read_to_string(&self.path)?;
+        let blob: EncryptedBlob = serde_json::from_str(&raw)?;
+        if !blob.address.eq_ignore_ascii_case(address) {
+            debug!(
+                "Credential store {} belongs to {}, not {}",
+                self.path.display(),
+                blob.address,
+                address
+            );
+            return Ok(None);
+        }
+
+        let mut plaintext = self.cipher.open(&blob)?;
+        let parsed = serde_json::from_slice(&plaintext);
+        plaintext.zeroize();
+        Ok(Some(parsed?))
+    }
+
+    /// Atomically replace the store (owner-only permissions on unix)
+    pub fn save(&self, creds: &StoredCredentials) -> Result<()> {
+        if let Some(dir) = self.path.parent() {
+            std::fs::create_dir_all(dir)?;
+        }
+
+        let mut plaintext = serde_json::to_vec(creds)?;
+        let blob = self.cipher.seal(&creds.address, &plaintext);
+        plaintext.zeroize();
+
+        let tmp = self.path.with_extension("tmp");
+        {
+            let mut opts = std::fs::OpenOptions::new();
+            opts.write(true).create(true).truncate(true);
+            #[cfg(unix)]
+            {
+                use std::os::unix::fs::OpenOptionsExt;
+                opts.mode(0o600);
+            }
+            let file = opts.open(&tmp)?;
+            serde_json::to_writer_pretty(file, &blob)?;
+        }
+        std::fs::rename(&tmp, &self.path)?;
+        Ok(())
+    }
+
+    pub fn clear(&self) -> Result<()> {
+        match std::fs::remove_file(&self.path) {
+            Ok(()) => Ok(()),
+            Err(e) if e.kind() == std::io::ErrorKind::NotFound => Ok(()),
+            Err(e) => Err(e.into()),
+        }
+    }
+}
+
+/// Creates, caches, validates and rotates CLOB API keys
+pub struct CredentialManager {
+    http: Client,
+    base_url: String,
+    wallet: Arc<Wallet>,
+    store: Option<EncryptedCredentialStore>,
+    current: RwLock<Option<StoredCredentials>>,
+    auth_failures: AtomicU32,
+    rotate_after_failures: u32,
+}
+
+impl CredentialManager {
+    pub fn new(base_url: impl Into<String>, wallet: Arc<Wallet>) -> Self {
+        Self {
+            http: Client::new(),
+            base_url: base_url.into().trim_end_matches('/').to_string(),
+            wallet,
+            store: None,
+            current: RwLock::new(None),
+            auth_failures: AtomicU32::new(0),
+            rotate_after_failures: DEFAULT_ROTATE_AFTER_FAILURES,
+        }
+    }
+
+    /// Persist credentials in `store`
+    pub fn with_store(mut self, store: EncryptedCredentialStore) -> Self {
+        self.store = Some(store);
+        self
+    }
+
+    /// Consecutive auth failures before re-validating (and rotating) the key
+    pub fn with_rotate_after_failures(mut self, failures: u32) -> Self {
+        self.rotate_after_failures = failures.max(1);
+        self
+    }
+
+    pub fn store(&self) -> Option<&EncryptedCredentialStore> {
+        self.store.as_ref()
+    }
+
+    /// Lowercase 0x-prefixed wallet address
+    pub fn address(&self) -> String {
+        format!("{:?}", self.wallet.address())
+    }
+
+    /// Current credentials: memory cache, then encrypted store, then derive/create
+    pub async fn credentials(&self) -> Result<ApiCredentials> {
+        Ok(self.current_stored().await?.to_api_credentials())
+    }
+
+    /// HMAC signer for L2 requests with the current credentials
+    pub async fn hmac_auth(&self) -> Result<HmacAuth> {
+        Ok(HmacAuth::new(self.credentials().await?, self.address()))
+    }
+
+    /// Current credentials with provisioning metadata
+    pub async fn current_stored(&self) -> Result<StoredCredentials> {
+        if let Some(creds) = self.current.read().await.as_ref() {
+            return Ok(creds.clone());
+        }
+
+        let mut guard = self.current.write().await;
+        if let Some(creds) = guard.as_ref() {
+            return Ok(creds.clone());
+        }
+
+        let address = self.address();
+        let loaded = match &self.store {
+            Some(store) => match store.load(&address) {
+                Ok(creds) => creds,
+                Err(e) => {
+                    warn!(
+                        "Ignoring unreadable credential store {}: {}",
+                        store.path().display(),
+                        e
+                    );
+                    None
+                }
+            },
+            None => None,
+        };
+
+        let creds = match loaded {
+            Some(creds) => {
+                debug!("Loaded CLOB API key {} from store", creds.api_key);
+                creds
+            }
+            None => {
+                let creds = self.derive_or_create(0).await?;
+                self.persist(&creds);
+                creds
+            }
+        };
+        *guard = Some(creds.clone());
+        Ok(creds)
+    }
+
+    /// Whether the exchange still accepts the current key
+    pub async fn validate(&self) -> Result<bool> {
+        let creds = self.current_stored().await?;
+        self.check_key(&creds).await
+    }
+
+    /// Create a fresh key (next L1 nonce), persist it, and revoke the old one
+    pub async fn rotate(&self) -> Result<ApiCredentials> {
+        let old = self.current_stored().await?;
+        let fresh = self.derive_or_create(old.nonce + 1).await?;
+        self.persist(&fresh);
+        *self.current.write().await = Some(fresh.clone());
+        self.auth_failures.store(0, Ordering::SeqCst);
+        info!(
+            "Rotated CLOB API key {} -> {} (nonce {})",
+            old.api_key, fresh.api_key, fresh.nonce
+        );
+
+        if old.api_key != fresh.api_key {
+            if let Err(e) = self.revoke(&old).await {
+                warn!("Failed to revoke old CLOB API key {}: {}", old.api_key, e);
+            }
+        }
+        Ok(fresh.to_api_credentials())
+    }
+
+    /// Record a 401/403 from the exchange; rotates once the key is confirmed dead.
+    ///
+    /// Returns the new credentials when a rotation happened.
+    pub async fn report_auth_failure(&self) -> Result<Option<ApiCredentials>> {
+        let failures = self.auth_failures.fetch_add(1, Ordering::SeqCst) + 1;
+        if failures < self.rotate_after_failures {
+            return Ok(None);
+        }
+
+        if self.validate().await? {
+            debug!(
+                "CLOB API key still valid after {} auth failures; not rotating",
+                failures
+            );
+            self.auth_failures.store(0, Ordering::SeqCst);
+            return Ok(None);
+        }
+
+        warn!(
+            "CLOB API key rejected after {} auth failures; rotating",
+            failures
+        );
+        self.rotate().await.map(Some)
+    }
+
+    /// Record an authenticated request that succeeded
+    pub fn report_auth_success(&self) {
+        self.auth_failures.store(0, Ordering::SeqCst);
+    }
+
+    fn persist(&self, creds: &StoredCredentials) {
+        if let Some(store) = &self.store {
+            if let Err(e) = store.save(creds) {
+                warn!(
+                    "Failed to persist CLOB API key to {}: {}",
+                    store.path().display(),
+                    e
+                );
+            }
+        }
+    }
+
+    /// Derive the key for `nonce`, creating it if the exchange has none
+    async fn derive_or_create(&self, nonce: u64) -> Result<StoredCredentials> {
+        let response = match self
+            .request_key(Method::GET, "/auth/derive-api-key", nonce)
+            .await
+        {
+            Ok(response) => response,
+            Err(e) => {
+                debug!("Derive API key (nonce {}) failed: {}; creating", nonce, e);
+                self.request_key(Method::POST, "/auth/api-key", nonce)
+                    .await?
+            }
+        };
+
+        Ok(StoredCredentials {
+            api_key: response.api_key,
+            secret: response.secret,
+            passphrase: response.passphrase,
+            address: self.address(),
+            nonce,
+            created_at: Utc::now(),
+        })
+    }
+
+    async fn request_key(&self, method: Method, path: &str, nonce: u64) -> Result<ApiKeyResponse> {
+        let headers = self.l1_headers(nonce).await?;
+        let resp = self
+            .http
+            .request(method, format!("{}{}", self.base_url, path))
+            .headers(headers)
+            .send()
+            .await?;
+
+        let status = resp.status();
+        if !status.is_success() {
+            let body = resp.text().await.unwrap_or_default();
+            return Err(PloyError::Auth(format!(
+                "{} failed: HTTP {}: {}",
+                path, status, body
+            )));
+        }
+        resp.json()
+            .await
+            .map_err(|e| PloyError::Auth(format!("{} returned invalid credentials: {}", path, e)))
+    }
+
+    async fn l1_headers(&self, nonce: u64) -> Result<HeaderMap> {
+        let timestamp = Utc::now().timestamp();
+        let (_, signature) = build_clob_auth_signature(&self.wallet, timestamp, nonce).await?;
+
+        let header = |value: String| {
+            HeaderValue::from_str(&value)
+                .map_err(|e| PloyError::Internal(format!("Invalid auth header: {}", e)))
+        };
+        let mut headers = HeaderMap::new();
+        headers.insert("POLY_ADDRESS", header(self.address())?);
+        headers.insert("POLY_SIGNATURE", header(signature)?);
+        headers.insert("POLY_TIMESTAMP", header(timestamp.to_string())?);
+        headers.insert("POLY_NONCE", header(nonce.to_string())?);
+        Ok(headers)
+    }
+
+    async fn check_key(&self, creds: &StoredCredentials) -> Result<bool> {
+        let path = "/auth/api-keys";
+        let auth = HmacAuth::new(creds.to_api_credentials(), creds.address.clone());
+        let resp = self
+            .http
+            .get(format!("{}{}", self.base_url, path))
+            .headers(auth.build_headers("GET", path, None)?)
+            .send()
+            .await?;
+
+        match resp.status() {
+            status if status.is_success() => Ok(true),
+            StatusCode::UNAUTHORIZED | StatusCode::FORBIDDEN => Ok(false),
+            status => Err(PloyError::Auth(format!(
+                "{} validation failed: HTTP {}",
+                path, status
+            ))),
+        }
+    }
+
+    async fn revoke(&self, creds: &StoredCredentials) -> Result<()> {
+        let path = "/auth/api-key";
+        let auth = HmacAuth::new(creds.to_api_credentials(), creds.address.clone());
+        let resp = self
+            .http
+            .delete(format!("{}{}", self.base_url, path))
+            .headers(auth.build_headers("DELETE", path, None)?)
+            .send()
+            .await?;
+
+        if resp.status().is_success() {
+            Ok(())
+        } else {
+            Err(PloyError::Auth(format!(
+                "{} revoke failed: HTTP {}",
+                path,
+                resp.status()
+            )))
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn sample(address: &str) -> StoredCredentials {
+        StoredCredentials {
+            api_key: "11111111-2222-3333-4444-555555555555".to_string(),
+            secret: BASE64.encode(b"secret"),
+            passphrase: "pass".to_string(),
+            address: address.to_string(),
+            nonce: 3,
+            created_at: Utc::now(),
+        }
+    }
+
+    #[test]
+    fn test_store_round_trip_is_encrypted() {
+        let path = std::env::temp_dir().join(format!("ploy_creds_{}.enc", uuid::Uuid::new_v4()));
+        let store = EncryptedCredentialStore::new(&path, CredentialCipher::from_secret(b"master"));
+        let address = "0xf39fd6e51aad88f6f4ce6ab8827279cfffb92266";
+
+        assert!(store.load(address).unwrap().is_none());
+        store.save(&sample(address)).unwrap();
+
+        let raw = std::fs::read_to_string(&path).unwrap();
+        assert!(!raw.contains("pass"));
+        assert!(!raw.contains("11111111-2222"));
+
+        let loaded = store.load(address).unwrap().unwrap();
+        assert_eq!(loaded.api_key, "11111111-2222-3333-4444-555555555555");
+        assert_eq!(loaded.passphrase, "pass");
+        assert_eq!(loaded.nonce, 3);
+
+        // Another wallet's store reads as empty
+        assert!(store
+            .load("0x0000000000000000000000000000000000000001")
+            .unwrap()
+            .is_none());
+
+        // Wrong key fails the MAC check
+        let other = EncryptedCredentialStore::new(&path, CredentialCipher::from_secret(b"other"));
+        assert!(other.load(address).is_err());
+
+        store.clear().unwrap();
+        assert!(!path.exists());
+    }
+
+    #[test]
+    fn test_tampered_ciphertext_is_rejected() {
+        let cipher = CredentialCipher::from_secret(b"master");
+        let mut blob = cipher.seal("0xabc", b"{\"k\":1}");
+        assert_eq!(cipher.open(&blob).unwrap(), b"{\"k\":1}");
+
+        let mut bytes = BASE64.decode(&blob.ciphertext).unwrap();
+        bytes[0] ^= 0x01;
+        blob.ciphertext = BASE64.encode(bytes);
+        assert!(cipher.open(&blob).is_err());
+    }
+}
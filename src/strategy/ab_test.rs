@@ -0,0 +1,507 @@
+//! Strategy-level A/B deployment.
+//!
+//! Two variants of the same strategy (different configs or model versions)
+//! run side by side in one process. The experiment's capital is split between
+//! them by weight and each variant's orders are admitted against its own
+//! budget. Realized PnL is sampled per variant at a fixed interval and turned
+//! into returns on allocated capital, so `ploy strategy ab-status` can compare
+//! the variants with a Welch t-test regardless of the split ratio.
+
+use chrono::{DateTime, Utc};
+use rust_decimal::prelude::ToPrimitive;
+use rust_decimal::Decimal;
+use serde::{Deserialize, Serialize};
+use std::path::{Path, PathBuf};
+
+use crate::domain::{OrderRequest, OrderSide, OrderStatus};
+use crate::error::{PloyError, Result};
+use crate::strategy::volatility::normal_cdf;
+
+/// `[experiment]` section of an A/B experiment file
+#[derive(Debug, Clone, Deserialize)]
+pub struct AbSettings {
+    pub name: String,
+    /// Capital shared by the variants (USD)
+    #[serde(default = "default_capital")]
+    pub capital: Decimal,
+    /// How often realized PnL is sampled into a return observation
+    #[serde(default = "default_sample_interval_secs")]
+    pub sample_interval_secs: u64,
+    /// Significance level for the variant comparison
+    #[serde(default = "default_alpha")]
+    pub alpha: f64,
+}
+
+fn default_capital() -> Decimal {
+    Decimal::from(1000)
+}
+
+fn default_sample_interval_secs() -> u64 {
+    300
+}
+
+fn default_alpha() -> f64 {
+    0.05
+}
+
+/// One `[[variants]]` entry
+#[derive(Debug, Clone, Deserialize)]
+pub struct AbVariantConfig {
+    pub label: String,
+    /// Strategy config file; relative paths resolve against the experiment file
+    pub config: PathBuf,
+    /// Share of the experiment capital
+    pub weight: f64,
+}
+
+#[derive(Debug, Clone, Deserialize)]
+pub struct AbExperimentConfig {
+    pub experiment: AbSettings,
+    pub variants: Vec<AbVariantConfig>,
+}
+
+impl AbExperimentConfig {
+    pub fn load(path: impl AsRef<Path>) -> Result<Self> {
+        let path = path.as_ref();
+        let raw = std::fs::read_to_string(path).map_err(|e| {
+            PloyError::Validation(format!(
+                "failed to read experiment {}: {}",
+                path.display(),
+                e
+            ))
+        })?;
+        let mut config: Self = toml::from_str(&raw)
+            .map_err(|e| PloyError::Validation(format!("invalid experiment config: {}", e)))?;
+
+        let base = path.parent().unwrap_or_else(|| Path::new("."));
+        for variant in &mut config.variants {
+            if variant.config.is_relative() {
+                variant.config = base.join(&variant.config);
+            }
+        }
+        config.validate()?;
+        Ok(config)
+    }
+
+    pub fn validate(&self) -> Result<()> {
+        if self.variants.len() != 2 {
+            return Err(PloyError::Validation(format!(
+                "experiment {} needs exactly 2 variants, found {}",
+                self.experiment.name,
+                self.variants.len()
+            )));
+        }
+        if self.variants[0].label == self.variants[1].label {
+            return Err(PloyError::Validation(
+                "variant labels must be distinct".to_string(),
+            ));
+        }
+        if self.variants.iter().any(|v| v.weight <= 0.0) {
+            return Err(PloyError::Validation(
+                "variant weights must be positive".to_string(),
+            ));
+        }
+        let total: f64 = self.variants.iter().map(|v| v.weight).sum();
+        if (total - 1.0).abs() > 1e-6 {
+            return Err(PloyError::Validation(format!(
+                "variant weights must sum to 1, got {}",
+                total
+            )));
+        }
+        if self.experiment.capital <= Decimal::ZERO {
+            return Err(PloyError::Validation(
+                "experiment capital must be positive".to_string(),
+            ));
+        }
+        Ok(())
+    }
+}
+
+#[derive(Debug, Clone)]
+struct VariantState {
+    label: String,
+    strategy_id: String,
+    config: PathBuf,
+    weight: f64,
+    budget: Decimal,
+    /// Buy notional submitted and not yet offset by sells
+    committed: Decimal,
+    /// Buy notional of orders left resting on the book
+    resting: Decimal,
+    orders: u64,
+    blocked: u64,
+    realized_pnl: Decimal,
+    last_sampled_pnl: Decimal,
+    /// Per-interval PnL divided by budget
+    returns: Vec<f64>,
+}
+
+/// Running A/B experiment: budgets, attribution and samples
+#[derive(Debug, Clone)]
+pub struct AbTracker {
+    settings: AbSettings,
+    variants: Vec<VariantState>,
+    started_at: DateTime<Utc>,
+}
+
+impl AbTracker {
+    /// `strategy_ids` are the ids of the started variants, in config order
+    pub fn new(config: &AbExperimentConfig, strategy_ids: &[String], now: DateTime<Utc>) -> Self {
+        let capital = config.experiment.capital;
+        let variants = config
+            .variants
+            .iter()
+            .zip(strategy_ids)
+            .map(|(v, id)| VariantState {
+                label: v.label.clone(),
+                strategy_id: id.clone(),
+                config: v.config.clone(),
+                weight: v.weight,
+                budget: (capital * Decimal::try_from(v.weight).unwrap_or(Decimal::ZERO))
+                    .round_dp(2),
+                committed: Decimal::ZERO,
+                resting: Decimal::ZERO,
+                orders: 0,
+                blocked: 0,
+                realized_pnl: Decimal::ZERO,
+                last_sampled_pnl: Decimal::ZERO,
+                returns: Vec::new(),
+            })
+            .collect();
+        Self {
+            settings: config.experiment.clone(),
+            variants,
+            started_at: now,
+        }
+    }
+
+    pub fn name(&self) -> &str {
+        &self.settings.name
+    }
+
+    pub fn sample_interval_secs(&self) -> u64 {
+        self.settings.sample_interval_secs.max(1)
+    }
+
+    pub fn strategy_ids(&self) -> Vec<String> {
+        self.variants
+            .iter()
+            .map(|v| v.strategy_id.clone())
+            .collect()
+    }
+
+    pub fn variant_label(&self, strategy_id: &str) -> Option<&str> {
+        self.variants
+            .iter()
+            .find(|v| v.strategy_id == strategy_id)
+            .map(|v| v.label.as_str())
+    }
+
+    fn variant_mut(&mut self, strategy_id: &str) -> Option<&mut VariantState> {
+        self.variants
+            .iter_mut()
+            .find(|v| v.strategy_id == strategy_id)
+    }
+
+    /// Admit an order against the variant's capital budget.
+    ///
+    /// Buys reserve their notional and are refused once the budget is used
+    /// up; sells release it. Orders from strategies outside the experiment
+    /// pass through. Every admitted order must be followed by
+    /// [`Self::settle_order`] once its execution result is known.
+    pub fn admit(&mut self, strategy_id: &str, order: &OrderRequest) -> bool {
+        let Some(variant) = self.variant_mut(strategy_id) else {
+            return true;
+        };
+        let notional = order.limit_price * Decimal::from(order.shares);
+        match order.order_side {
+            OrderSide::Buy => {
+                if variant.committed + notional > variant.budget {
+                    variant.blocked += 1;
+                    return false;
+                }
+                variant.committed += notional;
+            }
+            OrderSide::Sell => {
+                variant.committed = (variant.committed - notional).max(Decimal::ZERO);
+            }
+        }
+        variant.orders += 1;
+        true
+    }
+
+    /// Roll back what [`Self::admit`] assumed for the part of an order that
+    /// did not fill.
+    ///
+    /// A finished or failed buy releases its unfilled notional; a buy still
+    /// resting keeps it reserved. A sell re-commits its unfilled notional,
+    /// since the shares it was meant to offload are still held.
+    pub fn settle_order(
+        &mut self,
+        strategy_id: &str,
+        order: &OrderRequest,
+        status: &OrderStatus,
+        filled_shares: u64,
+    ) {
+        let Some(variant) = self.variant_mut(strategy_id) else {
+            return;
+        };
+        let unfilled =
+            order.limit_price * Decimal::from(order.shares.saturating_sub(filled_shares));
+        match order.order_side {
+            OrderSide::Buy if status.is_terminal() => {
+                variant.committed = (variant.committed - unfilled).max(Decimal::ZERO);
+            }
+            OrderSide::Buy => variant.resting += unfilled,
+            OrderSide::Sell => variant.committed += unfilled,
+        }
+    }
+
+    /// Release capital of positions that settled or were closed outside the
+    /// order flow: the commitment is capped at the variant's open position
+    /// notional plus buys left resting.
+    pub fn sync_open_notional(&mut self, strategy_id: &str, open_notional: Decimal) {
+        if let Some(variant) = self.variant_mut(strategy_id) {
+            let cap = open_notional.max(Decimal::ZERO) + variant.resting;
+            variant.committed = variant.committed.min(cap);
+        }
+    }
+
+    /// Record the variant's latest realized PnL (from its strategy state)
+    pub fn update_pnl(&mut self, strategy_id: &str, realized_pnl: Decimal) {
+        if let Some(variant) = self.variant_mut(strategy_id) {
+            variant.realized_pnl = realized_pnl;
+        }
+    }
+
+    /// Close the current sampling interval for every variant
+    pub fn sample(&mut self) {
+        for variant in &mut self.variants {
+            let delta = variant.realized_pnl - variant.last_sampled_pnl;
+            variant.last_sampled_pnl = variant.realized_pnl;
+            if variant.budget > Decimal::ZERO {
+                if let Some(ret) = (delta / variant.budget).to_f64() {
+                    variant.returns.push(ret);
+                }
+            }
+        }
+    }
+
+    pub fn report(&self, now: DateTime<Utc>) -> AbReport {
+        let variants: Vec<AbVariantReport> = self
+            .variants
+            .iter()
+            .map(|v| {
+                let (mean, stdev) = mean_stdev(&v.returns);
+                AbVariantReport {
+                    label: v.label.clone(),
+                    strategy_id: v.strategy_id.clone(),
+                    config: v.config.display().to_string(),
+                    weight: v.weight,
+                    budget: v.budget,
+                    committed: v.committed,
+                    orders: v.orders,
+                    blocked_orders: v.blocked,
+                    realized_pnl: v.realized_pnl,
+                    return_on_budget: if v.budget > Decimal::ZERO {
+                        (v.realized_pnl / v.budget).to_f64().unwrap_or(0.0)
+                    } else {
+                        0.0
+                    },
+                    samples: v.returns.len(),
+                    mean_return: mean,
+                    stdev_return: stdev,
+                }
+            })
+            .collect();
+
+        let comparison = match self.variants.as_slice() {
+            [a, b] => welch_test(&a.returns, &b.returns).map(|(t_stat, p_value)| {
+                let significant = p_value < self.settings.alpha;
+                let mean_diff = variants[1].mean_return - variants[0].mean_return;
+                AbComparison {
+                    mean_diff,
+                    t_stat,
+                    p_value,
+                    alpha: self.settings.alpha,
+                    significant,
+                    leader: significant.then(|| {
+                        if mean_diff > 0.0 {
+                            b.label.clone()
+                        } else {
+                            a.label.clone()
+                        }
+                    }),
+                }
+            }),
+            _ => None,
+        };
+
+        AbReport {
+            experiment: self.settings.name.clone(),
+            capital: self.settings.capital,
+            started_at: self.started_at,
+            updated_at: now,
+            variants,
+            comparison,
+        }
+    }
+}
+
+fn mean_stdev(xs: &[f64]) -> (f64, f64) {
+    if xs.is_empty() {
+        return (0.0, 0.0);
+    }
+    let n = xs.len() as f64;
+    let mean = xs.iter().sum::<f64>() / n;
+    if xs.len() < 2 {
+        return (mean, 0.0);
+    }
+    let var = xs.iter().map(|x| (x - mean).powi(2)).sum::<f64>() / (n - 1.0);
+    (mean, var.sqrt())
+}
+
+/// Welch's t-test; two-sided p-value from the normal approximation, which is
+/// adequate once each variant has a few dozen samples.
+fn welch_test(a: &[f64], b: &[f64]) -> Option<(f64, f64)> {
+    if a.len() < 2 || b.len() < 2 {
+        return None;
+    }
+    let (mean_a, sd_a) = mean_stdev(a);
+    let (mean_b, sd_b) = mean_stdev(b);
+    let se = (sd_a.powi(2) / a.len() as f64 + sd_b.powi(2) / b.len() as f64).sqrt();
+    if se <= 0.0 {
+        return None;
+    }
+    let t = (mean_b - mean_a) / se;
+    let p = 2.0 * (1.0 - normal_cdf(t.abs()));
+    Some((t, p.clamp(0.0, 1.0)))
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct AbVariantReport {
+    pub label: String,
+    pub strategy_id: String,
+    pub config: String,
+    pub weight: f64,
+    pub budget: Decimal,
+    pub committed: Decimal,
+    pub orders: u64,
+    /// Orders refused because the variant's budget was used up
+    pub blocked_orders: u64,
+    pub realized_pnl: Decimal,
+    pub return_on_budget: f64,
+    pub samples: usize,
+    pub mean_return: f64,
+    pub stdev_return: f64,
+}
+
+/// Second variant vs first
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct AbComparison {
+    pub mean_diff: f64,
+    pub t_stat: f64,
+    pub p_value: f64,
+    pub alpha: f64,
+    pub significant: bool,
+    /// Better variant, when the difference is significant
+    pub leader: Option<String>,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct AbReport {
+    pub experiment: String,
+    pub capital: Decimal,
+    pub started_at: DateTime<Utc>,
+    pub updated_at: DateTime<Utc>,
+    pub variants: Vec<AbVariantReport>,
+    pub comparison: Option<AbComparison>,
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::domain::Side;
+    use rust_decimal_macros::dec;
+
+    fn config() -> AbExperimentConfig {
+        toml::from_str(
+            r#"
+            [experiment]
+            name = "momentum-ab"
+            capital = 1000
+
+            [[variants]]
+            label = "control"
+            config = "momentum.toml"
+            weight = 0.7
+
+            [[variants]]
+            label = "candidate"
+            config = "momentum_v2.toml"
+            weight = 0.3
+            "#,
+        )
+        .unwrap()
+    }
+
+    #[test]
+    fn test_budget_split_and_significance() {
+        let config = config();
+        config.validate().unwrap();
+        let ids = vec!["m_control".to_string(), "m_candidate".to_string()];
+        let mut tracker = AbTracker::new(&config, &ids, Utc::now());
+
+        // Candidate budget is $300: a $250 buy fits, a second one does not
+        let buy = OrderRequest::buy_limit("tok".to_string(), Side::Up, 500, dec!(0.50));
+        assert!(tracker.admit("m_candidate", &buy));
+        assert!(!tracker.admit("m_candidate", &buy));
+        assert!(tracker.admit("m_control", &buy));
+        assert!(tracker.admit("other_strategy", &buy));
+
+        let (mut control, mut candidate) = (Decimal::ZERO, Decimal::ZERO);
+        for i in 0..40 {
+            control += if i % 2 == 0 { dec!(1.4) } else { dec!(-0.7) };
+            candidate += if i % 2 == 0 { dec!(3.0) } else { dec!(1.5) };
+            tracker.update_pnl("m_control", control);
+            tracker.update_pnl("m_candidate", candidate);
+            tracker.sample();
+        }
+
+        let report = tracker.report(Utc::now());
+        assert_eq!(report.variants[1].budget, dec!(300));
+        assert_eq!(report.variants[1].blocked_orders, 1);
+        assert_eq!(report.variants[1].samples, 40);
+        let comparison = report.comparison.unwrap();
+        assert!(comparison.significant);
+        assert_eq!(comparison.leader.as_deref(), Some("candidate"));
+    }
+    #[test]
+    fn test_unfilled_and_settled_orders_release_budget() {
+        let ids = vec!["m_control".to_string(), "m_candidate".to_string()];
+        let mut tracker = AbTracker::new(&config(), &ids, Utc::now());
+        let buy = OrderRequest::buy_limit("tok".to_string(), Side::Up, 500, dec!(0.50));
+        let committed = |t: &AbTracker| t.report(Utc::now()).variants[1].committed;
+
+        // A rejected buy gives its whole reservation back
+        assert!(tracker.admit("m_candidate", &buy));
+        tracker.settle_order("m_candidate", &buy, &OrderStatus::Rejected, 0);
+        assert_eq!(committed(&tracker), Decimal::ZERO);
+
+        // A partially filled IOC keeps only the filled notional
+        assert!(tracker.admit("m_candidate", &buy));
+        tracker.settle_order("m_candidate", &buy, &OrderStatus::Cancelled, 200);
+        assert_eq!(committed(&tracker), dec!(100));
+
+        // A sell that does not fill leaves the position, and its capital, in place
+        let sell = OrderRequest::sell_limit("tok".to_string(), Side::Up, 200, dec!(0.50));
+        assert!(tracker.admit("m_candidate", &sell));
+        tracker.settle_order("m_candidate", &sell, &OrderStatus::Failed, 0);
+        assert_eq!(committed(&tracker), dec!(100));
+
+        // Settlement closes the position outside the order flow
+        tracker.sync_open_notional("m_candidate", Decimal::ZERO);
+        assert_eq!(committed(&tracker), Decimal::ZERO);
+        assert!(tracker.admit("m_candidate", &buy));
+    }
+}
@@ -13,6 +13,7 @@ use std::sync::Arc;
 use tokio::sync::{OnceCell, RwLock};
 use tracing::{debug, info, warn};
 
+use super::feeds::infer_symbol_from_text;
 use super::momentum::{Direction, ExitConfig, MomentumConfig};
 use super::traits::{
     AlertLevel, DataFeed, MarketUpdate, OrderUpdate, PositionInfo, Strategy, StrategyAction,
@@ -20,6 +21,7 @@ use super::traits::{
 };
 use crate::domain::{OrderRequest, Side};
 use crate::error::Result;
+use crate::platform::Timeframe;
 
 fn database_url_from_env() -> Option<String> {
     std::env::var("PLOY_DATABASE__URL")
@@ -1130,21 +1132,29 @@ impl Strategy for MomentumStrategyAdapter {
                 down_token,
                 end_time,
                 price_to_beat: _,
-                title: _,
+                title,
+                window_secs,
             } => {
                 // Map series to symbol
-                let (symbol, window_secs) = match series_id.as_str() {
+                let (symbol, known_window_secs) = match series_id.as_str() {
                     // 5m windows
-                    "10684" => ("BTCUSDT", 300u64),
-                    "10683" => ("ETHUSDT", 300),
-                    "10686" => ("SOLUSDT", 300),
-                    "10685" => ("XRPUSDT", 300),
+                    "10684" => ("BTCUSDT", Some(300u64)),
+                    "10683" => ("ETHUSDT", Some(300)),
+                    "10686" => ("SOLUSDT", Some(300)),
+                    "10685" => ("XRPUSDT", Some(300)),
                     // 15m windows
-                    "10192" => ("BTCUSDT", 900),
-                    "10191" => ("ETHUSDT", 900),
-                    "10423" => ("SOLUSDT", 900),
-                    "10422" => ("XRPUSDT", 900),
-                    _ => return Ok(actions),
+                    "10192" => ("BTCUSDT", Some(900)),
+                    "10191" => ("ETHUSDT", Some(900)),
+                    "10423" => ("SOLUSDT", Some(900)),
+                    "10422" => ("XRPUSDT", Some(900)),
+                    // Other rounds (1h / 1d): symbol from the title, length from discovery
+                    _ => match title.as_deref().and_then(infer_symbol_from_text) {
+                        Some(symbol) => (symbol, None),
+                        None => return Ok(actions),
+                    },
+                };
+                let Some(window_secs) = window_secs.or(known_window_secs) else {
+                    return Ok(actions);
                 };
 
                 let mut events = self.events.write().await;
@@ -1190,7 +1200,9 @@ impl Strategy for MomentumStrategyAdapter {
                 }
             }
 
-            MarketUpdate::BinanceKline { .. } => {}
+            MarketUpdate::PolymarketBook { .. }
+            | MarketUpdate::BinanceKline { .. }
+            | MarketUpdate::RoundBoundary { .. } => {}
         }
 
         Ok(actions)
@@ -1359,6 +1371,8 @@ struct MonitoredMarket {
     no_token_id: String,
     description: String,
     end_time: DateTime<Utc>,
+    /// Round length in seconds (scales the hedge timeout)
+    window_secs: i64,
 }
 
 /// A partial (unhedged) position
@@ -1786,7 +1800,12 @@ impl Strategy for SplitArbStrategyAdapter {
                 end_time,
                 price_to_beat: _,
                 title: _,
+                window_secs,
             } => {
+                let window_secs = window_secs
+                    .map(|secs| secs as i64)
+                    .or_else(|| Timeframe::from_hint(series_id).and_then(|tf| tf.duration_secs()))
+                    .unwrap_or(Timeframe::REFERENCE_SECS);
                 let mut markets = self.markets.write().await;
                 markets.insert(
                     event_id.clone(),
@@ -1796,6 +1815,7 @@ impl Strategy for SplitArbStrategyAdapter {
                         no_token_id: down_token.clone(),
                         description: format!("Series {}", series_id),
                         end_time: *end_time,
+                        window_secs,
                     },
                 );
 
@@ -1968,13 +1988,19 @@ impl Strategy for SplitArbStrategyAdapter {
     async fn on_tick(&mut self, now: DateTime<Utc>) -> Result<Vec<StrategyAction>> {
         let mut actions = Vec::new();
 
-        // Check for hedge timeouts
+        // Check for hedge timeouts (calibrated on 15m rounds, stretched for longer ones)
         let mut timed_out = Vec::new();
         {
+            let markets = self.markets.read().await;
             let partials = self.partial_positions.read().await;
             for (market_id, pos) in partials.iter() {
+                let scale = markets
+                    .get(market_id)
+                    .map(|m| Timeframe::window_scale(m.window_secs))
+                    .unwrap_or(1.0);
+                let max_wait = (self.config.max_hedge_wait_secs as f64 * scale) as u64;
                 let elapsed = (now - pos.opened_at).num_seconds() as u64;
-                if elapsed > self.config.max_hedge_wait_secs {
+                if elapsed > max_wait {
                     timed_out.push(market_id.clone());
                 }
             }
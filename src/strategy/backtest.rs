@@ -25,6 +25,9 @@ use std::io::{BufRead, BufReader, Write as IoWrite};
 use std::path::Path;
 use tracing::{debug, info, warn};
 
+pub mod settlement;
+
+use self::settlement::{ReferencePrices, SettlementRule};
 use crate::strategy::volatility_arb::{
     calculate_implied_volatility, VolArbSignal, VolatilityArbConfig, VolatilityArbEngine,
 };
@@ -128,11 +131,20 @@ pub struct BacktestResults {
     pub largest_loss: Decimal,
     pub avg_holding_time_secs: f64,
     pub trades_by_symbol: HashMap<String, SymbolStats>,
+    /// Trades bucketed by UTC exit date (`YYYY-MM-DD`)
+    #[serde(default)]
+    pub trades_by_day: HashMap<String, SymbolStats>,
+    /// Shares requested by entry orders
+    #[serde(default)]
+    pub requested_shares: u64,
+    /// Shares actually filled by entry orders
+    #[serde(default)]
+    pub filled_shares: u64,
     pub trades: Vec<BacktestTrade>,
     pub equity_curve: Vec<(DateTime<Utc>, Decimal)>,
 }
 
-#[derive(Debug, Clone, Serialize, Deserialize)]
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
 pub struct SymbolStats {
     pub total_trades: u64,
     pub winning_trades: u64,
@@ -140,6 +152,23 @@ pub struct SymbolStats {
     pub total_pnl: Decimal,
 }
 
+impl SymbolStats {
+    /// Add a closed trade to this bucket and refresh the win rate.
+    pub fn record(&mut self, pnl: Decimal, won: bool) {
+        self.total_trades += 1;
+        if won {
+            self.winning_trades += 1;
+        }
+        self.total_pnl += pnl;
+        self.win_rate = self.winning_trades as f64 / self.total_trades as f64;
+    }
+}
+
+/// Key used for `BacktestResults::trades_by_day`.
+pub fn backtest_day_key(ts: DateTime<Utc>) -> String {
+    ts.format("%Y-%m-%d").to_string()
+}
+
 impl Default for BacktestResults {
     fn default() -> Self {
         Self {
@@ -161,6 +190,9 @@ impl Default for BacktestResults {
             largest_loss: Decimal::ZERO,
             avg_holding_time_secs: 0.0,
             trades_by_symbol: HashMap::new(),
+            trades_by_day: HashMap::new(),
+            requested_shares: 0,
+            filled_shares: 0,
             trades: Vec::new(),
             equity_curve: Vec::new(),
         }
@@ -374,6 +406,18 @@ impl BacktestEngine {
         // Group PM prices by market
         let markets = self.group_by_market(pm_prices);
 
+        // Candles by symbol, for settling markets recorded without an outcome
+        let mut candles: HashMap<String, Vec<KlineRecord>> = HashMap::new();
+        for kline in klines {
+            candles
+                .entry(kline.symbol.clone())
+                .or_default()
+                .push(kline.clone());
+        }
+        for series in candles.values_mut() {
+            series.sort_by_key(|k| k.timestamp);
+        }
+
         // Track start/end times
         if let Some(first) = pm_prices.first() {
             self.results.start_time = first.timestamp;
@@ -384,7 +428,7 @@ impl BacktestEngine {
 
         // Process each market
         for (market_id, prices) in markets {
-            self.process_market(&market_id, &prices, &vol_lookup);
+            self.process_market(&market_id, &prices, &vol_lookup, &candles);
         }
 
         // Calculate final statistics
@@ -452,17 +496,28 @@ impl BacktestEngine {
         market_id: &str,
         prices: &[&PMPriceRecord],
         vol_lookup: &HashMap<(String, i64), f64>,
+        candles: &HashMap<String, Vec<KlineRecord>>,
     ) {
-        if prices.is_empty() {
+        let Some(last) = prices.last() else {
             return;
-        }
+        };
 
-        let outcome = prices.last().and_then(|p| p.outcome);
-        if outcome.is_none() {
+        let outcome = last.outcome.or_else(|| {
+            let window_secs = (last.resolution_time - prices[0].timestamp).num_seconds();
+            let rule =
+                SettlementRule::for_market(market_id, window_secs, Some(last.threshold_price))?;
+            let reference = ReferencePrices {
+                chainlink: &[],
+                candles: candles.get(&last.symbol)?,
+            };
+            let start = last.resolution_time - chrono::Duration::seconds(window_secs);
+            rule.settle(start, last.resolution_time, reference)
+                .map(|settled| settled.up_won)
+        });
+        let Some(outcome) = outcome else {
             debug!(market_id, "Skipping market without outcome");
             return;
-        }
-        let outcome = outcome.unwrap();
+        };
 
         // Find best entry point
         let mut best_signal: Option<(VolArbSignal, &PMPriceRecord)> = None;
@@ -625,6 +680,9 @@ impl BacktestEngine {
         self.results.total_trades += 1;
         self.results.total_volume += cost;
         self.results.total_pnl += pnl;
+        // Vol-arb replay assumes full fills at the quoted price.
+        self.results.requested_shares += shares;
+        self.results.filled_shares += shares;
 
         if won {
             self.results.winning_trades += 1;
@@ -632,22 +690,17 @@ impl BacktestEngine {
             self.results.losing_trades += 1;
         }
 
-        // Update symbol stats
-        let symbol_stats = self
-            .results
+        // Update symbol / day stats
+        self.results
             .trades_by_symbol
             .entry(signal.symbol.clone())
-            .or_insert(SymbolStats {
-                total_trades: 0,
-                winning_trades: 0,
-                win_rate: 0.0,
-                total_pnl: Decimal::ZERO,
-            });
-        symbol_stats.total_trades += 1;
-        if won {
-            symbol_stats.winning_trades += 1;
-        }
-        symbol_stats.total_pnl += pnl;
+            .or_default()
+            .record(pnl, won);
+        self.results
+            .trades_by_day
+            .entry(backtest_day_key(entry_record.resolution_time))
+            .or_default()
+            .record(pnl, won);
 
         // Record equity curve point
         self.results
@@ -732,13 +785,6 @@ impl BacktestEngine {
             .map(|t| (t.exit_time - t.entry_time).num_seconds())
             .sum();
         self.results.avg_holding_time_secs = total_hold_time as f64 / trades.len() as f64;
-
-        // Update symbol win rates
-        for stats in self.results.trades_by_symbol.values_mut() {
-            if stats.total_trades > 0 {
-                stats.win_rate = stats.winning_trades as f64 / stats.total_trades as f64;
-            }
-        }
     }
 }
 
@@ -1157,6 +1203,14 @@ impl BacktestResults {
         report
     }
 
+    /// Filled / requested entry shares, when the engine tracked fills.
+    pub fn fill_rate(&self) -> Option<f64> {
+        if self.requested_shares == 0 {
+            return None;
+        }
+        Some(self.filled_shares as f64 / self.requested_shares as f64)
+    }
+
     /// Export results to JSON
     pub fn to_json(&self) -> Result<String, String> {
         serde_json::to_string_pretty(self).map_err(|e| e.to_string())
@@ -0,0 +1,329 @@
+//! Polymarket round settlement rules for backtest labels.
+//!
+//! Up/down series do not all resolve the same way, so labelling rounds with
+//! `close >= open` of whatever candle is at hand mislabels near-flat rounds:
+//!
+//! | Series            | Reference price                    | Strike                      | Tie  |
+//! |-------------------|------------------------------------|-----------------------------|------|
+//! | 5m / 15m / 4h     | Chainlink data stream              | stream price at window open | Up   |
+//! | Hourly            | Binance 1h candle spanning window  | candle open                 | Up   |
+//! | Daily             | Binance 1m candle opening at end   | same candle one day earlier | Up   |
+//! | "Above $X"        | Binance 1m candle opening at end   | price in the question       | No   |
+//!
+//! Stream prices are read as the last report at or before the boundary.
+
+use chrono::{DateTime, Duration, Utc};
+use rust_decimal::Decimal;
+use serde::{Deserialize, Serialize};
+
+use super::KlineRecord;
+use crate::platform::Timeframe;
+
+/// Oldest Chainlink report still accepted as the price at a boundary.
+pub const CHAINLINK_MAX_STALENESS_SECS: i64 = 60;
+
+/// Where the resolution source reads its prices.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum ReferenceSource {
+    /// Chainlink data stream report
+    Chainlink,
+    /// Binance spot candle of the given length
+    BinanceCandle { interval_secs: i64 },
+}
+
+/// How the price to beat is constructed.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum StrikeRule {
+    /// Reference price at the window open
+    WindowOpen,
+    /// Same fixing one day earlier
+    PreviousDay,
+    /// Price quoted in the market question
+    Fixed(Decimal),
+}
+
+/// Side that wins when the final price equals the strike.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum TieRule {
+    Up,
+    Down,
+}
+
+/// Resolution semantics of one market.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+pub struct SettlementRule {
+    pub source: ReferenceSource,
+    pub strike: StrikeRule,
+    pub ties: TieRule,
+}
+
+/// Chainlink report used as a settlement fixing.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct ReferenceTick {
+    pub timestamp: DateTime<Utc>,
+    pub price: Decimal,
+}
+
+/// Historical prices a rule can read from. Both slices must be sorted by
+/// timestamp; `candles` must hold the interval named by the rule's source.
+#[derive(Debug, Clone, Copy, Default)]
+pub struct ReferencePrices<'a> {
+    pub chainlink: &'a [ReferenceTick],
+    pub candles: &'a [KlineRecord],
+}
+
+/// Settled label for one round.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct RoundSettlement {
+    pub strike: Decimal,
+    pub final_price: Decimal,
+    /// Up (or Yes for threshold markets) won
+    pub up_won: bool,
+    /// Final price landed exactly on the strike
+    pub tie: bool,
+}
+
+impl SettlementRule {
+    /// 5m / 15m / 4h up/down series.
+    pub fn chainlink_window() -> Self {
+        Self {
+            source: ReferenceSource::Chainlink,
+            strike: StrikeRule::WindowOpen,
+            ties: TieRule::Up,
+        }
+    }
+
+    /// Hourly up/down series.
+    pub fn binance_hourly() -> Self {
+        Self {
+            source: ReferenceSource::BinanceCandle {
+                interval_secs: 60 * 60,
+            },
+            strike: StrikeRule::WindowOpen,
+            ties: TieRule::Up,
+        }
+    }
+
+    /// Daily up/down series (12:00 ET fixing vs. the prior day's).
+    pub fn binance_daily() -> Self {
+        Self {
+            source: ReferenceSource::BinanceCandle { interval_secs: 60 },
+            strike: StrikeRule::PreviousDay,
+            ties: TieRule::Up,
+        }
+    }
+
+    /// "Will X be above $strike" markets; a tie is not "above".
+    pub fn binance_threshold(strike: Decimal) -> Self {
+        Self {
+            source: ReferenceSource::BinanceCandle { interval_secs: 60 },
+            strike: StrikeRule::Fixed(strike),
+            ties: TieRule::Down,
+        }
+    }
+
+    /// Rule for a market, from its slug and round length. Markets that are
+    /// not up/down rounds need the quoted `threshold`.
+    pub fn for_market(slug: &str, window_secs: i64, threshold: Option<Decimal>) -> Option<Self> {
+        let slug = slug.to_ascii_lowercase();
+        if !(slug.contains("updown") || slug.contains("up-or-down")) {
+            return threshold.map(Self::binance_threshold);
+        }
+        if slug.split('-').any(|t| t == "4h") {
+            return Some(Self::chainlink_window());
+        }
+        let timeframe =
+            Timeframe::from_hint(&slug).or_else(|| Timeframe::from_duration_secs(window_secs))?;
+        match timeframe {
+            Timeframe::M5 | Timeframe::M15 => Some(Self::chainlink_window()),
+            Timeframe::H1 => Some(Self::binance_hourly()),
+            Timeframe::D1 => Some(Self::binance_daily()),
+            Timeframe::Other(_) => None,
+        }
+    }
+
+    /// Outcome for a strike / final price pair.
+    pub fn resolve(&self, strike: Decimal, final_price: Decimal) -> bool {
+        if final_price == strike {
+            return self.ties == TieRule::Up;
+        }
+        final_price > strike
+    }
+
+    /// Settle a round from historical prices. `None` when a fixing is
+    /// missing, rather than guessing a label.
+    pub fn settle(
+        &self,
+        window_start: DateTime<Utc>,
+        window_end: DateTime<Utc>,
+        prices: ReferencePrices<'_>,
+    ) -> Option<RoundSettlement> {
+        let (strike, final_price) = match self.source {
+            ReferenceSource::Chainlink => {
+                let final_price = chainlink_price_at(prices.chainlink, window_end)?;
+                let strike = match self.strike {
+                    StrikeRule::WindowOpen => chainlink_price_at(prices.chainlink, window_start)?,
+                    StrikeRule::PreviousDay => {
+                        chainlink_price_at(prices.chainlink, window_end - Duration::days(1))?
+                    }
+                    StrikeRule::Fixed(strike) => strike,
+                };
+                (strike, final_price)
+            }
+            ReferenceSource::BinanceCandle { .. } => match self.strike {
+                // One candle spans the whole window.
+                StrikeRule::WindowOpen => {
+                    let candle = candle_at(prices.candles, window_start)?;
+                    (candle.open, candle.close)
+                }
+                StrikeRule::PreviousDay => {
+                    let previous = candle_at(prices.candles, window_end - Duration::days(1))?;
+                    (previous.close, candle_at(prices.candles, window_end)?.close)
+                }
+                StrikeRule::Fixed(strike) => (strike, candle_at(prices.candles, window_end)?.close),
+            },
+        };
+
+        Some(RoundSettlement {
+            strike,
+            final_price,
+            up_won: self.resolve(strike, final_price),
+            tie: strike == final_price,
+        })
+    }
+}
+
+/// Last Chainlink report at or before `at`, if fresh enough.
+pub fn chainlink_price_at(ticks: &[ReferenceTick], at: DateTime<Utc>) -> Option<Decimal> {
+    let idx = ticks.partition_point(|t| t.timestamp <= at);
+    let tick = ticks.get(idx.checked_sub(1)?)?;
+    if (at - tick.timestamp).num_seconds() > CHAINLINK_MAX_STALENESS_SECS {
+        return None;
+    }
+    Some(tick.price)
+}
+
+/// Candle opening exactly at `open_time`.
+fn candle_at(candles: &[KlineRecord], open_time: DateTime<Utc>) -> Option<&KlineRecord> {
+    candles
+        .binary_search_by_key(&open_time, |k| k.timestamp)
+        .ok()
+        .map(|idx| &candles[idx])
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use chrono::TimeZone;
+    use rust_decimal_macros::dec;
+
+    fn ts(secs: i64) -> DateTime<Utc> {
+        Utc.timestamp_opt(secs, 0).unwrap()
+    }
+
+    fn tick(secs: i64, price: Decimal) -> ReferenceTick {
+        ReferenceTick {
+            timestamp: ts(secs),
+            price,
+        }
+    }
+
+    fn candle(secs: i64, open: Decimal, close: Decimal) -> KlineRecord {
+        KlineRecord {
+            timestamp: ts(secs),
+            symbol: "BTCUSDT".to_string(),
+            open,
+            high: open.max(close),
+            low: open.min(close),
+            close,
+            volume: dec!(1),
+        }
+    }
+
+    #[test]
+    fn test_chainlink_rounds_use_window_open_and_resolve_ties_up() {
+        // btc-updown-15m-1760000400: 2025-10-09 09:00–09:15 UTC
+        let start = 1_760_000_400;
+        let end = start + 900;
+        let rule = SettlementRule::for_market("btc-updown-15m-1760000400", 900, None).unwrap();
+        assert_eq!(rule, SettlementRule::chainlink_window());
+
+        let ticks = vec![
+            tick(start - 1, dec!(121950.10)),
+            tick(start + 1, dec!(122010.00)),
+            tick(end - 2, dec!(121950.10)),
+            tick(end + 1, dec!(121800.00)),
+        ];
+        let prices = ReferencePrices {
+            chainlink: &ticks,
+            candles: &[],
+        };
+        // Unchanged stream price at the boundaries: a tie, which settles Up
+        // even though the report right after the close is lower.
+        let settled = rule.settle(ts(start), ts(end), prices).unwrap();
+        assert_eq!(settled.strike, dec!(121950.10));
+        assert!(settled.tie);
+        assert!(settled.up_won);
+
+        // Stale stream: no label rather than a guess.
+        let stale = vec![tick(start - 120, dec!(1)), tick(end, dec!(2))];
+        let prices = ReferencePrices {
+            chainlink: &stale,
+            candles: &[],
+        };
+        assert!(rule.settle(ts(start), ts(end), prices).is_none());
+    }
+
+    #[test]
+    fn test_candle_rounds_hourly_daily_and_threshold() {
+        let hour = 1_760_004_000;
+        let hourly =
+            SettlementRule::for_market("bitcoin-up-or-down-october-9-6am-et", 3600, None).unwrap();
+        assert_eq!(hourly, SettlementRule::binance_hourly());
+        let candles = vec![candle(hour, dec!(122100), dec!(122099.99))];
+        let prices = ReferencePrices {
+            chainlink: &[],
+            candles: &candles,
+        };
+        assert!(
+            !hourly
+                .settle(ts(hour), ts(hour + 3600), prices)
+                .unwrap()
+                .up_won
+        );
+
+        // Daily: 12:00 ET fixing vs. the prior day's; a flat day settles Up.
+        let noon = 1_760_025_600;
+        let daily =
+            SettlementRule::for_market("bitcoin-up-or-down-on-october-9", 86_400, None).unwrap();
+        assert_eq!(daily, SettlementRule::binance_daily());
+        let candles = vec![
+            candle(noon - 86_400, dec!(120500), dec!(121000)),
+            candle(noon, dec!(120900), dec!(121000)),
+        ];
+        let prices = ReferencePrices {
+            chainlink: &[],
+            candles: &candles,
+        };
+        let settled = daily.settle(ts(noon - 86_400), ts(noon), prices).unwrap();
+        assert!(settled.tie && settled.up_won);
+
+        // Threshold: landing exactly on the strike is not "above".
+        let above = SettlementRule::for_market(
+            "bitcoin-above-121k-on-october-9",
+            86_400,
+            Some(dec!(121000)),
+        )
+        .unwrap();
+        assert!(
+            !above
+                .settle(ts(noon - 86_400), ts(noon), prices)
+                .unwrap()
+                .up_won
+        );
+        assert!(SettlementRule::for_market("bitcoin-above-121k", 86_400, None).is_none());
+    }
+}
@@ -0,0 +1,352 @@
+//! Backtest run comparison.
+//!
+//! Loads two stored `BacktestResults` JSON reports (as written by
+//! `ploy strategy backtest ... --output <path>`) and reports metric deltas,
+//! per-day and per-symbol breakdowns, and regressions beyond a tolerance.
+//!
+//! Usage:
+//!   ploy backtest diff runs/before.json runs/after.json --tolerance 0.05
+
+use std::collections::{BTreeSet, HashMap};
+use std::fmt;
+use std::path::Path;
+
+use rust_decimal::prelude::*;
+use rust_decimal::Decimal;
+use serde::Serialize;
+
+use crate::error::{PloyError, Result};
+use crate::strategy::backtest::{BacktestResults, SymbolStats};
+
+/// Load a stored backtest report from disk.
+pub fn load_backtest_report<P: AsRef<Path>>(path: P) -> Result<BacktestResults> {
+    let path = path.as_ref();
+    let raw = std::fs::read_to_string(path)?;
+    serde_json::from_str(&raw).map_err(|e| {
+        PloyError::Validation(format!(
+            "{} is not a backtest report: {}",
+            path.display(),
+            e
+        ))
+    })
+}
+
+/// Which direction of change counts as an improvement.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize)]
+#[serde(rename_all = "snake_case")]
+pub enum MetricDirection {
+    HigherIsBetter,
+    LowerIsBetter,
+    /// Reported but never flagged (e.g. trade count)
+    Informational,
+}
+
+/// One headline metric compared across runs.
+#[derive(Debug, Clone, Serialize)]
+pub struct MetricDelta {
+    pub name: &'static str,
+    pub run_a: f64,
+    pub run_b: f64,
+    pub delta: f64,
+    pub direction: MetricDirection,
+    pub regression: bool,
+}
+
+/// Per-day or per-symbol comparison row.
+#[derive(Debug, Clone, Serialize)]
+pub struct BreakdownDelta {
+    pub key: String,
+    pub trades_a: u64,
+    pub trades_b: u64,
+    pub pnl_a: Decimal,
+    pub pnl_b: Decimal,
+    pub pnl_delta: Decimal,
+    pub win_rate_a: f64,
+    pub win_rate_b: f64,
+    pub regression: bool,
+}
+
+/// Full comparison of run B against baseline run A.
+#[derive(Debug, Clone, Serialize)]
+pub struct BacktestDiff {
+    pub tolerance: f64,
+    pub metrics: Vec<MetricDelta>,
+    pub by_day: Vec<BreakdownDelta>,
+    pub by_symbol: Vec<BreakdownDelta>,
+}
+
+impl BacktestDiff {
+    /// Compare `run_b` against the baseline `run_a`.
+    ///
+    /// A metric regresses when it moves in the bad direction by more than
+    /// `tolerance` (relative to the baseline magnitude). With a zero
+    /// baseline any move in the bad direction is a regression.
+    pub fn compute(run_a: &BacktestResults, run_b: &BacktestResults, tolerance: f64) -> Self {
+        let tolerance = tolerance.max(0.0);
+        let mut metrics = vec![
+            metric(
+                "ev_per_trade",
+                dec_f64(run_a.avg_pnl_per_trade),
+                dec_f64(run_b.avg_pnl_per_trade),
+                MetricDirection::HigherIsBetter,
+                tolerance,
+            ),
+            metric(
+                "total_pnl",
+                dec_f64(run_a.total_pnl),
+                dec_f64(run_b.total_pnl),
+                MetricDirection::HigherIsBetter,
+                tolerance,
+            ),
+            metric(
+                "trades",
+                run_a.total_trades as f64,
+                run_b.total_trades as f64,
+                MetricDirection::Informational,
+                tolerance,
+            ),
+            metric(
+                "win_rate",
+                run_a.win_rate,
+                run_b.win_rate,
+                MetricDirection::HigherIsBetter,
+                tolerance,
+            ),
+            metric(
+                "max_drawdown",
+                dec_f64(run_a.max_drawdown),
+                dec_f64(run_b.max_drawdown),
+                MetricDirection::LowerIsBetter,
+                tolerance,
+            ),
+            metric(
+                "sharpe",
+                run_a.sharpe_ratio,
+                run_b.sharpe_ratio,
+                MetricDirection::HigherIsBetter,
+                tolerance,
+            ),
+        ];
+        // Older reports carry no fill accounting; skip rather than report 0%.
+        if let (Some(a), Some(b)) = (run_a.fill_rate(), run_b.fill_rate()) {
+            metrics.insert(
+                2,
+                metric(
+                    "fill_rate",
+                    a,
+                    b,
+                    MetricDirection::HigherIsBetter,
+                    tolerance,
+                ),
+            );
+        }
+
+        Self {
+            tolerance,
+            metrics,
+            by_day: breakdown(&run_a.trades_by_day, &run_b.trades_by_day, tolerance),
+            by_symbol: breakdown(&run_a.trades_by_symbol, &run_b.trades_by_symbol, tolerance),
+        }
+    }
+
+    /// Whether any headline metric or breakdown row regressed.
+    pub fn has_regressions(&self) -> bool {
+        self.metrics.iter().any(|m| m.regression)
+            || self.by_day.iter().any(|r| r.regression)
+            || self.by_symbol.iter().any(|r| r.regression)
+    }
+}
+
+fn dec_f64(value: Decimal) -> f64 {
+    value.to_f64().unwrap_or(0.0)
+}
+
+fn is_regression(a: f64, b: f64, direction: MetricDirection, tolerance: f64) -> bool {
+    let worsening = match direction {
+        MetricDirection::HigherIsBetter => a - b,
+        MetricDirection::LowerIsBetter => b - a,
+        MetricDirection::Informational => return false,
+    };
+    if !worsening.is_finite() || worsening <= 0.0 {
+        return false;
+    }
+    worsening > a.abs() * tolerance
+}
+
+fn metric(
+    name: &'static str,
+    run_a: f64,
+    run_b: f64,
+    direction: MetricDirection,
+    tolerance: f64,
+) -> MetricDelta {
+    MetricDelta {
+        name,
+        run_a,
+        run_b,
+        delta: run_b - run_a,
+        direction,
+        regression: is_regression(run_a, run_b, direction, tolerance),
+    }
+}
+
+fn breakdown(
+    a: &HashMap<String, SymbolStats>,
+    b: &HashMap<String, SymbolStats>,
+    tolerance: f64,
+) -> Vec<BreakdownDelta> {
+    let keys: BTreeSet<&String> = a.keys().chain(b.keys()).collect();
+    let empty = SymbolStats::default();
+    keys.into_iter()
+        .map(|key| {
+            let sa = a.get(key).unwrap_or(&empty);
+            let sb = b.get(key).unwrap_or(&empty);
+            BreakdownDelta {
+                key: key.clone(),
+                trades_a: sa.total_trades,
+                trades_b: sb.total_trades,
+                pnl_a: sa.total_pnl,
+                pnl_b: sb.total_pnl,
+                pnl_delta: sb.total_pnl - sa.total_pnl,
+                win_rate_a: sa.win_rate,
+                win_rate_b: sb.win_rate,
+                regression: is_regression(
+                    dec_f64(sa.total_pnl),
+                    dec_f64(sb.total_pnl),
+                    MetricDirection::HigherIsBetter,
+                    tolerance,
+                ),
+            }
+        })
+        .collect()
+}
+
+fn write_breakdown(
+    f: &mut fmt::Formatter<'_>,
+    title: &str,
+    rows: &[BreakdownDelta],
+) -> fmt::Result {
+    if rows.is_empty() {
+        return Ok(());
+    }
+    writeln!(f)?;
+    writeln!(f, "--- {} ---", title)?;
+    writeln!(
+        f,
+        "{:<12} {:>8} {:>8} {:>12} {:>12} {:>12}",
+        "key", "trades_a", "trades_b", "pnl_a", "pnl_b", "Δ pnl"
+    )?;
+    for row in rows {
+        writeln!(
+            f,
+            "{:<12} {:>8} {:>8} {:>12.2} {:>12.2} {:>+12.2}{}",
+            row.key,
+            row.trades_a,
+            row.trades_b,
+            row.pnl_a,
+            row.pnl_b,
+            row.pnl_delta,
+            if row.regression { "  REGRESSION" } else { "" }
+        )?;
+    }
+    Ok(())
+}
+
+impl fmt::Display for BacktestDiff {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        writeln!(
+            f,
+            "=== Backtest Diff (B vs A, tolerance {:.1}%) ===",
+            self.tolerance * 100.0
+        )?;
+        writeln!(
+            f,
+            "{:<14} {:>12} {:>12} {:>12}",
+            "metric", "run_a", "run_b", "Δ"
+        )?;
+        for m in &self.metrics {
+            writeln!(
+                f,
+                "{:<14} {:>12.4} {:>12.4} {:>+12.4}{}",
+                m.name,
+                m.run_a,
+                m.run_b,
+                m.delta,
+                if m.regression { "  REGRESSION" } else { "" }
+            )?;
+        }
+        write_breakdown(f, "By day", &self.by_day)?;
+        write_breakdown(f, "By symbol", &self.by_symbol)?;
+        writeln!(f)?;
+        if self.has_regressions() {
+            writeln!(f, "Regressions detected beyond tolerance.")
+        } else {
+            writeln!(f, "No regressions beyond tolerance.")
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use rust_decimal_macros::dec;
+
+    fn run(ev: Decimal, drawdown: Decimal, filled: u64) -> BacktestResults {
+        let mut results = BacktestResults {
+            total_trades: 10,
+            avg_pnl_per_trade: ev,
+            total_pnl: ev * dec!(10),
+            max_drawdown: drawdown,
+            requested_shares: 1000,
+            filled_shares: filled,
+            ..Default::default()
+        };
+        for (day, pnl) in [("2026-10-01", dec!(5)), ("2026-10-02", dec!(-1))] {
+            results
+                .trades_by_day
+                .entry(day.to_string())
+                .or_default()
+                .record(pnl, pnl > Decimal::ZERO);
+        }
+        results
+    }
+
+    #[test]
+    fn test_identical_runs_have_no_regressions() {
+        let a = run(dec!(0.5), dec!(0.10), 900);
+        let diff = BacktestDiff::compute(&a, &a.clone(), 0.05);
+        assert!(!diff.has_regressions());
+        assert!(diff.metrics.iter().any(|m| m.name == "fill_rate"));
+        assert_eq!(diff.by_day.len(), 2);
+    }
+
+    #[test]
+    fn test_flags_worse_ev_fill_rate_and_drawdown() {
+        let a = run(dec!(0.5), dec!(0.10), 900);
+        let mut b = run(dec!(0.4), dec!(0.12), 800);
+        b.trades_by_day
+            .entry("2026-10-03".to_string())
+            .or_default()
+            .record(dec!(-3), false);
+
+        let diff = BacktestDiff::compute(&a, &b, 0.05);
+        let flagged: Vec<&str> = diff
+            .metrics
+            .iter()
+            .filter(|m| m.regression)
+            .map(|m| m.name)
+            .collect();
+        assert!(flagged.contains(&"ev_per_trade"));
+        assert!(flagged.contains(&"fill_rate"));
+        assert!(flagged.contains(&"max_drawdown"));
+        assert!(!flagged.contains(&"trades"));
+
+        let new_day = diff.by_day.iter().find(|r| r.key == "2026-10-03").unwrap();
+        assert_eq!(new_day.trades_a, 0);
+        assert!(new_day.regression);
+
+        // A small move within tolerance is not flagged.
+        let c = run(dec!(0.49), dec!(0.10), 900);
+        assert!(!BacktestDiff::compute(&a, &c, 0.05).has_regressions());
+    }
+}
@@ -72,6 +72,7 @@ pub trait MarketFeed {
 /// All data is loaded upfront into a `VecDeque`, sorted by timestamp.
 /// This guarantees deterministic replay with no lookahead bias — each
 /// `next_update()` call returns the chronologically next event.
+#[derive(Clone)]
 pub struct HistoricalFeed {
     pub(crate) updates: VecDeque<MarketUpdate>,
 }
@@ -86,6 +87,18 @@ impl HistoricalFeed {
         self.updates.is_empty()
     }
 
+    /// Content hash of the remaining updates, used as the data snapshot id in
+    /// research reports. Identical inputs replay identically.
+    pub fn snapshot_id(&self) -> String {
+        use sha2::{Digest, Sha256};
+
+        let mut hasher = Sha256::new();
+        for update in &self.updates {
+            hasher.update(format!("{:?}\n", update).as_bytes());
+        }
+        hex::encode(&hasher.finalize()[..8])
+    }
+
     // ─── DB loader ───────────────────────────────────────────
 
     /// Load historical data from database tables:
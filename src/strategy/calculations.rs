@@ -3,7 +3,11 @@
 //! Consolidates common calculation patterns from across the codebase
 //! to ensure consistency and reduce duplication.
 
+use crate::domain::Quote;
+use chrono::{DateTime, Utc};
+use rust_decimal::prelude::FromPrimitive;
 use rust_decimal::Decimal;
+use serde::{Deserialize, Serialize};
 
 // =============================================================================
 // Trading Calculator
@@ -453,6 +457,120 @@ impl MarketCalibration {
     }
 }
 
+// =============================================================================
+// Mid-Price Estimation
+// =============================================================================
+
+/// How a fair mid price is estimated from top-of-book quotes
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum MidPriceMethod {
+    /// Raw (bid + ask) / 2
+    #[default]
+    Mid,
+    /// Size-weighted microprice: leans toward the side with less resting size
+    Microprice,
+    /// Time-decayed EWMA of the microprice
+    Ewma,
+}
+
+/// Mid-price estimator configuration
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(default)]
+pub struct MidPriceConfig {
+    pub method: MidPriceMethod,
+    /// EWMA half-life in milliseconds (only used by `Ewma`)
+    pub ewma_half_life_ms: u64,
+}
+
+impl Default for MidPriceConfig {
+    fn default() -> Self {
+        Self {
+            method: MidPriceMethod::Mid,
+            ewma_half_life_ms: 2_000,
+        }
+    }
+}
+
+/// Size-weighted microprice.
+///
+/// `(bid * ask_size + ask * bid_size) / (bid_size + ask_size)`; falls back to
+/// the raw mid when both sizes are zero.
+pub fn microprice(bid: Decimal, ask: Decimal, bid_size: Decimal, ask_size: Decimal) -> Decimal {
+    let total = bid_size + ask_size;
+    if total <= Decimal::ZERO {
+        return (bid + ask) / Decimal::from(2);
+    }
+    (bid * ask_size + ask * bid_size) / total
+}
+
+/// Bid-ask bounce-resistant mid-price estimator for one token
+#[derive(Debug, Clone)]
+pub struct MidPriceEstimator {
+    config: MidPriceConfig,
+    last: Option<(DateTime<Utc>, Decimal)>,
+}
+
+impl MidPriceEstimator {
+    pub fn new(config: MidPriceConfig) -> Self {
+        Self { config, last: None }
+    }
+
+    /// Feed a quote and return the updated estimate.
+    ///
+    /// One-sided quotes use the available side; empty quotes return None and
+    /// leave the estimate untouched.
+    pub fn update(&mut self, quote: &Quote) -> Option<Decimal> {
+        let instant = Self::instant_price(self.config.method, quote)?;
+        Some(self.update_price(instant, quote.timestamp))
+    }
+
+    /// Feed a single price with no book behind it (e.g. a spot trade) and
+    /// return the updated estimate. Only the EWMA smoothing applies.
+    pub fn update_price(&mut self, instant: Decimal, timestamp: DateTime<Utc>) -> Decimal {
+        let estimate = match (self.config.method, self.last) {
+            (MidPriceMethod::Ewma, Some((prev_ts, prev))) if self.config.ewma_half_life_ms > 0 => {
+                let dt_ms = (timestamp - prev_ts).num_milliseconds().max(0) as f64;
+                let half_lives = dt_ms / self.config.ewma_half_life_ms as f64;
+                let alpha = Decimal::from_f64(1.0 - 0.5f64.powf(half_lives))
+                    .unwrap_or(Decimal::ONE)
+                    .clamp(Decimal::ZERO, Decimal::ONE);
+                prev + alpha * (instant - prev)
+            }
+            _ => instant,
+        };
+
+        self.last = Some((timestamp, estimate));
+        estimate
+    }
+
+    /// Latest estimate
+    pub fn value(&self) -> Option<Decimal> {
+        self.last.map(|(_, price)| price)
+    }
+
+    pub fn reset(&mut self) {
+        self.last = None;
+    }
+
+    fn instant_price(method: MidPriceMethod, quote: &Quote) -> Option<Decimal> {
+        if method == MidPriceMethod::Mid {
+            return quote.mid_price();
+        }
+        match (
+            quote.best_bid,
+            quote.best_ask,
+            quote.bid_size,
+            quote.ask_size,
+        ) {
+            (Some(bid), Some(ask), Some(bid_size), Some(ask_size)) => {
+                Some(microprice(bid, ask, bid_size, ask_size))
+            }
+            _ => quote.mid_price(),
+        }
+    }
+}
+
 // =============================================================================
 // Helper Functions
 // =============================================================================
@@ -680,4 +798,65 @@ mod tests {
         let b2 = MarketCalibration::brier_contribution(0.9, false);
         assert!((b2 - 0.81).abs() < 1e-10);
     }
+
+    fn quote(bid: Decimal, ask: Decimal, bid_size: Decimal, ask_size: Decimal, ms: i64) -> Quote {
+        Quote {
+            side: crate::domain::Side::Up,
+            best_bid: Some(bid),
+            best_ask: Some(ask),
+            bid_size: Some(bid_size),
+            ask_size: Some(ask_size),
+            timestamp: DateTime::<Utc>::UNIX_EPOCH + chrono::Duration::milliseconds(ms),
+        }
+    }
+
+    #[test]
+    fn test_microprice_leans_to_thin_side() {
+        // Thin ask (10) vs deep bid (90): fair price sits near the ask
+        assert_eq!(
+            microprice(dec!(0.40), dec!(0.50), dec!(90), dec!(10)),
+            dec!(0.49)
+        );
+        assert_eq!(
+            microprice(dec!(0.40), dec!(0.50), dec!(0), dec!(0)),
+            dec!(0.45)
+        );
+    }
+
+    #[test]
+    fn test_ewma_mid_dampens_bounce() {
+        let config = MidPriceConfig {
+            method: MidPriceMethod::Ewma,
+            ewma_half_life_ms: 1_000,
+        };
+        let mut estimator = MidPriceEstimator::new(config);
+        let mut raw = MidPriceEstimator::new(MidPriceConfig::default());
+
+        // Book flips between bid-heavy and ask-heavy every 100ms
+        let mut ewma_range = (Decimal::MAX, Decimal::MIN);
+        for i in 0..60 {
+            let (bid_size, ask_size) = if i % 2 == 0 {
+                (dec!(90), dec!(10))
+            } else {
+                (dec!(10), dec!(90))
+            };
+            let q = quote(dec!(0.40), dec!(0.50), bid_size, ask_size, i * 100);
+            let est = estimator.update(&q).unwrap();
+            assert_eq!(raw.update(&q), Some(dec!(0.45)));
+            if i >= 40 {
+                ewma_range = (ewma_range.0.min(est), ewma_range.1.max(est));
+            }
+        }
+
+        // Raw microprice swings 0.41..0.49; the EWMA stays within a cent
+        assert!(ewma_range.1 - ewma_range.0 < dec!(0.01));
+
+        // Empty quotes leave the estimate untouched
+        let last = estimator.value();
+        let mut empty = quote(dec!(0), dec!(0), dec!(0), dec!(0), 10_000);
+        empty.best_bid = None;
+        empty.best_ask = None;
+        assert_eq!(estimator.update(&empty), None);
+        assert_eq!(estimator.value(), last);
+    }
 }
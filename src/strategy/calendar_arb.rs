@@ -0,0 +1,662 @@
+//! `calendar_arb` strategy.
+//!
+//! Maintains an implied-probability surface per underlying from concurrent
+//! 5m/15m/1h rounds and trades co-terminal inconsistencies: when rounds settle
+//! at the same instant with strikes `K_lo <= K_hi`, buying `UP(K_lo)` and
+//! `DOWN(K_hi)` pays at least $1, so a combined ask below `1 - min_edge` is
+//! locked-in edge. Total-variance inversions across horizons are logged for
+//! research but not traded.
+
+use crate::analysis::round_surface::{CalendarArbSignal, RoundSurface, RoundSurfaceConfig};
+use crate::domain::{OrderRequest, OrderStatus, Side};
+use crate::error::{PloyError, Result};
+use crate::strategy::traits::{
+    AlertLevel, DataFeed, MarketUpdate, OrderUpdate, PositionInfo, RiskLevel, Strategy,
+    StrategyAction, StrategyEvent, StrategyEventType, StrategyStateInfo,
+};
+use async_trait::async_trait;
+use chrono::{DateTime, Utc};
+use rust_decimal::Decimal;
+use serde::Deserialize;
+use std::collections::{HashMap, HashSet};
+use tracing::{debug, info, warn};
+
+#[derive(Debug, Clone, Deserialize)]
+struct MarketMapping {
+    symbol: String,
+    series_id: String,
+}
+
+/// Sizing and entry limits
+#[derive(Debug, Clone, Deserialize)]
+#[serde(default)]
+pub struct CalendarArbTradeConfig {
+    /// Shares per leg
+    pub shares: u64,
+    /// Maximum round pairs held at once
+    pub max_open_pairs: usize,
+    /// Do not enter pairs settling sooner than this
+    pub min_remaining_secs: i64,
+    /// Emit a log event for each total-variance inversion
+    pub log_variance_inversions: bool,
+}
+
+impl Default for CalendarArbTradeConfig {
+    fn default() -> Self {
+        Self {
+            shares: 50,
+            max_open_pairs: 2,
+            min_remaining_secs: 30,
+            log_variance_inversions: true,
+        }
+    }
+}
+
+#[derive(Debug, Clone, Deserialize)]
+struct Config {
+    markets: Vec<MarketMapping>,
+    #[serde(default)]
+    surface: RoundSurfaceConfig,
+    #[serde(default)]
+    trade: CalendarArbTradeConfig,
+}
+
+/// An entered round pair
+#[derive(Debug, Clone)]
+struct OpenPair {
+    signal: CalendarArbSignal,
+    up_filled: u64,
+    down_filled: u64,
+    opened_at: DateTime<Utc>,
+}
+
+#[derive(Debug, Clone)]
+struct PendingLeg {
+    pair_key: String,
+    side: Side,
+}
+
+pub struct CalendarArbStrategy {
+    id: String,
+    dry_run: bool,
+    cfg: Config,
+    enabled: bool,
+
+    symbol_by_series: HashMap<String, String>,
+
+    surfaces: HashMap<String, RoundSurface>, // symbol -> surface
+    spot: HashMap<String, Decimal>,          // symbol -> last Binance price
+    pairs: HashMap<String, OpenPair>,        // pair_key -> pair
+    pending: HashMap<String, PendingLeg>,    // client_order_id -> leg
+    reported_inversions: HashSet<String>,
+    realized_pnl: Decimal,
+}
+
+impl CalendarArbStrategy {
+    pub fn from_toml(id: String, config_str: &str, dry_run: bool) -> Result<Self> {
+        let cfg: Config = toml::from_str(config_str)
+            .map_err(|e| PloyError::Internal(format!("Invalid calendar_arb config: {e}")))?;
+        if cfg.markets.is_empty() {
+            return Err(PloyError::Internal("Missing [[markets]]".to_string()));
+        }
+
+        let symbol_by_series = cfg
+            .markets
+            .iter()
+            .map(|m| (m.series_id.clone(), m.symbol.clone()))
+            .collect();
+
+        Ok(Self {
+            id,
+            dry_run,
+            cfg,
+            enabled: true,
+            symbol_by_series,
+            surfaces: HashMap::new(),
+            spot: HashMap::new(),
+            pairs: HashMap::new(),
+            pending: HashMap::new(),
+            reported_inversions: HashSet::new(),
+            realized_pnl: Decimal::ZERO,
+        })
+    }
+
+    fn symbols(&self) -> Vec<String> {
+        let mut symbols: Vec<String> = self.cfg.markets.iter().map(|m| m.symbol.clone()).collect();
+        symbols.sort();
+        symbols.dedup();
+        symbols
+    }
+
+    /// Scan one underlying's surface and act on what it finds
+    fn evaluate(&mut self, symbol: &str, now: DateTime<Utc>) -> Vec<StrategyAction> {
+        let mut actions = Vec::new();
+        if !self.enabled {
+            return actions;
+        }
+        let Some(surface) = self.surfaces.get(symbol) else {
+            return actions;
+        };
+
+        let signals = surface.coterminal_arbs(now);
+        let inversions = match (
+            self.cfg.trade.log_variance_inversions,
+            self.spot.get(symbol),
+        ) {
+            (true, Some(spot)) => surface.variance_inversions(*spot, now),
+            _ => Vec::new(),
+        };
+
+        for signal in signals {
+            if self.pairs.len() >= self.cfg.trade.max_open_pairs {
+                break;
+            }
+            if self.pairs.contains_key(&signal.pair_key()) {
+                continue;
+            }
+            let remaining = (signal.end_time - now).num_seconds();
+            if remaining < self.cfg.trade.min_remaining_secs {
+                debug!(
+                    "{} calendar arb {} skipped: {}s to settlement",
+                    symbol,
+                    signal.pair_key(),
+                    remaining
+                );
+                continue;
+            }
+            actions.extend(self.enter_pair(signal, now));
+        }
+
+        for inversion in inversions {
+            if !self.reported_inversions.insert(inversion.pair_key()) {
+                continue;
+            }
+            actions.push(StrategyAction::LogEvent {
+                event: StrategyEvent::new(
+                    StrategyEventType::SignalDetected,
+                    format!(
+                        "{} variance inversion: {} (τ={}s) w={:.3e} > {} (τ={}s) w={:.3e}",
+                        symbol,
+                        inversion.short_event_id,
+                        inversion.short_tau_secs,
+                        inversion.short_variance,
+                        inversion.long_event_id,
+                        inversion.long_tau_secs,
+                        inversion.long_variance,
+                    ),
+                ),
+            });
+        }
+
+        actions
+    }
+
+    fn enter_pair(&mut self, signal: CalendarArbSignal, now: DateTime<Utc>) -> Vec<StrategyAction> {
+        let shares = self.cfg.trade.shares;
+        let pair_key = signal.pair_key();
+        let mut actions = vec![StrategyAction::LogEvent {
+            event: StrategyEvent::new(
+                StrategyEventType::EntryTriggered,
+                format!(
+                    "{} calendar arb: UP {} @ {} + DOWN {} @ {} = {} (edge {})",
+                    signal.symbol,
+                    signal.up_event_id,
+                    signal.up_ask,
+                    signal.down_event_id,
+                    signal.down_ask,
+                    signal.cost,
+                    signal.edge,
+                ),
+            )
+            .with_data("pair", pair_key.clone())
+            .with_data("edge", signal.edge.to_string()),
+        }];
+
+        let legs = [
+            (Side::Up, signal.up_token.clone(), signal.up_ask),
+            (Side::Down, signal.down_token.clone(), signal.down_ask),
+        ];
+        for (side, token_id, price) in legs {
+            let client_order_id = format!(
+                "{}_{}_{}_{}",
+                self.id,
+                pair_key,
+                side.as_str().to_lowercase(),
+                now.timestamp_millis()
+            );
+            self.pending.insert(
+                client_order_id.clone(),
+                PendingLeg {
+                    pair_key: pair_key.clone(),
+                    side,
+                },
+            );
+            actions.push(StrategyAction::SubmitOrder {
+                client_order_id,
+                order: OrderRequest::buy_limit(token_id, side, shares, price),
+                priority: 9,
+            });
+        }
+
+        info!(
+            "{} entering calendar arb {} ({} shares/leg, edge {})",
+            self.id, pair_key, shares, signal.edge
+        );
+        self.pairs.insert(
+            pair_key,
+            OpenPair {
+                signal,
+                up_filled: 0,
+                down_filled: 0,
+                opened_at: now,
+            },
+        );
+        actions
+    }
+
+    /// Book locked-in edge for pairs whose rounds have settled
+    fn settle_pairs(&mut self, now: DateTime<Utc>) -> Vec<StrategyAction> {
+        let settled: Vec<String> = self
+            .pairs
+            .iter()
+            .filter(|(_, p)| p.signal.end_time <= now)
+            .map(|(k, _)| k.clone())
+            .collect();
+
+        let mut actions = Vec::new();
+        for key in settled {
+            let Some(pair) = self.pairs.remove(&key) else {
+                continue;
+            };
+            self.pending.retain(|_, leg| leg.pair_key != key);
+
+            let hedged = pair.up_filled.min(pair.down_filled);
+            let locked = Decimal::from(hedged) * pair.signal.edge;
+            self.realized_pnl += locked;
+            if pair.up_filled != pair.down_filled {
+                warn!(
+                    "{} calendar arb {} settled unbalanced (up {} / down {})",
+                    self.id, key, pair.up_filled, pair.down_filled
+                );
+            }
+            actions.push(StrategyAction::LogEvent {
+                event: StrategyEvent::new(
+                    StrategyEventType::CycleCompleted,
+                    format!("calendar arb {} settled", key),
+                )
+                .with_data("hedged_shares", hedged.to_string())
+                .with_data("locked_pnl", locked.to_string()),
+            });
+        }
+        actions
+    }
+}
+
+#[async_trait]
+impl Strategy for CalendarArbStrategy {
+    fn id(&self) -> &str {
+        &self.id
+    }
+
+    fn name(&self) -> &str {
+        "calendar_arb"
+    }
+
+    fn description(&self) -> &str {
+        "Co-terminal round arbitrage from the implied probability surface"
+    }
+
+    fn required_feeds(&self) -> Vec<DataFeed> {
+        let series_ids: Vec<String> = self
+            .cfg
+            .markets
+            .iter()
+            .map(|m| m.series_id.clone())
+            .collect();
+
+        vec![
+            DataFeed::BinanceSpot {
+                symbols: self.symbols(),
+            },
+            DataFeed::PolymarketEvents { series_ids },
+            DataFeed::Tick { interval_ms: 1000 },
+        ]
+    }
+
+    async fn on_market_update(&mut self, update: &MarketUpdate) -> Result<Vec<StrategyAction>> {
+        let mut actions: Vec<StrategyAction> = Vec::new();
+
+        match update {
+            MarketUpdate::PolymarketQuote {
+                token_id,
+                quote,
+                timestamp,
+                ..
+            } => {
+                let symbol = self.surfaces.iter_mut().find_map(|(symbol, surface)| {
+                    surface
+                        .update_quote(token_id, quote)
+                        .then(|| symbol.clone())
+                });
+                if let Some(symbol) = symbol {
+                    actions.extend(self.evaluate(&symbol, *timestamp));
+                }
+            }
+
+            MarketUpdate::BinancePrice { symbol, price, .. } => {
+                self.spot.insert(symbol.clone(), *price);
+            }
+
+            MarketUpdate::EventDiscovered {
+                event_id,
+                series_id,
+                up_token,
+                down_token,
+                end_time,
+                price_to_beat,
+                ..
+            } => {
+                let Some(symbol) = self.symbol_by_series.get(series_id).cloned() else {
+                    return Ok(actions);
+                };
+                let Some(strike) = price_to_beat else {
+                    debug!(
+                        "{} {} has no price_to_beat; not on surface",
+                        symbol, event_id
+                    );
+                    return Ok(actions);
+                };
+
+                let surface_config = self.cfg.surface.clone();
+                self.surfaces
+                    .entry(symbol.clone())
+                    .or_insert_with(|| RoundSurface::new(symbol.clone(), surface_config))
+                    .upsert_round(event_id, *strike, *end_time, up_token, down_token);
+
+                actions.push(StrategyAction::SubscribeFeed {
+                    feed: DataFeed::PolymarketQuotes {
+                        tokens: vec![up_token.clone(), down_token.clone()],
+                    },
+                });
+            }
+
+            MarketUpdate::EventExpired { event_id } => {
+                for surface in self.surfaces.values_mut() {
+                    surface.remove_round(event_id);
+                }
+            }
+
+            MarketUpdate::PolymarketBook { .. }
+            | MarketUpdate::BinanceKline { .. }
+            | MarketUpdate::RoundBoundary { .. } => {}
+        }
+
+        Ok(actions)
+    }
+
+    async fn on_order_update(&mut self, update: &OrderUpdate) -> Result<Vec<StrategyAction>> {
+        let mut actions = Vec::new();
+        let Some(client_order_id) = update.client_order_id.as_deref() else {
+            return Ok(actions);
+        };
+        let Some(leg) = self.pending.get(client_order_id).cloned() else {
+            return Ok(actions);
+        };
+
+        match update.status {
+            OrderStatus::Filled => {
+                self.pending.remove(client_order_id);
+                if let Some(pair) = self.pairs.get_mut(&leg.pair_key) {
+                    match leg.side {
+                        Side::Up => pair.up_filled += update.filled_qty,
+                        Side::Down => pair.down_filled += update.filled_qty,
+                    }
+                }
+            }
+            OrderStatus::Cancelled | OrderStatus::Rejected | OrderStatus::Expired => {
+                self.pending.remove(client_order_id);
+                warn!(
+                    "{} calendar arb leg {} {}: {:?}",
+                    self.id, leg.pair_key, leg.side, update.status
+                );
+                actions.push(StrategyAction::Alert {
+                    level: AlertLevel::Warning,
+                    message: format!(
+                        "calendar arb {} {} leg {:?} - pair may be unhedged",
+                        leg.pair_key, leg.side, update.status
+                    ),
+                });
+                actions.push(StrategyAction::UpdateRisk {
+                    level: RiskLevel::Elevated,
+                    reason: format!("calendar arb {} leg failed", leg.pair_key),
+                });
+            }
+            _ => {}
+        }
+
+        Ok(actions)
+    }
+
+    async fn on_tick(&mut self, now: DateTime<Utc>) -> Result<Vec<StrategyAction>> {
+        for surface in self.surfaces.values_mut() {
+            for event_id in surface.prune_expired(now) {
+                self.reported_inversions
+                    .retain(|key| !key.split('|').any(|id| id == event_id));
+            }
+        }
+        Ok(self.settle_pairs(now))
+    }
+
+    fn state(&self) -> StrategyStateInfo {
+        let mut metrics: HashMap<String, String> = HashMap::new();
+        for (symbol, surface) in &self.surfaces {
+            metrics.insert(format!("{}_rounds", symbol), surface.len().to_string());
+        }
+        metrics.insert("open_pairs".to_string(), self.pairs.len().to_string());
+
+        let positions = self.positions();
+        StrategyStateInfo {
+            strategy_id: self.id.clone(),
+            phase: if self.enabled { "running" } else { "disabled" }.to_string(),
+            enabled: self.enabled,
+            active: self.is_active(),
+            position_count: positions.len(),
+            pending_order_count: self.pending.len(),
+            total_exposure: positions
+                .iter()
+                .map(|p| p.entry_price * Decimal::from(p.shares))
+                .sum(),
+            unrealized_pnl: Decimal::ZERO,
+            realized_pnl_today: self.realized_pnl,
+            last_update: Utc::now(),
+            metrics,
+        }
+    }
+
+    fn positions(&self) -> Vec<PositionInfo> {
+        let mut positions = Vec::new();
+        for pair in self.pairs.values() {
+            let legs = [
+                (
+                    Side::Up,
+                    &pair.signal.up_token,
+                    pair.up_filled,
+                    pair.signal.up_ask,
+                ),
+                (
+                    Side::Down,
+                    &pair.signal.down_token,
+                    pair.down_filled,
+                    pair.signal.down_ask,
+                ),
+            ];
+            for (side, token_id, shares, price) in legs {
+                if shares == 0 {
+                    continue;
+                }
+                let mut position =
+                    PositionInfo::new(token_id.clone(), side, shares, price, self.id.clone());
+                position.opened_at = pair.opened_at;
+                position
+                    .metadata
+                    .insert("pair".to_string(), pair.signal.pair_key());
+                positions.push(position);
+            }
+        }
+        positions
+    }
+
+    fn is_active(&self) -> bool {
+        !self.pairs.is_empty() || !self.pending.is_empty()
+    }
+
+    async fn shutdown(&mut self) -> Result<Vec<StrategyAction>> {
+        self.enabled = false;
+        let mut actions: Vec<StrategyAction> = self
+            .pending
+            .keys()
+            .map(|client_order_id| StrategyAction::CancelOrder {
+                order_id: client_order_id.clone(),
+            })
+            .collect();
+        actions.push(StrategyAction::Alert {
+            level: AlertLevel::Info,
+            message: format!("{} shutdown (dry_run={})", self.id, self.dry_run),
+        });
+        Ok(actions)
+    }
+
+    fn reset(&mut self) {
+        self.surfaces.clear();
+        self.spot.clear();
+        self.pairs.clear();
+        self.pending.clear();
+        self.reported_inversions.clear();
+        self.realized_pnl = Decimal::ZERO;
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::domain::Quote;
+    use chrono::Duration;
+    use rust_decimal_macros::dec;
+
+    const CONFIG: &str = r#"
+        [strategy]
+        name = "calendar_arb"
+
+        [[markets]]
+        symbol = "BTCUSDT"
+        series_id = "btc-5m"
+
+        [[markets]]
+        symbol = "BTCUSDT"
+        series_id = "btc-15m"
+
+        [trade]
+        shares = 10
+    "#;
+
+    fn discovered(
+        event_id: &str,
+        series_id: &str,
+        strike: Decimal,
+        end: DateTime<Utc>,
+    ) -> MarketUpdate {
+        MarketUpdate::EventDiscovered {
+            event_id: event_id.to_string(),
+            series_id: series_id.to_string(),
+            up_token: format!("{}-up", event_id),
+            down_token: format!("{}-down", event_id),
+            end_time: end,
+            price_to_beat: Some(strike),
+            title: None,
+            window_secs: None,
+        }
+    }
+
+    fn quote(token_id: &str, side: Side, ask: Decimal, at: DateTime<Utc>) -> MarketUpdate {
+        MarketUpdate::PolymarketQuote {
+            token_id: token_id.to_string(),
+            side,
+            quote: Quote {
+                side,
+                best_bid: Some(ask - dec!(0.01)),
+                best_ask: Some(ask),
+                bid_size: Some(dec!(100)),
+                ask_size: Some(dec!(100)),
+                timestamp: at,
+            },
+            timestamp: at,
+            seconds_to_settlement: None,
+        }
+    }
+
+    #[tokio::test]
+    async fn test_enters_coterminal_pair_once_and_settles() {
+        let mut strategy = CalendarArbStrategy::from_toml("cal".to_string(), CONFIG, true).unwrap();
+        let now = Utc::now();
+        let end = now + Duration::minutes(4);
+
+        strategy
+            .on_market_update(&discovered("r15", "btc-15m", dec!(100000), end))
+            .await
+            .unwrap();
+        strategy
+            .on_market_update(&discovered("r5", "btc-5m", dec!(100200), end))
+            .await
+            .unwrap();
+
+        strategy
+            .on_market_update(&quote("r15-up", Side::Up, dec!(0.55), now))
+            .await
+            .unwrap();
+        let actions = strategy
+            .on_market_update(&quote("r5-down", Side::Down, dec!(0.40), now))
+            .await
+            .unwrap();
+        let orders: Vec<&OrderRequest> = actions
+            .iter()
+            .filter_map(|a| match a {
+                StrategyAction::SubmitOrder { order, .. } => Some(order),
+                _ => None,
+            })
+            .collect();
+        assert_eq!(orders.len(), 2);
+        assert_eq!(orders[0].token_id, "r15-up");
+        assert_eq!(orders[1].token_id, "r5-down");
+
+        // Same pair is not re-entered
+        let again = strategy
+            .on_market_update(&quote("r5-down", Side::Down, dec!(0.40), now))
+            .await
+            .unwrap();
+        assert!(!again
+            .iter()
+            .any(|a| matches!(a, StrategyAction::SubmitOrder { .. })));
+
+        // Both legs fill, then the rounds settle
+        let client_ids: Vec<String> = strategy.pending.keys().cloned().collect();
+        for client_order_id in client_ids {
+            strategy
+                .on_order_update(&OrderUpdate {
+                    order_id: format!("oid-{}", client_order_id),
+                    client_order_id: Some(client_order_id),
+                    status: OrderStatus::Filled,
+                    filled_qty: 10,
+                    avg_fill_price: None,
+                    timestamp: now,
+                    error: None,
+                })
+                .await
+                .unwrap();
+        }
+        assert_eq!(strategy.positions().len(), 2);
+
+        strategy.on_tick(end + Duration::seconds(1)).await.unwrap();
+        assert!(!strategy.is_active());
+        assert_eq!(strategy.state().realized_pnl_today, dec!(0.50));
+    }
+}
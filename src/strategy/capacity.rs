@@ -0,0 +1,347 @@
+//! Strategy capacity estimation from recorded depth.
+//!
+//! Replays a strategy's recorded entry signals (`signal_history`) against the
+//! Polymarket book in force at signal time (`clob_orderbook_depth_snapshots`)
+//! and measures how expected value degrades as the order grows. EV per share
+//! is the signal's fair value minus the average fill price of walking the
+//! asks for a given notional.
+//!
+//! Usage:
+//!   ploy research capacity crypto_momentum --days 14 --sizes 50,250,1000
+
+use std::collections::BTreeMap;
+
+use chrono::{DateTime, Utc};
+use rust_decimal::Decimal;
+use rust_decimal_macros::dec;
+use serde::{Deserialize, Serialize};
+use sqlx::PgPool;
+use tracing::debug;
+
+use crate::collector::{DepthBook, OrderbookDepthReader};
+use crate::error::Result;
+
+fn default_sizes_usd() -> Vec<Decimal> {
+    vec![
+        dec!(10),
+        dec!(25),
+        dec!(50),
+        dec!(100),
+        dec!(250),
+        dec!(500),
+        dec!(1000),
+        dec!(2500),
+        dec!(5000),
+    ]
+}
+
+fn default_min_ev_per_dollar() -> Decimal {
+    dec!(0.01)
+}
+
+fn default_min_fill_ratio() -> Decimal {
+    dec!(0.9)
+}
+
+fn default_max_book_age_secs() -> i64 {
+    60
+}
+
+/// Capacity estimation settings.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct CapacityConfig {
+    /// Order notionals (USD) to evaluate, ascending
+    #[serde(default = "default_sizes_usd")]
+    pub sizes_usd: Vec<Decimal>,
+    /// EV per filled dollar a size must keep to count as deployable
+    #[serde(default = "default_min_ev_per_dollar")]
+    pub min_ev_per_dollar: Decimal,
+    /// Share of the notional the book must absorb on average
+    #[serde(default = "default_min_fill_ratio")]
+    pub min_fill_ratio: Decimal,
+    /// Signals whose latest recorded book is older than this are skipped
+    #[serde(default = "default_max_book_age_secs")]
+    pub max_book_age_secs: i64,
+}
+
+impl Default for CapacityConfig {
+    fn default() -> Self {
+        Self {
+            sizes_usd: default_sizes_usd(),
+            min_ev_per_dollar: default_min_ev_per_dollar(),
+            min_fill_ratio: default_min_fill_ratio(),
+            max_book_age_secs: default_max_book_age_secs(),
+        }
+    }
+}
+
+/// One recorded entry signal with the book it would have traded against.
+#[derive(Debug, Clone)]
+pub struct CapacitySample {
+    pub recorded_at: DateTime<Utc>,
+    pub symbol: String,
+    pub token_id: String,
+    /// Model probability of the bought token settling at 1
+    pub fair_value: Decimal,
+    pub book: DepthBook,
+}
+
+/// Aggregate outcome of deploying one notional on every signal.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct CapacityPoint {
+    pub size_usd: Decimal,
+    /// Signals with any fill at this size
+    pub filled_signals: usize,
+    /// Filled notional / requested notional, averaged over signals
+    pub fill_ratio: Decimal,
+    /// Average fill price minus best ask, averaged over filled signals
+    pub avg_slippage: Decimal,
+    /// Total EV / total filled notional
+    pub ev_per_dollar: Decimal,
+    /// Summed EV across signals
+    pub expected_pnl: Decimal,
+}
+
+/// Capacity curve for one symbol.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct CapacityCurve {
+    pub symbol: String,
+    pub signals: usize,
+    pub points: Vec<CapacityPoint>,
+    /// Largest size for which it and every smaller size stays deployable
+    pub max_capacity_usd: Option<Decimal>,
+}
+
+/// Result of buying up to `notional` dollars from the asks.
+#[derive(Debug, Clone, Copy, PartialEq)]
+struct NotionalFill {
+    shares: Decimal,
+    spent: Decimal,
+}
+
+fn fill_notional(book: &DepthBook, notional: Decimal) -> Option<NotionalFill> {
+    let mut budget = notional;
+    let mut shares = Decimal::ZERO;
+    for level in &book.asks {
+        if budget <= Decimal::ZERO {
+            break;
+        }
+        if level.price <= Decimal::ZERO {
+            continue;
+        }
+        let take = (budget / level.price).min(level.size);
+        shares += take;
+        budget -= take * level.price;
+    }
+    (shares > Decimal::ZERO).then(|| NotionalFill {
+        shares,
+        spent: notional - budget,
+    })
+}
+
+fn evaluate_size(samples: &[&CapacitySample], size_usd: Decimal) -> CapacityPoint {
+    let mut filled_signals = 0usize;
+    let mut fill_ratio_sum = Decimal::ZERO;
+    let mut slippage_sum = Decimal::ZERO;
+    let mut spent_total = Decimal::ZERO;
+    let mut expected_pnl = Decimal::ZERO;
+
+    for sample in samples {
+        let (Some(fill), Some(best_ask)) = (
+            fill_notional(&sample.book, size_usd),
+            sample.book.best_ask(),
+        ) else {
+            continue;
+        };
+        filled_signals += 1;
+        fill_ratio_sum += fill.spent / size_usd;
+        slippage_sum += fill.spent / fill.shares - best_ask;
+        spent_total += fill.spent;
+        expected_pnl += fill.shares * sample.fair_value - fill.spent;
+    }
+
+    let ratio = |sum: Decimal, n: usize| {
+        if n == 0 {
+            Decimal::ZERO
+        } else {
+            sum / Decimal::from(n)
+        }
+    };
+    CapacityPoint {
+        size_usd,
+        filled_signals,
+        fill_ratio: ratio(fill_ratio_sum, samples.len()),
+        avg_slippage: ratio(slippage_sum, filled_signals),
+        ev_per_dollar: if spent_total > Decimal::ZERO {
+            expected_pnl / spent_total
+        } else {
+            Decimal::ZERO
+        },
+        expected_pnl,
+    }
+}
+
+/// Build one capacity curve per symbol from replayed samples.
+pub fn estimate_capacity(
+    samples: &[CapacitySample],
+    config: &CapacityConfig,
+) -> Vec<CapacityCurve> {
+    let mut by_symbol: BTreeMap<&str, Vec<&CapacitySample>> = BTreeMap::new();
+    for sample in samples {
+        by_symbol.entry(&sample.symbol).or_default().push(sample);
+    }
+
+    let mut sizes: Vec<Decimal> = config
+        .sizes_usd
+        .iter()
+        .copied()
+        .filter(|s| *s > Decimal::ZERO)
+        .collect();
+    sizes.sort();
+    sizes.dedup();
+
+    by_symbol
+        .into_iter()
+        .map(|(symbol, samples)| {
+            let points: Vec<CapacityPoint> = sizes
+                .iter()
+                .map(|size| evaluate_size(&samples, *size))
+                .collect();
+            let max_capacity_usd = points
+                .iter()
+                .take_while(|p| {
+                    p.ev_per_dollar >= config.min_ev_per_dollar
+                        && p.fill_ratio >= config.min_fill_ratio
+                })
+                .last()
+                .map(|p| p.size_usd);
+            CapacityCurve {
+                symbol: symbol.to_string(),
+                signals: samples.len(),
+                points,
+                max_capacity_usd,
+            }
+        })
+        .collect()
+}
+
+/// Load a strategy's recorded entry signals in `[from, to]` and pair each with
+/// the recorded book at signal time. Signals without a fresh book are skipped.
+pub async fn load_capacity_samples(
+    pool: &PgPool,
+    strategy: &str,
+    from: DateTime<Utc>,
+    to: DateTime<Utc>,
+    config: &CapacityConfig,
+) -> Result<Vec<CapacitySample>> {
+    let rows = sqlx::query_as::<_, (DateTime<Utc>, Option<String>, String, Decimal)>(
+        r#"
+        SELECT recorded_at, symbol, token_id, fair_value
+        FROM signal_history
+        WHERE (strategy_id = $1 OR agent_id = $1)
+          AND recorded_at >= $2 AND recorded_at <= $3
+          AND token_id IS NOT NULL AND token_id != ''
+          AND fair_value IS NOT NULL
+          AND UPPER(COALESCE(side, '')) != 'SELL'
+        ORDER BY recorded_at
+        "#,
+    )
+    .bind(strategy)
+    .bind(from)
+    .bind(to)
+    .fetch_all(pool)
+    .await?;
+
+    let reader = OrderbookDepthReader::new(pool.clone());
+    let max_age_ms = config.max_book_age_secs.max(0) * 1000;
+    let mut samples = Vec::with_capacity(rows.len());
+    for (recorded_at, symbol, token_id, fair_value) in rows {
+        let ts_ms = recorded_at.timestamp_millis();
+        let Some(book) = reader.book_at(&token_id, ts_ms).await? else {
+            continue;
+        };
+        if ts_ms - book.ts_ms > max_age_ms {
+            debug!(
+                token_id,
+                age_ms = ts_ms - book.ts_ms,
+                "skipping signal with stale book"
+            );
+            continue;
+        }
+        samples.push(CapacitySample {
+            recorded_at,
+            symbol: symbol.unwrap_or_else(|| "UNKNOWN".to_string()),
+            token_id,
+            fair_value,
+            book,
+        });
+    }
+    Ok(samples)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::collector::DepthLevel;
+
+    fn sample(symbol: &str, fair_value: Decimal, asks: &[(Decimal, Decimal)]) -> CapacitySample {
+        CapacitySample {
+            recorded_at: Utc::now(),
+            symbol: symbol.to_string(),
+            token_id: "tok".to_string(),
+            fair_value,
+            book: DepthBook {
+                token_id: "tok".to_string(),
+                ts_ms: 0,
+                bids: Vec::new(),
+                asks: asks
+                    .iter()
+                    .map(|(price, size)| DepthLevel {
+                        price: *price,
+                        size: *size,
+                    })
+                    .collect(),
+            },
+        }
+    }
+
+    #[test]
+    fn test_capacity_curve_degrades_with_size() {
+        // 100 shares at 0.50, 100 at 0.55, 1000 at 0.70; fair value 0.60.
+        let asks = [
+            (dec!(0.50), dec!(100)),
+            (dec!(0.55), dec!(100)),
+            (dec!(0.70), dec!(1000)),
+        ];
+        let samples = vec![
+            sample("BTCUSDT", dec!(0.60), &asks),
+            sample("BTCUSDT", dec!(0.60), &asks),
+            sample("ETHUSDT", dec!(0.60), &[(dec!(0.50), dec!(10))]),
+        ];
+        let config = CapacityConfig {
+            sizes_usd: vec![dec!(50), dec!(105), dec!(400)],
+            ..CapacityConfig::default()
+        };
+
+        let curves = estimate_capacity(&samples, &config);
+        assert_eq!(curves.len(), 2);
+
+        let btc = &curves[0];
+        assert_eq!(btc.symbol, "BTCUSDT");
+        assert_eq!(btc.signals, 2);
+        // $50 fills at 0.50: EV per dollar = 0.60 / 0.50 - 1 = 0.2
+        assert_eq!(btc.points[0].ev_per_dollar, dec!(0.2));
+        assert_eq!(btc.points[0].avg_slippage, Decimal::ZERO);
+        // $105 walks into 0.55 and still clears the floor; $400 reaches 0.70
+        // and goes negative.
+        assert!(btc.points[1].ev_per_dollar > dec!(0.01));
+        assert!(btc.points[1].avg_slippage > Decimal::ZERO);
+        assert!(btc.points[2].ev_per_dollar < Decimal::ZERO);
+        assert_eq!(btc.max_capacity_usd, Some(dec!(105)));
+
+        // A thin book caps capacity by fill ratio, not EV.
+        let eth = &curves[1];
+        assert_eq!(eth.points[0].fill_ratio, dec!(0.1));
+        assert_eq!(eth.max_capacity_usd, None);
+    }
+}
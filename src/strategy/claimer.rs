@@ -7,7 +7,6 @@ use alloy::network::EthereumWallet;
 use alloy::primitives::{Address, U256};
 use alloy::providers::{Provider, ProviderBuilder};
 use alloy::signers::local::PrivateKeySigner;
-use alloy::sol;
 use base64::{engine::general_purpose::STANDARD as BASE64, Engine as _};
 #[cfg(feature = "builder_relayer_sdk")]
 use builder_relayer_client_rust::signer::DummySigner;
@@ -44,13 +43,12 @@ use tokio::sync::RwLock;
 use tokio::time::sleep;
 use tracing::{debug, error, info, warn};
 
+use crate::adapters::ctf::{
+    IConditionalTokens, CONDITIONAL_TOKENS_POLYGON, POLYGON_RPC_DEFAULT, USDC_E_POLYGON,
+};
 use crate::adapters::PolymarketClient;
 use crate::error::Result;
 
-// CTF contracts on Polygon
-const CONDITIONAL_TOKENS_POLYGON: &str = "0x4D97DCd97eC945f40cF65F87097ACe5EA0476045";
-const USDC_E_POLYGON: &str = "0x2791Bca1f2de4661ED88A30C99A7a9449Aa84174";
-const POLYGON_RPC_DEFAULT: &str = "https://polygon-bor-rpc.publicnode.com";
 const POLYGON_CHAIN_ID: u64 = 137;
 const DEFAULT_MIN_NATIVE_GAS_WEI: u64 = 5_000_000_000_000_000; // 0.005 MATIC buffer
 const DEFAULT_AUTO_TOPUP_TARGET_WEI: u128 = 20_000_000_000_000_000; // 0.02 MATIC
@@ -66,24 +64,6 @@ const RELAYER_DEFAULT_GAS_LIMIT: u64 = 10_000_000;
 const RELAYER_DEFAULT_MAX_POLLS: u64 = 100;
 const RELAYER_DEFAULT_POLL_INTERVAL_MS: u64 = 2_000;
 
-// Generate contract bindings for ConditionalTokens
-sol! {
-    #[allow(missing_docs)]
-    #[sol(rpc)]
-    interface IConditionalTokens {
-        /// Redeem positions for a resolved condition
-        function redeemPositions(
-            address collateralToken,
-            bytes32 parentCollectionId,
-            bytes32 conditionId,
-            uint256[] calldata indexSets
-        ) external;
-
-        /// Get balance of a token for an account
-        function balanceOf(address account, uint256 id) external view returns (uint256);
-    }
-}
-
 fn json_value_to_boolish(value: &serde_json::Value) -> Option<bool> {
     match value {
         serde_json::Value::Bool(v) => Some(*v),
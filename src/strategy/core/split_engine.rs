@@ -391,6 +391,31 @@ impl SplitArbEngine {
         );
     }
 
+    /// Snapshot of the running stats
+    pub async fn stats(&self) -> ArbStats {
+        self.stats.read().await.clone()
+    }
+
+    /// Number of markets being monitored
+    pub async fn market_count(&self) -> usize {
+        self.markets.read().await.len()
+    }
+
+    /// Open positions (unhedged + hedged) and their cost basis
+    pub async fn open_exposure(&self) -> (usize, Decimal) {
+        let partial = self.partial_positions.read().await;
+        let hedged = self.hedged_positions.read().await;
+        let partial_cost: Decimal = partial
+            .values()
+            .map(|p| p.first_entry_price * Decimal::from(p.shares))
+            .sum();
+        let hedged_cost: Decimal = hedged
+            .iter()
+            .map(|h| h.total_cost * Decimal::from(h.shares))
+            .sum();
+        (partial.len() + hedged.len(), partial_cost + hedged_cost)
+    }
+
     /// Get config reference
     pub fn config(&self) -> &SplitArbConfig {
         &self.config
@@ -18,6 +18,8 @@ pub enum MarketType {
     SportsSpread,
     /// Sports over/under totals
     SportsTotal,
+    /// Sports player props (player stat over/under)
+    SportsPlayerProp,
     /// Political markets
     Political,
     /// Other binary markets
@@ -31,6 +33,7 @@ impl std::fmt::Display for MarketType {
             MarketType::SportsMoneyline => write!(f, "Sports Moneyline"),
             MarketType::SportsSpread => write!(f, "Sports Spread"),
             MarketType::SportsTotal => write!(f, "Sports Total"),
+            MarketType::SportsPlayerProp => write!(f, "Sports Player Prop"),
             MarketType::Political => write!(f, "Political"),
             MarketType::Custom => write!(f, "Custom"),
         }
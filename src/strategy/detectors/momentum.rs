@@ -3,6 +3,7 @@
 //! Detects momentum shifts and trend changes based on price movements.
 
 use crate::domain::{Quote, Side};
+use crate::strategy::calculations::{MidPriceConfig, MidPriceEstimator};
 use chrono::{DateTime, Duration, Utc};
 use rust_decimal::Decimal;
 use serde::{Deserialize, Serialize};
@@ -22,6 +23,9 @@ pub struct MomentumDetectorConfig {
     pub min_trend_change: Decimal,
     /// Cooldown between signals (seconds)
     pub signal_cooldown_secs: i64,
+    /// Price fed into the moving averages
+    #[serde(default)]
+    pub mid_estimator: MidPriceConfig,
 }
 
 impl Default for MomentumDetectorConfig {
@@ -32,6 +36,7 @@ impl Default for MomentumDetectorConfig {
             min_momentum: Decimal::new(5, 2), // 0.05 = 5%
             min_trend_change: Decimal::new(2, 2), // 0.02 = 2%
             signal_cooldown_secs: 60,
+            mid_estimator: MidPriceConfig::default(),
         }
     }
 }
@@ -153,6 +158,9 @@ pub struct MomentumDetector {
     down_short: MovingAverage,
     /// Long-term MA for DOWN side
     down_long: MovingAverage,
+    /// Mid-price estimators per side
+    up_mid: MidPriceEstimator,
+    down_mid: MidPriceEstimator,
     /// Last signal time for cooldown
     last_signal_up: Option<DateTime<Utc>>,
     last_signal_down: Option<DateTime<Utc>>,
@@ -169,6 +177,8 @@ impl MomentumDetector {
             up_long: MovingAverage::new(config.long_window_secs),
             down_short: MovingAverage::new(config.short_window_secs),
             down_long: MovingAverage::new(config.long_window_secs),
+            up_mid: MidPriceEstimator::new(config.mid_estimator.clone()),
+            down_mid: MidPriceEstimator::new(config.mid_estimator.clone()),
             last_signal_up: None,
             last_signal_down: None,
             up_trend: TrendDirection::Neutral,
@@ -183,6 +193,8 @@ impl MomentumDetector {
         self.up_long.clear();
         self.down_short.clear();
         self.down_long.clear();
+        self.up_mid.reset();
+        self.down_mid.reset();
         self.last_signal_up = None;
         self.last_signal_down = None;
         self.up_trend = TrendDirection::Neutral;
@@ -192,10 +204,12 @@ impl MomentumDetector {
 
     /// Update with new quote and check for momentum signal
     pub fn update(&mut self, quote: &Quote) -> Option<MomentumSignal> {
-        // Use mid price or best_ask
-        let price = quote.mid_price().or(quote.best_ask)?;
-        let now = quote.timestamp;
         let side = quote.side;
+        let price = match side {
+            Side::Up => self.up_mid.update(quote),
+            Side::Down => self.down_mid.update(quote),
+        }?;
+        let now = quote.timestamp;
 
         // Update moving averages
         match side {
@@ -339,6 +353,7 @@ mod tests {
             min_momentum: dec!(0.05),
             min_trend_change: dec!(0.02),
             signal_cooldown_secs: 5,
+            mid_estimator: MidPriceConfig::default(),
         }
     }
 
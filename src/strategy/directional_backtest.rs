@@ -21,10 +21,11 @@ use serde::{Deserialize, Serialize};
 use tracing::{debug, info, trace};
 
 use crate::adapters::SpotPrice;
-use crate::strategy::backtest::BacktestResults;
+use crate::strategy::backtest::{backtest_day_key, BacktestResults, SymbolStats};
 use crate::strategy::backtest_feed::{MarketFeed, UpdateType};
 use crate::strategy::execution_sim::ExecutionSimulator;
 use crate::strategy::fee_model::FeeModel;
+use crate::strategy::fill_model::FillModel;
 use crate::strategy::momentum::Direction;
 use crate::strategy::probability::estimate_probability;
 
@@ -185,6 +186,8 @@ pub struct DirectionalBacktestEngine {
     max_drawdown: Decimal,
     equity_curve: Vec<(DateTime<Utc>, Decimal)>,
     last_entry_time: HashMap<String, DateTime<Utc>>,
+    entry_requested_shares: u64,
+    entry_filled_shares: u64,
     // Data range
     data_range_start: Option<DateTime<Utc>>,
     data_range_end: Option<DateTime<Utc>>,
@@ -207,11 +210,20 @@ impl DirectionalBacktestEngine {
             max_drawdown: Decimal::ZERO,
             equity_curve: Vec::new(),
             last_entry_time: HashMap::new(),
+            entry_requested_shares: 0,
+            entry_filled_shares: 0,
             data_range_start: None,
             data_range_end: None,
         }
     }
 
+    /// Price fills and entry costs with a model learned from production fills
+    pub fn with_fill_model(mut self, model: FillModel) -> Self {
+        self.fee_model = self.fee_model.with_fill_model(model.clone());
+        self.execution_sim = self.execution_sim.with_fill_model(model);
+        self
+    }
+
     pub fn config(&self) -> &DirectionalBacktestConfig {
         &self.config
     }
@@ -478,6 +490,8 @@ impl DirectionalBacktestEngine {
         }
 
         self.equity -= total_entry_cost;
+        self.entry_requested_shares += sim_result.requested_shares;
+        self.entry_filled_shares += sim_result.filled_shares;
 
         self.positions.push(DirectionalPosition {
             symbol: symbol.to_string(),
@@ -795,6 +809,19 @@ impl DirectionalBacktestEngine {
             .map(|t| Decimal::from(t.shares) * t.entry_price)
             .sum();
 
+        let mut trades_by_symbol: HashMap<String, SymbolStats> = HashMap::new();
+        let mut trades_by_day: HashMap<String, SymbolStats> = HashMap::new();
+        for t in &self.closed_trades {
+            trades_by_symbol
+                .entry(t.symbol.clone())
+                .or_default()
+                .record(t.pnl, t.won);
+            trades_by_day
+                .entry(backtest_day_key(t.exit_time))
+                .or_default()
+                .record(t.pnl, t.won);
+        }
+
         let start_time = self.data_range_start.unwrap_or(Utc::now());
         let end_time = self.data_range_end.unwrap_or(Utc::now());
 
@@ -816,7 +843,10 @@ impl DirectionalBacktestEngine {
             largest_win,
             largest_loss,
             avg_holding_time_secs: avg_holding,
-            trades_by_symbol: HashMap::new(),
+            trades_by_symbol,
+            trades_by_day,
+            requested_shares: self.entry_requested_shares,
+            filled_shares: self.entry_filled_shares,
             trades: Vec::new(),
             equity_curve: self.equity_curve.clone(),
         }
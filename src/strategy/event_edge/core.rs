@@ -8,12 +8,17 @@ use crate::adapters::PolymarketClient;
 use crate::config::EventEdgeAgentConfig;
 use crate::error::Result;
 use crate::strategy::event_edge::{
-    discover_best_event_id_by_title, scan_event_edge_once, EdgeRow, EventEdgeScan,
+    discover_best_event_id_by_title, scan_event_edge_with_history, EdgeRow, EventEdgeScan,
 };
+use crate::strategy::event_models::arena_history::ArenaHistoryHandle;
 use crate::strategy::event_models::arena_text::ArenaTextSnapshot;
+use crate::strategy::event_models::comment_sentiment::CommentSentimentHandle;
+use crate::strategy::{ExpectedValue, POLYMARKET_FEE_RATE};
+use crate::supervisor::AlertManager;
 use chrono::{DateTime, NaiveDate, Utc};
 use rust_decimal::Decimal;
 use std::collections::HashMap;
+use std::sync::Arc;
 use tracing::{info, warn};
 
 /// A single actionable trade opportunity produced by `scan_and_decide`.
@@ -59,15 +64,17 @@ pub struct EventEdgeCore {
     pub client: PolymarketClient,
     pub cfg: EventEdgeAgentConfig,
     pub state: EventEdgeState,
+    /// Optional Polymarket comment sentiment feature (shifts `p_true`).
+    pub sentiment: Option<CommentSentimentHandle>,
+    /// Optional Arena leaderboard history (scales confidence, detects flips).
+    pub arena_history: Option<ArenaHistoryHandle>,
+    /// Receives leaderboard-flip alerts for monitored markets.
+    pub alerts: Option<Arc<AlertManager>>,
 }
 
 impl EventEdgeCore {
     pub fn new(client: PolymarketClient, cfg: EventEdgeAgentConfig) -> Self {
-        Self {
-            client,
-            cfg,
-            state: EventEdgeState::default(),
-        }
+        Self::with_state(client, cfg, EventEdgeState::default())
     }
 
     pub fn with_state(
@@ -75,7 +82,28 @@ impl EventEdgeCore {
         cfg: EventEdgeAgentConfig,
         state: EventEdgeState,
     ) -> Self {
-        Self { client, cfg, state }
+        let arena_history = cfg
+            .arena_history
+            .enabled
+            .then(|| ArenaHistoryHandle::open(cfg.arena_history.clone()));
+        Self {
+            client,
+            cfg,
+            state,
+            sentiment: None,
+            arena_history,
+            alerts: None,
+        }
+    }
+
+    pub fn with_sentiment(mut self, sentiment: CommentSentimentHandle) -> Self {
+        self.sentiment = Some(sentiment);
+        self
+    }
+
+    pub fn with_alerts(mut self, alerts: Arc<AlertManager>) -> Self {
+        self.alerts = Some(alerts);
+        self
     }
 
     // ── Guards ───────────────────────────────────────────────────────
@@ -195,7 +223,33 @@ impl EventEdgeCore {
         event_id: &str,
         arena: Option<ArenaTextSnapshot>,
     ) -> Result<EventEdgeScan> {
-        scan_event_edge_once(&self.client, event_id, arena).await
+        let scan = scan_event_edge_with_history(
+            &self.client,
+            event_id,
+            arena,
+            self.arena_history.as_ref(),
+        )
+        .await?;
+        if let Some(flip) = scan.arena_trend.as_ref().and_then(|t| t.flip.as_ref()) {
+            let message = format!(
+                "event {} (\"{}\"): Arena leader {} -> {} (last_updated={:?})",
+                scan.event_id, scan.event_title, flip.from, flip.to, flip.last_updated
+            );
+            warn!("EventEdgeCore: leaderboard flip: {}", message);
+            if let Some(alerts) = &self.alerts {
+                alerts
+                    .warning("event_edge", "Arena leaderboard flip", &message)
+                    .await;
+            }
+        }
+        if let Some(sentiment) = &self.sentiment {
+            sentiment.watch(
+                &scan.event_id,
+                &scan.event_title,
+                scan.rows.iter().map(|r| r.outcome.clone()).collect(),
+            );
+        }
+        Ok(scan)
     }
 
     /// Run the full scan→filter pipeline for one event, returning at most
@@ -233,8 +287,23 @@ impl EventEdgeCore {
 
     fn evaluate_row(&self, r: &EdgeRow, scan: &EventEdgeScan) -> Option<TradeDecision> {
         let ask = r.market_ask?;
-        let edge = r.edge?;
-        let ev = r.ev.as_ref()?;
+        let mut edge = r.edge?;
+        let mut p_true = r.p_true;
+        let mut ev = r.ev.clone()?;
+
+        if let Some(shift) = self
+            .sentiment
+            .as_ref()
+            .and_then(|s| s.probability_shift(&scan.event_id, &r.outcome, Utc::now()))
+        {
+            p_true = (r.p_true + shift).clamp(Decimal::new(1, 2), Decimal::new(99, 2));
+            edge = p_true - ask;
+            ev = ExpectedValue::calculate(ask, p_true, Some(POLYMARKET_FEE_RATE));
+            info!(
+                "EventEdgeCore: comment sentiment shift {:+} on {} (p_true {} -> {})",
+                shift, r.outcome, r.p_true, p_true
+            );
+        }
 
         if ask > self.cfg.max_entry {
             return None;
@@ -270,7 +339,7 @@ impl EventEdgeCore {
             shares: self.cfg.shares,
             limit_price: ask,
             edge,
-            p_true: r.p_true,
+            p_true,
             net_ev: ev.net_ev,
         })
     }
@@ -10,6 +10,7 @@ pub mod data_source;
 use crate::adapters::polymarket_clob::GAMMA_API_URL;
 use crate::adapters::PolymarketClient;
 use crate::error::{PloyError, Result};
+use crate::strategy::event_models::arena_history::{ArenaHistoryHandle, ArenaTrend};
 use crate::strategy::event_models::arena_text::{
     fetch_arena_text_snapshot, scores_to_probabilities, ArenaTextSnapshot,
 };
@@ -57,6 +58,9 @@ pub struct EventEdgeScan {
     pub confidence: f64,
     pub arena_last_updated: Option<chrono::NaiveDate>,
     pub arena_staleness_days: Option<f64>,
+    /// Leaderboard trend for this market's orgs (when history is enabled).
+    #[serde(default)]
+    pub arena_trend: Option<ArenaTrend>,
     pub rows: Vec<EdgeRow>,
 }
 
@@ -205,6 +209,17 @@ pub async fn scan_event_edge_once(
     client: &PolymarketClient,
     event_id: &str,
     arena: Option<ArenaTextSnapshot>,
+) -> Result<EventEdgeScan> {
+    scan_event_edge_with_history(client, event_id, arena, None).await
+}
+
+/// Like [`scan_event_edge_once`], but records the snapshot into `history` and
+/// scales the confidence by the leaderboard trend of the market's orgs.
+pub async fn scan_event_edge_with_history(
+    client: &PolymarketClient,
+    event_id: &str,
+    arena: Option<ArenaTextSnapshot>,
+    history: Option<&ArenaHistoryHandle>,
 ) -> Result<EventEdgeScan> {
     let (event_title, end_time, outcomes) = load_event_outcomes(client, event_id).await?;
     let arena = match arena {
@@ -214,7 +229,7 @@ pub async fn scan_event_edge_once(
 
     let now = Utc::now();
     let time_to_end_days = (end_time - now).num_seconds().max(0) as f64 / 86_400.0;
-    let conf = confidence_factor(time_to_end_days, arena.staleness_days());
+    let mut conf = confidence_factor(time_to_end_days, arena.staleness_days());
 
     let mut orgs: Vec<String> = outcomes
         .iter()
@@ -223,6 +238,19 @@ pub async fn scan_event_edge_once(
     orgs.sort();
     orgs.dedup();
 
+    let mut arena_trend = None;
+    if let Some(history) = history {
+        if history.record(&arena) {
+            if let Err(e) = history.save().await {
+                warn!("EventEdge: failed to persist arena history: {}", e);
+            }
+        }
+        arena_trend = history.trend(&orgs);
+        if let Some(trend) = &arena_trend {
+            conf = (conf * trend.confidence_multiplier).clamp(0.0, 1.0);
+        }
+    }
+
     let org_scores = extract_org_scores_for_options(&arena, &orgs);
     let p_now = scores_to_probabilities(&org_scores, 20.0);
     let p_true = blend_with_uniform(&p_now, conf);
@@ -275,6 +303,7 @@ pub async fn scan_event_edge_once(
         confidence: conf,
         arena_last_updated: arena.last_updated,
         arena_staleness_days: arena.staleness_days(),
+        arena_trend,
         rows,
     })
 }
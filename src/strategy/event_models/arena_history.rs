@@ -0,0 +1,323 @@
+//! Arena leaderboard history for event-edge.
+//!
+//! Each fetched [`ArenaTextSnapshot`] is reduced to its best score per org and
+//! appended to a small JSON history. For the orgs a market lists as outcomes,
+//! [`ArenaHistoryHandle::trend`] derives score deltas, ranking change-points
+//! and leader flips, plus a confidence multiplier: a stable leader with a
+//! widening lead boosts confidence, recent flips penalize it.
+
+use crate::config::ArenaHistoryConfig;
+use crate::error::{PloyError, Result};
+use crate::strategy::event_models::arena_text::ArenaTextSnapshot;
+use chrono::{DateTime, NaiveDate, Utc};
+use serde::{Deserialize, Serialize};
+use std::collections::HashMap;
+use std::path::PathBuf;
+use std::sync::{Arc, RwLock};
+use tracing::warn;
+
+/// Best score per org at one point in time
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct ArenaHistoryPoint {
+    pub last_updated: Option<NaiveDate>,
+    pub fetched_at: DateTime<Utc>,
+    pub scores: HashMap<String, i32>,
+}
+
+impl ArenaHistoryPoint {
+    pub fn from_snapshot(snapshot: &ArenaTextSnapshot) -> Self {
+        Self {
+            last_updated: snapshot.last_updated,
+            fetched_at: snapshot.fetched_at,
+            scores: snapshot.best_score_by_org(),
+        }
+    }
+
+    /// `orgs` ordered by score (desc), ties by name; orgs without a score are skipped
+    fn ranking(&self, orgs: &[String]) -> Vec<(String, i32)> {
+        let mut ranked: Vec<(String, i32)> = orgs
+            .iter()
+            .filter_map(|o| self.scores.get(o).map(|s| (o.clone(), *s)))
+            .collect();
+        ranked.sort_by(|a, b| b.1.cmp(&a.1).then_with(|| a.0.cmp(&b.0)));
+        ranked
+    }
+
+    fn lead_margin(ranked: &[(String, i32)]) -> Option<i32> {
+        match ranked {
+            [first, second, ..] => Some(first.1 - second.1),
+            _ => None,
+        }
+    }
+}
+
+/// The leading org among a market's outcomes changed between snapshots
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct ArenaLeaderFlip {
+    pub from: String,
+    pub to: String,
+    pub last_updated: Option<NaiveDate>,
+}
+
+/// Leaderboard trend for a set of orgs
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct ArenaTrend {
+    pub leader: Option<String>,
+    /// Set when the latest snapshot changed the leader
+    pub flip: Option<ArenaLeaderFlip>,
+    /// Score change per org since the previous snapshot
+    pub score_deltas: HashMap<String, i32>,
+    /// Snapshots in the lookback window whose ranking differs from the one before
+    pub rank_change_points: usize,
+    /// Leader changes in the lookback window
+    pub leader_flips: usize,
+    /// Leader score minus runner-up score
+    pub lead_margin: Option<i32>,
+    pub lead_margin_delta: Option<i32>,
+    /// Factor applied to the event-edge confidence
+    pub confidence_multiplier: f64,
+}
+
+/// Shared, file-backed Arena history
+#[derive(Debug, Clone)]
+pub struct ArenaHistoryHandle {
+    config: ArenaHistoryConfig,
+    path: PathBuf,
+    points: Arc<RwLock<Vec<ArenaHistoryPoint>>>,
+}
+
+impl ArenaHistoryHandle {
+    /// Load the history file (missing or unreadable files start empty)
+    pub fn open(config: ArenaHistoryConfig) -> Self {
+        let path = config
+            .path
+            .as_ref()
+            .map(PathBuf::from)
+            .unwrap_or_else(default_history_path);
+        let points = match std::fs::read_to_string(&path) {
+            Ok(body) => serde_json::from_str(&body).unwrap_or_else(|e| {
+                warn!("arena history {} unreadable: {}", path.display(), e);
+                Vec::new()
+            }),
+            Err(_) => Vec::new(),
+        };
+        Self::with_points(config, path, points)
+    }
+
+    fn with_points(
+        config: ArenaHistoryConfig,
+        path: PathBuf,
+        points: Vec<ArenaHistoryPoint>,
+    ) -> Self {
+        Self {
+            config,
+            path,
+            points: Arc::new(RwLock::new(points)),
+        }
+    }
+
+    pub fn len(&self) -> usize {
+        self.points.read().map(|p| p.len()).unwrap_or(0)
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.len() == 0
+    }
+
+    /// Append a snapshot unless it repeats the latest one; returns true if stored
+    pub fn record(&self, snapshot: &ArenaTextSnapshot) -> bool {
+        let point = ArenaHistoryPoint::from_snapshot(snapshot);
+        let Ok(mut points) = self.points.write() else {
+            return false;
+        };
+        if points.last().is_some_and(|last| {
+            last.last_updated == point.last_updated && last.scores == point.scores
+        }) {
+            return false;
+        }
+        points.push(point);
+        let max = self.config.max_snapshots.max(2);
+        if points.len() > max {
+            let excess = points.len() - max;
+            points.drain(..excess);
+        }
+        true
+    }
+
+    /// Write the history file (tmp + rename)
+    pub async fn save(&self) -> Result<()> {
+        let body = {
+            let points = self
+                .points
+                .read()
+                .map_err(|_| PloyError::Internal("arena history lock poisoned".to_string()))?;
+            serde_json::to_string(&*points)?
+        };
+        if let Some(parent) = self.path.parent() {
+            tokio::fs::create_dir_all(parent).await?;
+        }
+        let tmp = self.path.with_extension("json.tmp");
+        tokio::fs::write(&tmp, body).await?;
+        tokio::fs::rename(&tmp, &self.path).await?;
+        Ok(())
+    }
+
+    /// Trend for `orgs` over the lookback window; `None` until two snapshots exist
+    pub fn trend(&self, orgs: &[String]) -> Option<ArenaTrend> {
+        let points = self.points.read().ok()?;
+        if points.len() < 2 {
+            return None;
+        }
+        let window = self.config.lookback_snapshots.max(1) + 1;
+        let recent = &points[points.len().saturating_sub(window)..];
+        let rankings: Vec<Vec<(String, i32)>> = recent.iter().map(|p| p.ranking(orgs)).collect();
+
+        let mut rank_change_points = 0;
+        let mut leader_flips = 0;
+        for pair in rankings.windows(2) {
+            let (prev, cur) = (&pair[0], &pair[1]);
+            let names = |r: &[(String, i32)]| r.iter().map(|(o, _)| o.clone()).collect::<Vec<_>>();
+            if names(prev) != names(cur) {
+                rank_change_points += 1;
+            }
+            if let (Some(a), Some(b)) = (prev.first(), cur.first()) {
+                if a.0 != b.0 {
+                    leader_flips += 1;
+                }
+            }
+        }
+
+        let latest_point = &recent[recent.len() - 1];
+        let previous_point = &recent[recent.len() - 2];
+        let latest = &rankings[rankings.len() - 1];
+        let previous = &rankings[rankings.len() - 2];
+
+        let leader = latest.first().map(|(o, _)| o.clone());
+        let flip = match (previous.first(), latest.first()) {
+            (Some(from), Some(to)) if from.0 != to.0 => Some(ArenaLeaderFlip {
+                from: from.0.clone(),
+                to: to.0.clone(),
+                last_updated: latest_point.last_updated,
+            }),
+            _ => None,
+        };
+
+        let score_deltas = orgs
+            .iter()
+            .filter_map(|o| {
+                let cur = latest_point.scores.get(o)?;
+                let prev = previous_point.scores.get(o)?;
+                Some((o.clone(), cur - prev))
+            })
+            .collect();
+
+        let lead_margin = ArenaHistoryPoint::lead_margin(latest);
+        let lead_margin_delta = match (lead_margin, ArenaHistoryPoint::lead_margin(previous)) {
+            (Some(cur), Some(prev)) if flip.is_none() => Some(cur - prev),
+            _ => None,
+        };
+
+        let confidence_multiplier = if leader_flips > 0 {
+            (1.0 - self.config.flip_penalty)
+                .clamp(0.0, 1.0)
+                .powi(leader_flips as i32)
+                .max(0.5)
+        } else if lead_margin_delta.is_some_and(|d| d > 0) {
+            1.0 + self.config.stable_boost.max(0.0)
+        } else if lead_margin_delta.is_some_and(|d| d < 0) {
+            1.0 - self.config.flip_penalty.clamp(0.0, 1.0) / 2.0
+        } else {
+            1.0
+        };
+
+        Some(ArenaTrend {
+            leader,
+            flip,
+            score_deltas,
+            rank_change_points,
+            leader_flips,
+            lead_margin,
+            lead_margin_delta,
+            confidence_multiplier,
+        })
+    }
+}
+
+fn default_history_path() -> PathBuf {
+    if let Ok(dir) = std::env::var("PLOY_STATE_DIR") {
+        return PathBuf::from(dir).join("arena_text_history.json");
+    }
+    PathBuf::from("data/state/arena_text_history.json")
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::strategy::event_models::arena_text::ArenaTextEntry;
+
+    fn snapshot(day: u32, scores: &[(&str, i32)]) -> ArenaTextSnapshot {
+        ArenaTextSnapshot {
+            last_updated: NaiveDate::from_ymd_opt(2026, 2, day),
+            fetched_at: Utc::now(),
+            entries: scores
+                .iter()
+                .enumerate()
+                .map(|(i, (org, score))| ArenaTextEntry {
+                    rank: i as u32 + 1,
+                    model: format!("{org}-model"),
+                    score: *score,
+                    org: org.to_string(),
+                })
+                .collect(),
+            source_url: "test".to_string(),
+        }
+    }
+
+    fn handle() -> ArenaHistoryHandle {
+        ArenaHistoryHandle::with_points(
+            ArenaHistoryConfig::default(),
+            std::env::temp_dir().join("ploy_arena_history_test.json"),
+            Vec::new(),
+        )
+    }
+
+    #[test]
+    fn test_widening_lead_boosts_and_duplicates_are_skipped() {
+        let history = handle();
+        let orgs = vec!["Anthropic".to_string(), "Google".to_string()];
+        assert!(history.record(&snapshot(1, &[("Anthropic", 1490), ("Google", 1485)])));
+        assert!(!history.record(&snapshot(1, &[("Anthropic", 1490), ("Google", 1485)])));
+        assert!(history.trend(&orgs).is_none());
+
+        assert!(history.record(&snapshot(2, &[("Anthropic", 1496), ("Google", 1484)])));
+        let trend = history.trend(&orgs).unwrap();
+        assert_eq!(trend.leader.as_deref(), Some("Anthropic"));
+        assert!(trend.flip.is_none());
+        assert_eq!(trend.score_deltas["Anthropic"], 6);
+        assert_eq!(trend.lead_margin, Some(12));
+        assert_eq!(trend.lead_margin_delta, Some(7));
+        assert!(trend.confidence_multiplier > 1.0);
+    }
+
+    #[test]
+    fn test_leader_flip_detected_and_penalized() {
+        let history = handle();
+        let orgs = vec!["Anthropic".to_string(), "Google".to_string()];
+        history.record(&snapshot(1, &[("Anthropic", 1496), ("Google", 1486)]));
+        // OpenAI is not an outcome of this market and must not count as leader
+        history.record(&snapshot(
+            2,
+            &[("OpenAI", 1520), ("Google", 1501), ("Anthropic", 1497)],
+        ));
+
+        let trend = history.trend(&orgs).unwrap();
+        let flip = trend.flip.unwrap();
+        assert_eq!(
+            (flip.from.as_str(), flip.to.as_str()),
+            ("Anthropic", "Google")
+        );
+        assert_eq!(trend.leader_flips, 1);
+        assert_eq!(trend.rank_change_points, 1);
+        assert!(trend.confidence_multiplier < 1.0);
+    }
+}
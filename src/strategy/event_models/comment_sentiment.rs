@@ -0,0 +1,527 @@
+//! Polymarket comment sentiment for event-driven markets.
+//!
+//! - Pulls recent comments for monitored events from the Gamma `/comments` API
+//! - Scores each comment per outcome via Grok (-1 = less likely, +1 = more likely)
+//! - Keeps a rolling, time-decayed sentiment index per (event, outcome)
+//!
+//! Strategies register the events they scan with a [`CommentSentimentHandle`]
+//! and read back an optional probability shift; the background
+//! [`CommentSentimentPipeline`] does the polling and scoring.
+
+use crate::adapters::polymarket_clob::GAMMA_API_URL;
+use crate::ai_clients::grok::GrokClient;
+use crate::config::CommentSentimentConfig;
+use crate::error::{PloyError, Result};
+use chrono::{DateTime, Duration, Utc};
+use rust_decimal::prelude::FromPrimitive;
+use rust_decimal::Decimal;
+use serde::{Deserialize, Serialize};
+use std::collections::{HashMap, VecDeque};
+use std::sync::{Arc, RwLock};
+use tracing::{debug, info, warn};
+
+/// Comment bodies are truncated to this many characters in the Grok prompt
+const MAX_COMMENT_CHARS: usize = 280;
+
+/// A Polymarket comment (subset of the Gamma response)
+#[derive(Debug, Clone, Default, Deserialize)]
+#[serde(default, rename_all = "camelCase")]
+pub struct PolymarketComment {
+    pub id: String,
+    pub body: Option<String>,
+    pub created_at: Option<DateTime<Utc>>,
+}
+
+/// Fetch the newest comments on a Polymarket event
+pub async fn fetch_event_comments(
+    http: &reqwest::Client,
+    event_id: &str,
+    limit: u32,
+) -> Result<Vec<PolymarketComment>> {
+    let url = format!("{}/comments", GAMMA_API_URL);
+    let limit = limit.to_string();
+    let resp = http
+        .get(&url)
+        .query(&[
+            ("parent_entity_type", "Event"),
+            ("parent_entity_id", event_id),
+            ("limit", limit.as_str()),
+            ("order", "createdAt"),
+            ("ascending", "false"),
+        ])
+        .send()
+        .await?;
+    if !resp.status().is_success() {
+        return Err(PloyError::Internal(format!(
+            "Gamma comments request failed for event {}: {}",
+            event_id,
+            resp.status()
+        )));
+    }
+    Ok(resp.json().await?)
+}
+
+/// An event whose comments are being scored
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct SentimentTarget {
+    pub event_id: String,
+    pub title: String,
+    /// Outcome names comments are scored against
+    pub outcomes: Vec<String>,
+}
+
+/// One comment's score for one outcome
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct CommentScore {
+    pub comment_id: String,
+    pub outcome: String,
+    /// -1.0 (outcome less likely) ..= 1.0 (outcome more likely)
+    pub score: f64,
+}
+
+/// Rolling sentiment for one (event, outcome)
+#[derive(Debug, Clone, Copy, PartialEq, Serialize, Deserialize)]
+pub struct SentimentReading {
+    /// Time-decayed mean score in [-1, 1]
+    pub index: f64,
+    /// Scored comments inside the window
+    pub comments: usize,
+}
+
+fn build_scoring_prompt(target: &SentimentTarget, comments: &[PolymarketComment]) -> String {
+    let mut prompt = format!(
+        r#"You are scoring Polymarket comments on the event "{}".
+Outcomes: {}
+
+For each comment, decide which single outcome it is about (or null if none) and score
+how much it suggests that outcome is MORE likely (+1.0) or LESS likely (-1.0) to resolve
+YES. Jokes, spam and off-topic comments get outcome null.
+
+Respond with ONLY a JSON array: [{{"id": "<comment id>", "outcome": "<outcome or null>", "score": <-1.0..1.0>}}]
+
+Comments:
+"#,
+        target.title,
+        target.outcomes.join(" | ")
+    );
+    for c in comments {
+        let body: String = c
+            .body
+            .as_deref()
+            .unwrap_or_default()
+            .chars()
+            .take(MAX_COMMENT_CHARS)
+            .collect();
+        prompt.push_str(&format!("[{}] {}\n", c.id, body.replace('\n', " ")));
+    }
+    prompt
+}
+
+#[derive(Debug, Deserialize)]
+struct RawScore {
+    id: serde_json::Value,
+    #[serde(default)]
+    outcome: Option<String>,
+    score: f64,
+}
+
+/// Parse Grok's JSON array; unknown outcomes and malformed entries are dropped
+pub fn parse_comment_scores(response: &str, outcomes: &[String]) -> Vec<CommentScore> {
+    let (Some(start), Some(end)) = (response.find('['), response.rfind(']')) else {
+        return Vec::new();
+    };
+    if end < start {
+        return Vec::new();
+    }
+    let raw: Vec<RawScore> = match serde_json::from_str(&response[start..=end]) {
+        Ok(raw) => raw,
+        Err(e) => {
+            debug!("unparseable comment scores: {}", e);
+            return Vec::new();
+        }
+    };
+
+    raw.into_iter()
+        .filter_map(|r| {
+            let outcome = r.outcome?;
+            let outcome = outcomes
+                .iter()
+                .find(|o| o.eq_ignore_ascii_case(outcome.trim()))?;
+            if !r.score.is_finite() {
+                return None;
+            }
+            let comment_id = match r.id {
+                serde_json::Value::String(s) => s,
+                other => other.to_string(),
+            };
+            Some(CommentScore {
+                comment_id,
+                outcome: outcome.clone(),
+                score: r.score.clamp(-1.0, 1.0),
+            })
+        })
+        .collect()
+}
+
+#[derive(Debug, Default)]
+struct TrackerState {
+    targets: HashMap<String, SentimentTarget>,
+    /// event_id -> comment_id -> comment time (pruned with the window)
+    seen: HashMap<String, HashMap<String, DateTime<Utc>>>,
+    /// (event_id, lowercase outcome) -> (comment time, score)
+    series: HashMap<(String, String), VecDeque<(DateTime<Utc>, f64)>>,
+}
+
+/// Shared sentiment state: strategies register events and read indices,
+/// the pipeline records scores
+#[derive(Debug, Clone)]
+pub struct CommentSentimentHandle {
+    config: CommentSentimentConfig,
+    state: Arc<RwLock<TrackerState>>,
+}
+
+impl CommentSentimentHandle {
+    pub fn new(config: CommentSentimentConfig) -> Self {
+        Self {
+            config,
+            state: Arc::new(RwLock::new(TrackerState::default())),
+        }
+    }
+
+    pub fn config(&self) -> &CommentSentimentConfig {
+        &self.config
+    }
+
+    fn window(&self) -> Duration {
+        Duration::seconds(self.config.window_secs.max(1) as i64)
+    }
+
+    /// Start (or refresh) monitoring an event's comments
+    pub fn watch(&self, event_id: &str, title: &str, outcomes: Vec<String>) {
+        if outcomes.is_empty() {
+            return;
+        }
+        let mut state = self.state.write().unwrap_or_else(|e| e.into_inner());
+        state.targets.insert(
+            event_id.to_string(),
+            SentimentTarget {
+                event_id: event_id.to_string(),
+                title: title.to_string(),
+                outcomes,
+            },
+        );
+    }
+
+    pub fn unwatch(&self, event_id: &str) {
+        let mut state = self.state.write().unwrap_or_else(|e| e.into_inner());
+        state.targets.remove(event_id);
+        state.seen.remove(event_id);
+        state.series.retain(|(eid, _), _| eid != event_id);
+    }
+
+    pub fn targets(&self) -> Vec<SentimentTarget> {
+        let state = self.state.read().unwrap_or_else(|e| e.into_inner());
+        let mut targets: Vec<SentimentTarget> = state.targets.values().cloned().collect();
+        targets.sort_by(|a, b| a.event_id.cmp(&b.event_id));
+        targets
+    }
+
+    /// Comments not yet scored and still inside the window
+    fn unseen(
+        &self,
+        event_id: &str,
+        comments: Vec<PolymarketComment>,
+        now: DateTime<Utc>,
+    ) -> Vec<PolymarketComment> {
+        let cutoff = now - self.window();
+        let state = self.state.read().unwrap_or_else(|e| e.into_inner());
+        let seen = state.seen.get(event_id);
+        comments
+            .into_iter()
+            .filter(|c| !c.id.is_empty() && c.body.as_deref().is_some_and(|b| !b.trim().is_empty()))
+            .filter(|c| c.created_at.map_or(true, |t| t > cutoff))
+            .filter(|c| seen.map_or(true, |s| !s.contains_key(&c.id)))
+            .collect()
+    }
+
+    /// Record scored comments; every comment in `comments` is marked seen
+    pub fn record(
+        &self,
+        event_id: &str,
+        comments: &[PolymarketComment],
+        scores: &[CommentScore],
+        now: DateTime<Utc>,
+    ) {
+        let cutoff = now - self.window();
+        let mut state = self.state.write().unwrap_or_else(|e| e.into_inner());
+
+        let times: HashMap<&str, DateTime<Utc>> = comments
+            .iter()
+            .map(|c| (c.id.as_str(), c.created_at.unwrap_or(now)))
+            .collect();
+        let seen = state.seen.entry(event_id.to_string()).or_default();
+        for (id, at) in &times {
+            seen.insert(id.to_string(), *at);
+        }
+        seen.retain(|_, at| *at > cutoff);
+
+        for score in scores {
+            let at = times.get(score.comment_id.as_str()).copied().unwrap_or(now);
+            let key = (event_id.to_string(), score.outcome.to_lowercase());
+            let series = state.series.entry(key).or_default();
+            series.push_back((at, score.score));
+        }
+        for series in state.series.values_mut() {
+            series.retain(|(at, _)| *at > cutoff);
+        }
+        state.series.retain(|_, s| !s.is_empty());
+    }
+
+    /// Rolling index for an outcome; weights halve every half window
+    pub fn reading(
+        &self,
+        event_id: &str,
+        outcome: &str,
+        now: DateTime<Utc>,
+    ) -> Option<SentimentReading> {
+        let window = self.window();
+        let half_life = window.num_seconds() as f64 / 2.0;
+        let cutoff = now - window;
+        let state = self.state.read().unwrap_or_else(|e| e.into_inner());
+        let series = state
+            .series
+            .get(&(event_id.to_string(), outcome.to_lowercase()))?;
+
+        let mut weighted = 0.0;
+        let mut total_weight = 0.0;
+        let mut comments = 0;
+        for (at, score) in series.iter().filter(|(at, _)| *at > cutoff) {
+            let age = (now - *at).num_seconds().max(0) as f64;
+            let weight = 0.5f64.powf(age / half_life);
+            weighted += weight * score;
+            total_weight += weight;
+            comments += 1;
+        }
+        if comments == 0 || total_weight <= 0.0 {
+            return None;
+        }
+        Some(SentimentReading {
+            index: (weighted / total_weight).clamp(-1.0, 1.0),
+            comments,
+        })
+    }
+
+    /// Probability adjustment (`weight * index`) once enough comments are scored
+    pub fn probability_shift(
+        &self,
+        event_id: &str,
+        outcome: &str,
+        now: DateTime<Utc>,
+    ) -> Option<Decimal> {
+        if !self.config.enabled {
+            return None;
+        }
+        let reading = self.reading(event_id, outcome, now)?;
+        if reading.comments < self.config.min_comments.max(1) {
+            return None;
+        }
+        Decimal::from_f64(reading.index).map(|index| (index * self.config.weight).round_dp(4))
+    }
+}
+
+/// Background poller: fetch → score via Grok → record
+pub struct CommentSentimentPipeline {
+    handle: CommentSentimentHandle,
+    grok: Arc<GrokClient>,
+    http: reqwest::Client,
+}
+
+impl CommentSentimentPipeline {
+    pub fn new(handle: CommentSentimentHandle, grok: Arc<GrokClient>) -> Result<Self> {
+        let http = reqwest::Client::builder()
+            .timeout(std::time::Duration::from_secs(15))
+            .build()?;
+        Ok(Self { handle, grok, http })
+    }
+
+    pub fn handle(&self) -> CommentSentimentHandle {
+        self.handle.clone()
+    }
+
+    /// Poll every monitored event once; returns the number of comments scored
+    pub async fn poll_once(&self) -> Result<usize> {
+        let limit = self.handle.config().max_comments_per_poll.max(1);
+        let mut scored = 0;
+        for target in self.handle.targets() {
+            let comments = match fetch_event_comments(&self.http, &target.event_id, limit).await {
+                Ok(c) => c,
+                Err(e) => {
+                    warn!(
+                        "comment sentiment: fetch failed for {}: {}",
+                        target.event_id, e
+                    );
+                    continue;
+                }
+            };
+            let now = Utc::now();
+            let fresh = self.handle.unseen(&target.event_id, comments, now);
+            if fresh.is_empty() {
+                continue;
+            }
+
+            let prompt = build_scoring_prompt(&target, &fresh);
+            let response = match self.grok.chat(&prompt).await {
+                Ok(r) => r,
+                Err(e) => {
+                    // Leave the comments unseen so the next poll retries them
+                    warn!(
+                        "comment sentiment: Grok scoring failed for {}: {}",
+                        target.event_id, e
+                    );
+                    continue;
+                }
+            };
+            let scores = parse_comment_scores(&response, &target.outcomes);
+            self.handle.record(&target.event_id, &fresh, &scores, now);
+            scored += scores.len();
+            debug!(
+                event_id = %target.event_id,
+                fetched = fresh.len(),
+                scored = scores.len(),
+                "comment sentiment updated"
+            );
+        }
+        Ok(scored)
+    }
+
+    pub fn spawn(self) -> tokio::task::JoinHandle<()> {
+        let interval =
+            std::time::Duration::from_secs(self.handle.config().poll_interval_secs.max(30));
+        tokio::spawn(async move {
+            let mut ticker = tokio::time::interval(interval);
+            loop {
+                ticker.tick().await;
+                if let Err(e) = self.poll_once().await {
+                    warn!("comment sentiment poll failed: {}", e);
+                }
+            }
+        })
+    }
+}
+
+/// Start the pipeline when enabled and Grok is configured; returns the shared handle
+pub fn spawn_comment_sentiment(config: &CommentSentimentConfig) -> Option<CommentSentimentHandle> {
+    if !config.enabled {
+        return None;
+    }
+    let grok = match GrokClient::from_env() {
+        Ok(grok) if grok.is_configured() => Arc::new(grok),
+        Ok(_) => {
+            warn!("comment_sentiment.enabled=true but GROK_API_KEY not set; feature disabled");
+            return None;
+        }
+        Err(e) => {
+            warn!("comment sentiment: failed to initialize GrokClient: {}", e);
+            return None;
+        }
+    };
+    let handle = CommentSentimentHandle::new(config.clone());
+    match CommentSentimentPipeline::new(handle.clone(), grok) {
+        Ok(pipeline) => {
+            pipeline.spawn();
+            info!(
+                poll_interval_secs = config.poll_interval_secs,
+                window_secs = config.window_secs,
+                "comment sentiment pipeline started"
+            );
+            Some(handle)
+        }
+        Err(e) => {
+            warn!("comment sentiment: failed to start pipeline: {}", e);
+            None
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use rust_decimal_macros::dec;
+
+    fn comment(id: &str, at: DateTime<Utc>) -> PolymarketComment {
+        PolymarketComment {
+            id: id.to_string(),
+            body: Some("text".to_string()),
+            created_at: Some(at),
+        }
+    }
+
+    #[test]
+    fn test_parse_comment_scores_maps_outcomes() {
+        let outcomes = vec!["Anthropic".to_string(), "OpenAI".to_string()];
+        let response = r#"Here you go:
+[{"id": "1", "outcome": "anthropic", "score": 0.8},
+ {"id": 2, "outcome": "OpenAI", "score": -3.0},
+ {"id": "3", "outcome": null, "score": 0.5},
+ {"id": "4", "outcome": "Google", "score": 0.9}]"#;
+
+        let scores = parse_comment_scores(response, &outcomes);
+        assert_eq!(scores.len(), 2);
+        assert_eq!(scores[0].outcome, "Anthropic");
+        assert_eq!(scores[1].comment_id, "2");
+        assert_eq!(scores[1].score, -1.0);
+        assert!(parse_comment_scores("no json here", &outcomes).is_empty());
+    }
+
+    #[test]
+    fn test_rolling_index_decays_and_gates_on_min_comments() {
+        let handle = CommentSentimentHandle::new(CommentSentimentConfig {
+            enabled: true,
+            weight: dec!(0.04),
+            min_comments: 2,
+            window_secs: 3600,
+            ..Default::default()
+        });
+        let now = Utc::now();
+        handle.watch("ev1", "Best AI model", vec!["Anthropic".to_string()]);
+
+        let old = comment("a", now - Duration::minutes(30));
+        handle.record(
+            "ev1",
+            &[old.clone()],
+            &[CommentScore {
+                comment_id: "a".to_string(),
+                outcome: "Anthropic".to_string(),
+                score: -1.0,
+            }],
+            now,
+        );
+        assert!(handle.probability_shift("ev1", "Anthropic", now).is_none());
+
+        let fresh = comment("b", now);
+        handle.record(
+            "ev1",
+            &[fresh],
+            &[CommentScore {
+                comment_id: "b".to_string(),
+                outcome: "Anthropic".to_string(),
+                score: 1.0,
+            }],
+            now,
+        );
+        // Half-life is 30 minutes: weights 0.5 (old) and 1.0 (fresh) -> index 1/3
+        let reading = handle.reading("ev1", "anthropic", now).unwrap();
+        assert_eq!(reading.comments, 2);
+        assert!((reading.index - 1.0 / 3.0).abs() < 1e-9);
+        assert_eq!(
+            handle.probability_shift("ev1", "Anthropic", now),
+            Some(dec!(0.0133))
+        );
+
+        // Already-seen comments are not re-scored; expired ones drop out
+        assert!(handle.unseen("ev1", vec![old], now).is_empty());
+        assert!(handle
+            .reading("ev1", "Anthropic", now + Duration::minutes(61))
+            .is_none());
+    }
+}
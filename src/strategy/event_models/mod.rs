@@ -1,3 +1,5 @@
 //! External event models used to estimate "true" probabilities from public data.
 
+pub mod arena_history;
 pub mod arena_text;
+pub mod comment_sentiment;
@@ -0,0 +1,499 @@
+//! Bracket orders: an entry plus linked take-profit and stop-loss exits.
+//!
+//! The entry goes through the executor; once it fills, both exits are armed
+//! as client-side conditional orders sized to the filled quantity and sharing
+//! the bracket ID, so the conditional manager cancels one when the other
+//! fires.
+//!
+//! Brackets are persisted (`bracket_orders`) before the entry is sent. Leg IDs
+//! are fixed up front and the entry carries a stable idempotency key, so a
+//! bracket interrupted between fill and arming is finished on recovery without
+//! buying twice or arming duplicate legs.
+
+use super::conditional::{
+    ConditionalOrder, ConditionalOrderManager, PriceSource, TriggerCondition,
+};
+use super::executor::{ExecutionResult, OrderExecutor};
+use crate::adapters::PostgresStore;
+use crate::domain::{OrderRequest, OrderSide};
+use crate::error::{PloyError, Result};
+use async_trait::async_trait;
+use chrono::{DateTime, Utc};
+use rust_decimal::Decimal;
+use serde::{Deserialize, Serialize};
+use std::sync::Arc;
+use tracing::{error, info, warn};
+use uuid::Uuid;
+
+/// Lifecycle state of a bracket
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "SCREAMING_SNAKE_CASE")]
+pub enum BracketState {
+    /// Persisted; entry in flight (or interrupted by a crash)
+    PendingEntry,
+    /// Entry filled and both exits armed
+    Armed,
+    /// Entry completed without a fill; nothing to protect
+    Unfilled,
+    /// Entry or arming failed
+    Failed,
+}
+
+impl BracketState {
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            BracketState::PendingEntry => "PENDING_ENTRY",
+            BracketState::Armed => "ARMED",
+            BracketState::Unfilled => "UNFILLED",
+            BracketState::Failed => "FAILED",
+        }
+    }
+
+    pub fn parse(raw: &str) -> Option<Self> {
+        match raw {
+            "PENDING_ENTRY" => Some(BracketState::PendingEntry),
+            "ARMED" => Some(BracketState::Armed),
+            "UNFILLED" => Some(BracketState::Unfilled),
+            "FAILED" => Some(BracketState::Failed),
+            _ => None,
+        }
+    }
+}
+
+/// An entry order with its take-profit and stop-loss exits
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct Bracket {
+    pub id: Uuid,
+    pub strategy_id: Option<String>,
+    pub entry: OrderRequest,
+    /// Sell everything once the bid reaches this price
+    pub take_profit: Decimal,
+    /// Stop trigger: fires once the bid drops to this price
+    pub stop_loss: Decimal,
+    /// Limit price of the stop-loss sell
+    pub stop_limit: Decimal,
+    pub take_profit_id: Uuid,
+    pub stop_loss_id: Uuid,
+    pub state: BracketState,
+    pub filled_shares: u64,
+    pub entry_order_id: Option<String>,
+    pub error: Option<String>,
+    pub created_at: DateTime<Utc>,
+}
+
+impl Bracket {
+    /// Build a bracket around a buy entry. Requires
+    /// `stop_limit <= stop_loss < entry price < take_profit`. An entry without
+    /// an idempotency key gets one derived from the bracket ID.
+    pub fn new(
+        mut entry: OrderRequest,
+        take_profit: Decimal,
+        stop_loss: Decimal,
+        stop_limit: Decimal,
+        strategy_id: Option<String>,
+    ) -> Result<Self> {
+        if entry.order_side != OrderSide::Buy {
+            return Err(PloyError::Validation(
+                "bracket entry must be a buy".to_string(),
+            ));
+        }
+        if !(stop_limit <= stop_loss
+            && stop_loss < entry.limit_price
+            && entry.limit_price < take_profit)
+        {
+            return Err(PloyError::Validation(format!(
+                "bracket prices out of order: stop {}/{} entry {} take-profit {}",
+                stop_loss, stop_limit, entry.limit_price, take_profit
+            )));
+        }
+
+        let id = Uuid::new_v4();
+        if entry.idempotency_key.is_none() {
+            entry.idempotency_key = Some(format!("bracket:{}", id));
+        }
+        Ok(Self {
+            id,
+            strategy_id,
+            entry,
+            take_profit,
+            stop_loss,
+            stop_limit,
+            take_profit_id: Uuid::new_v4(),
+            stop_loss_id: Uuid::new_v4(),
+            state: BracketState::PendingEntry,
+            filled_shares: 0,
+            entry_order_id: None,
+            error: None,
+            created_at: Utc::now(),
+        })
+    }
+
+    /// Take-profit and stop-loss legs sized to the filled quantity
+    pub fn legs(&self) -> (ConditionalOrder, ConditionalOrder) {
+        let token_id = self.entry.token_id.clone();
+        let side = self.entry.market_side;
+        let take_profit = ConditionalOrder::bracket_leg(
+            self.take_profit_id,
+            self.id,
+            TriggerCondition::PriceAtOrAbove {
+                token_id: token_id.clone(),
+                price: self.take_profit,
+                source: PriceSource::Bid,
+            },
+            OrderRequest::sell_limit(token_id.clone(), side, self.filled_shares, self.take_profit),
+            self.strategy_id.clone(),
+        );
+        let stop_loss = ConditionalOrder::bracket_leg(
+            self.stop_loss_id,
+            self.id,
+            TriggerCondition::PriceAtOrBelow {
+                token_id: token_id.clone(),
+                price: self.stop_loss,
+                source: PriceSource::Bid,
+            },
+            OrderRequest::sell_limit(token_id, side, self.filled_shares, self.stop_limit),
+            self.strategy_id.clone(),
+        );
+        (take_profit, stop_loss)
+    }
+}
+
+/// Persistence for brackets. `PostgresStore` implements this.
+#[async_trait]
+pub trait BracketStore: Send + Sync {
+    async fn insert_bracket(&self, bracket: &Bracket) -> Result<()>;
+    /// Load brackets still in `PENDING_ENTRY`
+    async fn load_pending_brackets(&self) -> Result<Vec<Bracket>>;
+    /// Persist state, fill and error of an existing bracket
+    async fn update_bracket(&self, bracket: &Bracket) -> Result<()>;
+}
+
+type BracketRow = (
+    Uuid,
+    Option<String>,
+    serde_json::Value,
+    Decimal,
+    Decimal,
+    Decimal,
+    Uuid,
+    Uuid,
+    String,
+    i64,
+    Option<String>,
+    Option<String>,
+    DateTime<Utc>,
+);
+
+#[async_trait]
+impl BracketStore for PostgresStore {
+    async fn insert_bracket(&self, bracket: &Bracket) -> Result<()> {
+        sqlx::query(
+            r#"
+            INSERT INTO bracket_orders (
+                id, strategy_id, entry_request, take_profit, stop_loss, stop_limit,
+                take_profit_id, stop_loss_id, state, created_at
+            )
+            VALUES ($1, $2, $3, $4, $5, $6, $7, $8, $9, $10)
+            "#,
+        )
+        .bind(bracket.id)
+        .bind(bracket.strategy_id.as_deref())
+        .bind(serde_json::to_value(&bracket.entry)?)
+        .bind(bracket.take_profit)
+        .bind(bracket.stop_loss)
+        .bind(bracket.stop_limit)
+        .bind(bracket.take_profit_id)
+        .bind(bracket.stop_loss_id)
+        .bind(bracket.state.as_str())
+        .bind(bracket.created_at)
+        .execute(self.pool())
+        .await?;
+        Ok(())
+    }
+
+    async fn load_pending_brackets(&self) -> Result<Vec<Bracket>> {
+        let rows: Vec<BracketRow> = sqlx::query_as(
+            r#"
+            SELECT id, strategy_id, entry_request, take_profit, stop_loss, stop_limit,
+                   take_profit_id, stop_loss_id, state, filled_shares, entry_order_id,
+                   error, created_at
+            FROM bracket_orders
+            WHERE state = 'PENDING_ENTRY'
+            ORDER BY created_at
+            "#,
+        )
+        .fetch_all(self.pool())
+        .await?;
+
+        let mut out = Vec::with_capacity(rows.len());
+        for (
+            id,
+            strategy_id,
+            entry,
+            take_profit,
+            stop_loss,
+            stop_limit,
+            take_profit_id,
+            stop_loss_id,
+            state,
+            filled_shares,
+            entry_order_id,
+            error,
+            created_at,
+        ) in rows
+        {
+            let state = BracketState::parse(&state).ok_or_else(|| {
+                PloyError::InvalidState(format!("bracket {} state {}", id, state))
+            })?;
+            out.push(Bracket {
+                id,
+                strategy_id,
+                entry: serde_json::from_value(entry)?,
+                take_profit,
+                stop_loss,
+                stop_limit,
+                take_profit_id,
+                stop_loss_id,
+                state,
+                filled_shares: filled_shares.max(0) as u64,
+                entry_order_id,
+                error,
+                created_at,
+            });
+        }
+        Ok(out)
+    }
+
+    async fn update_bracket(&self, bracket: &Bracket) -> Result<()> {
+        sqlx::query(
+            r#"
+            UPDATE bracket_orders
+            SET state = $2,
+                filled_shares = $3,
+                entry_order_id = $4,
+                error = $5,
+                updated_at = NOW()
+            WHERE id = $1
+            "#,
+        )
+        .bind(bracket.id)
+        .bind(bracket.state.as_str())
+        .bind(bracket.filled_shares as i64)
+        .bind(bracket.entry_order_id.as_deref())
+        .bind(bracket.error.as_deref())
+        .execute(self.pool())
+        .await?;
+        Ok(())
+    }
+}
+
+/// Submits bracket entries and arms their exits
+pub struct BracketManager {
+    store: Arc<dyn BracketStore>,
+    executor: Arc<OrderExecutor>,
+    conditionals: Arc<ConditionalOrderManager>,
+}
+
+impl BracketManager {
+    pub fn new(
+        store: Arc<dyn BracketStore>,
+        executor: Arc<OrderExecutor>,
+        conditionals: Arc<ConditionalOrderManager>,
+    ) -> Self {
+        Self {
+            store,
+            executor,
+            conditionals,
+        }
+    }
+
+    /// Persist the bracket, execute the entry and arm the exits for whatever
+    /// filled. Returns the bracket in its final state; entry errors are
+    /// recorded on the bracket and returned.
+    pub async fn submit(&self, bracket: Bracket) -> Result<Bracket> {
+        self.execute(bracket).await.map(|(bracket, _)| bracket)
+    }
+
+    /// Like [`submit`](Self::submit), also returning the entry's execution
+    /// result so the caller can book the fill.
+    pub async fn execute(&self, bracket: Bracket) -> Result<(Bracket, ExecutionResult)> {
+        if bracket.state != BracketState::PendingEntry {
+            return Err(PloyError::Validation(format!(
+                "bracket {} is not PENDING_ENTRY",
+                bracket.id
+            )));
+        }
+        self.store.insert_bracket(&bracket).await?;
+        self.advance(bracket).await
+    }
+
+    /// Finish brackets interrupted before their exits were armed.
+    ///
+    /// Run after `ConditionalOrderManager::recover`. The entry is re-sent
+    /// under its original idempotency key; without an idempotency manager the
+    /// entry outcome is unknown, so the bracket is failed for manual review
+    /// rather than risking a second buy. Returns how many brackets were armed.
+    pub async fn recover(&self) -> Result<usize> {
+        let pending = self.store.load_pending_brackets().await?;
+        let mut armed = 0;
+        for mut bracket in pending {
+            if !self.executor.has_idempotency() {
+                warn!(
+                    "Bracket {} interrupted and entry cannot be deduplicated; failing it",
+                    bracket.id
+                );
+                bracket.state = BracketState::Failed;
+                bracket.error = Some("interrupted before arming; check position".to_string());
+                self.store.update_bracket(&bracket).await?;
+                continue;
+            }
+            warn!("Resuming bracket {} interrupted before arming", bracket.id);
+            match self.advance(bracket).await {
+                Ok((b, _)) if b.state == BracketState::Armed => armed += 1,
+                Ok(_) => {}
+                Err(e) => error!("Bracket recovery failed: {}", e),
+            }
+        }
+        info!("Brackets recovered: {} armed", armed);
+        Ok(armed)
+    }
+
+    async fn advance(&self, mut bracket: Bracket) -> Result<(Bracket, ExecutionResult)> {
+        let result = match self.executor.execute(&bracket.entry).await {
+            Ok(result) => result,
+            Err(e) => {
+                error!("Bracket {} entry failed: {}", bracket.id, e);
+                bracket.state = BracketState::Failed;
+                bracket.error = Some(e.to_string());
+                self.store.update_bracket(&bracket).await?;
+                return Err(e);
+            }
+        };
+        bracket.entry_order_id = Some(result.order_id.clone());
+        bracket.filled_shares = result.filled_shares;
+
+        if bracket.filled_shares == 0 {
+            info!("Bracket {} entry did not fill; exits not armed", bracket.id);
+            bracket.state = BracketState::Unfilled;
+            self.store.update_bracket(&bracket).await?;
+            return Ok((bracket, result));
+        }
+
+        // The entry filled, so arming errors must not hide the fill from the
+        // caller. The bracket stays PENDING_ENTRY and recovery re-arms it.
+        if let Err(e) = self.arm_exits(&mut bracket).await {
+            error!("Bracket {} filled but exits not armed: {}", bracket.id, e);
+            bracket.error = Some(e.to_string());
+            return Ok((bracket, result));
+        }
+        info!(
+            "Bracket {} armed: {} shares, TP {} / SL {}",
+            bracket.id, bracket.filled_shares, bracket.take_profit, bracket.stop_loss
+        );
+        Ok((bracket, result))
+    }
+
+    async fn arm_exits(&self, bracket: &mut Bracket) -> Result<()> {
+        let (take_profit, stop_loss) = bracket.legs();
+        self.conditionals.arm(take_profit).await?;
+        self.conditionals.arm(stop_loss).await?;
+        bracket.state = BracketState::Armed;
+        self.store.update_bracket(bracket).await
+    }
+}
+
+#[cfg(test)]
+pub mod mock {
+    use super::*;
+    use std::collections::HashMap;
+    use std::sync::Mutex;
+
+    /// In-memory bracket store for unit tests.
+    #[derive(Default)]
+    pub struct MockBracketStore {
+        pub rows: Mutex<HashMap<Uuid, Bracket>>,
+    }
+
+    #[async_trait]
+    impl BracketStore for MockBracketStore {
+        async fn insert_bracket(&self, bracket: &Bracket) -> Result<()> {
+            self.rows
+                .lock()
+                .unwrap()
+                .insert(bracket.id, bracket.clone());
+            Ok(())
+        }
+
+        async fn load_pending_brackets(&self) -> Result<Vec<Bracket>> {
+            Ok(self
+                .rows
+                .lock()
+                .unwrap()
+                .values()
+                .filter(|b| b.state == BracketState::PendingEntry)
+                .cloned()
+                .collect())
+        }
+
+        async fn update_bracket(&self, bracket: &Bracket) -> Result<()> {
+            self.insert_bracket(bracket).await
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::super::conditional::mock::{DryRunExchange, MockConditionalStore};
+    use super::super::conditional::ConditionalOrderState;
+    use super::mock::MockBracketStore;
+    use super::*;
+    use crate::config::ExecutionConfig;
+    use crate::domain::{Quote, Side};
+    use rust_decimal_macros::dec;
+
+    fn quote(bid: Decimal) -> Quote {
+        Quote {
+            side: Side::Up,
+            best_bid: Some(bid),
+            best_ask: Some(bid + dec!(0.02)),
+            bid_size: Some(dec!(100)),
+            ask_size: Some(dec!(100)),
+            timestamp: Utc::now(),
+        }
+    }
+
+    #[tokio::test]
+    async fn test_take_profit_cancels_stop_loss() {
+        let executor = Arc::new(OrderExecutor::new_with_exchange(
+            Arc::new(DryRunExchange),
+            ExecutionConfig::default(),
+        ));
+        let legs = Arc::new(MockConditionalStore::default());
+        let conditionals = Arc::new(ConditionalOrderManager::new(legs.clone(), executor.clone()));
+        let store = Arc::new(MockBracketStore::default());
+        let mgr = BracketManager::new(store.clone(), executor, conditionals.clone());
+
+        let entry = OrderRequest::buy_limit("tok".to_string(), Side::Up, 20, dec!(0.50));
+        assert!(Bracket::new(entry.clone(), dec!(0.45), dec!(0.40), dec!(0.38), None).is_err());
+        let bracket = Bracket::new(entry, dec!(0.60), dec!(0.40), dec!(0.38), None).unwrap();
+        let bracket = mgr.submit(bracket).await.unwrap();
+        assert_eq!(bracket.state, BracketState::Armed);
+        assert_eq!(conditionals.armed().await.len(), 2);
+
+        assert_eq!(conditionals.on_quote("tok", quote(dec!(0.61))).await, 1);
+        let rows = legs.rows.lock().unwrap();
+        let tp = &rows[&bracket.take_profit_id];
+        assert_eq!(tp.state, ConditionalOrderState::Submitted);
+        assert_eq!(tp.order.shares, 20);
+        assert_eq!(
+            rows[&bracket.stop_loss_id].state,
+            ConditionalOrderState::Cancelled
+        );
+        drop(rows);
+        assert!(conditionals.armed().await.is_empty());
+        assert_eq!(
+            store.rows.lock().unwrap()[&bracket.id].state,
+            BracketState::Armed
+        );
+    }
+}
@@ -0,0 +1,894 @@
+//! Client-side conditional orders (stop-limit and trigger orders).
+//!
+//! Polymarket has no native stop orders, so triggers are held locally and
+//! persisted in Postgres (`conditional_orders`). The manager watches quote
+//! updates and submits the underlying limit order once the condition fires.
+//!
+//! Each trigger moves `ARMED -> TRIGGERED -> SUBMITTED|FAILED` using
+//! compare-and-set updates, so only one process can fire a given trigger.
+//! The order request carries a stable idempotency key, which makes it safe to
+//! resubmit triggers that were left in `TRIGGERED` by a crash.
+//!
+//! Triggers sharing a `bracket_id` are one-cancels-other: when one fires, its
+//! armed siblings are cancelled (see `bracket` for the entry side).
+//!
+//! Submitted triggers bypass the coordinator queue, so the manager reports
+//! each execution on an optional channel for position bookkeeping.
+
+use super::executor::{ExecutionResult, OrderExecutor};
+use crate::adapters::{PostgresStore, QuoteUpdate};
+use crate::domain::{OrderRequest, Quote};
+use crate::error::{PloyError, Result};
+use async_trait::async_trait;
+use chrono::{DateTime, Utc};
+use rust_decimal::Decimal;
+use serde::{Deserialize, Serialize};
+use std::collections::HashMap;
+use std::sync::Arc;
+use std::time::Duration;
+use tokio::sync::{broadcast, mpsc, RwLock};
+use tracing::{debug, error, info, warn};
+use uuid::Uuid;
+
+/// Which side of the book a price trigger reads
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum PriceSource {
+    Bid,
+    Ask,
+    Mid,
+}
+
+impl PriceSource {
+    fn read(&self, quote: &Quote) -> Option<Decimal> {
+        match self {
+            PriceSource::Bid => quote.best_bid,
+            PriceSource::Ask => quote.best_ask,
+            PriceSource::Mid => quote.mid_price(),
+        }
+    }
+}
+
+/// Condition that fires a conditional order
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[serde(tag = "type", rename_all = "snake_case")]
+pub enum TriggerCondition {
+    /// Fires when the token price is at or above `price` (e.g. buy-stop, take-profit)
+    PriceAtOrAbove {
+        token_id: String,
+        price: Decimal,
+        source: PriceSource,
+    },
+    /// Fires when the token price is at or below `price` (e.g. sell-stop)
+    PriceAtOrBelow {
+        token_id: String,
+        price: Decimal,
+        source: PriceSource,
+    },
+    /// Fires when the sum of both legs' asks drops to `threshold` or below
+    AskSumAtOrBelow {
+        up_token_id: String,
+        down_token_id: String,
+        threshold: Decimal,
+    },
+    /// Fires at a wall-clock time
+    AtTime { at: DateTime<Utc> },
+}
+
+impl TriggerCondition {
+    /// Token IDs whose quotes this condition depends on
+    pub fn token_ids(&self) -> Vec<&str> {
+        match self {
+            TriggerCondition::PriceAtOrAbove { token_id, .. }
+            | TriggerCondition::PriceAtOrBelow { token_id, .. } => vec![token_id.as_str()],
+            TriggerCondition::AskSumAtOrBelow {
+                up_token_id,
+                down_token_id,
+                ..
+            } => vec![up_token_id.as_str(), down_token_id.as_str()],
+            TriggerCondition::AtTime { .. } => Vec::new(),
+        }
+    }
+
+    /// Evaluate against the latest known quotes
+    pub fn is_met(&self, quotes: &HashMap<String, Quote>, now: DateTime<Utc>) -> bool {
+        match self {
+            TriggerCondition::PriceAtOrAbove {
+                token_id,
+                price,
+                source,
+            } => quotes
+                .get(token_id)
+                .and_then(|q| source.read(q))
+                .is_some_and(|p| p >= *price),
+            TriggerCondition::PriceAtOrBelow {
+                token_id,
+                price,
+                source,
+            } => quotes
+                .get(token_id)
+                .and_then(|q| source.read(q))
+                .is_some_and(|p| p <= *price),
+            TriggerCondition::AskSumAtOrBelow {
+                up_token_id,
+                down_token_id,
+                threshold,
+            } => {
+                let up = quotes.get(up_token_id).and_then(|q| q.best_ask);
+                let down = quotes.get(down_token_id).and_then(|q| q.best_ask);
+                matches!((up, down), (Some(u), Some(d)) if u + d <= *threshold)
+            }
+            TriggerCondition::AtTime { at } => now >= *at,
+        }
+    }
+}
+
+/// Lifecycle state of a conditional order
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "UPPERCASE")]
+pub enum ConditionalOrderState {
+    /// Waiting for the condition
+    Armed,
+    /// Condition fired; submission in flight (or interrupted by a crash)
+    Triggered,
+    /// Underlying order accepted by the executor
+    Submitted,
+    /// Cancelled before firing
+    Cancelled,
+    /// Submission failed
+    Failed,
+}
+
+impl ConditionalOrderState {
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            ConditionalOrderState::Armed => "ARMED",
+            ConditionalOrderState::Triggered => "TRIGGERED",
+            ConditionalOrderState::Submitted => "SUBMITTED",
+            ConditionalOrderState::Cancelled => "CANCELLED",
+            ConditionalOrderState::Failed => "FAILED",
+        }
+    }
+
+    pub fn parse(raw: &str) -> Option<Self> {
+        match raw {
+            "ARMED" => Some(ConditionalOrderState::Armed),
+            "TRIGGERED" => Some(ConditionalOrderState::Triggered),
+            "SUBMITTED" => Some(ConditionalOrderState::Submitted),
+            "CANCELLED" => Some(ConditionalOrderState::Cancelled),
+            "FAILED" => Some(ConditionalOrderState::Failed),
+            _ => None,
+        }
+    }
+}
+
+/// A stored trigger plus the limit order it submits
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct ConditionalOrder {
+    pub id: Uuid,
+    pub strategy_id: Option<String>,
+    pub condition: TriggerCondition,
+    pub order: OrderRequest,
+    pub state: ConditionalOrderState,
+    pub exchange_order_id: Option<String>,
+    pub error: Option<String>,
+    pub created_at: DateTime<Utc>,
+    pub triggered_at: Option<DateTime<Utc>>,
+    /// Bracket this trigger protects; siblings are cancelled when it fires
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub bracket_id: Option<Uuid>,
+}
+
+impl ConditionalOrder {
+    /// Build a new armed trigger. The order's idempotency key is pinned to the
+    /// trigger ID so crash-recovery resubmits are deduplicated.
+    pub fn new(
+        condition: TriggerCondition,
+        mut order: OrderRequest,
+        strategy_id: Option<String>,
+    ) -> Self {
+        let id = Uuid::new_v4();
+        order.idempotency_key = Some(format!("conditional:{}", id));
+        Self {
+            id,
+            strategy_id,
+            condition,
+            order,
+            state: ConditionalOrderState::Armed,
+            exchange_order_id: None,
+            error: None,
+            created_at: Utc::now(),
+            triggered_at: None,
+            bracket_id: None,
+        }
+    }
+
+    /// Protective leg of a bracket. The ID is fixed by the bracket, so arming
+    /// the same leg again after a crash does not create a duplicate.
+    pub fn bracket_leg(
+        id: Uuid,
+        bracket_id: Uuid,
+        condition: TriggerCondition,
+        order: OrderRequest,
+        strategy_id: Option<String>,
+    ) -> Self {
+        let mut leg = Self::new(condition, order, strategy_id);
+        leg.id = id;
+        leg.order.idempotency_key = Some(format!("conditional:{}", id));
+        leg.bracket_id = Some(bracket_id);
+        leg
+    }
+
+    /// Stop-limit: once the bid drops to `stop_price`, sell at `limit_price`.
+    pub fn stop_limit_sell(order: OrderRequest, stop_price: Decimal) -> Self {
+        let condition = TriggerCondition::PriceAtOrBelow {
+            token_id: order.token_id.clone(),
+            price: stop_price,
+            source: PriceSource::Bid,
+        };
+        Self::new(condition, order, None)
+    }
+}
+
+/// Persistence for conditional orders.
+///
+/// `PostgresStore` implements this; transitions are compare-and-set so that
+/// concurrent managers cannot fire the same trigger twice.
+#[async_trait]
+pub trait ConditionalOrderStore: Send + Sync {
+    /// Insert a trigger; inserting an existing ID is a no-op
+    async fn insert_conditional(&self, order: &ConditionalOrder) -> Result<()>;
+    /// Load triggers still in `ARMED` or `TRIGGERED`
+    async fn load_live_conditionals(&self) -> Result<Vec<ConditionalOrder>>;
+    /// Move `from -> to`; returns false if the row was not in `from`
+    async fn transition_conditional(
+        &self,
+        id: Uuid,
+        from: ConditionalOrderState,
+        to: ConditionalOrderState,
+        exchange_order_id: Option<&str>,
+        error: Option<&str>,
+    ) -> Result<bool>;
+}
+
+#[async_trait]
+impl ConditionalOrderStore for PostgresStore {
+    async fn insert_conditional(&self, order: &ConditionalOrder) -> Result<()> {
+        sqlx::query(
+            r#"
+            INSERT INTO conditional_orders (
+                id, strategy_id, condition, order_request, state, created_at, bracket_id
+            )
+            VALUES ($1, $2, $3, $4, $5, $6, $7)
+            ON CONFLICT (id) DO NOTHING
+            "#,
+        )
+        .bind(order.id)
+        .bind(order.strategy_id.as_deref())
+        .bind(serde_json::to_value(&order.condition)?)
+        .bind(serde_json::to_value(&order.order)?)
+        .bind(order.state.as_str())
+        .bind(order.created_at)
+        .bind(order.bracket_id)
+        .execute(self.pool())
+        .await?;
+        Ok(())
+    }
+
+    async fn load_live_conditionals(&self) -> Result<Vec<ConditionalOrder>> {
+        let rows: Vec<(
+            Uuid,
+            Option<String>,
+            serde_json::Value,
+            serde_json::Value,
+            String,
+            Option<String>,
+            Option<String>,
+            DateTime<Utc>,
+            Option<DateTime<Utc>>,
+            Option<Uuid>,
+        )> = sqlx::query_as(
+            r#"
+            SELECT id, strategy_id, condition, order_request, state,
+                   exchange_order_id, error, created_at, triggered_at, bracket_id
+            FROM conditional_orders
+            WHERE state IN ('ARMED', 'TRIGGERED')
+            ORDER BY created_at
+            "#,
+        )
+        .fetch_all(self.pool())
+        .await?;
+
+        let mut out = Vec::with_capacity(rows.len());
+        for (
+            id,
+            strategy_id,
+            condition,
+            order,
+            state,
+            exchange_order_id,
+            error,
+            created_at,
+            triggered_at,
+            bracket_id,
+        ) in rows
+        {
+            let state = ConditionalOrderState::parse(&state).ok_or_else(|| {
+                PloyError::InvalidState(format!("conditional order {} state {}", id, state))
+            })?;
+            out.push(ConditionalOrder {
+                id,
+                strategy_id,
+                condition: serde_json::from_value(condition)?,
+                order: serde_json::from_value(order)?,
+                state,
+                exchange_order_id,
+                error,
+                created_at,
+                triggered_at,
+                bracket_id,
+            });
+        }
+        Ok(out)
+    }
+
+    async fn transition_conditional(
+        &self,
+        id: Uuid,
+        from: ConditionalOrderState,
+        to: ConditionalOrderState,
+        exchange_order_id: Option<&str>,
+        error: Option<&str>,
+    ) -> Result<bool> {
+        let result = sqlx::query(
+            r#"
+            UPDATE conditional_orders
+            SET state = $3,
+                exchange_order_id = COALESCE($4, exchange_order_id),
+                error = COALESCE($5, error),
+                triggered_at = CASE WHEN $3 = 'TRIGGERED' THEN NOW() ELSE triggered_at END,
+                updated_at = NOW()
+            WHERE id = $1 AND state = $2
+            "#,
+        )
+        .bind(id)
+        .bind(from.as_str())
+        .bind(to.as_str())
+        .bind(exchange_order_id)
+        .bind(error)
+        .execute(self.pool())
+        .await?;
+        Ok(result.rows_affected() == 1)
+    }
+}
+
+/// A fired trigger whose order reached the executor
+#[derive(Debug, Clone)]
+pub struct ConditionalExecution {
+    pub order: ConditionalOrder,
+    pub result: ExecutionResult,
+}
+
+/// Watches quotes and fires armed conditional orders
+pub struct ConditionalOrderManager {
+    store: Arc<dyn ConditionalOrderStore>,
+    executor: Arc<OrderExecutor>,
+    armed: RwLock<HashMap<Uuid, ConditionalOrder>>,
+    quotes: RwLock<HashMap<String, Quote>>,
+    executions: Option<mpsc::Sender<ConditionalExecution>>,
+}
+
+impl ConditionalOrderManager {
+    pub fn new(store: Arc<dyn ConditionalOrderStore>, executor: Arc<OrderExecutor>) -> Self {
+        Self {
+            store,
+            executor,
+            armed: RwLock::new(HashMap::new()),
+            quotes: RwLock::new(HashMap::new()),
+            executions: None,
+        }
+    }
+
+    /// Report every submitted trigger, e.g. to book exit fills
+    pub fn with_executions(mut self, executions: mpsc::Sender<ConditionalExecution>) -> Self {
+        self.executions = Some(executions);
+        self
+    }
+
+    /// Reload live triggers after a restart.
+    ///
+    /// Armed triggers are re-armed in memory; triggers left in `TRIGGERED`
+    /// (crash between firing and submission) are resubmitted with their
+    /// original idempotency key. Returns `(rearmed, resubmitted)`.
+    pub async fn recover(&self) -> Result<(usize, usize)> {
+        let live = self.store.load_live_conditionals().await?;
+        let mut rearmed = 0;
+        let mut pending = Vec::new();
+        {
+            let mut armed = self.armed.write().await;
+            for order in live {
+                match order.state {
+                    ConditionalOrderState::Armed => {
+                        armed.insert(order.id, order);
+                        rearmed += 1;
+                    }
+                    ConditionalOrderState::Triggered => pending.push(order),
+                    _ => {}
+                }
+            }
+        }
+
+        let resubmitted = pending.len();
+        for order in pending {
+            warn!(
+                "Resubmitting conditional order {} interrupted after trigger",
+                order.id
+            );
+            self.submit(order).await;
+        }
+
+        info!(
+            "Conditional orders recovered: {} re-armed, {} resubmitted",
+            rearmed, resubmitted
+        );
+        Ok((rearmed, resubmitted))
+    }
+
+    /// Persist and arm a new conditional order
+    pub async fn arm(&self, order: ConditionalOrder) -> Result<Uuid> {
+        if order.state != ConditionalOrderState::Armed {
+            return Err(PloyError::Validation(format!(
+                "conditional order {} is not ARMED",
+                order.id
+            )));
+        }
+        self.store.insert_conditional(&order).await?;
+        let id = order.id;
+        debug!("Armed conditional order {}: {:?}", id, order.condition);
+        self.armed.write().await.insert(id, order);
+        Ok(id)
+    }
+
+    /// Cancel an armed trigger. Returns false if it already fired.
+    pub async fn cancel(&self, id: Uuid) -> Result<bool> {
+        let cancelled = self
+            .store
+            .transition_conditional(
+                id,
+                ConditionalOrderState::Armed,
+                ConditionalOrderState::Cancelled,
+                None,
+                None,
+            )
+            .await?;
+        if cancelled {
+            self.armed.write().await.remove(&id);
+        }
+        Ok(cancelled)
+    }
+
+    /// Currently armed triggers
+    pub async fn armed(&self) -> Vec<ConditionalOrder> {
+        self.armed.read().await.values().cloned().collect()
+    }
+
+    /// Record a quote and fire any triggers that depend on it
+    pub async fn on_quote(&self, token_id: &str, quote: Quote) -> usize {
+        self.quotes
+            .write()
+            .await
+            .insert(token_id.to_string(), quote);
+        self.evaluate(Utc::now()).await
+    }
+
+    /// Evaluate every armed trigger against the latest quotes. Returns how many fired.
+    pub async fn evaluate(&self, now: DateTime<Utc>) -> usize {
+        let fired: Vec<ConditionalOrder> = {
+            let quotes = self.quotes.read().await;
+            let mut armed = self.armed.write().await;
+            let ids: Vec<Uuid> = armed
+                .values()
+                .filter(|o| o.condition.is_met(&quotes, now))
+                .map(|o| o.id)
+                .collect();
+            ids.into_iter().filter_map(|id| armed.remove(&id)).collect()
+        };
+        // Bracket legs that fired in this pass, so a sibling firing first can cancel them
+        let fired_legs: Vec<(Uuid, Uuid)> = fired
+            .iter()
+            .filter_map(|o| o.bracket_id.map(|b| (b, o.id)))
+            .collect();
+
+        let mut count = 0;
+        for order in fired {
+            match self
+                .store
+                .transition_conditional(
+                    order.id,
+                    ConditionalOrderState::Armed,
+                    ConditionalOrderState::Triggered,
+                    None,
+                    None,
+                )
+                .await
+            {
+                Ok(true) => {
+                    info!("Conditional order {} triggered", order.id);
+                    if let Some(bracket_id) = order.bracket_id {
+                        self.cancel_siblings(bracket_id, order.id, &fired_legs)
+                            .await;
+                    }
+                    self.submit(order).await;
+                    count += 1;
+                }
+                Ok(false) => {
+                    debug!(
+                        "Conditional order {} already fired or cancelled elsewhere",
+                        order.id
+                    );
+                }
+                Err(e) => {
+                    // Keep it armed; the next tick retries the transition.
+                    error!("Failed to mark conditional order {}: {}", order.id, e);
+                    self.armed.write().await.insert(order.id, order);
+                }
+            }
+        }
+        count
+    }
+
+    /// Cancel the other armed legs of a bracket once `fired` has triggered
+    async fn cancel_siblings(&self, bracket_id: Uuid, fired: Uuid, fired_legs: &[(Uuid, Uuid)]) {
+        let mut siblings: Vec<Uuid> = self
+            .armed
+            .read()
+            .await
+            .values()
+            .filter(|o| o.bracket_id == Some(bracket_id))
+            .map(|o| o.id)
+            .collect();
+        siblings.extend(
+            fired_legs
+                .iter()
+                .filter(|(b, _)| *b == bracket_id)
+                .map(|(_, id)| *id),
+        );
+
+        for id in siblings.into_iter().filter(|id| *id != fired) {
+            match self.cancel(id).await {
+                Ok(true) => info!(
+                    "Cancelled conditional order {} (bracket {} sibling {} fired)",
+                    id, bracket_id, fired
+                ),
+                Ok(false) => debug!("Bracket {} sibling {} no longer armed", bracket_id, id),
+                Err(e) => error!(
+                    "Failed to cancel bracket {} sibling {}: {}",
+                    bracket_id, id, e
+                ),
+            }
+        }
+    }
+
+    async fn submit(&self, order: ConditionalOrder) {
+        let (to, result, err) = match self.executor.execute(&order.order).await {
+            Ok(result) => (ConditionalOrderState::Submitted, Some(result), None),
+            Err(e) => {
+                error!("Conditional order {} submission failed: {}", order.id, e);
+                (ConditionalOrderState::Failed, None, Some(e.to_string()))
+            }
+        };
+        let exchange_order_id = result.as_ref().map(|r| r.order_id.clone());
+
+        if let Err(e) = self
+            .store
+            .transition_conditional(
+                order.id,
+                ConditionalOrderState::Triggered,
+                to,
+                exchange_order_id.as_deref(),
+                err.as_deref(),
+            )
+            .await
+        {
+            // Left in TRIGGERED: recovery resubmits under the same idempotency key.
+            error!(
+                "Failed to persist conditional order {} outcome: {}",
+                order.id, e
+            );
+        }
+
+        if let (Some(executions), Some(result)) = (&self.executions, result) {
+            let id = order.id;
+            if executions
+                .send(ConditionalExecution { order, result })
+                .await
+                .is_err()
+            {
+                warn!(
+                    "Conditional order {} execution not reported: receiver closed",
+                    id
+                );
+            }
+        }
+    }
+
+    /// Evaluate once a second so time triggers fire without quote traffic.
+    pub async fn run(self: Arc<Self>, mut shutdown_rx: broadcast::Receiver<()>) {
+        let mut tick = tokio::time::interval(Duration::from_secs(1));
+        tick.set_missed_tick_behavior(tokio::time::MissedTickBehavior::Delay);
+        loop {
+            tokio::select! {
+                _ = tick.tick() => {
+                    self.evaluate(Utc::now()).await;
+                }
+                _ = shutdown_rx.recv() => break,
+            }
+        }
+    }
+
+    /// Feed one quote stream into the manager. Call once per market feed.
+    pub async fn watch(
+        self: Arc<Self>,
+        mut updates: broadcast::Receiver<QuoteUpdate>,
+        mut shutdown_rx: broadcast::Receiver<()>,
+    ) {
+        loop {
+            let update = tokio::select! {
+                update = updates.recv() => update,
+                _ = shutdown_rx.recv() => break,
+            };
+            match update {
+                Ok(update) => {
+                    self.on_quote(&update.token_id, update.quote).await;
+                }
+                Err(broadcast::error::RecvError::Lagged(n)) => {
+                    warn!("Conditional order manager lagged {} quote updates", n);
+                }
+                Err(broadcast::error::RecvError::Closed) => break,
+            }
+        }
+    }
+}
+
+#[cfg(test)]
+pub mod mock {
+    use super::*;
+    use crate::adapters::OrderResponse;
+    use crate::domain::OrderStatus;
+    use crate::exchange::{ExchangeClient, ExchangeKind};
+    use std::sync::Mutex;
+
+    /// In-memory conditional order store for unit tests.
+    #[derive(Default)]
+    pub struct MockConditionalStore {
+        pub rows: Mutex<HashMap<Uuid, ConditionalOrder>>,
+    }
+
+    #[async_trait]
+    impl ConditionalOrderStore for MockConditionalStore {
+        async fn insert_conditional(&self, order: &ConditionalOrder) -> Result<()> {
+            self.rows
+                .lock()
+                .unwrap()
+                .entry(order.id)
+                .or_insert_with(|| order.clone());
+            Ok(())
+        }
+
+        async fn load_live_conditionals(&self) -> Result<Vec<ConditionalOrder>> {
+            Ok(self
+                .rows
+                .lock()
+                .unwrap()
+                .values()
+                .filter(|o| {
+                    matches!(
+                        o.state,
+                        ConditionalOrderState::Armed | ConditionalOrderState::Triggered
+                    )
+                })
+                .cloned()
+                .collect())
+        }
+
+        async fn transition_conditional(
+            &self,
+            id: Uuid,
+            from: ConditionalOrderState,
+            to: ConditionalOrderState,
+            exchange_order_id: Option<&str>,
+            error: Option<&str>,
+        ) -> Result<bool> {
+            let mut rows = self.rows.lock().unwrap();
+            match rows.get_mut(&id) {
+                Some(row) if row.state == from => {
+                    row.state = to;
+                    if let Some(oid) = exchange_order_id {
+                        row.exchange_order_id = Some(oid.to_string());
+                    }
+                    if let Some(err) = error {
+                        row.error = Some(err.to_string());
+                    }
+                    Ok(true)
+                }
+                _ => Ok(false),
+            }
+        }
+    }
+
+    /// Dry-run exchange: every order is accepted and filled immediately.
+    pub struct DryRunExchange;
+
+    #[async_trait]
+    impl ExchangeClient for DryRunExchange {
+        fn kind(&self) -> ExchangeKind {
+            ExchangeKind::Polymarket
+        }
+
+        fn is_dry_run(&self) -> bool {
+            true
+        }
+
+        async fn submit_order_gateway(&self, request: &OrderRequest) -> Result<OrderResponse> {
+            Ok(OrderResponse {
+                id: format!("dry-{}", request.client_order_id),
+                status: "live".to_string(),
+                owner: None,
+                market: None,
+                asset_id: None,
+                side: None,
+                original_size: None,
+                size_matched: None,
+                price: None,
+                associate_trades: None,
+                created_at: None,
+                expiration: None,
+                order_type: None,
+            })
+        }
+
+        async fn get_order(&self, _order_id: &str) -> Result<OrderResponse> {
+            Err(PloyError::Internal("not used in dry run".to_string()))
+        }
+
+        async fn cancel_order(&self, _order_id: &str) -> Result<bool> {
+            Ok(true)
+        }
+
+        async fn get_best_prices(
+            &self,
+            _token_id: &str,
+        ) -> Result<(Option<Decimal>, Option<Decimal>)> {
+            Ok((None, None))
+        }
+
+        fn infer_order_status(&self, _order: &OrderResponse) -> OrderStatus {
+            OrderStatus::Filled
+        }
+
+        fn calculate_fill(&self, _order: &OrderResponse) -> (u64, Option<Decimal>) {
+            (0, None)
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::mock::{DryRunExchange, MockConditionalStore};
+    use super::*;
+    use crate::config::ExecutionConfig;
+    use crate::domain::Side;
+    use rust_decimal_macros::dec;
+
+    fn manager() -> (Arc<MockConditionalStore>, ConditionalOrderManager) {
+        let store = Arc::new(MockConditionalStore::default());
+        let executor = Arc::new(OrderExecutor::new_with_exchange(
+            Arc::new(DryRunExchange),
+            ExecutionConfig::default(),
+        ));
+        let mgr = ConditionalOrderManager::new(store.clone(), executor);
+        (store, mgr)
+    }
+
+    fn quote(bid: Decimal, ask: Decimal) -> Quote {
+        Quote {
+            side: Side::Up,
+            best_bid: Some(bid),
+            best_ask: Some(ask),
+            bid_size: Some(dec!(100)),
+            ask_size: Some(dec!(100)),
+            timestamp: Utc::now(),
+        }
+    }
+
+    #[test]
+    fn test_ask_sum_condition() {
+        let cond = TriggerCondition::AskSumAtOrBelow {
+            up_token_id: "up".to_string(),
+            down_token_id: "down".to_string(),
+            threshold: dec!(0.95),
+        };
+        let mut quotes = HashMap::new();
+        quotes.insert("up".to_string(), quote(dec!(0.40), dec!(0.45)));
+        assert!(!cond.is_met(&quotes, Utc::now()));
+
+        quotes.insert("down".to_string(), quote(dec!(0.48), dec!(0.50)));
+        assert!(cond.is_met(&quotes, Utc::now()));
+    }
+
+    #[tokio::test]
+    async fn test_stop_limit_fires_once() {
+        let (store, mgr) = manager();
+        let (tx, mut executions) = mpsc::channel(4);
+        let mgr = mgr.with_executions(tx);
+        let order = OrderRequest::sell_limit("tok".to_string(), Side::Up, 10, dec!(0.38));
+        let id = mgr
+            .arm(ConditionalOrder::stop_limit_sell(order, dec!(0.40)))
+            .await
+            .unwrap();
+
+        assert_eq!(mgr.on_quote("tok", quote(dec!(0.45), dec!(0.47))).await, 0);
+        assert_eq!(mgr.on_quote("tok", quote(dec!(0.39), dec!(0.41))).await, 1);
+        assert_eq!(mgr.on_quote("tok", quote(dec!(0.35), dec!(0.37))).await, 0);
+
+        let row = store.rows.lock().unwrap().get(&id).cloned().unwrap();
+        assert_eq!(row.state, ConditionalOrderState::Submitted);
+        assert_eq!(
+            row.order.idempotency_key,
+            Some(format!("conditional:{}", id))
+        );
+        let execution = executions.try_recv().unwrap();
+        assert_eq!(execution.order.id, id);
+        assert!(executions.try_recv().is_err());
+    }
+
+    #[tokio::test]
+    async fn test_cancelled_trigger_does_not_fire() {
+        let (_store, mgr) = manager();
+        let order = OrderRequest::buy_limit("tok".to_string(), Side::Up, 10, dec!(0.60));
+        let cond = TriggerCondition::PriceAtOrAbove {
+            token_id: "tok".to_string(),
+            price: dec!(0.55),
+            source: PriceSource::Ask,
+        };
+        let id = mgr
+            .arm(ConditionalOrder::new(cond, order, None))
+            .await
+            .unwrap();
+
+        assert!(mgr.cancel(id).await.unwrap());
+        assert_eq!(mgr.on_quote("tok", quote(dec!(0.58), dec!(0.60))).await, 0);
+        assert!(!mgr.cancel(id).await.unwrap());
+    }
+
+    #[tokio::test]
+    async fn test_recover_rearms_and_resubmits() {
+        let (store, mgr) = manager();
+        let armed = ConditionalOrder::new(
+            TriggerCondition::AtTime {
+                at: Utc::now() + chrono::Duration::hours(1),
+            },
+            OrderRequest::buy_limit("a".to_string(), Side::Up, 5, dec!(0.50)),
+            None,
+        );
+        let mut interrupted = ConditionalOrder::new(
+            TriggerCondition::AtTime { at: Utc::now() },
+            OrderRequest::buy_limit("b".to_string(), Side::Down, 5, dec!(0.50)),
+            None,
+        );
+        interrupted.state = ConditionalOrderState::Triggered;
+        store.insert_conditional(&armed).await.unwrap();
+        store.insert_conditional(&interrupted).await.unwrap();
+
+        assert_eq!(mgr.recover().await.unwrap(), (1, 1));
+        assert_eq!(mgr.armed().await.len(), 1);
+        let row = store
+            .rows
+            .lock()
+            .unwrap()
+            .get(&interrupted.id)
+            .cloned()
+            .unwrap();
+        assert_eq!(row.state, ConditionalOrderState::Submitted);
+    }
+}
@@ -0,0 +1,233 @@
+//! Cycle checkpoints for crash recovery.
+//!
+//! The engine persists a [`CycleCheckpoint`] on every in-cycle state transition.
+//! On restart, [`decide_resume`] applies the strategy's [`CycleResumePolicy`] to
+//! each incomplete cycle: a hedged-pending cycle (Leg1 filled, no Leg2 yet) can
+//! be resumed while its round is still live; a cycle with a Leg2 compensation
+//! saga in progress always resumes it; anything else with an unconfirmed order
+//! in flight is aborted.
+
+use chrono::{DateTime, Utc};
+use rust_decimal::Decimal;
+use serde::{Deserialize, Serialize};
+
+use crate::config::{CycleResumeConfig, CycleResumePolicy};
+use crate::domain::{Round, Side, StrategyState};
+use crate::persistence::Checkpointable;
+
+use super::saga::LegSaga;
+
+/// Snapshot type used for cycle checkpoints.
+pub const CYCLE_CHECKPOINT_TYPE: &str = "strategy_cycle";
+/// Component name for [`super::StrategyEngine`] cycles.
+pub const ENGINE_COMPONENT: &str = "strategy_engine";
+
+/// Everything needed to pick a cycle back up after a crash.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct CycleCheckpoint {
+    pub cycle_id: i32,
+    pub state: StrategyState,
+    /// Round the cycle belongs to (token ids + round end)
+    pub round: Round,
+    pub leg1_side: Side,
+    pub leg1_price: Decimal,
+    pub leg1_shares: u64,
+    pub leg1_order_id: String,
+    pub leg2_order_id: Option<String>,
+    /// Effective Leg2 sum target the cycle was opened with
+    pub target_sum: Decimal,
+    /// `cycles.version` at checkpoint time
+    pub cycle_version: i32,
+    pub checkpointed_at: DateTime<Utc>,
+    /// Leg2 compensation in progress, if Leg2 failed
+    #[serde(default)]
+    pub saga: Option<LegSaga>,
+}
+
+impl Checkpointable for CycleCheckpoint {
+    fn checkpoint_type(&self) -> &str {
+        CYCLE_CHECKPOINT_TYPE
+    }
+
+    fn component_name(&self) -> &str {
+        ENGINE_COMPONENT
+    }
+
+    fn to_checkpoint(&self) -> serde_json::Value {
+        serde_json::to_value(self).unwrap_or_default()
+    }
+
+    fn from_checkpoint(&mut self, data: &serde_json::Value) -> Result<(), String> {
+        *self = serde_json::from_value(data.clone()).map_err(|e| e.to_string())?;
+        Ok(())
+    }
+
+    fn version(&self) -> i32 {
+        self.cycle_version
+    }
+}
+
+/// What recovery does with one incomplete cycle.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum ResumeDecision {
+    /// Restore the cycle and keep waiting for Leg2
+    Resume,
+    /// Restore the cycle and force Leg2 right away
+    ForceLeg2,
+    /// Restore the cycle and continue its compensation saga
+    Compensate,
+    /// Mark the cycle aborted
+    Abort(String),
+}
+
+/// Apply a resume policy to a checkpointed cycle.
+pub fn decide_resume(
+    checkpoint: &CycleCheckpoint,
+    config: &CycleResumeConfig,
+    now: DateTime<Utc>,
+) -> ResumeDecision {
+    let remaining_secs = (checkpoint.round.end_time - now).num_seconds();
+    if remaining_secs <= 0 {
+        return ResumeDecision::Abort("round ended before recovery".to_string());
+    }
+
+    // Exposure is already known to exist; finish compensating regardless of policy.
+    if checkpoint.saga.as_ref().is_some_and(LegSaga::is_running) {
+        return ResumeDecision::Compensate;
+    }
+
+    match checkpoint.state {
+        StrategyState::Leg1Filled if checkpoint.leg2_order_id.is_none() => {}
+        StrategyState::Leg1Filled | StrategyState::Leg2Pending => {
+            return ResumeDecision::Abort("Leg2 order in flight at crash".to_string());
+        }
+        StrategyState::Leg1Pending => {
+            return ResumeDecision::Abort("Leg1 fill unconfirmed at crash".to_string());
+        }
+        other => {
+            return ResumeDecision::Abort(format!("nothing to resume in state {}", other));
+        }
+    }
+
+    match config.policy {
+        CycleResumePolicy::Abort => ResumeDecision::Abort("resume policy is abort".to_string()),
+        CycleResumePolicy::Resume if remaining_secs < config.min_remaining_secs => {
+            ResumeDecision::Abort(format!(
+                "only {}s left in round (min {}s)",
+                remaining_secs, config.min_remaining_secs
+            ))
+        }
+        CycleResumePolicy::Resume => ResumeDecision::Resume,
+        CycleResumePolicy::Hedge => ResumeDecision::ForceLeg2,
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use chrono::Duration;
+    use rust_decimal_macros::dec;
+
+    fn checkpoint(state: StrategyState, secs_left: i64) -> CycleCheckpoint {
+        let now = Utc::now();
+        CycleCheckpoint {
+            cycle_id: 7,
+            state,
+            round: Round {
+                id: Some(3),
+                slug: "btc-15m".to_string(),
+                up_token_id: "up".to_string(),
+                down_token_id: "down".to_string(),
+                start_time: now - Duration::minutes(5),
+                end_time: now + Duration::seconds(secs_left),
+                outcome: None,
+            },
+            leg1_side: Side::Up,
+            leg1_price: dec!(0.40),
+            leg1_shares: 20,
+            leg1_order_id: "leg1".to_string(),
+            leg2_order_id: None,
+            target_sum: dec!(0.965),
+            cycle_version: 1,
+            checkpointed_at: now,
+            saga: None,
+        }
+    }
+
+    fn policy(policy: CycleResumePolicy) -> CycleResumeConfig {
+        CycleResumeConfig {
+            policy,
+            min_remaining_secs: 60,
+        }
+    }
+
+    #[test]
+    fn test_resume_policies_for_live_hedged_cycle() {
+        let cp = checkpoint(StrategyState::Leg1Filled, 300);
+        let now = Utc::now();
+        assert!(matches!(
+            decide_resume(&cp, &policy(CycleResumePolicy::Abort), now),
+            ResumeDecision::Abort(_)
+        ));
+        assert_eq!(
+            decide_resume(&cp, &policy(CycleResumePolicy::Resume), now),
+            ResumeDecision::Resume
+        );
+        assert_eq!(
+            decide_resume(&cp, &policy(CycleResumePolicy::Hedge), now),
+            ResumeDecision::ForceLeg2
+        );
+    }
+
+    #[test]
+    fn test_unsafe_cycles_are_aborted() {
+        let now = Utc::now();
+        let resume = policy(CycleResumePolicy::Resume);
+
+        let ended = checkpoint(StrategyState::Leg1Filled, -10);
+        assert!(matches!(
+            decide_resume(&ended, &policy(CycleResumePolicy::Hedge), now),
+            ResumeDecision::Abort(_)
+        ));
+
+        let late = checkpoint(StrategyState::Leg1Filled, 30);
+        assert!(matches!(
+            decide_resume(&late, &resume, now),
+            ResumeDecision::Abort(_)
+        ));
+
+        for state in [StrategyState::Leg1Pending, StrategyState::Leg2Pending] {
+            assert!(matches!(
+                decide_resume(&checkpoint(state, 300), &resume, now),
+                ResumeDecision::Abort(_)
+            ));
+        }
+    }
+
+    #[test]
+    fn test_running_saga_is_compensated_under_any_policy() {
+        let now = Utc::now();
+        let mut cp = checkpoint(StrategyState::Leg2Pending, 300);
+        cp.saga = Some(LegSaga::new(
+            &Default::default(),
+            "Leg2 execution failed",
+            Side::Down,
+            cp.leg1_price,
+            cp.leg1_shares,
+            dec!(0.55),
+        ));
+        assert_eq!(
+            decide_resume(&cp, &policy(CycleResumePolicy::Abort), now),
+            ResumeDecision::Compensate
+        );
+    }
+
+    #[test]
+    fn test_checkpoint_round_trip() {
+        let cp = checkpoint(StrategyState::Leg1Filled, 300);
+        let mut restored = checkpoint(StrategyState::Idle, 0);
+        restored.from_checkpoint(&cp.to_checkpoint()).unwrap();
+        assert_eq!(restored.to_checkpoint(), cp.to_checkpoint());
+        assert_eq!(restored.version(), 1);
+    }
+}
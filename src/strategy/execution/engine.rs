@@ -1,17 +1,21 @@
+use super::cycle_checkpoint::{decide_resume, CycleCheckpoint, ResumeDecision};
 use super::engine_store::EngineStore;
+use super::saga::{LegSaga, SagaAction, SagaStatus, StepOutcome};
 use crate::adapters::{QuoteCache, QuoteUpdate};
 use crate::config::AppConfig;
 use crate::domain::{Order, OrderStatus, Round, Side, StrategyState, TimeInForce};
 use crate::error::{PloyError, Result};
+use crate::services::telemetry;
 use crate::strategy::{
-    MarketDepth, OrderExecutor, RiskManager, SignalDetector, SlippageCheck, SlippageConfig,
-    SlippageProtection, TradingCalculator,
+    check_leg2_condition, MarketDepth, OrderExecutor, RiskManager, SignalDetector, SlippageCheck,
+    SlippageConfig, SlippageProtection, TradingCalculator,
 };
 use chrono::Utc;
 use rust_decimal::Decimal;
+use std::sync::atomic::{AtomicBool, Ordering};
 use std::sync::Arc;
 use tokio::sync::{broadcast, Mutex, RwLock};
-use tracing::{debug, error, info, warn};
+use tracing::{debug, error, info, instrument, warn};
 
 /// Main strategy engine orchestrating all components
 pub struct StrategyEngine {
@@ -27,6 +31,8 @@ pub struct StrategyEngine {
     slippage: SlippageProtection,
     /// Mutex to prevent concurrent order submissions (separate from state lock)
     execution_mutex: Mutex<()>,
+    /// Whether checkpointed cycles were already recovered
+    cycles_recovered: AtomicBool,
 }
 
 /// Internal engine state
@@ -53,8 +59,12 @@ struct CycleContext {
     leg1_shares: u64,
     leg1_order_id: String,
     leg2_order_id: Option<String>,
+    /// Effective Leg2 sum target, fixed at entry so a resumed cycle keeps it.
+    target_sum: Decimal,
     /// Guard against duplicate forced Leg2 submissions from concurrent paths.
     force_leg2_attempted: bool,
+    /// Compensation saga once Leg2 has failed
+    saga: Option<LegSaga>,
     /// DB row version for optimistic locking (cycles.version column)
     cycle_version: i32,
 }
@@ -88,7 +98,14 @@ impl StrategyEngine {
         }
 
         let risk_manager = Arc::new(RiskManager::new(config.risk.clone()));
-        let signal_detector = SignalDetector::new(config.strategy.clone());
+        // Market slugs lead with the symbol ("btc-15m-up-down")
+        let symbol = config
+            .market
+            .market_slug
+            .split('-')
+            .next()
+            .unwrap_or_default();
+        let signal_detector = SignalDetector::new(config.strategy.clone()).with_symbol(symbol);
 
         // Create calculator from config buffers
         let calculator = TradingCalculator::with_buffers(
@@ -114,6 +131,7 @@ impl StrategyEngine {
             calculator,
             slippage,
             execution_mutex: Mutex::new(()),
+            cycles_recovered: AtomicBool::new(false),
         })
     }
 
@@ -129,9 +147,19 @@ impl StrategyEngine {
     }
 
     /// Main run loop
+    ///
+    /// Recovers checkpointed cycles first (unless [`Self::recover_cycles`] was
+    /// already called), so a Leg2 compensation interrupted by a crash resumes
+    /// before any new quote is acted on.
     pub async fn run(&self, mut updates: broadcast::Receiver<QuoteUpdate>) -> Result<()> {
         info!("Strategy engine starting");
 
+        if !self.cycles_recovered.load(Ordering::SeqCst) {
+            for (cycle_id, decision) in self.recover_cycles().await? {
+                info!("Recovery: cycle {} -> {:?}", cycle_id, decision);
+            }
+        }
+
         loop {
             // Check for shutdown
             if self.state.read().await.shutdown {
@@ -164,6 +192,11 @@ impl StrategyEngine {
     }
 
     /// Handle a quote update
+    #[instrument(
+        name = "quote",
+        skip_all,
+        fields(token_id = %update.token_id, side = %update.side, cycle_id = tracing::field::Empty)
+    )]
     async fn on_quote_update(&self, update: QuoteUpdate) -> Result<()> {
         // Snapshot state needed for decision-making without holding locks across async work.
         let (round, strategy_state, current_cycle) = {
@@ -174,6 +207,9 @@ impl StrategyEngine {
             };
             (round, state.strategy_state, state.current_cycle.clone())
         };
+        if let Some(ctx) = current_cycle.as_ref() {
+            telemetry::record_cycle_id(ctx.cycle_id);
+        }
 
         // Always enforce round/window transitions even when quote updates are frequent.
         if round.has_ended() {
@@ -185,13 +221,13 @@ impl StrategyEngine {
             return Ok(());
         }
 
-        if strategy_state == StrategyState::WatchWindow {
-            let minutes_elapsed = round.minutes_elapsed();
-            if minutes_elapsed >= self.config.strategy.window_min as i64 {
-                info!("Watch window expired after {} minutes", minutes_elapsed);
-                self.transition_to_idle().await?;
-                return Ok(());
-            }
+        if strategy_state == StrategyState::WatchWindow && !self.in_watch_window(&round) {
+            info!(
+                "Watch window expired after {} minutes",
+                round.minutes_elapsed()
+            );
+            self.transition_to_idle().await?;
+            return Ok(());
         }
 
         // Ignore updates for tokens that don't belong to the active round.
@@ -241,9 +277,7 @@ impl StrategyEngine {
                         if update.side != opposite_side {
                             None
                         } else if let Some(ask) = update.quote.best_ask {
-                            let detector = self.signal_detector.read().await;
-                            detector
-                                .check_leg2_condition(ctx.leg1_price, ask)
+                            check_leg2_condition(ctx.leg1_price, ask, ctx.target_sum)
                                 .then_some((opposite_side, ask))
                         } else {
                             None
@@ -309,9 +343,11 @@ impl StrategyEngine {
                 self.force_leg2_or_abort().await?;
             } else if state.strategy_state == StrategyState::WatchWindow {
                 // Check if window expired
-                let minutes_elapsed = round.minutes_elapsed();
-                if minutes_elapsed >= self.config.strategy.window_min as i64 {
-                    info!("Watch window expired after {} minutes", minutes_elapsed);
+                if !self.in_watch_window(&round) {
+                    info!(
+                        "Watch window expired after {} minutes",
+                        round.minutes_elapsed()
+                    );
                     drop(state);
                     self.transition_to_idle().await?;
                 }
@@ -354,17 +390,17 @@ impl StrategyEngine {
 
             // Transition to watch window if idle (and still within the configured entry window).
             if state.strategy_state == StrategyState::Idle {
-                if !round.has_ended()
-                    && round.minutes_elapsed() < self.config.strategy.window_min as i64
-                {
+                if !round.has_ended() && self.in_watch_window(&round) {
                     state.strategy_state = StrategyState::WatchWindow;
                     info!("Entering watch window for round: {}", round.slug);
                 } else {
                     debug!(
-                        "Round {} already outside watch window (elapsed={}m, window={}m, ended={})",
+                        "Round {} already outside watch window (elapsed={}s, window={}s, ended={})",
                         round.slug,
-                        round.minutes_elapsed(),
-                        self.config.strategy.window_min,
+                        round.seconds_elapsed(),
+                        self.config
+                            .strategy
+                            .watch_window_secs(round.duration_secs()),
                         round.has_ended(),
                     );
                 }
@@ -378,6 +414,7 @@ impl StrategyEngine {
         {
             let mut detector = self.signal_detector.write().await;
             detector.reset(Some(&round.slug));
+            detector.set_round_duration(round.duration_secs());
         }
 
         // Persist strategy state for observability/crash recovery (best effort).
@@ -394,7 +431,125 @@ impl StrategyEngine {
         Ok(())
     }
 
+    /// Apply the strategy's resume policy to cycles left incomplete by a crash.
+    ///
+    /// Call once at startup before `run`. At most one hedged-pending or
+    /// compensating cycle (the newest) is restored; every other incomplete cycle
+    /// is aborted.
+    pub async fn recover_cycles(&self) -> Result<Vec<(i32, ResumeDecision)>> {
+        {
+            let state = self.state.read().await;
+            if state.strategy_state != StrategyState::Idle || state.current_cycle.is_some() {
+                return Err(PloyError::InvalidState(format!(
+                    "Cycle recovery requires an idle engine (state {})",
+                    state.strategy_state
+                )));
+            }
+        }
+        self.cycles_recovered.store(true, Ordering::SeqCst);
+
+        let now = Utc::now();
+        let mut decisions = Vec::new();
+        let mut restored: Option<(CycleCheckpoint, ResumeDecision)> = None;
+
+        for (cycle_id, checkpoint) in self.store.load_incomplete_cycles().await? {
+            let decision = match checkpoint.as_ref() {
+                None => ResumeDecision::Abort("no checkpoint".to_string()),
+                Some(_) if restored.is_some() => {
+                    ResumeDecision::Abort("a newer cycle was resumed".to_string())
+                }
+                Some(cp) => decide_resume(cp, &self.config.strategy.resume, now),
+            };
+
+            match &decision {
+                ResumeDecision::Abort(reason) => {
+                    warn!("Recovery: aborting cycle {}: {}", cycle_id, reason);
+                    if let Err(e) = self
+                        .store
+                        .abort_cycle(cycle_id, &format!("Recovery: {}", reason))
+                        .await
+                    {
+                        error!("Failed to abort cycle {} in DB: {}", cycle_id, e);
+                    }
+                }
+                ResumeDecision::Resume | ResumeDecision::ForceLeg2 | ResumeDecision::Compensate => {
+                    restored = checkpoint.map(|cp| (cp, decision.clone()));
+                }
+            }
+            decisions.push((cycle_id, decision));
+        }
+
+        if let Some((checkpoint, decision)) = restored {
+            self.restore_cycle(&checkpoint).await;
+            match decision {
+                ResumeDecision::ForceLeg2 => self.force_leg2_or_abort().await?,
+                ResumeDecision::Compensate => self.resume_compensation().await?,
+                _ => {}
+            }
+        }
+
+        Ok(decisions)
+    }
+
+    /// Restore a hedged-pending cycle from its checkpoint.
+    async fn restore_cycle(&self, checkpoint: &CycleCheckpoint) {
+        {
+            let mut state = self.state.write().await;
+            state.current_round = Some(checkpoint.round.clone());
+            state.current_cycle = Some(CycleContext {
+                cycle_id: checkpoint.cycle_id,
+                leg1_side: checkpoint.leg1_side,
+                leg1_price: checkpoint.leg1_price,
+                leg1_shares: checkpoint.leg1_shares,
+                leg1_order_id: checkpoint.leg1_order_id.clone(),
+                leg2_order_id: checkpoint
+                    .saga
+                    .as_ref()
+                    .and(checkpoint.leg2_order_id.clone()),
+                target_sum: checkpoint.target_sum,
+                force_leg2_attempted: false,
+                saga: checkpoint.saga.clone(),
+                cycle_version: checkpoint.cycle_version,
+            });
+            state.strategy_state = if checkpoint.saga.is_some() {
+                StrategyState::Leg2Pending
+            } else {
+                StrategyState::Leg1Filled
+            };
+            state.version += 1;
+        }
+
+        {
+            let mut detector = self.signal_detector.write().await;
+            detector.reset(Some(&checkpoint.round.slug));
+            detector.mark_triggered(checkpoint.leg1_side);
+        }
+
+        info!(
+            "Resumed cycle {} in round {}: {} {} shares @ {}, target sum {}",
+            checkpoint.cycle_id,
+            checkpoint.round.slug,
+            checkpoint.leg1_side,
+            checkpoint.leg1_shares,
+            checkpoint.leg1_price,
+            checkpoint.target_sum
+        );
+
+        let strategy_state = self.state.read().await.strategy_state;
+        self.persist_strategy_state_best_effort(
+            strategy_state,
+            checkpoint.round.id,
+            Some(checkpoint.cycle_id),
+        )
+        .await;
+    }
+
     /// Enter Leg1 position
+    #[instrument(
+        name = "signal",
+        skip(self),
+        fields(leg = 1, cycle_id = tracing::field::Empty, trade_id = tracing::field::Empty)
+    )]
     async fn enter_leg1(&self, side: Side, price: Decimal) -> Result<()> {
         let _exec_guard = self.execution_mutex.lock().await;
 
@@ -511,9 +666,7 @@ impl StrategyEngine {
                     round.slug, current_round.slug
                 )));
             }
-            if current_round.has_ended()
-                || current_round.minutes_elapsed() >= self.config.strategy.window_min as i64
-            {
+            if current_round.has_ended() || !self.in_watch_window(current_round) {
                 return Err(PloyError::InvalidState(format!(
                     "Round {} is no longer within the entry window",
                     current_round.slug
@@ -535,13 +688,17 @@ impl StrategyEngine {
                 // Use client_order_id until we have an exchange order id.
                 leg1_order_id: request.client_order_id.clone(),
                 leg2_order_id: None,
+                target_sum: self.config.strategy.effective_sum_target(),
                 force_leg2_attempted: false,
+                saga: None,
                 cycle_version: 0,
             });
             state.version += 1;
 
             (cycle_id, expected_version)
         };
+        telemetry::record_cycle_id(cycle_id);
+        telemetry::record_trade_id(&request.client_order_id);
 
         // Persist state transition (best effort).
         self.persist_strategy_state_best_effort(
@@ -668,7 +825,9 @@ impl StrategyEngine {
                     leg1_shares: result.filled_shares,
                     leg1_order_id: result.order_id.clone(),
                     leg2_order_id: None,
+                    target_sum: self.config.strategy.effective_sum_target(),
                     force_leg2_attempted: false,
+                    saga: None,
                     cycle_version: 0,
                 };
 
@@ -745,7 +904,9 @@ impl StrategyEngine {
                     leg1_shares: result.filled_shares,
                     leg1_order_id: result.order_id,
                     leg2_order_id: None,
+                    target_sum: self.config.strategy.effective_sum_target(),
                     force_leg2_attempted: false,
+                    saga: None,
                     // version 0 → +1 after leg1 update = 1
                     cycle_version: 1,
                 });
@@ -760,6 +921,7 @@ impl StrategyEngine {
                 Some(cycle_id),
             )
             .await;
+            self.checkpoint_cycle_best_effort().await;
 
             info!(
                 "Leg1 filled: {} shares @ {}",
@@ -796,6 +958,11 @@ impl StrategyEngine {
         self.enter_leg2_inner(side, price, true).await
     }
 
+    #[instrument(
+        name = "signal",
+        skip(self),
+        fields(leg = 2, cycle_id = tracing::field::Empty, trade_id = tracing::field::Empty)
+    )]
     async fn enter_leg2_inner(&self, side: Side, price: Decimal, forced: bool) -> Result<()> {
         let _exec_guard = self.execution_mutex.lock().await;
 
@@ -979,6 +1146,8 @@ impl StrategyEngine {
             state.version += 1;
             expected_version
         };
+        telemetry::record_cycle_id(ctx.cycle_id);
+        telemetry::record_trade_id(&request.client_order_id);
 
         // Persist state transition (best effort).
         self.persist_strategy_state_best_effort(
@@ -993,6 +1162,7 @@ impl StrategyEngine {
             .store
             .update_cycle_state(ctx.cycle_id, StrategyState::Leg2Pending, ctx.cycle_version)
             .await;
+        self.checkpoint_cycle_best_effort().await;
 
         // Persist the intent before submitting to the exchange (best effort).
         let client_order_id = request.client_order_id.clone();
@@ -1044,45 +1214,17 @@ impl StrategyEngine {
         let result = match self.executor.execute(&request).await {
             Ok(r) => r,
             Err(e) => {
-                // Open exposure exists (Leg1). Best-effort unwind before halting.
-                let unwind_shares = ctx.leg1_shares;
-                let unwind_summary =
-                    match self.unwind_leg1_exposure(&ctx, &round, unwind_shares).await {
-                        Ok(s) => s,
-                        Err(err) => format!("unwind failed: {}", err),
-                    };
-
-                let reason = format!("Leg2 execution failed; {}", unwind_summary);
-                if let Err(err) = self.store.abort_cycle(ctx.cycle_id, &reason).await {
-                    error!("Failed to abort cycle {} in DB: {}", ctx.cycle_id, err);
-                }
-                self.risk_manager
-                    .record_failure("Leg2 execution failed")
-                    .await;
-
-                let today = Utc::now().date_naive();
-                if let Err(e) = self.store.record_cycle_abort(today).await {
-                    error!("Failed to record cycle abort: {}", e);
-                }
-
-                let halt_reason = "Leg2 execution failed - open exposure";
-                self.risk_manager.trigger_circuit_breaker(halt_reason).await;
-                if let Err(e) = self.store.halt_trading(today, halt_reason).await {
-                    error!("Failed to persist halt_trading: {}", e);
-                }
-                self.persist_halt_if_needed().await;
-
-                {
-                    let mut state = self.state.write().await;
-                    state.strategy_state = StrategyState::Abort;
-                    state.current_cycle = None;
-                    state.version += 1;
-                }
-
-                self.persist_strategy_state_best_effort(StrategyState::Abort, round.id, None)
-                    .await;
-
-                return Err(e);
+                // Open exposure exists (Leg1): hand over to the compensation saga.
+                error!("Leg2 execution failed for cycle {}: {}", ctx.cycle_id, e);
+                let saga = LegSaga::new(
+                    &self.config.execution.compensation,
+                    format!("Leg2 execution failed: {}", e),
+                    side,
+                    ctx.leg1_price,
+                    ctx.leg1_shares,
+                    order_price,
+                );
+                return self.run_compensation(&ctx, &round, saga).await;
             }
         };
 
@@ -1248,50 +1390,25 @@ impl StrategyEngine {
                 result.filled_shares, ctx.leg1_shares, result.status
             );
 
-            let today = Utc::now().date_naive();
-            let unhedged = ctx.leg1_shares.saturating_sub(result.filled_shares);
-            let unwind_summary = if unhedged > 0 {
-                match self.unwind_leg1_exposure(&ctx, &round, unhedged).await {
-                    Ok(s) => s,
-                    Err(err) => format!("unwind failed: {}", err),
-                }
-            } else {
-                "unwind skipped (no unhedged shares)".to_string()
-            };
-
-            let reason = format!(
-                "Leg2 not fully filled (filled {}, expected {}, {:?}); {}",
-                result.filled_shares, ctx.leg1_shares, result.status, unwind_summary
+            let mut saga = LegSaga::new(
+                &self.config.execution.compensation,
+                format!(
+                    "Leg2 not fully filled (filled {}, expected {}, {:?})",
+                    result.filled_shares, ctx.leg1_shares, result.status
+                ),
+                side,
+                ctx.leg1_price,
+                ctx.leg1_shares,
+                order_price,
+            )
+            .with_hedged(
+                result.filled_shares,
+                result.avg_fill_price.unwrap_or(order_price),
             );
-
-            if let Err(err) = self.store.abort_cycle(ctx.cycle_id, &reason).await {
-                error!("Failed to abort cycle {} in DB: {}", ctx.cycle_id, err);
-            }
-            self.risk_manager
-                .record_failure("Leg2 not fully filled")
-                .await;
-
-            if let Err(e) = self.store.record_cycle_abort(today).await {
-                error!("Failed to record cycle abort: {}", e);
+            if !result.status.is_terminal() {
+                saga = saga.with_stray_order(result.order_id.clone());
             }
-
-            // Halt trading for manual intervention (exposure may exist).
-            let halt_reason = "Leg2 not fully filled - open exposure";
-            self.risk_manager.trigger_circuit_breaker(halt_reason).await;
-            if let Err(e) = self.store.halt_trading(today, halt_reason).await {
-                error!("Failed to persist halt_trading: {}", e);
-            }
-            self.persist_halt_if_needed().await;
-
-            {
-                let mut state = self.state.write().await;
-                state.strategy_state = StrategyState::Abort;
-                state.current_cycle = None;
-                state.version += 1;
-            }
-
-            self.persist_strategy_state_best_effort(StrategyState::Abort, round.id, None)
-                .await;
+            self.run_compensation(&ctx, &round, saga).await?;
         }
 
         Ok(())
@@ -1308,8 +1425,20 @@ impl StrategyEngine {
         round: &Round,
         shares_to_unwind: u64,
     ) -> Result<String> {
+        self.unwind_leg1(ctx, round, shares_to_unwind)
+            .await
+            .map(|(_, summary)| summary)
+    }
+
+    /// Unwind Leg1 and return the shares actually sold alongside the summary.
+    async fn unwind_leg1(
+        &self,
+        ctx: &CycleContext,
+        round: &Round,
+        shares_to_unwind: u64,
+    ) -> Result<(u64, String)> {
         if shares_to_unwind == 0 {
-            return Ok("unwind skipped (0 shares)".to_string());
+            return Ok((0, "unwind skipped (0 shares)".to_string()));
         }
 
         let token_id = round.token_id(ctx.leg1_side).to_string();
@@ -1420,12 +1549,261 @@ impl StrategyEngine {
                 .await;
         }
 
-        Ok(format!(
-            "unwind: sold {} of {} shares (status={:?}, avg_fill_price={:?})",
-            result.filled_shares, shares_to_unwind, result.status, result.avg_fill_price
+        Ok((
+            result.filled_shares,
+            format!(
+                "unwind: sold {} of {} shares (status={:?}, avg_fill_price={:?})",
+                result.filled_shares, shares_to_unwind, result.status, result.avg_fill_price
+            ),
         ))
     }
 
+    /// Continue the compensation saga of a cycle restored from a checkpoint.
+    async fn resume_compensation(&self) -> Result<()> {
+        let _exec_guard = self.execution_mutex.lock().await;
+
+        let (ctx, round) = {
+            let state = self.state.read().await;
+            match (state.current_cycle.clone(), state.current_round.clone()) {
+                (Some(ctx), Some(round)) => (ctx, round),
+                _ => return Ok(()),
+            }
+        };
+        let Some(mut saga) = ctx.saga.clone() else {
+            return Ok(());
+        };
+
+        saga.resume_after_crash();
+        warn!(
+            "Resuming Leg2 compensation for cycle {}: {}",
+            ctx.cycle_id,
+            saga.summary()
+        );
+        self.run_compensation(&ctx, &round, saga).await
+    }
+
+    /// Drive a Leg2 compensation saga to completion.
+    ///
+    /// Caller must hold `execution_mutex`. The saga is checkpointed before every step so a
+    /// crash resumes it rather than leaving the exposure unmanaged.
+    async fn run_compensation(
+        &self,
+        ctx: &CycleContext,
+        round: &Round,
+        mut saga: LegSaga,
+    ) -> Result<()> {
+        let config = &self.config.execution.compensation;
+
+        loop {
+            self.checkpoint_saga(&saga).await;
+
+            let outcome = match saga.advance(config) {
+                SagaAction::Done(_) => break,
+                SagaAction::Cancel { order_id } => match self.executor.cancel(&order_id).await {
+                    Ok(_) => StepOutcome::Cancelled,
+                    Err(e) => StepOutcome::Failed(format!("cancel {} failed: {}", order_id, e)),
+                },
+                SagaAction::Resubmit { shares, price } => {
+                    self.resubmit_leg2(ctx, round, &mut saga, shares, price)
+                        .await
+                }
+                SagaAction::MarketOut { shares } => {
+                    match self.unwind_leg1(ctx, round, shares).await {
+                        Ok((sold, _)) => StepOutcome::Unwound { shares: sold },
+                        Err(e) => StepOutcome::Failed(format!("unwind failed: {}", e)),
+                    }
+                }
+                SagaAction::Alert { reason } => {
+                    error!("Cycle {} compensation escalated: {}", ctx.cycle_id, reason);
+                    StepOutcome::Alerted
+                }
+            };
+            saga.record(outcome);
+        }
+
+        self.finish_compensation(ctx, round, saga).await
+    }
+
+    /// Store the saga on the active cycle and checkpoint it (best effort).
+    async fn checkpoint_saga(&self, saga: &LegSaga) {
+        {
+            let mut state = self.state.write().await;
+            if let Some(active) = state.current_cycle.as_mut() {
+                active.saga = Some(saga.clone());
+            }
+        }
+        self.checkpoint_cycle_best_effort().await;
+    }
+
+    /// Resubmit the unhedged shares as a FOK Leg2 at the saga's widened price.
+    async fn resubmit_leg2(
+        &self,
+        ctx: &CycleContext,
+        round: &Round,
+        saga: &mut LegSaga,
+        shares: u64,
+        price: Decimal,
+    ) -> StepOutcome {
+        let token_id = round.token_id(saga.hedge_side).to_string();
+        let mut request =
+            crate::domain::OrderRequest::buy_limit(token_id, saga.hedge_side, shares, price);
+        request.time_in_force = TimeInForce::FOK;
+
+        info!(
+            "Compensation resubmit for cycle {}: {} {} shares @ {}",
+            ctx.cycle_id, saga.hedge_side, shares, price
+        );
+
+        let client_order_id = request.client_order_id.clone();
+        let order = Order::from_request(&request, Some(ctx.cycle_id), 2);
+        if let Err(e) = self.store.insert_order(&order).await {
+            return StepOutcome::Failed(format!("failed to persist resubmit: {}", e));
+        }
+
+        saga.begin(&client_order_id);
+        self.checkpoint_saga(saga).await;
+
+        let result = match self.executor.execute(&request).await {
+            Ok(r) => r,
+            Err(e) => return StepOutcome::Failed(format!("resubmit failed: {}", e)),
+        };
+
+        let _ = self
+            .store
+            .update_order_status(
+                &client_order_id,
+                OrderStatus::Submitted,
+                Some(&result.order_id),
+            )
+            .await;
+
+        let fill_price = result.avg_fill_price.unwrap_or(price);
+        if result.filled_shares > 0 {
+            let _ = self
+                .store
+                .update_order_fill(
+                    &client_order_id,
+                    result.filled_shares,
+                    fill_price,
+                    result.status,
+                )
+                .await;
+        } else {
+            let _ = self
+                .store
+                .update_order_status(&client_order_id, result.status, None)
+                .await;
+        }
+
+        StepOutcome::Hedged {
+            shares: result.filled_shares,
+            fill_price,
+            limit_price: price,
+        }
+    }
+
+    /// Settle the cycle once its compensation saga has stopped running.
+    async fn finish_compensation(
+        &self,
+        ctx: &CycleContext,
+        round: &Round,
+        saga: LegSaga,
+    ) -> Result<()> {
+        let reason = format!("{}; compensation: {}", saga.reason, saga.summary());
+
+        match saga.status {
+            SagaStatus::Hedged => {
+                let fill_price = saga.average_hedge_price().unwrap_or(saga.last_hedge_price);
+                let net_pnl =
+                    self.calculator
+                        .expected_pnl(saga.hedged_shares, ctx.leg1_price, fill_price);
+
+                self.risk_manager.record_success(net_pnl).await;
+                let today = Utc::now().date_naive();
+                if let Err(e) = self
+                    .store
+                    .update_cycle_leg2(
+                        ctx.cycle_id,
+                        fill_price,
+                        saga.hedged_shares,
+                        net_pnl,
+                        ctx.cycle_version,
+                    )
+                    .await
+                {
+                    error!(
+                        "Failed to update cycle {} after compensated Leg2: {}",
+                        ctx.cycle_id, e
+                    );
+                    let halt_reason = "DB update failed after Leg2 fill";
+                    self.risk_manager.trigger_circuit_breaker(halt_reason).await;
+                    if let Err(e) = self.store.halt_trading(today, halt_reason).await {
+                        error!("Failed to persist halt_trading: {}", e);
+                    }
+                }
+                if let Err(e) = self.store.record_cycle_completion(today, net_pnl).await {
+                    error!("Failed to record cycle completion: {}", e);
+                }
+                self.persist_halt_if_needed().await;
+
+                {
+                    let mut state = self.state.write().await;
+                    state.strategy_state = StrategyState::CycleComplete;
+                    if let Some(active) = state.current_cycle.as_mut() {
+                        active.saga = None;
+                    }
+                    state.version += 1;
+                }
+                self.persist_strategy_state_best_effort(
+                    StrategyState::CycleComplete,
+                    round.id,
+                    Some(ctx.cycle_id),
+                )
+                .await;
+
+                info!(
+                    "Cycle {} hedged by compensation: {} shares @ {}. Cycle PnL: {}",
+                    ctx.cycle_id, saga.hedged_shares, fill_price, net_pnl
+                );
+                Ok(())
+            }
+            // Exposure is flat: a plain abort, counted as a risk failure.
+            SagaStatus::Unwound => self.abort_cycle(&reason).await,
+            SagaStatus::Running | SagaStatus::Escalated => {
+                if let Err(e) = self.store.abort_cycle(ctx.cycle_id, &reason).await {
+                    error!("Failed to abort cycle {} in DB: {}", ctx.cycle_id, e);
+                }
+                self.risk_manager
+                    .record_failure("Leg2 compensation escalated")
+                    .await;
+
+                let today = Utc::now().date_naive();
+                if let Err(e) = self.store.record_cycle_abort(today).await {
+                    error!("Failed to record cycle abort: {}", e);
+                }
+
+                // Halt trading for manual intervention (exposure may exist).
+                let halt_reason = "Leg2 compensation escalated - open exposure";
+                self.risk_manager.trigger_circuit_breaker(halt_reason).await;
+                if let Err(e) = self.store.halt_trading(today, halt_reason).await {
+                    error!("Failed to persist halt_trading: {}", e);
+                }
+                self.persist_halt_if_needed().await;
+
+                {
+                    let mut state = self.state.write().await;
+                    state.strategy_state = StrategyState::Abort;
+                    state.current_cycle = None;
+                    state.version += 1;
+                }
+
+                self.persist_strategy_state_best_effort(StrategyState::Abort, round.id, None)
+                    .await;
+                Ok(())
+            }
+        }
+    }
+
     async fn persist_halt_if_needed(&self) {
         if self.risk_manager.can_trade().await {
             return;
@@ -1458,6 +1836,36 @@ impl StrategyEngine {
         }
     }
 
+    /// Persist a resumable checkpoint of the active cycle (best effort).
+    async fn checkpoint_cycle_best_effort(&self) {
+        let checkpoint = {
+            let state = self.state.read().await;
+            let (Some(ctx), Some(round)) =
+                (state.current_cycle.as_ref(), state.current_round.as_ref())
+            else {
+                return;
+            };
+            CycleCheckpoint {
+                cycle_id: ctx.cycle_id,
+                state: state.strategy_state,
+                round: round.clone(),
+                leg1_side: ctx.leg1_side,
+                leg1_price: ctx.leg1_price,
+                leg1_shares: ctx.leg1_shares,
+                leg1_order_id: ctx.leg1_order_id.clone(),
+                leg2_order_id: ctx.leg2_order_id.clone(),
+                target_sum: ctx.target_sum,
+                cycle_version: ctx.cycle_version,
+                checkpointed_at: Utc::now(),
+                saga: ctx.saga.clone(),
+            }
+        };
+
+        if let Err(e) = self.store.save_cycle_checkpoint(&checkpoint).await {
+            error!("Failed to checkpoint cycle {}: {}", checkpoint.cycle_id, e);
+        }
+    }
+
     /// Abort the current cycle and halt trading.
     ///
     /// If we're in `LEG1_FILLED` and no Leg2 has been started, this will attempt a best-effort
@@ -1658,6 +2066,15 @@ impl StrategyEngine {
         Ok(())
     }
 
+    /// Whether the round is still inside the (duration-scaled) watch window
+    fn in_watch_window(&self, round: &Round) -> bool {
+        round.seconds_elapsed()
+            < self
+                .config
+                .strategy
+                .watch_window_secs(round.duration_secs())
+    }
+
     /// Transition back to idle state
     async fn transition_to_idle(&self) -> Result<()> {
         {
@@ -1699,7 +2116,7 @@ mod tests {
         BalanceResponse, MarketResponse, MarketSummary, OrderResponse, PositionResponse,
         TradeResponse,
     };
-    use crate::config::AppConfig;
+    use crate::config::{AppConfig, CycleResumePolicy};
     use crate::domain::Round;
     use crate::exchange::{ExchangeClient, ExchangeKind};
     use crate::strategy::execution::engine_store::mock::MockStore;
@@ -1975,7 +2392,9 @@ mod tests {
                 leg1_shares: 100,
                 leg1_order_id: "test-order".to_string(),
                 leg2_order_id: None,
+                target_sum: dec!(0.915),
                 force_leg2_attempted: false,
+                saga: None,
                 cycle_version: 0,
             });
         }
@@ -2008,7 +2427,9 @@ mod tests {
                 leg1_shares: 50,
                 leg1_order_id: "leg1-order".to_string(),
                 leg2_order_id: None,
+                target_sum: dec!(0.915),
                 force_leg2_attempted: false,
+                saga: None,
                 cycle_version: 0,
             });
         }
@@ -2021,6 +2442,111 @@ mod tests {
         );
     }
 
+    fn checkpoint(cycle_id: i32, state: StrategyState) -> CycleCheckpoint {
+        CycleCheckpoint {
+            cycle_id,
+            state,
+            round: Round {
+                id: Some(1),
+                ..test_round(10)
+            },
+            leg1_side: Side::Up,
+            leg1_price: dec!(0.40),
+            leg1_shares: 100,
+            leg1_order_id: format!("leg1-{}", cycle_id),
+            leg2_order_id: None,
+            target_sum: dec!(0.90),
+            cycle_version: 1,
+            checkpointed_at: Utc::now(),
+            saga: None,
+        }
+    }
+
+    async fn recovery_engine(policy: CycleResumePolicy, store: MockStore) -> StrategyEngine {
+        let mut config = test_config();
+        config.strategy.resume.policy = policy;
+        let executor = OrderExecutor::new_with_exchange(
+            Arc::new(MockExchangeClient),
+            config.execution.clone(),
+        );
+        StrategyEngine::new(config, store, executor, QuoteCache::new())
+            .await
+            .expect("engine should construct")
+    }
+
+    #[tokio::test]
+    async fn recover_cycles_resumes_newest_hedged_cycle() {
+        let store = MockStore::new()
+            .with_checkpoint(checkpoint(5, StrategyState::Leg1Filled))
+            .with_checkpoint(checkpoint(8, StrategyState::Leg1Filled))
+            .with_checkpoint(checkpoint(3, StrategyState::Leg1Pending));
+        let engine = recovery_engine(CycleResumePolicy::Resume, store).await;
+
+        let decisions = engine.recover_cycles().await.unwrap();
+        assert_eq!(decisions[0], (8, ResumeDecision::Resume));
+        assert!(matches!(decisions[1], (5, ResumeDecision::Abort(_))));
+        assert!(matches!(decisions[2], (3, ResumeDecision::Abort(_))));
+
+        assert_eq!(engine.state().await, StrategyState::Leg1Filled);
+        let state = engine.state.read().await;
+        let ctx = state.current_cycle.as_ref().expect("cycle restored");
+        assert_eq!(ctx.cycle_id, 8);
+        assert_eq!(ctx.target_sum, dec!(0.90));
+        assert_eq!(ctx.cycle_version, 1);
+        assert_eq!(state.current_round.as_ref().unwrap().slug, "test-btc-15m");
+    }
+
+    #[tokio::test]
+    async fn recover_cycles_abort_policy_keeps_engine_idle() {
+        let store = MockStore::new().with_checkpoint(checkpoint(8, StrategyState::Leg1Filled));
+        let engine = recovery_engine(CycleResumePolicy::Abort, store).await;
+
+        let decisions = engine.recover_cycles().await.unwrap();
+        assert!(matches!(decisions[..], [(8, ResumeDecision::Abort(_))]));
+        assert_eq!(engine.state().await, StrategyState::Idle);
+        // Aborted cycles are no longer incomplete.
+        assert!(engine.recover_cycles().await.unwrap().is_empty());
+    }
+
+    #[tokio::test]
+    async fn run_resumes_compensation_interrupted_by_crash() {
+        // Crashed while a compensation resubmit was in flight
+        let compensation = crate::config::CompensationConfig::default();
+        let mut saga = LegSaga::new(
+            &compensation,
+            "Leg2 execution failed",
+            Side::Down,
+            dec!(0.40),
+            100,
+            dec!(0.50),
+        );
+        assert!(matches!(
+            saga.advance(&compensation),
+            SagaAction::Resubmit { shares: 100, .. }
+        ));
+        saga.begin("resubmit-1");
+        let store = MockStore::new().with_checkpoint(CycleCheckpoint {
+            saga: Some(saga),
+            ..checkpoint(7, StrategyState::Leg2Pending)
+        });
+        let engine = recovery_engine(CycleResumePolicy::Resume, store).await;
+
+        // The restarted engine escalates the unconfirmed resubmit before any quote
+        let (tx, rx) = broadcast::channel(1);
+        drop(tx);
+        engine.run(rx).await.unwrap();
+
+        assert_eq!(engine.state().await, StrategyState::Abort);
+        assert!(!engine.risk_manager.can_trade().await);
+        // The escalated cycle was aborted, so nothing is left to recover.
+        assert!(engine
+            .store
+            .load_incomplete_cycles()
+            .await
+            .unwrap()
+            .is_empty());
+    }
+
     #[tokio::test]
     async fn dry_run_safety_guard_rejects_live_mode_without_confirm_fills() {
         let mut config = test_config();
@@ -6,6 +6,7 @@ use async_trait::async_trait;
 use chrono::NaiveDate;
 use rust_decimal::Decimal;
 
+use super::cycle_checkpoint::CycleCheckpoint;
 use crate::domain::{Order, OrderStatus, Round, Side, StrategyState};
 use crate::error::Result;
 
@@ -43,6 +44,9 @@ pub trait EngineStore: Send + Sync {
         expected_version: i32,
     ) -> Result<bool>;
     async fn abort_cycle(&self, cycle_id: i32, reason: &str) -> Result<()>;
+    async fn save_cycle_checkpoint(&self, checkpoint: &CycleCheckpoint) -> Result<()>;
+    /// Incomplete cycles, newest first, with their checkpoint when one was saved.
+    async fn load_incomplete_cycles(&self) -> Result<Vec<(i32, Option<CycleCheckpoint>)>>;
 
     // --- Orders ---
     async fn insert_order(&self, order: &Order) -> Result<i32>;
@@ -118,6 +122,24 @@ impl EngineStore for crate::adapters::PostgresStore {
     async fn abort_cycle(&self, cycle_id: i32, reason: &str) -> Result<()> {
         self.abort_cycle(cycle_id, reason).await
     }
+    async fn save_cycle_checkpoint(&self, checkpoint: &CycleCheckpoint) -> Result<()> {
+        let data = serde_json::to_value(checkpoint)?;
+        self.update_cycle_checkpoint(checkpoint.cycle_id, data)
+            .await
+    }
+    async fn load_incomplete_cycles(&self) -> Result<Vec<(i32, Option<CycleCheckpoint>)>> {
+        Ok(self
+            .get_incomplete_cycles()
+            .await?
+            .into_iter()
+            .map(|c| {
+                let checkpoint = c
+                    .checkpoint
+                    .and_then(|data| serde_json::from_value(data).ok());
+                (c.cycle_id, checkpoint)
+            })
+            .collect())
+    }
     async fn insert_order(&self, order: &Order) -> Result<i32> {
         self.insert_order(order).await
     }
@@ -168,22 +190,36 @@ impl EngineStore for crate::adapters::PostgresStore {
 #[cfg(test)]
 pub mod mock {
     use super::*;
+    use std::collections::BTreeMap;
     use std::sync::atomic::{AtomicI32, Ordering};
+    use std::sync::Mutex;
 
     /// In-memory mock store for engine unit tests.
     ///
     /// All write operations succeed silently and return sequential IDs.
+    /// Cycle checkpoints are kept until the cycle is aborted.
     pub struct MockStore {
         next_id: AtomicI32,
+        checkpoints: Mutex<BTreeMap<i32, CycleCheckpoint>>,
     }
 
     impl MockStore {
         pub fn new() -> Self {
             Self {
                 next_id: AtomicI32::new(1),
+                checkpoints: Mutex::new(BTreeMap::new()),
             }
         }
 
+        /// Seed an incomplete cycle as if left behind by a crashed process.
+        pub fn with_checkpoint(self, checkpoint: CycleCheckpoint) -> Self {
+            self.checkpoints
+                .lock()
+                .unwrap()
+                .insert(checkpoint.cycle_id, checkpoint);
+            self
+        }
+
         fn next_id(&self) -> i32 {
             self.next_id.fetch_add(1, Ordering::SeqCst)
         }
@@ -231,9 +267,27 @@ pub mod mock {
         ) -> Result<bool> {
             Ok(true)
         }
-        async fn abort_cycle(&self, _cycle_id: i32, _reason: &str) -> Result<()> {
+        async fn abort_cycle(&self, cycle_id: i32, _reason: &str) -> Result<()> {
+            self.checkpoints.lock().unwrap().remove(&cycle_id);
+            Ok(())
+        }
+        async fn save_cycle_checkpoint(&self, checkpoint: &CycleCheckpoint) -> Result<()> {
+            self.checkpoints
+                .lock()
+                .unwrap()
+                .insert(checkpoint.cycle_id, checkpoint.clone());
             Ok(())
         }
+        async fn load_incomplete_cycles(&self) -> Result<Vec<(i32, Option<CycleCheckpoint>)>> {
+            Ok(self
+                .checkpoints
+                .lock()
+                .unwrap()
+                .values()
+                .rev()
+                .map(|c| (c.cycle_id, Some(c.clone())))
+                .collect())
+        }
         async fn insert_order(&self, _order: &Order) -> Result<i32> {
             Ok(self.next_id())
         }
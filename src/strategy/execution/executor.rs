@@ -1,15 +1,20 @@
+use super::fillability::FillabilityTracker;
 use super::idempotency::{IdempotencyManager, IdempotencyRecord, IdempotencyResult};
-use crate::adapters::{FeishuNotifier, PolymarketClient};
-use crate::config::ExecutionConfig;
-use crate::domain::{OrderRequest, OrderStatus, Side};
+use crate::adapters::{FeishuNotifier, OrderResponse, PolymarketClient};
+use crate::config::{ChaseConfig, ExecutionConfig};
+use crate::domain::{
+    ExecutionPreference, OrderRequest, OrderSide, OrderStatus, OrderType, Side, TimeInForce,
+};
 use crate::error::{OrderError, Result};
 use crate::exchange::ExchangeClient;
+use crate::services::{telemetry, FillDelta, FillLedger, FillSource, OrderMonitor, TrackedOrder};
+use chrono::{DateTime, Utc};
 use rust_decimal::prelude::ToPrimitive;
 use rust_decimal::Decimal;
-use std::sync::Arc;
+use std::sync::{Arc, Mutex};
 use std::time::Duration;
 use tokio::time::{sleep, timeout, Instant};
-use tracing::{debug, error, info, warn};
+use tracing::{debug, error, info, instrument, warn};
 
 /// Order executor for managing order lifecycle
 pub struct OrderExecutor {
@@ -17,6 +22,10 @@ pub struct OrderExecutor {
     config: ExecutionConfig,
     feishu: Option<Arc<FeishuNotifier>>,
     idempotency: Option<Arc<IdempotencyManager>>,
+    fillability: Option<Arc<FillabilityTracker>>,
+    fill_ledger: Option<Arc<FillLedger>>,
+    order_monitor: Option<Arc<OrderMonitor>>,
+    stats: Mutex<ExecutionStats>,
 }
 
 /// Execution result with fill details
@@ -27,6 +36,162 @@ pub struct ExecutionResult {
     pub filled_shares: u64,
     pub avg_fill_price: Option<Decimal>,
     pub elapsed_ms: u64,
+    /// Re-price steps taken by the chase policy, in order
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub chase_steps: Vec<ChaseStep>,
+}
+
+/// Audit record of one chase re-price: the resting order was cancelled and
+/// the remainder re-submitted at `to_price`
+#[derive(Debug, Clone, serde::Serialize, serde::Deserialize)]
+pub struct ChaseStep {
+    pub step: u32,
+    pub replaced_order_id: String,
+    /// Replacement order id; `None` when the re-submit failed
+    pub order_id: Option<String>,
+    pub from_price: Decimal,
+    pub to_price: Decimal,
+    pub best_bid: Option<Decimal>,
+    pub best_ask: Option<Decimal>,
+    /// Shares filled across all replaced orders so far
+    pub filled_shares: u64,
+    pub remaining_shares: u64,
+    pub at: DateTime<Utc>,
+}
+
+/// Which side of the spread an execution took
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+enum Liquidity {
+    Maker,
+    Taker,
+}
+
+/// Maker/taker split of live executions, to quantify fee savings
+#[derive(Debug, Clone, Default, serde::Serialize)]
+pub struct ExecutionStats {
+    pub maker_orders: u64,
+    pub taker_orders: u64,
+    /// Maker-first orders whose remainder was sent as a taker order
+    pub maker_fallbacks: u64,
+    pub maker_filled_shares: u64,
+    pub taker_filled_shares: u64,
+    pub maker_notional: Decimal,
+    pub taker_notional: Decimal,
+}
+
+impl ExecutionStats {
+    /// Share of filled shares executed as maker
+    pub fn maker_ratio(&self) -> Option<f64> {
+        let total = self.maker_filled_shares + self.taker_filled_shares;
+        (total > 0).then(|| self.maker_filled_shares as f64 / total as f64)
+    }
+
+    /// Taker fees avoided by the maker fills at `taker_fee_rate`
+    pub fn fee_savings(&self, taker_fee_rate: Decimal) -> Decimal {
+        self.maker_notional * taker_fee_rate
+    }
+
+    fn record(&mut self, liquidity: Liquidity, filled_shares: u64, price: Option<Decimal>) {
+        let notional = price.unwrap_or_default() * Decimal::from(filled_shares);
+        match liquidity {
+            Liquidity::Maker => {
+                self.maker_orders += 1;
+                self.maker_filled_shares += filled_shares;
+                self.maker_notional += notional;
+            }
+            Liquidity::Taker => {
+                self.taker_orders += 1;
+                self.taker_filled_shares += filled_shares;
+                self.taker_notional += notional;
+            }
+        }
+    }
+}
+
+/// Post-only price for the maker leg of a maker-first order, or `None` when
+/// the book has nothing to rest against.
+///
+/// A buy improves the best bid by one tick but stays below the best ask and
+/// never above its limit. Sells mirror this.
+pub fn maker_price(
+    tick: Decimal,
+    order_side: OrderSide,
+    limit_price: Decimal,
+    best_bid: Option<Decimal>,
+    best_ask: Option<Decimal>,
+) -> Option<Decimal> {
+    if tick <= Decimal::ZERO {
+        return None;
+    }
+    let price = match order_side {
+        OrderSide::Buy => {
+            let mut price = limit_price.min(best_ask? - tick);
+            if let Some(bid) = best_bid {
+                price = price.min(bid + tick);
+            }
+            price
+        }
+        OrderSide::Sell => {
+            let mut price = limit_price.max(best_bid? + tick);
+            if let Some(ask) = best_ask {
+                price = price.max(ask - tick);
+            }
+            price
+        }
+    };
+    (Decimal::new(1, 2)..=Decimal::new(99, 2))
+        .contains(&price)
+        .then_some(price)
+}
+
+/// Next chase price for a resting limit order, or `None` when the order
+/// cannot move closer to the market within the slippage budget.
+///
+/// A buy steps one tick up, or jumps one tick ahead of the best bid when it
+/// is queued behind it, but never crosses past the best ask. Sells mirror
+/// this. Prices stay within `max_slippage` of the original limit and inside
+/// the 0.01..=0.99 binary price range.
+pub fn next_chase_price(
+    config: &ChaseConfig,
+    order_side: OrderSide,
+    original_price: Decimal,
+    current_price: Decimal,
+    best_bid: Option<Decimal>,
+    best_ask: Option<Decimal>,
+) -> Option<Decimal> {
+    let tick = config.tick_size;
+    if tick <= Decimal::ZERO {
+        return None;
+    }
+
+    match order_side {
+        OrderSide::Buy => {
+            let mut next = current_price + tick;
+            if let Some(bid) = best_bid {
+                next = next.max(bid + tick);
+            }
+            if let Some(ask) = best_ask {
+                next = next.min(ask);
+            }
+            let next = next
+                .min(original_price + config.max_slippage)
+                .min(Decimal::new(99, 2));
+            (next > current_price).then_some(next)
+        }
+        OrderSide::Sell => {
+            let mut next = current_price - tick;
+            if let Some(ask) = best_ask {
+                next = next.min(ask - tick);
+            }
+            if let Some(bid) = best_bid {
+                next = next.max(bid);
+            }
+            let next = next
+                .max(original_price - config.max_slippage)
+                .max(Decimal::new(1, 2));
+            (next < current_price).then_some(next)
+        }
+    }
 }
 
 impl OrderExecutor {
@@ -42,6 +207,10 @@ impl OrderExecutor {
             config,
             feishu: FeishuNotifier::from_env(),
             idempotency: None,
+            fillability: None,
+            fill_ledger: None,
+            order_monitor: None,
+            stats: Mutex::new(ExecutionStats::default()),
         }
     }
 
@@ -57,13 +226,148 @@ impl OrderExecutor {
         self
     }
 
+    /// Record resolved passive orders for live fillability tracking
+    pub fn with_fillability(mut self, tracker: Arc<FillabilityTracker>) -> Self {
+        self.fillability = Some(tracker);
+        self
+    }
+
+    /// Count the trades of every order read exactly once: fills are accounted
+    /// from the ledger's deltas instead of the exchange's cumulative matched
+    /// size. Share the ledger with the [`OrderMonitor`] so overlapping reports
+    /// are not double-counted.
+    pub fn with_fill_ledger(mut self, ledger: Arc<FillLedger>) -> Self {
+        self.fill_ledger = Some(ledger);
+        self
+    }
+
+    /// Hand orders still resting after execution to the order monitor
+    pub fn with_order_monitor(mut self, monitor: Arc<OrderMonitor>) -> Self {
+        self.order_monitor = Some(monitor);
+        self
+    }
+
+    /// Tracker fed with resolved passive orders, if any
+    pub fn fillability(&self) -> Option<Arc<FillabilityTracker>> {
+        self.fillability.clone()
+    }
+
+    /// Whether repeated submits of one idempotency key are deduplicated
+    pub fn has_idempotency(&self) -> bool {
+        self.idempotency.is_some()
+    }
+
     /// Check if in dry run mode
     pub fn is_dry_run(&self) -> bool {
         self.client.is_dry_run()
     }
 
+    /// Configured execution preference for a strategy
+    pub fn preference_for(&self, strategy: &str) -> ExecutionPreference {
+        self.config.preference.for_strategy(strategy)
+    }
+
+    /// Maker/taker split of live executions so far
+    pub fn execution_stats(&self) -> ExecutionStats {
+        self.stats.lock().map(|s| s.clone()).unwrap_or_default()
+    }
+
+    fn record_execution(&self, liquidity: Liquidity, result: &ExecutionResult) {
+        if let Ok(mut stats) = self.stats.lock() {
+            stats.record(liquidity, result.filled_shares, result.avg_fill_price);
+        }
+    }
+
+    /// Feed a passive order's terminal outcome to the fillability tracker.
+    /// Rejected, failed and still-resting orders are not outcomes.
+    fn record_passive(&self, posted: &OrderRequest, result: &ExecutionResult) {
+        let Some(tracker) = &self.fillability else {
+            return;
+        };
+        if matches!(
+            result.status,
+            OrderStatus::Filled | OrderStatus::Cancelled | OrderStatus::Expired
+        ) {
+            tracker.record(&posted.token_id, posted.shares, result.filled_shares);
+        }
+    }
+
+    /// Read an order, adding what the fill ledger newly counts from its
+    /// trades to `counted`
+    async fn read_order(&self, order_id: &str, counted: &mut FillDelta) -> Result<OrderResponse> {
+        let order = self.client.get_order(order_id).await?;
+        if let (Some(ledger), Some(trades)) = (&self.fill_ledger, &order.associate_trades) {
+            let ingest = ledger
+                .ingest_order(order_id, trades, FillSource::Poll)
+                .await;
+            if !ingest.delta.is_zero() {
+                debug!(order_id, ?ingest, "fills ingested");
+            }
+            *counted += ingest.delta;
+        }
+        Ok(order)
+    }
+
+    /// Filled shares and average price of `order`: the ledger-counted fills
+    /// when its trades went through the ledger, else the exchange's matched size
+    fn order_fill(&self, order: &OrderResponse, counted: &FillDelta) -> (u64, Option<Decimal>) {
+        let has_trades = order
+            .associate_trades
+            .as_ref()
+            .is_some_and(|trades| !trades.is_empty());
+        if self.fill_ledger.is_none() || !has_trades {
+            return self.client.calculate_fill(order);
+        }
+        (
+            counted.shares.floor().to_u64().unwrap_or(0),
+            counted.avg_price(),
+        )
+    }
+
+    /// Let the order monitor follow an order that is still on the book
+    async fn track_resting(&self, request: &OrderRequest, result: &ExecutionResult) {
+        let Some(monitor) = &self.order_monitor else {
+            return;
+        };
+        if !matches!(
+            result.status,
+            OrderStatus::Submitted | OrderStatus::PartiallyFilled
+        ) || self.is_dry_run()
+        {
+            return;
+        }
+        let now = Utc::now();
+        monitor
+            .track_order(TrackedOrder {
+                client_order_id: request.client_order_id.clone(),
+                exchange_order_id: Some(result.order_id.clone()),
+                token_id: request.token_id.clone(),
+                side: request.order_side.to_string(),
+                shares: request.shares,
+                limit_price: request.limit_price,
+                status: result.status.clone(),
+                submitted_at: now,
+                last_checked: now,
+                check_count: 0,
+            })
+            .await;
+    }
+
     /// Execute an order with retry logic and idempotency protection
+    #[instrument(
+        name = "order",
+        skip_all,
+        fields(
+            trade_id = %telemetry::trade_id(&request.client_order_id),
+            token_id = %request.token_id,
+            order_side = %request.order_side,
+            shares = request.shares,
+            limit_price = %request.limit_price,
+        )
+    )]
     pub async fn execute(&self, request: &OrderRequest) -> Result<ExecutionResult> {
+        request.validate()?;
+
         // Check for duplicate order if idempotency is enabled
         if let Some(ref idempotency) = self.idempotency {
             let idem_key = IdempotencyManager::generate_key(request);
@@ -190,6 +494,7 @@ impl OrderExecutor {
                 filled_shares: 0,
                 avg_fill_price: Some(request.limit_price),
                 elapsed_ms: 0,
+                chase_steps: Vec::new(),
             });
         }
 
@@ -248,6 +553,7 @@ impl OrderExecutor {
                             .await;
                     }
 
+                    self.track_resting(request, &result).await;
                     return Ok(result);
                 }
                 Err(e) => {
@@ -269,7 +575,218 @@ impl OrderExecutor {
     /// Single execution attempt
     async fn try_execute(&self, request: &OrderRequest) -> Result<ExecutionResult> {
         let start = Instant::now();
+        if self.client.is_dry_run() {
+            return self.submit_and_confirm(request, start).await;
+        }
+
+        let preference = request
+            .execution_preference
+            .unwrap_or(self.config.preference.default);
+        if preference == ExecutionPreference::MakerFirst
+            && request.order_type == OrderType::Limit
+            && !request.post_only
+        {
+            return self.execute_maker_first(request, start).await;
+        }
+
+        let result = self.submit_and_confirm(request, start).await?;
+        let liquidity = if request.post_only {
+            self.record_passive(request, &result);
+            Liquidity::Maker
+        } else {
+            Liquidity::Taker
+        };
+        self.record_execution(liquidity, &result);
+        Ok(result)
+    }
+
+    /// Rest a post-only order inside the spread for up to `maker_max_wait_ms`,
+    /// then send the unfilled remainder as the original request.
+    ///
+    /// Once the maker order is live this never fails unless nothing filled,
+    /// so the retry loop cannot double-fill.
+    async fn execute_maker_first(
+        &self,
+        request: &OrderRequest,
+        start: Instant,
+    ) -> Result<ExecutionResult> {
+        let config = &self.config.preference;
+        let (best_bid, best_ask) = match self.client.get_best_prices(&request.token_id).await {
+            Ok(prices) => prices,
+            Err(e) => {
+                warn!(error = %e, "Maker-first quote fetch failed; sending as taker");
+                (None, None)
+            }
+        };
+        let Some(price) = maker_price(
+            config.tick_size,
+            request.order_side,
+            request.limit_price,
+            best_bid,
+            best_ask,
+        ) else {
+            return self.taker_fallback(request, None, start).await;
+        };
+
+        let mut maker = request.clone();
+        maker.client_order_id = format!("{}-maker", request.client_order_id);
+        maker.idempotency_key = request
+            .idempotency_key
+            .as_ref()
+            .map(|key| format!("{}-maker", key));
+        maker.limit_price = price;
+        maker.time_in_force = TimeInForce::GTC;
+        maker.expires_at = None;
+        maker.post_only = true;
+
+        let order_id = match self.client.submit_order_gateway(&maker).await {
+            Ok(resp) => resp.id,
+            Err(e) => {
+                // Typically a post-only reject because the book moved through our price
+                debug!(error = %e, %price, "Maker order rejected; sending as taker");
+                return self.taker_fallback(request, None, start).await;
+            }
+        };
+        debug!(order_id, %price, "Maker order resting");
+
+        let poll_interval = Duration::from_millis(self.config.poll_interval_ms.max(100));
+        let wait = Duration::from_millis(config.maker_max_wait_ms);
+        let mut counted = FillDelta::default();
+        let maker_result = match timeout(
+            wait,
+            self.wait_for_fill(&order_id, poll_interval, &mut counted),
+        )
+        .await
+        {
+            Ok(Ok(result)) => result,
+            Ok(Err(e)) => {
+                warn!(order_id, error = %e, "Maker order polling failed; leaving order resting");
+                return Ok(Self::resting(order_id, price, start));
+            }
+            Err(_) => {
+                if let Err(e) = self.client.cancel_order(&order_id).await {
+                    warn!(order_id, error = %e, "Maker cancel failed; leaving order resting");
+                    return Ok(Self::resting(order_id, price, start));
+                }
+                match self.read_order(&order_id, &mut counted).await {
+                    Ok(order) => {
+                        let (filled, avg) = self.order_fill(&order, &counted);
+                        ExecutionResult {
+                            order_id: order_id.clone(),
+                            status: OrderStatus::Cancelled,
+                            filled_shares: filled,
+                            avg_fill_price: avg.or(Some(price)),
+                            elapsed_ms: 0,
+                            chase_steps: Vec::new(),
+                        }
+                    }
+                    Err(e) => {
+                        // Fills on the cancelled order are unknown, so a taker
+                        // remainder could overfill
+                        warn!(order_id, error = %e, "Maker order unreadable after cancel");
+                        return Ok(ExecutionResult {
+                            order_id,
+                            status: OrderStatus::Cancelled,
+                            filled_shares: 0,
+                            avg_fill_price: Some(price),
+                            elapsed_ms: start.elapsed().as_millis() as u64,
+                            chase_steps: Vec::new(),
+                        });
+                    }
+                }
+            }
+        };
+
+        self.record_execution(Liquidity::Maker, &maker_result);
+        self.record_passive(&maker, &maker_result);
+        if maker_result.filled_shares >= request.shares {
+            return Ok(ExecutionResult {
+                status: OrderStatus::Filled,
+                elapsed_ms: start.elapsed().as_millis() as u64,
+                ..maker_result
+            });
+        }
+        self.taker_fallback(request, Some(maker_result), start)
+            .await
+    }
+
+    /// Send what the maker leg left unfilled as a taker order and merge the fills
+    async fn taker_fallback(
+        &self,
+        request: &OrderRequest,
+        maker: Option<ExecutionResult>,
+        start: Instant,
+    ) -> Result<ExecutionResult> {
+        let maker_filled = maker.as_ref().map_or(0, |m| m.filled_shares);
+        let mut taker = request.clone();
+        if maker.is_some() {
+            taker.client_order_id = format!("{}-taker", request.client_order_id);
+            taker.idempotency_key = request
+                .idempotency_key
+                .as_ref()
+                .map(|key| format!("{}-taker", key));
+            taker.shares = request.shares - maker_filled;
+        }
+
+        let result = match self.submit_and_confirm(&taker, start).await {
+            Ok(result) => result,
+            Err(e) => match maker {
+                Some(maker) if maker_filled > 0 => {
+                    warn!(error = %e, "Taker remainder failed after partial maker fill");
+                    return Ok(ExecutionResult {
+                        status: OrderStatus::PartiallyFilled,
+                        elapsed_ms: start.elapsed().as_millis() as u64,
+                        ..maker
+                    });
+                }
+                _ => return Err(e),
+            },
+        };
+        self.record_execution(Liquidity::Taker, &result);
+        if let Ok(mut stats) = self.stats.lock() {
+            stats.maker_fallbacks += 1;
+        }
+
+        let Some(maker) = maker.filter(|m| m.filled_shares > 0) else {
+            return Ok(result);
+        };
+        let filled = maker_filled + result.filled_shares;
+        let notional = maker.avg_fill_price.unwrap_or(request.limit_price)
+            * Decimal::from(maker_filled)
+            + result.avg_fill_price.unwrap_or(request.limit_price)
+                * Decimal::from(result.filled_shares);
+        let status = if filled >= request.shares {
+            OrderStatus::Filled
+        } else if result.status == OrderStatus::Submitted {
+            OrderStatus::Submitted
+        } else {
+            OrderStatus::PartiallyFilled
+        };
+        Ok(ExecutionResult {
+            status,
+            filled_shares: filled,
+            avg_fill_price: Some(notional / Decimal::from(filled)),
+            ..result
+        })
+    }
 
+    fn resting(order_id: String, price: Decimal, start: Instant) -> ExecutionResult {
+        ExecutionResult {
+            order_id,
+            status: OrderStatus::Submitted,
+            filled_shares: 0,
+            avg_fill_price: Some(price),
+            elapsed_ms: start.elapsed().as_millis() as u64,
+            chase_steps: Vec::new(),
+        }
+    }
+
+    /// Submit `request` as-is, then chase or confirm it per config
+    async fn submit_and_confirm(
+        &self,
+        request: &OrderRequest,
+        start: Instant,
+    ) -> Result<ExecutionResult> {
         // Submit order
         let order_resp = self.client.submit_order_gateway(request).await?;
         let order_id = order_resp.id.clone();
@@ -284,18 +801,31 @@ impl OrderExecutor {
                 filled_shares: request.shares,
                 avg_fill_price: Some(request.limit_price),
                 elapsed_ms: start.elapsed().as_millis() as u64,
+                chase_steps: Vec::new(),
             });
         }
 
+        // Resting limit orders are chased instead of confirmed. Like confirmation, chasing
+        // never fails after the first submit. Post-only orders keep their price: chasing
+        // toward the touch would turn them into takers.
+        if self.config.chase.enabled
+            && request.order_type == OrderType::Limit
+            && request.time_in_force == TimeInForce::GTC
+            && !request.post_only
+        {
+            return Ok(self.chase(request, order_id, start).await);
+        }
+
         // Optional best-effort confirmation: never fail the execution after a successful submit,
         // otherwise retry logic would resubmit and potentially create duplicates.
         if self.config.confirm_fills {
             let poll_interval = Duration::from_millis(self.config.poll_interval_ms.max(100));
             let confirm_timeout = Duration::from_millis(self.config.confirm_fill_timeout_ms);
 
+            let mut counted = FillDelta::default();
             match timeout(
                 confirm_timeout,
-                self.wait_for_fill(&order_id, poll_interval),
+                self.wait_for_fill(&order_id, poll_interval, &mut counted),
             )
             .await
             {
@@ -324,9 +854,9 @@ impl OrderExecutor {
             match request.time_in_force {
                 crate::domain::TimeInForce::IOC | crate::domain::TimeInForce::FOK => {
                     let _ = self.client.cancel_order(&order_id).await;
-                    if let Ok(order) = self.client.get_order(&order_id).await {
+                    if let Ok(order) = self.read_order(&order_id, &mut counted).await {
                         let status = self.client.infer_order_status(&order);
-                        let (filled_u64, price) = self.client.calculate_fill(&order);
+                        let (filled_u64, price) = self.order_fill(&order, &counted);
 
                         return Ok(ExecutionResult {
                             order_id,
@@ -334,10 +864,11 @@ impl OrderExecutor {
                             filled_shares: filled_u64,
                             avg_fill_price: price,
                             elapsed_ms: start.elapsed().as_millis() as u64,
+                            chase_steps: Vec::new(),
                         });
                     }
                 }
-                crate::domain::TimeInForce::GTC => {}
+                crate::domain::TimeInForce::GTC | crate::domain::TimeInForce::GTD => {}
             }
         }
 
@@ -353,19 +884,221 @@ impl OrderExecutor {
             filled_shares: 0,               // Will be determined at market resolution
             avg_fill_price: Some(request.limit_price),
             elapsed_ms: start.elapsed().as_millis() as u64,
+            chase_steps: Vec::new(),
         })
     }
 
+    /// Re-price a resting order toward the market until it fills, the step or
+    /// slippage budget runs out, or the book offers no better price.
+    ///
+    /// Each step cancels the live order, books whatever it filled, and
+    /// re-submits the remainder at [`next_chase_price`]. The last order is
+    /// left resting on the book.
+    async fn chase(
+        &self,
+        request: &OrderRequest,
+        mut order_id: String,
+        start: Instant,
+    ) -> ExecutionResult {
+        let config = &self.config.chase;
+        let poll_interval = Duration::from_millis(self.config.poll_interval_ms.max(100));
+        let rest = Duration::from_millis(
+            config
+                .reprice_after_ms
+                .max(poll_interval.as_millis() as u64),
+        );
+
+        let mut price = request.limit_price;
+        // Fills on orders that were already cancelled and replaced
+        let mut filled = 0u64;
+        let mut notional = Decimal::ZERO;
+        let mut steps: Vec<ChaseStep> = Vec::new();
+        // Ledger-counted fills of the live order
+        let mut counted = FillDelta::default();
+
+        let finish = |order_id: String,
+                      status: OrderStatus,
+                      filled: u64,
+                      notional: Decimal,
+                      price: Decimal,
+                      steps: Vec<ChaseStep>| {
+            let avg_fill_price = if filled > 0 {
+                Some(notional / Decimal::from(filled))
+            } else {
+                Some(price)
+            };
+            ExecutionResult {
+                order_id,
+                status,
+                filled_shares: filled,
+                avg_fill_price,
+                elapsed_ms: start.elapsed().as_millis() as u64,
+                chase_steps: steps,
+            }
+        };
+        let partial = |filled: u64, otherwise: OrderStatus| {
+            if filled > 0 {
+                OrderStatus::PartiallyFilled
+            } else {
+                otherwise
+            }
+        };
+
+        loop {
+            match timeout(
+                rest,
+                self.wait_for_fill(&order_id, poll_interval, &mut counted),
+            )
+            .await
+            {
+                Ok(Ok(result)) => {
+                    // Terminal on the exchange side (filled, or cancelled externally)
+                    filled += result.filled_shares;
+                    notional += result.avg_fill_price.unwrap_or(price)
+                        * Decimal::from(result.filled_shares);
+                    let status = if filled >= request.shares {
+                        OrderStatus::Filled
+                    } else {
+                        partial(filled, result.status)
+                    };
+                    return finish(order_id, status, filled, notional, price, steps);
+                }
+                Ok(Err(e)) => {
+                    warn!(order_id, error = %e, "Chase polling failed; leaving order resting");
+                    break;
+                }
+                Err(_) => {}
+            }
+
+            let step = steps.len() as u32 + 1;
+            if step > config.max_steps {
+                debug!(
+                    order_id,
+                    "Chase step budget exhausted; leaving order resting"
+                );
+                break;
+            }
+
+            let (best_bid, best_ask) = match self.client.get_best_prices(&request.token_id).await {
+                Ok(prices) => prices,
+                Err(e) => {
+                    warn!(order_id, error = %e, "Chase quote fetch failed; leaving order resting");
+                    break;
+                }
+            };
+            let Some(next_price) = next_chase_price(
+                config,
+                request.order_side,
+                request.limit_price,
+                price,
+                best_bid,
+                best_ask,
+            ) else {
+                debug!(order_id, %price, "Chase has no better price; leaving order resting");
+                break;
+            };
+
+            if let Err(e) = self.client.cancel_order(&order_id).await {
+                warn!(order_id, error = %e, "Chase cancel failed; leaving order resting");
+                break;
+            }
+            let order = match self.read_order(&order_id, &mut counted).await {
+                Ok(order) => order,
+                Err(e) => {
+                    // Fills on the cancelled order are unknown: re-submitting could overfill
+                    warn!(order_id, error = %e, "Chase could not read cancelled order; stopping");
+                    let status = partial(filled, OrderStatus::Cancelled);
+                    return finish(order_id, status, filled, notional, price, steps);
+                }
+            };
+            let (order_filled, order_price) = self.order_fill(&order, &counted);
+            filled += order_filled;
+            notional += order_price.unwrap_or(price) * Decimal::from(order_filled);
+
+            let remaining = request.shares.saturating_sub(filled);
+            if remaining == 0 {
+                return finish(
+                    order_id,
+                    OrderStatus::Filled,
+                    filled,
+                    notional,
+                    price,
+                    steps,
+                );
+            }
+
+            let mut next = request.clone();
+            next.client_order_id = format!("{}-chase{}", request.client_order_id, step);
+            next.idempotency_key = request
+                .idempotency_key
+                .as_ref()
+                .map(|key| format!("{}-chase{}", key, step));
+            next.shares = remaining;
+            next.limit_price = next_price;
+
+            let submitted = self.client.submit_order_gateway(&next).await;
+            let mut record = ChaseStep {
+                step,
+                replaced_order_id: order_id.clone(),
+                order_id: None,
+                from_price: price,
+                to_price: next_price,
+                best_bid,
+                best_ask,
+                filled_shares: filled,
+                remaining_shares: remaining,
+                at: Utc::now(),
+            };
+            match submitted {
+                Ok(resp) => {
+                    info!(
+                        step,
+                        replaced_order_id = %order_id,
+                        order_id = %resp.id,
+                        from_price = %price,
+                        to_price = %next_price,
+                        remaining,
+                        "Chase re-priced order"
+                    );
+                    record.order_id = Some(resp.id.clone());
+                    steps.push(record);
+                    order_id = resp.id;
+                    price = next_price;
+                    counted = FillDelta::default();
+                }
+                Err(e) => {
+                    warn!(
+                        step,
+                        replaced_order_id = %order_id,
+                        to_price = %next_price,
+                        error = %e,
+                        "Chase re-submit failed; remainder left unfilled"
+                    );
+                    steps.push(record);
+                    let status = partial(filled, OrderStatus::Cancelled);
+                    return finish(order_id, status, filled, notional, price, steps);
+                }
+            }
+        }
+
+        let status = partial(filled, OrderStatus::Submitted);
+        finish(order_id, status, filled, notional, price, steps)
+    }
+
     /// Poll for order fill
+    ///
+    /// Ledger-counted fills accumulate in `counted`, which outlives a
+    /// timed-out poll so the caller's final read still sees them.
     async fn wait_for_fill(
         &self,
         order_id: &str,
         poll_interval: Duration,
+        counted: &mut FillDelta,
     ) -> Result<ExecutionResult> {
         loop {
-            let order = self.client.get_order(order_id).await?;
+            let order = self.read_order(order_id, counted).await?;
             let status = self.client.infer_order_status(&order);
-            let (filled_u64, price) = self.client.calculate_fill(&order);
+            let (filled_u64, price) = self.order_fill(&order, counted);
 
             match status {
                 OrderStatus::Filled => {
@@ -375,6 +1108,7 @@ impl OrderExecutor {
                         filled_shares: filled_u64,
                         avg_fill_price: price,
                         elapsed_ms: 0, // Will be updated by caller
+                        chase_steps: Vec::new(),
                     });
                 }
                 OrderStatus::Cancelled | OrderStatus::Rejected | OrderStatus::Expired => {
@@ -384,6 +1118,7 @@ impl OrderExecutor {
                         filled_shares: filled_u64,
                         avg_fill_price: price,
                         elapsed_ms: 0,
+                        chase_steps: Vec::new(),
                     });
                 }
                 _ => {
@@ -513,8 +1248,90 @@ impl ExecutionParams {
 #[cfg(test)]
 mod tests {
     use super::*;
+    use crate::exchange::ExchangeKind;
+    use async_trait::async_trait;
     use rust_decimal_macros::dec;
 
+    /// Live exchange whose orders rest and are cancelled after 40 shares fill
+    struct PartialFillExchange;
+
+    #[async_trait]
+    impl ExchangeClient for PartialFillExchange {
+        fn kind(&self) -> ExchangeKind {
+            ExchangeKind::Polymarket
+        }
+
+        fn is_dry_run(&self) -> bool {
+            false
+        }
+
+        async fn submit_order_gateway(&self, _request: &OrderRequest) -> Result<OrderResponse> {
+            self.get_order("ex-1").await
+        }
+
+        async fn get_order(&self, order_id: &str) -> Result<OrderResponse> {
+            Ok(OrderResponse {
+                id: order_id.to_string(),
+                status: "canceled".to_string(),
+                owner: None,
+                market: None,
+                asset_id: None,
+                side: None,
+                original_size: Some("100".to_string()),
+                size_matched: Some("40".to_string()),
+                price: Some("0.45".to_string()),
+                associate_trades: None,
+                created_at: None,
+                expiration: None,
+                order_type: None,
+            })
+        }
+
+        async fn cancel_order(&self, _order_id: &str) -> Result<bool> {
+            Ok(true)
+        }
+
+        async fn get_best_prices(
+            &self,
+            _token_id: &str,
+        ) -> Result<(Option<Decimal>, Option<Decimal>)> {
+            Ok((None, None))
+        }
+
+        fn infer_order_status(&self, _order: &OrderResponse) -> OrderStatus {
+            OrderStatus::Cancelled
+        }
+
+        fn calculate_fill(&self, _order: &OrderResponse) -> (u64, Option<Decimal>) {
+            (40, Some(dec!(0.45)))
+        }
+    }
+
+    #[tokio::test]
+    async fn test_passive_outcomes_feed_fillability() {
+        let tracker = Arc::new(FillabilityTracker::default());
+        let config = ExecutionConfig {
+            confirm_fills: true,
+            poll_interval_ms: 100,
+            ..ExecutionConfig::default()
+        };
+        let executor = OrderExecutor::new_with_exchange(Arc::new(PartialFillExchange), config)
+            .with_fillability(tracker.clone());
+
+        let passive =
+            OrderRequest::buy_limit("tok".to_string(), Side::Up, 100, dec!(0.45)).post_only();
+        let result = executor.execute(&passive).await.unwrap();
+        assert_eq!(result.status, OrderStatus::Cancelled);
+        let stats = tracker.stats("tok", 3600);
+        assert_eq!((stats.orders, stats.posted_shares), (1, 100));
+        assert_eq!(stats.fill_rate(), Some(0.4));
+
+        // Taker orders are not passive outcomes
+        let taker = OrderRequest::buy_limit("tok".to_string(), Side::Up, 100, dec!(0.45));
+        executor.execute(&taker).await.unwrap();
+        assert_eq!(tracker.stats("tok", 3600).orders, 1);
+    }
+
     #[test]
     fn test_execution_params() {
         let params = ExecutionParams::new(100, dec!(0.50)).with_slippage(dec!(0.02));
@@ -522,4 +1339,72 @@ mod tests {
         // 0.50 * 1.02 = 0.51
         assert_eq!(params.effective_max_price(), dec!(0.51));
     }
+
+    #[test]
+    fn test_next_chase_price_steps_toward_market_within_budget() {
+        let config = ChaseConfig {
+            enabled: true,
+            tick_size: dec!(0.01),
+            max_slippage: dec!(0.03),
+            ..ChaseConfig::default()
+        };
+        let buy = |current, bid, ask| {
+            next_chase_price(&config, OrderSide::Buy, dec!(0.50), current, bid, ask)
+        };
+
+        // Plain tick step, then jump ahead of a bid that queued in front of us
+        assert_eq!(buy(dec!(0.50), None, None), Some(dec!(0.51)));
+        assert_eq!(
+            buy(dec!(0.50), Some(dec!(0.51)), Some(dec!(0.60))),
+            Some(dec!(0.52))
+        );
+        // Never past the ask, never past the slippage budget
+        assert_eq!(
+            buy(dec!(0.50), Some(dec!(0.50)), Some(dec!(0.505))),
+            Some(dec!(0.505))
+        );
+        assert_eq!(buy(dec!(0.53), Some(dec!(0.55)), Some(dec!(0.60))), None);
+
+        let sell = next_chase_price(
+            &config,
+            OrderSide::Sell,
+            dec!(0.50),
+            dec!(0.50),
+            Some(dec!(0.40)),
+            Some(dec!(0.48)),
+        );
+        assert_eq!(sell, Some(dec!(0.47)));
+    }
+
+    #[test]
+    fn test_maker_price_rests_inside_the_spread() {
+        let tick = dec!(0.01);
+        let buy = |limit, bid, ask| maker_price(tick, OrderSide::Buy, limit, bid, ask);
+
+        // Improve the bid by a tick, capped by the limit
+        assert_eq!(
+            buy(dec!(0.55), Some(dec!(0.48)), Some(dec!(0.52))),
+            Some(dec!(0.49))
+        );
+        assert_eq!(
+            buy(dec!(0.45), Some(dec!(0.48)), Some(dec!(0.52))),
+            Some(dec!(0.45))
+        );
+        // One-tick spread: join below the ask instead of crossing it
+        assert_eq!(
+            buy(dec!(0.55), Some(dec!(0.50)), Some(dec!(0.51))),
+            Some(dec!(0.50))
+        );
+        // No ask to rest against: taker only
+        assert_eq!(buy(dec!(0.55), Some(dec!(0.50)), None), None);
+
+        let sell = maker_price(
+            tick,
+            OrderSide::Sell,
+            dec!(0.40),
+            Some(dec!(0.48)),
+            Some(dec!(0.52)),
+        );
+        assert_eq!(sell, Some(dec!(0.51)));
+    }
 }
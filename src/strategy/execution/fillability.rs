@@ -0,0 +1,298 @@
+//! Live fillability of our own passive orders.
+//!
+//! Every post-only or maker-first order that reaches a terminal state is
+//! recorded as (posted shares, filled shares) against its token. The tracker
+//! answers share-weighted fill rates over rolling windows, mirrors them into
+//! [`Metrics`], and a [`FillabilityGate`] turns them into the live fill rate
+//! a strategy's `FillabilityValidator` checks before sizing up.
+
+use chrono::{DateTime, Duration, Utc};
+use serde::{Deserialize, Serialize};
+use std::collections::{HashMap, VecDeque};
+use std::sync::{Arc, Mutex};
+
+use crate::services::Metrics;
+
+/// Rolling windows tracked per token.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(default)]
+pub struct FillabilityConfig {
+    /// Window lengths in seconds; outcomes older than the longest are dropped
+    pub windows_secs: Vec<u64>,
+}
+
+impl Default for FillabilityConfig {
+    fn default() -> Self {
+        Self {
+            windows_secs: vec![900, 3600, 14_400],
+        }
+    }
+}
+
+impl FillabilityConfig {
+    fn retention_secs(&self) -> u64 {
+        self.windows_secs.iter().copied().max().unwrap_or(3600)
+    }
+}
+
+/// One resolved passive order
+#[derive(Debug, Clone, Copy)]
+struct PassiveOutcome {
+    at: DateTime<Utc>,
+    posted: u64,
+    filled: u64,
+}
+
+/// Passive order outcomes for one token over one window.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Serialize)]
+pub struct FillabilityStats {
+    pub window_secs: u64,
+    pub orders: u64,
+    /// Orders with any fill
+    pub filled_orders: u64,
+    pub posted_shares: u64,
+    pub filled_shares: u64,
+}
+
+impl FillabilityStats {
+    /// Filled over posted shares
+    pub fn fill_rate(&self) -> Option<f64> {
+        (self.posted_shares > 0).then(|| self.filled_shares as f64 / self.posted_shares as f64)
+    }
+}
+
+/// Per-token rolling record of how much of our resting size actually fills.
+#[derive(Debug, Default)]
+pub struct FillabilityTracker {
+    config: FillabilityConfig,
+    outcomes: Mutex<HashMap<String, VecDeque<PassiveOutcome>>>,
+    metrics: Option<Arc<Metrics>>,
+}
+
+impl FillabilityTracker {
+    pub fn new(config: FillabilityConfig) -> Self {
+        Self {
+            config,
+            outcomes: Mutex::new(HashMap::new()),
+            metrics: None,
+        }
+    }
+
+    /// Mirror fill rates into Prometheus
+    pub fn with_metrics(mut self, metrics: Arc<Metrics>) -> Self {
+        self.metrics = Some(metrics);
+        self
+    }
+
+    pub fn config(&self) -> &FillabilityConfig {
+        &self.config
+    }
+
+    /// Record a passive order that reached a terminal state
+    pub fn record(&self, token_id: &str, posted_shares: u64, filled_shares: u64) {
+        self.record_at(token_id, posted_shares, filled_shares, Utc::now());
+    }
+
+    pub fn record_at(
+        &self,
+        token_id: &str,
+        posted_shares: u64,
+        filled_shares: u64,
+        at: DateTime<Utc>,
+    ) {
+        if posted_shares == 0 {
+            return;
+        }
+        let filled = filled_shares.min(posted_shares);
+        let cutoff = at - Duration::seconds(self.config.retention_secs() as i64);
+
+        let (stats, expired) = {
+            let mut outcomes = match self.outcomes.lock() {
+                Ok(outcomes) => outcomes,
+                Err(poisoned) => poisoned.into_inner(),
+            };
+            outcomes
+                .entry(token_id.to_string())
+                .or_default()
+                .push_back(PassiveOutcome {
+                    at,
+                    posted: posted_shares,
+                    filled,
+                });
+
+            let mut expired = Vec::new();
+            outcomes.retain(|token, queue| {
+                while queue.front().is_some_and(|o| o.at < cutoff) {
+                    queue.pop_front();
+                }
+                if queue.is_empty() {
+                    expired.push(token.clone());
+                }
+                !queue.is_empty()
+            });
+
+            let stats: Vec<FillabilityStats> = outcomes
+                .get(token_id)
+                .map(|queue| {
+                    self.config
+                        .windows_secs
+                        .iter()
+                        .map(|window| window_stats(queue, *window, at))
+                        .collect()
+                })
+                .unwrap_or_default();
+            (stats, expired)
+        };
+
+        if let Some(metrics) = &self.metrics {
+            metrics.record_passive_outcome(posted_shares, filled);
+            for token in &expired {
+                metrics.clear_fillability(token);
+            }
+            for window in &stats {
+                if let Some(rate) = window.fill_rate() {
+                    metrics.set_fillability(token_id, window.window_secs, rate);
+                }
+            }
+        }
+    }
+
+    /// Outcomes for `token_id` over the last `window_secs`
+    pub fn stats(&self, token_id: &str, window_secs: u64) -> FillabilityStats {
+        self.stats_at(token_id, window_secs, Utc::now())
+    }
+
+    pub fn stats_at(
+        &self,
+        token_id: &str,
+        window_secs: u64,
+        now: DateTime<Utc>,
+    ) -> FillabilityStats {
+        let outcomes = match self.outcomes.lock() {
+            Ok(outcomes) => outcomes,
+            Err(poisoned) => poisoned.into_inner(),
+        };
+        outcomes
+            .get(token_id)
+            .map(|queue| window_stats(queue, window_secs, now))
+            .unwrap_or(FillabilityStats {
+                window_secs,
+                ..FillabilityStats::default()
+            })
+    }
+
+    /// Stats for every tracked token over `window_secs`, sorted by token
+    pub fn all_stats(&self, window_secs: u64) -> Vec<(String, FillabilityStats)> {
+        let now = Utc::now();
+        let outcomes = match self.outcomes.lock() {
+            Ok(outcomes) => outcomes,
+            Err(poisoned) => poisoned.into_inner(),
+        };
+        let mut all: Vec<(String, FillabilityStats)> = outcomes
+            .iter()
+            .map(|(token, queue)| (token.clone(), window_stats(queue, window_secs, now)))
+            .collect();
+        all.sort_by(|a, b| a.0.cmp(&b.0));
+        all
+    }
+}
+
+fn window_stats(
+    queue: &VecDeque<PassiveOutcome>,
+    window_secs: u64,
+    now: DateTime<Utc>,
+) -> FillabilityStats {
+    let since = now - Duration::seconds(window_secs as i64);
+    queue.iter().rev().take_while(|o| o.at >= since).fold(
+        FillabilityStats {
+            window_secs,
+            ..FillabilityStats::default()
+        },
+        |mut stats, o| {
+            stats.orders += 1;
+            stats.filled_orders += u64::from(o.filled > 0);
+            stats.posted_shares += o.posted;
+            stats.filled_shares += o.filled;
+            stats
+        },
+    )
+}
+
+/// Minimum live fillability a strategy requires before sizing up.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(default)]
+pub struct FillabilityGate {
+    /// Enforce the gate in the coordinator's risk gate
+    pub enabled: bool,
+    /// Size allowed before the token's fill rate is proven
+    pub base_shares: u64,
+    /// Window the fill rate is measured over
+    pub window_secs: u64,
+    /// Required filled / posted share ratio
+    pub min_fill_rate: f64,
+    /// Fewer resolved passive orders than this count as unproven
+    pub min_orders: u64,
+}
+
+impl Default for FillabilityGate {
+    fn default() -> Self {
+        Self {
+            enabled: false,
+            base_shares: 50,
+            window_secs: 3600,
+            min_fill_rate: 0.3,
+            min_orders: 10,
+        }
+    }
+}
+
+impl FillabilityGate {
+    /// Live fill rate for `token_id`, or `None` while there are too few samples
+    pub fn fill_rate(&self, tracker: &FillabilityTracker, token_id: &str) -> Option<f64> {
+        let stats = tracker.stats(token_id, self.window_secs);
+        if stats.orders < self.min_orders {
+            return None;
+        }
+        stats.fill_rate()
+    }
+
+    /// Whether `token_id` has proven enough live fillability to size up
+    pub fn allows_size_up(&self, tracker: &FillabilityTracker, token_id: &str) -> bool {
+        self.fill_rate(tracker, token_id)
+            .is_some_and(|rate| rate >= self.min_fill_rate)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_rolling_fill_rate_and_gate() {
+        let tracker = FillabilityTracker::new(FillabilityConfig {
+            windows_secs: vec![600, 3600],
+        });
+        let now = Utc::now();
+        let gate = FillabilityGate {
+            window_secs: 600,
+            min_fill_rate: 0.5,
+            min_orders: 2,
+            ..FillabilityGate::default()
+        };
+
+        // An old, fully filled order falls outside the short window
+        tracker.record_at("tok", 100, 100, now - Duration::seconds(1200));
+        tracker.record_at("tok", 100, 20, now - Duration::seconds(60));
+        tracker.record_at("tok", 100, 0, now - Duration::seconds(30));
+
+        let short = tracker.stats_at("tok", 600, now);
+        assert_eq!(short.orders, 2);
+        assert_eq!(short.filled_orders, 1);
+        assert_eq!(short.fill_rate(), Some(0.1));
+        let long = tracker.stats_at("tok", 3600, now);
+        assert_eq!(long.fill_rate(), Some(0.4));
+
+        assert!(!gate.allows_size_up(&tracker, "tok"));
+        assert_eq!(gate.fill_rate(&tracker, "other"), None);
+    }
+}
@@ -299,6 +299,9 @@ mod tests {
             limit_price: dec!(0.50),
             order_type: OrderType::Limit,
             time_in_force: TimeInForce::GTC,
+            expires_at: None,
+            post_only: false,
+            execution_preference: None,
         }
     }
 
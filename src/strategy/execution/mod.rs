@@ -1,16 +1,31 @@
 //! Order execution pipeline.
 //!
 //! Contains the strategy engine state machine, order executor with retry logic,
-//! fund management, idempotency protection, and the EngineStore trait for DI.
+//! fund management, live passive fillability tracking, idempotency protection,
+//! cycle checkpoints for crash recovery, Leg2 compensation sagas, bracket
+//! orders, and the EngineStore trait for DI.
 
+pub mod bracket;
+pub mod conditional;
+pub mod cycle_checkpoint;
 pub mod engine;
 pub mod engine_store;
 pub mod executor;
+pub mod fillability;
 pub mod fund_manager;
 pub mod idempotency;
+pub mod saga;
 
+pub use bracket::{Bracket, BracketManager, BracketState, BracketStore};
+pub use conditional::{
+    ConditionalExecution, ConditionalOrder, ConditionalOrderManager, ConditionalOrderState,
+    ConditionalOrderStore, PriceSource, TriggerCondition,
+};
+pub use cycle_checkpoint::{decide_resume, CycleCheckpoint, ResumeDecision};
 pub use engine::StrategyEngine;
 pub use engine_store::EngineStore;
 pub use executor::OrderExecutor;
+pub use fillability::{FillabilityConfig, FillabilityGate, FillabilityStats, FillabilityTracker};
 pub use fund_manager::{FundManager, FundStatus, PositionSizeResult};
 pub use idempotency::{IdempotencyManager, IdempotencyResult};
+pub use saga::{LegSaga, SagaAction, SagaStatus, StepOutcome};
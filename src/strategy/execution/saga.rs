@@ -0,0 +1,379 @@
+//! Saga-style compensation for a failed Leg2 hedge.
+//!
+//! Once Leg1 has filled, a failed or partial Leg2 leaves directional exposure.
+//! A [`LegSaga`] walks the configured [`CompensationStep`]s (cancel the stray
+//! order, resubmit at a widened price, market out of Leg1, alert) until the
+//! cycle is flat or escalated. The engine stores the saga in the cycle
+//! checkpoint after every step, so a restart resumes compensation at the step
+//! it stopped on instead of aborting blind.
+
+use chrono::{DateTime, Utc};
+use rust_decimal::Decimal;
+use serde::{Deserialize, Serialize};
+
+use crate::config::{CompensationConfig, CompensationStep};
+use crate::domain::Side;
+
+/// Where a compensation saga stands.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum SagaStatus {
+    /// Steps left to run
+    Running,
+    /// Every Leg1 share ended up hedged
+    Hedged,
+    /// Exposure is flat, at least part of it sold back
+    Unwound,
+    /// Exposure may remain; trading must halt
+    Escalated,
+}
+
+/// One executed step, kept for post-mortems.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct SagaEvent {
+    pub step: CompensationStep,
+    pub detail: String,
+    pub at: DateTime<Utc>,
+}
+
+/// What the engine should do next.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum SagaAction {
+    Cancel { order_id: String },
+    Resubmit { shares: u64, price: Decimal },
+    MarketOut { shares: u64 },
+    Alert { reason: String },
+    Done(SagaStatus),
+}
+
+/// Result of running one [`SagaAction`].
+#[derive(Debug, Clone)]
+pub enum StepOutcome {
+    Cancelled,
+    /// A resubmitted hedge filled `shares` (possibly 0)
+    Hedged {
+        shares: u64,
+        fill_price: Decimal,
+        limit_price: Decimal,
+    },
+    /// The market-out sold `shares` of Leg1
+    Unwound {
+        shares: u64,
+    },
+    Alerted,
+    Failed(String),
+}
+
+/// Persisted compensation state for one cycle.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct LegSaga {
+    /// Plan captured at start, so a restart keeps the same steps
+    pub steps: Vec<CompensationStep>,
+    pub step_index: usize,
+    pub resubmits: u32,
+    pub hedge_side: Side,
+    /// Limit of the most recent Leg2 attempt
+    pub last_hedge_price: Decimal,
+    /// Break-even cap for resubmits (`1 - leg1_price`)
+    pub max_hedge_price: Decimal,
+    pub leg1_shares: u64,
+    pub hedged_shares: u64,
+    /// Notional paid for hedged shares
+    pub hedge_cost: Decimal,
+    pub unwound_shares: u64,
+    /// Exchange id of a Leg2 order that may still be resting
+    pub stray_order_id: Option<String>,
+    /// Client id of an order submitted but not yet recorded
+    pub in_flight: Option<String>,
+    pub status: SagaStatus,
+    /// Why compensation started
+    pub reason: String,
+    pub history: Vec<SagaEvent>,
+    pub started_at: DateTime<Utc>,
+}
+
+impl LegSaga {
+    pub fn new(
+        config: &CompensationConfig,
+        reason: impl Into<String>,
+        hedge_side: Side,
+        leg1_price: Decimal,
+        leg1_shares: u64,
+        last_hedge_price: Decimal,
+    ) -> Self {
+        Self {
+            steps: config.steps.clone(),
+            step_index: 0,
+            resubmits: 0,
+            hedge_side,
+            last_hedge_price,
+            max_hedge_price: (Decimal::ONE - leg1_price).max(Decimal::ZERO),
+            leg1_shares,
+            hedged_shares: 0,
+            hedge_cost: Decimal::ZERO,
+            unwound_shares: 0,
+            stray_order_id: None,
+            in_flight: None,
+            status: SagaStatus::Running,
+            reason: reason.into(),
+            history: Vec::new(),
+            started_at: Utc::now(),
+        }
+    }
+
+    /// Shares the failed Leg2 already hedged
+    pub fn with_hedged(mut self, shares: u64, price: Decimal) -> Self {
+        self.hedged_shares += shares;
+        self.hedge_cost += price * Decimal::from(shares);
+        self
+    }
+
+    /// Leg2 order that was not terminal when it failed
+    pub fn with_stray_order(mut self, order_id: impl Into<String>) -> Self {
+        self.stray_order_id = Some(order_id.into());
+        self
+    }
+
+    pub fn is_running(&self) -> bool {
+        self.status == SagaStatus::Running
+    }
+
+    /// Leg1 shares neither hedged nor sold back
+    pub fn open_shares(&self) -> u64 {
+        self.leg1_shares
+            .saturating_sub(self.hedged_shares + self.unwound_shares)
+    }
+
+    pub fn average_hedge_price(&self) -> Option<Decimal> {
+        (self.hedged_shares > 0).then(|| self.hedge_cost / Decimal::from(self.hedged_shares))
+    }
+
+    pub fn summary(&self) -> String {
+        format!(
+            "hedged {}, unwound {} of {} shares after {} resubmits",
+            self.hedged_shares, self.unwound_shares, self.leg1_shares, self.resubmits
+        )
+    }
+
+    /// Pick the next applicable step, skipping ones with nothing to do.
+    pub fn advance(&mut self, config: &CompensationConfig) -> SagaAction {
+        loop {
+            if !self.is_running() {
+                return SagaAction::Done(self.status);
+            }
+
+            let open = self.open_shares();
+            if open == 0 {
+                self.status = if self.unwound_shares == 0 {
+                    SagaStatus::Hedged
+                } else {
+                    SagaStatus::Unwound
+                };
+                continue;
+            }
+
+            // Out of steps with exposure left: escalate even without an alert step.
+            let Some(step) = self.steps.get(self.step_index).copied() else {
+                self.status = SagaStatus::Escalated;
+                continue;
+            };
+
+            match step {
+                CompensationStep::Cancel => {
+                    if let Some(order_id) = &self.stray_order_id {
+                        return SagaAction::Cancel {
+                            order_id: order_id.clone(),
+                        };
+                    }
+                }
+                CompensationStep::Resubmit if self.resubmits < config.max_resubmits => {
+                    let price =
+                        (self.last_hedge_price + config.widen_step).min(self.max_hedge_price);
+                    return SagaAction::Resubmit {
+                        shares: open,
+                        price,
+                    };
+                }
+                CompensationStep::Resubmit => {}
+                CompensationStep::MarketOut => return SagaAction::MarketOut { shares: open },
+                CompensationStep::Alert => {
+                    return SagaAction::Alert {
+                        reason: format!("{}; {} shares still unhedged", self.reason, open),
+                    };
+                }
+            }
+            self.step_index += 1;
+        }
+    }
+
+    /// Mark an order as submitted before waiting on the exchange.
+    pub fn begin(&mut self, client_order_id: impl Into<String>) {
+        self.in_flight = Some(client_order_id.into());
+    }
+
+    /// Record the outcome of the action last returned by [`Self::advance`].
+    pub fn record(&mut self, outcome: StepOutcome) {
+        let Some(step) = self.steps.get(self.step_index).copied() else {
+            return;
+        };
+        self.in_flight = None;
+
+        let detail = match outcome {
+            StepOutcome::Cancelled => {
+                self.stray_order_id = None;
+                self.step_index += 1;
+                "cancelled stray Leg2 order".to_string()
+            }
+            StepOutcome::Hedged {
+                shares,
+                fill_price,
+                limit_price,
+            } => {
+                self.resubmits += 1;
+                self.last_hedge_price = limit_price;
+                self.hedged_shares += shares;
+                self.hedge_cost += fill_price * Decimal::from(shares);
+                format!("resubmit @ {} filled {} shares", limit_price, shares)
+            }
+            StepOutcome::Unwound { shares } => {
+                self.unwound_shares += shares;
+                self.step_index += 1;
+                format!("market-out sold {} shares", shares)
+            }
+            StepOutcome::Alerted => {
+                self.status = SagaStatus::Escalated;
+                self.step_index += 1;
+                "alerted, trading halted".to_string()
+            }
+            StepOutcome::Failed(err) => {
+                match step {
+                    CompensationStep::Resubmit => self.resubmits += 1,
+                    CompensationStep::Cancel => {
+                        self.stray_order_id = None;
+                        self.step_index += 1;
+                    }
+                    _ => self.step_index += 1,
+                }
+                err
+            }
+        };
+
+        self.history.push(SagaEvent {
+            step,
+            detail,
+            at: Utc::now(),
+        });
+    }
+
+    /// Prepare a saga restored from a checkpoint.
+    ///
+    /// An order that was in flight at the crash has an unknown fill, so rather
+    /// than guess the remaining exposure we skip straight to the alert step.
+    pub fn resume_after_crash(&mut self) {
+        let Some(order_id) = self.in_flight.take() else {
+            return;
+        };
+        let step = self
+            .steps
+            .get(self.step_index)
+            .copied()
+            .unwrap_or(CompensationStep::Alert);
+        self.history.push(SagaEvent {
+            step,
+            detail: format!("order {} unconfirmed at crash", order_id),
+            at: Utc::now(),
+        });
+        self.step_index = self.steps[self.step_index.min(self.steps.len())..]
+            .iter()
+            .position(|s| *s == CompensationStep::Alert)
+            .map_or(self.steps.len(), |offset| self.step_index + offset);
+        self.reason = format!("{}; order {} unconfirmed at crash", self.reason, order_id);
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use rust_decimal_macros::dec;
+
+    fn saga() -> LegSaga {
+        LegSaga::new(
+            &CompensationConfig::default(),
+            "Leg2 not fully filled",
+            Side::Down,
+            dec!(0.40),
+            20,
+            dec!(0.55),
+        )
+        .with_hedged(5, dec!(0.55))
+        .with_stray_order("ex-1")
+    }
+
+    #[test]
+    fn test_saga_walks_steps_until_flat() {
+        let config = CompensationConfig::default();
+        let mut saga = saga();
+
+        assert_eq!(
+            saga.advance(&config),
+            SagaAction::Cancel {
+                order_id: "ex-1".to_string()
+            }
+        );
+        saga.record(StepOutcome::Cancelled);
+
+        assert_eq!(
+            saga.advance(&config),
+            SagaAction::Resubmit {
+                shares: 15,
+                price: dec!(0.56)
+            }
+        );
+        saga.record(StepOutcome::Hedged {
+            shares: 5,
+            fill_price: dec!(0.56),
+            limit_price: dec!(0.56),
+        });
+
+        // Second resubmit widens again; a failure still counts as an attempt.
+        assert_eq!(
+            saga.advance(&config),
+            SagaAction::Resubmit {
+                shares: 10,
+                price: dec!(0.57)
+            }
+        );
+        saga.record(StepOutcome::Failed("rejected".to_string()));
+
+        assert_eq!(saga.advance(&config), SagaAction::MarketOut { shares: 10 });
+        saga.record(StepOutcome::Unwound { shares: 10 });
+
+        assert_eq!(saga.advance(&config), SagaAction::Done(SagaStatus::Unwound));
+        assert_eq!(saga.history.len(), 4);
+        assert_eq!(saga.average_hedge_price(), Some(dec!(0.555)));
+    }
+
+    #[test]
+    fn test_in_flight_order_at_crash_escalates() {
+        let config = CompensationConfig::default();
+        let mut saga = saga();
+        saga.advance(&config);
+        saga.record(StepOutcome::Cancelled);
+        saga.advance(&config);
+        saga.begin("client-2");
+
+        let mut restored: LegSaga =
+            serde_json::from_value(serde_json::to_value(&saga).unwrap()).unwrap();
+        restored.resume_after_crash();
+
+        assert!(matches!(
+            restored.advance(&config),
+            SagaAction::Alert { .. }
+        ));
+        restored.record(StepOutcome::Alerted);
+        assert_eq!(
+            restored.advance(&config),
+            SagaAction::Done(SagaStatus::Escalated)
+        );
+    }
+}
@@ -5,6 +5,8 @@
 //! - Realistic fill timing (not instant)
 //! - Bid-ask spread impact
 //! - Market impact on large orders
+//! - Adverse selection (quotes fading before our order arrives)
+//! - Optional learned fill model replacing the fixed depth/impact buffers
 //!
 //! # CRITICAL FIX
 //! Previously, backtesting assumed instant full fills at signal price,
@@ -17,6 +19,8 @@ use rust_decimal::Decimal;
 use rust_decimal_macros::dec;
 use serde::{Deserialize, Serialize};
 
+use super::fill_model::{FillFeatures, FillModel};
+
 /// Execution simulation configuration
 #[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct ExecutionSimConfig {
@@ -38,6 +42,13 @@ pub struct ExecutionSimConfig {
     pub enable_market_impact: bool,
     /// Market impact coefficient (price moves by this * order_size/depth)
     pub impact_coefficient: Decimal,
+    /// Adverse selection (quote fade) model
+    #[serde(default)]
+    pub adverse_selection: AdverseSelectionConfig,
+    /// Fill model learned from production fills; when set it replaces the
+    /// depth-based partial fills and the impact coefficient
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub fill_model: Option<FillModel>,
 }
 
 impl Default for ExecutionSimConfig {
@@ -52,10 +63,150 @@ impl Default for ExecutionSimConfig {
             avg_fill_delay_secs: 5, // 5 second average delay
             enable_market_impact: true,
             impact_coefficient: dec!(0.1), // 10% impact per depth ratio
+            adverse_selection: AdverseSelectionConfig::default(),
+            fill_model: None,
+        }
+    }
+}
+
+/// Adverse selection model configuration
+///
+/// The quoted price we act on may be gone by the time our order lands. The
+/// probability that it fades grows with order latency and is much higher when
+/// the next tick moves against us (someone faster took the liquidity).
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(default)]
+pub struct AdverseSelectionConfig {
+    /// Enable quote-fade simulation
+    pub enabled: bool,
+    /// Signal-to-exchange latency in milliseconds
+    pub latency_ms: u64,
+    /// Fade probability when the next tick direction is unknown
+    pub base_fade_prob: f64,
+    /// Fade probability when the next tick moves against the order
+    pub adverse_fade_prob: f64,
+    /// Fade probability when the next tick moves with (or not against) the order
+    pub favorable_fade_prob: f64,
+    /// Additional fade probability per 100ms of latency
+    pub fade_prob_per_100ms: f64,
+    /// Upper bound on fade probability
+    pub max_fade_prob: f64,
+    /// On fade, fill at the next tick's price (true) or miss entirely (false)
+    pub reprice_on_fade: bool,
+    /// Seed for the deterministic fade draw (reproducible backtests)
+    pub seed: u64,
+}
+
+impl Default for AdverseSelectionConfig {
+    fn default() -> Self {
+        Self {
+            enabled: false,
+            latency_ms: 250,
+            base_fade_prob: 0.10,
+            adverse_fade_prob: 0.35,
+            favorable_fade_prob: 0.02,
+            fade_prob_per_100ms: 0.03,
+            max_fade_prob: 0.95,
+            reprice_on_fade: true,
+            seed: 0,
+        }
+    }
+}
+
+/// One order observation from real fill logs, used for calibration
+#[derive(Debug, Clone, Copy)]
+pub struct FillLogSample {
+    /// Signal-to-exchange latency in milliseconds
+    pub latency_ms: u64,
+    /// Whether the next tick moved against the order (None if unknown)
+    pub adverse_tick: Option<bool>,
+    /// Whether the order filled at the quoted price
+    pub filled_at_quote: bool,
+}
+
+impl AdverseSelectionConfig {
+    /// Probability that the quote fades before the order arrives.
+    pub fn fade_probability(&self, adverse_tick: Option<bool>) -> f64 {
+        let base = match adverse_tick {
+            Some(true) => self.adverse_fade_prob,
+            Some(false) => self.favorable_fade_prob,
+            None => self.base_fade_prob,
+        };
+        let latency_term = self.fade_prob_per_100ms * (self.latency_ms as f64 / 100.0);
+        (base + latency_term).clamp(0.0, self.max_fade_prob.clamp(0.0, 1.0))
+    }
+
+    /// Fit fade probabilities from real fill logs.
+    ///
+    /// The latency slope is a within-group least-squares fit (grouped by tick
+    /// direction); group intercepts are the fade rates at zero latency. Groups
+    /// without samples keep their current values. `latency_ms` is set to the
+    /// mean observed latency.
+    pub fn calibrate(&self, samples: &[FillLogSample]) -> Self {
+        let mut out = self.clone();
+        if samples.is_empty() {
+            return out;
         }
+
+        let x = |s: &FillLogSample| s.latency_ms as f64 / 100.0;
+        let y = |s: &FillLogSample| if s.filled_at_quote { 0.0 } else { 1.0 };
+        let group_means = |g: Option<bool>| -> Option<(f64, f64)> {
+            let members: Vec<&FillLogSample> =
+                samples.iter().filter(|s| s.adverse_tick == g).collect();
+            if members.is_empty() {
+                return None;
+            }
+            let n = members.len() as f64;
+            Some((
+                members.iter().map(|s| x(s)).sum::<f64>() / n,
+                members.iter().map(|s| y(s)).sum::<f64>() / n,
+            ))
+        };
+
+        let groups = [None, Some(true), Some(false)];
+        let means: Vec<Option<(f64, f64)>> = groups.iter().map(|g| group_means(*g)).collect();
+
+        let (mut sxy, mut sxx) = (0.0, 0.0);
+        for sample in samples {
+            let idx = groups
+                .iter()
+                .position(|g| *g == sample.adverse_tick)
+                .unwrap_or(0);
+            if let Some((mx, my)) = means[idx] {
+                sxy += (x(sample) - mx) * (y(sample) - my);
+                sxx += (x(sample) - mx).powi(2);
+            }
+        }
+        let slope = if sxx > 0.0 { (sxy / sxx).max(0.0) } else { 0.0 };
+        out.fade_prob_per_100ms = slope;
+
+        let intercept = |m: Option<(f64, f64)>, current: f64| {
+            m.map(|(mx, my)| (my - slope * mx).clamp(0.0, 1.0))
+                .unwrap_or(current)
+        };
+        out.base_fade_prob = intercept(means[0], self.base_fade_prob);
+        out.adverse_fade_prob = intercept(means[1], self.adverse_fade_prob);
+        out.favorable_fade_prob = intercept(means[2], self.favorable_fade_prob);
+        out.latency_ms = (samples.iter().map(|s| s.latency_ms as f64).sum::<f64>()
+            / samples.len() as f64)
+            .round() as u64;
+        out
     }
 }
 
+/// Deterministic uniform draw in [0, 1) (splitmix64).
+fn uniform_draw(seed: u64, signal_time: DateTime<Utc>, price: Decimal, shares: u64) -> f64 {
+    let mut z = seed
+        ^ (signal_time.timestamp_nanos_opt().unwrap_or_default() as u64)
+        ^ (price.mantissa() as u64).rotate_left(17)
+        ^ shares.rotate_left(41);
+    z = z.wrapping_add(0x9E37_79B9_7F4A_7C15);
+    z = (z ^ (z >> 30)).wrapping_mul(0xBF58_476D_1CE4_E5B9);
+    z = (z ^ (z >> 27)).wrapping_mul(0x94D0_49BB_1331_11EB);
+    z ^= z >> 31;
+    (z >> 11) as f64 / (1u64 << 53) as f64
+}
+
 /// Execution result
 #[derive(Debug, Clone)]
 pub struct ExecutionResult {
@@ -75,6 +226,8 @@ pub struct ExecutionResult {
     pub market_impact: Decimal,
     /// Whether this was a partial fill
     pub is_partial: bool,
+    /// Whether the quoted price faded before the order arrived
+    pub adverse_faded: bool,
 }
 
 /// Realistic execution simulator
@@ -95,6 +248,12 @@ impl ExecutionSimulator {
         Self { config }
     }
 
+    /// Use a fill model learned from production fills
+    pub fn with_fill_model(mut self, model: FillModel) -> Self {
+        self.config.fill_model = Some(model);
+        self
+    }
+
     /// Simulate a buy order execution
     ///
     /// # Arguments
@@ -111,6 +270,49 @@ impl ExecutionSimulator {
         signal_time: DateTime<Utc>,
         shares: u64,
         market_depth_shares: u64,
+    ) -> ExecutionResult {
+        self.simulate_buy_with_next_tick(
+            signal_price,
+            signal_time,
+            shares,
+            market_depth_shares,
+            None,
+        )
+    }
+
+    /// Simulate a buy order, applying adverse selection against the next tick
+    ///
+    /// `next_price` is the mid observed after the configured latency; a rise
+    /// means the ask likely lifted away from us.
+    pub fn simulate_buy_with_next_tick(
+        &self,
+        signal_price: Decimal,
+        signal_time: DateTime<Utc>,
+        shares: u64,
+        market_depth_shares: u64,
+        next_price: Option<Decimal>,
+    ) -> ExecutionResult {
+        let adverse_tick = next_price.map(|p| p > signal_price);
+        if !self.quote_fades(signal_price, signal_time, shares, adverse_tick) {
+            return self.fill_buy(signal_price, signal_time, shares, market_depth_shares);
+        }
+        match next_price {
+            Some(next) if self.config.adverse_selection.reprice_on_fade => {
+                let mut result = self.fill_buy(next, signal_time, shares, market_depth_shares);
+                result.slippage = result.fill_price - signal_price;
+                result.adverse_faded = true;
+                result
+            }
+            _ => self.faded_miss(signal_price, signal_time, shares),
+        }
+    }
+
+    fn fill_buy(
+        &self,
+        signal_price: Decimal,
+        signal_time: DateTime<Utc>,
+        shares: u64,
+        market_depth_shares: u64,
     ) -> ExecutionResult {
         // Calculate ask price (buy side)
         let half_spread = if self.config.use_spread {
@@ -121,19 +323,12 @@ impl ExecutionSimulator {
         let ask_price = signal_price + half_spread;
 
         // Determine fill quantity
-        let (filled_shares, is_partial) = if self.config.enable_partial_fills {
-            self.calculate_fill_quantity(shares, market_depth_shares)
-        } else {
-            (shares, false)
-        };
+        let (filled_shares, is_partial) =
+            self.fill_quantity(half_spread, shares, market_depth_shares);
 
         // Calculate market impact
-        let market_impact = if self.config.enable_market_impact && market_depth_shares > 0 {
-            let depth_ratio = Decimal::from(filled_shares) / Decimal::from(market_depth_shares);
-            ask_price * self.config.impact_coefficient * depth_ratio
-        } else {
-            Decimal::ZERO
-        };
+        let market_impact =
+            self.market_impact(ask_price, half_spread, filled_shares, market_depth_shares);
 
         // Final fill price includes spread and market impact
         let fill_price = ask_price + market_impact;
@@ -162,6 +357,7 @@ impl ExecutionSimulator {
             slippage,
             market_impact,
             is_partial,
+            adverse_faded: false,
         }
     }
 
@@ -181,6 +377,49 @@ impl ExecutionSimulator {
         signal_time: DateTime<Utc>,
         shares: u64,
         market_depth_shares: u64,
+    ) -> ExecutionResult {
+        self.simulate_sell_with_next_tick(
+            signal_price,
+            signal_time,
+            shares,
+            market_depth_shares,
+            None,
+        )
+    }
+
+    /// Simulate a sell order, applying adverse selection against the next tick
+    ///
+    /// `next_price` is the mid observed after the configured latency; a drop
+    /// means the bid likely got hit before us.
+    pub fn simulate_sell_with_next_tick(
+        &self,
+        signal_price: Decimal,
+        signal_time: DateTime<Utc>,
+        shares: u64,
+        market_depth_shares: u64,
+        next_price: Option<Decimal>,
+    ) -> ExecutionResult {
+        let adverse_tick = next_price.map(|p| p < signal_price);
+        if !self.quote_fades(signal_price, signal_time, shares, adverse_tick) {
+            return self.fill_sell(signal_price, signal_time, shares, market_depth_shares);
+        }
+        match next_price {
+            Some(next) if self.config.adverse_selection.reprice_on_fade => {
+                let mut result = self.fill_sell(next, signal_time, shares, market_depth_shares);
+                result.slippage = signal_price - result.fill_price;
+                result.adverse_faded = true;
+                result
+            }
+            _ => self.faded_miss(signal_price, signal_time, shares),
+        }
+    }
+
+    fn fill_sell(
+        &self,
+        signal_price: Decimal,
+        signal_time: DateTime<Utc>,
+        shares: u64,
+        market_depth_shares: u64,
     ) -> ExecutionResult {
         // Calculate bid price (sell side)
         let half_spread = if self.config.use_spread {
@@ -191,19 +430,12 @@ impl ExecutionSimulator {
         let bid_price = signal_price - half_spread;
 
         // Determine fill quantity
-        let (filled_shares, is_partial) = if self.config.enable_partial_fills {
-            self.calculate_fill_quantity(shares, market_depth_shares)
-        } else {
-            (shares, false)
-        };
+        let (filled_shares, is_partial) =
+            self.fill_quantity(half_spread, shares, market_depth_shares);
 
         // Calculate market impact (negative for sells)
-        let market_impact = if self.config.enable_market_impact && market_depth_shares > 0 {
-            let depth_ratio = Decimal::from(filled_shares) / Decimal::from(market_depth_shares);
-            bid_price * self.config.impact_coefficient * depth_ratio
-        } else {
-            Decimal::ZERO
-        };
+        let market_impact =
+            self.market_impact(bid_price, half_spread, filled_shares, market_depth_shares);
 
         // Final fill price includes spread and market impact
         let fill_price = bid_price - market_impact;
@@ -232,6 +464,95 @@ impl ExecutionSimulator {
             slippage,
             market_impact,
             is_partial,
+            adverse_faded: false,
+        }
+    }
+
+    /// Draw whether the quote fades before the order arrives
+    fn quote_fades(
+        &self,
+        signal_price: Decimal,
+        signal_time: DateTime<Utc>,
+        shares: u64,
+        adverse_tick: Option<bool>,
+    ) -> bool {
+        let model = &self.config.adverse_selection;
+        if !model.enabled {
+            return false;
+        }
+        let draw = uniform_draw(model.seed, signal_time, signal_price, shares);
+        draw < model.fade_probability(adverse_tick)
+    }
+
+    /// Result for an order whose quote faded with no re-price available
+    fn faded_miss(
+        &self,
+        signal_price: Decimal,
+        signal_time: DateTime<Utc>,
+        shares: u64,
+    ) -> ExecutionResult {
+        let latency = Duration::milliseconds(self.config.adverse_selection.latency_ms as i64);
+        ExecutionResult {
+            fill_price: signal_price,
+            filled_shares: 0,
+            requested_shares: shares,
+            fill_pct: Decimal::ZERO,
+            fill_time: signal_time + latency,
+            slippage: Decimal::ZERO,
+            market_impact: Decimal::ZERO,
+            is_partial: true,
+            adverse_faded: true,
+        }
+    }
+
+    /// Fill quantity from the learned model, else from market depth
+    fn fill_quantity(&self, half_spread: Decimal, requested: u64, depth: u64) -> (u64, bool) {
+        match &self.config.fill_model {
+            Some(_) if depth == 0 => (0, true),
+            Some(model) => {
+                let features = self.fill_features(half_spread, requested, depth);
+                let filled = (requested as f64 * model.fill_probability(&features)).floor() as u64;
+                (filled.min(requested), filled < requested)
+            }
+            None if self.config.enable_partial_fills => {
+                self.calculate_fill_quantity(requested, depth)
+            }
+            None => (requested, false),
+        }
+    }
+
+    /// Price impact beyond the quote, from the learned model or the impact coefficient
+    fn market_impact(
+        &self,
+        quote: Decimal,
+        half_spread: Decimal,
+        filled: u64,
+        depth: u64,
+    ) -> Decimal {
+        if let Some(model) = &self.config.fill_model {
+            let features = self.fill_features(half_spread, filled, depth);
+            return quote
+                * Decimal::from_f64_retain(model.expected_slippage_pct(&features))
+                    .unwrap_or_default();
+        }
+        if self.config.enable_market_impact && depth > 0 {
+            let depth_ratio = Decimal::from(filled) / Decimal::from(depth);
+            quote * self.config.impact_coefficient * depth_ratio
+        } else {
+            Decimal::ZERO
+        }
+    }
+
+    fn fill_features(&self, half_spread: Decimal, shares: u64, depth: u64) -> FillFeatures {
+        FillFeatures {
+            spread: (half_spread * dec!(2)).to_f64(),
+            depth_ratio: if depth > 0 {
+                Some(shares as f64 / depth as f64)
+            } else {
+                None
+            },
+            shares: Some(shares as f64),
+            latency_ms: Some(self.config.adverse_selection.latency_ms as f64),
         }
     }
 
@@ -473,4 +794,117 @@ mod tests {
         assert!(result.fill_pct < Decimal::ONE);
         assert!(result.fill_pct >= dec!(0.5)); // At least min_fill_pct
     }
+
+    fn always_fade(reprice_on_fade: bool) -> ExecutionSimulator {
+        ExecutionSimulator::with_config(ExecutionSimConfig {
+            enable_market_impact: false,
+            enable_fill_delay: false,
+            adverse_selection: AdverseSelectionConfig {
+                enabled: true,
+                base_fade_prob: 1.0,
+                adverse_fade_prob: 1.0,
+                favorable_fade_prob: 0.0,
+                fade_prob_per_100ms: 0.0,
+                max_fade_prob: 1.0,
+                reprice_on_fade,
+                ..Default::default()
+            },
+            ..Default::default()
+        })
+    }
+
+    #[test]
+    fn test_adverse_tick_reprices_buy() {
+        let sim = always_fade(true);
+        let signal_time = Utc::now();
+
+        let result =
+            sim.simulate_buy_with_next_tick(dec!(0.50), signal_time, 100, 1000, Some(dec!(0.55)));
+
+        assert!(result.adverse_faded);
+        assert_eq!(result.filled_shares, 100);
+        assert!(result.fill_price > dec!(0.55));
+        assert_eq!(result.slippage, result.fill_price - dec!(0.50));
+    }
+
+    #[test]
+    fn test_favorable_tick_does_not_fade() {
+        let sim = always_fade(true);
+        let result =
+            sim.simulate_sell_with_next_tick(dec!(0.50), Utc::now(), 100, 1000, Some(dec!(0.55)));
+
+        assert!(!result.adverse_faded);
+        assert!(result.fill_price < dec!(0.50));
+    }
+
+    #[test]
+    fn test_fade_without_reprice_misses() {
+        let sim = always_fade(false);
+        let result = sim.simulate_buy(dec!(0.50), Utc::now(), 100, 1000);
+
+        assert!(result.adverse_faded);
+        assert_eq!(result.filled_shares, 0);
+        assert!(result.is_partial);
+    }
+
+    #[test]
+    fn test_calibrate_from_fill_logs() {
+        let mut samples = Vec::new();
+        for i in 0..100u64 {
+            // Adverse ticks fade 60% of the time, favorable ticks 10%.
+            samples.push(FillLogSample {
+                latency_ms: 200,
+                adverse_tick: Some(true),
+                filled_at_quote: i % 10 >= 6,
+            });
+            samples.push(FillLogSample {
+                latency_ms: 200,
+                adverse_tick: Some(false),
+                filled_at_quote: i % 10 != 0,
+            });
+        }
+
+        let fitted = AdverseSelectionConfig::default().calibrate(&samples);
+        assert_eq!(fitted.latency_ms, 200);
+        assert_eq!(fitted.fade_prob_per_100ms, 0.0);
+        assert!((fitted.adverse_fade_prob - 0.6).abs() < 1e-9);
+        assert!((fitted.favorable_fade_prob - 0.1).abs() < 1e-9);
+        // No direction-unknown samples: keep the prior.
+        assert_eq!(fitted.base_fade_prob, 0.10);
+    }
+
+    #[test]
+    fn test_learned_fill_model_replaces_fixed_buffers() {
+        // Half of every order fills; 1% slippage beyond the quote
+        let model = FillModel {
+            fill_coefficients: vec![0.0; 5],
+            slippage_coefficients: vec![0.01, 0.0, 0.0, 0.0, 0.0],
+            feature_means: vec![0.0; 4],
+            samples: 100,
+            filled_samples: 80,
+            fitted_at: Utc::now(),
+        };
+        let sim = ExecutionSimulator::with_config(ExecutionSimConfig {
+            enable_market_impact: false,
+            ..Default::default()
+        })
+        .with_fill_model(model);
+
+        let buy = sim.simulate_buy(dec!(0.50), Utc::now(), 100, 1000);
+        assert_eq!(buy.filled_shares, 50);
+        assert!(buy.is_partial);
+        // ask 0.505 plus 1% learned slippage
+        assert!((buy.market_impact - dec!(0.00505)).abs() < dec!(0.000001));
+        assert!((buy.fill_price - dec!(0.51005)).abs() < dec!(0.000001));
+
+        let sell = sim.simulate_sell(dec!(0.50), Utc::now(), 100, 1000);
+        assert!((sell.fill_price - dec!(0.49005)).abs() < dec!(0.000001));
+
+        // No liquidity still means no fill
+        assert_eq!(
+            sim.simulate_buy(dec!(0.50), Utc::now(), 100, 0)
+                .filled_shares,
+            0
+        );
+    }
 }
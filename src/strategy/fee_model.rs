@@ -7,10 +7,14 @@
 //! cost estimation. The fee curve produces zero fees at p=0 and p=1
 //! (settled markets) and maximum fees at p=0.50 (maximum uncertainty).
 //!
+//! Pre-trade slippage uses the learned [`FillModel`] when one is attached,
+//! otherwise a fixed linear depth buffer.
+//!
 //! # Domain-specific parameters
 //! - Crypto (5m/15m markets): fee_rate=0.25, exponent=2
 //! - Sports: fee_rate=0.0175, exponent=1
 
+use rust_decimal::prelude::ToPrimitive;
 use rust_decimal::Decimal;
 use rust_decimal::MathematicalOps;
 use rust_decimal_macros::dec;
@@ -20,6 +24,7 @@ use std::sync::Arc;
 use std::time::{Duration, Instant};
 use tokio::sync::RwLock;
 
+use super::fill_model::{FillFeatures, FillModel};
 use crate::error::{PloyError, Result};
 
 // ---------------------------------------------------------------------------
@@ -35,6 +40,9 @@ pub struct FeeModel {
     pub fee_rate: Decimal,
     /// Exponent applied to `p * (1 - p)` (e.g. 2 for crypto)
     pub exponent: u32,
+    /// Slippage model learned from production fills
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub fill_model: Option<FillModel>,
 }
 
 /// Breakdown of all-in trading cost at a given price point.
@@ -56,6 +64,7 @@ impl FeeModel {
         FeeModel {
             fee_rate: dec!(0.25),
             exponent: 2,
+            fill_model: None,
         }
     }
 
@@ -64,9 +73,16 @@ impl FeeModel {
         FeeModel {
             fee_rate: dec!(0.0175),
             exponent: 1,
+            fill_model: None,
         }
     }
 
+    /// Estimate slippage with a learned fill model instead of the fixed buffer
+    pub fn with_fill_model(mut self, model: FillModel) -> Self {
+        self.fill_model = Some(model);
+        self
+    }
+
     /// Fee in shares for buying `shares` at price `p`.
     ///
     /// Formula: `shares * fee_rate * (p * (1 - p))^exponent`
@@ -94,8 +110,10 @@ impl FeeModel {
 
     /// All-in cost given current book state.
     ///
-    /// `depth_ratio` is `order_size / best_level_size` — used for a simple
-    /// linear slippage estimate (0.5% per 100% depth ratio).
+    /// `depth_ratio` is `order_size / best_level_size`. Slippage comes from
+    /// the learned fill model when set (spread and depth ratio as features,
+    /// size and latency at their training means), otherwise from a simple
+    /// linear estimate (0.5% per 100% depth ratio).
     pub fn all_in_cost(
         &self,
         price: Decimal,
@@ -105,8 +123,20 @@ impl FeeModel {
     ) -> AllInCost {
         let taker_fee = self.effective_rate(price);
         let spread_cost = (best_ask - best_bid) / dec!(2);
-        // Simple linear slippage model: 0.5% per 100% depth ratio
-        let depth_slippage = depth_ratio * dec!(0.005);
+        let depth_slippage = match &self.fill_model {
+            Some(model) => {
+                let features = FillFeatures {
+                    spread: (best_ask - best_bid).to_f64(),
+                    depth_ratio: depth_ratio.to_f64(),
+                    ..Default::default()
+                };
+                price
+                    * Decimal::from_f64_retain(model.expected_slippage_pct(&features))
+                        .unwrap_or_default()
+            }
+            // Simple linear slippage model: 0.5% per 100% depth ratio
+            None => depth_ratio * dec!(0.005),
+        };
         AllInCost {
             taker_fee,
             spread_cost,
@@ -10,21 +10,27 @@ use sqlx::PgPool;
 use std::collections::HashMap;
 use std::sync::Arc;
 use std::time::Duration;
-use tokio::sync::RwLock;
+use tokio::sync::{Notify, RwLock};
 use tracing::{debug, error, info, warn};
 
 use super::manager::StrategyManager;
+use super::round_calendar::{RoundCalendar, RoundCalendarConfig, RoundPhase};
 use super::traits::{DataFeed, KlineBar, MarketUpdate};
+use crate::adapters::polymarket_ws::PriceLevel;
 use crate::adapters::{
     BinanceKlineWebSocket, BinanceWebSocket, PolymarketClient, PolymarketWebSocket,
 };
 use crate::collector::BinanceKlineClient;
+use crate::coordination::BookLevel;
 use crate::error::Result;
+use crate::platform::Timeframe;
 
 const MAX_EVENTS_PER_SERIES: usize = 6;
 const POLYMARKET_REFRESH_SECS: u64 = 30;
+/// Upper bound on round scheduler sleeps so newly ingested rounds are picked up.
+const ROUND_SCHEDULER_MAX_SLEEP_SECS: u64 = 5;
 
-fn infer_symbol_from_text(text: &str) -> Option<&'static str> {
+pub(super) fn infer_symbol_from_text(text: &str) -> Option<&'static str> {
     let lower = text.to_ascii_lowercase();
     if lower.contains("bitcoin") || lower.contains("btc") {
         Some("BTCUSDT")
@@ -39,25 +45,25 @@ fn infer_symbol_from_text(text: &str) -> Option<&'static str> {
     }
 }
 
-fn infer_horizon_from_text(text: &str) -> Option<&'static str> {
-    let lower = text.to_ascii_lowercase();
-    if lower.contains("15m")
-        || lower.contains("15-minute")
-        || lower.contains("15 minute")
-        || lower.contains("15min")
-        || lower.contains("15 min")
-    {
-        Some("15m")
-    } else if lower.contains("5m")
-        || lower.contains("5-minute")
-        || lower.contains("5 minute")
-        || lower.contains("5min")
-        || lower.contains("5 min")
-    {
-        Some("5m")
-    } else {
-        None
-    }
+fn infer_horizon_from_text(text: &str) -> Option<Timeframe> {
+    Timeframe::from_hint(text).filter(|tf| tf.duration_secs().is_some())
+}
+
+/// Round length for a discovered event: series/slug hint first, then the
+/// Gamma start/end window.
+fn infer_event_window_secs(
+    series_id: &str,
+    details: &crate::adapters::polymarket_clob::GammaEventInfo,
+    end_time: DateTime<Utc>,
+) -> Option<u64> {
+    infer_horizon_from_text(series_id)
+        .or_else(|| details.slug.as_deref().and_then(infer_horizon_from_text))
+        .or_else(|| {
+            parse_rfc3339_utc(details.start_time.as_deref())
+                .and_then(|start| Timeframe::from_duration_secs((end_time - start).num_seconds()))
+        })
+        .and_then(|tf| tf.duration_secs())
+        .map(|secs| secs as u64)
 }
 
 fn apply_dimension_candidate(
@@ -72,7 +78,7 @@ fn apply_dimension_candidate(
     }
     if horizon.is_none() {
         if let Some(h) = infer_horizon_from_text(text) {
-            *horizon = Some(h.to_string());
+            *horizon = Some(h.as_str().to_string());
         }
     }
 }
@@ -102,6 +108,15 @@ fn infer_symbol_horizon_from_event(
         }
     }
 
+    if horizon.is_none() {
+        let start = parse_rfc3339_utc(details.start_time.as_deref());
+        let end = parse_rfc3339_utc(details.end_date.as_deref());
+        if let (Some(start), Some(end)) = (start, end) {
+            horizon = Timeframe::from_duration_secs((end - start).num_seconds())
+                .map(|tf| tf.as_str().to_string());
+        }
+    }
+
     (symbol, horizon)
 }
 
@@ -126,7 +141,7 @@ async fn upsert_pm_market_metadata(
     let (symbol, horizon) = infer_symbol_horizon_from_event(details);
     let raw_market: Value = serde_json::to_value(details).unwrap_or_else(|_| Value::Null);
 
-    // Keep dataset clean for sequence training alignment: crypto symbols + 5m/15m only.
+    // Keep dataset clean for sequence training alignment: crypto symbols + 5m/15m/1h/1d only.
     let (Some(symbol), Some(horizon)) = (symbol, horizon) else {
         return Ok(());
     };
@@ -160,6 +175,18 @@ async fn upsert_pm_market_metadata(
     Ok(())
 }
 
+fn book_levels(levels: &[PriceLevel]) -> Vec<BookLevel> {
+    levels
+        .iter()
+        .filter_map(|level| {
+            Some(BookLevel {
+                price: level.price.parse().ok()?,
+                size: level.size.parse().ok()?,
+            })
+        })
+        .collect()
+}
+
 fn parse_price_from_question(question: &str) -> Option<rust_decimal::Decimal> {
     // Intentionally strict: avoid mis-parsing dates/times in "Up or Down" titles.
     // Only parse when the string contains a clear price marker like `$` or `↑/↓`.
@@ -211,6 +238,10 @@ pub struct DataFeedManager {
     series_events: Arc<RwLock<HashMap<String, HashMap<String, DiscoveredEvent>>>>,
     /// Optional DB pool used to persist normalized market metadata for model training.
     metadata_pool: Option<Arc<PgPool>>,
+    /// Round end times from Gamma series metadata (drives boundary scheduling)
+    round_calendar: Arc<RwLock<RoundCalendar>>,
+    /// Wakes the Polymarket refresh loop immediately at a round rotation
+    rotation_notify: Arc<Notify>,
 }
 
 /// Mapping from token to event info
@@ -230,6 +261,7 @@ struct DiscoveredEvent {
     end_time: chrono::DateTime<Utc>,
     price_to_beat: Option<rust_decimal::Decimal>,
     title: Option<String>,
+    window_secs: Option<u64>,
 }
 
 impl DataFeedManager {
@@ -259,9 +291,22 @@ impl DataFeedManager {
             active_feeds: Arc::new(RwLock::new(Vec::new())),
             series_events: Arc::new(RwLock::new(HashMap::new())),
             metadata_pool,
+            round_calendar: Arc::new(RwLock::new(RoundCalendar::default())),
+            rotation_notify: Arc::new(Notify::new()),
         }
     }
 
+    /// Configure round warm-up / cool-down windows
+    pub fn with_round_calendar(mut self, config: RoundCalendarConfig) -> Self {
+        self.round_calendar = Arc::new(RwLock::new(RoundCalendar::new(config)));
+        self
+    }
+
+    /// Shared round calendar (seconds-to-settlement / phase lookups)
+    pub fn round_calendar(&self) -> Arc<RwLock<RoundCalendar>> {
+        self.round_calendar.clone()
+    }
+
     /// Configure Binance feed for given symbols
     pub fn with_binance(mut self, symbols: Vec<String>) -> Self {
         if !symbols.is_empty() {
@@ -495,6 +540,7 @@ impl DataFeedManager {
         if let Some(ref pm_ws) = self.polymarket_ws {
             let manager = self.manager.clone();
             let mut rx = pm_ws.subscribe_updates();
+            let round_calendar = self.round_calendar.clone();
 
             tokio::spawn(async move {
                 info!("Polymarket quote feed started - waiting for quotes");
@@ -522,11 +568,17 @@ impl DataFeedManager {
                                     update.quote.best_ask
                                 );
                             }
+                            let now = Utc::now();
+                            let seconds_to_settlement = round_calendar
+                                .read()
+                                .await
+                                .token_seconds_to_settlement(&update.token_id, now);
                             let market_update = MarketUpdate::PolymarketQuote {
                                 token_id: update.token_id,
                                 side: update.side,
                                 quote: update.quote,
-                                timestamp: Utc::now(),
+                                timestamp: now,
+                                seconds_to_settlement,
                             };
                             manager.send_market_update(market_update);
                         }
@@ -541,6 +593,29 @@ impl DataFeedManager {
                 }
                 warn!("Polymarket quote feed ended");
             });
+
+            // Full book snapshots, for strategies that size against depth
+            let manager = self.manager.clone();
+            let mut books = pm_ws.subscribe_books();
+            tokio::spawn(async move {
+                loop {
+                    match books.recv().await {
+                        Ok(book) => {
+                            manager.send_market_update(MarketUpdate::PolymarketBook {
+                                token_id: book.asset_id.clone(),
+                                bids: book_levels(&book.bids),
+                                asks: book_levels(&book.asks),
+                                timestamp: Utc::now(),
+                            });
+                        }
+                        Err(tokio::sync::broadcast::error::RecvError::Lagged(n)) => {
+                            debug!("Book feed lagged by {} snapshots", n);
+                        }
+                        Err(tokio::sync::broadcast::error::RecvError::Closed) => break,
+                    }
+                }
+                warn!("Polymarket book feed ended");
+            });
         }
 
         Ok(())
@@ -573,6 +648,10 @@ impl DataFeedManager {
             match client.get_all_active_events(series_id).await {
                 Ok(events) => {
                     let total_events = events.len();
+                    self.round_calendar
+                        .write()
+                        .await
+                        .ingest_series(series_id, &events);
 
                     // /series/{id} returns lightweight event summaries without markets/tokens.
                     // Filter to near-future events and fetch full details for a small subset.
@@ -717,8 +796,13 @@ impl DataFeedManager {
                             end_time,
                             price_to_beat,
                             title: title.clone(),
+                            window_secs: infer_event_window_secs(series_id, &details, end_time),
                         };
 
+                        self.round_calendar.write().await.register_tokens(
+                            &details.id,
+                            &[up_token.as_str(), down_token.as_str()],
+                        );
                         discovered.insert(details.id.clone(), ev);
                         token_ids.push(up_token);
                         token_ids.push(down_token);
@@ -762,6 +846,7 @@ impl DataFeedManager {
                                     end_time: ev.end_time,
                                     price_to_beat: ev.price_to_beat,
                                     title: ev.title.clone(),
+                                    window_secs: ev.window_secs,
                                 };
                                 self.manager.send_market_update(update);
                             }
@@ -848,11 +933,59 @@ impl DataFeedManager {
         // Start periodic refresh for Polymarket series (keeps token set rotating).
         if !series_ids_to_refresh.is_empty() {
             self.spawn_polymarket_refresh(series_ids_to_refresh).await;
+            self.spawn_round_scheduler();
         }
 
         Ok(all_tokens)
     }
 
+    /// Emit `RoundBoundary` updates at the exact warm-up / rotation / cool-down
+    /// instants from the round calendar, and wake the refresh loop on rotation.
+    fn spawn_round_scheduler(&self) {
+        let manager = self.manager.clone();
+        let round_calendar = self.round_calendar.clone();
+        let rotation_notify = self.rotation_notify.clone();
+
+        tokio::spawn(async move {
+            let max_sleep = Duration::from_secs(ROUND_SCHEDULER_MAX_SLEEP_SECS);
+            let mut last = Utc::now();
+            loop {
+                let next = round_calendar.read().await.next_transition_after(last);
+                let wait = next
+                    .and_then(|at| (at - Utc::now()).to_std().ok())
+                    .unwrap_or(max_sleep)
+                    .min(max_sleep);
+                tokio::time::sleep(wait).await;
+
+                let now = Utc::now();
+                let due = round_calendar.read().await.due(last, now);
+                let mut rotated = false;
+                for transition in due {
+                    info!(
+                        series_id = %transition.series_id,
+                        event_id = %transition.event_id,
+                        phase = transition.phase.as_str(),
+                        end_time = %transition.end_time,
+                        "Round boundary"
+                    );
+                    rotated |= transition.phase == RoundPhase::CoolDown;
+                    manager.send_market_update(MarketUpdate::RoundBoundary {
+                        series_id: transition.series_id,
+                        event_id: transition.event_id,
+                        phase: transition.phase,
+                        end_time: transition.end_time,
+                        timestamp: now,
+                    });
+                }
+                if rotated {
+                    rotation_notify.notify_one();
+                }
+                round_calendar.write().await.prune(now);
+                last = now;
+            }
+        });
+    }
+
     async fn spawn_polymarket_refresh(&self, series_ids: Vec<String>) {
         let Some(pm_client) = self.pm_client.clone() else {
             return;
@@ -864,17 +997,29 @@ impl DataFeedManager {
         let manager = self.manager.clone();
         let series_events = self.series_events.clone();
         let metadata_pool = self.metadata_pool.clone();
+        let round_calendar = self.round_calendar.clone();
+        let rotation_notify = self.rotation_notify.clone();
 
         tokio::spawn(async move {
             let mut ticker = tokio::time::interval(Duration::from_secs(POLYMARKET_REFRESH_SECS));
             loop {
-                ticker.tick().await;
+                // Poll as a fallback; the round scheduler wakes us at each rotation.
+                tokio::select! {
+                    _ = ticker.tick() => {}
+                    _ = rotation_notify.notified() => {
+                        debug!("Polymarket refresh triggered by round rotation");
+                    }
+                }
 
                 for series_id in &series_ids {
                     // Fetch active events and keep only the nearest ones.
                     let Ok(events) = pm_client.get_all_active_events(series_id).await else {
                         continue;
                     };
+                    round_calendar
+                        .write()
+                        .await
+                        .ingest_series(series_id, &events);
 
                     // /series/{id} returns lightweight summaries; fetch details for only a small
                     // near-future subset.
@@ -1004,6 +1149,10 @@ impl DataFeedManager {
                         pm_ws
                             .register_token(&down_token, crate::domain::Side::Down)
                             .await;
+                        round_calendar.write().await.register_tokens(
+                            &details.id,
+                            &[up_token.as_str(), down_token.as_str()],
+                        );
 
                         discovered.insert(
                             details.id.clone(),
@@ -1015,6 +1164,7 @@ impl DataFeedManager {
                                 end_time,
                                 price_to_beat,
                                 title,
+                                window_secs: infer_event_window_secs(series_id, &details, end_time),
                             },
                         );
                     }
@@ -1056,6 +1206,7 @@ impl DataFeedManager {
                                     end_time: ev.end_time,
                                     price_to_beat: ev.price_to_beat,
                                     title: ev.title.clone(),
+                                    window_secs: ev.window_secs,
                                 });
                             }
 
@@ -0,0 +1,424 @@
+//! Learned Fill Model
+//!
+//! Fill probability and slippage fitted from production fills instead of the
+//! fixed buffers in `execution_sim` and `fee_model`. Observations come from
+//! [`TradeLogger`](super::trade_logger::TradeLogger) records that carry the
+//! submitted price and requested size.
+//!
+//! Both models share one design vector:
+//! `[1, spread (cents), size / depth, ln(1 + shares), latency (100ms)]`.
+//! - Fill probability: logistic regression on the filled fraction (IRLS)
+//! - Slippage: least squares on `(fill - submitted) / submitted` over fills
+//!
+//! Missing features are imputed with the training mean, so a call site that
+//! only knows the book (pre-trade TCA) still gets a calibrated estimate.
+//! Coefficients persist as JSON next to the trade log.
+
+use std::path::Path;
+
+use chrono::{DateTime, Utc};
+use rust_decimal::prelude::ToPrimitive;
+use serde::{Deserialize, Serialize};
+
+use super::trade_logger::{TradeOutcome, TradeRecord};
+use crate::error::{PloyError, Result};
+
+const NUM_FEATURES: usize = 4;
+const RIDGE: f64 = 1e-3;
+const MAX_IRLS_ITERATIONS: usize = 50;
+
+/// Inputs to the fill model; `None` falls back to the training mean
+#[derive(Debug, Clone, Copy, Default, PartialEq, Serialize, Deserialize)]
+pub struct FillFeatures {
+    /// Quoted bid-ask spread in price units (0.02 = 2¢)
+    pub spread: Option<f64>,
+    /// Order size over displayed depth at the touch
+    pub depth_ratio: Option<f64>,
+    /// Order size in shares
+    pub shares: Option<f64>,
+    /// Signal-to-exchange latency in milliseconds
+    pub latency_ms: Option<f64>,
+}
+
+impl FillFeatures {
+    fn raw(&self) -> [Option<f64>; NUM_FEATURES] {
+        [
+            self.spread.map(|s| s * 100.0),
+            self.depth_ratio.map(|d| d.clamp(0.0, 10.0)),
+            self.shares.map(|s| s.max(0.0).ln_1p()),
+            self.latency_ms.map(|l| l.max(0.0) / 100.0),
+        ]
+    }
+}
+
+/// One submitted order and what came back
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct FillObservation {
+    pub features: FillFeatures,
+    /// Filled shares over requested shares (0.0 - 1.0)
+    pub fill_fraction: f64,
+    /// Adverse slippage relative to the submitted price (None if unfilled)
+    pub slippage_pct: Option<f64>,
+}
+
+impl FillObservation {
+    /// Build from a logged trade; `None` if the submitted price was not logged.
+    ///
+    /// Logged entries are buys, so paying above the submitted price is
+    /// adverse. Cancelled entries count as unfilled.
+    pub fn from_trade(trade: &TradeRecord) -> Option<Self> {
+        let ctx = &trade.context;
+        let submitted = ctx.submitted_price?.to_f64()?;
+        if submitted <= 0.0 {
+            return None;
+        }
+        let requested = ctx.requested_shares.unwrap_or(trade.shares);
+        if requested == 0 {
+            return None;
+        }
+        let filled = if trade.outcome == TradeOutcome::Cancelled {
+            0
+        } else {
+            trade.shares.min(requested)
+        };
+        let spread = match (ctx.bid_price, ctx.ask_price) {
+            (Some(bid), Some(ask)) if ask >= bid => (ask - bid).to_f64(),
+            _ => ctx.spread_cents.and_then(|c| c.to_f64()).map(|c| c / 100.0),
+        };
+        let depth_ratio = ctx
+            .ask_depth
+            .filter(|d| *d > 0)
+            .map(|d| requested as f64 / d as f64);
+        let slippage_pct = if filled > 0 {
+            trade
+                .entry_price
+                .to_f64()
+                .map(|fill| (fill - submitted) / submitted)
+        } else {
+            None
+        };
+
+        Some(Self {
+            features: FillFeatures {
+                spread,
+                depth_ratio,
+                shares: Some(requested as f64),
+                latency_ms: ctx.fill_latency_ms.map(|l| l as f64),
+            },
+            fill_fraction: filled as f64 / requested as f64,
+            slippage_pct,
+        })
+    }
+}
+
+/// Fitted fill-probability and slippage coefficients
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct FillModel {
+    /// Logistic coefficients (intercept first)
+    pub fill_coefficients: Vec<f64>,
+    /// Linear slippage coefficients (intercept first)
+    pub slippage_coefficients: Vec<f64>,
+    /// Training means used to impute missing features
+    pub feature_means: Vec<f64>,
+    /// Observations used for the fill-probability fit
+    pub samples: usize,
+    /// Filled observations used for the slippage fit
+    pub filled_samples: usize,
+    pub fitted_at: DateTime<Utc>,
+}
+
+impl FillModel {
+    /// Default location, next to `data/trades.json`
+    pub const DEFAULT_PATH: &'static str = "data/fill_model.json";
+    /// Fewer observations than this are rejected by [`FillModel::fit`]
+    pub const MIN_SAMPLES: usize = 20;
+
+    /// Fit both models from logged fills
+    pub fn fit(observations: &[FillObservation]) -> Result<Self> {
+        if observations.len() < Self::MIN_SAMPLES {
+            return Err(PloyError::Validation(format!(
+                "need at least {} fill observations, got {}",
+                Self::MIN_SAMPLES,
+                observations.len()
+            )));
+        }
+
+        let mut feature_means = vec![0.0; NUM_FEATURES];
+        for (i, mean) in feature_means.iter_mut().enumerate() {
+            let values: Vec<f64> = observations
+                .iter()
+                .filter_map(|o| o.features.raw()[i])
+                .collect();
+            if !values.is_empty() {
+                *mean = values.iter().sum::<f64>() / values.len() as f64;
+            }
+        }
+
+        let mut model = Self {
+            fill_coefficients: vec![0.0; NUM_FEATURES + 1],
+            slippage_coefficients: vec![0.0; NUM_FEATURES + 1],
+            feature_means,
+            samples: observations.len(),
+            filled_samples: 0,
+            fitted_at: Utc::now(),
+        };
+
+        let rows: Vec<Vec<f64>> = observations
+            .iter()
+            .map(|o| model.design_row(&o.features))
+            .collect();
+        let fractions: Vec<f64> = observations
+            .iter()
+            .map(|o| o.fill_fraction.clamp(0.0, 1.0))
+            .collect();
+        model.fill_coefficients = fit_logistic(&rows, &fractions)?;
+
+        let (filled_rows, slippages): (Vec<Vec<f64>>, Vec<f64>) = observations
+            .iter()
+            .zip(&rows)
+            .filter_map(|(o, row)| o.slippage_pct.map(|s| (row.clone(), s)))
+            .unzip();
+        model.filled_samples = filled_rows.len();
+        if !filled_rows.is_empty() {
+            model.slippage_coefficients = fit_linear(&filled_rows, &slippages)?;
+        }
+
+        Ok(model)
+    }
+
+    /// Fit from the trade log; trades without a submitted price are skipped
+    pub fn fit_trades(trades: &[TradeRecord]) -> Result<Self> {
+        let observations: Vec<FillObservation> = trades
+            .iter()
+            .filter_map(FillObservation::from_trade)
+            .collect();
+        Self::fit(&observations)
+    }
+
+    /// Expected filled fraction of the order (0.0 - 1.0)
+    pub fn fill_probability(&self, features: &FillFeatures) -> f64 {
+        sigmoid(dot(&self.fill_coefficients, &self.design_row(features)))
+    }
+
+    /// Expected adverse slippage as a fraction of the submitted price (>= 0)
+    pub fn expected_slippage_pct(&self, features: &FillFeatures) -> f64 {
+        dot(&self.slippage_coefficients, &self.design_row(features)).max(0.0)
+    }
+
+    fn design_row(&self, features: &FillFeatures) -> Vec<f64> {
+        let mut row = Vec::with_capacity(NUM_FEATURES + 1);
+        row.push(1.0);
+        for (i, value) in features.raw().iter().enumerate() {
+            row.push(value.unwrap_or_else(|| self.feature_means.get(i).copied().unwrap_or(0.0)));
+        }
+        row
+    }
+
+    pub fn load(path: &Path) -> Result<Self> {
+        let model: Self = serde_json::from_str(&std::fs::read_to_string(path)?)?;
+        if model.fill_coefficients.len() != NUM_FEATURES + 1
+            || model.slippage_coefficients.len() != NUM_FEATURES + 1
+        {
+            return Err(PloyError::Validation(format!(
+                "fill model at {} has wrong coefficient count",
+                path.display()
+            )));
+        }
+        Ok(model)
+    }
+
+    /// Load from [`FillModel::DEFAULT_PATH`] if a model has been fitted
+    pub fn load_default() -> Option<Self> {
+        let path = Path::new(Self::DEFAULT_PATH);
+        if !path.exists() {
+            return None;
+        }
+        match Self::load(path) {
+            Ok(model) => Some(model),
+            Err(e) => {
+                tracing::warn!("Ignoring fill model at {}: {}", path.display(), e);
+                None
+            }
+        }
+    }
+
+    pub fn save(&self, path: &Path) -> Result<()> {
+        if let Some(parent) = path.parent() {
+            std::fs::create_dir_all(parent)?;
+        }
+        std::fs::write(path, serde_json::to_string_pretty(self)?)?;
+        Ok(())
+    }
+}
+
+fn dot(a: &[f64], b: &[f64]) -> f64 {
+    a.iter().zip(b).map(|(x, y)| x * y).sum()
+}
+
+fn sigmoid(z: f64) -> f64 {
+    1.0 / (1.0 + (-z).exp())
+}
+
+/// Logistic regression on fractional targets via ridge-damped IRLS
+fn fit_logistic(rows: &[Vec<f64>], targets: &[f64]) -> Result<Vec<f64>> {
+    let k = NUM_FEATURES + 1;
+    let mut beta = vec![0.0; k];
+    for _ in 0..MAX_IRLS_ITERATIONS {
+        let mut hessian = vec![vec![0.0; k]; k];
+        let mut gradient = vec![0.0; k];
+        for (row, y) in rows.iter().zip(targets) {
+            let p = sigmoid(dot(&beta, row));
+            let w = (p * (1.0 - p)).max(1e-6);
+            for (i, xi) in row.iter().enumerate() {
+                gradient[i] += (y - p) * xi;
+                for (j, xj) in row.iter().enumerate() {
+                    hessian[i][j] += w * xi * xj;
+                }
+            }
+        }
+        for (i, (g, b)) in gradient.iter_mut().zip(&beta).enumerate().skip(1) {
+            *g -= RIDGE * b;
+            hessian[i][i] += RIDGE;
+        }
+        let step = solve(hessian, gradient)?;
+        for (b, s) in beta.iter_mut().zip(&step) {
+            *b += s;
+        }
+        if step.iter().all(|s| s.abs() < 1e-8) {
+            break;
+        }
+    }
+    Ok(beta)
+}
+
+/// Ridge least squares via the normal equations
+fn fit_linear(rows: &[Vec<f64>], targets: &[f64]) -> Result<Vec<f64>> {
+    let k = NUM_FEATURES + 1;
+    let mut xtx = vec![vec![0.0; k]; k];
+    let mut xty = vec![0.0; k];
+    for (row, y) in rows.iter().zip(targets) {
+        for (i, xi) in row.iter().enumerate() {
+            xty[i] += xi * y;
+            for (j, xj) in row.iter().enumerate() {
+                xtx[i][j] += xi * xj;
+            }
+        }
+    }
+    for (i, r) in xtx.iter_mut().enumerate().skip(1) {
+        r[i] += RIDGE;
+    }
+    solve(xtx, xty)
+}
+
+/// Gaussian elimination with partial pivoting
+fn solve(mut a: Vec<Vec<f64>>, mut b: Vec<f64>) -> Result<Vec<f64>> {
+    let n = b.len();
+    for col in 0..n {
+        let pivot = (col..n)
+            .max_by(|&i, &j| a[i][col].abs().total_cmp(&a[j][col].abs()))
+            .unwrap_or(col);
+        if a[pivot][col].abs() < 1e-12 {
+            return Err(PloyError::Validation(
+                "fill model fit is singular; need more varied observations".to_string(),
+            ));
+        }
+        a.swap(col, pivot);
+        b.swap(col, pivot);
+        let (upper, lower) = a.split_at_mut(col + 1);
+        let pivot_row = &upper[col];
+        let (b_upper, b_lower) = b.split_at_mut(col + 1);
+        for (row, rhs) in lower.iter_mut().zip(b_lower.iter_mut()) {
+            let factor = row[col] / pivot_row[col];
+            for (x, p) in row[col..].iter_mut().zip(&pivot_row[col..]) {
+                *x -= factor * p;
+            }
+            *rhs -= factor * b_upper[col];
+        }
+    }
+    let mut x = vec![0.0; n];
+    for row in (0..n).rev() {
+        let tail: f64 = a[row][row + 1..]
+            .iter()
+            .zip(&x[row + 1..])
+            .map(|(a, x)| a * x)
+            .sum();
+        x[row] = (b[row] - tail) / a[row][row];
+    }
+    Ok(x)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn observation(spread: f64, depth_ratio: f64, latency_ms: f64, i: usize) -> FillObservation {
+        // Fills get worse with spread, size and latency
+        let fill_fraction = (1.0 - 0.6 * depth_ratio - 0.001 * latency_ms).clamp(0.05, 1.0);
+        let noise = ((i * 37) % 11) as f64 * 1e-4;
+        FillObservation {
+            features: FillFeatures {
+                spread: Some(spread),
+                depth_ratio: Some(depth_ratio),
+                shares: Some(50.0 + 10.0 * (i % 7) as f64),
+                latency_ms: Some(latency_ms),
+            },
+            fill_fraction,
+            slippage_pct: Some(0.2 * spread + 0.01 * depth_ratio + noise),
+        }
+    }
+
+    fn synthetic() -> Vec<FillObservation> {
+        (0..200)
+            .map(|i| {
+                let spread = 0.01 + 0.01 * (i % 5) as f64;
+                let depth_ratio = 0.1 * (i % 9) as f64;
+                let latency_ms = 50.0 + 50.0 * (i % 6) as f64;
+                observation(spread, depth_ratio, latency_ms, i)
+            })
+            .collect()
+    }
+
+    #[test]
+    fn test_fit_recovers_directional_effects() {
+        let model = FillModel::fit(&synthetic()).unwrap();
+        assert_eq!(model.samples, 200);
+        assert_eq!(model.filled_samples, 200);
+
+        let small = FillFeatures {
+            spread: Some(0.01),
+            depth_ratio: Some(0.1),
+            shares: Some(60.0),
+            latency_ms: Some(50.0),
+        };
+        let large = FillFeatures {
+            spread: Some(0.05),
+            depth_ratio: Some(0.8),
+            latency_ms: Some(300.0),
+            ..small
+        };
+        assert!(model.fill_probability(&small) > model.fill_probability(&large) + 0.3);
+        assert!(model.expected_slippage_pct(&large) > model.expected_slippage_pct(&small));
+        // Slippage is close to the generating 0.2 * spread + 0.01 * depth_ratio
+        assert!((model.expected_slippage_pct(&small) - 0.003).abs() < 0.002);
+
+        // Missing features impute to the training mean
+        let partial = FillFeatures {
+            spread: Some(0.01),
+            ..Default::default()
+        };
+        let p = model.fill_probability(&partial);
+        assert!(p > 0.0 && p < 1.0);
+    }
+
+    #[test]
+    fn test_too_few_samples_and_json_round_trip() {
+        assert!(FillModel::fit(&synthetic()[..5]).is_err());
+
+        let model = FillModel::fit(&synthetic()).unwrap();
+        let path =
+            std::env::temp_dir().join(format!("ploy_fill_model_{}.json", std::process::id()));
+        model.save(&path).unwrap();
+        assert_eq!(FillModel::load(&path).unwrap(), model);
+        let _ = std::fs::remove_file(&path);
+    }
+}
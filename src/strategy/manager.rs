@@ -3,6 +3,7 @@
 //! Manages the lifecycle of trading strategies:
 //! - Start/stop strategies
 //! - Route market data and events
+//! - Gate order actions until each strategy has warmed up
 //! - Track running strategies
 //! - Provide status information
 
@@ -16,6 +17,7 @@ use tracing::{debug, error, info};
 use super::traits::{
     MarketUpdate, OrderUpdate, PositionInfo, Strategy, StrategyAction, StrategyStateInfo,
 };
+use super::warmup::{WarmupConfig, WarmupStatus, WarmupTracker};
 use crate::error::Result;
 use anyhow::anyhow;
 
@@ -39,6 +41,8 @@ pub struct StrategyManager {
     tick_interval_ms: u64,
     /// Shutdown signal
     shutdown_tx: broadcast::Sender<()>,
+    /// Warmup applied by `start_strategy`
+    default_warmup: WarmupConfig,
 }
 
 /// A running strategy instance with its task handle
@@ -51,6 +55,8 @@ struct RunningStrategy {
     started_at: DateTime<Utc>,
     /// Configuration used to start the strategy
     config_path: Option<String>,
+    /// Cold-start warmup gate (shared with the task)
+    warmup: Arc<RwLock<WarmupTracker>>,
 }
 
 impl StrategyManager {
@@ -69,19 +75,37 @@ impl StrategyManager {
             action_rx: Arc::new(RwLock::new(Some(action_rx))),
             tick_interval_ms,
             shutdown_tx,
+            default_warmup: WarmupConfig::default(),
         }
     }
 
+    /// Set the warmup used when a strategy is started without its own
+    pub fn with_warmup(mut self, warmup: WarmupConfig) -> Self {
+        self.default_warmup = warmup;
+        self
+    }
+
     /// Take the action receiver (can only be called once)
     pub async fn take_action_receiver(&self) -> Option<mpsc::Receiver<(String, StrategyAction)>> {
         self.action_rx.write().await.take()
     }
 
-    /// Start a strategy
+    /// Start a strategy with the manager's default warmup
     pub async fn start_strategy(
         &self,
         strategy: Box<dyn Strategy>,
         config_path: Option<String>,
+    ) -> Result<()> {
+        self.start_strategy_with_warmup(strategy, config_path, self.default_warmup.clone())
+            .await
+    }
+
+    /// Start a strategy with its own warmup config
+    pub async fn start_strategy_with_warmup(
+        &self,
+        strategy: Box<dyn Strategy>,
+        config_path: Option<String>,
+        warmup: WarmupConfig,
     ) -> Result<()> {
         let strategy_id = strategy.id().to_string();
         let strategy_name = strategy.name().to_string();
@@ -108,9 +132,21 @@ impl StrategyManager {
             debug!("Strategy {} subscribed to feed: {:?}", strategy_id, feed);
         }
 
+        if warmup.enabled {
+            info!(
+                "Strategy {} warming up (min {} ticks, {}s)",
+                strategy_id, warmup.min_ticks, warmup.min_secs
+            );
+        }
+        let warmup = Arc::new(RwLock::new(WarmupTracker::new(
+            warmup,
+            &required_feeds,
+            Utc::now(),
+        )));
+
         // Create the background task for this strategy
         let task_handle = self
-            .spawn_strategy_task(strategy_id.clone(), strategy.clone())
+            .spawn_strategy_task(strategy_id.clone(), strategy.clone(), warmup.clone())
             .await;
 
         // Store the running strategy
@@ -123,6 +159,7 @@ impl StrategyManager {
                     task_handle: Some(task_handle),
                     started_at: Utc::now(),
                     config_path,
+                    warmup,
                 },
             );
         }
@@ -199,6 +236,7 @@ impl StrategyManager {
                 position_count: positions.len(),
                 started_at: running.started_at,
                 config_path: running.config_path.clone(),
+                warmup: running.warmup.read().await.status(Utc::now()),
             });
         }
 
@@ -221,6 +259,7 @@ impl StrategyManager {
                 position_count: positions.len(),
                 started_at: running.started_at,
                 config_path: running.config_path.clone(),
+                warmup: running.warmup.read().await.status(Utc::now()),
             })
         } else {
             None
@@ -266,6 +305,7 @@ impl StrategyManager {
         &self,
         strategy_id: String,
         strategy: Arc<RwLock<Box<dyn Strategy>>>,
+        warmup: Arc<RwLock<WarmupTracker>>,
     ) -> JoinHandle<()> {
         let mut market_rx = self.market_tx.subscribe();
         let mut order_rx = self.order_tx.subscribe();
@@ -280,6 +320,7 @@ impl StrategyManager {
                 tokio::select! {
                     // Handle market updates
                     Ok(update) = market_rx.recv() => {
+                        warmup.write().await.observe(&update);
                         let actions = {
                             let mut strategy = strategy.write().await;
                             match strategy.on_market_update(&update).await {
@@ -291,6 +332,7 @@ impl StrategyManager {
                             }
                         };
 
+                        let actions = gate_warmup(&strategy_id, &warmup, actions).await;
                         for action in actions {
                             let _ = action_tx.send((strategy_id.clone(), action)).await;
                         }
@@ -309,6 +351,7 @@ impl StrategyManager {
                             }
                         };
 
+                        let actions = gate_warmup(&strategy_id, &warmup, actions).await;
                         for action in actions {
                             let _ = action_tx.send((strategy_id.clone(), action)).await;
                         }
@@ -327,6 +370,7 @@ impl StrategyManager {
                             }
                         };
 
+                        let actions = gate_warmup(&strategy_id, &warmup, actions).await;
                         for action in actions {
                             let _ = action_tx.send((strategy_id.clone(), action)).await;
                         }
@@ -343,6 +387,30 @@ impl StrategyManager {
     }
 }
 
+/// Filter a warming strategy's actions, logging when warmup completes.
+async fn gate_warmup(
+    strategy_id: &str,
+    warmup: &RwLock<WarmupTracker>,
+    actions: Vec<StrategyAction>,
+) -> Vec<StrategyAction> {
+    let now = Utc::now();
+    let mut tracker = warmup.write().await;
+    let was_warm = tracker.is_warm();
+    let emitted = actions.len();
+    let actions = tracker.gate(actions, now);
+    if !was_warm && tracker.is_warm() {
+        info!("Strategy {} warmed up, trading enabled", strategy_id);
+    } else if actions.len() < emitted {
+        debug!(
+            "Strategy {} warming up: dropped {} order actions ({})",
+            strategy_id,
+            emitted - actions.len(),
+            tracker.status(now).summary()
+        );
+    }
+    actions
+}
+
 // ============================================================================
 // Strategy Status
 // ============================================================================
@@ -362,6 +430,8 @@ pub struct StrategyStatus {
     pub started_at: DateTime<Utc>,
     /// Config file path (if started from config)
     pub config_path: Option<String>,
+    /// Cold-start warmup progress
+    pub warmup: WarmupStatus,
 }
 
 impl StrategyStatus {
@@ -392,6 +462,16 @@ pub struct StrategyFactory;
 impl StrategyFactory {
     /// Create a strategy from a TOML configuration string
     pub fn from_toml(config_content: &str, dry_run: bool) -> Result<Box<dyn Strategy>> {
+        Self::from_toml_tagged(config_content, dry_run, None)
+    }
+
+    /// Create a strategy whose id carries `tag`, so several instances of the
+    /// same strategy (e.g. A/B variants) can run in one manager
+    pub fn from_toml_tagged(
+        config_content: &str,
+        dry_run: bool,
+        tag: Option<&str>,
+    ) -> Result<Box<dyn Strategy>> {
         use toml::Value;
 
         let config: Value =
@@ -406,7 +486,11 @@ impl StrategyFactory {
             .and_then(|v| v.as_str())
             .ok_or_else(|| anyhow!("Missing strategy.name"))?;
 
-        let strategy_id = format!("{}_{}", strategy_name, chrono::Utc::now().timestamp());
+        let timestamp = chrono::Utc::now().timestamp();
+        let strategy_id = match tag {
+            Some(tag) => format!("{}_{}_{}", strategy_name, tag, timestamp),
+            None => format!("{}_{}", strategy_name, timestamp),
+        };
 
         match strategy_name {
             "momentum" => {
@@ -433,10 +517,41 @@ impl StrategyFactory {
                 )?;
                 Ok(Box::new(strat))
             }
+            "calendar_arb" => {
+                let strat = super::calendar_arb::CalendarArbStrategy::from_toml(
+                    strategy_id,
+                    config_content,
+                    dry_run,
+                )?;
+                Ok(Box::new(strat))
+            }
+            "settlement_sniper" => {
+                let strat = super::settlement_sniper::SettlementSniperStrategy::from_toml(
+                    strategy_id,
+                    config_content,
+                    dry_run,
+                )?;
+                strat.spawn_venue_feeds();
+                Ok(Box::new(strat))
+            }
             other => Err(anyhow!("Unknown strategy type: {}", other).into()),
         }
     }
 
+    /// Read the optional `[warmup]` section of a strategy config
+    pub fn warmup_from_toml(config_content: &str) -> Result<WarmupConfig> {
+        let config: toml::Value =
+            toml::from_str(config_content).map_err(|e| anyhow!("Invalid TOML: {}", e))?;
+
+        match config.get("warmup") {
+            Some(section) => Ok(section
+                .clone()
+                .try_into()
+                .map_err(|e| anyhow!("Invalid [warmup] section: {}", e))?),
+            None => Ok(WarmupConfig::default()),
+        }
+    }
+
     /// Get list of available strategy types
     pub fn available_strategies() -> Vec<StrategyInfo> {
         vec![
@@ -455,6 +570,17 @@ impl StrategyFactory {
                 description: "Associative memory on Binance klines for 5m UP/DOWN".to_string(),
                 config_template: "pattern_memory_default.toml".to_string(),
             },
+            StrategyInfo {
+                name: "calendar_arb".to_string(),
+                description: "Co-terminal arbitrage across concurrent round horizons".to_string(),
+                config_template: "calendar_arb_default.toml".to_string(),
+            },
+            StrategyInfo {
+                name: "settlement_sniper".to_string(),
+                description: "Final-seconds entries backed by a multi-venue price quorum"
+                    .to_string(),
+                config_template: "settlement_sniper_default.toml".to_string(),
+            },
         ]
     }
 }
@@ -490,4 +616,18 @@ mod tests {
         assert!(!strategies.is_empty());
         assert!(strategies.iter().any(|s| s.name == "momentum"));
     }
+
+    #[test]
+    fn test_warmup_from_toml() {
+        let warmup = StrategyFactory::warmup_from_toml(
+            "[strategy]\nname = \"momentum\"\n\n[warmup]\nmin_ticks = 5\nrequired_feeds = [\"binance_spot\"]\n",
+        )
+        .unwrap();
+        assert_eq!(warmup.min_ticks, 5);
+        assert_eq!(warmup.min_secs, WarmupConfig::default().min_secs);
+
+        let default =
+            StrategyFactory::warmup_from_toml("[strategy]\nname = \"momentum\"\n").unwrap();
+        assert!(default.enabled && default.required_feeds.is_none());
+    }
 }
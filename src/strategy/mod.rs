@@ -23,31 +23,41 @@
 // Strategy trait and core types
 // =============================================================================
 
+pub mod ab_test;
 pub mod adapters;
 pub mod event_edge;
 pub mod event_models;
 pub mod feeds;
 pub mod manager;
 pub mod registry;
+pub mod round_calendar;
 pub mod traits;
+pub mod warmup;
 
 pub use traits::{
     AlertLevel, DataFeed, MarketUpdate, OrderUpdate, PositionInfo, RiskLevel, Strategy,
     StrategyAction, StrategyConfig, StrategyEvent, StrategyEventType, StrategyStateInfo,
 };
 
+pub use ab_test::{AbExperimentConfig, AbReport, AbTracker};
 pub use adapters::{MomentumStrategyAdapter, SplitArbStrategyAdapter};
 pub use feeds::{DataFeedBuilder, DataFeedManager};
 pub use manager::{StrategyFactory, StrategyInfo, StrategyManager, StrategyStatus};
+pub use round_calendar::{
+    RoundCalendar, RoundCalendarConfig, RoundEntry, RoundPhase, RoundTransition,
+};
+pub use warmup::{WarmupConfig, WarmupFeed, WarmupStatus, WarmupTracker};
 
 // =============================================================================
 // New modular architecture
 // =============================================================================
 
+pub mod calendar_arb;
 pub mod core;
 pub mod crypto;
 pub mod nba_comeback;
 pub mod pattern_memory;
+pub mod politics;
 pub mod sports;
 
 // =============================================================================
@@ -55,8 +65,10 @@ pub mod sports;
 // =============================================================================
 
 pub mod backtest;
+pub mod backtest_diff;
 pub mod backtest_feed;
 pub mod calculations;
+pub mod capacity;
 pub mod claimer;
 pub mod dump_hedge;
 pub mod execution;
@@ -71,22 +83,37 @@ pub mod paper_runner;
 pub mod parquet_analysis;
 pub mod position_manager;
 pub mod reconciliation;
+pub mod research_report;
 pub mod risk_mgmt;
+pub mod settlement_sniper;
 pub mod signal;
+pub mod simulate;
 pub mod split_arb;
+pub mod split_merge_executor;
+pub mod timeseries;
+pub mod trade_journal;
 pub mod trade_logger;
 pub mod trading_costs;
 pub mod volatility;
 pub mod volatility_arb;
 pub mod directional_backtest;
 pub mod fee_model;
+pub mod fill_model;
 pub mod probability;
 
 // Runtime re-exports
 pub use claimer::{AutoClaimer, ClaimResult, ClaimerConfig, RedeemablePosition};
+pub use execution::bracket::{Bracket, BracketManager, BracketState};
+pub use execution::conditional::{
+    ConditionalExecution, ConditionalOrder, ConditionalOrderManager, ConditionalOrderState,
+    TriggerCondition,
+};
 pub use execution::engine::StrategyEngine;
 pub use execution::engine_store;
 pub use execution::executor::OrderExecutor;
+pub use execution::fillability::{
+    FillabilityConfig, FillabilityGate, FillabilityStats, FillabilityTracker,
+};
 pub use execution::fund_manager::{FundManager, FundStatus, PositionSizeResult};
 pub use execution::idempotency::{IdempotencyManager, IdempotencyResult};
 
@@ -102,10 +129,13 @@ pub use multi_outcome::{
     detect_split_merge_opportunity,
     // Core types
     fetch_multi_outcome_event,
+    fetch_outcome_books,
     generate_ev_table,
     ArbitrageType,
     // EV analysis
     ExpectedValue,
+    FairValueBand,
+    FairValueBands,
     MarketMakingAction,
     // Market making
     MarketMakingConfig,
@@ -122,6 +152,10 @@ pub use multi_outcome::{
     SplitMergeType,
     POLYMARKET_FEE_RATE,
 };
+pub use timeseries::{RingWindow, SeriesKind, SeriesStats, TimeSeriesCache, TimeSeriesConfig};
+pub use trade_journal::{
+    AnnotatedTrade, JournalEntry, JournalNote, JournalQuery, JournalUpdate, TradeJournal,
+};
 pub use trade_logger::{
     BucketStats, SymbolStats, TradeContext, TradeLogger, TradeOutcome, TradeRecord, TradingStats,
 };
@@ -137,7 +171,9 @@ pub use dump_hedge::{
 };
 pub use event_edge::core::{EventEdgeCore, EventEdgeState, TradeDecision};
 pub use event_edge::{run_event_edge, EventEdgeConfig};
-pub use execution_sim::{ExecutionResult, ExecutionSimConfig, ExecutionSimulator};
+pub use execution_sim::{
+    AdverseSelectionConfig, ExecutionResult, ExecutionSimConfig, ExecutionSimulator, FillLogSample,
+};
 pub use momentum::{
     Direction, EventInfo, EventMatcher, ExitConfig, ExitManager, ExitReason, MomentumConfig,
     MomentumDetector, MomentumEngine, MomentumSignal, Position,
@@ -161,12 +197,12 @@ pub use nba_comeback::nba_winprob::{
 };
 pub use paper_runner::{run_paper_trading, PaperTradingConfig, PaperTradingRunner, TrackedMarket};
 pub use position_manager::{
-    Position as PersistedPosition, PositionManager, PositionStatus as PersistedPositionStatus,
-    PositionSummary,
+    Position as PersistedPosition, PositionManager, PositionQuery,
+    PositionStatus as PersistedPositionStatus, PositionSummary,
 };
 pub use reconciliation::{
-    DiscrepancySeverity, PositionDiscrepancy, ReconciliationConfig, ReconciliationResult,
-    ReconciliationService,
+    classify_onchain, DiscrepancySeverity, OnchainDiscrepancy, OnchainDiscrepancyKind,
+    PositionDiscrepancy, ReconciliationConfig, ReconciliationResult, ReconciliationService,
 };
 pub use registry::{EventFilter, EventStatus, EventUpsertRequest, RegisteredEvent};
 pub use risk_mgmt::risk::RiskManager;
@@ -190,6 +226,7 @@ pub use volatility_arb::{
 };
 pub use probability::{estimate_probability, full_estimate, Features, ProbabilityEstimate};
 pub use fee_model::{AllInCost, FeeModel, FeeRateCache};
+pub use fill_model::{FillFeatures, FillModel, FillObservation};
 pub use directional_backtest::{DirectionalBacktestConfig, DirectionalBacktestEngine, DirectionalClosedTrade};
 
 // New consolidated modules
@@ -198,9 +235,9 @@ pub use calculations::{
     DEFAULT_SLIPPAGE, MIN_PROFIT_TARGET, POLYMARKET_FEE_RATE as CALC_FEE_RATE,
 };
 pub use risk_mgmt::validation::{
-    leg1_entry_chain, leg2_entry_chain, ExposureValidator, RiskStateValidator, SpreadValidator,
-    SumTargetValidator, TimeRemainingValidator, ValidationChain, ValidationContext,
-    ValidationError, Validator,
+    leg1_entry_chain, leg2_entry_chain, ExposureValidator, FillabilityValidator,
+    RiskStateValidator, SpreadValidator, SumTargetValidator, TimeRemainingValidator,
+    ValidationChain, ValidationContext, ValidationError, Validator, ValidatorOutcome,
 };
 
 // Backward-compat module aliases for risk/slippage/validation
@@ -224,4 +261,12 @@ pub use core::{
 pub use crypto::{run_crypto_split_arb, CryptoMarketDiscovery, CryptoSplitArbConfig};
 
 // Sports strategies
-pub use sports::{run_sports_split_arb, SportsLeague, SportsMarketDiscovery, SportsSplitArbConfig};
+pub use sports::{
+    run_sports_split_arb, SportsLeague, SportsMarketClass, SportsMarketDiscovery,
+    SportsSplitArbConfig,
+};
+
+// Politics strategies
+pub use politics::{
+    PoliticalMarketClass, PoliticsMarketDiscovery, PoliticsSplitArbAgent, PoliticsSplitArbConfig,
+};
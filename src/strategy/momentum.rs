@@ -25,6 +25,7 @@ use crate::adapters::{
 use crate::config::RiskConfig;
 use crate::domain::{OrderRequest, Side};
 use crate::error::Result;
+use crate::platform::Timeframe;
 use crate::strategy::dump_hedge::{DumpHedgeConfig, DumpHedgeEngine};
 use crate::strategy::fee_model::FeeModel;
 use crate::strategy::fund_manager::{FundManager, PositionSizeResult};
@@ -299,6 +300,11 @@ impl EventInfo {
         self.end_time - Utc::now()
     }
 
+    /// Round length in seconds (5m / 15m / 1h / 1d)
+    pub fn window_secs(&self) -> i64 {
+        (self.end_time - self.start_time).num_seconds().max(0)
+    }
+
     /// Time elapsed since the event start (seconds, clamped to >= 0).
     pub fn seconds_since_start(&self) -> i64 {
         Utc::now()
@@ -387,20 +393,10 @@ impl EventMatcher {
         }
     }
 
-    fn horizon_for_series(series_id: &str) -> &'static str {
-        match series_id {
-            "10684" | "10683" | "10686" | "10685" => "5m",
-            "10192" | "10191" | "10423" | "10422" => "15m",
-            _ => "other",
-        }
-    }
-
     fn window_secs_for_horizon(horizon: &str) -> i64 {
-        match horizon {
-            "15m" => 15 * 60,
-            "5m" => 5 * 60,
-            _ => 5 * 60,
-        }
+        Timeframe::from_hint(horizon)
+            .and_then(|tf| tf.duration_secs())
+            .unwrap_or(5 * 60)
     }
 
     /// Find the best event for a symbol
@@ -682,14 +678,23 @@ impl EventMatcher {
                 None => continue,
             };
 
-            let horizon = Self::horizon_for_series(series_id).to_string();
-            let window_secs = Self::window_secs_for_horizon(&horizon);
-            let start_time = event_details
+            let explicit_start = event_details
                 .start_time
                 .as_ref()
                 .and_then(|s| DateTime::parse_from_rfc3339(s).ok())
-                .map(|dt| dt.with_timezone(&Utc))
-                .unwrap_or_else(|| end_time - ChronoDuration::seconds(window_secs));
+                .map(|dt| dt.with_timezone(&Utc));
+            // Known series ids first, then the slug, then the event's own start/end.
+            let horizon = Timeframe::from_hint(series_id)
+                .or_else(|| event_details.slug.as_deref().and_then(Timeframe::from_hint))
+                .or_else(|| {
+                    explicit_start
+                        .and_then(|s| Timeframe::from_duration_secs((end_time - s).num_seconds()))
+                })
+                .map(|tf| tf.as_str().to_string())
+                .unwrap_or_else(|| "other".to_string());
+            let window_secs = Self::window_secs_for_horizon(&horizon);
+            let start_time =
+                explicit_start.unwrap_or_else(|| end_time - ChronoDuration::seconds(window_secs));
 
             // Parse price_to_beat from market title (e.g., "Will BTC be above $94,000?")
             let price_to_beat = EventInfo::parse_price_from_question(
@@ -1455,6 +1460,8 @@ pub struct Position {
     pub entry_p_hat: Option<f64>,
     /// Chainlink open price (S0) at window start
     pub window_open_price: Option<Decimal>,
+    /// Round length of the traded event (seconds)
+    pub window_secs: i64,
 }
 
 impl Position {
@@ -1565,9 +1572,11 @@ impl ExitManager {
             }
         }
 
-        // 4. Time-based exit before resolution
+        // 4. Time-based exit before resolution (lead time stretches for 1h / 1d rounds)
         let time_to_resolution = pos.time_to_resolution();
-        if time_to_resolution.num_seconds() < self.config.exit_before_resolution_secs as i64 {
+        let exit_lead_secs = self.config.exit_before_resolution_secs as f64
+            * Timeframe::lead_time_scale(pos.window_secs);
+        if (time_to_resolution.num_seconds() as f64) < exit_lead_secs {
             return Some(ExitReason::TimeExit);
         }
 
@@ -1784,8 +1793,10 @@ impl MomentumEngine {
             max_time_remaining_secs: config.max_time_remaining_secs,
             ..Default::default()
         };
+        // Keep 20 historical events, priced the same way as the detector's own
+        let event_tracker =
+            EventTracker::new(20).with_mid_estimator(volatility_config.mid_estimator.clone());
         let volatility_detector = VolatilityDetector::new(volatility_config);
-        let event_tracker = EventTracker::new(20); // Keep 20 historical events
 
         Self {
             config,
@@ -2458,11 +2469,12 @@ impl MomentumEngine {
 
         // Also check for volatility signal (deviation from start price)
         {
-            // Get OBI from Binance LOB cache if available
-            let obi = if let Some(ref lob) = self.lob_cache {
-                lob.get_obi(symbol, 5).await // Use top 5 levels
-            } else {
-                None
+            // Get OBI from Binance LOB cache if available and trusted
+            let obi = match self.lob_cache {
+                Some(ref lob) if lob.trust(symbol).await.is_trusted() => {
+                    lob.get_obi(symbol, 5).await // Use top 5 levels
+                }
+                _ => None,
             };
 
             let tracker = self.event_tracker.read().await;
@@ -2839,6 +2851,7 @@ impl MomentumEngine {
                     let key = key.clone();
                     let direction = pos.direction;
                     let time_remaining = pos.time_to_resolution().num_seconds() as f64;
+                    let time_stop_secs = 30.0 * Timeframe::lead_time_scale(pos.window_secs);
 
                     // Map Binance symbol back to Chainlink
                     if let Some(cl_symbol) = crate::adapters::chainlink_rtds::to_chainlink_symbol(&pos.symbol) {
@@ -2874,8 +2887,8 @@ impl MomentumEngine {
                                 }
                             }
 
-                            // Time stop: < 30s remaining AND negative EV
-                            if time_remaining < 30.0 {
+                            // Time stop: < 30s remaining (scaled for long rounds) AND negative EV
+                            if time_remaining < time_stop_secs {
                                 let ask_f64 = update.quote.best_ask
                                     .and_then(|a| a.to_f64())
                                     .unwrap_or(0.5);
@@ -3193,6 +3206,7 @@ impl MomentumEngine {
                         condition_id: event.condition_id.clone(),
                         entry_p_hat: None,
                         window_open_price: None,
+                        window_secs: event.window_secs(),
                     };
 
                     let mut positions = self.positions.write().await;
@@ -3200,8 +3214,9 @@ impl MomentumEngine {
 
                     // Log trade entry
                     if let Some(ref logger) = self.trade_logger {
+                        let latency_ms = (Utc::now() - signal.timestamp).num_milliseconds();
                         logger
-                            .record_entry(
+                            .record_entry_with_context(
                                 &signal.symbol,
                                 &event.slug,
                                 &event.condition_id,
@@ -3210,6 +3225,11 @@ impl MomentumEngine {
                                 tracked_shares,
                                 signal.cex_move_pct,
                                 signal.edge,
+                                super::trade_logger::TradeContext::with_execution(
+                                    signal.pm_price,
+                                    shares_to_trade,
+                                    latency_ms.max(0) as u64,
+                                ),
                             )
                             .await;
                     }
@@ -3517,6 +3537,7 @@ impl MomentumEngine {
                         condition_id: event.condition_id.clone(),
                         entry_p_hat: None,
                         window_open_price: None,
+                        window_secs: event.window_secs(),
                     };
 
                     let mut positions = self.positions.write().await;
@@ -3524,8 +3545,9 @@ impl MomentumEngine {
 
                     // Log trade
                     if let Some(ref logger) = self.trade_logger {
+                        let latency_ms = (Utc::now() - signal.timestamp).num_milliseconds();
                         logger
-                            .record_entry(
+                            .record_entry_with_context(
                                 &signal.symbol,
                                 &event.slug,
                                 &event.condition_id,
@@ -3534,6 +3556,11 @@ impl MomentumEngine {
                                 tracked_shares,
                                 signal.cex_move_pct,
                                 signal.edge,
+                                super::trade_logger::TradeContext::with_execution(
+                                    signal.pm_price,
+                                    shares_to_trade,
+                                    latency_ms.max(0) as u64,
+                                ),
                             )
                             .await;
                     }
@@ -3594,6 +3621,7 @@ mod tests {
             condition_id: "test_condition".into(),
             entry_p_hat: None,
             window_open_price: None,
+            window_secs: 900,
         };
 
         // 10% profit
@@ -3628,6 +3656,7 @@ mod tests {
             condition_id: "test_condition".into(),
             entry_p_hat: None,
             window_open_price: None,
+            window_secs: 900,
         };
 
         // 25% profit should trigger take profit
@@ -3654,6 +3683,7 @@ mod tests {
             condition_id: "test_condition".into(),
             entry_p_hat: None,
             window_open_price: None,
+            window_secs: 900,
         };
 
         // 20% loss should trigger stop loss
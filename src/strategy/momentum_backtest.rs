@@ -20,9 +20,13 @@ use sqlx::PgPool;
 use tracing::{debug, info};
 
 use crate::adapters::SpotPrice;
-use crate::strategy::backtest::BacktestResults;
+use crate::analysis::overfit::{
+    evaluate_overfit, OverfitConfig, OverfitReport, OverfitTrade, ParameterTrial,
+};
+use crate::strategy::backtest::{backtest_day_key, BacktestResults, BacktestTrade, SymbolStats};
 use crate::strategy::backtest_feed::{MarketFeed, UpdateType};
 use crate::strategy::execution_sim::ExecutionSimulator;
+use crate::strategy::fill_model::FillModel;
 use crate::strategy::momentum::{Direction, MomentumConfig, MomentumDetector, MomentumSignal};
 
 // ─────────────────────────────────────────────────────────────
@@ -96,6 +100,8 @@ pub struct MomentumBacktestEngine {
     max_drawdown: Decimal,
     equity_curve: Vec<(DateTime<Utc>, Decimal)>,
     last_entry_time: HashMap<String, DateTime<Utc>>,
+    entry_requested_shares: u64,
+    entry_filled_shares: u64,
     data_range_start: Option<DateTime<Utc>>,
     data_range_end: Option<DateTime<Utc>>,
 }
@@ -133,11 +139,19 @@ impl MomentumBacktestEngine {
             max_drawdown: Decimal::ZERO,
             equity_curve: Vec::new(),
             last_entry_time: HashMap::new(),
+            entry_requested_shares: 0,
+            entry_filled_shares: 0,
             data_range_start: None,
             data_range_end: None,
         }
     }
 
+    /// Simulate fills with a model learned from production fills
+    pub fn with_fill_model(mut self, model: FillModel) -> Self {
+        self.execution_sim = self.execution_sim.with_fill_model(model);
+        self
+    }
+
     pub fn config(&self) -> &MomentumBacktestConfig {
         &self.config
     }
@@ -282,6 +296,8 @@ impl MomentumBacktestEngine {
         }
 
         self.equity -= cost;
+        self.entry_requested_shares += sim_result.requested_shares;
+        self.entry_filled_shares += sim_result.filled_shares;
 
         self.positions.push(BacktestPosition {
             symbol: signal.symbol.clone(),
@@ -472,6 +488,19 @@ impl MomentumBacktestEngine {
             .map(|t| Decimal::from(t.shares) * t.entry_price)
             .sum();
 
+        let mut trades_by_symbol: HashMap<String, SymbolStats> = HashMap::new();
+        let mut trades_by_day: HashMap<String, SymbolStats> = HashMap::new();
+        for t in &self.closed_trades {
+            trades_by_symbol
+                .entry(t.symbol.clone())
+                .or_default()
+                .record(t.pnl, t.won);
+            trades_by_day
+                .entry(backtest_day_key(t.exit_time))
+                .or_default()
+                .record(t.pnl, t.won);
+        }
+
         let start_time = self.data_range_start.unwrap_or(Utc::now());
         let end_time = self.data_range_end.unwrap_or(Utc::now());
 
@@ -493,7 +522,10 @@ impl MomentumBacktestEngine {
             largest_win,
             largest_loss,
             avg_holding_time_secs: avg_holding,
-            trades_by_symbol: HashMap::new(),
+            trades_by_symbol,
+            trades_by_day,
+            requested_shares: self.entry_requested_shares,
+            filled_shares: self.entry_filled_shares,
             trades: Vec::new(), // full BacktestTrade list omitted for momentum
             equity_curve: self.equity_curve.clone(),
         }
@@ -545,6 +577,9 @@ impl fmt::Display for BacktestResults {
             self.winning_trades, self.losing_trades
         )?;
         writeln!(f, "Win rate:      {:.1}%", self.win_rate * 100.0)?;
+        if let Some(fill_rate) = self.fill_rate() {
+            writeln!(f, "Fill rate:     {:.1}%", fill_rate * 100.0)?;
+        }
         writeln!(f, "Total PnL:     ${:.2}", self.total_pnl)?;
         writeln!(f, "Avg PnL/trade: ${:.4}", self.avg_pnl_per_trade)?;
         writeln!(f, "Sharpe ratio:  {:.2}", self.sharpe_ratio)?;
@@ -557,6 +592,67 @@ impl fmt::Display for BacktestResults {
     }
 }
 
+// ─────────────────────────────────────────────────────────────
+// Overfit checks
+// ─────────────────────────────────────────────────────────────
+
+fn overfit_trial(config: &MomentumBacktestConfig, trades: &[BacktestTrade]) -> ParameterTrial {
+    ParameterTrial {
+        params: vec![
+            config.momentum_config.min_move_pct.to_f64().unwrap_or(0.0),
+            config.momentum_config.min_edge.to_f64().unwrap_or(0.0),
+        ],
+        trades: trades
+            .iter()
+            .map(|t| OverfitTrade {
+                entry_time: t.entry_time,
+                exit_time: t.exit_time,
+                pnl: t.pnl.to_f64().unwrap_or(0.0),
+            })
+            .collect(),
+    }
+}
+
+/// Re-run the backtest with `min_move_pct` and `min_edge` scaled by
+/// ±`neighborhood_radius` and score `baseline` (the run with `base`) for
+/// overfitting. `feed` must replay the same data `baseline` was produced from.
+pub fn evaluate_momentum_overfit<F: MarketFeed + Clone>(
+    base: &MomentumBacktestConfig,
+    feed: &F,
+    baseline: &BacktestResults,
+    config: &OverfitConfig,
+) -> OverfitReport {
+    let radius = config.neighborhood_radius;
+    let scales = [1.0 - radius, 1.0, 1.0 + radius];
+    let scale = |v: Decimal, s: f64| v * Decimal::from_f64(s).unwrap_or(Decimal::ONE);
+
+    let mut trials = Vec::with_capacity(scales.len() * scales.len());
+    let mut selected = 0;
+    for move_scale in scales {
+        for edge_scale in scales {
+            if move_scale == 1.0 && edge_scale == 1.0 {
+                selected = trials.len();
+                trials.push(overfit_trial(base, &baseline.trades));
+                continue;
+            }
+            let mut variant = base.clone();
+            variant.momentum_config.min_move_pct =
+                scale(variant.momentum_config.min_move_pct, move_scale);
+            variant.momentum_config.min_edge = scale(variant.momentum_config.min_edge, edge_scale);
+            let results = MomentumBacktestEngine::new(variant.clone()).run(&mut feed.clone());
+            debug!(
+                move_scale,
+                edge_scale,
+                trades = results.total_trades,
+                "overfit neighborhood run"
+            );
+            trials.push(overfit_trial(&variant, &results.trades));
+        }
+    }
+
+    evaluate_overfit(&trials, selected, config)
+}
+
 // ─────────────────────────────────────────────────────────────
 // Result persistence (Step 9)
 // ─────────────────────────────────────────────────────────────
@@ -566,17 +662,22 @@ pub async fn save_backtest_results(
     pool: &PgPool,
     config: &MomentumBacktestConfig,
     results: &BacktestResults,
+    overfit: Option<&OverfitReport>,
 ) -> Result<()> {
     let mut tx = pool.begin().await?;
 
-    // Determine evaluation status from Sharpe ratio
-    let status = if results.sharpe_ratio > 1.0 {
+    // Determine evaluation status from Sharpe ratio; a failed overfit check
+    // caps it at WARN.
+    let status = if results.sharpe_ratio > 1.0 && overfit.is_none_or(|o| o.passed) {
         "PASS"
     } else if results.sharpe_ratio > 0.5 {
         "WARN"
     } else {
         "FAIL"
     };
+    let evidence_payload = overfit
+        .map(|report| serde_json::to_value(report).map(|v| serde_json::json!({ "overfit": v })))
+        .transpose()?;
 
     // 1. Insert strategy_evaluations row
     let eval_id: i64 = sqlx::query_scalar(
@@ -584,10 +685,10 @@ pub async fn save_backtest_results(
         INSERT INTO strategy_evaluations (
             evaluated_at, strategy_id, domain, stage, status,
             score, pnl_usd, win_rate, sharpe,
-            max_drawdown_pct, evidence_kind
+            max_drawdown_pct, evidence_kind, evidence_payload
         )
         VALUES (NOW(), 'momentum', 'crypto', 'BACKTEST', $1,
-                $2, $3, $4, $5, $6, 'backtest_run')
+                $2, $3, $4, $5, $6, 'backtest_run', $7)
         RETURNING id
         "#,
     )
@@ -597,6 +698,7 @@ pub async fn save_backtest_results(
     .bind(Decimal::from_f64(results.win_rate).unwrap_or(Decimal::ZERO))
     .bind(Decimal::from_f64(results.sharpe_ratio).unwrap_or(Decimal::ZERO))
     .bind(results.max_drawdown)
+    .bind(&evidence_payload)
     .fetch_one(&mut *tx)
     .await?;
 
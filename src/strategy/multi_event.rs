@@ -49,13 +49,25 @@ impl EventTracker {
             .map(|dt| dt.with_timezone(&Utc))
             .unwrap_or_else(Utc::now);
 
+        let event_slug = event.slug.clone().unwrap_or_default();
+        let symbol = event_slug.split('-').next().unwrap_or_default();
+        let mut signal_detector = SignalDetector::new(config).with_symbol(symbol);
+        if let Some(start) = event
+            .start_time
+            .as_ref()
+            .and_then(|d| chrono::DateTime::parse_from_rfc3339(d).ok())
+        {
+            signal_detector
+                .set_round_duration((end_time - start.with_timezone(&Utc)).num_seconds());
+        }
+
         Self {
             event_id: event.id.clone(),
-            event_slug: event.slug.clone().unwrap_or_default(),
+            event_slug,
             up_token_id,
             down_token_id,
             end_time,
-            signal_detector: SignalDetector::new(config),
+            signal_detector,
             up_quote: None,
             down_quote: None,
             is_active: true,
@@ -309,6 +321,8 @@ impl MultiEventMonitor {
                             drop_pct: Decimal::ZERO,
                             timestamp: Utc::now(),
                             spread_bps: 0,
+                            threshold_pct: Decimal::ZERO,
+                            realized_vol: None,
                         },
                         up_quote,
                         down_quote,
@@ -382,6 +396,9 @@ mod tests {
             fee_buffer: dec!(0.005),
             slippage_buffer: dec!(0.02),
             profit_buffer: dec!(0.01),
+            resume: Default::default(),
+            signal_mid: None,
+            vol_threshold: None,
         }
     }
 
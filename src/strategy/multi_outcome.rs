@@ -9,8 +9,13 @@
 //! - Expected Value (EV) calculations with fee adjustment
 //! - Split/Merge market making arbitrage detection
 //! - Near-settlement opportunity scanning
+//! - Depth-adjusted fair-value bands, so arbitrage on thin books is only
+//!   flagged when it is executable at a target size
 
+use crate::adapters::polymarket_clob::OrderBookResponse;
 use crate::adapters::PolymarketClient;
+use crate::collector::{DepthBook, DepthLevel};
+use crate::domain::OrderSide;
 use crate::error::Result;
 use chrono::{DateTime, Duration, Utc};
 use rust_decimal::Decimal;
@@ -18,6 +23,7 @@ use rust_decimal_macros::dec;
 use serde::{Deserialize, Serialize};
 use std::collections::HashMap;
 use std::str::FromStr;
+use tracing::warn;
 
 /// Polymarket fee rate (approximately 2%)
 pub const POLYMARKET_FEE_RATE: Decimal = dec!(0.02);
@@ -333,6 +339,9 @@ pub struct Outcome {
     pub yes_size: Option<Decimal>,
     /// No order size
     pub no_size: Option<Decimal>,
+    /// Yes token orderbook, when depth has been fetched
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub yes_book: Option<DepthBook>,
     /// Last update time
     pub timestamp: DateTime<Utc>,
 }
@@ -437,6 +446,88 @@ pub struct MultiOutcomeArbitrage {
     pub detected_at: DateTime<Utc>,
 }
 
+/// Depth-weighted executable price range for one outcome at a target size.
+///
+/// `lower` is the average price selling `target_shares` into the bids and
+/// `upper` the average price buying them from the asks; fair value lies
+/// in between. A side without liquidity widens the band to 0 or 1.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct FairValueBand {
+    pub token_id: String,
+    pub target_shares: Decimal,
+    pub lower: Decimal,
+    pub upper: Decimal,
+    pub mid: Decimal,
+    /// Shares the bids absorb up to `target_shares`
+    pub bid_shares: Decimal,
+    /// Shares the asks supply up to `target_shares`
+    pub ask_shares: Decimal,
+    /// Top-of-book prices, for comparison
+    pub best_bid: Option<Decimal>,
+    pub best_ask: Option<Decimal>,
+}
+
+impl FairValueBand {
+    pub fn from_book(book: &DepthBook, target_shares: Decimal) -> Self {
+        let sell = book.sweep(OrderSide::Sell, target_shares);
+        let buy = book.sweep(OrderSide::Buy, target_shares);
+        let lower = sell.map(|s| s.avg_price).unwrap_or(Decimal::ZERO);
+        let upper = buy.map(|s| s.avg_price).unwrap_or(Decimal::ONE);
+        Self {
+            token_id: book.token_id.clone(),
+            target_shares,
+            lower,
+            upper,
+            mid: (lower + upper) / Decimal::TWO,
+            bid_shares: sell.map(|s| s.filled).unwrap_or(Decimal::ZERO),
+            ask_shares: buy.map(|s| s.filled).unwrap_or(Decimal::ZERO),
+            best_bid: book.best_bid(),
+            best_ask: book.best_ask(),
+        }
+    }
+
+    pub fn width(&self) -> Decimal {
+        self.upper - self.lower
+    }
+
+    /// Can `target_shares` be sold at `lower`?
+    pub fn sellable(&self) -> bool {
+        self.bid_shares >= self.target_shares
+    }
+
+    /// Can `target_shares` be bought at `upper`?
+    pub fn buyable(&self) -> bool {
+        self.ask_shares >= self.target_shares
+    }
+}
+
+/// Fair-value bands keyed by Yes token id
+pub type FairValueBands = HashMap<String, FairValueBand>;
+
+/// Depth book from a CLOB `/book` response
+pub fn depth_book_from_response(response: &OrderBookResponse) -> DepthBook {
+    let parse = |levels: &[crate::adapters::polymarket_clob::OrderBookLevel]| -> Vec<DepthLevel> {
+        levels
+            .iter()
+            .filter_map(|l| {
+                let price = l.price.parse::<Decimal>().ok()?;
+                let size = l.size.parse::<Decimal>().ok()?;
+                (size > Decimal::ZERO).then_some(DepthLevel { price, size })
+            })
+            .collect()
+    };
+    let mut bids = parse(&response.bids);
+    let mut asks = parse(&response.asks);
+    bids.sort_by(|a, b| b.price.cmp(&a.price));
+    asks.sort_by(|a, b| a.price.cmp(&b.price));
+    DepthBook {
+        token_id: response.asset_id.clone(),
+        ts_ms: Utc::now().timestamp_millis(),
+        bids,
+        asks,
+    }
+}
+
 /// Multi-outcome market monitor
 pub struct MultiOutcomeMonitor {
     /// Event ID
@@ -476,6 +567,7 @@ impl MultiOutcomeMonitor {
             no_price: None,
             yes_size: None,
             no_size: None,
+            yes_book: None,
             timestamp: Utc::now(),
         };
 
@@ -533,6 +625,28 @@ impl MultiOutcomeMonitor {
         }
     }
 
+    /// Update the Yes orderbook for an outcome
+    pub fn update_book(&mut self, token_id: &str, book: DepthBook) {
+        if let Some(outcome) = self.outcomes.get_mut(token_id) {
+            outcome.yes_book = Some(book);
+            outcome.timestamp = Utc::now();
+        }
+    }
+
+    /// Fair-value bands at `target_shares` for every outcome with a book
+    pub fn fair_value_bands(&self, target_shares: Decimal) -> FairValueBands {
+        self.outcomes
+            .iter()
+            .filter_map(|(token_id, outcome)| {
+                let book = outcome.yes_book.as_ref()?;
+                Some((
+                    token_id.clone(),
+                    FairValueBand::from_book(book, target_shares),
+                ))
+            })
+            .collect()
+    }
+
     /// Get all token IDs
     pub fn all_token_ids(&self) -> Vec<String> {
         self.outcomes.keys().cloned().collect()
@@ -649,11 +763,81 @@ impl MultiOutcomeMonitor {
             .collect()
     }
 
-    /// Find all arbitrage opportunities
-    pub fn find_all_arbitrage(&self) -> Vec<MultiOutcomeArbitrage> {
+    /// Monotonicity violations that survive the depth-weighted bands: the
+    /// outcome that should be more likely can be bought at its band `upper`
+    /// for less than the other sells at its band `lower`, for the full
+    /// target size on both legs.
+    pub fn find_executable_monotonicity(
+        &self,
+        bands: &FairValueBands,
+    ) -> Vec<MultiOutcomeArbitrage> {
+        let mut violations = Vec::new();
+        for sorted in [&self.up_outcomes, &self.down_outcomes] {
+            for pair in sorted.windows(2) {
+                let (Some(outcome_a), Some(outcome_b)) =
+                    (self.outcomes.get(&pair[0]), self.outcomes.get(&pair[1]))
+                else {
+                    continue;
+                };
+                let (Some(band_a), Some(band_b)) = (bands.get(&pair[0]), bands.get(&pair[1]))
+                else {
+                    continue;
+                };
+                if !band_a.buyable() || !band_b.sellable() || band_b.lower <= band_a.upper {
+                    continue;
+                }
+                violations.push(MultiOutcomeArbitrage {
+                    arb_type: ArbitrageType::MonotonicityViolation {
+                        outcome_a: outcome_a.name.clone(),
+                        outcome_b: outcome_b.name.clone(),
+                        prob_a: band_a.upper,
+                        prob_b: band_b.lower,
+                        expected_relationship: format!(
+                            "{} should have >= probability than {} ({} shares executable)",
+                            outcome_a.name, outcome_b.name, band_a.target_shares
+                        ),
+                    },
+                    profit_per_dollar: band_b.lower - band_a.upper,
+                    confidence: dec!(0.95),
+                    detected_at: Utc::now(),
+                });
+            }
+        }
+        violations
+    }
+
+    /// Spread arbitrage against the bands. Buying No costs `1 - lower` on
+    /// the Yes book, so this only fires on a crossed book.
+    pub fn find_executable_spread(&self, bands: &FairValueBands) -> Vec<MultiOutcomeArbitrage> {
+        self.outcomes
+            .iter()
+            .filter_map(|(token_id, outcome)| {
+                let band = bands.get(token_id)?;
+                if !band.buyable() || !band.sellable() {
+                    return None;
+                }
+                let yes = band.upper;
+                let no = Decimal::ONE - band.lower;
+                (yes + no < Decimal::ONE).then(|| MultiOutcomeArbitrage {
+                    arb_type: ArbitrageType::SpreadArbitrage {
+                        outcome: outcome.name.clone(),
+                        yes_price: yes,
+                        no_price: no,
+                        profit: Decimal::ONE - yes - no,
+                    },
+                    profit_per_dollar: Decimal::ONE - yes - no,
+                    confidence: dec!(0.95),
+                    detected_at: Utc::now(),
+                })
+            })
+            .collect()
+    }
+
+    /// Find all arbitrage opportunities executable within `bands`
+    pub fn find_all_arbitrage(&self, bands: &FairValueBands) -> Vec<MultiOutcomeArbitrage> {
         let mut arbs = Vec::new();
-        arbs.extend(self.find_monotonicity_violations());
-        arbs.extend(self.find_spread_arbitrage());
+        arbs.extend(self.find_executable_monotonicity(bands));
+        arbs.extend(self.find_executable_spread(bands));
 
         // Sort by profit potential
         arbs.sort_by(|a, b| b.profit_per_dollar.cmp(&a.profit_per_dollar));
@@ -761,6 +945,17 @@ pub async fn fetch_multi_outcome_event(
     Ok(monitor)
 }
 
+/// Fetch the Yes orderbook of every outcome; outcomes whose book cannot be
+/// fetched keep no book and therefore get no fair-value band.
+pub async fn fetch_outcome_books(client: &PolymarketClient, monitor: &mut MultiOutcomeMonitor) {
+    for token_id in monitor.all_token_ids() {
+        match client.get_order_book(&token_id).await {
+            Ok(response) => monitor.update_book(&token_id, depth_book_from_response(&response)),
+            Err(e) => warn!(token_id = %token_id, error = %e, "orderbook fetch failed"),
+        }
+    }
+}
+
 /// Detect Split/Merge arbitrage opportunity for a binary market
 ///
 /// Split Arbitrage: When Yes_bid + No_bid > 1, split $1 into Yes+No, sell both
@@ -1099,4 +1294,48 @@ mod tests {
             "Should detect monotonicity violation"
         );
     }
+
+    #[test]
+    fn test_thin_book_violation_is_not_executable() {
+        let book = |token: &str, bids: &[(Decimal, Decimal)], asks: &[(Decimal, Decimal)]| {
+            let level = |&(price, size): &(Decimal, Decimal)| DepthLevel { price, size };
+            DepthBook {
+                token_id: token.into(),
+                ts_ms: 0,
+                bids: bids.iter().map(level).collect(),
+                asks: asks.iter().map(level).collect(),
+            }
+        };
+        let mut monitor = MultiOutcomeMonitor::new("test", "BTC Price Test");
+        monitor.add_outcome("t84".to_string(), "↓ 84,000".to_string());
+        monitor.add_outcome("t82".to_string(), "↓ 82,000".to_string());
+
+        // Top of book: 84k asks 0.05, 82k bids 0.07 -- but only 10 shares each.
+        monitor.update_book(
+            "t84",
+            book(
+                "t84",
+                &[(dec!(0.04), dec!(500))],
+                &[(dec!(0.05), dec!(10)), (dec!(0.09), dec!(500))],
+            ),
+        );
+        monitor.update_book(
+            "t82",
+            book(
+                "t82",
+                &[(dec!(0.07), dec!(10)), (dec!(0.02), dec!(500))],
+                &[(dec!(0.08), dec!(500))],
+            ),
+        );
+
+        let thin = monitor.fair_value_bands(dec!(10));
+        let arbs = monitor.find_all_arbitrage(&thin);
+        assert_eq!(arbs.len(), 1);
+        assert_eq!(arbs[0].profit_per_dollar, dec!(0.02));
+
+        let sized = monitor.fair_value_bands(dec!(100));
+        assert_eq!(sized["t84"].upper, dec!(0.086));
+        assert_eq!(sized["t82"].lower, dec!(0.025));
+        assert!(monitor.find_all_arbitrage(&sized).is_empty());
+    }
 }
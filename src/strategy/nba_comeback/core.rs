@@ -17,6 +17,7 @@ use tracing::{debug, info, warn};
 
 use super::nba_winprob::{GameFeatures, LiveWinProbModel};
 use crate::config::NbaComebackConfig;
+use crate::strategy::event_models::comment_sentiment::CommentSentimentHandle;
 use crate::strategy::nba_comeback::comeback_stats::ComebackStatsProvider;
 use crate::strategy::nba_comeback::espn::{EspnClient, LiveGame};
 
@@ -125,6 +126,8 @@ pub struct NbaComebackCore {
     pub winprob_model: LiveWinProbModel,
     pub cfg: NbaComebackConfig,
     pub state: NbaComebackState,
+    /// Optional Polymarket comment sentiment feature (shifts win probability).
+    pub sentiment: Option<CommentSentimentHandle>,
 }
 
 impl NbaComebackCore {
@@ -135,9 +138,15 @@ impl NbaComebackCore {
             winprob_model: LiveWinProbModel::default_untrained(),
             cfg,
             state: NbaComebackState::default(),
+            sentiment: None,
         }
     }
 
+    pub fn with_sentiment(mut self, sentiment: CommentSentimentHandle) -> Self {
+        self.sentiment = Some(sentiment);
+        self
+    }
+
     // ── Guards (same pattern as EventEdgeCore) ──────────────────
 
     pub fn reset_daily_if_needed(&mut self) {
@@ -646,6 +655,40 @@ impl NbaComebackCore {
         self.scan_games_inner(&games)
     }
 
+    /// Shift the trailing team's win probability by the comment sentiment index
+    /// of its Polymarket event (no-op when the feature is off or data is thin).
+    pub fn apply_comment_sentiment(
+        &self,
+        candidate: &ComebackCandidate,
+        event_id: Option<&str>,
+    ) -> ComebackCandidate {
+        let mut adjusted = candidate.clone();
+        let (Some(sentiment), Some(event_id)) = (self.sentiment.as_ref(), event_id) else {
+            return adjusted;
+        };
+
+        let game = &candidate.game;
+        sentiment.watch(
+            event_id,
+            &format!("{} vs {}", game.away_team, game.home_team),
+            vec![game.home_team.clone(), game.away_team.clone()],
+        );
+        if let Some(shift) =
+            sentiment.probability_shift(event_id, &candidate.trailing_team, Utc::now())
+        {
+            let shift = shift.to_string().parse::<f64>().unwrap_or(0.0);
+            adjusted.adjusted_win_prob = (candidate.adjusted_win_prob + shift).clamp(0.01, 0.99);
+            debug!(
+                "{} comment sentiment shift {:+.4}: win_prob {:.3} -> {:.3}",
+                candidate.trailing_abbrev,
+                shift,
+                candidate.adjusted_win_prob,
+                adjusted.adjusted_win_prob
+            );
+        }
+        adjusted
+    }
+
     /// Given a candidate and a market price, determine if there's a tradeable edge
     pub fn evaluate_opportunity(
         &self,
@@ -800,6 +843,8 @@ mod tests {
             early_exit_enabled: true,
             early_exit_take_profit_pct: 15.0,
             early_exit_stop_loss_pct: 20.0,
+            comment_sentiment: Default::default(),
+            score_guard: Default::default(),
         }
     }
 
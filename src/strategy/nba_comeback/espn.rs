@@ -4,7 +4,7 @@
 //! No API key required.
 
 use anyhow::{Context, Result};
-use chrono::NaiveDate;
+use chrono::{DateTime, NaiveDate, NaiveDateTime, Utc};
 use serde::Deserialize;
 use std::time::Duration;
 use tracing::debug;
@@ -45,6 +45,18 @@ pub struct LiveGame {
     pub away_quarter_scores: Vec<QuarterScore>,
 }
 
+/// A game on the schedule, with its tip-off time
+#[derive(Debug, Clone, serde::Serialize)]
+pub struct ScheduledGame {
+    pub espn_game_id: String,
+    pub home_team: String,
+    pub away_team: String,
+    pub home_abbrev: String,
+    pub away_abbrev: String,
+    pub start_time: DateTime<Utc>,
+    pub status: GameStatus,
+}
+
 impl LiveGame {
     /// Point differential from home team's perspective
     pub fn home_diff(&self) -> i32 {
@@ -77,6 +89,9 @@ struct EspnResponse {
 #[derive(Debug, Deserialize)]
 struct EspnEvent {
     id: String,
+    /// Tip-off, e.g. "2025-01-15T00:30Z"
+    #[serde(default)]
+    date: Option<String>,
     competitions: Vec<EspnCompetition>,
 }
 
@@ -150,22 +165,35 @@ impl EspnClient {
         self.fetch_games_for_date_internal(Some(date)).await
     }
 
+    /// Fetch the schedule (with tip-off times) for a calendar date (UTC)
+    pub async fn fetch_schedule(&self, date: NaiveDate) -> Result<Vec<ScheduledGame>> {
+        let data = self.fetch_scoreboard(Some(date)).await?;
+        let games: Vec<ScheduledGame> = data
+            .events
+            .iter()
+            .filter_map(|event| {
+                let game = Self::parse_event(event)?;
+                let start_time = Self::parse_start_time(event.date.as_deref()?)?;
+                Some(ScheduledGame {
+                    espn_game_id: game.espn_game_id,
+                    home_team: game.home_team,
+                    away_team: game.away_team,
+                    home_abbrev: game.home_abbrev,
+                    away_abbrev: game.away_abbrev,
+                    start_time,
+                    status: game.status,
+                })
+            })
+            .collect();
+        debug!("ESPN: fetched {} scheduled games for {}", games.len(), date);
+        Ok(games)
+    }
+
     async fn fetch_games_for_date_internal(
         &self,
         date: Option<NaiveDate>,
     ) -> Result<Vec<LiveGame>> {
-        let mut req = self.http.get(ESPN_SCOREBOARD_URL);
-        if let Some(d) = date {
-            req = req.query(&[("dates", d.format("%Y%m%d").to_string())]);
-        }
-
-        // ESPN supports date-scoped scoreboard queries via `dates=YYYYMMDD`.
-        let resp = req.send().await.context("ESPN scoreboard request failed")?;
-
-        let data: EspnResponse = resp
-            .json()
-            .await
-            .context("ESPN scoreboard JSON parse failed")?;
+        let data = self.fetch_scoreboard(date).await?;
 
         let mut games = Vec::new();
         for event in &data.events {
@@ -178,6 +206,32 @@ impl EspnClient {
         Ok(games)
     }
 
+    async fn fetch_scoreboard(&self, date: Option<NaiveDate>) -> Result<EspnResponse> {
+        let mut req = self.http.get(ESPN_SCOREBOARD_URL);
+        if let Some(d) = date {
+            req = req.query(&[("dates", d.format("%Y%m%d").to_string())]);
+        }
+
+        // ESPN supports date-scoped scoreboard queries via `dates=YYYYMMDD`.
+        let resp = req.send().await.context("ESPN scoreboard request failed")?;
+
+        resp.json()
+            .await
+            .context("ESPN scoreboard JSON parse failed")
+    }
+
+    /// ESPN omits seconds ("2025-01-15T00:30Z"); accept full RFC 3339 too
+    fn parse_start_time(raw: &str) -> Option<DateTime<Utc>> {
+        DateTime::parse_from_rfc3339(raw)
+            .map(|dt| dt.with_timezone(&Utc))
+            .ok()
+            .or_else(|| {
+                NaiveDateTime::parse_from_str(raw, "%Y-%m-%dT%H:%MZ")
+                    .ok()
+                    .map(|dt| dt.and_utc())
+            })
+    }
+
     /// Filter games currently in a specific quarter
     pub fn games_in_quarter(games: &[LiveGame], quarter: u8) -> Vec<&LiveGame> {
         games
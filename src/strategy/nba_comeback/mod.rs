@@ -9,6 +9,7 @@ pub mod core;
 pub mod espn;
 pub mod grok_decision;
 pub mod grok_intel;
+pub mod score_guard;
 
 // Infrastructure modules (moved from strategy/ root)
 pub mod nba_data_collector;
@@ -22,6 +23,7 @@ pub use comeback_stats::{ComebackStatsProvider, TeamComebackProfile};
 pub use core::{
     ComebackOpportunity, GamePosition, NbaComebackCore, NbaComebackState, PositionEntry,
 };
-pub use espn::{EspnClient, GameStatus, LiveGame, QuarterScore};
+pub use espn::{EspnClient, GameStatus, LiveGame, QuarterScore, ScheduledGame};
 pub use grok_decision::{GrokDecision, RiskMetrics, UnifiedDecisionRequest};
 pub use grok_intel::{GrokGameIntel, GrokSignalEvaluator, GrokTradeSignal};
+pub use score_guard::{check_live_score, ScoreCheck, ScoreObservation};
@@ -0,0 +1,272 @@
+//! Live Score Mismatch Guard
+//!
+//! ESPN's scoreboard occasionally lags or freezes. Before an entry, the ESPN
+//! score/period is cross-checked against independent sources (Polymarket
+//! live-game metadata, or any other scores feed mapped into a
+//! [`ScoreObservation`]); the entry proceeds only when enough fresh sources
+//! agree within tolerance.
+
+use chrono::{DateTime, Utc};
+
+use crate::ai_clients::polymarket_sports::EventDetails;
+use crate::config::LiveScoreGuardConfig;
+use crate::strategy::nba_comeback::espn::LiveGame;
+
+/// One source's view of a live game, oriented home/away
+#[derive(Debug, Clone, PartialEq)]
+pub struct ScoreObservation {
+    pub source: String,
+    pub home_score: i32,
+    pub away_score: i32,
+    pub period: u8,
+    pub observed_at: DateTime<Utc>,
+}
+
+impl ScoreObservation {
+    pub fn from_espn(game: &LiveGame, observed_at: DateTime<Utc>) -> Self {
+        Self {
+            source: "espn".to_string(),
+            home_score: game.home_score,
+            away_score: game.away_score,
+            period: game.quarter,
+            observed_at,
+        }
+    }
+
+    /// Polymarket live metadata for `game`
+    ///
+    /// The score string follows the title's team order ("A vs. B" / "A @ B"
+    /// => "a-b"); `None` when the game is not live or the order can't be
+    /// resolved against the ESPN team names.
+    pub fn from_pm_event(
+        event: &EventDetails,
+        game: &LiveGame,
+        observed_at: DateTime<Utc>,
+    ) -> Option<Self> {
+        if !event.live || event.ended {
+            return None;
+        }
+        let (first, second) = event.get_scores()?;
+        let period = parse_period(event.period.as_deref()?)?;
+        let title = event.title.to_lowercase();
+        let first_team = [" vs. ", " vs ", " @ "]
+            .iter()
+            .find_map(|sep| title.split_once(sep).map(|(a, _)| a.to_string()))?;
+
+        let home_first = team_mentioned(&first_team, &game.home_team, &game.home_abbrev);
+        let away_first = team_mentioned(&first_team, &game.away_team, &game.away_abbrev);
+        let (home_score, away_score) = match (home_first, away_first) {
+            (true, false) => (first, second),
+            (false, true) => (second, first),
+            _ => return None,
+        };
+        Some(Self {
+            source: "polymarket".to_string(),
+            home_score: home_score as i32,
+            away_score: away_score as i32,
+            period,
+            observed_at,
+        })
+    }
+
+    fn age_secs(&self, now: DateTime<Utc>) -> i64 {
+        (now - self.observed_at).num_seconds()
+    }
+
+    /// Mismatch description against `other`, or `None` when within tolerance
+    fn mismatch(&self, other: &Self, cfg: &LiveScoreGuardConfig) -> Option<String> {
+        let tolerance = cfg.score_tolerance.max(0);
+        let leader = |o: &Self| (o.home_score - o.away_score).signum();
+        if self.period.abs_diff(other.period) > cfg.period_tolerance
+            || (self.home_score - other.home_score).abs() > tolerance
+            || (self.away_score - other.away_score).abs() > tolerance
+            || leader(self) != leader(other)
+        {
+            return Some(format!(
+                "{} Q{} {}-{} vs {} Q{} {}-{}",
+                self.source,
+                self.period,
+                self.home_score,
+                self.away_score,
+                other.source,
+                other.period,
+                other.home_score,
+                other.away_score
+            ));
+        }
+        None
+    }
+}
+
+/// Quarter number from a live-status period ("Q3" => 3, "OT" => 5, "2OT" => 6)
+pub fn parse_period(period: &str) -> Option<u8> {
+    let p = period.trim().to_uppercase();
+    if let Some(q) = p.strip_prefix('Q') {
+        return q.parse().ok().filter(|q| (1..=4).contains(q));
+    }
+    if p == "HT" {
+        return Some(2);
+    }
+    if let Some(ot) = p.strip_suffix("OT") {
+        let n: u8 = if ot.is_empty() { 1 } else { ot.parse().ok()? };
+        return Some(4 + n);
+    }
+    None
+}
+
+fn team_mentioned(text: &str, team_name: &str, team_abbrev: &str) -> bool {
+    let nickname = team_name
+        .split_whitespace()
+        .last()
+        .unwrap_or(team_name)
+        .to_lowercase();
+    let abbrev = team_abbrev.to_lowercase();
+    (!nickname.is_empty() && text.contains(&nickname))
+        || text.split_whitespace().any(|w| w == abbrev)
+}
+
+/// Outcome of the cross-check
+#[derive(Debug, Clone, PartialEq)]
+pub enum ScoreCheck {
+    /// Enough fresh sources agree
+    Confirmed { sources: usize },
+    /// Entry must not proceed
+    Rejected(String),
+}
+
+/// Require `min_sources` fresh observations (ESPN included) agreeing with ESPN
+pub fn check_live_score(
+    cfg: &LiveScoreGuardConfig,
+    espn: &ScoreObservation,
+    others: &[ScoreObservation],
+    now: DateTime<Utc>,
+) -> ScoreCheck {
+    if !cfg.enabled {
+        return ScoreCheck::Confirmed { sources: 1 };
+    }
+    let espn_age = espn.age_secs(now);
+    if espn_age > cfg.max_staleness_secs {
+        return ScoreCheck::Rejected(format!(
+            "{} data stale ({}s > {}s)",
+            espn.source, espn_age, cfg.max_staleness_secs
+        ));
+    }
+
+    let mut agreeing = 1;
+    let mut problems = Vec::new();
+    for other in others {
+        let age = other.age_secs(now);
+        if age > cfg.max_staleness_secs {
+            problems.push(format!("{} stale ({}s)", other.source, age));
+            continue;
+        }
+        match espn.mismatch(other, cfg) {
+            Some(mismatch) => problems.push(format!("mismatch: {}", mismatch)),
+            None => agreeing += 1,
+        }
+    }
+
+    if agreeing >= cfg.min_sources.max(1) {
+        ScoreCheck::Confirmed { sources: agreeing }
+    } else if problems.is_empty() {
+        ScoreCheck::Rejected(format!(
+            "only {} of {} required sources available",
+            agreeing, cfg.min_sources
+        ))
+    } else {
+        ScoreCheck::Rejected(problems.join("; "))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::strategy::nba_comeback::espn::GameStatus;
+    use chrono::Duration;
+
+    fn game() -> LiveGame {
+        LiveGame {
+            espn_game_id: "401".to_string(),
+            home_team: "Cleveland Cavaliers".to_string(),
+            away_team: "Brooklyn Nets".to_string(),
+            home_abbrev: "CLE".to_string(),
+            away_abbrev: "BKN".to_string(),
+            home_score: 80,
+            away_score: 68,
+            quarter: 3,
+            clock: "4:12".to_string(),
+            time_remaining_mins: 16.2,
+            status: GameStatus::InProgress,
+            home_quarter_scores: vec![],
+            away_quarter_scores: vec![],
+        }
+    }
+
+    fn pm_event(score: &str, period: &str) -> EventDetails {
+        serde_json::from_value(serde_json::json!({
+            "id": "1",
+            "title": "Nets vs. Cavaliers",
+            "slug": "nba-bkn-cle",
+            "score": score,
+            "live": true,
+            "period": period,
+        }))
+        .unwrap()
+    }
+
+    #[test]
+    fn test_pm_metadata_confirms_or_blocks_espn() {
+        let cfg = LiveScoreGuardConfig::default();
+        let now = Utc::now();
+        let game = game();
+        let espn = ScoreObservation::from_espn(&game, now);
+
+        // Title lists the away team first, so "68-79" is away-home
+        let pm = ScoreObservation::from_pm_event(&pm_event("68-79", "Q3"), &game, now).unwrap();
+        assert_eq!((pm.home_score, pm.away_score, pm.period), (79, 68, 3));
+        assert_eq!(
+            check_live_score(&cfg, &espn, &[pm], now),
+            ScoreCheck::Confirmed { sources: 2 }
+        );
+
+        // Polymarket is already in Q4 with a tighter game: ESPN is behind
+        let ahead = ScoreObservation::from_pm_event(&pm_event("75-80", "Q4"), &game, now).unwrap();
+        assert!(matches!(
+            check_live_score(&cfg, &espn, &[ahead], now),
+            ScoreCheck::Rejected(_)
+        ));
+
+        // ESPN alone is never enough
+        assert!(matches!(
+            check_live_score(&cfg, &espn, &[], now),
+            ScoreCheck::Rejected(_)
+        ));
+    }
+
+    #[test]
+    fn test_stale_observations_are_ignored() {
+        let cfg = LiveScoreGuardConfig::default();
+        let now = Utc::now();
+        let game = game();
+        let stale = now - Duration::seconds(cfg.max_staleness_secs + 5);
+
+        let espn = ScoreObservation::from_espn(&game, stale);
+        let pm = ScoreObservation::from_pm_event(&pm_event("68-80", "Q3"), &game, now).unwrap();
+        assert!(matches!(
+            check_live_score(&cfg, &espn, &[pm.clone()], now),
+            ScoreCheck::Rejected(reason) if reason.contains("stale")
+        ));
+
+        let espn = ScoreObservation::from_espn(&game, now);
+        let old_pm = ScoreObservation {
+            observed_at: stale,
+            ..pm
+        };
+        assert!(matches!(
+            check_live_score(&cfg, &espn, &[old_pm], now),
+            ScoreCheck::Rejected(_)
+        ));
+        assert_eq!(parse_period("2OT"), Some(6));
+        assert_eq!(parse_period("NS"), None);
+    }
+}
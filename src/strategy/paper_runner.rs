@@ -17,6 +17,7 @@ use tracing::{debug, error, info, warn};
 
 use crate::adapters::{BinanceWebSocket, PolymarketClient, PolymarketWebSocket};
 use crate::collector::BinanceKlineClient;
+use crate::exchange::LatencyConfig;
 use crate::strategy::core::{BinaryMarket, MarketDiscovery};
 use crate::strategy::{CryptoMarketDiscovery, PaperTrader, PaperTradingStats, VolatilityArbConfig};
 
@@ -35,6 +36,8 @@ pub struct PaperTradingConfig {
     pub stats_interval_secs: u64,
     /// Log file path for signals
     pub log_file: Option<String>,
+    /// Simulated quote latency applied to the Polymarket feed
+    pub latency: LatencyConfig,
 }
 
 impl Default for PaperTradingConfig {
@@ -50,6 +53,7 @@ impl Default for PaperTradingConfig {
             kline_update_interval_secs: 60, // Update volatility every minute
             stats_interval_secs: 300,       // Print stats every 5 minutes
             log_file: Some("./data/paper_signals.json".into()),
+            latency: LatencyConfig::default(),
         }
     }
 }
@@ -167,8 +171,12 @@ impl PaperTradingRunner {
         }
 
         // Create WebSocket connections
-        let pm_ws =
+        let mut pm_ws =
             PolymarketWebSocket::new("wss://ws-subscriptions-clob.polymarket.com/ws/market");
+        if let Some(latency) = self.config.latency.injector() {
+            info!(quote = ?latency.config().quote, "Simulated quote latency enabled");
+            pm_ws = pm_ws.with_latency(latency);
+        }
 
         // Binance WS needs lowercase symbols
         let binance_symbols: Vec<String> = self
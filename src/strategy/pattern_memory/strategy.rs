@@ -800,6 +800,7 @@ impl Strategy for PatternMemoryStrategy {
                 side,
                 quote,
                 timestamp,
+                ..
             } => {
                 self.update_quote(token_id, *side, quote, *timestamp);
             }
@@ -812,6 +813,7 @@ impl Strategy for PatternMemoryStrategy {
                 end_time,
                 price_to_beat,
                 title,
+                ..
             } => {
                 let Some(symbol) = self.symbol_for_series(series_id) else {
                     return Ok(actions);
@@ -881,7 +883,9 @@ impl Strategy for PatternMemoryStrategy {
             }
 
             // pattern_memory doesn't need trade ticks / spot prices.
-            MarketUpdate::BinancePrice { .. } => {}
+            MarketUpdate::PolymarketBook { .. }
+            | MarketUpdate::BinancePrice { .. }
+            | MarketUpdate::RoundBoundary { .. } => {}
         }
 
         Ok(actions)
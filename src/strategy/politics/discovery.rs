@@ -0,0 +1,260 @@
+//! Political market discovery
+//!
+//! Pulls active markets through the politics client and keeps high-volume
+//! binary markets in the opted-in classes whose resolution falls inside the
+//! configured horizon.
+
+use super::market_class::{classify_political_market, PoliticalMarket, PoliticalMarketClass};
+use crate::ai_clients::{PolymarketPoliticsClient, PolymarketPoliticsMarket};
+use crate::error::Result;
+use crate::strategy::core::{BinaryMarket, MarketDiscovery, MarketType};
+use async_trait::async_trait;
+use chrono::{DateTime, Duration, Utc};
+use serde::{Deserialize, Serialize};
+use tracing::{debug, info};
+
+/// Gamma markets scanned per discovery pass
+const DISCOVERY_FETCH_LIMIT: u32 = 500;
+
+/// Which political markets are worth monitoring
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(default)]
+pub struct PoliticsMarketFilter {
+    /// Market classes to trade
+    pub classes: Vec<PoliticalMarketClass>,
+    /// Minimum lifetime volume (USD)
+    pub min_volume_usd: f64,
+    /// Minimum current liquidity (USD)
+    pub min_liquidity_usd: f64,
+    /// Skip markets resolving sooner than this (news-driven endgames)
+    pub min_hours_to_resolution: i64,
+    /// Skip markets resolving later than this (capital lock-up)
+    pub max_days_to_resolution: i64,
+    /// Keep the N highest-volume markets
+    pub max_markets: usize,
+}
+
+impl Default for PoliticsMarketFilter {
+    fn default() -> Self {
+        Self {
+            classes: PoliticalMarketClass::all(),
+            min_volume_usd: 250_000.0,
+            min_liquidity_usd: 10_000.0,
+            min_hours_to_resolution: 24,
+            max_days_to_resolution: 180,
+            max_markets: 40,
+        }
+    }
+}
+
+/// Political market discovery
+pub struct PoliticsMarketDiscovery {
+    client: PolymarketPoliticsClient,
+    filter: PoliticsMarketFilter,
+}
+
+impl PoliticsMarketDiscovery {
+    pub fn new(client: PolymarketPoliticsClient) -> Self {
+        Self::with_filter(client, PoliticsMarketFilter::default())
+    }
+
+    pub fn with_filter(client: PolymarketPoliticsClient, filter: PoliticsMarketFilter) -> Self {
+        Self { client, filter }
+    }
+
+    pub fn filter(&self) -> &PoliticsMarketFilter {
+        &self.filter
+    }
+
+    /// Discover markets with their class and activity, highest volume first
+    pub async fn discover_political_markets(&self) -> Result<Vec<PoliticalMarket>> {
+        let candidates = self.client.fetch_all_markets(DISCOVERY_FETCH_LIMIT).await?;
+        let scanned = candidates.len();
+        let markets = select_markets(candidates, &self.filter, Utc::now());
+        info!(
+            "Selected {} of {} markets for politics split arb ({:?})",
+            markets.len(),
+            scanned,
+            self.filter.classes
+        );
+        Ok(markets)
+    }
+}
+
+fn parse_end_date(end_date: &str) -> Option<DateTime<Utc>> {
+    DateTime::parse_from_rfc3339(end_date)
+        .ok()
+        .map(|dt| dt.with_timezone(&Utc))
+}
+
+/// Build a binary market; Gamma lists the YES token first
+fn binary_market(
+    market: &PolymarketPoliticsMarket,
+    end_time: DateTime<Utc>,
+) -> Option<BinaryMarket> {
+    let (yes_token_id, no_token_id) = market.get_token_ids()?;
+    if market.condition_id.is_empty() {
+        return None;
+    }
+    Some(BinaryMarket {
+        event_id: market
+            .slug
+            .clone()
+            .unwrap_or_else(|| market.condition_id.clone()),
+        condition_id: market.condition_id.clone(),
+        yes_token_id,
+        no_token_id,
+        yes_label: "Yes".to_string(),
+        no_label: "No".to_string(),
+        end_time,
+        market_type: MarketType::Political,
+        metadata: market.question.clone(),
+    })
+}
+
+/// Apply `filter` to raw Gamma markets
+pub fn select_markets(
+    candidates: Vec<PolymarketPoliticsMarket>,
+    filter: &PoliticsMarketFilter,
+    now: DateTime<Utc>,
+) -> Vec<PoliticalMarket> {
+    let earliest = now + Duration::hours(filter.min_hours_to_resolution);
+    let latest = now + Duration::days(filter.max_days_to_resolution);
+
+    let mut markets: Vec<PoliticalMarket> = candidates
+        .iter()
+        .filter(|m| m.active && !m.closed)
+        .filter_map(|m| {
+            let question = m.question.as_deref()?;
+            let class = classify_political_market(question, &m.tags)?;
+            if !filter.classes.contains(&class) {
+                return None;
+            }
+            let volume_usd = m.volume.unwrap_or(0.0);
+            let liquidity_usd = m.liquidity.unwrap_or(0.0);
+            if volume_usd < filter.min_volume_usd || liquidity_usd < filter.min_liquidity_usd {
+                return None;
+            }
+            // Both legs must be quoted; multi-candidate events arrive as one binary per candidate
+            m.get_prices()?;
+            let end_time = m.end_date.as_deref().and_then(parse_end_date)?;
+            if end_time < earliest || end_time > latest {
+                debug!("Skipping {}: resolves {}", question, end_time);
+                return None;
+            }
+            Some(PoliticalMarket {
+                class,
+                volume_usd,
+                liquidity_usd,
+                market: binary_market(m, end_time)?,
+            })
+        })
+        .collect();
+
+    markets.sort_by(|a, b| b.volume_usd.total_cmp(&a.volume_usd));
+    markets.truncate(filter.max_markets);
+    markets
+}
+
+#[async_trait]
+impl MarketDiscovery for PoliticsMarketDiscovery {
+    fn market_type(&self) -> MarketType {
+        MarketType::Political
+    }
+
+    async fn discover_markets(&self) -> Result<Vec<BinaryMarket>> {
+        Ok(self
+            .discover_political_markets()
+            .await?
+            .into_iter()
+            .map(|m| m.market)
+            .collect())
+    }
+
+    async fn get_market(&self, event_id: &str) -> Result<Option<BinaryMarket>> {
+        let event = self.client.get_event_details(event_id).await?;
+        let end_time = event
+            .end_date
+            .as_deref()
+            .and_then(parse_end_date)
+            .unwrap_or_else(Utc::now);
+
+        for data in &event.markets {
+            let (Some(condition_id), Some((yes_token_id, no_token_id))) =
+                (data.condition_id.clone(), data.get_token_ids())
+            else {
+                continue;
+            };
+            // The caller picked this event, so an unmatched question is still political
+            let class = classify_political_market(&data.question, &[])
+                .unwrap_or(PoliticalMarketClass::Other);
+            if !self.filter.classes.contains(&class) {
+                continue;
+            }
+            return Ok(Some(BinaryMarket {
+                event_id: event_id.to_string(),
+                condition_id,
+                yes_token_id,
+                no_token_id,
+                yes_label: "Yes".to_string(),
+                no_label: "No".to_string(),
+                end_time,
+                market_type: MarketType::Political,
+                metadata: Some(data.question.clone()),
+            }));
+        }
+
+        Ok(None)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn market(question: &str, volume: f64, days_out: i64) -> PolymarketPoliticsMarket {
+        PolymarketPoliticsMarket {
+            condition_id: format!("0x{}", question.len()),
+            question: Some(question.to_string()),
+            slug: None,
+            active: true,
+            closed: false,
+            end_date: Some((Utc::now() + Duration::days(days_out)).to_rfc3339()),
+            clob_token_ids: Some(r#"["111", "222"]"#.to_string()),
+            outcome_prices: Some(r#"["0.40", "0.60"]"#.to_string()),
+            volume: Some(volume),
+            liquidity: Some(50_000.0),
+            description: None,
+            tags: Vec::new(),
+        }
+    }
+
+    #[test]
+    fn test_select_markets_filters_and_ranks() {
+        let filter = PoliticsMarketFilter::default();
+        let candidates = vec![
+            market("Will Trump approval be above 45%?", 400_000.0, 30),
+            market("Democrats win the Senate in the midterm?", 2_000_000.0, 90),
+            // Too thin
+            market("Will NATO admit a new member?", 10_000.0, 60),
+            // Resolves tomorrow morning
+            market("Will the cabinet nomination pass?", 900_000.0, 0),
+            // Resolves beyond the horizon
+            market("Who wins the 2028 presidential election?", 5_000_000.0, 900),
+            // Not political
+            market("Will the Lakers win the title?", 3_000_000.0, 60),
+        ];
+
+        let selected = select_markets(candidates, &filter, Utc::now());
+        let classes: Vec<_> = selected.iter().map(|m| m.class).collect();
+        assert_eq!(
+            classes,
+            vec![
+                PoliticalMarketClass::Congressional,
+                PoliticalMarketClass::Approval
+            ]
+        );
+        assert_eq!(selected[0].market.yes_token_id, "111");
+        assert_eq!(selected[0].market.market_type, MarketType::Political);
+    }
+}
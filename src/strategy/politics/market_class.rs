@@ -0,0 +1,135 @@
+//! Political market classification
+//!
+//! Classifies Gamma political markets by subject (presidential, congressional,
+//! approval, geopolitical, executive) so a strategy can opt into classes.
+
+use crate::ai_clients::PoliticalCategory;
+use crate::strategy::core::BinaryMarket;
+use serde::{Deserialize, Serialize};
+
+/// Political market classes a strategy can opt into
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum PoliticalMarketClass {
+    Approval,
+    Executive,
+    Presidential,
+    Congressional,
+    Geopolitical,
+    /// Tagged political but no subject keyword matched
+    Other,
+}
+
+impl PoliticalMarketClass {
+    /// Matching precedence: "Trump approval" is an approval market, not a presidential one
+    const KEYWORD_CLASSES: [PoliticalMarketClass; 5] = [
+        PoliticalMarketClass::Approval,
+        PoliticalMarketClass::Executive,
+        PoliticalMarketClass::Presidential,
+        PoliticalMarketClass::Congressional,
+        PoliticalMarketClass::Geopolitical,
+    ];
+
+    pub fn all() -> Vec<PoliticalMarketClass> {
+        let mut classes = Self::KEYWORD_CLASSES.to_vec();
+        classes.push(PoliticalMarketClass::Other);
+        classes
+    }
+
+    pub fn category(&self) -> PoliticalCategory {
+        match self {
+            PoliticalMarketClass::Approval => PoliticalCategory::Approval,
+            PoliticalMarketClass::Executive => PoliticalCategory::Executive,
+            PoliticalMarketClass::Presidential => PoliticalCategory::Presidential,
+            PoliticalMarketClass::Congressional => PoliticalCategory::Congressional,
+            PoliticalMarketClass::Geopolitical => PoliticalCategory::Geopolitical,
+            PoliticalMarketClass::Other => PoliticalCategory::All,
+        }
+    }
+}
+
+impl std::fmt::Display for PoliticalMarketClass {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            PoliticalMarketClass::Approval => write!(f, "approval"),
+            PoliticalMarketClass::Executive => write!(f, "executive"),
+            PoliticalMarketClass::Presidential => write!(f, "presidential"),
+            PoliticalMarketClass::Congressional => write!(f, "congressional"),
+            PoliticalMarketClass::Geopolitical => write!(f, "geopolitical"),
+            PoliticalMarketClass::Other => write!(f, "other"),
+        }
+    }
+}
+
+/// A discovered political market with its class and activity
+#[derive(Debug, Clone)]
+pub struct PoliticalMarket {
+    pub class: PoliticalMarketClass,
+    pub volume_usd: f64,
+    pub liquidity_usd: f64,
+    pub market: BinaryMarket,
+}
+
+/// Classify a market by question and Gamma tags.
+///
+/// Keywords match whole words only, so "house" does not match "household".
+/// Returns `None` for markets that are not political.
+pub fn classify_political_market(question: &str, tags: &[String]) -> Option<PoliticalMarketClass> {
+    let question = question.to_lowercase();
+    let words: Vec<&str> = question
+        .split(|c: char| !c.is_alphanumeric())
+        .filter(|w| !w.is_empty())
+        .collect();
+
+    let class = PoliticalMarketClass::KEYWORD_CLASSES
+        .into_iter()
+        .find(|class| {
+            class
+                .category()
+                .keywords()
+                .iter()
+                .any(|k| words.contains(k))
+        });
+    if class.is_some() {
+        return class;
+    }
+
+    tags.iter()
+        .map(|t| t.to_lowercase())
+        .any(|t| t.contains("politic") || t.contains("election"))
+        .then_some(PoliticalMarketClass::Other)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_classify_political_market() {
+        let none: Vec<String> = Vec::new();
+        assert_eq!(
+            classify_political_market("Trump approval rating above 45% on June 30?", &none),
+            Some(PoliticalMarketClass::Approval)
+        );
+        assert_eq!(
+            classify_political_market("Will the Senate confirmation of Hegseth pass?", &none),
+            Some(PoliticalMarketClass::Executive)
+        );
+        assert_eq!(
+            classify_political_market("Will Newsom win the 2028 presidential election?", &none),
+            Some(PoliticalMarketClass::Presidential)
+        );
+        assert_eq!(
+            classify_political_market("Democrats win the House in the midterm?", &none),
+            Some(PoliticalMarketClass::Congressional)
+        );
+        assert_eq!(
+            classify_political_market("Will the household savings rate rise?", &none),
+            None
+        );
+        assert_eq!(
+            classify_political_market("Will Macron dissolve parliament?", &["Politics".into()]),
+            Some(PoliticalMarketClass::Other)
+        );
+    }
+}
@@ -0,0 +1,11 @@
+//! Politics market strategies
+//!
+//! Split arbitrage on high-volume binary political markets.
+
+mod discovery;
+mod market_class;
+mod runner;
+
+pub use discovery::{select_markets, PoliticsMarketDiscovery, PoliticsMarketFilter};
+pub use market_class::{classify_political_market, PoliticalMarket, PoliticalMarketClass};
+pub use runner::{PoliticsSplitArbAgent, PoliticsSplitArbConfig};
@@ -0,0 +1,361 @@
+//! Politics split arbitrage runner
+//!
+//! Runs the core split-arb engine over high-volume binary political markets
+//! as a standard `TradingAgent`: markets are rediscovered periodically, the
+//! engine is fed from a dedicated WebSocket, and state is reported to the
+//! coordinator on every heartbeat.
+
+use super::{PoliticsMarketDiscovery, PoliticsMarketFilter};
+use crate::adapters::PolymarketWebSocket;
+use crate::agents::{AgentContext, TradingAgent};
+use crate::coordinator::CoordinatorCommand;
+use crate::domain::Side;
+use crate::error::Result;
+use crate::platform::{AgentRiskParams, AgentStatus, Domain};
+use crate::strategy::core::{SplitArbConfig, SplitArbEngine};
+use async_trait::async_trait;
+use chrono::Utc;
+use rust_decimal::Decimal;
+use rust_decimal_macros::dec;
+use serde::{Deserialize, Serialize};
+use std::collections::{HashMap, HashSet};
+use std::sync::Arc;
+use tokio::sync::broadcast::error::RecvError;
+use tracing::{info, warn};
+
+const POLYMARKET_WS_URL: &str = "wss://ws-subscriptions-clob.polymarket.com/ws/market";
+
+/// Configuration specific to politics split arbitrage
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct PoliticsSplitArbConfig {
+    /// Base split arb config
+    #[serde(flatten)]
+    pub base: SplitArbConfig,
+
+    /// Market selection
+    #[serde(default)]
+    pub filter: PoliticsMarketFilter,
+
+    #[serde(default = "default_agent_id")]
+    pub agent_id: String,
+
+    #[serde(default = "default_name")]
+    pub name: String,
+
+    /// How often to rediscover markets
+    #[serde(default = "default_rediscover_interval_secs")]
+    pub rediscover_interval_secs: u64,
+
+    #[serde(default = "default_heartbeat_interval_secs")]
+    pub heartbeat_interval_secs: u64,
+
+    #[serde(default = "AgentRiskParams::conservative")]
+    pub risk_params: AgentRiskParams,
+}
+
+fn default_agent_id() -> String {
+    "politics_split_arb".to_string()
+}
+
+fn default_name() -> String {
+    "Politics Split Arb".to_string()
+}
+
+fn default_rediscover_interval_secs() -> u64 {
+    3600
+}
+
+fn default_heartbeat_interval_secs() -> u64 {
+    5
+}
+
+impl Default for PoliticsSplitArbConfig {
+    fn default() -> Self {
+        Self {
+            base: SplitArbConfig {
+                max_entry_price: dec!(0.48), // Political books rarely dip far below fair
+                target_total_cost: dec!(0.95), // Legs can sit for days, so accept thin pairs
+                min_profit_margin: dec!(0.03), // 3¢ minimum
+                max_hedge_wait_secs: 3 * 24 * 3600, // Prices move on news, not minutes
+                shares_per_trade: 50,
+                max_unhedged_positions: 4,
+                unhedged_stop_loss: dec!(0.30), // Wide: headline spikes mean-revert
+            },
+            filter: PoliticsMarketFilter::default(),
+            agent_id: default_agent_id(),
+            name: default_name(),
+            rediscover_interval_secs: default_rediscover_interval_secs(),
+            heartbeat_interval_secs: default_heartbeat_interval_secs(),
+            risk_params: AgentRiskParams::conservative(),
+        }
+    }
+}
+
+/// Pull-based agent running split arbitrage on political markets
+pub struct PoliticsSplitArbAgent {
+    config: PoliticsSplitArbConfig,
+    engine: SplitArbEngine,
+    discovery: PoliticsMarketDiscovery,
+    /// Condition IDs already handed to the engine
+    known_markets: HashSet<String>,
+}
+
+impl PoliticsSplitArbAgent {
+    /// `engine` should be built from `config.base`
+    pub fn new(
+        config: PoliticsSplitArbConfig,
+        engine: SplitArbEngine,
+        discovery: PoliticsMarketDiscovery,
+    ) -> Self {
+        Self {
+            config,
+            engine,
+            discovery,
+            known_markets: HashSet::new(),
+        }
+    }
+
+    /// Add newly discovered markets to the engine and the WebSocket subscription
+    async fn refresh_markets(&mut self, ws: &PolymarketWebSocket) {
+        let markets = match self.discovery.discover_political_markets().await {
+            Ok(markets) => markets,
+            Err(e) => {
+                warn!(agent = self.config.agent_id, error = %e, "politics discovery failed");
+                return;
+            }
+        };
+
+        let mut added = Vec::new();
+        for political in markets {
+            if !self
+                .known_markets
+                .insert(political.market.condition_id.clone())
+            {
+                continue;
+            }
+            ws.register_token(&political.market.yes_token_id, Side::Up)
+                .await;
+            ws.register_token(&political.market.no_token_id, Side::Down)
+                .await;
+            info!(
+                agent = self.config.agent_id,
+                class = %political.class,
+                volume_usd = political.volume_usd,
+                question = political.market.metadata.as_deref().unwrap_or(""),
+                "monitoring political market"
+            );
+            added.push(political.market);
+        }
+
+        if !added.is_empty() {
+            self.engine.add_markets(added).await;
+            ws.request_resubscribe();
+        }
+    }
+
+    async fn snapshot(&self, status: AgentStatus) -> crate::coordinator::AgentSnapshot {
+        let stats = self.engine.stats().await;
+        let (position_count, exposure) = self.engine.open_exposure().await;
+        let mut metrics = HashMap::new();
+        metrics.insert(
+            "markets".to_string(),
+            self.engine.market_count().await.to_string(),
+        );
+        metrics.insert(
+            "signals_detected".to_string(),
+            stats.signals_detected.to_string(),
+        );
+        metrics.insert(
+            "first_leg_entries".to_string(),
+            stats.first_leg_entries.to_string(),
+        );
+        metrics.insert(
+            "hedges_completed".to_string(),
+            stats.hedges_completed.to_string(),
+        );
+        metrics.insert("net_pnl".to_string(), stats.net_pnl().to_string());
+        metrics.insert("dry_run".to_string(), self.engine.is_dry_run().to_string());
+
+        crate::coordinator::AgentSnapshot {
+            agent_id: self.config.agent_id.clone(),
+            name: self.config.name.clone(),
+            domain: Domain::Politics,
+            status,
+            position_count,
+            exposure,
+            daily_pnl: Decimal::ZERO,
+            unrealized_pnl: Decimal::ZERO,
+            metrics,
+            last_heartbeat: Utc::now(),
+            error_message: None,
+        }
+    }
+}
+
+#[async_trait]
+impl TradingAgent for PoliticsSplitArbAgent {
+    fn id(&self) -> &str {
+        &self.config.agent_id
+    }
+
+    fn name(&self) -> &str {
+        &self.config.name
+    }
+
+    fn domain(&self) -> Domain {
+        Domain::Politics
+    }
+
+    fn risk_params(&self) -> AgentRiskParams {
+        self.config.risk_params.clone()
+    }
+
+    async fn run(mut self, mut ctx: AgentContext) -> Result<()> {
+        info!(
+            agent = self.config.agent_id,
+            dry_run = self.engine.is_dry_run(),
+            "politics split arb agent starting"
+        );
+
+        let ws = Arc::new(PolymarketWebSocket::new(POLYMARKET_WS_URL));
+        let mut updates = ws.subscribe_updates();
+        let ws_task = {
+            let ws = Arc::clone(&ws);
+            let agent_id = self.config.agent_id.clone();
+            tokio::spawn(async move {
+                if let Err(e) = ws.run(Vec::new()).await {
+                    warn!(agent = agent_id, error = %e, "politics WebSocket stopped");
+                }
+            })
+        };
+
+        let mut status = AgentStatus::Running;
+        let rediscover_dur = tokio::time::Duration::from_secs(self.config.rediscover_interval_secs);
+        let heartbeat_dur = tokio::time::Duration::from_secs(self.config.heartbeat_interval_secs);
+        let mut rediscover_tick = tokio::time::interval(rediscover_dur);
+        let mut heartbeat_tick = tokio::time::interval(heartbeat_dur);
+        rediscover_tick.set_missed_tick_behavior(tokio::time::MissedTickBehavior::Delay);
+        heartbeat_tick.set_missed_tick_behavior(tokio::time::MissedTickBehavior::Delay);
+
+        loop {
+            tokio::select! {
+                _ = rediscover_tick.tick() => {
+                    if matches!(status, AgentStatus::Running) {
+                        self.refresh_markets(&ws).await;
+                    }
+                }
+
+                update = updates.recv() => {
+                    match update {
+                        Ok(update) => {
+                            if !matches!(status, AgentStatus::Running) || !ctx.admit_quote() {
+                                continue;
+                            }
+                            self.engine
+                                .on_price_update(
+                                    &update.token_id,
+                                    update.quote.best_bid,
+                                    update.quote.best_ask,
+                                )
+                                .await;
+                        }
+                        Err(RecvError::Lagged(skipped)) => {
+                            warn!(agent = self.config.agent_id, skipped, "quote updates lagged");
+                        }
+                        Err(RecvError::Closed) => {
+                            warn!(agent = self.config.agent_id, "quote channel closed");
+                            break;
+                        }
+                    }
+                }
+
+                cmd = ctx.command_rx().recv() => {
+                    match cmd {
+                        Some(CoordinatorCommand::Pause) => {
+                            info!(agent = self.config.agent_id, "pausing");
+                            status = AgentStatus::Paused;
+                        }
+                        Some(CoordinatorCommand::Resume) => {
+                            info!(agent = self.config.agent_id, "resuming");
+                            status = AgentStatus::Running;
+                        }
+                        Some(CoordinatorCommand::Shutdown) | None => {
+                            info!(agent = self.config.agent_id, "shutting down");
+                            break;
+                        }
+                        Some(CoordinatorCommand::ForceClose) => {
+                            // Engine legs are tracked locally, not as coordinator positions
+                            warn!(
+                                agent = self.config.agent_id,
+                                "force close: stopping; open split-arb legs need manual review"
+                            );
+                            break;
+                        }
+                        Some(CoordinatorCommand::HealthCheck(tx)) => {
+                            let snapshot = self.snapshot(status).await;
+                            let stats = self.engine.stats().await;
+                            let _ = tx.send(crate::coordinator::AgentHealthResponse {
+                                snapshot,
+                                is_healthy: matches!(status, AgentStatus::Running),
+                                uptime_secs: 0,
+                                orders_submitted: stats.first_leg_entries + stats.hedges_completed,
+                                orders_filled: 0,
+                            });
+                        }
+                    }
+                }
+
+                _ = heartbeat_tick.tick() => {
+                    let snapshot = self.snapshot(status).await;
+                    let _ = ctx.report_state_with_metrics(
+                        &self.config.name,
+                        status,
+                        snapshot.position_count,
+                        snapshot.exposure,
+                        snapshot.daily_pnl,
+                        snapshot.unrealized_pnl,
+                        snapshot.metrics,
+                        None,
+                    ).await;
+                }
+            }
+        }
+
+        ws_task.abort();
+        self.engine.print_stats().await;
+        info!(
+            agent = self.config.agent_id,
+            "politics split arb agent stopped"
+        );
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_config_defaults_are_politics_tuned() {
+        let cfg = PoliticsSplitArbConfig::default();
+        let core = SplitArbConfig::default();
+        assert!(cfg.base.max_hedge_wait_secs > core.max_hedge_wait_secs);
+        assert!(cfg.base.max_entry_price > core.max_entry_price);
+        assert!(cfg.base.unhedged_stop_loss > core.unhedged_stop_loss);
+
+        let parsed: PoliticsSplitArbConfig = serde_json::from_value(serde_json::json!({
+            "max_entry_price": "0.45",
+            "target_total_cost": "0.95",
+            "min_profit_margin": "0.03",
+            "max_hedge_wait_secs": 86400,
+            "shares_per_trade": 20,
+            "max_unhedged_positions": 2,
+            "unhedged_stop_loss": "0.25",
+            "filter": { "min_volume_usd": 1000000.0 }
+        }))
+        .unwrap();
+        assert_eq!(parsed.agent_id, "politics_split_arb");
+        assert_eq!(parsed.filter.min_volume_usd, 1_000_000.0);
+        assert_eq!(parsed.filter.max_days_to_resolution, 180);
+    }
+}
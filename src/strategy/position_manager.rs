@@ -21,6 +21,7 @@ use rust_decimal::Decimal;
 use rust_decimal_macros::dec;
 use serde::{Deserialize, Serialize};
 use sqlx::types::chrono::{DateTime, Utc};
+use sqlx::{Postgres, QueryBuilder};
 use std::sync::Arc;
 use tracing::{debug, info, warn};
 
@@ -74,6 +75,16 @@ pub struct PositionSummary {
     pub win_rate: Decimal,
 }
 
+/// Filters and paging for [`PositionManager::list_positions`]
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+pub struct PositionQuery {
+    pub strategy_id: Option<String>,
+    pub symbol: Option<String>,
+    pub status: Option<PositionStatus>,
+    pub limit: i64,
+    pub offset: i64,
+}
+
 /// Position manager for persistent position tracking
 pub struct PositionManager {
     store: Arc<PostgresStore>,
@@ -278,7 +289,7 @@ impl PositionManager {
             SET status = 'CLOSED',
                 closed_at = NOW(),
                 exit_price = $1,
-                pnl = $2
+                pnl = COALESCE(pnl, 0) + $2
             WHERE id = $3
             "#,
         )
@@ -312,6 +323,55 @@ impl PositionManager {
         Ok(net_pnl)
     }
 
+    /// Record a partial exit of `shares` at `exit_price`.
+    ///
+    /// The remaining shares keep their average entry price; the gross PnL of
+    /// the sold shares accrues into `pnl`, which [`Self::close_position`]
+    /// adds to when the rest is closed. Returns that gross PnL.
+    pub async fn reduce_position(
+        &self,
+        position_id: i32,
+        shares: u64,
+        exit_price: Decimal,
+    ) -> Result<Decimal> {
+        let position = self.get_position(position_id).await?;
+        if position.status == PositionStatus::Closed {
+            return Err(PloyError::Internal(format!(
+                "Position {} is already closed",
+                position_id
+            )));
+        }
+        let held = position.shares.max(0) as u64;
+        if shares == 0 || shares >= held {
+            return Err(PloyError::Validation(format!(
+                "cannot reduce position {} holding {} shares by {}",
+                position_id, held, shares
+            )));
+        }
+
+        let gross_pnl = (exit_price - position.avg_entry_price) * Decimal::from(shares);
+        sqlx::query(
+            r#"
+            UPDATE positions
+            SET shares = shares - $1,
+                amount_usd = avg_entry_price * (shares - $1),
+                pnl = COALESCE(pnl, 0) + $2
+            WHERE id = $3 AND status = 'OPEN'
+            "#,
+        )
+        .bind(shares as i64)
+        .bind(gross_pnl)
+        .bind(position_id)
+        .execute(self.store.pool())
+        .await?;
+
+        info!(
+            "Reduced position #{}: {} sold {} of {} shares @ {} | Gross PnL: ${:.2}",
+            position_id, position.symbol, shares, held, exit_price, gross_pnl
+        );
+        Ok(gross_pnl)
+    }
+
     /// Get a position by ID
     pub async fn get_position(&self, position_id: i32) -> Result<Position> {
         let row = sqlx::query_as::<
@@ -663,6 +723,99 @@ impl PositionManager {
             Ok(None)
         }
     }
+
+    /// List positions matching `query`, newest first, with the total match count
+    pub async fn list_positions(&self, query: &PositionQuery) -> Result<(Vec<Position>, i64)> {
+        fn push_filters(qb: &mut QueryBuilder<'_, Postgres>, query: &PositionQuery) {
+            if let Some(strategy_id) = &query.strategy_id {
+                qb.push(" AND strategy_id = ")
+                    .push_bind(strategy_id.clone());
+            }
+            if let Some(symbol) = &query.symbol {
+                qb.push(" AND symbol = ").push_bind(symbol.clone());
+            }
+            if let Some(status) = query.status {
+                qb.push(" AND status = ").push_bind(status.to_string());
+            }
+        }
+
+        let mut count_qb =
+            QueryBuilder::<Postgres>::new("SELECT COUNT(*)::BIGINT FROM positions WHERE 1=1");
+        push_filters(&mut count_qb, query);
+        let (total,): (i64,) = count_qb
+            .build_query_as()
+            .fetch_one(self.store.pool())
+            .await?;
+
+        let mut qb = QueryBuilder::<Postgres>::new(
+            r#"
+            SELECT id, event_id, symbol, token_id, market_side,
+                   shares, avg_entry_price, amount_usd,
+                   opened_at, closed_at, status, pnl, exit_price, strategy_id
+            FROM positions
+            WHERE 1=1
+            "#,
+        );
+        push_filters(&mut qb, query);
+        qb.push(" ORDER BY opened_at DESC LIMIT ")
+            .push_bind(query.limit)
+            .push(" OFFSET ")
+            .push_bind(query.offset);
+
+        let rows = qb
+            .build_query_as::<(
+                i32,
+                String,
+                String,
+                String,
+                String,
+                i64,
+                Decimal,
+                Decimal,
+                DateTime<Utc>,
+                Option<DateTime<Utc>>,
+                String,
+                Option<Decimal>,
+                Option<Decimal>,
+                Option<String>,
+            )>()
+            .fetch_all(self.store.pool())
+            .await?;
+
+        let mut positions = Vec::with_capacity(rows.len());
+        for row in rows {
+            let market_side = match row.4.as_str() {
+                "UP" => Side::Up,
+                "DOWN" => Side::Down,
+                _ => continue,
+            };
+
+            let status = match row.10.as_str() {
+                "OPEN" => PositionStatus::Open,
+                "CLOSED" => PositionStatus::Closed,
+                _ => continue,
+            };
+
+            positions.push(Position {
+                id: row.0,
+                event_id: row.1,
+                symbol: row.2,
+                token_id: row.3,
+                market_side,
+                shares: row.5,
+                avg_entry_price: row.6,
+                amount_usd: row.7,
+                opened_at: row.8,
+                closed_at: row.9,
+                status,
+                pnl: row.11,
+                exit_price: row.12,
+                strategy_id: row.13,
+            });
+        }
+
+        Ok((positions, total))
+    }
 }
 
 #[cfg(test)]
@@ -5,16 +5,21 @@
 //! - Auto-correct minor differences
 //! - Alert on critical mismatches
 //! - Track reconciliation history
+//! - Cross-check both against on-chain CTF balances when an indexer is attached
 
-use crate::adapters::{PolymarketClient, PostgresStore};
+use crate::adapters::{
+    CtfBalanceIndexer, CtfTransfer, CtfTransferKind, PolymarketClient, PostgresStore,
+};
 use crate::error::Result;
 use crate::strategy::position_manager::PositionManager;
+use rust_decimal::prelude::ToPrimitive;
 use rust_decimal::Decimal;
 use serde::{Deserialize, Serialize};
 use sqlx::types::chrono::{DateTime, Utc};
-use std::collections::HashMap;
+use std::collections::{HashMap, HashSet};
 use std::sync::Arc;
 use std::time::Duration;
+use tokio::sync::Mutex;
 use tokio::time::{interval, Instant};
 use tracing::{debug, error, info, warn};
 
@@ -49,6 +54,60 @@ pub struct PositionDiscrepancy {
     pub severity: DiscrepancySeverity,
 }
 
+/// How the on-chain balance explains a local / Data API mismatch
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+pub enum OnchainDiscrepancyKind {
+    /// Tokens were burned (redeemed / merged) but the local book still holds them
+    MissedSettlement,
+    /// The latest indexed transfer accounts for the gap the local book missed
+    MissedTransfer,
+    /// Chain agrees with the local book; the Data API is lagging
+    DataApiStale,
+    /// Chain agrees with the Data API; the local book drifted
+    LocalDrift,
+    /// Local, Data API and chain all disagree
+    Unexplained,
+}
+
+/// Three-way position mismatch against the wallet's on-chain balance
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct OnchainDiscrepancy {
+    pub token_id: String,
+    pub local_shares: i64,
+    pub data_api_shares: i64,
+    pub onchain_shares: i64,
+    pub kind: OnchainDiscrepancyKind,
+    pub severity: DiscrepancySeverity,
+    /// Latest indexed transfer of the token, if any
+    pub last_transfer_tx: Option<String>,
+}
+
+/// Classify local / Data API / on-chain share counts for one token
+pub fn classify_onchain(
+    local_shares: i64,
+    data_api_shares: i64,
+    onchain_shares: i64,
+    last_transfer: Option<&CtfTransfer>,
+) -> Option<OnchainDiscrepancyKind> {
+    if local_shares == onchain_shares {
+        return (data_api_shares != onchain_shares).then_some(OnchainDiscrepancyKind::DataApiStale);
+    }
+    if let Some(transfer) = last_transfer {
+        if transfer.kind.is_outflow() == (onchain_shares < local_shares) {
+            return Some(if transfer.kind == CtfTransferKind::Burned {
+                OnchainDiscrepancyKind::MissedSettlement
+            } else {
+                OnchainDiscrepancyKind::MissedTransfer
+            });
+        }
+    }
+    if data_api_shares == onchain_shares {
+        Some(OnchainDiscrepancyKind::LocalDrift)
+    } else {
+        Some(OnchainDiscrepancyKind::Unexplained)
+    }
+}
+
 /// Reconciliation result
 #[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct ReconciliationResult {
@@ -58,6 +117,9 @@ pub struct ReconciliationResult {
     pub critical_issues: usize,
     pub duration_ms: u64,
     pub discrepancies: Vec<PositionDiscrepancy>,
+    /// Mismatches against on-chain balances (empty without an indexer)
+    #[serde(default)]
+    pub onchain_discrepancies: Vec<OnchainDiscrepancy>,
 }
 
 /// Reconciliation configuration
@@ -88,6 +150,7 @@ pub struct ReconciliationService {
     client: Arc<PolymarketClient>,
     store: Arc<PostgresStore>,
     config: ReconciliationConfig,
+    onchain: Option<Mutex<CtfBalanceIndexer>>,
 }
 
 impl ReconciliationService {
@@ -103,9 +166,16 @@ impl ReconciliationService {
             client,
             store,
             config,
+            onchain: None,
         }
     }
 
+    /// Also reconcile against the wallet's on-chain CTF balances
+    pub fn with_onchain_indexer(mut self, indexer: CtfBalanceIndexer) -> Self {
+        self.onchain = Some(Mutex::new(indexer));
+        self
+    }
+
     /// Run reconciliation service in background
     ///
     /// This will run indefinitely, performing reconciliation at the configured interval.
@@ -142,6 +212,19 @@ impl ReconciliationService {
                             );
                         }
                     }
+                    for disc in &result.onchain_discrepancies {
+                        if disc.severity == DiscrepancySeverity::Critical {
+                            error!(
+                                "CRITICAL: On-chain {:?} for {}: local={}, data_api={}, onchain={}, tx={:?}",
+                                disc.kind,
+                                &disc.token_id[..16.min(disc.token_id.len())],
+                                disc.local_shares,
+                                disc.data_api_shares,
+                                disc.onchain_shares,
+                                disc.last_transfer_tx
+                            );
+                        }
+                    }
                 }
                 Err(e) => {
                     error!("Reconciliation failed: {}", e);
@@ -170,6 +253,21 @@ impl ReconciliationService {
         // Get exchange balances
         let exchange_balances = self.get_exchange_balances().await?;
 
+        // Chain balances decide between local and Data API when they disagree
+        let onchain_discrepancies =
+            match self.reconcile_onchain(&local_map, &exchange_balances).await {
+                Ok(found) => found,
+                Err(e) => {
+                    warn!("On-chain reconciliation failed: {}", e);
+                    Vec::new()
+                }
+            };
+        let data_api_stale: HashSet<&str> = onchain_discrepancies
+            .iter()
+            .filter(|d| d.kind == OnchainDiscrepancyKind::DataApiStale)
+            .map(|d| d.token_id.as_str())
+            .collect();
+
         // Compare and detect discrepancies
         let mut discrepancies = Vec::new();
         let mut auto_corrections = 0;
@@ -189,7 +287,15 @@ impl ReconciliationService {
             let exchange_shares = *exchange_balances.get(&token_id).unwrap_or(&0);
             let difference = local_shares - exchange_shares;
 
-            if difference != 0 {
+            if difference != 0 && data_api_stale.contains(token_id.as_str()) {
+                // Local matches the chain: never "correct" it towards a lagging Data API
+                debug!(
+                    "Data API lags chain for {}: local={}, exchange={}",
+                    &token_id[..16.min(token_id.len())],
+                    local_shares,
+                    exchange_shares
+                );
+            } else if difference != 0 {
                 // Calculate severity
                 let severity = self.calculate_severity(local_shares, exchange_shares);
 
@@ -231,16 +337,37 @@ impl ReconciliationService {
             }
         }
 
+        for disc in &onchain_discrepancies {
+            if disc.kind == OnchainDiscrepancyKind::DataApiStale {
+                continue;
+            }
+            if disc.severity == DiscrepancySeverity::Critical {
+                critical_issues += 1;
+            }
+            if let Err(e) = self
+                .record_discrepancy(
+                    &disc.token_id,
+                    disc.local_shares,
+                    disc.onchain_shares,
+                    disc.severity,
+                )
+                .await
+            {
+                warn!("Failed to record on-chain discrepancy: {}", e);
+            }
+        }
+
         let duration_ms = start.elapsed().as_millis() as u64;
 
         // Record reconciliation result
         let result = ReconciliationResult {
             timestamp: Utc::now(),
-            discrepancies_found: discrepancies.len(),
+            discrepancies_found: discrepancies.len() + onchain_discrepancies.len(),
             auto_corrections,
             critical_issues,
             duration_ms,
             discrepancies,
+            onchain_discrepancies,
         };
 
         self.record_reconciliation(&result).await?;
@@ -268,6 +395,65 @@ impl ReconciliationService {
         Ok(balances)
     }
 
+    /// Compare local and Data API positions with the wallet's on-chain balances
+    async fn reconcile_onchain(
+        &self,
+        local_map: &HashMap<String, (i64, Vec<(i32, i64)>)>,
+        exchange_balances: &HashMap<String, i64>,
+    ) -> Result<Vec<OnchainDiscrepancy>> {
+        let Some(indexer) = &self.onchain else {
+            return Ok(Vec::new());
+        };
+        let mut indexer = indexer.lock().await;
+        indexer.sync().await?;
+
+        // Tokens known to either book, plus anything that moved on-chain
+        let mut tokens: Vec<String> = local_map
+            .keys()
+            .chain(exchange_balances.keys())
+            .chain(indexer.indexed_tokens())
+            .cloned()
+            .collect();
+        tokens.sort();
+        tokens.dedup();
+        let balances = indexer.balances(&tokens).await?;
+
+        let mut found = Vec::new();
+        for token_id in tokens {
+            let local_shares = local_map.get(&token_id).map_or(0, |(shares, _)| *shares);
+            let data_api_shares = exchange_balances.get(&token_id).copied().unwrap_or(0);
+            let onchain_shares = balances
+                .get(&token_id)
+                .and_then(|b| b.trunc().to_i64())
+                .unwrap_or(0);
+            let last_transfer = indexer.last_transfer(&token_id);
+            let Some(kind) =
+                classify_onchain(local_shares, data_api_shares, onchain_shares, last_transfer)
+            else {
+                continue;
+            };
+            let severity = match kind {
+                OnchainDiscrepancyKind::MissedSettlement | OnchainDiscrepancyKind::Unexplained => {
+                    DiscrepancySeverity::Critical
+                }
+                OnchainDiscrepancyKind::DataApiStale => DiscrepancySeverity::Info,
+                OnchainDiscrepancyKind::MissedTransfer | OnchainDiscrepancyKind::LocalDrift => {
+                    self.calculate_severity(local_shares, onchain_shares)
+                }
+            };
+            found.push(OnchainDiscrepancy {
+                last_transfer_tx: last_transfer.map(|t| t.transaction_hash.clone()),
+                token_id,
+                local_shares,
+                data_api_shares,
+                onchain_shares,
+                kind,
+                severity,
+            });
+        }
+        Ok(found)
+    }
+
     /// Calculate discrepancy severity
     fn calculate_severity(&self, local_shares: i64, exchange_shares: i64) -> DiscrepancySeverity {
         if exchange_shares == 0 {
@@ -459,6 +645,7 @@ impl ReconciliationService {
                 critical_issues,
                 duration_ms: row.4.unwrap_or(0) as u64,
                 discrepancies,
+                onchain_discrepancies: Vec::new(),
             });
         }
 
@@ -546,6 +733,45 @@ mod tests {
         assert!(diff_pct >= config.critical_threshold_pct);
     }
 
+    #[test]
+    fn test_classify_onchain() {
+        use rust_decimal_macros::dec;
+
+        let transfer = |kind| CtfTransfer {
+            block_number: 1,
+            transaction_hash: "0xabc".to_string(),
+            token_id: "123".to_string(),
+            from: String::new(),
+            to: String::new(),
+            amount: dec!(100),
+            kind,
+        };
+
+        assert_eq!(classify_onchain(100, 100, 100, None), None);
+        assert_eq!(
+            classify_onchain(100, 0, 100, None),
+            Some(OnchainDiscrepancyKind::DataApiStale)
+        );
+        // Redeemed on-chain, Data API caught up, local book never closed
+        assert_eq!(
+            classify_onchain(100, 0, 0, Some(&transfer(CtfTransferKind::Burned))),
+            Some(OnchainDiscrepancyKind::MissedSettlement)
+        );
+        assert_eq!(
+            classify_onchain(100, 0, 0, Some(&transfer(CtfTransferKind::Sent))),
+            Some(OnchainDiscrepancyKind::MissedTransfer)
+        );
+        // An inflow cannot explain a lower balance
+        assert_eq!(
+            classify_onchain(100, 60, 60, Some(&transfer(CtfTransferKind::Received))),
+            Some(OnchainDiscrepancyKind::LocalDrift)
+        );
+        assert_eq!(
+            classify_onchain(100, 80, 60, None),
+            Some(OnchainDiscrepancyKind::Unexplained)
+        );
+    }
+
     // Note: Integration tests require database and exchange client
     // Run with: cargo test --features test-integration
 }
@@ -0,0 +1,291 @@
+//! Versioned research report artifacts.
+//!
+//! Every backtest/research run emits a [`ResearchReport`] carrying what is
+//! needed to reproduce it: config hash, git sha, data snapshot id and seed.
+//! Reports are stored one JSON file per run under
+//! `<root>/<kind>/<id>.json` (root defaults to `research_reports`, override
+//! with `PLOY_RESEARCH_REPORTS_DIR`). Promotion gates and audits should read
+//! these artifacts rather than ad-hoc `--output` files.
+//!
+//! Usage:
+//!   ploy research list --kind backtest.momentum
+//!   ploy research show backtest.momentum-20260101T000000Z-1a2b3c4d
+
+use std::path::{Path, PathBuf};
+
+use chrono::{DateTime, Utc};
+use serde::{Deserialize, Serialize};
+use sha2::{Digest, Sha256};
+use tracing::warn;
+
+use crate::error::{PloyError, Result};
+
+/// Bumped whenever the artifact layout changes incompatibly.
+pub const REPORT_SCHEMA_VERSION: u32 = 1;
+/// Default report root, relative to the working directory.
+pub const DEFAULT_REPORTS_DIR: &str = "research_reports";
+
+/// What is needed to reproduce a run.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct ReproMetadata {
+    /// SHA-256 of the canonical JSON config
+    pub config_hash: String,
+    /// Commit the binary was run from (`PLOY_GIT_SHA` or `git rev-parse HEAD`)
+    pub git_sha: Option<String>,
+    /// Content hash of the replayed input data
+    pub data_snapshot_id: Option<String>,
+    /// RNG seed, for stochastic runs
+    pub seed: Option<u64>,
+}
+
+/// One stored research run.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct ResearchReport {
+    pub schema_version: u32,
+    pub id: String,
+    /// Run family, e.g. `backtest.momentum`
+    pub kind: String,
+    pub created_at: DateTime<Utc>,
+    pub repro: ReproMetadata,
+    pub config: serde_json::Value,
+    /// Headline numbers shown by `ploy research list`
+    #[serde(default)]
+    pub summary: serde_json::Value,
+    pub results: serde_json::Value,
+}
+
+/// Report fields needed for listings (skips the full results payload).
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct ResearchReportHeader {
+    pub schema_version: u32,
+    pub id: String,
+    pub kind: String,
+    pub created_at: DateTime<Utc>,
+    pub repro: ReproMetadata,
+    #[serde(default)]
+    pub summary: serde_json::Value,
+}
+
+impl ResearchReport {
+    /// Build a report for a finished run, stamping config hash and git sha.
+    pub fn new<C: Serialize, R: Serialize>(kind: &str, config: &C, results: &R) -> Result<Self> {
+        let config = serde_json::to_value(config)?;
+        let config_hash = config_hash(&config);
+        let created_at = Utc::now();
+        let id = format!(
+            "{}-{}-{}",
+            kind,
+            created_at.format("%Y%m%dT%H%M%SZ"),
+            &config_hash[..8]
+        );
+
+        Ok(Self {
+            schema_version: REPORT_SCHEMA_VERSION,
+            id,
+            kind: kind.to_string(),
+            created_at,
+            repro: ReproMetadata {
+                config_hash,
+                git_sha: current_git_sha(),
+                data_snapshot_id: None,
+                seed: None,
+            },
+            config,
+            summary: serde_json::Value::Null,
+            results: serde_json::to_value(results)?,
+        })
+    }
+
+    pub fn with_data_snapshot(mut self, snapshot_id: impl Into<String>) -> Self {
+        self.repro.data_snapshot_id = Some(snapshot_id.into());
+        self
+    }
+
+    pub fn with_seed(mut self, seed: u64) -> Self {
+        self.repro.seed = Some(seed);
+        self
+    }
+
+    pub fn with_summary(mut self, summary: serde_json::Value) -> Self {
+        self.summary = summary;
+        self
+    }
+}
+
+/// SHA-256 (hex) of a config's JSON encoding.
+pub fn config_hash(config: &serde_json::Value) -> String {
+    let mut hasher = Sha256::new();
+    hasher.update(config.to_string().as_bytes());
+    hex::encode(hasher.finalize())
+}
+
+/// Commit the current binary runs from, if known.
+pub fn current_git_sha() -> Option<String> {
+    if let Ok(sha) = std::env::var("PLOY_GIT_SHA") {
+        let sha = sha.trim().to_string();
+        if !sha.is_empty() {
+            return Some(sha);
+        }
+    }
+
+    let output = std::process::Command::new("git")
+        .args(["rev-parse", "HEAD"])
+        .output()
+        .ok()?;
+    if !output.status.success() {
+        return None;
+    }
+    let sha = String::from_utf8(output.stdout).ok()?.trim().to_string();
+    (!sha.is_empty()).then_some(sha)
+}
+
+/// Directory-backed report store (`<root>/<kind>/<id>.json`).
+#[derive(Debug, Clone)]
+pub struct ResearchReportStore {
+    root: PathBuf,
+}
+
+impl ResearchReportStore {
+    pub fn new(root: impl Into<PathBuf>) -> Self {
+        Self { root: root.into() }
+    }
+
+    /// Store rooted at `PLOY_RESEARCH_REPORTS_DIR`, or `research_reports`.
+    pub fn from_env() -> Self {
+        let root = std::env::var("PLOY_RESEARCH_REPORTS_DIR")
+            .ok()
+            .filter(|dir| !dir.trim().is_empty())
+            .unwrap_or_else(|| DEFAULT_REPORTS_DIR.to_string());
+        Self::new(root)
+    }
+
+    pub fn root(&self) -> &Path {
+        &self.root
+    }
+
+    /// Write a report; never overwrites an existing artifact.
+    pub fn save(&self, report: &ResearchReport) -> Result<PathBuf> {
+        let dir = self.root.join(&report.kind);
+        std::fs::create_dir_all(&dir)?;
+        let path = dir.join(format!("{}.json", report.id));
+
+        let file = std::fs::OpenOptions::new()
+            .write(true)
+            .create_new(true)
+            .open(&path)
+            .map_err(|e| match e.kind() {
+                std::io::ErrorKind::AlreadyExists => {
+                    PloyError::Validation(format!("research report {} already exists", report.id))
+                }
+                _ => PloyError::Io(e),
+            })?;
+        serde_json::to_writer_pretty(file, report)?;
+        Ok(path)
+    }
+
+    /// Report headers, newest first, optionally filtered by kind.
+    pub fn list(&self, kind: Option<&str>) -> Result<Vec<ResearchReportHeader>> {
+        let mut headers = Vec::new();
+        for path in self.report_paths(kind)? {
+            let raw = std::fs::read_to_string(&path)?;
+            match serde_json::from_str::<ResearchReportHeader>(&raw) {
+                Ok(header) => headers.push(header),
+                Err(e) => warn!("Skipping unreadable report {}: {}", path.display(), e),
+            }
+        }
+        headers.sort_by(|a, b| b.created_at.cmp(&a.created_at));
+        Ok(headers)
+    }
+
+    /// Load a full report by id.
+    pub fn load(&self, id: &str) -> Result<ResearchReport> {
+        let file_name = format!("{}.json", id);
+        let path = self
+            .report_paths(None)?
+            .into_iter()
+            .find(|path| path.file_name().and_then(|n| n.to_str()) == Some(file_name.as_str()))
+            .ok_or_else(|| PloyError::Validation(format!("research report {} not found", id)))?;
+
+        let raw = std::fs::read_to_string(&path)?;
+        serde_json::from_str(&raw).map_err(|e| {
+            PloyError::Validation(format!(
+                "{} is not a research report: {}",
+                path.display(),
+                e
+            ))
+        })
+    }
+
+    fn report_paths(&self, kind: Option<&str>) -> Result<Vec<PathBuf>> {
+        if !self.root.exists() {
+            return Ok(Vec::new());
+        }
+
+        let kind_dirs: Vec<PathBuf> = match kind {
+            Some(kind) => vec![self.root.join(kind)],
+            None => std::fs::read_dir(&self.root)?
+                .filter_map(|entry| entry.ok().map(|e| e.path()))
+                .filter(|path| path.is_dir())
+                .collect(),
+        };
+
+        let mut paths = Vec::new();
+        for dir in kind_dirs.into_iter().filter(|dir| dir.is_dir()) {
+            for entry in std::fs::read_dir(dir)? {
+                let path = entry?.path();
+                if path.extension().and_then(|e| e.to_str()) == Some("json") {
+                    paths.push(path);
+                }
+            }
+        }
+        Ok(paths)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use serde_json::json;
+
+    #[test]
+    fn test_config_hash_is_stable() {
+        let a = config_hash(&json!({"symbols": ["BTCUSDT"], "capital": 1000}));
+        let b = config_hash(&json!({"symbols": ["BTCUSDT"], "capital": 1000}));
+        let c = config_hash(&json!({"symbols": ["ETHUSDT"], "capital": 1000}));
+        assert_eq!(a, b);
+        assert_ne!(a, c);
+        assert_eq!(a.len(), 64);
+    }
+
+    #[test]
+    fn test_store_round_trip() {
+        let root = std::env::temp_dir().join(format!("ploy_reports_{}", uuid::Uuid::new_v4()));
+        let store = ResearchReportStore::new(&root);
+        assert!(store.list(None).unwrap().is_empty());
+
+        let report = ResearchReport::new(
+            "backtest.momentum",
+            &json!({"symbols": ["BTCUSDT"]}),
+            &json!({"total_trades": 3}),
+        )
+        .unwrap()
+        .with_data_snapshot("abc123")
+        .with_seed(42)
+        .with_summary(json!({"total_trades": 3}));
+
+        store.save(&report).unwrap();
+        assert!(store.save(&report).is_err(), "artifacts are immutable");
+
+        let listed = store.list(Some("backtest.momentum")).unwrap();
+        assert_eq!(listed.len(), 1);
+        assert_eq!(listed[0].id, report.id);
+        assert!(store.list(Some("backtest.directional")).unwrap().is_empty());
+
+        let loaded = store.load(&report.id).unwrap();
+        assert_eq!(loaded.repro, report.repro);
+        assert_eq!(loaded.results, json!({"total_trades": 3}));
+        assert!(store.load("missing").is_err());
+
+        std::fs::remove_dir_all(&root).ok();
+    }
+}
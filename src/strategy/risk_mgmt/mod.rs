@@ -9,5 +9,5 @@ pub use slippage::{MarketDepth, SlippageCheck, SlippageConfig, SlippageProtectio
 pub use validation::{
     leg1_entry_chain, leg2_entry_chain, ExposureValidator, RiskStateValidator, SpreadValidator,
     SumTargetValidator, TimeRemainingValidator, ValidationChain, ValidationContext,
-    ValidationError, Validator,
+    ValidationError, Validator, ValidatorOutcome,
 };
@@ -1,3 +1,5 @@
+#[cfg(feature = "api")]
+use crate::api::webhooks::{self, WebhookEventType};
 use crate::config::RiskConfig;
 use crate::domain::{RiskState, Round};
 use crate::error::{Result, RiskError};
@@ -186,6 +188,16 @@ impl RiskManager {
         error!("CIRCUIT BREAKER TRIGGERED: {}", reason);
         *self.state.write().await = RiskState::Halted;
         *self.halt_reason.write().await = Some(reason.to_string());
+        #[cfg(feature = "api")]
+        webhooks::emit(
+            WebhookEventType::CircuitBreaker,
+            serde_json::json!({
+                "source": "risk_manager",
+                "state": "halted",
+                "reason": reason,
+                "halted_at": Utc::now(),
+            }),
+        );
     }
 
     /// Reset circuit breaker (manual intervention)
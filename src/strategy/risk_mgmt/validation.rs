@@ -5,6 +5,7 @@
 
 use crate::domain::{RiskState, Round};
 use crate::error::{PloyError, Result};
+use crate::strategy::execution::FillabilityGate;
 use rust_decimal::Decimal;
 use std::fmt;
 
@@ -61,6 +62,8 @@ pub struct ValidationContext {
     pub risk_state: Option<RiskState>,
     /// Sum target for arbitrage
     pub sum_target: Option<Decimal>,
+    /// Live passive fill rate of the traded token (None = too few samples)
+    pub live_fill_rate: Option<f64>,
 }
 
 impl ValidationContext {
@@ -74,6 +77,7 @@ impl ValidationContext {
             round: None,
             risk_state: None,
             sum_target: None,
+            live_fill_rate: None,
         }
     }
 
@@ -112,6 +116,12 @@ impl ValidationContext {
         self.sum_target = Some(target);
         self
     }
+
+    /// Usually `FillabilityGate::fill_rate` for the traded token
+    pub fn with_live_fill_rate(mut self, fill_rate: Option<f64>) -> Self {
+        self.live_fill_rate = fill_rate;
+        self
+    }
 }
 
 impl Default for ValidationContext {
@@ -451,10 +461,92 @@ impl Validator for SumTargetValidator {
     }
 }
 
+// =============================================================================
+// Fillability Validator
+// =============================================================================
+
+/// Blocks sizing above `base_shares` until the token's live passive fill rate
+/// has reached `min_fill_rate`
+pub struct FillabilityValidator {
+    pub min_fill_rate: f64,
+    pub base_shares: u64,
+}
+
+impl FillabilityValidator {
+    pub fn new(min_fill_rate: f64, base_shares: u64) -> Self {
+        Self {
+            min_fill_rate,
+            base_shares,
+        }
+    }
+
+    pub fn from_gate(gate: &FillabilityGate) -> Self {
+        Self::new(gate.min_fill_rate, gate.base_shares)
+    }
+}
+
+impl Validator for FillabilityValidator {
+    fn name(&self) -> &str {
+        "Fillability"
+    }
+
+    fn validate(&self, ctx: &ValidationContext) -> Result<()> {
+        let shares = ctx.shares.ok_or_else(|| ValidationError {
+            validator: self.name().to_string(),
+            reason: "Missing shares".to_string(),
+            details: None,
+        })?;
+
+        if shares <= self.base_shares {
+            return Ok(());
+        }
+
+        match ctx.live_fill_rate {
+            Some(rate) if rate >= self.min_fill_rate => Ok(()),
+            rate => Err(ValidationError {
+                validator: self.name().to_string(),
+                reason: "Insufficient live fillability to size up".to_string(),
+                details: Some(format!(
+                    "shares={}, base={}, fill_rate={}, min={:.2}",
+                    shares,
+                    self.base_shares,
+                    rate.map_or_else(|| "unproven".to_string(), |r| format!("{:.2}", r)),
+                    self.min_fill_rate
+                )),
+            }
+            .into()),
+        }
+    }
+
+    fn is_applicable(&self, ctx: &ValidationContext) -> bool {
+        ctx.shares.is_some()
+    }
+}
+
 // =============================================================================
 // Validation Chain
 // =============================================================================
 
+/// Outcome of one validator, as reported by [`ValidationChain::outcomes`]
+#[derive(Debug, Clone)]
+pub struct ValidatorOutcome {
+    pub validator: String,
+    /// False when the validator was skipped as not applicable
+    pub applicable: bool,
+    /// Rejection reason (None = passed or skipped)
+    pub error: Option<String>,
+}
+
+impl ValidatorOutcome {
+    pub fn status(&self) -> &'static str {
+        match (self.applicable, &self.error) {
+            (false, _) => "SKIP",
+            (true, None) => "PASS",
+            (true, Some(_)) => "FAIL",
+        }
+    }
+}
+
 /// Chain of validators to run in sequence
 pub struct ValidationChain {
     validators: Vec<Box<dyn Validator>>,
@@ -512,6 +604,29 @@ impl ValidationChain {
         }
         errors
     }
+
+    /// Run every validator and report each one, skipped validators included
+    pub fn outcomes(&self, ctx: &ValidationContext) -> Vec<ValidatorOutcome> {
+        self.validators
+            .iter()
+            .map(|validator| {
+                let applicable = !self.skip_inapplicable || validator.is_applicable(ctx);
+                let error = if applicable {
+                    validator.validate(ctx).err().map(|e| match e {
+                        PloyError::Validation(msg) => msg,
+                        other => other.to_string(),
+                    })
+                } else {
+                    None
+                };
+                ValidatorOutcome {
+                    validator: validator.name().to_string(),
+                    applicable,
+                    error,
+                }
+            })
+            .collect()
+    }
 }
 
 impl Default for ValidationChain {
@@ -525,16 +640,24 @@ impl Default for ValidationChain {
 // =============================================================================
 
 /// Create a validation chain for Leg1 entry
+///
+/// An enabled fillability gate also caps the size until the token's live
+/// fill rate (`ValidationContext::live_fill_rate`) is proven.
 pub fn leg1_entry_chain(
     max_exposure: Decimal,
     min_time_seconds: u64,
     max_spread_bps: u32,
+    fillability: Option<&FillabilityGate>,
 ) -> ValidationChain {
-    ValidationChain::new()
+    let chain = ValidationChain::new()
         .add(RiskStateValidator::new())
         .add(ExposureValidator::new(max_exposure))
         .add(TimeRemainingValidator::new(min_time_seconds))
-        .add(SpreadValidator::new(max_spread_bps))
+        .add(SpreadValidator::new(max_spread_bps));
+    match fillability.filter(|gate| gate.enabled) {
+        Some(gate) => chain.add(FillabilityValidator::from_gate(gate)),
+        None => chain,
+    }
 }
 
 /// Create a validation chain for Leg2 entry
@@ -619,6 +742,23 @@ mod tests {
         assert!(validator.validate(&ctx).is_err());
     }
 
+    #[test]
+    fn test_fillability_validator() {
+        let validator = FillabilityValidator::new(0.4, 50);
+
+        // Base size never needs proof
+        let ctx = ValidationContext::new().with_trade(50, dec!(0.50));
+        assert!(validator.validate(&ctx).is_ok());
+
+        // Sizing up needs a proven fill rate above the minimum
+        let ctx = ValidationContext::new().with_trade(100, dec!(0.50));
+        assert!(validator.validate(&ctx).is_err());
+        let ctx = ctx.with_live_fill_rate(Some(0.2));
+        assert!(validator.validate(&ctx).is_err());
+        let ctx = ctx.with_live_fill_rate(Some(0.6));
+        assert!(validator.validate(&ctx).is_ok());
+    }
+
     #[test]
     fn test_validation_chain() {
         let chain = ValidationChain::new()
@@ -640,7 +780,7 @@ mod tests {
 
     #[test]
     fn test_leg1_entry_chain() {
-        let chain = leg1_entry_chain(dec!(100), 60, 200);
+        let chain = leg1_entry_chain(dec!(100), 60, 200, None);
 
         let ctx = ValidationContext::new()
             .with_trade(100, dec!(0.50))
@@ -649,5 +789,14 @@ mod tests {
             .with_risk_state(RiskState::Normal);
 
         assert!(chain.validate(&ctx).is_ok());
+
+        // An enabled gate holds the size at base until fillability is proven
+        let gate = FillabilityGate {
+            enabled: true,
+            ..FillabilityGate::default()
+        };
+        let gated = leg1_entry_chain(dec!(100), 60, 200, Some(&gate));
+        assert!(gated.validate(&ctx).is_err());
+        assert!(gated.validate(&ctx.with_live_fill_rate(Some(0.6))).is_ok());
     }
 }
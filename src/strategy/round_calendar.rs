@@ -0,0 +1,322 @@
+//! Round calendar
+//!
+//! Tracks round end times parsed from Gamma series metadata so round rotation
+//! can be scheduled at the exact boundary instead of being inferred from
+//! periodic token diffs. Each round produces three transitions:
+//!
+//! - `WarmUp` at `end_time - warmup_secs` (pre-rotation window)
+//! - `CoolDown` at `end_time` (rotation / settlement)
+//! - `Open` at `end_time + cooldown_secs` (post-rotation window ends)
+
+use chrono::{DateTime, Duration, Utc};
+use serde::{Deserialize, Serialize};
+use std::collections::HashMap;
+
+use crate::adapters::polymarket_clob::GammaEventInfo;
+
+/// Round lifecycle phase for a series
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum RoundPhase {
+    /// Normal trading inside a round
+    Open,
+    /// Within `warmup_secs` before the current round settles
+    WarmUp,
+    /// Within `cooldown_secs` after a round settled
+    CoolDown,
+}
+
+impl RoundPhase {
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            RoundPhase::Open => "open",
+            RoundPhase::WarmUp => "warm_up",
+            RoundPhase::CoolDown => "cool_down",
+        }
+    }
+}
+
+/// Warm-up / cool-down window configuration
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(default)]
+pub struct RoundCalendarConfig {
+    /// Seconds before settlement that count as pre-rotation warm-up
+    pub warmup_secs: i64,
+    /// Seconds after settlement that count as post-rotation cool-down
+    pub cooldown_secs: i64,
+}
+
+impl Default for RoundCalendarConfig {
+    fn default() -> Self {
+        Self {
+            warmup_secs: 30,
+            cooldown_secs: 10,
+        }
+    }
+}
+
+/// A single scheduled round
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct RoundEntry {
+    pub event_id: String,
+    pub series_id: String,
+    pub start_time: Option<DateTime<Utc>>,
+    pub end_time: DateTime<Utc>,
+}
+
+/// A phase transition due at a precise instant
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct RoundTransition {
+    pub at: DateTime<Utc>,
+    pub series_id: String,
+    pub event_id: String,
+    pub end_time: DateTime<Utc>,
+    pub phase: RoundPhase,
+}
+
+/// Per-series calendar of upcoming round boundaries
+#[derive(Debug, Default)]
+pub struct RoundCalendar {
+    config: RoundCalendarConfig,
+    /// event_id -> round
+    rounds: HashMap<String, RoundEntry>,
+    /// token_id -> event_id
+    token_events: HashMap<String, String>,
+}
+
+fn parse_ts(value: Option<&str>) -> Option<DateTime<Utc>> {
+    value
+        .and_then(|v| DateTime::parse_from_rfc3339(v).ok())
+        .map(|dt| dt.with_timezone(&Utc))
+}
+
+impl RoundCalendar {
+    pub fn new(config: RoundCalendarConfig) -> Self {
+        Self {
+            config,
+            rounds: HashMap::new(),
+            token_events: HashMap::new(),
+        }
+    }
+
+    pub fn config(&self) -> &RoundCalendarConfig {
+        &self.config
+    }
+
+    /// Ingest the (lightweight) event list returned for a Gamma series.
+    ///
+    /// Every non-closed event with a parseable `endDate` becomes a round.
+    /// Returns the number of rounds added or updated.
+    pub fn ingest_series(&mut self, series_id: &str, events: &[GammaEventInfo]) -> usize {
+        let mut changed = 0;
+        for event in events.iter().filter(|e| !e.closed) {
+            let Some(end_time) = parse_ts(event.end_date.as_deref()) else {
+                continue;
+            };
+            let entry = RoundEntry {
+                event_id: event.id.clone(),
+                series_id: series_id.to_string(),
+                start_time: parse_ts(event.start_time.as_deref()),
+                end_time,
+            };
+            if self.insert(entry) {
+                changed += 1;
+            }
+        }
+        changed
+    }
+
+    /// Insert or replace a round. Returns true if anything changed.
+    pub fn insert(&mut self, entry: RoundEntry) -> bool {
+        if self.rounds.get(&entry.event_id) == Some(&entry) {
+            return false;
+        }
+        self.rounds.insert(entry.event_id.clone(), entry);
+        true
+    }
+
+    /// Associate outcome tokens with a round (for per-quote settlement timing).
+    pub fn register_tokens(&mut self, event_id: &str, token_ids: &[&str]) {
+        for token_id in token_ids {
+            self.token_events
+                .insert((*token_id).to_string(), event_id.to_string());
+        }
+    }
+
+    pub fn get(&self, event_id: &str) -> Option<&RoundEntry> {
+        self.rounds.get(event_id)
+    }
+
+    pub fn len(&self) -> usize {
+        self.rounds.len()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.rounds.is_empty()
+    }
+
+    /// Seconds until the round settles (negative once past end time).
+    pub fn seconds_to_settlement(&self, event_id: &str, now: DateTime<Utc>) -> Option<i64> {
+        self.rounds
+            .get(event_id)
+            .map(|r| (r.end_time - now).num_seconds())
+    }
+
+    /// Seconds until the round owning `token_id` settles.
+    pub fn token_seconds_to_settlement(&self, token_id: &str, now: DateTime<Utc>) -> Option<i64> {
+        let event_id = self.token_events.get(token_id)?;
+        self.seconds_to_settlement(event_id, now)
+    }
+
+    /// Current phase of a series at `now`.
+    pub fn phase(&self, series_id: &str, now: DateTime<Utc>) -> RoundPhase {
+        let warmup = Duration::seconds(self.config.warmup_secs.max(0));
+        let cooldown = Duration::seconds(self.config.cooldown_secs.max(0));
+        let mut phase = RoundPhase::Open;
+        for round in self.rounds.values().filter(|r| r.series_id == series_id) {
+            if round.end_time <= now && now < round.end_time + cooldown {
+                // Cool-down takes precedence: the rotation just happened.
+                return RoundPhase::CoolDown;
+            }
+            if now < round.end_time && round.end_time - warmup <= now {
+                phase = RoundPhase::WarmUp;
+            }
+        }
+        phase
+    }
+
+    fn transitions_of(&self, round: &RoundEntry) -> [RoundTransition; 3] {
+        let make = |at: DateTime<Utc>, phase: RoundPhase| RoundTransition {
+            at,
+            series_id: round.series_id.clone(),
+            event_id: round.event_id.clone(),
+            end_time: round.end_time,
+            phase,
+        };
+        [
+            make(
+                round.end_time - Duration::seconds(self.config.warmup_secs.max(0)),
+                RoundPhase::WarmUp,
+            ),
+            make(round.end_time, RoundPhase::CoolDown),
+            make(
+                round.end_time + Duration::seconds(self.config.cooldown_secs.max(0)),
+                RoundPhase::Open,
+            ),
+        ]
+    }
+
+    /// Transitions with `after < at <= until`, ordered by time.
+    pub fn due(&self, after: DateTime<Utc>, until: DateTime<Utc>) -> Vec<RoundTransition> {
+        let mut out: Vec<RoundTransition> = self
+            .rounds
+            .values()
+            .flat_map(|r| self.transitions_of(r))
+            .filter(|t| t.at > after && t.at <= until)
+            .collect();
+        out.sort_by(|a, b| a.at.cmp(&b.at).then_with(|| a.event_id.cmp(&b.event_id)));
+        out
+    }
+
+    /// Time of the next transition strictly after `now`.
+    pub fn next_transition_after(&self, now: DateTime<Utc>) -> Option<DateTime<Utc>> {
+        self.rounds
+            .values()
+            .flat_map(|r| self.transitions_of(r))
+            .map(|t| t.at)
+            .filter(|at| *at > now)
+            .min()
+    }
+
+    /// Drop rounds whose cool-down ended before `now`. Returns removed count.
+    pub fn prune(&mut self, now: DateTime<Utc>) -> usize {
+        let cooldown = Duration::seconds(self.config.cooldown_secs.max(0));
+        let before = self.rounds.len();
+        self.rounds.retain(|_, r| r.end_time + cooldown >= now);
+        let rounds = &self.rounds;
+        self.token_events
+            .retain(|_, event_id| rounds.contains_key(event_id));
+        before - self.rounds.len()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use chrono::TimeZone;
+
+    fn at(secs: i64) -> DateTime<Utc> {
+        Utc.timestamp_opt(1_700_000_000 + secs, 0).unwrap()
+    }
+
+    fn gamma(id: &str, end: DateTime<Utc>, closed: bool) -> GammaEventInfo {
+        GammaEventInfo {
+            id: id.to_string(),
+            slug: None,
+            title: None,
+            start_time: None,
+            end_date: Some(end.to_rfc3339()),
+            closed,
+            markets: Vec::new(),
+        }
+    }
+
+    fn calendar() -> RoundCalendar {
+        let mut cal = RoundCalendar::new(RoundCalendarConfig {
+            warmup_secs: 30,
+            cooldown_secs: 10,
+        });
+        cal.ingest_series(
+            "s1",
+            &[
+                gamma("e1", at(300), false),
+                gamma("e2", at(600), false),
+                gamma("e0", at(0), true),
+            ],
+        );
+        cal
+    }
+
+    #[test]
+    fn test_ingest_skips_closed_and_is_idempotent() {
+        let mut cal = calendar();
+        assert_eq!(cal.len(), 2);
+        assert_eq!(cal.ingest_series("s1", &[gamma("e1", at(300), false)]), 0);
+        assert_eq!(cal.ingest_series("s1", &[gamma("e1", at(310), false)]), 1);
+    }
+
+    #[test]
+    fn test_phase_windows() {
+        let cal = calendar();
+        assert_eq!(cal.phase("s1", at(100)), RoundPhase::Open);
+        assert_eq!(cal.phase("s1", at(280)), RoundPhase::WarmUp);
+        assert_eq!(cal.phase("s1", at(305)), RoundPhase::CoolDown);
+        assert_eq!(cal.phase("s1", at(320)), RoundPhase::Open);
+        assert_eq!(cal.phase("other", at(280)), RoundPhase::Open);
+    }
+
+    #[test]
+    fn test_due_transitions_in_order() {
+        let cal = calendar();
+        let due = cal.due(at(200), at(320));
+        let phases: Vec<RoundPhase> = due.iter().map(|t| t.phase).collect();
+        assert_eq!(
+            phases,
+            vec![RoundPhase::WarmUp, RoundPhase::CoolDown, RoundPhase::Open]
+        );
+        assert_eq!(due[1].at, at(300));
+        assert_eq!(cal.next_transition_after(at(310)), Some(at(570)));
+    }
+
+    #[test]
+    fn test_token_settlement_and_prune() {
+        let mut cal = calendar();
+        cal.register_tokens("e1", &["up", "down"]);
+        assert_eq!(cal.token_seconds_to_settlement("up", at(250)), Some(50));
+        assert_eq!(cal.token_seconds_to_settlement("missing", at(250)), None);
+
+        assert_eq!(cal.prune(at(400)), 1);
+        assert_eq!(cal.token_seconds_to_settlement("up", at(400)), None);
+        assert!(cal.get("e2").is_some());
+    }
+}
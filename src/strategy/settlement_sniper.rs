@@ -0,0 +1,943 @@
+//! `settlement_sniper` strategy.
+//!
+//! In the last seconds of a round the outcome is usually already decided, but
+//! a single venue print (or a stale one) is not enough to bet on it. The
+//! sniper only trades when a quorum of spot venues from the
+//! [`ConsensusPriceCache`] agree on which side of the strike the underlying
+//! sits, and the least favourable of them is still far enough away that a
+//! conservative volatility bound over the remaining seconds cannot plausibly
+//! cross it. Orders are all-or-nothing: the whole size must be fillable at or
+//! below the limit across the visible asks.
+
+use crate::adapters::{
+    CoinbaseWebSocket, ConsensusConfig, ConsensusPriceCache, KrakenWebSocket, SourceStatus,
+    SpotSource,
+};
+use crate::coordination::BookLevel;
+use crate::domain::{OrderRequest, OrderStatus, Side};
+use crate::error::{PloyError, Result};
+use crate::strategy::timeseries::{SeriesKind, TimeSeriesCache, TimeSeriesConfig};
+use crate::strategy::traits::{
+    AlertLevel, DataFeed, MarketUpdate, OrderUpdate, PositionInfo, Strategy, StrategyAction,
+    StrategyEvent, StrategyEventType, StrategyStateInfo,
+};
+use crate::strategy::volatility::normal_cdf;
+use async_trait::async_trait;
+use chrono::{DateTime, Utc};
+use rust_decimal::prelude::{FromPrimitive, ToPrimitive};
+use rust_decimal::{Decimal, RoundingStrategy};
+use serde::Deserialize;
+use std::collections::HashMap;
+use tracing::{debug, info, warn};
+
+#[derive(Debug, Clone, Deserialize)]
+struct MarketMapping {
+    symbol: String,
+    series_id: String,
+}
+
+/// Entry window, probability threshold and sizing
+#[derive(Debug, Clone, Deserialize)]
+#[serde(default)]
+pub struct SniperConfig {
+    /// Only consider rounds settling within this many seconds
+    pub window_secs: i64,
+    /// Too close to settlement for the order to rest and fill
+    pub min_remaining_secs: i64,
+    /// Minimum estimated probability that the current side settles in the money
+    pub min_probability: f64,
+    /// Required gap between that probability and the price paid
+    pub min_edge: Decimal,
+    /// Hard cap on the entry price
+    pub max_price: Decimal,
+    pub shares: u64,
+    /// Ignore order books older than this
+    pub max_quote_age_secs: i64,
+    /// Realized volatility lookback
+    pub vol_lookback_secs: i64,
+    /// Minimum price samples before the volatility estimate is trusted
+    pub min_vol_samples: usize,
+    /// Volatility floor in bps per sqrt(second)
+    pub min_vol_bps: f64,
+    /// Scale applied to the volatility bound (>1 is more conservative)
+    pub vol_multiplier: f64,
+    /// Extra spot venues to stream alongside Binance
+    pub venues: Vec<SpotSource>,
+}
+
+impl Default for SniperConfig {
+    fn default() -> Self {
+        Self {
+            window_secs: 30,
+            min_remaining_secs: 2,
+            min_probability: 0.97,
+            min_edge: Decimal::new(1, 2),
+            max_price: Decimal::new(99, 2),
+            shares: 20,
+            max_quote_age_secs: 2,
+            vol_lookback_secs: 300,
+            min_vol_samples: 10,
+            min_vol_bps: 0.5,
+            vol_multiplier: 1.5,
+            venues: vec![SpotSource::Coinbase, SpotSource::Kraken],
+        }
+    }
+}
+
+#[derive(Debug, Clone, Deserialize)]
+struct Config {
+    markets: Vec<MarketMapping>,
+    #[serde(default)]
+    sniper: SniperConfig,
+    #[serde(default)]
+    consensus: ConsensusConfig,
+}
+
+/// Average price and worst level for buying a size against the asks
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct Fill {
+    pub avg_price: Decimal,
+    pub worst_price: Decimal,
+}
+
+/// Walk `asks` and return the fill for all `shares` at or below `limit`,
+/// or `None` if the visible depth cannot absorb the whole size.
+pub fn fill_all_or_nothing(asks: &[BookLevel], shares: u64, limit: Decimal) -> Option<Fill> {
+    let mut levels: Vec<&BookLevel> = asks
+        .iter()
+        .filter(|l| l.price > Decimal::ZERO && l.price <= limit && l.size > Decimal::ZERO)
+        .collect();
+    levels.sort_by(|a, b| a.price.cmp(&b.price));
+
+    let target = Decimal::from(shares);
+    let mut remaining = target;
+    let mut cost = Decimal::ZERO;
+    let mut worst_price = Decimal::ZERO;
+    for level in levels {
+        if remaining <= Decimal::ZERO {
+            break;
+        }
+        let take = level.size.min(remaining);
+        cost += take * level.price;
+        remaining -= take;
+        worst_price = level.price;
+    }
+
+    (shares > 0 && remaining <= Decimal::ZERO).then(|| Fill {
+        avg_price: cost / target,
+        worst_price,
+    })
+}
+
+/// Probability the underlying stays on its current side of `strike` for
+/// `remaining_secs`, given a volatility bound per sqrt(second).
+pub fn settlement_probability(
+    price: Decimal,
+    strike: Decimal,
+    sigma_per_sqrt_sec: f64,
+    remaining_secs: f64,
+) -> f64 {
+    let (Some(price), Some(strike)) = (price.to_f64(), strike.to_f64()) else {
+        return 0.5;
+    };
+    if price <= 0.0 || strike <= 0.0 || price == strike {
+        return 0.5;
+    }
+    if remaining_secs <= 0.0 {
+        return 1.0;
+    }
+    if sigma_per_sqrt_sec <= 0.0 {
+        return 0.5;
+    }
+    let distance = (price / strike).ln().abs();
+    normal_cdf(distance / (sigma_per_sqrt_sec * remaining_secs.sqrt()))
+}
+
+#[derive(Debug, Clone)]
+struct Round {
+    symbol: String,
+    strike: Decimal,
+    end_time: DateTime<Utc>,
+    up_token: String,
+    down_token: String,
+    /// One attempt per round
+    attempted: bool,
+}
+
+#[derive(Debug, Clone)]
+struct Entry {
+    event_id: String,
+    symbol: String,
+    strike: Decimal,
+    end_time: DateTime<Utc>,
+    side: Side,
+    token_id: String,
+    limit_price: Decimal,
+    probability: f64,
+    filled: u64,
+    fill_price: Option<Decimal>,
+    opened_at: DateTime<Utc>,
+}
+
+pub struct SettlementSniperStrategy {
+    id: String,
+    dry_run: bool,
+    cfg: Config,
+    enabled: bool,
+
+    symbol_by_series: HashMap<String, String>,
+    consensus: ConsensusPriceCache,
+
+    rounds: HashMap<String, Round>, // event_id -> round
+    asks: HashMap<String, (Vec<BookLevel>, DateTime<Utc>)>, // token_id -> ask ladder
+    prices: TimeSeriesCache,        // symbol -> Binance prints, one per second
+    entries: HashMap<String, Entry>, // event_id -> entry
+    pending: HashMap<String, String>, // client_order_id -> event_id
+    realized_pnl: Decimal,
+}
+
+impl SettlementSniperStrategy {
+    pub fn from_toml(id: String, config_str: &str, dry_run: bool) -> Result<Self> {
+        let cfg: Config = toml::from_str(config_str)
+            .map_err(|e| PloyError::Internal(format!("Invalid settlement_sniper config: {e}")))?;
+        if cfg.markets.is_empty() {
+            return Err(PloyError::Internal("Missing [[markets]]".to_string()));
+        }
+        if !(0.5..1.0).contains(&cfg.sniper.min_probability) {
+            return Err(PloyError::Internal(
+                "sniper.min_probability must be in [0.5, 1.0)".to_string(),
+            ));
+        }
+
+        let symbol_by_series = cfg
+            .markets
+            .iter()
+            .map(|m| (m.series_id.clone(), m.symbol.clone()))
+            .collect();
+        let consensus = ConsensusPriceCache::new(cfg.consensus.clone());
+        // One print per second: the ring holds the whole vol lookback
+        let prices = TimeSeriesCache::new(TimeSeriesConfig {
+            price_window: cfg.sniper.vol_lookback_secs.max(1) as usize + 1,
+            ..TimeSeriesConfig::default()
+        });
+
+        Ok(Self {
+            id,
+            dry_run,
+            cfg,
+            enabled: true,
+            symbol_by_series,
+            consensus,
+            rounds: HashMap::new(),
+            asks: HashMap::new(),
+            prices,
+            entries: HashMap::new(),
+            pending: HashMap::new(),
+            realized_pnl: Decimal::ZERO,
+        })
+    }
+
+    /// Stream the configured non-Binance venues into the consensus cache.
+    /// Binance arrives through the strategy's own market updates.
+    pub fn spawn_venue_feeds(&self) {
+        let symbols = self.symbols();
+        for venue in &self.cfg.sniper.venues {
+            match venue {
+                SpotSource::Coinbase => {
+                    let ws = CoinbaseWebSocket::new(symbols.clone());
+                    self.consensus.spawn_exchange(ws.subscribe());
+                    tokio::spawn(async move {
+                        if let Err(e) = ws.run().await {
+                            warn!("Coinbase spot feed stopped: {}", e);
+                        }
+                    });
+                }
+                SpotSource::Kraken => {
+                    let ws = KrakenWebSocket::new(symbols.clone());
+                    self.consensus.spawn_exchange(ws.subscribe());
+                    tokio::spawn(async move {
+                        if let Err(e) = ws.run().await {
+                            warn!("Kraken spot feed stopped: {}", e);
+                        }
+                    });
+                }
+                SpotSource::Binance => {}
+            }
+        }
+    }
+
+    fn symbols(&self) -> Vec<String> {
+        let mut symbols: Vec<String> = self.cfg.markets.iter().map(|m| m.symbol.clone()).collect();
+        symbols.sort();
+        symbols.dedup();
+        symbols
+    }
+
+    fn record_price(&mut self, symbol: &str, price: Decimal, at: DateTime<Utc>) {
+        let Some(price) = price.to_f64() else {
+            return;
+        };
+        // Downsample to one print per second
+        if self
+            .prices
+            .window(symbol, SeriesKind::Price)
+            .and_then(|w| w.last())
+            .is_some_and(|(ts, _)| (at - ts).num_milliseconds() < 1000)
+        {
+            return;
+        }
+        self.prices.push(symbol, SeriesKind::Price, at, price);
+    }
+
+    /// Conservative volatility per sqrt(second): realized, floored, scaled
+    fn volatility_bound(&self, symbol: &str) -> Option<f64> {
+        let window = self.prices.window(symbol, SeriesKind::Price)?;
+        let (latest, _) = window.last()?;
+        // Feed gaps can leave older prints in the ring; only the lookback counts
+        let lookback = chrono::Duration::seconds(self.cfg.sniper.vol_lookback_secs);
+        let history: Vec<(DateTime<Utc>, f64)> = window
+            .iter()
+            .filter(|(ts, _)| latest - *ts <= lookback)
+            .copied()
+            .collect();
+        if history.len() < self.cfg.sniper.min_vol_samples.max(2) {
+            return None;
+        }
+        let (mut sum_sq, mut secs) = (0.0, 0.0);
+        for ((t0, p0), (t1, p1)) in history.iter().zip(history.iter().skip(1)) {
+            if *p0 > 0.0 && *p1 > 0.0 {
+                sum_sq += (p1 / p0).ln().powi(2);
+                secs += (*t1 - *t0).num_milliseconds() as f64 / 1000.0;
+            }
+        }
+        let realized = if secs > 0.0 {
+            (sum_sq / secs).sqrt()
+        } else {
+            0.0
+        };
+        let floor = self.cfg.sniper.min_vol_bps / 10_000.0;
+        Some(realized.max(floor) * self.cfg.sniper.vol_multiplier)
+    }
+
+    fn ask_ladder(&self, token_id: &str, now: DateTime<Utc>) -> Option<&[BookLevel]> {
+        let (levels, at) = self.asks.get(token_id)?;
+        ((now - *at).num_seconds() <= self.cfg.sniper.max_quote_age_secs)
+            .then_some(levels.as_slice())
+    }
+
+    /// Take the sanitized top of book from a quote, keeping the deeper levels
+    /// of the last book snapshot that sit behind it.
+    fn update_top_ask(&mut self, token_id: &str, top: BookLevel, at: DateTime<Utc>) {
+        let mut levels = vec![top];
+        if let Some((previous, _)) = self.asks.get(token_id) {
+            levels.extend(previous.iter().filter(|l| l.price > top.price).copied());
+        }
+        self.asks.insert(token_id.to_string(), (levels, at));
+    }
+
+    async fn evaluate(&mut self, now: DateTime<Utc>) -> Vec<StrategyAction> {
+        let mut actions = Vec::new();
+        if !self.enabled {
+            return actions;
+        }
+
+        let candidates: Vec<String> = self
+            .rounds
+            .iter()
+            .filter(|(_, r)| !r.attempted)
+            .filter(|(_, r)| {
+                let remaining = (r.end_time - now).num_seconds();
+                remaining >= self.cfg.sniper.min_remaining_secs
+                    && remaining <= self.cfg.sniper.window_secs
+            })
+            .map(|(id, _)| id.clone())
+            .collect();
+
+        for event_id in candidates {
+            if let Some(round_actions) = self.evaluate_round(&event_id, now).await {
+                actions.extend(round_actions);
+            }
+        }
+        actions
+    }
+
+    async fn evaluate_round(
+        &mut self,
+        event_id: &str,
+        now: DateTime<Utc>,
+    ) -> Option<Vec<StrategyAction>> {
+        let round = self.rounds.get(event_id)?.clone();
+        let sniper = &self.cfg.sniper;
+
+        // Quorum of fresh, non-outlier venues
+        let Some(consensus) = self.consensus.consensus_at(&round.symbol, now).await else {
+            debug!("{} {} no consensus quorum", self.id, event_id);
+            return None;
+        };
+        let healthy: Vec<Decimal> = self
+            .consensus
+            .health_at(&round.symbol, now)
+            .await
+            .into_iter()
+            .filter(|h| h.status == SourceStatus::Healthy)
+            .map(|h| h.price)
+            .collect();
+
+        // Every venue in the quorum must be on the same side of the strike
+        let side = if healthy.iter().all(|p| *p > round.strike) {
+            Side::Up
+        } else if healthy.iter().all(|p| *p < round.strike) {
+            Side::Down
+        } else {
+            debug!("{} {} venues straddle strike", self.id, event_id);
+            return None;
+        };
+        let worst = match side {
+            Side::Up => healthy.iter().min(),
+            Side::Down => healthy.iter().max(),
+        }
+        .copied()?;
+
+        let sigma = self.volatility_bound(&round.symbol)?;
+        let remaining = (round.end_time - now).num_milliseconds() as f64 / 1000.0;
+        let probability = settlement_probability(worst, round.strike, sigma, remaining);
+        if probability < sniper.min_probability {
+            return None;
+        }
+
+        let fair = Decimal::from_f64(probability)?;
+        let limit = (fair - sniper.min_edge)
+            .min(sniper.max_price)
+            .round_dp_with_strategy(2, RoundingStrategy::ToZero);
+        let token_id = match side {
+            Side::Up => round.up_token.clone(),
+            Side::Down => round.down_token.clone(),
+        };
+        let Some(asks) = self.ask_ladder(&token_id, now) else {
+            debug!("{} {} no fresh book for {}", self.id, event_id, side);
+            return None;
+        };
+        let Some(fill) = fill_all_or_nothing(asks, sniper.shares, limit) else {
+            debug!(
+                "{} {} {} not fillable: {} shares @ <= {} ({} ask levels)",
+                self.id,
+                event_id,
+                side,
+                sniper.shares,
+                limit,
+                asks.len()
+            );
+            return None;
+        };
+
+        let shares = sniper.shares;
+        if let Some(r) = self.rounds.get_mut(event_id) {
+            r.attempted = true;
+        }
+
+        let client_order_id = format!(
+            "{}_{}_{}_{}",
+            self.id,
+            event_id,
+            side.as_str().to_lowercase(),
+            now.timestamp_millis()
+        );
+        info!(
+            "{} sniping {} {} x{} @ <= {} (p={:.4}, consensus {} from {:?}, strike {}, {:.1}s left)",
+            self.id,
+            event_id,
+            side,
+            shares,
+            limit,
+            probability,
+            consensus.price,
+            consensus.sources,
+            round.strike,
+            remaining
+        );
+        self.pending
+            .insert(client_order_id.clone(), event_id.to_string());
+        self.entries.insert(
+            event_id.to_string(),
+            Entry {
+                event_id: event_id.to_string(),
+                symbol: round.symbol.clone(),
+                strike: round.strike,
+                end_time: round.end_time,
+                side,
+                token_id: token_id.clone(),
+                limit_price: limit,
+                probability,
+                filled: 0,
+                fill_price: None,
+                opened_at: now,
+            },
+        );
+
+        Some(vec![
+            StrategyAction::LogEvent {
+                event: StrategyEvent::new(
+                    StrategyEventType::EntryTriggered,
+                    format!(
+                        "{} settlement snipe {} {} @ <= {}",
+                        round.symbol, event_id, side, limit
+                    ),
+                )
+                .with_data("probability", format!("{:.4}", probability))
+                .with_data("consensus", consensus.price.to_string())
+                .with_data("sources", consensus.sources.len().to_string())
+                .with_data("expected_avg_price", fill.avg_price.to_string()),
+            },
+            StrategyAction::SubmitOrder {
+                client_order_id,
+                order: OrderRequest::buy_limit(token_id, side, shares, limit),
+                priority: 10,
+            },
+        ])
+    }
+
+    /// Book estimated PnL for entries whose round has ended
+    async fn settle_entries(&mut self, now: DateTime<Utc>) -> Vec<StrategyAction> {
+        let due: Vec<String> = self
+            .entries
+            .values()
+            .filter(|e| e.end_time <= now)
+            .map(|e| e.event_id.clone())
+            .collect();
+
+        let mut actions = Vec::new();
+        for event_id in due {
+            let Some(entry) = self.entries.remove(&event_id) else {
+                continue;
+            };
+            self.rounds.remove(&event_id);
+            self.pending.retain(|_, id| *id != event_id);
+            if entry.filled == 0 {
+                continue;
+            }
+
+            let Some(settle_price) = self
+                .consensus
+                .consensus_at(&entry.symbol, now)
+                .await
+                .map(|c| c.price)
+            else {
+                warn!(
+                    "{} {} ended without consensus; outcome left to redemption",
+                    self.id, event_id
+                );
+                continue;
+            };
+            let won = match entry.side {
+                Side::Up => settle_price >= entry.strike,
+                Side::Down => settle_price < entry.strike,
+            };
+            let cost = entry.fill_price.unwrap_or(entry.limit_price) * Decimal::from(entry.filled);
+            let pnl = if won {
+                Decimal::from(entry.filled) - cost
+            } else {
+                -cost
+            };
+            self.realized_pnl += pnl;
+            if !won {
+                warn!(
+                    "{} {} snipe lost (p={:.4}, settle {} vs strike {})",
+                    self.id, event_id, entry.probability, settle_price, entry.strike
+                );
+            }
+            actions.push(StrategyAction::LogEvent {
+                event: StrategyEvent::new(
+                    StrategyEventType::CycleCompleted,
+                    format!(
+                        "settlement snipe {} {}",
+                        event_id,
+                        if won { "won" } else { "lost" }
+                    ),
+                )
+                .with_data("estimated_pnl", pnl.to_string())
+                .with_data("settle_price", settle_price.to_string()),
+            });
+        }
+        actions
+    }
+}
+
+#[async_trait]
+impl Strategy for SettlementSniperStrategy {
+    fn id(&self) -> &str {
+        &self.id
+    }
+
+    fn name(&self) -> &str {
+        "settlement_sniper"
+    }
+
+    fn description(&self) -> &str {
+        "Final-seconds entries backed by a multi-venue price quorum"
+    }
+
+    fn required_feeds(&self) -> Vec<DataFeed> {
+        let series_ids: Vec<String> = self
+            .cfg
+            .markets
+            .iter()
+            .map(|m| m.series_id.clone())
+            .collect();
+
+        vec![
+            DataFeed::BinanceSpot {
+                symbols: self.symbols(),
+            },
+            DataFeed::PolymarketEvents { series_ids },
+            DataFeed::Tick { interval_ms: 500 },
+        ]
+    }
+
+    async fn on_market_update(&mut self, update: &MarketUpdate) -> Result<Vec<StrategyAction>> {
+        let mut actions: Vec<StrategyAction> = Vec::new();
+
+        match update {
+            MarketUpdate::PolymarketQuote {
+                token_id,
+                quote,
+                timestamp,
+                ..
+            } => {
+                match (quote.best_ask, quote.ask_size) {
+                    (Some(price), Some(size)) => {
+                        self.update_top_ask(token_id, BookLevel { price, size }, *timestamp);
+                    }
+                    _ => {
+                        self.asks.remove(token_id);
+                    }
+                }
+                actions.extend(self.evaluate(*timestamp).await);
+            }
+
+            MarketUpdate::PolymarketBook {
+                token_id,
+                asks,
+                timestamp,
+                ..
+            } => {
+                if self
+                    .rounds
+                    .values()
+                    .any(|r| &r.up_token == token_id || &r.down_token == token_id)
+                {
+                    self.asks
+                        .insert(token_id.clone(), (asks.clone(), *timestamp));
+                    actions.extend(self.evaluate(*timestamp).await);
+                }
+            }
+
+            MarketUpdate::BinancePrice {
+                symbol,
+                price,
+                timestamp,
+            } => {
+                self.consensus
+                    .update(SpotSource::Binance, symbol, *price, *timestamp)
+                    .await;
+                self.record_price(symbol, *price, *timestamp);
+            }
+
+            MarketUpdate::EventDiscovered {
+                event_id,
+                series_id,
+                up_token,
+                down_token,
+                end_time,
+                price_to_beat,
+                ..
+            } => {
+                let Some(symbol) = self.symbol_by_series.get(series_id).cloned() else {
+                    return Ok(actions);
+                };
+                let Some(strike) = price_to_beat else {
+                    debug!("{} {} has no price_to_beat; skipped", symbol, event_id);
+                    return Ok(actions);
+                };
+                self.rounds.entry(event_id.clone()).or_insert(Round {
+                    symbol,
+                    strike: *strike,
+                    end_time: *end_time,
+                    up_token: up_token.clone(),
+                    down_token: down_token.clone(),
+                    attempted: false,
+                });
+                actions.push(StrategyAction::SubscribeFeed {
+                    feed: DataFeed::PolymarketQuotes {
+                        tokens: vec![up_token.clone(), down_token.clone()],
+                    },
+                });
+            }
+
+            MarketUpdate::EventExpired { event_id } => {
+                if let Some(round) = self.rounds.remove(event_id) {
+                    self.asks.remove(&round.up_token);
+                    self.asks.remove(&round.down_token);
+                }
+            }
+
+            MarketUpdate::BinanceKline { .. } | MarketUpdate::RoundBoundary { .. } => {}
+        }
+
+        Ok(actions)
+    }
+
+    async fn on_order_update(&mut self, update: &OrderUpdate) -> Result<Vec<StrategyAction>> {
+        let Some(client_order_id) = update.client_order_id.as_deref() else {
+            return Ok(Vec::new());
+        };
+        let Some(event_id) = self.pending.get(client_order_id).cloned() else {
+            return Ok(Vec::new());
+        };
+
+        match update.status {
+            OrderStatus::Filled | OrderStatus::PartiallyFilled => {
+                if let Some(entry) = self.entries.get_mut(&event_id) {
+                    entry.filled = update.filled_qty;
+                    entry.fill_price = update.avg_fill_price.or(entry.fill_price);
+                }
+                if update.status == OrderStatus::Filled {
+                    self.pending.remove(client_order_id);
+                }
+            }
+            OrderStatus::Cancelled | OrderStatus::Rejected | OrderStatus::Expired => {
+                self.pending.remove(client_order_id);
+                if self.entries.get(&event_id).is_some_and(|e| e.filled == 0) {
+                    self.entries.remove(&event_id);
+                }
+                debug!("{} snipe {} order {:?}", self.id, event_id, update.status);
+            }
+            _ => {}
+        }
+
+        Ok(Vec::new())
+    }
+
+    async fn on_tick(&mut self, now: DateTime<Utc>) -> Result<Vec<StrategyAction>> {
+        let mut actions = self.evaluate(now).await;
+        actions.extend(self.settle_entries(now).await);
+        Ok(actions)
+    }
+
+    fn state(&self) -> StrategyStateInfo {
+        let mut metrics: HashMap<String, String> = HashMap::new();
+        metrics.insert("tracked_rounds".to_string(), self.rounds.len().to_string());
+        for symbol in self.symbols() {
+            if let Some(sigma) = self.volatility_bound(&symbol) {
+                metrics.insert(
+                    format!("{}_vol_bps", symbol),
+                    format!("{:.2}", sigma * 10_000.0),
+                );
+            }
+        }
+
+        let positions = self.positions();
+        StrategyStateInfo {
+            strategy_id: self.id.clone(),
+            phase: if self.enabled { "running" } else { "disabled" }.to_string(),
+            enabled: self.enabled,
+            active: self.is_active(),
+            position_count: positions.len(),
+            pending_order_count: self.pending.len(),
+            total_exposure: positions
+                .iter()
+                .map(|p| p.entry_price * Decimal::from(p.shares))
+                .sum(),
+            unrealized_pnl: Decimal::ZERO,
+            realized_pnl_today: self.realized_pnl,
+            last_update: Utc::now(),
+            metrics,
+        }
+    }
+
+    fn positions(&self) -> Vec<PositionInfo> {
+        self.entries
+            .values()
+            .filter(|e| e.filled > 0)
+            .map(|e| {
+                let mut position = PositionInfo::new(
+                    e.token_id.clone(),
+                    e.side,
+                    e.filled,
+                    e.fill_price.unwrap_or(e.limit_price),
+                    self.id.clone(),
+                );
+                position.opened_at = e.opened_at;
+                position
+                    .metadata
+                    .insert("event_id".to_string(), e.event_id.clone());
+                position
+                    .metadata
+                    .insert("probability".to_string(), format!("{:.4}", e.probability));
+                position
+            })
+            .collect()
+    }
+
+    fn is_active(&self) -> bool {
+        !self.entries.is_empty() || !self.pending.is_empty()
+    }
+
+    async fn shutdown(&mut self) -> Result<Vec<StrategyAction>> {
+        self.enabled = false;
+        let mut actions: Vec<StrategyAction> = self
+            .pending
+            .keys()
+            .map(|client_order_id| StrategyAction::CancelOrder {
+                order_id: client_order_id.clone(),
+            })
+            .collect();
+        actions.push(StrategyAction::Alert {
+            level: AlertLevel::Info,
+            message: format!("{} shutdown (dry_run={})", self.id, self.dry_run),
+        });
+        Ok(actions)
+    }
+
+    fn reset(&mut self) {
+        self.rounds.clear();
+        self.asks.clear();
+        self.prices.clear();
+        self.entries.clear();
+        self.pending.clear();
+        self.realized_pnl = Decimal::ZERO;
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::domain::Quote;
+    use chrono::Duration;
+    use rust_decimal_macros::dec;
+
+    const CONFIG: &str = r#"
+        [strategy]
+        name = "settlement_sniper"
+
+        [[markets]]
+        symbol = "BTCUSDT"
+        series_id = "btc-5m"
+
+        [sniper]
+        shares = 20
+    "#;
+
+    fn submitted(actions: &[StrategyAction]) -> Vec<&OrderRequest> {
+        actions
+            .iter()
+            .filter_map(|a| match a {
+                StrategyAction::SubmitOrder { order, .. } => Some(order),
+                _ => None,
+            })
+            .collect()
+    }
+
+    #[test]
+    fn test_fill_all_or_nothing_walks_depth() {
+        let asks = [
+            BookLevel {
+                price: dec!(0.96),
+                size: dec!(10),
+            },
+            BookLevel {
+                price: dec!(0.95),
+                size: dec!(10),
+            },
+            BookLevel {
+                price: dec!(0.99),
+                size: dec!(100),
+            },
+        ];
+        let fill = fill_all_or_nothing(&asks, 20, dec!(0.97)).unwrap();
+        assert_eq!(fill.avg_price, dec!(0.955));
+        assert_eq!(fill.worst_price, dec!(0.96));
+        assert!(fill_all_or_nothing(&asks, 21, dec!(0.97)).is_none());
+    }
+
+    #[tokio::test]
+    async fn test_snipes_only_with_agreeing_quorum() {
+        let mut strategy =
+            SettlementSniperStrategy::from_toml("snipe".to_string(), CONFIG, true).unwrap();
+        let now = Utc::now();
+        let end = now + Duration::seconds(10);
+
+        for i in (0..20).rev() {
+            strategy
+                .on_market_update(&MarketUpdate::BinancePrice {
+                    symbol: "BTCUSDT".to_string(),
+                    price: dec!(100300),
+                    timestamp: now - Duration::seconds(i),
+                })
+                .await
+                .unwrap();
+        }
+        strategy
+            .on_market_update(&MarketUpdate::EventDiscovered {
+                event_id: "r1".to_string(),
+                series_id: "btc-5m".to_string(),
+                up_token: "r1-up".to_string(),
+                down_token: "r1-down".to_string(),
+                end_time: end,
+                price_to_beat: Some(dec!(100000)),
+                title: None,
+                window_secs: Some(300),
+            })
+            .await
+            .unwrap();
+        strategy
+            .on_market_update(&MarketUpdate::PolymarketQuote {
+                token_id: "r1-up".to_string(),
+                side: Side::Up,
+                quote: Quote {
+                    side: Side::Up,
+                    best_bid: Some(dec!(0.94)),
+                    best_ask: Some(dec!(0.95)),
+                    bid_size: Some(dec!(100)),
+                    ask_size: Some(dec!(10)),
+                    timestamp: now,
+                },
+                timestamp: now,
+                seconds_to_settlement: Some(10),
+            })
+            .await
+            .unwrap();
+
+        // Binance alone is not a quorum; Kraken below the strike splits it
+        strategy
+            .consensus
+            .update(SpotSource::Kraken, "BTCUSDT", dec!(99990), now)
+            .await;
+        assert!(submitted(&strategy.on_tick(now).await.unwrap()).is_empty());
+
+        strategy
+            .consensus
+            .update(SpotSource::Kraken, "BTCUSDT", dec!(100310), now)
+            .await;
+        // The top level alone cannot absorb 20 shares
+        assert!(submitted(&strategy.on_tick(now).await.unwrap()).is_empty());
+
+        // Deeper asks from the book snapshot complete the size within the limit
+        let level = |price, size| BookLevel { price, size };
+        let actions = strategy
+            .on_market_update(&MarketUpdate::PolymarketBook {
+                token_id: "r1-up".to_string(),
+                bids: vec![level(dec!(0.94), dec!(100))],
+                asks: vec![level(dec!(0.95), dec!(10)), level(dec!(0.96), dec!(15))],
+                timestamp: now,
+            })
+            .await
+            .unwrap();
+        let orders = submitted(&actions);
+        assert_eq!(orders.len(), 1);
+        assert_eq!(orders[0].token_id, "r1-up");
+        assert_eq!(orders[0].shares, 20);
+
+        // One attempt per round
+        assert!(submitted(&strategy.on_tick(now).await.unwrap()).is_empty());
+    }
+}
@@ -1,6 +1,9 @@
-use crate::config::StrategyConfig;
+use crate::config::{StrategyConfig, VolThresholdParams};
 use crate::domain::{DumpSignal, Quote, Side};
+use crate::platform::Timeframe;
+use crate::strategy::calculations::MidPriceEstimator;
 use chrono::{DateTime, Duration, Utc};
+use rust_decimal::prelude::{FromPrimitive, ToPrimitive};
 use rust_decimal::Decimal;
 use std::collections::VecDeque;
 use tracing::{debug, info};
@@ -48,6 +51,40 @@ impl PriceWindow {
         self.prices.back().map(|(_, p)| *p)
     }
 
+    /// Realized volatility scaled to `horizon`, with the number of returns it
+    /// was estimated from.
+    ///
+    /// Sum of squared log returns per second of history, times the horizon.
+    fn realized_vol(&self, horizon: Duration) -> Option<(f64, usize)> {
+        let (first_ts, _) = self.prices.front()?;
+        let (last_ts, _) = self.prices.back()?;
+        let elapsed = (*last_ts - *first_ts).num_milliseconds() as f64 / 1000.0;
+        if elapsed <= 0.0 {
+            return None;
+        }
+
+        let mut sum_sq = 0.0;
+        let mut returns = 0;
+        for ((_, prev), (_, next)) in self.prices.iter().zip(self.prices.iter().skip(1)) {
+            let (Some(prev), Some(next)) = (prev.to_f64(), next.to_f64()) else {
+                continue;
+            };
+            if prev <= 0.0 || next <= 0.0 {
+                continue;
+            }
+            sum_sq += (next / prev).ln().powi(2);
+            returns += 1;
+        }
+
+        let horizon_secs = horizon.num_milliseconds() as f64 / 1000.0;
+        Some(((sum_sq / elapsed * horizon_secs).sqrt(), returns))
+    }
+
+    /// Change the window length (existing observations are kept)
+    fn set_window(&mut self, window_seconds: i64) {
+        self.window_duration = Duration::seconds(window_seconds);
+    }
+
     /// Check if we have enough data
     fn has_data(&self) -> bool {
         !self.prices.is_empty()
@@ -68,8 +105,19 @@ pub struct SignalDetector {
     up_window: PriceWindow,
     /// Rolling window for DOWN side best_ask
     down_window: PriceWindow,
+    /// Optional mid estimators; when set, windows track the estimate instead of best_ask
+    up_mid: Option<MidPriceEstimator>,
+    down_mid: Option<MidPriceEstimator>,
     /// Window size in seconds (for 3-second rolling high)
     window_seconds: i64,
+    /// Length of the current round, when known
+    round_secs: Option<i64>,
+    /// Longer price history per side for realized volatility (only fed when
+    /// `vol_threshold` is configured)
+    up_history: PriceWindow,
+    down_history: PriceWindow,
+    /// Volatility threshold parameters for this detector's symbol
+    vol_params: Option<VolThresholdParams>,
     /// Whether we've triggered in the current round
     triggered_up: bool,
     triggered_down: bool,
@@ -85,27 +133,102 @@ impl SignalDetector {
 
     /// Create a new signal detector with custom window size
     pub fn with_window(config: StrategyConfig, window_seconds: i64) -> Self {
+        let up_mid = config.signal_mid.clone().map(MidPriceEstimator::new);
+        let down_mid = up_mid.clone();
+        let lookback_secs = config
+            .vol_threshold
+            .as_ref()
+            .map_or(0, |v| v.lookback_secs.max(window_seconds));
+        let vol_params = config.vol_threshold.as_ref().map(|v| v.params_for(None));
         Self {
             config,
             up_window: PriceWindow::new(window_seconds),
             down_window: PriceWindow::new(window_seconds),
+            up_mid,
+            down_mid,
             window_seconds,
+            round_secs: None,
+            up_history: PriceWindow::new(lookback_secs),
+            down_history: PriceWindow::new(lookback_secs),
+            vol_params,
             triggered_up: false,
             triggered_down: false,
             current_round: None,
         }
     }
 
+    /// Use the volatility threshold overrides configured for `symbol`
+    /// (e.g. `btc`, `sol`)
+    pub fn with_symbol(mut self, symbol: &str) -> Self {
+        self.vol_params = self
+            .config
+            .vol_threshold
+            .as_ref()
+            .map(|v| v.params_for(Some(symbol)));
+        self
+    }
+
     /// Reset for a new round
     pub fn reset(&mut self, round_slug: Option<&str>) {
         self.up_window.clear();
         self.down_window.clear();
+        self.up_history.clear();
+        self.down_history.clear();
+        for estimator in [&mut self.up_mid, &mut self.down_mid].into_iter().flatten() {
+            estimator.reset();
+        }
         self.triggered_up = false;
         self.triggered_down = false;
         self.current_round = round_slug.map(|s| s.to_string());
         debug!("Signal detector reset for round: {:?}", round_slug);
     }
 
+    /// Adapt to the round length. The rolling-high window stretches with √t
+    /// on 1h / 1d rounds so slower dumps still register; 5m / 15m are unchanged.
+    pub fn set_round_duration(&mut self, round_secs: i64) {
+        let scaled =
+            (self.window_seconds as f64 * Timeframe::lead_time_scale(round_secs)).round() as i64;
+        self.up_window.set_window(scaled);
+        self.down_window.set_window(scaled);
+        self.round_secs = Some(round_secs);
+    }
+
+    /// Drop threshold currently in effect for `side`, with the realized
+    /// volatility it was derived from.
+    ///
+    /// Falls back to the fixed `move_pct` when volatility scaling is off or
+    /// there is not enough history yet.
+    pub fn effective_threshold(&self, side: Side) -> (Decimal, Option<Decimal>) {
+        let fixed = (self.config.move_pct, None);
+        let (Some(params), Some(vol_config)) = (self.vol_params, &self.config.vol_threshold) else {
+            return fixed;
+        };
+        let history = match side {
+            Side::Up => &self.up_history,
+            Side::Down => &self.down_history,
+        };
+        let Some((sigma, returns)) = history.realized_vol(self.up_window.window_duration) else {
+            return fixed;
+        };
+        if returns < vol_config.min_samples {
+            return fixed;
+        }
+        let Some(sigma) = Decimal::from_f64(sigma) else {
+            return fixed;
+        };
+
+        let threshold = (params.k * sigma)
+            .max(params.min_move_pct)
+            .min(params.max_move_pct);
+        (threshold.round_dp(6), Some(sigma.round_dp(8)))
+    }
+
+    /// Watch window (seconds after round start) for the current round length
+    pub fn watch_window_secs(&self) -> i64 {
+        self.config
+            .watch_window_secs(self.round_secs.unwrap_or(Timeframe::REFERENCE_SECS))
+    }
+
     /// Update with new quote data and check for signals
     pub fn update(&mut self, quote: &Quote, round_slug: Option<&str>) -> Option<DumpSignal> {
         // Check if we've moved to a new round
@@ -121,6 +244,26 @@ impl SignalDetector {
         let now = quote.timestamp;
         let side = quote.side;
 
+        // Price series the dump is measured on
+        let estimator = match side {
+            Side::Up => self.up_mid.as_mut(),
+            Side::Down => self.down_mid.as_mut(),
+        };
+        let signal_price = match estimator {
+            Some(estimator) => estimator.update(quote).unwrap_or(best_ask),
+            None => best_ask,
+        };
+
+        // Threshold from history before this quote, so the move being
+        // tested does not inflate its own volatility estimate
+        let threshold = self.effective_threshold(side);
+        if self.vol_params.is_some() {
+            match side {
+                Side::Up => self.up_history.push(now, signal_price),
+                Side::Down => self.down_history.push(now, signal_price),
+            };
+        }
+
         // Check if already triggered for this side
         let already_triggered = match side {
             Side::Up => self.triggered_up,
@@ -130,16 +273,16 @@ impl SignalDetector {
         if already_triggered {
             // Still update the window for tracking
             match side {
-                Side::Up => self.up_window.push(now, best_ask),
-                Side::Down => self.down_window.push(now, best_ask),
+                Side::Up => self.up_window.push(now, signal_price),
+                Side::Down => self.down_window.push(now, signal_price),
             };
             return None;
         }
 
         // Update the appropriate window
         match side {
-            Side::Up => self.up_window.push(now, best_ask),
-            Side::Down => self.down_window.push(now, best_ask),
+            Side::Up => self.up_window.push(now, signal_price),
+            Side::Down => self.down_window.push(now, signal_price),
         };
 
         // Get rolling high from window
@@ -149,18 +292,21 @@ impl SignalDetector {
         };
 
         // Check for dump signal
-        if let Some(signal) = self.check_dump_inner(side, rolling_high, quote) {
+        if let Some(signal) =
+            self.check_dump_inner(side, rolling_high, signal_price, quote, threshold)
+        {
             // Mark as triggered
             match side {
                 Side::Up => self.triggered_up = true,
                 Side::Down => self.triggered_down = true,
             };
             info!(
-                "Dump signal detected: {:?} dropped {:.2}% from {:.4} to {:.4}",
+                "Dump signal detected: {:?} dropped {:.2}% from {:.4} to {:.4} (threshold {:.2}%)",
                 signal.side,
                 signal.drop_pct * Decimal::from(100),
                 signal.reference_price,
-                signal.trigger_price
+                signal.trigger_price,
+                signal.threshold_pct * Decimal::from(100)
             );
             return Some(signal);
         }
@@ -169,11 +315,17 @@ impl SignalDetector {
     }
 
     /// Check if a dump has occurred
+    ///
+    /// The drop is measured on `signal_price` (best ask, or the mid estimate);
+    /// the trigger price is always the current best ask. `threshold` is the
+    /// (threshold, realized vol) pair from [`Self::effective_threshold`].
     fn check_dump_inner(
         &self,
         side: Side,
         rolling_high: Option<Decimal>,
+        signal_price: Decimal,
         quote: &Quote,
+        (threshold_pct, realized_vol): (Decimal, Option<Decimal>),
     ) -> Option<DumpSignal> {
         let Some(rolling_high) = rolling_high else {
             return None;
@@ -184,17 +336,17 @@ impl SignalDetector {
         };
 
         // Calculate drop percentage
-        // Dump = current_ask / rolling_high <= (1 - move_pct)
-        // Or: drop_pct = 1 - (current_ask / rolling_high) >= move_pct
+        // Dump = signal_price / rolling_high <= (1 - threshold)
+        // Or: drop_pct = 1 - (signal_price / rolling_high) >= threshold
         if rolling_high <= Decimal::ZERO {
             return None;
         }
 
-        let ratio = current_ask / rolling_high;
+        let ratio = signal_price / rolling_high;
         let drop_pct = Decimal::ONE - ratio;
 
         // Check if drop exceeds threshold
-        if drop_pct >= self.config.move_pct {
+        if drop_pct >= threshold_pct {
             // Calculate spread for anti-fake-dump filter
             let spread_bps = quote.spread_bps().unwrap_or(9999);
 
@@ -205,6 +357,8 @@ impl SignalDetector {
                 drop_pct,
                 timestamp: quote.timestamp,
                 spread_bps,
+                threshold_pct,
+                realized_vol,
             })
         } else {
             None
@@ -264,6 +418,9 @@ mod tests {
             fee_buffer: dec!(0.005),
             slippage_buffer: dec!(0.02),
             profit_buffer: dec!(0.01),
+            resume: Default::default(),
+            signal_mid: None,
+            vol_threshold: None,
         }
     }
 
@@ -358,4 +515,126 @@ mod tests {
         // leg1 = 0.45, opposite = 0.47, sum = 0.92 > 0.915 -> false
         assert!(!detector.check_leg2_condition(dec!(0.45), dec!(0.47)));
     }
+
+    #[test]
+    fn test_mid_estimator_ignores_one_tick_flicker() {
+        use crate::strategy::calculations::{MidPriceConfig, MidPriceMethod};
+
+        let now = Utc::now();
+        let quote = |bid, ask, ms| Quote {
+            side: Side::Up,
+            best_bid: Some(bid),
+            best_ask: Some(ask),
+            bid_size: Some(dec!(100)),
+            ask_size: Some(dec!(100)),
+            timestamp: now + Duration::milliseconds(ms),
+        };
+        let calm = quote(dec!(0.49), dec!(0.50), 0);
+        // Single quote where the book gaps down 16%
+        let flicker = quote(dec!(0.41), dec!(0.42), 100);
+
+        let mut raw = SignalDetector::new(test_config());
+        raw.update(&calm, Some("r"));
+        assert!(raw.update(&flicker, Some("r")).is_some());
+
+        let mut config = test_config();
+        config.signal_mid = Some(MidPriceConfig {
+            method: MidPriceMethod::Ewma,
+            ewma_half_life_ms: 2_000,
+        });
+        let mut smoothed = SignalDetector::new(config);
+        smoothed.update(&calm, Some("r"));
+        assert!(smoothed.update(&flicker, Some("r")).is_none());
+    }
+
+    #[test]
+    fn test_hourly_round_stretches_windows() {
+        let now = Utc::now();
+        let quote = |ask, secs| Quote {
+            side: Side::Up,
+            best_bid: Some(ask - dec!(0.01)),
+            best_ask: Some(ask),
+            bid_size: Some(dec!(100)),
+            ask_size: Some(dec!(100)),
+            timestamp: now + Duration::seconds(secs),
+        };
+
+        // A 16% drop spread over 5s falls outside the default 3s rolling high...
+        let mut m15 = SignalDetector::new(test_config());
+        m15.set_round_duration(900);
+        m15.update(&quote(dec!(0.50), 0), Some("r"));
+        assert!(m15.update(&quote(dec!(0.42), 5), Some("r")).is_none());
+        assert_eq!(m15.watch_window_secs(), 120);
+
+        // ...but is caught on a 1h round (window 3s -> 6s), which also gets
+        // a 4x watch window.
+        let mut h1 = SignalDetector::new(test_config());
+        h1.set_round_duration(3600);
+        h1.update(&quote(dec!(0.50), 0), Some("r"));
+        assert!(h1.update(&quote(dec!(0.42), 5), Some("r")).is_some());
+        assert_eq!(h1.watch_window_secs(), 480);
+    }
+
+    #[test]
+    fn test_vol_threshold_follows_regime_and_symbol() {
+        use crate::config::{VolThresholdConfig, VolThresholdOverride};
+
+        let now = Utc::now();
+        let quote = |ask: Decimal, ms: i64| Quote {
+            side: Side::Up,
+            best_bid: Some(ask - dec!(0.01)),
+            best_ask: Some(ask),
+            bid_size: Some(dec!(100)),
+            ask_size: Some(dec!(100)),
+            timestamp: now + Duration::milliseconds(ms),
+        };
+        let mut config = test_config();
+        let mut vol = VolThresholdConfig {
+            min_samples: 10,
+            ..Default::default()
+        };
+        vol.symbols.insert(
+            "sol".to_string(),
+            VolThresholdOverride {
+                min_move_pct: Some(dec!(0.08)),
+                ..Default::default()
+            },
+        );
+        config.vol_threshold = Some(vol);
+
+        // Calm tape: sigma is tiny, the threshold sits at the 5% floor and a
+        // 6% drop fires even though it is well under the fixed 15%
+        let mut calm = SignalDetector::new(config.clone());
+        for i in 0..40 {
+            let ask = if i % 2 == 0 { dec!(0.500) } else { dec!(0.501) };
+            calm.update(&quote(ask, i * 250), Some("r"));
+        }
+        assert_eq!(calm.effective_threshold(Side::Up).0, dec!(0.05));
+        let signal = calm.update(&quote(dec!(0.47), 10_000), Some("r")).unwrap();
+        assert_eq!(signal.threshold_pct, dec!(0.05));
+        assert!(signal.realized_vol.is_some());
+
+        // The same tape on SOL uses its 8% floor
+        let mut sol = SignalDetector::new(config.clone()).with_symbol("SOL");
+        for i in 0..40 {
+            let ask = if i % 2 == 0 { dec!(0.500) } else { dec!(0.501) };
+            sol.update(&quote(ask, i * 250), Some("r"));
+        }
+        assert!(sol.update(&quote(dec!(0.47), 10_000), Some("r")).is_none());
+
+        // Wild tape: 4% swings every quarter second push the threshold above 15%
+        let mut wild = SignalDetector::new(config);
+        for i in 0..40 {
+            let ask = if i % 2 == 0 { dec!(0.50) } else { dec!(0.52) };
+            wild.update(&quote(ask, i * 250), Some("r"));
+        }
+        let (threshold, sigma) = wild.effective_threshold(Side::Up);
+        assert!(threshold > dec!(0.15), "threshold {threshold}");
+        assert!(sigma.is_some());
+        assert!(wild.update(&quote(dec!(0.44), 10_000), Some("r")).is_none());
+
+        // Without history the fixed move_pct applies
+        let fresh = SignalDetector::new(test_config());
+        assert_eq!(fresh.effective_threshold(Side::Up), (dec!(0.15), None));
+    }
 }
@@ -0,0 +1,986 @@
+//! What-if strategy simulation (`ploy simulate`)
+//!
+//! Evaluates a strategy against a snapshot of live state — active rounds,
+//! the latest CLOB quotes, Binance spot and open positions as persisted by
+//! the collectors and coordinator — without placing orders. Every step of
+//! the entry validation chain is reported, and the entry is priced into an
+//! EV breakdown (fair value, edge, fees, slippage).
+//!
+//! Configs are plain serde structs; `set key value` patches one field
+//! through JSON, so any config field can be overridden by name.
+
+use crate::agents::crypto::{
+    dynamic_min_edge, estimate_p_up_window, required_return_from_threshold, CryptoEntryMode,
+    CryptoTradingConfig,
+};
+use crate::config::AppConfig;
+use crate::domain::{RiskState, Round, Side};
+use crate::error::{PloyError, Result};
+use crate::platform::Timeframe;
+use crate::strategy::execution::FillabilityGate;
+use crate::strategy::fee_model::FeeModel;
+use crate::strategy::risk_mgmt::validation::{
+    leg1_entry_chain, leg2_entry_chain, ExposureValidator, RiskStateValidator,
+    TimeRemainingValidator, ValidationChain, ValidationContext, ValidatorOutcome,
+};
+use chrono::{DateTime, Utc};
+use clap::ValueEnum;
+use rust_decimal::prelude::{FromPrimitive, ToPrimitive};
+use rust_decimal::Decimal;
+use serde::de::DeserializeOwned;
+use serde::{Deserialize, Serialize};
+use sqlx::PgPool;
+use std::collections::HashMap;
+use std::fmt;
+
+/// Quotes older than this are not considered live
+const QUOTE_MAX_AGE_SECS: i64 = 600;
+
+/// Strategies the simulator can evaluate
+#[derive(ValueEnum, Debug, Clone, Copy, PartialEq, Eq)]
+pub enum SimStrategy {
+    /// Dump-and-hedge two-leg cycle (Leg1 on a dump, Leg2 at the sum target)
+    TwoLeg,
+    /// Crypto momentum agent, directional / arb-only / straddle entries
+    Crypto,
+}
+
+impl SimStrategy {
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            SimStrategy::TwoLeg => "two-leg",
+            SimStrategy::Crypto => "crypto",
+        }
+    }
+}
+
+/// Top of book for one token
+#[derive(Debug, Clone)]
+pub struct SimQuote {
+    pub best_bid: Option<Decimal>,
+    pub best_ask: Option<Decimal>,
+    pub ask_size: Option<Decimal>,
+    pub received_at: DateTime<Utc>,
+}
+
+impl SimQuote {
+    /// Spread relative to the ask, in basis points
+    pub fn spread_bps(&self) -> Option<u32> {
+        let (bid, ask) = (self.best_bid?, self.best_ask?);
+        if ask <= Decimal::ZERO || bid <= Decimal::ZERO {
+            return None;
+        }
+        ((ask - bid) / ask * Decimal::from(10_000)).round().to_u32()
+    }
+}
+
+/// An active round with its quotes and spot context
+#[derive(Debug, Clone)]
+pub struct SimMarket {
+    pub round: Round,
+    pub up: Option<SimQuote>,
+    pub down: Option<SimQuote>,
+    /// First ask seen after the round started, per side (dump reference)
+    pub up_open_ask: Option<Decimal>,
+    pub down_open_ask: Option<Decimal>,
+    /// Binance symbol and settlement threshold from `pm_market_metadata`
+    pub symbol: Option<String>,
+    pub price_to_beat: Option<Decimal>,
+    /// Spot at round start and now
+    pub spot_start: Option<Decimal>,
+    pub spot_now: Option<Decimal>,
+    /// Std-dev of 1s spot returns over the last 60s
+    pub volatility_1s: Option<Decimal>,
+}
+
+impl SimMarket {
+    pub fn quote(&self, side: Side) -> Option<&SimQuote> {
+        match side {
+            Side::Up => self.up.as_ref(),
+            Side::Down => self.down.as_ref(),
+        }
+    }
+
+    fn open_ask(&self, side: Side) -> Option<Decimal> {
+        match side {
+            Side::Up => self.up_open_ask,
+            Side::Down => self.down_open_ask,
+        }
+    }
+
+    fn ask(&self, side: Side) -> Option<Decimal> {
+        self.quote(side)?.best_ask.filter(|a| *a > Decimal::ZERO)
+    }
+}
+
+/// Net open inventory of one token
+#[derive(Debug, Clone)]
+pub struct SimPosition {
+    pub token_id: String,
+    pub market_slug: String,
+    pub side: String,
+    pub shares: Decimal,
+    pub avg_price: Decimal,
+}
+
+/// Live state the strategies are evaluated against
+#[derive(Debug, Clone)]
+pub struct SimSnapshot {
+    pub loaded_at: DateTime<Utc>,
+    pub markets: Vec<SimMarket>,
+    pub positions: Vec<SimPosition>,
+    /// Risk state assumed by the risk-state validator (default: normal)
+    pub risk_state: RiskState,
+}
+
+impl SimSnapshot {
+    /// Cost basis of all open positions
+    pub fn exposure(&self) -> Decimal {
+        self.positions.iter().map(|p| p.shares * p.avg_price).sum()
+    }
+
+    /// Market whose slug equals `query`, else the first containing it
+    pub fn find(&self, query: &str) -> Option<&SimMarket> {
+        self.markets
+            .iter()
+            .find(|m| m.round.slug == query)
+            .or_else(|| self.markets.iter().find(|m| m.round.slug.contains(query)))
+    }
+}
+
+/// Two-leg cycle parameters (seeded from `[strategy]` and `[risk]`)
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct TwoLegSimConfig {
+    pub shares: u64,
+    pub window_min: u64,
+    pub move_pct: Decimal,
+    pub sum_target: Decimal,
+    pub fee_buffer: Decimal,
+    pub slippage_buffer: Decimal,
+    pub profit_buffer: Decimal,
+    pub max_single_exposure_usd: Decimal,
+    pub min_remaining_seconds: u64,
+    pub max_spread_bps: u32,
+    /// Leg1 size gate; snapshots carry no live fill rates, so an enabled gate
+    /// reports any size above its base as unproven
+    #[serde(default)]
+    pub fillability: FillabilityGate,
+}
+
+impl TwoLegSimConfig {
+    pub fn from_app_config(config: &AppConfig) -> Self {
+        Self {
+            shares: config.strategy.shares,
+            window_min: config.strategy.window_min,
+            move_pct: config.strategy.move_pct,
+            sum_target: config.strategy.sum_target,
+            fee_buffer: config.strategy.fee_buffer,
+            slippage_buffer: config.strategy.slippage_buffer,
+            profit_buffer: config.strategy.profit_buffer,
+            max_single_exposure_usd: config.risk.max_single_exposure_usd,
+            min_remaining_seconds: config.risk.min_remaining_seconds,
+            max_spread_bps: 500,
+            fillability: FillabilityGate::default(),
+        }
+    }
+}
+
+/// Overwrite one field of a serde config. `value` is parsed as JSON when
+/// possible (numbers, bools, arrays) and taken as a string otherwise.
+pub fn patch_config<T: Serialize + DeserializeOwned>(
+    config: &T,
+    key: &str,
+    value: &str,
+) -> Result<T> {
+    let mut json = serde_json::to_value(config)?;
+    let fields = json
+        .as_object_mut()
+        .ok_or_else(|| PloyError::Internal("config is not an object".to_string()))?;
+    if !fields.contains_key(key) {
+        let mut known: Vec<&String> = fields.keys().collect();
+        known.sort();
+        return Err(PloyError::Validation(format!(
+            "unknown field '{}' (known: {})",
+            key,
+            known
+                .iter()
+                .map(|k| k.as_str())
+                .collect::<Vec<_>>()
+                .join(", ")
+        )));
+    }
+    let parsed = serde_json::from_str(value)
+        .unwrap_or_else(|_| serde_json::Value::String(value.to_string()));
+    // Decimals serialize as strings; keep numeric input in that form.
+    let parsed = match (&fields[key], parsed) {
+        (serde_json::Value::String(_), serde_json::Value::Number(n)) => {
+            serde_json::Value::String(n.to_string())
+        }
+        (_, parsed) => parsed,
+    };
+    fields.insert(key.to_string(), parsed);
+    serde_json::from_value(json)
+        .map_err(|e| PloyError::Validation(format!("invalid value for '{}': {}", key, e)))
+}
+
+/// One step of the simulated decision
+#[derive(Debug, Clone)]
+pub struct SimCheck {
+    pub name: String,
+    /// PASS / FAIL / SKIP
+    pub status: &'static str,
+    pub detail: String,
+}
+
+impl SimCheck {
+    fn gate(name: &str, passed: bool, detail: String) -> Self {
+        Self {
+            name: name.to_string(),
+            status: if passed { "PASS" } else { "FAIL" },
+            detail,
+        }
+    }
+
+    fn from_outcome(stage: &str, outcome: ValidatorOutcome) -> Self {
+        Self {
+            name: format!("{}/{}", stage, outcome.validator),
+            status: outcome.status(),
+            detail: outcome.error.unwrap_or_default(),
+        }
+    }
+}
+
+/// Expected value of the simulated entry, per share and in total
+#[derive(Debug, Clone, Default)]
+pub struct EvBreakdown {
+    /// Model probability the position pays out $1 per share
+    pub fair_value: Decimal,
+    /// Entry price per share (sum of asks for paired entries)
+    pub price: Decimal,
+    /// fair_value - price
+    pub gross_edge: Decimal,
+    /// Taker fee per share from the parabolic fee curve
+    pub fee: Decimal,
+    /// Expected depth slippage per share
+    pub slippage: Decimal,
+    /// gross_edge - fee - slippage
+    pub net_edge: Decimal,
+    /// Net edge required by the strategy
+    pub min_edge: Decimal,
+    pub shares: u64,
+}
+
+impl EvBreakdown {
+    pub fn expected_pnl(&self) -> Decimal {
+        self.net_edge * Decimal::from(self.shares)
+    }
+}
+
+/// What the strategy would do, and why
+#[derive(Debug, Clone)]
+pub struct SimDecision {
+    pub strategy: SimStrategy,
+    pub market_slug: String,
+    /// e.g. `BUY UP 100 @ 0.4500`, or None for no trade
+    pub action: Option<String>,
+    pub checks: Vec<SimCheck>,
+    pub ev: Option<EvBreakdown>,
+}
+
+impl SimDecision {
+    fn new(strategy: SimStrategy, market: &SimMarket) -> Self {
+        Self {
+            strategy,
+            market_slug: market.round.slug.clone(),
+            action: None,
+            checks: Vec::new(),
+            ev: None,
+        }
+    }
+
+    pub fn passed(&self) -> bool {
+        self.checks.iter().all(|c| c.status != "FAIL")
+    }
+
+    /// Set the action only when every check passed
+    fn conclude(mut self, action: String) -> Self {
+        if self.passed() {
+            self.action = Some(action);
+        }
+        self
+    }
+}
+
+impl fmt::Display for SimDecision {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        writeln!(f, "{} on {}", self.strategy.as_str(), self.market_slug)?;
+        writeln!(f, "  validation chain:")?;
+        for check in &self.checks {
+            if check.detail.is_empty() {
+                writeln!(f, "    {:<4} {}", check.status, check.name)?;
+            } else {
+                writeln!(
+                    f,
+                    "    {:<4} {:<28} {}",
+                    check.status, check.name, check.detail
+                )?;
+            }
+        }
+        if let Some(ev) = &self.ev {
+            writeln!(f, "  EV breakdown (per share):")?;
+            writeln!(f, "    fair value   {:>9.4}", ev.fair_value)?;
+            writeln!(f, "    entry price  {:>9.4}", ev.price)?;
+            writeln!(f, "    gross edge   {:>+9.4}", ev.gross_edge)?;
+            writeln!(f, "    taker fee    {:>9.4}", -ev.fee)?;
+            writeln!(f, "    slippage     {:>9.4}", -ev.slippage)?;
+            writeln!(
+                f,
+                "    net edge     {:>+9.4}  (min {:.4})",
+                ev.net_edge, ev.min_edge
+            )?;
+            writeln!(
+                f,
+                "    expected PnL {:>+9.2} USD on {} shares",
+                ev.expected_pnl(),
+                ev.shares
+            )?;
+        }
+        match &self.action {
+            Some(action) => write!(f, "  => WOULD {} (dry: no order placed)", action),
+            None => write!(f, "  => NO TRADE"),
+        }
+    }
+}
+
+fn fee_and_slippage(
+    fees: &FeeModel,
+    quote: &SimQuote,
+    price: Decimal,
+    shares: u64,
+) -> (Decimal, Decimal) {
+    let depth_ratio = match quote.ask_size {
+        Some(size) if size > Decimal::ZERO => Decimal::from(shares) / size,
+        _ => Decimal::ONE,
+    };
+    let cost = fees.all_in_cost(
+        price,
+        quote.best_bid.unwrap_or(price),
+        quote.best_ask.unwrap_or(price),
+        depth_ratio,
+    );
+    (cost.taker_fee, cost.depth_slippage)
+}
+
+/// Two-leg cycle: Leg1 buys the side that dumped `move_pct` from its open
+/// ask, Leg2 hedges the other side when the pair sum is under target.
+pub fn simulate_two_leg(
+    config: &TwoLegSimConfig,
+    market: &SimMarket,
+    snapshot: &SimSnapshot,
+    now: DateTime<Utc>,
+) -> SimDecision {
+    let mut decision = SimDecision::new(SimStrategy::TwoLeg, market);
+    let fees = FeeModel::crypto();
+
+    // Leg1 candidate: the side with the larger drop from its open ask
+    let drop_from_open = |side: Side| -> Option<Decimal> {
+        let (open, ask) = (market.open_ask(side)?, market.ask(side)?);
+        (open > Decimal::ZERO).then(|| (open - ask) / open)
+    };
+    let (side, dropped) = [Side::Up, Side::Down]
+        .into_iter()
+        .filter_map(|s| drop_from_open(s).map(|d| (s, d)))
+        .max_by_key(|(_, d)| *d)
+        .unwrap_or((Side::Up, Decimal::ZERO));
+    let (Some(leg1_ask), Some(leg2_ask)) = (market.ask(side), market.ask(side.opposite())) else {
+        decision.checks.push(SimCheck::gate(
+            "quotes",
+            false,
+            "no live ask on both sides".to_string(),
+        ));
+        return decision;
+    };
+
+    let round_secs = (market.round.end_time - market.round.start_time).num_seconds();
+    let elapsed = (now - market.round.start_time).num_seconds();
+    let window = (config.window_min as f64 * 60.0 * Timeframe::window_scale(round_secs)) as i64;
+    decision.checks.push(SimCheck::gate(
+        "watch_window",
+        elapsed <= window,
+        format!("elapsed={}s, window={}s", elapsed, window),
+    ));
+    decision.checks.push(SimCheck::gate(
+        "dump_trigger",
+        dropped >= config.move_pct,
+        format!(
+            "{} dropped {:.2}% (need {:.2}%)",
+            side,
+            dropped * Decimal::ONE_HUNDRED,
+            config.move_pct * Decimal::ONE_HUNDRED
+        ),
+    ));
+
+    let mut ctx = ValidationContext::new()
+        .with_trade(config.shares, leg1_ask)
+        .with_round(market.round.clone())
+        .with_risk_state(snapshot.risk_state)
+        .with_leg1(leg1_ask)
+        .with_opposite_ask(leg2_ask);
+    if let Some(spread) = market.quote(side).and_then(SimQuote::spread_bps) {
+        ctx = ctx.with_spread(spread);
+    }
+    let leg1 = leg1_entry_chain(
+        config.max_single_exposure_usd,
+        config.min_remaining_seconds,
+        config.max_spread_bps,
+        Some(&config.fillability),
+    );
+    let leg2 = leg2_entry_chain(
+        config.sum_target,
+        config.fee_buffer,
+        config.slippage_buffer,
+        config.profit_buffer,
+    );
+    decision.checks.extend(
+        leg1.outcomes(&ctx)
+            .into_iter()
+            .map(|o| SimCheck::from_outcome("leg1", o)),
+    );
+    decision.checks.extend(
+        leg2.outcomes(&ctx)
+            .into_iter()
+            .map(|o| SimCheck::from_outcome("leg2", o)),
+    );
+
+    // The completed pair pays exactly $1 per share.
+    let (fee1, slip1) = market
+        .quote(side)
+        .map(|q| fee_and_slippage(&fees, q, leg1_ask, config.shares))
+        .unwrap_or_default();
+    let (fee2, slip2) = market
+        .quote(side.opposite())
+        .map(|q| fee_and_slippage(&fees, q, leg2_ask, config.shares))
+        .unwrap_or_default();
+    let price = leg1_ask + leg2_ask;
+    let gross_edge = Decimal::ONE - price;
+    decision.ev = Some(EvBreakdown {
+        fair_value: Decimal::ONE,
+        price,
+        gross_edge,
+        fee: fee1 + fee2,
+        slippage: slip1 + slip2,
+        net_edge: gross_edge - fee1 - fee2 - slip1 - slip2,
+        min_edge: config.profit_buffer,
+        shares: config.shares,
+    });
+
+    decision.conclude(format!(
+        "BUY {} {} @ {:.4} then {} @ {:.4}",
+        side,
+        config.shares,
+        leg1_ask,
+        side.opposite(),
+        leg2_ask
+    ))
+}
+
+/// Crypto momentum agent entry on its current window signal
+pub fn simulate_crypto(
+    config: &CryptoTradingConfig,
+    market: &SimMarket,
+    snapshot: &SimSnapshot,
+    now: DateTime<Utc>,
+) -> SimDecision {
+    let mut decision = SimDecision::new(SimStrategy::Crypto, market);
+    let fees = FeeModel::crypto();
+
+    let (Some(up_ask), Some(down_ask)) = (market.ask(Side::Up), market.ask(Side::Down)) else {
+        decision.checks.push(SimCheck::gate(
+            "quotes",
+            false,
+            "no live ask on both sides".to_string(),
+        ));
+        return decision;
+    };
+    let (Some(start), Some(spot)) = (market.spot_start, market.spot_now) else {
+        decision.checks.push(SimCheck::gate(
+            "spot",
+            false,
+            "no Binance spot for the window".to_string(),
+        ));
+        return decision;
+    };
+    let window_move = if start > Decimal::ZERO {
+        (spot - start) / start
+    } else {
+        Decimal::ZERO
+    };
+    let side = if window_move >= Decimal::ZERO {
+        Side::Up
+    } else {
+        Side::Down
+    };
+    let remaining = (market.round.end_time - now).num_seconds();
+
+    decision.checks.push(SimCheck::gate(
+        "time_remaining",
+        remaining >= config.min_time_remaining_secs as i64
+            && remaining <= config.max_time_remaining_secs as i64,
+        format!(
+            "remaining={}s, allowed={}..{}s",
+            remaining, config.min_time_remaining_secs, config.max_time_remaining_secs
+        ),
+    ));
+    decision.checks.push(SimCheck::gate(
+        "window_move",
+        window_move.abs() >= config.min_window_move_pct,
+        format!(
+            "move={:+.4}%, min={:.4}%",
+            window_move * Decimal::ONE_HUNDRED,
+            config.min_window_move_pct * Decimal::ONE_HUNDRED
+        ),
+    ));
+    let spread = |q: Option<&SimQuote>| -> Decimal {
+        match q.and_then(|q| Some((q.best_bid?, q.best_ask?))) {
+            Some((bid, ask)) if bid > Decimal::ZERO && ask > Decimal::ZERO => (ask - bid) / ask,
+            _ => Decimal::ONE,
+        }
+    };
+    let widest = spread(market.up.as_ref()).max(spread(market.down.as_ref()));
+    decision.checks.push(SimCheck::gate(
+        "spread",
+        widest <= config.max_spread_pct,
+        format!("widest={:.4}, max={:.4}", widest, config.max_spread_pct),
+    ));
+
+    let sum_of_asks = up_ask + down_ask;
+    match config.entry_mode {
+        CryptoEntryMode::ArbOnly => decision.checks.push(SimCheck::gate(
+            "entry_mode/arb_only",
+            sum_of_asks < config.sum_threshold,
+            format!(
+                "sum_of_asks={:.4}, threshold={:.4}",
+                sum_of_asks, config.sum_threshold
+            ),
+        )),
+        CryptoEntryMode::VolStraddle => {
+            let vol = market.volatility_1s.unwrap_or(Decimal::ZERO);
+            decision.checks.push(SimCheck::gate(
+                "entry_mode/vol_straddle",
+                sum_of_asks < config.straddle_threshold && vol >= config.straddle_min_vol,
+                format!(
+                    "sum_of_asks={:.4} (< {:.4}), vol={} (>= {})",
+                    sum_of_asks, config.straddle_threshold, vol, config.straddle_min_vol
+                ),
+            ));
+            let ctx = ValidationContext::new()
+                .with_trade(config.default_shares, sum_of_asks)
+                .with_round(market.round.clone())
+                .with_risk_state(snapshot.risk_state);
+            decision.checks.extend(
+                risk_chain(config, snapshot)
+                    .outcomes(&ctx)
+                    .into_iter()
+                    .map(|o| SimCheck::from_outcome("risk", o)),
+            );
+            let gross_edge = Decimal::ONE - sum_of_asks;
+            let (fee_up, slip_up) = market
+                .up
+                .as_ref()
+                .map(|q| fee_and_slippage(&fees, q, up_ask, config.default_shares))
+                .unwrap_or_default();
+            let (fee_down, slip_down) = market
+                .down
+                .as_ref()
+                .map(|q| fee_and_slippage(&fees, q, down_ask, config.default_shares))
+                .unwrap_or_default();
+            decision.ev = Some(EvBreakdown {
+                fair_value: Decimal::ONE,
+                price: sum_of_asks,
+                gross_edge,
+                fee: fee_up + fee_down,
+                slippage: slip_up + slip_down,
+                net_edge: gross_edge - fee_up - fee_down - slip_up - slip_down,
+                min_edge: Decimal::ZERO,
+                shares: config.default_shares,
+            });
+            return decision.conclude(format!(
+                "BUY UP {} @ {:.4} + DOWN {} @ {:.4}",
+                config.default_shares, up_ask, config.default_shares, down_ask
+            ));
+        }
+        CryptoEntryMode::Directional => {}
+    }
+
+    let price = if side == Side::Up { up_ask } else { down_ask };
+    let required_return = market
+        .price_to_beat
+        .and_then(|thr| required_return_from_threshold(start, thr))
+        .unwrap_or(Decimal::ZERO);
+    let p_up = estimate_p_up_window(
+        window_move,
+        required_return,
+        market.volatility_1s,
+        remaining,
+        config.oracle_lag_buffer_secs,
+    );
+    let fair_value = if side == Side::Up {
+        p_up
+    } else {
+        Decimal::ONE - p_up
+    };
+    let gross_edge = fair_value - price;
+    let min_edge = dynamic_min_edge(window_move.abs(), config.min_edge);
+    decision.checks.push(SimCheck::gate(
+        "edge",
+        gross_edge >= min_edge,
+        format!(
+            "edge={:+.4}, min={:.4} (before toxicity/venue/calendar)",
+            gross_edge, min_edge
+        ),
+    ));
+
+    let ctx = ValidationContext::new()
+        .with_trade(config.default_shares, price)
+        .with_round(market.round.clone())
+        .with_risk_state(snapshot.risk_state);
+    decision.checks.extend(
+        risk_chain(config, snapshot)
+            .outcomes(&ctx)
+            .into_iter()
+            .map(|o| SimCheck::from_outcome("risk", o)),
+    );
+
+    let (fee, slippage) = market
+        .quote(side)
+        .map(|q| fee_and_slippage(&fees, q, price, config.default_shares))
+        .unwrap_or_default();
+    decision.ev = Some(EvBreakdown {
+        fair_value,
+        price,
+        gross_edge,
+        fee,
+        slippage,
+        net_edge: gross_edge - fee - slippage,
+        min_edge,
+        shares: config.default_shares,
+    });
+    decision.conclude(format!(
+        "BUY {} {} @ {:.4}",
+        side, config.default_shares, price
+    ))
+}
+
+/// Agent risk limits as a validation chain
+fn risk_chain(config: &CryptoTradingConfig, snapshot: &SimSnapshot) -> ValidationChain {
+    ValidationChain::new()
+        .add(RiskStateValidator::new())
+        .add(
+            ExposureValidator::new(config.risk_params.max_order_value)
+                .with_total_limit(config.risk_params.max_total_exposure, snapshot.exposure()),
+        )
+        .add(TimeRemainingValidator::new(config.min_time_remaining_secs))
+}
+
+/// Load active rounds, live quotes, spot context and open positions
+pub async fn load_snapshot(pool: &PgPool, include_dry_run: bool) -> Result<SimSnapshot> {
+    let now = Utc::now();
+
+    #[allow(clippy::type_complexity)]
+    let rounds: Vec<(
+        i32,
+        String,
+        String,
+        String,
+        DateTime<Utc>,
+        DateTime<Utc>,
+        Option<String>,
+        Option<Decimal>,
+    )> = sqlx::query_as(
+        r#"
+        SELECT r.id, r.slug, r.up_token_id, r.down_token_id, r.start_time, r.end_time,
+               m.symbol, m.price_to_beat
+        FROM rounds r
+        LEFT JOIN pm_market_metadata m ON m.market_slug = r.slug
+        WHERE r.start_time <= $1 AND r.end_time > $1
+        ORDER BY r.end_time ASC
+        "#,
+    )
+    .bind(now)
+    .fetch_all(pool)
+    .await?;
+
+    let tokens: Vec<String> = rounds
+        .iter()
+        .flat_map(|r| [r.2.clone(), r.3.clone()])
+        .collect();
+    let mut quotes: HashMap<String, SimQuote> = sqlx::query_as::<
+        _,
+        (
+            String,
+            Option<Decimal>,
+            Option<Decimal>,
+            Option<Decimal>,
+            DateTime<Utc>,
+        ),
+    >(
+        r#"
+        SELECT DISTINCT ON (token_id) token_id, best_bid, best_ask, ask_size, received_at
+        FROM clob_quote_ticks
+        WHERE token_id = ANY($1) AND received_at > $2
+        ORDER BY token_id, received_at DESC
+        "#,
+    )
+    .bind(&tokens)
+    .bind(now - chrono::Duration::seconds(QUOTE_MAX_AGE_SECS))
+    .fetch_all(pool)
+    .await?
+    .into_iter()
+    .map(|(token, best_bid, best_ask, ask_size, received_at)| {
+        (
+            token,
+            SimQuote {
+                best_bid,
+                best_ask,
+                ask_size,
+                received_at,
+            },
+        )
+    })
+    .collect();
+
+    let round_ids: Vec<i32> = rounds.iter().map(|r| r.0).collect();
+    let open_asks: HashMap<String, Decimal> = sqlx::query_as::<_, (String, Decimal)>(
+        r#"
+        SELECT DISTINCT ON (q.token_id) q.token_id, q.best_ask
+        FROM clob_quote_ticks q
+        JOIN rounds r ON q.token_id IN (r.up_token_id, r.down_token_id)
+        WHERE r.id = ANY($1) AND q.received_at >= r.start_time AND q.best_ask IS NOT NULL
+        ORDER BY q.token_id, q.received_at ASC
+        "#,
+    )
+    .bind(&round_ids)
+    .fetch_all(pool)
+    .await?
+    .into_iter()
+    .collect();
+
+    let mut spot_cache: HashMap<String, (Option<Decimal>, Option<Decimal>)> = HashMap::new();
+    let mut markets = Vec::with_capacity(rounds.len());
+    for (id, slug, up_token_id, down_token_id, start_time, end_time, symbol, price_to_beat) in
+        rounds
+    {
+        let (spot_start, spot_now, volatility_1s) = match symbol.as_deref() {
+            Some(symbol) => {
+                let spot_start = spot_at(pool, symbol, start_time).await?;
+                let (spot_now, vol) = match spot_cache.get(symbol) {
+                    Some(cached) => *cached,
+                    None => {
+                        let loaded = spot_now_and_volatility(pool, symbol, now).await?;
+                        spot_cache.insert(symbol.to_string(), loaded);
+                        loaded
+                    }
+                };
+                (spot_start, spot_now, vol)
+            }
+            None => (None, None, None),
+        };
+        markets.push(SimMarket {
+            up: quotes.remove(&up_token_id),
+            down: quotes.remove(&down_token_id),
+            up_open_ask: open_asks.get(&up_token_id).copied(),
+            down_open_ask: open_asks.get(&down_token_id).copied(),
+            round: Round {
+                id: Some(id),
+                slug,
+                up_token_id,
+                down_token_id,
+                start_time,
+                end_time,
+                outcome: None,
+            },
+            symbol,
+            price_to_beat,
+            spot_start,
+            spot_now,
+            volatility_1s,
+        });
+    }
+
+    let positions = sqlx::query_as::<_, (String, String, String, Decimal, Decimal)>(
+        r#"
+        SELECT token_id, market_slug, market_side,
+               SUM(CASE WHEN is_buy THEN filled_shares ELSE -filled_shares END)::numeric,
+               SUM(CASE WHEN is_buy THEN filled_shares * COALESCE(avg_fill_price, limit_price)
+                        ELSE 0 END)
+               / NULLIF(SUM(CASE WHEN is_buy THEN filled_shares ELSE 0 END), 0)
+        FROM agent_order_executions
+        WHERE filled_shares > 0
+          AND ($1 OR dry_run = FALSE)
+          AND token_id NOT IN (SELECT token_id FROM pm_token_settlements WHERE resolved = TRUE)
+        GROUP BY token_id, market_slug, market_side
+        HAVING SUM(CASE WHEN is_buy THEN filled_shares ELSE -filled_shares END) > 0
+           AND SUM(CASE WHEN is_buy THEN filled_shares ELSE 0 END) > 0
+        "#,
+    )
+    .bind(include_dry_run)
+    .fetch_all(pool)
+    .await?
+    .into_iter()
+    .map(
+        |(token_id, market_slug, side, shares, avg_price)| SimPosition {
+            token_id,
+            market_slug,
+            side,
+            shares,
+            avg_price,
+        },
+    )
+    .collect();
+
+    Ok(SimSnapshot {
+        loaded_at: now,
+        markets,
+        positions,
+        risk_state: RiskState::Normal,
+    })
+}
+
+async fn spot_at(pool: &PgPool, symbol: &str, at: DateTime<Utc>) -> Result<Option<Decimal>> {
+    Ok(sqlx::query_scalar(
+        "SELECT price FROM binance_price_ticks WHERE symbol = $1 AND trade_time <= $2 \
+         ORDER BY trade_time DESC LIMIT 1",
+    )
+    .bind(symbol)
+    .bind(at)
+    .fetch_optional(pool)
+    .await?)
+}
+
+/// Latest spot and the std-dev of 1s returns over the last 60s
+async fn spot_now_and_volatility(
+    pool: &PgPool,
+    symbol: &str,
+    now: DateTime<Utc>,
+) -> Result<(Option<Decimal>, Option<Decimal>)> {
+    let prices: Vec<Decimal> = sqlx::query_scalar(
+        r#"
+        SELECT DISTINCT ON (date_trunc('second', trade_time)) price
+        FROM binance_price_ticks
+        WHERE symbol = $1 AND trade_time > $2 AND trade_time <= $3
+        ORDER BY date_trunc('second', trade_time), trade_time DESC
+        "#,
+    )
+    .bind(symbol)
+    .bind(now - chrono::Duration::seconds(60))
+    .bind(now)
+    .fetch_all(pool)
+    .await?;
+
+    let spot = match prices.last() {
+        Some(p) => Some(*p),
+        None => spot_at(pool, symbol, now).await?,
+    };
+    Ok((spot, return_volatility(&prices)))
+}
+
+/// Population std-dev of consecutive returns (None below 5 prices)
+fn return_volatility(prices: &[Decimal]) -> Option<Decimal> {
+    if prices.len() < 5 {
+        return None;
+    }
+    let returns: Vec<f64> = prices
+        .windows(2)
+        .filter(|w| !w[0].is_zero())
+        .filter_map(|w| ((w[1] - w[0]) / w[0]).to_f64())
+        .collect();
+    if returns.is_empty() {
+        return None;
+    }
+    let mean = returns.iter().sum::<f64>() / returns.len() as f64;
+    let variance = returns.iter().map(|r| (r - mean).powi(2)).sum::<f64>() / returns.len() as f64;
+    Decimal::from_f64(variance.sqrt())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use chrono::Duration;
+    use rust_decimal_macros::dec;
+
+    fn quote(bid: Decimal, ask: Decimal) -> SimQuote {
+        SimQuote {
+            best_bid: Some(bid),
+            best_ask: Some(ask),
+            ask_size: Some(dec!(1000)),
+            received_at: Utc::now(),
+        }
+    }
+
+    fn market(now: DateTime<Utc>) -> SimMarket {
+        SimMarket {
+            round: Round {
+                id: Some(1),
+                slug: "btc-updown-15m-1760000000".into(),
+                up_token_id: "up".into(),
+                down_token_id: "down".into(),
+                start_time: now - Duration::minutes(1),
+                end_time: now + Duration::minutes(14),
+                outcome: None,
+            },
+            up: Some(quote(dec!(0.34), dec!(0.35))),
+            down: Some(quote(dec!(0.57), dec!(0.58))),
+            up_open_ask: Some(dec!(0.50)),
+            down_open_ask: Some(dec!(0.51)),
+            symbol: None,
+            price_to_beat: None,
+            spot_start: None,
+            spot_now: None,
+            volatility_1s: None,
+        }
+    }
+
+    #[test]
+    fn test_two_leg_reports_chain_and_ev() {
+        let now = Utc::now();
+        let snapshot = SimSnapshot {
+            loaded_at: now,
+            markets: vec![market(now)],
+            positions: Vec::new(),
+            risk_state: RiskState::Normal,
+        };
+        let config = TwoLegSimConfig {
+            shares: 20,
+            window_min: 2,
+            move_pct: dec!(0.15),
+            sum_target: dec!(0.96),
+            fee_buffer: dec!(0.005),
+            slippage_buffer: dec!(0.01),
+            profit_buffer: dec!(0.01),
+            max_single_exposure_usd: dec!(50),
+            min_remaining_seconds: 60,
+            max_spread_bps: 500,
+            fillability: FillabilityGate::default(),
+        };
+
+        let decision = simulate_two_leg(&config, &snapshot.markets[0], &snapshot, now);
+        assert!(decision.passed(), "{}", decision);
+        assert_eq!(
+            decision.action.as_deref(),
+            Some("BUY UP 20 @ 0.3500 then DOWN @ 0.5800")
+        );
+        let ev = decision.ev.as_ref().unwrap();
+        assert_eq!(ev.gross_edge, dec!(0.07));
+        assert!(ev.net_edge < ev.gross_edge && ev.net_edge > Decimal::ZERO);
+
+        // A tighter sum target fails Leg2 and the report says so
+        let strict = patch_config(&config, "sum_target", "0.90").unwrap();
+        let decision = simulate_two_leg(&strict, &snapshot.markets[0], &snapshot, now);
+        assert!(decision.action.is_none());
+        assert!(decision
+            .checks
+            .iter()
+            .any(|c| c.name == "leg2/SumTarget" && c.status == "FAIL"));
+        assert!(patch_config(&config, "no_such_field", "1").is_err());
+    }
+}
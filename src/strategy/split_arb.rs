@@ -8,12 +8,19 @@
 //!
 //! Key insight: Don't need to buy both sides simultaneously.
 //! Retail panic creates mispricings at different times.
+//!
+//! Legs are sized from execution reports, not requests: the hedge only covers
+//! what leg 1 actually filled, and a residual manager works off any unfilled
+//! remainder (re-quote, cancel, or market-out) so the pair never stays lopsided.
 
 use crate::adapters::{PolymarketClient, PolymarketWebSocket, QuoteUpdate};
-use crate::domain::Side;
+use crate::domain::{OrderStatus, Side};
 use crate::error::Result;
+use crate::platform::Timeframe;
+use crate::strategy::execution::executor::ExecutionResult;
 use crate::strategy::OrderExecutor;
 use chrono::{DateTime, Duration, Utc};
+use rust_decimal::prelude::ToPrimitive;
 use rust_decimal::Decimal;
 use rust_decimal_macros::dec;
 use serde::{Deserialize, Serialize};
@@ -61,7 +68,8 @@ pub struct SplitArbConfig {
     /// Minimum profit margin required (e.g., 0.05 = 5¢ per pair)
     pub min_profit_margin: Decimal,
 
-    /// Maximum time to wait for hedge (seconds)
+    /// Maximum time to wait for hedge (seconds), calibrated on 15m rounds and
+    /// stretched for longer ones
     pub max_hedge_wait_secs: u64,
 
     /// Shares per trade
@@ -75,6 +83,22 @@ pub struct SplitArbConfig {
 
     /// Series IDs to monitor
     pub series_ids: Vec<String>,
+
+    /// How a resting hedge remainder is worked off once it goes stale
+    #[serde(default)]
+    pub residual_policy: ResidualPolicy,
+
+    /// Seconds a working order may rest before the residual manager acts on it
+    #[serde(default = "default_residual_timeout_secs")]
+    pub residual_timeout_secs: u64,
+}
+
+fn default_residual_timeout_secs() -> u64 {
+    30
+}
+
+fn default_round_secs() -> i64 {
+    Timeframe::REFERENCE_SECS
 }
 
 impl Default for SplitArbConfig {
@@ -83,7 +107,7 @@ impl Default for SplitArbConfig {
             max_entry_price: dec!(0.35),    // Max 35¢ per side
             target_total_cost: dec!(0.70),  // Target 70¢ total (30¢ profit)
             min_profit_margin: dec!(0.05),  // Min 5¢ profit
-            max_hedge_wait_secs: 900,       // 15 minutes max wait
+            max_hedge_wait_secs: 900,       // 15 minutes max wait (15m rounds)
             shares_per_trade: 100,          // ~$35 per leg
             max_unhedged_positions: 3,      // Max 3 unhedged at once
             unhedged_stop_loss: dec!(0.15), // 15% stop loss on unhedged
@@ -92,6 +116,132 @@ impl Default for SplitArbConfig {
                 "10191".into(), // ETH 15m
                 "41".into(),    // BTC daily
             ],
+            residual_policy: ResidualPolicy::Requote,
+            residual_timeout_secs: default_residual_timeout_secs(),
+        }
+    }
+}
+
+/// What to do with a hedge order that rests partially unfilled
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum ResidualPolicy {
+    /// Cancel and re-quote the remainder at the current ask (if still profitable)
+    #[default]
+    Requote,
+    /// Cancel and wait for a fresh hedge signal
+    Cancel,
+    /// Cancel and sell the unhedged excess of leg 1 at the bid
+    MarketOut,
+}
+
+/// Next step for a position's partial-fill residual
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum ResidualAction {
+    None,
+    /// Cancel leg 1's unfilled remainder so unhedged exposure stops growing
+    CancelEntry,
+    /// Cancel the stale hedge order
+    CancelHedge,
+    /// Cancel the stale hedge order and re-quote what is still unhedged
+    RequoteHedge {
+        price: Decimal,
+    },
+    /// Cancel the stale hedge order and sell leg 1's unhedged excess
+    MarketOut,
+}
+
+/// Fill state of one leg, built from execution reports
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+pub struct LegFill {
+    /// Working order on the book, if any
+    pub order_id: Option<String>,
+    /// Shares requested on the working order
+    pub order_shares: u64,
+    /// Shares filled on the working order so far
+    pub order_filled: u64,
+    /// When the working order was placed
+    pub quoted_at: Option<DateTime<Utc>>,
+    /// Shares filled across all orders of this leg
+    pub filled: u64,
+    /// Cost of the filled shares
+    pub cost: Decimal,
+}
+
+impl LegFill {
+    pub fn is_working(&self) -> bool {
+        self.order_id.is_some()
+    }
+
+    /// Unfilled shares on the working order
+    pub fn remaining(&self) -> u64 {
+        self.order_shares.saturating_sub(self.order_filled)
+    }
+
+    pub fn avg_price(&self) -> Option<Decimal> {
+        (self.filled > 0).then(|| self.cost / Decimal::from(self.filled))
+    }
+
+    /// Start tracking a newly placed order
+    pub fn on_submitted(&mut self, order_id: String, shares: u64, now: DateTime<Utc>) {
+        self.order_id = Some(order_id);
+        self.order_shares = shares;
+        self.order_filled = 0;
+        self.quoted_at = Some(now);
+    }
+
+    /// Apply a cumulative execution report for the working order.
+    /// Returns the newly filled shares.
+    pub fn on_report(
+        &mut self,
+        status: OrderStatus,
+        cumulative_filled: u64,
+        price: Decimal,
+    ) -> u64 {
+        let delta = cumulative_filled
+            .min(self.order_shares)
+            .saturating_sub(self.order_filled);
+        if delta > 0 {
+            self.order_filled += delta;
+            self.filled += delta;
+            self.cost += price * Decimal::from(delta);
+        }
+        let terminal = matches!(
+            status,
+            OrderStatus::Filled
+                | OrderStatus::Cancelled
+                | OrderStatus::Rejected
+                | OrderStatus::Expired
+        );
+        if terminal || self.order_filled >= self.order_shares {
+            self.close_order();
+        }
+        delta
+    }
+
+    /// Record a fill without an order (dry run)
+    pub fn on_simulated_fill(&mut self, shares: u64, price: Decimal) {
+        self.filled += shares;
+        self.cost += price * Decimal::from(shares);
+    }
+
+    /// Stop tracking the working order (cancelled or done)
+    pub fn close_order(&mut self) {
+        self.order_id = None;
+        self.order_shares = 0;
+        self.order_filled = 0;
+        self.quoted_at = None;
+    }
+
+    /// Remove sold shares at average cost
+    pub fn reduce(&mut self, shares: u64) {
+        let shares = shares.min(self.filled);
+        if let Some(avg) = self.avg_price() {
+            self.cost -= avg * Decimal::from(shares);
+        }
+        self.filled -= shares;
+        if self.filled == 0 {
+            self.cost = Decimal::ZERO;
         }
     }
 }
@@ -123,6 +273,10 @@ pub struct PartialPosition {
     /// Event end time (for timeout)
     pub event_end_time: DateTime<Utc>,
 
+    /// Round length in seconds (scales hedge timeout and exit lead)
+    #[serde(default = "default_round_secs")]
+    pub round_secs: i64,
+
     /// Token ID of the other side (for hedging)
     pub other_token_id: String,
 
@@ -131,6 +285,86 @@ pub struct PartialPosition {
 
     /// Maximum price we can pay for hedge to hit target profit
     pub max_hedge_price: Decimal,
+
+    /// Actual fills of the first leg
+    #[serde(default)]
+    pub first_leg: LegFill,
+
+    /// Actual fills of the hedge leg
+    #[serde(default)]
+    pub hedge_leg: LegFill,
+}
+
+impl PartialPosition {
+    /// Average first-leg fill price (limit price until something fills)
+    pub fn first_avg_price(&self) -> Decimal {
+        self.first_leg.avg_price().unwrap_or(self.first_entry_price)
+    }
+
+    /// Filled first-leg shares not covered by hedge fills
+    pub fn open_exposure(&self) -> u64 {
+        self.first_leg.filled.saturating_sub(self.hedge_leg.filled)
+    }
+
+    /// Filled first-leg shares not covered by hedge fills or a working hedge order
+    pub fn unhedged_shares(&self) -> u64 {
+        self.open_exposure()
+            .saturating_sub(self.hedge_leg.remaining())
+    }
+
+    /// Both legs are done and the filled sizes match
+    pub fn is_complete(&self) -> bool {
+        !self.first_leg.is_working()
+            && !self.hedge_leg.is_working()
+            && self.first_leg.filled > 0
+            && self.hedge_leg.filled >= self.first_leg.filled
+    }
+
+    /// Nothing working and nothing held (entry never filled, or fully exited)
+    pub fn is_flat(&self) -> bool {
+        !self.first_leg.is_working() && !self.hedge_leg.is_working() && self.first_leg.filled == 0
+    }
+
+    pub fn refresh_status(&mut self) {
+        self.status = if self.hedge_leg.is_working() {
+            PositionStatus::HedgePending
+        } else {
+            PositionStatus::WaitingForHedge
+        };
+    }
+
+    /// Decide how to work off the partial-fill residual
+    pub fn residual_action(
+        &self,
+        config: &SplitArbConfig,
+        hedge_ask: Option<Decimal>,
+        now: DateTime<Utc>,
+    ) -> ResidualAction {
+        let timeout = Duration::seconds(config.residual_timeout_secs as i64);
+        let stale = |leg: &LegFill| leg.quoted_at.is_some_and(|t| now - t >= timeout);
+
+        // Once hedging has started (or the entry went stale) never add unhedged size
+        if self.first_leg.is_working()
+            && (self.hedge_leg.filled > 0 || self.hedge_leg.is_working() || stale(&self.first_leg))
+        {
+            return ResidualAction::CancelEntry;
+        }
+
+        if !self.hedge_leg.is_working() || !stale(&self.hedge_leg) {
+            return ResidualAction::None;
+        }
+
+        match config.residual_policy {
+            ResidualPolicy::Requote => match hedge_ask {
+                Some(price) if price <= self.max_hedge_price => {
+                    ResidualAction::RequoteHedge { price }
+                }
+                _ => ResidualAction::CancelHedge,
+            },
+            ResidualPolicy::Cancel => ResidualAction::CancelHedge,
+            ResidualPolicy::MarketOut => ResidualAction::MarketOut,
+        }
+    }
 }
 
 #[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
@@ -185,6 +419,7 @@ pub struct MonitoredMarket {
     pub up_token_id: String,
     pub down_token_id: String,
     pub event_end_time: DateTime<Utc>,
+    pub round_secs: i64,
     pub series_id: String,
 }
 
@@ -313,12 +548,25 @@ impl SplitArbEngine {
                     .map(|dt| dt.with_timezone(&Utc))
                     .unwrap_or_else(|| Utc::now() + Duration::hours(24));
 
+                let round_secs = Timeframe::from_hint(series_id)
+                    .or_else(|| details.slug.as_deref().and_then(Timeframe::from_hint))
+                    .and_then(|tf| tf.duration_secs())
+                    .or_else(|| {
+                        details
+                            .start_time
+                            .as_ref()
+                            .and_then(|s| DateTime::parse_from_rfc3339(s).ok())
+                            .map(|start| (end_time - start.with_timezone(&Utc)).num_seconds())
+                    })
+                    .unwrap_or(Timeframe::REFERENCE_SECS);
+
                 let market_info = MonitoredMarket {
                     event_id: event.id.clone(),
                     condition_id: condition_id.clone(),
                     up_token_id: up_token.token_id.clone(),
                     down_token_id: down_token.token_id.clone(),
                     event_end_time: end_time,
+                    round_secs,
                     series_id: series_id.clone(),
                 };
 
@@ -487,23 +735,32 @@ impl SplitArbEngine {
         );
 
         // Execute entry
+        let shares = self.config.shares_per_trade;
+        let mut first_leg = LegFill::default();
         if self.dry_run {
-            info!(
-                "  [DRY RUN] Would buy {} shares of {}",
-                self.config.shares_per_trade, side
-            );
+            info!("  [DRY RUN] Would buy {} shares of {}", shares, side);
+            first_leg.on_simulated_fill(shares, entry_price);
         } else {
             // Place order
-            match self
-                .execute_buy(token_id, entry_price, self.config.shares_per_trade)
-                .await
-            {
-                Ok(_) => {
+            match self.execute_buy(token_id, entry_price, shares).await {
+                Ok(result) => {
+                    first_leg.on_submitted(result.order_id.clone(), shares, Utc::now());
+                    first_leg.on_report(
+                        result.status,
+                        result.filled_shares,
+                        result.avg_fill_price.unwrap_or(entry_price),
+                    );
                     info!(
-                        "  ✓ Order placed for {} @ {}¢",
+                        "  ✓ Order placed for {} @ {}¢ ({}/{} filled)",
                         side,
-                        entry_price * dec!(100)
+                        entry_price * dec!(100),
+                        first_leg.filled,
+                        shares
                     );
+                    if first_leg.filled == 0 && !first_leg.is_working() {
+                        warn!("  ✗ Entry order ended without fills ({:?})", result.status);
+                        return;
+                    }
                 }
                 Err(e) => {
                     error!("  ✗ Order failed: {}", e);
@@ -522,9 +779,12 @@ impl SplitArbEngine {
             shares: self.config.shares_per_trade,
             entry_time: Utc::now(),
             event_end_time: market.event_end_time,
+            round_secs: market.round_secs,
             other_token_id: other_token_id.clone(),
             status: PositionStatus::WaitingForHedge,
             max_hedge_price,
+            first_leg,
+            hedge_leg: LegFill::default(),
         };
 
         {
@@ -547,6 +807,12 @@ impl SplitArbEngine {
             _ => return,
         };
 
+        // Hedge only what leg 1 has actually filled
+        let hedge_shares = position.unhedged_shares();
+        if hedge_shares == 0 {
+            return;
+        }
+
         // Get the price of the side we need to hedge
         let hedge_price = match position.first_side {
             ArbSide::Up => down_ask,
@@ -559,7 +825,7 @@ impl SplitArbEngine {
         }
 
         // Calculate locked profit
-        let total_cost = position.first_entry_price + hedge_price;
+        let total_cost = position.first_avg_price() + hedge_price;
         let locked_profit = Decimal::ONE - total_cost;
 
         if locked_profit < self.config.min_profit_margin {
@@ -572,8 +838,9 @@ impl SplitArbEngine {
         };
 
         info!(
-            "🔒 HEDGE SIGNAL: {} @ {}¢ (total: {}¢, profit: {}¢)",
+            "🔒 HEDGE SIGNAL: {} x{} @ {}¢ (total: {}¢, profit: {}¢)",
             hedge_side,
+            hedge_shares,
             hedge_price * dec!(100),
             total_cost * dec!(100),
             locked_profit * dec!(100)
@@ -583,15 +850,31 @@ impl SplitArbEngine {
         if self.dry_run {
             info!(
                 "  [DRY RUN] Would buy {} shares of {} to hedge",
-                position.shares, hedge_side
+                hedge_shares, hedge_side
             );
+            position
+                .hedge_leg
+                .on_simulated_fill(hedge_shares, hedge_price);
         } else {
             match self
-                .execute_buy(&position.other_token_id, hedge_price, position.shares)
+                .execute_buy(&position.other_token_id, hedge_price, hedge_shares)
                 .await
             {
-                Ok(_) => {
-                    info!("  ✓ Hedge order placed");
+                Ok(result) => {
+                    position.hedge_leg.on_submitted(
+                        result.order_id.clone(),
+                        hedge_shares,
+                        Utc::now(),
+                    );
+                    position.hedge_leg.on_report(
+                        result.status,
+                        result.filled_shares,
+                        result.avg_fill_price.unwrap_or(hedge_price),
+                    );
+                    info!(
+                        "  ✓ Hedge order placed ({}/{} filled)",
+                        position.hedge_leg.order_filled, hedge_shares
+                    );
                 }
                 Err(e) => {
                     error!("  ✗ Hedge order failed: {}", e);
@@ -599,64 +882,85 @@ impl SplitArbEngine {
                 }
             }
         }
+        position.refresh_status();
+
+        if !position.is_complete() {
+            debug!(
+                "Hedge in progress for {}: {} of {} filled shares hedged",
+                &condition_id[..8.min(condition_id.len())],
+                position.hedge_leg.filled,
+                position.first_leg.filled
+            );
+            return;
+        }
+
+        // Move to hedged positions
+        let Some(position) = positions.remove(condition_id) else {
+            return;
+        };
+        drop(positions);
+
+        self.record_hedged(position).await;
+    }
+
+    /// Book a completed pair as a hedged position
+    async fn record_hedged(&self, position: PartialPosition) {
+        let shares = position.hedge_leg.filled.min(position.first_leg.filled);
+        let first_price = position.first_avg_price();
+        let hedge_price = position.hedge_leg.avg_price().unwrap_or(Decimal::ZERO);
+        let total_cost = first_price + hedge_price;
+        let locked_profit = Decimal::ONE - total_cost;
+        let (up_price, down_price) = match position.first_side {
+            ArbSide::Up => (first_price, hedge_price),
+            ArbSide::Down => (hedge_price, first_price),
+        };
+        let (up_token_id, down_token_id) = match position.first_side {
+            ArbSide::Up => (position.first_token_id, position.other_token_id),
+            ArbSide::Down => (position.other_token_id, position.first_token_id),
+        };
 
-        // Create hedged position
         let hedged = HedgedPosition {
-            event_id: position.event_id.clone(),
-            condition_id: condition_id.to_string(),
-            up_token_id: if position.first_side == ArbSide::Up {
-                position.first_token_id.clone()
-            } else {
-                position.other_token_id.clone()
-            },
-            down_token_id: if position.first_side == ArbSide::Down {
-                position.first_token_id.clone()
-            } else {
-                position.other_token_id.clone()
-            },
-            up_entry_price: if position.first_side == ArbSide::Up {
-                position.first_entry_price
-            } else {
-                hedge_price
-            },
-            down_entry_price: if position.first_side == ArbSide::Down {
-                position.first_entry_price
-            } else {
-                hedge_price
-            },
+            event_id: position.event_id,
+            condition_id: position.condition_id,
+            up_token_id,
+            down_token_id,
+            up_entry_price: up_price,
+            down_entry_price: down_price,
             total_cost,
             locked_profit,
-            shares: position.shares,
+            shares,
             entry_time: position.entry_time,
             hedge_time: Utc::now(),
             event_end_time: position.event_end_time,
         };
 
-        // Move to hedged positions
-        positions.remove(condition_id);
-        drop(positions);
-
         {
             let mut hedged_positions = self.hedged_positions.write().await;
-            hedged_positions.push(hedged.clone());
+            hedged_positions.push(hedged);
         }
 
         {
             let mut stats = self.stats.write().await;
             stats.hedges_completed += 1;
-            stats.total_profit += locked_profit * Decimal::from(hedged.shares);
+            stats.total_profit += locked_profit * Decimal::from(shares);
         }
 
         info!(
-            "✅ POSITION HEDGED: Total cost {}¢, Locked profit {}¢/share (${:.2} total)",
+            "✅ POSITION HEDGED: {} shares, Total cost {}¢, Locked profit {}¢/share (${:.2} total)",
+            shares,
             total_cost * dec!(100),
             locked_profit * dec!(100),
-            locked_profit * Decimal::from(hedged.shares)
+            locked_profit * Decimal::from(shares)
         );
     }
 
-    /// Periodic position checks (timeout, stop loss)
+    /// Periodic position checks (fills, residuals, timeout, stop loss)
     async fn check_positions(&self) {
+        if !self.dry_run {
+            self.refresh_fills().await;
+        }
+        self.manage_residuals().await;
+
         let now = Utc::now();
         let mut to_remove = Vec::new();
 
@@ -666,7 +970,10 @@ impl SplitArbEngine {
             for (condition_id, position) in positions.iter() {
                 // Check timeout
                 let elapsed = now - position.entry_time;
-                let max_wait = Duration::seconds(self.config.max_hedge_wait_secs as i64);
+                let max_wait = Duration::seconds(
+                    (self.config.max_hedge_wait_secs as f64
+                        * Timeframe::window_scale(position.round_secs)) as i64,
+                );
 
                 if elapsed > max_wait {
                     warn!(
@@ -682,7 +989,8 @@ impl SplitArbEngine {
 
                 // Check if event is about to end
                 let time_to_end = position.event_end_time - now;
-                if time_to_end < Duration::seconds(30) {
+                let exit_lead_secs = 30.0 * Timeframe::lead_time_scale(position.round_secs);
+                if time_to_end < Duration::seconds(exit_lead_secs as i64) {
                     warn!(
                         "⏰ Event ending soon, exiting unhedged: {} {}",
                         position.first_side,
@@ -699,30 +1007,253 @@ impl SplitArbEngine {
         }
     }
 
-    /// Exit an unhedged position
-    async fn exit_unhedged(&self, condition_id: &str, reason: &str) {
-        let mut positions = self.partial_positions.write().await;
+    /// Poll execution reports for working orders and apply new fills
+    async fn refresh_fills(&self) {
+        let working: Vec<(String, Option<String>, Option<String>)> = {
+            let positions = self.partial_positions.read().await;
+            positions
+                .iter()
+                .filter(|(_, p)| p.first_leg.is_working() || p.hedge_leg.is_working())
+                .map(|(cid, p)| {
+                    (
+                        cid.clone(),
+                        p.first_leg.order_id.clone(),
+                        p.hedge_leg.order_id.clone(),
+                    )
+                })
+                .collect()
+        };
 
-        let position = match positions.remove(condition_id) {
-            Some(p) => p,
-            None => return,
+        for (condition_id, first_order, hedge_order) in working {
+            let first_report = match &first_order {
+                Some(id) => self.fetch_report(id).await,
+                None => None,
+            };
+            let hedge_report = match &hedge_order {
+                Some(id) => self.fetch_report(id).await,
+                None => None,
+            };
+
+            let mut positions = self.partial_positions.write().await;
+            let Some(position) = positions.get_mut(&condition_id) else {
+                continue;
+            };
+            if let Some((status, filled, price)) = first_report {
+                if position.first_leg.order_id == first_order {
+                    let price = price.unwrap_or(position.first_entry_price);
+                    let new = position.first_leg.on_report(status, filled, price);
+                    if new > 0 {
+                        info!(
+                            "Leg 1 fill on {}: +{} ({} total)",
+                            &condition_id[..8.min(condition_id.len())],
+                            new,
+                            position.first_leg.filled
+                        );
+                    }
+                }
+            }
+            if let Some((status, filled, price)) = hedge_report {
+                if position.hedge_leg.order_id == hedge_order {
+                    let price = price.unwrap_or(position.max_hedge_price);
+                    let new = position.hedge_leg.on_report(status, filled, price);
+                    if new > 0 {
+                        info!(
+                            "Hedge fill on {}: +{} ({} of {} hedged)",
+                            &condition_id[..8.min(condition_id.len())],
+                            new,
+                            position.hedge_leg.filled,
+                            position.first_leg.filled
+                        );
+                    }
+                }
+            }
+            position.refresh_status();
+        }
+    }
+
+    /// Fetch (status, cumulative filled shares, avg price) for an order
+    async fn fetch_report(&self, order_id: &str) -> Option<(OrderStatus, u64, Option<Decimal>)> {
+        match self.client.get_order(order_id).await {
+            Ok(order) => {
+                let status = PolymarketClient::infer_order_status(&order);
+                let (filled, avg_price) = PolymarketClient::calculate_fill(&order);
+                Some((
+                    status,
+                    filled.trunc().to_u64().unwrap_or(0),
+                    (avg_price > Decimal::ZERO).then_some(avg_price),
+                ))
+            }
+            Err(e) => {
+                debug!("Failed to fetch order {}: {}", order_id, e);
+                None
+            }
+        }
+    }
+
+    /// Cancel a leg's working order and apply its final fills.
+    /// Returns false if the cancel failed (order stays tracked).
+    async fn cancel_working(&self, leg: &mut LegFill, fallback_price: Decimal) -> bool {
+        let Some(order_id) = leg.order_id.clone() else {
+            return true;
         };
+        if !self.dry_run {
+            if let Err(e) = self.executor.cancel(&order_id).await {
+                warn!("Failed to cancel order {}: {}", order_id, e);
+                return false;
+            }
+            if let Some((status, filled, price)) = self.fetch_report(&order_id).await {
+                leg.on_report(status, filled, price.unwrap_or(fallback_price));
+            }
+        }
+        leg.close_order();
+        true
+    }
 
-        drop(positions);
+    /// Work off partial-fill residuals, then settle positions whose legs are done
+    async fn manage_residuals(&self) {
+        let now = Utc::now();
+        let planned: Vec<(String, ResidualAction)> = {
+            let positions = self.partial_positions.read().await;
+            let cache = self.price_cache.read().await;
+            positions
+                .iter()
+                .map(|(cid, p)| {
+                    let ask = cache.get_ask(&p.other_token_id);
+                    (cid.clone(), p.residual_action(&self.config, ask, now))
+                })
+                .filter(|(_, action)| *action != ResidualAction::None)
+                .collect()
+        };
+
+        for (condition_id, action) in planned {
+            self.apply_residual(&condition_id, action).await;
+        }
+
+        let finished: Vec<PartialPosition> = {
+            let mut positions = self.partial_positions.write().await;
+            let ids: Vec<String> = positions
+                .iter()
+                .filter(|(_, p)| p.is_complete() || p.is_flat())
+                .map(|(cid, _)| cid.clone())
+                .collect();
+            ids.iter().filter_map(|id| positions.remove(id)).collect()
+        };
+        for position in finished {
+            if position.is_complete() {
+                self.record_hedged(position).await;
+            } else {
+                debug!(
+                    "Dropping {} position on {}: entry closed without fills",
+                    position.first_side,
+                    &position.condition_id[..8.min(position.condition_id.len())]
+                );
+            }
+        }
+    }
+
+    async fn apply_residual(&self, condition_id: &str, action: ResidualAction) {
+        // Positions are only mutated from the engine loop, so work on a copy
+        let Some(mut position) = self
+            .partial_positions
+            .read()
+            .await
+            .get(condition_id)
+            .cloned()
+        else {
+            return;
+        };
+        let short_id = &condition_id[..8.min(condition_id.len())];
+
+        match action {
+            ResidualAction::None => return,
+            ResidualAction::CancelEntry => {
+                let remaining = position.first_leg.remaining();
+                let fallback = position.first_entry_price;
+                if self.cancel_working(&mut position.first_leg, fallback).await {
+                    info!(
+                        "Residual: cancelled {} unfilled entry shares on {} ({} filled)",
+                        remaining, short_id, position.first_leg.filled
+                    );
+                }
+            }
+            ResidualAction::CancelHedge => {
+                let fallback = position.max_hedge_price;
+                if self.cancel_working(&mut position.hedge_leg, fallback).await {
+                    info!(
+                        "Residual: cancelled stale hedge on {} ({} of {} hedged)",
+                        short_id, position.hedge_leg.filled, position.first_leg.filled
+                    );
+                }
+            }
+            ResidualAction::RequoteHedge { price } => {
+                let fallback = position.max_hedge_price;
+                if self.cancel_working(&mut position.hedge_leg, fallback).await {
+                    let shares = position.unhedged_shares();
+                    if shares > 0 {
+                        info!(
+                            "Residual: re-quoting {} hedge shares on {} @ {}¢",
+                            shares,
+                            short_id,
+                            price * dec!(100)
+                        );
+                        match self
+                            .execute_buy(&position.other_token_id, price, shares)
+                            .await
+                        {
+                            Ok(result) => {
+                                position.hedge_leg.on_submitted(
+                                    result.order_id.clone(),
+                                    shares,
+                                    Utc::now(),
+                                );
+                                position.hedge_leg.on_report(
+                                    result.status,
+                                    result.filled_shares,
+                                    result.avg_fill_price.unwrap_or(price),
+                                );
+                            }
+                            Err(e) => error!("  ✗ Hedge re-quote failed: {}", e),
+                        }
+                    }
+                }
+            }
+            ResidualAction::MarketOut => {
+                let fallback = position.max_hedge_price;
+                if self.cancel_working(&mut position.hedge_leg, fallback).await {
+                    self.market_out(&mut position, "residual").await;
+                }
+            }
+        }
+
+        position.refresh_status();
+        self.partial_positions
+            .write()
+            .await
+            .insert(condition_id.to_string(), position);
+    }
+
+    /// Sell leg 1's unhedged excess at the bid so both legs match
+    async fn market_out(&self, position: &mut PartialPosition, reason: &str) {
+        let shares = position.open_exposure();
+        if shares == 0 {
+            return;
+        }
 
         // Get current bid for our position
         let cache = self.price_cache.read().await;
         let current_bid = cache.get_bid(&position.first_token_id);
         drop(cache);
 
-        let exit_price = current_bid.unwrap_or(position.first_entry_price);
-        let pnl = exit_price - position.first_entry_price;
-        let pnl_total = pnl * Decimal::from(position.shares);
+        let entry_price = position.first_avg_price();
+        let exit_price = current_bid.unwrap_or(entry_price);
+        let pnl = exit_price - entry_price;
+        let pnl_total = pnl * Decimal::from(shares);
 
         info!(
-            "🚪 EXITING UNHEDGED: {} @ {}¢ → {}¢ ({}: {:.2}¢/share, ${:.2} total)",
+            "🚪 EXITING UNHEDGED: {} x{} @ {}¢ → {}¢ ({}: {:.2}¢/share, ${:.2} total)",
             position.first_side,
-            position.first_entry_price * dec!(100),
+            shares,
+            entry_price * dec!(100),
             exit_price * dec!(100),
             reason,
             pnl * dec!(100),
@@ -732,12 +1263,14 @@ impl SplitArbEngine {
         if !self.dry_run {
             // Place sell order
             if let Err(e) = self
-                .execute_sell(&position.first_token_id, exit_price, position.shares)
+                .execute_sell(&position.first_token_id, exit_price, shares)
                 .await
             {
                 error!("  ✗ Exit order failed: {}", e);
+                return;
             }
         }
+        position.first_leg.reduce(shares);
 
         {
             let mut stats = self.stats.write().await;
@@ -750,8 +1283,40 @@ impl SplitArbEngine {
         }
     }
 
+    /// Exit an unhedged position
+    async fn exit_unhedged(&self, condition_id: &str, reason: &str) {
+        let mut positions = self.partial_positions.write().await;
+
+        let mut position = match positions.remove(condition_id) {
+            Some(p) => p,
+            None => return,
+        };
+
+        drop(positions);
+
+        // Stop working orders first so exposure can't change under us
+        let entry_fallback = position.first_entry_price;
+        let hedge_fallback = position.max_hedge_price;
+        self.cancel_working(&mut position.first_leg, entry_fallback)
+            .await;
+        self.cancel_working(&mut position.hedge_leg, hedge_fallback)
+            .await;
+
+        self.market_out(&mut position, reason).await;
+
+        // Whatever was hedged before the exit is still a locked pair
+        if position.is_complete() {
+            self.record_hedged(position).await;
+        }
+    }
+
     /// Execute a buy order
-    async fn execute_buy(&self, token_id: &str, price: Decimal, shares: u64) -> Result<()> {
+    async fn execute_buy(
+        &self,
+        token_id: &str,
+        price: Decimal,
+        shares: u64,
+    ) -> Result<ExecutionResult> {
         let order = crate::domain::OrderRequest::buy_limit(
             token_id.to_string(),
             Side::Up, // Side doesn't matter for token-based orders
@@ -759,8 +1324,7 @@ impl SplitArbEngine {
             price,
         );
 
-        self.executor.execute(&order).await?;
-        Ok(())
+        self.executor.execute(&order).await
     }
 
     /// Execute a sell order
@@ -858,3 +1422,107 @@ pub async fn run_split_arb(
     ws_handle.abort();
     Ok(())
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn position(shares: u64) -> PartialPosition {
+        PartialPosition {
+            event_id: "ev".into(),
+            condition_id: "0xcondition".into(),
+            first_side: ArbSide::Up,
+            first_token_id: "up".into(),
+            first_entry_price: dec!(0.30),
+            shares,
+            entry_time: Utc::now(),
+            event_end_time: Utc::now() + Duration::hours(1),
+            round_secs: 900,
+            other_token_id: "down".into(),
+            status: PositionStatus::WaitingForHedge,
+            max_hedge_price: dec!(0.40),
+            first_leg: LegFill::default(),
+            hedge_leg: LegFill::default(),
+        }
+    }
+
+    #[test]
+    fn test_hedge_sized_from_actual_fills() {
+        let now = Utc::now();
+        let mut p = position(100);
+        p.first_leg.on_submitted("o1".into(), 100, now);
+        assert_eq!(
+            p.first_leg
+                .on_report(OrderStatus::PartiallyFilled, 40, dec!(0.30)),
+            40
+        );
+        // Cumulative reports only count new fills
+        assert_eq!(
+            p.first_leg
+                .on_report(OrderStatus::PartiallyFilled, 40, dec!(0.30)),
+            0
+        );
+        assert!(p.first_leg.is_working());
+        assert_eq!(p.unhedged_shares(), 40);
+
+        p.hedge_leg.on_submitted("h1".into(), 40, now);
+        p.hedge_leg
+            .on_report(OrderStatus::PartiallyFilled, 25, dec!(0.38));
+        assert_eq!(p.open_exposure(), 15);
+        assert_eq!(p.unhedged_shares(), 0); // remainder is working on the book
+        assert!(!p.is_complete());
+
+        p.first_leg
+            .on_report(OrderStatus::Cancelled, 40, dec!(0.30));
+        p.hedge_leg.on_report(OrderStatus::Filled, 40, dec!(0.38));
+        assert!(p.is_complete());
+        assert_eq!(p.hedge_leg.avg_price(), Some(dec!(0.38)));
+    }
+
+    #[test]
+    fn test_residual_action_follows_policy() {
+        let now = Utc::now();
+        let stale = now - Duration::seconds(60);
+        let mut config = SplitArbConfig::default();
+        let mut p = position(100);
+
+        // Entry remainder is cancelled once hedging starts
+        p.first_leg.on_submitted("o1".into(), 100, now);
+        p.first_leg
+            .on_report(OrderStatus::PartiallyFilled, 50, dec!(0.30));
+        p.hedge_leg.on_submitted("h1".into(), 50, stale);
+        assert_eq!(
+            p.residual_action(&config, Some(dec!(0.35)), now),
+            ResidualAction::CancelEntry
+        );
+
+        p.first_leg.close_order();
+        p.hedge_leg
+            .on_report(OrderStatus::PartiallyFilled, 20, dec!(0.35));
+        assert_eq!(
+            p.residual_action(&config, Some(dec!(0.36)), now),
+            ResidualAction::RequoteHedge { price: dec!(0.36) }
+        );
+        // Too expensive to re-quote: cancel instead
+        assert_eq!(
+            p.residual_action(&config, Some(dec!(0.45)), now),
+            ResidualAction::CancelHedge
+        );
+
+        config.residual_policy = ResidualPolicy::MarketOut;
+        assert_eq!(
+            p.residual_action(&config, None, now),
+            ResidualAction::MarketOut
+        );
+        // Fresh hedge orders are left alone
+        assert_eq!(
+            p.residual_action(&config, None, stale + Duration::seconds(5)),
+            ResidualAction::None
+        );
+
+        let mut sold = p.clone();
+        sold.first_leg.reduce(sold.open_exposure());
+        assert_eq!(sold.first_leg.filled, 20);
+        assert_eq!(sold.first_leg.avg_price(), Some(dec!(0.30)));
+    }
+}
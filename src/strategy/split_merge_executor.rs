@@ -0,0 +1,544 @@
+//! Split/merge arbitrage executor
+//!
+//! Turns a `SplitMergeOpportunity` into trades:
+//! - **Split & Sell**: split USDC into YES+NO sets on-chain, then sell both
+//!   legs when `yes_bid + no_bid > 1 + fees`.
+//! - **Buy & Merge**: buy both legs, then merge the matched pairs back into
+//!   USDC on-chain when `yes_ask + no_ask < 1 - fees`.
+//!
+//! Legs are IOC limit orders priced at the touch plus a slippage guard, so a
+//! moving book can shrink the fill but never push it past the guard. Gas is
+//! estimated before execution and the actual receipt cost is charged to the
+//! realized PnL. Leftover sets (split but unsold) are merged back; any
+//! one-sided remainder is reported as residual inventory.
+
+use std::sync::Arc;
+
+use rust_decimal::Decimal;
+use rust_decimal_macros::dec;
+use serde::{Deserialize, Serialize};
+use tracing::{info, warn};
+
+use crate::adapters::ctf::{wei_to_native, ConditionalTokens, CtfTxReceipt};
+use crate::domain::{OrderRequest, Side, TimeInForce};
+use crate::error::Result;
+use crate::strategy::execution::executor::ExecutionResult;
+use crate::strategy::execution::OrderExecutor;
+use crate::strategy::fee_model::FeeModel;
+use crate::strategy::multi_outcome::{SplitMergeOpportunity, SplitMergeType};
+
+/// Split/merge executor configuration
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(default)]
+pub struct SplitMergeExecutorConfig {
+    /// Max price concession per leg vs the touch (absolute, e.g. 0.01 = 1¢)
+    pub max_leg_slippage: Decimal,
+    /// Minimum expected net profit (USD) after fees and gas
+    pub min_net_profit_usd: Decimal,
+    /// Max outcome sets per execution
+    pub max_sets: u64,
+    /// Gas units budgeted for a split transaction
+    pub split_gas_units: u64,
+    /// Gas units budgeted for a merge transaction
+    pub merge_gas_units: u64,
+    /// Native token (POL) price in USD for gas accounting
+    pub native_usd_price: Decimal,
+    /// Taker fee curve applied to each leg
+    pub fee_model: FeeModel,
+}
+
+impl Default for SplitMergeExecutorConfig {
+    fn default() -> Self {
+        Self {
+            max_leg_slippage: dec!(0.01),
+            min_net_profit_usd: dec!(0.50),
+            max_sets: 500,
+            split_gas_units: 200_000,
+            merge_gas_units: 160_000,
+            native_usd_price: dec!(0.25),
+            fee_model: FeeModel::sports(),
+        }
+    }
+}
+
+/// Binary market the executor trades
+#[derive(Debug, Clone)]
+pub struct SplitMergeMarket {
+    pub condition_id: String,
+    pub yes_token_id: String,
+    pub no_token_id: String,
+    pub neg_risk: bool,
+}
+
+/// Sized, priced plan for one split/merge round trip
+#[derive(Debug, Clone, Serialize)]
+pub struct SplitMergePlan {
+    pub opportunity_type: SplitMergeType,
+    pub sets: u64,
+    /// Slippage-guarded limit for the YES leg
+    pub yes_limit: Decimal,
+    /// Slippage-guarded limit for the NO leg
+    pub no_limit: Decimal,
+    /// Taker fees (USD) at the touch
+    pub expected_fees_usd: Decimal,
+    /// Estimated gas (USD) for the on-chain leg
+    pub estimated_gas_usd: Decimal,
+    /// Net profit (USD) if both legs fill at the touch
+    pub expected_net_usd: Decimal,
+    /// Net profit (USD) if both legs fill at the slippage guard
+    pub worst_case_net_usd: Decimal,
+}
+
+/// Outcome of an executed plan
+#[derive(Debug, Clone, Serialize)]
+pub struct SplitMergeExecution {
+    pub plan: SplitMergePlan,
+    pub yes_filled: u64,
+    pub no_filled: u64,
+    /// Sets split or merged on-chain (including unwind merges)
+    pub sets_on_chain: u64,
+    pub tx_hashes: Vec<String>,
+    pub gas_used: u64,
+    pub gas_cost_usd: Decimal,
+    /// Cash PnL (USD) after fees and gas; residual inventory is not marked
+    pub realized_pnl_usd: Decimal,
+    /// One-sided leftovers: positive = held YES/NO shares
+    pub residual_yes: u64,
+    pub residual_no: u64,
+}
+
+impl SplitMergeExecution {
+    pub fn has_residual(&self) -> bool {
+        self.residual_yes > 0 || self.residual_no > 0
+    }
+}
+
+/// Taker fee (USD) for `shares` at `price`.
+fn leg_fee_usd(fee_model: &FeeModel, shares: u64, price: Decimal) -> Decimal {
+    fee_model.fee_shares(Decimal::from(shares), price) * price
+}
+
+/// Size and price a split/merge opportunity. Returns `None` when it does not
+/// clear the configured profit floor or could lose money inside the slippage
+/// guard.
+pub fn plan_split_merge(
+    opportunity: &SplitMergeOpportunity,
+    requested_sets: u64,
+    gas_price_wei: u128,
+    config: &SplitMergeExecutorConfig,
+) -> Option<SplitMergePlan> {
+    let sets = requested_sets.min(config.max_sets);
+    if sets == 0 {
+        return None;
+    }
+    let size = Decimal::from(sets);
+    let slip = config.max_leg_slippage.max(Decimal::ZERO);
+
+    let (touch_yes, touch_no, yes_limit, no_limit, gas_units) = match opportunity.opportunity_type {
+        SplitMergeType::SplitAndSell => (
+            opportunity.yes_bid,
+            opportunity.no_bid,
+            (opportunity.yes_bid - slip).max(dec!(0.01)),
+            (opportunity.no_bid - slip).max(dec!(0.01)),
+            config.split_gas_units,
+        ),
+        SplitMergeType::BuyAndMerge => (
+            opportunity.yes_ask,
+            opportunity.no_ask,
+            (opportunity.yes_ask + slip).min(dec!(0.99)),
+            (opportunity.no_ask + slip).min(dec!(0.99)),
+            config.merge_gas_units,
+        ),
+    };
+
+    let estimated_gas_usd =
+        wei_to_native(gas_units as u128 * gas_price_wei) * config.native_usd_price;
+
+    let net_at = |yes: Decimal, no: Decimal| -> (Decimal, Decimal) {
+        let fees =
+            leg_fee_usd(&config.fee_model, sets, yes) + leg_fee_usd(&config.fee_model, sets, no);
+        let gross = match opportunity.opportunity_type {
+            SplitMergeType::SplitAndSell => (yes + no - Decimal::ONE) * size,
+            SplitMergeType::BuyAndMerge => (Decimal::ONE - yes - no) * size,
+        };
+        (gross - fees - estimated_gas_usd, fees)
+    };
+    let (expected_net_usd, expected_fees_usd) = net_at(touch_yes, touch_no);
+    let (worst_case_net_usd, _) = net_at(yes_limit, no_limit);
+
+    if expected_net_usd < config.min_net_profit_usd || worst_case_net_usd < Decimal::ZERO {
+        return None;
+    }
+
+    Some(SplitMergePlan {
+        opportunity_type: opportunity.opportunity_type,
+        sets,
+        yes_limit,
+        no_limit,
+        expected_fees_usd,
+        estimated_gas_usd,
+        expected_net_usd,
+        worst_case_net_usd,
+    })
+}
+
+/// Executes split/merge plans against the CLOB and the CTF contracts
+pub struct SplitMergeExecutor {
+    executor: Arc<OrderExecutor>,
+    ctf: Arc<dyn ConditionalTokens>,
+    config: SplitMergeExecutorConfig,
+}
+
+impl SplitMergeExecutor {
+    pub fn new(
+        executor: Arc<OrderExecutor>,
+        ctf: Arc<dyn ConditionalTokens>,
+        config: SplitMergeExecutorConfig,
+    ) -> Self {
+        Self {
+            executor,
+            ctf,
+            config,
+        }
+    }
+
+    pub fn config(&self) -> &SplitMergeExecutorConfig {
+        &self.config
+    }
+
+    /// Plan against the live gas price.
+    pub async fn plan(
+        &self,
+        opportunity: &SplitMergeOpportunity,
+        requested_sets: u64,
+    ) -> Result<Option<SplitMergePlan>> {
+        let gas_price_wei = self.ctf.gas_price_wei().await?;
+        Ok(plan_split_merge(
+            opportunity,
+            requested_sets,
+            gas_price_wei,
+            &self.config,
+        ))
+    }
+
+    /// Execute a plan. In dry-run mode no on-chain transaction is sent and
+    /// the estimated gas is charged instead.
+    pub async fn execute(
+        &self,
+        market: &SplitMergeMarket,
+        plan: SplitMergePlan,
+    ) -> Result<SplitMergeExecution> {
+        match plan.opportunity_type {
+            SplitMergeType::SplitAndSell => self.split_and_sell(market, plan).await,
+            SplitMergeType::BuyAndMerge => self.buy_and_merge(market, plan).await,
+        }
+    }
+
+    async fn split_and_sell(
+        &self,
+        market: &SplitMergeMarket,
+        plan: SplitMergePlan,
+    ) -> Result<SplitMergeExecution> {
+        let sets = plan.sets;
+        let mut receipts = vec![
+            self.on_chain(market, sets, SplitMergeType::SplitAndSell)
+                .await?,
+        ];
+
+        let yes = OrderRequest::sell_limit(
+            market.yes_token_id.clone(),
+            Side::Up,
+            plan.sets,
+            plan.yes_limit,
+        );
+        let no = OrderRequest::sell_limit(
+            market.no_token_id.clone(),
+            Side::Down,
+            plan.sets,
+            plan.no_limit,
+        );
+        let (yes_fill, no_fill) = self.execute_legs(yes, no).await;
+
+        // Sets that were split but not sold on both sides go back to USDC.
+        let unsold_yes = sets - yes_fill.shares;
+        let unsold_no = sets - no_fill.shares;
+        let unwind = unsold_yes.min(unsold_no);
+        let mut merged_back = 0;
+        if unwind > 0 {
+            match self
+                .on_chain(market, unwind, SplitMergeType::BuyAndMerge)
+                .await
+            {
+                Ok(receipt) => {
+                    receipts.push(receipt);
+                    merged_back = unwind;
+                }
+                Err(e) => warn!(
+                    condition_id = %market.condition_id,
+                    sets = unwind,
+                    error = %e,
+                    "failed to merge back unsold split sets"
+                ),
+            }
+        }
+
+        let fees = leg_fee_usd(&self.config.fee_model, yes_fill.shares, yes_fill.price)
+            + leg_fee_usd(&self.config.fee_model, no_fill.shares, no_fill.price);
+        let proceeds = yes_fill.notional() + no_fill.notional();
+        let collateral_out = Decimal::from(sets - merged_back);
+        let gross = proceeds - collateral_out;
+
+        Ok(self.finish(
+            plan,
+            &yes_fill,
+            &no_fill,
+            sets + merged_back,
+            receipts,
+            gross - fees,
+            unsold_yes - merged_back,
+            unsold_no - merged_back,
+        ))
+    }
+
+    async fn buy_and_merge(
+        &self,
+        market: &SplitMergeMarket,
+        plan: SplitMergePlan,
+    ) -> Result<SplitMergeExecution> {
+        let yes = OrderRequest::buy_limit(
+            market.yes_token_id.clone(),
+            Side::Up,
+            plan.sets,
+            plan.yes_limit,
+        );
+        let no = OrderRequest::buy_limit(
+            market.no_token_id.clone(),
+            Side::Down,
+            plan.sets,
+            plan.no_limit,
+        );
+        let (yes_fill, no_fill) = self.execute_legs(yes, no).await;
+
+        let pairs = yes_fill.shares.min(no_fill.shares);
+        let mut receipts = Vec::new();
+        let mut merged = 0;
+        if pairs > 0 {
+            match self
+                .on_chain(market, pairs, SplitMergeType::BuyAndMerge)
+                .await
+            {
+                Ok(receipt) => {
+                    receipts.push(receipt);
+                    merged = pairs;
+                }
+                Err(e) => warn!(
+                    condition_id = %market.condition_id,
+                    sets = pairs,
+                    error = %e,
+                    "merge failed; holding matched pairs"
+                ),
+            }
+        }
+
+        let fees = leg_fee_usd(&self.config.fee_model, yes_fill.shares, yes_fill.price)
+            + leg_fee_usd(&self.config.fee_model, no_fill.shares, no_fill.price);
+        let cost = yes_fill.notional() + no_fill.notional();
+        let gross = Decimal::from(merged) - cost;
+
+        Ok(self.finish(
+            plan,
+            &yes_fill,
+            &no_fill,
+            merged,
+            receipts,
+            gross - fees,
+            yes_fill.shares - merged,
+            no_fill.shares - merged,
+        ))
+    }
+
+    /// Submit both legs concurrently; a failed leg counts as zero fill.
+    async fn execute_legs(
+        &self,
+        mut yes: OrderRequest,
+        mut no: OrderRequest,
+    ) -> (LegFill, LegFill) {
+        yes.time_in_force = TimeInForce::IOC;
+        no.time_in_force = TimeInForce::IOC;
+        let (yes_result, no_result) =
+            tokio::join!(self.executor.execute(&yes), self.executor.execute(&no));
+        (
+            LegFill::from_result(&yes, yes_result),
+            LegFill::from_result(&no, no_result),
+        )
+    }
+
+    async fn on_chain(
+        &self,
+        market: &SplitMergeMarket,
+        sets: u64,
+        kind: SplitMergeType,
+    ) -> Result<CtfTxReceipt> {
+        let amount = Decimal::from(sets);
+        if self.executor.is_dry_run() {
+            let gas_units = match kind {
+                SplitMergeType::SplitAndSell => self.config.split_gas_units,
+                SplitMergeType::BuyAndMerge => self.config.merge_gas_units,
+            };
+            let gas_price = self.ctf.gas_price_wei().await.unwrap_or_default();
+            info!(
+                condition_id = %market.condition_id,
+                sets,
+                "[DRY RUN] skipping CTF {}",
+                kind
+            );
+            return Ok(CtfTxReceipt {
+                tx_hash: "dry-run".to_string(),
+                gas_used: gas_units,
+                effective_gas_price_wei: gas_price,
+            });
+        }
+        match kind {
+            SplitMergeType::SplitAndSell => {
+                self.ctf
+                    .split(&market.condition_id, amount, market.neg_risk)
+                    .await
+            }
+            SplitMergeType::BuyAndMerge => {
+                self.ctf
+                    .merge(&market.condition_id, amount, market.neg_risk)
+                    .await
+            }
+        }
+    }
+
+    #[allow(clippy::too_many_arguments)]
+    fn finish(
+        &self,
+        plan: SplitMergePlan,
+        yes_fill: &LegFill,
+        no_fill: &LegFill,
+        sets_on_chain: u64,
+        receipts: Vec<CtfTxReceipt>,
+        pnl_before_gas: Decimal,
+        residual_yes: u64,
+        residual_no: u64,
+    ) -> SplitMergeExecution {
+        let gas_used = receipts.iter().map(|r| r.gas_used).sum();
+        let gas_cost_usd = receipts
+            .iter()
+            .map(CtfTxReceipt::gas_cost_native)
+            .sum::<Decimal>()
+            * self.config.native_usd_price;
+        let execution = SplitMergeExecution {
+            plan,
+            yes_filled: yes_fill.shares,
+            no_filled: no_fill.shares,
+            sets_on_chain,
+            tx_hashes: receipts.into_iter().map(|r| r.tx_hash).collect(),
+            gas_used,
+            gas_cost_usd,
+            realized_pnl_usd: pnl_before_gas - gas_cost_usd,
+            residual_yes,
+            residual_no,
+        };
+        info!(
+            kind = %execution.plan.opportunity_type,
+            sets = execution.plan.sets,
+            yes_filled = execution.yes_filled,
+            no_filled = execution.no_filled,
+            gas_usd = %execution.gas_cost_usd.round_dp(4),
+            pnl_usd = %execution.realized_pnl_usd.round_dp(4),
+            "split/merge arbitrage executed"
+        );
+        if execution.has_residual() {
+            warn!(
+                residual_yes = execution.residual_yes,
+                residual_no = execution.residual_no,
+                "split/merge left one-sided inventory"
+            );
+        }
+        execution
+    }
+}
+
+/// Filled size and average price of one leg
+struct LegFill {
+    shares: u64,
+    price: Decimal,
+}
+
+impl LegFill {
+    fn from_result(request: &OrderRequest, result: Result<ExecutionResult>) -> Self {
+        match result {
+            Ok(r) => Self {
+                shares: r.filled_shares.min(request.shares),
+                price: r.avg_fill_price.unwrap_or(request.limit_price),
+            },
+            Err(e) => {
+                warn!(token_id = %request.token_id, error = %e, "split/merge leg failed");
+                Self {
+                    shares: 0,
+                    price: request.limit_price,
+                }
+            }
+        }
+    }
+
+    fn notional(&self) -> Decimal {
+        Decimal::from(self.shares) * self.price
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::strategy::multi_outcome::detect_split_merge_opportunity;
+
+    fn config() -> SplitMergeExecutorConfig {
+        SplitMergeExecutorConfig {
+            min_net_profit_usd: dec!(0.10),
+            fee_model: FeeModel {
+                fee_rate: Decimal::ZERO,
+                exponent: 1,
+            },
+            ..Default::default()
+        }
+    }
+
+    #[test]
+    fn test_plan_split_and_sell_accounts_gas_and_slippage() {
+        let opp =
+            detect_split_merge_opportunity(dec!(0.53), dec!(0.55), dec!(0.50), dec!(0.52), dec!(0))
+                .unwrap();
+        assert_eq!(opp.opportunity_type, SplitMergeType::SplitAndSell);
+
+        // 100 sets × 3¢ edge = $3 gross; 200k gas at 100 gwei × $0.25 = $0.005
+        let plan = plan_split_merge(&opp, 100, 100_000_000_000, &config()).unwrap();
+        assert_eq!(plan.yes_limit, dec!(0.52));
+        assert_eq!(plan.no_limit, dec!(0.49));
+        assert_eq!(plan.estimated_gas_usd, dec!(0.005));
+        assert_eq!(plan.expected_net_usd, dec!(2.995));
+        assert_eq!(plan.worst_case_net_usd, dec!(0.995));
+    }
+
+    #[test]
+    fn test_plan_rejects_edge_inside_slippage_guard() {
+        let opp =
+            detect_split_merge_opportunity(dec!(0.40), dec!(0.48), dec!(0.41), dec!(0.51), dec!(0))
+                .unwrap();
+        assert_eq!(opp.opportunity_type, SplitMergeType::BuyAndMerge);
+
+        // 1¢ edge vs 2¢ combined slippage guard: could lose money.
+        assert!(plan_split_merge(&opp, 100, 0, &config()).is_none());
+
+        let tight = SplitMergeExecutorConfig {
+            max_leg_slippage: dec!(0.002),
+            ..config()
+        };
+        let plan = plan_split_merge(&opp, 1_000, 0, &tight).unwrap();
+        assert_eq!(plan.sets, 500);
+        assert_eq!(plan.yes_limit, dec!(0.482));
+        assert_eq!(plan.expected_net_usd, dec!(5));
+    }
+}
@@ -1,7 +1,12 @@
 //! Sports market discovery
 //!
-//! Discovers sports betting markets from Polymarket.
+//! Discovers sports betting markets from Polymarket and classifies them into
+//! moneyline, spread, total and player-prop markets.
 
+use super::market_class::{
+    classify_sports_market, SportsMarket, SportsMarketClass, SportsMarketKind,
+};
+use crate::adapters::polymarket_clob::{GammaMarketInfo, MarketResponse};
 use crate::adapters::PolymarketClient;
 use crate::error::Result;
 use crate::strategy::core::{BinaryMarket, MarketDiscovery, MarketType};
@@ -40,18 +45,27 @@ impl std::fmt::Display for SportsLeague {
 pub struct SportsMarketDiscovery {
     client: PolymarketClient,
     leagues: Vec<SportsLeague>,
+    /// Market classes to return (default: moneyline only)
+    classes: Vec<SportsMarketClass>,
 }
 
 impl SportsMarketDiscovery {
     pub fn new(client: PolymarketClient) -> Self {
+        Self::with_leagues(client, vec![SportsLeague::NBA, SportsLeague::NFL])
+    }
+
+    pub fn with_leagues(client: PolymarketClient, leagues: Vec<SportsLeague>) -> Self {
         Self {
             client,
-            leagues: vec![SportsLeague::NBA, SportsLeague::NFL],
+            leagues,
+            classes: vec![SportsMarketClass::Moneyline],
         }
     }
 
-    pub fn with_leagues(client: PolymarketClient, leagues: Vec<SportsLeague>) -> Self {
-        Self { client, leagues }
+    /// Opt into specific market classes (spreads, totals, player props)
+    pub fn with_market_classes(mut self, classes: Vec<SportsMarketClass>) -> Self {
+        self.classes = classes;
+        self
     }
 
     /// Get search keywords for a league
@@ -84,8 +98,65 @@ impl SportsMarketDiscovery {
             .map(|dt| dt.with_timezone(&Utc))
     }
 
+    /// Classify a Gamma market, keeping only the opted-in classes
+    fn classify(&self, gamma_market: &GammaMarketInfo, question: &str) -> Option<SportsMarketKind> {
+        let kind = classify_sports_market(
+            gamma_market.sports_market_type.as_deref(),
+            question,
+            gamma_market.group_item_title.as_deref(),
+        )?;
+        self.classes.contains(&kind.class()).then_some(kind)
+    }
+
+    /// Build a binary market from CLOB tokens.
+    ///
+    /// "Yes"/"Over" is the yes side; for team-vs-team outcomes the second token is.
+    fn binary_market(
+        event_id: String,
+        mut clob_market: MarketResponse,
+        end_time: DateTime<Utc>,
+        class: SportsMarketClass,
+        question: String,
+    ) -> Option<BinaryMarket> {
+        if clob_market.tokens.len() < 2 {
+            return None;
+        }
+
+        // Move token IDs out of owned vec to avoid cloning
+        let first_outcome = clob_market.tokens[0].outcome.to_lowercase();
+        let is_first_yes = first_outcome == "yes" || first_outcome == "over";
+        let mut tokens = clob_market.tokens.drain(..2);
+        let first = tokens.next()?;
+        let second = tokens.next()?;
+        drop(tokens);
+        let (yes, no) = if is_first_yes {
+            (first, second)
+        } else {
+            (second, first)
+        };
+        let label = |outcome: String, fallback: &str| {
+            if outcome.trim().is_empty() {
+                fallback.to_string()
+            } else {
+                outcome
+            }
+        };
+
+        Some(BinaryMarket {
+            event_id,
+            condition_id: clob_market.condition_id,
+            yes_token_id: yes.token_id,
+            no_token_id: no.token_id,
+            yes_label: label(yes.outcome, "Yes"),
+            no_label: label(no.outcome, "No"),
+            end_time,
+            market_type: class.market_type(),
+            metadata: Some(question),
+        })
+    }
+
     /// Fetch markets for a specific league
-    async fn fetch_league_markets(&self, league: SportsLeague) -> Result<Vec<BinaryMarket>> {
+    async fn fetch_league_markets(&self, league: SportsLeague) -> Result<Vec<SportsMarket>> {
         let keywords = self.league_keywords(league);
         info!(
             "Searching for {} markets with keywords: {:?}",
@@ -131,44 +202,34 @@ impl SportsMarketDiscovery {
                     None => continue,
                 };
 
+                // Get market question for metadata
+                let question = gamma_market
+                    .question
+                    .clone()
+                    .or_else(|| event.title.clone())
+                    .unwrap_or_else(|| "Unknown".to_string());
+
+                // Classify before hitting the CLOB so unwanted classes cost nothing
+                let Some(kind) = self.classify(gamma_market, &question) else {
+                    continue;
+                };
+
                 // Get CLOB market for token IDs
                 match self.client.get_market(&condition_id).await {
-                    Ok(mut clob_market) => {
-                        if clob_market.tokens.len() < 2 {
-                            continue;
-                        }
-
-                        // Move token IDs out of owned vec to avoid cloning
-                        let is_first_yes = clob_market.tokens[0].outcome.to_lowercase() == "yes";
-                        let mut tokens = clob_market.tokens.drain(..2);
-                        let first = tokens.next().unwrap();
-                        let second = tokens.next().unwrap();
-                        let (yes_token, no_token) = if is_first_yes {
-                            (first.token_id, second.token_id)
-                        } else {
-                            (second.token_id, first.token_id)
-                        };
-
-                        // Get market question for metadata
-                        let question = gamma_market
-                            .question
-                            .clone()
-                            .or_else(|| event.title.clone())
-                            .unwrap_or_else(|| "Unknown".to_string());
-
-                        let market = BinaryMarket {
-                            event_id: event.id.clone(),
-                            condition_id,
-                            yes_token_id: yes_token,
-                            no_token_id: no_token,
-                            yes_label: "Yes".to_string(),
-                            no_label: "No".to_string(),
+                    Ok(clob_market) => {
+                        if let Some(market) = Self::binary_market(
+                            event.id.clone(),
+                            clob_market,
                             end_time,
-                            market_type: MarketType::SportsMoneyline,
-                            metadata: Some(question),
-                        };
-
-                        markets.push(market);
+                            kind.class(),
+                            question,
+                        ) {
+                            markets.push(SportsMarket {
+                                league,
+                                kind,
+                                market,
+                            });
+                        }
                     }
                     Err(e) => {
                         debug!("Failed to get CLOB market {}: {}", condition_id, e);
@@ -177,18 +238,17 @@ impl SportsMarketDiscovery {
             }
         }
 
-        info!("Found {} {} binary markets", markets.len(), league);
+        info!(
+            "Found {} {} markets ({:?})",
+            markets.len(),
+            league,
+            self.classes
+        );
         Ok(markets)
     }
-}
 
-#[async_trait]
-impl MarketDiscovery for SportsMarketDiscovery {
-    fn market_type(&self) -> MarketType {
-        MarketType::SportsMoneyline
-    }
-
-    async fn discover_markets(&self) -> Result<Vec<BinaryMarket>> {
+    /// Discover markets across all leagues with their typed terms
+    pub async fn discover_sports_markets(&self) -> Result<Vec<SportsMarket>> {
         let mut all_markets = Vec::new();
 
         for league in &self.leagues {
@@ -205,6 +265,25 @@ impl MarketDiscovery for SportsMarketDiscovery {
 
         Ok(all_markets)
     }
+}
+
+#[async_trait]
+impl MarketDiscovery for SportsMarketDiscovery {
+    fn market_type(&self) -> MarketType {
+        self.classes
+            .first()
+            .map(|class| class.market_type())
+            .unwrap_or(MarketType::SportsMoneyline)
+    }
+
+    async fn discover_markets(&self) -> Result<Vec<BinaryMarket>> {
+        Ok(self
+            .discover_sports_markets()
+            .await?
+            .into_iter()
+            .map(|m| m.market)
+            .collect())
+    }
 
     async fn get_market(&self, event_id: &str) -> Result<Option<BinaryMarket>> {
         let event_details = self.client.get_event_details(event_id).await?;
@@ -215,41 +294,26 @@ impl MarketDiscovery for SportsMarketDiscovery {
             .and_then(|s| Self::parse_end_date(s))
             .unwrap_or_else(Utc::now);
 
-        // Get first market with condition_id
+        // Get first opted-in market with condition_id
         for gamma_market in &event_details.markets {
             if let Some(condition_id) = &gamma_market.condition_id {
-                let mut clob_market = self.client.get_market(condition_id).await?;
-
-                if clob_market.tokens.len() >= 2 {
-                    // Move token IDs out of owned vec to avoid cloning
-                    let is_first_yes = clob_market.tokens[0].outcome.to_lowercase() == "yes";
-                    let mut tokens = clob_market.tokens.drain(..2);
-                    let first = tokens.next().unwrap();
-                    let second = tokens.next().unwrap();
-                    let (yes_token, no_token) = if is_first_yes {
-                        (first.token_id, second.token_id)
-                    } else {
-                        (second.token_id, first.token_id)
-                    };
-
-                    let question = gamma_market
-                        .question
-                        .clone()
-                        .or_else(|| event_details.title.clone())
-                        .unwrap_or_else(|| "Unknown".to_string());
-
-                    let market = BinaryMarket {
-                        event_id: event_id.to_string(),
-                        condition_id: condition_id.clone(),
-                        yes_token_id: yes_token,
-                        no_token_id: no_token,
-                        yes_label: "Yes".to_string(),
-                        no_label: "No".to_string(),
-                        end_time,
-                        market_type: MarketType::SportsMoneyline,
-                        metadata: Some(question),
-                    };
+                let question = gamma_market
+                    .question
+                    .clone()
+                    .or_else(|| event_details.title.clone())
+                    .unwrap_or_else(|| "Unknown".to_string());
+                let Some(kind) = self.classify(gamma_market, &question) else {
+                    continue;
+                };
 
+                let clob_market = self.client.get_market(condition_id).await?;
+                if let Some(market) = Self::binary_market(
+                    event_id.to_string(),
+                    clob_market,
+                    end_time,
+                    kind.class(),
+                    question,
+                ) {
                     return Ok(Some(market));
                 }
             }
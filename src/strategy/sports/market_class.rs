@@ -0,0 +1,283 @@
+//! Sports market classification
+//!
+//! Classifies Gamma sports markets into moneyline, spread, total and player-prop
+//! classes and normalizes them into typed market structs.
+
+use crate::strategy::core::{BinaryMarket, MarketType};
+use rust_decimal::Decimal;
+use serde::{Deserialize, Serialize};
+use std::str::FromStr;
+
+use super::SportsLeague;
+
+/// Sports market classes a strategy can opt into
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum SportsMarketClass {
+    Moneyline,
+    Spread,
+    Total,
+    PlayerProp,
+}
+
+impl SportsMarketClass {
+    pub fn market_type(&self) -> MarketType {
+        match self {
+            SportsMarketClass::Moneyline => MarketType::SportsMoneyline,
+            SportsMarketClass::Spread => MarketType::SportsSpread,
+            SportsMarketClass::Total => MarketType::SportsTotal,
+            SportsMarketClass::PlayerProp => MarketType::SportsPlayerProp,
+        }
+    }
+}
+
+impl std::fmt::Display for SportsMarketClass {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            SportsMarketClass::Moneyline => write!(f, "moneyline"),
+            SportsMarketClass::Spread => write!(f, "spread"),
+            SportsMarketClass::Total => write!(f, "total"),
+            SportsMarketClass::PlayerProp => write!(f, "player_prop"),
+        }
+    }
+}
+
+/// Class-specific terms of a sports market
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(tag = "class", rename_all = "snake_case")]
+pub enum SportsMarketKind {
+    Moneyline,
+    /// `team` covers when its score plus `line` beats the opponent
+    Spread {
+        team: String,
+        line: Decimal,
+    },
+    /// Combined score over/under `line`
+    Total {
+        line: Decimal,
+    },
+    /// Player `stat` over/under `line`
+    PlayerProp {
+        player: String,
+        stat: String,
+        line: Decimal,
+    },
+}
+
+impl SportsMarketKind {
+    pub fn class(&self) -> SportsMarketClass {
+        match self {
+            SportsMarketKind::Moneyline => SportsMarketClass::Moneyline,
+            SportsMarketKind::Spread { .. } => SportsMarketClass::Spread,
+            SportsMarketKind::Total { .. } => SportsMarketClass::Total,
+            SportsMarketKind::PlayerProp { .. } => SportsMarketClass::PlayerProp,
+        }
+    }
+
+    pub fn line(&self) -> Option<Decimal> {
+        match self {
+            SportsMarketKind::Moneyline => None,
+            SportsMarketKind::Spread { line, .. }
+            | SportsMarketKind::Total { line }
+            | SportsMarketKind::PlayerProp { line, .. } => Some(*line),
+        }
+    }
+}
+
+/// A discovered sports market with its typed terms
+#[derive(Debug, Clone)]
+pub struct SportsMarket {
+    pub league: SportsLeague,
+    pub kind: SportsMarketKind,
+    pub market: BinaryMarket,
+}
+
+impl SportsMarket {
+    pub fn class(&self) -> SportsMarketClass {
+        self.kind.class()
+    }
+}
+
+/// Gamma `sportsMarketType` values that denote player stat props.
+const PROP_STATS: &[&str] = &[
+    "points",
+    "rebounds",
+    "assists",
+    "threes",
+    "steals",
+    "blocks",
+    "pra",
+    "passing_yards",
+    "rushing_yards",
+    "receiving_yards",
+    "touchdowns",
+    "strikeouts",
+    "hits",
+    "home_runs",
+    "shots",
+    "goals",
+];
+
+/// Last signed decimal number in `text`, e.g. "(-6.5)" or "O/U 224.5".
+fn parse_line(text: &str) -> Option<Decimal> {
+    let mut best = None;
+    let mut token = String::new();
+    for c in text.chars().chain(std::iter::once(' ')) {
+        if c.is_ascii_digit() || c == '.' || ((c == '-' || c == '+') && token.is_empty()) {
+            token.push(c);
+            continue;
+        }
+        let trimmed = token.trim_start_matches('+').trim_end_matches('.');
+        if let Ok(value) = Decimal::from_str(trimmed) {
+            best = Some(value);
+        }
+        token.clear();
+    }
+    best
+}
+
+/// Text before the first ':' (team or player name), if any.
+fn subject(question: &str) -> Option<&str> {
+    let (head, _) = question.split_once(':')?;
+    let head = head.trim();
+    (!head.is_empty()).then_some(head)
+}
+
+/// Stat keyword in a prop question ("Jayson Tatum: Points O/U 27.5" → "points").
+fn prop_stat(question: &str) -> Option<String> {
+    let (_, tail) = question.split_once(':')?;
+    let tail = tail.to_lowercase();
+    PROP_STATS
+        .iter()
+        .find(|stat| tail.contains(&stat.replace('_', " ")))
+        .map(|stat| stat.to_string())
+}
+
+/// Spread team: "Spread: Celtics (-6.5)" → "Celtics".
+fn spread_team(question: &str, group_item_title: Option<&str>) -> String {
+    let text = question
+        .split_once(':')
+        .map(|(_, tail)| tail)
+        .unwrap_or(question);
+    let team = text.split('(').next().unwrap_or(text).trim();
+    if team.is_empty() {
+        group_item_title.unwrap_or_default().trim().to_string()
+    } else {
+        team.to_string()
+    }
+}
+
+/// Classify a Gamma sports market.
+///
+/// Prefers Gamma's `sportsMarketType`; falls back to question text for older
+/// markets without it. Returns None when a line-based market has no parseable line.
+pub fn classify_sports_market(
+    sports_market_type: Option<&str>,
+    question: &str,
+    group_item_title: Option<&str>,
+) -> Option<SportsMarketKind> {
+    let lower = question.to_lowercase();
+    let hinted = sports_market_type.map(|t| t.trim().to_lowercase());
+
+    let class = match hinted.as_deref() {
+        Some("moneyline") => SportsMarketClass::Moneyline,
+        Some("spreads") | Some("spread") => SportsMarketClass::Spread,
+        Some("totals") | Some("total") => SportsMarketClass::Total,
+        Some(t) if PROP_STATS.contains(&t) => SportsMarketClass::PlayerProp,
+        _ if lower.starts_with("spread") => SportsMarketClass::Spread,
+        _ if lower.contains("o/u") || lower.contains("over/under") => {
+            // "Team vs. Team: Total Points O/U" is a game total, not a prop.
+            if prop_stat(question).is_some() && !lower.contains(" vs") {
+                SportsMarketClass::PlayerProp
+            } else {
+                SportsMarketClass::Total
+            }
+        }
+        _ => SportsMarketClass::Moneyline,
+    };
+
+    match class {
+        SportsMarketClass::Moneyline => Some(SportsMarketKind::Moneyline),
+        SportsMarketClass::Spread => Some(SportsMarketKind::Spread {
+            team: spread_team(question, group_item_title),
+            line: parse_line(question)?,
+        }),
+        SportsMarketClass::Total => Some(SportsMarketKind::Total {
+            line: parse_line(question)?,
+        }),
+        SportsMarketClass::PlayerProp => Some(SportsMarketKind::PlayerProp {
+            player: subject(question)
+                .or(group_item_title)
+                .unwrap_or_default()
+                .to_string(),
+            stat: hinted
+                .filter(|t| PROP_STATS.contains(&t.as_str()))
+                .or_else(|| prop_stat(question))
+                .unwrap_or_default(),
+            line: parse_line(question)?,
+        }),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use rust_decimal_macros::dec;
+
+    #[test]
+    fn test_classify_from_question_text() {
+        assert_eq!(
+            classify_sports_market(None, "Lakers vs. Celtics", None),
+            Some(SportsMarketKind::Moneyline)
+        );
+        assert_eq!(
+            classify_sports_market(None, "Spread: Celtics (-6.5)", None),
+            Some(SportsMarketKind::Spread {
+                team: "Celtics".to_string(),
+                line: dec!(-6.5),
+            })
+        );
+        assert_eq!(
+            classify_sports_market(None, "Lakers vs. Celtics: O/U 224.5", None),
+            Some(SportsMarketKind::Total { line: dec!(224.5) })
+        );
+        assert_eq!(
+            classify_sports_market(None, "Jayson Tatum: Points O/U 27.5", None),
+            Some(SportsMarketKind::PlayerProp {
+                player: "Jayson Tatum".to_string(),
+                stat: "points".to_string(),
+                line: dec!(27.5),
+            })
+        );
+    }
+
+    #[test]
+    fn test_gamma_type_takes_precedence() {
+        let kind = classify_sports_market(
+            Some("rebounds"),
+            "Will Nikola Jokic record over 12.5 rebounds?",
+            Some("Nikola Jokic"),
+        )
+        .unwrap();
+        assert_eq!(kind.class(), SportsMarketClass::PlayerProp);
+        assert_eq!(kind.line(), Some(dec!(12.5)));
+        assert_eq!(
+            kind,
+            SportsMarketKind::PlayerProp {
+                player: "Nikola Jokic".to_string(),
+                stat: "rebounds".to_string(),
+                line: dec!(12.5),
+            }
+        );
+
+        // Line markets without a parseable line are dropped.
+        assert_eq!(
+            classify_sports_market(Some("totals"), "Lakers vs. Celtics total", None),
+            None
+        );
+        assert_eq!(
+            SportsMarketClass::Spread.market_type(),
+            MarketType::SportsSpread
+        );
+    }
+}
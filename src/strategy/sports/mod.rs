@@ -3,7 +3,9 @@
 //! Specialized strategies for sports betting markets (NBA, NFL, etc.).
 
 mod discovery;
+mod market_class;
 mod runner;
 
 pub use discovery::{SportsLeague, SportsMarketDiscovery};
+pub use market_class::{classify_sports_market, SportsMarket, SportsMarketClass, SportsMarketKind};
 pub use runner::{run_sports_split_arb, SportsSplitArbConfig};
@@ -2,7 +2,7 @@
 //!
 //! Main entry point for running split arbitrage on sports markets.
 
-use super::{SportsLeague, SportsMarketDiscovery};
+use super::{SportsLeague, SportsMarketClass, SportsMarketDiscovery};
 use crate::adapters::{PolymarketClient, PolymarketWebSocket};
 use crate::error::Result;
 use crate::strategy::core::{MarketDiscovery, SplitArbConfig, SplitArbEngine};
@@ -22,6 +22,14 @@ pub struct SportsSplitArbConfig {
 
     /// Leagues to monitor
     pub leagues: Vec<SportsLeague>,
+
+    /// Market classes to trade (moneyline, spread, total, player_prop)
+    #[serde(default = "default_market_classes")]
+    pub market_classes: Vec<SportsMarketClass>,
+}
+
+fn default_market_classes() -> Vec<SportsMarketClass> {
+    vec![SportsMarketClass::Moneyline]
 }
 
 impl Default for SportsSplitArbConfig {
@@ -37,6 +45,7 @@ impl Default for SportsSplitArbConfig {
                 unhedged_stop_loss: dec!(0.20),
             },
             leagues: vec![SportsLeague::NBA, SportsLeague::NFL],
+            market_classes: default_market_classes(),
         }
     }
 }
@@ -70,7 +79,8 @@ pub async fn run_sports_split_arb(
     println!("\x1b[36m╚══════════════════════════════════════════════════════════════╝\x1b[0m\n");
 
     // Create discovery
-    let discovery = SportsMarketDiscovery::with_leagues(client.clone(), config.leagues.clone());
+    let discovery = SportsMarketDiscovery::with_leagues(client.clone(), config.leagues.clone())
+        .with_market_classes(config.market_classes.clone());
 
     // Discover markets
     let markets = discovery.discover_markets().await?;
@@ -0,0 +1,450 @@
+//! In-memory time-series cache for hot-path features.
+//!
+//! Strategies keep asking Postgres for the last few minutes of prices,
+//! imbalances and spreads. [`TimeSeriesCache`] holds a fixed-size ring buffer
+//! per (id, series kind) instead, fed straight from [`MarketUpdate`]s, and
+//! answers rolling mean/std/min/max in O(1) and quantiles with one index
+//! lookup. Nothing here touches the database.
+
+use chrono::{DateTime, Utc};
+use rust_decimal::prelude::ToPrimitive;
+use serde::{Deserialize, Serialize};
+use std::collections::{HashMap, VecDeque};
+
+use super::traits::MarketUpdate;
+
+/// Kind of series tracked per key.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum SeriesKind {
+    /// Mid price (Polymarket) or last trade (Binance)
+    Price,
+    /// Top-of-book imbalance, (bid_size - ask_size) / (bid_size + ask_size)
+    Obi,
+    /// Best ask minus best bid
+    Spread,
+}
+
+/// Ring buffer sizes per series kind.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(default)]
+pub struct TimeSeriesConfig {
+    pub price_window: usize,
+    pub obi_window: usize,
+    pub spread_window: usize,
+}
+
+impl Default for TimeSeriesConfig {
+    fn default() -> Self {
+        Self {
+            price_window: 300,
+            obi_window: 120,
+            spread_window: 120,
+        }
+    }
+}
+
+impl TimeSeriesConfig {
+    fn window(&self, kind: SeriesKind) -> usize {
+        let window = match kind {
+            SeriesKind::Price => self.price_window,
+            SeriesKind::Obi => self.obi_window,
+            SeriesKind::Spread => self.spread_window,
+        };
+        window.max(1)
+    }
+}
+
+/// Fixed-capacity window with incrementally maintained statistics.
+///
+/// Sums back mean/std, monotonic deques back min/max, and a sorted copy of
+/// the window backs quantiles. Sums are rebuilt from the buffer once per
+/// `capacity` pushes so float drift stays bounded.
+#[derive(Debug, Clone)]
+pub struct RingWindow {
+    capacity: usize,
+    values: VecDeque<(DateTime<Utc>, f64)>,
+    sorted: Vec<f64>,
+    /// (sequence, value), values decreasing front to back
+    max_deque: VecDeque<(u64, f64)>,
+    /// (sequence, value), values increasing front to back
+    min_deque: VecDeque<(u64, f64)>,
+    sum: f64,
+    sum_sq: f64,
+    /// Sequence number of the next push
+    seq: u64,
+}
+
+impl RingWindow {
+    pub fn new(capacity: usize) -> Self {
+        let capacity = capacity.max(1);
+        Self {
+            capacity,
+            values: VecDeque::with_capacity(capacity),
+            sorted: Vec::with_capacity(capacity),
+            max_deque: VecDeque::new(),
+            min_deque: VecDeque::new(),
+            sum: 0.0,
+            sum_sq: 0.0,
+            seq: 0,
+        }
+    }
+
+    /// Append a value, evicting the oldest one when full. Non-finite values
+    /// are ignored.
+    pub fn push(&mut self, at: DateTime<Utc>, value: f64) {
+        if !value.is_finite() {
+            return;
+        }
+
+        if self.values.len() == self.capacity {
+            if let Some((_, old)) = self.values.pop_front() {
+                self.sum -= old;
+                self.sum_sq -= old * old;
+                let idx = self.sorted.partition_point(|v| *v < old);
+                self.sorted.remove(idx);
+            }
+        }
+
+        // Oldest retained sequence after this push
+        let oldest = (self.seq + 1).saturating_sub(self.capacity as u64);
+        while self.max_deque.front().is_some_and(|(s, _)| *s < oldest) {
+            self.max_deque.pop_front();
+        }
+        while self.min_deque.front().is_some_and(|(s, _)| *s < oldest) {
+            self.min_deque.pop_front();
+        }
+        while self.max_deque.back().is_some_and(|(_, v)| *v <= value) {
+            self.max_deque.pop_back();
+        }
+        while self.min_deque.back().is_some_and(|(_, v)| *v >= value) {
+            self.min_deque.pop_back();
+        }
+        self.max_deque.push_back((self.seq, value));
+        self.min_deque.push_back((self.seq, value));
+
+        self.values.push_back((at, value));
+        self.sum += value;
+        self.sum_sq += value * value;
+        let idx = self.sorted.partition_point(|v| *v < value);
+        self.sorted.insert(idx, value);
+
+        self.seq += 1;
+        if self.seq % self.capacity as u64 == 0 {
+            self.resync();
+        }
+    }
+
+    fn resync(&mut self) {
+        self.sum = self.values.iter().map(|(_, v)| v).sum();
+        self.sum_sq = self.values.iter().map(|(_, v)| v * v).sum();
+    }
+
+    pub fn len(&self) -> usize {
+        self.values.len()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.values.is_empty()
+    }
+
+    pub fn is_full(&self) -> bool {
+        self.values.len() == self.capacity
+    }
+
+    pub fn capacity(&self) -> usize {
+        self.capacity
+    }
+
+    /// Most recent value and its timestamp
+    pub fn last(&self) -> Option<(DateTime<Utc>, f64)> {
+        self.values.back().copied()
+    }
+
+    /// Values oldest first
+    pub fn iter(&self) -> impl Iterator<Item = &(DateTime<Utc>, f64)> {
+        self.values.iter()
+    }
+
+    pub fn mean(&self) -> Option<f64> {
+        if self.values.is_empty() {
+            return None;
+        }
+        Some(self.sum / self.values.len() as f64)
+    }
+
+    /// Sample standard deviation (needs at least two values)
+    pub fn std(&self) -> Option<f64> {
+        let n = self.values.len();
+        if n < 2 {
+            return None;
+        }
+        let n = n as f64;
+        let variance = (self.sum_sq - self.sum * self.sum / n) / (n - 1.0);
+        Some(variance.max(0.0).sqrt())
+    }
+
+    pub fn min(&self) -> Option<f64> {
+        self.min_deque.front().map(|(_, v)| *v)
+    }
+
+    pub fn max(&self) -> Option<f64> {
+        self.max_deque.front().map(|(_, v)| *v)
+    }
+
+    /// Linear-interpolated quantile, `q` clamped to [0, 1]
+    pub fn quantile(&self, q: f64) -> Option<f64> {
+        if self.sorted.is_empty() || q.is_nan() {
+            return None;
+        }
+        let pos = q.clamp(0.0, 1.0) * (self.sorted.len() - 1) as f64;
+        let lo = pos.floor() as usize;
+        let hi = pos.ceil() as usize;
+        let frac = pos - lo as f64;
+        Some(self.sorted[lo] + (self.sorted[hi] - self.sorted[lo]) * frac)
+    }
+
+    /// Z-score of the latest value against the window
+    pub fn zscore(&self) -> Option<f64> {
+        let (_, last) = self.last()?;
+        let std = self.std()?;
+        if std < 1e-12 {
+            return None;
+        }
+        Some((last - self.mean()?) / std)
+    }
+}
+
+/// Snapshot of one series' rolling statistics.
+#[derive(Debug, Clone, Serialize)]
+pub struct SeriesStats {
+    pub id: String,
+    pub kind: SeriesKind,
+    pub len: usize,
+    pub last: Option<f64>,
+    pub last_at: Option<DateTime<Utc>>,
+    pub mean: Option<f64>,
+    pub std: Option<f64>,
+    pub min: Option<f64>,
+    pub max: Option<f64>,
+}
+
+/// Per-key ring buffers of recent prices, imbalances and spreads.
+#[derive(Debug, Clone, Default)]
+pub struct TimeSeriesCache {
+    config: TimeSeriesConfig,
+    series: HashMap<String, HashMap<SeriesKind, RingWindow>>,
+}
+
+impl TimeSeriesCache {
+    pub fn new(config: TimeSeriesConfig) -> Self {
+        Self {
+            config,
+            series: HashMap::new(),
+        }
+    }
+
+    /// Record a value for `id`, creating its buffer on first use
+    pub fn push(&mut self, id: &str, kind: SeriesKind, at: DateTime<Utc>, value: f64) {
+        let capacity = self.config.window(kind);
+        if !self.series.contains_key(id) {
+            self.series.insert(id.to_string(), HashMap::new());
+        }
+        if let Some(windows) = self.series.get_mut(id) {
+            windows
+                .entry(kind)
+                .or_insert_with(|| RingWindow::new(capacity))
+                .push(at, value);
+        }
+    }
+
+    /// Feed a market update: Polymarket quotes record mid, spread and OBI
+    /// per token, Binance prices and kline closes record price per symbol.
+    pub fn record_update(&mut self, update: &MarketUpdate) {
+        match update {
+            MarketUpdate::PolymarketQuote {
+                token_id,
+                quote,
+                timestamp,
+                ..
+            } => {
+                if let (Some(bid), Some(ask)) = (quote.best_bid, quote.best_ask) {
+                    if let Some(spread) = (ask - bid).to_f64() {
+                        self.push(token_id, SeriesKind::Spread, *timestamp, spread);
+                    }
+                }
+                if let Some(mid) = quote.mid_price().and_then(|m| m.to_f64()) {
+                    self.push(token_id, SeriesKind::Price, *timestamp, mid);
+                }
+                if let (Some(bid_size), Some(ask_size)) = (quote.bid_size, quote.ask_size) {
+                    let total = bid_size + ask_size;
+                    if !total.is_zero() {
+                        if let Some(obi) = ((bid_size - ask_size) / total).to_f64() {
+                            self.push(token_id, SeriesKind::Obi, *timestamp, obi);
+                        }
+                    }
+                }
+            }
+            MarketUpdate::BinancePrice {
+                symbol,
+                price,
+                timestamp,
+            } => {
+                if let Some(price) = price.to_f64() {
+                    self.push(symbol, SeriesKind::Price, *timestamp, price);
+                }
+            }
+            MarketUpdate::BinanceKline {
+                symbol,
+                kline,
+                timestamp,
+                ..
+            } => {
+                if let Some(close) = kline.close.to_f64() {
+                    self.push(symbol, SeriesKind::Price, *timestamp, close);
+                }
+            }
+            MarketUpdate::PolymarketBook { .. }
+            | MarketUpdate::EventDiscovered { .. }
+            | MarketUpdate::EventExpired { .. }
+            | MarketUpdate::RoundBoundary { .. } => {}
+        }
+    }
+
+    pub fn window(&self, id: &str, kind: SeriesKind) -> Option<&RingWindow> {
+        self.series.get(id)?.get(&kind)
+    }
+
+    pub fn last(&self, id: &str, kind: SeriesKind) -> Option<f64> {
+        self.window(id, kind)?.last().map(|(_, v)| v)
+    }
+
+    pub fn mean(&self, id: &str, kind: SeriesKind) -> Option<f64> {
+        self.window(id, kind)?.mean()
+    }
+
+    pub fn std(&self, id: &str, kind: SeriesKind) -> Option<f64> {
+        self.window(id, kind)?.std()
+    }
+
+    pub fn min(&self, id: &str, kind: SeriesKind) -> Option<f64> {
+        self.window(id, kind)?.min()
+    }
+
+    pub fn max(&self, id: &str, kind: SeriesKind) -> Option<f64> {
+        self.window(id, kind)?.max()
+    }
+
+    pub fn quantile(&self, id: &str, kind: SeriesKind, q: f64) -> Option<f64> {
+        self.window(id, kind)?.quantile(q)
+    }
+
+    pub fn zscore(&self, id: &str, kind: SeriesKind) -> Option<f64> {
+        self.window(id, kind)?.zscore()
+    }
+
+    /// Drop every series for `id` (e.g. when its market expires)
+    pub fn remove(&mut self, id: &str) {
+        self.series.remove(id);
+    }
+
+    pub fn clear(&mut self) {
+        self.series.clear();
+    }
+
+    /// Number of tracked ids
+    pub fn len(&self) -> usize {
+        self.series.len()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.series.is_empty()
+    }
+
+    /// Rolling statistics for every tracked series, sorted by id then kind
+    pub fn stats(&self) -> Vec<SeriesStats> {
+        let mut stats: Vec<SeriesStats> = self
+            .series
+            .iter()
+            .flat_map(|(id, windows)| {
+                windows.iter().map(move |(kind, window)| {
+                    let last = window.last();
+                    SeriesStats {
+                        id: id.clone(),
+                        kind: *kind,
+                        len: window.len(),
+                        last: last.map(|(_, v)| v),
+                        last_at: last.map(|(at, _)| at),
+                        mean: window.mean(),
+                        std: window.std(),
+                        min: window.min(),
+                        max: window.max(),
+                    }
+                })
+            })
+            .collect();
+        stats.sort_by(|a, b| a.id.cmp(&b.id).then(a.kind.cmp(&b.kind)));
+        stats
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::domain::{Quote, Side};
+    use rust_decimal_macros::dec;
+
+    #[test]
+    fn test_ring_window_rolls_statistics() {
+        let now = Utc::now();
+        let mut window = RingWindow::new(4);
+        for value in [5.0, 1.0, 3.0, 2.0, 4.0, 6.0] {
+            window.push(now, value);
+        }
+
+        // Window now holds [3, 2, 4, 6]
+        assert_eq!(window.len(), 4);
+        assert_eq!(window.mean(), Some(3.75));
+        assert_eq!(window.min(), Some(2.0));
+        assert_eq!(window.max(), Some(6.0));
+        assert_eq!(window.quantile(0.0), Some(2.0));
+        assert_eq!(window.quantile(0.5), Some(3.5));
+        assert_eq!(window.quantile(1.0), Some(6.0));
+        let expected_std = (((3.0f64 - 3.75).powi(2)
+            + (2.0f64 - 3.75).powi(2)
+            + (4.0f64 - 3.75).powi(2)
+            + (6.0f64 - 3.75).powi(2))
+            / 3.0)
+            .sqrt();
+        assert!((window.std().unwrap() - expected_std).abs() < 1e-9);
+    }
+
+    #[test]
+    fn test_cache_records_quote_features() {
+        let now = Utc::now();
+        let mut cache = TimeSeriesCache::new(TimeSeriesConfig::default());
+        cache.record_update(&MarketUpdate::PolymarketQuote {
+            token_id: "tok".to_string(),
+            side: Side::Up,
+            quote: Quote {
+                side: Side::Up,
+                best_bid: Some(dec!(0.40)),
+                best_ask: Some(dec!(0.44)),
+                bid_size: Some(dec!(300)),
+                ask_size: Some(dec!(100)),
+                timestamp: now,
+            },
+            timestamp: now,
+            seconds_to_settlement: None,
+        });
+
+        let price = cache.last("tok", SeriesKind::Price).unwrap();
+        let spread = cache.last("tok", SeriesKind::Spread).unwrap();
+        assert!((price - 0.42).abs() < 1e-9);
+        assert!((spread - 0.04).abs() < 1e-9);
+        assert_eq!(cache.last("tok", SeriesKind::Obi), Some(0.5));
+
+        cache.remove("tok");
+        assert!(cache.is_empty());
+    }
+}
@@ -0,0 +1,527 @@
+//! Operator journal for logged trades.
+//!
+//! Attaches notes, tags and a post-mortem classification to trades recorded
+//! by [`TradeLogger`](super::trade_logger::TradeLogger). Entries live in a
+//! sidecar file keyed by trade id (default `data/trade_journal.json`,
+//! override with `PLOY_TRADE_JOURNAL_FILE`) so the trade logger, which
+//! rewrites `trades.json` from memory, never clobbers them.
+//!
+//! Usage:
+//!   ploy journal note <trade_id> --text "quote was 4s old" --tag stale-quote
+//!   ploy journal list --outcome lost --tag stale-quote
+
+use std::collections::{BTreeSet, HashMap};
+use std::path::{Path, PathBuf};
+
+use chrono::{DateTime, Utc};
+use rust_decimal::Decimal;
+use serde::{Deserialize, Serialize};
+use tokio::sync::Mutex;
+use uuid::Uuid;
+
+use super::trade_logger::{TradeOutcome, TradeRecord};
+use crate::error::{PloyError, Result};
+
+/// Default journal location, relative to the working directory.
+pub const DEFAULT_JOURNAL_PATH: &str = "data/trade_journal.json";
+
+const MAX_LABEL_LEN: usize = 64;
+const MAX_NOTE_LEN: usize = 4_000;
+
+/// Serializes load-modify-save cycles so concurrent annotations in this
+/// process (API requests, CLI) never drop each other's changes.
+static JOURNAL_WRITE_LOCK: Mutex<()> = Mutex::const_new(());
+
+/// Free-form operator note
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct JournalNote {
+    pub text: String,
+    pub author: Option<String>,
+    pub created_at: DateTime<Utc>,
+}
+
+/// Journal annotations for one trade
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct JournalEntry {
+    pub trade_id: String,
+    #[serde(default)]
+    pub notes: Vec<JournalNote>,
+    #[serde(default)]
+    pub tags: BTreeSet<String>,
+    /// Post-mortem classification (e.g. `good-trade`, `bad-signal`, `execution-error`)
+    pub classification: Option<String>,
+    pub updated_at: DateTime<Utc>,
+}
+
+impl JournalEntry {
+    fn new(trade_id: &str) -> Self {
+        Self {
+            trade_id: trade_id.to_string(),
+            notes: Vec::new(),
+            tags: BTreeSet::new(),
+            classification: None,
+            updated_at: Utc::now(),
+        }
+    }
+}
+
+/// Changes applied to a trade's journal entry
+#[derive(Debug, Clone, Default, Deserialize)]
+#[serde(default)]
+pub struct JournalUpdate {
+    pub note: Option<String>,
+    pub author: Option<String>,
+    pub add_tags: Vec<String>,
+    pub remove_tags: Vec<String>,
+    pub classification: Option<String>,
+    pub clear_classification: bool,
+}
+
+impl JournalUpdate {
+    fn is_empty(&self) -> bool {
+        self.note.is_none()
+            && self.add_tags.is_empty()
+            && self.remove_tags.is_empty()
+            && self.classification.is_none()
+            && !self.clear_classification
+    }
+}
+
+/// Filters for [`TradeJournal::query`]
+#[derive(Debug, Clone, Default, Deserialize)]
+#[serde(default)]
+pub struct JournalQuery {
+    pub tag: Option<String>,
+    pub classification: Option<String>,
+    /// `open`, `won`, `lost` (includes early exits at a loss), `exited`, `cancelled`
+    pub outcome: Option<String>,
+    pub symbol: Option<String>,
+    /// Only trades that have a journal entry
+    pub annotated_only: bool,
+    pub limit: Option<usize>,
+}
+
+/// Trade record joined with its journal entry
+#[derive(Debug, Clone, Serialize)]
+pub struct AnnotatedTrade {
+    #[serde(flatten)]
+    pub trade: TradeRecord,
+    pub journal: Option<JournalEntry>,
+}
+
+/// Outcome label used by journal queries
+pub fn outcome_label(trade: &TradeRecord) -> &'static str {
+    match trade.outcome {
+        TradeOutcome::Open => "open",
+        TradeOutcome::Won => "won",
+        TradeOutcome::Lost => "lost",
+        TradeOutcome::ExitedEarly { .. } => "exited",
+        TradeOutcome::Cancelled => "cancelled",
+    }
+}
+
+fn matches_outcome(trade: &TradeRecord, outcome: &str) -> bool {
+    match outcome {
+        "lost" | "loss" | "losses" => {
+            trade.outcome == TradeOutcome::Lost
+                || (matches!(trade.outcome, TradeOutcome::ExitedEarly { .. })
+                    && trade.pnl_usd.is_some_and(|pnl| pnl < Decimal::ZERO))
+        }
+        "won" | "win" | "wins" => trade.outcome == TradeOutcome::Won,
+        other => outcome_label(trade) == other,
+    }
+}
+
+/// Normalize a tag / classification to lowercase kebab-case.
+pub fn normalize_label(raw: &str) -> Result<String> {
+    let label = raw
+        .trim()
+        .to_ascii_lowercase()
+        .replace(|c: char| c == '_' || c.is_whitespace(), "-");
+    let valid = !label.is_empty()
+        && label.len() <= MAX_LABEL_LEN
+        && label
+            .chars()
+            .all(|c| c.is_ascii_alphanumeric() || matches!(c, '-' | ':' | '.'));
+    if !valid {
+        return Err(PloyError::Validation(format!(
+            "invalid label '{}': use [a-z0-9-:.], max {} chars",
+            raw, MAX_LABEL_LEN
+        )));
+    }
+    Ok(label)
+}
+
+/// A [`JournalUpdate`] with the trade id, note and labels checked and normalized
+struct ValidatedUpdate {
+    trade_id: String,
+    note: Option<String>,
+    author: Option<String>,
+    add_tags: Vec<String>,
+    remove_tags: Vec<String>,
+    classification: Option<String>,
+    clear_classification: bool,
+}
+
+impl ValidatedUpdate {
+    fn new(trade_id: &str, update: &JournalUpdate) -> Result<Self> {
+        let trade_id = trade_id.trim();
+        if trade_id.is_empty() {
+            return Err(PloyError::Validation("trade_id is required".to_string()));
+        }
+        if update.is_empty() {
+            return Err(PloyError::Validation(
+                "nothing to record: pass a note, tags or a classification".to_string(),
+            ));
+        }
+
+        let note = match update.note.as_deref().map(str::trim) {
+            Some("") => return Err(PloyError::Validation("note is empty".to_string())),
+            Some(text) if text.len() > MAX_NOTE_LEN => {
+                return Err(PloyError::Validation(format!(
+                    "note exceeds {} chars",
+                    MAX_NOTE_LEN
+                )))
+            }
+            other => other.map(str::to_string),
+        };
+        let add_tags = update
+            .add_tags
+            .iter()
+            .map(|t| normalize_label(t))
+            .collect::<Result<Vec<_>>>()?;
+        let remove_tags = update
+            .remove_tags
+            .iter()
+            .map(|t| normalize_label(t))
+            .collect::<Result<Vec<_>>>()?;
+        let classification = update
+            .classification
+            .as_deref()
+            .map(normalize_label)
+            .transpose()?;
+
+        Ok(Self {
+            trade_id: trade_id.to_string(),
+            note,
+            author: update
+                .author
+                .as_deref()
+                .map(str::trim)
+                .filter(|a| !a.is_empty())
+                .map(str::to_string),
+            add_tags,
+            remove_tags,
+            classification,
+            clear_classification: update.clear_classification,
+        })
+    }
+}
+
+/// File-backed journal store
+#[derive(Debug, Clone)]
+pub struct TradeJournal {
+    path: PathBuf,
+}
+
+impl TradeJournal {
+    pub fn new(path: impl Into<PathBuf>) -> Self {
+        Self { path: path.into() }
+    }
+
+    /// Journal at `PLOY_TRADE_JOURNAL_FILE`, or `data/trade_journal.json`.
+    pub fn from_env() -> Self {
+        let path = std::env::var("PLOY_TRADE_JOURNAL_FILE")
+            .ok()
+            .filter(|p| !p.trim().is_empty())
+            .unwrap_or_else(|| DEFAULT_JOURNAL_PATH.to_string());
+        Self::new(path)
+    }
+
+    pub fn path(&self) -> &Path {
+        &self.path
+    }
+
+    /// All entries keyed by trade id
+    pub fn load(&self) -> Result<HashMap<String, JournalEntry>> {
+        if !self.path.exists() {
+            return Ok(HashMap::new());
+        }
+        let raw = std::fs::read_to_string(&self.path)?;
+        let entries: Vec<JournalEntry> = serde_json::from_str(&raw)?;
+        Ok(entries
+            .into_iter()
+            .map(|entry| (entry.trade_id.clone(), entry))
+            .collect())
+    }
+
+    fn save(&self, entries: &HashMap<String, JournalEntry>) -> Result<()> {
+        if let Some(parent) = self.path.parent() {
+            std::fs::create_dir_all(parent)?;
+        }
+        let mut items: Vec<&JournalEntry> = entries.values().collect();
+        items.sort_by(|a, b| a.trade_id.cmp(&b.trade_id));
+
+        // Write-then-rename so a concurrent reader never sees a torn file; the
+        // temp name is unique so two writers never share one.
+        let tmp = self
+            .path
+            .with_extension(format!("json.{}.tmp", Uuid::new_v4().simple()));
+        std::fs::write(&tmp, serde_json::to_vec_pretty(&items)?)?;
+        std::fs::rename(&tmp, &self.path)?;
+        Ok(())
+    }
+
+    /// Journal entry for one trade
+    pub fn get(&self, trade_id: &str) -> Result<Option<JournalEntry>> {
+        Ok(self.load()?.remove(trade_id))
+    }
+
+    /// Apply an update to a trade's entry, creating it if needed.
+    pub async fn annotate(&self, trade_id: &str, update: &JournalUpdate) -> Result<JournalEntry> {
+        let change = ValidatedUpdate::new(trade_id, update)?;
+
+        let _guard = JOURNAL_WRITE_LOCK.lock().await;
+        let journal = self.clone();
+        tokio::task::spawn_blocking(move || journal.apply(change))
+            .await
+            .map_err(|e| PloyError::Internal(format!("journal update task failed: {}", e)))?
+    }
+
+    fn apply(&self, change: ValidatedUpdate) -> Result<JournalEntry> {
+        let mut entries = self.load()?;
+        let entry = entries
+            .entry(change.trade_id.clone())
+            .or_insert_with(|| JournalEntry::new(&change.trade_id));
+
+        if let Some(text) = change.note {
+            entry.notes.push(JournalNote {
+                text,
+                author: change.author,
+                created_at: Utc::now(),
+            });
+        }
+        for tag in change.remove_tags {
+            entry.tags.remove(&tag);
+        }
+        entry.tags.extend(change.add_tags);
+        if change.clear_classification {
+            entry.classification = None;
+        }
+        if change.classification.is_some() {
+            entry.classification = change.classification;
+        }
+        entry.updated_at = Utc::now();
+
+        let entry = entry.clone();
+        self.save(&entries)?;
+        Ok(entry)
+    }
+
+    /// Join trades with journal entries and filter, newest first.
+    pub fn query(
+        &self,
+        trades: &[TradeRecord],
+        query: &JournalQuery,
+    ) -> Result<Vec<AnnotatedTrade>> {
+        let entries = self.load()?;
+        Ok(filter_annotated(trades, &entries, query))
+    }
+}
+
+fn filter_annotated(
+    trades: &[TradeRecord],
+    entries: &HashMap<String, JournalEntry>,
+    query: &JournalQuery,
+) -> Vec<AnnotatedTrade> {
+    let tag = query.tag.as_deref().and_then(|t| normalize_label(t).ok());
+    let classification = query
+        .classification
+        .as_deref()
+        .and_then(|c| normalize_label(c).ok());
+    let outcome = query
+        .outcome
+        .as_deref()
+        .map(|o| o.trim().to_ascii_lowercase());
+    let needs_entry = query.annotated_only || tag.is_some() || classification.is_some();
+
+    let mut out: Vec<AnnotatedTrade> = trades
+        .iter()
+        .filter(|trade| {
+            query
+                .symbol
+                .as_deref()
+                .map_or(true, |s| trade.symbol.eq_ignore_ascii_case(s.trim()))
+        })
+        .filter(|trade| {
+            outcome
+                .as_deref()
+                .map_or(true, |o| matches_outcome(trade, o))
+        })
+        .filter_map(|trade| {
+            let entry = entries.get(&trade.id);
+            if needs_entry && entry.is_none() {
+                return None;
+            }
+            if let (Some(tag), Some(entry)) = (&tag, entry) {
+                if !entry.tags.contains(tag) {
+                    return None;
+                }
+            }
+            if let (Some(class), Some(entry)) = (&classification, entry) {
+                if entry.classification.as_ref() != Some(class) {
+                    return None;
+                }
+            }
+            Some(AnnotatedTrade {
+                trade: trade.clone(),
+                journal: entry.cloned(),
+            })
+        })
+        .collect();
+
+    out.sort_by(|a, b| b.trade.timestamp.cmp(&a.trade.timestamp));
+    if let Some(limit) = query.limit {
+        out.truncate(limit);
+    }
+    out
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use rust_decimal_macros::dec;
+
+    fn trade(id: &str, outcome: TradeOutcome, pnl: Option<Decimal>) -> TradeRecord {
+        TradeRecord {
+            id: id.to_string(),
+            timestamp: Utc::now(),
+            symbol: "BTCUSDT".to_string(),
+            event_slug: "btc-updown-15m".to_string(),
+            condition_id: format!("0x{id}"),
+            direction: "Up".to_string(),
+            entry_price: dec!(0.40),
+            shares: 10,
+            cost_usd: dec!(4),
+            momentum_pct: Decimal::ZERO,
+            edge_pct: Decimal::ZERO,
+            outcome,
+            payout_usd: None,
+            pnl_usd: pnl,
+            resolved_at: None,
+            context: Default::default(),
+        }
+    }
+
+    #[tokio::test]
+    async fn annotate_and_query_losses_by_tag() {
+        let dir = std::env::temp_dir().join(format!("ploy-journal-{}", uuid::Uuid::new_v4()));
+        let journal = TradeJournal::new(dir.join("journal.json"));
+
+        let trades = vec![
+            trade("a", TradeOutcome::Lost, Some(dec!(-4))),
+            trade("b", TradeOutcome::Won, Some(dec!(6))),
+            trade(
+                "c",
+                TradeOutcome::ExitedEarly {
+                    exit_price: dec!(0.30),
+                },
+                Some(dec!(-1)),
+            ),
+        ];
+        for id in ["a", "b", "c"] {
+            journal
+                .annotate(
+                    id,
+                    &JournalUpdate {
+                        add_tags: vec!["Stale Quote".to_string()],
+                        ..Default::default()
+                    },
+                )
+                .await
+                .unwrap();
+        }
+        let entry = journal
+            .annotate(
+                "a",
+                &JournalUpdate {
+                    note: Some("book was 4s old at entry".to_string()),
+                    classification: Some("execution_error".to_string()),
+                    ..Default::default()
+                },
+            )
+            .await
+            .unwrap();
+        assert_eq!(entry.notes.len(), 1);
+        assert!(entry.tags.contains("stale-quote"));
+        assert_eq!(entry.classification.as_deref(), Some("execution-error"));
+        assert!(journal
+            .annotate("a", &JournalUpdate::default())
+            .await
+            .is_err());
+        assert!(normalize_label("bad/tag").is_err());
+
+        let losses = journal
+            .query(
+                &trades,
+                &JournalQuery {
+                    tag: Some("stale-quote".to_string()),
+                    outcome: Some("lost".to_string()),
+                    ..Default::default()
+                },
+            )
+            .unwrap();
+        let mut ids: Vec<&str> = losses.iter().map(|t| t.trade.id.as_str()).collect();
+        ids.sort();
+        assert_eq!(ids, vec!["a", "c"]);
+
+        let classified = journal
+            .query(
+                &trades,
+                &JournalQuery {
+                    classification: Some("execution-error".to_string()),
+                    ..Default::default()
+                },
+            )
+            .unwrap();
+        assert_eq!(classified.len(), 1);
+
+        let _ = std::fs::remove_dir_all(dir);
+    }
+
+    #[tokio::test(flavor = "multi_thread", worker_threads = 4)]
+    async fn concurrent_annotations_are_all_kept() {
+        let dir = std::env::temp_dir().join(format!("ploy-journal-{}", uuid::Uuid::new_v4()));
+        let journal = TradeJournal::new(dir.join("journal.json"));
+
+        let writers: Vec<_> = (0..16)
+            .map(|i| {
+                let journal = journal.clone();
+                tokio::spawn(async move {
+                    journal
+                        .annotate(
+                            if i % 2 == 0 { "a" } else { "b" },
+                            &JournalUpdate {
+                                note: Some(format!("note {i}")),
+                                add_tags: vec![format!("tag-{i}")],
+                                ..Default::default()
+                            },
+                        )
+                        .await
+                })
+            })
+            .collect();
+        for writer in writers {
+            writer.await.unwrap().unwrap();
+        }
+
+        let entries = journal.load().unwrap();
+        assert_eq!(entries["a"].notes.len(), 8);
+        assert_eq!(entries["b"].notes.len(), 8);
+        assert_eq!(entries["a"].tags.len() + entries["b"].tags.len(), 16);
+        // No temp files are left behind
+        assert_eq!(std::fs::read_dir(&dir).unwrap().count(), 1);
+
+        let _ = std::fs::remove_dir_all(dir);
+    }
+}
@@ -110,6 +110,14 @@ pub struct TradeContext {
     // === Strategy Mode ===
     /// Strategy type: "early_mispricing" or "late_reversal"
     pub strategy_mode: Option<String>,
+
+    // === Execution (fill model calibration) ===
+    /// Limit price submitted with the order
+    pub submitted_price: Option<Decimal>,
+    /// Shares requested (`TradeRecord::shares` is what filled)
+    pub requested_shares: Option<u64>,
+    /// Signal-to-fill latency in milliseconds
+    pub fill_latency_ms: Option<u64>,
 }
 
 impl TradeContext {
@@ -148,6 +156,20 @@ impl TradeContext {
             ..Default::default()
         }
     }
+
+    /// Create context with the submitted order, for fill model calibration
+    pub fn with_execution(
+        submitted_price: Decimal,
+        requested_shares: u64,
+        latency_ms: u64,
+    ) -> Self {
+        Self {
+            submitted_price: Some(submitted_price),
+            requested_shares: Some(requested_shares),
+            fill_latency_ms: Some(latency_ms),
+            ..Default::default()
+        }
+    }
 }
 
 /// Trade outcome
@@ -624,6 +646,17 @@ impl TradeLogger {
         trades.iter().rev().take(limit).cloned().collect()
     }
 
+    /// Get a trade by id
+    pub async fn get_trade(&self, id: &str) -> Option<TradeRecord> {
+        let trades = self.trades.read().await;
+        trades.iter().find(|t| t.id == id).cloned()
+    }
+
+    /// Get all trades
+    pub async fn get_all_trades(&self) -> Vec<TradeRecord> {
+        self.trades.read().await.clone()
+    }
+
     /// Get trades for a specific symbol
     pub async fn get_trades_by_symbol(&self, symbol: &str) -> Vec<TradeRecord> {
         let trades = self.trades.read().await;
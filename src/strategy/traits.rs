@@ -8,8 +8,10 @@ use rust_decimal::Decimal;
 use serde::{Deserialize, Serialize};
 use std::collections::HashMap;
 
+use crate::coordination::BookLevel;
 use crate::domain::{OrderRequest, OrderStatus, Quote, Side};
 use crate::error::Result;
+use crate::strategy::round_calendar::RoundPhase;
 
 // ============================================================================
 // Strategy Trait
@@ -112,6 +114,17 @@ pub enum MarketUpdate {
         side: Side,
         quote: Quote,
         timestamp: DateTime<Utc>,
+        /// Seconds until the token's round settles (from the round calendar).
+        seconds_to_settlement: Option<i64>,
+    },
+
+    /// Full order book snapshot from Polymarket. Unlike quotes these are not
+    /// sanitized; levels arrive as the venue sent them.
+    PolymarketBook {
+        token_id: String,
+        bids: Vec<BookLevel>,
+        asks: Vec<BookLevel>,
+        timestamp: DateTime<Utc>,
     },
 
     /// Price update from Binance
@@ -140,20 +153,53 @@ pub enum MarketUpdate {
         price_to_beat: Option<Decimal>,
         /// Optional human title for logging/debugging.
         title: Option<String>,
+        /// Round length in seconds when known (5m / 15m / 1h / 1d).
+        window_secs: Option<u64>,
     },
 
     /// Event expired/closed
     EventExpired { event_id: String },
+
+    /// Scheduled round phase transition (warm-up, rotation, cool-down end)
+    RoundBoundary {
+        series_id: String,
+        event_id: String,
+        /// Phase being entered
+        phase: RoundPhase,
+        end_time: DateTime<Utc>,
+        timestamp: DateTime<Utc>,
+    },
 }
 
 impl MarketUpdate {
     pub fn timestamp(&self) -> DateTime<Utc> {
         match self {
             MarketUpdate::PolymarketQuote { timestamp, .. } => *timestamp,
+            MarketUpdate::PolymarketBook { timestamp, .. } => *timestamp,
             MarketUpdate::BinancePrice { timestamp, .. } => *timestamp,
             MarketUpdate::BinanceKline { timestamp, .. } => *timestamp,
             MarketUpdate::EventDiscovered { .. } => Utc::now(),
             MarketUpdate::EventExpired { .. } => Utc::now(),
+            MarketUpdate::RoundBoundary { timestamp, .. } => *timestamp,
+        }
+    }
+
+    /// Seconds to settlement, when the update carries it.
+    pub fn seconds_to_settlement(&self) -> Option<i64> {
+        match self {
+            MarketUpdate::PolymarketQuote {
+                seconds_to_settlement,
+                ..
+            } => *seconds_to_settlement,
+            MarketUpdate::RoundBoundary {
+                end_time,
+                timestamp,
+                ..
+            } => Some((*end_time - *timestamp).num_seconds()),
+            MarketUpdate::EventDiscovered { end_time, .. } => {
+                Some((*end_time - Utc::now()).num_seconds())
+            }
+            _ => None,
         }
     }
 }
@@ -12,6 +12,7 @@ use std::collections::{HashMap, VecDeque};
 use tracing::{debug, info};
 
 use crate::domain::Side;
+use crate::strategy::calculations::{MidPriceConfig, MidPriceEstimator};
 
 /// Standard normal CDF approximation (Abramowitz-Stegun)
 /// Accurate to ~4 decimal places
@@ -53,6 +54,9 @@ pub struct VolatilityConfig {
     pub history_window: usize,
     /// Shares per trade
     pub shares_per_trade: u64,
+    /// Smoothing applied to the spot prices events are tracked with
+    #[serde(default)]
+    pub mid_estimator: MidPriceConfig,
 }
 
 impl Default for VolatilityConfig {
@@ -67,6 +71,7 @@ impl Default for VolatilityConfig {
             max_time_remaining_secs: 600,    // Max 10 minutes left
             history_window: 20,              // Track last 20 events
             shares_per_trade: 100,
+            mid_estimator: MidPriceConfig::default(),
         }
     }
 }
@@ -98,6 +103,8 @@ pub struct ActiveEvent {
     pub high_price: Decimal,
     pub low_price: Decimal,
     pub last_update: DateTime<Utc>,
+    /// Smooths raw spot prints before they move current/high/low
+    mid: MidPriceEstimator,
 }
 
 impl ActiveEvent {
@@ -118,11 +125,20 @@ impl ActiveEvent {
             high_price: start_price,
             low_price: start_price,
             last_update: start_time,
+            mid: MidPriceEstimator::new(MidPriceConfig::default()),
         }
     }
 
+    /// Track prices through `config`'s estimator, seeded at the start price
+    pub fn with_mid_estimator(mut self, config: MidPriceConfig) -> Self {
+        self.mid = MidPriceEstimator::new(config);
+        self.mid.update_price(self.start_price, self.start_time);
+        self
+    }
+
     /// Update with new price
     pub fn update_price(&mut self, price: Decimal, timestamp: DateTime<Utc>) {
+        let price = self.mid.update_price(price, timestamp);
         self.current_price = price;
         self.last_update = timestamp;
         if price > self.high_price {
@@ -178,6 +194,8 @@ pub struct EventTracker {
     history: HashMap<String, VecDeque<EventRecord>>,
     /// Maximum history to keep per symbol
     max_history: usize,
+    /// Estimator config for newly registered events
+    mid_config: MidPriceConfig,
 }
 
 impl EventTracker {
@@ -186,9 +204,16 @@ impl EventTracker {
             active_events: HashMap::new(),
             history: HashMap::new(),
             max_history,
+            mid_config: MidPriceConfig::default(),
         }
     }
 
+    /// Smooth event prices with `config` instead of taking raw prints
+    pub fn with_mid_estimator(mut self, config: MidPriceConfig) -> Self {
+        self.mid_config = config;
+        self
+    }
+
     /// Register a new event
     pub fn register_event(
         &mut self,
@@ -215,7 +240,8 @@ impl EventTracker {
             start_time,
             end_time,
             start_price,
-        );
+        )
+        .with_mid_estimator(self.mid_config.clone());
 
         self.active_events.insert(key, event);
     }
@@ -374,7 +400,8 @@ pub struct VolatilityDetector {
 impl VolatilityDetector {
     pub fn new(config: VolatilityConfig) -> Self {
         Self {
-            event_tracker: EventTracker::new(config.history_window),
+            event_tracker: EventTracker::new(config.history_window)
+                .with_mid_estimator(config.mid_estimator.clone()),
             config,
         }
     }
@@ -707,4 +734,24 @@ mod tests {
         let event = tracker.get_active_event("BTCUSDT", "event1").unwrap();
         assert_eq!(event.current_price, dec!(100100));
     }
+
+    #[test]
+    fn test_ewma_tracker_ignores_one_print_spike() {
+        use crate::strategy::calculations::MidPriceMethod;
+
+        let mut tracker = EventTracker::new(10).with_mid_estimator(MidPriceConfig {
+            method: MidPriceMethod::Ewma,
+            ewma_half_life_ms: 2_000,
+        });
+        let start = Utc::now();
+        let end = start + Duration::minutes(15);
+
+        tracker.register_event("BTCUSDT", "event1", start, end, dec!(100000));
+        tracker.update_price("BTCUSDT", dec!(100100), start + Duration::milliseconds(100));
+
+        let event = tracker.get_active_event("BTCUSDT", "event1").unwrap();
+        assert!(event.current_price > dec!(100000));
+        assert!(event.current_price < dec!(100010));
+        assert!(event.range_pct() < dec!(0.0001));
+    }
 }
@@ -0,0 +1,263 @@
+//! Cold-start warmup gate.
+//!
+//! Right after connect, rolling windows and quote caches are empty, so the
+//! first signals a strategy emits are built on nothing. The strategy manager
+//! keeps feeding a warming strategy market data but drops its order actions
+//! until the configured minimum ticks, minimum seconds and required feeds have
+//! all been satisfied.
+
+use chrono::{DateTime, Utc};
+use serde::{Deserialize, Serialize};
+use std::collections::BTreeSet;
+use std::fmt;
+
+use super::traits::{DataFeed, MarketUpdate, StrategyAction};
+
+/// Data feed kinds a strategy can be required to hear from before trading.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum WarmupFeed {
+    PolymarketQuotes,
+    BinanceSpot,
+    BinanceKlines,
+    PolymarketEvents,
+}
+
+impl WarmupFeed {
+    pub fn of_feed(feed: &DataFeed) -> Option<Self> {
+        match feed {
+            DataFeed::PolymarketQuotes { .. } => Some(Self::PolymarketQuotes),
+            DataFeed::BinanceSpot { .. } => Some(Self::BinanceSpot),
+            DataFeed::BinanceKlines { .. } => Some(Self::BinanceKlines),
+            DataFeed::PolymarketEvents { .. } => Some(Self::PolymarketEvents),
+            DataFeed::Tick { .. } => None,
+        }
+    }
+
+    pub fn of_update(update: &MarketUpdate) -> Self {
+        match update {
+            MarketUpdate::PolymarketQuote { .. } | MarketUpdate::PolymarketBook { .. } => {
+                Self::PolymarketQuotes
+            }
+            MarketUpdate::BinancePrice { .. } => Self::BinanceSpot,
+            MarketUpdate::BinanceKline { .. } => Self::BinanceKlines,
+            MarketUpdate::EventDiscovered { .. }
+            | MarketUpdate::EventExpired { .. }
+            | MarketUpdate::RoundBoundary { .. } => Self::PolymarketEvents,
+        }
+    }
+}
+
+impl fmt::Display for WarmupFeed {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let name = match self {
+            Self::PolymarketQuotes => "polymarket_quotes",
+            Self::BinanceSpot => "binance_spot",
+            Self::BinanceKlines => "binance_klines",
+            Self::PolymarketEvents => "polymarket_events",
+        };
+        write!(f, "{}", name)
+    }
+}
+
+/// `[warmup]` section of a strategy config
+#[derive(Debug, Clone, Deserialize)]
+#[serde(default)]
+pub struct WarmupConfig {
+    pub enabled: bool,
+    /// Market updates to receive before trading
+    pub min_ticks: u64,
+    /// Seconds since start before trading
+    pub min_secs: u64,
+    /// Feeds that must each deliver at least one update.
+    /// `None` requires every non-tick feed the strategy declares.
+    pub required_feeds: Option<Vec<WarmupFeed>>,
+}
+
+impl Default for WarmupConfig {
+    fn default() -> Self {
+        Self {
+            enabled: true,
+            min_ticks: 20,
+            min_secs: 30,
+            required_feeds: None,
+        }
+    }
+}
+
+/// Snapshot of a strategy's warmup progress.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct WarmupStatus {
+    pub warm: bool,
+    pub ticks: u64,
+    pub min_ticks: u64,
+    pub elapsed_secs: u64,
+    pub min_secs: u64,
+    pub missing_feeds: Vec<WarmupFeed>,
+    pub warm_at: Option<DateTime<Utc>>,
+}
+
+impl WarmupStatus {
+    /// Short form for status tables, e.g. `warming 12/20 ticks, 8/30s, waiting binance_spot`
+    pub fn summary(&self) -> String {
+        if self.warm {
+            return "warm".to_string();
+        }
+        let mut parts = vec![format!(
+            "warming {}/{} ticks, {}/{}s",
+            self.ticks.min(self.min_ticks),
+            self.min_ticks,
+            self.elapsed_secs.min(self.min_secs),
+            self.min_secs
+        )];
+        if !self.missing_feeds.is_empty() {
+            let feeds: Vec<String> = self.missing_feeds.iter().map(|f| f.to_string()).collect();
+            parts.push(format!("waiting {}", feeds.join(",")));
+        }
+        parts.join(", ")
+    }
+}
+
+/// Per-strategy warmup state, owned by the strategy manager.
+#[derive(Debug, Clone)]
+pub struct WarmupTracker {
+    config: WarmupConfig,
+    started_at: DateTime<Utc>,
+    ticks: u64,
+    required: BTreeSet<WarmupFeed>,
+    seen: BTreeSet<WarmupFeed>,
+    warm_at: Option<DateTime<Utc>>,
+}
+
+impl WarmupTracker {
+    pub fn new(config: WarmupConfig, declared_feeds: &[DataFeed], now: DateTime<Utc>) -> Self {
+        let required = match &config.required_feeds {
+            Some(feeds) => feeds.iter().copied().collect(),
+            None => declared_feeds
+                .iter()
+                .filter_map(WarmupFeed::of_feed)
+                .collect(),
+        };
+        let warm_at = (!config.enabled).then_some(now);
+        Self {
+            config,
+            started_at: now,
+            ticks: 0,
+            required,
+            seen: BTreeSet::new(),
+            warm_at,
+        }
+    }
+
+    pub fn observe(&mut self, update: &MarketUpdate) {
+        if self.warm_at.is_none() {
+            self.ticks += 1;
+            self.seen.insert(WarmupFeed::of_update(update));
+        }
+    }
+
+    pub fn is_warm(&self) -> bool {
+        self.warm_at.is_some()
+    }
+
+    /// Whether warmup is complete; latches once it is.
+    pub fn check(&mut self, now: DateTime<Utc>) -> bool {
+        if self.warm_at.is_none() && self.ready(now) {
+            self.warm_at = Some(now);
+        }
+        self.is_warm()
+    }
+
+    fn ready(&self, now: DateTime<Utc>) -> bool {
+        self.ticks >= self.config.min_ticks
+            && self.elapsed_secs(now) >= self.config.min_secs
+            && self.required.is_subset(&self.seen)
+    }
+
+    /// Drop actions that could open exposure while still warming.
+    pub fn gate(
+        &mut self,
+        actions: Vec<StrategyAction>,
+        now: DateTime<Utc>,
+    ) -> Vec<StrategyAction> {
+        if self.check(now) {
+            return actions;
+        }
+        actions
+            .into_iter()
+            .filter(|a| {
+                !matches!(
+                    a,
+                    StrategyAction::SubmitOrder { .. } | StrategyAction::ModifyOrder { .. }
+                )
+            })
+            .collect()
+    }
+
+    pub fn status(&self, now: DateTime<Utc>) -> WarmupStatus {
+        WarmupStatus {
+            warm: self.is_warm() || self.ready(now),
+            ticks: self.ticks,
+            min_ticks: self.config.min_ticks,
+            elapsed_secs: self.elapsed_secs(now),
+            min_secs: self.config.min_secs,
+            missing_feeds: self.required.difference(&self.seen).copied().collect(),
+            warm_at: self.warm_at,
+        }
+    }
+
+    fn elapsed_secs(&self, now: DateTime<Utc>) -> u64 {
+        (now - self.started_at).num_seconds().max(0) as u64
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use chrono::Duration;
+    use rust_decimal::Decimal;
+
+    fn price(ts: DateTime<Utc>) -> MarketUpdate {
+        MarketUpdate::BinancePrice {
+            symbol: "BTCUSDT".to_string(),
+            price: Decimal::ONE,
+            timestamp: ts,
+        }
+    }
+
+    #[test]
+    fn test_warmup_requires_ticks_time_and_feeds() {
+        let t0 = Utc::now();
+        let feeds = vec![
+            DataFeed::BinanceSpot {
+                symbols: vec!["BTCUSDT".to_string()],
+            },
+            DataFeed::PolymarketEvents {
+                series_ids: vec!["10423".to_string()],
+            },
+            DataFeed::Tick { interval_ms: 1000 },
+        ];
+        let config = WarmupConfig {
+            min_ticks: 2,
+            min_secs: 10,
+            ..Default::default()
+        };
+        let mut tracker = WarmupTracker::new(config, &feeds, t0);
+
+        tracker.observe(&price(t0));
+        tracker.observe(&price(t0));
+        let later = t0 + Duration::seconds(11);
+        assert!(!tracker.check(later));
+        assert_eq!(
+            tracker.status(later).missing_feeds,
+            vec![WarmupFeed::PolymarketEvents]
+        );
+
+        tracker.observe(&MarketUpdate::EventExpired {
+            event_id: "e1".to_string(),
+        });
+        assert!(!tracker.check(t0 + Duration::seconds(5)));
+        assert!(tracker.check(later));
+        assert_eq!(tracker.status(later).summary(), "warm");
+    }
+}
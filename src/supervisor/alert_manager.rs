@@ -1,9 +1,10 @@
-//! Alert Manager for Feishu Integration
+//! Alert Manager for Feishu / Discord Integration
 //!
-//! Routes alerts based on severity and integrates with Feishu for notifications.
-//! Includes rate limiting to prevent alert storms.
+//! Routes alerts based on severity and fans them out to every configured
+//! [`AlertChannel`]. Includes rate limiting to prevent alert storms.
 
 use crate::adapters::{FeishuNotifier, TransactionManager};
+use async_trait::async_trait;
 use chrono::{DateTime, Utc};
 use std::collections::HashMap;
 use std::sync::Arc;
@@ -81,6 +82,11 @@ impl Alert {
         self
     }
 
+    /// Key identifying repeats of the same alert
+    pub fn dedup_key(&self) -> String {
+        format!("{}:{}:{}", self.component, self.level, self.title)
+    }
+
     /// Format for Feishu message
     pub fn format_feishu(&self) -> String {
         format!(
@@ -94,6 +100,32 @@ impl Alert {
     }
 }
 
+/// Outbound notification channel (Feishu, Discord, ...)
+#[async_trait]
+pub trait AlertChannel: Send + Sync {
+    /// Channel name for logs
+    fn name(&self) -> &str;
+
+    /// Whether this channel delivers alerts of `level`
+    fn accepts(&self, _level: AlertLevel) -> bool {
+        true
+    }
+
+    /// Deliver one alert
+    async fn send(&self, alert: &Alert) -> Result<(), String>;
+}
+
+#[async_trait]
+impl AlertChannel for FeishuNotifier {
+    fn name(&self) -> &str {
+        "feishu"
+    }
+
+    async fn send(&self, alert: &Alert) -> Result<(), String> {
+        self.send_message(&alert.format_feishu()).await
+    }
+}
+
 /// Configuration for alert manager
 #[derive(Debug, Clone)]
 pub struct AlertManagerConfig {
@@ -125,7 +157,7 @@ struct RateLimitState {
 /// Alert Manager for coordinating notifications
 pub struct AlertManager {
     config: AlertManagerConfig,
-    feishu: Option<Arc<FeishuNotifier>>,
+    channels: Vec<Arc<dyn AlertChannel>>,
     transaction_manager: Option<Arc<TransactionManager>>,
     rate_limits: Arc<RwLock<HashMap<String, RateLimitState>>>,
     alerts_this_minute: Arc<RwLock<Vec<DateTime<Utc>>>>,
@@ -138,7 +170,7 @@ impl AlertManager {
         let (event_tx, _) = tokio::sync::broadcast::channel(64);
         Self {
             config,
-            feishu: None,
+            channels: Vec::new(),
             transaction_manager: None,
             rate_limits: Arc::new(RwLock::new(HashMap::new())),
             alerts_this_minute: Arc::new(RwLock::new(Vec::new())),
@@ -152,11 +184,21 @@ impl AlertManager {
     }
 
     /// Set Feishu notifier for alerts
-    pub fn with_feishu(mut self, feishu: Arc<FeishuNotifier>) -> Self {
-        self.feishu = Some(feishu);
+    pub fn with_feishu(self, feishu: Arc<FeishuNotifier>) -> Self {
+        self.with_channel(feishu)
+    }
+
+    /// Add a notification channel
+    pub fn with_channel(mut self, channel: Arc<dyn AlertChannel>) -> Self {
+        self.channels.push(channel);
         self
     }
 
+    /// Whether any notification channel is configured
+    pub fn has_channels(&self) -> bool {
+        !self.channels.is_empty()
+    }
+
     /// Set transaction manager for persistence
     pub fn with_transaction_manager(mut self, tm: Arc<TransactionManager>) -> Self {
         self.transaction_manager = Some(tm);
@@ -170,7 +212,7 @@ impl AlertManager {
 
     /// Generate rate limit key for an alert
     fn rate_limit_key(alert: &Alert) -> String {
-        format!("{}:{}:{}", alert.component, alert.level, alert.title)
+        alert.dedup_key()
     }
 
     /// Check if alert should be rate limited
@@ -232,6 +274,21 @@ impl AlertManager {
 
     /// Send an alert
     pub async fn alert(&self, alert: Alert) {
+        // Check if we should notify external channels
+        let should_notify = match alert.level {
+            AlertLevel::Info => self.config.notify_info,
+            AlertLevel::Warning | AlertLevel::Error | AlertLevel::Critical => true,
+        };
+        self.dispatch(alert, should_notify).await;
+    }
+
+    /// Deliver a scheduled report (daily PnL summary etc.) to external channels
+    /// even when it is Info level and `notify_info` is off.
+    pub async fn report(&self, alert: Alert) {
+        self.dispatch(alert, true).await;
+    }
+
+    async fn dispatch(&self, alert: Alert, should_notify: bool) {
         // Always broadcast locally
         let _ = self.event_tx.send(alert.clone());
 
@@ -268,12 +325,6 @@ impl AlertManager {
             }
         }
 
-        // Check if we should notify via Feishu
-        let should_notify = match alert.level {
-            AlertLevel::Info => self.config.notify_info,
-            AlertLevel::Warning | AlertLevel::Error | AlertLevel::Critical => true,
-        };
-
         if !should_notify {
             return;
         }
@@ -288,13 +339,10 @@ impl AlertManager {
             return;
         }
 
-        // Send to Feishu
-        if let Some(ref feishu) = self.feishu {
-            let message = alert.format_feishu();
-
-            // Send to Feishu (all alerts go through same channel)
-            if let Err(e) = feishu.send_message(&message).await {
-                error!("Failed to send Feishu alert: {}", e);
+        // Fan out to every channel routed for this level
+        for channel in self.channels.iter().filter(|c| c.accepts(alert.level)) {
+            if let Err(e) = channel.send(&alert).await {
+                error!("Failed to send {} alert: {}", channel.name(), e);
             }
         }
     }
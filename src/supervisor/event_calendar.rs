@@ -0,0 +1,417 @@
+//! Trading Calendar for High-Impact Events
+//!
+//! Collects scheduled events that move markets — NBA tip-offs from the ESPN
+//! schedule, CPI / FOMC releases and token unlocks from a schedule file or
+//! inline config — into a shared calendar. Strategies ask whether a
+//! high-impact event touching their domain/symbol is near, and scale entry
+//! size down and entry edge thresholds up while one is.
+
+use chrono::{DateTime, Duration, Utc};
+use rust_decimal::prelude::ToPrimitive;
+use rust_decimal::Decimal;
+use serde::{Deserialize, Serialize};
+use std::collections::BTreeMap;
+use std::sync::{Arc, RwLock};
+use tokio::sync::broadcast;
+use tracing::{debug, info, warn};
+
+use crate::error::Result;
+use crate::platform::Domain;
+use crate::strategy::nba_comeback::EspnClient;
+
+const SOURCE_CONFIG: &str = "config";
+const SOURCE_SCHEDULE_FILE: &str = "schedule_file";
+const SOURCE_NBA: &str = "espn_nba";
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum CalendarEventKind {
+    /// Sports game tip-off / kick-off
+    Game,
+    /// CPI release
+    Cpi,
+    /// FOMC rate decision
+    Fomc,
+    /// Token unlock / vesting cliff
+    TokenUnlock,
+    Other,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Default, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum EventImpact {
+    Low,
+    Medium,
+    #[default]
+    High,
+}
+
+/// A scheduled market-moving event
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct CalendarEvent {
+    pub id: String,
+    pub kind: CalendarEventKind,
+    pub title: String,
+    pub at: DateTime<Utc>,
+    #[serde(default)]
+    pub impact: EventImpact,
+    /// Domains the event applies to (empty = all)
+    #[serde(default)]
+    pub domains: Vec<Domain>,
+    /// Symbols / team abbreviations it applies to (empty = the whole domain)
+    #[serde(default)]
+    pub symbols: Vec<String>,
+}
+
+impl CalendarEvent {
+    /// Whether the event concerns `domain` (and `symbol`, when given)
+    pub fn applies_to(&self, domain: Domain, symbol: Option<&str>) -> bool {
+        if !self.domains.is_empty() && !self.domains.contains(&domain) {
+            return false;
+        }
+        match symbol {
+            Some(symbol) if !self.symbols.is_empty() => {
+                self.symbols.iter().any(|s| s.eq_ignore_ascii_case(symbol))
+            }
+            _ => true,
+        }
+    }
+}
+
+/// Configuration for the event calendar
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(default)]
+pub struct EventCalendarConfig {
+    /// Enable the calendar refresh service (default: false)
+    pub enabled: bool,
+    /// Interval between schedule refreshes (default: 15m)
+    pub refresh_interval_secs: u64,
+    /// JSON file with a list of events (CPI / FOMC / unlocks); reloaded on refresh
+    pub schedule_path: Option<String>,
+    /// Events declared inline
+    pub events: Vec<CalendarEvent>,
+    /// Days of NBA schedule to ingest from ESPN, today included (0 disables)
+    pub nba_schedule_days: u32,
+    /// Impact assigned to game tip-offs (default: high)
+    pub game_impact: EventImpact,
+    /// Events below this impact are ignored by the adjustments (default: high)
+    pub min_impact: EventImpact,
+    /// Adjust entries this long before an event (default: 30m)
+    pub window_before_mins: i64,
+    /// ... and this long after it (default: 15m)
+    pub window_after_mins: i64,
+    /// Entry size multiplier inside the window (default: 0.5)
+    pub size_multiplier: Decimal,
+    /// Entry edge threshold multiplier inside the window (default: 1.5)
+    pub edge_multiplier: Decimal,
+}
+
+impl Default for EventCalendarConfig {
+    fn default() -> Self {
+        Self {
+            enabled: false,
+            refresh_interval_secs: 900,
+            schedule_path: None,
+            events: Vec::new(),
+            nba_schedule_days: 2,
+            game_impact: EventImpact::High,
+            min_impact: EventImpact::High,
+            window_before_mins: 30,
+            window_after_mins: 15,
+            size_multiplier: Decimal::new(5, 1),
+            edge_multiplier: Decimal::new(15, 1),
+        }
+    }
+}
+
+/// How a strategy should adjust an entry right now
+#[derive(Debug, Clone, PartialEq)]
+pub struct CalendarAdjustment {
+    pub size_multiplier: Decimal,
+    pub edge_multiplier: Decimal,
+    /// The event causing the adjustment
+    pub event: Option<CalendarEvent>,
+}
+
+impl Default for CalendarAdjustment {
+    fn default() -> Self {
+        Self {
+            size_multiplier: Decimal::ONE,
+            edge_multiplier: Decimal::ONE,
+            event: None,
+        }
+    }
+}
+
+impl CalendarAdjustment {
+    /// `shares` scaled by the size multiplier (0 means skip the entry)
+    pub fn scale_shares(&self, shares: u64) -> u64 {
+        (Decimal::from(shares) * self.size_multiplier)
+            .floor()
+            .to_u64()
+            .unwrap_or(0)
+    }
+}
+
+#[derive(Debug, Default)]
+struct CalendarState {
+    config: EventCalendarConfig,
+    /// Events per ingestion source, replaced wholesale on refresh
+    sources: BTreeMap<String, Vec<CalendarEvent>>,
+}
+
+/// Shared event calendar, refreshed by [`EventCalendarService`] and read by
+/// agents.
+#[derive(Debug, Clone, Default)]
+pub struct EventCalendar {
+    inner: Arc<RwLock<CalendarState>>,
+}
+
+impl EventCalendar {
+    pub fn new(config: EventCalendarConfig) -> Self {
+        let calendar = Self {
+            inner: Arc::new(RwLock::new(CalendarState {
+                config: config.clone(),
+                sources: BTreeMap::new(),
+            })),
+        };
+        calendar.replace_source(SOURCE_CONFIG, config.events);
+        calendar
+    }
+
+    /// Replace all events from one source
+    pub fn replace_source(&self, source: &str, events: Vec<CalendarEvent>) {
+        if let Ok(mut state) = self.inner.write() {
+            state.sources.insert(source.to_string(), events);
+        }
+    }
+
+    /// Known events from `from` onward, in time order
+    pub fn upcoming(&self, from: DateTime<Utc>) -> Vec<CalendarEvent> {
+        let Ok(state) = self.inner.read() else {
+            return Vec::new();
+        };
+        let mut events: Vec<CalendarEvent> = state
+            .sources
+            .values()
+            .flatten()
+            .filter(|e| e.at >= from)
+            .cloned()
+            .collect();
+        events.sort_by_key(|e| e.at);
+        events
+    }
+
+    /// The nearest event of at least `min_impact` for `domain` / `symbol` that
+    /// starts within `within` of `now` (or started less than the configured
+    /// after-window ago).
+    pub fn high_impact_within(
+        &self,
+        domain: Domain,
+        symbol: Option<&str>,
+        now: DateTime<Utc>,
+        within: Duration,
+    ) -> Option<CalendarEvent> {
+        let state = self.inner.read().ok()?;
+        let after = Duration::minutes(state.config.window_after_mins.max(0));
+        state
+            .sources
+            .values()
+            .flatten()
+            .filter(|e| e.impact >= state.config.min_impact)
+            .filter(|e| e.at >= now - after && e.at <= now + within)
+            .filter(|e| e.applies_to(domain, symbol))
+            .min_by_key(|e| (e.at - now).num_seconds().abs())
+            .cloned()
+    }
+
+    /// Size / threshold adjustment for an entry at `now`
+    pub fn adjustment(
+        &self,
+        domain: Domain,
+        symbol: Option<&str>,
+        now: DateTime<Utc>,
+    ) -> CalendarAdjustment {
+        let (before, size_multiplier, edge_multiplier) = match self.inner.read() {
+            Ok(state) => (
+                Duration::minutes(state.config.window_before_mins.max(0)),
+                state.config.size_multiplier,
+                state.config.edge_multiplier,
+            ),
+            Err(_) => return CalendarAdjustment::default(),
+        };
+        match self.high_impact_within(domain, symbol, now, before) {
+            Some(event) => CalendarAdjustment {
+                size_multiplier,
+                edge_multiplier,
+                event: Some(event),
+            },
+            None => CalendarAdjustment::default(),
+        }
+    }
+
+    /// Drop events that ended before `now - window_after`
+    pub fn prune(&self, now: DateTime<Utc>) {
+        if let Ok(mut state) = self.inner.write() {
+            let cutoff = now - Duration::minutes(state.config.window_after_mins.max(0));
+            for events in state.sources.values_mut() {
+                events.retain(|e| e.at >= cutoff);
+            }
+        }
+    }
+}
+
+/// Load a schedule file: a JSON array of [`CalendarEvent`]
+pub fn load_schedule_file(path: &str) -> Result<Vec<CalendarEvent>> {
+    let raw = std::fs::read_to_string(path)?;
+    Ok(serde_json::from_str(&raw)?)
+}
+
+/// Periodically re-ingests the schedule sources into the shared calendar
+pub struct EventCalendarService {
+    config: EventCalendarConfig,
+    calendar: EventCalendar,
+    espn: EspnClient,
+}
+
+impl EventCalendarService {
+    pub fn new(config: EventCalendarConfig, calendar: EventCalendar) -> Self {
+        Self {
+            config,
+            calendar,
+            espn: EspnClient::new(),
+        }
+    }
+
+    /// Refresh every source once. A failing source keeps its previous events.
+    pub async fn refresh(&self) {
+        let now = Utc::now();
+
+        if let Some(path) = self.config.schedule_path.as_deref() {
+            match load_schedule_file(path) {
+                Ok(events) => self.calendar.replace_source(SOURCE_SCHEDULE_FILE, events),
+                Err(e) => warn!(path, error = %e, "event calendar: schedule file load failed"),
+            }
+        }
+
+        if self.config.nba_schedule_days > 0 {
+            let mut games = Vec::new();
+            let mut failed = false;
+            for offset in 0..self.config.nba_schedule_days {
+                let date = (now + Duration::days(offset as i64)).date_naive();
+                match self.espn.fetch_schedule(date).await {
+                    Ok(schedule) => games.extend(schedule.into_iter().map(|g| CalendarEvent {
+                        id: format!("nba:{}", g.espn_game_id),
+                        kind: CalendarEventKind::Game,
+                        title: format!("{} @ {}", g.away_team, g.home_team),
+                        at: g.start_time,
+                        impact: self.config.game_impact,
+                        domains: vec![Domain::Sports],
+                        symbols: vec![g.home_abbrev, g.away_abbrev, g.home_team, g.away_team],
+                    })),
+                    Err(e) => {
+                        warn!(%date, error = %e, "event calendar: ESPN schedule fetch failed");
+                        failed = true;
+                    }
+                }
+            }
+            if !failed {
+                self.calendar.replace_source(SOURCE_NBA, games);
+            }
+        }
+
+        self.calendar.prune(now);
+        let upcoming = self.calendar.upcoming(now);
+        debug!(
+            events = upcoming.len(),
+            next = ?upcoming.first().map(|e| (&e.title, e.at)),
+            "event calendar refreshed"
+        );
+    }
+
+    /// Run the refresh loop until shutdown
+    pub async fn run(self, mut shutdown_rx: broadcast::Receiver<()>) {
+        let period = std::time::Duration::from_secs(self.config.refresh_interval_secs.max(60));
+        let mut interval = tokio::time::interval(period);
+        interval.set_missed_tick_behavior(tokio::time::MissedTickBehavior::Delay);
+        info!(
+            refresh_secs = period.as_secs(),
+            nba_days = self.config.nba_schedule_days,
+            schedule_path = ?self.config.schedule_path,
+            "event calendar started"
+        );
+
+        loop {
+            tokio::select! {
+                _ = interval.tick() => self.refresh().await,
+                _ = shutdown_rx.recv() => {
+                    info!("event calendar stopping");
+                    break;
+                }
+            }
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn event(kind: CalendarEventKind, at: DateTime<Utc>, symbols: &[&str]) -> CalendarEvent {
+        CalendarEvent {
+            id: format!("{:?}", kind),
+            kind,
+            title: String::new(),
+            at,
+            impact: EventImpact::High,
+            domains: vec![Domain::Crypto],
+            symbols: symbols.iter().map(|s| s.to_string()).collect(),
+        }
+    }
+
+    #[test]
+    fn test_adjustment_inside_event_window() {
+        let now = Utc::now();
+        let calendar = EventCalendar::new(EventCalendarConfig {
+            events: vec![
+                event(CalendarEventKind::Fomc, now + Duration::minutes(20), &[]),
+                event(
+                    CalendarEventKind::TokenUnlock,
+                    now + Duration::minutes(5),
+                    &["SUI"],
+                ),
+            ],
+            ..EventCalendarConfig::default()
+        });
+
+        // FOMC in 20 minutes applies to every crypto symbol
+        let adj = calendar.adjustment(Domain::Crypto, Some("BTC"), now);
+        assert_eq!(adj.size_multiplier, Decimal::new(5, 1));
+        assert_eq!(adj.edge_multiplier, Decimal::new(15, 1));
+        assert_eq!(adj.scale_shares(25), 12);
+        assert_eq!(adj.event.unwrap().kind, CalendarEventKind::Fomc);
+
+        // The unlock is nearer but only for its own token
+        let sui = calendar.adjustment(Domain::Crypto, Some("sui"), now);
+        assert_eq!(sui.event.unwrap().kind, CalendarEventKind::TokenUnlock);
+
+        // Other domains and times outside the window are unaffected
+        assert_eq!(
+            calendar.adjustment(Domain::Sports, Some("LAL"), now),
+            CalendarAdjustment::default()
+        );
+        let later = now + Duration::hours(2);
+        assert_eq!(
+            calendar.adjustment(Domain::Crypto, Some("BTC"), later),
+            CalendarAdjustment::default()
+        );
+        assert!(calendar
+            .high_impact_within(
+                Domain::Crypto,
+                None,
+                later - Duration::hours(1),
+                Duration::hours(1)
+            )
+            .is_none());
+    }
+}
@@ -0,0 +1,399 @@
+//! Live Spread / Liquidity Anomaly Detector
+//!
+//! Watches every Polymarket quote update and flags a token as untradable when
+//! its book stops looking like a market: the spread blows out to a multiple of
+//! its rolling median, top-of-book depth collapses against its rolling median,
+//! or one side of the book disappears. Flags live in a shared registry that the
+//! coordinator checks before admitting BUY intents and the TUI badges; a token
+//! is cleared after a run of clean updates.
+
+use chrono::{DateTime, Utc};
+use rust_decimal::Decimal;
+use serde::{Deserialize, Serialize};
+use std::collections::{HashMap, VecDeque};
+use std::sync::{Arc, RwLock};
+use tokio::sync::broadcast;
+use tracing::{info, warn};
+
+use crate::adapters::QuoteUpdate;
+use crate::domain::Quote;
+
+/// Configuration for the anomaly detector
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(default)]
+pub struct MarketAnomalyConfig {
+    /// Enable the detector (default: false)
+    pub enabled: bool,
+    /// Clean updates kept per token for the rolling medians (default: 200)
+    pub window: usize,
+    /// Clean updates required before spread/depth checks apply (default: 20)
+    pub min_samples: usize,
+    /// Spread above this multiple of the rolling median is a blowout (default: 3)
+    pub spread_multiple: Decimal,
+    /// Absolute spread below which no blowout is reported (default: 0.03)
+    pub min_blowout_spread: Decimal,
+    /// Depth below this share of the rolling median is a collapse (default: 0.2)
+    pub depth_collapse_ratio: Decimal,
+    /// Consecutive clean updates required to clear a flag (default: 10)
+    pub recovery_updates: u32,
+}
+
+impl Default for MarketAnomalyConfig {
+    fn default() -> Self {
+        Self {
+            enabled: false,
+            window: 200,
+            min_samples: 20,
+            spread_multiple: Decimal::from(3),
+            min_blowout_spread: Decimal::new(3, 2),
+            depth_collapse_ratio: Decimal::new(2, 1),
+            recovery_updates: 10,
+        }
+    }
+}
+
+/// Why a token was marked untradable
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum AnomalyKind {
+    SpreadBlowout,
+    DepthCollapse,
+    OneSidedBook,
+}
+
+impl std::fmt::Display for AnomalyKind {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Self::SpreadBlowout => write!(f, "spread blowout"),
+            Self::DepthCollapse => write!(f, "depth collapse"),
+            Self::OneSidedBook => write!(f, "one-sided book"),
+        }
+    }
+}
+
+/// An active untradable flag
+#[derive(Debug, Clone, PartialEq, Serialize)]
+pub struct MarketAnomaly {
+    pub token_id: String,
+    pub kind: AnomalyKind,
+    pub detail: String,
+    pub since: DateTime<Utc>,
+}
+
+/// Shared untradable-token registry, written by the detector and read by the
+/// coordinator (entry gate) and the TUI (badges).
+#[derive(Debug, Clone, Default)]
+pub struct MarketAnomalies {
+    inner: Arc<RwLock<HashMap<String, MarketAnomaly>>>,
+}
+
+impl MarketAnomalies {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Whether new entries on `token_id` are currently blocked
+    pub fn is_untradable(&self, token_id: &str) -> bool {
+        self.inner
+            .read()
+            .map(|m| m.contains_key(token_id))
+            .unwrap_or(false)
+    }
+
+    pub fn get(&self, token_id: &str) -> Option<MarketAnomaly> {
+        self.inner.read().ok()?.get(token_id).cloned()
+    }
+
+    /// All active flags, oldest first
+    pub fn snapshot(&self) -> Vec<MarketAnomaly> {
+        let mut flags: Vec<MarketAnomaly> = self
+            .inner
+            .read()
+            .map(|m| m.values().cloned().collect())
+            .unwrap_or_default();
+        flags.sort_by_key(|a| a.since);
+        flags
+    }
+
+    /// Flag a token; an existing flag keeps its original `since`.
+    /// Returns true when the token was not flagged before.
+    fn mark(&self, token_id: &str, kind: AnomalyKind, detail: String) -> bool {
+        let Ok(mut flags) = self.inner.write() else {
+            return false;
+        };
+        match flags.get_mut(token_id) {
+            Some(existing) => {
+                existing.kind = kind;
+                existing.detail = detail;
+                false
+            }
+            None => {
+                flags.insert(
+                    token_id.to_string(),
+                    MarketAnomaly {
+                        token_id: token_id.to_string(),
+                        kind,
+                        detail,
+                        since: Utc::now(),
+                    },
+                );
+                true
+            }
+        }
+    }
+
+    fn clear(&self, token_id: &str) -> Option<MarketAnomaly> {
+        self.inner.write().ok()?.remove(token_id)
+    }
+}
+
+/// Rolling book statistics for one token
+#[derive(Debug, Default)]
+struct BookStats {
+    spreads: VecDeque<Decimal>,
+    depths: VecDeque<Decimal>,
+    clean_streak: u32,
+}
+
+impl BookStats {
+    fn push(&mut self, spread: Decimal, depth: Decimal, window: usize) {
+        self.spreads.push_back(spread);
+        self.depths.push_back(depth);
+        while self.spreads.len() > window.max(1) {
+            self.spreads.pop_front();
+            self.depths.pop_front();
+        }
+    }
+}
+
+fn median(values: &VecDeque<Decimal>) -> Option<Decimal> {
+    if values.is_empty() {
+        return None;
+    }
+    let mut sorted: Vec<Decimal> = values.iter().copied().collect();
+    sorted.sort();
+    let mid = sorted.len() / 2;
+    Some(if sorted.len() % 2 == 0 {
+        (sorted[mid - 1] + sorted[mid]) / Decimal::TWO
+    } else {
+        sorted[mid]
+    })
+}
+
+/// Per-token anomaly detector feeding a [`MarketAnomalies`] registry
+pub struct MarketAnomalyDetector {
+    config: MarketAnomalyConfig,
+    registry: MarketAnomalies,
+    books: HashMap<String, BookStats>,
+}
+
+impl MarketAnomalyDetector {
+    pub fn new(config: MarketAnomalyConfig, registry: MarketAnomalies) -> Self {
+        Self {
+            config,
+            registry,
+            books: HashMap::new(),
+        }
+    }
+
+    /// Classify a quote against the token's clean history, without side effects
+    fn classify(&self, stats: &BookStats, quote: &Quote) -> Option<(AnomalyKind, String)> {
+        let bid_size = quote.bid_size.unwrap_or_default();
+        let ask_size = quote.ask_size.unwrap_or_default();
+        let (bid, ask) = match (quote.best_bid, quote.best_ask) {
+            (Some(bid), Some(ask)) if bid_size > Decimal::ZERO && ask_size > Decimal::ZERO => {
+                (bid, ask)
+            }
+            (bid, ask) => {
+                return Some((
+                    AnomalyKind::OneSidedBook,
+                    format!("bid={:?} ask={:?}", bid, ask),
+                ))
+            }
+        };
+        if stats.spreads.len() < self.config.min_samples {
+            return None;
+        }
+
+        let spread = ask - bid;
+        if let Some(median_spread) = median(&stats.spreads) {
+            if spread >= self.config.min_blowout_spread
+                && spread > median_spread * self.config.spread_multiple
+            {
+                return Some((
+                    AnomalyKind::SpreadBlowout,
+                    format!("spread {} vs median {}", spread, median_spread),
+                ));
+            }
+        }
+        let depth = bid_size + ask_size;
+        if let Some(median_depth) = median(&stats.depths) {
+            if depth < median_depth * self.config.depth_collapse_ratio {
+                return Some((
+                    AnomalyKind::DepthCollapse,
+                    format!("depth {} vs median {}", depth, median_depth),
+                ));
+            }
+        }
+        None
+    }
+
+    /// Feed one quote; updates the registry and returns the anomaly in force
+    /// for this update, if any. Anomalous quotes are kept out of the rolling
+    /// medians so a long blowout cannot become the new baseline.
+    pub fn observe(&mut self, token_id: &str, quote: &Quote) -> Option<AnomalyKind> {
+        let mut stats = self.books.remove(token_id).unwrap_or_default();
+        let verdict = self.classify(&stats, quote);
+
+        match &verdict {
+            Some((kind, detail)) => {
+                stats.clean_streak = 0;
+                if self.registry.mark(token_id, *kind, detail.clone()) {
+                    warn!(token_id, kind = %kind, detail = %detail, "market marked untradable");
+                }
+            }
+            None => {
+                if let (Some(bid), Some(ask)) = (quote.best_bid, quote.best_ask) {
+                    let depth =
+                        quote.bid_size.unwrap_or_default() + quote.ask_size.unwrap_or_default();
+                    stats.push(ask - bid, depth, self.config.window);
+                }
+                stats.clean_streak = stats.clean_streak.saturating_add(1);
+                if stats.clean_streak >= self.config.recovery_updates {
+                    if let Some(cleared) = self.registry.clear(token_id) {
+                        info!(
+                            token_id,
+                            kind = %cleared.kind,
+                            since = %cleared.since,
+                            "market tradable again"
+                        );
+                    }
+                }
+            }
+        }
+
+        self.books.insert(token_id.to_string(), stats);
+        verdict.map(|(kind, _)| kind)
+    }
+
+    /// Consume quote updates until shutdown or the feed closes.
+    /// Flags raised by this detector are cleared on exit.
+    pub async fn run(
+        mut self,
+        mut updates: broadcast::Receiver<QuoteUpdate>,
+        mut shutdown_rx: broadcast::Receiver<()>,
+    ) {
+        info!(
+            spread_multiple = %self.config.spread_multiple,
+            depth_collapse_ratio = %self.config.depth_collapse_ratio,
+            "market anomaly detector started"
+        );
+        loop {
+            let update = tokio::select! {
+                update = updates.recv() => update,
+                _ = shutdown_rx.recv() => break,
+            };
+            match update {
+                Ok(update) => {
+                    self.observe(&update.token_id, &update.quote);
+                }
+                Err(broadcast::error::RecvError::Lagged(skipped)) => {
+                    warn!(skipped, "market anomaly detector lagging behind quote feed");
+                }
+                Err(broadcast::error::RecvError::Closed) => break,
+            }
+        }
+        for token_id in self.books.keys() {
+            self.registry.clear(token_id);
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::domain::Side;
+    use rust_decimal_macros::dec;
+
+    fn quote(bid: Option<Decimal>, ask: Option<Decimal>, size: Decimal) -> Quote {
+        Quote {
+            side: Side::Up,
+            best_bid: bid,
+            best_ask: ask,
+            bid_size: bid.map(|_| size),
+            ask_size: ask.map(|_| size),
+            timestamp: Utc::now(),
+        }
+    }
+
+    fn warmed_up(registry: &MarketAnomalies) -> MarketAnomalyDetector {
+        let config = MarketAnomalyConfig {
+            min_samples: 5,
+            recovery_updates: 3,
+            ..Default::default()
+        };
+        let mut detector = MarketAnomalyDetector::new(config, registry.clone());
+        for _ in 0..10 {
+            let clean = quote(Some(dec!(0.49)), Some(dec!(0.51)), dec!(100));
+            assert_eq!(detector.observe("tok", &clean), None);
+        }
+        detector
+    }
+
+    #[test]
+    fn test_flags_spread_blowout_and_depth_collapse() {
+        let registry = MarketAnomalies::new();
+        let mut detector = warmed_up(&registry);
+
+        let wide = quote(Some(dec!(0.40)), Some(dec!(0.60)), dec!(100));
+        assert_eq!(
+            detector.observe("tok", &wide),
+            Some(AnomalyKind::SpreadBlowout)
+        );
+        assert!(registry.is_untradable("tok"));
+        assert!(!registry.is_untradable("other"));
+
+        let thin = quote(Some(dec!(0.49)), Some(dec!(0.51)), dec!(10));
+        assert_eq!(
+            detector.observe("tok", &thin),
+            Some(AnomalyKind::DepthCollapse)
+        );
+        assert_eq!(
+            registry.get("tok").map(|a| a.kind),
+            Some(AnomalyKind::DepthCollapse)
+        );
+
+        // Cleared only after `recovery_updates` clean quotes
+        let clean = quote(Some(dec!(0.49)), Some(dec!(0.51)), dec!(100));
+        detector.observe("tok", &clean);
+        detector.observe("tok", &clean);
+        assert!(registry.is_untradable("tok"));
+        detector.observe("tok", &clean);
+        assert!(!registry.is_untradable("tok"));
+    }
+
+    #[test]
+    fn test_one_sided_book_flags_without_history() {
+        let registry = MarketAnomalies::new();
+        let mut detector =
+            MarketAnomalyDetector::new(MarketAnomalyConfig::default(), registry.clone());
+
+        let bid_only = quote(Some(dec!(0.30)), None, dec!(50));
+        assert_eq!(
+            detector.observe("tok", &bid_only),
+            Some(AnomalyKind::OneSidedBook)
+        );
+        assert_eq!(registry.snapshot().len(), 1);
+
+        // A blowout must not leak into the baseline either
+        let registry = MarketAnomalies::new();
+        let mut detector = warmed_up(&registry);
+        let wide = quote(Some(dec!(0.30)), Some(dec!(0.70)), dec!(100));
+        for _ in 0..20 {
+            assert_eq!(
+                detector.observe("tok", &wide),
+                Some(AnomalyKind::SpreadBlowout)
+            );
+        }
+    }
+}
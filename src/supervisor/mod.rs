@@ -2,13 +2,37 @@
 //!
 //! This module provides automated supervision infrastructure:
 //! - Watchdog for heartbeat monitoring and auto-restart
-//! - Alert manager for Feishu integration
+//! - Alert manager for Feishu / Discord integration
 //! - Playbook for recovery actions
+//! - Resource monitor for host-pressure throttling
+//! - Venue monitor for exchange / chain health de-risking
+//! - Event calendar for size / threshold adjustments around scheduled events
+//! - Market anomaly detector for per-token untradable flags
+//! - Performance monitor for live-vs-backtest strategy decay
 
 pub mod alert_manager;
+pub mod event_calendar;
+pub mod market_anomaly;
+pub mod performance_monitor;
 pub mod playbook;
+pub mod resource_monitor;
+pub mod venue_monitor;
 pub mod watchdog;
 
-pub use alert_manager::{AlertLevel, AlertManager, AlertManagerConfig};
+pub use alert_manager::{AlertChannel, AlertLevel, AlertManager, AlertManagerConfig};
+pub use event_calendar::{
+    CalendarAdjustment, CalendarEvent, CalendarEventKind, EventCalendar, EventCalendarConfig,
+    EventCalendarService, EventImpact,
+};
+pub use market_anomaly::{
+    AnomalyKind, MarketAnomalies, MarketAnomaly, MarketAnomalyConfig, MarketAnomalyDetector,
+};
+pub use performance_monitor::{PerformanceMonitor, PerformanceMonitorConfig, StrategyExpectation};
 pub use playbook::{RecoveryAction, RecoveryPlaybook};
+pub use resource_monitor::{
+    PressureLevel, QuoteThrottle, ResourceMonitor, ResourceMonitorConfig, ResourceSample,
+};
+pub use venue_monitor::{
+    VenueAction, VenueHealth, VenueHealthSnapshot, VenueMonitor, VenueMonitorConfig, VenueStatus,
+};
 pub use watchdog::{ComponentHealth, Watchdog, WatchdogConfig};
@@ -0,0 +1,535 @@
+//! Performance Monitor for Strategy Decay
+//!
+//! Compares each strategy's live record against the expectation from its
+//! latest backtest evaluation (`strategy_evaluations`, stage BACKTEST) and
+//! alerts when live EV per trade or fill rate falls below it by more than
+//! `z_threshold` standard errors. Optionally pauses the strategy's agents
+//! until an operator resumes them (typically after a parameter refresh).
+
+use chrono::{Duration as ChronoDuration, Utc};
+use rust_decimal::prelude::ToPrimitive;
+use rust_decimal::Decimal;
+use serde::{Deserialize, Serialize};
+use sqlx::PgPool;
+use std::collections::{BTreeMap, HashMap, HashSet};
+use std::sync::Arc;
+use std::time::Duration;
+use tokio::sync::broadcast;
+use tracing::{debug, info, warn};
+
+use crate::coordinator::CoordinatorHandle;
+use crate::error::Result;
+use crate::supervisor::alert_manager::Alert;
+use crate::supervisor::{AlertLevel, AlertManager};
+
+/// Configuration for the performance monitor
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(default)]
+pub struct PerformanceMonitorConfig {
+    /// Enable the monitor (default: false)
+    pub enabled: bool,
+    /// Interval between evaluations (default: 15m)
+    pub poll_interval_secs: u64,
+    /// Live window compared against the backtest (default: 7 days)
+    pub lookback_days: i64,
+    /// Closed trades (EV) / entry orders (fill rate) required before a
+    /// metric is judged (default: 30)
+    pub min_live_samples: u64,
+    /// Standard errors below expectation that count as decay (default: 3.0)
+    pub z_threshold: f64,
+    /// Pause the strategy's agents on decay (default: false, alert only)
+    pub auto_pause: bool,
+    /// Include dry-run executions in the live record (default: false)
+    pub include_dry_run: bool,
+    /// Explicit expectations by strategy id; override backtest evaluations
+    pub expectations: HashMap<String, StrategyExpectation>,
+}
+
+impl Default for PerformanceMonitorConfig {
+    fn default() -> Self {
+        Self {
+            enabled: false,
+            poll_interval_secs: 900,
+            lookback_days: 7,
+            min_live_samples: 30,
+            z_threshold: 3.0,
+            auto_pause: false,
+            include_dry_run: false,
+            expectations: HashMap::new(),
+        }
+    }
+}
+
+/// What the backtest says a strategy should do
+#[derive(Debug, Clone, Copy, PartialEq, Serialize, Deserialize)]
+pub struct StrategyExpectation {
+    /// Mean PnL per closed trade (USD)
+    pub ev_per_trade: f64,
+    /// Share of entry orders that fill
+    #[serde(default)]
+    pub fill_rate: Option<f64>,
+}
+
+/// One row from `agent_order_executions`
+#[derive(Debug, Clone)]
+pub struct LiveExecution {
+    pub strategy: String,
+    pub agent_id: String,
+    pub token_id: String,
+    pub is_buy: bool,
+    pub filled_shares: Decimal,
+    pub price: Option<Decimal>,
+}
+
+/// Live record of one strategy over the lookback window
+#[derive(Debug, Clone, Default, PartialEq, Serialize)]
+pub struct LiveMetrics {
+    /// PnL of each closed position (sold out or settled)
+    pub trade_pnls: Vec<f64>,
+    pub entry_orders: u64,
+    pub filled_entries: u64,
+    pub agent_ids: Vec<String>,
+}
+
+impl LiveMetrics {
+    fn mean_and_std_err(&self) -> Option<(f64, f64)> {
+        let n = self.trade_pnls.len();
+        if n < 2 {
+            return None;
+        }
+        let mean = self.trade_pnls.iter().sum::<f64>() / n as f64;
+        let var = self
+            .trade_pnls
+            .iter()
+            .map(|p| (p - mean).powi(2))
+            .sum::<f64>()
+            / (n - 1) as f64;
+        Some((mean, (var / n as f64).sqrt()))
+    }
+
+    pub fn fill_rate(&self) -> Option<f64> {
+        (self.entry_orders > 0).then(|| self.filled_entries as f64 / self.entry_orders as f64)
+    }
+}
+
+/// A live metric significantly worse than its expectation
+#[derive(Debug, Clone, PartialEq, Serialize)]
+pub struct Deviation {
+    pub metric: &'static str,
+    pub expected: f64,
+    pub live: f64,
+    pub z_score: f64,
+    pub samples: u64,
+}
+
+/// Group executions into closed positions per strategy. A position closes
+/// when it is sold out or its token settles; still-open ones are ignored.
+pub fn build_live_metrics(
+    executions: &[LiveExecution],
+    settlements: &HashMap<String, Decimal>,
+) -> BTreeMap<String, LiveMetrics> {
+    #[derive(Default)]
+    struct Position {
+        shares: Decimal,
+        cash: Decimal,
+    }
+
+    let mut metrics: BTreeMap<String, LiveMetrics> = BTreeMap::new();
+    let mut positions: BTreeMap<(String, String), Position> = BTreeMap::new();
+
+    for exec in executions {
+        let entry = metrics.entry(exec.strategy.clone()).or_default();
+        if !entry.agent_ids.contains(&exec.agent_id) {
+            entry.agent_ids.push(exec.agent_id.clone());
+        }
+        if exec.is_buy {
+            entry.entry_orders += 1;
+        }
+        let Some(price) = exec.price.filter(|_| exec.filled_shares > Decimal::ZERO) else {
+            continue;
+        };
+
+        let key = (exec.strategy.clone(), exec.token_id.clone());
+        let position = positions.entry(key.clone()).or_default();
+        if exec.is_buy {
+            entry.filled_entries += 1;
+            position.shares += exec.filled_shares;
+            position.cash -= exec.filled_shares * price;
+        } else if position.shares > Decimal::ZERO {
+            let sold = exec.filled_shares.min(position.shares);
+            position.shares -= sold;
+            position.cash += sold * price;
+            if position.shares.is_zero() {
+                entry.trade_pnls.push(position.cash.to_f64().unwrap_or(0.0));
+                positions.remove(&key);
+            }
+        }
+    }
+
+    for ((strategy, token_id), position) in positions {
+        let Some(payout) = settlements.get(&token_id) else {
+            continue;
+        };
+        let pnl = position.cash + position.shares * payout;
+        if let Some(entry) = metrics.get_mut(&strategy) {
+            entry.trade_pnls.push(pnl.to_f64().unwrap_or(0.0));
+        }
+    }
+    metrics
+}
+
+impl PerformanceMonitorConfig {
+    /// Metrics whose live value sits more than `z_threshold` standard errors
+    /// below the expectation
+    pub fn deviations(&self, expected: &StrategyExpectation, live: &LiveMetrics) -> Vec<Deviation> {
+        let mut out = Vec::new();
+
+        let trades = live.trade_pnls.len() as u64;
+        if trades >= self.min_live_samples {
+            if let Some((mean, std_err)) = live.mean_and_std_err() {
+                let z = if std_err > 0.0 {
+                    (mean - expected.ev_per_trade) / std_err
+                } else if mean < expected.ev_per_trade {
+                    f64::NEG_INFINITY
+                } else {
+                    0.0
+                };
+                if z < -self.z_threshold {
+                    out.push(Deviation {
+                        metric: "ev_per_trade",
+                        expected: expected.ev_per_trade,
+                        live: mean,
+                        z_score: z,
+                        samples: trades,
+                    });
+                }
+            }
+        }
+
+        if let (Some(p0), Some(p)) = (expected.fill_rate, live.fill_rate()) {
+            let n = live.entry_orders;
+            if n >= self.min_live_samples && p0 > 0.0 && p0 < 1.0 {
+                let z = (p - p0) / (p0 * (1.0 - p0) / n as f64).sqrt();
+                if z < -self.z_threshold {
+                    out.push(Deviation {
+                        metric: "fill_rate",
+                        expected: p0,
+                        live: p,
+                        z_score: z,
+                        samples: n,
+                    });
+                }
+            }
+        }
+        out
+    }
+}
+
+/// Performance monitor daemon
+pub struct PerformanceMonitor {
+    config: PerformanceMonitorConfig,
+    pool: PgPool,
+    account_id: String,
+    alerts: Option<Arc<AlertManager>>,
+}
+
+impl PerformanceMonitor {
+    pub fn new(config: PerformanceMonitorConfig, pool: PgPool, account_id: &str) -> Self {
+        Self {
+            config,
+            pool,
+            account_id: account_id.to_string(),
+            alerts: None,
+        }
+    }
+
+    pub fn with_alerts(mut self, alerts: Arc<AlertManager>) -> Self {
+        self.alerts = Some(alerts);
+        self
+    }
+
+    /// Run the evaluation loop until shutdown.
+    ///
+    /// A decaying strategy is alerted (and paused, with `auto_pause`) once;
+    /// it is re-armed after an evaluation finds it back within bounds.
+    pub async fn run(self, handle: CoordinatorHandle, mut shutdown_rx: broadcast::Receiver<()>) {
+        let mut flagged: HashSet<String> = HashSet::new();
+        let mut interval =
+            tokio::time::interval(Duration::from_secs(self.config.poll_interval_secs.max(60)));
+
+        info!(
+            interval_secs = self.config.poll_interval_secs,
+            lookback_days = self.config.lookback_days,
+            z_threshold = self.config.z_threshold,
+            auto_pause = self.config.auto_pause,
+            "performance monitor started"
+        );
+
+        loop {
+            tokio::select! {
+                _ = interval.tick() => {}
+                _ = shutdown_rx.recv() => break,
+            }
+
+            let (expectations, live) = match self.load().await {
+                Ok(loaded) => loaded,
+                Err(e) => {
+                    warn!(error = %e, "performance monitor: failed to load metrics");
+                    continue;
+                }
+            };
+
+            for (strategy, metrics) in &live {
+                let Some(expected) = expectations.get(strategy) else {
+                    continue;
+                };
+                let deviations = self.config.deviations(expected, metrics);
+                debug!(strategy = %strategy, ?expected, trades = metrics.trade_pnls.len(),
+                    fill_rate = ?metrics.fill_rate(), ?deviations, "performance check");
+
+                if deviations.is_empty() {
+                    if flagged.remove(strategy) {
+                        info!(strategy = %strategy, "live performance back within backtest bounds");
+                    }
+                    continue;
+                }
+                if !flagged.insert(strategy.clone()) {
+                    continue;
+                }
+                self.raise(&handle, strategy, metrics, &deviations).await;
+            }
+        }
+
+        info!("performance monitor stopped");
+    }
+
+    async fn raise(
+        &self,
+        handle: &CoordinatorHandle,
+        strategy: &str,
+        metrics: &LiveMetrics,
+        deviations: &[Deviation],
+    ) {
+        let detail = deviations
+            .iter()
+            .map(|d| {
+                format!(
+                    "{} live {:.4} vs expected {:.4} (z {:.1}, n {})",
+                    d.metric, d.live, d.expected, d.z_score, d.samples
+                )
+            })
+            .collect::<Vec<_>>()
+            .join("; ");
+        warn!(strategy, detail = %detail, "strategy performance decayed versus backtest");
+
+        let mut paused = Vec::new();
+        if self.config.auto_pause {
+            for agent_id in &metrics.agent_ids {
+                match handle.pause_agent(agent_id).await {
+                    Ok(()) => paused.push(agent_id.clone()),
+                    Err(e) => {
+                        warn!(agent_id = %agent_id, error = %e, "failed to pause decayed strategy")
+                    }
+                }
+            }
+        }
+
+        if let Some(alerts) = &self.alerts {
+            let mut message = detail;
+            if !paused.is_empty() {
+                message.push_str(&format!("\npaused agents: {}", paused.join(", ")));
+            }
+            alerts
+                .alert(
+                    Alert::new(
+                        AlertLevel::Warning,
+                        "performance_monitor",
+                        &format!("Strategy {strategy} decayed versus backtest"),
+                        &message,
+                    )
+                    .with_metadata(serde_json::json!({
+                        "strategy": strategy,
+                        "deviations": deviations,
+                        "paused_agents": paused,
+                    })),
+                )
+                .await;
+        }
+    }
+
+    async fn load(
+        &self,
+    ) -> Result<(
+        HashMap<String, StrategyExpectation>,
+        BTreeMap<String, LiveMetrics>,
+    )> {
+        let mut expectations: HashMap<String, StrategyExpectation> =
+            sqlx::query_as::<_, (String, i64, Decimal, Option<String>)>(
+                r#"
+                SELECT DISTINCT ON (strategy_id)
+                       strategy_id, sample_size, pnl_usd,
+                       COALESCE(evidence_payload->'metrics'->>'fill_rate',
+                                evidence_payload->>'fill_rate',
+                                metadata->>'fill_rate')
+                FROM strategy_evaluations
+                WHERE stage = 'BACKTEST'
+                  AND account_id = $1
+                  AND sample_size > 0
+                  AND pnl_usd IS NOT NULL
+                ORDER BY strategy_id, evaluated_at DESC
+                "#,
+            )
+            .bind(&self.account_id)
+            .fetch_all(&self.pool)
+            .await?
+            .into_iter()
+            .map(|(strategy, sample_size, pnl_usd, fill_rate)| {
+                let expectation = StrategyExpectation {
+                    ev_per_trade: (pnl_usd / Decimal::from(sample_size))
+                        .to_f64()
+                        .unwrap_or(0.0),
+                    fill_rate: fill_rate.and_then(|v| v.trim().parse().ok()),
+                };
+                (strategy, expectation)
+            })
+            .collect();
+        expectations.extend(self.config.expectations.clone());
+
+        let since = Utc::now() - ChronoDuration::days(self.config.lookback_days.max(1));
+        let executions: Vec<LiveExecution> =
+            sqlx::query_as::<_, (String, String, String, bool, Decimal, Option<Decimal>)>(
+                r#"
+            SELECT COALESCE(NULLIF(metadata->>'strategy', ''), agent_id),
+                   agent_id, token_id, is_buy, filled_shares::numeric, avg_fill_price
+            FROM agent_order_executions
+            WHERE executed_at >= $1
+              AND account_id = $2
+              AND ($3 OR dry_run = FALSE)
+            ORDER BY executed_at ASC, id ASC
+            "#,
+            )
+            .bind(since)
+            .bind(&self.account_id)
+            .bind(self.config.include_dry_run)
+            .fetch_all(&self.pool)
+            .await?
+            .into_iter()
+            .map(
+                |(strategy, agent_id, token_id, is_buy, filled_shares, price)| LiveExecution {
+                    strategy,
+                    agent_id,
+                    token_id,
+                    is_buy,
+                    filled_shares,
+                    price,
+                },
+            )
+            .collect();
+
+        let tokens: Vec<String> = executions
+            .iter()
+            .map(|e| e.token_id.clone())
+            .collect::<HashSet<_>>()
+            .into_iter()
+            .collect();
+        let settlements: HashMap<String, Decimal> = sqlx::query_as::<_, (String, Decimal)>(
+            r#"
+            SELECT token_id, settled_price
+            FROM pm_token_settlements
+            WHERE resolved = TRUE AND settled_price IS NOT NULL AND token_id = ANY($1)
+            "#,
+        )
+        .bind(&tokens)
+        .fetch_all(&self.pool)
+        .await?
+        .into_iter()
+        .collect();
+
+        Ok((expectations, build_live_metrics(&executions, &settlements)))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use rust_decimal_macros::dec;
+
+    fn exec(token: &str, is_buy: bool, shares: Decimal, price: Decimal) -> LiveExecution {
+        LiveExecution {
+            strategy: "momentum".to_string(),
+            agent_id: "crypto".to_string(),
+            token_id: token.to_string(),
+            is_buy,
+            filled_shares: shares,
+            price: (shares > Decimal::ZERO).then_some(price),
+        }
+    }
+
+    #[test]
+    fn test_live_metrics_close_positions_by_sale_or_settlement() {
+        let executions = vec![
+            exec("a", true, dec!(10), dec!(0.40)),
+            exec("a", false, dec!(10), dec!(0.55)),
+            exec("b", true, dec!(10), dec!(0.60)),
+            exec("c", true, Decimal::ZERO, dec!(0.50)),
+            exec("d", true, dec!(5), dec!(0.50)),
+        ];
+        let settlements = HashMap::from([("b".to_string(), Decimal::ZERO)]);
+
+        let metrics = build_live_metrics(&executions, &settlements);
+        let live = &metrics["momentum"];
+        // a: sold for +1.5, b: settled worthless for -6, d: still open
+        assert_eq!(live.trade_pnls, vec![1.5, -6.0]);
+        assert_eq!(live.entry_orders, 4);
+        assert_eq!(live.filled_entries, 3);
+        assert_eq!(live.agent_ids, vec!["crypto".to_string()]);
+    }
+
+    #[test]
+    fn test_deviations_need_samples_and_significance() {
+        let config = PerformanceMonitorConfig {
+            min_live_samples: 20,
+            ..Default::default()
+        };
+        let expected = StrategyExpectation {
+            ev_per_trade: 0.5,
+            fill_rate: Some(0.8),
+        };
+
+        // Noisy but centred on expectation: no alert.
+        let healthy = LiveMetrics {
+            trade_pnls: (0..40)
+                .map(|i| if i % 2 == 0 { 3.0 } else { -2.0 })
+                .collect(),
+            entry_orders: 40,
+            filled_entries: 31,
+            agent_ids: vec![],
+        };
+        assert!(config.deviations(&expected, &healthy).is_empty());
+
+        // Losing on average and filling half as often.
+        let decayed = LiveMetrics {
+            trade_pnls: (0..40)
+                .map(|i| if i % 4 == 0 { 1.0 } else { -1.0 })
+                .collect(),
+            entry_orders: 40,
+            filled_entries: 16,
+            agent_ids: vec![],
+        };
+        let metrics: Vec<_> = config
+            .deviations(&expected, &decayed)
+            .into_iter()
+            .map(|d| d.metric)
+            .collect();
+        assert_eq!(metrics, vec!["ev_per_trade", "fill_rate"]);
+
+        // Too few samples to judge.
+        let sparse = LiveMetrics {
+            trade_pnls: decayed.trade_pnls[..10].to_vec(),
+            entry_orders: 10,
+            filled_entries: 4,
+            agent_ids: vec![],
+        };
+        assert!(config.deviations(&expected, &sparse).is_empty());
+    }
+}
@@ -2,6 +2,7 @@
 //!
 //! Defines recovery actions and sequences for handling various failure scenarios.
 
+use std::time::Duration;
 use tracing::{debug, info, warn};
 
 /// Recovery actions that can be taken
@@ -87,6 +88,12 @@ pub struct RecoveryPlaybook {
     pub stale_escalation_secs: u64,
     /// WebSocket reconnect timeout before alerting (seconds)
     pub ws_reconnect_timeout_secs: u64,
+    /// Delay before the first restart of a crashed component (seconds)
+    pub restart_backoff_base_secs: u64,
+    /// Upper bound for the exponential restart delay (seconds)
+    pub restart_backoff_max_secs: u64,
+    /// Restarts older than this no longer count towards `max_restart_attempts` (seconds)
+    pub restart_window_secs: u64,
 }
 
 impl Default for RecoveryPlaybook {
@@ -95,6 +102,9 @@ impl Default for RecoveryPlaybook {
             max_restart_attempts: 3,
             stale_escalation_secs: 120,
             ws_reconnect_timeout_secs: 60,
+            restart_backoff_base_secs: 2,
+            restart_backoff_max_secs: 120,
+            restart_window_secs: 600,
         }
     }
 }
@@ -105,6 +115,22 @@ impl RecoveryPlaybook {
         Self::default()
     }
 
+    /// Delay before restart number `attempt` (1-based, within `restart_window_secs`).
+    ///
+    /// Doubles per attempt up to `restart_backoff_max_secs`; `None` once
+    /// `max_restart_attempts` is exhausted.
+    pub fn restart_backoff(&self, attempt: u32) -> Option<Duration> {
+        if attempt == 0 || attempt > self.max_restart_attempts {
+            return None;
+        }
+        let factor = 1u64 << (attempt - 1).min(16);
+        let secs = self
+            .restart_backoff_base_secs
+            .saturating_mul(factor)
+            .min(self.restart_backoff_max_secs);
+        Some(Duration::from_secs(secs))
+    }
+
     /// Determine recovery actions for a failure scenario
     pub fn get_actions(&self, scenario: &FailureScenario) -> Vec<RecoveryAction> {
         match scenario {
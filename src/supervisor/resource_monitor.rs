@@ -0,0 +1,588 @@
+//! Resource Monitor for Host Pressure Throttling
+//!
+//! Samples CPU, memory and file-descriptor usage from `/proc` and degrades the
+//! runtime gracefully before the kernel OOM-kills it: quote processing is
+//! subsampled under elevated pressure, and non-critical agents are paused under
+//! critical pressure. Everything recovers automatically once the host calms down.
+
+use serde::{Deserialize, Serialize};
+use std::collections::HashSet;
+use std::sync::atomic::{AtomicU32, AtomicU64, Ordering};
+use std::sync::Arc;
+use std::time::Duration;
+use tokio::sync::{broadcast, watch};
+use tracing::{debug, info, warn};
+
+use crate::coordinator::CoordinatorHandle;
+use crate::platform::AgentStatus;
+
+/// Configuration for the resource monitor
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(default)]
+pub struct ResourceMonitorConfig {
+    /// Enable the monitor (default: false)
+    pub enabled: bool,
+    /// Interval between samples (default: 5s)
+    pub sample_interval_secs: u64,
+    /// Host memory usage that triggers elevated pressure (default: 75%)
+    pub memory_elevated_pct: f64,
+    /// Host memory usage that triggers critical pressure (default: 90%)
+    pub memory_critical_pct: f64,
+    /// Host CPU usage that triggers elevated pressure (default: 80%)
+    pub cpu_elevated_pct: f64,
+    /// Host CPU usage that triggers critical pressure (default: 95%)
+    pub cpu_critical_pct: f64,
+    /// Open FDs as a share of the soft limit for elevated pressure (default: 70%)
+    pub fd_elevated_pct: f64,
+    /// Open FDs as a share of the soft limit for critical pressure (default: 90%)
+    pub fd_critical_pct: f64,
+    /// Consecutive calmer samples required before stepping down a level (default: 3)
+    pub recovery_samples: u32,
+    /// Process 1 of every N quotes under elevated pressure (default: 2)
+    pub elevated_quote_stride: u32,
+    /// Process 1 of every N quotes under critical pressure (default: 5)
+    pub critical_quote_stride: u32,
+    /// Agents that are never paused under critical pressure
+    pub critical_agents: Vec<String>,
+}
+
+impl Default for ResourceMonitorConfig {
+    fn default() -> Self {
+        Self {
+            enabled: false,
+            sample_interval_secs: 5,
+            memory_elevated_pct: 75.0,
+            memory_critical_pct: 90.0,
+            cpu_elevated_pct: 80.0,
+            cpu_critical_pct: 95.0,
+            fd_elevated_pct: 70.0,
+            fd_critical_pct: 90.0,
+            recovery_samples: 3,
+            elevated_quote_stride: 2,
+            critical_quote_stride: 5,
+            critical_agents: Vec::new(),
+        }
+    }
+}
+
+impl ResourceMonitorConfig {
+    /// Quote stride applied at a pressure level (1 = process every quote)
+    pub fn quote_stride(&self, level: PressureLevel) -> u32 {
+        match level {
+            PressureLevel::Normal => 1,
+            PressureLevel::Elevated => self.elevated_quote_stride.max(1),
+            PressureLevel::Critical => self.critical_quote_stride.max(1),
+        }
+    }
+
+    /// Pressure level implied by a single sample, ignoring hysteresis
+    pub fn classify(&self, sample: &ResourceSample) -> PressureLevel {
+        let level_for = |value: Option<f64>, elevated: f64, critical: f64| match value {
+            Some(v) if v >= critical => PressureLevel::Critical,
+            Some(v) if v >= elevated => PressureLevel::Elevated,
+            _ => PressureLevel::Normal,
+        };
+        [
+            level_for(
+                sample.memory_used_pct(),
+                self.memory_elevated_pct,
+                self.memory_critical_pct,
+            ),
+            level_for(sample.cpu_pct, self.cpu_elevated_pct, self.cpu_critical_pct),
+            level_for(
+                sample.fd_used_pct(),
+                self.fd_elevated_pct,
+                self.fd_critical_pct,
+            ),
+        ]
+        .into_iter()
+        .max()
+        .unwrap_or(PressureLevel::Normal)
+    }
+}
+
+/// Host pressure level
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum PressureLevel {
+    /// Plenty of headroom
+    Normal,
+    /// Quote processing is subsampled
+    Elevated,
+    /// Quote processing is subsampled and non-critical agents are paused
+    Critical,
+}
+
+impl std::fmt::Display for PressureLevel {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            PressureLevel::Normal => write!(f, "normal"),
+            PressureLevel::Elevated => write!(f, "elevated"),
+            PressureLevel::Critical => write!(f, "critical"),
+        }
+    }
+}
+
+/// One resource sample
+#[derive(Debug, Clone, Default)]
+pub struct ResourceSample {
+    pub mem_total_kb: u64,
+    pub mem_available_kb: u64,
+    /// Resident set size of this process
+    pub process_rss_kb: u64,
+    /// Host CPU usage since the previous sample (None on the first sample)
+    pub cpu_pct: Option<f64>,
+    pub open_fds: u64,
+    /// Soft `RLIMIT_NOFILE` (None when unlimited or unreadable)
+    pub fd_limit: Option<u64>,
+}
+
+impl ResourceSample {
+    pub fn memory_used_pct(&self) -> Option<f64> {
+        if self.mem_total_kb == 0 {
+            return None;
+        }
+        let used = self.mem_total_kb.saturating_sub(self.mem_available_kb);
+        Some(used as f64 / self.mem_total_kb as f64 * 100.0)
+    }
+
+    pub fn fd_used_pct(&self) -> Option<f64> {
+        match self.fd_limit {
+            Some(limit) if limit > 0 => Some(self.open_fds as f64 / limit as f64 * 100.0),
+            _ => None,
+        }
+    }
+}
+
+/// Cumulative host CPU counters from the aggregate `cpu` line of `/proc/stat`
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct CpuTimes {
+    pub busy: u64,
+    pub total: u64,
+}
+
+impl CpuTimes {
+    /// Busy share between two readings, in percent
+    pub fn usage_since(&self, prev: &CpuTimes) -> Option<f64> {
+        let total = self.total.checked_sub(prev.total)?;
+        if total == 0 {
+            return None;
+        }
+        let busy = self.busy.saturating_sub(prev.busy);
+        Some((busy as f64 / total as f64 * 100.0).min(100.0))
+    }
+}
+
+/// Parse the aggregate `cpu` line of `/proc/stat`.
+pub fn parse_proc_stat(raw: &str) -> Option<CpuTimes> {
+    let line = raw.lines().find(|l| l.starts_with("cpu "))?;
+    let fields: Vec<u64> = line
+        .split_whitespace()
+        .skip(1)
+        .filter_map(|v| v.parse().ok())
+        .collect();
+    if fields.len() < 4 {
+        return None;
+    }
+    // user nice system idle iowait irq softirq steal ...; guest time is
+    // already included in user/nice so only the first eight are summed.
+    let total: u64 = fields.iter().take(8).sum();
+    let idle = fields[3] + fields.get(4).copied().unwrap_or(0);
+    Some(CpuTimes {
+        busy: total.saturating_sub(idle),
+        total,
+    })
+}
+
+fn kb_field(raw: &str, key: &str) -> Option<u64> {
+    raw.lines()
+        .find(|l| l.starts_with(key))?
+        .split_whitespace()
+        .nth(1)?
+        .parse()
+        .ok()
+}
+
+/// Parse `MemTotal` / `MemAvailable` (kB) from `/proc/meminfo`.
+pub fn parse_meminfo(raw: &str) -> Option<(u64, u64)> {
+    Some((kb_field(raw, "MemTotal:")?, kb_field(raw, "MemAvailable:")?))
+}
+
+/// Parse `VmRSS` (kB) from `/proc/self/status`.
+pub fn parse_status_rss(raw: &str) -> Option<u64> {
+    kb_field(raw, "VmRSS:")
+}
+
+/// Parse the soft open-files limit from `/proc/self/limits`.
+pub fn parse_fd_limit(raw: &str) -> Option<u64> {
+    let line = raw.lines().find(|l| l.starts_with("Max open files"))?;
+    line["Max open files".len()..]
+        .split_whitespace()
+        .next()?
+        .parse()
+        .ok()
+}
+
+/// Reads samples from `/proc`, keeping the previous CPU reading for deltas.
+#[derive(Debug, Default)]
+pub struct ProcSampler {
+    prev_cpu: Option<CpuTimes>,
+}
+
+impl ProcSampler {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Take a sample. Returns None when `/proc` is unavailable (non-Linux).
+    pub fn sample(&mut self) -> Option<ResourceSample> {
+        let (mem_total_kb, mem_available_kb) =
+            parse_meminfo(&std::fs::read_to_string("/proc/meminfo").ok()?)?;
+
+        let cpu = std::fs::read_to_string("/proc/stat")
+            .ok()
+            .and_then(|raw| parse_proc_stat(&raw));
+        let cpu_pct = match (cpu, self.prev_cpu) {
+            (Some(now), Some(prev)) => now.usage_since(&prev),
+            _ => None,
+        };
+        self.prev_cpu = cpu;
+
+        Some(ResourceSample {
+            mem_total_kb,
+            mem_available_kb,
+            process_rss_kb: std::fs::read_to_string("/proc/self/status")
+                .ok()
+                .and_then(|raw| parse_status_rss(&raw))
+                .unwrap_or(0),
+            cpu_pct,
+            open_fds: std::fs::read_dir("/proc/self/fd")
+                .map(|dir| dir.count() as u64)
+                .unwrap_or(0),
+            fd_limit: std::fs::read_to_string("/proc/self/limits")
+                .ok()
+                .and_then(|raw| parse_fd_limit(&raw)),
+        })
+    }
+}
+
+/// Pressure state machine with hysteresis.
+///
+/// Escalation is immediate; stepping down happens one level at a time after
+/// `recovery_samples` consecutive calmer samples, so a single quiet tick in the
+/// middle of a burst does not flap agents back on.
+#[derive(Debug)]
+pub struct PressureTracker {
+    level: PressureLevel,
+    calm_samples: u32,
+    recovery_samples: u32,
+}
+
+impl PressureTracker {
+    pub fn new(recovery_samples: u32) -> Self {
+        Self {
+            level: PressureLevel::Normal,
+            calm_samples: 0,
+            recovery_samples: recovery_samples.max(1),
+        }
+    }
+
+    pub fn level(&self) -> PressureLevel {
+        self.level
+    }
+
+    /// Feed the level implied by the latest sample; returns the tracked level.
+    pub fn observe(&mut self, observed: PressureLevel) -> PressureLevel {
+        if observed >= self.level {
+            self.level = observed;
+            self.calm_samples = 0;
+            return self.level;
+        }
+
+        self.calm_samples += 1;
+        if self.calm_samples >= self.recovery_samples {
+            self.calm_samples = 0;
+            self.level = match self.level {
+                PressureLevel::Critical => PressureLevel::Elevated,
+                _ => PressureLevel::Normal,
+            };
+        }
+        self.level
+    }
+}
+
+/// Shared quote-processing throttle.
+///
+/// Agents call [`QuoteThrottle::admit`] for each incoming quote; under pressure
+/// only one in every `stride` quotes is admitted. Clones share one counter;
+/// [`QuoteThrottle::per_agent`] gives each agent its own.
+#[derive(Debug, Clone)]
+pub struct QuoteThrottle {
+    stride: Arc<AtomicU32>,
+    counter: Arc<AtomicU64>,
+}
+
+impl Default for QuoteThrottle {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl QuoteThrottle {
+    pub fn new() -> Self {
+        Self {
+            stride: Arc::new(AtomicU32::new(1)),
+            counter: Arc::new(AtomicU64::new(0)),
+        }
+    }
+
+    /// Same stride, separate counter, so one busy agent's quotes do not
+    /// decide which of another agent's quotes are admitted
+    pub fn per_agent(&self) -> Self {
+        Self {
+            stride: self.stride.clone(),
+            counter: Arc::new(AtomicU64::new(0)),
+        }
+    }
+
+    pub fn stride(&self) -> u32 {
+        self.stride.load(Ordering::Relaxed)
+    }
+
+    pub fn set_stride(&self, stride: u32) {
+        self.stride.store(stride.max(1), Ordering::Relaxed);
+    }
+
+    /// Whether the caller should process this quote
+    pub fn admit(&self) -> bool {
+        let stride = self.stride() as u64;
+        if stride <= 1 {
+            return true;
+        }
+        self.counter.fetch_add(1, Ordering::Relaxed) % stride == 0
+    }
+}
+
+/// Resource monitor daemon
+pub struct ResourceMonitor {
+    config: ResourceMonitorConfig,
+    throttle: QuoteThrottle,
+    level_tx: watch::Sender<PressureLevel>,
+}
+
+impl ResourceMonitor {
+    pub fn new(config: ResourceMonitorConfig, throttle: QuoteThrottle) -> Self {
+        let (level_tx, _) = watch::channel(PressureLevel::Normal);
+        Self {
+            config,
+            throttle,
+            level_tx,
+        }
+    }
+
+    /// Subscribe to pressure level changes
+    pub fn subscribe(&self) -> watch::Receiver<PressureLevel> {
+        self.level_tx.subscribe()
+    }
+
+    /// Run the sampling loop until shutdown.
+    ///
+    /// Agents paused by the monitor are resumed when pressure drops below
+    /// critical and on shutdown; agents paused by anyone else are left alone.
+    pub async fn run(self, handle: CoordinatorHandle, mut shutdown_rx: broadcast::Receiver<()>) {
+        let mut sampler = ProcSampler::new();
+        let mut tracker = PressureTracker::new(self.config.recovery_samples);
+        let mut paused_by_monitor: HashSet<String> = HashSet::new();
+        let mut interval =
+            tokio::time::interval(Duration::from_secs(self.config.sample_interval_secs.max(1)));
+
+        info!(
+            interval_secs = self.config.sample_interval_secs,
+            critical_agents = ?self.config.critical_agents,
+            "resource monitor started"
+        );
+
+        loop {
+            tokio::select! {
+                _ = interval.tick() => {}
+                _ = shutdown_rx.recv() => break,
+            }
+
+            let Some(sample) = sampler.sample() else {
+                warn!("resource monitor: /proc unavailable, stopping");
+                break;
+            };
+            let previous = tracker.level();
+            let level = tracker.observe(self.config.classify(&sample));
+            debug!(
+                level = %level,
+                memory_pct = ?sample.memory_used_pct(),
+                cpu_pct = ?sample.cpu_pct,
+                fd_pct = ?sample.fd_used_pct(),
+                rss_kb = sample.process_rss_kb,
+                "resource sample"
+            );
+
+            if level == previous {
+                continue;
+            }
+
+            let stride = self.config.quote_stride(level);
+            self.throttle.set_stride(stride);
+            let _ = self.level_tx.send(level);
+            if level > previous {
+                warn!(
+                    from = %previous,
+                    to = %level,
+                    quote_stride = stride,
+                    memory_pct = ?sample.memory_used_pct(),
+                    cpu_pct = ?sample.cpu_pct,
+                    fd_pct = ?sample.fd_used_pct(),
+                    "resource pressure rising"
+                );
+            } else {
+                info!(from = %previous, to = %level, quote_stride = stride, "resource pressure easing");
+            }
+
+            if level == PressureLevel::Critical {
+                self.pause_non_critical(&handle, &mut paused_by_monitor)
+                    .await;
+            } else if previous == PressureLevel::Critical {
+                Self::resume_paused(&handle, &mut paused_by_monitor).await;
+            }
+        }
+
+        self.throttle.set_stride(1);
+        Self::resume_paused(&handle, &mut paused_by_monitor).await;
+        info!("resource monitor stopped");
+    }
+
+    async fn pause_non_critical(&self, handle: &CoordinatorHandle, paused: &mut HashSet<String>) {
+        let state = handle.read_state().await;
+        for (agent_id, snapshot) in &state.agents {
+            if snapshot.status != AgentStatus::Running
+                || self.config.critical_agents.contains(agent_id)
+            {
+                continue;
+            }
+            match handle.pause_agent(agent_id).await {
+                Ok(()) => {
+                    warn!(agent_id = %agent_id, "resource monitor paused agent");
+                    paused.insert(agent_id.clone());
+                }
+                Err(e) => warn!(agent_id = %agent_id, error = %e, "failed to pause agent"),
+            }
+        }
+    }
+
+    async fn resume_paused(handle: &CoordinatorHandle, paused: &mut HashSet<String>) {
+        for agent_id in paused.drain() {
+            match handle.resume_agent(&agent_id).await {
+                Ok(()) => info!(agent_id = %agent_id, "resource monitor resumed agent"),
+                Err(e) => warn!(agent_id = %agent_id, error = %e, "failed to resume agent"),
+            }
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_parse_proc_files() {
+        let stat = "cpu  100 0 50 800 50 0 0 0 0 0\ncpu0 50 0 25 400 25 0 0 0 0 0\n";
+        assert_eq!(
+            parse_proc_stat(stat),
+            Some(CpuTimes {
+                busy: 150,
+                total: 1000
+            })
+        );
+
+        let meminfo =
+            "MemTotal:        2000000 kB\nMemFree:  100 kB\nMemAvailable:     500000 kB\n";
+        assert_eq!(parse_meminfo(meminfo), Some((2_000_000, 500_000)));
+
+        let status = "Name:\tploy\nVmRSS:\t  123456 kB\n";
+        assert_eq!(parse_status_rss(status), Some(123_456));
+
+        let limits = "Limit                     Soft Limit           Hard Limit           Units\n\
+                      Max open files            1024                 524288               files\n";
+        assert_eq!(parse_fd_limit(limits), Some(1024));
+    }
+
+    #[test]
+    fn test_classify_takes_worst_metric() {
+        let config = ResourceMonitorConfig::default();
+        let mut sample = ResourceSample {
+            mem_total_kb: 1000,
+            mem_available_kb: 500,
+            cpu_pct: Some(10.0),
+            open_fds: 100,
+            fd_limit: Some(1024),
+            ..Default::default()
+        };
+        assert_eq!(config.classify(&sample), PressureLevel::Normal);
+
+        sample.cpu_pct = Some(85.0);
+        assert_eq!(config.classify(&sample), PressureLevel::Elevated);
+
+        sample.mem_available_kb = 50;
+        assert_eq!(config.classify(&sample), PressureLevel::Critical);
+    }
+
+    #[test]
+    fn test_tracker_escalates_fast_and_recovers_slowly() {
+        let mut tracker = PressureTracker::new(2);
+        assert_eq!(
+            tracker.observe(PressureLevel::Critical),
+            PressureLevel::Critical
+        );
+        assert_eq!(
+            tracker.observe(PressureLevel::Normal),
+            PressureLevel::Critical
+        );
+        // A renewed spike resets the calm streak.
+        assert_eq!(
+            tracker.observe(PressureLevel::Critical),
+            PressureLevel::Critical
+        );
+        assert_eq!(
+            tracker.observe(PressureLevel::Normal),
+            PressureLevel::Critical
+        );
+        assert_eq!(
+            tracker.observe(PressureLevel::Normal),
+            PressureLevel::Elevated
+        );
+        assert_eq!(
+            tracker.observe(PressureLevel::Normal),
+            PressureLevel::Elevated
+        );
+        assert_eq!(
+            tracker.observe(PressureLevel::Normal),
+            PressureLevel::Normal
+        );
+    }
+
+    #[test]
+    fn test_quote_throttle_admits_one_per_stride() {
+        let throttle = QuoteThrottle::new();
+        assert!((0..10).all(|_| throttle.admit()));
+
+        throttle.set_stride(5);
+        let admitted = (0..100).filter(|_| throttle.clone().admit()).count();
+        assert_eq!(admitted, 20);
+
+        // Each agent admits one in every stride of its own quotes
+        let (a, b) = (throttle.per_agent(), throttle.per_agent());
+        let admitted_a = (0..10).filter(|_| a.admit()).count();
+        let admitted_b = (0..10).filter(|_| b.admit()).count();
+        assert_eq!((admitted_a, admitted_b), (2, 2));
+
+        throttle.set_stride(0);
+        assert_eq!(throttle.stride(), 1);
+        assert_eq!(a.stride(), 1);
+    }
+}
@@ -0,0 +1,622 @@
+//! Venue Monitor for Exchange and Chain Health
+//!
+//! Polls the Polymarket status page, Binance system status and Polygon RPC
+//! (gas price, head freshness, reorgs) and de-risks the runtime before an
+//! outage bites: entry thresholds are widened, new entries are paused, or part
+//! of the open book is sold, per a configurable policy for each status level.
+//! Everything is restored once the venues report healthy again.
+
+use chrono::{DateTime, Utc};
+use rust_decimal::Decimal;
+use serde::{Deserialize, Serialize};
+use serde_json::{json, Value};
+use std::collections::BTreeMap;
+use std::sync::{Arc, RwLock};
+use std::time::Duration;
+use tokio::sync::broadcast;
+use tracing::{debug, info, warn};
+
+use crate::coordinator::CoordinatorHandle;
+
+/// Block hashes kept for reorg detection
+const REORG_WINDOW_BLOCKS: u64 = 128;
+
+/// Runtime reaction while the venues are at a given status
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum VenueAction {
+    /// Multiply strategy entry edge thresholds by `widen_multiplier`
+    WidenThresholds,
+    /// Block new BUY intents in the coordinator
+    PauseEntries,
+    /// Sell `derisk_fraction` of every open position, once per escalation
+    Derisk,
+}
+
+/// Configuration for the venue monitor
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(default)]
+pub struct VenueMonitorConfig {
+    /// Enable the monitor (default: false)
+    pub enabled: bool,
+    /// Interval between polls (default: 30s)
+    pub poll_interval_secs: u64,
+    /// HTTP timeout per request (default: 5s)
+    pub request_timeout_ms: u64,
+    /// Statuspage summary (`status.indicator`); empty disables the check
+    pub polymarket_status_url: String,
+    /// Binance `sapi/v1/system/status`; empty disables the check
+    pub binance_status_url: String,
+    /// Polygon JSON-RPC endpoint; empty disables chain checks
+    pub polygon_rpc_url: String,
+    /// Gas price that marks the chain degraded (default: 300 gwei)
+    pub gas_degraded_gwei: f64,
+    /// Gas price that marks the chain down (default: 1000 gwei)
+    pub gas_down_gwei: f64,
+    /// Chain head older than this marks the chain down (default: 60s)
+    pub head_stale_secs: i64,
+    /// Consecutive healthier polls required before stepping down (default: 3)
+    pub recovery_polls: u32,
+    /// Actions while degraded (default: widen thresholds)
+    pub degraded_actions: Vec<VenueAction>,
+    /// Actions while down (default: widen thresholds, pause entries).
+    /// `derisk` is opt-in: during an exchange outage the sells cannot fill.
+    pub down_actions: Vec<VenueAction>,
+    /// Entry edge multiplier while thresholds are widened (default: 1.5)
+    pub widen_multiplier: Decimal,
+    /// Share of each open position sold by `derisk` (default: 0.5)
+    pub derisk_fraction: Decimal,
+}
+
+impl Default for VenueMonitorConfig {
+    fn default() -> Self {
+        Self {
+            enabled: false,
+            poll_interval_secs: 30,
+            request_timeout_ms: 5_000,
+            polymarket_status_url: "https://status.polymarket.com/api/v2/status.json".to_string(),
+            binance_status_url: "https://api.binance.com/sapi/v1/system/status".to_string(),
+            polygon_rpc_url: "https://polygon-bor-rpc.publicnode.com".to_string(),
+            gas_degraded_gwei: 300.0,
+            gas_down_gwei: 1_000.0,
+            head_stale_secs: 60,
+            recovery_polls: 3,
+            degraded_actions: vec![VenueAction::WidenThresholds],
+            down_actions: vec![VenueAction::WidenThresholds, VenueAction::PauseEntries],
+            widen_multiplier: Decimal::new(15, 1),
+            derisk_fraction: Decimal::new(5, 1),
+        }
+    }
+}
+
+impl VenueMonitorConfig {
+    /// Actions configured for a status level
+    pub fn actions_for(&self, status: VenueStatus) -> Vec<VenueAction> {
+        match status {
+            VenueStatus::Operational => Vec::new(),
+            VenueStatus::Degraded => self.degraded_actions.clone(),
+            VenueStatus::Down => self.down_actions.clone(),
+        }
+    }
+
+    /// Chain status from the gas price, head age and a just-detected reorg
+    pub fn classify_chain(
+        &self,
+        gas_gwei: Option<f64>,
+        head_age_secs: i64,
+        reorg_at: Option<u64>,
+    ) -> (VenueStatus, String) {
+        let mut status = VenueStatus::Operational;
+        let mut notes = Vec::new();
+
+        if let Some(gas) = gas_gwei {
+            let level = if gas >= self.gas_down_gwei {
+                VenueStatus::Down
+            } else if gas >= self.gas_degraded_gwei {
+                VenueStatus::Degraded
+            } else {
+                VenueStatus::Operational
+            };
+            status = status.max(level);
+            notes.push(format!("gas {gas:.0} gwei"));
+        }
+        if head_age_secs >= self.head_stale_secs {
+            status = VenueStatus::Down;
+            notes.push(format!("head {head_age_secs}s old"));
+        }
+        if let Some(height) = reorg_at {
+            status = status.max(VenueStatus::Degraded);
+            notes.push(format!("reorg at block {height}"));
+        }
+        (status, notes.join("; "))
+    }
+}
+
+/// Venue health level (worst of all monitored sources)
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, PartialOrd, Ord, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum VenueStatus {
+    #[default]
+    Operational,
+    Degraded,
+    Down,
+}
+
+impl std::fmt::Display for VenueStatus {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            VenueStatus::Operational => write!(f, "operational"),
+            VenueStatus::Degraded => write!(f, "degraded"),
+            VenueStatus::Down => write!(f, "down"),
+        }
+    }
+}
+
+/// Latest reading of one monitored source
+#[derive(Debug, Clone, Serialize)]
+pub struct VenueCheck {
+    /// `polymarket`, `binance` or `polygon`
+    pub source: String,
+    pub status: VenueStatus,
+    pub detail: String,
+    pub checked_at: DateTime<Utc>,
+}
+
+/// Map a Statuspage summary (`{"status": {"indicator": ...}}`).
+///
+/// An unknown indicator is treated as degraded.
+pub fn parse_statuspage(body: &Value) -> Option<(VenueStatus, String)> {
+    let status = body.get("status")?;
+    let indicator = status.get("indicator")?.as_str()?;
+    let description = status
+        .get("description")
+        .and_then(Value::as_str)
+        .unwrap_or(indicator);
+    let level = match indicator {
+        "none" => VenueStatus::Operational,
+        "major" | "critical" | "maintenance" => VenueStatus::Down,
+        _ => VenueStatus::Degraded,
+    };
+    Some((level, description.to_string()))
+}
+
+/// Map Binance system status (`{"status": 0, "msg": "normal"}`; 1 = maintenance).
+pub fn parse_binance_status(body: &Value) -> Option<(VenueStatus, String)> {
+    let status = body.get("status")?.as_u64()?;
+    let msg = body.get("msg").and_then(Value::as_str).unwrap_or_default();
+    let level = if status == 0 {
+        VenueStatus::Operational
+    } else {
+        VenueStatus::Down
+    };
+    Some((level, msg.to_string()))
+}
+
+fn hex_u128(value: &Value) -> Option<u128> {
+    u128::from_str_radix(value.as_str()?.trim_start_matches("0x"), 16).ok()
+}
+
+/// Gas price in gwei from an `eth_gasPrice` result
+pub fn parse_gas_price_gwei(result: &Value) -> Option<f64> {
+    Some(hex_u128(result)? as f64 / 1e9)
+}
+
+/// Header fields of the latest block used for health checks
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct BlockHead {
+    pub number: u64,
+    pub hash: String,
+    pub parent_hash: String,
+    pub timestamp: i64,
+}
+
+/// Parse an `eth_getBlockByNumber` result
+pub fn parse_block_head(block: &Value) -> Option<BlockHead> {
+    Some(BlockHead {
+        number: u64::try_from(hex_u128(block.get("number")?)?).ok()?,
+        hash: block.get("hash")?.as_str()?.to_string(),
+        parent_hash: block.get("parentHash")?.as_str()?.to_string(),
+        timestamp: i64::try_from(hex_u128(block.get("timestamp")?)?).ok()?,
+    })
+}
+
+/// Remembers recent block hashes to spot reorgs between polls
+#[derive(Debug, Default)]
+pub struct ReorgDetector {
+    hashes: BTreeMap<u64, String>,
+}
+
+impl ReorgDetector {
+    /// Record the latest head; returns the lowest height whose previously
+    /// seen block was replaced.
+    pub fn observe(&mut self, head: &BlockHead) -> Option<u64> {
+        let parent_height = head.number.checked_sub(1);
+        let reorg_at = if self
+            .hashes
+            .get(&head.number)
+            .is_some_and(|seen| *seen != head.hash)
+        {
+            Some(head.number)
+        } else {
+            parent_height.filter(|height| {
+                self.hashes
+                    .get(height)
+                    .is_some_and(|seen| *seen != head.parent_hash)
+            })
+        };
+
+        if let Some(height) = reorg_at {
+            self.hashes.retain(|seen, _| *seen < height);
+        }
+        if let Some(height) = parent_height {
+            self.hashes.insert(height, head.parent_hash.clone());
+        }
+        self.hashes.insert(head.number, head.hash.clone());
+        let floor = head.number.saturating_sub(REORG_WINDOW_BLOCKS);
+        self.hashes.retain(|seen, _| *seen >= floor);
+        reorg_at
+    }
+}
+
+/// Status state machine with hysteresis.
+///
+/// Escalation is immediate; stepping down to the observed level happens after
+/// `recovery_polls` consecutive healthier polls.
+#[derive(Debug)]
+pub struct StatusTracker {
+    status: VenueStatus,
+    calm_polls: u32,
+    recovery_polls: u32,
+}
+
+impl StatusTracker {
+    pub fn new(recovery_polls: u32) -> Self {
+        Self {
+            status: VenueStatus::Operational,
+            calm_polls: 0,
+            recovery_polls: recovery_polls.max(1),
+        }
+    }
+
+    pub fn status(&self) -> VenueStatus {
+        self.status
+    }
+
+    /// Feed the status implied by the latest poll; returns the tracked status.
+    pub fn observe(&mut self, observed: VenueStatus) -> VenueStatus {
+        if observed >= self.status {
+            self.status = observed;
+            self.calm_polls = 0;
+            return self.status;
+        }
+
+        self.calm_polls += 1;
+        if self.calm_polls >= self.recovery_polls {
+            self.calm_polls = 0;
+            self.status = observed;
+        }
+        self.status
+    }
+}
+
+/// Snapshot published by the monitor
+#[derive(Debug, Clone, Serialize)]
+pub struct VenueHealthSnapshot {
+    pub status: VenueStatus,
+    pub checks: Vec<VenueCheck>,
+    /// Actions in force for the current status
+    pub actions: Vec<VenueAction>,
+    pub entry_edge_multiplier: Decimal,
+    pub changed_at: Option<DateTime<Utc>>,
+}
+
+impl Default for VenueHealthSnapshot {
+    fn default() -> Self {
+        Self {
+            status: VenueStatus::Operational,
+            checks: Vec::new(),
+            actions: Vec::new(),
+            entry_edge_multiplier: Decimal::ONE,
+            changed_at: None,
+        }
+    }
+}
+
+/// Shared venue health, read by the coordinator (entry pause) and agents
+/// (threshold widening).
+#[derive(Debug, Clone, Default)]
+pub struct VenueHealth {
+    inner: Arc<RwLock<VenueHealthSnapshot>>,
+}
+
+impl VenueHealth {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    pub fn snapshot(&self) -> VenueHealthSnapshot {
+        self.inner.read().map(|s| s.clone()).unwrap_or_default()
+    }
+
+    pub fn status(&self) -> VenueStatus {
+        self.inner.read().map(|s| s.status).unwrap_or_default()
+    }
+
+    /// Whether new BUY intents are currently blocked
+    pub fn entries_paused(&self) -> bool {
+        self.inner
+            .read()
+            .map(|s| s.actions.contains(&VenueAction::PauseEntries))
+            .unwrap_or(false)
+    }
+
+    /// Multiplier for strategy entry edge thresholds (1 = unchanged)
+    pub fn entry_edge_multiplier(&self) -> Decimal {
+        self.inner
+            .read()
+            .map(|s| s.entry_edge_multiplier)
+            .unwrap_or(Decimal::ONE)
+    }
+
+    fn publish(&self, snapshot: VenueHealthSnapshot) {
+        if let Ok(mut current) = self.inner.write() {
+            *current = snapshot;
+        }
+    }
+}
+
+/// Venue monitor daemon
+pub struct VenueMonitor {
+    config: VenueMonitorConfig,
+    health: VenueHealth,
+    http: reqwest::Client,
+}
+
+impl VenueMonitor {
+    pub fn new(config: VenueMonitorConfig, health: VenueHealth) -> Self {
+        let http = reqwest::Client::builder()
+            .timeout(Duration::from_millis(config.request_timeout_ms.max(500)))
+            .build()
+            .unwrap_or_default();
+        Self {
+            config,
+            health,
+            http,
+        }
+    }
+
+    /// Run the polling loop until shutdown.
+    ///
+    /// Threshold widening and the entry pause follow the tracked status and
+    /// are lifted on recovery and on shutdown; de-risking sells are issued
+    /// once when the status escalates into a level that lists `derisk`.
+    pub async fn run(self, handle: CoordinatorHandle, mut shutdown_rx: broadcast::Receiver<()>) {
+        let mut reorgs = ReorgDetector::default();
+        let mut tracker = StatusTracker::new(self.config.recovery_polls);
+        let mut changed_at = None;
+        let mut interval =
+            tokio::time::interval(Duration::from_secs(self.config.poll_interval_secs.max(1)));
+
+        info!(
+            interval_secs = self.config.poll_interval_secs,
+            degraded_actions = ?self.config.degraded_actions,
+            down_actions = ?self.config.down_actions,
+            "venue monitor started"
+        );
+
+        loop {
+            tokio::select! {
+                _ = interval.tick() => {}
+                _ = shutdown_rx.recv() => break,
+            }
+
+            let checks = self.poll(&mut reorgs).await;
+            let observed = checks.iter().map(|c| c.status).max().unwrap_or_default();
+            let previous = tracker.status();
+            let status = tracker.observe(observed);
+            debug!(status = %status, observed = %observed, ?checks, "venue poll");
+
+            let actions = self.config.actions_for(status);
+            if status != previous {
+                changed_at = Some(Utc::now());
+            }
+            self.health.publish(VenueHealthSnapshot {
+                status,
+                entry_edge_multiplier: if actions.contains(&VenueAction::WidenThresholds) {
+                    self.config.widen_multiplier.max(Decimal::ONE)
+                } else {
+                    Decimal::ONE
+                },
+                actions: actions.clone(),
+                checks: checks.clone(),
+                changed_at,
+            });
+
+            if status == previous {
+                continue;
+            }
+            let unhealthy: Vec<String> = checks
+                .iter()
+                .filter(|c| c.status > VenueStatus::Operational)
+                .map(|c| format!("{}: {}", c.source, c.detail))
+                .collect();
+            if status < previous {
+                info!(from = %previous, to = %status, ?actions, "venue health recovering");
+                continue;
+            }
+            warn!(from = %previous, to = %status, ?actions, ?unhealthy, "venue health degrading");
+
+            if actions.contains(&VenueAction::Derisk) {
+                let reason = format!("venue {}: {}", status, unhealthy.join(", "));
+                if let Err(e) = handle
+                    .derisk_positions(self.config.derisk_fraction, reason)
+                    .await
+                {
+                    warn!(error = %e, "venue monitor failed to request de-risking");
+                }
+            }
+        }
+
+        self.health.publish(VenueHealthSnapshot::default());
+        info!("venue monitor stopped");
+    }
+
+    async fn poll(&self, reorgs: &mut ReorgDetector) -> Vec<VenueCheck> {
+        let mut checks = Vec::new();
+        if !self.config.polymarket_status_url.is_empty() {
+            checks.push(
+                self.check_status_page(
+                    "polymarket",
+                    &self.config.polymarket_status_url,
+                    parse_statuspage,
+                )
+                .await,
+            );
+        }
+        if !self.config.binance_status_url.is_empty() {
+            checks.push(
+                self.check_status_page(
+                    "binance",
+                    &self.config.binance_status_url,
+                    parse_binance_status,
+                )
+                .await,
+            );
+        }
+        if !self.config.polygon_rpc_url.is_empty() {
+            checks.push(self.check_chain(reorgs).await);
+        }
+        checks
+    }
+
+    /// An unreachable or unparseable status endpoint counts as degraded
+    async fn check_status_page(
+        &self,
+        source: &str,
+        url: &str,
+        parse: fn(&Value) -> Option<(VenueStatus, String)>,
+    ) -> VenueCheck {
+        let body = match self.http.get(url).send().await {
+            Ok(resp) => match resp.error_for_status() {
+                Ok(resp) => resp.json::<Value>().await.map_err(|e| e.to_string()),
+                Err(e) => Err(e.to_string()),
+            },
+            Err(e) => Err(e.to_string()),
+        };
+        let (status, detail) = match body {
+            Ok(body) => parse(&body).unwrap_or((
+                VenueStatus::Degraded,
+                "unrecognised status payload".to_string(),
+            )),
+            Err(e) => (VenueStatus::Degraded, format!("status unreachable: {e}")),
+        };
+        VenueCheck {
+            source: source.to_string(),
+            status,
+            detail,
+            checked_at: Utc::now(),
+        }
+    }
+
+    async fn check_chain(&self, reorgs: &mut ReorgDetector) -> VenueCheck {
+        let gas_gwei = match self.rpc("eth_gasPrice", json!([])).await {
+            Ok(result) => parse_gas_price_gwei(&result),
+            Err(e) => {
+                debug!(error = %e, "venue monitor: eth_gasPrice failed");
+                None
+            }
+        };
+        let head = self
+            .rpc("eth_getBlockByNumber", json!(["latest", false]))
+            .await
+            .map(|result| parse_block_head(&result));
+
+        let (status, detail) = match head {
+            Ok(Some(head)) => {
+                let reorg_at = reorgs.observe(&head);
+                let age = Utc::now().timestamp() - head.timestamp;
+                self.config.classify_chain(gas_gwei, age, reorg_at)
+            }
+            Ok(None) => (
+                VenueStatus::Degraded,
+                "unrecognised block payload".to_string(),
+            ),
+            Err(e) => (VenueStatus::Degraded, format!("rpc unreachable: {e}")),
+        };
+        VenueCheck {
+            source: "polygon".to_string(),
+            status,
+            detail,
+            checked_at: Utc::now(),
+        }
+    }
+
+    async fn rpc(&self, method: &str, params: Value) -> std::result::Result<Value, String> {
+        let request = json!({ "jsonrpc": "2.0", "id": 1, "method": method, "params": params });
+        let resp: Value = self
+            .http
+            .post(&self.config.polygon_rpc_url)
+            .json(&request)
+            .send()
+            .await
+            .and_then(|r| r.error_for_status())
+            .map_err(|e| e.to_string())?
+            .json()
+            .await
+            .map_err(|e| e.to_string())?;
+        if let Some(error) = resp.get("error") {
+            return Err(error.to_string());
+        }
+        resp.get("result")
+            .cloned()
+            .ok_or_else(|| "missing result".to_string())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_parse_status_payloads() {
+        let page = json!({ "status": { "indicator": "major", "description": "Partial outage" } });
+        assert_eq!(
+            parse_statuspage(&page),
+            Some((VenueStatus::Down, "Partial outage".to_string()))
+        );
+        let binance = json!({ "status": 1, "msg": "system_maintenance" });
+        assert_eq!(
+            parse_binance_status(&binance).map(|(s, _)| s),
+            Some(VenueStatus::Down)
+        );
+        assert_eq!(parse_gas_price_gwei(&json!("0x6fc23ac00")), Some(30.0));
+
+        let config = VenueMonitorConfig::default();
+        assert_eq!(
+            config.classify_chain(Some(450.0), 2, None).0,
+            VenueStatus::Degraded
+        );
+        assert_eq!(
+            config.classify_chain(Some(30.0), 120, None).0,
+            VenueStatus::Down
+        );
+    }
+
+    #[test]
+    fn test_reorg_detector_flags_replaced_blocks() {
+        let head = |number: u64, hash: &str, parent: &str| BlockHead {
+            number,
+            hash: hash.to_string(),
+            parent_hash: parent.to_string(),
+            timestamp: 0,
+        };
+        let mut detector = ReorgDetector::default();
+        assert_eq!(detector.observe(&head(100, "a100", "a99")), None);
+        assert_eq!(detector.observe(&head(101, "a101", "a100")), None);
+        // Block 101 was replaced under us.
+        assert_eq!(detector.observe(&head(102, "b102", "b101")), Some(101));
+        assert_eq!(detector.observe(&head(103, "b103", "b102")), None);
+        // Same height, different hash.
+        assert_eq!(detector.observe(&head(103, "c103", "b102")), Some(103));
+    }
+}
@@ -0,0 +1,208 @@
+//! Alert center state for the dashboard
+//!
+//! Collects [`Alert`]s from the [`AlertManager`](crate::supervisor::AlertManager)
+//! broadcast, folds repeats into one entry, and tracks operator
+//! acknowledgements. An acknowledged alert key stays suppressed for a TTL;
+//! acknowledgements are persisted so a restart doesn't resurrect stale alerts.
+
+use std::collections::HashMap;
+use std::path::{Path, PathBuf};
+
+use chrono::{DateTime, Duration, Utc};
+use serde::{Deserialize, Serialize};
+
+use crate::error::Result;
+use crate::supervisor::alert_manager::{Alert, AlertLevel};
+
+/// Default acknowledgement file
+pub const DEFAULT_ACK_PATH: &str = "data/state/tui_alert_acks.json";
+
+/// Maximum number of distinct alerts kept
+const MAX_ALERTS: usize = 200;
+
+/// How long an acknowledgement suppresses repeats of the same alert
+const DEFAULT_ACK_TTL_HOURS: i64 = 6;
+
+/// One alert (and its repeats) in the alert center
+#[derive(Debug, Clone)]
+pub struct AlertEntry {
+    /// `component:level:title`, see [`Alert::dedup_key`]
+    pub key: String,
+    pub level: AlertLevel,
+    pub component: String,
+    pub title: String,
+    /// Latest message
+    pub message: String,
+    /// Affected strategy/agent, from alert metadata or the component
+    pub strategy: String,
+    pub first_seen: DateTime<Utc>,
+    pub last_seen: DateTime<Utc>,
+    pub count: u32,
+    pub acknowledged: bool,
+}
+
+#[derive(Debug, Default, Serialize, Deserialize)]
+struct AckFile {
+    acks: HashMap<String, DateTime<Utc>>,
+}
+
+/// Alert list plus persisted acknowledgements
+#[derive(Debug)]
+pub struct AlertCenter {
+    /// Most recently seen first
+    entries: Vec<AlertEntry>,
+    acks: HashMap<String, DateTime<Utc>>,
+    ack_path: Option<PathBuf>,
+    ack_ttl: Duration,
+    /// Selected row in the alert tab
+    pub selected: usize,
+}
+
+impl Default for AlertCenter {
+    fn default() -> Self {
+        Self {
+            entries: Vec::new(),
+            acks: HashMap::new(),
+            ack_path: None,
+            ack_ttl: Duration::hours(DEFAULT_ACK_TTL_HOURS),
+            selected: 0,
+        }
+    }
+}
+
+/// Strategy named in alert metadata, falling back to the component
+fn alert_strategy(alert: &Alert) -> String {
+    alert
+        .metadata
+        .as_ref()
+        .and_then(|m| {
+            ["strategy", "agent_id", "agent"]
+                .iter()
+                .find_map(|k| m.get(*k).and_then(|v| v.as_str()))
+        })
+        .unwrap_or(&alert.component)
+        .to_string()
+}
+
+impl AlertCenter {
+    /// In-memory alert center (acknowledgements are not persisted)
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Alert center persisting acknowledgements to `path`.
+    ///
+    /// Expired acknowledgements in the file are dropped on load.
+    pub fn load(path: impl AsRef<Path>) -> Result<Self> {
+        let path = path.as_ref().to_path_buf();
+        let mut center = Self {
+            ack_path: Some(path.clone()),
+            ..Self::default()
+        };
+        if path.exists() {
+            let file: AckFile = serde_json::from_str(&std::fs::read_to_string(&path)?)?;
+            let cutoff = Utc::now() - center.ack_ttl;
+            center.acks = file
+                .acks
+                .into_iter()
+                .filter(|(_, at)| *at > cutoff)
+                .collect();
+        }
+        Ok(center)
+    }
+
+    /// Set how long an acknowledgement suppresses repeats
+    pub fn with_ack_ttl(mut self, ttl: Duration) -> Self {
+        self.ack_ttl = ttl;
+        self
+    }
+
+    pub fn entries(&self) -> &[AlertEntry] {
+        &self.entries
+    }
+
+    pub fn selected_entry(&self) -> Option<&AlertEntry> {
+        self.entries.get(self.selected)
+    }
+
+    pub fn unacknowledged_count(&self) -> usize {
+        self.entries.iter().filter(|e| !e.acknowledged).count()
+    }
+
+    fn is_acked(&mut self, key: &str, at: DateTime<Utc>) -> bool {
+        match self.acks.get(key) {
+            Some(acked_at) if at - *acked_at < self.ack_ttl => true,
+            Some(_) => {
+                self.acks.remove(key);
+                false
+            }
+            None => false,
+        }
+    }
+
+    /// Record an alert; repeats of an existing key are folded into its entry
+    pub fn push(&mut self, alert: Alert) {
+        let key = alert.dedup_key();
+        let acknowledged = self.is_acked(&key, alert.timestamp);
+
+        let mut entry = match self.entries.iter().position(|e| e.key == key) {
+            Some(idx) => {
+                let mut entry = self.entries.remove(idx);
+                entry.count += 1;
+                entry.message = alert.message;
+                entry.last_seen = alert.timestamp;
+                entry
+            }
+            None => AlertEntry {
+                strategy: alert_strategy(&alert),
+                key,
+                level: alert.level,
+                component: alert.component,
+                title: alert.title,
+                message: alert.message,
+                first_seen: alert.timestamp,
+                last_seen: alert.timestamp,
+                count: 1,
+                acknowledged,
+            },
+        };
+        entry.acknowledged = acknowledged;
+        self.entries.insert(0, entry);
+        self.entries.truncate(MAX_ALERTS);
+        self.selected = self.selected.min(self.entries.len().saturating_sub(1));
+    }
+
+    pub fn select_prev(&mut self) {
+        self.selected = self.selected.saturating_sub(1);
+    }
+
+    pub fn select_next(&mut self) {
+        if self.selected + 1 < self.entries.len() {
+            self.selected += 1;
+        }
+    }
+
+    /// Acknowledge the selected alert and persist the acknowledgement
+    pub fn acknowledge_selected(&mut self) -> Result<()> {
+        let Some(entry) = self.entries.get_mut(self.selected) else {
+            return Ok(());
+        };
+        entry.acknowledged = true;
+        self.acks.insert(entry.key.clone(), Utc::now());
+        self.save()
+    }
+
+    fn save(&self) -> Result<()> {
+        let Some(path) = &self.ack_path else {
+            return Ok(());
+        };
+        if let Some(parent) = path.parent() {
+            std::fs::create_dir_all(parent)?;
+        }
+        let file = AckFile {
+            acks: self.acks.clone(),
+        };
+        std::fs::write(path, serde_json::to_string_pretty(&file)?)?;
+        Ok(())
+    }
+}
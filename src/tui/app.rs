@@ -2,15 +2,25 @@
 //!
 //! Manages all display state for the dashboard.
 
+use std::collections::HashMap;
+
 use chrono::{DateTime, Utc};
+use rust_decimal::prelude::ToPrimitive;
 use rust_decimal::Decimal;
 use rust_decimal_macros::dec;
 
+use crate::analysis::{up_token_greeks, BinaryGreeksConfig};
+use crate::coordinator::heatmap::{ExposureHeatmap, HeatmapThresholds, MarketSettlement};
 use crate::domain::Side;
+use crate::platform::{Domain, Position};
+use crate::tui::alerts::AlertCenter;
+use crate::tui::candles::{CandleService, ChartTimeframe};
 use crate::tui::data::{
-    DashboardStats, DisplayAgent, DisplayPosition, DisplayRiskState, DisplayTransaction,
-    MarketState,
+    DashboardStats, DisplayAgent, DisplayGreeks, DisplayPosition, DisplayRiskState,
+    DisplayTransaction, MarketState,
 };
+use crate::tui::equity::EquityCurve;
+use crate::tui::logs::LogTail;
 
 /// Maximum number of transactions to keep in history
 const MAX_TRANSACTIONS: usize = 100;
@@ -22,6 +32,8 @@ pub enum ActiveTab {
     Portfolio,
     /// Agent monitor view showing coordinator agents
     AgentMonitor,
+    /// Alert center with acknowledgements
+    Alerts,
 }
 
 /// Pending action to confirm via modal
@@ -47,6 +59,8 @@ pub struct TuiApp {
     pub positions: Vec<DisplayPosition>,
     /// Current market state
     pub market: MarketState,
+    /// Sides flagged untradable by the anomaly detector, with the reason
+    pub untradable: HashMap<Side, String>,
     /// Recent transactions (newest first)
     pub transactions: Vec<DisplayTransaction>,
     /// Dashboard statistics
@@ -69,6 +83,8 @@ pub struct TuiApp {
     pub agent_snapshots: Vec<DisplayAgent>,
     /// Risk state from coordinator
     pub risk_state: DisplayRiskState,
+    /// Exposure heatmap from coordinator
+    pub exposure_heatmap: Option<ExposureHeatmap>,
     /// Current active tab
     pub active_tab: ActiveTab,
     /// Modal dialog (confirmation prompts)
@@ -77,6 +93,20 @@ pub struct TuiApp {
     pub filter_mode: bool,
     /// Current filter input text
     pub filter_input: String,
+    /// Per-token mid/spread history for the chart panel
+    pub candles: CandleService,
+    /// Candle interval shown in the chart panel
+    pub chart_timeframe: ChartTimeframe,
+    /// Tailed tracing log for the log pane
+    pub logs: LogTail,
+    /// Alerts from the alert manager
+    pub alerts: AlertCenter,
+    /// Agent highlighted in the agent monitor (jumped to from an alert)
+    pub focused_agent: Option<String>,
+    /// PnL realized this session from reduced positions
+    pub realized_pnl: Decimal,
+    /// Session realized + unrealized PnL over time
+    pub equity: EquityCurve,
 }
 
 impl Default for TuiApp {
@@ -91,6 +121,7 @@ impl TuiApp {
         Self {
             positions: Vec::new(),
             market: MarketState::default(),
+            untradable: HashMap::new(),
             transactions: Vec::new(),
             stats: DashboardStats::default(),
             tx_scroll_offset: 0,
@@ -102,10 +133,18 @@ impl TuiApp {
             selected_market: String::new(),
             agent_snapshots: Vec::new(),
             risk_state: DisplayRiskState::default(),
+            exposure_heatmap: None,
             active_tab: ActiveTab::Portfolio,
             modal: None,
             filter_mode: false,
             filter_input: String::new(),
+            candles: CandleService::new(),
+            chart_timeframe: ChartTimeframe::default(),
+            logs: LogTail::new(),
+            alerts: AlertCenter::new(),
+            focused_agent: None,
+            realized_pnl: Decimal::ZERO,
+            equity: EquityCurve::new(),
         }
     }
 
@@ -137,7 +176,20 @@ impl TuiApp {
         self.market
             .with_positions(up_shares, down_shares, total_pnl);
 
-        self.last_update = Utc::now();
+        let now = Utc::now();
+        self.candles.record(Side::Up, up_bid, up_ask, now);
+        self.candles.record(Side::Down, down_bid, down_ask, now);
+        self.last_update = now;
+    }
+
+    /// Switch the chart panel to the next longer candle interval
+    pub fn next_timeframe(&mut self) {
+        self.chart_timeframe = self.chart_timeframe.next();
+    }
+
+    /// Switch the chart panel to the next shorter candle interval
+    pub fn prev_timeframe(&mut self) {
+        self.chart_timeframe = self.chart_timeframe.prev();
     }
 
     /// Calculate position statistics
@@ -165,6 +217,14 @@ impl TuiApp {
         current_price: Decimal,
         avg_price: Decimal,
     ) {
+        // Realize PnL on the reduced shares at the current price
+        if let Some(prev) = self.positions.iter().find(|p| p.side == side) {
+            let reduced = prev.shares.saturating_sub(shares);
+            if reduced > 0 {
+                self.realized_pnl += Decimal::from(reduced) * (current_price - prev.avg_price);
+            }
+        }
+
         // Remove existing position for this side
         self.positions.retain(|p| p.side != side);
 
@@ -179,6 +239,48 @@ impl TuiApp {
             (Side::Down, Side::Up) => std::cmp::Ordering::Greater,
             _ => std::cmp::Ordering::Equal,
         });
+        self.refresh_greeks();
+        self.record_equity(Utc::now());
+    }
+
+    /// Session realized + unrealized PnL
+    pub fn session_pnl(&self) -> Decimal {
+        self.realized_pnl + self.positions.iter().map(|p| p.pnl).sum::<Decimal>()
+    }
+
+    /// Sample the session PnL into the equity curve
+    pub fn record_equity(&mut self, at: DateTime<Utc>) {
+        let pnl = self.session_pnl();
+        self.equity.record(at, pnl);
+    }
+
+    /// Recompute position Greeks from spot, round strike and time left
+    fn refresh_greeks(&mut self) {
+        let inputs = (
+            self.stats.binance_price.and_then(|p| p.to_f64()),
+            self.stats.round_strike.and_then(|k| k.to_f64()),
+            self.stats.round_end_time,
+        );
+        let (Some(spot), Some(strike), Some(end)) = inputs else {
+            for pos in &mut self.positions {
+                pos.greeks = None;
+            }
+            return;
+        };
+        let defaults = BinaryGreeksConfig::default();
+        let vol = self.stats.vol_15m.unwrap_or(defaults.default_vol_15m);
+        let tau =
+            ((end - Utc::now()).num_milliseconds() as f64 / 1000.0).max(defaults.min_tau_secs);
+        let up = up_token_greeks(spot, strike, vol, tau);
+        for pos in &mut self.positions {
+            let g = up.for_side(pos.side);
+            let shares = pos.shares as f64;
+            pos.greeks = Some(DisplayGreeks {
+                delta: g.delta * shares,
+                theta: g.theta * shares,
+                vega: g.vega * shares,
+            });
+        }
     }
 
     /// Clear all positions
@@ -197,6 +299,7 @@ impl TuiApp {
 
         // Update stats
         self.stats.trade_count += 1;
+        self.record_equity(Utc::now());
     }
 
     /// Update volume
@@ -207,6 +310,19 @@ impl TuiApp {
     /// Set round end time
     pub fn set_round_end_time(&mut self, end_time: Option<DateTime<Utc>>) {
         self.stats.round_end_time = end_time;
+        self.refresh_greeks();
+    }
+
+    /// Set round opening (strike) price
+    pub fn set_round_strike(&mut self, strike: Option<Decimal>) {
+        self.stats.round_strike = strike;
+        self.refresh_greeks();
+    }
+
+    /// Set underlying volatility per 15 minutes used for Greeks
+    pub fn set_vol_15m(&mut self, vol: Option<f64>) {
+        self.stats.vol_15m = vol;
+        self.refresh_greeks();
     }
 
     /// Set strategy state
@@ -223,6 +339,19 @@ impl TuiApp {
     pub fn update_binance_price(&mut self, symbol: String, price: Decimal) {
         self.stats.binance_symbol = symbol;
         self.stats.binance_price = Some(price);
+        self.refresh_greeks();
+    }
+
+    /// Raise or clear the untradable badge for a market side
+    pub fn set_market_anomaly(&mut self, side: Side, reason: Option<String>) {
+        match reason {
+            Some(reason) => {
+                self.untradable.insert(side, reason);
+            }
+            None => {
+                self.untradable.remove(&side);
+            }
+        }
     }
 
     /// Update WebSocket connection status
@@ -235,18 +364,47 @@ impl TuiApp {
         self.stats.last_error = Some(error);
     }
 
-    /// Scroll transactions up
+    /// Scroll transactions up (moves the selection on the alerts tab)
     pub fn scroll_up(&mut self) {
+        if self.active_tab == ActiveTab::Alerts {
+            self.alerts.select_prev();
+            return;
+        }
         self.tx_scroll_offset = self.tx_scroll_offset.saturating_sub(1);
     }
 
-    /// Scroll transactions down
+    /// Scroll transactions down (moves the selection on the alerts tab)
     pub fn scroll_down(&mut self) {
+        if self.active_tab == ActiveTab::Alerts {
+            self.alerts.select_next();
+            return;
+        }
         if self.tx_scroll_offset < self.transactions.len().saturating_sub(1) {
             self.tx_scroll_offset += 1;
         }
     }
 
+    /// Acknowledge the selected alert
+    pub fn acknowledge_alert(&mut self) {
+        if self.active_tab != ActiveTab::Alerts {
+            return;
+        }
+        if let Err(e) = self.alerts.acknowledge_selected() {
+            self.set_last_error(format!("Failed to persist alert ack: {}", e));
+        }
+    }
+
+    /// Jump from the selected alert to its strategy in the agent monitor
+    pub fn goto_alert_strategy(&mut self) {
+        if self.active_tab != ActiveTab::Alerts {
+            return;
+        }
+        if let Some(entry) = self.alerts.selected_entry() {
+            self.focused_agent = Some(entry.strategy.clone());
+            self.active_tab = ActiveTab::AgentMonitor;
+        }
+    }
+
     /// Reset scroll to top
     pub fn scroll_to_top(&mut self) {
         self.tx_scroll_offset = 0;
@@ -289,7 +447,8 @@ impl TuiApp {
     pub fn toggle_tab(&mut self) {
         self.active_tab = match self.active_tab {
             ActiveTab::Portfolio => ActiveTab::AgentMonitor,
-            ActiveTab::AgentMonitor => ActiveTab::Portfolio,
+            ActiveTab::AgentMonitor => ActiveTab::Alerts,
+            ActiveTab::Alerts => ActiveTab::Portfolio,
         };
     }
 
@@ -332,6 +491,11 @@ impl TuiApp {
         self.risk_state = risk;
     }
 
+    /// Update exposure heatmap from coordinator
+    pub fn update_exposure_heatmap(&mut self, heatmap: ExposureHeatmap) {
+        self.exposure_heatmap = Some(heatmap);
+    }
+
     /// Create demo data for testing
     pub fn with_demo_data(mut self) -> Self {
         // Demo positions
@@ -440,6 +604,46 @@ impl TuiApp {
             total_exposure: dec!(1700.50),
         };
 
+        // Demo exposure heatmap (for Agent Monitor tab)
+        let now = Utc::now();
+        let demo_position = |domain: Domain, slug: &str, shares: u64, price: Decimal| Position {
+            position_id: format!("demo-{}", slug),
+            agent_id: "demo".to_string(),
+            domain,
+            market_slug: slug.to_string(),
+            token_id: format!("{}-up", slug),
+            side: Side::Up,
+            shares,
+            entry_price: price,
+            current_price: Some(price),
+            is_hedged: false,
+            entry_time: now,
+            updated_at: now,
+            metadata: HashMap::new(),
+        };
+        let positions = vec![
+            demo_position(Domain::Crypto, "btc-updown-5m-demo", 1800, dec!(0.52)),
+            demo_position(Domain::Crypto, "eth-updown-15m-demo", 600, dec!(0.48)),
+            demo_position(Domain::Sports, "nba-lal-bos-demo", 1000, dec!(0.45)),
+        ];
+        let settles_in = |symbol: &str, secs: i64| MarketSettlement {
+            end_time: Some(now + chrono::Duration::seconds(secs)),
+            symbol: Some(symbol.to_string()),
+        };
+        let markets = HashMap::from([
+            ("btc-updown-5m-demo".to_string(), settles_in("BTCUSDT", 140)),
+            (
+                "eth-updown-15m-demo".to_string(),
+                settles_in("ETHUSDT", 600),
+            ),
+        ]);
+        self.exposure_heatmap = Some(ExposureHeatmap::build(
+            &positions,
+            &markets,
+            HeatmapThresholds::default(),
+            now,
+        ));
+
         self
     }
 }
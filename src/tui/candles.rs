@@ -0,0 +1,154 @@
+//! Candle service for dashboard charts
+//!
+//! Samples mid-price and bid/ask spread for each tracked token from incoming
+//! quotes and aggregates them into fixed-interval candles on demand.
+
+use std::collections::VecDeque;
+
+use chrono::{DateTime, Utc};
+use rust_decimal::prelude::ToPrimitive;
+use rust_decimal::Decimal;
+
+use crate::domain::Side;
+
+/// How long raw samples are kept
+const RETENTION_SECS: i64 = 3600;
+
+/// Candle interval shown in the chart panel
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum ChartTimeframe {
+    #[default]
+    S1,
+    S5,
+    S15,
+    M1,
+}
+
+impl ChartTimeframe {
+    const ALL: [ChartTimeframe; 4] = [Self::S1, Self::S5, Self::S15, Self::M1];
+
+    /// Candle length in seconds
+    pub fn seconds(self) -> i64 {
+        match self {
+            Self::S1 => 1,
+            Self::S5 => 5,
+            Self::S15 => 15,
+            Self::M1 => 60,
+        }
+    }
+
+    pub fn label(self) -> &'static str {
+        match self {
+            Self::S1 => "1s",
+            Self::S5 => "5s",
+            Self::S15 => "15s",
+            Self::M1 => "1m",
+        }
+    }
+
+    fn index(self) -> usize {
+        Self::ALL.iter().position(|t| *t == self).unwrap_or(0)
+    }
+
+    /// Next longer timeframe (wraps)
+    pub fn next(self) -> Self {
+        Self::ALL[(self.index() + 1) % Self::ALL.len()]
+    }
+
+    /// Next shorter timeframe (wraps)
+    pub fn prev(self) -> Self {
+        Self::ALL[(self.index() + Self::ALL.len() - 1) % Self::ALL.len()]
+    }
+}
+
+/// One aggregated candle
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct Candle {
+    /// Bucket start (unix seconds)
+    pub start: i64,
+    /// Closing mid-price
+    pub mid: f64,
+    /// Closing bid/ask spread
+    pub spread: f64,
+}
+
+#[derive(Debug, Clone, Copy)]
+struct Sample {
+    secs: i64,
+    mid: f64,
+    spread: f64,
+}
+
+/// Rolling per-token quote history
+#[derive(Debug, Default)]
+pub struct CandleService {
+    up: VecDeque<Sample>,
+    down: VecDeque<Sample>,
+}
+
+impl CandleService {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    fn series(&self, side: Side) -> &VecDeque<Sample> {
+        match side {
+            Side::Up => &self.up,
+            Side::Down => &self.down,
+        }
+    }
+
+    /// Record a bid/ask quote for `side`.
+    ///
+    /// Quotes within the same second overwrite each other; empty books are ignored.
+    pub fn record(&mut self, side: Side, bid: Decimal, ask: Decimal, at: DateTime<Utc>) {
+        let (Some(bid), Some(ask)) = (bid.to_f64(), ask.to_f64()) else {
+            return;
+        };
+        if bid <= 0.0 || ask <= 0.0 {
+            return;
+        }
+
+        let sample = Sample {
+            secs: at.timestamp(),
+            mid: (bid + ask) / 2.0,
+            spread: ask - bid,
+        };
+        let series = match side {
+            Side::Up => &mut self.up,
+            Side::Down => &mut self.down,
+        };
+        match series.back_mut() {
+            Some(last) if last.secs == sample.secs => *last = sample,
+            _ => series.push_back(sample),
+        }
+        while series
+            .front()
+            .is_some_and(|s| sample.secs - s.secs > RETENTION_SECS)
+        {
+            series.pop_front();
+        }
+    }
+
+    /// The most recent `limit` candles for `side`, oldest first
+    pub fn candles(&self, side: Side, timeframe: ChartTimeframe, limit: usize) -> Vec<Candle> {
+        let width = timeframe.seconds();
+        let mut candles: Vec<Candle> = Vec::new();
+        for s in self.series(side) {
+            let start = s.secs.div_euclid(width) * width;
+            match candles.last_mut() {
+                Some(c) if c.start == start => {
+                    c.mid = s.mid;
+                    c.spread = s.spread;
+                }
+                _ => candles.push(Candle {
+                    start,
+                    mid: s.mid,
+                    spread: s.spread,
+                }),
+            }
+        }
+        let skip = candles.len().saturating_sub(limit);
+        candles.split_off(skip)
+    }
+}
@@ -23,6 +23,19 @@ pub struct DisplayPosition {
     pub cost: Decimal,
     /// Average entry price
     pub avg_price: Decimal,
+    /// Sensitivities when spot, strike and round end are known
+    pub greeks: Option<DisplayGreeks>,
+}
+
+/// Position sensitivities in USD (already scaled by shares)
+#[derive(Debug, Clone, Copy, Default, PartialEq)]
+pub struct DisplayGreeks {
+    /// Change for a +1% underlying move
+    pub delta: f64,
+    /// Change per minute of time passing
+    pub theta: f64,
+    /// Change for a +10% relative increase in volatility
+    pub vega: f64,
 }
 
 impl DisplayPosition {
@@ -39,6 +52,7 @@ impl DisplayPosition {
             pnl,
             cost,
             avg_price,
+            greeks: None,
         }
     }
 
@@ -181,6 +195,10 @@ pub struct DashboardStats {
     pub volume: Decimal,
     /// Round end time (for countdown)
     pub round_end_time: Option<DateTime<Utc>>,
+    /// Round opening (strike) price of the underlying
+    pub round_strike: Option<Decimal>,
+    /// Underlying volatility per 15 minutes (None = model default)
+    pub vol_15m: Option<f64>,
     /// Current strategy state
     pub strategy_state: String,
     /// Is dry run mode
@@ -0,0 +1,88 @@
+//! Session equity curve
+//!
+//! Samples the session's cumulative realized + unrealized PnL and tracks the
+//! running peak and maximum drawdown for the equity chart.
+
+use std::collections::VecDeque;
+
+use chrono::{DateTime, Utc};
+use rust_decimal::prelude::ToPrimitive;
+use rust_decimal::Decimal;
+
+/// Maximum number of samples kept
+const MAX_POINTS: usize = 2000;
+
+/// One sample: seconds since session start and total PnL (USD)
+pub type EquityPoint = (f64, f64);
+
+/// Cumulative session PnL with drawdown tracking
+#[derive(Debug, Default)]
+pub struct EquityCurve {
+    start: Option<DateTime<Utc>>,
+    points: VecDeque<EquityPoint>,
+    peak: Option<EquityPoint>,
+    max_drawdown: f64,
+    /// Peak and trough of the largest drawdown so far
+    drawdown: Option<(EquityPoint, EquityPoint)>,
+}
+
+impl EquityCurve {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Record the session's total PnL at `at`
+    pub fn record(&mut self, at: DateTime<Utc>, pnl: Decimal) {
+        let start = *self.start.get_or_insert(at);
+        let x = (at - start).num_milliseconds() as f64 / 1000.0;
+        let point = (x, pnl.to_f64().unwrap_or(0.0));
+
+        if self.points.len() >= MAX_POINTS {
+            self.points.pop_front();
+        }
+        self.points.push_back(point);
+
+        let peak = match self.peak {
+            Some(peak) if peak.1 >= point.1 => peak,
+            _ => {
+                self.peak = Some(point);
+                point
+            }
+        };
+        let drawdown = peak.1 - point.1;
+        if drawdown > self.max_drawdown {
+            self.max_drawdown = drawdown;
+            self.drawdown = Some((peak, point));
+        }
+    }
+
+    pub fn points(&self) -> Vec<EquityPoint> {
+        self.points.iter().copied().collect()
+    }
+
+    pub fn last(&self) -> Option<f64> {
+        self.points.back().map(|p| p.1)
+    }
+
+    pub fn max_drawdown(&self) -> f64 {
+        self.max_drawdown
+    }
+
+    /// Peak and trough points of the maximum drawdown
+    pub fn drawdown_markers(&self) -> Option<(EquityPoint, EquityPoint)> {
+        self.drawdown
+    }
+
+    /// (min, max) of x and y over the kept samples, for chart bounds
+    pub fn bounds(&self) -> Option<([f64; 2], [f64; 2])> {
+        let first = self.points.front()?;
+        let last = self.points.back()?;
+        let (lo, hi) = self
+            .points
+            .iter()
+            .fold((f64::INFINITY, f64::NEG_INFINITY), |(lo, hi), p| {
+                (lo.min(p.1), hi.max(p.1))
+            });
+        Some(([first.0, last.0.max(first.0 + 1.0)], [lo, hi]))
+    }
+}
@@ -42,6 +42,8 @@ pub enum AppEvent {
     },
     /// Round end time update
     RoundEndTime(Option<chrono::DateTime<chrono::Utc>>),
+    /// Round opening (strike) price update
+    RoundStrike(Option<rust_decimal::Decimal>),
     /// Strategy state change
     StrategyState(String),
     /// Binance price update
@@ -53,6 +55,11 @@ pub enum AppEvent {
     ConnectionStatus(bool),
     /// Error message from a data source
     Error(String),
+    /// Untradable flag raised (`Some(reason)`) or cleared for a market side
+    MarketAnomaly {
+        side: crate::domain::Side,
+        reason: Option<String>,
+    },
     /// Agent status update from coordinator
     AgentUpdate(Vec<crate::coordinator::AgentSnapshot>),
     /// Risk state update from coordinator
@@ -64,6 +71,8 @@ pub enum AppEvent {
         circuit_breaker: String,
         total_exposure: rust_decimal::Decimal,
     },
+    /// Exposure by symbol / domain / time-to-settlement from coordinator
+    HeatmapUpdate(crate::coordinator::ExposureHeatmap),
 }
 
 /// Event handler that manages the event loop
@@ -149,6 +158,24 @@ pub enum KeyAction {
     PrevMarket,
     /// Toggle between tabs
     ToggleTab,
+    /// Longer chart candle interval
+    NextTimeframe,
+    /// Shorter chart candle interval
+    PrevTimeframe,
+    /// Cycle the log pane's minimum level
+    CycleLogLevel,
+    /// Edit the log pane's module filter
+    EditLogFilter,
+    /// Pause/resume the log pane
+    ToggleLogPause,
+    /// Scroll the log pane up
+    LogScrollUp,
+    /// Scroll the log pane down
+    LogScrollDown,
+    /// Acknowledge the selected alert
+    AcknowledgeAlert,
+    /// Jump to the selected alert's strategy
+    GotoAlertStrategy,
     /// Pause all agents
     PauseAgents,
     /// Resume all agents
@@ -176,6 +203,15 @@ impl From<KeyEvent> for KeyAction {
             KeyCode::Left | KeyCode::Char('[') => KeyAction::PrevMarket,
             KeyCode::Right | KeyCode::Char(']') => KeyAction::NextMarket,
             KeyCode::Tab => KeyAction::ToggleTab,
+            KeyCode::Char('t') => KeyAction::NextTimeframe,
+            KeyCode::Char('T') => KeyAction::PrevTimeframe,
+            KeyCode::Char('l') => KeyAction::CycleLogLevel,
+            KeyCode::Char('m') => KeyAction::EditLogFilter,
+            KeyCode::Char(' ') => KeyAction::ToggleLogPause,
+            KeyCode::PageUp => KeyAction::LogScrollUp,
+            KeyCode::PageDown => KeyAction::LogScrollDown,
+            KeyCode::Char('a') => KeyAction::AcknowledgeAlert,
+            KeyCode::Char('g') => KeyAction::GotoAlertStrategy,
             KeyCode::Char('p') => KeyAction::PauseAgents,
             KeyCode::Char('r') => KeyAction::ResumeAgents,
             KeyCode::Char('x') => KeyAction::EmergencyClose,
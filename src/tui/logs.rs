@@ -0,0 +1,225 @@
+//! Log tail for the dashboard
+//!
+//! A `tracing` layer forwards every event over an in-process channel so the
+//! dashboard can show the log without a console writer corrupting the
+//! terminal. [`LogTail`] keeps the recent lines and the pane's view state:
+//! minimum level, module filter, pause and scroll.
+
+use std::collections::VecDeque;
+use std::fmt::Write as _;
+use std::sync::{Mutex, OnceLock};
+
+use chrono::{DateTime, Utc};
+use crossterm::event::{KeyCode, KeyEvent, KeyModifiers};
+use tokio::sync::mpsc;
+use tracing::field::{Field, Visit};
+use tracing::{Event, Level, Subscriber};
+use tracing_subscriber::layer::Context;
+use tracing_subscriber::Layer;
+
+/// Maximum number of log lines kept in the pane
+const MAX_LOG_LINES: usize = 2000;
+
+static LOG_RECEIVER: OnceLock<Mutex<Option<mpsc::UnboundedReceiver<LogLine>>>> = OnceLock::new();
+
+/// One captured log event
+#[derive(Debug, Clone)]
+pub struct LogLine {
+    pub at: DateTime<Utc>,
+    pub level: Level,
+    /// Module path / target, e.g. `ploy::strategy::engine`
+    pub target: String,
+    pub message: String,
+}
+
+/// Collects the `message` field plus any other fields as `key=value`
+#[derive(Default)]
+struct MessageVisitor {
+    message: String,
+    fields: String,
+}
+
+impl Visit for MessageVisitor {
+    fn record_str(&mut self, field: &Field, value: &str) {
+        if field.name() == "message" {
+            self.message.push_str(value);
+        } else {
+            let _ = write!(self.fields, " {}={}", field.name(), value);
+        }
+    }
+
+    fn record_debug(&mut self, field: &Field, value: &dyn std::fmt::Debug) {
+        if field.name() == "message" {
+            let _ = write!(self.message, "{:?}", value);
+        } else {
+            let _ = write!(self.fields, " {}={:?}", field.name(), value);
+        }
+    }
+}
+
+/// `tracing` layer that forwards events to the dashboard
+pub struct LogTailLayer {
+    tx: mpsc::UnboundedSender<LogLine>,
+}
+
+impl<S: Subscriber> Layer<S> for LogTailLayer {
+    fn on_event(&self, event: &Event<'_>, _ctx: Context<'_, S>) {
+        let mut visitor = MessageVisitor::default();
+        event.record(&mut visitor);
+        let meta = event.metadata();
+        // The dashboard may have exited; dropping lines is fine then
+        let _ = self.tx.send(LogLine {
+            at: Utc::now(),
+            level: *meta.level(),
+            target: meta.target().to_string(),
+            message: visitor.message + &visitor.fields,
+        });
+    }
+}
+
+/// Create a log tail layer and the receiving end of its channel
+pub fn log_tail_channel() -> (LogTailLayer, mpsc::UnboundedReceiver<LogLine>) {
+    let (tx, rx) = mpsc::unbounded_channel();
+    (LogTailLayer { tx }, rx)
+}
+
+/// Create a log tail layer whose receiver is picked up by the dashboard runner
+pub fn install_log_tail() -> LogTailLayer {
+    let (layer, rx) = log_tail_channel();
+    *LOG_RECEIVER
+        .get_or_init(|| Mutex::new(None))
+        .lock()
+        .unwrap_or_else(|e| e.into_inner()) = Some(rx);
+    layer
+}
+
+/// Take the receiver registered by [`install_log_tail`], if any
+pub fn take_log_receiver() -> Option<mpsc::UnboundedReceiver<LogLine>> {
+    LOG_RECEIVER
+        .get()?
+        .lock()
+        .unwrap_or_else(|e| e.into_inner())
+        .take()
+}
+
+/// Recent log lines and the log pane's view state
+#[derive(Debug)]
+pub struct LogTail {
+    lines: VecDeque<LogLine>,
+    /// Least severe level shown
+    pub min_level: Level,
+    /// Only show targets starting with this prefix (empty = all)
+    pub module_filter: String,
+    /// Module filter input is being edited
+    pub editing: bool,
+    /// Lines received while paused stay hidden until resumed
+    paused_len: Option<usize>,
+    /// Lines scrolled up from the newest visible line
+    pub scroll: usize,
+}
+
+impl Default for LogTail {
+    fn default() -> Self {
+        Self {
+            lines: VecDeque::with_capacity(MAX_LOG_LINES),
+            min_level: Level::INFO,
+            module_filter: String::new(),
+            editing: false,
+            paused_len: None,
+            scroll: 0,
+        }
+    }
+}
+
+impl LogTail {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    pub fn push(&mut self, line: LogLine) {
+        if self.lines.len() >= MAX_LOG_LINES {
+            self.lines.pop_front();
+            // Keep the frozen view anchored to the same lines
+            if let Some(len) = self.paused_len.as_mut() {
+                *len = len.saturating_sub(1);
+            }
+        }
+        self.lines.push_back(line);
+    }
+
+    pub fn is_paused(&self) -> bool {
+        self.paused_len.is_some()
+    }
+
+    /// Freeze or resume the view; resuming jumps back to the newest line
+    pub fn toggle_pause(&mut self) {
+        self.paused_len = match self.paused_len {
+            Some(_) => {
+                self.scroll = 0;
+                None
+            }
+            None => Some(self.lines.len()),
+        };
+    }
+
+    /// Cycle the minimum level: TRACE → DEBUG → INFO → WARN → ERROR → TRACE
+    pub fn cycle_level(&mut self) {
+        self.min_level = match self.min_level {
+            Level::TRACE => Level::DEBUG,
+            Level::DEBUG => Level::INFO,
+            Level::INFO => Level::WARN,
+            Level::WARN => Level::ERROR,
+            Level::ERROR => Level::TRACE,
+        };
+        self.scroll = 0;
+    }
+
+    pub fn scroll_up(&mut self) {
+        self.scroll = self.scroll.saturating_add(1);
+    }
+
+    pub fn scroll_down(&mut self) {
+        self.scroll = self.scroll.saturating_sub(1);
+    }
+
+    fn matches(&self, line: &LogLine) -> bool {
+        // `Level` orders by verbosity: ERROR < WARN < ... < TRACE
+        line.level <= self.min_level
+            && (self.module_filter.is_empty() || line.target.starts_with(&self.module_filter))
+    }
+
+    /// Up to `height` filtered lines ending at the current scroll position, oldest first
+    pub fn visible(&self, height: usize) -> Vec<&LogLine> {
+        let end = self.paused_len.unwrap_or(self.lines.len());
+        let filtered: Vec<&LogLine> = self
+            .lines
+            .iter()
+            .take(end)
+            .filter(|l| self.matches(l))
+            .collect();
+        let scroll = self.scroll.min(filtered.len().saturating_sub(height));
+        let stop = filtered.len() - scroll;
+        filtered[stop.saturating_sub(height)..stop].to_vec()
+    }
+
+    /// Edit the module filter; Enter applies, Esc clears
+    pub fn handle_filter_key(&mut self, key: KeyEvent) {
+        match key.code {
+            KeyCode::Esc => {
+                self.editing = false;
+                self.module_filter.clear();
+            }
+            KeyCode::Enter => self.editing = false,
+            KeyCode::Backspace => {
+                self.module_filter.pop();
+            }
+            KeyCode::Char(c)
+                if key.modifiers.is_empty() || key.modifiers == KeyModifiers::SHIFT =>
+            {
+                self.module_filter.push(c);
+            }
+            _ => {}
+        }
+        self.scroll = 0;
+    }
+}
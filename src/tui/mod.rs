@@ -2,9 +2,13 @@
 //!
 //! Provides a cyberpunk-style dashboard for monitoring trading activity.
 
+pub mod alerts;
 pub mod app;
+pub mod candles;
 pub mod data;
+pub mod equity;
 pub mod event;
+pub mod logs;
 pub mod runner;
 pub mod theme;
 pub mod ui;
@@ -120,6 +124,11 @@ pub async fn run_tui(mut app: TuiApp) -> io::Result<()> {
                     continue;
                 }
 
+                if app.logs.editing {
+                    app.logs.handle_filter_key(key);
+                    continue;
+                }
+
                 // If we're editing the filter input, treat keys as text entry.
                 if app.filter_mode {
                     match key.code {
@@ -157,6 +166,15 @@ pub async fn run_tui(mut app: TuiApp) -> io::Result<()> {
                     KeyAction::NextMarket => app.next_market(),
                     KeyAction::PrevMarket => app.prev_market(),
                     KeyAction::ToggleTab => app.toggle_tab(),
+                    KeyAction::NextTimeframe => app.next_timeframe(),
+                    KeyAction::PrevTimeframe => app.prev_timeframe(),
+                    KeyAction::CycleLogLevel => app.logs.cycle_level(),
+                    KeyAction::EditLogFilter => app.logs.editing = true,
+                    KeyAction::ToggleLogPause => app.logs.toggle_pause(),
+                    KeyAction::LogScrollUp => app.logs.scroll_up(),
+                    KeyAction::LogScrollDown => app.logs.scroll_down(),
+                    KeyAction::AcknowledgeAlert => app.acknowledge_alert(),
+                    KeyAction::GotoAlertStrategy => app.goto_alert_strategy(),
                     KeyAction::PauseAgents => app.show_modal(
                         "Pause ALL agents? [y/N]".to_string(),
                         app::PendingAction::PauseAgents,
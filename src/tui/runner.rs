@@ -2,6 +2,7 @@
 //!
 //! Connects WebSocket data sources to the TUI dashboard.
 
+use std::path::PathBuf;
 use std::sync::atomic::{AtomicBool, Ordering};
 use std::sync::Arc;
 use std::time::Duration;
@@ -16,9 +17,14 @@ use crate::adapters::{
 };
 use crate::domain::Side;
 use crate::error::Result;
+use crate::supervisor::{
+    AlertManager, MarketAnomalies, MarketAnomalyConfig, MarketAnomalyDetector,
+};
+use crate::tui::alerts::{AlertCenter, DEFAULT_ACK_PATH};
 use crate::tui::app::TuiApp;
 use crate::tui::data::{DisplayAgent, DisplayRiskState, DisplayTransaction};
 use crate::tui::event::{AppEvent, KeyAction};
+use crate::tui::logs::{log_tail_channel, take_log_receiver};
 use crate::tui::{init_terminal, restore_terminal, ui};
 
 /// Dashboard configuration
@@ -32,6 +38,8 @@ pub struct DashboardConfig {
     pub token_ids: Vec<String>,
     /// Dry run mode indicator
     pub dry_run: bool,
+    /// File persisting alert acknowledgements
+    pub alert_ack_path: PathBuf,
 }
 
 impl Default for DashboardConfig {
@@ -41,6 +49,9 @@ impl Default for DashboardConfig {
             symbols: vec!["BTCUSDT".to_string()],
             token_ids: Vec::new(),
             dry_run: true,
+            alert_ack_path: std::env::var("PLOY_TUI_ALERT_ACKS")
+                .map(PathBuf::from)
+                .unwrap_or_else(|_| PathBuf::from(DEFAULT_ACK_PATH)),
         }
     }
 }
@@ -50,6 +61,7 @@ pub struct DashboardRunner {
     config: DashboardConfig,
     app: TuiApp,
     running: Arc<AtomicBool>,
+    alerts: Arc<AlertManager>,
 }
 
 impl DashboardRunner {
@@ -57,14 +69,34 @@ impl DashboardRunner {
     pub fn new(config: DashboardConfig) -> Self {
         let mut app = TuiApp::new();
         app.set_dry_run(config.dry_run);
+        match AlertCenter::load(&config.alert_ack_path) {
+            Ok(center) => app.alerts = center,
+            Err(e) => warn!(
+                "Failed to load alert acks from {}: {}",
+                config.alert_ack_path.display(),
+                e
+            ),
+        }
 
         Self {
             config,
             app,
             running: Arc::new(AtomicBool::new(true)),
+            alerts: Arc::new(AlertManager::with_defaults()),
         }
     }
 
+    /// Show alerts from an existing alert manager instead of a private one
+    pub fn with_alert_manager(mut self, alerts: Arc<AlertManager>) -> Self {
+        self.alerts = alerts;
+        self
+    }
+
+    /// Alert manager feeding the alert center tab
+    pub fn alert_manager(&self) -> Arc<AlertManager> {
+        Arc::clone(&self.alerts)
+    }
+
     /// Run the dashboard with live data
     pub async fn run(mut self) -> Result<()> {
         info!("Starting dashboard...");
@@ -81,6 +113,10 @@ impl DashboardRunner {
         // Create event channel for data updates
         let (event_tx, mut event_rx) = mpsc::unbounded_channel::<AppEvent>();
 
+        // Log lines from the tracing layer; without one installed the channel is closed
+        let mut log_rx = take_log_receiver().unwrap_or_else(|| log_tail_channel().1);
+        let mut alert_rx = self.alerts.subscribe();
+
         // Spawn Binance price feed if symbols configured
         if !self.config.symbols.is_empty() {
             let symbols = self.config.symbols.clone();
@@ -119,6 +155,11 @@ impl DashboardRunner {
                 _ = tokio::time::sleep(Duration::from_millis(50)) => {
                     if crossterm::event::poll(Duration::from_millis(0)).unwrap_or(false) {
                         if let Ok(crossterm::event::Event::Key(key)) = crossterm::event::read() {
+                            if self.app.logs.editing {
+                                self.app.logs.handle_filter_key(key);
+                                continue;
+                            }
+
                             // If we're editing the filter input, treat keys as text entry.
                             if self.app.filter_mode {
                                 use crossterm::event::KeyCode;
@@ -186,6 +227,15 @@ impl DashboardRunner {
                                 KeyAction::NextMarket => self.app.next_market(),
                                 KeyAction::PrevMarket => self.app.prev_market(),
                                 KeyAction::ToggleTab => self.app.toggle_tab(),
+                                KeyAction::NextTimeframe => self.app.next_timeframe(),
+                                KeyAction::PrevTimeframe => self.app.prev_timeframe(),
+                                KeyAction::CycleLogLevel => self.app.logs.cycle_level(),
+                                KeyAction::EditLogFilter => self.app.logs.editing = true,
+                                KeyAction::ToggleLogPause => self.app.logs.toggle_pause(),
+                                KeyAction::LogScrollUp => self.app.logs.scroll_up(),
+                                KeyAction::LogScrollDown => self.app.logs.scroll_down(),
+                                KeyAction::AcknowledgeAlert => self.app.acknowledge_alert(),
+                                KeyAction::GotoAlertStrategy => self.app.goto_alert_strategy(),
                                 KeyAction::PauseAgents => self.app.show_modal(
                                     "Pause ALL agents? [y/N]".to_string(),
                                     crate::tui::app::PendingAction::PauseAgents,
@@ -213,6 +263,16 @@ impl DashboardRunner {
                 Some(event) = event_rx.recv() => {
                     self.handle_event(event);
                 }
+
+                // Tail log lines
+                Some(line) = log_rx.recv() => {
+                    self.app.logs.push(line);
+                }
+
+                // Alert center
+                Ok(alert) = alert_rx.recv() => {
+                    self.app.alerts.push(alert);
+                }
             }
 
             if !self.app.is_running() {
@@ -269,6 +329,9 @@ impl DashboardRunner {
             AppEvent::RoundEndTime(end_time) => {
                 self.app.set_round_end_time(end_time);
             }
+            AppEvent::RoundStrike(strike) => {
+                self.app.set_round_strike(strike);
+            }
             AppEvent::StrategyState(state) => {
                 self.app.set_strategy_state(&state);
             }
@@ -278,7 +341,17 @@ impl DashboardRunner {
             AppEvent::ConnectionStatus(connected) => {
                 self.app.set_connection_status(connected);
             }
+            AppEvent::MarketAnomaly { side, reason } => {
+                self.app.set_market_anomaly(side, reason);
+            }
             AppEvent::Error(msg) => {
+                let alerts = Arc::clone(&self.alerts);
+                let message = msg.clone();
+                tokio::spawn(async move {
+                    alerts
+                        .warning("dashboard", "Data feed error", &message)
+                        .await;
+                });
                 self.app.set_last_error(msg);
             }
             AppEvent::AgentUpdate(snaps) => {
@@ -302,6 +375,9 @@ impl DashboardRunner {
                     total_exposure,
                 });
             }
+            AppEvent::HeatmapUpdate(heatmap) => {
+                self.app.update_exposure_heatmap(heatmap);
+            }
             AppEvent::Tick | AppEvent::Key(_) | AppEvent::Resize(_, _) => {
                 // Handled in main loop
             }
@@ -395,12 +471,27 @@ impl DashboardRunner {
         let mut up_quote: Option<crate::domain::Quote> = None;
         let mut down_quote: Option<crate::domain::Quote> = None;
 
+        // Local anomaly detector drives the untradable badges
+        let anomalies = MarketAnomalies::new();
+        let mut detector =
+            MarketAnomalyDetector::new(MarketAnomalyConfig::default(), anomalies.clone());
+
         // Forward quote updates
         while running.load(Ordering::SeqCst) {
             match rx.recv().await {
                 Ok(update) => {
                     debug!("Quote update: {:?} {:?}", update.side, update.quote);
 
+                    let was_flagged = anomalies.is_untradable(&update.token_id);
+                    detector.observe(&update.token_id, &update.quote);
+                    let flag = anomalies.get(&update.token_id);
+                    if was_flagged != flag.is_some() {
+                        let _ = event_tx.send(AppEvent::MarketAnomaly {
+                            side: update.side,
+                            reason: flag.map(|a| a.kind.to_string()),
+                        });
+                    }
+
                     // Update tracked quotes
                     match update.side {
                         Side::Up => up_quote = Some(update.quote),
@@ -496,6 +587,7 @@ pub async fn run_dashboard_auto(series: Option<&str>, dry_run: bool) -> Result<(
         symbols: vec![binance_symbol.to_string()],
         token_ids,
         dry_run,
+        ..Default::default()
     };
 
     let mut runner = DashboardRunner::new(config);
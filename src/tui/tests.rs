@@ -73,3 +73,178 @@ fn test_quit() {
     app.quit();
     assert!(!app.is_running());
 }
+
+#[test]
+fn test_position_greeks_need_strike() {
+    let mut app = TuiApp::new();
+    app.update_position(crate::domain::Side::Up, 100, dec!(0.55), dec!(0.50));
+    app.update_position(crate::domain::Side::Down, 100, dec!(0.45), dec!(0.50));
+    app.update_binance_price("BTCUSDT".into(), dec!(100100));
+    app.set_round_end_time(Some(chrono::Utc::now() + chrono::Duration::minutes(5)));
+    assert!(app.positions.iter().all(|p| p.greeks.is_none()));
+
+    app.set_round_strike(Some(dec!(100000)));
+    let up = app.positions[0].greeks.unwrap();
+    let down = app.positions[1].greeks.unwrap();
+    assert!(up.delta > 0.0 && up.theta > 0.0);
+    assert_eq!(down.delta, -up.delta);
+}
+
+#[test]
+fn test_candles_follow_quotes_and_timeframe() {
+    use crate::domain::Side;
+    use crate::tui::candles::{CandleService, ChartTimeframe};
+
+    let mut app = TuiApp::new();
+    app.update_quotes(
+        dec!(0.48),
+        dec!(0.52),
+        dec!(0.46),
+        dec!(0.50),
+        dec!(10),
+        dec!(10),
+    );
+    let up = app.candles.candles(Side::Up, app.chart_timeframe, 10);
+    assert_eq!(up.len(), 1);
+    assert!((up[0].mid - 0.50).abs() < 1e-9);
+    assert!((up[0].spread - 0.04).abs() < 1e-9);
+
+    app.next_timeframe();
+    assert_eq!(app.chart_timeframe, ChartTimeframe::S5);
+    app.prev_timeframe();
+    app.prev_timeframe();
+    assert_eq!(app.chart_timeframe, ChartTimeframe::M1);
+
+    // Samples in the same 5s bucket collapse to one candle closing on the last quote
+    let mut service = CandleService::new();
+    let t0 = chrono::DateTime::from_timestamp(1_700_000_000, 0).unwrap();
+    for i in 0..10 {
+        let bid = dec!(0.40) + rust_decimal::Decimal::new(i, 2);
+        service.record(
+            Side::Down,
+            bid,
+            bid + dec!(0.02),
+            t0 + chrono::Duration::seconds(i),
+        );
+    }
+    assert_eq!(
+        service.candles(Side::Down, ChartTimeframe::S1, 100).len(),
+        10
+    );
+    let five = service.candles(Side::Down, ChartTimeframe::S5, 100);
+    assert_eq!(five.len(), 2);
+    assert!((five[1].mid - 0.50).abs() < 1e-9);
+    assert_eq!(service.candles(Side::Down, ChartTimeframe::S1, 3).len(), 3);
+}
+
+#[test]
+fn test_log_tail_filters_pause_and_scroll() {
+    use crate::tui::logs::{LogLine, LogTail};
+    use tracing::Level;
+
+    let line = |level, target: &str, message: &str| LogLine {
+        at: chrono::Utc::now(),
+        level,
+        target: target.to_string(),
+        message: message.to_string(),
+    };
+
+    let mut logs = LogTail::new();
+    logs.push(line(Level::DEBUG, "ploy::strategy", "debug"));
+    logs.push(line(Level::INFO, "ploy::strategy::engine", "tick"));
+    logs.push(line(Level::ERROR, "ploy::adapters", "ws down"));
+    logs.push(line(Level::WARN, "ploy::strategy", "slow"));
+
+    let messages = |logs: &LogTail, height| {
+        logs.visible(height)
+            .iter()
+            .map(|l| l.message.clone())
+            .collect::<Vec<_>>()
+    };
+    assert_eq!(messages(&logs, 10), vec!["tick", "ws down", "slow"]);
+
+    logs.module_filter = "ploy::strategy".to_string();
+    assert_eq!(messages(&logs, 10), vec!["tick", "slow"]);
+    logs.cycle_level();
+    assert_eq!(messages(&logs, 10), vec!["slow"]);
+    logs.module_filter.clear();
+    logs.cycle_level();
+    assert_eq!(logs.min_level, Level::ERROR);
+    assert_eq!(messages(&logs, 10), vec!["ws down"]);
+    logs.cycle_level();
+    logs.cycle_level();
+
+    // Paused view ignores new lines; scrolling moves back through the filtered lines
+    logs.toggle_pause();
+    logs.push(line(Level::INFO, "ploy::strategy", "new"));
+    assert_eq!(messages(&logs, 2), vec!["ws down", "slow"]);
+    logs.scroll_up();
+    assert_eq!(messages(&logs, 2), vec!["tick", "ws down"]);
+    logs.toggle_pause();
+    assert_eq!(messages(&logs, 2), vec!["slow", "new"]);
+}
+
+#[test]
+fn test_alert_center_ack_persists_and_suppresses_repeats() {
+    use crate::supervisor::alert_manager::{Alert, AlertLevel};
+    use crate::tui::alerts::AlertCenter;
+
+    let path = std::env::temp_dir().join(format!("ploy-alert-acks-{}.json", uuid::Uuid::new_v4()));
+    let feed_down = || {
+        Alert::new(AlertLevel::Error, "feed", "Feed Down", "ws closed")
+            .with_metadata(serde_json::json!({ "strategy": "sol-momentum" }))
+    };
+
+    let mut app = TuiApp::new();
+    app.alerts = AlertCenter::load(&path).unwrap();
+    app.alerts.push(feed_down());
+    app.alerts
+        .push(Alert::new(AlertLevel::Warning, "risk", "Exposure", "high"));
+    app.alerts.push(feed_down());
+    assert_eq!(app.alerts.entries().len(), 2);
+    assert_eq!(app.alerts.entries()[0].count, 2);
+    assert_eq!(app.alerts.unacknowledged_count(), 2);
+
+    // Ack only works on the alerts tab
+    app.acknowledge_alert();
+    assert_eq!(app.alerts.unacknowledged_count(), 2);
+    app.toggle_tab();
+    app.toggle_tab();
+    app.acknowledge_alert();
+    assert_eq!(app.alerts.unacknowledged_count(), 1);
+
+    app.goto_alert_strategy();
+    assert_eq!(app.focused_agent.as_deref(), Some("sol-momentum"));
+
+    // After a restart the acknowledged alert stays suppressed
+    let mut restarted = AlertCenter::load(&path).unwrap();
+    restarted.push(feed_down());
+    assert!(restarted.entries()[0].acknowledged);
+    assert_eq!(restarted.unacknowledged_count(), 0);
+    let _ = std::fs::remove_file(&path);
+}
+
+#[test]
+fn test_equity_curve_tracks_session_pnl_and_drawdown() {
+    use crate::domain::Side;
+
+    let mut app = TuiApp::new();
+    assert!(app.equity.bounds().is_none());
+
+    // 100 UP @ 0.40 marked at 0.50: +10 unrealized
+    app.update_position(Side::Up, 100, dec!(0.50), dec!(0.40));
+    assert_eq!(app.session_pnl(), dec!(10));
+    // Mark drops to 0.30: -10
+    app.update_position(Side::Up, 100, dec!(0.30), dec!(0.40));
+    // Sell half at 0.45: realize +2.5, remaining 50 marked at 0.45 is +2.5
+    app.update_position(Side::Up, 50, dec!(0.45), dec!(0.40));
+    assert_eq!(app.realized_pnl, dec!(2.5));
+    assert_eq!(app.session_pnl(), dec!(5));
+
+    assert_eq!(app.equity.points().len(), 3);
+    assert!((app.equity.max_drawdown() - 20.0).abs() < 1e-9);
+    let (peak, trough) = app.equity.drawdown_markers().unwrap();
+    assert!((peak.1 - 10.0).abs() < 1e-9);
+    assert!((trough.1 + 10.0).abs() < 1e-9);
+    assert_eq!(app.equity.last(), Some(5.0));
+}
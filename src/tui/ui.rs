@@ -15,10 +15,17 @@ use crate::tui::widgets;
 
 const KEYBINDINGS: &[(&str, &str)] = &[
     ("q / Ctrl+C", "Quit"),
-    ("j/k / Up/Down", "Scroll transactions"),
+    ("j/k / Up/Down", "Scroll transactions / select alert"),
     ("h / ?", "Toggle help"),
     ("[ / ] / Left/Right", "Switch market"),
-    ("Tab", "Toggle view"),
+    ("Tab", "Cycle view (portfolio/agents/alerts)"),
+    ("a", "Acknowledge alert (alerts view)"),
+    ("g", "Go to alert's strategy (alerts view)"),
+    ("t / T", "Chart timeframe longer/shorter"),
+    ("l", "Cycle log level"),
+    ("m", "Filter logs by module"),
+    ("Space", "Pause/resume logs"),
+    ("PgUp/PgDn", "Scroll logs"),
     ("/", "Filter transactions"),
     ("p", "Pause agents (confirm)"),
     ("r", "Resume agents (confirm)"),
@@ -32,6 +39,7 @@ pub fn render(f: &mut Frame, app: &TuiApp) {
     match app.active_tab {
         ActiveTab::Portfolio => render_portfolio(f, app),
         ActiveTab::AgentMonitor => render_agent_monitor(f, app),
+        ActiveTab::Alerts => render_alerts(f, app),
     }
 
     // Render help overlay on top of everything
@@ -52,31 +60,60 @@ pub fn render(f: &mut Frame, app: &TuiApp) {
 
 fn render_portfolio(f: &mut Frame, app: &TuiApp) {
     let chunks = Layout::vertical([
-        Constraint::Length(7), // Positions panel
+        Constraint::Length(8), // Positions panel
         Constraint::Length(5), // Market Analysis panel
+        Constraint::Length(8), // Price chart + equity curve panels
         Constraint::Length(5), // Risk panel
         Constraint::Min(8),    // Transactions panel (fills remaining)
+        Constraint::Length(8), // Log tail panel
         Constraint::Length(1), // Footer status bar
     ])
     .split(f.area());
 
     widgets::render_positions(f, chunks[0], app);
     widgets::render_market_analysis(f, chunks[1], app);
-    widgets::render_risk_status(f, chunks[2], app);
-    widgets::render_transactions(f, chunks[3], app);
-    widgets::render_footer(f, chunks[4], app);
+    let charts = Layout::horizontal([Constraint::Percentage(60), Constraint::Percentage(40)])
+        .split(chunks[2]);
+    widgets::render_price_chart(f, charts[0], app);
+    widgets::render_equity_curve(f, charts[1], app);
+    widgets::render_risk_status(f, chunks[3], app);
+    widgets::render_transactions(f, chunks[4], app);
+    widgets::render_log_tail(f, chunks[5], app);
+    widgets::render_footer(f, chunks[6], app);
 }
 
 fn render_agent_monitor(f: &mut Frame, app: &TuiApp) {
     let chunks = Layout::vertical([
         Constraint::Length(5), // Risk panel
+        Constraint::Length(8), // Exposure heatmap
         Constraint::Min(10),   // Agents table
+        Constraint::Length(8), // Log tail panel
         Constraint::Length(1), // Footer
     ])
     .split(f.area());
 
     widgets::render_risk_status(f, chunks[0], app);
-    widgets::render_agent_status(f, chunks[1], &app.agent_snapshots);
+    widgets::render_exposure_heatmap(f, chunks[1], app.exposure_heatmap.as_ref());
+    widgets::render_agent_status(
+        f,
+        chunks[2],
+        &app.agent_snapshots,
+        app.focused_agent.as_deref(),
+    );
+    widgets::render_log_tail(f, chunks[3], app);
+    widgets::render_footer(f, chunks[4], app);
+}
+
+fn render_alerts(f: &mut Frame, app: &TuiApp) {
+    let chunks = Layout::vertical([
+        Constraint::Min(10),   // Alert list and details
+        Constraint::Length(8), // Log tail panel
+        Constraint::Length(1), // Footer
+    ])
+    .split(f.area());
+
+    widgets::render_alert_center(f, chunks[0], app);
+    widgets::render_log_tail(f, chunks[1], app);
     widgets::render_footer(f, chunks[2], app);
 }
 
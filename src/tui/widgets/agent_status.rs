@@ -5,8 +5,13 @@ use ratatui::widgets::{Block, Borders, Cell, Row, Table};
 
 use crate::tui::data::DisplayAgent;
 
-/// Render the agent status panel
-pub fn render_agent_status(f: &mut Frame, area: Rect, agents: &[DisplayAgent]) {
+/// Render the agent status panel, highlighting the `focused` agent (by id or name)
+pub fn render_agent_status(
+    f: &mut Frame,
+    area: Rect,
+    agents: &[DisplayAgent],
+    focused: Option<&str>,
+) {
     let header_cells = [
         "Agent",
         "Domain",
@@ -33,6 +38,7 @@ pub fn render_agent_status(f: &mut Frame, area: Rect, agents: &[DisplayAgent]) {
         let status_color = match a.status.as_str() {
             "Running" => Color::Green,
             "Paused" => Color::Yellow,
+            "Standby" => Color::Cyan,
             "Stopped" => Color::Red,
             "Error" => Color::Red,
             _ => Color::Gray,
@@ -57,7 +63,7 @@ pub fn render_agent_status(f: &mut Frame, area: Rect, agents: &[DisplayAgent]) {
             .map(|m| format!("{:.2}x", m))
             .unwrap_or_else(|| "-".to_string());
 
-        Row::new(vec![
+        let row = Row::new(vec![
             Cell::from(a.name.clone()).style(Style::default().fg(Color::White)),
             Cell::from(a.domain.clone()).style(Style::default().fg(Color::Magenta)),
             Cell::from(a.status.clone()).style(Style::default().fg(status_color)),
@@ -68,7 +74,12 @@ pub fn render_agent_status(f: &mut Frame, area: Rect, agents: &[DisplayAgent]) {
             Cell::from(loss_streak).style(Style::default().fg(Color::White)),
             Cell::from(multiplier).style(Style::default().fg(Color::White)),
             Cell::from(a.last_heartbeat.clone()).style(Style::default().fg(Color::DarkGray)),
-        ])
+        ]);
+        if focused.is_some_and(|id| id == a.agent_id || id == a.name) {
+            row.style(Style::default().bg(Color::DarkGray))
+        } else {
+            row
+        }
     });
 
     let table = Table::new(
@@ -0,0 +1,131 @@
+//! Alert center widget
+//!
+//! Displays alert manager alerts with severity colors, repeat counts and
+//! acknowledgement state, plus details for the selected alert.
+
+use ratatui::{
+    layout::{Constraint, Layout, Rect},
+    style::{Modifier, Style},
+    text::{Line, Span},
+    widgets::{Block, Borders, Cell, Paragraph, Row, Table},
+    Frame,
+};
+
+use crate::supervisor::AlertLevel;
+use crate::tui::alerts::AlertEntry;
+use crate::tui::app::TuiApp;
+use crate::tui::theme::THEME;
+
+fn level_style(level: AlertLevel) -> Style {
+    match level {
+        AlertLevel::Critical => THEME.loss_style().add_modifier(Modifier::BOLD),
+        AlertLevel::Error => THEME.loss_style(),
+        AlertLevel::Warning => THEME.highlight_style(),
+        AlertLevel::Info => THEME.text_style(),
+    }
+}
+
+fn alert_row(entry: &AlertEntry, selected: bool) -> Row<'_> {
+    let (level_style, text_style) = if entry.acknowledged {
+        (THEME.inactive_style(), THEME.inactive_style())
+    } else {
+        (level_style(entry.level), THEME.text_style())
+    };
+    let ack = if entry.acknowledged { "ACK" } else { "" };
+
+    let row = Row::new(vec![
+        Cell::from(entry.last_seen.format("%H:%M:%S").to_string()).style(THEME.inactive_style()),
+        Cell::from(entry.level.as_str().to_uppercase()).style(level_style),
+        Cell::from(format!("x{}", entry.count)).style(text_style),
+        Cell::from(entry.strategy.as_str()).style(text_style),
+        Cell::from(entry.title.as_str()).style(text_style),
+        Cell::from(ack).style(THEME.inactive_style()),
+    ]);
+    if selected {
+        row.style(Style::default().add_modifier(Modifier::REVERSED))
+    } else {
+        row
+    }
+}
+
+/// Render the alert center
+pub fn render_alert_center(f: &mut Frame, area: Rect, app: &TuiApp) {
+    let center = &app.alerts;
+    let chunks = Layout::vertical([
+        Constraint::Min(5),    // Alert list
+        Constraint::Length(5), // Selected alert details
+    ])
+    .split(area);
+
+    let title = format!(
+        " ALERTS [{} open / {}] ",
+        center.unacknowledged_count(),
+        center.entries().len()
+    );
+    let header = Row::new(["Time", "Level", "Count", "Strategy", "Title", ""])
+        .style(THEME.title_style())
+        .height(1);
+
+    // Keep the selected row in view
+    let visible = chunks[0].height.saturating_sub(3) as usize;
+    let skip = (center.selected + 1).saturating_sub(visible);
+    let rows = center
+        .entries()
+        .iter()
+        .enumerate()
+        .skip(skip)
+        .map(|(i, e)| alert_row(e, i == center.selected));
+
+    let table = Table::new(
+        rows,
+        [
+            Constraint::Length(9),
+            Constraint::Length(9),
+            Constraint::Length(6),
+            Constraint::Length(20),
+            Constraint::Min(20),
+            Constraint::Length(4),
+        ],
+    )
+    .header(header)
+    .block(
+        Block::default()
+            .title(title)
+            .title_style(THEME.title_style())
+            .borders(Borders::ALL)
+            .border_style(THEME.border_style()),
+    );
+    f.render_widget(table, chunks[0]);
+
+    let details = match center.selected_entry() {
+        Some(e) => vec![
+            Line::from(vec![
+                Span::styled(format!("  [{}] ", e.component), THEME.border_style()),
+                Span::styled(e.message.as_str(), level_style(e.level)),
+            ]),
+            Line::from(Span::styled(
+                format!(
+                    "  first {}  last {}  strategy {}",
+                    e.first_seen.format("%Y-%m-%d %H:%M:%S"),
+                    e.last_seen.format("%Y-%m-%d %H:%M:%S"),
+                    e.strategy
+                ),
+                THEME.inactive_style(),
+            )),
+            Line::from(Span::styled(
+                "  a = acknowledge    g = go to strategy",
+                THEME.inactive_style(),
+            )),
+        ],
+        None => vec![Line::from(Span::styled(
+            "  No alerts",
+            THEME.inactive_style(),
+        ))],
+    };
+    let block = Block::default()
+        .title(" DETAILS ")
+        .title_style(THEME.title_style())
+        .borders(Borders::ALL)
+        .border_style(THEME.border_style());
+    f.render_widget(Paragraph::new(details).block(block), chunks[1]);
+}
@@ -0,0 +1,82 @@
+//! Session equity curve widget
+//!
+//! Plots cumulative realized + unrealized PnL as a braille line chart and
+//! marks the peak and trough of the maximum drawdown.
+
+use ratatui::{
+    layout::Rect,
+    symbols::Marker,
+    text::{Line, Span},
+    widgets::{Axis, Block, Borders, Chart, Dataset, GraphType, Paragraph},
+    Frame,
+};
+
+use crate::tui::app::TuiApp;
+use crate::tui::theme::THEME;
+
+/// Render the session equity curve panel
+pub fn render_equity_curve(f: &mut Frame, area: Rect, app: &TuiApp) {
+    let equity = &app.equity;
+    let last = equity.last().unwrap_or(0.0);
+    let title = format!(
+        " SESSION PNL ${:+.2} | MAX DD ${:.2} ",
+        last,
+        equity.max_drawdown()
+    );
+    let block = Block::default()
+        .title(title)
+        .title_style(THEME.title_style())
+        .borders(Borders::ALL)
+        .border_style(THEME.border_style());
+
+    let Some((x_bounds, [lo, hi])) = equity.bounds() else {
+        let empty = Paragraph::new(Line::from(Span::styled(
+            "  Waiting for fills...",
+            THEME.inactive_style(),
+        )))
+        .block(block);
+        f.render_widget(empty, area);
+        return;
+    };
+
+    // Always include zero so the sign of the PnL is readable
+    let pad = ((hi - lo) * 0.1).max(0.01);
+    let y_bounds = [lo.min(0.0) - pad, hi.max(0.0) + pad];
+
+    let points = equity.points();
+    let markers: Vec<(f64, f64)> = equity
+        .drawdown_markers()
+        .map(|(peak, trough)| vec![peak, trough])
+        .unwrap_or_default();
+
+    let datasets = vec![
+        Dataset::default()
+            .marker(Marker::Braille)
+            .graph_type(GraphType::Line)
+            .style(THEME.pnl_style(last >= 0.0))
+            .data(&points),
+        Dataset::default()
+            .marker(Marker::Dot)
+            .graph_type(GraphType::Scatter)
+            .style(THEME.highlight_style())
+            .data(&markers),
+    ];
+
+    let chart = Chart::new(datasets)
+        .block(block)
+        .x_axis(
+            Axis::default()
+                .style(THEME.inactive_style())
+                .bounds(x_bounds),
+        )
+        .y_axis(
+            Axis::default()
+                .style(THEME.inactive_style())
+                .bounds(y_bounds)
+                .labels([
+                    Span::raw(format!("{:.2}", y_bounds[0])),
+                    Span::raw(format!("{:.2}", y_bounds[1])),
+                ]),
+        );
+    f.render_widget(chart, area);
+}
@@ -0,0 +1,99 @@
+//! Exposure Heatmap widget — exposure by symbol x time-to-settlement
+
+use ratatui::prelude::*;
+use ratatui::widgets::{Block, Borders, Cell, Paragraph, Row, Table};
+use rust_decimal::Decimal;
+
+use crate::coordinator::ExposureHeatmap;
+
+/// Render symbols as rows and settlement buckets as columns, coloring each
+/// cell by its share of total exposure. The first alert goes in the title.
+pub fn render_exposure_heatmap(f: &mut Frame, area: Rect, heatmap: Option<&ExposureHeatmap>) {
+    let Some(heatmap) = heatmap else {
+        let block = Block::default()
+            .borders(Borders::ALL)
+            .title(" Exposure Heatmap ")
+            .border_style(Style::default().fg(Color::DarkGray));
+        let text = Paragraph::new("waiting for coordinator exposure...")
+            .style(Style::default().fg(Color::DarkGray))
+            .block(block);
+        f.render_widget(text, area);
+        return;
+    };
+
+    let header = Row::new(
+        std::iter::once("Symbol")
+            .chain(heatmap.buckets.iter().map(String::as_str))
+            .map(|h| {
+                Cell::from(h.to_string()).style(
+                    Style::default()
+                        .fg(Color::Cyan)
+                        .add_modifier(Modifier::BOLD),
+                )
+            }),
+    );
+
+    let cell_color = |exposure: Decimal| {
+        if exposure.is_zero() || heatmap.total_exposure.is_zero() {
+            return Color::DarkGray;
+        }
+        let share = exposure / heatmap.total_exposure;
+        if share > heatmap.thresholds.concentration_pct {
+            Color::Red
+        } else if share > heatmap.thresholds.concentration_pct / Decimal::TWO {
+            Color::Yellow
+        } else {
+            Color::Green
+        }
+    };
+
+    let rows = heatmap.by_symbol.iter().map(|symbol| {
+        let symbol_style = if symbol.concentrated {
+            Style::default().fg(Color::Red).add_modifier(Modifier::BOLD)
+        } else {
+            Style::default().fg(Color::White)
+        };
+        let cells = heatmap.buckets.iter().map(|bucket| {
+            let exposure = heatmap.exposure_at(&symbol.key, bucket);
+            let text = if exposure.is_zero() {
+                "-".to_string()
+            } else {
+                format!("${}", exposure.round_dp(0))
+            };
+            Cell::from(text).style(Style::default().fg(cell_color(exposure)))
+        });
+        Row::new(std::iter::once(Cell::from(symbol.key.clone()).style(symbol_style)).chain(cells))
+    });
+
+    let mut widths = vec![Constraint::Length(10)];
+    widths.extend(heatmap.buckets.iter().map(|_| Constraint::Length(10)));
+
+    let (title, title_color) = match heatmap.alerts.first() {
+        Some(alert) => (
+            format!(
+                " Exposure Heatmap ${} | {} ",
+                heatmap.total_exposure.round_dp(2),
+                alert
+            ),
+            Color::Red,
+        ),
+        None => (
+            format!(" Exposure Heatmap ${} ", heatmap.total_exposure.round_dp(2)),
+            Color::Green,
+        ),
+    };
+
+    let table = Table::new(rows, widths).header(header).block(
+        Block::default()
+            .borders(Borders::ALL)
+            .title(title)
+            .title_style(
+                Style::default()
+                    .fg(title_color)
+                    .add_modifier(Modifier::BOLD),
+            )
+            .border_style(Style::default().fg(Color::DarkGray)),
+    );
+
+    f.render_widget(table, area);
+}
@@ -65,6 +65,16 @@ pub fn render_footer(f: &mut Frame, area: Rect, app: &TuiApp) {
     };
     indicators.push(conn_indicator);
 
+    // Unacknowledged alerts
+    let open_alerts = app.alerts.unacknowledged_count();
+    if open_alerts > 0 {
+        indicators.push(Span::raw(" "));
+        indicators.push(Span::styled(
+            format!("[ALERTS: {}]", open_alerts),
+            THEME.loss_style(),
+        ));
+    }
+
     // Show truncated error if present
     if let Some(ref err) = stats.last_error {
         let truncated = if err.len() > 30 { &err[..30] } else { err };
@@ -0,0 +1,75 @@
+//! Log tail panel widget
+//!
+//! Displays recent tracing output with level/module filtering.
+
+use ratatui::{
+    layout::Rect,
+    style::{Modifier, Style},
+    text::{Line, Span},
+    widgets::{Block, Borders, Paragraph},
+    Frame,
+};
+use tracing::Level;
+
+use crate::tui::app::TuiApp;
+use crate::tui::logs::LogLine;
+use crate::tui::theme::THEME;
+
+fn level_style(level: Level) -> Style {
+    match level {
+        Level::ERROR => THEME.loss_style().add_modifier(Modifier::BOLD),
+        Level::WARN => THEME.highlight_style(),
+        Level::INFO => THEME.text_style(),
+        Level::DEBUG | Level::TRACE => THEME.inactive_style(),
+    }
+}
+
+fn render_line(line: &LogLine) -> Line<'_> {
+    let style = level_style(line.level);
+    // ERROR lines are highlighted across the whole row
+    let message_style = if line.level == Level::ERROR {
+        style
+    } else {
+        THEME.text_style()
+    };
+
+    Line::from(vec![
+        Span::styled(
+            format!(" {} ", line.at.format("%H:%M:%S")),
+            THEME.inactive_style(),
+        ),
+        Span::styled(format!("{:<5} ", line.level), style),
+        Span::styled(format!("{}: ", line.target), THEME.inactive_style()),
+        Span::styled(line.message.as_str(), message_style),
+    ])
+}
+
+/// Render the log tail panel
+pub fn render_log_tail(f: &mut Frame, area: Rect, app: &TuiApp) {
+    let logs = &app.logs;
+    let mut title = format!(" LOGS [>={}] ", logs.min_level);
+    if logs.editing {
+        title.push_str(&format!("[MODULE: {}_] ", logs.module_filter));
+    } else if !logs.module_filter.is_empty() {
+        title.push_str(&format!("[MODULE: {}] ", logs.module_filter));
+    }
+    if logs.is_paused() {
+        title.push_str("[PAUSED] ");
+    }
+
+    let block = Block::default()
+        .title(title)
+        .title_style(THEME.title_style())
+        .borders(Borders::ALL)
+        .border_style(THEME.border_style());
+
+    let inner = block.inner(area);
+    f.render_widget(block, area);
+
+    let lines: Vec<Line> = logs
+        .visible(inner.height as usize)
+        .into_iter()
+        .map(render_line)
+        .collect();
+    f.render_widget(Paragraph::new(lines), inner);
+}
@@ -11,6 +11,7 @@ use ratatui::{
 use rust_decimal::Decimal;
 use rust_decimal_macros::dec;
 
+use crate::domain::Side;
 use crate::tui::app::TuiApp;
 use crate::tui::theme::THEME;
 
@@ -48,7 +49,7 @@ pub fn render_market_analysis(f: &mut Frame, area: Rect, app: &TuiApp) {
         THEME.loss_style()
     };
 
-    let line1 = Line::from(vec![
+    let mut line1 = vec![
         Span::raw("  UP: "),
         Span::styled(format!("${:.4}", market.up_price), THEME.up_style()),
         Span::raw("   DOWN: "),
@@ -57,9 +58,19 @@ pub fn render_market_analysis(f: &mut Frame, area: Rect, app: &TuiApp) {
         Span::styled(format!("${:.4}", market.combined), combined_style),
         Span::raw("   Spread: "),
         Span::styled(format!("{:+.2}%", market.spread_pct), spread_style),
-    ]);
+    ];
+    // Untradable badges from the anomaly detector
+    for side in [Side::Up, Side::Down] {
+        if let Some(reason) = app.untradable.get(&side) {
+            line1.push(Span::raw("   "));
+            line1.push(Span::styled(
+                format!("[UNTRADABLE {}: {}]", side.as_str(), reason),
+                THEME.loss_style(),
+            ));
+        }
+    }
 
-    f.render_widget(Paragraph::new(line1), chunks[0]);
+    f.render_widget(Paragraph::new(Line::from(line1)), chunks[0]);
 
     // Line 2: Pairs, Delta, Total PnL
     let pnl_style = THEME.pnl_style(market.total_pnl >= Decimal::ZERO);
@@ -3,15 +3,25 @@
 //! Modular widgets for the dashboard display.
 
 pub mod agent_status;
+pub mod alert_center;
+pub mod equity_curve;
+pub mod exposure_heatmap;
 pub mod footer;
+pub mod log_tail;
 pub mod market_analysis;
 pub mod positions;
+pub mod price_chart;
 pub mod risk_status;
 pub mod transactions;
 
 pub use agent_status::render_agent_status;
+pub use alert_center::render_alert_center;
+pub use equity_curve::render_equity_curve;
+pub use exposure_heatmap::render_exposure_heatmap;
 pub use footer::render_footer;
+pub use log_tail::render_log_tail;
 pub use market_analysis::render_market_analysis;
 pub use positions::render_positions;
+pub use price_chart::render_price_chart;
 pub use risk_status::render_risk_status;
 pub use transactions::render_transactions;
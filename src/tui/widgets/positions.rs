@@ -1,6 +1,6 @@
 //! Positions panel widget
 //!
-//! Displays UP/DOWN positions with progress bars and PnL.
+//! Displays UP/DOWN positions with progress bars, PnL and Greeks.
 
 use ratatui::{
     layout::{Constraint, Layout, Rect},
@@ -54,6 +54,7 @@ fn render_position_row(f: &mut Frame, area: Rect, pos: &DisplayPosition) {
     let chunks = Layout::vertical([
         Constraint::Length(1), // Main line with progress bar
         Constraint::Length(1), // Cost/avg line
+        Constraint::Length(1), // Greeks line
     ])
     .split(area);
 
@@ -112,6 +113,21 @@ fn render_position_row(f: &mut Frame, area: Rect, pos: &DisplayPosition) {
     ]);
 
     f.render_widget(Paragraph::new(detail_line), chunks[1]);
+
+    // Greeks line (needs spot, strike and round end)
+    if let Some(g) = pos.greeks {
+        let greeks_line = Line::from(vec![
+            Span::raw("        "),
+            Span::styled(
+                format!(
+                    "Δ ${:+.2}/1% | Θ ${:+.2}/min | V ${:+.2}/10%vol",
+                    g.delta, g.theta, g.vega
+                ),
+                THEME.inactive_style(),
+            ),
+        ]);
+        f.render_widget(Paragraph::new(greeks_line), chunks[2]);
+    }
 }
 
 /// Format share count with commas
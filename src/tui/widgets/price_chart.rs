@@ -0,0 +1,88 @@
+//! Price chart panel widget
+//!
+//! Displays mid-price and spread sparklines for the UP and DOWN tokens.
+
+use ratatui::{
+    layout::{Constraint, Layout, Rect},
+    style::Style,
+    text::{Line, Span},
+    widgets::{Block, Borders, Paragraph, Sparkline},
+    Frame,
+};
+
+use crate::domain::Side;
+use crate::tui::app::TuiApp;
+use crate::tui::candles::Candle;
+use crate::tui::theme::THEME;
+
+/// Scale values to sparkline bars relative to the series minimum.
+///
+/// Bars are offset by one so a flat series still renders.
+fn to_bars(values: &[f64]) -> Vec<u64> {
+    let min = values.iter().copied().fold(f64::INFINITY, f64::min);
+    values
+        .iter()
+        .map(|v| ((v - min) * 10_000.0).round() as u64 + 1)
+        .collect()
+}
+
+fn render_series(f: &mut Frame, area: Rect, label: Line, values: &[f64], style: Style) {
+    let rows = Layout::vertical([Constraint::Length(1), Constraint::Min(1)]).split(area);
+    f.render_widget(Paragraph::new(label), rows[0]);
+
+    let bars = to_bars(values);
+    f.render_widget(Sparkline::default().data(&bars).style(style), rows[1]);
+}
+
+fn render_token(f: &mut Frame, area: Rect, side: Side, candles: &[Candle]) {
+    let (name, style) = match side {
+        Side::Up => ("UP", THEME.up_style()),
+        Side::Down => ("DOWN", THEME.down_style()),
+    };
+    let rows = Layout::vertical([Constraint::Ratio(1, 2), Constraint::Ratio(1, 2)]).split(area);
+
+    let mids: Vec<f64> = candles.iter().map(|c| c.mid).collect();
+    let spreads: Vec<f64> = candles.iter().map(|c| c.spread).collect();
+    let last = candles.last();
+
+    let mid_label = Line::from(vec![
+        Span::styled(format!("  {} mid ", name), style),
+        Span::styled(
+            last.map_or("--".to_string(), |c| format!("{:.4}", c.mid)),
+            THEME.text_style(),
+        ),
+    ]);
+    let spread_label = Line::from(vec![
+        Span::styled("  spread ", THEME.inactive_style()),
+        Span::styled(
+            last.map_or("--".to_string(), |c| format!("{:.4}", c.spread)),
+            THEME.text_style(),
+        ),
+    ]);
+
+    render_series(f, rows[0], mid_label, &mids, style);
+    render_series(f, rows[1], spread_label, &spreads, THEME.highlight_style());
+}
+
+/// Render the price chart panel
+pub fn render_price_chart(f: &mut Frame, area: Rect, app: &TuiApp) {
+    let title = format!(" PRICE CHART [{}] ", app.chart_timeframe.label());
+    let block = Block::default()
+        .title(title)
+        .title_style(THEME.title_style())
+        .borders(Borders::ALL)
+        .border_style(THEME.border_style());
+
+    let inner = block.inner(area);
+    f.render_widget(block, area);
+
+    let columns =
+        Layout::horizontal([Constraint::Percentage(50), Constraint::Percentage(50)]).split(inner);
+    // One candle per terminal column
+    let limit = columns[0].width as usize;
+
+    for (side, column) in [Side::Up, Side::Down].into_iter().zip(columns.iter()) {
+        let candles = app.candles.candles(side, app.chart_timeframe, limit);
+        render_token(f, *column, side, &candles);
+    }
+}
@@ -241,12 +241,54 @@ impl TestContext {
     }
 }
 
+fn passing_overfit_payload() -> Value {
+    json!({
+        "overfit": {
+            "deflated_sharpe": {
+                "sharpe": 0.4,
+                "observations": 200,
+                "trials": 9,
+                "expected_max_sharpe": 0.1,
+                "probability": 0.99
+            },
+            "neighborhood": {
+                "selected_score": 0.4,
+                "neighbors": 8,
+                "neighbor_mean": 0.35,
+                "neighbor_min": 0.3,
+                "ratio": 0.875
+            },
+            "cpcv": {
+                "splits": 15,
+                "oos_sharpe_mean": 0.3,
+                "oos_sharpe_min": 0.1,
+                "oos_positive_fraction": 1.0,
+                "pbo": 0.1
+            },
+            "passed": true,
+            "failures": []
+        }
+    })
+}
+
 async fn insert_evaluation(
     pool: &PgPool,
     strategy_id: &str,
     stage: &str,
     status: &str,
     evaluated_at: DateTime<Utc>,
+) {
+    let payload = (stage == "BACKTEST").then(passing_overfit_payload);
+    insert_evaluation_with_payload(pool, strategy_id, stage, status, evaluated_at, payload).await;
+}
+
+async fn insert_evaluation_with_payload(
+    pool: &PgPool,
+    strategy_id: &str,
+    stage: &str,
+    status: &str,
+    evaluated_at: DateTime<Utc>,
+    evidence_payload: Option<Value>,
 ) {
     let evidence_ref = format!(
         "s3://ploy-evidence/{}/{}/{}.json",
@@ -274,9 +316,10 @@ async fn insert_evaluation(
             status,
             evidence_kind,
             evidence_ref,
-            evidence_hash
+            evidence_hash,
+            evidence_payload
         )
-        VALUES ($1, $2, $3, NULL, $4, $5, $6, $7, $8, $9, $10)
+        VALUES ($1, $2, $3, NULL, $4, $5, $6, $7, $8, $9, $10, $11)
         "#,
     )
     .bind("default")
@@ -289,6 +332,7 @@ async fn insert_evaluation(
     .bind("report")
     .bind(evidence_ref)
     .bind(evidence_hash)
+    .bind(evidence_payload)
     .execute(pool)
     .await
     .expect("failed to insert strategy evaluation");
@@ -668,6 +712,72 @@ async fn deployment_enable_rejects_untraceable_evidence() {
     );
 }
 
+#[tokio::test]
+async fn deployment_enable_rejects_backtest_without_passing_overfit_scores() {
+    let _guard = env_lock().lock().expect("failed to acquire env lock");
+    let Some(ctx) = TestContext::new(&[
+        ("PLOY_DEPLOYMENTS_REQUIRE_EVIDENCE", "true"),
+        ("PLOY_DEPLOYMENTS_REQUIRED_STAGES", "backtest"),
+        ("PLOY_DEPLOYMENTS_MAX_EVIDENCE_AGE_HOURS", "24"),
+    ])
+    .await
+    else {
+        return;
+    };
+
+    let deployment_id = "dep-overfit";
+    let strategy_id = "strategy-overfit";
+    upsert_disabled_deployment(&ctx.app, deployment_id, strategy_id).await;
+    let enable_uri = format!("/api/deployments/{deployment_id}/enable");
+    let admin = [("x-ploy-admin-token", "admin-test-token")];
+
+    insert_evaluation_with_payload(
+        &ctx.pool,
+        strategy_id,
+        "BACKTEST",
+        "PASS",
+        Utc::now() - ChronoDuration::minutes(30),
+        None,
+    )
+    .await;
+    let (status, body) = send_json(&ctx.app, Method::POST, &enable_uri, &admin, None).await;
+    assert_eq!(status, StatusCode::UNPROCESSABLE_ENTITY, "{body}");
+    assert!(
+        body.contains("missing overfit scores"),
+        "expected missing-overfit error, got: {body}"
+    );
+
+    let mut failed = passing_overfit_payload();
+    failed["overfit"]["passed"] = json!(false);
+    failed["overfit"]["failures"] = json!(["PBO 0.700 > 0.500"]);
+    insert_evaluation_with_payload(
+        &ctx.pool,
+        strategy_id,
+        "BACKTEST",
+        "PASS",
+        Utc::now() - ChronoDuration::minutes(20),
+        Some(failed),
+    )
+    .await;
+    let (status, body) = send_json(&ctx.app, Method::POST, &enable_uri, &admin, None).await;
+    assert_eq!(status, StatusCode::UNPROCESSABLE_ENTITY, "{body}");
+    assert!(
+        body.contains("PBO 0.700"),
+        "expected PBO failure, got: {body}"
+    );
+
+    insert_evaluation(
+        &ctx.pool,
+        strategy_id,
+        "BACKTEST",
+        "PASS",
+        Utc::now() - ChronoDuration::minutes(10),
+    )
+    .await;
+    let (status, body) = send_json(&ctx.app, Method::POST, &enable_uri, &admin, None).await;
+    assert_eq!(status, StatusCode::OK, "{body}");
+}
+
 #[tokio::test]
 async fn sidecar_positions_fallback_is_fail_closed_without_account_scope() {
     let _guard = env_lock().lock().expect("failed to acquire env lock");